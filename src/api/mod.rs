@@ -0,0 +1,375 @@
+//! Library API: an async Linear client with typed request builders
+//!
+//! Errors are [`ClientError`]s, which carry no CLI types; exit codes are only
+//! a convenience for binaries. Entities are returned as `serde_json::Value`
+//! with the fields the request selected, or deserialized into any type with
+//! the `*_as` methods; the `models` feature generates one per schema type.
+
+use futures::stream::{Stream, TryStreamExt};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::time::Duration;
+
+use crate::client::Client;
+use crate::common::constants::{client as client_constants, env, pagination};
+use crate::mutation_builder::{
+    build_archive_mutation, build_create_mutation, build_delete_mutation, build_update_mutation,
+};
+use crate::query_builder::{build_get_query, build_get_query_with_fields, build_list_query, ListQuery};
+
+pub use crate::client::{ClientError, GraphQLError, GraphQLRequest, HttpOptions, RetryPolicy};
+pub use crate::common::FieldsetPreset;
+#[cfg(feature = "models")]
+pub use crate::generated::models;
+pub use crate::generated::{OrderBy, Resource};
+pub use crate::paginate::{ConnectionPage, Direction, PageArgs, Paginator};
+pub use crate::render::PageInfo;
+
+/// Result type of the library API
+pub type Result<T> = std::result::Result<T, ClientError>;
+
+/// Async client for Linear's GraphQL API
+pub struct LinearClient {
+    client: Client,
+}
+
+impl LinearClient {
+    /// Client for the public API with default settings
+    pub fn new(api_key: &str) -> Result<Self> {
+        Self::builder(api_key).build()
+    }
+
+    /// Client configured from `LINEARS_API_KEY`, `LINEARS_ENDPOINT` and `LINEARS_WORKSPACE`
+    pub fn from_env() -> Result<Self> {
+        let api_key = std::env::var(env::API_KEY)
+            .ok()
+            .filter(|key| !key.trim().is_empty())
+            .ok_or_else(|| ClientError::Auth(format!("{} is not set", env::API_KEY)))?;
+        let mut builder = Self::builder(&api_key);
+        if let Ok(endpoint) = std::env::var(env::ENDPOINT) {
+            builder = builder.endpoint(&endpoint);
+        }
+        if let Ok(workspace) = std::env::var(env::WORKSPACE) {
+            builder = builder.workspace(&workspace);
+        }
+        builder.build()
+    }
+
+    /// Configure endpoint, timeout, transport and retries before building
+    pub fn builder(api_key: &str) -> LinearClientBuilder {
+        LinearClientBuilder {
+            api_key: api_key.to_string(),
+            endpoint: None,
+            timeout: Duration::from_secs(client_constants::DEFAULT_TIMEOUT_SECS),
+            workspace: None,
+            http: HttpOptions::default(),
+            retry: RetryPolicy::default(),
+            partial_results: false,
+        }
+    }
+
+    /// Wrap an already configured low-level client
+    pub fn from_client(client: Client) -> Self {
+        Self { client }
+    }
+
+    /// The underlying client, for raw requests and rate-limit state
+    pub fn client(&self) -> &Client {
+        &self.client
+    }
+
+    /// List a resource's connection (`Resource::Issues`, `Resource::Teams`, ...)
+    pub fn list(&self, resource: Resource) -> ListRequest<'_> {
+        ListRequest {
+            client: &self.client,
+            resource,
+            query: ListQuery::default(),
+            limit: None,
+        }
+    }
+
+    /// Fetch one entity by ID (or identifier/key where Linear accepts one)
+    pub async fn get(&self, resource: Resource, id: &str) -> Result<serde_json::Value> {
+        let (query, variables) = build_get_query(resource, id);
+        let data = self.execute(&query, variables).await?;
+        Ok(data[resource.field_name()].clone())
+    }
+
+    /// Fetch one entity and deserialize it, e.g. into `models::Issue`
+    pub async fn get_as<T: DeserializeOwned>(&self, resource: Resource, id: &str) -> Result<T> {
+        from_value(self.get(resource, id).await?)
+    }
+
+    /// Fetch one entity with an explicit field selection
+    pub async fn get_with_fields(
+        &self,
+        resource: Resource,
+        id: &str,
+        fields: &[&str],
+    ) -> Result<serde_json::Value> {
+        let (query, variables) = build_get_query_with_fields(resource, id, &fields.join(" "));
+        let data = self.execute(&query, variables).await?;
+        Ok(data[resource.field_name()].clone())
+    }
+
+    /// Create an entity from a JSON or typed input (e.g. `models::IssueCreateInput`), returning it
+    pub async fn create(&self, resource: Resource, input: impl Serialize) -> Result<serde_json::Value> {
+        let (query, variables) = build_create_mutation(resource.field_name(), to_value(input)?);
+        let payload = self.mutate(&query, variables).await?;
+        Ok(payload[resource.field_name()].clone())
+    }
+
+    /// Update an entity, returning it
+    pub async fn update(
+        &self,
+        resource: Resource,
+        id: &str,
+        input: impl Serialize,
+    ) -> Result<serde_json::Value> {
+        let (query, variables) = build_update_mutation(resource.field_name(), id, to_value(input)?);
+        let payload = self.mutate(&query, variables).await?;
+        Ok(payload[resource.field_name()].clone())
+    }
+
+    /// Archive an entity, returning whether Linear reported success
+    pub async fn archive(&self, resource: Resource, id: &str) -> Result<bool> {
+        let (query, variables) = build_archive_mutation(resource.field_name(), id);
+        let payload = self.mutate(&query, variables).await?;
+        Ok(payload["success"].as_bool().unwrap_or(false))
+    }
+
+    /// Delete an entity, returning whether Linear reported success
+    pub async fn delete(&self, resource: Resource, id: &str) -> Result<bool> {
+        let (query, variables) = build_delete_mutation(resource.field_name(), id);
+        let payload = self.mutate(&query, variables).await?;
+        Ok(payload["success"].as_bool().unwrap_or(false))
+    }
+
+    /// Run any GraphQL document, returning its `data`
+    pub async fn execute(&self, query: &str, variables: serde_json::Value) -> Result<serde_json::Value> {
+        let request = GraphQLRequest {
+            query: query.to_string(),
+            variables: Some(variables),
+            operation_name: None,
+        };
+        let response = self.client.execute(request).await?;
+        Ok(response.data.unwrap_or_default())
+    }
+
+    /// Run a mutation, returning its payload (the root field's value)
+    async fn mutate(&self, query: &str, variables: serde_json::Value) -> Result<serde_json::Value> {
+        let data = self.execute(query, variables).await?;
+        data.as_object()
+            .and_then(|fields| fields.values().next().cloned())
+            .ok_or_else(|| ClientError::Other("Mutation returned no payload".to_string()))
+    }
+}
+
+/// Builder for [`LinearClient`]
+pub struct LinearClientBuilder {
+    api_key: String,
+    endpoint: Option<String>,
+    timeout: Duration,
+    workspace: Option<String>,
+    http: HttpOptions,
+    retry: RetryPolicy,
+    partial_results: bool,
+}
+
+impl LinearClientBuilder {
+    /// GraphQL endpoint (defaults to Linear's public API)
+    pub fn endpoint(mut self, endpoint: &str) -> Self {
+        self.endpoint = Some(endpoint.to_string());
+        self
+    }
+
+    /// Per-request timeout
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Workspace slug or ID (for multi-workspace API keys)
+    pub fn workspace(mut self, workspace: &str) -> Self {
+        self.workspace = Some(workspace.to_string());
+        self
+    }
+
+    /// Proxy, CA certificate, extra headers, user agent and compression
+    pub fn http_options(mut self, options: HttpOptions) -> Self {
+        self.http = options;
+        self
+    }
+
+    /// Retry and rate-limit behaviour
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry = policy;
+        self
+    }
+
+    /// Keep `data` when a response also carries errors
+    pub fn partial_results(mut self, enabled: bool) -> Self {
+        self.partial_results = enabled;
+        self
+    }
+
+    pub fn build(self) -> Result<LinearClient> {
+        let client = Client::new(
+            &self.api_key,
+            self.endpoint.as_deref(),
+            self.timeout.as_secs().max(1),
+            self.workspace.as_deref(),
+            &self.http,
+        )?
+        .with_retry_policy(self.retry)
+        .with_partial_results(self.partial_results);
+        Ok(LinearClient { client })
+    }
+}
+
+/// One page of a connection
+#[derive(Debug, Clone)]
+pub struct Page {
+    pub nodes: Vec<serde_json::Value>,
+    pub page_info: PageInfo,
+}
+
+/// Builder for a list request; finish with [`page`](Self::page),
+/// [`all`](Self::all) or [`stream`](Self::stream)
+pub struct ListRequest<'a> {
+    client: &'a Client,
+    resource: Resource,
+    query: ListQuery,
+    /// Stop after this many nodes
+    limit: Option<usize>,
+}
+
+impl<'a> ListRequest<'a> {
+    /// Filter expression, e.g. `{"state": {"type": {"eq": "started"}}}`
+    pub fn filter(mut self, filter: serde_json::Value) -> Self {
+        self.query.filter = Some(filter);
+        self
+    }
+
+    /// Scalar fields to select instead of the preset's
+    pub fn select<I, S>(mut self, fields: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.query.select = Some(fields.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Include a relation (`relation[:fields]`, e.g. `"assignee:name,email"`)
+    pub fn expand(mut self, spec: &str) -> Self {
+        self.query.expand.push(spec.to_string());
+        self
+    }
+
+    /// Field selection preset (ignored when fields are selected)
+    pub fn preset(mut self, preset: FieldsetPreset) -> Self {
+        self.query.preset = preset;
+        self
+    }
+
+    pub fn order_by(mut self, order_by: OrderBy) -> Self {
+        self.query.order_by = Some(order_by);
+        self
+    }
+
+    pub fn include_archived(mut self, include: bool) -> Self {
+        self.query.include_archived = include;
+        self
+    }
+
+    /// Page size (defaults to 50 when streaming)
+    pub fn first(mut self, first: i32) -> Self {
+        self.query.first = Some(first);
+        self
+    }
+
+    /// Start after this cursor
+    pub fn after(mut self, cursor: &str) -> Self {
+        self.query.after = Some(cursor.to_string());
+        self
+    }
+
+    /// Stop after this many nodes in total
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Fetch a single page
+    pub async fn page(self) -> Result<Page> {
+        fetch_page(self.client, self.resource, &self.query).await
+    }
+
+    /// Fetch every page (up to the limit) and collect the nodes
+    pub async fn all(self) -> Result<Vec<serde_json::Value>> {
+        self.stream().try_collect().await
+    }
+
+    /// Like [`all`](Self::all), deserializing each node
+    pub async fn all_as<T: DeserializeOwned>(self) -> Result<Vec<T>> {
+        self.stream_as().try_collect().await
+    }
+
+    /// Like [`stream`](Self::stream), deserializing each node
+    pub fn stream_as<T: DeserializeOwned>(self) -> impl Stream<Item = Result<T>> + 'a {
+        self.stream().and_then(|node| async move { from_value(node) })
+    }
+
+    /// Stream nodes, fetching further pages as the stream is consumed
+    pub fn stream(self) -> impl Stream<Item = Result<serde_json::Value>> + 'a {
+        let Self {
+            client,
+            resource,
+            mut query,
+            limit,
+        } = self;
+        let page_size = query.first.take().unwrap_or(pagination::PAGE_SIZE);
+        let start = query.after.take();
+
+        let paginator = Paginator::list(client, resource, query, false)
+            .page_size(page_size)
+            .cursor(start);
+        match limit {
+            Some(limit) => paginator.limit(limit),
+            None => paginator,
+        }
+        .stream()
+    }
+}
+
+fn from_value<T: DeserializeOwned>(value: serde_json::Value) -> Result<T> {
+    serde_json::from_value(value)
+        .map_err(|e| ClientError::Other(format!("Failed to deserialize response: {}", e)))
+}
+
+fn to_value(input: impl Serialize) -> Result<serde_json::Value> {
+    serde_json::to_value(input).map_err(|e| ClientError::Other(format!("Failed to serialize input: {}", e)))
+}
+
+async fn fetch_page(client: &Client, resource: Resource, query: &ListQuery) -> Result<Page> {
+    let (document, variables) = build_list_query(resource, query);
+    let response = client
+        .execute(GraphQLRequest {
+            query: document,
+            variables: Some(variables),
+            operation_name: None,
+        })
+        .await?;
+    let mut data = response.data.unwrap_or_default();
+    let connection = data[resource.plural_name()].take();
+    let nodes = match connection.get("nodes") {
+        Some(serde_json::Value::Array(nodes)) => nodes.clone(),
+        _ => Vec::new(),
+    };
+    let page_info = connection
+        .get("pageInfo")
+        .and_then(|p| serde_json::from_value(p.clone()).ok())
+        .unwrap_or_default();
+    Ok(Page { nodes, page_info })
+}
@@ -2,14 +2,18 @@
 
 use clap::{Args, Parser, Subcommand, ValueEnum};
 
-use crate::common::constants::{client as client_const, env as env_const};
-use crate::common::FieldsetPreset;
+use crate::common::constants::{
+    client as client_const, complexity as complexity_const, env as env_const, pagination as pagination_const,
+    webhook as webhook_const,
+};
+use crate::common::{FieldsetPreset, OutputFormat};
 use crate::generated::{MutationOp, OrderBy, Resource};
 
 use std::sync::OnceLock;
+use std::time::Duration;
 
 /// Schema metadata embedded at compile time
-const SCHEMA_META: &str = include_str!("../../schemas/linear/schema.meta.json");
+pub const SCHEMA_META: &str = include_str!("../../schemas/linear/schema.meta.json");
 
 /// Static storage for the computed version string
 static VERSION: OnceLock<String> = OnceLock::new();
@@ -81,21 +85,182 @@ pub struct GlobalOptions {
     /// Workspace slug or ID (for multi-workspace API keys)
     #[arg(long, env = env_const::WORKSPACE)]
     pub workspace: Option<String>,
+
+    /// Keep partial data when GraphQL also returns errors (exit code 5)
+    #[arg(long)]
+    pub partial: bool,
+
+    /// Complexity threshold for the pre-flight query estimate
+    #[arg(long, default_value_t = complexity_const::MAX_COMPLEXITY, env = env_const::MAX_COMPLEXITY)]
+    pub max_complexity: u64,
+
+    /// What to do when a query's estimated complexity exceeds --max-complexity
+    #[arg(long, value_enum, default_value = "warn")]
+    pub complexity_check: ComplexityCheck,
+
+    #[command(flatten)]
+    pub retry: RetryOptions,
+
+    #[command(flatten)]
+    pub cache: CacheOptions,
+
+    #[command(flatten)]
+    pub network: NetworkOptions,
+
+    /// Record GraphQL requests and responses as cassettes in DIR
+    #[arg(long, value_name = "DIR", conflicts_with = "replay")]
+    pub record: Option<String>,
+
+    /// Serve responses from cassettes in DIR without network access
+    #[arg(long, value_name = "DIR")]
+    pub replay: Option<String>,
+
+    /// How replayed requests are matched to cassettes
+    #[arg(long, value_enum, default_value = "strict", requires = "replay")]
+    pub replay_match: ReplayMode,
+
+    /// Write every HTTP exchange (headers redacted, bodies, timings) to FILE
+    #[arg(long, value_name = "FILE")]
+    pub trace: Option<String>,
+
+    /// Trace file format (default: har for .har files, jsonl otherwise)
+    #[arg(long, value_enum, requires = "trace")]
+    pub trace_format: Option<TraceFileFormat>,
+
+    /// Answer list, get and search from the local mirror (see `sync pull`)
+    #[arg(long, conflicts_with_all = ["record", "replay"])]
+    pub offline: bool,
 }
 
-/// Output format options
+/// File format for --trace
 #[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
-pub enum OutputFormat {
-    /// JSON output
-    Json,
-    /// YAML output
-    Yaml,
-    /// Table output (default)
-    Table,
-    /// Human-readable text for single entities
-    Text,
-    /// Newline-delimited JSON (one object per line)
-    Ndjson,
+pub enum TraceFileFormat {
+    /// HTTP Archive, for browser dev tools and HAR viewers
+    Har,
+    /// One JSON object per exchange
+    Jsonl,
+}
+
+/// Cassette matching for --replay
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ReplayMode {
+    /// Query and variables must match exactly
+    Strict,
+    /// Fall back to the same query, then the same operation name
+    Lenient,
+}
+
+/// Retry behaviour for failed requests
+#[derive(Args, Clone)]
+#[command(next_help_heading = "Retry options")]
+pub struct RetryOptions {
+    /// Maximum retries after the first attempt
+    #[arg(long, default_value_t = client_const::MAX_RETRIES, env = env_const::MAX_RETRIES)]
+    pub max_retries: u32,
+
+    /// Maximum retries for network errors and timeouts
+    #[arg(long, default_value_t = client_const::MAX_NETWORK_RETRIES)]
+    pub max_network_retries: u32,
+
+    /// Base delay for exponential backoff (ms)
+    #[arg(long, default_value_t = client_const::BASE_DELAY_MS)]
+    pub retry_base_delay: u64,
+
+    /// Maximum delay between retries (ms)
+    #[arg(long, default_value_t = client_const::MAX_DELAY_MS)]
+    pub retry_max_delay: u64,
+
+    /// Don't retry transient network errors (connection refused or reset)
+    #[arg(long)]
+    pub no_retry_network: bool,
+
+    /// Don't retry timed-out requests
+    #[arg(long)]
+    pub no_retry_timeout: bool,
+
+    /// Also retry timed-out mutations (they may already have been applied)
+    #[arg(long, conflicts_with = "no_retry_timeout")]
+    pub retry_mutation_timeouts: bool,
+
+    /// Total seconds to wait on rate limits before giving up
+    #[arg(long, default_value_t = client_const::MAX_RATE_LIMIT_WAIT_SECS, env = env_const::MAX_RATE_LIMIT_WAIT)]
+    pub max_rate_limit_wait: u64,
+}
+
+/// Response cache behaviour for read-only queries (off by default)
+#[derive(Args, Clone)]
+#[command(next_help_heading = "Cache options")]
+pub struct CacheOptions {
+    /// Cache slow-changing lookups: organization, teams and workflow states for 1h,
+    /// users and labels for 15m, projects and cycles for 5m
+    #[arg(long, env = env_const::CACHE, value_parser = clap::builder::FalseyValueParser::new())]
+    pub cache: bool,
+
+    /// Also cache every other query, for 60s (implies --cache)
+    #[arg(long)]
+    pub cache_all: bool,
+
+    /// Don't read or write the response cache (overrides --cache and LINEARS_CACHE)
+    #[arg(long, conflicts_with_all = ["cache_all", "refresh"])]
+    pub no_cache: bool,
+
+    /// Ignore cached responses and store fresh ones (implies --cache)
+    #[arg(long)]
+    pub refresh: bool,
+}
+
+/// Proxy, TLS and header settings for API requests
+#[derive(Args, Clone)]
+#[command(next_help_heading = "Network options")]
+pub struct NetworkOptions {
+    /// Proxy URL for all requests (HTTPS_PROXY and HTTP_PROXY are honored otherwise)
+    #[arg(long, value_name = "URL")]
+    pub proxy: Option<String>,
+
+    /// PEM file with extra CA certificates to trust
+    #[arg(long, value_name = "PEM", env = env_const::CA_CERT)]
+    pub ca_cert: Option<String>,
+
+    /// Extra header for API requests (repeatable; replaces LINEARS_HEADERS, one per line)
+    #[arg(
+        long = "header",
+        value_name = "NAME:VALUE",
+        value_parser = parse_header,
+        value_delimiter = '\n',
+        env = env_const::HEADERS
+    )]
+    pub headers: Vec<(String, String)>,
+
+    /// User-Agent header for API requests
+    #[arg(long, value_name = "UA")]
+    pub user_agent: Option<String>,
+
+    /// Gzip request bodies
+    #[arg(long)]
+    pub compress: bool,
+}
+
+/// Parse a `--header NAME:VALUE` argument
+fn parse_header(arg: &str) -> Result<(String, String), String> {
+    let (name, value) = arg
+        .split_once(':')
+        .ok_or_else(|| format!("expected NAME:VALUE, got '{}'", arg))?;
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(format!("missing header name in '{}'", arg));
+    }
+    Ok((name.to_string(), value.trim().to_string()))
+}
+
+/// Pre-flight complexity check behaviour
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ComplexityCheck {
+    /// Skip the check
+    Off,
+    /// Print a warning and send the query anyway
+    Warn,
+    /// Refuse to send the query
+    Refuse,
 }
 
 /// Available commands
@@ -121,8 +286,13 @@ pub enum Commands {
         /// The resource type
         resource: Resource,
 
-        /// Entity ID (UUID) or identifier (e.g., ENG-123)
-        id: String,
+        /// Entity IDs (UUID) or identifiers (e.g., ENG-123)
+        #[arg(required_unless_present = "ids_from")]
+        ids: Vec<String>,
+
+        /// Read more IDs from a file, one per line ('-' for stdin)
+        #[arg(long, value_name = "PATH")]
+        ids_from: Option<String>,
     },
 
     /// Search entities with smart search strategy
@@ -132,6 +302,10 @@ pub enum Commands {
 
         /// Search text
         text: String,
+
+        /// Maximum number of results (fetched in pages of 50)
+        #[arg(long, default_value_t = 20, value_parser = clap::value_parser!(u32).range(1..))]
+        limit: u32,
     },
 
     /// Execute arbitrary GraphQL query
@@ -201,11 +375,81 @@ pub enum Commands {
         vars: VarsOptions,
     },
 
+    /// Undo journaled update, archive and delete operations
+    Undo {
+        #[command(flatten)]
+        options: UndoOptions,
+    },
+
+    /// Upload a file and print its asset URL
+    Upload {
+        /// Path of the file to upload
+        path: String,
+
+        #[command(flatten)]
+        options: UploadOptions,
+    },
+
+    /// Import issues from a CSV, Jira XML or GitHub JSON export
+    Import {
+        /// Source format
+        #[arg(value_enum)]
+        format: ImportFormat,
+
+        /// Export file to import
+        file: String,
+
+        #[command(flatten)]
+        options: ImportOptions,
+    },
+
+    /// Export resources to NDJSON files plus a manifest (for backups)
+    Export {
+        #[command(flatten)]
+        options: ExportOptions,
+    },
+
+    /// Poll for changes and print created, updated and archived events
+    Watch {
+        /// The resource type to watch
+        resource: Resource,
+
+        #[command(flatten)]
+        options: WatchOptions,
+    },
+
+    /// Show the remaining API rate-limit budget
+    RateLimit,
+
     /// Schema management commands
     Schema {
         #[command(subcommand)]
         action: SchemaAction,
     },
+
+    /// Inspect or clear the response cache
+    Cache {
+        #[command(subcommand)]
+        action: CacheAction,
+    },
+
+    /// Mirror the workspace into a local SQLite database for --offline
+    Sync {
+        #[command(subcommand)]
+        action: SyncAction,
+    },
+
+    /// Manage webhooks, receive them locally, or send signed test deliveries
+    Webhook {
+        #[command(subcommand)]
+        action: WebhookAction,
+    },
+
+    /// Local development tools
+    Dev {
+        #[command(subcommand)]
+        action: DevAction,
+    },
 }
 
 /// List command options
@@ -231,6 +475,16 @@ pub struct ListOptions {
     #[arg(long)]
     pub all: bool,
 
+    /// With --all, page N createdAt ranges concurrently (no 1000 cap)
+    #[arg(
+        long,
+        value_name = "N",
+        requires = "all",
+        conflicts_with_all = ["after", "before", "last"],
+        value_parser = clap::value_parser!(u8).range(2..=pagination_const::MAX_PARALLEL as i64)
+    )]
+    pub parallel: Option<u8>,
+
     /// Include archived entities
     #[arg(long)]
     pub include_archived: bool,
@@ -268,9 +522,170 @@ pub struct InputOptions {
     #[arg(long)]
     pub input: Option<String>,
 
-    /// Input from file
+    /// Input from file (JSON, YAML, or markdown with front matter for `.md` files)
     #[arg(long)]
     pub input_file: Option<String>,
+
+    /// Input from a markdown file: YAML front matter becomes fields, the body the description
+    #[arg(long, conflicts_with_all = ["input", "input_file"])]
+    pub from_markdown: Option<String>,
+
+    /// Retry-safe create: the same key never creates a duplicate ('auto' hashes the input)
+    #[arg(long, value_name = "KEY")]
+    pub idempotency_key: Option<String>,
+}
+
+/// Undo options
+#[derive(Args, Clone)]
+pub struct UndoOptions {
+    /// Undo the N most recent journaled operations
+    #[arg(long, default_value_t = 1, conflicts_with = "id")]
+    pub last: usize,
+
+    /// Undo a specific journal entry
+    #[arg(long)]
+    pub id: Option<String>,
+
+    /// Show the inverse mutations without executing them
+    #[arg(long)]
+    pub dry_run: bool,
+
+    /// List journal entries instead of undoing
+    #[arg(long, conflicts_with_all = ["id", "dry_run"])]
+    pub list: bool,
+}
+
+/// Upload options
+#[derive(Args, Clone)]
+pub struct UploadOptions {
+    /// Issue ID or identifier to link the uploaded file to
+    #[arg(long)]
+    pub issue: Option<String>,
+
+    /// How to link the file to the issue
+    #[arg(long, value_enum, default_value = "attachment", requires = "issue")]
+    pub link: UploadLink,
+
+    /// Attachment title (defaults to the file name)
+    #[arg(long, requires = "issue")]
+    pub title: Option<String>,
+
+    /// Override the detected content type
+    #[arg(long)]
+    pub content_type: Option<String>,
+}
+
+/// Import source formats
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ImportFormat {
+    /// CSV with a header row (Linear's own CSV export columns by default)
+    Csv,
+    /// Jira's XML (RSS) issue export
+    JiraXml,
+    /// `gh issue list --json ...` output or the REST API's issue list
+    GithubJson,
+}
+
+/// Import options
+#[derive(Args, Clone)]
+pub struct ImportOptions {
+    /// Team to create the issues in (key, name or ID)
+    #[arg(long)]
+    pub team: String,
+
+    /// JSON or YAML file mapping source fields onto issue input fields
+    #[arg(long)]
+    pub mapping: Option<String>,
+
+    /// File recording old → new IDs, so re-runs skip imported rows (default: <file>.idmap.json)
+    #[arg(long)]
+    pub id_map: Option<String>,
+
+    /// Resolve names and report what would be created, without creating anything
+    #[arg(long)]
+    pub dry_run: bool,
+}
+
+/// Export options
+#[derive(Args, Clone)]
+pub struct ExportOptions {
+    /// Resources to export (comma-separated, e.g. issue,project,document,comment)
+    #[arg(long, value_delimiter = ',', required = true)]
+    pub resources: Vec<Resource>,
+
+    /// Directory for the NDJSON files and manifest.json
+    #[arg(long)]
+    pub out_dir: String,
+
+    /// Only entities updated at or after this date or ISO 8601 duration (e.g. -P1D)
+    #[arg(long, allow_hyphen_values = true)]
+    pub since: Option<String>,
+
+    /// Gzip each file (`.ndjson.gz`)
+    #[arg(long)]
+    pub gzip: bool,
+
+    /// Page N createdAt ranges of each resource concurrently
+    #[arg(
+        long,
+        value_name = "N",
+        value_parser = clap::value_parser!(u8).range(2..=pagination_const::MAX_PARALLEL as i64)
+    )]
+    pub parallel: Option<u8>,
+}
+
+/// Watch options
+#[derive(Args, Clone)]
+pub struct WatchOptions {
+    /// Only entities matching this expression (e.g. "team.key = ENG and priority <= 2")
+    #[arg(long = "where", value_name = "EXPR")]
+    pub where_expr: Option<String>,
+
+    /// Time between polls (e.g. 30s, 5m, 500ms)
+    #[arg(long, default_value = "30s", value_parser = parse_interval)]
+    pub interval: Duration,
+
+    /// Shell command run for each event, with the event JSON on stdin
+    #[arg(long, value_name = "CMD")]
+    pub exec: Option<String>,
+
+    /// Field selection preset (changes are reported for these fields)
+    #[arg(long, value_enum, default_value = "default")]
+    pub preset: FieldsetPreset,
+
+    /// Stop after N polls, counting the initial snapshot
+    #[arg(long, value_name = "N", value_parser = clap::value_parser!(u64).range(1..))]
+    pub polls: Option<u64>,
+}
+
+/// Parse a `--interval` like `30s`, `5m`, `1h` or `500ms` (bare numbers are seconds)
+fn parse_interval(arg: &str) -> Result<Duration, String> {
+    let arg = arg.trim();
+    let split = arg.find(|c: char| !c.is_ascii_digit()).unwrap_or(arg.len());
+    let (number, unit) = arg.split_at(split);
+    let number: u64 = number
+        .parse()
+        .map_err(|_| format!("expected a duration like 30s or 5m, got '{}'", arg))?;
+    let duration = match unit {
+        "ms" => Duration::from_millis(number),
+        "" | "s" => Duration::from_secs(number),
+        "m" => Duration::from_secs(number * 60),
+        "h" => Duration::from_secs(number * 3600),
+        _ => return Err(format!("unknown unit '{}' (use ms, s, m or h)", unit)),
+    };
+    if duration.is_zero() {
+        return Err("the interval must be greater than zero".to_string());
+    }
+    Ok(duration)
+}
+
+/// How an uploaded file is linked to an issue
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum UploadLink {
+    /// Create an attachment on the issue
+    Attachment,
+    /// Append a markdown link to the issue description
+    Description,
 }
 
 /// Set options for update command
@@ -309,3 +724,289 @@ pub enum SchemaAction {
     /// Show diff between local and upstream schema
     Diff,
 }
+
+/// Response cache actions
+#[derive(Subcommand, Clone)]
+pub enum CacheAction {
+    /// Show cache location, entry counts and size
+    Stats,
+    /// Remove cached responses
+    Clear {
+        /// Only remove entries for this resource
+        #[arg(long)]
+        resource: Option<Resource>,
+    },
+}
+
+/// Offline mirror actions
+#[derive(Subcommand, Clone)]
+pub enum SyncAction {
+    /// Fetch entities changed since the last pull (everything on the first)
+    Pull {
+        /// Only pull these resources (issue, project, cycle, team, user, issueLabel, workflowState, comment)
+        #[arg(long = "resource", value_name = "RESOURCE")]
+        resources: Vec<Resource>,
+
+        /// Discard the mirrored rows and pull everything again
+        #[arg(long)]
+        full: bool,
+    },
+    /// Show the mirror location, entity counts and last pull per resource
+    Status,
+}
+
+/// Webhook actions
+#[derive(Subcommand, Clone)]
+pub enum WebhookAction {
+    /// Run a local receiver that verifies signatures and prints or forwards deliveries
+    Listen {
+        #[command(flatten)]
+        options: WebhookListenOptions,
+    },
+    /// Send a signed fixture payload to a receiver (or print it)
+    SendTest {
+        #[command(flatten)]
+        options: WebhookSendTestOptions,
+    },
+    /// List webhooks
+    List {
+        /// Only webhooks scoped to this team (key, name or ID)
+        #[arg(long)]
+        team: Option<String>,
+
+        /// Include archived webhooks
+        #[arg(long)]
+        include_archived: bool,
+    },
+    /// Create a webhook
+    Create {
+        #[command(flatten)]
+        options: WebhookCreateOptions,
+    },
+    /// Change a webhook's URL, label, resource types or enabled state
+    Update {
+        /// Webhook ID
+        id: String,
+
+        #[command(flatten)]
+        options: WebhookUpdateOptions,
+    },
+    /// Delete a webhook
+    Delete {
+        /// Webhook ID
+        id: String,
+    },
+    /// Replace a webhook's signing secret and print the new one
+    RotateSecret {
+        /// Webhook ID
+        id: String,
+
+        /// New secret (random if omitted)
+        #[arg(long)]
+        secret: Option<String>,
+    },
+    /// Send a delivery signed with a webhook's secret to a local receiver
+    Verify {
+        /// Webhook ID
+        id: String,
+
+        /// Receiver URL (e.g. from `webhook listen`)
+        #[arg(long, value_name = "URL")]
+        url: String,
+
+        /// Payload type to send (defaults to the webhook's first resource type)
+        #[arg(long = "type")]
+        kind: Option<String>,
+    },
+}
+
+/// Entity types a webhook can subscribe to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum WebhookResourceType {
+    Issue,
+    Comment,
+    #[value(alias = "issueattachment")]
+    Attachment,
+    #[value(alias = "issuelabel")]
+    IssueLabel,
+    Reaction,
+    Project,
+    #[value(alias = "projectupdate")]
+    ProjectUpdate,
+    Cycle,
+    Document,
+    Initiative,
+    #[value(alias = "initiativeupdate")]
+    InitiativeUpdate,
+    Customer,
+    #[value(alias = "customerneed")]
+    CustomerNeed,
+    User,
+    #[value(alias = "auditentry")]
+    AuditEntry,
+    #[value(name = "issue-sla", alias = "issuesla")]
+    IssueSla,
+}
+
+impl WebhookResourceType {
+    /// Name used in the API's `resourceTypes`
+    pub fn as_graphql_value(self) -> &'static str {
+        match self {
+            WebhookResourceType::Issue => "Issue",
+            WebhookResourceType::Comment => "Comment",
+            WebhookResourceType::Attachment => "Attachment",
+            WebhookResourceType::IssueLabel => "IssueLabel",
+            WebhookResourceType::Reaction => "Reaction",
+            WebhookResourceType::Project => "Project",
+            WebhookResourceType::ProjectUpdate => "ProjectUpdate",
+            WebhookResourceType::Cycle => "Cycle",
+            WebhookResourceType::Document => "Document",
+            WebhookResourceType::Initiative => "Initiative",
+            WebhookResourceType::InitiativeUpdate => "InitiativeUpdate",
+            WebhookResourceType::Customer => "Customer",
+            WebhookResourceType::CustomerNeed => "CustomerNeed",
+            WebhookResourceType::User => "User",
+            WebhookResourceType::AuditEntry => "AuditEntry",
+            WebhookResourceType::IssueSla => "IssueSLA",
+        }
+    }
+}
+
+/// Webhook creation options
+#[derive(Args, Clone)]
+pub struct WebhookCreateOptions {
+    /// URL Linear delivers to
+    #[arg(long)]
+    pub url: String,
+
+    /// Entity types to subscribe to (comma-separated, e.g. issue,comment,project)
+    #[arg(long, value_enum, value_delimiter = ',', ignore_case = true, required = true)]
+    pub resource_types: Vec<WebhookResourceType>,
+
+    /// Only events from this team (key, name or ID)
+    #[arg(long, required_unless_present = "all_public_teams")]
+    pub team: Option<String>,
+
+    /// Events from every public team instead of one
+    #[arg(long, conflicts_with = "team")]
+    pub all_public_teams: bool,
+
+    /// Label shown in Linear's settings
+    #[arg(long)]
+    pub label: Option<String>,
+
+    /// Signing secret (random if omitted; printed once)
+    #[arg(long)]
+    pub secret: Option<String>,
+
+    /// Create the webhook disabled
+    #[arg(long)]
+    pub disabled: bool,
+}
+
+/// Webhook update options
+#[derive(Args, Clone)]
+pub struct WebhookUpdateOptions {
+    /// New delivery URL
+    #[arg(long)]
+    pub url: Option<String>,
+
+    /// Replace the subscribed entity types (comma-separated)
+    #[arg(long, value_enum, value_delimiter = ',', ignore_case = true)]
+    pub resource_types: Option<Vec<WebhookResourceType>>,
+
+    /// New label
+    #[arg(long)]
+    pub label: Option<String>,
+
+    /// Resume deliveries
+    #[arg(long, conflicts_with = "disable")]
+    pub enable: bool,
+
+    /// Pause deliveries
+    #[arg(long)]
+    pub disable: bool,
+}
+
+/// Webhook receiver options
+#[derive(Args, Clone)]
+pub struct WebhookListenOptions {
+    /// Address to bind
+    #[arg(long, default_value = "127.0.0.1")]
+    pub host: String,
+
+    /// Port to listen on (0 picks a free port)
+    #[arg(long, default_value = "8080")]
+    pub port: u16,
+
+    /// The webhook's signing secret
+    #[arg(long, env = env_const::WEBHOOK_SECRET, hide_env_values = true)]
+    pub secret: String,
+
+    /// Reject payloads whose webhookTimestamp is further than this from now (seconds, 0 disables)
+    #[arg(long, value_name = "SECS", default_value_t = webhook_const::MAX_AGE_SECS)]
+    pub max_age: u64,
+
+    /// Only handle these types or type.action pairs (comma-separated, e.g. Issue,Comment.create)
+    #[arg(long, value_delimiter = ',')]
+    pub event: Vec<String>,
+
+    /// Shell command run for each delivery, with its JSON on stdin
+    #[arg(long, value_name = "CMD")]
+    pub exec: Option<String>,
+
+    /// URL to re-post each delivery to, with Linear's headers and body unchanged
+    #[arg(long, value_name = "URL")]
+    pub forward: Option<String>,
+
+    /// Exit after handling N deliveries
+    #[arg(long, value_name = "N", value_parser = clap::value_parser!(u64).range(1..))]
+    pub count: Option<u64>,
+}
+
+/// Test delivery options
+#[derive(Args, Clone)]
+pub struct WebhookSendTestOptions {
+    /// Receiver URL; without it the signed request is printed instead
+    #[arg(long, value_name = "URL")]
+    pub url: Option<String>,
+
+    /// Signing secret
+    #[arg(long, env = env_const::WEBHOOK_SECRET, hide_env_values = true)]
+    pub secret: String,
+
+    /// Payload type (Issue, Comment, Project, AgentSessionEvent, AppUserNotification, ...)
+    #[arg(long = "type", default_value = "Issue")]
+    pub kind: String,
+
+    /// Payload action (create, update, remove, ...)
+    #[arg(long, default_value = "create")]
+    pub action: String,
+
+    /// Sign this payload file (JSON) instead of the built-in fixture; its webhookTimestamp is refreshed
+    #[arg(long, value_name = "PATH", conflicts_with_all = ["kind", "action"])]
+    pub payload: Option<String>,
+
+    /// Backdate webhookTimestamp by this many seconds (to test the receiver's window)
+    #[arg(long, value_name = "SECS", default_value_t = 0)]
+    pub age: u64,
+}
+
+/// Local development actions
+#[derive(Subcommand, Clone)]
+pub enum DevAction {
+    /// Run an in-memory fake Linear API (use with --endpoint)
+    Serve {
+        /// Address to bind
+        #[arg(long, default_value = "127.0.0.1")]
+        host: String,
+
+        /// Port to listen on (0 picks a free port)
+        #[arg(long, default_value = "4000")]
+        port: u16,
+
+        /// Number of seeded issues
+        #[arg(long, default_value = "25")]
+        issues: u32,
+    },
+}
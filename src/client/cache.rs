@@ -0,0 +1,407 @@
+//! On-disk response cache for read-only queries
+//!
+//! Entries live in `$XDG_CACHE_HOME/linears/responses/{resources}-{hash}.json`,
+//! where `{resources}` names every resource the query reads (`team+user`).
+//! The hash covers the endpoint, workspace, normalized query and variables, so
+//! different accounts and workspaces never share entries. Mutations remove
+//! every entry that reads a resource they touch.
+
+use graphql_parser::query::{Definition, OperationDefinition, Selection, SelectionSet, parse_query};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::path::{Path, PathBuf};
+
+use super::{ClientError, GraphQLRequest};
+use crate::common::constants::cache as cache_constants;
+use crate::generated::Resource;
+
+/// A cached response
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CacheEntry {
+    /// Resources the query reads, joined with `+`
+    pub resource: String,
+    /// Unix timestamp (seconds) when the entry was stored
+    pub stored_at: i64,
+    pub ttl_secs: u64,
+    pub data: serde_json::Value,
+}
+
+impl CacheEntry {
+    pub fn is_fresh(&self, now: i64) -> bool {
+        now < self.stored_at.saturating_add(self.ttl_secs as i64)
+    }
+}
+
+/// Response cache scoped to one endpoint, workspace and API key
+pub struct ResponseCache {
+    dir: PathBuf,
+    /// Cache every query, using the fallback TTL where no default exists
+    cache_all: bool,
+    /// Skip cached entries but store fresh responses
+    refresh: bool,
+    /// Hash of endpoint, workspace and API key, mixed into every key
+    scope: String,
+}
+
+impl ResponseCache {
+    pub fn new(dir: &Path, endpoint: &str, workspace: Option<&str>, api_key: &str) -> Self {
+        let digest = Sha256::digest(format!("{}\n{}\n{}", endpoint, workspace.unwrap_or_default(), api_key));
+        Self {
+            dir: dir.to_path_buf(),
+            cache_all: false,
+            refresh: false,
+            scope: digest.iter().map(|b| format!("{:02x}", b)).collect(),
+        }
+    }
+
+    /// Cache every query, not just resources with a default TTL
+    pub fn with_cache_all(mut self, enabled: bool) -> Self {
+        self.cache_all = enabled;
+        self
+    }
+
+    /// Ignore cached entries (fresh responses are still stored)
+    pub fn with_refresh(mut self, enabled: bool) -> Self {
+        self.refresh = enabled;
+        self
+    }
+
+    /// Fresh cached data for a query, if any
+    pub fn get(&self, request: &GraphQLRequest) -> Option<serde_json::Value> {
+        if self.refresh {
+            return None;
+        }
+        let resources = query_resources(request)?;
+        let path = self.path(&resources, request);
+        let entry: CacheEntry = serde_json::from_str(&std::fs::read_to_string(path).ok()?).ok()?;
+        entry
+            .is_fresh(chrono::Utc::now().timestamp())
+            .then_some(entry.data)
+    }
+
+    /// Store the data of a successful query
+    pub fn put(&self, request: &GraphQLRequest, data: &serde_json::Value) -> Result<(), ClientError> {
+        let Some(resources) = query_resources(request) else {
+            return Ok(());
+        };
+        // The shortest-lived resource decides how long the whole response stays fresh
+        let ttls: Option<Vec<u64>> = resources
+            .iter()
+            .map(|r| default_ttl_secs(*r).or(self.cache_all.then_some(cache_constants::FALLBACK_TTL_SECS)))
+            .collect();
+        let Some(ttl_secs) = ttls.and_then(|ttls| ttls.into_iter().min()) else {
+            return Ok(());
+        };
+
+        std::fs::create_dir_all(&self.dir).map_err(|e| {
+            ClientError::Other(format!("Failed to create cache directory {}: {}", self.dir.display(), e))
+        })?;
+        let entry = CacheEntry {
+            resource: resources_key(&resources),
+            stored_at: chrono::Utc::now().timestamp(),
+            ttl_secs,
+            data: data.clone(),
+        };
+        let content = serde_json::to_string(&entry)
+            .map_err(|e| ClientError::Other(format!("Failed to serialize cache entry: {}", e)))?;
+        let path = self.path(&resources, request);
+        std::fs::write(&path, content)
+            .map_err(|e| ClientError::Other(format!("Failed to write cache entry {}: {}", path.display(), e)))
+    }
+
+    /// Drop cached entries of the resources a mutation touches
+    pub fn invalidate(&self, request: &GraphQLRequest) -> Result<usize, ClientError> {
+        let mut removed = 0;
+        for resource in mutation_resources(request) {
+            removed += clear(&self.dir, Some(resource))?;
+        }
+        Ok(removed)
+    }
+
+    fn path(&self, resources: &[Resource], request: &GraphQLRequest) -> PathBuf {
+        let query = request.query.split_whitespace().collect::<Vec<_>>().join(" ");
+        let variables = request.variables.clone().unwrap_or(serde_json::Value::Null);
+        let digest = Sha256::digest(format!("{}\n{}\n{}", self.scope, query, variables).as_bytes());
+        let hash: String = digest.iter().take(12).map(|b| format!("{:02x}", b)).collect();
+        self.dir.join(format!("{}-{}.json", resources_key(resources), hash))
+    }
+}
+
+/// Default time-to-live for a resource; None means not cached by default
+pub fn default_ttl_secs(resource: Resource) -> Option<u64> {
+    match canonical(resource) {
+        Resource::Organization | Resource::Team | Resource::WorkflowState => {
+            Some(cache_constants::LOOKUP_TTL_SECS)
+        }
+        Resource::User | Resource::IssueLabel => Some(cache_constants::DIRECTORY_TTL_SECS),
+        Resource::Project | Resource::Cycle => Some(cache_constants::PLANNING_TTL_SECS),
+        _ => None,
+    }
+}
+
+/// Cache usage summary
+#[derive(Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CacheStats {
+    pub entries: usize,
+    pub fresh: usize,
+    pub expired: usize,
+    pub bytes: u64,
+    /// Entry count per resource
+    pub resources: std::collections::BTreeMap<String, usize>,
+}
+
+/// Summarize the entries in a cache directory
+pub fn stats(dir: &Path) -> Result<CacheStats, ClientError> {
+    let mut stats = CacheStats::default();
+    let now = chrono::Utc::now().timestamp();
+    for path in entry_paths(dir)? {
+        let Ok(content) = std::fs::read_to_string(&path) else {
+            continue;
+        };
+        let Ok(entry) = serde_json::from_str::<CacheEntry>(&content) else {
+            continue;
+        };
+        stats.entries += 1;
+        stats.bytes += content.len() as u64;
+        if entry.is_fresh(now) {
+            stats.fresh += 1;
+        } else {
+            stats.expired += 1;
+        }
+        *stats.resources.entry(entry.resource).or_default() += 1;
+    }
+    Ok(stats)
+}
+
+/// Remove cached entries (all, or one resource's), returning how many were removed
+pub fn clear(dir: &Path, resource: Option<Resource>) -> Result<usize, ClientError> {
+    let resource = resource.map(canonical);
+    let mut removed = 0;
+    for path in entry_paths(dir)? {
+        // File names are `{resource}+{resource}-{hash}.json`
+        let name = path.file_stem().and_then(|n| n.to_str()).unwrap_or_default();
+        let matches = resource.is_none_or(|r| {
+            name.rsplit_once('-')
+                .is_some_and(|(prefix, _)| prefix.split('+').any(|p| p == r.field_name()))
+        });
+        if matches && std::fs::remove_file(&path).is_ok() {
+            removed += 1;
+        }
+    }
+    Ok(removed)
+}
+
+fn entry_paths(dir: &Path) -> Result<Vec<PathBuf>, ClientError> {
+    let entries = match std::fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => {
+            return Err(ClientError::Other(format!(
+                "Failed to read cache directory {}: {}",
+                dir.display(),
+                e
+            )));
+        }
+    };
+    Ok(entries
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+        .collect())
+}
+
+/// Resources a query reads, one per root field; None unless every root field is a resource
+fn query_resources(request: &GraphQLRequest) -> Option<Vec<Resource>> {
+    if request.is_mutation() {
+        return None;
+    }
+    let mut resources = root_fields(request)?
+        .iter()
+        .map(|field| {
+            Resource::all()
+                .iter()
+                .copied()
+                .find(|r| r.field_name() == field || r.plural_name() == field)
+                .map(canonical)
+        })
+        .collect::<Option<Vec<_>>>()?;
+    resources.sort_by_key(|r| r.field_name());
+    resources.dedup();
+    (!resources.is_empty()).then_some(resources)
+}
+
+/// File name prefix and stats key for a set of resources
+fn resources_key(resources: &[Resource]) -> String {
+    resources.iter().map(|r| r.field_name()).collect::<Vec<_>>().join("+")
+}
+
+/// The singular resource for a plural one (`teams` and `team` share entries)
+fn canonical(resource: Resource) -> Resource {
+    Resource::all()
+        .iter()
+        .copied()
+        .find(|r| r.plural_name() == resource.plural_name() && r.field_name() != r.plural_name())
+        .unwrap_or(resource)
+}
+
+/// Resources a mutation writes, from the longest resource prefix of each root field
+fn mutation_resources(request: &GraphQLRequest) -> Vec<Resource> {
+    if !request.is_mutation() {
+        return Vec::new();
+    }
+    root_fields(request)
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|field| {
+            Resource::all()
+                .iter()
+                .copied()
+                .filter(|r| {
+                    field
+                        .strip_prefix(r.field_name())
+                        .is_some_and(|rest| rest.starts_with(|c: char| c.is_ascii_uppercase()))
+                })
+                .max_by_key(|r| r.field_name().len())
+        })
+        .collect()
+}
+
+/// Root field names of the request's operation (aliases resolved, `__typename` skipped)
+///
+/// None if the document does not parse or selects root fields through named fragments.
+fn root_fields(request: &GraphQLRequest) -> Option<Vec<String>> {
+    let document = parse_query::<String>(&request.query).ok()?;
+    let selection_set = document.definitions.iter().find_map(|d| match d {
+        Definition::Operation(OperationDefinition::SelectionSet(set)) => Some(set),
+        Definition::Operation(OperationDefinition::Query(q))
+            if request.operation_name.is_none() || q.name == request.operation_name =>
+        {
+            Some(&q.selection_set)
+        }
+        Definition::Operation(OperationDefinition::Mutation(m))
+            if request.operation_name.is_none() || m.name == request.operation_name =>
+        {
+            Some(&m.selection_set)
+        }
+        _ => None,
+    })?;
+    let mut fields = Vec::new();
+    collect_fields(selection_set, &mut fields)?;
+    Some(fields)
+}
+
+fn collect_fields(set: &SelectionSet<String>, fields: &mut Vec<String>) -> Option<()> {
+    for selection in &set.items {
+        match selection {
+            Selection::Field(field) if field.name == "__typename" => {}
+            Selection::Field(field) => fields.push(field.name.clone()),
+            Selection::InlineFragment(fragment) => collect_fields(&fragment.selection_set, fields)?,
+            Selection::FragmentSpread(_) => return None,
+        }
+    }
+    Some(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(query: &str) -> GraphQLRequest {
+        GraphQLRequest {
+            query: query.to_string(),
+            variables: Some(serde_json::json!({ "first": 20 })),
+            operation_name: None,
+        }
+    }
+
+    #[test]
+    fn test_resource_detection() {
+        let list = request("query ListTeams($first: Int) { teams(first: $first) { nodes { id } } }");
+        assert_eq!(query_resources(&list), Some(vec![Resource::Team]));
+        assert_eq!(
+            query_resources(&request("{ states: workflowStates { nodes { id } } }")),
+            Some(vec![Resource::WorkflowState])
+        );
+        assert_eq!(
+            query_resources(&request("{ users { nodes { id } } teams { nodes { id } } }")),
+            Some(vec![Resource::Team, Resource::User])
+        );
+        assert_eq!(query_resources(&request("{ teams { nodes { id } } __schema { queryType { name } } }")), None);
+
+        let create = request("mutation IssueLabelCreate($input: IssueLabelCreateInput!) { issueLabelCreate(input: $input) { success } }");
+        assert_eq!(query_resources(&create), None);
+        assert_eq!(mutation_resources(&create), vec![Resource::IssueLabel]);
+    }
+
+    #[test]
+    fn test_mixed_resources_use_shortest_ttl() {
+        let dir = std::env::temp_dir().join(format!("linears-cache-mixed-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let data = serde_json::json!({ "teams": { "nodes": [] }, "issues": { "nodes": [] } });
+
+        // Issues have no default TTL, so a query mixing them in is not cached...
+        let mixed = request("{ teams { nodes { id } } issues { nodes { id } } }");
+        let cache = ResponseCache::new(&dir, "http://localhost/graphql", None, "key");
+        cache.put(&mixed, &data).unwrap();
+        assert_eq!(cache.get(&mixed), None);
+
+        // ...unless everything is cached, and then for the shortest TTL
+        let cache = cache.with_cache_all(true);
+        cache.put(&mixed, &data).unwrap();
+        assert_eq!(cache.get(&mixed), Some(data));
+        let stats = stats(&dir).unwrap();
+        assert_eq!(stats.resources.get("issue+team"), Some(&1));
+        let entry = std::fs::read_dir(&dir).unwrap().next().unwrap().unwrap().path();
+        let entry: CacheEntry = serde_json::from_str(&std::fs::read_to_string(entry).unwrap()).unwrap();
+        assert_eq!(entry.ttl_secs, cache_constants::FALLBACK_TTL_SECS);
+
+        // A mutation of either resource drops the entry
+        let update = request("mutation { issueUpdate(id: \"x\", input: {}) { success } }");
+        assert_eq!(cache.invalidate(&update).unwrap(), 1);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_put_get_and_invalidate() {
+        let dir = std::env::temp_dir().join(format!("linears-cache-test-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let cache = ResponseCache::new(&dir, "http://localhost/graphql", None, "key");
+        let data = serde_json::json!({ "teams": { "nodes": [] } });
+
+        let teams = request("query { teams { nodes { id } } }");
+        cache.put(&teams, &data).unwrap();
+        assert_eq!(cache.get(&teams), Some(data.clone()));
+
+        // Issues have no default TTL, so they're only cached with cache_all
+        let issues = request("query { issues { nodes { id } } }");
+        cache.put(&issues, &data).unwrap();
+        assert_eq!(cache.get(&issues), None);
+
+        // Another API key never sees these entries
+        let other = ResponseCache::new(&dir, "http://localhost/graphql", None, "other-key");
+        assert_eq!(other.get(&teams), None);
+
+        assert!(cache.with_refresh(true).get(&teams).is_none());
+
+        let cache = ResponseCache::new(&dir, "http://localhost/graphql", None, "key");
+        let update = request("mutation { teamUpdate(id: \"x\", input: {}) { success } }");
+        assert_eq!(cache.invalidate(&update).unwrap(), 1);
+        assert_eq!(cache.get(&teams), None);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_expiry() {
+        let entry = CacheEntry {
+            resource: "team".to_string(),
+            stored_at: 1_000,
+            ttl_secs: 60,
+            data: serde_json::Value::Null,
+        };
+        assert!(entry.is_fresh(1_059));
+        assert!(!entry.is_fresh(1_060));
+    }
+}
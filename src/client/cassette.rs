@@ -0,0 +1,342 @@
+//! Record/replay cassettes: GraphQL exchanges stored as JSON files
+//!
+//! Each interaction is one file, `{operation}-{hash}.json`, holding the
+//! normalized request and the raw response. The hash covers the normalized
+//! query and variables, so recording the same request again overwrites it.
+
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use super::{ClientError, GraphQLRequest};
+
+/// Response headers worth keeping (rate limits, retries)
+const RECORDED_HEADERS: &[&str] = &["retry-after", "x-complexity"];
+const RECORDED_HEADER_PREFIX: &str = "x-ratelimit-";
+
+/// How replayed requests are matched to cassettes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReplayMatch {
+    /// Query and variables must match exactly (after normalization)
+    Strict,
+    /// Fall back to the same query with any variables, then the same operation name
+    Lenient,
+}
+
+/// A request in normalized form
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CassetteRequest {
+    pub operation: String,
+    /// Query with whitespace collapsed
+    pub query: String,
+    pub variables: serde_json::Value,
+}
+
+impl CassetteRequest {
+    pub fn from_request(request: &GraphQLRequest) -> Self {
+        let query = request.query.split_whitespace().collect::<Vec<_>>().join(" ");
+        let operation = request
+            .operation_name
+            .clone()
+            .unwrap_or_else(|| operation_name(&query));
+        Self {
+            operation,
+            query,
+            variables: request.variables.clone().unwrap_or(serde_json::Value::Null),
+        }
+    }
+
+    /// Stable key over query and variables (serde_json maps are sorted)
+    fn key(&self) -> String {
+        let digest = Sha256::digest(format!("{}\n{}", self.query, self.variables).as_bytes());
+        digest.iter().take(8).map(|b| format!("{:02x}", b)).collect()
+    }
+}
+
+/// A recorded response
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CassetteResponse {
+    pub status: u16,
+    #[serde(default)]
+    pub headers: serde_json::Map<String, serde_json::Value>,
+    pub body: serde_json::Value,
+}
+
+impl CassetteResponse {
+    pub fn new(status: StatusCode, headers: &HeaderMap, body: &[u8]) -> Self {
+        let headers = headers
+            .iter()
+            .filter(|(name, _)| {
+                let name = name.as_str();
+                RECORDED_HEADERS.contains(&name) || name.starts_with(RECORDED_HEADER_PREFIX)
+            })
+            .filter_map(|(name, value)| {
+                Some((name.to_string(), serde_json::json!(value.to_str().ok()?)))
+            })
+            .collect();
+        // Non-JSON bodies (e.g. proxy error pages) are kept as strings
+        let body = serde_json::from_slice(body)
+            .unwrap_or_else(|_| serde_json::json!(String::from_utf8_lossy(body)));
+        Self {
+            status: status.as_u16(),
+            headers,
+            body,
+        }
+    }
+
+    pub fn status(&self) -> StatusCode {
+        StatusCode::from_u16(self.status).unwrap_or(StatusCode::OK)
+    }
+
+    pub fn header_map(&self) -> HeaderMap {
+        let mut map = HeaderMap::new();
+        for (name, value) in &self.headers {
+            if let (Ok(name), Some(Ok(value))) = (
+                HeaderName::from_bytes(name.as_bytes()),
+                value.as_str().map(HeaderValue::from_str),
+            ) {
+                map.insert(name, value);
+            }
+        }
+        map
+    }
+
+    pub fn body_bytes(&self) -> Vec<u8> {
+        match &self.body {
+            serde_json::Value::String(text) => text.clone().into_bytes(),
+            body => body.to_string().into_bytes(),
+        }
+    }
+}
+
+/// One recorded exchange
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Interaction {
+    pub request: CassetteRequest,
+    pub response: CassetteResponse,
+}
+
+/// Writes interactions into a cassette directory
+pub struct Recorder {
+    dir: PathBuf,
+}
+
+impl Recorder {
+    pub fn new(dir: &Path) -> Self {
+        Self {
+            dir: dir.to_path_buf(),
+        }
+    }
+
+    pub fn record(&self, interaction: &Interaction) -> Result<(), ClientError> {
+        std::fs::create_dir_all(&self.dir).map_err(|e| {
+            ClientError::Other(format!("Failed to create cassette directory {}: {}", self.dir.display(), e))
+        })?;
+        let path = self.dir.join(format!(
+            "{}-{}.json",
+            sanitize(&interaction.request.operation),
+            interaction.request.key()
+        ));
+        let content = serde_json::to_string_pretty(interaction)
+            .map_err(|e| ClientError::Other(format!("Failed to serialize cassette: {}", e)))?;
+        std::fs::write(&path, content).map_err(|e| {
+            ClientError::Other(format!("Failed to write cassette {}: {}", path.display(), e))
+        })
+    }
+}
+
+/// Serves responses from a cassette directory
+pub struct Player {
+    dir: PathBuf,
+    mode: ReplayMatch,
+    interactions: Vec<Interaction>,
+    /// Times each interaction has been served (lenient mode prefers unused ones)
+    served: Mutex<Vec<usize>>,
+}
+
+impl Player {
+    /// Load every cassette in a directory
+    pub fn load(dir: &Path, mode: ReplayMatch) -> Result<Self, ClientError> {
+        let entries = std::fs::read_dir(dir).map_err(|e| {
+            ClientError::Other(format!("Failed to read cassette directory {}: {}", dir.display(), e))
+        })?;
+
+        let mut paths: Vec<PathBuf> = entries
+            .filter_map(|entry| entry.ok().map(|e| e.path()))
+            .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+            .collect();
+        paths.sort();
+
+        let interactions = paths
+            .iter()
+            .map(|path| {
+                let content = std::fs::read_to_string(path).map_err(|e| {
+                    ClientError::Other(format!("Failed to read cassette {}: {}", path.display(), e))
+                })?;
+                serde_json::from_str(&content).map_err(|e| {
+                    ClientError::Other(format!("Invalid cassette {}: {}", path.display(), e))
+                })
+            })
+            .collect::<Result<Vec<Interaction>, _>>()?;
+
+        Ok(Self {
+            dir: dir.to_path_buf(),
+            mode,
+            served: Mutex::new(vec![0; interactions.len()]),
+            interactions,
+        })
+    }
+
+    /// Find the recorded response for a request
+    pub fn play(&self, request: &GraphQLRequest) -> Result<CassetteResponse, ClientError> {
+        let wanted = CassetteRequest::from_request(request);
+        let index = self.find(&wanted).ok_or_else(|| {
+            ClientError::Other(format!(
+                "No cassette in {} matches {} request '{}' (recorded with --record?)",
+                self.dir.display(),
+                match self.mode {
+                    ReplayMatch::Strict => "strict",
+                    ReplayMatch::Lenient => "lenient",
+                },
+                wanted.operation
+            ))
+        })?;
+
+        if let Ok(mut served) = self.served.lock() {
+            served[index] += 1;
+        }
+        Ok(self.interactions[index].response.clone())
+    }
+
+    fn find(&self, wanted: &CassetteRequest) -> Option<usize> {
+        let exact = |i: &Interaction| i.request.query == wanted.query && i.request.variables == wanted.variables;
+        if let Some(index) = self.interactions.iter().position(exact) {
+            return Some(index);
+        }
+        if self.mode == ReplayMatch::Strict {
+            return None;
+        }
+
+        let same_query = |i: &Interaction| i.request.query == wanted.query;
+        let same_operation = |i: &Interaction| i.request.operation == wanted.operation;
+        self.least_served(same_query)
+            .or_else(|| self.least_served(same_operation))
+    }
+
+    /// Among matching interactions, the one served the fewest times (first on ties)
+    fn least_served(&self, matches: impl Fn(&Interaction) -> bool) -> Option<usize> {
+        let served = self.served.lock().ok()?;
+        self.interactions
+            .iter()
+            .enumerate()
+            .filter(|(_, interaction)| matches(interaction))
+            .min_by_key(|(index, _)| served[*index])
+            .map(|(index, _)| index)
+    }
+}
+
+/// Operation name from a query document, e.g. "ListIssues"
+fn operation_name(query: &str) -> String {
+    let mut words = query.split(|c: char| c.is_whitespace() || c == '(' || c == '{');
+    match words.next() {
+        Some("query" | "mutation" | "subscription") => words
+            .find(|w| !w.is_empty())
+            .filter(|w| w.chars().all(|c| c.is_alphanumeric() || c == '_'))
+            .map(String::from)
+            .unwrap_or_else(|| "anonymous".to_string()),
+        _ => "anonymous".to_string(),
+    }
+}
+
+fn sanitize(name: &str) -> String {
+    name.chars()
+        .map(|c| if c.is_alphanumeric() || c == '_' || c == '-' { c } else { '_' })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(query: &str, variables: serde_json::Value) -> GraphQLRequest {
+        GraphQLRequest {
+            query: query.to_string(),
+            variables: Some(variables),
+            operation_name: None,
+        }
+    }
+
+    fn interaction(query: &str, variables: serde_json::Value, marker: &str) -> Interaction {
+        Interaction {
+            request: CassetteRequest::from_request(&request(query, variables)),
+            response: CassetteResponse {
+                status: 200,
+                headers: serde_json::Map::new(),
+                body: serde_json::json!({ "data": { "marker": marker } }),
+            },
+        }
+    }
+
+    fn player(mode: ReplayMatch, interactions: Vec<Interaction>) -> Player {
+        Player {
+            dir: PathBuf::from("cassettes"),
+            mode,
+            served: Mutex::new(vec![0; interactions.len()]),
+            interactions,
+        }
+    }
+
+    #[test]
+    fn test_normalization() {
+        let a = CassetteRequest::from_request(&request(
+            "query ListIssues($first: Int) {\n  issues(first: $first) { nodes { id } }\n}",
+            serde_json::json!({ "first": 20 }),
+        ));
+        let b = CassetteRequest::from_request(&request(
+            "query ListIssues($first: Int) { issues(first: $first) { nodes { id } } }",
+            serde_json::json!({ "first": 20 }),
+        ));
+        assert_eq!(a, b);
+        assert_eq!(a.key(), b.key());
+        assert_eq!(a.operation, "ListIssues");
+    }
+
+    #[test]
+    fn test_strict_and_lenient_matching() {
+        let query = "query ListIssues($first: Int) { issues(first: $first) { nodes { id } } }";
+        let recorded = vec![interaction(query, serde_json::json!({ "first": 20 }), "twenty")];
+
+        let strict = player(ReplayMatch::Strict, recorded.clone());
+        assert!(strict.play(&request(query, serde_json::json!({ "first": 20 }))).is_ok());
+        assert!(strict.play(&request(query, serde_json::json!({ "first": 5 }))).is_err());
+
+        let lenient = player(ReplayMatch::Lenient, recorded);
+        let response = lenient.play(&request(query, serde_json::json!({ "first": 5 }))).unwrap();
+        assert_eq!(response.body["data"]["marker"], "twenty");
+
+        // Same operation name, different selection
+        let wider = "query ListIssues($first: Int) { issues(first: $first) { nodes { id title } } }";
+        assert!(lenient.play(&request(wider, serde_json::json!({}))).is_ok());
+        assert!(lenient.play(&request("query Other { viewer { id } }", serde_json::json!({}))).is_err());
+    }
+
+    #[test]
+    fn test_lenient_prefers_unserved() {
+        let query = "query ListIssues($after: String) { issues(after: $after) { nodes { id } } }";
+        let lenient = player(
+            ReplayMatch::Lenient,
+            vec![
+                interaction(query, serde_json::json!({ "after": "a" }), "page1"),
+                interaction(query, serde_json::json!({ "after": "b" }), "page2"),
+            ],
+        );
+        let first = lenient.play(&request(query, serde_json::json!({ "after": "x" }))).unwrap();
+        let second = lenient.play(&request(query, serde_json::json!({ "after": "y" }))).unwrap();
+        assert_eq!(first.body["data"]["marker"], "page1");
+        assert_eq!(second.body["data"]["marker"], "page2");
+    }
+}
@@ -0,0 +1,114 @@
+//! Transport settings: proxy, custom CA, extra headers, user agent and compression
+
+#[cfg(feature = "compress")]
+use flate2::{write::GzEncoder, Compression};
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+#[cfg(feature = "compress")]
+use std::io::Write;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use super::ClientError;
+use crate::common::constants::client as client_constants;
+
+/// HTTP transport options applied when building the client
+#[derive(Debug, Clone, Default)]
+pub struct HttpOptions {
+    /// Proxy URL for all requests (`HTTPS_PROXY`/`HTTP_PROXY` are used otherwise)
+    pub proxy: Option<String>,
+    /// PEM file with extra root certificates to trust
+    pub ca_cert: Option<PathBuf>,
+    /// Extra headers sent with every API request (not with uploads)
+    pub headers: Vec<(String, String)>,
+    /// User-Agent header (defaults to `linears/<version>`)
+    pub user_agent: Option<String>,
+    /// Gzip request bodies
+    pub compress: bool,
+}
+
+impl HttpOptions {
+    /// A reqwest builder with the proxy, CA, user agent and timeout applied
+    pub(super) fn builder(&self, timeout_secs: u64) -> Result<reqwest::ClientBuilder, ClientError> {
+        let mut builder = reqwest::Client::builder()
+            .timeout(Duration::from_secs(timeout_secs))
+            .user_agent(
+                self.user_agent
+                    .as_deref()
+                    .unwrap_or(client_constants::USER_AGENT),
+            );
+
+        if let Some(ref url) = self.proxy {
+            let proxy = reqwest::Proxy::all(url)
+                .map_err(|e| ClientError::Other(format!("Invalid proxy URL '{}': {}", url, e)))?;
+            builder = builder.proxy(proxy);
+        }
+
+        if let Some(ref path) = self.ca_cert {
+            let pem = std::fs::read(path).map_err(|e| {
+                ClientError::Other(format!("Failed to read CA certificate {}: {}", path.display(), e))
+            })?;
+            let certs = reqwest::Certificate::from_pem_bundle(&pem).map_err(|e| {
+                ClientError::Other(format!("Invalid CA certificate {}: {}", path.display(), e))
+            })?;
+            if certs.is_empty() {
+                return Err(ClientError::Other(format!(
+                    "No certificates found in {}",
+                    path.display()
+                )));
+            }
+            for cert in certs {
+                builder = builder.add_root_certificate(cert);
+            }
+        }
+
+        Ok(builder)
+    }
+
+    /// Add the extra headers to a header map, replacing existing values
+    pub(super) fn apply_headers(&self, headers: &mut HeaderMap) -> Result<(), ClientError> {
+        for (name, value) in &self.headers {
+            let name = HeaderName::from_bytes(name.as_bytes())
+                .map_err(|e| ClientError::Other(format!("Invalid header name '{}': {}", name, e)))?;
+            let value = HeaderValue::from_str(value).map_err(|e| {
+                ClientError::Other(format!("Invalid value for header '{}': {}", name, e))
+            })?;
+            headers.insert(name, value);
+        }
+        Ok(())
+    }
+}
+
+/// Gzip a request body
+#[cfg(feature = "compress")]
+pub(super) fn gzip(body: &[u8]) -> Result<Vec<u8>, ClientError> {
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder
+        .write_all(body)
+        .and_then(|_| encoder.finish())
+        .map_err(|e| ClientError::Other(format!("Failed to compress request: {}", e)))
+}
+
+/// Without the `compress` feature there is no encoder to gzip with
+#[cfg(not(feature = "compress"))]
+pub(super) fn gzip(_body: &[u8]) -> Result<Vec<u8>, ClientError> {
+    Err(ClientError::Other(
+        "Request compression requires the `compress` feature".to_string(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_invalid_ca_cert() {
+        let path = std::env::temp_dir().join(format!("linears-ca-{}.pem", std::process::id()));
+        std::fs::write(&path, "not a certificate").unwrap();
+        let options = HttpOptions {
+            ca_cert: Some(path.clone()),
+            ..Default::default()
+        };
+        assert!(options.builder(30).is_err());
+        let _ = std::fs::remove_file(&path);
+    }
+}
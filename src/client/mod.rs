@@ -1,14 +1,38 @@
 //! HTTP client for Linear's GraphQL API
 
+mod cache;
+mod cassette;
+mod http;
+mod rate_limit;
+mod retry;
+mod trace;
+
+pub use cache::{clear as clear_cache, default_ttl_secs, stats as cache_stats, ResponseCache};
+pub use cassette::ReplayMatch;
+pub use http::HttpOptions;
+pub use rate_limit::RateLimitState;
+pub use retry::RetryPolicy;
+pub use trace::TraceFormat;
+
+use cassette::{CassetteRequest, CassetteResponse, Interaction, Player, Recorder};
+use trace::{Exchange, Tracer};
+
+use retry::{error_reason, RetryEvent};
+
 use chrono::{Duration as ChronoDuration, Utc};
-use rand::Rng;
-use reqwest::header::{HeaderMap, HeaderValue, AUTHORIZATION, CONTENT_TYPE};
+use reqwest::header::{HeaderMap, HeaderValue, AUTHORIZATION, CONTENT_ENCODING, CONTENT_TYPE, USER_AGENT};
 use serde::{Deserialize, Serialize};
-use std::time::Duration;
+use std::future::Future;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 use thiserror::Error;
 use tokio::time::sleep;
 
-use crate::common::{constants::client as client_constants, constants::env, ExitCode};
+use crate::common::{
+    constants::{client::USER_AGENT as USER_AGENT_DEFAULT, env},
+    ExitCode,
+};
 
 /// Errors that can occur when using the Linear API client
 #[derive(Debug, Error)]
@@ -21,11 +45,19 @@ pub enum ClientError {
     #[error("Network error: {0}")]
     Network(String),
 
+    /// Request timed out (exit code 3) - the server may still have applied it
+    #[error("Network error: {0}")]
+    Timeout(String),
+
     /// GraphQL error from Linear (exit code 4)
     /// Contains the error message and optionally the raw GraphQL errors for debugging
     #[error("GraphQL error: {0}")]
     GraphQL(String, Option<Vec<GraphQLError>>),
 
+    /// Data returned alongside GraphQL errors in partial-result mode (exit code 5)
+    #[error("Partial result: {} GraphQL error(s)", .0.len())]
+    PartialData(Vec<GraphQLError>),
+
     /// Rate limited (retriable - wait time ≤60s)
     #[error("Rate limited: {0}")]
     RateLimited(u64),
@@ -49,7 +81,9 @@ impl ClientError {
         match self {
             ClientError::Auth(_) => ExitCode::AuthError,
             ClientError::Network(_) => ExitCode::NetworkError,
+            ClientError::Timeout(_) => ExitCode::NetworkError,
             ClientError::GraphQL(_, _) => ExitCode::GraphQLError,
+            ClientError::PartialData(_) => ExitCode::PartialSuccess,
             ClientError::RateLimited(_) => ExitCode::GeneralError,
             ClientError::RateLimitedTooLong(_) => ExitCode::GeneralError,
             ClientError::Server(_) => ExitCode::GeneralError,
@@ -62,10 +96,16 @@ impl ClientError {
         match self {
             ClientError::Auth(_) => Some(format!("Check that {} is set correctly", env::API_KEY)),
             ClientError::Network(_) => Some("Check your network connection".to_string()),
+            ClientError::Timeout(_) => {
+                Some("Check your network connection or increase --timeout".to_string())
+            }
             ClientError::RateLimited(_) | ClientError::RateLimitedTooLong(_) => {
                 Some("The API is rate-limiting your requests. Wait and try again".to_string())
             }
             ClientError::Server(_) => Some("Linear is experiencing issues. Try again later".to_string()),
+            ClientError::PartialData(_) => {
+                Some("Some fields could not be resolved; see errors for their paths".to_string())
+            }
             _ => None,
         }
     }
@@ -75,7 +115,31 @@ impl ClientError {
 /// GraphQL client for Linear API
 pub struct Client {
     http: reqwest::Client,
+    /// Client without Linear credentials, for signed upload URLs
+    upload_http: reqwest::Client,
     endpoint: String,
+    /// Headers sent with every API request, as written to traces
+    request_headers: HeaderMap,
+    /// Gzip request bodies
+    compress: bool,
+    /// Keep data from responses that also carry errors
+    partial_results: bool,
+    /// Print the remaining rate-limit budget after each request
+    verbose: bool,
+    /// Budgets from the most recent response headers
+    rate_limit: Arc<Mutex<RateLimitState>>,
+    retry_policy: RetryPolicy,
+    /// Report retries as JSON lines on stderr (for JSON output modes)
+    structured_events: bool,
+    cassettes: Option<Cassettes>,
+    cache: Option<ResponseCache>,
+    tracer: Option<Tracer>,
+}
+
+/// Record or replay mode for GraphQL exchanges
+enum Cassettes {
+    Record(Recorder),
+    Replay(Player),
 }
 
 /// GraphQL request body
@@ -88,6 +152,13 @@ pub struct GraphQLRequest {
     pub operation_name: Option<String>,
 }
 
+impl GraphQLRequest {
+    /// Whether the document is a mutation (not safe to blindly re-send)
+    pub fn is_mutation(&self) -> bool {
+        self.query.trim_start().starts_with("mutation")
+    }
+}
+
 /// GraphQL response
 #[derive(Debug, Deserialize)]
 pub struct GraphQLResponse {
@@ -115,12 +186,13 @@ pub struct ErrorLocation {
 }
 
 impl Client {
-    /// Create a new client with the given API key
+    /// Create a new client with the given API key and transport options
     pub fn new(
         api_key: &str,
         endpoint: Option<&str>,
         timeout_secs: u64,
         workspace: Option<&str>,
+        options: &HttpOptions,
     ) -> Result<Self, ClientError> {
         let mut headers = HeaderMap::new();
         // Linear API expects the API key directly without Bearer prefix
@@ -140,9 +212,26 @@ impl Client {
             );
         }
 
-        let http = reqwest::Client::builder()
+        options.apply_headers(&mut headers)?;
+
+        let mut request_headers = headers.clone();
+        let user_agent = options.user_agent.as_deref().unwrap_or(USER_AGENT_DEFAULT);
+        if let Ok(value) = HeaderValue::from_str(user_agent) {
+            request_headers.insert(USER_AGENT, value);
+        }
+        if options.compress {
+            request_headers.insert(CONTENT_ENCODING, HeaderValue::from_static("gzip"));
+        }
+
+        let http = options
+            .builder(timeout_secs)?
             .default_headers(headers)
-            .timeout(Duration::from_secs(timeout_secs))
+            .build()
+            .map_err(|e| ClientError::Other(format!("Failed to create HTTP client: {}", e)))?;
+
+        // Signed upload URLs must not receive the API key or gateway headers
+        let upload_http = options
+            .builder(timeout_secs)?
             .build()
             .map_err(|e| ClientError::Other(format!("Failed to create HTTP client: {}", e)))?;
 
@@ -150,37 +239,195 @@ impl Client {
             .unwrap_or("https://api.linear.app/graphql")
             .to_string();
 
-        Ok(Self { http, endpoint })
+        Ok(Self {
+            http,
+            upload_http,
+            endpoint,
+            request_headers,
+            compress: options.compress,
+            partial_results: false,
+            verbose: false,
+            rate_limit: Arc::new(Mutex::new(RateLimitState::default())),
+            retry_policy: RetryPolicy::default(),
+            structured_events: false,
+            cassettes: None,
+            cache: None,
+            tracer: None,
+        })
+    }
+
+    /// Record every GraphQL exchange into a cassette directory
+    pub fn with_recording(mut self, dir: &Path) -> Self {
+        self.cassettes = Some(Cassettes::Record(Recorder::new(dir)));
+        self
+    }
+
+    /// Serve GraphQL responses from a cassette directory instead of the network
+    pub fn with_replay(mut self, dir: &Path, mode: ReplayMatch) -> Result<Self, ClientError> {
+        self.cassettes = Some(Cassettes::Replay(Player::load(dir, mode)?));
+        Ok(self)
+    }
+
+    /// Write every HTTP exchange to a trace file
+    pub fn with_trace(mut self, path: &Path, format: TraceFormat) -> Result<Self, ClientError> {
+        self.tracer = Some(Tracer::create(path, format)?);
+        Ok(self)
+    }
+
+    /// Serve read-only queries from an on-disk response cache
+    pub fn with_cache(mut self, cache: ResponseCache) -> Self {
+        self.cache = Some(cache);
+        self
     }
 
-    /// Execute a GraphQL request with automatic retries for 5xx errors and rate limits
+    /// Use a custom retry policy
+    pub fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = policy;
+        self
+    }
+
+    /// Report retry events on stderr as JSON lines instead of prose
+    pub fn with_structured_events(mut self, enabled: bool) -> Self {
+        self.structured_events = enabled;
+        self
+    }
+
+    /// Keep `data` when a response also carries errors (partial-result mode)
+    ///
+    /// Strict mode (the default) turns any GraphQL error into `ClientError::GraphQL`.
+    pub fn with_partial_results(mut self, enabled: bool) -> Self {
+        self.partial_results = enabled;
+        self
+    }
+
+    /// Print the remaining rate-limit budget to stderr after each request
+    pub fn with_verbose(mut self, enabled: bool) -> Self {
+        self.verbose = enabled;
+        self
+    }
+
+    /// Snapshot of the rate-limit budgets seen so far
+    pub fn rate_limit(&self) -> RateLimitState {
+        self.rate_limit.lock().map(|s| s.clone()).unwrap_or_default()
+    }
+
+    /// Pause before the next request of a loop if a budget is running low
+    ///
+    /// Spreads the remaining budget over the time until it resets, so long
+    /// pagination and batch loops slow down instead of hitting a 429.
+    pub async fn throttle(&self) {
+        let Some(delay) = self.rate_limit().throttle_delay(Utc::now()) else {
+            return;
+        };
+        if self.verbose {
+            eprintln!("Rate limit budget low, pausing {}ms", delay.as_millis());
+        }
+        sleep(delay).await;
+    }
+
+    /// Upload bytes to a signed URL returned by the fileUpload mutation
+    pub async fn upload(
+        &self,
+        upload_url: &str,
+        content_type: &str,
+        headers: &[(String, String)],
+        body: Vec<u8>,
+    ) -> Result<(), ClientError> {
+        let mut request = self
+            .upload_http
+            .put(upload_url)
+            .header(CONTENT_TYPE, content_type)
+            .header("Cache-Control", "public, max-age=31536000");
+        for (key, value) in headers {
+            request = request.header(key.as_str(), value.as_str());
+        }
+
+        let request_size = body.len();
+        let request = request
+            .body(body)
+            .build()
+            .map_err(|e| ClientError::Other(format!("Invalid upload request: {}", e)))?;
+        let request_headers = request.headers().clone();
+        let started = Utc::now();
+        let clock = Instant::now();
+
+        let result = async {
+            let response = self.upload_http.execute(request).await?;
+            let status = response.status();
+            let headers = response.headers().clone();
+            let bytes = response.bytes().await?;
+            Ok::<_, reqwest::Error>((status, headers, bytes))
+        }
+        .await;
+
+        if let Some(tracer) = &self.tracer {
+            let (response, response_body, error) = match &result {
+                Ok((status, headers, bytes)) => (Some((*status, headers)), &bytes[..], None),
+                Err(e) => (None, &[][..], Some(e.to_string())),
+            };
+            let exchange = Exchange {
+                started,
+                method: "PUT",
+                url: upload_url,
+                operation: None,
+                attempt: 1,
+                request_headers: &request_headers,
+                request_body: None,
+                request_size,
+                response,
+                response_body,
+                latency: clock.elapsed(),
+                error,
+            };
+            if let Err(e) = tracer.record(&exchange) {
+                eprintln!("Warning: {}", e);
+            }
+        }
+
+        let (status, _, _) = result.map_err(|e| ClientError::Network(format!("Upload failed: {}", e)))?;
+        if status.is_server_error() {
+            return Err(ClientError::Server(format!("Upload failed: HTTP {}", status)));
+        }
+        if !status.is_success() {
+            return Err(ClientError::Other(format!("Upload failed: HTTP {}", status)));
+        }
+
+        Ok(())
+    }
+
+    /// Execute a GraphQL request, retrying per the client's retry policy
+    ///
+    /// Server errors are always retried; network errors and timeouts are retried
+    /// if the policy allows (timed-out mutations only when opted in). Rate-limit
+    /// waits don't count as attempts but are capped in total.
     pub async fn execute(&self, request: GraphQLRequest) -> Result<GraphQLResponse, ClientError> {
+        if let Some(data) = self.cache.as_ref().and_then(|cache| cache.get(&request)) {
+            if self.verbose {
+                eprintln!("Served from response cache");
+            }
+            return Ok(GraphQLResponse {
+                data: Some(data),
+                errors: None,
+            });
+        }
+
+        let policy = &self.retry_policy;
+        let is_mutation = request.is_mutation();
         let mut retries = 0;
+        let mut rate_limit_waited = 0;
+        let mut attempt = 0;
 
         loop {
-            let result = self.execute_once(&request).await;
-
-            match result {
+            attempt += 1;
+            match self.execute_once(&request, attempt).await {
                 Ok(response) => return Ok(response),
-                Err(ClientError::Server(ref msg)) if retries < client_constants::MAX_RETRIES => {
-                    // Retry on server errors with exponential backoff + jitter
-                    retries += 1;
-                    let base_delay = client_constants::BASE_DELAY_MS * 2u64.pow(retries - 1);
-                    let jitter = rand::rng().random_range(0..=base_delay / 2);
-                    let delay = (base_delay + jitter).min(client_constants::MAX_DELAY_MS);
-
-                    eprintln!(
-                        "Server error ({}), retrying in {}ms (attempt {}/{})",
-                        msg, delay, retries, client_constants::MAX_RETRIES
-                    );
-
-                    sleep(Duration::from_millis(delay)).await;
-                }
                 Err(ClientError::RateLimited(secs)) => {
-                    // Rate limited with short wait - retry after waiting
-                    eprintln!("Rate limited. Waiting {} seconds before retrying...", secs);
-                    sleep(Duration::from_secs(secs)).await;
-                    // Continue loop without incrementing retries
+                    rate_limit_waited = self.wait_for_rate_limit(secs, rate_limit_waited).await?;
+                }
+                Err(e) if retries < policy.max_retries_for(&e) && policy.should_retry(&e, is_mutation) => {
+                    // Retry with exponential backoff + jitter
+                    retries += 1;
+                    self.backoff(&e, retries, policy.max_retries_for(&e)).await;
                 }
                 Err(e) => {
                     // Non-retryable error or max retries reached
@@ -190,39 +437,144 @@ impl Client {
         }
     }
 
-    /// Execute a single GraphQL request (no retries)
-    async fn execute_once(&self, request: &GraphQLRequest) -> Result<GraphQLResponse, ClientError> {
-        let response = self
-            .http
-            .post(&self.endpoint)
-            .json(request)
-            .send()
-            .await
-            .map_err(|e| {
-                if e.is_timeout() {
-                    ClientError::Network(format!("Request timed out: {}", e))
-                } else if e.is_connect() {
-                    ClientError::Network(format!("Connection failed: {}", e))
-                } else {
-                    ClientError::Network(format!("Request failed: {}", e))
+    /// Execute a non-idempotent request (e.g. a create) without ever applying it twice
+    ///
+    /// Server errors and timeouts are ambiguous: the mutation may have been applied
+    /// even though no usable response arrived. Before retrying, `check_applied` is
+    /// asked whether the previous attempt took effect and its response is used if so.
+    /// If the check itself fails, the original error is returned rather than risking
+    /// a duplicate.
+    pub async fn execute_idempotent<F, Fut>(
+        &self,
+        request: GraphQLRequest,
+        check_applied: F,
+    ) -> Result<GraphQLResponse, ClientError>
+    where
+        F: Fn() -> Fut,
+        Fut: Future<Output = Result<Option<GraphQLResponse>, ClientError>>,
+    {
+        let policy = &self.retry_policy;
+        let mut retries = 0;
+        let mut rate_limit_waited = 0;
+        let mut attempt = 0;
+
+        loop {
+            attempt += 1;
+            match self.execute_once(&request, attempt).await {
+                Ok(response) => return Ok(response),
+                Err(ClientError::RateLimited(secs)) => {
+                    // A 429 was never processed, so retrying is safe
+                    rate_limit_waited = self.wait_for_rate_limit(secs, rate_limit_waited).await?;
                 }
-            })?;
+                Err(
+                    e @ (ClientError::Server(_)
+                    | ClientError::Network(_)
+                    | ClientError::Timeout(_)
+                    | ClientError::GraphQL(_, _)),
+                ) => {
+                    // A rejected re-run (e.g. duplicate id) also means it was applied earlier
+                    match check_applied().await {
+                        Ok(Some(response)) => return Ok(response),
+                        Ok(None) => {}
+                        Err(_) => return Err(e),
+                    }
 
-        let status = response.status();
+                    // The lookup makes timeouts safe to retry, so only the class switch applies
+                    let retryable = match e {
+                        ClientError::Timeout(_) => policy.retry_timeout,
+                        ClientError::GraphQL(_, _) => false,
+                        _ => policy.should_retry(&e, false),
+                    };
+                    if !retryable || retries >= policy.max_retries_for(&e) {
+                        return Err(e);
+                    }
+
+                    retries += 1;
+                    self.backoff(&e, retries, policy.max_retries_for(&e)).await;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    /// Sleep before retry `attempt`, reporting the retry on stderr
+    async fn backoff(&self, error: &ClientError, attempt: u32, max_retries: u32) {
+        let delay = self.retry_policy.delay_ms(attempt);
+        RetryEvent {
+            event: "retry",
+            reason: error_reason(error),
+            message: error.to_string(),
+            attempt: Some(attempt),
+            max_retries: Some(max_retries),
+            delay_ms: delay,
+        }
+        .emit(self.structured_events);
+        sleep(Duration::from_millis(delay)).await;
+    }
+
+    /// Wait out a short rate limit, returning the new total waited
+    ///
+    /// Fails once the policy's total rate-limit wait would be exceeded.
+    async fn wait_for_rate_limit(&self, secs: u64, waited: u64) -> Result<u64, ClientError> {
+        let cap = self.retry_policy.max_rate_limit_wait_secs;
+        if waited + secs > cap {
+            return Err(ClientError::RateLimitedTooLong(format!(
+                "Rate limited. Gave up after waiting {} seconds in total (limit: {}s)",
+                waited, cap
+            )));
+        }
+
+        RetryEvent {
+            event: "rate_limit_wait",
+            reason: "rate_limited",
+            message: format!("Rate limited. Waiting {} seconds before retrying...", secs),
+            attempt: None,
+            max_retries: None,
+            delay_ms: secs * 1000,
+        }
+        .emit(self.structured_events);
+        sleep(Duration::from_secs(secs)).await;
+        Ok(waited + secs)
+    }
+
+    /// Execute a single GraphQL request (no retries); `attempt` counts from 1
+    async fn execute_once(
+        &self,
+        request: &GraphQLRequest,
+        attempt: u32,
+    ) -> Result<GraphQLResponse, ClientError> {
+        let (status, headers, bytes) = match &self.cassettes {
+            Some(Cassettes::Replay(player)) => {
+                let response = player.play(request)?;
+                (response.status(), response.header_map(), response.body_bytes())
+            }
+            _ => self.send(request, attempt).await?,
+        };
+
+        if let Some(Cassettes::Record(recorder)) = &self.cassettes {
+            let interaction = Interaction {
+                request: CassetteRequest::from_request(request),
+                response: CassetteResponse::new(status, &headers, &bytes),
+            };
+            if let Err(e) = recorder.record(&interaction) {
+                eprintln!("Warning: {}", e);
+            }
+        }
+
+        self.record_rate_limit(&headers);
 
         if status == reqwest::StatusCode::UNAUTHORIZED {
             return Err(ClientError::Auth("Invalid or missing API key".to_string()));
         }
 
         if status == reqwest::StatusCode::TOO_MANY_REQUESTS {
-            let retry_after = response
-                .headers()
+            let retry_after = headers
                 .get("Retry-After")
                 .and_then(|h| h.to_str().ok())
                 .and_then(|s| s.parse::<u64>().ok());
 
             if let Some(secs) = retry_after {
-                if secs <= client_constants::RATE_LIMIT_AUTO_RETRY_THRESHOLD_SECS {
+                if secs <= self.retry_policy.rate_limit_wait_threshold_secs() {
                     // Short wait - can be retried automatically
                     return Err(ClientError::RateLimited(secs));
                 } else {
@@ -235,8 +587,11 @@ impl Client {
                     )));
                 }
             } else {
-                // No Retry-After header - assume short wait
-                return Err(ClientError::RateLimited(5));
+                // No Retry-After header - wait for the exhausted budget, else assume short wait
+                let secs = self.rate_limit().exhausted_reset_secs(Utc::now()).unwrap_or(5);
+                return Err(ClientError::RateLimited(
+                    secs.min(self.retry_policy.rate_limit_wait_threshold_secs()),
+                ));
             }
         }
 
@@ -244,9 +599,7 @@ impl Client {
             return Err(ClientError::Server(format!("HTTP {}", status)));
         }
 
-        let body: GraphQLResponse = response
-            .json()
-            .await
+        let body: GraphQLResponse = serde_json::from_slice(&bytes)
             .map_err(|e| ClientError::Other(format!("Failed to parse response: {}", e)))?;
 
         // Check for GraphQL errors in the response
@@ -262,10 +615,124 @@ impl Client {
                         return Err(ClientError::Auth(msg.to_string()));
                     }
                 }
+                // Partial-result mode keeps whatever data did resolve
+                if self.partial_results && has_resolved_data(body.data.as_ref()) {
+                    self.update_cache(request, &body);
+                    return Ok(body);
+                }
                 return Err(ClientError::GraphQL(messages.join("; "), Some(errors)));
             }
         }
 
+        self.update_cache(request, &body);
         Ok(body)
     }
+
+    /// Store a successful query's data, or drop entries a mutation made stale
+    ///
+    /// Partial query results (data with errors) are never stored.
+    fn update_cache(&self, request: &GraphQLRequest, response: &GraphQLResponse) {
+        let Some(cache) = &self.cache else {
+            return;
+        };
+        let complete = response.errors.as_ref().is_none_or(|errors| errors.is_empty());
+        let result = if request.is_mutation() {
+            cache.invalidate(request).map(|_| ())
+        } else {
+            match &response.data {
+                Some(data) if complete => cache.put(request, data),
+                _ => Ok(()),
+            }
+        };
+        if let Err(e) = result {
+            eprintln!("Warning: {}", e);
+        }
+    }
+
+    /// Send a request over the network, returning the raw response parts
+    async fn send(
+        &self,
+        request: &GraphQLRequest,
+        attempt: u32,
+    ) -> Result<(reqwest::StatusCode, HeaderMap, Vec<u8>), ClientError> {
+        let body = serde_json::to_vec(request)
+            .map_err(|e| ClientError::Other(format!("Failed to serialize request: {}", e)))?;
+        let started = Utc::now();
+        let clock = Instant::now();
+        let result = self.post(&body).await;
+
+        if let Some(tracer) = &self.tracer {
+            let operation = CassetteRequest::from_request(request).operation;
+            let (response, response_body, error) = match &result {
+                Ok((status, headers, bytes)) => (Some((*status, headers)), bytes.as_slice(), None),
+                Err(e) => (None, &[][..], Some(e.to_string())),
+            };
+            let exchange = Exchange {
+                started,
+                method: "POST",
+                url: &self.endpoint,
+                operation: Some(&operation),
+                attempt,
+                request_headers: &self.request_headers,
+                request_body: Some(&String::from_utf8_lossy(&body)),
+                request_size: body.len(),
+                response,
+                response_body,
+                latency: clock.elapsed(),
+                error,
+            };
+            if let Err(e) = tracer.record(&exchange) {
+                eprintln!("Warning: {}", e);
+            }
+        }
+        result
+    }
+
+    /// POST a serialized request body to the endpoint
+    async fn post(&self, body: &[u8]) -> Result<(reqwest::StatusCode, HeaderMap, Vec<u8>), ClientError> {
+        let builder = self.http.post(&self.endpoint);
+        let builder = if self.compress {
+            builder
+                .header(CONTENT_ENCODING, "gzip")
+                .body(http::gzip(body)?)
+        } else {
+            builder.body(body.to_vec())
+        };
+        let response = builder.send().await.map_err(network_error)?;
+
+        let status = response.status();
+        let headers = response.headers().clone();
+        let bytes = response.bytes().await.map_err(network_error)?;
+        Ok((status, headers, bytes.to_vec()))
+    }
+
+    /// Merge a response's rate-limit headers into the shared state
+    fn record_rate_limit(&self, headers: &HeaderMap) {
+        let Ok(mut state) = self.rate_limit.lock() else {
+            return;
+        };
+        state.update(headers);
+        if self.verbose {
+            if let Some(summary) = state.summary() {
+                eprintln!("{}", summary);
+            }
+        }
+    }
+}
+
+/// Classify a reqwest error as a timeout or network error
+fn network_error(e: reqwest::Error) -> ClientError {
+    if e.is_timeout() {
+        ClientError::Timeout(format!("Request timed out: {}", e))
+    } else if e.is_connect() {
+        ClientError::Network(format!("Connection failed: {}", e))
+    } else {
+        ClientError::Network(format!("Request failed: {}", e))
+    }
+}
+
+/// Check whether response data has at least one resolved (non-null) root field
+fn has_resolved_data(data: Option<&serde_json::Value>) -> bool {
+    data.and_then(|d| d.as_object())
+        .is_some_and(|fields| fields.values().any(|v| !v.is_null()))
 }
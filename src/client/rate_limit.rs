@@ -0,0 +1,230 @@
+//! Rate-limit and complexity budget tracking from Linear's response headers
+
+use chrono::{DateTime, Utc};
+use reqwest::header::HeaderMap;
+use serde::Serialize;
+use std::time::Duration;
+
+use crate::common::constants::client as client_constants;
+
+/// Response headers carrying the request and complexity budgets
+pub mod headers {
+    pub const REQUESTS_LIMIT: &str = "x-ratelimit-requests-limit";
+    pub const REQUESTS_REMAINING: &str = "x-ratelimit-requests-remaining";
+    pub const REQUESTS_RESET: &str = "x-ratelimit-requests-reset";
+    pub const COMPLEXITY_LIMIT: &str = "x-ratelimit-complexity-limit";
+    pub const COMPLEXITY_REMAINING: &str = "x-ratelimit-complexity-remaining";
+    pub const COMPLEXITY_RESET: &str = "x-ratelimit-complexity-reset";
+    /// Complexity cost of the request that produced the response
+    pub const COMPLEXITY: &str = "x-complexity";
+}
+
+/// One rate-limited budget (requests or complexity points)
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Budget {
+    pub limit: Option<u64>,
+    pub remaining: Option<u64>,
+    /// When the budget is fully replenished
+    pub reset: Option<DateTime<Utc>>,
+}
+
+impl Budget {
+    fn update(&mut self, headers: &HeaderMap, limit: &str, remaining: &str, reset: &str) {
+        if let Some(value) = header_u64(headers, limit) {
+            self.limit = Some(value);
+        }
+        if let Some(value) = header_u64(headers, remaining) {
+            self.remaining = Some(value);
+        }
+        if let Some(value) = header_u64(headers, reset) {
+            self.reset = reset_time(value);
+        }
+    }
+
+    /// Whether the remaining budget is below the low-water mark
+    pub fn is_low(&self) -> bool {
+        match (self.remaining, self.limit) {
+            (Some(remaining), Some(limit)) if limit > 0 => {
+                (remaining as f64) < limit as f64 * client_constants::LOW_BUDGET_FRACTION
+            }
+            _ => false,
+        }
+    }
+
+    /// Delay that spreads the remaining budget evenly until the reset
+    fn pacing_delay(&self, now: DateTime<Utc>) -> Option<Duration> {
+        if !self.is_low() {
+            return None;
+        }
+        let until_reset = (self.reset? - now).to_std().ok()?;
+        let remaining = self.remaining?.max(1) as u32;
+        Some(until_reset / remaining)
+    }
+
+    fn describe(&self) -> Option<String> {
+        Some(format!("{}/{}", self.remaining?, self.limit?))
+    }
+}
+
+/// Rate-limit state observed across requests
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RateLimitState {
+    pub requests: Budget,
+    pub complexity: Budget,
+    /// Complexity cost of the most recent request
+    pub last_complexity: Option<u64>,
+}
+
+impl RateLimitState {
+    /// Update from a response's headers (absent headers keep previous values)
+    pub fn update(&mut self, headers: &HeaderMap) {
+        self.requests.update(
+            headers,
+            self::headers::REQUESTS_LIMIT,
+            self::headers::REQUESTS_REMAINING,
+            self::headers::REQUESTS_RESET,
+        );
+        self.complexity.update(
+            headers,
+            self::headers::COMPLEXITY_LIMIT,
+            self::headers::COMPLEXITY_REMAINING,
+            self::headers::COMPLEXITY_RESET,
+        );
+        if let Some(cost) = header_u64(headers, self::headers::COMPLEXITY) {
+            self.last_complexity = Some(cost);
+        }
+    }
+
+    /// Whether any budget header has been seen yet
+    pub fn is_known(&self) -> bool {
+        self.requests.remaining.is_some() || self.complexity.remaining.is_some()
+    }
+
+    /// Pre-emptive delay before the next request when a budget is running low
+    pub fn throttle_delay(&self, now: DateTime<Utc>) -> Option<Duration> {
+        let delay = [self.requests.pacing_delay(now), self.complexity.pacing_delay(now)]
+            .into_iter()
+            .flatten()
+            .max()?;
+        Some(delay.min(Duration::from_millis(client_constants::MAX_THROTTLE_DELAY_MS)))
+    }
+
+    /// Seconds until the earliest exhausted budget resets (for 429s without Retry-After)
+    pub fn exhausted_reset_secs(&self, now: DateTime<Utc>) -> Option<u64> {
+        [&self.requests, &self.complexity]
+            .into_iter()
+            .filter(|b| b.remaining == Some(0))
+            .filter_map(|b| b.reset)
+            .map(|reset| (reset - now).num_seconds().max(1) as u64)
+            .min()
+    }
+
+    /// One-line summary for --verbose output
+    pub fn summary(&self) -> Option<String> {
+        let mut parts = Vec::new();
+        if let Some(requests) = self.requests.describe() {
+            parts.push(format!("{} requests", requests));
+        }
+        if let Some(complexity) = self.complexity.describe() {
+            parts.push(format!("{} complexity", complexity));
+        }
+        if parts.is_empty() {
+            return None;
+        }
+        let mut summary = format!("Rate limit: {} remaining", parts.join(", "));
+        if let Some(cost) = self.last_complexity {
+            summary.push_str(&format!(" (query cost {})", cost));
+        }
+        Some(summary)
+    }
+}
+
+fn header_u64(headers: &HeaderMap, name: &str) -> Option<u64> {
+    let value = headers.get(name)?.to_str().ok()?.trim();
+    // Some budgets are reported as floats
+    value
+        .parse::<u64>()
+        .ok()
+        .or_else(|| value.parse::<f64>().ok().filter(|v| *v >= 0.0).map(|v| v as u64))
+}
+
+/// Reset headers are UNIX timestamps in milliseconds (seconds are accepted too)
+fn reset_time(value: u64) -> Option<DateTime<Utc>> {
+    if value < 10_000_000_000 {
+        DateTime::from_timestamp(value as i64, 0)
+    } else {
+        DateTime::from_timestamp_millis(value as i64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::HeaderValue;
+
+    fn header_map(pairs: &[(&'static str, &str)]) -> HeaderMap {
+        let mut map = HeaderMap::new();
+        for (name, value) in pairs {
+            map.insert(*name, HeaderValue::from_str(value).unwrap());
+        }
+        map
+    }
+
+    #[test]
+    fn test_update_from_headers() {
+        let mut state = RateLimitState::default();
+        state.update(&header_map(&[
+            (headers::REQUESTS_LIMIT, "1500"),
+            (headers::REQUESTS_REMAINING, "1499"),
+            (headers::REQUESTS_RESET, "1736500000000"),
+            (headers::COMPLEXITY, "42"),
+        ]));
+        assert_eq!(state.requests.remaining, Some(1499));
+        assert_eq!(state.requests.reset.unwrap().timestamp(), 1_736_500_000);
+        assert_eq!(state.last_complexity, Some(42));
+        assert_eq!(
+            state.summary().as_deref(),
+            Some("Rate limit: 1499/1500 requests remaining (query cost 42)")
+        );
+
+        // Responses without headers keep the last known values
+        state.update(&HeaderMap::new());
+        assert_eq!(state.requests.remaining, Some(1499));
+    }
+
+    #[test]
+    fn test_throttle_only_when_low() {
+        let now = Utc::now();
+        let mut state = RateLimitState {
+            requests: Budget {
+                limit: Some(1000),
+                remaining: Some(500),
+                reset: Some(now + chrono::Duration::seconds(60)),
+            },
+            ..Default::default()
+        };
+        assert_eq!(state.throttle_delay(now), None);
+
+        state.requests.remaining = Some(20);
+        let delay = state.throttle_delay(now).unwrap();
+        assert_eq!(delay, Duration::from_secs(3));
+    }
+
+    #[test]
+    fn test_exhausted_reset_secs() {
+        let now = Utc::now();
+        assert_eq!(RateLimitState::default().exhausted_reset_secs(now), None);
+
+        let state = RateLimitState {
+            complexity: Budget {
+                limit: Some(250_000),
+                remaining: Some(0),
+                reset: Some(now + chrono::Duration::seconds(30)),
+            },
+            ..Default::default()
+        };
+        assert!(matches!(state.exhausted_reset_secs(now), Some(29..=30)));
+    }
+}
@@ -0,0 +1,163 @@
+//! Retry policy: which errors are retried, how often, and how long to wait
+
+use rand::Rng;
+use serde::Serialize;
+
+use super::ClientError;
+use crate::common::constants::client as client_constants;
+
+/// How the client retries failed requests
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Retries after the first attempt (rate-limit waits are not counted)
+    pub max_retries: u32,
+    /// Retry limit for network errors and timeouts (also bounded by `max_retries`)
+    pub max_network_retries: u32,
+    /// Base delay for exponential backoff (ms)
+    pub base_delay_ms: u64,
+    /// Maximum delay between attempts (ms)
+    pub max_delay_ms: u64,
+    /// Retry transient network errors (connection refused or reset)
+    pub retry_network: bool,
+    /// Retry timed-out queries
+    pub retry_timeout: bool,
+    /// Also retry timed-out mutations, which may already have been applied
+    pub retry_mutation_timeouts: bool,
+    /// Total time a request may spend waiting on rate limits (seconds)
+    pub max_rate_limit_wait_secs: u64,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: client_constants::MAX_RETRIES,
+            max_network_retries: client_constants::MAX_NETWORK_RETRIES,
+            base_delay_ms: client_constants::BASE_DELAY_MS,
+            max_delay_ms: client_constants::MAX_DELAY_MS,
+            retry_network: true,
+            retry_timeout: true,
+            retry_mutation_timeouts: false,
+            max_rate_limit_wait_secs: client_constants::MAX_RATE_LIMIT_WAIT_SECS,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Whether an error class is retried for a query or mutation
+    pub fn should_retry(&self, error: &ClientError, is_mutation: bool) -> bool {
+        match error {
+            ClientError::Server(_) => true,
+            ClientError::Network(_) => self.retry_network,
+            ClientError::Timeout(_) => {
+                self.retry_timeout && (!is_mutation || self.retry_mutation_timeouts)
+            }
+            _ => false,
+        }
+    }
+
+    /// Retry limit for an error class
+    pub fn max_retries_for(&self, error: &ClientError) -> u32 {
+        match error {
+            ClientError::Network(_) | ClientError::Timeout(_) => {
+                self.max_network_retries.min(self.max_retries)
+            }
+            _ => self.max_retries,
+        }
+    }
+
+    /// Exponential backoff with jitter for the given retry attempt (1-based)
+    pub fn delay_ms(&self, attempt: u32) -> u64 {
+        let base_delay = self
+            .base_delay_ms
+            .saturating_mul(2u64.saturating_pow(attempt.saturating_sub(1)));
+        let jitter = rand::rng().random_range(0..=base_delay / 2);
+        base_delay.saturating_add(jitter).min(self.max_delay_ms)
+    }
+
+    /// Longest single Retry-After that is waited out automatically
+    pub fn rate_limit_wait_threshold_secs(&self) -> u64 {
+        self.max_rate_limit_wait_secs
+            .min(client_constants::RATE_LIMIT_AUTO_RETRY_THRESHOLD_SECS)
+    }
+}
+
+/// A retry or rate-limit wait, reported on stderr
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RetryEvent {
+    /// "retry" or "rate_limit_wait"
+    pub event: &'static str,
+    /// Error kind that triggered it (e.g. "server", "network", "timeout")
+    pub reason: &'static str,
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attempt: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_retries: Option<u32>,
+    pub delay_ms: u64,
+}
+
+impl RetryEvent {
+    /// Print to stderr, as one JSON line when `structured`
+    pub fn emit(&self, structured: bool) {
+        if structured {
+            if let Ok(line) = serde_json::to_string(self) {
+                eprintln!("{}", line);
+            }
+            return;
+        }
+        match (self.attempt, self.max_retries) {
+            (Some(attempt), Some(max)) => eprintln!(
+                "{}, retrying in {}ms (attempt {}/{})",
+                self.message, self.delay_ms, attempt, max
+            ),
+            _ => eprintln!("{}", self.message),
+        }
+    }
+}
+
+/// Short error kind name for retry events
+pub fn error_reason(error: &ClientError) -> &'static str {
+    match error {
+        ClientError::Server(_) => "server",
+        ClientError::Network(_) => "network",
+        ClientError::Timeout(_) => "timeout",
+        ClientError::RateLimited(_) => "rate_limited",
+        ClientError::GraphQL(_, _) => "graphql",
+        _ => "other",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_policy_excludes_mutation_timeouts() {
+        let policy = RetryPolicy::default();
+        let timeout = ClientError::Timeout("timed out".to_string());
+        assert!(policy.should_retry(&timeout, false));
+        assert!(!policy.should_retry(&timeout, true));
+        assert!(policy.should_retry(&ClientError::Network("reset".to_string()), true));
+        assert!(!policy.should_retry(&ClientError::Auth("bad key".to_string()), false));
+
+        assert_eq!(policy.max_retries_for(&timeout), client_constants::MAX_NETWORK_RETRIES);
+
+        let opted_in = RetryPolicy {
+            retry_mutation_timeouts: true,
+            ..RetryPolicy::default()
+        };
+        assert!(opted_in.should_retry(&timeout, true));
+    }
+
+    #[test]
+    fn test_delay_is_capped() {
+        let policy = RetryPolicy {
+            base_delay_ms: 100,
+            max_delay_ms: 1_000,
+            ..RetryPolicy::default()
+        };
+        assert!((100..=150).contains(&policy.delay_ms(1)));
+        assert_eq!(policy.delay_ms(64), 1_000);
+    }
+}
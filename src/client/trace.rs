@@ -0,0 +1,362 @@
+//! Trace export: every HTTP exchange written as HAR or JSON lines
+//!
+//! JSON lines are appended as exchanges happen. A HAR file is a single
+//! document, so it is rewritten after each exchange and stays valid if the
+//! command is interrupted. Credentials are redacted: sensitive headers, the
+//! query string of signed URLs, and secret-bearing keys anywhere in JSON
+//! request and response bodies (variables and data alike).
+
+use chrono::{DateTime, SecondsFormat, Utc};
+use reqwest::header::HeaderMap;
+use reqwest::StatusCode;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use super::ClientError;
+
+/// Headers whose values are never written
+const REDACTED_HEADERS: &[&str] = &["authorization", "proxy-authorization", "cookie", "set-cookie"];
+/// Custom header names containing these usually carry credentials too
+const REDACTED_HEADER_WORDS: &[&str] = &["key", "token", "secret"];
+/// JSON body keys containing these (case and `_` ignored) have their values masked
+const REDACTED_BODY_WORDS: &[&str] = &["secret", "token", "apikey", "password"];
+const REDACTED: &str = "[REDACTED]";
+
+/// Trace file format
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TraceFormat {
+    /// HTTP Archive 1.2, readable by browser dev tools and HAR viewers
+    Har,
+    /// One JSON object per exchange
+    Jsonl,
+}
+
+impl TraceFormat {
+    /// HAR for `.har` files, JSON lines otherwise
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|e| e.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("har") => Self::Har,
+            _ => Self::Jsonl,
+        }
+    }
+}
+
+/// One HTTP exchange, successful or not
+pub struct Exchange<'a> {
+    pub started: DateTime<Utc>,
+    pub method: &'a str,
+    pub url: &'a str,
+    /// GraphQL operation name, if any
+    pub operation: Option<&'a str>,
+    /// 1 for the first try of a request, 2 for its first retry, ...
+    pub attempt: u32,
+    pub request_headers: &'a HeaderMap,
+    /// Body text (None for binary uploads)
+    pub request_body: Option<&'a str>,
+    pub request_size: usize,
+    /// Status and headers, or None if no response arrived
+    pub response: Option<(StatusCode, &'a HeaderMap)>,
+    pub response_body: &'a [u8],
+    pub latency: Duration,
+    pub error: Option<String>,
+}
+
+/// Writes exchanges to a trace file
+pub struct Tracer {
+    path: PathBuf,
+    format: TraceFormat,
+    /// HAR entries so far (the whole document is rewritten each time)
+    entries: Mutex<Vec<serde_json::Value>>,
+}
+
+impl Tracer {
+    /// Start a trace, truncating any existing file
+    pub fn create(path: &Path, format: TraceFormat) -> Result<Self, ClientError> {
+        let tracer = Self {
+            path: path.to_path_buf(),
+            format,
+            entries: Mutex::new(Vec::new()),
+        };
+        match format {
+            TraceFormat::Har => tracer.write_har(&[])?,
+            TraceFormat::Jsonl => std::fs::write(path, "").map_err(|e| tracer.write_error(e))?,
+        }
+        Ok(tracer)
+    }
+
+    /// Append an exchange to the trace
+    pub fn record(&self, exchange: &Exchange) -> Result<(), ClientError> {
+        match self.format {
+            TraceFormat::Har => {
+                let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+                entries.push(har_entry(exchange));
+                self.write_har(&entries)
+            }
+            TraceFormat::Jsonl => {
+                let mut file = std::fs::OpenOptions::new()
+                    .append(true)
+                    .create(true)
+                    .open(&self.path)
+                    .map_err(|e| self.write_error(e))?;
+                writeln!(file, "{}", json_line(exchange)).map_err(|e| self.write_error(e))
+            }
+        }
+    }
+
+    fn write_har(&self, entries: &[serde_json::Value]) -> Result<(), ClientError> {
+        let document = serde_json::json!({
+            "log": {
+                "version": "1.2",
+                "creator": { "name": "linears", "version": env!("CARGO_PKG_VERSION") },
+                "entries": entries,
+            }
+        });
+        let content = serde_json::to_string_pretty(&document)
+            .map_err(|e| ClientError::Other(format!("Failed to serialize trace: {}", e)))?;
+        std::fs::write(&self.path, content).map_err(|e| self.write_error(e))
+    }
+
+    fn write_error(&self, e: std::io::Error) -> ClientError {
+        ClientError::Other(format!("Failed to write trace {}: {}", self.path.display(), e))
+    }
+}
+
+fn json_line(exchange: &Exchange) -> serde_json::Value {
+    let mut line = serde_json::json!({
+        "startedAt": exchange.started.to_rfc3339_opts(SecondsFormat::Millis, true),
+        "method": exchange.method,
+        "url": redact_url(exchange.url),
+        "operation": exchange.operation,
+        "attempt": exchange.attempt,
+        "latencyMs": exchange.latency.as_millis() as u64,
+        "request": {
+            "headers": header_object(exchange.request_headers),
+            "size": exchange.request_size,
+            "body": exchange.request_body.map(|body| redact_json(body_value(body))),
+        },
+    });
+    if let Some((status, headers)) = exchange.response {
+        line["response"] = serde_json::json!({
+            "status": status.as_u16(),
+            "headers": header_object(headers),
+            "size": exchange.response_body.len(),
+            "body": redact_json(body_value(&String::from_utf8_lossy(exchange.response_body))),
+        });
+    }
+    if let Some(ref error) = exchange.error {
+        line["error"] = serde_json::json!(error);
+    }
+    line
+}
+
+fn har_entry(exchange: &Exchange) -> serde_json::Value {
+    let latency_ms = exchange.latency.as_secs_f64() * 1000.0;
+    let (status, status_text, response_headers) = match exchange.response {
+        Some((status, headers)) => (
+            status.as_u16(),
+            status.canonical_reason().unwrap_or_default(),
+            header_list(headers),
+        ),
+        // HAR uses status 0 for requests that got no response
+        None => (0, "", Vec::new()),
+    };
+    let mut request = serde_json::json!({
+        "method": exchange.method,
+        "url": redact_url(exchange.url),
+        "httpVersion": "HTTP/1.1",
+        "headers": header_list(exchange.request_headers),
+        "queryString": [],
+        "cookies": [],
+        "headersSize": -1,
+        "bodySize": exchange.request_size,
+    });
+    if let Some(body) = exchange.request_body {
+        request["postData"] = serde_json::json!({ "mimeType": "application/json", "text": redact_body(body) });
+    }
+    let mut entry = serde_json::json!({
+        "startedDateTime": exchange.started.to_rfc3339_opts(SecondsFormat::Millis, true),
+        "time": latency_ms,
+        "request": request,
+        "response": {
+            "status": status,
+            "statusText": status_text,
+            "httpVersion": "HTTP/1.1",
+            "headers": response_headers,
+            "cookies": [],
+            "content": {
+                "size": exchange.response_body.len(),
+                "mimeType": "application/json",
+                "text": redact_body(&String::from_utf8_lossy(exchange.response_body)),
+            },
+            "redirectURL": "",
+            "headersSize": -1,
+            "bodySize": exchange.response_body.len(),
+        },
+        "cache": {},
+        "timings": { "send": 0, "wait": latency_ms, "receive": 0 },
+        "_operation": exchange.operation,
+        "_attempt": exchange.attempt,
+    });
+    if let Some(ref error) = exchange.error {
+        entry["_error"] = serde_json::json!(error);
+    }
+    entry
+}
+
+/// JSON bodies are embedded as JSON, anything else as a string
+fn body_value(text: &str) -> serde_json::Value {
+    serde_json::from_str(text).unwrap_or_else(|_| serde_json::json!(text))
+}
+
+/// A body with secret values masked; non-JSON text is kept as is
+fn redact_body(text: &str) -> String {
+    match serde_json::from_str::<serde_json::Value>(text) {
+        Ok(value) => redact_json(value).to_string(),
+        Err(_) => text.to_string(),
+    }
+}
+
+/// Mask the values of secret-bearing keys at any depth
+fn redact_json(value: serde_json::Value) -> serde_json::Value {
+    match value {
+        serde_json::Value::Object(map) => map
+            .into_iter()
+            .map(|(key, value)| {
+                let value = if is_secret_key(&key) && !value.is_null() {
+                    serde_json::json!(REDACTED)
+                } else {
+                    redact_json(value)
+                };
+                (key, value)
+            })
+            .collect(),
+        serde_json::Value::Array(items) => items.into_iter().map(redact_json).collect(),
+        other => other,
+    }
+}
+
+fn is_secret_key(key: &str) -> bool {
+    let key = key.to_ascii_lowercase().replace('_', "");
+    REDACTED_BODY_WORDS.iter().any(|w| key.contains(w))
+}
+
+fn header_object(headers: &HeaderMap) -> serde_json::Map<String, serde_json::Value> {
+    header_pairs(headers)
+        .into_iter()
+        .map(|(name, value)| (name, serde_json::json!(value)))
+        .collect()
+}
+
+fn header_list(headers: &HeaderMap) -> Vec<serde_json::Value> {
+    header_pairs(headers)
+        .into_iter()
+        .map(|(name, value)| serde_json::json!({ "name": name, "value": value }))
+        .collect()
+}
+
+fn header_pairs(headers: &HeaderMap) -> Vec<(String, String)> {
+    headers
+        .iter()
+        .map(|(name, value)| {
+            let name = name.as_str();
+            let value = if is_sensitive(name) {
+                REDACTED.to_string()
+            } else {
+                String::from_utf8_lossy(value.as_bytes()).into_owned()
+            };
+            (name.to_string(), value)
+        })
+        .collect()
+}
+
+fn is_sensitive(name: &str) -> bool {
+    let name = name.to_ascii_lowercase();
+    REDACTED_HEADERS.contains(&name.as_str()) || REDACTED_HEADER_WORDS.iter().any(|w| name.contains(w))
+}
+
+/// Signed upload URLs carry their credentials in the query string
+fn redact_url(url: &str) -> String {
+    match url.split_once('?') {
+        Some((base, _)) => format!("{}?{}", base, REDACTED),
+        None => url.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::HeaderValue;
+
+    fn exchange<'a>(headers: &'a HeaderMap, body: &'a [u8]) -> Exchange<'a> {
+        Exchange {
+            started: Utc::now(),
+            method: "POST",
+            url: "http://localhost/graphql",
+            operation: Some("ListTeams"),
+            attempt: 2,
+            request_headers: headers,
+            request_body: Some(r#"{"query":"{ teams { nodes { id } } }"}"#),
+            request_size: 38,
+            response: Some((StatusCode::OK, headers)),
+            response_body: body,
+            latency: Duration::from_millis(120),
+            error: None,
+        }
+    }
+
+    #[test]
+    fn test_redaction() {
+        let mut headers = HeaderMap::new();
+        headers.insert("authorization", HeaderValue::from_static("lin_api_secret"));
+        headers.insert("x-gateway-key", HeaderValue::from_static("abc"));
+        headers.insert("x-complexity", HeaderValue::from_static("12"));
+
+        let line = json_line(&exchange(&headers, br#"{"data":{}}"#));
+        assert_eq!(line["request"]["headers"]["authorization"], REDACTED);
+        assert_eq!(line["request"]["headers"]["x-gateway-key"], REDACTED);
+        assert_eq!(line["response"]["headers"]["x-complexity"], "12");
+        assert_eq!(line["response"]["body"]["data"], serde_json::json!({}));
+        assert_eq!(line["attempt"], 2);
+        assert_eq!(line["latencyMs"], 120);
+
+        assert_eq!(redact_url("https://uploads.example/f?X-Sig=abc"), "https://uploads.example/f?[REDACTED]");
+    }
+
+    #[test]
+    fn test_body_secrets_redacted() {
+        let headers = HeaderMap::new();
+        let request = r#"{"query":"mutation WebhookCreate($input: WebhookCreateInput!) { webhookCreate(input: $input) { success } }","variables":{"input":{"url":"https://example.com/hook","secret":"lin_wh_s3cret"}}}"#;
+        let response = br#"{"data":{"webhook":{"id":"wh-1","secret":"lin_wh_s3cret","signing_token":null}}}"#;
+        let mut traced = exchange(&headers, response);
+        traced.request_body = Some(request);
+
+        let line = json_line(&traced);
+        assert!(!line.to_string().contains("lin_wh_s3cret"));
+        assert_eq!(line["request"]["body"]["variables"]["input"]["secret"], REDACTED);
+        assert_eq!(line["request"]["body"]["variables"]["input"]["url"], "https://example.com/hook");
+        assert_eq!(line["response"]["body"]["data"]["webhook"]["secret"], REDACTED);
+        assert!(line["response"]["body"]["data"]["webhook"]["signing_token"].is_null());
+
+        let entry = har_entry(&traced);
+        assert!(!entry.to_string().contains("lin_wh_s3cret"));
+        assert!(entry["request"]["postData"]["text"].as_str().unwrap().contains("webhookCreate"));
+    }
+
+    #[test]
+    fn test_har_document() {
+        let path = std::env::temp_dir().join(format!("linears-trace-{}.har", std::process::id()));
+        let tracer = Tracer::create(&path, TraceFormat::from_path(&path)).unwrap();
+        let headers = HeaderMap::new();
+        tracer.record(&exchange(&headers, b"{}")).unwrap();
+        tracer.record(&exchange(&headers, b"{}")).unwrap();
+
+        let har: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        let entries = har["log"]["entries"].as_array().unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0]["response"]["status"], 200);
+        assert_eq!(entries[0]["_operation"], "ListTeams");
+        let _ = std::fs::remove_file(&path);
+    }
+}
@@ -0,0 +1,69 @@
+//! Cache commands: stats, clear
+
+use anyhow::{Context, Result};
+use std::path::PathBuf;
+
+use crate::cli::{CacheAction, Cli};
+use crate::client::{cache_stats, clear_cache, default_ttl_secs};
+use crate::common::constants::{cache, env, state};
+use crate::generated::Resource;
+use crate::render;
+
+/// Directory holding cached responses
+pub fn cache_dir() -> Result<PathBuf> {
+    let cache_home = match std::env::var(env::CACHE_HOME) {
+        Ok(dir) if !dir.trim().is_empty() => PathBuf::from(dir),
+        _ => {
+            let home = std::env::var(env::HOME)
+                .context("Cannot locate the cache: neither XDG_CACHE_HOME nor HOME is set")?;
+            PathBuf::from(home).join(".cache")
+        }
+    };
+    Ok(cache_home.join(state::DIR_NAME).join(cache::RESPONSES_DIR))
+}
+
+/// Handle response cache commands
+pub fn cmd_cache(cli: &Cli, action: CacheAction) -> Result<()> {
+    let dir = cache_dir()?;
+    let (operation, result) = match action {
+        CacheAction::Stats => {
+            let stats = cache_stats(&dir)?;
+            let resources: Vec<serde_json::Value> = stats
+                .resources
+                .iter()
+                .map(|(resource, entries)| {
+                    let ttl = Resource::all()
+                        .iter()
+                        .find(|r| r.field_name() == resource)
+                        .and_then(|r| default_ttl_secs(*r))
+                        .unwrap_or(cache::FALLBACK_TTL_SECS);
+                    serde_json::json!({ "resource": resource, "entries": entries, "ttlSecs": ttl })
+                })
+                .collect();
+            let result = serde_json::json!({
+                "dir": dir.display().to_string(),
+                "entries": stats.entries,
+                "fresh": stats.fresh,
+                "expired": stats.expired,
+                "bytes": stats.bytes,
+                "resources": resources,
+            });
+            ("cacheStats", result)
+        }
+        CacheAction::Clear { resource } => {
+            let removed = clear_cache(&dir, resource)?;
+            let result = serde_json::json!({
+                "dir": dir.display().to_string(),
+                "resource": resource.map(|r| r.field_name()),
+                "removed": removed,
+            });
+            ("cacheClear", result)
+        }
+    };
+
+    println!(
+        "{}",
+        render::render_cache(cli.global.output, operation, &result, cli.global.pretty)
+    );
+    Ok(())
+}
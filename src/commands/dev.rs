@@ -0,0 +1,29 @@
+//! Dev commands: local fake API server
+
+use anyhow::{Context, Result};
+use std::io::Write;
+
+use crate::cli::{Cli, DevAction};
+use crate::fake_server::FakeServer;
+
+/// Handle development commands
+pub async fn cmd_dev(_cli: &Cli, action: DevAction) -> Result<()> {
+    match action {
+        DevAction::Serve { host, port, issues } => {
+            let server = FakeServer::bind(&host, port, issues)
+                .await
+                .with_context(|| format!("Failed to bind {}:{}", host, port))?;
+
+            // The URL is the only stdout line so scripts can read it (useful with --port 0)
+            println!("Fake Linear API listening on {}", server.url()?);
+            std::io::stdout().flush()?;
+            eprintln!("Use: linears --endpoint {} <command>", server.url()?);
+
+            tokio::select! {
+                result = server.serve() => result?,
+                _ = tokio::signal::ctrl_c() => eprintln!("Shutting down"),
+            }
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,286 @@
+//! Export command: every entity of the chosen resources as NDJSON, plus a manifest
+//!
+//! Layout of `--out-dir` (format version 1):
+//! - `<plural>.ndjson` (or `.ndjson.gz` with `--gzip`): one wide-preset
+//!   entity per line, archived entities included
+//! - `manifest.json`: format version, schema commit, the `--since` cutoff
+//!   (relative durations resolved to RFC 3339), timestamps, and per resource
+//!   its file, entity count, whether it finished and any error
+//!
+//! Files are written as `<file>.tmp` and renamed once complete. The manifest
+//! is written even when a resource fails.
+
+use anyhow::{Context, Result};
+use chrono::Utc;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use futures::future::Either;
+use futures::TryStreamExt;
+use std::collections::HashSet;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+use crate::cli::{Cli, ExportOptions, SCHEMA_META};
+use crate::client::{Client, GraphQLError};
+use crate::common::constants::{export, pagination};
+use crate::common::FieldsetPreset;
+use crate::generated::{self, Resource};
+use crate::interrupt::{self, Interrupted};
+use crate::paginate::{dedup_by_id, fetch_partitions, partition_filters, Paginator};
+use crate::progress::with_spinner;
+use crate::query_builder::ListQuery;
+use crate::render;
+use crate::validate;
+
+use super::{check_complexity, create_client, ensure_complete};
+
+/// An NDJSON file, optionally gzipped
+enum NdjsonWriter {
+    Plain(BufWriter<File>),
+    Gzip(GzEncoder<BufWriter<File>>),
+}
+
+impl NdjsonWriter {
+    fn create(path: &Path, gzip: bool) -> Result<Self> {
+        let file = File::create(path).with_context(|| format!("Failed to create {}", path.display()))?;
+        let file = BufWriter::new(file);
+        Ok(if gzip {
+            Self::Gzip(GzEncoder::new(file, Compression::default()))
+        } else {
+            Self::Plain(file)
+        })
+    }
+
+    fn write_node(&mut self, node: &serde_json::Value) -> Result<()> {
+        let writer: &mut dyn Write = match self {
+            Self::Plain(w) => w,
+            Self::Gzip(w) => w,
+        };
+        serde_json::to_writer(&mut *writer, node)?;
+        writer.write_all(b"\n")?;
+        Ok(())
+    }
+
+    fn finish(self) -> Result<()> {
+        match self {
+            Self::Plain(mut w) => w.flush()?,
+            Self::Gzip(w) => w.finish()?.flush()?,
+        }
+        Ok(())
+    }
+}
+
+/// Export each resource to its own NDJSON file and write the manifest
+pub async fn cmd_export(cli: &Cli, options: ExportOptions) -> Result<()> {
+    // A relative --since is fixed once, so every resource shares one cutoff
+    let since = options.since.as_deref().map(validate::resolve_date).transpose()?;
+    let filter = since
+        .as_ref()
+        .map(|since| serde_json::json!({ "updatedAt": { "gte": since } }));
+    if let Some(ref filter) = filter {
+        if let Some(resource) = options
+            .resources
+            .iter()
+            .find(|r| generated::validate_filter_keys(**r, filter).is_err())
+        {
+            anyhow::bail!("{} cannot be filtered by updatedAt, so --since does not apply", resource.field_name());
+        }
+    }
+    if options.parallel.is_some() {
+        let created = serde_json::json!({ "createdAt": {} });
+        if let Some(resource) = options
+            .resources
+            .iter()
+            .find(|r| generated::validate_filter_keys(**r, &created).is_err())
+        {
+            anyhow::bail!("--parallel needs a createdAt filter, which {} does not support", resource.plural_name());
+        }
+    }
+
+    let client = create_client(&cli.global)?;
+    let dir = PathBuf::from(&options.out_dir);
+    std::fs::create_dir_all(&dir).with_context(|| format!("Failed to create {}", dir.display()))?;
+    let schema: serde_json::Value = serde_json::from_str(SCHEMA_META).unwrap_or_default();
+    let exported_at = Utc::now().to_rfc3339();
+
+    // The first Ctrl-C stops after the current page; the manifest marks what finished
+    let _graceful = interrupt::graceful();
+    let mut rows = Vec::new();
+    let mut errors = Vec::new();
+    let mut interrupted = false;
+    let mut failure = None;
+    for &resource in &options.resources {
+        let file_name = format!(
+            "{}.ndjson{}",
+            resource.plural_name(),
+            if options.gzip { ".gz" } else { "" }
+        );
+        // Written aside and renamed once complete, so a failed run never
+        // leaves a truncated file under the final name
+        let path = dir.join(&file_name);
+        let partial = dir.join(format!("{}.tmp", file_name));
+        let started_at = Utc::now().to_rfc3339();
+
+        let mut tally = Tally::default();
+        let outcome = export_resource(cli, &client, resource, filter.as_ref(), &options, &partial, &mut tally)
+            .await
+            .and_then(|finished| {
+                if finished {
+                    std::fs::rename(&partial, &path)
+                        .with_context(|| format!("Failed to move {} into place", partial.display()))?;
+                }
+                Ok(finished)
+            });
+        let complete = matches!(outcome, Ok(true));
+        if !complete {
+            let _ = std::fs::remove_file(&partial);
+        }
+        errors.extend(tally.errors);
+
+        let mut row = serde_json::json!({
+            "resource": resource.field_name(),
+            "file": file_name,
+            "count": tally.count,
+            "startedAt": started_at,
+            "finishedAt": Utc::now().to_rfc3339(),
+            "complete": complete,
+        });
+        match outcome {
+            Ok(true) => rows.push(row),
+            Ok(false) => {
+                rows.push(row);
+                interrupted = true;
+                break;
+            }
+            Err(e) => {
+                row["error"] = serde_json::json!(format!("{:#}", e));
+                rows.push(row);
+                failure = Some(e);
+                break;
+            }
+        }
+    }
+
+    let finished = !interrupted && failure.is_none();
+    let manifest = serde_json::json!({
+        "formatVersion": export::FORMAT_VERSION,
+        "linearsVersion": env!("CARGO_PKG_VERSION"),
+        "schema": {
+            "commit": schema["commit"],
+            "commitDate": schema["commitDate"],
+        },
+        "exportedAt": exported_at,
+        "completedAt": finished.then(|| Utc::now().to_rfc3339()),
+        "since": since,
+        "gzip": options.gzip,
+        "resources": rows,
+    });
+    let manifest_path = dir.join(export::MANIFEST_FILE);
+    std::fs::write(&manifest_path, serde_json::to_string_pretty(&manifest)?)
+        .with_context(|| format!("Failed to write {}", manifest_path.display()))?;
+
+    let mut result = manifest;
+    result["dir"] = serde_json::json!(dir.display().to_string());
+    println!(
+        "{}",
+        render::render_export(cli.global.output, &result, cli.global.pretty)
+    );
+    if let Some(e) = failure {
+        return Err(e);
+    }
+    if interrupted {
+        let resource = rows.last().and_then(|r| r["resource"].as_str()).unwrap_or_default();
+        eprintln!("Stopped while exporting {}; the manifest marks it incomplete", resource);
+        return Err(Interrupted.into());
+    }
+    ensure_complete(errors)
+}
+
+/// Entities written and GraphQL errors seen while exporting one resource
+#[derive(Default)]
+struct Tally {
+    count: usize,
+    errors: Vec<GraphQLError>,
+}
+
+/// Write one resource's entities to `path`, tallying them as they are written
+///
+/// Returns whether every page was fetched (false if interrupted).
+async fn export_resource(
+    cli: &Cli,
+    client: &Client,
+    resource: Resource,
+    filter: Option<&serde_json::Value>,
+    options: &ExportOptions,
+    path: &Path,
+    tally: &mut Tally,
+) -> Result<bool> {
+    let mut writer = NdjsonWriter::create(path, options.gzip)?;
+    let query = ListQuery {
+        include_archived: true,
+        filter: filter.cloned(),
+        preset: FieldsetPreset::Wide,
+        ..Default::default()
+    };
+    let verbose = cli.global.verbose;
+
+    // With --parallel, each page below is a whole createdAt range
+    let (pages, ranges) = match options.parallel {
+        Some(parallel) => {
+            let count = parallel as usize * pagination::PARTITIONS_PER_WORKER;
+            let Some(probe) = interrupt::cancellable(with_spinner(
+                &format!("Probing {}...", resource.plural_name()),
+                partition_filters(client, resource, filter, true, count),
+            ))
+            .await
+            else {
+                return Ok(false);
+            };
+            let filters = probe?.map(|(_, filters)| filters).unwrap_or_default();
+            let paginate = move |filter: serde_json::Value| {
+                let partition = ListQuery {
+                    filter: Some(filter),
+                    ..query.clone()
+                };
+                Paginator::list(client, resource, partition, verbose)
+            };
+            if let Some(first) = filters.first() {
+                let request = paginate(first.clone()).first_request();
+                check_complexity(&cli.global, &request.query, request.variables.as_ref().unwrap_or(&serde_json::Value::Null))?;
+            }
+            let ranges = filters.len();
+            (Either::Left(fetch_partitions(filters, parallel as usize, paginate)), Some(ranges))
+        }
+        None => {
+            let paginator = Paginator::list(client, resource, query, verbose);
+            let first = paginator.first_request();
+            check_complexity(&cli.global, &first.query, first.variables.as_ref().unwrap_or(&serde_json::Value::Null))?;
+            (Either::Right(paginator.pages()), None)
+        }
+    };
+    futures::pin_mut!(pages);
+
+    let mut seen = HashSet::new();
+    for page_count in 1.. {
+        let progress = match ranges {
+            Some(ranges) => format!("Exporting {} (range {}/{})...", resource.plural_name(), page_count, ranges),
+            None => format!("Exporting {} (page {})...", resource.plural_name(), page_count),
+        };
+        let Some(page) = interrupt::cancellable(with_spinner(&progress, pages.try_next())).await else {
+            return Ok(false);
+        };
+        let Some(mut page) = page? else {
+            break;
+        };
+        // Ranges share their boundaries, so an entity can arrive twice
+        dedup_by_id(&mut page.nodes, &mut seen);
+        for node in &page.nodes {
+            writer.write_node(node)?;
+        }
+        tally.count += page.nodes.len();
+        tally.errors.extend(page.errors);
+    }
+    writer.finish()?;
+    Ok(true)
+}
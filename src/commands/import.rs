@@ -0,0 +1,407 @@
+//! Import command: issues from other trackers via `issueBatchCreate`
+
+use anyhow::{Context, Result};
+use serde_json::{Map, Value};
+use std::collections::{BTreeSet, HashMap};
+use std::path::{Path, PathBuf};
+
+use crate::cli::{Cli, ImportFormat, ImportOptions};
+use crate::client::{Client, ClientError, GraphQLRequest, GraphQLResponse};
+use crate::common::constants::import as import_const;
+use crate::generated::{get_mutation_result_fields, Resource};
+use crate::import::{self, Mapping};
+use crate::interrupt::{self, Interrupted};
+use crate::mutation_builder::{build_issue_batch_create_mutation, idempotent_entity_id};
+use crate::progress::with_spinner;
+use crate::query_builder::{build_list_query, ListQuery};
+use crate::render;
+use crate::resolve;
+use crate::validate;
+
+use super::create_client;
+
+/// Progress of one source row
+struct ImportRow {
+    /// 1-based position in the source file
+    row: usize,
+    source_id: String,
+    title: String,
+    /// imported, skipped, invalid, failed, or ready (dry run)
+    status: &'static str,
+    input: Option<Value>,
+    issue: Option<Value>,
+    error: Option<String>,
+}
+
+impl ImportRow {
+    fn fail(&mut self, status: &'static str, error: String) {
+        self.status = status;
+        self.error = Some(error);
+        self.input = None;
+    }
+
+    fn to_json(&self, dry_run: bool) -> Value {
+        let mut row = serde_json::json!({
+            "row": self.row,
+            "sourceId": self.source_id,
+            "title": self.title,
+            "status": self.status,
+        });
+        if let Some(ref issue) = self.issue {
+            row["issue"] = issue.clone();
+        }
+        if let Some(ref error) = self.error {
+            row["error"] = Value::from(error.as_str());
+        }
+        if let (true, Some(input)) = (dry_run, &self.input) {
+            row["input"] = input.clone();
+        }
+        row
+    }
+}
+
+/// Import issues from an export file into a team
+pub async fn cmd_import(cli: &Cli, format: ImportFormat, file: String, options: ImportOptions) -> Result<()> {
+    let mut mapping = Mapping::defaults(format);
+    if let Some(ref path) = options.mapping {
+        mapping = mapping.merge(Mapping::parse(&validate::read_file(path)?)?);
+    }
+    let records = import::read_records(format, &validate::read_file(&file)?)?;
+
+    let id_map_path = options
+        .id_map
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(format!("{}{}", file, import_const::ID_MAP_SUFFIX)));
+    let mut id_map = load_id_map(&id_map_path)?;
+
+    // Map every record; rows already created are skipped, pending ones checked later
+    let mut rows: Vec<ImportRow> = records
+        .iter()
+        .enumerate()
+        .map(|(i, record)| {
+            let source_id = mapping.source_id(record);
+            let title = mapping
+                .fields
+                .get("title")
+                .and_then(|field| record.get(field))
+                .and_then(Value::as_str)
+                .unwrap_or_default()
+                .to_string();
+            let mut row = ImportRow {
+                row: i + 1,
+                source_id,
+                title,
+                status: "ready",
+                input: None,
+                issue: None,
+                error: None,
+            };
+            if let Some(issue) = id_map.get(&row.source_id).filter(|issue| !is_pending(issue)) {
+                row.status = "skipped";
+                row.issue = Some(issue.clone());
+            } else {
+                match mapping.apply(record) {
+                    Ok(input) => row.input = Some(input),
+                    Err(e) => row.fail("invalid", format!("{:#}", e)),
+                }
+            }
+            row
+        })
+        .collect();
+
+    let client = create_client(&cli.global)?;
+    let team_id = with_spinner("Resolving team...", resolve::resolve_team(&client, &options.team)).await?;
+    with_spinner("Resolving names...", resolve_names(&client, &team_id, &mut rows)).await?;
+
+    if !options.dry_run {
+        create_issues(cli, &client, &team_id, &mut rows, &mut id_map, &id_map_path).await?;
+    }
+
+    let count = |status: &str| rows.iter().filter(|r| r.status == status).count();
+    let result = serde_json::json!({
+        "file": file,
+        "team": options.team,
+        "dryRun": options.dry_run,
+        "idMap": id_map_path.display().to_string(),
+        "imported": count("imported"),
+        "ready": count("ready"),
+        "skipped": count("skipped"),
+        "invalid": count("invalid"),
+        "failed": count("failed"),
+        "rows": rows.iter().map(|r| r.to_json(options.dry_run)).collect::<Vec<_>>(),
+    });
+    println!(
+        "{}",
+        render::render_import(cli.global.output, &result, cli.global.pretty)
+    );
+
+    if interrupt::is_interrupted() {
+        eprintln!("Stopped after {} issues; run the import again to continue", count("imported"));
+        return Err(Interrupted.into());
+    }
+    let not_imported = count("invalid") + count("failed");
+    if not_imported > 0 {
+        anyhow::bail!(
+            "{} of {} rows {} not be imported",
+            not_imported,
+            rows.len(),
+            if options.dry_run { "would" } else { "could" }
+        );
+    }
+    Ok(())
+}
+
+/// Replace names with IDs in every ready row, failing rows with unknown names
+async fn resolve_names(client: &Client, team_id: &str, rows: &mut [ImportRow]) -> Result<()> {
+    let inputs = || rows.iter().filter_map(|r| r.input.as_ref());
+    let labels: BTreeSet<String> = inputs()
+        .filter_map(|input| input["labels"].as_array())
+        .flatten()
+        .filter_map(|l| l.as_str().map(String::from))
+        .collect();
+    let assignees: BTreeSet<String> = inputs()
+        .filter_map(|input| input["assignee"].as_str().map(String::from))
+        .collect();
+    let needs_states = inputs().any(|input| input.get("state").is_some());
+
+    let label_ids = resolve::label_ids(client, &labels.into_iter().collect::<Vec<_>>(), Some(team_id)).await?;
+    let states = if needs_states {
+        resolve::team_states(client, team_id).await?
+    } else {
+        HashMap::new()
+    };
+    let mut users: HashMap<String, Result<String, String>> = HashMap::new();
+    for name in assignees {
+        let user = resolve::resolve_user(client, &name).await.map_err(|e| format!("{:#}", e));
+        users.insert(name, user);
+    }
+
+    for row in rows.iter_mut() {
+        let Some(Value::Object(mut input)) = row.input.take() else {
+            continue;
+        };
+        match finalize(&mut input, team_id, &label_ids, &states, &users) {
+            Ok(()) => row.input = Some(Value::Object(input)),
+            Err(e) => row.fail("invalid", e),
+        }
+    }
+    Ok(())
+}
+
+/// Turn a mapped row into an `IssueCreateInput`
+fn finalize(
+    input: &mut Map<String, Value>,
+    team_id: &str,
+    label_ids: &HashMap<String, String>,
+    states: &HashMap<String, String>,
+    users: &HashMap<String, Result<String, String>>,
+) -> Result<(), String> {
+    input.insert("teamId".to_string(), Value::from(team_id));
+    if let Some(assignee) = input.remove("assignee") {
+        let name = assignee.as_str().unwrap_or_default();
+        match users.get(name) {
+            Some(Ok(id)) => {
+                input.insert("assigneeId".to_string(), Value::from(id.as_str()));
+            }
+            Some(Err(e)) => return Err(e.clone()),
+            None => return Err(format!("Unknown user '{}'", name)),
+        }
+    }
+    if let Some(Value::Array(labels)) = input.remove("labels") {
+        let names: Vec<&str> = labels.iter().filter_map(Value::as_str).collect();
+        let unknown: Vec<&str> = names
+            .iter()
+            .copied()
+            .filter(|n| !label_ids.contains_key(&n.to_lowercase()))
+            .collect();
+        if !unknown.is_empty() {
+            return Err(format!("Unknown labels: {}", unknown.join(", ")));
+        }
+        let ids: Vec<&String> = names.iter().map(|n| &label_ids[&n.to_lowercase()]).collect();
+        input.insert("labelIds".to_string(), serde_json::json!(ids));
+    }
+    if let Some(state) = input.remove("state") {
+        let name = state.as_str().unwrap_or_default();
+        let id = states
+            .get(&name.to_lowercase())
+            .ok_or_else(|| format!("Unknown workflow state '{}' in the team", name))?;
+        input.insert("stateId".to_string(), Value::from(id.as_str()));
+    }
+    Ok(())
+}
+
+/// Create the ready rows in batches, recording each batch in the ID map
+///
+/// Every row gets a deterministic issue ID from the team and its source ID,
+/// written to the ID map as pending before its batch is sent. A batch whose
+/// outcome is unknown is looked up by those IDs, on retry and on the next
+/// run, instead of being created twice.
+async fn create_issues(
+    cli: &Cli,
+    client: &Client,
+    team_id: &str,
+    rows: &mut [ImportRow],
+    id_map: &mut Map<String, Value>,
+    id_map_path: &Path,
+) -> Result<()> {
+    // The first Ctrl-C stops after the current batch; the ID map keeps what was created
+    let _graceful = interrupt::graceful();
+    let mut ready: Vec<&mut ImportRow> = rows.iter_mut().filter(|r| r.status == "ready").collect();
+    let mut ids = Vec::with_capacity(ready.len());
+    for row in ready.iter_mut() {
+        let id = idempotent_entity_id("issue", &format!("import:{}:{}", team_id, row.source_id));
+        if let Some(Value::Object(input)) = row.input.as_mut() {
+            input.insert("id".to_string(), Value::from(id.as_str()));
+        }
+        ids.push(id);
+    }
+
+    // Pending rows from an interrupted or failed run may exist already
+    let pending: Vec<String> = ready
+        .iter()
+        .zip(&ids)
+        .filter(|(row, _)| id_map.get(&row.source_id).is_some_and(is_pending))
+        .map(|(_, id)| id.clone())
+        .collect();
+    if !pending.is_empty() {
+        let found = with_spinner("Checking pending issues...", find_issues(client, &pending)).await?;
+        for (row, id) in ready.iter_mut().zip(&ids) {
+            if let Some(issue) = found.get(id) {
+                row.status = "skipped";
+                row.input = None;
+                row.issue = Some(issue.clone());
+                id_map.insert(row.source_id.clone(), issue.clone());
+            }
+        }
+        save_id_map(id_map_path, id_map)?;
+    }
+    let (mut ready, ids): (Vec<&mut ImportRow>, Vec<String>) =
+        ready.into_iter().zip(ids).filter(|(row, _)| row.status == "ready").unzip();
+
+    let batches = ready.len().div_ceil(import_const::BATCH_SIZE);
+    for (i, (batch, batch_ids)) in ready
+        .chunks_mut(import_const::BATCH_SIZE)
+        .zip(ids.chunks(import_const::BATCH_SIZE))
+        .enumerate()
+    {
+        if interrupt::is_interrupted() {
+            break;
+        }
+        for (row, id) in batch.iter().zip(batch_ids) {
+            id_map.insert(row.source_id.clone(), serde_json::json!({ "id": id, "pending": true }));
+        }
+        save_id_map(id_map_path, id_map)?;
+
+        let inputs: Vec<Value> = batch.iter().filter_map(|r| r.input.clone()).collect();
+        let (query, variables) = build_issue_batch_create_mutation(inputs);
+        if cli.global.verbose {
+            eprintln!("Query: {}", query);
+            eprintln!("Variables: {}", serde_json::to_string_pretty(&variables)?);
+        }
+        let request = GraphQLRequest {
+            query,
+            variables: Some(variables),
+            operation_name: None,
+        };
+        let check = || find_batch(client, batch_ids);
+        let response = with_spinner(
+            &format!("Creating issues (batch {} of {})...", i + 1, batches),
+            client.execute_idempotent(request, check),
+        )
+        .await;
+
+        let created = match response {
+            Ok(response) => response.data.unwrap_or_default()["issueBatchCreate"]["issues"]
+                .as_array()
+                .cloned()
+                .unwrap_or_default(),
+            Err(e) => {
+                // The rows stay pending, so the next run checks for them first
+                for row in batch.iter_mut() {
+                    row.fail("failed", e.to_string());
+                }
+                continue;
+            }
+        };
+        for (row, issue) in batch.iter_mut().zip(created.iter().map(Some).chain(std::iter::repeat(None))) {
+            match issue {
+                Some(issue) => {
+                    row.status = "imported";
+                    row.issue = Some(issue.clone());
+                    id_map.insert(row.source_id.clone(), issue.clone());
+                }
+                None => row.fail("failed", "Missing from the batch create response".to_string()),
+            }
+        }
+        save_id_map(id_map_path, id_map)?;
+    }
+    Ok(())
+}
+
+/// Issues that exist among `ids`, by ID
+///
+/// Pending IDs pile up across failed runs, so they are looked up a batch at a
+/// time to stay within the API's page size.
+async fn find_issues(client: &Client, ids: &[String]) -> Result<HashMap<String, Value>, ClientError> {
+    let mut found = HashMap::new();
+    for chunk in ids.chunks(import_const::BATCH_SIZE) {
+        let query = ListQuery {
+            first: Some(chunk.len() as i32),
+            include_archived: true,
+            filter: Some(serde_json::json!({ "id": { "in": chunk } })),
+            select: Some(vec![get_mutation_result_fields("issue").to_string()]),
+            ..Default::default()
+        };
+        let (query, variables) = build_list_query(Resource::Issue, &query);
+        let response = client
+            .execute(GraphQLRequest {
+                query,
+                variables: Some(variables),
+                operation_name: None,
+            })
+            .await?;
+        let data = response.data.unwrap_or_default();
+        found.extend(
+            data["issues"]["nodes"]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .filter_map(|issue| Some((issue["id"].as_str()?.to_string(), issue.clone()))),
+        );
+    }
+    Ok(found)
+}
+
+/// The batch create's response, if every issue in it already exists
+async fn find_batch(client: &Client, ids: &[String]) -> Result<Option<GraphQLResponse>, ClientError> {
+    let mut found = find_issues(client, ids).await?;
+    let issues: Option<Vec<Value>> = ids.iter().map(|id| found.remove(id)).collect();
+    Ok(issues.map(|issues| {
+        eprintln!("{} issues already exist; not creating duplicates", issues.len());
+        GraphQLResponse {
+            data: Some(serde_json::json!({
+                "issueBatchCreate": { "success": true, "issues": issues }
+            })),
+            errors: None,
+        }
+    }))
+}
+
+/// Whether an ID map entry was written before its batch was confirmed
+fn is_pending(entry: &Value) -> bool {
+    entry["pending"].as_bool() == Some(true)
+}
+
+/// Old → new IDs from earlier runs (empty if the file does not exist)
+fn load_id_map(path: &Path) -> Result<Map<String, Value>> {
+    if !path.exists() {
+        return Ok(Map::new());
+    }
+    let content = std::fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))?;
+    serde_json::from_str(&content).with_context(|| format!("Invalid ID map {}", path.display()))
+}
+
+fn save_id_map(path: &Path, id_map: &Map<String, Value>) -> Result<()> {
+    std::fs::write(path, serde_json::to_string_pretty(id_map)?)
+        .with_context(|| format!("Failed to write ID map {}", path.display()))
+}
@@ -4,24 +4,63 @@
 //! - query: list, get, search, raw
 //! - mutation: create, update, delete, archive, unarchive, mutate
 //! - discovery: resources, ops
+//! - export: NDJSON workspace backups
+//! - import: issues from other trackers' exports
 //! - schema: info, diff
+//! - cache: response cache stats and clearing
+//! - sync: local mirror pulls and status
+//! - offline: list, get and search answered from the mirror
+//! - dev: local fake API server
+//! - rate_limit: API budget status
+//! - undo: journal replay
+//! - upload: file uploads
+//! - watch: change polling
+//! - webhook: webhook management, local receiver and signed test deliveries
 
+pub mod cache;
+pub mod dev;
 pub mod discovery;
+pub mod export;
+pub mod import;
 pub mod mutation;
+pub mod offline;
 pub mod query;
+pub mod rate_limit;
 pub mod schema;
+pub mod sync;
+pub mod undo;
+pub mod upload;
+pub mod watch;
+pub mod webhook;
 
 use anyhow::Result;
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+use tokio::io::AsyncWriteExt;
 
-use crate::cli::GlobalOptions;
-use crate::client::Client;
+use crate::cli::{ComplexityCheck, GlobalOptions, NetworkOptions, ReplayMode, RetryOptions, TraceFileFormat};
+use crate::client::{
+    Client, ClientError, GraphQLError, HttpOptions, ReplayMatch, ResponseCache, RetryPolicy, TraceFormat,
+};
 use crate::common::constants::env;
+use crate::common::OutputFormat;
+use crate::complexity::{estimate_complexity, suggest_page_size};
 
 // Re-export all command handlers for easy access
+pub use cache::cmd_cache;
+pub use dev::cmd_dev;
 pub use discovery::{cmd_ops, cmd_resources};
+pub use export::cmd_export;
+pub use import::cmd_import;
 pub use mutation::{cmd_archive, cmd_create, cmd_delete, cmd_mutate, cmd_unarchive, cmd_update};
 pub use query::{cmd_get, cmd_list, cmd_raw, cmd_search};
+pub use rate_limit::cmd_rate_limit;
 pub use schema::cmd_schema;
+pub use sync::cmd_sync;
+pub use undo::cmd_undo;
+pub use upload::cmd_upload;
+pub use watch::cmd_watch;
+pub use webhook::cmd_webhook;
 
 /// Get the API key from environment, returning error message if missing or empty
 pub fn get_api_key() -> Result<String, String> {
@@ -54,11 +93,151 @@ pub fn get_api_key() -> Result<String, String> {
 
 /// Create a client using the CLI's global options
 pub fn create_client(global: &GlobalOptions) -> Result<Client> {
-    let api_key = get_api_key().expect("API key already validated");
-    Ok(Client::new(
+    if global.offline {
+        anyhow::bail!("--offline only applies to list, get and search");
+    }
+    // Replays never reach the API, so they work without a key
+    let api_key = match global.replay {
+        Some(_) => get_api_key().unwrap_or_default(),
+        None => get_api_key().expect("API key already validated"),
+    };
+    let mut client = Client::new(
         &api_key,
         global.endpoint.as_deref(),
         global.timeout,
         global.workspace.as_deref(),
-    )?)
+        &http_options(&global.network),
+    )?
+    .with_partial_results(global.partial)
+    .with_verbose(global.verbose)
+    .with_retry_policy(retry_policy(&global.retry))
+    .with_structured_events(matches!(global.output, OutputFormat::Json | OutputFormat::Ndjson));
+
+    if let Some(ref dir) = global.record {
+        client = client.with_recording(Path::new(dir));
+    }
+    if let Some(ref path) = global.trace {
+        let path = Path::new(path);
+        let format = match global.trace_format {
+            Some(TraceFileFormat::Har) => TraceFormat::Har,
+            Some(TraceFileFormat::Jsonl) => TraceFormat::Jsonl,
+            None => TraceFormat::from_path(path),
+        };
+        client = client.with_trace(path, format)?;
+    }
+    if let Some(ref dir) = global.replay {
+        let mode = match global.replay_match {
+            ReplayMode::Strict => ReplayMatch::Strict,
+            ReplayMode::Lenient => ReplayMatch::Lenient,
+        };
+        client = client.with_replay(Path::new(dir), mode)?;
+    }
+
+    // Caching is opt-in; cassettes must see every request, so it is off while recording or replaying
+    let cache_requested = global.cache.cache || global.cache.cache_all || global.cache.refresh;
+    let cache_enabled =
+        cache_requested && !global.cache.no_cache && global.record.is_none() && global.replay.is_none();
+    if cache_enabled {
+        if let Ok(dir) = cache::cache_dir() {
+            let endpoint = global.endpoint.as_deref().unwrap_or_default();
+            let cache = ResponseCache::new(&dir, endpoint, global.workspace.as_deref(), &api_key)
+                .with_cache_all(global.cache.cache_all)
+                .with_refresh(global.cache.refresh);
+            client = client.with_cache(cache);
+        }
+    }
+
+    Ok(client)
+}
+
+/// Build the client's retry policy from the retry flags
+fn retry_policy(options: &RetryOptions) -> RetryPolicy {
+    RetryPolicy {
+        max_retries: options.max_retries,
+        max_network_retries: options.max_network_retries,
+        base_delay_ms: options.retry_base_delay,
+        max_delay_ms: options.retry_max_delay,
+        retry_network: !options.no_retry_network,
+        retry_timeout: !options.no_retry_timeout,
+        retry_mutation_timeouts: options.retry_mutation_timeouts,
+        max_rate_limit_wait_secs: options.max_rate_limit_wait,
+    }
+}
+
+/// Build the client's transport options from the network flags
+fn http_options(options: &NetworkOptions) -> HttpOptions {
+    HttpOptions {
+        proxy: options.proxy.clone(),
+        ca_cert: options.ca_cert.as_ref().map(PathBuf::from),
+        headers: options.headers.clone(),
+        user_agent: options.user_agent.clone(),
+        compress: options.compress,
+    }
+}
+
+/// Fail with exit code 5 if partial-result mode kept any GraphQL errors
+/// Call after rendering, so the partial data is still printed
+pub fn ensure_complete(errors: Vec<GraphQLError>) -> Result<()> {
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(ClientError::PartialData(errors).into())
+    }
+}
+
+/// Estimate a query's complexity before sending it
+///
+/// Prints the estimate with --verbose, then warns or refuses (per
+/// --complexity-check) when it exceeds --max-complexity.
+pub fn check_complexity(global: &GlobalOptions, query: &str, variables: &serde_json::Value) -> Result<()> {
+    if global.complexity_check == ComplexityCheck::Off && !global.verbose {
+        return Ok(());
+    }
+
+    let estimate = estimate_complexity(query, variables);
+    if global.verbose {
+        eprintln!("Estimated complexity: {:.0} points", estimate);
+    }
+
+    let max = global.max_complexity as f64;
+    if global.complexity_check == ComplexityCheck::Off || estimate <= max {
+        return Ok(());
+    }
+
+    let mut message = format!(
+        "Estimated query complexity {:.0} exceeds the limit of {}",
+        estimate, global.max_complexity
+    );
+    match suggest_page_size(query, variables, max) {
+        Some(size) => message.push_str(&format!(". A page size of {} fits (--first {})", size, size)),
+        None => message.push_str(". Select fewer fields or expansions"),
+    }
+
+    if global.complexity_check == ComplexityCheck::Refuse {
+        anyhow::bail!("{}", message);
+    }
+    eprintln!("Warning: {}", message);
+    Ok(())
+}
+
+/// Run an `--exec` hook through the shell with a JSON line on stdin
+pub async fn run_hook(command: &str, input: &serde_json::Value) -> Result<()> {
+    let mut shell = if cfg!(windows) {
+        let mut shell = tokio::process::Command::new("cmd");
+        shell.arg("/C");
+        shell
+    } else {
+        let mut shell = tokio::process::Command::new("sh");
+        shell.arg("-c");
+        shell
+    };
+    let mut child = shell.arg(command).stdin(Stdio::piped()).spawn()?;
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin.write_all(format!("{}\n", input).as_bytes()).await?;
+    }
+    let status = child.wait().await?;
+    if !status.success() {
+        anyhow::bail!("'{}' exited with {}", command, status);
+    }
+    Ok(())
 }
@@ -3,17 +3,141 @@
 use anyhow::Result;
 
 use crate::cli::{Cli, InputOptions, SetOptions, VarsOptions};
-use crate::client::GraphQLRequest;
-use crate::generated::{MutationOp, Resource};
+use crate::client::{Client, ClientError, GraphQLRequest, GraphQLResponse};
+use crate::generated::{get_entity_fields, get_mutation_result_fields, MutationOp, Resource};
+use crate::journal::{self, JournalEntry, JournalOp};
 use crate::mutation_builder::{
     build_archive_mutation, build_create_mutation, build_delete_mutation, build_mutation,
-    build_unarchive_mutation, build_update_mutation,
+    build_unarchive_mutation, build_update_mutation, idempotent_entity_id, input_fingerprint,
 };
 use crate::progress::with_spinner;
+use crate::query_builder::build_get_query_with_fields;
 use crate::render;
+use crate::resolve;
 use crate::validate;
 
-use super::create_client;
+use super::{create_client, ensure_complete};
+
+/// Input field that receives the markdown body for a resource
+fn markdown_body_field(resource: Resource) -> &'static str {
+    match resource {
+        Resource::Document => "content",
+        Resource::ProjectUpdate | Resource::Comment => "body",
+        _ => validate::MARKDOWN_BODY_FIELD,
+    }
+}
+
+/// Resolve create input, returning whether it came from a markdown document
+fn resolve_create_input(resource: Resource, input: &InputOptions) -> Result<(serde_json::Value, bool)> {
+    // --from-markdown, or an --input-file named `.md`/`.markdown`; inline input takes precedence
+    let path = match (&input.from_markdown, &input.input, &input.input_file) {
+        (Some(path), _, _) => path,
+        (None, None, Some(path)) if validate::is_markdown_path(path) => path,
+        _ => return Ok((validate::resolve_input(input.input.as_deref(), input.input_file.as_deref())?, false)),
+    };
+
+    // Markdown files use the resource's own body field (e.g. `content` for documents)
+    let content = validate::read_file(path)?;
+    Ok((validate::parse_markdown(&content, markdown_body_field(resource))?, true))
+}
+
+/// Fetch an entity's current values before a journaled mutation
+/// Returns None (with a warning) if they could not be captured
+async fn fetch_prior_entity(
+    cli: &Cli,
+    client: &Client,
+    resource: Resource,
+    id: &str,
+    fields: &str,
+) -> Option<serde_json::Value> {
+    let (query, variables) = build_get_query_with_fields(resource, id, fields);
+
+    if cli.global.verbose {
+        eprintln!("Query: {}", query);
+        eprintln!("Variables: {}", serde_json::to_string_pretty(&variables).unwrap_or_default());
+    }
+
+    let request = GraphQLRequest {
+        query,
+        variables: Some(variables),
+        operation_name: None,
+    };
+
+    match with_spinner("Capturing prior values...", client.execute(request)).await {
+        Ok(response) => response
+            .data
+            .and_then(|data| data.get(resource.field_name()).cloned())
+            .filter(|entity| !entity.is_null()),
+        Err(e) => {
+            eprintln!("Warning: could not capture prior values, undo will not be available: {}", e);
+            None
+        }
+    }
+}
+
+/// Append a completed mutation to the undo journal (warns instead of failing)
+fn record_journal_entry(
+    cli: &Cli,
+    op: JournalOp,
+    resource: Resource,
+    id: &str,
+    prior: Option<&serde_json::Value>,
+    before: serde_json::Value,
+    after: serde_json::Value,
+) {
+    // Prefer the UUID from the prior fetch over an identifier like ENG-123
+    let entity_id = prior.and_then(|p| p["id"].as_str()).unwrap_or(id);
+    let entry = JournalEntry::new(op, resource.field_name(), entity_id, before, after);
+
+    match journal::append(&entry) {
+        Ok(()) if cli.global.verbose => eprintln!("Journaled as {} (linears undo --id {})", op.as_str(), entry.id),
+        Ok(()) => {}
+        Err(e) => eprintln!("Warning: failed to write undo journal: {:#}", e),
+    }
+}
+
+/// Look up an entity created by an earlier attempt of an idempotent create
+/// Returns a response shaped like the create mutation's if it exists
+async fn find_created_entity(
+    client: &Client,
+    resource: Resource,
+    entity_id: &str,
+) -> Result<Option<GraphQLResponse>, ClientError> {
+    let resource_name = resource.field_name();
+    let (query, variables) = build_get_query_with_fields(
+        resource,
+        entity_id,
+        get_mutation_result_fields(resource_name),
+    );
+    let request = GraphQLRequest {
+        query,
+        variables: Some(variables),
+        operation_name: None,
+    };
+
+    let entity = match client.execute(request).await {
+        Ok(response) => response.data.and_then(|data| data.get(resource_name).cloned()),
+        // Linear reports a missing entity as an "Entity not found" GraphQL error
+        Err(ClientError::GraphQL(_, _)) => None,
+        Err(e) => return Err(e),
+    };
+
+    Ok(entity.filter(|e| !e.is_null()).map(|entity| {
+        eprintln!(
+            "{} {} already exists; not creating a duplicate",
+            resource_name, entity_id
+        );
+        GraphQLResponse {
+            data: Some(serde_json::json!({
+                format!("{}Create", resource_name): {
+                    "success": true,
+                    resource_name: entity,
+                }
+            })),
+            errors: None,
+        }
+    }))
+}
 
 /// Create a new entity
 pub async fn cmd_create(cli: &Cli, resource: Resource, input: InputOptions) -> Result<()> {
@@ -21,7 +145,34 @@ pub async fn cmd_create(cli: &Cli, resource: Resource, input: InputOptions) -> R
     let client = create_client(&cli.global)?;
 
     // Parse the input
-    let input_value = validate::resolve_input(input.input.as_deref(), input.input_file.as_deref())?;
+    let (mut input_value, from_markdown) = resolve_create_input(resource, &input)?;
+
+    // Front matter refers to teams, labels and users by name
+    if from_markdown {
+        resolve::resolve_friendly_fields(&client, &mut input_value).await?;
+    }
+
+    // An idempotency key pins the entity ID so retries can detect an applied create
+    let idempotent_id = if let Some(ref key) = input.idempotency_key {
+        let key = if key == "auto" {
+            input_fingerprint(&input_value)
+        } else {
+            key.clone()
+        };
+        let entity_id = match input_value.get("id").and_then(|id| id.as_str()) {
+            Some(id) => id.to_string(),
+            None => idempotent_entity_id(resource.field_name(), &key),
+        };
+        if let Some(obj) = input_value.as_object_mut() {
+            obj.insert("id".to_string(), serde_json::json!(entity_id));
+        }
+        if cli.global.verbose {
+            eprintln!("Idempotency key: {} (id {})", key, entity_id);
+        }
+        Some(entity_id)
+    } else {
+        None
+    };
 
     // Build the mutation
     let (query, variables) = build_create_mutation(resource.field_name(), input_value);
@@ -38,19 +189,27 @@ pub async fn cmd_create(cli: &Cli, resource: Resource, input: InputOptions) -> R
         operation_name: None,
     };
 
-    let response = with_spinner(
-        &format!("Creating {}...", resource.field_name()),
-        client.execute(request),
-    )
-    .await?;
+    let message = format!("Creating {}...", resource.field_name());
+    let response = match idempotent_id {
+        Some(ref entity_id) => {
+            let check = || find_created_entity(&client, resource, entity_id);
+            with_spinner(&message, client.execute_idempotent(request, check)).await?
+        }
+        None => with_spinner(&message, client.execute(request)).await?,
+    };
 
     // Render the response using render module
     println!(
         "{}",
-        render::render_helper_mutation(cli.global.output, &response.data, cli.global.pretty)
+        render::render_helper_mutation(
+            cli.global.output,
+            &response.data,
+            response.errors.as_deref(),
+            cli.global.pretty
+        )
     );
 
-    Ok(())
+    ensure_complete(response.errors.unwrap_or_default())
 }
 
 /// Update an existing entity
@@ -61,8 +220,20 @@ pub async fn cmd_update(cli: &Cli, resource: Resource, id: String, set: SetOptio
     // Parse the input
     let input_value = validate::resolve_input(set.set.as_deref(), set.set_file.as_deref())?;
 
+    // Capture prior values of the fields being changed for the undo journal
+    let keys: Vec<&str> = input_value
+        .as_object()
+        .map(|o| o.keys().map(String::as_str).collect())
+        .unwrap_or_default();
+    let selection = journal::prior_values_selection(&keys);
+    let prior = fetch_prior_entity(cli, &client, resource, &id, &selection).await;
+    let before = prior
+        .as_ref()
+        .map(|entity| journal::prior_values_from_entity(entity, &keys))
+        .unwrap_or_default();
+
     // Build the mutation
-    let (query, variables) = build_update_mutation(resource.field_name(), &id, input_value);
+    let (query, variables) = build_update_mutation(resource.field_name(), &id, input_value.clone());
 
     if cli.global.verbose {
         eprintln!("Query: {}", query);
@@ -82,13 +253,20 @@ pub async fn cmd_update(cli: &Cli, resource: Resource, id: String, set: SetOptio
     )
     .await?;
 
+    record_journal_entry(cli, JournalOp::Update, resource, &id, prior.as_ref(), before, input_value);
+
     // Render the response using render module
     println!(
         "{}",
-        render::render_helper_mutation(cli.global.output, &response.data, cli.global.pretty)
+        render::render_helper_mutation(
+            cli.global.output,
+            &response.data,
+            response.errors.as_deref(),
+            cli.global.pretty
+        )
     );
 
-    Ok(())
+    ensure_complete(response.errors.unwrap_or_default())
 }
 
 /// Delete an entity
@@ -96,6 +274,10 @@ pub async fn cmd_delete(cli: &Cli, resource: Resource, id: String) -> Result<()>
     // Create client
     let client = create_client(&cli.global)?;
 
+    // Snapshot the entity for the undo journal
+    let prior = fetch_prior_entity(cli, &client, resource, &id, get_entity_fields(resource)).await;
+    let before = prior.clone().unwrap_or_default();
+
     // Build the mutation
     let (query, variables) = build_delete_mutation(resource.field_name(), &id);
 
@@ -117,13 +299,21 @@ pub async fn cmd_delete(cli: &Cli, resource: Resource, id: String) -> Result<()>
     )
     .await?;
 
+    let after = serde_json::json!({ "deleted": true });
+    record_journal_entry(cli, JournalOp::Delete, resource, &id, prior.as_ref(), before, after);
+
     // Render the response using render module
     println!(
         "{}",
-        render::render_helper_mutation(cli.global.output, &response.data, cli.global.pretty)
+        render::render_helper_mutation(
+            cli.global.output,
+            &response.data,
+            response.errors.as_deref(),
+            cli.global.pretty
+        )
     );
 
-    Ok(())
+    ensure_complete(response.errors.unwrap_or_default())
 }
 
 /// Archive an entity
@@ -131,6 +321,13 @@ pub async fn cmd_archive(cli: &Cli, resource: Resource, id: String) -> Result<()
     // Create client
     let client = create_client(&cli.global)?;
 
+    // Capture archive state for the undo journal
+    let prior = fetch_prior_entity(cli, &client, resource, &id, "id archivedAt").await;
+    let before = prior
+        .as_ref()
+        .map(|entity| journal::prior_values_from_entity(entity, &["archivedAt"]))
+        .unwrap_or_default();
+
     // Build the mutation
     let (query, variables) = build_archive_mutation(resource.field_name(), &id);
 
@@ -152,13 +349,21 @@ pub async fn cmd_archive(cli: &Cli, resource: Resource, id: String) -> Result<()
     )
     .await?;
 
+    let after = serde_json::json!({ "archived": true });
+    record_journal_entry(cli, JournalOp::Archive, resource, &id, prior.as_ref(), before, after);
+
     // Render the response using render module
     println!(
         "{}",
-        render::render_helper_mutation(cli.global.output, &response.data, cli.global.pretty)
+        render::render_helper_mutation(
+            cli.global.output,
+            &response.data,
+            response.errors.as_deref(),
+            cli.global.pretty
+        )
     );
 
-    Ok(())
+    ensure_complete(response.errors.unwrap_or_default())
 }
 
 /// Unarchive an entity
@@ -190,10 +395,15 @@ pub async fn cmd_unarchive(cli: &Cli, resource: Resource, id: String) -> Result<
     // Render the response using render module
     println!(
         "{}",
-        render::render_helper_mutation(cli.global.output, &response.data, cli.global.pretty)
+        render::render_helper_mutation(
+            cli.global.output,
+            &response.data,
+            response.errors.as_deref(),
+            cli.global.pretty
+        )
     );
 
-    Ok(())
+    ensure_complete(response.errors.unwrap_or_default())
 }
 
 /// Execute any mutation operation
@@ -254,8 +464,14 @@ pub async fn cmd_mutate(cli: &Cli, op: MutationOp, vars: VarsOptions) -> Result<
     // Render the response using render module
     println!(
         "{}",
-        render::render_mutation_json(cli.global.output, op_name, &result, cli.global.pretty)
+        render::render_mutation_json(
+            cli.global.output,
+            op_name,
+            &result,
+            response.errors.as_deref(),
+            cli.global.pretty
+        )
     );
 
-    Ok(())
+    ensure_complete(response.errors.unwrap_or_default())
 }
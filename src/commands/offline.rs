@@ -0,0 +1,157 @@
+//! --offline: list, get and search answered from the local mirror
+
+use anyhow::Result;
+
+use crate::cli::{Cli, ListOptions};
+use crate::client::GraphQLError;
+use crate::common::constants::pagination;
+use crate::common::OutputFormat;
+use crate::generated::{OrderBy, Resource};
+use crate::mirror::{self, mirror_path, mirrored_relations, Mirror};
+use crate::query_builder::{node_selection, ListQuery};
+use crate::render;
+
+use super::ensure_complete;
+use super::sync::mirrored_names;
+
+/// Open the mirror for a resource `sync pull` stores (singular, see [`mirror::canonical`])
+fn open(resource: Resource) -> Result<Mirror> {
+    if mirrored_relations(resource).is_none() {
+        anyhow::bail!(
+            "{} is not mirrored; --offline supports: {}",
+            resource.field_name(),
+            mirrored_names()
+        );
+    }
+    Mirror::open_existing(&mirror_path()?)
+}
+
+/// List mirrored entities matching the filter, paged by `--first`/`--after`
+pub fn list(
+    cli: &Cli,
+    resource: Resource,
+    options: &ListOptions,
+    filter: Option<serde_json::Value>,
+) -> Result<()> {
+    if options.last.is_some() || options.before.is_some() || options.parallel.is_some() {
+        anyhow::bail!("--last, --before and --parallel are not supported with --offline");
+    }
+    let resource = mirror::canonical(resource);
+    let mirror = open(resource)?;
+
+    let mut nodes = Vec::new();
+    for entity in mirror.entities(resource, options.include_archived)? {
+        if filter.as_ref().map_or(Ok(true), |f| mirror::matches(&entity, f))? {
+            nodes.push(entity);
+        }
+    }
+    if options.order_by == Some(OrderBy::UpdatedAt) {
+        nodes.sort_by(|a, b| b["updatedAt"].as_str().cmp(&a["updatedAt"].as_str()));
+    }
+
+    // Cursors are entity IDs
+    if let Some(ref after) = options.after {
+        let Some(position) = nodes.iter().position(|n| n["id"].as_str() == Some(after.as_str())) else {
+            anyhow::bail!("Cursor '{}' is not in the local mirror's results", after);
+        };
+        nodes.drain(..=position);
+    }
+    let count = if options.all {
+        pagination::MAX_RECORDS
+    } else {
+        options.first.unwrap_or(pagination::PAGE_SIZE).max(0) as usize
+    };
+    let has_next_page = nodes.len() > count;
+    nodes.truncate(count);
+
+    let page_info = serde_json::json!({
+        "hasNextPage": has_next_page,
+        "hasPreviousPage": options.after.is_some(),
+        "startCursor": nodes.first().map(|n| n["id"].clone()),
+        "endCursor": nodes.last().map(|n| n["id"].clone()),
+    });
+    let selection = node_selection(resource, &ListQuery::from_options(options, None));
+    let nodes = mirror::project(&nodes, &selection)?;
+
+    println!(
+        "{}",
+        render::render_list_json(
+            cli.global.output,
+            resource.field_name(),
+            &serde_json::Value::Array(nodes),
+            Some(&page_info),
+            None,
+            None,
+            cli.global.pretty
+        )
+    );
+    Ok(())
+}
+
+/// Get mirrored entities by ID, identifier or key
+pub fn get(cli: &Cli, resource: Resource, ids: &[String]) -> Result<()> {
+    let resource = mirror::canonical(resource);
+    let mirror = open(resource)?;
+    let resource_name = resource.field_name();
+
+    if let [id] = ids {
+        let Some(entity) = mirror.find(resource, id)? else {
+            anyhow::bail!("{} '{}' not found in the local mirror", resource_name, id);
+        };
+        println!(
+            "{}",
+            render::render_entity_json(cli.global.output, resource_name, &entity, None, cli.global.pretty)
+        );
+        return Ok(());
+    }
+
+    let mut entries = Vec::new();
+    let mut errors = Vec::new();
+    for id in ids {
+        match mirror.find(resource, id)? {
+            Some(entity) => entries.push(serde_json::json!({ "id": id, "entity": entity })),
+            None => {
+                let message = "Not found in the local mirror".to_string();
+                entries.push(serde_json::json!({ "id": id, "error": message }));
+                errors.push(GraphQLError {
+                    message,
+                    locations: None,
+                    path: Some(vec![serde_json::json!(id)]),
+                    extensions: None,
+                });
+            }
+        }
+    }
+
+    println!(
+        "{}",
+        render::render_batch_get(cli.global.output, resource_name, &entries, None, cli.global.pretty)
+    );
+    if matches!(cli.global.output, OutputFormat::Table | OutputFormat::Text) {
+        for error in &errors {
+            if let Some(id) = error.path.as_ref().and_then(|p| p[0].as_str()) {
+                eprintln!("Not found: {} ({})", id, error.message);
+            }
+        }
+    }
+    ensure_complete(errors)
+}
+
+/// Full-text search over mirrored titles and descriptions
+pub fn search(cli: &Cli, resource: Resource, text: &str, limit: usize) -> Result<()> {
+    let resource = mirror::canonical(resource);
+    let mirror = open(resource)?;
+    let nodes = mirror.search(resource, text, limit)?;
+    println!(
+        "{}",
+        render::render_search_json(
+            cli.global.output,
+            resource.field_name(),
+            "offlineFullText",
+            &serde_json::Value::Array(nodes),
+            None,
+            cli.global.pretty
+        )
+    );
+    Ok(())
+}
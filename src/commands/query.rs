@@ -1,17 +1,25 @@
 //! Query commands: list, get, search, raw
 
 use anyhow::Result;
+use futures::TryStreamExt;
+use std::collections::HashSet;
 
 use crate::cli::{Cli, ListOptions, VarsOptions};
-use crate::client::GraphQLRequest;
-use crate::common::constants::pagination;
-use crate::generated::{self, Resource};
+use crate::client::{Client, ClientError, GraphQLError, GraphQLRequest};
+use crate::common::constants::{batch, pagination};
+use crate::common::OutputFormat;
+use crate::complexity::estimate_complexity;
+use crate::generated::{self, OrderBy, Resource};
+use crate::interrupt::{self, Interrupted};
 use crate::progress::with_spinner;
-use crate::query_builder::{build_get_query, build_list_query_with_filter, build_search_query};
-use crate::render;
+use crate::paginate::{dedup_by_id, fetch_partitions, partition_filters, Direction, PageArgs, Paginator};
+use crate::query_builder::{
+    batch_alias, build_batch_get_query, build_get_query, build_list_query_with_filter, build_search_query, ListQuery,
+};
+use crate::render::{self, Truncation};
 use crate::validate;
 
-use super::create_client;
+use super::{check_complexity, create_client, ensure_complete, offline};
 
 /// Resolve filter from any source (inline, file, or stdin) with proper error handling
 fn resolve_filter(options: &ListOptions) -> Result<Option<serde_json::Value>> {
@@ -63,6 +71,10 @@ pub async fn cmd_list(cli: &Cli, resource: Resource, options: ListOptions) -> Re
         }
     }
 
+    if cli.global.offline {
+        return offline::list(cli, resource, &options, filter_value);
+    }
+
     // Create client
     let client = create_client(&cli.global)?;
 
@@ -71,78 +83,61 @@ pub async fn cmd_list(cli: &Cli, resource: Resource, options: ListOptions) -> Re
     let plural_name = resource.plural_name();
 
     // If --all is specified, auto-paginate
-    let (nodes, page_info) = if options.all {
+    let (nodes, page_info, errors, truncation) = if let Some(parallel) = options.parallel {
+        let (nodes, errors, truncation) =
+            fetch_partitioned(cli, &client, resource, &options, filter_value, parallel as usize).await?;
+        (serde_json::Value::Array(nodes), None, errors, truncation)
+    } else if options.all {
+        // The first Ctrl-C stops paging and prints what was fetched so far
+        let _graceful = interrupt::graceful();
+        let mut truncation = None;
+
+        let query = ListQuery::from_options(&options, filter_value.clone());
+        // --last/--before page backwards from the end (or the given cursor)
+        let (direction, start) = if options.last.is_some() || options.before.is_some() {
+            (Direction::Backward, options.before.clone())
+        } else {
+            (Direction::Forward, options.after.clone())
+        };
+        let paginator = Paginator::list(&client, resource, query, cli.global.verbose)
+            .direction(direction)
+            .cursor(start.clone())
+            .limit(pagination::MAX_RECORDS);
+
+        // Every page has the same shape, so check only the first
+        let first = paginator.first_request();
+        check_complexity(&cli.global, &first.query, first.variables.as_ref().unwrap_or(&serde_json::Value::Null))?;
 
         let mut all_nodes: Vec<serde_json::Value> = Vec::new();
-        let mut cursor: Option<String> = None;
-        // This will always be assigned at least once in the loop before being returned
-        #[allow(unused_assignments)]
         let mut final_page_info: Option<serde_json::Value> = None;
+        let mut page_errors = Vec::new();
+        let mut cursor = start;
 
-        loop {
-            // Build query with current cursor
-            let mut page_options = options.clone();
-            page_options.first = Some(pagination::PAGE_SIZE);
-            page_options.after = cursor.clone();
-            page_options.all = false; // Prevent infinite recursion
-
-            let (query, variables) = build_list_query_with_filter(resource, &page_options, filter_value.clone());
-
-            if cli.global.verbose {
-                eprintln!("Query: {}", query);
-                eprintln!("Variables: {}", serde_json::to_string_pretty(&variables)?);
-            }
-
-            let request = GraphQLRequest {
-                query,
-                variables: Some(variables),
-                operation_name: None,
-            };
-
-            let page_count = all_nodes.len() / pagination::PAGE_SIZE as usize + 1;
-            let response = with_spinner(
+        let pages = paginator.pages();
+        futures::pin_mut!(pages);
+        for page_count in 1.. {
+            let Some(page) = interrupt::cancellable(with_spinner(
                 &format!("Fetching {} (page {})...", resource_name, page_count),
-                client.execute(request),
-            )
-            .await?;
-
-            let data = response.data.unwrap_or_default();
-            let resource_data = &data[&plural_name];
-
-            // Extract nodes from this page
-            if let Some(nodes_arr) = resource_data.get("nodes").and_then(|n| n.as_array()) {
-                all_nodes.extend(nodes_arr.iter().cloned());
-            }
-
-            // Check pagination info
-            let has_next = resource_data
-                .get("pageInfo")
-                .and_then(|p| p.get("hasNextPage"))
-                .and_then(|h| h.as_bool())
-                .unwrap_or(false);
-
-            let end_cursor = resource_data
-                .get("pageInfo")
-                .and_then(|p| p.get("endCursor"))
-                .and_then(|c| c.as_str())
-                .map(String::from);
-
-            final_page_info = resource_data.get("pageInfo").cloned();
-
-            // Stop if no more pages or max records reached
-            if !has_next || end_cursor.is_none() || all_nodes.len() >= pagination::MAX_RECORDS {
+                pages.try_next(),
+            ))
+            .await
+            else {
+                truncation = Some(Truncation { resume_cursor: cursor });
                 break;
-            }
-
-            cursor = end_cursor;
-        }
-
-        // Truncate to max if we exceeded
-        if all_nodes.len() > pagination::MAX_RECORDS {
-            all_nodes.truncate(pagination::MAX_RECORDS);
+            };
+            let Some(page) = page? else {
+                break;
+            };
+            page_errors.extend(page.errors);
+            all_nodes.extend(page.nodes);
+            cursor = match direction {
+                Direction::Forward => page.page_info.end_cursor.clone(),
+                Direction::Backward => page.page_info.start_cursor.clone(),
+            };
+            final_page_info = serde_json::to_value(&page.page_info).ok();
         }
 
-        (serde_json::Value::Array(all_nodes), final_page_info)
+        (serde_json::Value::Array(all_nodes), final_page_info, page_errors, truncation)
     } else {
         // Single page fetch
         let (query, variables) = build_list_query_with_filter(resource, &options, filter_value);
@@ -152,6 +147,8 @@ pub async fn cmd_list(cli: &Cli, resource: Resource, options: ListOptions) -> Re
             eprintln!("Variables: {}", serde_json::to_string_pretty(&variables)?);
         }
 
+        check_complexity(&cli.global, &query, &variables)?;
+
         let request = GraphQLRequest {
             query,
             variables: Some(variables),
@@ -169,7 +166,7 @@ pub async fn cmd_list(cli: &Cli, resource: Resource, options: ListOptions) -> Re
         let nodes = resource_data.get("nodes").cloned().unwrap_or_default();
         let page_info = resource_data.get("pageInfo").cloned();
 
-        (nodes, page_info)
+        (nodes, page_info, response.errors.unwrap_or_default(), None)
     };
 
     // Render the response using render module
@@ -180,15 +177,160 @@ pub async fn cmd_list(cli: &Cli, resource: Resource, options: ListOptions) -> Re
             resource_name,
             &nodes,
             page_info.as_ref(),
+            Some(errors.as_slice()),
+            truncation.as_ref(),
             cli.global.pretty
         )
     );
 
-    Ok(())
+    if let Some(truncation) = truncation {
+        let count = nodes.as_array().map_or(0, Vec::len);
+        match truncation.resume_cursor {
+            Some(cursor) => eprintln!(
+                "Stopped after {} {}; resume with --all {} {}",
+                count,
+                plural_name,
+                if options.last.is_some() || options.before.is_some() { "--before" } else { "--after" },
+                cursor
+            ),
+            None if count > 0 => eprintln!(
+                "Stopped after {} {}; a parallel fetch cannot be resumed",
+                count, plural_name
+            ),
+            None => eprintln!("Stopped before the first page of {} arrived", plural_name),
+        }
+        return Err(Interrupted.into());
+    }
+    ensure_complete(errors)
+}
+
+/// `--all --parallel`: page createdAt ranges concurrently and merge them in order
+async fn fetch_partitioned(
+    cli: &Cli,
+    client: &Client,
+    resource: Resource,
+    options: &ListOptions,
+    filter: Option<serde_json::Value>,
+    parallel: usize,
+) -> Result<(Vec<serde_json::Value>, Vec<GraphQLError>, Option<Truncation>)> {
+    if generated::validate_filter_keys(resource, &serde_json::json!({ "createdAt": {} })).is_err() {
+        anyhow::bail!("--parallel needs a createdAt filter, which {} does not support", resource.plural_name());
+    }
+    let _graceful = interrupt::graceful();
+
+    let count = parallel * pagination::PARTITIONS_PER_WORKER;
+    let Some(probe) = interrupt::cancellable(with_spinner(
+        &format!("Probing {}...", resource.plural_name()),
+        partition_filters(client, resource, filter.as_ref(), options.include_archived, count),
+    ))
+    .await
+    else {
+        return Ok((Vec::new(), Vec::new(), Some(Truncation { resume_cursor: None })));
+    };
+    let Some((range, filters)) = probe? else {
+        return Ok((Vec::new(), Vec::new(), None));
+    };
+    let partition_count = filters.len();
+
+    let mut query = ListQuery::from_options(options, None);
+    // Merging by updatedAt needs the field, even if it was not selected
+    if options.order_by == Some(OrderBy::UpdatedAt) {
+        if let Some(ref mut select) = query.select {
+            if !select.iter().any(|f| f == "updatedAt") {
+                select.push("updatedAt".to_string());
+            }
+        }
+    }
+    let verbose = cli.global.verbose;
+    // Unlike a sequential --all, every range is fetched in full
+    let paginate = |filter: serde_json::Value| {
+        let partition = ListQuery {
+            filter: Some(filter),
+            ..query.clone()
+        };
+        Paginator::list(client, resource, partition, verbose)
+    };
+
+    // Every partition has the same shape, so check only the first
+    if let Some(first) = filters.first() {
+        let request = paginate(first.clone()).first_request();
+        check_complexity(&cli.global, &request.query, request.variables.as_ref().unwrap_or(&serde_json::Value::Null))?;
+    }
+
+    let partitions = fetch_partitions(filters, parallel, paginate);
+    futures::pin_mut!(partitions);
+
+    let mut nodes = Vec::new();
+    let mut errors = Vec::new();
+    let mut seen = HashSet::new();
+    let mut truncation = None;
+    for done in 0..partition_count {
+        let Some(partition) = interrupt::cancellable(with_spinner(
+            &format!("Fetching {} (range {}/{})...", resource.plural_name(), done + 1, partition_count),
+            partitions.try_next(),
+        ))
+        .await
+        else {
+            truncation = Some(Truncation { resume_cursor: None });
+            break;
+        };
+        let Some(mut partition) = partition? else {
+            break;
+        };
+        dedup_by_id(&mut partition.nodes, &mut seen);
+        nodes.extend(partition.nodes);
+        errors.extend(partition.errors);
+    }
+
+    if options.order_by == Some(OrderBy::UpdatedAt) {
+        let updated = |node: &serde_json::Value| node["updatedAt"].as_str().unwrap_or_default().to_string();
+        nodes.sort_by_key(updated);
+        if range.descending {
+            nodes.reverse();
+        }
+    }
+    Ok((nodes, errors, truncation))
+}
+
+/// Get entities by ID or key (several IDs are fetched in batched queries)
+pub async fn cmd_get(
+    cli: &Cli,
+    resource: Resource,
+    ids: Vec<String>,
+    ids_from: Option<String>,
+) -> Result<()> {
+    match (ids.as_slice(), ids_from) {
+        ([id], None) => get_one(cli, resource, id.clone()).await,
+        (_, ids_from) => {
+            let mut ids = ids;
+            if let Some(source) = ids_from {
+                let content = if source == "-" {
+                    validate::read_stdin()?
+                } else {
+                    validate::read_file(&source)?
+                };
+                ids.extend(
+                    content
+                        .lines()
+                        .map(str::trim)
+                        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+                        .map(String::from),
+                );
+            }
+            if ids.is_empty() {
+                anyhow::bail!("No IDs given");
+            }
+            get_many(cli, resource, &ids).await
+        }
+    }
 }
 
 /// Get a single entity by ID or key
-pub async fn cmd_get(cli: &Cli, resource: Resource, id: String) -> Result<()> {
+async fn get_one(cli: &Cli, resource: Resource, id: String) -> Result<()> {
+    if cli.global.offline {
+        return offline::get(cli, resource, &[id]);
+    }
+
     // Detect ID type for verbose output (Linear API accepts both UUID and identifier)
     let id_type = validate::detect_id_type(&id);
     if cli.global.verbose {
@@ -211,6 +353,8 @@ pub async fn cmd_get(cli: &Cli, resource: Resource, id: String) -> Result<()> {
         eprintln!("Variables: {}", serde_json::to_string_pretty(&variables)?);
     }
 
+    check_complexity(&cli.global, &query, &variables)?;
+
     // Execute the query with spinner
     let request = GraphQLRequest {
         query,
@@ -232,18 +376,193 @@ pub async fn cmd_get(cli: &Cli, resource: Resource, id: String) -> Result<()> {
     // Render the response using render module
     println!(
         "{}",
-        render::render_entity_json(cli.global.output, resource_name, &entity, cli.global.pretty)
+        render::render_entity_json(
+            cli.global.output,
+            resource_name,
+            &entity,
+            response.errors.as_deref(),
+            cli.global.pretty
+        )
+    );
+
+    ensure_complete(response.errors.unwrap_or_default())
+}
+
+/// Outcome of fetching one ID in a batch
+enum BatchResult {
+    Found(serde_json::Value),
+    Missing(String),
+}
+
+/// Fetch many entities in aliased batch queries, reporting missing IDs individually
+async fn get_many(cli: &Cli, resource: Resource, ids: &[String]) -> Result<()> {
+    if cli.global.offline {
+        return offline::get(cli, resource, ids);
+    }
+
+    let client = create_client(&cli.global)?;
+    let chunk_size = batch_size(cli.global.max_complexity, resource);
+    if cli.global.verbose {
+        eprintln!("Fetching {} IDs in batches of up to {}", ids.len(), chunk_size);
+    }
+
+    // The first Ctrl-C stops fetching and prints the results so far
+    let _graceful = interrupt::graceful();
+    let mut results: Vec<Option<BatchResult>> = ids.iter().map(|_| None).collect();
+    let positions: Vec<usize> = (0..ids.len()).collect();
+    'chunks: for chunk in positions.chunks(chunk_size) {
+        // Missing IDs null the whole response (the fields are non-null), so
+        // drop them and re-run the rest of the chunk until it succeeds
+        let mut pending = chunk.to_vec();
+        while !pending.is_empty() {
+            let chunk_ids: Vec<&str> = pending.iter().map(|&i| ids[i].as_str()).collect();
+            let (query, variables) = build_batch_get_query(resource, &chunk_ids);
+            if cli.global.verbose {
+                eprintln!("Query: {}", query);
+                eprintln!("Variables: {}", serde_json::to_string_pretty(&variables)?);
+            }
+            check_complexity(&cli.global, &query, &variables)?;
+
+            let request = GraphQLRequest {
+                query,
+                variables: Some(variables),
+                operation_name: None,
+            };
+            let Some(response) = interrupt::cancellable(with_spinner(
+                &format!("Fetching {} {}...", chunk_ids.len(), resource.plural_name()),
+                client.execute(request),
+            ))
+            .await
+            else {
+                break 'chunks;
+            };
+            let (data, errors) = match response {
+                Ok(response) => (response.data.unwrap_or_default(), response.errors.unwrap_or_default()),
+                Err(ClientError::GraphQL(message, Some(errors))) => {
+                    if !errors.iter().any(|e| alias_index(e, pending.len()).is_some()) {
+                        return Err(ClientError::GraphQL(message, Some(errors)).into());
+                    }
+                    (serde_json::Value::Null, errors)
+                }
+                Err(e) => return Err(e.into()),
+            };
+
+            let mut failed = Vec::new();
+            for error in &errors {
+                if let Some(n) = alias_index(error, pending.len()) {
+                    results[pending[n]] = Some(BatchResult::Missing(error.message.clone()));
+                    failed.push(n);
+                }
+            }
+            if data.is_null() && failed.is_empty() {
+                // Nothing to drop, so a retry would get the same answer
+                let message = if errors.is_empty() {
+                    "No data returned".to_string()
+                } else {
+                    errors.iter().map(|e| e.message.as_str()).collect::<Vec<_>>().join("; ")
+                };
+                for &i in &pending {
+                    results[i] = Some(BatchResult::Missing(message.clone()));
+                }
+                pending.clear();
+                continue;
+            }
+            if data.is_null() {
+                pending = pending
+                    .iter()
+                    .enumerate()
+                    .filter(|(n, _)| !failed.contains(n))
+                    .map(|(_, &i)| i)
+                    .collect();
+                continue;
+            }
+
+            for (n, &i) in pending.iter().enumerate() {
+                if results[i].is_none() {
+                    results[i] = Some(match data.get(batch_alias(n)) {
+                        Some(entity) if !entity.is_null() => BatchResult::Found(entity.clone()),
+                        _ => BatchResult::Missing("Entity not found".to_string()),
+                    });
+                }
+            }
+            pending.clear();
+            interrupt::cancellable(client.throttle()).await;
+        }
+    }
+    let truncation = interrupt::is_interrupted().then(Truncation::default);
+
+    // Reassemble in input order
+    let mut entries = Vec::new();
+    let mut errors = Vec::new();
+    for (id, result) in ids.iter().zip(results) {
+        match result {
+            Some(BatchResult::Found(entity)) => {
+                entries.push(serde_json::json!({ "id": id, "entity": entity }));
+            }
+            Some(BatchResult::Missing(message)) => {
+                entries.push(serde_json::json!({ "id": id, "error": message }));
+                errors.push(GraphQLError {
+                    message,
+                    locations: None,
+                    path: Some(vec![serde_json::json!(id)]),
+                    extensions: None,
+                });
+            }
+            None => {}
+        }
+    }
+
+    println!(
+        "{}",
+        render::render_batch_get(
+            cli.global.output,
+            resource.field_name(),
+            &entries,
+            truncation.as_ref(),
+            cli.global.pretty
+        )
     );
+    if matches!(cli.global.output, OutputFormat::Table | OutputFormat::Text) {
+        for entry in &entries {
+            if let (Some(id), Some(error)) = (entry["id"].as_str(), entry["error"].as_str()) {
+                eprintln!("Not found: {} ({})", id, error);
+            }
+        }
+    }
+
+    if truncation.is_some() {
+        eprintln!("Stopped after {} of {} IDs", entries.len(), ids.len());
+        return Err(Interrupted.into());
+    }
+
+    // Missing IDs don't fail the batch, but are reported with the partial-result exit code
+    ensure_complete(errors)
+}
 
-    Ok(())
+/// Largest batch whose estimated complexity stays under the limit
+fn batch_size(max_complexity: u64, resource: Resource) -> usize {
+    let (query, variables) = build_batch_get_query(resource, &["id"]);
+    let per_entity = estimate_complexity(&query, &variables).max(1.0);
+    ((max_complexity as f64 / per_entity) as usize).clamp(1, batch::MAX_BATCH_SIZE)
+}
+
+/// Position within the batch of the alias an error's path starts with
+fn alias_index(error: &GraphQLError, batch_len: usize) -> Option<usize> {
+    let alias = error.path.as_ref()?.first()?.as_str()?;
+    let n: usize = alias.strip_prefix('i')?.parse().ok()?;
+    (n < batch_len).then_some(n)
 }
 
 /// Search for entities using smart search strategy
-pub async fn cmd_search(cli: &Cli, resource: Resource, text: String) -> Result<()> {
+pub async fn cmd_search(cli: &Cli, resource: Resource, text: String, limit: usize) -> Result<()> {
+    if cli.global.offline {
+        return offline::search(cli, resource, &text, limit);
+    }
+
     // Create client
     let client = create_client(&cli.global)?;
 
-    // Build the search query
+    // Build the search query; the paginator sets first/after per page
     let (query, variables, strategy) = build_search_query(resource, &text);
 
     if cli.global.verbose {
@@ -252,26 +571,33 @@ pub async fn cmd_search(cli: &Cli, resource: Resource, text: String) -> Result<(
         eprintln!("Variables: {}", serde_json::to_string_pretty(&variables)?);
     }
 
-    // Execute the query with spinner
-    let request = GraphQLRequest {
-        query,
-        variables: Some(variables),
-        operation_name: None,
-    };
-
-    let response = with_spinner(
-        &format!("Searching {}...", resource.field_name()),
-        client.execute(request),
-    )
-    .await?;
+    check_complexity(&cli.global, &query, &variables)?;
 
-    // Extract nodes from response
-    let data = response.data.unwrap_or_default();
     let resource_name = resource.field_name();
     // Use schema-derived plural name (avoids naive pluralization bugs)
     let plural_name = resource.plural_name();
-    let resource_data = &data[plural_name];
-    let nodes = resource_data.get("nodes").cloned().unwrap_or_default();
+    let paginator = Paginator::new(&client, plural_name, |args: &PageArgs| {
+        let mut variables = variables.clone();
+        args.apply(&mut variables);
+        GraphQLRequest {
+            query: query.clone(),
+            variables: Some(variables),
+            operation_name: None,
+        }
+    })
+    .limit(limit);
+
+    let pages: Vec<_> = with_spinner(
+        &format!("Searching {}...", resource_name),
+        paginator.pages().try_collect(),
+    )
+    .await?;
+    let mut nodes = Vec::new();
+    let mut errors = Vec::new();
+    for page in pages {
+        nodes.extend(page.nodes);
+        errors.extend(page.errors);
+    }
 
     // Render the response using render module
     println!(
@@ -280,12 +606,13 @@ pub async fn cmd_search(cli: &Cli, resource: Resource, text: String) -> Result<(
             cli.global.output,
             resource_name,
             strategy.as_str(),
-            &nodes,
+            &serde_json::Value::Array(nodes),
+            (!errors.is_empty()).then_some(errors.as_slice()),
             cli.global.pretty
         )
     );
 
-    Ok(())
+    ensure_complete(errors)
 }
 
 /// Execute arbitrary GraphQL queries
@@ -338,6 +665,8 @@ pub async fn cmd_raw(cli: &Cli, query: String, vars: VarsOptions) -> Result<()>
         }
     }
 
+    check_complexity(&cli.global, &query_text, &variables)?;
+
     // Execute the query with spinner
     let request = GraphQLRequest {
         query: query_text,
@@ -350,8 +679,13 @@ pub async fn cmd_raw(cli: &Cli, query: String, vars: VarsOptions) -> Result<()>
     // Render the response using render module
     println!(
         "{}",
-        render::render_raw(cli.global.output, &response.data, cli.global.pretty)
+        render::render_raw(
+            cli.global.output,
+            &response.data,
+            response.errors.as_deref(),
+            cli.global.pretty
+        )
     );
 
-    Ok(())
+    ensure_complete(response.errors.unwrap_or_default())
 }
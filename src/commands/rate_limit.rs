@@ -0,0 +1,127 @@
+//! Rate-limit command: show the remaining request and complexity budgets
+
+use anyhow::Result;
+use chrono::DateTime;
+
+use crate::cli::Cli;
+use crate::client::{GraphQLRequest, RateLimitState};
+use crate::progress::with_spinner;
+use crate::render;
+
+use super::create_client;
+
+const RATE_LIMIT_QUERY: &str = r#"query RateLimitStatus {
+  rateLimitStatus {
+    identifier
+    kind
+    limits {
+      type
+      allowedAmount
+      remainingAmount
+      reset
+    }
+  }
+}"#;
+
+/// Print the current rate-limit status
+pub async fn cmd_rate_limit(cli: &Cli) -> Result<()> {
+    let client = create_client(&cli.global)?;
+
+    if cli.global.verbose {
+        eprintln!("Query: {}", RATE_LIMIT_QUERY);
+    }
+
+    let request = GraphQLRequest {
+        query: RATE_LIMIT_QUERY.to_string(),
+        variables: None,
+        operation_name: None,
+    };
+
+    let response = with_spinner("Checking rate limit...", client.execute(request)).await?;
+    let data = response.data.unwrap_or_default();
+    let status = &data["rateLimitStatus"];
+
+    // Prefer the response headers; fall back to the payload if none were sent
+    let state = client.rate_limit();
+    let budgets = if state.is_known() {
+        budgets_from_state(&state)
+    } else {
+        budgets_from_payload(status)
+    };
+
+    let mut result = serde_json::json!({
+        "identifier": status["identifier"],
+        "kind": status["kind"],
+        "budgets": budgets,
+    });
+    if let Some(cost) = state.last_complexity {
+        result["lastComplexity"] = serde_json::json!(cost);
+    }
+
+    println!(
+        "{}",
+        render::render_rate_limit(cli.global.output, &result, cli.global.pretty)
+    );
+
+    Ok(())
+}
+
+/// Budget rows from rate-limit response headers
+fn budgets_from_state(state: &RateLimitState) -> Vec<serde_json::Value> {
+    [("requests", &state.requests), ("complexity", &state.complexity)]
+        .into_iter()
+        .filter(|(_, budget)| budget.remaining.is_some())
+        .map(|(kind, budget)| {
+            serde_json::json!({
+                "type": kind,
+                "remaining": budget.remaining,
+                "limit": budget.limit,
+                "resetsAt": budget.reset.map(|r| r.to_rfc3339()),
+            })
+        })
+        .collect()
+}
+
+/// Budget rows from the rateLimitStatus query payload
+fn budgets_from_payload(status: &serde_json::Value) -> Vec<serde_json::Value> {
+    status["limits"]
+        .as_array()
+        .map(|limits| {
+            limits
+                .iter()
+                .map(|limit| {
+                    let reset = limit["reset"]
+                        .as_f64()
+                        .and_then(|ms| DateTime::from_timestamp_millis(ms as i64));
+                    serde_json::json!({
+                        "type": limit["type"],
+                        "remaining": limit["remainingAmount"].as_f64().map(|v| v as u64),
+                        "limit": limit["allowedAmount"].as_f64().map(|v| v as u64),
+                        "resetsAt": reset.map(|r| r.to_rfc3339()),
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_budgets_from_payload() {
+        let status = serde_json::json!({
+            "limits": [{
+                "type": "complexity",
+                "allowedAmount": 3000000.0,
+                "remainingAmount": 2999000.0,
+                "reset": 1736500000000.0,
+            }]
+        });
+        let budgets = budgets_from_payload(&status);
+        assert_eq!(budgets.len(), 1);
+        assert_eq!(budgets[0]["remaining"], 2_999_000);
+        assert_eq!(budgets[0]["resetsAt"], "2025-01-10T09:06:40+00:00");
+    }
+}
@@ -0,0 +1,151 @@
+//! Sync commands: pull the workspace into the local mirror, status
+
+use anyhow::Result;
+use chrono::Utc;
+use futures::TryStreamExt;
+use std::path::Path;
+
+use crate::cli::{Cli, SyncAction};
+use crate::common::FieldsetPreset;
+use crate::generated::Resource;
+use crate::interrupt::{self, Interrupted};
+use crate::mirror::{canonical, mirror_path, sync_mark, Mirror, MIRRORED};
+use crate::paginate::Paginator;
+use crate::progress::with_spinner;
+use crate::query_builder::ListQuery;
+use crate::render;
+
+use super::{create_client, ensure_complete};
+
+/// Handle mirror commands
+pub async fn cmd_sync(cli: &Cli, action: SyncAction) -> Result<()> {
+    let path = mirror_path()?;
+    match action {
+        SyncAction::Pull { resources, full } => pull(cli, &path, &resources, full).await,
+        SyncAction::Status => status(cli, &path),
+    }
+}
+
+/// Fetch each mirrored resource's changes since its last pull
+async fn pull(cli: &Cli, path: &Path, only: &[Resource], full: bool) -> Result<()> {
+    // `issues` and `issue` name the same mirrored resource
+    let only: Vec<Resource> = only.iter().copied().map(canonical).collect();
+    if let Some(resource) = only.iter().find(|r| !MIRRORED.iter().any(|(m, _)| m == *r)) {
+        anyhow::bail!(
+            "{} is not mirrored; sync pull supports: {}",
+            resource.field_name(),
+            mirrored_names()
+        );
+    }
+    let client = create_client(&cli.global)?;
+    let mut mirror = Mirror::open(path)?;
+
+    // The first Ctrl-C stops after the current page; finished resources stay synced
+    let _graceful = interrupt::graceful();
+    let mut rows = Vec::new();
+    let mut errors = Vec::new();
+    let mut interrupted = false;
+    for (resource, relations) in MIRRORED.iter().filter(|(r, _)| only.is_empty() || only.contains(r)) {
+        let resource = *resource;
+        if full {
+            mirror.reset(resource)?;
+        }
+        let since = mirror.last_updated_at(resource)?;
+        let started = Utc::now();
+        let query = ListQuery {
+            include_archived: true,
+            filter: since.as_ref().map(|since| serde_json::json!({ "updatedAt": { "gt": since } })),
+            preset: FieldsetPreset::Wide,
+            expand: relations.iter().map(|r| r.to_string()).collect(),
+            ..Default::default()
+        };
+        let paginator = Paginator::list(&client, resource, query, cli.global.verbose);
+
+        let mut fetched = 0;
+        let mut newest: Option<String> = None;
+        let pages = paginator.pages();
+        futures::pin_mut!(pages);
+        for page_count in 1.. {
+            let Some(page) = interrupt::cancellable(with_spinner(
+                &format!("Pulling {} (page {})...", resource.plural_name(), page_count),
+                pages.try_next(),
+            ))
+            .await
+            else {
+                interrupted = true;
+                break;
+            };
+            let Some(page) = page? else {
+                break;
+            };
+            fetched += page.nodes.len();
+            errors.extend(page.errors);
+            if let Some(updated_at) = mirror.upsert(resource, &page.nodes)? {
+                if newest.as_deref().is_none_or(|n| updated_at.as_str() > n) {
+                    newest = Some(updated_at);
+                }
+            }
+        }
+        // A partial pull keeps its rows but not its mark, so the next pull refetches them
+        if interrupted {
+            break;
+        }
+        let mark = newest.map(|newest| sync_mark(&newest, started));
+        mirror.mark_synced(resource, mark.as_deref())?;
+        rows.push(serde_json::json!({
+            "resource": resource.field_name(),
+            "fetched": fetched,
+            "since": since,
+        }));
+    }
+
+    let result = serde_json::json!({
+        "path": mirror.path().display().to_string(),
+        "resources": rows,
+    });
+    println!(
+        "{}",
+        render::render_sync(cli.global.output, "syncPull", &result, cli.global.pretty)
+    );
+    if interrupted {
+        eprintln!("Stopped after {} resources; run 'linears sync pull' again to continue", rows.len());
+        return Err(Interrupted.into());
+    }
+    ensure_complete(errors)
+}
+
+/// Show entity counts and the last pull per resource
+fn status(cli: &Cli, path: &Path) -> Result<()> {
+    let mirror = Mirror::open_existing(path)?;
+    let rows: Vec<serde_json::Value> = mirror
+        .status()?
+        .into_iter()
+        .map(|state| {
+            serde_json::json!({
+                "resource": state.resource,
+                "entities": state.entities,
+                "archived": state.archived,
+                "lastUpdatedAt": state.last_updated_at,
+                "syncedAt": state.synced_at,
+            })
+        })
+        .collect();
+    let result = serde_json::json!({
+        "path": mirror.path().display().to_string(),
+        "resources": rows,
+    });
+    println!(
+        "{}",
+        render::render_sync(cli.global.output, "syncStatus", &result, cli.global.pretty)
+    );
+    Ok(())
+}
+
+/// Comma-separated field names of the mirrored resources
+pub fn mirrored_names() -> String {
+    MIRRORED
+        .iter()
+        .map(|(r, _)| r.field_name())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
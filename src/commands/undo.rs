@@ -0,0 +1,146 @@
+//! Undo command: replay inverse mutations from the journal
+
+use anyhow::Result;
+use chrono::Utc;
+
+use crate::cli::{Cli, UndoOptions};
+use crate::client::GraphQLRequest;
+use crate::journal::{self, JournalEntry};
+use crate::progress::with_spinner;
+use crate::render;
+
+use super::create_client;
+
+/// Undo journaled mutations (or list the journal)
+pub async fn cmd_undo(cli: &Cli, options: UndoOptions) -> Result<()> {
+    let entries = journal::load()?;
+
+    if options.list {
+        let nodes: Vec<serde_json::Value> = entries
+            .iter()
+            .rev()
+            .map(|entry| {
+                serde_json::json!({
+                    "id": entry.id,
+                    "timestamp": entry.timestamp,
+                    "op": entry.op.as_str(),
+                    "resource": entry.resource,
+                    "entityId": entry.entity_id,
+                    "before": entry.before,
+                    "after": entry.after,
+                    "undoneAt": entry.undone_at,
+                })
+            })
+            .collect();
+        println!(
+            "{}",
+            render::render_journal(cli.global.output, "journal", &nodes, cli.global.pretty)
+        );
+        return Ok(());
+    }
+
+    // Select target entries, newest first
+    let targets: Vec<usize> = if let Some(ref id) = options.id {
+        let index = entries
+            .iter()
+            .position(|e| &e.id == id)
+            .ok_or_else(|| anyhow::anyhow!("No journal entry with id '{}'", id))?;
+        if entries[index].undone_at.is_some() {
+            anyhow::bail!("Journal entry '{}' has already been undone", id);
+        }
+        vec![index]
+    } else {
+        entries
+            .iter()
+            .enumerate()
+            .rev()
+            .filter(|(_, e)| e.undone_at.is_none())
+            .take(options.last)
+            .map(|(i, _)| i)
+            .collect()
+    };
+
+    if targets.is_empty() {
+        anyhow::bail!("Nothing to undo: the journal has no pending entries");
+    }
+
+    let client = if options.dry_run {
+        None
+    } else {
+        Some(create_client(&cli.global)?)
+    };
+
+    let mut results = Vec::new();
+    let mut failures = 0;
+
+    for index in targets {
+        let entry = &entries[index];
+        let mut result = undo_result(entry);
+
+        let Some((query, variables)) = entry.inverse_mutation() else {
+            result["status"] = serde_json::json!("unsupported");
+            results.push(result);
+            continue;
+        };
+
+        if cli.global.verbose || options.dry_run {
+            eprintln!("Query: {}", query);
+            eprintln!("Variables: {}", serde_json::to_string_pretty(&variables)?);
+        }
+
+        let Some(ref client) = client else {
+            result["status"] = serde_json::json!("planned");
+            results.push(result);
+            continue;
+        };
+
+        client.throttle().await;
+
+        let request = GraphQLRequest {
+            query,
+            variables: Some(variables),
+            operation_name: None,
+        };
+
+        match with_spinner(
+            &format!("Undoing {} {}...", entry.op.as_str(), entry.resource),
+            client.execute(request),
+        )
+        .await
+        {
+            Ok(_) => {
+                // Recorded per entry, so a later failure keeps earlier undos
+                journal::mark_undone(&entry.id, &Utc::now().to_rfc3339())?;
+                result["status"] = serde_json::json!("undone");
+            }
+            Err(e) => {
+                failures += 1;
+                result["status"] = serde_json::json!("failed");
+                result["error"] = serde_json::json!(e.to_string());
+            }
+        }
+        results.push(result);
+    }
+
+    println!(
+        "{}",
+        render::render_journal(cli.global.output, "undo", &results, cli.global.pretty)
+    );
+
+    if failures > 0 {
+        anyhow::bail!("{} of {} undo operations failed", failures, results.len());
+    }
+
+    Ok(())
+}
+
+/// Summary row for an undo target
+fn undo_result(entry: &JournalEntry) -> serde_json::Value {
+    serde_json::json!({
+        "id": entry.id,
+        "op": entry.op.as_str(),
+        "resource": entry.resource,
+        "entityId": entry.entity_id,
+        "inverse": entry.inverse_op_name(),
+    })
+}
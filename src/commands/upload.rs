@@ -0,0 +1,254 @@
+//! Upload command: request a signed URL via fileUpload, PUT the bytes, link to an issue
+
+use anyhow::{Context, Result};
+use std::path::Path;
+
+use crate::cli::{Cli, UploadLink, UploadOptions};
+use crate::client::{Client, GraphQLRequest};
+use crate::mutation_builder::{
+    build_create_mutation, build_file_upload_mutation, build_update_mutation,
+};
+use crate::progress::with_spinner;
+use crate::render;
+
+use super::create_client;
+
+const ISSUE_DESCRIPTION_QUERY: &str = r#"query IssueDescription($id: String!) {
+  issue(id: $id) {
+    id
+    description
+  }
+}"#;
+
+/// Upload a file and print its asset URL
+pub async fn cmd_upload(cli: &Cli, path: String, options: UploadOptions) -> Result<()> {
+    // Read the file up front so size and content type are known
+    let bytes = std::fs::read(&path).with_context(|| format!("Failed to read file: {}", path))?;
+    let filename = Path::new(&path)
+        .file_name()
+        .and_then(|n| n.to_str())
+        .context("Upload path has no file name")?
+        .to_string();
+    let content_type = options
+        .content_type
+        .clone()
+        .unwrap_or_else(|| guess_content_type(&filename).to_string());
+    let size = bytes.len() as u64;
+
+    // Create client
+    let client = create_client(&cli.global)?;
+
+    // Request a signed upload URL
+    let (query, variables) = build_file_upload_mutation(&filename, &content_type, size);
+
+    if cli.global.verbose {
+        eprintln!("Query: {}", query);
+        eprintln!("Variables: {}", serde_json::to_string_pretty(&variables)?);
+    }
+
+    let request = GraphQLRequest {
+        query,
+        variables: Some(variables),
+        operation_name: None,
+    };
+
+    let response = with_spinner("Requesting upload URL...", client.execute(request)).await?;
+    let data = response.data.unwrap_or_default();
+    let upload_file = &data["fileUpload"]["uploadFile"];
+
+    let upload_url = upload_file["uploadUrl"]
+        .as_str()
+        .context("fileUpload did not return an upload URL")?;
+    let asset_url = upload_file["assetUrl"]
+        .as_str()
+        .context("fileUpload did not return an asset URL")?
+        .to_string();
+    let headers: Vec<(String, String)> = upload_file["headers"]
+        .as_array()
+        .map(|headers| {
+            headers
+                .iter()
+                .filter_map(|h| Some((h["key"].as_str()?.to_string(), h["value"].as_str()?.to_string())))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    if cli.global.verbose {
+        eprintln!("Uploading {} ({} bytes, {})", filename, size, content_type);
+    }
+
+    // PUT the bytes to the signed URL
+    with_spinner(
+        &format!("Uploading {}...", filename),
+        client.upload(upload_url, &content_type, &headers, bytes),
+    )
+    .await?;
+
+    let mut result = serde_json::json!({
+        "filename": filename,
+        "contentType": content_type,
+        "size": size,
+        "assetUrl": asset_url,
+    });
+
+    // Optionally link the asset to an issue
+    if let Some(ref issue_id) = options.issue {
+        let linked = match options.link {
+            UploadLink::Attachment => {
+                let title = options.title.as_deref().unwrap_or(&filename);
+                link_attachment(cli, &client, issue_id, title, &asset_url).await?
+            }
+            UploadLink::Description => {
+                let markdown = asset_markdown(&filename, &content_type, &asset_url);
+                append_to_description(cli, &client, issue_id, &markdown).await?
+            }
+        };
+        result["issue"] = serde_json::json!(issue_id);
+        result["linked"] = linked;
+    }
+
+    // Render the response using render module
+    println!(
+        "{}",
+        render::render_upload(cli.global.output, &result, cli.global.pretty)
+    );
+
+    Ok(())
+}
+
+/// Attach an uploaded asset to an issue via attachmentCreate
+async fn link_attachment(
+    cli: &Cli,
+    client: &Client,
+    issue_id: &str,
+    title: &str,
+    asset_url: &str,
+) -> Result<serde_json::Value> {
+    let input = serde_json::json!({
+        "issueId": issue_id,
+        "title": title,
+        "url": asset_url,
+    });
+    let (query, variables) = build_create_mutation("attachment", input);
+
+    if cli.global.verbose {
+        eprintln!("Query: {}", query);
+        eprintln!("Variables: {}", serde_json::to_string_pretty(&variables)?);
+    }
+
+    let request = GraphQLRequest {
+        query,
+        variables: Some(variables),
+        operation_name: None,
+    };
+
+    let response = with_spinner("Creating attachment...", client.execute(request)).await?;
+    let data = response.data.unwrap_or_default();
+    Ok(data["attachmentCreate"].clone())
+}
+
+/// Append a markdown link to an issue's description
+async fn append_to_description(
+    cli: &Cli,
+    client: &Client,
+    issue_id: &str,
+    markdown: &str,
+) -> Result<serde_json::Value> {
+    let request = GraphQLRequest {
+        query: ISSUE_DESCRIPTION_QUERY.to_string(),
+        variables: Some(serde_json::json!({ "id": issue_id })),
+        operation_name: None,
+    };
+    let response = with_spinner("Fetching issue...", client.execute(request)).await?;
+    let data = response.data.unwrap_or_default();
+    let current = data["issue"]["description"].as_str().unwrap_or_default();
+
+    let description = if current.trim().is_empty() {
+        markdown.to_string()
+    } else {
+        format!("{}\n\n{}", current.trim_end(), markdown)
+    };
+
+    let (query, variables) = build_update_mutation(
+        "issue",
+        issue_id,
+        serde_json::json!({ "description": description }),
+    );
+
+    if cli.global.verbose {
+        eprintln!("Query: {}", query);
+        eprintln!("Variables: {}", serde_json::to_string_pretty(&variables)?);
+    }
+
+    let request = GraphQLRequest {
+        query,
+        variables: Some(variables),
+        operation_name: None,
+    };
+
+    let response = with_spinner("Updating issue...", client.execute(request)).await?;
+    let data = response.data.unwrap_or_default();
+    Ok(data["issueUpdate"].clone())
+}
+
+/// Markdown reference to an asset: inline image for images, link otherwise
+fn asset_markdown(filename: &str, content_type: &str, asset_url: &str) -> String {
+    if content_type.starts_with("image/") {
+        format!("![{}]({})", filename, asset_url)
+    } else {
+        format!("[{}]({})", filename, asset_url)
+    }
+}
+
+/// Guess a content type from a file name's extension
+fn guess_content_type(filename: &str) -> &'static str {
+    let extension = Path::new(filename)
+        .extension()
+        .and_then(|e| e.to_str())
+        .map(|e| e.to_ascii_lowercase())
+        .unwrap_or_default();
+
+    match extension.as_str() {
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "gif" => "image/gif",
+        "webp" => "image/webp",
+        "svg" => "image/svg+xml",
+        "pdf" => "application/pdf",
+        "json" => "application/json",
+        "zip" => "application/zip",
+        "gz" => "application/gzip",
+        "mp4" => "video/mp4",
+        "mov" => "video/quicktime",
+        "webm" => "video/webm",
+        "txt" | "log" => "text/plain",
+        "md" => "text/markdown",
+        "csv" => "text/csv",
+        "html" | "htm" => "text/html",
+        _ => "application/octet-stream",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_guess_content_type() {
+        assert_eq!(guess_content_type("screenshot.PNG"), "image/png");
+        assert_eq!(guess_content_type("server.log"), "text/plain");
+        assert_eq!(guess_content_type("blob"), "application/octet-stream");
+    }
+
+    #[test]
+    fn test_asset_markdown() {
+        assert_eq!(
+            asset_markdown("a.png", "image/png", "https://x/a.png"),
+            "![a.png](https://x/a.png)"
+        );
+        assert_eq!(
+            asset_markdown("a.log", "text/plain", "https://x/a.log"),
+            "[a.log](https://x/a.log)"
+        );
+    }
+}
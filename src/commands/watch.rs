@@ -0,0 +1,260 @@
+//! Watch command: poll for changes and emit created, updated and archived events
+//!
+//! The first poll loads a snapshot of every matching entity. Later polls only
+//! fetch entities updated since the newest `updatedAt` seen, diff them against
+//! the snapshot and print one event each. Until an entity has been seen, polls
+//! fetch every matching entity again, so the bound never comes from the local
+//! clock. Entities that stop matching the `--where` expression are not reported.
+
+use anyhow::Result;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::time::Duration;
+
+use crate::cli::{Cli, WatchOptions};
+use crate::client::{Client, ClientError};
+use crate::common::constants::watch as watch_const;
+use crate::generated::{self, get_preset_fields, Resource};
+use crate::interrupt;
+use crate::paginate::Paginator;
+use crate::progress::with_spinner;
+use crate::query_builder::{parse_where, ListQuery};
+use crate::render;
+
+use super::{create_client, run_hook};
+
+/// Fields that change on every update and are not reported as changes
+const IGNORED_FIELDS: &[&str] = &["updatedAt"];
+
+/// Poll a resource and print an event for each change
+pub async fn cmd_watch(cli: &Cli, resource: Resource, options: WatchOptions) -> Result<()> {
+    let filter = options.where_expr.as_deref().map(parse_where).transpose()?;
+    if let Some(ref filter) = filter {
+        if let Err(errors) = generated::validate_filter_keys(resource, filter) {
+            let keys: Vec<String> = errors
+                .into_iter()
+                .map(|(key, suggestion)| match suggestion {
+                    Some(s) => format!("'{}' (did you mean '{}'?)", key, s),
+                    None => format!("'{}'", key),
+                })
+                .collect();
+            anyhow::bail!("Unknown --where field {} for {}", keys.join(", "), resource.plural_name());
+        }
+    }
+    if generated::validate_filter_keys(resource, &serde_json::json!({ "updatedAt": {} })).is_err() {
+        anyhow::bail!("watch needs an updatedAt filter, which {} does not support", resource.plural_name());
+    }
+
+    // The preset plus the fields the diff relies on (repeated fields are fine in GraphQL)
+    let query = ListQuery {
+        filter,
+        select: Some(vec![
+            get_preset_fields(resource, options.preset).to_string(),
+            "updatedAt".to_string(),
+            "archivedAt".to_string(),
+        ]),
+        ..Default::default()
+    };
+    let client = create_client(&cli.global)?;
+
+    // Ctrl-C ends the watch between polls or cancels the one in flight
+    let _graceful = interrupt::graceful();
+    let mut snapshot: Option<HashMap<String, Value>> = None;
+    let mut last_seen: Option<String> = None;
+    let mut failures = 0;
+    let mut polls = 0;
+    loop {
+        let fetched = match snapshot {
+            None => {
+                let message = format!("Loading {}...", resource.plural_name());
+                interrupt::cancellable(with_spinner(&message, fetch(cli, &client, resource, &query, None))).await
+            }
+            Some(_) => interrupt::cancellable(fetch(cli, &client, resource, &query, last_seen.as_deref())).await,
+        };
+        let Some(fetched) = fetched else {
+            break;
+        };
+        let delay = match fetched {
+            Ok(nodes) => {
+                failures = 0;
+                polls += 1;
+                for node in &nodes {
+                    if let Some(updated_at) = node["updatedAt"].as_str() {
+                        if last_seen.as_deref().is_none_or(|seen| updated_at > seen) {
+                            last_seen = Some(updated_at.to_string());
+                        }
+                    }
+                }
+                match snapshot {
+                    None => {
+                        let by_id = nodes.into_iter().filter_map(|n| Some((n["id"].as_str()?.to_string(), n)));
+                        snapshot = Some(by_id.collect());
+                    }
+                    Some(ref mut snapshot) => {
+                        for node in nodes {
+                            if let Some(event) = change_event(resource, snapshot, node) {
+                                emit(cli, options.exec.as_deref(), &event).await;
+                            }
+                        }
+                    }
+                }
+                options.interval
+            }
+            Err(e) if is_transient(&e) => {
+                failures += 1;
+                let delay = backoff(options.interval, failures);
+                eprintln!("Poll failed: {}; retrying in {}s", e, delay.as_secs_f64().ceil());
+                delay
+            }
+            Err(e) => return Err(e.into()),
+        };
+        if options.polls.is_some_and(|max| polls >= max) {
+            break;
+        }
+        if interrupt::cancellable(tokio::time::sleep(delay)).await.is_none() {
+            break;
+        }
+    }
+    Ok(())
+}
+
+/// Every matching entity, or only those updated after `since` (archived ones included)
+///
+/// With an empty snapshot and no `since`, everything returned is a new entity.
+async fn fetch(
+    cli: &Cli,
+    client: &Client,
+    resource: Resource,
+    query: &ListQuery,
+    since: Option<&str>,
+) -> Result<Vec<Value>, ClientError> {
+    let query = match since {
+        None => query.clone(),
+        Some(since) => {
+            let changed = serde_json::json!({ "updatedAt": { "gt": since } });
+            ListQuery {
+                include_archived: true,
+                filter: Some(match query.filter {
+                    Some(ref filter) => serde_json::json!({ "and": [filter, changed] }),
+                    None => changed,
+                }),
+                ..query.clone()
+            }
+        }
+    };
+    Paginator::list(client, resource, query, cli.global.verbose)
+        .all()
+        .await
+}
+
+/// Update the snapshot with a fetched entity, returning the event it causes
+fn change_event(resource: Resource, snapshot: &mut HashMap<String, Value>, node: Value) -> Option<Value> {
+    let id = node["id"].as_str()?.to_string();
+    let mut event = serde_json::json!({
+        "resource": resource.field_name(),
+        "id": id,
+        "at": node["updatedAt"],
+    });
+    if !node["archivedAt"].is_null() {
+        // Archived entities are only reported if they were being watched
+        snapshot.remove(&id)?;
+        event["event"] = Value::from("archived");
+        event["at"] = node["archivedAt"].clone();
+    } else {
+        match snapshot.insert(id, node.clone()) {
+            None => event["event"] = Value::from("created"),
+            Some(previous) => {
+                let changes = changed_fields(&previous, &node);
+                if changes.is_empty() {
+                    return None;
+                }
+                event["event"] = Value::from("updated");
+                event["previous"] = changes.iter().map(|f| (f.clone(), previous[f].clone())).collect();
+                event["changes"] = Value::from(changes);
+            }
+        }
+    }
+    event["entity"] = node;
+    Some(event)
+}
+
+/// Top-level fields whose values differ
+fn changed_fields(previous: &Value, current: &Value) -> Vec<String> {
+    let (Some(previous), Some(current)) = (previous.as_object(), current.as_object()) else {
+        return Vec::new();
+    };
+    let mut fields: Vec<String> = current
+        .keys()
+        .chain(previous.keys().filter(|k| !current.contains_key(*k)))
+        .filter(|k| !IGNORED_FIELDS.contains(&k.as_str()))
+        .filter(|k| previous.get(*k) != current.get(*k))
+        .cloned()
+        .collect();
+    fields.sort();
+    fields
+}
+
+/// Print an event and pass it to the `--exec` hook
+async fn emit(cli: &Cli, exec: Option<&str>, event: &Value) {
+    println!("{}", render::render_watch_event(cli.global.output, event));
+    if let Some(command) = exec {
+        if let Err(e) = run_hook(command, event).await {
+            eprintln!("warning: --exec failed for {} event: {:#}", event["event"].as_str().unwrap_or_default(), e);
+        }
+    }
+}
+
+/// Errors worth polling through: the network, the server, rate limits
+fn is_transient(error: &ClientError) -> bool {
+    matches!(
+        error,
+        ClientError::Network(_)
+            | ClientError::Timeout(_)
+            | ClientError::Server(_)
+            | ClientError::RateLimited(_)
+            | ClientError::RateLimitedTooLong(_)
+    )
+}
+
+/// Wait after `failures` failed polls in a row: doubling from the interval, capped
+fn backoff(interval: Duration, failures: u32) -> Duration {
+    let max = Duration::from_secs(watch_const::MAX_BACKOFF_SECS).max(interval);
+    interval.saturating_mul(2u32.saturating_pow(failures.min(16))).min(max)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_change_events() {
+        let mut snapshot = HashMap::new();
+        snapshot.insert("a".to_string(), json!({ "id": "a", "title": "Old", "updatedAt": "1", "archivedAt": null }));
+
+        let created = change_event(Resource::Issue, &mut snapshot, json!({ "id": "b", "updatedAt": "2" })).unwrap();
+        assert_eq!(created["event"], "created");
+
+        let same = json!({ "id": "a", "title": "Old", "updatedAt": "3", "archivedAt": null });
+        assert!(change_event(Resource::Issue, &mut snapshot, same).is_none());
+
+        let renamed = json!({ "id": "a", "title": "New", "updatedAt": "4", "archivedAt": null });
+        let updated = change_event(Resource::Issue, &mut snapshot, renamed).unwrap();
+        assert_eq!(updated["event"], "updated");
+        assert_eq!(updated["changes"], json!(["title"]));
+        assert_eq!(updated["previous"]["title"], "Old");
+
+        let archived = json!({ "id": "a", "title": "New", "updatedAt": "5", "archivedAt": "5" });
+        assert_eq!(change_event(Resource::Issue, &mut snapshot, archived.clone()).unwrap()["event"], "archived");
+        assert!(change_event(Resource::Issue, &mut snapshot, archived).is_none());
+    }
+
+    #[test]
+    fn test_backoff() {
+        let interval = Duration::from_secs(30);
+        assert_eq!(backoff(interval, 1), Duration::from_secs(60));
+        assert_eq!(backoff(interval, 2), Duration::from_secs(120));
+        assert_eq!(backoff(interval, 10), Duration::from_secs(watch_const::MAX_BACKOFF_SECS));
+        assert_eq!(backoff(Duration::from_secs(600), 3), Duration::from_secs(600));
+    }
+}
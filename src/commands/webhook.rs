@@ -0,0 +1,337 @@
+//! Webhook commands: management, local receiver, signed test deliveries
+
+use anyhow::{Context, Result};
+use chrono::Utc;
+use reqwest::StatusCode;
+use serde_json::Value;
+use std::time::Duration;
+use tokio::sync::mpsc;
+
+use crate::cli::{
+    Cli, WebhookAction, WebhookCreateOptions, WebhookListenOptions, WebhookResourceType, WebhookSendTestOptions,
+    WebhookUpdateOptions,
+};
+use crate::client::{Client, GraphQLRequest};
+use crate::common::constants::webhook as webhook_const;
+use crate::generated::Resource;
+use crate::interrupt;
+use crate::mutation_builder::{build_create_mutation, build_update_mutation};
+use crate::paginate::Paginator;
+use crate::progress::with_spinner;
+use crate::query_builder::{build_get_query_with_fields, ListQuery};
+use crate::render;
+use crate::resolve;
+use crate::validate;
+use crate::webhook::{self, fixtures, Delivery, WebhookServer};
+
+use super::{cmd_delete, create_client, ensure_complete, run_hook};
+
+/// Fields shown by `webhook list` (never the secret)
+const LIST_FIELDS: &str =
+    "id label url enabled resourceTypes allPublicTeams team { id key name } createdAt updatedAt archivedAt";
+
+/// Handle webhook commands
+pub async fn cmd_webhook(cli: &Cli, action: WebhookAction) -> Result<()> {
+    match action {
+        WebhookAction::Listen { options } => listen(cli, options).await,
+        WebhookAction::SendTest { options } => send_test(cli, options).await,
+        WebhookAction::List { team, include_archived } => list(cli, team, include_archived).await,
+        WebhookAction::Create { options } => create(cli, options).await,
+        WebhookAction::Update { id, options } => update(cli, &id, options).await,
+        WebhookAction::Delete { id } => cmd_delete(cli, Resource::Webhook, id).await,
+        WebhookAction::RotateSecret { id, secret } => rotate_secret(cli, &id, secret).await,
+        WebhookAction::Verify { id, url, kind } => verify(cli, &id, &url, kind).await,
+    }
+}
+
+/// List webhooks, optionally only those scoped to one team
+async fn list(cli: &Cli, team: Option<String>, include_archived: bool) -> Result<()> {
+    let client = create_client(&cli.global)?;
+    let query = ListQuery {
+        include_archived,
+        select: Some(vec![LIST_FIELDS.to_string()]),
+        ..Default::default()
+    };
+    let fetch = Paginator::list(&client, Resource::Webhook, query, cli.global.verbose)
+        .all();
+    let mut webhooks = with_spinner("Loading webhooks...", fetch).await?;
+
+    // The webhooks connection takes no filter, so team scoping happens here
+    if let Some(ref team) = team {
+        let team_id = resolve::resolve_team(&client, team).await?;
+        webhooks.retain(|webhook| webhook["team"]["id"].as_str() == Some(team_id.as_str()));
+    }
+    let result = serde_json::json!({ "webhooks": webhooks });
+    println!("{}", render::render_webhook(cli.global.output, "webhooks", &result, cli.global.pretty));
+    Ok(())
+}
+
+/// Create a webhook, printing the secret when it was generated here
+async fn create(cli: &Cli, options: WebhookCreateOptions) -> Result<()> {
+    let client = create_client(&cli.global)?;
+    let secret = options.secret.clone().unwrap_or_else(fixtures::secret);
+    let mut input = serde_json::json!({
+        "url": options.url,
+        "resourceTypes": resource_type_names(&options.resource_types),
+        "secret": secret,
+        "enabled": !options.disabled,
+    });
+    if let Some(label) = options.label {
+        input["label"] = Value::from(label);
+    }
+    match options.team {
+        Some(ref team) => input["teamId"] = Value::from(resolve::resolve_team(&client, team).await?),
+        None => input["allPublicTeams"] = Value::from(true),
+    }
+
+    let (query, variables) = build_create_mutation(Resource::Webhook.field_name(), input);
+    let data = execute(cli, &client, "Creating webhook...", query, variables).await?;
+    let mut webhook = data["webhookCreate"]["webhook"].clone();
+    if options.secret.is_none() && webhook.is_object() {
+        webhook["secret"] = Value::from(secret);
+    }
+    println!("{}", render::render_webhook(cli.global.output, "webhookCreate", &webhook, cli.global.pretty));
+    Ok(())
+}
+
+/// Change a webhook's URL, label, resource types or enabled state
+async fn update(cli: &Cli, id: &str, options: WebhookUpdateOptions) -> Result<()> {
+    let mut input = serde_json::Map::new();
+    if let Some(url) = options.url {
+        input.insert("url".to_string(), Value::from(url));
+    }
+    if let Some(ref types) = options.resource_types {
+        input.insert("resourceTypes".to_string(), Value::from(resource_type_names(types)));
+    }
+    if let Some(label) = options.label {
+        input.insert("label".to_string(), Value::from(label));
+    }
+    if options.enable || options.disable {
+        input.insert("enabled".to_string(), Value::from(options.enable));
+    }
+    if input.is_empty() {
+        anyhow::bail!("Nothing to update; pass --url, --resource-types, --label, --enable or --disable");
+    }
+
+    let client = create_client(&cli.global)?;
+    let (query, variables) = build_update_mutation(Resource::Webhook.field_name(), id, Value::Object(input));
+    let data = execute(cli, &client, "Updating webhook...", query, variables).await?;
+    let webhook = &data["webhookUpdate"]["webhook"];
+    println!("{}", render::render_webhook(cli.global.output, "webhookUpdate", webhook, cli.global.pretty));
+    Ok(())
+}
+
+/// Replace a webhook's secret (the API has no rotation of its own) and print it
+async fn rotate_secret(cli: &Cli, id: &str, secret: Option<String>) -> Result<()> {
+    let secret = secret.unwrap_or_else(fixtures::secret);
+    let client = create_client(&cli.global)?;
+    let input = serde_json::json!({ "secret": secret });
+    let (query, variables) = build_update_mutation(Resource::Webhook.field_name(), id, input);
+    let data = execute(cli, &client, "Rotating webhook secret...", query, variables).await?;
+    let mut webhook = data["webhookUpdate"]["webhook"].clone();
+    if webhook.is_object() {
+        webhook["secret"] = Value::from(secret);
+    }
+    println!("{}", render::render_webhook(cli.global.output, "webhookRotateSecret", &webhook, cli.global.pretty));
+    Ok(())
+}
+
+/// Send a fixture signed with the webhook's own secret to a local receiver
+async fn verify(cli: &Cli, id: &str, url: &str, kind: Option<String>) -> Result<()> {
+    let client = create_client(&cli.global)?;
+    let (query, variables) = build_get_query_with_fields(Resource::Webhook, id, "id url enabled resourceTypes secret");
+    let data = execute(cli, &client, "Fetching webhook...", query, variables).await?;
+    let found = &data["webhook"];
+    if found.is_null() {
+        anyhow::bail!("Webhook '{}' not found", id);
+    }
+    let Some(secret) = found["secret"].as_str() else {
+        anyhow::bail!("The API did not return a secret for webhook '{}'", id);
+    };
+    if found["enabled"].as_bool() == Some(false) {
+        eprintln!("warning: webhook '{}' is disabled, so Linear is not delivering to it", id);
+    }
+
+    let kind = kind
+        .or_else(|| found["resourceTypes"][0].as_str().map(String::from))
+        .unwrap_or_else(|| "Issue".to_string());
+    let mut payload = fixtures::payload(&kind, "create", Utc::now());
+    payload["webhookId"] = Value::from(id);
+    let (mut result, status) = post(url, secret, &payload).await?;
+    result["webhookId"] = Value::from(id);
+    println!("{}", render::render_webhook(cli.global.output, "webhookVerify", &result, cli.global.pretty));
+    if !status.is_success() {
+        anyhow::bail!("The receiver answered {}", status);
+    }
+    Ok(())
+}
+
+/// API names for resource type flags
+fn resource_type_names(types: &[WebhookResourceType]) -> Vec<&'static str> {
+    types.iter().map(|t| t.as_graphql_value()).collect()
+}
+
+/// Run a webhook query or mutation, returning its data
+async fn execute(cli: &Cli, client: &Client, message: &str, query: String, variables: Value) -> Result<Value> {
+    if cli.global.verbose {
+        eprintln!("Query: {}", query);
+        eprintln!("Variables: {}", serde_json::to_string_pretty(&variables)?);
+    }
+    let request = GraphQLRequest {
+        query,
+        variables: Some(variables),
+        operation_name: None,
+    };
+    let response = with_spinner(message, client.execute(request)).await?;
+    ensure_complete(response.errors.unwrap_or_default())?;
+    Ok(response.data.unwrap_or_default())
+}
+
+/// Receive deliveries until Ctrl-C (or `--count`), printing and forwarding each
+async fn listen(cli: &Cli, options: WebhookListenOptions) -> Result<()> {
+    let max_age = (options.max_age > 0).then(|| Duration::from_secs(options.max_age));
+    let server = WebhookServer::bind(&options.host, options.port, &options.secret, max_age)
+        .await
+        .with_context(|| format!("Failed to bind {}:{}", options.host, options.port))?;
+    // stdout carries only deliveries, so the address goes to stderr
+    eprintln!("Listening for webhooks on http://{}/", server.local_addr()?);
+
+    let (sender, mut receiver) = mpsc::channel(64);
+    let server = tokio::spawn(server.serve(sender));
+    let forward_client = options.forward.as_ref().map(|_| reqwest::Client::new());
+
+    // Ctrl-C stops listening after the delivery being handled
+    let _graceful = interrupt::graceful();
+    let mut handled = 0;
+    while let Some(received) = interrupt::cancellable(receiver.recv()).await.flatten() {
+        let delivery = match received {
+            Ok(delivery) => delivery,
+            Err(rejection) => {
+                eprintln!("Rejected delivery: {}", rejection);
+                continue;
+            }
+        };
+        if !delivery.matches(&options.event) {
+            continue;
+        }
+        let json = delivery.to_json();
+        println!("{}", render::render_webhook_delivery(cli.global.output, &json, &delivery.summary()));
+        if let Some(ref command) = options.exec {
+            if let Err(e) = run_hook(command, &json).await {
+                eprintln!("warning: --exec failed for {}: {:#}", delivery.event(), e);
+            }
+        }
+        if let (Some(client), Some(url)) = (&forward_client, &options.forward) {
+            if let Err(e) = forward(client, url, &delivery).await {
+                eprintln!("warning: --forward failed for {}: {:#}", delivery.event(), e);
+            }
+        }
+        handled += 1;
+        if options.count.is_some_and(|count| handled >= count) {
+            break;
+        }
+    }
+    server.abort();
+    Ok(())
+}
+
+/// Re-post a delivery as Linear sent it
+async fn forward(client: &reqwest::Client, url: &str, delivery: &Delivery) -> Result<()> {
+    let mut request = client
+        .post(url)
+        .header("content-type", "application/json")
+        .body(delivery.body.clone());
+    for (name, value) in &delivery.headers {
+        request = request.header(name, value);
+    }
+    let response = request.send().await?;
+    if !response.status().is_success() {
+        anyhow::bail!("{} answered {}", url, response.status());
+    }
+    Ok(())
+}
+
+/// Sign a fixture (or a payload file) and post it, or print the signed request
+async fn send_test(cli: &Cli, options: WebhookSendTestOptions) -> Result<()> {
+    let sent_at = Utc::now() - chrono::Duration::seconds(options.age as i64);
+    let payload = match options.payload {
+        Some(ref path) => {
+            let mut payload: Value = serde_json::from_str(&validate::read_file(path)?)
+                .with_context(|| format!("Failed to parse payload file '{}'", path))?;
+            if !payload.is_object() {
+                anyhow::bail!("Payload file '{}' must hold a JSON object", path);
+            }
+            payload["webhookTimestamp"] = Value::from(sent_at.timestamp_millis());
+            payload
+        }
+        None => fixtures::payload(&options.kind, &options.action, sent_at),
+    };
+    let Some(url) = options.url else {
+        let request = SignedRequest::new(&options.secret, &payload)?;
+        let result = serde_json::json!({
+            "event": request.event,
+            "headers": request.headers.iter().map(|(n, v)| (n.to_string(), Value::from(v.as_str()))).collect::<serde_json::Map<_, _>>(),
+            "body": request.body,
+        });
+        println!("{}", render::render_webhook(cli.global.output, "webhookSendTest", &result, cli.global.pretty));
+        return Ok(());
+    };
+
+    let (result, status) = post(&url, &options.secret, &payload).await?;
+    println!("{}", render::render_webhook(cli.global.output, "webhookSendTest", &result, cli.global.pretty));
+    if !status.is_success() {
+        anyhow::bail!("The receiver answered {}", status);
+    }
+    Ok(())
+}
+
+/// A payload signed the way Linear signs deliveries
+struct SignedRequest {
+    /// `Type.action`
+    event: String,
+    delivery_id: String,
+    headers: [(&'static str, String); 4],
+    body: String,
+}
+
+impl SignedRequest {
+    fn new(secret: &str, payload: &Value) -> Result<Self> {
+        let body = serde_json::to_string(payload)?;
+        let kind = payload["type"].as_str().unwrap_or_default();
+        let delivery_id = fixtures::delivery_id();
+        Ok(Self {
+            event: format!("{}.{}", kind, payload["action"].as_str().unwrap_or_default()),
+            headers: [
+                ("content-type", "application/json".to_string()),
+                (webhook_const::DELIVERY_HEADER, delivery_id.clone()),
+                (webhook_const::EVENT_HEADER, kind.to_string()),
+                (webhook_const::SIGNATURE_HEADER, webhook::sign(secret, body.as_bytes())),
+            ],
+            delivery_id,
+            body,
+        })
+    }
+}
+
+/// Sign and post a payload, returning the result to print and the receiver's status
+async fn post(url: &str, secret: &str, payload: &Value) -> Result<(Value, StatusCode)> {
+    let signed = SignedRequest::new(secret, payload)?;
+    let mut request = reqwest::Client::new().post(url).body(signed.body);
+    for (name, value) in &signed.headers {
+        request = request.header(*name, value);
+    }
+    let response = request
+        .send()
+        .await
+        .with_context(|| format!("Failed to send the test delivery to {}", url))?;
+    let status = response.status();
+    let text = response.text().await.unwrap_or_default();
+    let result = serde_json::json!({
+        "url": url,
+        "event": signed.event,
+        "deliveryId": signed.delivery_id,
+        "status": status.as_u16(),
+        "response": serde_json::from_str::<Value>(&text).unwrap_or(Value::from(text)),
+    });
+    Ok((result, status))
+}
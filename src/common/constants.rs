@@ -6,6 +6,18 @@ pub mod env {
     pub const OUTPUT: &str = "LINEARS_OUTPUT";
     pub const ENDPOINT: &str = "LINEARS_ENDPOINT";
     pub const WORKSPACE: &str = "LINEARS_WORKSPACE";
+    pub const MAX_COMPLEXITY: &str = "LINEARS_MAX_COMPLEXITY";
+    pub const MAX_RETRIES: &str = "LINEARS_MAX_RETRIES";
+    pub const MAX_RATE_LIMIT_WAIT: &str = "LINEARS_MAX_RATE_LIMIT_WAIT";
+    pub const HEADERS: &str = "LINEARS_HEADERS";
+    pub const CA_CERT: &str = "LINEARS_CA_CERT";
+    pub const STATE_HOME: &str = "XDG_STATE_HOME";
+    pub const CACHE_HOME: &str = "XDG_CACHE_HOME";
+    pub const DATA_HOME: &str = "XDG_DATA_HOME";
+    pub const MIRROR: &str = "LINEARS_MIRROR";
+    pub const WEBHOOK_SECRET: &str = "LINEARS_WEBHOOK_SECRET";
+    pub const CACHE: &str = "LINEARS_CACHE";
+    pub const HOME: &str = "HOME";
 }
 
 /// Pagination defaults
@@ -14,20 +26,120 @@ pub mod pagination {
     pub const MAX_RECORDS: usize = 1000;
     /// Page size for auto-pagination
     pub const PAGE_SIZE: i32 = 50;
+    /// Default number of search results
+    pub const SEARCH_LIMIT: i32 = 20;
+    /// Most concurrent partitions for `--all --parallel`
+    pub const MAX_PARALLEL: u8 = 16;
+    /// createdAt ranges per concurrent worker, so uneven ranges balance out
+    pub const PARTITIONS_PER_WORKER: usize = 4;
+}
+
+/// Batched lookups (`get` with several IDs)
+pub mod batch {
+    /// Maximum entities fetched per aliased query
+    pub const MAX_BATCH_SIZE: usize = 50;
 }
 
 /// Client configuration
 pub mod client {
     /// Maximum retry attempts for 5xx errors
     pub const MAX_RETRIES: u32 = 10;
+    /// Maximum retry attempts for network errors and timeouts
+    pub const MAX_NETWORK_RETRIES: u32 = 3;
     /// Base delay for exponential backoff (ms)
     pub const BASE_DELAY_MS: u64 = 100;
     /// Maximum delay cap (ms)
     pub const MAX_DELAY_MS: u64 = 30_000;
     /// Rate limit auto-retry threshold (seconds)
     pub const RATE_LIMIT_AUTO_RETRY_THRESHOLD_SECS: u64 = 60;
+    /// Total time a request may spend waiting on rate limits (seconds)
+    pub const MAX_RATE_LIMIT_WAIT_SECS: u64 = 300;
     /// Default request timeout (seconds)
     pub const DEFAULT_TIMEOUT_SECS: u64 = 30;
+    /// Default User-Agent header
+    pub const USER_AGENT: &str = concat!("linears/", env!("CARGO_PKG_VERSION"));
+    /// Fraction of a rate-limit budget below which loops slow down
+    pub const LOW_BUDGET_FRACTION: f64 = 0.1;
+    /// Maximum pre-emptive delay between requests when a budget is low (ms)
+    pub const MAX_THROTTLE_DELAY_MS: u64 = 10_000;
+}
+
+/// Query complexity scoring (mirrors Linear's)
+pub mod complexity {
+    /// Linear's per-query complexity limit
+    pub const MAX_COMPLEXITY: u64 = 10_000;
+    /// Page size Linear assumes for connections without `first`/`last`
+    pub const DEFAULT_PAGE_SIZE: u64 = 50;
+    /// Cost of a scalar field
+    pub const SCALAR_COST: f64 = 0.1;
+    /// Cost of an object field
+    pub const OBJECT_COST: f64 = 1.0;
+}
+
+/// Local state files
+pub mod state {
+    /// Directory name under $XDG_STATE_HOME
+    pub const DIR_NAME: &str = "linears";
+    /// Undo journal file (one JSON entry per line)
+    pub const JOURNAL_FILE: &str = "journal.ndjson";
+}
+
+/// Offline mirror
+pub mod mirror {
+    /// SQLite file under $XDG_DATA_HOME/linears
+    pub const DB_FILE: &str = "mirror.db";
+    /// Seconds a pull's high-water mark stays behind its start time
+    pub const SYNC_MARGIN_SECS: i64 = 300;
+}
+
+/// Issue imports (`import`)
+pub mod import {
+    /// Issues per `issueBatchCreate` call
+    pub const BATCH_SIZE: usize = 50;
+    /// Appended to the source file name for the default ID map
+    pub const ID_MAP_SUFFIX: &str = ".idmap.json";
+}
+
+/// Workspace export (`export`)
+pub mod export {
+    /// Version of the export layout, bumped on incompatible changes
+    pub const FORMAT_VERSION: u32 = 1;
+    /// Manifest written next to the NDJSON files
+    pub const MANIFEST_FILE: &str = "manifest.json";
+}
+
+/// Change polling (`watch`)
+pub mod watch {
+    /// Longest wait between polls after repeated failures (seconds)
+    pub const MAX_BACKOFF_SECS: u64 = 300;
+}
+
+/// Webhook receiving and test deliveries (`webhook`)
+pub mod webhook {
+    /// Hex HMAC-SHA256 of the raw body, keyed with the webhook's signing secret
+    pub const SIGNATURE_HEADER: &str = "linear-signature";
+    /// Unique ID of each delivery
+    pub const DELIVERY_HEADER: &str = "linear-delivery";
+    /// Payload type, e.g. `Issue`
+    pub const EVENT_HEADER: &str = "linear-event";
+    /// Largest accepted distance between `webhookTimestamp` and now (seconds)
+    pub const MAX_AGE_SECS: u64 = 60;
+    /// Largest request body accepted (bytes)
+    pub const MAX_BODY_BYTES: usize = 5 * 1024 * 1024;
+}
+
+/// Response cache
+pub mod cache {
+    /// Directory under $XDG_CACHE_HOME/linears holding cached responses
+    pub const RESPONSES_DIR: &str = "responses";
+    /// TTL for rarely-changing lookups: organization, teams, workflow states (seconds)
+    pub const LOOKUP_TTL_SECS: u64 = 3600;
+    /// TTL for users and labels (seconds)
+    pub const DIRECTORY_TTL_SECS: u64 = 900;
+    /// TTL for projects and cycles (seconds)
+    pub const PLANNING_TTL_SECS: u64 = 300;
+    /// TTL for other resources when caching everything with --cache-all (seconds)
+    pub const FALLBACK_TTL_SECS: u64 = 60;
 }
 
 /// Display formatting
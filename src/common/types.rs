@@ -1,18 +1,34 @@
 //! Core type definitions
 
-use clap::ValueEnum;
-
 /// Field selection presets for queries
-#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
 pub enum FieldsetPreset {
     /// Minimal fields (id, name/title)
     Minimal,
     /// Default fields
+    #[default]
     Default,
     /// Wide field selection
     Wide,
 }
 
+/// Output format options
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
+pub enum OutputFormat {
+    /// JSON output
+    Json,
+    /// YAML output
+    Yaml,
+    /// Table output (default)
+    Table,
+    /// Human-readable text for single entities
+    Text,
+    /// Newline-delimited JSON (one object per line)
+    Ndjson,
+}
+
 /// Exit codes for the CLI (per PRD §9)
 #[repr(u8)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -22,6 +38,9 @@ pub enum ExitCode {
     AuthError = 2,
     NetworkError = 3,
     GraphQLError = 4,
+    PartialSuccess = 5,
+    /// Stopped by Ctrl-C (128 + SIGINT)
+    Interrupted = 130,
 }
 
 impl From<ExitCode> for std::process::ExitCode {
@@ -0,0 +1,247 @@
+//! Client-side query complexity estimation
+//!
+//! Mirrors Linear's scoring so oversized queries can be caught before the
+//! round trip: each scalar field costs 0.1 points, each object 1 point, and
+//! the `nodes`/`edges` of a connection are multiplied by its page size
+//! (`first`/`last`, or the API default when neither is given). Fields are
+//! classified as scalar, object or connection from the generated schema
+//! registry; fields the schema does not know are priced by whether they have
+//! a sub-selection.
+
+use graphql_parser::query::{
+    Definition, Document, Field, FragmentDefinition, OperationDefinition, Selection, SelectionSet,
+    TypeCondition, Value as GqlValue, parse_query,
+};
+use std::collections::HashMap;
+
+use crate::common::constants::complexity;
+use crate::generated::{get_field_type, FieldKind};
+
+/// Estimate the complexity of a GraphQL document
+///
+/// Page-size arguments given as `$variables` are resolved from `variables`.
+/// Documents that do not parse score 0 (the API reports the syntax error).
+pub fn estimate_complexity(query: &str, variables: &serde_json::Value) -> f64 {
+    let Ok(document) = parse_query::<String>(query) else {
+        return 0.0;
+    };
+    let fragments = fragments(&document);
+    let estimator = Estimator {
+        variables,
+        fragments: &fragments,
+    };
+    let operation = document.definitions.iter().find_map(|d| match d {
+        Definition::Operation(op) => Some(op),
+        Definition::Fragment(_) => None,
+    });
+    match operation {
+        Some(OperationDefinition::SelectionSet(set)) => {
+            estimator.selection_set(set, Some("Query"), 1.0, &mut Vec::new())
+        }
+        Some(OperationDefinition::Query(q)) => {
+            estimator.selection_set(&q.selection_set, Some("Query"), 1.0, &mut Vec::new())
+        }
+        Some(OperationDefinition::Mutation(m)) => {
+            estimator.selection_set(&m.selection_set, Some("Mutation"), 1.0, &mut Vec::new())
+        }
+        Some(OperationDefinition::Subscription(s)) => {
+            estimator.selection_set(&s.selection_set, Some("Subscription"), 1.0, &mut Vec::new())
+        }
+        None => 0.0,
+    }
+}
+
+/// Largest page size (at most the current `first`/`last`) whose estimate fits
+///
+/// Returns None if the query has no page-size variable or nothing fits.
+pub fn suggest_page_size(query: &str, variables: &serde_json::Value, max_complexity: f64) -> Option<u64> {
+    let key = ["first", "last"]
+        .into_iter()
+        .find(|k| variables.get(*k).is_some_and(|v| v.is_u64()))?;
+    let current = variables[key].as_u64()?;
+
+    let fits = |size: u64| {
+        let mut vars = variables.clone();
+        vars[key] = serde_json::json!(size);
+        estimate_complexity(query, &vars) <= max_complexity
+    };
+    if !fits(1) {
+        return None;
+    }
+
+    // Cost grows monotonically with page size, so binary search the boundary
+    let (mut low, mut high) = (1, current);
+    while low < high {
+        let mid = (low + high).div_ceil(2);
+        if fits(mid) {
+            low = mid;
+        } else {
+            high = mid - 1;
+        }
+    }
+    Some(low)
+}
+
+fn fragments<'a>(document: &'a Document<'a, String>) -> HashMap<&'a str, &'a FragmentDefinition<'a, String>> {
+    document
+        .definitions
+        .iter()
+        .filter_map(|d| match d {
+            Definition::Fragment(f) => Some((f.name.as_str(), f)),
+            Definition::Operation(_) => None,
+        })
+        .collect()
+}
+
+struct Estimator<'a> {
+    variables: &'a serde_json::Value,
+    fragments: &'a HashMap<&'a str, &'a FragmentDefinition<'a, String>>,
+}
+
+impl<'a> Estimator<'a> {
+    /// Cost of a selection set on `parent_type` (None when the schema does not know it)
+    ///
+    /// `page_size` multiplies the `nodes`/`edges` of a connection; it is 1 elsewhere.
+    /// `spreads` holds the fragments being expanded, so cyclic spreads end.
+    fn selection_set(
+        &self,
+        set: &'a SelectionSet<'a, String>,
+        parent_type: Option<&str>,
+        page_size: f64,
+        spreads: &mut Vec<&'a str>,
+    ) -> f64 {
+        let mut cost = 0.0;
+        for selection in &set.items {
+            cost += match selection {
+                Selection::Field(field) => self.field(field, parent_type, page_size, spreads),
+                Selection::InlineFragment(fragment) => {
+                    let parent_type = match fragment.type_condition {
+                        Some(TypeCondition::On(ref name)) => Some(name.as_str()),
+                        None => parent_type,
+                    };
+                    self.selection_set(&fragment.selection_set, parent_type, page_size, spreads)
+                }
+                Selection::FragmentSpread(spread) => {
+                    let name = spread.fragment_name.as_str();
+                    match self.fragments.get(name) {
+                        Some(fragment) if !spreads.contains(&name) => {
+                            let TypeCondition::On(ref type_name) = fragment.type_condition;
+                            spreads.push(name);
+                            let cost =
+                                self.selection_set(&fragment.selection_set, Some(type_name), page_size, spreads);
+                            spreads.pop();
+                            cost
+                        }
+                        _ => 0.0,
+                    }
+                }
+            };
+        }
+        cost
+    }
+
+    /// Cost of a single field and its sub-selection
+    fn field(
+        &self,
+        field: &'a Field<'a, String>,
+        parent_type: Option<&str>,
+        parent_page_size: f64,
+        spreads: &mut Vec<&'a str>,
+    ) -> f64 {
+        let (kind, field_type) = match parent_type.and_then(|parent| get_field_type(parent, &field.name)) {
+            Some((kind, field_type)) => (kind, Some(field_type)),
+            None if field.selection_set.items.is_empty() => (FieldKind::Scalar, None),
+            None => (FieldKind::Object, None),
+        };
+        let multiplier = if field.name == "nodes" || field.name == "edges" {
+            parent_page_size
+        } else {
+            1.0
+        };
+        let page_size = match kind {
+            FieldKind::Scalar => return multiplier * complexity::SCALAR_COST,
+            FieldKind::Object => 1.0,
+            FieldKind::Connection => self.page_size(field).unwrap_or(complexity::DEFAULT_PAGE_SIZE) as f64,
+        };
+        multiplier * (complexity::OBJECT_COST + self.selection_set(&field.selection_set, field_type, page_size, spreads))
+    }
+
+    /// The `first`/`last` argument, from a literal or `$variable`
+    fn page_size(&self, field: &Field<String>) -> Option<u64> {
+        field
+            .arguments
+            .iter()
+            .filter(|(name, _)| name == "first" || name == "last")
+            .find_map(|(_, value)| match value {
+                GqlValue::Int(n) => n.as_i64().and_then(|n| u64::try_from(n).ok()),
+                GqlValue::Variable(var) => self.variables.get(var).and_then(|v| v.as_u64()),
+                _ => None,
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn close(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-6
+    }
+
+    #[test]
+    fn test_scalar_and_object_costs() {
+        let query = "query { viewer { id name organization { id } } }";
+        // viewer (1) + 2 scalars + organization (1) + 1 scalar
+        assert!(close(estimate_complexity(query, &serde_json::json!({})), 2.3));
+    }
+
+    #[test]
+    fn test_connection_multiplier_from_variables() {
+        let query = r#"query ListIssues($first: Int, $filter: IssueFilter) {
+  issues(first: $first, filter: $filter) {
+    nodes { id title labels { nodes { id name } } }
+    pageInfo { hasNextPage endCursor }
+  }
+}"#;
+        let vars = serde_json::json!({ "first": 10, "filter": { "title": { "contains": "a, b" } } });
+        // issues (1) + 10 * (node 1 + 2 scalars + labels (1 + 50 * (1 + 0.2))) + pageInfo (1.2)
+        let expected = 1.0 + 10.0 * (1.0 + 0.2 + 1.0 + 50.0 * 1.2) + 1.2;
+        assert!(close(estimate_complexity(query, &vars), expected));
+    }
+
+    #[test]
+    fn test_literal_page_size_and_alias() {
+        let query = "{ open: issues(first: 5) { nodes { id } } }";
+        assert!(close(estimate_complexity(query, &serde_json::json!({})), 1.0 + 5.0 * 1.1));
+    }
+
+    #[test]
+    fn test_schema_classifies_fields_and_expands_fragments() {
+        // `team.issues` is a connection by schema type, even without `first`
+        let query = r#"query { team(id: "t") { ...TeamIssues } }
+fragment TeamIssues on Team { issues { nodes { id } } }"#;
+        assert!(close(estimate_complexity(query, &serde_json::json!({})), 1.0 + 1.0 + 50.0 * 1.1));
+
+        // A `nodes` field outside a connection is not multiplied
+        let query = "{ viewer { ... on User { nodes { id } } } }";
+        assert!(close(estimate_complexity(query, &serde_json::json!({})), 1.0 + 1.1));
+
+        assert!(close(estimate_complexity("{ not valid", &serde_json::json!({})), 0.0));
+    }
+
+    #[test]
+    fn test_suggest_page_size() {
+        let query = "query($first: Int) { issues(first: $first) { nodes { id comments { nodes { id body } } } } }";
+        let vars = serde_json::json!({ "first": 100 });
+        let size = suggest_page_size(query, &vars, 1000.0).unwrap();
+        assert!(size < 100);
+
+        let mut fitted = vars.clone();
+        fitted["first"] = serde_json::json!(size);
+        assert!(estimate_complexity(query, &fitted) <= 1000.0);
+        fitted["first"] = serde_json::json!(size + 1);
+        assert!(estimate_complexity(query, &fitted) > 1000.0);
+
+        assert_eq!(suggest_page_size("{ viewer { id } }", &serde_json::json!({}), 1000.0), None);
+    }
+}
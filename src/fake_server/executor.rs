@@ -0,0 +1,744 @@
+//! GraphQL execution against the in-memory store
+//!
+//! Supports the subset of the Linear API the CLI uses: connection queries with
+//! cursor pagination and filters, single-entity lookups, `viewer`, and
+//! create/update/archive/unarchive/delete mutations. Documents may use
+//! variables, aliases, fragments and `__typename`.
+
+use graphql_parser::query::{
+    Definition, Document, Field, OperationDefinition, Selection, SelectionSet, TypeCondition,
+    Value as GqlValue, parse_query,
+};
+use serde_json::{Map, Value, json};
+use std::collections::HashMap;
+
+use super::store::{self, EntityType, Relation, Store};
+
+/// Default connection page size (same as the real API)
+const DEFAULT_PAGE_SIZE: usize = 50;
+
+type Fragments<'a> = HashMap<String, &'a graphql_parser::query::FragmentDefinition<'a, String>>;
+
+/// Execute a GraphQL request body (`{query, variables, operationName}`)
+pub fn execute(store: &mut Store, body: &Value) -> Value {
+    let Some(query) = body.get("query").and_then(|q| q.as_str()) else {
+        return error_response("Request body must contain a query");
+    };
+    let document: Document<String> = match parse_query(query) {
+        Ok(document) => document,
+        Err(e) => return error_response(&format!("Syntax Error: {}", e)),
+    };
+
+    let fragments: Fragments = document
+        .definitions
+        .iter()
+        .filter_map(|d| match d {
+            Definition::Fragment(f) => Some((f.name.clone(), f)),
+            _ => None,
+        })
+        .collect();
+
+    let operation_name = body.get("operationName").and_then(|n| n.as_str());
+    let operation = document.definitions.iter().find_map(|d| match d {
+        Definition::Operation(op)
+            if operation_name.is_none_or(|name| op_name(op) == Some(name)) =>
+        {
+            Some(op)
+        }
+        _ => None,
+    });
+    let Some(operation) = operation else {
+        return error_response("No operation found in document");
+    };
+
+    let mut variables = body
+        .get("variables")
+        .and_then(|v| v.as_object())
+        .cloned()
+        .unwrap_or_default();
+    let (selection_set, is_mutation) = match operation {
+        OperationDefinition::SelectionSet(set) => (set, false),
+        OperationDefinition::Query(q) => {
+            apply_defaults(&mut variables, &q.variable_definitions);
+            (&q.selection_set, false)
+        }
+        OperationDefinition::Mutation(m) => {
+            apply_defaults(&mut variables, &m.variable_definitions);
+            (&m.selection_set, true)
+        }
+        OperationDefinition::Subscription(_) => {
+            return error_response("Subscriptions are not supported by the fake server");
+        }
+    };
+
+    let mut executor = Executor {
+        store,
+        variables,
+        fragments,
+        errors: Vec::new(),
+    };
+    let mut data = Map::new();
+    for field in executor.fields(selection_set, "Query") {
+        let key = field.alias.clone().unwrap_or_else(|| field.name.clone());
+        let value = if is_mutation {
+            executor.mutation_field(field)
+        } else {
+            executor.query_field(field)
+        };
+        match value {
+            Ok(value) => {
+                data.insert(key, value);
+            }
+            Err(message) => {
+                executor.errors.push(json!({
+                    "message": message,
+                    "path": [key.clone()],
+                    "extensions": { "code": "INVALID_INPUT" },
+                }));
+                data.insert(key, Value::Null);
+            }
+        }
+    }
+
+    let mut response = json!({ "data": data });
+    if !executor.errors.is_empty() {
+        response["errors"] = Value::Array(executor.errors);
+    }
+    response
+}
+
+fn error_response(message: &str) -> Value {
+    json!({
+        "errors": [{ "message": message, "extensions": { "code": "GRAPHQL_PARSE_FAILED" } }],
+    })
+}
+
+fn op_name<'a>(op: &'a OperationDefinition<String>) -> Option<&'a str> {
+    match op {
+        OperationDefinition::Query(q) => q.name.as_deref(),
+        OperationDefinition::Mutation(m) => m.name.as_deref(),
+        _ => None,
+    }
+}
+
+fn apply_defaults(
+    variables: &mut Map<String, Value>,
+    definitions: &[graphql_parser::query::VariableDefinition<String>],
+) {
+    for definition in definitions {
+        if let Some(default) = &definition.default_value {
+            if variables.get(&definition.name).is_none_or(|v| v.is_null()) {
+                variables.insert(definition.name.clone(), literal(default, &Map::new()));
+            }
+        }
+    }
+}
+
+/// Convert a GraphQL value to JSON, resolving `$variables`
+fn literal(value: &GqlValue<String>, variables: &Map<String, Value>) -> Value {
+    match value {
+        GqlValue::Variable(name) => variables.get(name).cloned().unwrap_or(Value::Null),
+        GqlValue::Int(n) => json!(n.as_i64()),
+        GqlValue::Float(f) => json!(f),
+        GqlValue::String(s) => json!(s),
+        GqlValue::Boolean(b) => json!(b),
+        GqlValue::Null => Value::Null,
+        GqlValue::Enum(e) => json!(e),
+        GqlValue::List(items) => items.iter().map(|v| literal(v, variables)).collect(),
+        GqlValue::Object(fields) => fields
+            .iter()
+            .map(|(k, v)| (k.clone(), literal(v, variables)))
+            .collect::<Map<_, _>>()
+            .into(),
+    }
+}
+
+struct Executor<'a> {
+    store: &'a mut Store,
+    variables: Map<String, Value>,
+    fragments: Fragments<'a>,
+    errors: Vec<Value>,
+}
+
+impl<'a> Executor<'a> {
+    /// Fields of a selection set with fragments expanded for `type_name`
+    fn fields(
+        &self,
+        set: &'a SelectionSet<'a, String>,
+        type_name: &str,
+    ) -> Vec<&'a Field<'a, String>> {
+        let mut fields = Vec::new();
+        for selection in &set.items {
+            match selection {
+                Selection::Field(field) => fields.push(field),
+                Selection::InlineFragment(fragment) => {
+                    let applies = match &fragment.type_condition {
+                        Some(TypeCondition::On(on)) => on == type_name,
+                        None => true,
+                    };
+                    if applies {
+                        fields.extend(self.fields(&fragment.selection_set, type_name));
+                    }
+                }
+                Selection::FragmentSpread(spread) => {
+                    if let Some(fragment) = self.fragments.get(&spread.fragment_name) {
+                        let TypeCondition::On(on) = &fragment.type_condition;
+                        if on == type_name {
+                            fields.extend(self.fields(&fragment.selection_set, type_name));
+                        }
+                    }
+                }
+            }
+        }
+        fields
+    }
+
+    fn args(&self, field: &Field<String>) -> Map<String, Value> {
+        field
+            .arguments
+            .iter()
+            .map(|(name, value)| (name.clone(), literal(value, &self.variables)))
+            .collect()
+    }
+
+    fn query_field(&mut self, field: &'a Field<'a, String>) -> Result<Value, String> {
+        let args = self.args(field);
+        if field.name == "__typename" {
+            return Ok(json!("Query"));
+        }
+        if field.name == "viewer" {
+            let viewer = self
+                .store
+                .find(EntityType::User, &store::uuid(EntityType::User, 1))
+                .cloned();
+            return Ok(viewer.map_or(Value::Null, |v| {
+                self.entity(EntityType::User, &v, &field.selection_set)
+            }));
+        }
+        if let Some(entity_type) = EntityType::from_plural_name(&field.name) {
+            let entities: Vec<Value> = self.store.all(entity_type).cloned().collect();
+            return self.connection(entity_type, entities, &args, &field.selection_set);
+        }
+        if let Some(entity_type) = EntityType::from_field_name(&field.name) {
+            let id = args
+                .get("id")
+                .and_then(|v| v.as_str())
+                .ok_or_else(|| format!("Argument 'id' is required for {}", field.name))?;
+            let entity = self
+                .store
+                .find(entity_type, id)
+                .cloned()
+                .ok_or_else(|| store::not_found(entity_type, id))?;
+            return Ok(self.entity(entity_type, &entity, &field.selection_set));
+        }
+        Err(format!(
+            "Cannot query field \"{}\" on type \"Query\" (not implemented by the fake server)",
+            field.name
+        ))
+    }
+
+    fn mutation_field(&mut self, field: &'a Field<'a, String>) -> Result<Value, String> {
+        let args = self.args(field);
+        let (entity_type, action) = EntityType::all()
+            .iter()
+            .find_map(|t| {
+                let action = field.name.strip_prefix(t.field_name())?;
+                matches!(action, "Create" | "Update" | "Archive" | "Unarchive" | "Delete").then_some((*t, action))
+            })
+            .ok_or_else(|| {
+                format!(
+                    "Cannot query field \"{}\" on type \"Mutation\" (not implemented by the fake server)",
+                    field.name
+                )
+            })?;
+
+        let id = args.get("id").and_then(|v| v.as_str()).unwrap_or_default();
+        let input = args.get("input").cloned().unwrap_or(Value::Null);
+        let entity = match action {
+            "Create" => self.store.create(entity_type, &input)?,
+            "Update" => self.store.update(entity_type, id, &input)?,
+            "Archive" => self.store.set_archived(entity_type, id, true)?,
+            "Unarchive" => self.store.set_archived(entity_type, id, false)?,
+            _ => self.store.delete(entity_type, id)?,
+        };
+
+        let mut payload = Map::new();
+        for sub in self.fields(&field.selection_set, "Payload") {
+            let key = sub.alias.clone().unwrap_or_else(|| sub.name.clone());
+            let value = match sub.name.as_str() {
+                "success" => json!(true),
+                "lastSyncId" => json!(self.store.sync_id()),
+                "entityId" => entity["id"].clone(),
+                "__typename" => json!(format!("{}Payload", entity_type.type_name())),
+                name if name == entity_type.field_name() || name == "entity" => {
+                    self.entity(entity_type, &entity, &sub.selection_set)
+                }
+                _ => Value::Null,
+            };
+            payload.insert(key, value);
+        }
+        Ok(Value::Object(payload))
+    }
+
+    /// Resolve a selection set on an entity
+    fn entity(
+        &mut self,
+        entity_type: EntityType,
+        entity: &Value,
+        set: &'a SelectionSet<'a, String>,
+    ) -> Value {
+        let mut result = Map::new();
+        for field in self.fields(set, entity_type.type_name()) {
+            let key = field.alias.clone().unwrap_or_else(|| field.name.clone());
+            let value = if field.name == "__typename" {
+                json!(entity_type.type_name())
+            } else if let Some(relation) = store::relation(entity_type, &field.name) {
+                match self.relation(entity, relation, field) {
+                    Ok(value) => value,
+                    Err(message) => {
+                        self.errors
+                            .push(json!({ "message": message, "path": [key.clone()] }));
+                        Value::Null
+                    }
+                }
+            } else {
+                plain(entity.get(&field.name).unwrap_or(&Value::Null), field)
+            };
+            result.insert(key, value);
+        }
+        Value::Object(result)
+    }
+
+    fn relation(
+        &mut self,
+        entity: &Value,
+        relation: Relation,
+        field: &'a Field<'a, String>,
+    ) -> Result<Value, String> {
+        let target = relation.target();
+        if let Relation::One(_, fk) = relation {
+            let related = entity[fk]
+                .as_str()
+                .and_then(|id| self.store.find(target, id))
+                .cloned();
+            return Ok(related.map_or(Value::Null, |r| {
+                self.entity(target, &r, &field.selection_set)
+            }));
+        }
+        let related = self
+            .related(entity, relation)
+            .into_iter()
+            .cloned()
+            .collect();
+        let args = self.args(field);
+        self.connection(target, related, &args, &field.selection_set)
+    }
+
+    /// Entities on the far side of a to-many relation
+    fn related(&self, entity: &Value, relation: Relation) -> Vec<&Value> {
+        let target = self.store.all(relation.target());
+        match relation {
+            Relation::One(_, fk) => target.filter(|r| entity[fk] == r["id"]).collect(),
+            Relation::Many(_, ids) => {
+                let ids = entity[ids].as_array().cloned().unwrap_or_default();
+                target.filter(|r| ids.contains(&r["id"])).collect()
+            }
+            Relation::Reverse(_, fk) => target.filter(|r| r[fk] == entity["id"]).collect(),
+            Relation::ReverseMany(_, ids) => target
+                .filter(|r| {
+                    r[ids]
+                        .as_array()
+                        .is_some_and(|ids| ids.contains(&entity["id"]))
+                })
+                .collect(),
+        }
+    }
+
+    /// Filter, paginate and resolve a connection
+    fn connection(
+        &mut self,
+        entity_type: EntityType,
+        entities: Vec<Value>,
+        args: &Map<String, Value>,
+        set: &'a SelectionSet<'a, String>,
+    ) -> Result<Value, String> {
+        let include_archived = args
+            .get("includeArchived")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+        let filter = args.get("filter").filter(|f| !f.is_null());
+        let mut matching = Vec::new();
+        for entity in entities {
+            if !include_archived && !entity["archivedAt"].is_null() {
+                continue;
+            }
+            if let Some(filter) = filter {
+                if !self.matches(entity_type, &entity, filter)? {
+                    continue;
+                }
+            }
+            matching.push(entity);
+        }
+
+        let page = paginate(&matching, args)?;
+        let type_name = format!("{}Connection", entity_type.type_name());
+        let mut result = Map::new();
+        for field in self.fields(set, &type_name) {
+            let key = field.alias.clone().unwrap_or_else(|| field.name.clone());
+            let value = match field.name.as_str() {
+                "__typename" => json!(type_name),
+                "nodes" => page
+                    .nodes
+                    .iter()
+                    .map(|node| self.entity(entity_type, node, &field.selection_set))
+                    .collect(),
+                "edges" => {
+                    let mut edges = Vec::new();
+                    for node in &page.nodes {
+                        let mut edge = Map::new();
+                        for sub in self.fields(&field.selection_set, "Edge") {
+                            let edge_key = sub.alias.clone().unwrap_or_else(|| sub.name.clone());
+                            let value = match sub.name.as_str() {
+                                "cursor" => node["id"].clone(),
+                                "node" => self.entity(entity_type, node, &sub.selection_set),
+                                _ => Value::Null,
+                            };
+                            edge.insert(edge_key, value);
+                        }
+                        edges.push(Value::Object(edge));
+                    }
+                    Value::Array(edges)
+                }
+                "pageInfo" => plain(&page.page_info, field),
+                _ => Value::Null,
+            };
+            result.insert(key, value);
+        }
+        Ok(Value::Object(result))
+    }
+
+    /// Evaluate a Linear-style filter object against an entity
+    fn matches(
+        &self,
+        entity_type: EntityType,
+        entity: &Value,
+        filter: &Value,
+    ) -> Result<bool, String> {
+        let Some(filter) = filter.as_object() else {
+            return Ok(true);
+        };
+        for (key, condition) in filter {
+            let matched = match key.as_str() {
+                "and" => self.all_match(entity_type, entity, condition)?,
+                "or" => {
+                    let mut any = false;
+                    for sub in condition.as_array().into_iter().flatten() {
+                        if self.matches(entity_type, entity, sub)? {
+                            any = true;
+                            break;
+                        }
+                    }
+                    any
+                }
+                field => match store::relation(entity_type, field) {
+                    Some(relation) => self.relation_matches(entity, relation, condition)?,
+                    None => compare(entity.get(field).unwrap_or(&Value::Null), condition)?,
+                },
+            };
+            if !matched {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+
+    fn all_match(
+        &self,
+        entity_type: EntityType,
+        entity: &Value,
+        filters: &Value,
+    ) -> Result<bool, String> {
+        for sub in filters.as_array().into_iter().flatten() {
+            if !self.matches(entity_type, entity, sub)? {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+
+    /// Nested relation filter; to-many relations match if some related entity does
+    fn relation_matches(
+        &self,
+        entity: &Value,
+        relation: Relation,
+        condition: &Value,
+    ) -> Result<bool, String> {
+        let target = relation.target();
+        if let Relation::One(_, fk) = relation {
+            let Some(related) = entity[fk]
+                .as_str()
+                .and_then(|id| self.store.find(target, id))
+            else {
+                // `{ assignee: { null: true } }`
+                return Ok(condition
+                    .get("null")
+                    .and_then(|v| v.as_bool())
+                    .unwrap_or(false));
+            };
+            if let Some(is_null) = condition.get("null").and_then(|v| v.as_bool()) {
+                return Ok(!is_null);
+            }
+            return self.matches(target, related, condition);
+        }
+
+        let related = self.related(entity, relation);
+        let condition = condition
+            .get("some")
+            .or_else(|| condition.get("every"))
+            .unwrap_or(condition);
+        for r in related {
+            if self.matches(target, r, condition)? {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+}
+
+/// Select fields from a plain JSON value (scalars, embedded objects)
+fn plain(value: &Value, field: &Field<String>) -> Value {
+    if field.selection_set.items.is_empty() {
+        return value.clone();
+    }
+    match value {
+        Value::Object(object) => {
+            let mut result = Map::new();
+            for selection in &field.selection_set.items {
+                if let Selection::Field(sub) = selection {
+                    let key = sub.alias.clone().unwrap_or_else(|| sub.name.clone());
+                    result.insert(
+                        key,
+                        plain(object.get(&sub.name).unwrap_or(&Value::Null), sub),
+                    );
+                }
+            }
+            Value::Object(result)
+        }
+        Value::Array(items) => items.iter().map(|item| plain(item, field)).collect(),
+        other => other.clone(),
+    }
+}
+
+/// Apply a comparator object (`{ eq: "x" }`, `{ in: [...] }`) to a value
+fn compare(value: &Value, condition: &Value) -> Result<bool, String> {
+    let Some(comparators) = condition.as_object() else {
+        return Ok(value == condition);
+    };
+    for (op, expected) in comparators {
+        let text = value.as_str().map(str::to_lowercase);
+        let expected_text = expected.as_str().map(str::to_lowercase);
+        let matched = match op.as_str() {
+            "eq" => value == expected,
+            "neq" => value != expected,
+            "in" => expected.as_array().is_some_and(|a| a.contains(value)),
+            "nin" => !expected.as_array().is_some_and(|a| a.contains(value)),
+            "null" => value.is_null() == expected.as_bool().unwrap_or(true),
+            "eqIgnoreCase" => text.is_some() && text == expected_text,
+            "neqIgnoreCase" => text != expected_text,
+            "contains" => {
+                matches!((value.as_str(), expected.as_str()), (Some(v), Some(e)) if v.contains(e))
+            }
+            "notContains" => {
+                !matches!((value.as_str(), expected.as_str()), (Some(v), Some(e)) if v.contains(e))
+            }
+            "containsIgnoreCase" => {
+                matches!((&text, &expected_text), (Some(v), Some(e)) if v.contains(e.as_str()))
+            }
+            "notContainsIgnoreCase" => {
+                !matches!((&text, &expected_text), (Some(v), Some(e)) if v.contains(e.as_str()))
+            }
+            "startsWith" => {
+                matches!((value.as_str(), expected.as_str()), (Some(v), Some(e)) if v.starts_with(e))
+            }
+            "endsWith" => {
+                matches!((value.as_str(), expected.as_str()), (Some(v), Some(e)) if v.ends_with(e))
+            }
+            "lt" | "lte" | "gt" | "gte" => {
+                ordered(value, expected).is_some_and(|ordering| match op.as_str() {
+                    "lt" => ordering.is_lt(),
+                    "lte" => ordering.is_le(),
+                    "gt" => ordering.is_gt(),
+                    _ => ordering.is_ge(),
+                })
+            }
+            other => return Err(format!("Unsupported filter comparator: {}", other)),
+        };
+        if !matched {
+            return Ok(false);
+        }
+    }
+    Ok(true)
+}
+
+/// Order numbers numerically and strings (e.g. timestamps) lexically
+fn ordered(value: &Value, expected: &Value) -> Option<std::cmp::Ordering> {
+    match (value, expected) {
+        (Value::Number(a), Value::Number(b)) => a.as_f64()?.partial_cmp(&b.as_f64()?),
+        (Value::String(a), Value::String(b)) => Some(a.as_str().cmp(b.as_str())),
+        _ => None,
+    }
+}
+
+struct Page {
+    nodes: Vec<Value>,
+    page_info: Value,
+}
+
+/// Cursor pagination; the cursor is the entity ID
+fn paginate(entities: &[Value], args: &Map<String, Value>) -> Result<Page, String> {
+    let position = |cursor: &str| {
+        entities
+            .iter()
+            .position(|e| e["id"] == cursor)
+            .ok_or_else(|| format!("Invalid cursor: {}", cursor))
+    };
+
+    let mut start = 0;
+    let mut end = entities.len();
+    if let Some(after) = args.get("after").and_then(|v| v.as_str()) {
+        start = position(after)? + 1;
+    }
+    if let Some(before) = args.get("before").and_then(|v| v.as_str()) {
+        end = position(before)?.max(start);
+    }
+
+    let first = args
+        .get("first")
+        .and_then(|v| v.as_u64())
+        .map(|n| n as usize);
+    let last = args
+        .get("last")
+        .and_then(|v| v.as_u64())
+        .map(|n| n as usize);
+    if first.is_some() || last.is_none() {
+        end = end.min(start + first.unwrap_or(DEFAULT_PAGE_SIZE));
+    }
+    if let Some(last) = last {
+        start = start.max(end.saturating_sub(last));
+    }
+
+    let nodes = entities[start..end].to_vec();
+    let page_info = json!({
+        "hasNextPage": end < entities.len(),
+        "hasPreviousPage": start > 0,
+        "startCursor": nodes.first().map(|n| n["id"].clone()),
+        "endCursor": nodes.last().map(|n| n["id"].clone()),
+    });
+    Ok(Page { nodes, page_info })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(store: &mut Store, query: &str, variables: Value) -> Value {
+        execute(store, &json!({ "query": query, "variables": variables }))
+    }
+
+    #[test]
+    fn test_pagination_and_relations() {
+        let mut store = Store::seeded(5);
+        let query = "query($first: Int, $after: String) { issues(first: $first, after: $after) { pageInfo { hasNextPage endCursor } nodes { identifier team { key } } } }";
+        let page1 = run(&mut store, query, json!({ "first": 3 }));
+        assert_eq!(
+            page1["data"]["issues"]["nodes"].as_array().unwrap().len(),
+            3
+        );
+        assert_eq!(page1["data"]["issues"]["nodes"][0]["team"]["key"], "ENG");
+        assert_eq!(page1["data"]["issues"]["pageInfo"]["hasNextPage"], true);
+
+        let cursor = page1["data"]["issues"]["pageInfo"]["endCursor"].clone();
+        let page2 = run(&mut store, query, json!({ "first": 3, "after": cursor }));
+        assert_eq!(page2["data"]["issues"]["nodes"][0]["identifier"], "ENG-4");
+        assert_eq!(page2["data"]["issues"]["pageInfo"]["hasNextPage"], false);
+    }
+
+    #[test]
+    fn test_filters() {
+        let mut store = Store::seeded(10);
+        let query =
+            "query($filter: IssueFilter) { issues(filter: $filter) { nodes { identifier } } }";
+        let result = run(
+            &mut store,
+            query,
+            json!({ "filter": { "state": { "name": { "eq": "Done" } }, "title": { "containsIgnoreCase": "issue" } } }),
+        );
+        let nodes = result["data"]["issues"]["nodes"].as_array().unwrap();
+        assert!(!nodes.is_empty());
+        assert!(
+            nodes
+                .iter()
+                .all(|n| n["identifier"] == "ENG-4" || n["identifier"] == "ENG-9")
+        );
+
+        let labelled = run(
+            &mut store,
+            query,
+            json!({ "filter": { "labels": { "name": { "eq": "Bug" } } } }),
+        );
+        assert_eq!(
+            labelled["data"]["issues"]["nodes"]
+                .as_array()
+                .unwrap()
+                .len(),
+            2
+        );
+    }
+
+    #[test]
+    fn test_mutations_and_lookup_by_identifier() {
+        let mut store = Store::seeded(2);
+        let team_id = store::uuid(EntityType::Team, 1);
+        let created = run(
+            &mut store,
+            "mutation($input: IssueCreateInput!) { issueCreate(input: $input) { success issue { id identifier state { name } } } }",
+            json!({ "input": { "title": "New", "teamId": team_id } }),
+        );
+        let issue = &created["data"]["issueCreate"]["issue"];
+        assert_eq!(issue["identifier"], "ENG-3");
+        assert_eq!(issue["state"]["name"], "Todo");
+
+        run(
+            &mut store,
+            "mutation($id: String!) { issueArchive(id: $id) { success } }",
+            json!({ "id": "ENG-3" }),
+        );
+        let fetched = run(
+            &mut store,
+            "{ issue(id: \"eng-3\") { ...F } } fragment F on Issue { title archivedAt __typename }",
+            json!({}),
+        );
+        assert_eq!(fetched["data"]["issue"]["title"], "New");
+        assert!(!fetched["data"]["issue"]["archivedAt"].is_null());
+        assert_eq!(fetched["data"]["issue"]["__typename"], "Issue");
+
+        let listed = run(&mut store, "{ issues { nodes { id } } }", json!({}));
+        assert_eq!(
+            listed["data"]["issues"]["nodes"].as_array().unwrap().len(),
+            2
+        );
+    }
+
+    #[test]
+    fn test_unknown_field_is_an_error() {
+        let mut store = Store::seeded(1);
+        let result = run(&mut store, "{ documents { nodes { id } } }", json!({}));
+        assert!(
+            result["errors"][0]["message"]
+                .as_str()
+                .unwrap()
+                .contains("documents")
+        );
+        assert!(result["data"]["documents"].is_null());
+    }
+}
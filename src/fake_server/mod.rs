@@ -0,0 +1,73 @@
+//! Local fake Linear API for offline development and tests
+//!
+//! A minimal HTTP/1.1 server that answers GraphQL POSTs from an in-memory
+//! store. It implements the subset of the API the CLI uses; see
+//! [`executor`] for what is supported.
+
+mod executor;
+mod store;
+
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use tokio::net::TcpListener;
+
+use crate::http_server::{serve_connection, Request};
+
+pub use store::Store;
+
+/// Largest request body accepted (bytes)
+const MAX_BODY_BYTES: usize = 10 * 1024 * 1024;
+
+/// A bound fake server, ready to serve
+pub struct FakeServer {
+    listener: TcpListener,
+    store: Arc<Mutex<Store>>,
+}
+
+impl FakeServer {
+    /// Bind to `host:port` (port 0 picks a free port) with a seeded store
+    pub async fn bind(host: &str, port: u16, issue_count: u32) -> std::io::Result<Self> {
+        let listener = TcpListener::bind((host, port)).await?;
+        Ok(Self {
+            listener,
+            store: Arc::new(Mutex::new(Store::seeded(issue_count))),
+        })
+    }
+
+    pub fn local_addr(&self) -> std::io::Result<SocketAddr> {
+        self.listener.local_addr()
+    }
+
+    /// GraphQL endpoint URL, for `--endpoint`
+    pub fn url(&self) -> std::io::Result<String> {
+        Ok(format!("http://{}/graphql", self.local_addr()?))
+    }
+
+    /// Accept connections until the task is dropped
+    pub async fn serve(self) -> std::io::Result<()> {
+        loop {
+            let (stream, _) = self.listener.accept().await?;
+            let store = Arc::clone(&self.store);
+            tokio::spawn(async move {
+                let handle = |request: Request| std::future::ready(("200 OK", handle_graphql(&store, &request.body)));
+                let _ = serve_connection(stream, MAX_BODY_BYTES, error_body, handle).await;
+            });
+        }
+    }
+}
+
+fn handle_graphql(store: &Mutex<Store>, body: &[u8]) -> String {
+    let request: serde_json::Value = match serde_json::from_slice(body) {
+        Ok(request) => request,
+        Err(e) => return error_body(&format!("Invalid JSON body: {}", e)),
+    };
+    let mut store = match store.lock() {
+        Ok(store) => store,
+        Err(poisoned) => poisoned.into_inner(),
+    };
+    executor::execute(&mut store, &request).to_string()
+}
+
+fn error_body(message: &str) -> String {
+    serde_json::json!({ "errors": [{ "message": message }] }).to_string()
+}
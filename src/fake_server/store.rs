@@ -0,0 +1,562 @@
+//! In-memory entity store for the fake server, seeded with deterministic data
+//!
+//! Entities are flat JSON objects. Relations are stored as foreign keys
+//! (`teamId`, `labelIds`), the same shape mutation inputs use, and resolved
+//! through [`relation`] when a query selects them.
+
+use chrono::Utc;
+use serde_json::{Value, json};
+
+/// Fixed timestamps for seeded data (same as the test factories)
+const FIXED_TIMESTAMP: &str = "2025-01-10T12:00:00.000Z";
+const FIXED_UPDATED_AT: &str = "2025-01-10T14:30:00.000Z";
+
+/// Entity types the fake server implements
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EntityType {
+    Issue,
+    Team,
+    User,
+    WorkflowState,
+    IssueLabel,
+    Comment,
+    Project,
+    Cycle,
+}
+
+impl EntityType {
+    pub fn all() -> &'static [EntityType] {
+        &[
+            EntityType::Issue,
+            EntityType::Team,
+            EntityType::User,
+            EntityType::WorkflowState,
+            EntityType::IssueLabel,
+            EntityType::Comment,
+            EntityType::Project,
+            EntityType::Cycle,
+        ]
+    }
+
+    /// GraphQL type name (e.g. "WorkflowState")
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            EntityType::Issue => "Issue",
+            EntityType::Team => "Team",
+            EntityType::User => "User",
+            EntityType::WorkflowState => "WorkflowState",
+            EntityType::IssueLabel => "IssueLabel",
+            EntityType::Comment => "Comment",
+            EntityType::Project => "Project",
+            EntityType::Cycle => "Cycle",
+        }
+    }
+
+    /// Singular query field (e.g. "workflowState")
+    pub fn field_name(&self) -> &'static str {
+        match self {
+            EntityType::Issue => "issue",
+            EntityType::Team => "team",
+            EntityType::User => "user",
+            EntityType::WorkflowState => "workflowState",
+            EntityType::IssueLabel => "issueLabel",
+            EntityType::Comment => "comment",
+            EntityType::Project => "project",
+            EntityType::Cycle => "cycle",
+        }
+    }
+
+    /// Connection query field (e.g. "workflowStates")
+    pub fn plural_name(&self) -> &'static str {
+        match self {
+            EntityType::Issue => "issues",
+            EntityType::Team => "teams",
+            EntityType::User => "users",
+            EntityType::WorkflowState => "workflowStates",
+            EntityType::IssueLabel => "issueLabels",
+            EntityType::Comment => "comments",
+            EntityType::Project => "projects",
+            EntityType::Cycle => "cycles",
+        }
+    }
+
+    pub fn from_field_name(name: &str) -> Option<EntityType> {
+        Self::all().iter().copied().find(|t| t.field_name() == name)
+    }
+
+    pub fn from_plural_name(name: &str) -> Option<EntityType> {
+        Self::all()
+            .iter()
+            .copied()
+            .find(|t| t.plural_name() == name)
+    }
+
+    /// Prefix for deterministic UUIDs
+    fn id_prefix(&self) -> &'static str {
+        match self {
+            EntityType::Issue => "iss",
+            EntityType::Team => "tea",
+            EntityType::User => "usr",
+            EntityType::WorkflowState => "wfs",
+            EntityType::IssueLabel => "lbl",
+            EntityType::Comment => "cmt",
+            EntityType::Project => "prj",
+            EntityType::Cycle => "cyc",
+        }
+    }
+}
+
+/// How a relation field is stored
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Relation {
+    /// Foreign key on this entity (`issue.teamId`)
+    One(EntityType, &'static str),
+    /// ID list on this entity (`issue.labelIds`)
+    Many(EntityType, &'static str),
+    /// Foreign key on the target pointing back (`comment.issueId`)
+    Reverse(EntityType, &'static str),
+    /// ID list on the target containing this entity (`issue.labelIds`)
+    ReverseMany(EntityType, &'static str),
+}
+
+impl Relation {
+    pub fn target(&self) -> EntityType {
+        match self {
+            Relation::One(t, _)
+            | Relation::Many(t, _)
+            | Relation::Reverse(t, _)
+            | Relation::ReverseMany(t, _) => *t,
+        }
+    }
+}
+
+/// Relation stored for a field of an entity type, if any
+pub fn relation(entity_type: EntityType, field: &str) -> Option<Relation> {
+    use EntityType::*;
+    use Relation::*;
+
+    let relation = match (entity_type, field) {
+        (Issue, "team") => One(Team, "teamId"),
+        (Issue, "state") => One(WorkflowState, "stateId"),
+        (Issue, "assignee") => One(User, "assigneeId"),
+        (Issue, "creator") => One(User, "creatorId"),
+        (Issue, "project") => One(Project, "projectId"),
+        (Issue, "cycle") => One(Cycle, "cycleId"),
+        (Issue, "parent") => One(Issue, "parentId"),
+        (Issue, "labels") => Many(IssueLabel, "labelIds"),
+        (Issue, "subscribers") => Many(User, "subscriberIds"),
+        (Issue, "comments") => Reverse(Comment, "issueId"),
+        (Issue, "children") => Reverse(Issue, "parentId"),
+        (Team, "issues") => Reverse(Issue, "teamId"),
+        (Team, "states") => Reverse(WorkflowState, "teamId"),
+        (Team, "labels") => Reverse(IssueLabel, "teamId"),
+        (Team, "cycles") => Reverse(Cycle, "teamId"),
+        (Team, "members") => Many(User, "memberIds"),
+        (Team, "projects") => ReverseMany(Project, "teamIds"),
+        (User, "assignedIssues") => Reverse(Issue, "assigneeId"),
+        (User, "createdIssues") => Reverse(Issue, "creatorId"),
+        (User, "teams") => ReverseMany(Team, "memberIds"),
+        (WorkflowState, "team") => One(Team, "teamId"),
+        (WorkflowState, "issues") => Reverse(Issue, "stateId"),
+        (IssueLabel, "team") => One(Team, "teamId"),
+        (IssueLabel, "parent") => One(IssueLabel, "parentId"),
+        (IssueLabel, "issues") => ReverseMany(Issue, "labelIds"),
+        (Comment, "issue") => One(Issue, "issueId"),
+        (Comment, "user") => One(User, "userId"),
+        (Comment, "parent") => One(Comment, "parentId"),
+        (Comment, "children") => Reverse(Comment, "parentId"),
+        (Project, "lead") => One(User, "leadId"),
+        (Project, "creator") => One(User, "creatorId"),
+        (Project, "teams") => Many(Team, "teamIds"),
+        (Project, "members") => Many(User, "memberIds"),
+        (Project, "issues") => Reverse(Issue, "projectId"),
+        (Cycle, "team") => One(Team, "teamId"),
+        (Cycle, "issues") => Reverse(Issue, "cycleId"),
+        _ => return None,
+    };
+    Some(relation)
+}
+
+/// All entities, in creation order per type
+pub struct Store {
+    entities: Vec<(EntityType, Value)>,
+    /// Sequence for generated IDs
+    sequence: u32,
+    sync_id: u64,
+}
+
+impl Store {
+    /// Seed a workspace with two teams and `issue_count` issues
+    pub fn seeded(issue_count: u32) -> Self {
+        let mut store = Store {
+            entities: Vec::new(),
+            sequence: 1000,
+            sync_id: 1,
+        };
+
+        let users: Vec<String> = (1..=5).map(|n| uuid(EntityType::User, n)).collect();
+        for n in 1..=5u32 {
+            store.insert(
+                EntityType::User,
+                json!({
+                    "id": uuid(EntityType::User, n),
+                    "name": format!("Test User {}", n),
+                    "displayName": format!("User {}", n),
+                    "email": format!("user{}@example.com", n),
+                    "active": true,
+                    "admin": n == 1,
+                    "guest": false,
+                    "isMe": n == 1,
+                }),
+            );
+        }
+
+        let teams = [("ENG", "Engineering"), ("DES", "Design")];
+        for (i, (key, name)) in teams.iter().enumerate() {
+            let n = i as u32 + 1;
+            store.insert(
+                EntityType::Team,
+                json!({
+                    "id": uuid(EntityType::Team, n),
+                    "key": key,
+                    "name": name,
+                    "description": format!("Team {} description", n),
+                    "private": false,
+                    "memberIds": users,
+                    "issueCount": 0,
+                }),
+            );
+
+            let states = [
+                ("Backlog", "backlog", "#bec2c8"),
+                ("Todo", "unstarted", "#e2e2e2"),
+                ("In Progress", "started", "#f2c94c"),
+                ("In Review", "started", "#6fcf97"),
+                ("Done", "completed", "#27ae60"),
+                ("Canceled", "canceled", "#95a2b3"),
+            ];
+            for (j, (state_name, state_type, color)) in states.iter().enumerate() {
+                store.insert(
+                    EntityType::WorkflowState,
+                    json!({
+                        "id": uuid(EntityType::WorkflowState, n * 10 + j as u32),
+                        "name": state_name,
+                        "type": state_type,
+                        "color": color,
+                        "position": j as f64,
+                        "teamId": uuid(EntityType::Team, n),
+                    }),
+                );
+            }
+
+            store.insert(
+                EntityType::Cycle,
+                json!({
+                    "id": uuid(EntityType::Cycle, n),
+                    "number": 1,
+                    "name": format!("{} Sprint 1", key),
+                    "startsAt": "2025-01-06T00:00:00.000Z",
+                    "endsAt": "2025-01-20T00:00:00.000Z",
+                    "progress": 0.4,
+                    "teamId": uuid(EntityType::Team, n),
+                }),
+            );
+        }
+
+        let labels = [
+            ("Bug", "#FF5630"),
+            ("Feature", "#36B37E"),
+            ("Documentation", "#0065FF"),
+            ("Question", "#FFAB00"),
+        ];
+        for (i, (name, color)) in labels.iter().enumerate() {
+            let n = i as u32 + 1;
+            store.insert(
+                EntityType::IssueLabel,
+                json!({
+                    "id": uuid(EntityType::IssueLabel, n),
+                    "name": name,
+                    "color": color,
+                    "description": format!("Label {} description", n),
+                    "isGroup": false,
+                    "teamId": null,
+                }),
+            );
+        }
+
+        for n in 1..=2u32 {
+            store.insert(
+                EntityType::Project,
+                json!({
+                    "id": uuid(EntityType::Project, n),
+                    "name": format!("Project {}", n),
+                    "description": format!("Description for project {}", n),
+                    "slugId": format!("project-{}", n),
+                    "state": if n == 1 { "started" } else { "planned" },
+                    "progress": 0.25 * n as f64,
+                    "leadId": uuid(EntityType::User, n),
+                    "teamIds": [uuid(EntityType::Team, 1)],
+                    "memberIds": [uuid(EntityType::User, n)],
+                }),
+            );
+        }
+
+        for n in 1..=issue_count {
+            let state = match n % 5 {
+                0 => 10,
+                1 => 11,
+                2 => 12,
+                3 => 13,
+                _ => 14,
+            };
+            store.insert(EntityType::Issue, json!({
+                "id": uuid(EntityType::Issue, n),
+                "identifier": format!("ENG-{}", n),
+                "number": n,
+                "title": format!("Test Issue {}", n),
+                "description": format!("Description for test issue {}", n),
+                "priority": n % 5,
+                "priorityLabel": priority_label(n % 5),
+                "estimate": null,
+                "teamId": uuid(EntityType::Team, 1),
+                "stateId": uuid(EntityType::WorkflowState, state),
+                "assigneeId": if n % 3 == 0 { Value::Null } else { json!(uuid(EntityType::User, n % 5 + 1)) },
+                "creatorId": uuid(EntityType::User, 1),
+                "projectId": if n % 2 == 0 { json!(uuid(EntityType::Project, 1)) } else { Value::Null },
+                "cycleId": if n % 4 == 0 { json!(uuid(EntityType::Cycle, 1)) } else { Value::Null },
+                "parentId": null,
+                "labelIds": [uuid(EntityType::IssueLabel, n % 4 + 1)],
+                "subscriberIds": [],
+                "url": format!("https://linear.app/fake/issue/ENG-{}", n),
+            }));
+
+            if n % 2 == 1 {
+                store.insert(
+                    EntityType::Comment,
+                    json!({
+                        "id": uuid(EntityType::Comment, n),
+                        "body": format!("This is test comment {}", n),
+                        "issueId": uuid(EntityType::Issue, n),
+                        "userId": uuid(EntityType::User, n % 5 + 1),
+                        "parentId": null,
+                    }),
+                );
+            }
+        }
+        if let Some(team) = store.get_mut(EntityType::Team, &uuid(EntityType::Team, 1)) {
+            team["issueCount"] = json!(issue_count);
+        }
+
+        store
+    }
+
+    /// Entities of a type, in creation order
+    pub fn all(&self, entity_type: EntityType) -> impl Iterator<Item = &Value> {
+        self.entities
+            .iter()
+            .filter(move |(t, _)| *t == entity_type)
+            .map(|(_, entity)| entity)
+    }
+
+    /// Find by ID, or by identifier (issues) / key (teams)
+    pub fn find(&self, entity_type: EntityType, id: &str) -> Option<&Value> {
+        self.all(entity_type).find(|entity| {
+            entity["id"] == id
+                || entity["identifier"]
+                    .as_str()
+                    .is_some_and(|i| i.eq_ignore_ascii_case(id))
+                || (entity_type == EntityType::Team
+                    && entity["key"]
+                        .as_str()
+                        .is_some_and(|k| k.eq_ignore_ascii_case(id)))
+        })
+    }
+
+    fn get_mut(&mut self, entity_type: EntityType, id: &str) -> Option<&mut Value> {
+        let id = self.find(entity_type, id)?["id"].clone();
+        self.entities
+            .iter_mut()
+            .find(|(t, entity)| *t == entity_type && entity["id"] == id)
+            .map(|(_, entity)| entity)
+    }
+
+    fn insert(&mut self, entity_type: EntityType, mut entity: Value) {
+        for (key, value) in [
+            ("createdAt", FIXED_TIMESTAMP),
+            ("updatedAt", FIXED_UPDATED_AT),
+        ] {
+            if entity.get(key).is_none() {
+                entity[key] = json!(value);
+            }
+        }
+        if entity.get("archivedAt").is_none() {
+            entity["archivedAt"] = Value::Null;
+        }
+        self.entities.push((entity_type, entity));
+    }
+
+    /// Last sync ID, bumped by every mutation
+    pub fn sync_id(&self) -> u64 {
+        self.sync_id
+    }
+
+    /// Create an entity from a mutation input
+    pub fn create(&mut self, entity_type: EntityType, input: &Value) -> Result<Value, String> {
+        let input = input.as_object().ok_or("input must be an object")?;
+        self.sequence += 1;
+        let now = Utc::now().to_rfc3339();
+
+        let mut entity = json!({
+            "id": input.get("id").cloned().unwrap_or_else(|| json!(uuid(entity_type, self.sequence))),
+            "createdAt": now,
+            "updatedAt": now,
+            "archivedAt": null,
+        });
+        if self
+            .find(entity_type, entity["id"].as_str().unwrap_or_default())
+            .is_some()
+        {
+            return Err(format!(
+                "{} with this id already exists",
+                entity_type.type_name()
+            ));
+        }
+        for (key, value) in input {
+            entity[key] = value.clone();
+        }
+
+        if entity_type == EntityType::Issue {
+            let team_id = input
+                .get("teamId")
+                .and_then(|v| v.as_str())
+                .ok_or("Argument Validation Error: teamId is required")?;
+            let team = self
+                .find(EntityType::Team, team_id)
+                .ok_or_else(|| format!("Team not found: {}", team_id))?
+                .clone();
+            let number = team["issueCount"].as_u64().unwrap_or(0) + 1;
+            entity["teamId"] = team["id"].clone();
+            entity["number"] = json!(number);
+            entity["identifier"] = json!(format!(
+                "{}-{}",
+                team["key"].as_str().unwrap_or("ENG"),
+                number
+            ));
+            entity["url"] = json!(format!(
+                "https://linear.app/fake/issue/{}",
+                entity["identifier"].as_str().unwrap_or_default()
+            ));
+            let priority = entity["priority"].as_u64().unwrap_or(0);
+            entity["priority"] = json!(priority);
+            entity["priorityLabel"] = json!(priority_label(priority as u32));
+            if entity.get("stateId").is_none() {
+                // New issues start in the team's first unstarted state
+                entity["stateId"] = self
+                    .all(EntityType::WorkflowState)
+                    .find(|s| s["teamId"] == team["id"] && s["type"] == "unstarted")
+                    .map(|s| s["id"].clone())
+                    .unwrap_or(Value::Null);
+            }
+            if let Some(team) = self.get_mut(EntityType::Team, team_id) {
+                team["issueCount"] = json!(number);
+            }
+        }
+
+        self.sync_id += 1;
+        self.entities.push((entity_type, entity.clone()));
+        Ok(entity)
+    }
+
+    /// Merge a mutation input into an entity
+    pub fn update(
+        &mut self,
+        entity_type: EntityType,
+        id: &str,
+        input: &Value,
+    ) -> Result<Value, String> {
+        let input = input.as_object().ok_or("input must be an object")?.clone();
+        let entity = self
+            .get_mut(entity_type, id)
+            .ok_or_else(|| not_found(entity_type, id))?;
+        for (key, value) in input {
+            if key != "id" {
+                entity[&key] = value;
+            }
+        }
+        if entity_type == EntityType::Issue {
+            let priority = entity["priority"].as_u64().unwrap_or(0);
+            entity["priorityLabel"] = json!(priority_label(priority as u32));
+        }
+        entity["updatedAt"] = json!(Utc::now().to_rfc3339());
+        let entity = entity.clone();
+        self.sync_id += 1;
+        Ok(entity)
+    }
+
+    /// Set or clear `archivedAt`
+    pub fn set_archived(
+        &mut self,
+        entity_type: EntityType,
+        id: &str,
+        archived: bool,
+    ) -> Result<Value, String> {
+        let entity = self
+            .get_mut(entity_type, id)
+            .ok_or_else(|| not_found(entity_type, id))?;
+        let now = Utc::now().to_rfc3339();
+        entity["archivedAt"] = if archived { json!(now) } else { Value::Null };
+        entity["updatedAt"] = json!(now);
+        let entity = entity.clone();
+        self.sync_id += 1;
+        Ok(entity)
+    }
+
+    /// Delete an entity (issues go to the trash and stay restorable)
+    pub fn delete(&mut self, entity_type: EntityType, id: &str) -> Result<Value, String> {
+        if entity_type == EntityType::Issue {
+            let entity = self.set_archived(entity_type, id, true)?;
+            if let Some(issue) = self.get_mut(entity_type, id) {
+                issue["trashed"] = json!(true);
+            }
+            return Ok(entity);
+        }
+        let id = self
+            .find(entity_type, id)
+            .ok_or_else(|| not_found(entity_type, id))?["id"]
+            .clone();
+        let index = self
+            .entities
+            .iter()
+            .position(|(t, entity)| *t == entity_type && entity["id"] == id)
+            .ok_or_else(|| not_found(entity_type, id.as_str().unwrap_or_default()))?;
+        self.sync_id += 1;
+        Ok(self.entities.remove(index).1)
+    }
+}
+
+/// Deterministic UUID, same format as the test factories
+pub fn uuid(entity_type: EntityType, num: u32) -> String {
+    format!(
+        "550e8400-e29b-41d4-a716-{}{:09}",
+        entity_type.id_prefix(),
+        num
+    )
+}
+
+pub fn not_found(entity_type: EntityType, id: &str) -> String {
+    format!(
+        "Entity not found: {} - Could not find referenced {}.",
+        id,
+        entity_type.type_name()
+    )
+}
+
+fn priority_label(priority: u32) -> &'static str {
+    match priority {
+        1 => "Urgent",
+        2 => "High",
+        3 => "Medium",
+        4 => "Low",
+        _ => "No priority",
+    }
+}
@@ -0,0 +1,4232 @@
+//! Generated field type registry - DO NOT EDIT
+//! Run `cargo xtask codegen` to regenerate
+
+/// Shape of a field's value
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldKind {
+    /// Scalar or enum value
+    Scalar,
+    /// Object, interface or union value (single or list)
+    Object,
+    /// Paginated connection (`nodes`/`edges` repeat per page entry)
+    Connection,
+}
+
+/// (parent type, field, kind, named field type), sorted by parent type then field
+static FIELD_TYPES: &[(&str, &str, FieldKind, &str)] = &[
+    ("ActorBot", "avatarUrl", FieldKind::Scalar, "String"),
+    ("ActorBot", "id", FieldKind::Scalar, "ID"),
+    ("ActorBot", "name", FieldKind::Scalar, "String"),
+    ("ActorBot", "subType", FieldKind::Scalar, "String"),
+    ("ActorBot", "type", FieldKind::Scalar, "String"),
+    ("ActorBot", "userDisplayName", FieldKind::Scalar, "String"),
+    ("AgentActivity", "agentSession", FieldKind::Object, "AgentSession"),
+    ("AgentActivity", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("AgentActivity", "content", FieldKind::Object, "AgentActivityContent"),
+    ("AgentActivity", "contextualMetadata", FieldKind::Scalar, "JSON"),
+    ("AgentActivity", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("AgentActivity", "ephemeral", FieldKind::Scalar, "Boolean"),
+    ("AgentActivity", "id", FieldKind::Scalar, "ID"),
+    ("AgentActivity", "signal", FieldKind::Scalar, "AgentActivitySignal"),
+    ("AgentActivity", "signalMetadata", FieldKind::Scalar, "JSON"),
+    ("AgentActivity", "sourceComment", FieldKind::Object, "Comment"),
+    ("AgentActivity", "sourceMetadata", FieldKind::Scalar, "JSON"),
+    ("AgentActivity", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("AgentActivity", "user", FieldKind::Object, "User"),
+    ("AgentActivityActionContent", "action", FieldKind::Scalar, "String"),
+    ("AgentActivityActionContent", "parameter", FieldKind::Scalar, "String"),
+    ("AgentActivityActionContent", "result", FieldKind::Scalar, "String"),
+    ("AgentActivityActionContent", "type", FieldKind::Scalar, "AgentActivityType"),
+    ("AgentActivityConnection", "edges", FieldKind::Object, "AgentActivityEdge"),
+    ("AgentActivityConnection", "nodes", FieldKind::Object, "AgentActivity"),
+    ("AgentActivityConnection", "pageInfo", FieldKind::Object, "PageInfo"),
+    ("AgentActivityEdge", "cursor", FieldKind::Scalar, "String"),
+    ("AgentActivityEdge", "node", FieldKind::Object, "AgentActivity"),
+    ("AgentActivityElicitationContent", "body", FieldKind::Scalar, "String"),
+    ("AgentActivityElicitationContent", "type", FieldKind::Scalar, "AgentActivityType"),
+    ("AgentActivityErrorContent", "body", FieldKind::Scalar, "String"),
+    ("AgentActivityErrorContent", "type", FieldKind::Scalar, "AgentActivityType"),
+    ("AgentActivityPayload", "agentActivity", FieldKind::Object, "AgentActivity"),
+    ("AgentActivityPayload", "lastSyncId", FieldKind::Scalar, "Float"),
+    ("AgentActivityPayload", "success", FieldKind::Scalar, "Boolean"),
+    ("AgentActivityPromptContent", "body", FieldKind::Scalar, "String"),
+    ("AgentActivityPromptContent", "type", FieldKind::Scalar, "AgentActivityType"),
+    ("AgentActivityResponseContent", "body", FieldKind::Scalar, "String"),
+    ("AgentActivityResponseContent", "type", FieldKind::Scalar, "AgentActivityType"),
+    ("AgentActivityThoughtContent", "body", FieldKind::Scalar, "String"),
+    ("AgentActivityThoughtContent", "type", FieldKind::Scalar, "AgentActivityType"),
+    ("AgentActivityWebhookPayload", "agentSessionId", FieldKind::Scalar, "String"),
+    ("AgentActivityWebhookPayload", "archivedAt", FieldKind::Scalar, "String"),
+    ("AgentActivityWebhookPayload", "content", FieldKind::Scalar, "JSONObject"),
+    ("AgentActivityWebhookPayload", "createdAt", FieldKind::Scalar, "String"),
+    ("AgentActivityWebhookPayload", "id", FieldKind::Scalar, "String"),
+    ("AgentActivityWebhookPayload", "signal", FieldKind::Scalar, "String"),
+    ("AgentActivityWebhookPayload", "signalMetadata", FieldKind::Scalar, "JSONObject"),
+    ("AgentActivityWebhookPayload", "sourceCommentId", FieldKind::Scalar, "String"),
+    ("AgentActivityWebhookPayload", "updatedAt", FieldKind::Scalar, "String"),
+    ("AgentActivityWebhookPayload", "userId", FieldKind::Scalar, "String"),
+    ("AgentSession", "activities", FieldKind::Connection, "AgentActivityConnection"),
+    ("AgentSession", "appUser", FieldKind::Object, "User"),
+    ("AgentSession", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("AgentSession", "comment", FieldKind::Object, "Comment"),
+    ("AgentSession", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("AgentSession", "creator", FieldKind::Object, "User"),
+    ("AgentSession", "dismissedAt", FieldKind::Scalar, "DateTime"),
+    ("AgentSession", "dismissedBy", FieldKind::Object, "User"),
+    ("AgentSession", "endedAt", FieldKind::Scalar, "DateTime"),
+    ("AgentSession", "externalLink", FieldKind::Scalar, "String"),
+    ("AgentSession", "externalUrls", FieldKind::Scalar, "JSON"),
+    ("AgentSession", "id", FieldKind::Scalar, "ID"),
+    ("AgentSession", "issue", FieldKind::Object, "Issue"),
+    ("AgentSession", "plan", FieldKind::Scalar, "JSON"),
+    ("AgentSession", "promptContext", FieldKind::Scalar, "String"),
+    ("AgentSession", "pullRequests", FieldKind::Connection, "AgentSessionToPullRequestConnection"),
+    ("AgentSession", "sourceComment", FieldKind::Object, "Comment"),
+    ("AgentSession", "sourceMetadata", FieldKind::Scalar, "JSON"),
+    ("AgentSession", "startedAt", FieldKind::Scalar, "DateTime"),
+    ("AgentSession", "status", FieldKind::Scalar, "AgentSessionStatus"),
+    ("AgentSession", "summary", FieldKind::Scalar, "String"),
+    ("AgentSession", "type", FieldKind::Scalar, "AgentSessionType"),
+    ("AgentSession", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("AgentSessionConnection", "edges", FieldKind::Object, "AgentSessionEdge"),
+    ("AgentSessionConnection", "nodes", FieldKind::Object, "AgentSession"),
+    ("AgentSessionConnection", "pageInfo", FieldKind::Object, "PageInfo"),
+    ("AgentSessionEdge", "cursor", FieldKind::Scalar, "String"),
+    ("AgentSessionEdge", "node", FieldKind::Object, "AgentSession"),
+    ("AgentSessionEventWebhookPayload", "action", FieldKind::Scalar, "String"),
+    ("AgentSessionEventWebhookPayload", "agentActivity", FieldKind::Object, "AgentActivityWebhookPayload"),
+    ("AgentSessionEventWebhookPayload", "agentSession", FieldKind::Object, "AgentSessionWebhookPayload"),
+    ("AgentSessionEventWebhookPayload", "appUserId", FieldKind::Scalar, "String"),
+    ("AgentSessionEventWebhookPayload", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("AgentSessionEventWebhookPayload", "guidance", FieldKind::Object, "GuidanceRuleWebhookPayload"),
+    ("AgentSessionEventWebhookPayload", "oauthClientId", FieldKind::Scalar, "String"),
+    ("AgentSessionEventWebhookPayload", "organizationId", FieldKind::Scalar, "String"),
+    ("AgentSessionEventWebhookPayload", "previousComments", FieldKind::Object, "CommentChildWebhookPayload"),
+    ("AgentSessionEventWebhookPayload", "promptContext", FieldKind::Scalar, "String"),
+    ("AgentSessionEventWebhookPayload", "type", FieldKind::Scalar, "String"),
+    ("AgentSessionEventWebhookPayload", "webhookId", FieldKind::Scalar, "String"),
+    ("AgentSessionEventWebhookPayload", "webhookTimestamp", FieldKind::Scalar, "Float"),
+    ("AgentSessionPayload", "agentSession", FieldKind::Object, "AgentSession"),
+    ("AgentSessionPayload", "lastSyncId", FieldKind::Scalar, "Float"),
+    ("AgentSessionPayload", "success", FieldKind::Scalar, "Boolean"),
+    ("AgentSessionToPullRequest", "agentSession", FieldKind::Object, "AgentSession"),
+    ("AgentSessionToPullRequest", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("AgentSessionToPullRequest", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("AgentSessionToPullRequest", "id", FieldKind::Scalar, "ID"),
+    ("AgentSessionToPullRequest", "pullRequest", FieldKind::Object, "PullRequest"),
+    ("AgentSessionToPullRequest", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("AgentSessionToPullRequestConnection", "edges", FieldKind::Object, "AgentSessionToPullRequestEdge"),
+    ("AgentSessionToPullRequestConnection", "nodes", FieldKind::Object, "AgentSessionToPullRequest"),
+    ("AgentSessionToPullRequestConnection", "pageInfo", FieldKind::Object, "PageInfo"),
+    ("AgentSessionToPullRequestEdge", "cursor", FieldKind::Scalar, "String"),
+    ("AgentSessionToPullRequestEdge", "node", FieldKind::Object, "AgentSessionToPullRequest"),
+    ("AgentSessionWebhookPayload", "appUserId", FieldKind::Scalar, "String"),
+    ("AgentSessionWebhookPayload", "archivedAt", FieldKind::Scalar, "String"),
+    ("AgentSessionWebhookPayload", "comment", FieldKind::Object, "CommentChildWebhookPayload"),
+    ("AgentSessionWebhookPayload", "commentId", FieldKind::Scalar, "String"),
+    ("AgentSessionWebhookPayload", "createdAt", FieldKind::Scalar, "String"),
+    ("AgentSessionWebhookPayload", "creator", FieldKind::Object, "UserChildWebhookPayload"),
+    ("AgentSessionWebhookPayload", "creatorId", FieldKind::Scalar, "String"),
+    ("AgentSessionWebhookPayload", "endedAt", FieldKind::Scalar, "String"),
+    ("AgentSessionWebhookPayload", "id", FieldKind::Scalar, "String"),
+    ("AgentSessionWebhookPayload", "issue", FieldKind::Object, "IssueWithDescriptionChildWebhookPayload"),
+    ("AgentSessionWebhookPayload", "issueId", FieldKind::Scalar, "String"),
+    ("AgentSessionWebhookPayload", "organizationId", FieldKind::Scalar, "String"),
+    ("AgentSessionWebhookPayload", "sourceCommentId", FieldKind::Scalar, "String"),
+    ("AgentSessionWebhookPayload", "sourceMetadata", FieldKind::Scalar, "JSONObject"),
+    ("AgentSessionWebhookPayload", "startedAt", FieldKind::Scalar, "String"),
+    ("AgentSessionWebhookPayload", "status", FieldKind::Scalar, "String"),
+    ("AgentSessionWebhookPayload", "summary", FieldKind::Scalar, "String"),
+    ("AgentSessionWebhookPayload", "type", FieldKind::Scalar, "String"),
+    ("AgentSessionWebhookPayload", "updatedAt", FieldKind::Scalar, "String"),
+    ("AiPromptRules", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("AiPromptRules", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("AiPromptRules", "id", FieldKind::Scalar, "ID"),
+    ("AiPromptRules", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("AiPromptRules", "updatedBy", FieldKind::Object, "User"),
+    ("AppUserNotificationWebhookPayload", "action", FieldKind::Scalar, "String"),
+    ("AppUserNotificationWebhookPayload", "appUserId", FieldKind::Scalar, "String"),
+    ("AppUserNotificationWebhookPayload", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("AppUserNotificationWebhookPayload", "notification", FieldKind::Object, "NotificationWebhookPayload"),
+    ("AppUserNotificationWebhookPayload", "oauthClientId", FieldKind::Scalar, "String"),
+    ("AppUserNotificationWebhookPayload", "organizationId", FieldKind::Scalar, "String"),
+    ("AppUserNotificationWebhookPayload", "type", FieldKind::Scalar, "String"),
+    ("AppUserNotificationWebhookPayload", "webhookId", FieldKind::Scalar, "String"),
+    ("AppUserNotificationWebhookPayload", "webhookTimestamp", FieldKind::Scalar, "Float"),
+    ("AppUserTeamAccessChangedWebhookPayload", "action", FieldKind::Scalar, "String"),
+    ("AppUserTeamAccessChangedWebhookPayload", "addedTeamIds", FieldKind::Scalar, "String"),
+    ("AppUserTeamAccessChangedWebhookPayload", "appUserId", FieldKind::Scalar, "String"),
+    ("AppUserTeamAccessChangedWebhookPayload", "canAccessAllPublicTeams", FieldKind::Scalar, "Boolean"),
+    ("AppUserTeamAccessChangedWebhookPayload", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("AppUserTeamAccessChangedWebhookPayload", "oauthClientId", FieldKind::Scalar, "String"),
+    ("AppUserTeamAccessChangedWebhookPayload", "organizationId", FieldKind::Scalar, "String"),
+    ("AppUserTeamAccessChangedWebhookPayload", "removedTeamIds", FieldKind::Scalar, "String"),
+    ("AppUserTeamAccessChangedWebhookPayload", "type", FieldKind::Scalar, "String"),
+    ("AppUserTeamAccessChangedWebhookPayload", "webhookId", FieldKind::Scalar, "String"),
+    ("AppUserTeamAccessChangedWebhookPayload", "webhookTimestamp", FieldKind::Scalar, "Float"),
+    ("Application", "clientId", FieldKind::Scalar, "String"),
+    ("Application", "description", FieldKind::Scalar, "String"),
+    ("Application", "developer", FieldKind::Scalar, "String"),
+    ("Application", "developerUrl", FieldKind::Scalar, "String"),
+    ("Application", "id", FieldKind::Scalar, "String"),
+    ("Application", "imageUrl", FieldKind::Scalar, "String"),
+    ("Application", "name", FieldKind::Scalar, "String"),
+    ("ArchivePayload", "lastSyncId", FieldKind::Scalar, "Float"),
+    ("ArchivePayload", "success", FieldKind::Scalar, "Boolean"),
+    ("ArchiveResponse", "archive", FieldKind::Scalar, "String"),
+    ("ArchiveResponse", "databaseVersion", FieldKind::Scalar, "Float"),
+    ("ArchiveResponse", "includesDependencies", FieldKind::Scalar, "String"),
+    ("ArchiveResponse", "totalCount", FieldKind::Scalar, "Float"),
+    ("AsksChannelConnectPayload", "addBot", FieldKind::Scalar, "Boolean"),
+    ("AsksChannelConnectPayload", "integration", FieldKind::Object, "Integration"),
+    ("AsksChannelConnectPayload", "lastSyncId", FieldKind::Scalar, "Float"),
+    ("AsksChannelConnectPayload", "mapping", FieldKind::Object, "SlackChannelNameMapping"),
+    ("AsksChannelConnectPayload", "success", FieldKind::Scalar, "Boolean"),
+    ("AsksWebFormsAuthResponse", "email", FieldKind::Scalar, "String"),
+    ("AsksWebFormsAuthResponse", "name", FieldKind::Scalar, "String"),
+    ("Attachment", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("Attachment", "bodyData", FieldKind::Scalar, "String"),
+    ("Attachment", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("Attachment", "creator", FieldKind::Object, "User"),
+    ("Attachment", "externalUserCreator", FieldKind::Object, "ExternalUser"),
+    ("Attachment", "groupBySource", FieldKind::Scalar, "Boolean"),
+    ("Attachment", "id", FieldKind::Scalar, "ID"),
+    ("Attachment", "issue", FieldKind::Object, "Issue"),
+    ("Attachment", "metadata", FieldKind::Scalar, "JSONObject"),
+    ("Attachment", "originalIssue", FieldKind::Object, "Issue"),
+    ("Attachment", "source", FieldKind::Scalar, "JSONObject"),
+    ("Attachment", "sourceType", FieldKind::Scalar, "String"),
+    ("Attachment", "subtitle", FieldKind::Scalar, "String"),
+    ("Attachment", "title", FieldKind::Scalar, "String"),
+    ("Attachment", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("Attachment", "url", FieldKind::Scalar, "String"),
+    ("AttachmentConnection", "edges", FieldKind::Object, "AttachmentEdge"),
+    ("AttachmentConnection", "nodes", FieldKind::Object, "Attachment"),
+    ("AttachmentConnection", "pageInfo", FieldKind::Object, "PageInfo"),
+    ("AttachmentEdge", "cursor", FieldKind::Scalar, "String"),
+    ("AttachmentEdge", "node", FieldKind::Object, "Attachment"),
+    ("AttachmentPayload", "attachment", FieldKind::Object, "Attachment"),
+    ("AttachmentPayload", "lastSyncId", FieldKind::Scalar, "Float"),
+    ("AttachmentPayload", "success", FieldKind::Scalar, "Boolean"),
+    ("AttachmentSourcesPayload", "sources", FieldKind::Scalar, "JSONObject"),
+    ("AttachmentWebhookPayload", "archivedAt", FieldKind::Scalar, "String"),
+    ("AttachmentWebhookPayload", "createdAt", FieldKind::Scalar, "String"),
+    ("AttachmentWebhookPayload", "creatorId", FieldKind::Scalar, "String"),
+    ("AttachmentWebhookPayload", "externalUserCreatorId", FieldKind::Scalar, "String"),
+    ("AttachmentWebhookPayload", "groupBySource", FieldKind::Scalar, "Boolean"),
+    ("AttachmentWebhookPayload", "id", FieldKind::Scalar, "String"),
+    ("AttachmentWebhookPayload", "issueId", FieldKind::Scalar, "String"),
+    ("AttachmentWebhookPayload", "metadata", FieldKind::Scalar, "JSONObject"),
+    ("AttachmentWebhookPayload", "originalIssueId", FieldKind::Scalar, "String"),
+    ("AttachmentWebhookPayload", "source", FieldKind::Scalar, "JSONObject"),
+    ("AttachmentWebhookPayload", "sourceType", FieldKind::Scalar, "String"),
+    ("AttachmentWebhookPayload", "subtitle", FieldKind::Scalar, "String"),
+    ("AttachmentWebhookPayload", "title", FieldKind::Scalar, "String"),
+    ("AttachmentWebhookPayload", "updatedAt", FieldKind::Scalar, "String"),
+    ("AttachmentWebhookPayload", "url", FieldKind::Scalar, "String"),
+    ("AuditEntry", "actor", FieldKind::Object, "User"),
+    ("AuditEntry", "actorId", FieldKind::Scalar, "String"),
+    ("AuditEntry", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("AuditEntry", "countryCode", FieldKind::Scalar, "String"),
+    ("AuditEntry", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("AuditEntry", "id", FieldKind::Scalar, "ID"),
+    ("AuditEntry", "ip", FieldKind::Scalar, "String"),
+    ("AuditEntry", "metadata", FieldKind::Scalar, "JSONObject"),
+    ("AuditEntry", "organization", FieldKind::Object, "Organization"),
+    ("AuditEntry", "requestInformation", FieldKind::Scalar, "JSONObject"),
+    ("AuditEntry", "type", FieldKind::Scalar, "String"),
+    ("AuditEntry", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("AuditEntryConnection", "edges", FieldKind::Object, "AuditEntryEdge"),
+    ("AuditEntryConnection", "nodes", FieldKind::Object, "AuditEntry"),
+    ("AuditEntryConnection", "pageInfo", FieldKind::Object, "PageInfo"),
+    ("AuditEntryEdge", "cursor", FieldKind::Scalar, "String"),
+    ("AuditEntryEdge", "node", FieldKind::Object, "AuditEntry"),
+    ("AuditEntryType", "description", FieldKind::Scalar, "String"),
+    ("AuditEntryType", "type", FieldKind::Scalar, "String"),
+    ("AuditEntryWebhookPayload", "actorId", FieldKind::Scalar, "String"),
+    ("AuditEntryWebhookPayload", "archivedAt", FieldKind::Scalar, "String"),
+    ("AuditEntryWebhookPayload", "countryCode", FieldKind::Scalar, "String"),
+    ("AuditEntryWebhookPayload", "createdAt", FieldKind::Scalar, "String"),
+    ("AuditEntryWebhookPayload", "id", FieldKind::Scalar, "String"),
+    ("AuditEntryWebhookPayload", "ip", FieldKind::Scalar, "String"),
+    ("AuditEntryWebhookPayload", "metadata", FieldKind::Scalar, "JSONObject"),
+    ("AuditEntryWebhookPayload", "organizationId", FieldKind::Scalar, "String"),
+    ("AuditEntryWebhookPayload", "requestInformation", FieldKind::Scalar, "JSONObject"),
+    ("AuditEntryWebhookPayload", "type", FieldKind::Scalar, "String"),
+    ("AuditEntryWebhookPayload", "updatedAt", FieldKind::Scalar, "String"),
+    ("AuthIdentityProvider", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("AuthIdentityProvider", "defaultMigrated", FieldKind::Scalar, "Boolean"),
+    ("AuthIdentityProvider", "id", FieldKind::Scalar, "ID"),
+    ("AuthIdentityProvider", "issuerEntityId", FieldKind::Scalar, "String"),
+    ("AuthIdentityProvider", "priority", FieldKind::Scalar, "Float"),
+    ("AuthIdentityProvider", "samlEnabled", FieldKind::Scalar, "Boolean"),
+    ("AuthIdentityProvider", "scimEnabled", FieldKind::Scalar, "Boolean"),
+    ("AuthIdentityProvider", "spEntityId", FieldKind::Scalar, "String"),
+    ("AuthIdentityProvider", "ssoBinding", FieldKind::Scalar, "String"),
+    ("AuthIdentityProvider", "ssoEndpoint", FieldKind::Scalar, "String"),
+    ("AuthIdentityProvider", "ssoSignAlgo", FieldKind::Scalar, "String"),
+    ("AuthIdentityProvider", "ssoSigningCert", FieldKind::Scalar, "String"),
+    ("AuthIdentityProvider", "type", FieldKind::Scalar, "IdentityProviderType"),
+    ("AuthOrganization", "allowedAuthServices", FieldKind::Scalar, "String"),
+    ("AuthOrganization", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("AuthOrganization", "deletionRequestedAt", FieldKind::Scalar, "DateTime"),
+    ("AuthOrganization", "enabled", FieldKind::Scalar, "Boolean"),
+    ("AuthOrganization", "id", FieldKind::Scalar, "ID"),
+    ("AuthOrganization", "logoUrl", FieldKind::Scalar, "String"),
+    ("AuthOrganization", "name", FieldKind::Scalar, "String"),
+    ("AuthOrganization", "previousUrlKeys", FieldKind::Scalar, "String"),
+    ("AuthOrganization", "region", FieldKind::Scalar, "String"),
+    ("AuthOrganization", "releaseChannel", FieldKind::Scalar, "ReleaseChannel"),
+    ("AuthOrganization", "samlEnabled", FieldKind::Scalar, "Boolean"),
+    ("AuthOrganization", "samlSettings", FieldKind::Scalar, "JSONObject"),
+    ("AuthOrganization", "scimEnabled", FieldKind::Scalar, "Boolean"),
+    ("AuthOrganization", "serviceId", FieldKind::Scalar, "String"),
+    ("AuthOrganization", "urlKey", FieldKind::Scalar, "String"),
+    ("AuthOrganization", "userCount", FieldKind::Scalar, "Float"),
+    ("AuthResolverResponse", "allowDomainAccess", FieldKind::Scalar, "Boolean"),
+    ("AuthResolverResponse", "availableOrganizations", FieldKind::Object, "AuthOrganization"),
+    ("AuthResolverResponse", "email", FieldKind::Scalar, "String"),
+    ("AuthResolverResponse", "id", FieldKind::Scalar, "String"),
+    ("AuthResolverResponse", "lastUsedOrganizationId", FieldKind::Scalar, "String"),
+    ("AuthResolverResponse", "lockedOrganizations", FieldKind::Object, "AuthOrganization"),
+    ("AuthResolverResponse", "lockedUsers", FieldKind::Object, "AuthUser"),
+    ("AuthResolverResponse", "token", FieldKind::Scalar, "String"),
+    ("AuthResolverResponse", "users", FieldKind::Object, "AuthUser"),
+    ("AuthUser", "active", FieldKind::Scalar, "Boolean"),
+    ("AuthUser", "avatarUrl", FieldKind::Scalar, "String"),
+    ("AuthUser", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("AuthUser", "displayName", FieldKind::Scalar, "String"),
+    ("AuthUser", "email", FieldKind::Scalar, "String"),
+    ("AuthUser", "id", FieldKind::Scalar, "ID"),
+    ("AuthUser", "identityProvider", FieldKind::Object, "AuthIdentityProvider"),
+    ("AuthUser", "name", FieldKind::Scalar, "String"),
+    ("AuthUser", "organization", FieldKind::Object, "AuthOrganization"),
+    ("AuthUser", "role", FieldKind::Scalar, "UserRoleType"),
+    ("AuthUser", "userAccountId", FieldKind::Scalar, "String"),
+    ("AuthenticationSessionResponse", "browserType", FieldKind::Scalar, "String"),
+    ("AuthenticationSessionResponse", "client", FieldKind::Scalar, "String"),
+    ("AuthenticationSessionResponse", "countryCodes", FieldKind::Scalar, "String"),
+    ("AuthenticationSessionResponse", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("AuthenticationSessionResponse", "id", FieldKind::Scalar, "String"),
+    ("AuthenticationSessionResponse", "ip", FieldKind::Scalar, "String"),
+    ("AuthenticationSessionResponse", "isCurrentSession", FieldKind::Scalar, "Boolean"),
+    ("AuthenticationSessionResponse", "lastActiveAt", FieldKind::Scalar, "DateTime"),
+    ("AuthenticationSessionResponse", "location", FieldKind::Scalar, "String"),
+    ("AuthenticationSessionResponse", "locationCity", FieldKind::Scalar, "String"),
+    ("AuthenticationSessionResponse", "locationCountry", FieldKind::Scalar, "String"),
+    ("AuthenticationSessionResponse", "locationCountryCode", FieldKind::Scalar, "String"),
+    ("AuthenticationSessionResponse", "locationRegionCode", FieldKind::Scalar, "String"),
+    ("AuthenticationSessionResponse", "name", FieldKind::Scalar, "String"),
+    ("AuthenticationSessionResponse", "operatingSystem", FieldKind::Scalar, "String"),
+    ("AuthenticationSessionResponse", "service", FieldKind::Scalar, "String"),
+    ("AuthenticationSessionResponse", "type", FieldKind::Scalar, "AuthenticationSessionType"),
+    ("AuthenticationSessionResponse", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("AuthenticationSessionResponse", "userAgent", FieldKind::Scalar, "String"),
+    ("BaseWebhookPayload", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("BaseWebhookPayload", "organizationId", FieldKind::Scalar, "String"),
+    ("BaseWebhookPayload", "webhookId", FieldKind::Scalar, "String"),
+    ("BaseWebhookPayload", "webhookTimestamp", FieldKind::Scalar, "Float"),
+    ("Comment", "agentSession", FieldKind::Object, "AgentSession"),
+    ("Comment", "agentSessions", FieldKind::Connection, "AgentSessionConnection"),
+    ("Comment", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("Comment", "body", FieldKind::Scalar, "String"),
+    ("Comment", "bodyData", FieldKind::Scalar, "String"),
+    ("Comment", "botActor", FieldKind::Object, "ActorBot"),
+    ("Comment", "children", FieldKind::Connection, "CommentConnection"),
+    ("Comment", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("Comment", "createdIssues", FieldKind::Connection, "IssueConnection"),
+    ("Comment", "documentContent", FieldKind::Object, "DocumentContent"),
+    ("Comment", "documentContentId", FieldKind::Scalar, "String"),
+    ("Comment", "editedAt", FieldKind::Scalar, "DateTime"),
+    ("Comment", "externalThread", FieldKind::Object, "SyncedExternalThread"),
+    ("Comment", "externalUser", FieldKind::Object, "ExternalUser"),
+    ("Comment", "id", FieldKind::Scalar, "ID"),
+    ("Comment", "initiativeUpdate", FieldKind::Object, "InitiativeUpdate"),
+    ("Comment", "initiativeUpdateId", FieldKind::Scalar, "String"),
+    ("Comment", "issue", FieldKind::Object, "Issue"),
+    ("Comment", "issueId", FieldKind::Scalar, "String"),
+    ("Comment", "parent", FieldKind::Object, "Comment"),
+    ("Comment", "parentId", FieldKind::Scalar, "String"),
+    ("Comment", "post", FieldKind::Object, "Post"),
+    ("Comment", "projectUpdate", FieldKind::Object, "ProjectUpdate"),
+    ("Comment", "projectUpdateId", FieldKind::Scalar, "String"),
+    ("Comment", "quotedText", FieldKind::Scalar, "String"),
+    ("Comment", "reactionData", FieldKind::Scalar, "JSONObject"),
+    ("Comment", "reactions", FieldKind::Object, "Reaction"),
+    ("Comment", "resolvedAt", FieldKind::Scalar, "DateTime"),
+    ("Comment", "resolvingComment", FieldKind::Object, "Comment"),
+    ("Comment", "resolvingCommentId", FieldKind::Scalar, "String"),
+    ("Comment", "resolvingUser", FieldKind::Object, "User"),
+    ("Comment", "syncedWith", FieldKind::Object, "ExternalEntityInfo"),
+    ("Comment", "threadSummary", FieldKind::Scalar, "JSONObject"),
+    ("Comment", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("Comment", "url", FieldKind::Scalar, "String"),
+    ("Comment", "user", FieldKind::Object, "User"),
+    ("CommentChildWebhookPayload", "body", FieldKind::Scalar, "String"),
+    ("CommentChildWebhookPayload", "documentContentId", FieldKind::Scalar, "String"),
+    ("CommentChildWebhookPayload", "id", FieldKind::Scalar, "String"),
+    ("CommentChildWebhookPayload", "initiativeUpdateId", FieldKind::Scalar, "String"),
+    ("CommentChildWebhookPayload", "issueId", FieldKind::Scalar, "String"),
+    ("CommentChildWebhookPayload", "projectUpdateId", FieldKind::Scalar, "String"),
+    ("CommentChildWebhookPayload", "userId", FieldKind::Scalar, "String"),
+    ("CommentConnection", "edges", FieldKind::Object, "CommentEdge"),
+    ("CommentConnection", "nodes", FieldKind::Object, "Comment"),
+    ("CommentConnection", "pageInfo", FieldKind::Object, "PageInfo"),
+    ("CommentEdge", "cursor", FieldKind::Scalar, "String"),
+    ("CommentEdge", "node", FieldKind::Object, "Comment"),
+    ("CommentPayload", "comment", FieldKind::Object, "Comment"),
+    ("CommentPayload", "lastSyncId", FieldKind::Scalar, "Float"),
+    ("CommentPayload", "success", FieldKind::Scalar, "Boolean"),
+    ("CommentWebhookPayload", "archivedAt", FieldKind::Scalar, "String"),
+    ("CommentWebhookPayload", "body", FieldKind::Scalar, "String"),
+    ("CommentWebhookPayload", "botActor", FieldKind::Scalar, "String"),
+    ("CommentWebhookPayload", "createdAt", FieldKind::Scalar, "String"),
+    ("CommentWebhookPayload", "documentContent", FieldKind::Object, "DocumentContentChildWebhookPayload"),
+    ("CommentWebhookPayload", "documentContentId", FieldKind::Scalar, "String"),
+    ("CommentWebhookPayload", "editedAt", FieldKind::Scalar, "String"),
+    ("CommentWebhookPayload", "externalUser", FieldKind::Object, "ExternalUserChildWebhookPayload"),
+    ("CommentWebhookPayload", "externalUserId", FieldKind::Scalar, "String"),
+    ("CommentWebhookPayload", "id", FieldKind::Scalar, "String"),
+    ("CommentWebhookPayload", "initiativeUpdate", FieldKind::Object, "InitiativeUpdateChildWebhookPayload"),
+    ("CommentWebhookPayload", "initiativeUpdateId", FieldKind::Scalar, "String"),
+    ("CommentWebhookPayload", "issue", FieldKind::Object, "IssueChildWebhookPayload"),
+    ("CommentWebhookPayload", "issueId", FieldKind::Scalar, "String"),
+    ("CommentWebhookPayload", "parent", FieldKind::Object, "CommentChildWebhookPayload"),
+    ("CommentWebhookPayload", "parentId", FieldKind::Scalar, "String"),
+    ("CommentWebhookPayload", "postId", FieldKind::Scalar, "String"),
+    ("CommentWebhookPayload", "projectUpdate", FieldKind::Object, "ProjectUpdateChildWebhookPayload"),
+    ("CommentWebhookPayload", "projectUpdateId", FieldKind::Scalar, "String"),
+    ("CommentWebhookPayload", "quotedText", FieldKind::Scalar, "String"),
+    ("CommentWebhookPayload", "reactionData", FieldKind::Scalar, "JSONObject"),
+    ("CommentWebhookPayload", "resolvedAt", FieldKind::Scalar, "String"),
+    ("CommentWebhookPayload", "resolvingCommentId", FieldKind::Scalar, "String"),
+    ("CommentWebhookPayload", "resolvingUserId", FieldKind::Scalar, "String"),
+    ("CommentWebhookPayload", "syncedWith", FieldKind::Scalar, "JSONObject"),
+    ("CommentWebhookPayload", "updatedAt", FieldKind::Scalar, "String"),
+    ("CommentWebhookPayload", "user", FieldKind::Object, "UserChildWebhookPayload"),
+    ("CommentWebhookPayload", "userId", FieldKind::Scalar, "String"),
+    ("ContactPayload", "success", FieldKind::Scalar, "Boolean"),
+    ("CreateCsvExportReportPayload", "success", FieldKind::Scalar, "Boolean"),
+    ("CreateOrJoinOrganizationResponse", "organization", FieldKind::Object, "AuthOrganization"),
+    ("CreateOrJoinOrganizationResponse", "user", FieldKind::Object, "AuthUser"),
+    ("CustomResourceWebhookPayload", "action", FieldKind::Scalar, "String"),
+    ("CustomResourceWebhookPayload", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("CustomResourceWebhookPayload", "organizationId", FieldKind::Scalar, "String"),
+    ("CustomResourceWebhookPayload", "type", FieldKind::Scalar, "String"),
+    ("CustomResourceWebhookPayload", "webhookId", FieldKind::Scalar, "String"),
+    ("CustomResourceWebhookPayload", "webhookTimestamp", FieldKind::Scalar, "Float"),
+    ("CustomView", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("CustomView", "color", FieldKind::Scalar, "String"),
+    ("CustomView", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("CustomView", "creator", FieldKind::Object, "User"),
+    ("CustomView", "description", FieldKind::Scalar, "String"),
+    ("CustomView", "facet", FieldKind::Object, "Facet"),
+    ("CustomView", "feedItemFilterData", FieldKind::Scalar, "JSONObject"),
+    ("CustomView", "filterData", FieldKind::Scalar, "JSONObject"),
+    ("CustomView", "filters", FieldKind::Scalar, "JSONObject"),
+    ("CustomView", "icon", FieldKind::Scalar, "String"),
+    ("CustomView", "id", FieldKind::Scalar, "ID"),
+    ("CustomView", "initiativeFilterData", FieldKind::Scalar, "JSONObject"),
+    ("CustomView", "initiatives", FieldKind::Connection, "InitiativeConnection"),
+    ("CustomView", "issues", FieldKind::Connection, "IssueConnection"),
+    ("CustomView", "modelName", FieldKind::Scalar, "String"),
+    ("CustomView", "name", FieldKind::Scalar, "String"),
+    ("CustomView", "organization", FieldKind::Object, "Organization"),
+    ("CustomView", "organizationViewPreferences", FieldKind::Object, "ViewPreferences"),
+    ("CustomView", "owner", FieldKind::Object, "User"),
+    ("CustomView", "projectFilterData", FieldKind::Scalar, "JSONObject"),
+    ("CustomView", "projects", FieldKind::Connection, "ProjectConnection"),
+    ("CustomView", "shared", FieldKind::Scalar, "Boolean"),
+    ("CustomView", "slugId", FieldKind::Scalar, "String"),
+    ("CustomView", "team", FieldKind::Object, "Team"),
+    ("CustomView", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("CustomView", "updatedBy", FieldKind::Object, "User"),
+    ("CustomView", "updates", FieldKind::Connection, "FeedItemConnection"),
+    ("CustomView", "userViewPreferences", FieldKind::Object, "ViewPreferences"),
+    ("CustomView", "viewPreferencesValues", FieldKind::Object, "ViewPreferencesValues"),
+    ("CustomViewConnection", "edges", FieldKind::Object, "CustomViewEdge"),
+    ("CustomViewConnection", "nodes", FieldKind::Object, "CustomView"),
+    ("CustomViewConnection", "pageInfo", FieldKind::Object, "PageInfo"),
+    ("CustomViewEdge", "cursor", FieldKind::Scalar, "String"),
+    ("CustomViewEdge", "node", FieldKind::Object, "CustomView"),
+    ("CustomViewHasSubscribersPayload", "hasSubscribers", FieldKind::Scalar, "Boolean"),
+    ("CustomViewNotificationSubscription", "active", FieldKind::Scalar, "Boolean"),
+    ("CustomViewNotificationSubscription", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("CustomViewNotificationSubscription", "contextViewType", FieldKind::Scalar, "ContextViewType"),
+    ("CustomViewNotificationSubscription", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("CustomViewNotificationSubscription", "customView", FieldKind::Object, "CustomView"),
+    ("CustomViewNotificationSubscription", "customer", FieldKind::Object, "Customer"),
+    ("CustomViewNotificationSubscription", "cycle", FieldKind::Object, "Cycle"),
+    ("CustomViewNotificationSubscription", "id", FieldKind::Scalar, "ID"),
+    ("CustomViewNotificationSubscription", "initiative", FieldKind::Object, "Initiative"),
+    ("CustomViewNotificationSubscription", "label", FieldKind::Object, "IssueLabel"),
+    ("CustomViewNotificationSubscription", "notificationSubscriptionTypes", FieldKind::Scalar, "String"),
+    ("CustomViewNotificationSubscription", "project", FieldKind::Object, "Project"),
+    ("CustomViewNotificationSubscription", "subscriber", FieldKind::Object, "User"),
+    ("CustomViewNotificationSubscription", "team", FieldKind::Object, "Team"),
+    ("CustomViewNotificationSubscription", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("CustomViewNotificationSubscription", "user", FieldKind::Object, "User"),
+    ("CustomViewNotificationSubscription", "userContextViewType", FieldKind::Scalar, "UserContextViewType"),
+    ("CustomViewPayload", "customView", FieldKind::Object, "CustomView"),
+    ("CustomViewPayload", "lastSyncId", FieldKind::Scalar, "Float"),
+    ("CustomViewPayload", "success", FieldKind::Scalar, "Boolean"),
+    ("CustomViewSuggestionPayload", "description", FieldKind::Scalar, "String"),
+    ("CustomViewSuggestionPayload", "icon", FieldKind::Scalar, "String"),
+    ("CustomViewSuggestionPayload", "name", FieldKind::Scalar, "String"),
+    ("Customer", "approximateNeedCount", FieldKind::Scalar, "Float"),
+    ("Customer", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("Customer", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("Customer", "domains", FieldKind::Scalar, "String"),
+    ("Customer", "externalIds", FieldKind::Scalar, "String"),
+    ("Customer", "id", FieldKind::Scalar, "ID"),
+    ("Customer", "integration", FieldKind::Object, "Integration"),
+    ("Customer", "logoUrl", FieldKind::Scalar, "String"),
+    ("Customer", "mainSourceId", FieldKind::Scalar, "String"),
+    ("Customer", "name", FieldKind::Scalar, "String"),
+    ("Customer", "owner", FieldKind::Object, "User"),
+    ("Customer", "revenue", FieldKind::Scalar, "Int"),
+    ("Customer", "size", FieldKind::Scalar, "Float"),
+    ("Customer", "slackChannelId", FieldKind::Scalar, "String"),
+    ("Customer", "slugId", FieldKind::Scalar, "String"),
+    ("Customer", "status", FieldKind::Object, "CustomerStatus"),
+    ("Customer", "tier", FieldKind::Object, "CustomerTier"),
+    ("Customer", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("Customer", "url", FieldKind::Scalar, "String"),
+    ("CustomerChildWebhookPayload", "domains", FieldKind::Scalar, "String"),
+    ("CustomerChildWebhookPayload", "externalIds", FieldKind::Scalar, "String"),
+    ("CustomerChildWebhookPayload", "id", FieldKind::Scalar, "String"),
+    ("CustomerChildWebhookPayload", "name", FieldKind::Scalar, "String"),
+    ("CustomerConnection", "edges", FieldKind::Object, "CustomerEdge"),
+    ("CustomerConnection", "nodes", FieldKind::Object, "Customer"),
+    ("CustomerConnection", "pageInfo", FieldKind::Object, "PageInfo"),
+    ("CustomerEdge", "cursor", FieldKind::Scalar, "String"),
+    ("CustomerEdge", "node", FieldKind::Object, "Customer"),
+    ("CustomerNeed", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("CustomerNeed", "attachment", FieldKind::Object, "Attachment"),
+    ("CustomerNeed", "body", FieldKind::Scalar, "String"),
+    ("CustomerNeed", "bodyData", FieldKind::Scalar, "String"),
+    ("CustomerNeed", "comment", FieldKind::Object, "Comment"),
+    ("CustomerNeed", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("CustomerNeed", "creator", FieldKind::Object, "User"),
+    ("CustomerNeed", "customer", FieldKind::Object, "Customer"),
+    ("CustomerNeed", "id", FieldKind::Scalar, "ID"),
+    ("CustomerNeed", "issue", FieldKind::Object, "Issue"),
+    ("CustomerNeed", "originalIssue", FieldKind::Object, "Issue"),
+    ("CustomerNeed", "priority", FieldKind::Scalar, "Float"),
+    ("CustomerNeed", "project", FieldKind::Object, "Project"),
+    ("CustomerNeed", "projectAttachment", FieldKind::Object, "ProjectAttachment"),
+    ("CustomerNeed", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("CustomerNeed", "url", FieldKind::Scalar, "String"),
+    ("CustomerNeedArchivePayload", "entity", FieldKind::Object, "CustomerNeed"),
+    ("CustomerNeedArchivePayload", "lastSyncId", FieldKind::Scalar, "Float"),
+    ("CustomerNeedArchivePayload", "success", FieldKind::Scalar, "Boolean"),
+    ("CustomerNeedChildWebhookPayload", "attachmentId", FieldKind::Scalar, "String"),
+    ("CustomerNeedChildWebhookPayload", "customerId", FieldKind::Scalar, "String"),
+    ("CustomerNeedChildWebhookPayload", "id", FieldKind::Scalar, "String"),
+    ("CustomerNeedChildWebhookPayload", "issueId", FieldKind::Scalar, "String"),
+    ("CustomerNeedChildWebhookPayload", "projectId", FieldKind::Scalar, "String"),
+    ("CustomerNeedConnection", "edges", FieldKind::Object, "CustomerNeedEdge"),
+    ("CustomerNeedConnection", "nodes", FieldKind::Object, "CustomerNeed"),
+    ("CustomerNeedConnection", "pageInfo", FieldKind::Object, "PageInfo"),
+    ("CustomerNeedEdge", "cursor", FieldKind::Scalar, "String"),
+    ("CustomerNeedEdge", "node", FieldKind::Object, "CustomerNeed"),
+    ("CustomerNeedNotification", "actor", FieldKind::Object, "User"),
+    ("CustomerNeedNotification", "actorAvatarColor", FieldKind::Scalar, "String"),
+    ("CustomerNeedNotification", "actorAvatarUrl", FieldKind::Scalar, "String"),
+    ("CustomerNeedNotification", "actorInitials", FieldKind::Scalar, "String"),
+    ("CustomerNeedNotification", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("CustomerNeedNotification", "botActor", FieldKind::Object, "ActorBot"),
+    ("CustomerNeedNotification", "category", FieldKind::Scalar, "NotificationCategory"),
+    ("CustomerNeedNotification", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("CustomerNeedNotification", "customerNeed", FieldKind::Object, "CustomerNeed"),
+    ("CustomerNeedNotification", "customerNeedId", FieldKind::Scalar, "String"),
+    ("CustomerNeedNotification", "emailedAt", FieldKind::Scalar, "DateTime"),
+    ("CustomerNeedNotification", "externalUserActor", FieldKind::Object, "ExternalUser"),
+    ("CustomerNeedNotification", "groupingKey", FieldKind::Scalar, "String"),
+    ("CustomerNeedNotification", "groupingPriority", FieldKind::Scalar, "Float"),
+    ("CustomerNeedNotification", "id", FieldKind::Scalar, "ID"),
+    ("CustomerNeedNotification", "inboxUrl", FieldKind::Scalar, "String"),
+    ("CustomerNeedNotification", "initiativeUpdateHealth", FieldKind::Scalar, "String"),
+    ("CustomerNeedNotification", "isLinearActor", FieldKind::Scalar, "Boolean"),
+    ("CustomerNeedNotification", "issueStatusType", FieldKind::Scalar, "String"),
+    ("CustomerNeedNotification", "projectUpdateHealth", FieldKind::Scalar, "String"),
+    ("CustomerNeedNotification", "readAt", FieldKind::Scalar, "DateTime"),
+    ("CustomerNeedNotification", "relatedIssue", FieldKind::Object, "Issue"),
+    ("CustomerNeedNotification", "relatedProject", FieldKind::Object, "Project"),
+    ("CustomerNeedNotification", "snoozedUntilAt", FieldKind::Scalar, "DateTime"),
+    ("CustomerNeedNotification", "subtitle", FieldKind::Scalar, "String"),
+    ("CustomerNeedNotification", "title", FieldKind::Scalar, "String"),
+    ("CustomerNeedNotification", "type", FieldKind::Scalar, "String"),
+    ("CustomerNeedNotification", "unsnoozedAt", FieldKind::Scalar, "DateTime"),
+    ("CustomerNeedNotification", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("CustomerNeedNotification", "url", FieldKind::Scalar, "String"),
+    ("CustomerNeedNotification", "user", FieldKind::Object, "User"),
+    ("CustomerNeedPayload", "lastSyncId", FieldKind::Scalar, "Float"),
+    ("CustomerNeedPayload", "need", FieldKind::Object, "CustomerNeed"),
+    ("CustomerNeedPayload", "success", FieldKind::Scalar, "Boolean"),
+    ("CustomerNeedUpdatePayload", "lastSyncId", FieldKind::Scalar, "Float"),
+    ("CustomerNeedUpdatePayload", "need", FieldKind::Object, "CustomerNeed"),
+    ("CustomerNeedUpdatePayload", "success", FieldKind::Scalar, "Boolean"),
+    ("CustomerNeedUpdatePayload", "updatedRelatedNeeds", FieldKind::Object, "CustomerNeed"),
+    ("CustomerNeedWebhookPayload", "archivedAt", FieldKind::Scalar, "String"),
+    ("CustomerNeedWebhookPayload", "attachment", FieldKind::Object, "AttachmentWebhookPayload"),
+    ("CustomerNeedWebhookPayload", "attachmentId", FieldKind::Scalar, "String"),
+    ("CustomerNeedWebhookPayload", "body", FieldKind::Scalar, "String"),
+    ("CustomerNeedWebhookPayload", "commentId", FieldKind::Scalar, "String"),
+    ("CustomerNeedWebhookPayload", "createdAt", FieldKind::Scalar, "String"),
+    ("CustomerNeedWebhookPayload", "creatorId", FieldKind::Scalar, "String"),
+    ("CustomerNeedWebhookPayload", "customer", FieldKind::Object, "CustomerChildWebhookPayload"),
+    ("CustomerNeedWebhookPayload", "customerId", FieldKind::Scalar, "String"),
+    ("CustomerNeedWebhookPayload", "id", FieldKind::Scalar, "String"),
+    ("CustomerNeedWebhookPayload", "issue", FieldKind::Object, "IssueChildWebhookPayload"),
+    ("CustomerNeedWebhookPayload", "issueId", FieldKind::Scalar, "String"),
+    ("CustomerNeedWebhookPayload", "originalIssueId", FieldKind::Scalar, "String"),
+    ("CustomerNeedWebhookPayload", "priority", FieldKind::Scalar, "Float"),
+    ("CustomerNeedWebhookPayload", "project", FieldKind::Object, "ProjectChildWebhookPayload"),
+    ("CustomerNeedWebhookPayload", "projectAttachmentId", FieldKind::Scalar, "String"),
+    ("CustomerNeedWebhookPayload", "projectId", FieldKind::Scalar, "String"),
+    ("CustomerNeedWebhookPayload", "updatedAt", FieldKind::Scalar, "String"),
+    ("CustomerNotification", "actor", FieldKind::Object, "User"),
+    ("CustomerNotification", "actorAvatarColor", FieldKind::Scalar, "String"),
+    ("CustomerNotification", "actorAvatarUrl", FieldKind::Scalar, "String"),
+    ("CustomerNotification", "actorInitials", FieldKind::Scalar, "String"),
+    ("CustomerNotification", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("CustomerNotification", "botActor", FieldKind::Object, "ActorBot"),
+    ("CustomerNotification", "category", FieldKind::Scalar, "NotificationCategory"),
+    ("CustomerNotification", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("CustomerNotification", "customer", FieldKind::Object, "Customer"),
+    ("CustomerNotification", "customerId", FieldKind::Scalar, "String"),
+    ("CustomerNotification", "emailedAt", FieldKind::Scalar, "DateTime"),
+    ("CustomerNotification", "externalUserActor", FieldKind::Object, "ExternalUser"),
+    ("CustomerNotification", "groupingKey", FieldKind::Scalar, "String"),
+    ("CustomerNotification", "groupingPriority", FieldKind::Scalar, "Float"),
+    ("CustomerNotification", "id", FieldKind::Scalar, "ID"),
+    ("CustomerNotification", "inboxUrl", FieldKind::Scalar, "String"),
+    ("CustomerNotification", "initiativeUpdateHealth", FieldKind::Scalar, "String"),
+    ("CustomerNotification", "isLinearActor", FieldKind::Scalar, "Boolean"),
+    ("CustomerNotification", "issueStatusType", FieldKind::Scalar, "String"),
+    ("CustomerNotification", "projectUpdateHealth", FieldKind::Scalar, "String"),
+    ("CustomerNotification", "readAt", FieldKind::Scalar, "DateTime"),
+    ("CustomerNotification", "snoozedUntilAt", FieldKind::Scalar, "DateTime"),
+    ("CustomerNotification", "subtitle", FieldKind::Scalar, "String"),
+    ("CustomerNotification", "title", FieldKind::Scalar, "String"),
+    ("CustomerNotification", "type", FieldKind::Scalar, "String"),
+    ("CustomerNotification", "unsnoozedAt", FieldKind::Scalar, "DateTime"),
+    ("CustomerNotification", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("CustomerNotification", "url", FieldKind::Scalar, "String"),
+    ("CustomerNotification", "user", FieldKind::Object, "User"),
+    ("CustomerNotificationSubscription", "active", FieldKind::Scalar, "Boolean"),
+    ("CustomerNotificationSubscription", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("CustomerNotificationSubscription", "contextViewType", FieldKind::Scalar, "ContextViewType"),
+    ("CustomerNotificationSubscription", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("CustomerNotificationSubscription", "customView", FieldKind::Object, "CustomView"),
+    ("CustomerNotificationSubscription", "customer", FieldKind::Object, "Customer"),
+    ("CustomerNotificationSubscription", "cycle", FieldKind::Object, "Cycle"),
+    ("CustomerNotificationSubscription", "id", FieldKind::Scalar, "ID"),
+    ("CustomerNotificationSubscription", "initiative", FieldKind::Object, "Initiative"),
+    ("CustomerNotificationSubscription", "label", FieldKind::Object, "IssueLabel"),
+    ("CustomerNotificationSubscription", "notificationSubscriptionTypes", FieldKind::Scalar, "String"),
+    ("CustomerNotificationSubscription", "project", FieldKind::Object, "Project"),
+    ("CustomerNotificationSubscription", "subscriber", FieldKind::Object, "User"),
+    ("CustomerNotificationSubscription", "team", FieldKind::Object, "Team"),
+    ("CustomerNotificationSubscription", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("CustomerNotificationSubscription", "user", FieldKind::Object, "User"),
+    ("CustomerNotificationSubscription", "userContextViewType", FieldKind::Scalar, "UserContextViewType"),
+    ("CustomerPayload", "customer", FieldKind::Object, "Customer"),
+    ("CustomerPayload", "lastSyncId", FieldKind::Scalar, "Float"),
+    ("CustomerPayload", "success", FieldKind::Scalar, "Boolean"),
+    ("CustomerStatus", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("CustomerStatus", "color", FieldKind::Scalar, "String"),
+    ("CustomerStatus", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("CustomerStatus", "description", FieldKind::Scalar, "String"),
+    ("CustomerStatus", "displayName", FieldKind::Scalar, "String"),
+    ("CustomerStatus", "id", FieldKind::Scalar, "ID"),
+    ("CustomerStatus", "name", FieldKind::Scalar, "String"),
+    ("CustomerStatus", "position", FieldKind::Scalar, "Float"),
+    ("CustomerStatus", "type", FieldKind::Scalar, "CustomerStatusType"),
+    ("CustomerStatus", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("CustomerStatusChildWebhookPayload", "color", FieldKind::Scalar, "String"),
+    ("CustomerStatusChildWebhookPayload", "description", FieldKind::Scalar, "String"),
+    ("CustomerStatusChildWebhookPayload", "displayName", FieldKind::Scalar, "String"),
+    ("CustomerStatusChildWebhookPayload", "id", FieldKind::Scalar, "String"),
+    ("CustomerStatusChildWebhookPayload", "name", FieldKind::Scalar, "String"),
+    ("CustomerStatusChildWebhookPayload", "type", FieldKind::Scalar, "String"),
+    ("CustomerStatusConnection", "edges", FieldKind::Object, "CustomerStatusEdge"),
+    ("CustomerStatusConnection", "nodes", FieldKind::Object, "CustomerStatus"),
+    ("CustomerStatusConnection", "pageInfo", FieldKind::Object, "PageInfo"),
+    ("CustomerStatusEdge", "cursor", FieldKind::Scalar, "String"),
+    ("CustomerStatusEdge", "node", FieldKind::Object, "CustomerStatus"),
+    ("CustomerStatusPayload", "lastSyncId", FieldKind::Scalar, "Float"),
+    ("CustomerStatusPayload", "status", FieldKind::Object, "CustomerStatus"),
+    ("CustomerStatusPayload", "success", FieldKind::Scalar, "Boolean"),
+    ("CustomerTier", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("CustomerTier", "color", FieldKind::Scalar, "String"),
+    ("CustomerTier", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("CustomerTier", "description", FieldKind::Scalar, "String"),
+    ("CustomerTier", "displayName", FieldKind::Scalar, "String"),
+    ("CustomerTier", "id", FieldKind::Scalar, "ID"),
+    ("CustomerTier", "name", FieldKind::Scalar, "String"),
+    ("CustomerTier", "position", FieldKind::Scalar, "Float"),
+    ("CustomerTier", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("CustomerTierChildWebhookPayload", "color", FieldKind::Scalar, "String"),
+    ("CustomerTierChildWebhookPayload", "description", FieldKind::Scalar, "String"),
+    ("CustomerTierChildWebhookPayload", "displayName", FieldKind::Scalar, "String"),
+    ("CustomerTierChildWebhookPayload", "id", FieldKind::Scalar, "String"),
+    ("CustomerTierChildWebhookPayload", "name", FieldKind::Scalar, "String"),
+    ("CustomerTierConnection", "edges", FieldKind::Object, "CustomerTierEdge"),
+    ("CustomerTierConnection", "nodes", FieldKind::Object, "CustomerTier"),
+    ("CustomerTierConnection", "pageInfo", FieldKind::Object, "PageInfo"),
+    ("CustomerTierEdge", "cursor", FieldKind::Scalar, "String"),
+    ("CustomerTierEdge", "node", FieldKind::Object, "CustomerTier"),
+    ("CustomerTierPayload", "lastSyncId", FieldKind::Scalar, "Float"),
+    ("CustomerTierPayload", "success", FieldKind::Scalar, "Boolean"),
+    ("CustomerTierPayload", "tier", FieldKind::Object, "CustomerTier"),
+    ("CustomerWebhookPayload", "approximateNeedCount", FieldKind::Scalar, "Float"),
+    ("CustomerWebhookPayload", "archivedAt", FieldKind::Scalar, "String"),
+    ("CustomerWebhookPayload", "createdAt", FieldKind::Scalar, "String"),
+    ("CustomerWebhookPayload", "domains", FieldKind::Scalar, "String"),
+    ("CustomerWebhookPayload", "externalIds", FieldKind::Scalar, "String"),
+    ("CustomerWebhookPayload", "id", FieldKind::Scalar, "String"),
+    ("CustomerWebhookPayload", "logoUrl", FieldKind::Scalar, "String"),
+    ("CustomerWebhookPayload", "mainSourceId", FieldKind::Scalar, "String"),
+    ("CustomerWebhookPayload", "name", FieldKind::Scalar, "String"),
+    ("CustomerWebhookPayload", "ownerId", FieldKind::Scalar, "String"),
+    ("CustomerWebhookPayload", "revenue", FieldKind::Scalar, "Float"),
+    ("CustomerWebhookPayload", "size", FieldKind::Scalar, "Float"),
+    ("CustomerWebhookPayload", "slackChannelId", FieldKind::Scalar, "String"),
+    ("CustomerWebhookPayload", "slugId", FieldKind::Scalar, "String"),
+    ("CustomerWebhookPayload", "status", FieldKind::Object, "CustomerStatusChildWebhookPayload"),
+    ("CustomerWebhookPayload", "statusId", FieldKind::Scalar, "String"),
+    ("CustomerWebhookPayload", "tier", FieldKind::Object, "CustomerTierChildWebhookPayload"),
+    ("CustomerWebhookPayload", "tierId", FieldKind::Scalar, "String"),
+    ("CustomerWebhookPayload", "updatedAt", FieldKind::Scalar, "String"),
+    ("CustomerWebhookPayload", "url", FieldKind::Scalar, "String"),
+    ("Cycle", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("Cycle", "autoArchivedAt", FieldKind::Scalar, "DateTime"),
+    ("Cycle", "completedAt", FieldKind::Scalar, "DateTime"),
+    ("Cycle", "completedIssueCountHistory", FieldKind::Scalar, "Float"),
+    ("Cycle", "completedScopeHistory", FieldKind::Scalar, "Float"),
+    ("Cycle", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("Cycle", "currentProgress", FieldKind::Scalar, "JSONObject"),
+    ("Cycle", "description", FieldKind::Scalar, "String"),
+    ("Cycle", "endsAt", FieldKind::Scalar, "DateTime"),
+    ("Cycle", "id", FieldKind::Scalar, "ID"),
+    ("Cycle", "inProgressScopeHistory", FieldKind::Scalar, "Float"),
+    ("Cycle", "inheritedFrom", FieldKind::Object, "Cycle"),
+    ("Cycle", "isActive", FieldKind::Scalar, "Boolean"),
+    ("Cycle", "isFuture", FieldKind::Scalar, "Boolean"),
+    ("Cycle", "isNext", FieldKind::Scalar, "Boolean"),
+    ("Cycle", "isPast", FieldKind::Scalar, "Boolean"),
+    ("Cycle", "isPrevious", FieldKind::Scalar, "Boolean"),
+    ("Cycle", "issueCountHistory", FieldKind::Scalar, "Float"),
+    ("Cycle", "issues", FieldKind::Connection, "IssueConnection"),
+    ("Cycle", "name", FieldKind::Scalar, "String"),
+    ("Cycle", "number", FieldKind::Scalar, "Float"),
+    ("Cycle", "progress", FieldKind::Scalar, "Float"),
+    ("Cycle", "progressHistory", FieldKind::Scalar, "JSONObject"),
+    ("Cycle", "scopeHistory", FieldKind::Scalar, "Float"),
+    ("Cycle", "startsAt", FieldKind::Scalar, "DateTime"),
+    ("Cycle", "team", FieldKind::Object, "Team"),
+    ("Cycle", "uncompletedIssuesUponClose", FieldKind::Connection, "IssueConnection"),
+    ("Cycle", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("CycleArchivePayload", "entity", FieldKind::Object, "Cycle"),
+    ("CycleArchivePayload", "lastSyncId", FieldKind::Scalar, "Float"),
+    ("CycleArchivePayload", "success", FieldKind::Scalar, "Boolean"),
+    ("CycleChildWebhookPayload", "endsAt", FieldKind::Scalar, "String"),
+    ("CycleChildWebhookPayload", "id", FieldKind::Scalar, "String"),
+    ("CycleChildWebhookPayload", "name", FieldKind::Scalar, "String"),
+    ("CycleChildWebhookPayload", "number", FieldKind::Scalar, "Float"),
+    ("CycleChildWebhookPayload", "startsAt", FieldKind::Scalar, "String"),
+    ("CycleConnection", "edges", FieldKind::Object, "CycleEdge"),
+    ("CycleConnection", "nodes", FieldKind::Object, "Cycle"),
+    ("CycleConnection", "pageInfo", FieldKind::Object, "PageInfo"),
+    ("CycleEdge", "cursor", FieldKind::Scalar, "String"),
+    ("CycleEdge", "node", FieldKind::Object, "Cycle"),
+    ("CycleNotificationSubscription", "active", FieldKind::Scalar, "Boolean"),
+    ("CycleNotificationSubscription", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("CycleNotificationSubscription", "contextViewType", FieldKind::Scalar, "ContextViewType"),
+    ("CycleNotificationSubscription", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("CycleNotificationSubscription", "customView", FieldKind::Object, "CustomView"),
+    ("CycleNotificationSubscription", "customer", FieldKind::Object, "Customer"),
+    ("CycleNotificationSubscription", "cycle", FieldKind::Object, "Cycle"),
+    ("CycleNotificationSubscription", "id", FieldKind::Scalar, "ID"),
+    ("CycleNotificationSubscription", "initiative", FieldKind::Object, "Initiative"),
+    ("CycleNotificationSubscription", "label", FieldKind::Object, "IssueLabel"),
+    ("CycleNotificationSubscription", "notificationSubscriptionTypes", FieldKind::Scalar, "String"),
+    ("CycleNotificationSubscription", "project", FieldKind::Object, "Project"),
+    ("CycleNotificationSubscription", "subscriber", FieldKind::Object, "User"),
+    ("CycleNotificationSubscription", "team", FieldKind::Object, "Team"),
+    ("CycleNotificationSubscription", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("CycleNotificationSubscription", "user", FieldKind::Object, "User"),
+    ("CycleNotificationSubscription", "userContextViewType", FieldKind::Scalar, "UserContextViewType"),
+    ("CyclePayload", "cycle", FieldKind::Object, "Cycle"),
+    ("CyclePayload", "lastSyncId", FieldKind::Scalar, "Float"),
+    ("CyclePayload", "success", FieldKind::Scalar, "Boolean"),
+    ("CycleWebhookPayload", "archivedAt", FieldKind::Scalar, "String"),
+    ("CycleWebhookPayload", "autoArchivedAt", FieldKind::Scalar, "String"),
+    ("CycleWebhookPayload", "completedAt", FieldKind::Scalar, "String"),
+    ("CycleWebhookPayload", "completedIssueCountHistory", FieldKind::Scalar, "Float"),
+    ("CycleWebhookPayload", "completedScopeHistory", FieldKind::Scalar, "Float"),
+    ("CycleWebhookPayload", "createdAt", FieldKind::Scalar, "String"),
+    ("CycleWebhookPayload", "description", FieldKind::Scalar, "String"),
+    ("CycleWebhookPayload", "endsAt", FieldKind::Scalar, "String"),
+    ("CycleWebhookPayload", "id", FieldKind::Scalar, "String"),
+    ("CycleWebhookPayload", "inProgressScopeHistory", FieldKind::Scalar, "Float"),
+    ("CycleWebhookPayload", "inheritedFromId", FieldKind::Scalar, "String"),
+    ("CycleWebhookPayload", "issueCountHistory", FieldKind::Scalar, "Float"),
+    ("CycleWebhookPayload", "name", FieldKind::Scalar, "String"),
+    ("CycleWebhookPayload", "number", FieldKind::Scalar, "Float"),
+    ("CycleWebhookPayload", "scopeHistory", FieldKind::Scalar, "Float"),
+    ("CycleWebhookPayload", "startsAt", FieldKind::Scalar, "String"),
+    ("CycleWebhookPayload", "teamId", FieldKind::Scalar, "String"),
+    ("CycleWebhookPayload", "uncompletedIssuesUponCloseIds", FieldKind::Scalar, "String"),
+    ("CycleWebhookPayload", "updatedAt", FieldKind::Scalar, "String"),
+    ("Dashboard", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("Dashboard", "color", FieldKind::Scalar, "String"),
+    ("Dashboard", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("Dashboard", "creator", FieldKind::Object, "User"),
+    ("Dashboard", "description", FieldKind::Scalar, "String"),
+    ("Dashboard", "icon", FieldKind::Scalar, "String"),
+    ("Dashboard", "id", FieldKind::Scalar, "ID"),
+    ("Dashboard", "issueFilter", FieldKind::Scalar, "JSONObject"),
+    ("Dashboard", "name", FieldKind::Scalar, "String"),
+    ("Dashboard", "organization", FieldKind::Object, "Organization"),
+    ("Dashboard", "owner", FieldKind::Object, "User"),
+    ("Dashboard", "projectFilter", FieldKind::Scalar, "JSONObject"),
+    ("Dashboard", "shared", FieldKind::Scalar, "Boolean"),
+    ("Dashboard", "slugId", FieldKind::Scalar, "String"),
+    ("Dashboard", "sortOrder", FieldKind::Scalar, "Float"),
+    ("Dashboard", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("Dashboard", "updatedBy", FieldKind::Object, "User"),
+    ("Dashboard", "widgets", FieldKind::Scalar, "JSONObject"),
+    ("DeletePayload", "entityId", FieldKind::Scalar, "String"),
+    ("DeletePayload", "lastSyncId", FieldKind::Scalar, "Float"),
+    ("DeletePayload", "success", FieldKind::Scalar, "Boolean"),
+    ("Document", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("Document", "color", FieldKind::Scalar, "String"),
+    ("Document", "comments", FieldKind::Connection, "CommentConnection"),
+    ("Document", "content", FieldKind::Scalar, "String"),
+    ("Document", "contentState", FieldKind::Scalar, "String"),
+    ("Document", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("Document", "creator", FieldKind::Object, "User"),
+    ("Document", "documentContentId", FieldKind::Scalar, "String"),
+    ("Document", "hiddenAt", FieldKind::Scalar, "DateTime"),
+    ("Document", "icon", FieldKind::Scalar, "String"),
+    ("Document", "id", FieldKind::Scalar, "ID"),
+    ("Document", "initiative", FieldKind::Object, "Initiative"),
+    ("Document", "issue", FieldKind::Object, "Issue"),
+    ("Document", "lastAppliedTemplate", FieldKind::Object, "Template"),
+    ("Document", "project", FieldKind::Object, "Project"),
+    ("Document", "slugId", FieldKind::Scalar, "String"),
+    ("Document", "sortOrder", FieldKind::Scalar, "Float"),
+    ("Document", "team", FieldKind::Object, "Team"),
+    ("Document", "title", FieldKind::Scalar, "String"),
+    ("Document", "trashed", FieldKind::Scalar, "Boolean"),
+    ("Document", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("Document", "updatedBy", FieldKind::Object, "User"),
+    ("Document", "url", FieldKind::Scalar, "String"),
+    ("DocumentArchivePayload", "entity", FieldKind::Object, "Document"),
+    ("DocumentArchivePayload", "lastSyncId", FieldKind::Scalar, "Float"),
+    ("DocumentArchivePayload", "success", FieldKind::Scalar, "Boolean"),
+    ("DocumentChildWebhookPayload", "id", FieldKind::Scalar, "String"),
+    ("DocumentChildWebhookPayload", "initiative", FieldKind::Object, "InitiativeChildWebhookPayload"),
+    ("DocumentChildWebhookPayload", "initiativeId", FieldKind::Scalar, "String"),
+    ("DocumentChildWebhookPayload", "project", FieldKind::Object, "ProjectChildWebhookPayload"),
+    ("DocumentChildWebhookPayload", "projectId", FieldKind::Scalar, "String"),
+    ("DocumentChildWebhookPayload", "title", FieldKind::Scalar, "String"),
+    ("DocumentConnection", "edges", FieldKind::Object, "DocumentEdge"),
+    ("DocumentConnection", "nodes", FieldKind::Object, "Document"),
+    ("DocumentConnection", "pageInfo", FieldKind::Object, "PageInfo"),
+    ("DocumentContent", "aiPromptRules", FieldKind::Object, "AiPromptRules"),
+    ("DocumentContent", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("DocumentContent", "content", FieldKind::Scalar, "String"),
+    ("DocumentContent", "contentState", FieldKind::Scalar, "String"),
+    ("DocumentContent", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("DocumentContent", "document", FieldKind::Object, "Document"),
+    ("DocumentContent", "id", FieldKind::Scalar, "ID"),
+    ("DocumentContent", "initiative", FieldKind::Object, "Initiative"),
+    ("DocumentContent", "issue", FieldKind::Object, "Issue"),
+    ("DocumentContent", "project", FieldKind::Object, "Project"),
+    ("DocumentContent", "projectMilestone", FieldKind::Object, "ProjectMilestone"),
+    ("DocumentContent", "restoredAt", FieldKind::Scalar, "DateTime"),
+    ("DocumentContent", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("DocumentContentChildWebhookPayload", "document", FieldKind::Object, "DocumentChildWebhookPayload"),
+    ("DocumentContentChildWebhookPayload", "project", FieldKind::Object, "ProjectChildWebhookPayload"),
+    ("DocumentContentHistoryPayload", "history", FieldKind::Object, "DocumentContentHistoryType"),
+    ("DocumentContentHistoryPayload", "success", FieldKind::Scalar, "Boolean"),
+    ("DocumentContentHistoryType", "actorIds", FieldKind::Scalar, "String"),
+    ("DocumentContentHistoryType", "contentData", FieldKind::Scalar, "JSON"),
+    ("DocumentContentHistoryType", "contentDataSnapshotAt", FieldKind::Scalar, "DateTime"),
+    ("DocumentContentHistoryType", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("DocumentContentHistoryType", "id", FieldKind::Scalar, "String"),
+    ("DocumentEdge", "cursor", FieldKind::Scalar, "String"),
+    ("DocumentEdge", "node", FieldKind::Object, "Document"),
+    ("DocumentNotification", "actor", FieldKind::Object, "User"),
+    ("DocumentNotification", "actorAvatarColor", FieldKind::Scalar, "String"),
+    ("DocumentNotification", "actorAvatarUrl", FieldKind::Scalar, "String"),
+    ("DocumentNotification", "actorInitials", FieldKind::Scalar, "String"),
+    ("DocumentNotification", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("DocumentNotification", "botActor", FieldKind::Object, "ActorBot"),
+    ("DocumentNotification", "category", FieldKind::Scalar, "NotificationCategory"),
+    ("DocumentNotification", "commentId", FieldKind::Scalar, "String"),
+    ("DocumentNotification", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("DocumentNotification", "documentId", FieldKind::Scalar, "String"),
+    ("DocumentNotification", "emailedAt", FieldKind::Scalar, "DateTime"),
+    ("DocumentNotification", "externalUserActor", FieldKind::Object, "ExternalUser"),
+    ("DocumentNotification", "groupingKey", FieldKind::Scalar, "String"),
+    ("DocumentNotification", "groupingPriority", FieldKind::Scalar, "Float"),
+    ("DocumentNotification", "id", FieldKind::Scalar, "ID"),
+    ("DocumentNotification", "inboxUrl", FieldKind::Scalar, "String"),
+    ("DocumentNotification", "initiativeUpdateHealth", FieldKind::Scalar, "String"),
+    ("DocumentNotification", "isLinearActor", FieldKind::Scalar, "Boolean"),
+    ("DocumentNotification", "issueStatusType", FieldKind::Scalar, "String"),
+    ("DocumentNotification", "parentCommentId", FieldKind::Scalar, "String"),
+    ("DocumentNotification", "projectUpdateHealth", FieldKind::Scalar, "String"),
+    ("DocumentNotification", "reactionEmoji", FieldKind::Scalar, "String"),
+    ("DocumentNotification", "readAt", FieldKind::Scalar, "DateTime"),
+    ("DocumentNotification", "snoozedUntilAt", FieldKind::Scalar, "DateTime"),
+    ("DocumentNotification", "subtitle", FieldKind::Scalar, "String"),
+    ("DocumentNotification", "title", FieldKind::Scalar, "String"),
+    ("DocumentNotification", "type", FieldKind::Scalar, "String"),
+    ("DocumentNotification", "unsnoozedAt", FieldKind::Scalar, "DateTime"),
+    ("DocumentNotification", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("DocumentNotification", "url", FieldKind::Scalar, "String"),
+    ("DocumentNotification", "user", FieldKind::Object, "User"),
+    ("DocumentPayload", "document", FieldKind::Object, "Document"),
+    ("DocumentPayload", "lastSyncId", FieldKind::Scalar, "Float"),
+    ("DocumentPayload", "success", FieldKind::Scalar, "Boolean"),
+    ("DocumentSearchPayload", "archivePayload", FieldKind::Object, "ArchiveResponse"),
+    ("DocumentSearchPayload", "edges", FieldKind::Object, "DocumentSearchResultEdge"),
+    ("DocumentSearchPayload", "nodes", FieldKind::Object, "DocumentSearchResult"),
+    ("DocumentSearchPayload", "pageInfo", FieldKind::Object, "PageInfo"),
+    ("DocumentSearchPayload", "totalCount", FieldKind::Scalar, "Float"),
+    ("DocumentSearchResult", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("DocumentSearchResult", "color", FieldKind::Scalar, "String"),
+    ("DocumentSearchResult", "comments", FieldKind::Connection, "CommentConnection"),
+    ("DocumentSearchResult", "content", FieldKind::Scalar, "String"),
+    ("DocumentSearchResult", "contentState", FieldKind::Scalar, "String"),
+    ("DocumentSearchResult", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("DocumentSearchResult", "creator", FieldKind::Object, "User"),
+    ("DocumentSearchResult", "documentContentId", FieldKind::Scalar, "String"),
+    ("DocumentSearchResult", "hiddenAt", FieldKind::Scalar, "DateTime"),
+    ("DocumentSearchResult", "icon", FieldKind::Scalar, "String"),
+    ("DocumentSearchResult", "id", FieldKind::Scalar, "ID"),
+    ("DocumentSearchResult", "initiative", FieldKind::Object, "Initiative"),
+    ("DocumentSearchResult", "issue", FieldKind::Object, "Issue"),
+    ("DocumentSearchResult", "lastAppliedTemplate", FieldKind::Object, "Template"),
+    ("DocumentSearchResult", "metadata", FieldKind::Scalar, "JSONObject"),
+    ("DocumentSearchResult", "project", FieldKind::Object, "Project"),
+    ("DocumentSearchResult", "slugId", FieldKind::Scalar, "String"),
+    ("DocumentSearchResult", "sortOrder", FieldKind::Scalar, "Float"),
+    ("DocumentSearchResult", "team", FieldKind::Object, "Team"),
+    ("DocumentSearchResult", "title", FieldKind::Scalar, "String"),
+    ("DocumentSearchResult", "trashed", FieldKind::Scalar, "Boolean"),
+    ("DocumentSearchResult", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("DocumentSearchResult", "updatedBy", FieldKind::Object, "User"),
+    ("DocumentSearchResult", "url", FieldKind::Scalar, "String"),
+    ("DocumentSearchResultEdge", "cursor", FieldKind::Scalar, "String"),
+    ("DocumentSearchResultEdge", "node", FieldKind::Object, "DocumentSearchResult"),
+    ("DocumentWebhookPayload", "archivedAt", FieldKind::Scalar, "String"),
+    ("DocumentWebhookPayload", "color", FieldKind::Scalar, "String"),
+    ("DocumentWebhookPayload", "content", FieldKind::Scalar, "String"),
+    ("DocumentWebhookPayload", "createdAt", FieldKind::Scalar, "String"),
+    ("DocumentWebhookPayload", "creatorId", FieldKind::Scalar, "String"),
+    ("DocumentWebhookPayload", "description", FieldKind::Scalar, "String"),
+    ("DocumentWebhookPayload", "hiddenAt", FieldKind::Scalar, "String"),
+    ("DocumentWebhookPayload", "icon", FieldKind::Scalar, "String"),
+    ("DocumentWebhookPayload", "id", FieldKind::Scalar, "String"),
+    ("DocumentWebhookPayload", "initiativeId", FieldKind::Scalar, "String"),
+    ("DocumentWebhookPayload", "lastAppliedTemplateId", FieldKind::Scalar, "String"),
+    ("DocumentWebhookPayload", "projectId", FieldKind::Scalar, "String"),
+    ("DocumentWebhookPayload", "resourceFolderId", FieldKind::Scalar, "String"),
+    ("DocumentWebhookPayload", "slugId", FieldKind::Scalar, "String"),
+    ("DocumentWebhookPayload", "sortOrder", FieldKind::Scalar, "Float"),
+    ("DocumentWebhookPayload", "subscriberIds", FieldKind::Scalar, "String"),
+    ("DocumentWebhookPayload", "title", FieldKind::Scalar, "String"),
+    ("DocumentWebhookPayload", "trashed", FieldKind::Scalar, "Boolean"),
+    ("DocumentWebhookPayload", "updatedAt", FieldKind::Scalar, "String"),
+    ("DocumentWebhookPayload", "updatedById", FieldKind::Scalar, "String"),
+    ("Draft", "anchor", FieldKind::Scalar, "String"),
+    ("Draft", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("Draft", "bodyData", FieldKind::Scalar, "JSON"),
+    ("Draft", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("Draft", "customerNeed", FieldKind::Object, "CustomerNeed"),
+    ("Draft", "data", FieldKind::Scalar, "JSONObject"),
+    ("Draft", "id", FieldKind::Scalar, "ID"),
+    ("Draft", "initiative", FieldKind::Object, "Initiative"),
+    ("Draft", "initiativeUpdate", FieldKind::Object, "InitiativeUpdate"),
+    ("Draft", "isAutogenerated", FieldKind::Scalar, "Boolean"),
+    ("Draft", "issue", FieldKind::Object, "Issue"),
+    ("Draft", "parentComment", FieldKind::Object, "Comment"),
+    ("Draft", "post", FieldKind::Object, "Post"),
+    ("Draft", "project", FieldKind::Object, "Project"),
+    ("Draft", "projectUpdate", FieldKind::Object, "ProjectUpdate"),
+    ("Draft", "team", FieldKind::Object, "Team"),
+    ("Draft", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("Draft", "user", FieldKind::Object, "User"),
+    ("Draft", "wasLocalDraft", FieldKind::Scalar, "Boolean"),
+    ("DraftConnection", "edges", FieldKind::Object, "DraftEdge"),
+    ("DraftConnection", "nodes", FieldKind::Object, "Draft"),
+    ("DraftConnection", "pageInfo", FieldKind::Object, "PageInfo"),
+    ("DraftEdge", "cursor", FieldKind::Scalar, "String"),
+    ("DraftEdge", "node", FieldKind::Object, "Draft"),
+    ("EmailIntakeAddress", "address", FieldKind::Scalar, "String"),
+    ("EmailIntakeAddress", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("EmailIntakeAddress", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("EmailIntakeAddress", "creator", FieldKind::Object, "User"),
+    ("EmailIntakeAddress", "customerRequestsEnabled", FieldKind::Scalar, "Boolean"),
+    ("EmailIntakeAddress", "enabled", FieldKind::Scalar, "Boolean"),
+    ("EmailIntakeAddress", "forwardingEmailAddress", FieldKind::Scalar, "String"),
+    ("EmailIntakeAddress", "id", FieldKind::Scalar, "ID"),
+    ("EmailIntakeAddress", "issueCanceledAutoReply", FieldKind::Scalar, "String"),
+    ("EmailIntakeAddress", "issueCanceledAutoReplyData", FieldKind::Scalar, "JSONObject"),
+    ("EmailIntakeAddress", "issueCanceledAutoReplyEnabled", FieldKind::Scalar, "Boolean"),
+    ("EmailIntakeAddress", "issueCompletedAutoReply", FieldKind::Scalar, "String"),
+    ("EmailIntakeAddress", "issueCompletedAutoReplyData", FieldKind::Scalar, "JSONObject"),
+    ("EmailIntakeAddress", "issueCompletedAutoReplyEnabled", FieldKind::Scalar, "Boolean"),
+    ("EmailIntakeAddress", "issueCreatedAutoReply", FieldKind::Scalar, "String"),
+    ("EmailIntakeAddress", "issueCreatedAutoReplyData", FieldKind::Scalar, "JSONObject"),
+    ("EmailIntakeAddress", "issueCreatedAutoReplyEnabled", FieldKind::Scalar, "Boolean"),
+    ("EmailIntakeAddress", "organization", FieldKind::Object, "Organization"),
+    ("EmailIntakeAddress", "repliesEnabled", FieldKind::Scalar, "Boolean"),
+    ("EmailIntakeAddress", "senderName", FieldKind::Scalar, "String"),
+    ("EmailIntakeAddress", "sesDomainIdentity", FieldKind::Object, "SesDomainIdentity"),
+    ("EmailIntakeAddress", "team", FieldKind::Object, "Team"),
+    ("EmailIntakeAddress", "template", FieldKind::Object, "Template"),
+    ("EmailIntakeAddress", "type", FieldKind::Scalar, "EmailIntakeAddressType"),
+    ("EmailIntakeAddress", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("EmailIntakeAddress", "useUserNamesInReplies", FieldKind::Scalar, "Boolean"),
+    ("EmailIntakeAddressPayload", "emailIntakeAddress", FieldKind::Object, "EmailIntakeAddress"),
+    ("EmailIntakeAddressPayload", "lastSyncId", FieldKind::Scalar, "Float"),
+    ("EmailIntakeAddressPayload", "success", FieldKind::Scalar, "Boolean"),
+    ("EmailUnsubscribePayload", "success", FieldKind::Scalar, "Boolean"),
+    ("EmailUserAccountAuthChallengeResponse", "authType", FieldKind::Scalar, "String"),
+    ("EmailUserAccountAuthChallengeResponse", "success", FieldKind::Scalar, "Boolean"),
+    ("Emoji", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("Emoji", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("Emoji", "creator", FieldKind::Object, "User"),
+    ("Emoji", "id", FieldKind::Scalar, "ID"),
+    ("Emoji", "name", FieldKind::Scalar, "String"),
+    ("Emoji", "organization", FieldKind::Object, "Organization"),
+    ("Emoji", "source", FieldKind::Scalar, "String"),
+    ("Emoji", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("Emoji", "url", FieldKind::Scalar, "String"),
+    ("EmojiConnection", "edges", FieldKind::Object, "EmojiEdge"),
+    ("EmojiConnection", "nodes", FieldKind::Object, "Emoji"),
+    ("EmojiConnection", "pageInfo", FieldKind::Object, "PageInfo"),
+    ("EmojiEdge", "cursor", FieldKind::Scalar, "String"),
+    ("EmojiEdge", "node", FieldKind::Object, "Emoji"),
+    ("EmojiPayload", "emoji", FieldKind::Object, "Emoji"),
+    ("EmojiPayload", "lastSyncId", FieldKind::Scalar, "Float"),
+    ("EmojiPayload", "success", FieldKind::Scalar, "Boolean"),
+    ("Entity", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("Entity", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("Entity", "id", FieldKind::Scalar, "ID"),
+    ("Entity", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("EntityExternalLink", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("EntityExternalLink", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("EntityExternalLink", "creator", FieldKind::Object, "User"),
+    ("EntityExternalLink", "id", FieldKind::Scalar, "ID"),
+    ("EntityExternalLink", "initiative", FieldKind::Object, "Initiative"),
+    ("EntityExternalLink", "label", FieldKind::Scalar, "String"),
+    ("EntityExternalLink", "sortOrder", FieldKind::Scalar, "Float"),
+    ("EntityExternalLink", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("EntityExternalLink", "url", FieldKind::Scalar, "String"),
+    ("EntityExternalLinkConnection", "edges", FieldKind::Object, "EntityExternalLinkEdge"),
+    ("EntityExternalLinkConnection", "nodes", FieldKind::Object, "EntityExternalLink"),
+    ("EntityExternalLinkConnection", "pageInfo", FieldKind::Object, "PageInfo"),
+    ("EntityExternalLinkEdge", "cursor", FieldKind::Scalar, "String"),
+    ("EntityExternalLinkEdge", "node", FieldKind::Object, "EntityExternalLink"),
+    ("EntityExternalLinkPayload", "entityExternalLink", FieldKind::Object, "EntityExternalLink"),
+    ("EntityExternalLinkPayload", "lastSyncId", FieldKind::Scalar, "Float"),
+    ("EntityExternalLinkPayload", "success", FieldKind::Scalar, "Boolean"),
+    ("EntityWebhookPayload", "action", FieldKind::Scalar, "String"),
+    ("EntityWebhookPayload", "actor", FieldKind::Object, "EntityActorWebhookPayload"),
+    ("EntityWebhookPayload", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("EntityWebhookPayload", "data", FieldKind::Object, "DataWebhookPayload"),
+    ("EntityWebhookPayload", "organizationId", FieldKind::Scalar, "String"),
+    ("EntityWebhookPayload", "type", FieldKind::Scalar, "String"),
+    ("EntityWebhookPayload", "updatedFrom", FieldKind::Scalar, "JSONObject"),
+    ("EntityWebhookPayload", "url", FieldKind::Scalar, "String"),
+    ("EntityWebhookPayload", "webhookId", FieldKind::Scalar, "String"),
+    ("EntityWebhookPayload", "webhookTimestamp", FieldKind::Scalar, "Float"),
+    ("ExternalEntityInfo", "id", FieldKind::Scalar, "String"),
+    ("ExternalEntityInfo", "metadata", FieldKind::Object, "ExternalEntityInfoMetadata"),
+    ("ExternalEntityInfo", "service", FieldKind::Scalar, "ExternalSyncService"),
+    ("ExternalEntityInfoGithubMetadata", "number", FieldKind::Scalar, "Float"),
+    ("ExternalEntityInfoGithubMetadata", "owner", FieldKind::Scalar, "String"),
+    ("ExternalEntityInfoGithubMetadata", "repo", FieldKind::Scalar, "String"),
+    ("ExternalEntityInfoJiraMetadata", "issueKey", FieldKind::Scalar, "String"),
+    ("ExternalEntityInfoJiraMetadata", "issueTypeId", FieldKind::Scalar, "String"),
+    ("ExternalEntityInfoJiraMetadata", "projectId", FieldKind::Scalar, "String"),
+    ("ExternalEntitySlackMetadata", "channelId", FieldKind::Scalar, "String"),
+    ("ExternalEntitySlackMetadata", "channelName", FieldKind::Scalar, "String"),
+    ("ExternalEntitySlackMetadata", "isFromSlack", FieldKind::Scalar, "Boolean"),
+    ("ExternalEntitySlackMetadata", "messageUrl", FieldKind::Scalar, "String"),
+    ("ExternalUser", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("ExternalUser", "avatarUrl", FieldKind::Scalar, "String"),
+    ("ExternalUser", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("ExternalUser", "displayName", FieldKind::Scalar, "String"),
+    ("ExternalUser", "email", FieldKind::Scalar, "String"),
+    ("ExternalUser", "id", FieldKind::Scalar, "ID"),
+    ("ExternalUser", "lastSeen", FieldKind::Scalar, "DateTime"),
+    ("ExternalUser", "name", FieldKind::Scalar, "String"),
+    ("ExternalUser", "organization", FieldKind::Object, "Organization"),
+    ("ExternalUser", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("ExternalUserChildWebhookPayload", "email", FieldKind::Scalar, "String"),
+    ("ExternalUserChildWebhookPayload", "id", FieldKind::Scalar, "String"),
+    ("ExternalUserChildWebhookPayload", "name", FieldKind::Scalar, "String"),
+    ("ExternalUserConnection", "edges", FieldKind::Object, "ExternalUserEdge"),
+    ("ExternalUserConnection", "nodes", FieldKind::Object, "ExternalUser"),
+    ("ExternalUserConnection", "pageInfo", FieldKind::Object, "PageInfo"),
+    ("ExternalUserEdge", "cursor", FieldKind::Scalar, "String"),
+    ("ExternalUserEdge", "node", FieldKind::Object, "ExternalUser"),
+    ("Facet", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("Facet", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("Facet", "id", FieldKind::Scalar, "ID"),
+    ("Facet", "sortOrder", FieldKind::Scalar, "Float"),
+    ("Facet", "sourceFeedUser", FieldKind::Object, "User"),
+    ("Facet", "sourceInitiative", FieldKind::Object, "Initiative"),
+    ("Facet", "sourceOrganization", FieldKind::Object, "Organization"),
+    ("Facet", "sourcePage", FieldKind::Scalar, "FacetPageSource"),
+    ("Facet", "sourceProject", FieldKind::Object, "Project"),
+    ("Facet", "sourceTeam", FieldKind::Object, "Team"),
+    ("Facet", "targetCustomView", FieldKind::Object, "CustomView"),
+    ("Facet", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("FacetConnection", "edges", FieldKind::Object, "FacetEdge"),
+    ("FacetConnection", "nodes", FieldKind::Object, "Facet"),
+    ("FacetConnection", "pageInfo", FieldKind::Object, "PageInfo"),
+    ("FacetEdge", "cursor", FieldKind::Scalar, "String"),
+    ("FacetEdge", "node", FieldKind::Object, "Facet"),
+    ("Favorite", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("Favorite", "children", FieldKind::Connection, "FavoriteConnection"),
+    ("Favorite", "color", FieldKind::Scalar, "String"),
+    ("Favorite", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("Favorite", "customView", FieldKind::Object, "CustomView"),
+    ("Favorite", "customer", FieldKind::Object, "Customer"),
+    ("Favorite", "cycle", FieldKind::Object, "Cycle"),
+    ("Favorite", "dashboard", FieldKind::Object, "Dashboard"),
+    ("Favorite", "detail", FieldKind::Scalar, "String"),
+    ("Favorite", "document", FieldKind::Object, "Document"),
+    ("Favorite", "facet", FieldKind::Object, "Facet"),
+    ("Favorite", "folderName", FieldKind::Scalar, "String"),
+    ("Favorite", "icon", FieldKind::Scalar, "String"),
+    ("Favorite", "id", FieldKind::Scalar, "ID"),
+    ("Favorite", "initiative", FieldKind::Object, "Initiative"),
+    ("Favorite", "initiativeTab", FieldKind::Scalar, "InitiativeTab"),
+    ("Favorite", "issue", FieldKind::Object, "Issue"),
+    ("Favorite", "label", FieldKind::Object, "IssueLabel"),
+    ("Favorite", "owner", FieldKind::Object, "User"),
+    ("Favorite", "parent", FieldKind::Object, "Favorite"),
+    ("Favorite", "predefinedViewTeam", FieldKind::Object, "Team"),
+    ("Favorite", "predefinedViewType", FieldKind::Scalar, "String"),
+    ("Favorite", "project", FieldKind::Object, "Project"),
+    ("Favorite", "projectLabel", FieldKind::Object, "ProjectLabel"),
+    ("Favorite", "projectTab", FieldKind::Scalar, "ProjectTab"),
+    ("Favorite", "projectTeam", FieldKind::Object, "Team"),
+    ("Favorite", "pullRequest", FieldKind::Object, "PullRequest"),
+    ("Favorite", "release", FieldKind::Object, "Release"),
+    ("Favorite", "releasePipeline", FieldKind::Object, "ReleasePipeline"),
+    ("Favorite", "sortOrder", FieldKind::Scalar, "Float"),
+    ("Favorite", "title", FieldKind::Scalar, "String"),
+    ("Favorite", "type", FieldKind::Scalar, "String"),
+    ("Favorite", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("Favorite", "url", FieldKind::Scalar, "String"),
+    ("Favorite", "user", FieldKind::Object, "User"),
+    ("FavoriteConnection", "edges", FieldKind::Object, "FavoriteEdge"),
+    ("FavoriteConnection", "nodes", FieldKind::Object, "Favorite"),
+    ("FavoriteConnection", "pageInfo", FieldKind::Object, "PageInfo"),
+    ("FavoriteEdge", "cursor", FieldKind::Scalar, "String"),
+    ("FavoriteEdge", "node", FieldKind::Object, "Favorite"),
+    ("FavoritePayload", "favorite", FieldKind::Object, "Favorite"),
+    ("FavoritePayload", "lastSyncId", FieldKind::Scalar, "Float"),
+    ("FavoritePayload", "success", FieldKind::Scalar, "Boolean"),
+    ("FeedItem", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("FeedItem", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("FeedItem", "id", FieldKind::Scalar, "ID"),
+    ("FeedItem", "initiativeUpdate", FieldKind::Object, "InitiativeUpdate"),
+    ("FeedItem", "organization", FieldKind::Object, "Organization"),
+    ("FeedItem", "post", FieldKind::Object, "Post"),
+    ("FeedItem", "projectUpdate", FieldKind::Object, "ProjectUpdate"),
+    ("FeedItem", "team", FieldKind::Object, "Team"),
+    ("FeedItem", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("FeedItem", "user", FieldKind::Object, "User"),
+    ("FeedItemConnection", "edges", FieldKind::Object, "FeedItemEdge"),
+    ("FeedItemConnection", "nodes", FieldKind::Object, "FeedItem"),
+    ("FeedItemConnection", "pageInfo", FieldKind::Object, "PageInfo"),
+    ("FeedItemEdge", "cursor", FieldKind::Scalar, "String"),
+    ("FeedItemEdge", "node", FieldKind::Object, "FeedItem"),
+    ("FetchDataPayload", "data", FieldKind::Scalar, "JSONObject"),
+    ("FetchDataPayload", "filters", FieldKind::Scalar, "JSONObject"),
+    ("FetchDataPayload", "query", FieldKind::Scalar, "String"),
+    ("FetchDataPayload", "success", FieldKind::Scalar, "Boolean"),
+    ("FileUploadDeletePayload", "success", FieldKind::Scalar, "Boolean"),
+    ("FrontAttachmentPayload", "attachment", FieldKind::Object, "Attachment"),
+    ("FrontAttachmentPayload", "lastSyncId", FieldKind::Scalar, "Float"),
+    ("FrontAttachmentPayload", "success", FieldKind::Scalar, "Boolean"),
+    ("GitAutomationState", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("GitAutomationState", "branchPattern", FieldKind::Scalar, "String"),
+    ("GitAutomationState", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("GitAutomationState", "event", FieldKind::Scalar, "GitAutomationStates"),
+    ("GitAutomationState", "id", FieldKind::Scalar, "ID"),
+    ("GitAutomationState", "state", FieldKind::Object, "WorkflowState"),
+    ("GitAutomationState", "targetBranch", FieldKind::Object, "GitAutomationTargetBranch"),
+    ("GitAutomationState", "team", FieldKind::Object, "Team"),
+    ("GitAutomationState", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("GitAutomationStateConnection", "edges", FieldKind::Object, "GitAutomationStateEdge"),
+    ("GitAutomationStateConnection", "nodes", FieldKind::Object, "GitAutomationState"),
+    ("GitAutomationStateConnection", "pageInfo", FieldKind::Object, "PageInfo"),
+    ("GitAutomationStateEdge", "cursor", FieldKind::Scalar, "String"),
+    ("GitAutomationStateEdge", "node", FieldKind::Object, "GitAutomationState"),
+    ("GitAutomationStatePayload", "gitAutomationState", FieldKind::Object, "GitAutomationState"),
+    ("GitAutomationStatePayload", "lastSyncId", FieldKind::Scalar, "Float"),
+    ("GitAutomationStatePayload", "success", FieldKind::Scalar, "Boolean"),
+    ("GitAutomationTargetBranch", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("GitAutomationTargetBranch", "automationStates", FieldKind::Connection, "GitAutomationStateConnection"),
+    ("GitAutomationTargetBranch", "branchPattern", FieldKind::Scalar, "String"),
+    ("GitAutomationTargetBranch", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("GitAutomationTargetBranch", "id", FieldKind::Scalar, "ID"),
+    ("GitAutomationTargetBranch", "isRegex", FieldKind::Scalar, "Boolean"),
+    ("GitAutomationTargetBranch", "team", FieldKind::Object, "Team"),
+    ("GitAutomationTargetBranch", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("GitAutomationTargetBranchPayload", "lastSyncId", FieldKind::Scalar, "Float"),
+    ("GitAutomationTargetBranchPayload", "success", FieldKind::Scalar, "Boolean"),
+    ("GitAutomationTargetBranchPayload", "targetBranch", FieldKind::Object, "GitAutomationTargetBranch"),
+    ("GitHubCommitIntegrationPayload", "integration", FieldKind::Object, "Integration"),
+    ("GitHubCommitIntegrationPayload", "lastSyncId", FieldKind::Scalar, "Float"),
+    ("GitHubCommitIntegrationPayload", "success", FieldKind::Scalar, "Boolean"),
+    ("GitHubCommitIntegrationPayload", "webhookSecret", FieldKind::Scalar, "String"),
+    ("GitHubEnterpriseServerInstallVerificationPayload", "success", FieldKind::Scalar, "Boolean"),
+    ("GitHubEnterpriseServerPayload", "installUrl", FieldKind::Scalar, "String"),
+    ("GitHubEnterpriseServerPayload", "integration", FieldKind::Object, "Integration"),
+    ("GitHubEnterpriseServerPayload", "lastSyncId", FieldKind::Scalar, "Float"),
+    ("GitHubEnterpriseServerPayload", "setupUrl", FieldKind::Scalar, "String"),
+    ("GitHubEnterpriseServerPayload", "success", FieldKind::Scalar, "Boolean"),
+    ("GitHubEnterpriseServerPayload", "webhookSecret", FieldKind::Scalar, "String"),
+    ("GitLabIntegrationCreatePayload", "integration", FieldKind::Object, "Integration"),
+    ("GitLabIntegrationCreatePayload", "lastSyncId", FieldKind::Scalar, "Float"),
+    ("GitLabIntegrationCreatePayload", "success", FieldKind::Scalar, "Boolean"),
+    ("GitLabIntegrationCreatePayload", "webhookSecret", FieldKind::Scalar, "String"),
+    ("GuidanceRuleWebhookPayload", "body", FieldKind::Scalar, "String"),
+    ("GuidanceRuleWebhookPayload", "origin", FieldKind::Object, "GuidanceRuleOriginWebhookPayload"),
+    ("IdentityProvider", "adminsGroupPush", FieldKind::Scalar, "JSONObject"),
+    ("IdentityProvider", "allowNameChange", FieldKind::Scalar, "Boolean"),
+    ("IdentityProvider", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("IdentityProvider", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("IdentityProvider", "defaultMigrated", FieldKind::Scalar, "Boolean"),
+    ("IdentityProvider", "guestsGroupPush", FieldKind::Scalar, "JSONObject"),
+    ("IdentityProvider", "id", FieldKind::Scalar, "ID"),
+    ("IdentityProvider", "issuerEntityId", FieldKind::Scalar, "String"),
+    ("IdentityProvider", "ownersGroupPush", FieldKind::Scalar, "JSONObject"),
+    ("IdentityProvider", "priority", FieldKind::Scalar, "Float"),
+    ("IdentityProvider", "samlEnabled", FieldKind::Scalar, "Boolean"),
+    ("IdentityProvider", "scimEnabled", FieldKind::Scalar, "Boolean"),
+    ("IdentityProvider", "spEntityId", FieldKind::Scalar, "String"),
+    ("IdentityProvider", "ssoBinding", FieldKind::Scalar, "String"),
+    ("IdentityProvider", "ssoEndpoint", FieldKind::Scalar, "String"),
+    ("IdentityProvider", "ssoSignAlgo", FieldKind::Scalar, "String"),
+    ("IdentityProvider", "ssoSigningCert", FieldKind::Scalar, "String"),
+    ("IdentityProvider", "type", FieldKind::Scalar, "IdentityProviderType"),
+    ("IdentityProvider", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("ImageUploadFromUrlPayload", "lastSyncId", FieldKind::Scalar, "Float"),
+    ("ImageUploadFromUrlPayload", "success", FieldKind::Scalar, "Boolean"),
+    ("ImageUploadFromUrlPayload", "url", FieldKind::Scalar, "String"),
+    ("Initiative", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("Initiative", "color", FieldKind::Scalar, "String"),
+    ("Initiative", "completedAt", FieldKind::Scalar, "DateTime"),
+    ("Initiative", "content", FieldKind::Scalar, "String"),
+    ("Initiative", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("Initiative", "creator", FieldKind::Object, "User"),
+    ("Initiative", "description", FieldKind::Scalar, "String"),
+    ("Initiative", "documentContent", FieldKind::Object, "DocumentContent"),
+    ("Initiative", "documents", FieldKind::Connection, "DocumentConnection"),
+    ("Initiative", "facets", FieldKind::Object, "Facet"),
+    ("Initiative", "frequencyResolution", FieldKind::Scalar, "FrequencyResolutionType"),
+    ("Initiative", "health", FieldKind::Scalar, "InitiativeUpdateHealthType"),
+    ("Initiative", "healthUpdatedAt", FieldKind::Scalar, "DateTime"),
+    ("Initiative", "history", FieldKind::Connection, "InitiativeHistoryConnection"),
+    ("Initiative", "icon", FieldKind::Scalar, "String"),
+    ("Initiative", "id", FieldKind::Scalar, "ID"),
+    ("Initiative", "initiativeUpdates", FieldKind::Connection, "InitiativeUpdateConnection"),
+    ("Initiative", "integrationsSettings", FieldKind::Object, "IntegrationsSettings"),
+    ("Initiative", "lastUpdate", FieldKind::Object, "InitiativeUpdate"),
+    ("Initiative", "links", FieldKind::Connection, "EntityExternalLinkConnection"),
+    ("Initiative", "name", FieldKind::Scalar, "String"),
+    ("Initiative", "organization", FieldKind::Object, "Organization"),
+    ("Initiative", "owner", FieldKind::Object, "User"),
+    ("Initiative", "parentInitiative", FieldKind::Object, "Initiative"),
+    ("Initiative", "projects", FieldKind::Connection, "ProjectConnection"),
+    ("Initiative", "slugId", FieldKind::Scalar, "String"),
+    ("Initiative", "sortOrder", FieldKind::Scalar, "Float"),
+    ("Initiative", "startedAt", FieldKind::Scalar, "DateTime"),
+    ("Initiative", "status", FieldKind::Scalar, "InitiativeStatus"),
+    ("Initiative", "subInitiatives", FieldKind::Connection, "InitiativeConnection"),
+    ("Initiative", "targetDate", FieldKind::Scalar, "TimelessDate"),
+    ("Initiative", "targetDateResolution", FieldKind::Scalar, "DateResolutionType"),
+    ("Initiative", "trashed", FieldKind::Scalar, "Boolean"),
+    ("Initiative", "updateReminderFrequency", FieldKind::Scalar, "Float"),
+    ("Initiative", "updateReminderFrequencyInWeeks", FieldKind::Scalar, "Float"),
+    ("Initiative", "updateRemindersDay", FieldKind::Scalar, "Day"),
+    ("Initiative", "updateRemindersHour", FieldKind::Scalar, "Float"),
+    ("Initiative", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("Initiative", "url", FieldKind::Scalar, "String"),
+    ("InitiativeArchivePayload", "entity", FieldKind::Object, "Initiative"),
+    ("InitiativeArchivePayload", "lastSyncId", FieldKind::Scalar, "Float"),
+    ("InitiativeArchivePayload", "success", FieldKind::Scalar, "Boolean"),
+    ("InitiativeChildWebhookPayload", "id", FieldKind::Scalar, "String"),
+    ("InitiativeChildWebhookPayload", "name", FieldKind::Scalar, "String"),
+    ("InitiativeChildWebhookPayload", "url", FieldKind::Scalar, "String"),
+    ("InitiativeConnection", "edges", FieldKind::Object, "InitiativeEdge"),
+    ("InitiativeConnection", "nodes", FieldKind::Object, "Initiative"),
+    ("InitiativeConnection", "pageInfo", FieldKind::Object, "PageInfo"),
+    ("InitiativeEdge", "cursor", FieldKind::Scalar, "String"),
+    ("InitiativeEdge", "node", FieldKind::Object, "Initiative"),
+    ("InitiativeHistory", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("InitiativeHistory", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("InitiativeHistory", "entries", FieldKind::Scalar, "JSONObject"),
+    ("InitiativeHistory", "id", FieldKind::Scalar, "ID"),
+    ("InitiativeHistory", "initiative", FieldKind::Object, "Initiative"),
+    ("InitiativeHistory", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("InitiativeHistoryConnection", "edges", FieldKind::Object, "InitiativeHistoryEdge"),
+    ("InitiativeHistoryConnection", "nodes", FieldKind::Object, "InitiativeHistory"),
+    ("InitiativeHistoryConnection", "pageInfo", FieldKind::Object, "PageInfo"),
+    ("InitiativeHistoryEdge", "cursor", FieldKind::Scalar, "String"),
+    ("InitiativeHistoryEdge", "node", FieldKind::Object, "InitiativeHistory"),
+    ("InitiativeNotification", "actor", FieldKind::Object, "User"),
+    ("InitiativeNotification", "actorAvatarColor", FieldKind::Scalar, "String"),
+    ("InitiativeNotification", "actorAvatarUrl", FieldKind::Scalar, "String"),
+    ("InitiativeNotification", "actorInitials", FieldKind::Scalar, "String"),
+    ("InitiativeNotification", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("InitiativeNotification", "botActor", FieldKind::Object, "ActorBot"),
+    ("InitiativeNotification", "category", FieldKind::Scalar, "NotificationCategory"),
+    ("InitiativeNotification", "comment", FieldKind::Object, "Comment"),
+    ("InitiativeNotification", "commentId", FieldKind::Scalar, "String"),
+    ("InitiativeNotification", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("InitiativeNotification", "document", FieldKind::Object, "Document"),
+    ("InitiativeNotification", "emailedAt", FieldKind::Scalar, "DateTime"),
+    ("InitiativeNotification", "externalUserActor", FieldKind::Object, "ExternalUser"),
+    ("InitiativeNotification", "groupingKey", FieldKind::Scalar, "String"),
+    ("InitiativeNotification", "groupingPriority", FieldKind::Scalar, "Float"),
+    ("InitiativeNotification", "id", FieldKind::Scalar, "ID"),
+    ("InitiativeNotification", "inboxUrl", FieldKind::Scalar, "String"),
+    ("InitiativeNotification", "initiative", FieldKind::Object, "Initiative"),
+    ("InitiativeNotification", "initiativeId", FieldKind::Scalar, "String"),
+    ("InitiativeNotification", "initiativeUpdate", FieldKind::Object, "InitiativeUpdate"),
+    ("InitiativeNotification", "initiativeUpdateHealth", FieldKind::Scalar, "String"),
+    ("InitiativeNotification", "initiativeUpdateId", FieldKind::Scalar, "String"),
+    ("InitiativeNotification", "isLinearActor", FieldKind::Scalar, "Boolean"),
+    ("InitiativeNotification", "issueStatusType", FieldKind::Scalar, "String"),
+    ("InitiativeNotification", "parentComment", FieldKind::Object, "Comment"),
+    ("InitiativeNotification", "parentCommentId", FieldKind::Scalar, "String"),
+    ("InitiativeNotification", "projectUpdateHealth", FieldKind::Scalar, "String"),
+    ("InitiativeNotification", "reactionEmoji", FieldKind::Scalar, "String"),
+    ("InitiativeNotification", "readAt", FieldKind::Scalar, "DateTime"),
+    ("InitiativeNotification", "snoozedUntilAt", FieldKind::Scalar, "DateTime"),
+    ("InitiativeNotification", "subtitle", FieldKind::Scalar, "String"),
+    ("InitiativeNotification", "title", FieldKind::Scalar, "String"),
+    ("InitiativeNotification", "type", FieldKind::Scalar, "String"),
+    ("InitiativeNotification", "unsnoozedAt", FieldKind::Scalar, "DateTime"),
+    ("InitiativeNotification", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("InitiativeNotification", "url", FieldKind::Scalar, "String"),
+    ("InitiativeNotification", "user", FieldKind::Object, "User"),
+    ("InitiativeNotificationSubscription", "active", FieldKind::Scalar, "Boolean"),
+    ("InitiativeNotificationSubscription", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("InitiativeNotificationSubscription", "contextViewType", FieldKind::Scalar, "ContextViewType"),
+    ("InitiativeNotificationSubscription", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("InitiativeNotificationSubscription", "customView", FieldKind::Object, "CustomView"),
+    ("InitiativeNotificationSubscription", "customer", FieldKind::Object, "Customer"),
+    ("InitiativeNotificationSubscription", "cycle", FieldKind::Object, "Cycle"),
+    ("InitiativeNotificationSubscription", "id", FieldKind::Scalar, "ID"),
+    ("InitiativeNotificationSubscription", "initiative", FieldKind::Object, "Initiative"),
+    ("InitiativeNotificationSubscription", "label", FieldKind::Object, "IssueLabel"),
+    ("InitiativeNotificationSubscription", "notificationSubscriptionTypes", FieldKind::Scalar, "String"),
+    ("InitiativeNotificationSubscription", "project", FieldKind::Object, "Project"),
+    ("InitiativeNotificationSubscription", "subscriber", FieldKind::Object, "User"),
+    ("InitiativeNotificationSubscription", "team", FieldKind::Object, "Team"),
+    ("InitiativeNotificationSubscription", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("InitiativeNotificationSubscription", "user", FieldKind::Object, "User"),
+    ("InitiativeNotificationSubscription", "userContextViewType", FieldKind::Scalar, "UserContextViewType"),
+    ("InitiativePayload", "initiative", FieldKind::Object, "Initiative"),
+    ("InitiativePayload", "lastSyncId", FieldKind::Scalar, "Float"),
+    ("InitiativePayload", "success", FieldKind::Scalar, "Boolean"),
+    ("InitiativeRelation", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("InitiativeRelation", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("InitiativeRelation", "id", FieldKind::Scalar, "ID"),
+    ("InitiativeRelation", "initiative", FieldKind::Object, "Initiative"),
+    ("InitiativeRelation", "relatedInitiative", FieldKind::Object, "Initiative"),
+    ("InitiativeRelation", "sortOrder", FieldKind::Scalar, "Float"),
+    ("InitiativeRelation", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("InitiativeRelation", "user", FieldKind::Object, "User"),
+    ("InitiativeRelationConnection", "edges", FieldKind::Object, "InitiativeRelationEdge"),
+    ("InitiativeRelationConnection", "nodes", FieldKind::Object, "InitiativeRelation"),
+    ("InitiativeRelationConnection", "pageInfo", FieldKind::Object, "PageInfo"),
+    ("InitiativeRelationEdge", "cursor", FieldKind::Scalar, "String"),
+    ("InitiativeRelationEdge", "node", FieldKind::Object, "InitiativeRelation"),
+    ("InitiativeRelationPayload", "initiativeRelation", FieldKind::Object, "InitiativeRelation"),
+    ("InitiativeRelationPayload", "lastSyncId", FieldKind::Scalar, "Float"),
+    ("InitiativeRelationPayload", "success", FieldKind::Scalar, "Boolean"),
+    ("InitiativeToProject", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("InitiativeToProject", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("InitiativeToProject", "id", FieldKind::Scalar, "ID"),
+    ("InitiativeToProject", "initiative", FieldKind::Object, "Initiative"),
+    ("InitiativeToProject", "project", FieldKind::Object, "Project"),
+    ("InitiativeToProject", "sortOrder", FieldKind::Scalar, "String"),
+    ("InitiativeToProject", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("InitiativeToProjectConnection", "edges", FieldKind::Object, "InitiativeToProjectEdge"),
+    ("InitiativeToProjectConnection", "nodes", FieldKind::Object, "InitiativeToProject"),
+    ("InitiativeToProjectConnection", "pageInfo", FieldKind::Object, "PageInfo"),
+    ("InitiativeToProjectEdge", "cursor", FieldKind::Scalar, "String"),
+    ("InitiativeToProjectEdge", "node", FieldKind::Object, "InitiativeToProject"),
+    ("InitiativeToProjectPayload", "initiativeToProject", FieldKind::Object, "InitiativeToProject"),
+    ("InitiativeToProjectPayload", "lastSyncId", FieldKind::Scalar, "Float"),
+    ("InitiativeToProjectPayload", "success", FieldKind::Scalar, "Boolean"),
+    ("InitiativeUpdate", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("InitiativeUpdate", "body", FieldKind::Scalar, "String"),
+    ("InitiativeUpdate", "bodyData", FieldKind::Scalar, "String"),
+    ("InitiativeUpdate", "commentCount", FieldKind::Scalar, "Int"),
+    ("InitiativeUpdate", "comments", FieldKind::Connection, "CommentConnection"),
+    ("InitiativeUpdate", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("InitiativeUpdate", "diff", FieldKind::Scalar, "JSONObject"),
+    ("InitiativeUpdate", "diffMarkdown", FieldKind::Scalar, "String"),
+    ("InitiativeUpdate", "editedAt", FieldKind::Scalar, "DateTime"),
+    ("InitiativeUpdate", "health", FieldKind::Scalar, "InitiativeUpdateHealthType"),
+    ("InitiativeUpdate", "id", FieldKind::Scalar, "ID"),
+    ("InitiativeUpdate", "infoSnapshot", FieldKind::Scalar, "JSONObject"),
+    ("InitiativeUpdate", "initiative", FieldKind::Object, "Initiative"),
+    ("InitiativeUpdate", "isDiffHidden", FieldKind::Scalar, "Boolean"),
+    ("InitiativeUpdate", "isStale", FieldKind::Scalar, "Boolean"),
+    ("InitiativeUpdate", "reactionData", FieldKind::Scalar, "JSONObject"),
+    ("InitiativeUpdate", "reactions", FieldKind::Object, "Reaction"),
+    ("InitiativeUpdate", "slugId", FieldKind::Scalar, "String"),
+    ("InitiativeUpdate", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("InitiativeUpdate", "url", FieldKind::Scalar, "String"),
+    ("InitiativeUpdate", "user", FieldKind::Object, "User"),
+    ("InitiativeUpdateArchivePayload", "entity", FieldKind::Object, "InitiativeUpdate"),
+    ("InitiativeUpdateArchivePayload", "lastSyncId", FieldKind::Scalar, "Float"),
+    ("InitiativeUpdateArchivePayload", "success", FieldKind::Scalar, "Boolean"),
+    ("InitiativeUpdateChildWebhookPayload", "bodyData", FieldKind::Scalar, "String"),
+    ("InitiativeUpdateChildWebhookPayload", "editedAt", FieldKind::Scalar, "String"),
+    ("InitiativeUpdateChildWebhookPayload", "health", FieldKind::Scalar, "String"),
+    ("InitiativeUpdateChildWebhookPayload", "id", FieldKind::Scalar, "String"),
+    ("InitiativeUpdateConnection", "edges", FieldKind::Object, "InitiativeUpdateEdge"),
+    ("InitiativeUpdateConnection", "nodes", FieldKind::Object, "InitiativeUpdate"),
+    ("InitiativeUpdateConnection", "pageInfo", FieldKind::Object, "PageInfo"),
+    ("InitiativeUpdateEdge", "cursor", FieldKind::Scalar, "String"),
+    ("InitiativeUpdateEdge", "node", FieldKind::Object, "InitiativeUpdate"),
+    ("InitiativeUpdatePayload", "initiativeUpdate", FieldKind::Object, "InitiativeUpdate"),
+    ("InitiativeUpdatePayload", "lastSyncId", FieldKind::Scalar, "Float"),
+    ("InitiativeUpdatePayload", "success", FieldKind::Scalar, "Boolean"),
+    ("InitiativeUpdateReminderPayload", "lastSyncId", FieldKind::Scalar, "Float"),
+    ("InitiativeUpdateReminderPayload", "success", FieldKind::Scalar, "Boolean"),
+    ("InitiativeUpdateWebhookPayload", "archivedAt", FieldKind::Scalar, "String"),
+    ("InitiativeUpdateWebhookPayload", "body", FieldKind::Scalar, "String"),
+    ("InitiativeUpdateWebhookPayload", "bodyData", FieldKind::Scalar, "String"),
+    ("InitiativeUpdateWebhookPayload", "createdAt", FieldKind::Scalar, "String"),
+    ("InitiativeUpdateWebhookPayload", "editedAt", FieldKind::Scalar, "String"),
+    ("InitiativeUpdateWebhookPayload", "health", FieldKind::Scalar, "String"),
+    ("InitiativeUpdateWebhookPayload", "id", FieldKind::Scalar, "String"),
+    ("InitiativeUpdateWebhookPayload", "initiative", FieldKind::Object, "InitiativeChildWebhookPayload"),
+    ("InitiativeUpdateWebhookPayload", "initiativeId", FieldKind::Scalar, "String"),
+    ("InitiativeUpdateWebhookPayload", "reactionData", FieldKind::Scalar, "JSONObject"),
+    ("InitiativeUpdateWebhookPayload", "slugId", FieldKind::Scalar, "String"),
+    ("InitiativeUpdateWebhookPayload", "updatedAt", FieldKind::Scalar, "String"),
+    ("InitiativeUpdateWebhookPayload", "url", FieldKind::Scalar, "String"),
+    ("InitiativeUpdateWebhookPayload", "user", FieldKind::Object, "UserChildWebhookPayload"),
+    ("InitiativeUpdateWebhookPayload", "userId", FieldKind::Scalar, "String"),
+    ("InitiativeWebhookPayload", "archivedAt", FieldKind::Scalar, "String"),
+    ("InitiativeWebhookPayload", "color", FieldKind::Scalar, "String"),
+    ("InitiativeWebhookPayload", "completedAt", FieldKind::Scalar, "String"),
+    ("InitiativeWebhookPayload", "createdAt", FieldKind::Scalar, "String"),
+    ("InitiativeWebhookPayload", "creator", FieldKind::Object, "UserChildWebhookPayload"),
+    ("InitiativeWebhookPayload", "creatorId", FieldKind::Scalar, "String"),
+    ("InitiativeWebhookPayload", "description", FieldKind::Scalar, "String"),
+    ("InitiativeWebhookPayload", "frequencyResolution", FieldKind::Scalar, "String"),
+    ("InitiativeWebhookPayload", "health", FieldKind::Scalar, "String"),
+    ("InitiativeWebhookPayload", "healthUpdatedAt", FieldKind::Scalar, "String"),
+    ("InitiativeWebhookPayload", "icon", FieldKind::Scalar, "String"),
+    ("InitiativeWebhookPayload", "id", FieldKind::Scalar, "String"),
+    ("InitiativeWebhookPayload", "lastUpdate", FieldKind::Object, "InitiativeUpdateChildWebhookPayload"),
+    ("InitiativeWebhookPayload", "lastUpdateId", FieldKind::Scalar, "String"),
+    ("InitiativeWebhookPayload", "name", FieldKind::Scalar, "String"),
+    ("InitiativeWebhookPayload", "organizationId", FieldKind::Scalar, "String"),
+    ("InitiativeWebhookPayload", "owner", FieldKind::Object, "UserChildWebhookPayload"),
+    ("InitiativeWebhookPayload", "ownerId", FieldKind::Scalar, "String"),
+    ("InitiativeWebhookPayload", "parentInitiative", FieldKind::Object, "InitiativeChildWebhookPayload"),
+    ("InitiativeWebhookPayload", "projects", FieldKind::Object, "ProjectChildWebhookPayload"),
+    ("InitiativeWebhookPayload", "slugId", FieldKind::Scalar, "String"),
+    ("InitiativeWebhookPayload", "sortOrder", FieldKind::Scalar, "Float"),
+    ("InitiativeWebhookPayload", "startedAt", FieldKind::Scalar, "String"),
+    ("InitiativeWebhookPayload", "status", FieldKind::Scalar, "String"),
+    ("InitiativeWebhookPayload", "subInitiatives", FieldKind::Object, "InitiativeChildWebhookPayload"),
+    ("InitiativeWebhookPayload", "targetDate", FieldKind::Scalar, "String"),
+    ("InitiativeWebhookPayload", "targetDateResolution", FieldKind::Scalar, "String"),
+    ("InitiativeWebhookPayload", "trashed", FieldKind::Scalar, "Boolean"),
+    ("InitiativeWebhookPayload", "updateReminderFrequency", FieldKind::Scalar, "Float"),
+    ("InitiativeWebhookPayload", "updateReminderFrequencyInWeeks", FieldKind::Scalar, "Float"),
+    ("InitiativeWebhookPayload", "updateRemindersDay", FieldKind::Scalar, "Float"),
+    ("InitiativeWebhookPayload", "updateRemindersHour", FieldKind::Scalar, "Float"),
+    ("InitiativeWebhookPayload", "updatedAt", FieldKind::Scalar, "String"),
+    ("InitiativeWebhookPayload", "url", FieldKind::Scalar, "String"),
+    ("Integration", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("Integration", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("Integration", "creator", FieldKind::Object, "User"),
+    ("Integration", "id", FieldKind::Scalar, "ID"),
+    ("Integration", "organization", FieldKind::Object, "Organization"),
+    ("Integration", "service", FieldKind::Scalar, "String"),
+    ("Integration", "team", FieldKind::Object, "Team"),
+    ("Integration", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("IntegrationActorWebhookPayload", "id", FieldKind::Scalar, "String"),
+    ("IntegrationActorWebhookPayload", "service", FieldKind::Scalar, "String"),
+    ("IntegrationActorWebhookPayload", "type", FieldKind::Scalar, "String"),
+    ("IntegrationChildWebhookPayload", "id", FieldKind::Scalar, "String"),
+    ("IntegrationChildWebhookPayload", "service", FieldKind::Scalar, "String"),
+    ("IntegrationConnection", "edges", FieldKind::Object, "IntegrationEdge"),
+    ("IntegrationConnection", "nodes", FieldKind::Object, "Integration"),
+    ("IntegrationConnection", "pageInfo", FieldKind::Object, "PageInfo"),
+    ("IntegrationEdge", "cursor", FieldKind::Scalar, "String"),
+    ("IntegrationEdge", "node", FieldKind::Object, "Integration"),
+    ("IntegrationHasScopesPayload", "hasAllScopes", FieldKind::Scalar, "Boolean"),
+    ("IntegrationHasScopesPayload", "missingScopes", FieldKind::Scalar, "String"),
+    ("IntegrationPayload", "integration", FieldKind::Object, "Integration"),
+    ("IntegrationPayload", "lastSyncId", FieldKind::Scalar, "Float"),
+    ("IntegrationPayload", "success", FieldKind::Scalar, "Boolean"),
+    ("IntegrationRequestPayload", "success", FieldKind::Scalar, "Boolean"),
+    ("IntegrationSlackWorkspaceNamePayload", "name", FieldKind::Scalar, "String"),
+    ("IntegrationSlackWorkspaceNamePayload", "success", FieldKind::Scalar, "Boolean"),
+    ("IntegrationTemplate", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("IntegrationTemplate", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("IntegrationTemplate", "foreignEntityId", FieldKind::Scalar, "String"),
+    ("IntegrationTemplate", "id", FieldKind::Scalar, "ID"),
+    ("IntegrationTemplate", "integration", FieldKind::Object, "Integration"),
+    ("IntegrationTemplate", "template", FieldKind::Object, "Template"),
+    ("IntegrationTemplate", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("IntegrationTemplateConnection", "edges", FieldKind::Object, "IntegrationTemplateEdge"),
+    ("IntegrationTemplateConnection", "nodes", FieldKind::Object, "IntegrationTemplate"),
+    ("IntegrationTemplateConnection", "pageInfo", FieldKind::Object, "PageInfo"),
+    ("IntegrationTemplateEdge", "cursor", FieldKind::Scalar, "String"),
+    ("IntegrationTemplateEdge", "node", FieldKind::Object, "IntegrationTemplate"),
+    ("IntegrationTemplatePayload", "integrationTemplate", FieldKind::Object, "IntegrationTemplate"),
+    ("IntegrationTemplatePayload", "lastSyncId", FieldKind::Scalar, "Float"),
+    ("IntegrationTemplatePayload", "success", FieldKind::Scalar, "Boolean"),
+    ("IntegrationsSettings", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("IntegrationsSettings", "contextViewType", FieldKind::Scalar, "ContextViewType"),
+    ("IntegrationsSettings", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("IntegrationsSettings", "id", FieldKind::Scalar, "ID"),
+    ("IntegrationsSettings", "initiative", FieldKind::Object, "Initiative"),
+    ("IntegrationsSettings", "project", FieldKind::Object, "Project"),
+    ("IntegrationsSettings", "slackInitiativeUpdateCreated", FieldKind::Scalar, "Boolean"),
+    ("IntegrationsSettings", "slackIssueAddedToTriage", FieldKind::Scalar, "Boolean"),
+    ("IntegrationsSettings", "slackIssueAddedToView", FieldKind::Scalar, "Boolean"),
+    ("IntegrationsSettings", "slackIssueCreated", FieldKind::Scalar, "Boolean"),
+    ("IntegrationsSettings", "slackIssueNewComment", FieldKind::Scalar, "Boolean"),
+    ("IntegrationsSettings", "slackIssueSlaBreached", FieldKind::Scalar, "Boolean"),
+    ("IntegrationsSettings", "slackIssueSlaHighRisk", FieldKind::Scalar, "Boolean"),
+    ("IntegrationsSettings", "slackIssueStatusChangedAll", FieldKind::Scalar, "Boolean"),
+    ("IntegrationsSettings", "slackIssueStatusChangedDone", FieldKind::Scalar, "Boolean"),
+    ("IntegrationsSettings", "slackProjectUpdateCreated", FieldKind::Scalar, "Boolean"),
+    ("IntegrationsSettings", "slackProjectUpdateCreatedToTeam", FieldKind::Scalar, "Boolean"),
+    ("IntegrationsSettings", "slackProjectUpdateCreatedToWorkspace", FieldKind::Scalar, "Boolean"),
+    ("IntegrationsSettings", "team", FieldKind::Object, "Team"),
+    ("IntegrationsSettings", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("IntegrationsSettingsPayload", "integrationsSettings", FieldKind::Object, "IntegrationsSettings"),
+    ("IntegrationsSettingsPayload", "lastSyncId", FieldKind::Scalar, "Float"),
+    ("IntegrationsSettingsPayload", "success", FieldKind::Scalar, "Boolean"),
+    ("Issue", "activitySummary", FieldKind::Scalar, "JSONObject"),
+    ("Issue", "addedToCycleAt", FieldKind::Scalar, "DateTime"),
+    ("Issue", "addedToProjectAt", FieldKind::Scalar, "DateTime"),
+    ("Issue", "addedToTeamAt", FieldKind::Scalar, "DateTime"),
+    ("Issue", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("Issue", "asksExternalUserRequester", FieldKind::Object, "ExternalUser"),
+    ("Issue", "asksRequester", FieldKind::Object, "User"),
+    ("Issue", "assignee", FieldKind::Object, "User"),
+    ("Issue", "attachments", FieldKind::Connection, "AttachmentConnection"),
+    ("Issue", "autoArchivedAt", FieldKind::Scalar, "DateTime"),
+    ("Issue", "autoClosedAt", FieldKind::Scalar, "DateTime"),
+    ("Issue", "boardOrder", FieldKind::Scalar, "Float"),
+    ("Issue", "botActor", FieldKind::Object, "ActorBot"),
+    ("Issue", "branchName", FieldKind::Scalar, "String"),
+    ("Issue", "canceledAt", FieldKind::Scalar, "DateTime"),
+    ("Issue", "children", FieldKind::Connection, "IssueConnection"),
+    ("Issue", "comments", FieldKind::Connection, "CommentConnection"),
+    ("Issue", "completedAt", FieldKind::Scalar, "DateTime"),
+    ("Issue", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("Issue", "creator", FieldKind::Object, "User"),
+    ("Issue", "customerTicketCount", FieldKind::Scalar, "Int"),
+    ("Issue", "cycle", FieldKind::Object, "Cycle"),
+    ("Issue", "delegate", FieldKind::Object, "User"),
+    ("Issue", "description", FieldKind::Scalar, "String"),
+    ("Issue", "descriptionState", FieldKind::Scalar, "String"),
+    ("Issue", "documentContent", FieldKind::Object, "DocumentContent"),
+    ("Issue", "documents", FieldKind::Connection, "DocumentConnection"),
+    ("Issue", "dueDate", FieldKind::Scalar, "TimelessDate"),
+    ("Issue", "estimate", FieldKind::Scalar, "Float"),
+    ("Issue", "externalUserCreator", FieldKind::Object, "ExternalUser"),
+    ("Issue", "favorite", FieldKind::Object, "Favorite"),
+    ("Issue", "formerAttachments", FieldKind::Connection, "AttachmentConnection"),
+    ("Issue", "formerNeeds", FieldKind::Connection, "CustomerNeedConnection"),
+    ("Issue", "history", FieldKind::Connection, "IssueHistoryConnection"),
+    ("Issue", "id", FieldKind::Scalar, "ID"),
+    ("Issue", "identifier", FieldKind::Scalar, "String"),
+    ("Issue", "incomingSuggestions", FieldKind::Connection, "IssueSuggestionConnection"),
+    ("Issue", "integrationSourceType", FieldKind::Scalar, "IntegrationService"),
+    ("Issue", "inverseRelations", FieldKind::Connection, "IssueRelationConnection"),
+    ("Issue", "labelIds", FieldKind::Scalar, "String"),
+    ("Issue", "labels", FieldKind::Connection, "IssueLabelConnection"),
+    ("Issue", "lastAppliedTemplate", FieldKind::Object, "Template"),
+    ("Issue", "needs", FieldKind::Connection, "CustomerNeedConnection"),
+    ("Issue", "number", FieldKind::Scalar, "Float"),
+    ("Issue", "parent", FieldKind::Object, "Issue"),
+    ("Issue", "previousIdentifiers", FieldKind::Scalar, "String"),
+    ("Issue", "priority", FieldKind::Scalar, "Float"),
+    ("Issue", "priorityLabel", FieldKind::Scalar, "String"),
+    ("Issue", "prioritySortOrder", FieldKind::Scalar, "Float"),
+    ("Issue", "project", FieldKind::Object, "Project"),
+    ("Issue", "projectMilestone", FieldKind::Object, "ProjectMilestone"),
+    ("Issue", "reactionData", FieldKind::Scalar, "JSONObject"),
+    ("Issue", "reactions", FieldKind::Object, "Reaction"),
+    ("Issue", "recurringIssueTemplate", FieldKind::Object, "Template"),
+    ("Issue", "relations", FieldKind::Connection, "IssueRelationConnection"),
+    ("Issue", "slaBreachesAt", FieldKind::Scalar, "DateTime"),
+    ("Issue", "slaHighRiskAt", FieldKind::Scalar, "DateTime"),
+    ("Issue", "slaMediumRiskAt", FieldKind::Scalar, "DateTime"),
+    ("Issue", "slaStartedAt", FieldKind::Scalar, "DateTime"),
+    ("Issue", "slaType", FieldKind::Scalar, "String"),
+    ("Issue", "snoozedBy", FieldKind::Object, "User"),
+    ("Issue", "snoozedUntilAt", FieldKind::Scalar, "DateTime"),
+    ("Issue", "sortOrder", FieldKind::Scalar, "Float"),
+    ("Issue", "sourceComment", FieldKind::Object, "Comment"),
+    ("Issue", "startedAt", FieldKind::Scalar, "DateTime"),
+    ("Issue", "startedTriageAt", FieldKind::Scalar, "DateTime"),
+    ("Issue", "state", FieldKind::Object, "WorkflowState"),
+    ("Issue", "stateHistory", FieldKind::Connection, "IssueStateSpanConnection"),
+    ("Issue", "subIssueSortOrder", FieldKind::Scalar, "Float"),
+    ("Issue", "subscribers", FieldKind::Connection, "UserConnection"),
+    ("Issue", "suggestions", FieldKind::Connection, "IssueSuggestionConnection"),
+    ("Issue", "suggestionsGeneratedAt", FieldKind::Scalar, "DateTime"),
+    ("Issue", "syncedWith", FieldKind::Object, "ExternalEntityInfo"),
+    ("Issue", "team", FieldKind::Object, "Team"),
+    ("Issue", "title", FieldKind::Scalar, "String"),
+    ("Issue", "trashed", FieldKind::Scalar, "Boolean"),
+    ("Issue", "triagedAt", FieldKind::Scalar, "DateTime"),
+    ("Issue", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("Issue", "url", FieldKind::Scalar, "String"),
+    ("IssueArchivePayload", "entity", FieldKind::Object, "Issue"),
+    ("IssueArchivePayload", "lastSyncId", FieldKind::Scalar, "Float"),
+    ("IssueArchivePayload", "success", FieldKind::Scalar, "Boolean"),
+    ("IssueAssignedToYouNotificationWebhookPayload", "actor", FieldKind::Object, "UserChildWebhookPayload"),
+    ("IssueAssignedToYouNotificationWebhookPayload", "actorId", FieldKind::Scalar, "String"),
+    ("IssueAssignedToYouNotificationWebhookPayload", "archivedAt", FieldKind::Scalar, "String"),
+    ("IssueAssignedToYouNotificationWebhookPayload", "createdAt", FieldKind::Scalar, "String"),
+    ("IssueAssignedToYouNotificationWebhookPayload", "externalUserActorId", FieldKind::Scalar, "String"),
+    ("IssueAssignedToYouNotificationWebhookPayload", "id", FieldKind::Scalar, "String"),
+    ("IssueAssignedToYouNotificationWebhookPayload", "issue", FieldKind::Object, "IssueWithDescriptionChildWebhookPayload"),
+    ("IssueAssignedToYouNotificationWebhookPayload", "issueId", FieldKind::Scalar, "String"),
+    ("IssueAssignedToYouNotificationWebhookPayload", "type", FieldKind::Scalar, "IssueAssignedToYouNotificationType"),
+    ("IssueAssignedToYouNotificationWebhookPayload", "updatedAt", FieldKind::Scalar, "String"),
+    ("IssueAssignedToYouNotificationWebhookPayload", "userId", FieldKind::Scalar, "String"),
+    ("IssueBatchPayload", "issues", FieldKind::Object, "Issue"),
+    ("IssueBatchPayload", "lastSyncId", FieldKind::Scalar, "Float"),
+    ("IssueBatchPayload", "success", FieldKind::Scalar, "Boolean"),
+    ("IssueChildWebhookPayload", "id", FieldKind::Scalar, "String"),
+    ("IssueChildWebhookPayload", "identifier", FieldKind::Scalar, "String"),
+    ("IssueChildWebhookPayload", "team", FieldKind::Object, "TeamChildWebhookPayload"),
+    ("IssueChildWebhookPayload", "teamId", FieldKind::Scalar, "String"),
+    ("IssueChildWebhookPayload", "title", FieldKind::Scalar, "String"),
+    ("IssueChildWebhookPayload", "url", FieldKind::Scalar, "String"),
+    ("IssueCommentMentionNotificationWebhookPayload", "actor", FieldKind::Object, "UserChildWebhookPayload"),
+    ("IssueCommentMentionNotificationWebhookPayload", "actorId", FieldKind::Scalar, "String"),
+    ("IssueCommentMentionNotificationWebhookPayload", "archivedAt", FieldKind::Scalar, "String"),
+    ("IssueCommentMentionNotificationWebhookPayload", "comment", FieldKind::Object, "CommentChildWebhookPayload"),
+    ("IssueCommentMentionNotificationWebhookPayload", "commentId", FieldKind::Scalar, "String"),
+    ("IssueCommentMentionNotificationWebhookPayload", "createdAt", FieldKind::Scalar, "String"),
+    ("IssueCommentMentionNotificationWebhookPayload", "externalUserActorId", FieldKind::Scalar, "String"),
+    ("IssueCommentMentionNotificationWebhookPayload", "id", FieldKind::Scalar, "String"),
+    ("IssueCommentMentionNotificationWebhookPayload", "issue", FieldKind::Object, "IssueWithDescriptionChildWebhookPayload"),
+    ("IssueCommentMentionNotificationWebhookPayload", "issueId", FieldKind::Scalar, "String"),
+    ("IssueCommentMentionNotificationWebhookPayload", "parentComment", FieldKind::Object, "CommentChildWebhookPayload"),
+    ("IssueCommentMentionNotificationWebhookPayload", "parentCommentId", FieldKind::Scalar, "String"),
+    ("IssueCommentMentionNotificationWebhookPayload", "type", FieldKind::Scalar, "IssueCommentMentionNotificationType"),
+    ("IssueCommentMentionNotificationWebhookPayload", "updatedAt", FieldKind::Scalar, "String"),
+    ("IssueCommentMentionNotificationWebhookPayload", "userId", FieldKind::Scalar, "String"),
+    ("IssueCommentReactionNotificationWebhookPayload", "actor", FieldKind::Object, "UserChildWebhookPayload"),
+    ("IssueCommentReactionNotificationWebhookPayload", "actorId", FieldKind::Scalar, "String"),
+    ("IssueCommentReactionNotificationWebhookPayload", "archivedAt", FieldKind::Scalar, "String"),
+    ("IssueCommentReactionNotificationWebhookPayload", "comment", FieldKind::Object, "CommentChildWebhookPayload"),
+    ("IssueCommentReactionNotificationWebhookPayload", "commentId", FieldKind::Scalar, "String"),
+    ("IssueCommentReactionNotificationWebhookPayload", "createdAt", FieldKind::Scalar, "String"),
+    ("IssueCommentReactionNotificationWebhookPayload", "externalUserActorId", FieldKind::Scalar, "String"),
+    ("IssueCommentReactionNotificationWebhookPayload", "id", FieldKind::Scalar, "String"),
+    ("IssueCommentReactionNotificationWebhookPayload", "issue", FieldKind::Object, "IssueWithDescriptionChildWebhookPayload"),
+    ("IssueCommentReactionNotificationWebhookPayload", "issueId", FieldKind::Scalar, "String"),
+    ("IssueCommentReactionNotificationWebhookPayload", "parentComment", FieldKind::Object, "CommentChildWebhookPayload"),
+    ("IssueCommentReactionNotificationWebhookPayload", "parentCommentId", FieldKind::Scalar, "String"),
+    ("IssueCommentReactionNotificationWebhookPayload", "reactionEmoji", FieldKind::Scalar, "String"),
+    ("IssueCommentReactionNotificationWebhookPayload", "type", FieldKind::Scalar, "IssueCommentReactionNotificationType"),
+    ("IssueCommentReactionNotificationWebhookPayload", "updatedAt", FieldKind::Scalar, "String"),
+    ("IssueCommentReactionNotificationWebhookPayload", "userId", FieldKind::Scalar, "String"),
+    ("IssueConnection", "edges", FieldKind::Object, "IssueEdge"),
+    ("IssueConnection", "nodes", FieldKind::Object, "Issue"),
+    ("IssueConnection", "pageInfo", FieldKind::Object, "PageInfo"),
+    ("IssueDraft", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("IssueDraft", "assigneeId", FieldKind::Scalar, "String"),
+    ("IssueDraft", "attachments", FieldKind::Scalar, "JSONObject"),
+    ("IssueDraft", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("IssueDraft", "creator", FieldKind::Object, "User"),
+    ("IssueDraft", "cycleId", FieldKind::Scalar, "String"),
+    ("IssueDraft", "delegateId", FieldKind::Scalar, "String"),
+    ("IssueDraft", "description", FieldKind::Scalar, "String"),
+    ("IssueDraft", "descriptionData", FieldKind::Scalar, "JSON"),
+    ("IssueDraft", "dueDate", FieldKind::Scalar, "TimelessDate"),
+    ("IssueDraft", "estimate", FieldKind::Scalar, "Float"),
+    ("IssueDraft", "id", FieldKind::Scalar, "ID"),
+    ("IssueDraft", "labelIds", FieldKind::Scalar, "String"),
+    ("IssueDraft", "needs", FieldKind::Scalar, "JSONObject"),
+    ("IssueDraft", "parent", FieldKind::Object, "IssueDraft"),
+    ("IssueDraft", "parentId", FieldKind::Scalar, "String"),
+    ("IssueDraft", "parentIssue", FieldKind::Object, "Issue"),
+    ("IssueDraft", "parentIssueId", FieldKind::Scalar, "String"),
+    ("IssueDraft", "priority", FieldKind::Scalar, "Float"),
+    ("IssueDraft", "priorityLabel", FieldKind::Scalar, "String"),
+    ("IssueDraft", "projectId", FieldKind::Scalar, "String"),
+    ("IssueDraft", "projectMilestoneId", FieldKind::Scalar, "String"),
+    ("IssueDraft", "schedule", FieldKind::Scalar, "JSONObject"),
+    ("IssueDraft", "sourceCommentId", FieldKind::Scalar, "String"),
+    ("IssueDraft", "stateId", FieldKind::Scalar, "String"),
+    ("IssueDraft", "subIssueSortOrder", FieldKind::Scalar, "Float"),
+    ("IssueDraft", "teamId", FieldKind::Scalar, "String"),
+    ("IssueDraft", "title", FieldKind::Scalar, "String"),
+    ("IssueDraft", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("IssueDraftConnection", "edges", FieldKind::Object, "IssueDraftEdge"),
+    ("IssueDraftConnection", "nodes", FieldKind::Object, "IssueDraft"),
+    ("IssueDraftConnection", "pageInfo", FieldKind::Object, "PageInfo"),
+    ("IssueDraftEdge", "cursor", FieldKind::Scalar, "String"),
+    ("IssueDraftEdge", "node", FieldKind::Object, "IssueDraft"),
+    ("IssueEdge", "cursor", FieldKind::Scalar, "String"),
+    ("IssueEdge", "node", FieldKind::Object, "Issue"),
+    ("IssueEmojiReactionNotificationWebhookPayload", "actor", FieldKind::Object, "UserChildWebhookPayload"),
+    ("IssueEmojiReactionNotificationWebhookPayload", "actorId", FieldKind::Scalar, "String"),
+    ("IssueEmojiReactionNotificationWebhookPayload", "archivedAt", FieldKind::Scalar, "String"),
+    ("IssueEmojiReactionNotificationWebhookPayload", "createdAt", FieldKind::Scalar, "String"),
+    ("IssueEmojiReactionNotificationWebhookPayload", "externalUserActorId", FieldKind::Scalar, "String"),
+    ("IssueEmojiReactionNotificationWebhookPayload", "id", FieldKind::Scalar, "String"),
+    ("IssueEmojiReactionNotificationWebhookPayload", "issue", FieldKind::Object, "IssueWithDescriptionChildWebhookPayload"),
+    ("IssueEmojiReactionNotificationWebhookPayload", "issueId", FieldKind::Scalar, "String"),
+    ("IssueEmojiReactionNotificationWebhookPayload", "reactionEmoji", FieldKind::Scalar, "String"),
+    ("IssueEmojiReactionNotificationWebhookPayload", "type", FieldKind::Scalar, "IssueEmojiReactionNotificationType"),
+    ("IssueEmojiReactionNotificationWebhookPayload", "updatedAt", FieldKind::Scalar, "String"),
+    ("IssueEmojiReactionNotificationWebhookPayload", "userId", FieldKind::Scalar, "String"),
+    ("IssueFilterSuggestionPayload", "filter", FieldKind::Scalar, "JSONObject"),
+    ("IssueFilterSuggestionPayload", "logId", FieldKind::Scalar, "String"),
+    ("IssueHistory", "actor", FieldKind::Object, "User"),
+    ("IssueHistory", "actorId", FieldKind::Scalar, "String"),
+    ("IssueHistory", "actors", FieldKind::Object, "User"),
+    ("IssueHistory", "addedLabelIds", FieldKind::Scalar, "String"),
+    ("IssueHistory", "addedLabels", FieldKind::Object, "IssueLabel"),
+    ("IssueHistory", "addedToReleaseIds", FieldKind::Scalar, "String"),
+    ("IssueHistory", "addedToReleases", FieldKind::Object, "Release"),
+    ("IssueHistory", "archived", FieldKind::Scalar, "Boolean"),
+    ("IssueHistory", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("IssueHistory", "attachment", FieldKind::Object, "Attachment"),
+    ("IssueHistory", "attachmentId", FieldKind::Scalar, "String"),
+    ("IssueHistory", "autoArchived", FieldKind::Scalar, "Boolean"),
+    ("IssueHistory", "autoClosed", FieldKind::Scalar, "Boolean"),
+    ("IssueHistory", "botActor", FieldKind::Object, "ActorBot"),
+    ("IssueHistory", "changes", FieldKind::Scalar, "JSONObject"),
+    ("IssueHistory", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("IssueHistory", "customerNeedId", FieldKind::Scalar, "String"),
+    ("IssueHistory", "descriptionUpdatedBy", FieldKind::Object, "User"),
+    ("IssueHistory", "fromAssignee", FieldKind::Object, "User"),
+    ("IssueHistory", "fromAssigneeId", FieldKind::Scalar, "String"),
+    ("IssueHistory", "fromCycle", FieldKind::Object, "Cycle"),
+    ("IssueHistory", "fromCycleId", FieldKind::Scalar, "String"),
+    ("IssueHistory", "fromDelegate", FieldKind::Object, "User"),
+    ("IssueHistory", "fromDueDate", FieldKind::Scalar, "TimelessDate"),
+    ("IssueHistory", "fromEstimate", FieldKind::Scalar, "Float"),
+    ("IssueHistory", "fromParent", FieldKind::Object, "Issue"),
+    ("IssueHistory", "fromParentId", FieldKind::Scalar, "String"),
+    ("IssueHistory", "fromPriority", FieldKind::Scalar, "Float"),
+    ("IssueHistory", "fromProject", FieldKind::Object, "Project"),
+    ("IssueHistory", "fromProjectId", FieldKind::Scalar, "String"),
+    ("IssueHistory", "fromState", FieldKind::Object, "WorkflowState"),
+    ("IssueHistory", "fromStateId", FieldKind::Scalar, "String"),
+    ("IssueHistory", "fromTeam", FieldKind::Object, "Team"),
+    ("IssueHistory", "fromTeamId", FieldKind::Scalar, "String"),
+    ("IssueHistory", "fromTitle", FieldKind::Scalar, "String"),
+    ("IssueHistory", "id", FieldKind::Scalar, "ID"),
+    ("IssueHistory", "issue", FieldKind::Object, "Issue"),
+    ("IssueHistory", "issueImport", FieldKind::Object, "IssueImport"),
+    ("IssueHistory", "relationChanges", FieldKind::Object, "IssueRelationHistoryPayload"),
+    ("IssueHistory", "removedFromReleaseIds", FieldKind::Scalar, "String"),
+    ("IssueHistory", "removedFromReleases", FieldKind::Object, "Release"),
+    ("IssueHistory", "removedLabelIds", FieldKind::Scalar, "String"),
+    ("IssueHistory", "removedLabels", FieldKind::Object, "IssueLabel"),
+    ("IssueHistory", "toAssignee", FieldKind::Object, "User"),
+    ("IssueHistory", "toAssigneeId", FieldKind::Scalar, "String"),
+    ("IssueHistory", "toConvertedProject", FieldKind::Object, "Project"),
+    ("IssueHistory", "toConvertedProjectId", FieldKind::Scalar, "String"),
+    ("IssueHistory", "toCycle", FieldKind::Object, "Cycle"),
+    ("IssueHistory", "toCycleId", FieldKind::Scalar, "String"),
+    ("IssueHistory", "toDelegate", FieldKind::Object, "User"),
+    ("IssueHistory", "toDueDate", FieldKind::Scalar, "TimelessDate"),
+    ("IssueHistory", "toEstimate", FieldKind::Scalar, "Float"),
+    ("IssueHistory", "toParent", FieldKind::Object, "Issue"),
+    ("IssueHistory", "toParentId", FieldKind::Scalar, "String"),
+    ("IssueHistory", "toPriority", FieldKind::Scalar, "Float"),
+    ("IssueHistory", "toProject", FieldKind::Object, "Project"),
+    ("IssueHistory", "toProjectId", FieldKind::Scalar, "String"),
+    ("IssueHistory", "toState", FieldKind::Object, "WorkflowState"),
+    ("IssueHistory", "toStateId", FieldKind::Scalar, "String"),
+    ("IssueHistory", "toTeam", FieldKind::Object, "Team"),
+    ("IssueHistory", "toTeamId", FieldKind::Scalar, "String"),
+    ("IssueHistory", "toTitle", FieldKind::Scalar, "String"),
+    ("IssueHistory", "trashed", FieldKind::Scalar, "Boolean"),
+    ("IssueHistory", "triageResponsibilityAutoAssigned", FieldKind::Scalar, "Boolean"),
+    ("IssueHistory", "triageResponsibilityNotifiedUsers", FieldKind::Object, "User"),
+    ("IssueHistory", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("IssueHistory", "updatedDescription", FieldKind::Scalar, "Boolean"),
+    ("IssueHistoryConnection", "edges", FieldKind::Object, "IssueHistoryEdge"),
+    ("IssueHistoryConnection", "nodes", FieldKind::Object, "IssueHistory"),
+    ("IssueHistoryConnection", "pageInfo", FieldKind::Object, "PageInfo"),
+    ("IssueHistoryEdge", "cursor", FieldKind::Scalar, "String"),
+    ("IssueHistoryEdge", "node", FieldKind::Object, "IssueHistory"),
+    ("IssueImport", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("IssueImport", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("IssueImport", "creatorId", FieldKind::Scalar, "String"),
+    ("IssueImport", "csvFileUrl", FieldKind::Scalar, "String"),
+    ("IssueImport", "displayName", FieldKind::Scalar, "String"),
+    ("IssueImport", "error", FieldKind::Scalar, "String"),
+    ("IssueImport", "errorMetadata", FieldKind::Scalar, "JSONObject"),
+    ("IssueImport", "id", FieldKind::Scalar, "ID"),
+    ("IssueImport", "mapping", FieldKind::Scalar, "JSONObject"),
+    ("IssueImport", "progress", FieldKind::Scalar, "Float"),
+    ("IssueImport", "service", FieldKind::Scalar, "String"),
+    ("IssueImport", "serviceMetadata", FieldKind::Scalar, "JSONObject"),
+    ("IssueImport", "status", FieldKind::Scalar, "String"),
+    ("IssueImport", "teamName", FieldKind::Scalar, "String"),
+    ("IssueImport", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("IssueImportCheckPayload", "success", FieldKind::Scalar, "Boolean"),
+    ("IssueImportDeletePayload", "issueImport", FieldKind::Object, "IssueImport"),
+    ("IssueImportDeletePayload", "lastSyncId", FieldKind::Scalar, "Float"),
+    ("IssueImportDeletePayload", "success", FieldKind::Scalar, "Boolean"),
+    ("IssueImportJqlCheckPayload", "count", FieldKind::Scalar, "Float"),
+    ("IssueImportJqlCheckPayload", "error", FieldKind::Scalar, "String"),
+    ("IssueImportJqlCheckPayload", "success", FieldKind::Scalar, "Boolean"),
+    ("IssueImportPayload", "issueImport", FieldKind::Object, "IssueImport"),
+    ("IssueImportPayload", "lastSyncId", FieldKind::Scalar, "Float"),
+    ("IssueImportPayload", "success", FieldKind::Scalar, "Boolean"),
+    ("IssueImportSyncCheckPayload", "canSync", FieldKind::Scalar, "Boolean"),
+    ("IssueImportSyncCheckPayload", "error", FieldKind::Scalar, "String"),
+    ("IssueLabel", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("IssueLabel", "children", FieldKind::Connection, "IssueLabelConnection"),
+    ("IssueLabel", "color", FieldKind::Scalar, "String"),
+    ("IssueLabel", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("IssueLabel", "creator", FieldKind::Object, "User"),
+    ("IssueLabel", "description", FieldKind::Scalar, "String"),
+    ("IssueLabel", "id", FieldKind::Scalar, "ID"),
+    ("IssueLabel", "inheritedFrom", FieldKind::Object, "IssueLabel"),
+    ("IssueLabel", "isGroup", FieldKind::Scalar, "Boolean"),
+    ("IssueLabel", "issues", FieldKind::Connection, "IssueConnection"),
+    ("IssueLabel", "lastAppliedAt", FieldKind::Scalar, "DateTime"),
+    ("IssueLabel", "name", FieldKind::Scalar, "String"),
+    ("IssueLabel", "organization", FieldKind::Object, "Organization"),
+    ("IssueLabel", "parent", FieldKind::Object, "IssueLabel"),
+    ("IssueLabel", "retiredAt", FieldKind::Scalar, "DateTime"),
+    ("IssueLabel", "retiredBy", FieldKind::Object, "User"),
+    ("IssueLabel", "team", FieldKind::Object, "Team"),
+    ("IssueLabel", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("IssueLabelChildWebhookPayload", "color", FieldKind::Scalar, "String"),
+    ("IssueLabelChildWebhookPayload", "id", FieldKind::Scalar, "String"),
+    ("IssueLabelChildWebhookPayload", "name", FieldKind::Scalar, "String"),
+    ("IssueLabelChildWebhookPayload", "parentId", FieldKind::Scalar, "String"),
+    ("IssueLabelConnection", "edges", FieldKind::Object, "IssueLabelEdge"),
+    ("IssueLabelConnection", "nodes", FieldKind::Object, "IssueLabel"),
+    ("IssueLabelConnection", "pageInfo", FieldKind::Object, "PageInfo"),
+    ("IssueLabelEdge", "cursor", FieldKind::Scalar, "String"),
+    ("IssueLabelEdge", "node", FieldKind::Object, "IssueLabel"),
+    ("IssueLabelPayload", "issueLabel", FieldKind::Object, "IssueLabel"),
+    ("IssueLabelPayload", "lastSyncId", FieldKind::Scalar, "Float"),
+    ("IssueLabelPayload", "success", FieldKind::Scalar, "Boolean"),
+    ("IssueLabelWebhookPayload", "archivedAt", FieldKind::Scalar, "String"),
+    ("IssueLabelWebhookPayload", "color", FieldKind::Scalar, "String"),
+    ("IssueLabelWebhookPayload", "createdAt", FieldKind::Scalar, "String"),
+    ("IssueLabelWebhookPayload", "creatorId", FieldKind::Scalar, "String"),
+    ("IssueLabelWebhookPayload", "description", FieldKind::Scalar, "String"),
+    ("IssueLabelWebhookPayload", "id", FieldKind::Scalar, "String"),
+    ("IssueLabelWebhookPayload", "inheritedFromId", FieldKind::Scalar, "String"),
+    ("IssueLabelWebhookPayload", "isGroup", FieldKind::Scalar, "Boolean"),
+    ("IssueLabelWebhookPayload", "name", FieldKind::Scalar, "String"),
+    ("IssueLabelWebhookPayload", "parentId", FieldKind::Scalar, "String"),
+    ("IssueLabelWebhookPayload", "teamId", FieldKind::Scalar, "String"),
+    ("IssueLabelWebhookPayload", "updatedAt", FieldKind::Scalar, "String"),
+    ("IssueMentionNotificationWebhookPayload", "actor", FieldKind::Object, "UserChildWebhookPayload"),
+    ("IssueMentionNotificationWebhookPayload", "actorId", FieldKind::Scalar, "String"),
+    ("IssueMentionNotificationWebhookPayload", "archivedAt", FieldKind::Scalar, "String"),
+    ("IssueMentionNotificationWebhookPayload", "createdAt", FieldKind::Scalar, "String"),
+    ("IssueMentionNotificationWebhookPayload", "externalUserActorId", FieldKind::Scalar, "String"),
+    ("IssueMentionNotificationWebhookPayload", "id", FieldKind::Scalar, "String"),
+    ("IssueMentionNotificationWebhookPayload", "issue", FieldKind::Object, "IssueWithDescriptionChildWebhookPayload"),
+    ("IssueMentionNotificationWebhookPayload", "issueId", FieldKind::Scalar, "String"),
+    ("IssueMentionNotificationWebhookPayload", "type", FieldKind::Scalar, "IssueMentionNotificationType"),
+    ("IssueMentionNotificationWebhookPayload", "updatedAt", FieldKind::Scalar, "String"),
+    ("IssueMentionNotificationWebhookPayload", "userId", FieldKind::Scalar, "String"),
+    ("IssueNewCommentNotificationWebhookPayload", "actor", FieldKind::Object, "UserChildWebhookPayload"),
+    ("IssueNewCommentNotificationWebhookPayload", "actorId", FieldKind::Scalar, "String"),
+    ("IssueNewCommentNotificationWebhookPayload", "archivedAt", FieldKind::Scalar, "String"),
+    ("IssueNewCommentNotificationWebhookPayload", "comment", FieldKind::Object, "CommentChildWebhookPayload"),
+    ("IssueNewCommentNotificationWebhookPayload", "commentId", FieldKind::Scalar, "String"),
+    ("IssueNewCommentNotificationWebhookPayload", "createdAt", FieldKind::Scalar, "String"),
+    ("IssueNewCommentNotificationWebhookPayload", "externalUserActorId", FieldKind::Scalar, "String"),
+    ("IssueNewCommentNotificationWebhookPayload", "id", FieldKind::Scalar, "String"),
+    ("IssueNewCommentNotificationWebhookPayload", "issue", FieldKind::Object, "IssueWithDescriptionChildWebhookPayload"),
+    ("IssueNewCommentNotificationWebhookPayload", "issueId", FieldKind::Scalar, "String"),
+    ("IssueNewCommentNotificationWebhookPayload", "parentComment", FieldKind::Object, "CommentChildWebhookPayload"),
+    ("IssueNewCommentNotificationWebhookPayload", "parentCommentId", FieldKind::Scalar, "String"),
+    ("IssueNewCommentNotificationWebhookPayload", "type", FieldKind::Scalar, "IssueNewCommentNotificationType"),
+    ("IssueNewCommentNotificationWebhookPayload", "updatedAt", FieldKind::Scalar, "String"),
+    ("IssueNewCommentNotificationWebhookPayload", "userId", FieldKind::Scalar, "String"),
+    ("IssueNotification", "actor", FieldKind::Object, "User"),
+    ("IssueNotification", "actorAvatarColor", FieldKind::Scalar, "String"),
+    ("IssueNotification", "actorAvatarUrl", FieldKind::Scalar, "String"),
+    ("IssueNotification", "actorInitials", FieldKind::Scalar, "String"),
+    ("IssueNotification", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("IssueNotification", "botActor", FieldKind::Object, "ActorBot"),
+    ("IssueNotification", "category", FieldKind::Scalar, "NotificationCategory"),
+    ("IssueNotification", "comment", FieldKind::Object, "Comment"),
+    ("IssueNotification", "commentId", FieldKind::Scalar, "String"),
+    ("IssueNotification", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("IssueNotification", "emailedAt", FieldKind::Scalar, "DateTime"),
+    ("IssueNotification", "externalUserActor", FieldKind::Object, "ExternalUser"),
+    ("IssueNotification", "groupingKey", FieldKind::Scalar, "String"),
+    ("IssueNotification", "groupingPriority", FieldKind::Scalar, "Float"),
+    ("IssueNotification", "id", FieldKind::Scalar, "ID"),
+    ("IssueNotification", "inboxUrl", FieldKind::Scalar, "String"),
+    ("IssueNotification", "initiativeUpdateHealth", FieldKind::Scalar, "String"),
+    ("IssueNotification", "isLinearActor", FieldKind::Scalar, "Boolean"),
+    ("IssueNotification", "issue", FieldKind::Object, "Issue"),
+    ("IssueNotification", "issueId", FieldKind::Scalar, "String"),
+    ("IssueNotification", "issueStatusType", FieldKind::Scalar, "String"),
+    ("IssueNotification", "parentComment", FieldKind::Object, "Comment"),
+    ("IssueNotification", "parentCommentId", FieldKind::Scalar, "String"),
+    ("IssueNotification", "projectUpdateHealth", FieldKind::Scalar, "String"),
+    ("IssueNotification", "reactionEmoji", FieldKind::Scalar, "String"),
+    ("IssueNotification", "readAt", FieldKind::Scalar, "DateTime"),
+    ("IssueNotification", "snoozedUntilAt", FieldKind::Scalar, "DateTime"),
+    ("IssueNotification", "subscriptions", FieldKind::Object, "NotificationSubscription"),
+    ("IssueNotification", "subtitle", FieldKind::Scalar, "String"),
+    ("IssueNotification", "team", FieldKind::Object, "Team"),
+    ("IssueNotification", "title", FieldKind::Scalar, "String"),
+    ("IssueNotification", "type", FieldKind::Scalar, "String"),
+    ("IssueNotification", "unsnoozedAt", FieldKind::Scalar, "DateTime"),
+    ("IssueNotification", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("IssueNotification", "url", FieldKind::Scalar, "String"),
+    ("IssueNotification", "user", FieldKind::Object, "User"),
+    ("IssuePayload", "issue", FieldKind::Object, "Issue"),
+    ("IssuePayload", "lastSyncId", FieldKind::Scalar, "Float"),
+    ("IssuePayload", "success", FieldKind::Scalar, "Boolean"),
+    ("IssuePriorityValue", "label", FieldKind::Scalar, "String"),
+    ("IssuePriorityValue", "priority", FieldKind::Scalar, "Int"),
+    ("IssueRelation", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("IssueRelation", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("IssueRelation", "id", FieldKind::Scalar, "ID"),
+    ("IssueRelation", "issue", FieldKind::Object, "Issue"),
+    ("IssueRelation", "relatedIssue", FieldKind::Object, "Issue"),
+    ("IssueRelation", "type", FieldKind::Scalar, "String"),
+    ("IssueRelation", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("IssueRelationConnection", "edges", FieldKind::Object, "IssueRelationEdge"),
+    ("IssueRelationConnection", "nodes", FieldKind::Object, "IssueRelation"),
+    ("IssueRelationConnection", "pageInfo", FieldKind::Object, "PageInfo"),
+    ("IssueRelationEdge", "cursor", FieldKind::Scalar, "String"),
+    ("IssueRelationEdge", "node", FieldKind::Object, "IssueRelation"),
+    ("IssueRelationHistoryPayload", "identifier", FieldKind::Scalar, "String"),
+    ("IssueRelationHistoryPayload", "type", FieldKind::Scalar, "String"),
+    ("IssueRelationPayload", "issueRelation", FieldKind::Object, "IssueRelation"),
+    ("IssueRelationPayload", "lastSyncId", FieldKind::Scalar, "Float"),
+    ("IssueRelationPayload", "success", FieldKind::Scalar, "Boolean"),
+    ("IssueSearchPayload", "archivePayload", FieldKind::Object, "ArchiveResponse"),
+    ("IssueSearchPayload", "edges", FieldKind::Object, "IssueSearchResultEdge"),
+    ("IssueSearchPayload", "nodes", FieldKind::Object, "IssueSearchResult"),
+    ("IssueSearchPayload", "pageInfo", FieldKind::Object, "PageInfo"),
+    ("IssueSearchPayload", "totalCount", FieldKind::Scalar, "Float"),
+    ("IssueSearchResult", "activitySummary", FieldKind::Scalar, "JSONObject"),
+    ("IssueSearchResult", "addedToCycleAt", FieldKind::Scalar, "DateTime"),
+    ("IssueSearchResult", "addedToProjectAt", FieldKind::Scalar, "DateTime"),
+    ("IssueSearchResult", "addedToTeamAt", FieldKind::Scalar, "DateTime"),
+    ("IssueSearchResult", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("IssueSearchResult", "asksExternalUserRequester", FieldKind::Object, "ExternalUser"),
+    ("IssueSearchResult", "asksRequester", FieldKind::Object, "User"),
+    ("IssueSearchResult", "assignee", FieldKind::Object, "User"),
+    ("IssueSearchResult", "attachments", FieldKind::Connection, "AttachmentConnection"),
+    ("IssueSearchResult", "autoArchivedAt", FieldKind::Scalar, "DateTime"),
+    ("IssueSearchResult", "autoClosedAt", FieldKind::Scalar, "DateTime"),
+    ("IssueSearchResult", "boardOrder", FieldKind::Scalar, "Float"),
+    ("IssueSearchResult", "botActor", FieldKind::Object, "ActorBot"),
+    ("IssueSearchResult", "branchName", FieldKind::Scalar, "String"),
+    ("IssueSearchResult", "canceledAt", FieldKind::Scalar, "DateTime"),
+    ("IssueSearchResult", "children", FieldKind::Connection, "IssueConnection"),
+    ("IssueSearchResult", "comments", FieldKind::Connection, "CommentConnection"),
+    ("IssueSearchResult", "completedAt", FieldKind::Scalar, "DateTime"),
+    ("IssueSearchResult", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("IssueSearchResult", "creator", FieldKind::Object, "User"),
+    ("IssueSearchResult", "customerTicketCount", FieldKind::Scalar, "Int"),
+    ("IssueSearchResult", "cycle", FieldKind::Object, "Cycle"),
+    ("IssueSearchResult", "delegate", FieldKind::Object, "User"),
+    ("IssueSearchResult", "description", FieldKind::Scalar, "String"),
+    ("IssueSearchResult", "descriptionState", FieldKind::Scalar, "String"),
+    ("IssueSearchResult", "documentContent", FieldKind::Object, "DocumentContent"),
+    ("IssueSearchResult", "documents", FieldKind::Connection, "DocumentConnection"),
+    ("IssueSearchResult", "dueDate", FieldKind::Scalar, "TimelessDate"),
+    ("IssueSearchResult", "estimate", FieldKind::Scalar, "Float"),
+    ("IssueSearchResult", "externalUserCreator", FieldKind::Object, "ExternalUser"),
+    ("IssueSearchResult", "favorite", FieldKind::Object, "Favorite"),
+    ("IssueSearchResult", "formerAttachments", FieldKind::Connection, "AttachmentConnection"),
+    ("IssueSearchResult", "formerNeeds", FieldKind::Connection, "CustomerNeedConnection"),
+    ("IssueSearchResult", "history", FieldKind::Connection, "IssueHistoryConnection"),
+    ("IssueSearchResult", "id", FieldKind::Scalar, "ID"),
+    ("IssueSearchResult", "identifier", FieldKind::Scalar, "String"),
+    ("IssueSearchResult", "incomingSuggestions", FieldKind::Connection, "IssueSuggestionConnection"),
+    ("IssueSearchResult", "integrationSourceType", FieldKind::Scalar, "IntegrationService"),
+    ("IssueSearchResult", "inverseRelations", FieldKind::Connection, "IssueRelationConnection"),
+    ("IssueSearchResult", "labelIds", FieldKind::Scalar, "String"),
+    ("IssueSearchResult", "labels", FieldKind::Connection, "IssueLabelConnection"),
+    ("IssueSearchResult", "lastAppliedTemplate", FieldKind::Object, "Template"),
+    ("IssueSearchResult", "metadata", FieldKind::Scalar, "JSONObject"),
+    ("IssueSearchResult", "needs", FieldKind::Connection, "CustomerNeedConnection"),
+    ("IssueSearchResult", "number", FieldKind::Scalar, "Float"),
+    ("IssueSearchResult", "parent", FieldKind::Object, "Issue"),
+    ("IssueSearchResult", "previousIdentifiers", FieldKind::Scalar, "String"),
+    ("IssueSearchResult", "priority", FieldKind::Scalar, "Float"),
+    ("IssueSearchResult", "priorityLabel", FieldKind::Scalar, "String"),
+    ("IssueSearchResult", "prioritySortOrder", FieldKind::Scalar, "Float"),
+    ("IssueSearchResult", "project", FieldKind::Object, "Project"),
+    ("IssueSearchResult", "projectMilestone", FieldKind::Object, "ProjectMilestone"),
+    ("IssueSearchResult", "reactionData", FieldKind::Scalar, "JSONObject"),
+    ("IssueSearchResult", "reactions", FieldKind::Object, "Reaction"),
+    ("IssueSearchResult", "recurringIssueTemplate", FieldKind::Object, "Template"),
+    ("IssueSearchResult", "relations", FieldKind::Connection, "IssueRelationConnection"),
+    ("IssueSearchResult", "slaBreachesAt", FieldKind::Scalar, "DateTime"),
+    ("IssueSearchResult", "slaHighRiskAt", FieldKind::Scalar, "DateTime"),
+    ("IssueSearchResult", "slaMediumRiskAt", FieldKind::Scalar, "DateTime"),
+    ("IssueSearchResult", "slaStartedAt", FieldKind::Scalar, "DateTime"),
+    ("IssueSearchResult", "slaType", FieldKind::Scalar, "String"),
+    ("IssueSearchResult", "snoozedBy", FieldKind::Object, "User"),
+    ("IssueSearchResult", "snoozedUntilAt", FieldKind::Scalar, "DateTime"),
+    ("IssueSearchResult", "sortOrder", FieldKind::Scalar, "Float"),
+    ("IssueSearchResult", "sourceComment", FieldKind::Object, "Comment"),
+    ("IssueSearchResult", "startedAt", FieldKind::Scalar, "DateTime"),
+    ("IssueSearchResult", "startedTriageAt", FieldKind::Scalar, "DateTime"),
+    ("IssueSearchResult", "state", FieldKind::Object, "WorkflowState"),
+    ("IssueSearchResult", "stateHistory", FieldKind::Connection, "IssueStateSpanConnection"),
+    ("IssueSearchResult", "subIssueSortOrder", FieldKind::Scalar, "Float"),
+    ("IssueSearchResult", "subscribers", FieldKind::Connection, "UserConnection"),
+    ("IssueSearchResult", "suggestions", FieldKind::Connection, "IssueSuggestionConnection"),
+    ("IssueSearchResult", "suggestionsGeneratedAt", FieldKind::Scalar, "DateTime"),
+    ("IssueSearchResult", "syncedWith", FieldKind::Object, "ExternalEntityInfo"),
+    ("IssueSearchResult", "team", FieldKind::Object, "Team"),
+    ("IssueSearchResult", "title", FieldKind::Scalar, "String"),
+    ("IssueSearchResult", "trashed", FieldKind::Scalar, "Boolean"),
+    ("IssueSearchResult", "triagedAt", FieldKind::Scalar, "DateTime"),
+    ("IssueSearchResult", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("IssueSearchResult", "url", FieldKind::Scalar, "String"),
+    ("IssueSearchResultEdge", "cursor", FieldKind::Scalar, "String"),
+    ("IssueSearchResultEdge", "node", FieldKind::Object, "IssueSearchResult"),
+    ("IssueSlaWebhookPayload", "action", FieldKind::Scalar, "String"),
+    ("IssueSlaWebhookPayload", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("IssueSlaWebhookPayload", "issueData", FieldKind::Object, "IssueWebhookPayload"),
+    ("IssueSlaWebhookPayload", "organizationId", FieldKind::Scalar, "String"),
+    ("IssueSlaWebhookPayload", "type", FieldKind::Scalar, "String"),
+    ("IssueSlaWebhookPayload", "url", FieldKind::Scalar, "String"),
+    ("IssueSlaWebhookPayload", "webhookId", FieldKind::Scalar, "String"),
+    ("IssueSlaWebhookPayload", "webhookTimestamp", FieldKind::Scalar, "Float"),
+    ("IssueStateSpan", "endedAt", FieldKind::Scalar, "DateTime"),
+    ("IssueStateSpan", "id", FieldKind::Scalar, "ID"),
+    ("IssueStateSpan", "startedAt", FieldKind::Scalar, "DateTime"),
+    ("IssueStateSpan", "state", FieldKind::Object, "WorkflowState"),
+    ("IssueStateSpan", "stateId", FieldKind::Scalar, "ID"),
+    ("IssueStateSpanConnection", "edges", FieldKind::Object, "IssueStateSpanEdge"),
+    ("IssueStateSpanConnection", "nodes", FieldKind::Object, "IssueStateSpan"),
+    ("IssueStateSpanConnection", "pageInfo", FieldKind::Object, "PageInfo"),
+    ("IssueStateSpanEdge", "cursor", FieldKind::Scalar, "String"),
+    ("IssueStateSpanEdge", "node", FieldKind::Object, "IssueStateSpan"),
+    ("IssueStatusChangedNotificationWebhookPayload", "actor", FieldKind::Object, "UserChildWebhookPayload"),
+    ("IssueStatusChangedNotificationWebhookPayload", "actorId", FieldKind::Scalar, "String"),
+    ("IssueStatusChangedNotificationWebhookPayload", "archivedAt", FieldKind::Scalar, "String"),
+    ("IssueStatusChangedNotificationWebhookPayload", "createdAt", FieldKind::Scalar, "String"),
+    ("IssueStatusChangedNotificationWebhookPayload", "externalUserActorId", FieldKind::Scalar, "String"),
+    ("IssueStatusChangedNotificationWebhookPayload", "id", FieldKind::Scalar, "String"),
+    ("IssueStatusChangedNotificationWebhookPayload", "issue", FieldKind::Object, "IssueWithDescriptionChildWebhookPayload"),
+    ("IssueStatusChangedNotificationWebhookPayload", "issueId", FieldKind::Scalar, "String"),
+    ("IssueStatusChangedNotificationWebhookPayload", "type", FieldKind::Scalar, "IssueStatusChangedNotificationType"),
+    ("IssueStatusChangedNotificationWebhookPayload", "updatedAt", FieldKind::Scalar, "String"),
+    ("IssueStatusChangedNotificationWebhookPayload", "userId", FieldKind::Scalar, "String"),
+    ("IssueSuggestion", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("IssueSuggestion", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("IssueSuggestion", "dismissalReason", FieldKind::Scalar, "String"),
+    ("IssueSuggestion", "id", FieldKind::Scalar, "ID"),
+    ("IssueSuggestion", "issue", FieldKind::Object, "Issue"),
+    ("IssueSuggestion", "issueId", FieldKind::Scalar, "String"),
+    ("IssueSuggestion", "metadata", FieldKind::Object, "IssueSuggestionMetadata"),
+    ("IssueSuggestion", "state", FieldKind::Scalar, "IssueSuggestionState"),
+    ("IssueSuggestion", "stateChangedAt", FieldKind::Scalar, "DateTime"),
+    ("IssueSuggestion", "suggestedIssue", FieldKind::Object, "Issue"),
+    ("IssueSuggestion", "suggestedIssueId", FieldKind::Scalar, "String"),
+    ("IssueSuggestion", "suggestedLabel", FieldKind::Object, "IssueLabel"),
+    ("IssueSuggestion", "suggestedLabelId", FieldKind::Scalar, "String"),
+    ("IssueSuggestion", "suggestedProject", FieldKind::Object, "Project"),
+    ("IssueSuggestion", "suggestedTeam", FieldKind::Object, "Team"),
+    ("IssueSuggestion", "suggestedUser", FieldKind::Object, "User"),
+    ("IssueSuggestion", "suggestedUserId", FieldKind::Scalar, "String"),
+    ("IssueSuggestion", "type", FieldKind::Scalar, "IssueSuggestionType"),
+    ("IssueSuggestion", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("IssueSuggestionConnection", "edges", FieldKind::Object, "IssueSuggestionEdge"),
+    ("IssueSuggestionConnection", "nodes", FieldKind::Object, "IssueSuggestion"),
+    ("IssueSuggestionConnection", "pageInfo", FieldKind::Object, "PageInfo"),
+    ("IssueSuggestionEdge", "cursor", FieldKind::Scalar, "String"),
+    ("IssueSuggestionEdge", "node", FieldKind::Object, "IssueSuggestion"),
+    ("IssueSuggestionMetadata", "appliedAutomationRuleId", FieldKind::Scalar, "String"),
+    ("IssueSuggestionMetadata", "classification", FieldKind::Scalar, "String"),
+    ("IssueSuggestionMetadata", "evalLogId", FieldKind::Scalar, "String"),
+    ("IssueSuggestionMetadata", "rank", FieldKind::Scalar, "Float"),
+    ("IssueSuggestionMetadata", "reasons", FieldKind::Scalar, "String"),
+    ("IssueSuggestionMetadata", "score", FieldKind::Scalar, "Float"),
+    ("IssueSuggestionMetadata", "variant", FieldKind::Scalar, "String"),
+    ("IssueTitleSuggestionFromCustomerRequestPayload", "lastSyncId", FieldKind::Scalar, "Float"),
+    ("IssueTitleSuggestionFromCustomerRequestPayload", "logId", FieldKind::Scalar, "String"),
+    ("IssueTitleSuggestionFromCustomerRequestPayload", "title", FieldKind::Scalar, "String"),
+    ("IssueToRelease", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("IssueToRelease", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("IssueToRelease", "id", FieldKind::Scalar, "ID"),
+    ("IssueToRelease", "issue", FieldKind::Object, "Issue"),
+    ("IssueToRelease", "release", FieldKind::Object, "Release"),
+    ("IssueToRelease", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("IssueToReleaseConnection", "edges", FieldKind::Object, "IssueToReleaseEdge"),
+    ("IssueToReleaseConnection", "nodes", FieldKind::Object, "IssueToRelease"),
+    ("IssueToReleaseConnection", "pageInfo", FieldKind::Object, "PageInfo"),
+    ("IssueToReleaseEdge", "cursor", FieldKind::Scalar, "String"),
+    ("IssueToReleaseEdge", "node", FieldKind::Object, "IssueToRelease"),
+    ("IssueToReleasePayload", "issueToRelease", FieldKind::Object, "IssueToRelease"),
+    ("IssueToReleasePayload", "lastSyncId", FieldKind::Scalar, "Float"),
+    ("IssueToReleasePayload", "success", FieldKind::Scalar, "Boolean"),
+    ("IssueUnassignedFromYouNotificationWebhookPayload", "actor", FieldKind::Object, "UserChildWebhookPayload"),
+    ("IssueUnassignedFromYouNotificationWebhookPayload", "actorId", FieldKind::Scalar, "String"),
+    ("IssueUnassignedFromYouNotificationWebhookPayload", "archivedAt", FieldKind::Scalar, "String"),
+    ("IssueUnassignedFromYouNotificationWebhookPayload", "createdAt", FieldKind::Scalar, "String"),
+    ("IssueUnassignedFromYouNotificationWebhookPayload", "externalUserActorId", FieldKind::Scalar, "String"),
+    ("IssueUnassignedFromYouNotificationWebhookPayload", "id", FieldKind::Scalar, "String"),
+    ("IssueUnassignedFromYouNotificationWebhookPayload", "issue", FieldKind::Object, "IssueWithDescriptionChildWebhookPayload"),
+    ("IssueUnassignedFromYouNotificationWebhookPayload", "issueId", FieldKind::Scalar, "String"),
+    ("IssueUnassignedFromYouNotificationWebhookPayload", "type", FieldKind::Scalar, "IssueUnassignedFromYouNotificationType"),
+    ("IssueUnassignedFromYouNotificationWebhookPayload", "updatedAt", FieldKind::Scalar, "String"),
+    ("IssueUnassignedFromYouNotificationWebhookPayload", "userId", FieldKind::Scalar, "String"),
+    ("IssueWebhookPayload", "addedToCycleAt", FieldKind::Scalar, "String"),
+    ("IssueWebhookPayload", "addedToProjectAt", FieldKind::Scalar, "String"),
+    ("IssueWebhookPayload", "addedToTeamAt", FieldKind::Scalar, "String"),
+    ("IssueWebhookPayload", "archivedAt", FieldKind::Scalar, "String"),
+    ("IssueWebhookPayload", "assignee", FieldKind::Object, "UserChildWebhookPayload"),
+    ("IssueWebhookPayload", "assigneeId", FieldKind::Scalar, "String"),
+    ("IssueWebhookPayload", "autoArchivedAt", FieldKind::Scalar, "String"),
+    ("IssueWebhookPayload", "autoClosedAt", FieldKind::Scalar, "String"),
+    ("IssueWebhookPayload", "botActor", FieldKind::Scalar, "String"),
+    ("IssueWebhookPayload", "canceledAt", FieldKind::Scalar, "String"),
+    ("IssueWebhookPayload", "completedAt", FieldKind::Scalar, "String"),
+    ("IssueWebhookPayload", "createdAt", FieldKind::Scalar, "String"),
+    ("IssueWebhookPayload", "creator", FieldKind::Object, "UserChildWebhookPayload"),
+    ("IssueWebhookPayload", "creatorId", FieldKind::Scalar, "String"),
+    ("IssueWebhookPayload", "cycle", FieldKind::Object, "CycleChildWebhookPayload"),
+    ("IssueWebhookPayload", "cycleId", FieldKind::Scalar, "String"),
+    ("IssueWebhookPayload", "delegate", FieldKind::Object, "UserChildWebhookPayload"),
+    ("IssueWebhookPayload", "delegateId", FieldKind::Scalar, "String"),
+    ("IssueWebhookPayload", "description", FieldKind::Scalar, "String"),
+    ("IssueWebhookPayload", "descriptionData", FieldKind::Scalar, "String"),
+    ("IssueWebhookPayload", "dueDate", FieldKind::Scalar, "String"),
+    ("IssueWebhookPayload", "estimate", FieldKind::Scalar, "Float"),
+    ("IssueWebhookPayload", "externalUserCreator", FieldKind::Object, "ExternalUserChildWebhookPayload"),
+    ("IssueWebhookPayload", "externalUserCreatorId", FieldKind::Scalar, "String"),
+    ("IssueWebhookPayload", "id", FieldKind::Scalar, "String"),
+    ("IssueWebhookPayload", "identifier", FieldKind::Scalar, "String"),
+    ("IssueWebhookPayload", "integrationSourceType", FieldKind::Scalar, "String"),
+    ("IssueWebhookPayload", "labelIds", FieldKind::Scalar, "String"),
+    ("IssueWebhookPayload", "labels", FieldKind::Object, "IssueLabelChildWebhookPayload"),
+    ("IssueWebhookPayload", "lastAppliedTemplateId", FieldKind::Scalar, "String"),
+    ("IssueWebhookPayload", "number", FieldKind::Scalar, "Float"),
+    ("IssueWebhookPayload", "parentId", FieldKind::Scalar, "String"),
+    ("IssueWebhookPayload", "previousIdentifiers", FieldKind::Scalar, "String"),
+    ("IssueWebhookPayload", "priority", FieldKind::Scalar, "Float"),
+    ("IssueWebhookPayload", "priorityLabel", FieldKind::Scalar, "String"),
+    ("IssueWebhookPayload", "prioritySortOrder", FieldKind::Scalar, "Float"),
+    ("IssueWebhookPayload", "project", FieldKind::Object, "ProjectChildWebhookPayload"),
+    ("IssueWebhookPayload", "projectId", FieldKind::Scalar, "String"),
+    ("IssueWebhookPayload", "projectMilestone", FieldKind::Object, "ProjectMilestoneChildWebhookPayload"),
+    ("IssueWebhookPayload", "projectMilestoneId", FieldKind::Scalar, "String"),
+    ("IssueWebhookPayload", "reactionData", FieldKind::Scalar, "JSONObject"),
+    ("IssueWebhookPayload", "recurringIssueTemplateId", FieldKind::Scalar, "String"),
+    ("IssueWebhookPayload", "slaBreachesAt", FieldKind::Scalar, "String"),
+    ("IssueWebhookPayload", "slaHighRiskAt", FieldKind::Scalar, "String"),
+    ("IssueWebhookPayload", "slaMediumRiskAt", FieldKind::Scalar, "String"),
+    ("IssueWebhookPayload", "slaStartedAt", FieldKind::Scalar, "String"),
+    ("IssueWebhookPayload", "slaType", FieldKind::Scalar, "String"),
+    ("IssueWebhookPayload", "snoozedUntilAt", FieldKind::Scalar, "String"),
+    ("IssueWebhookPayload", "sortOrder", FieldKind::Scalar, "Float"),
+    ("IssueWebhookPayload", "sourceCommentId", FieldKind::Scalar, "String"),
+    ("IssueWebhookPayload", "startedAt", FieldKind::Scalar, "String"),
+    ("IssueWebhookPayload", "startedTriageAt", FieldKind::Scalar, "String"),
+    ("IssueWebhookPayload", "state", FieldKind::Object, "WorkflowStateChildWebhookPayload"),
+    ("IssueWebhookPayload", "stateId", FieldKind::Scalar, "String"),
+    ("IssueWebhookPayload", "subIssueSortOrder", FieldKind::Scalar, "Float"),
+    ("IssueWebhookPayload", "subscriberIds", FieldKind::Scalar, "String"),
+    ("IssueWebhookPayload", "syncedWith", FieldKind::Scalar, "JSONObject"),
+    ("IssueWebhookPayload", "team", FieldKind::Object, "TeamChildWebhookPayload"),
+    ("IssueWebhookPayload", "teamId", FieldKind::Scalar, "String"),
+    ("IssueWebhookPayload", "title", FieldKind::Scalar, "String"),
+    ("IssueWebhookPayload", "trashed", FieldKind::Scalar, "Boolean"),
+    ("IssueWebhookPayload", "triagedAt", FieldKind::Scalar, "String"),
+    ("IssueWebhookPayload", "updatedAt", FieldKind::Scalar, "String"),
+    ("IssueWebhookPayload", "url", FieldKind::Scalar, "String"),
+    ("IssueWithDescriptionChildWebhookPayload", "description", FieldKind::Scalar, "String"),
+    ("IssueWithDescriptionChildWebhookPayload", "id", FieldKind::Scalar, "String"),
+    ("IssueWithDescriptionChildWebhookPayload", "identifier", FieldKind::Scalar, "String"),
+    ("IssueWithDescriptionChildWebhookPayload", "team", FieldKind::Object, "TeamChildWebhookPayload"),
+    ("IssueWithDescriptionChildWebhookPayload", "teamId", FieldKind::Scalar, "String"),
+    ("IssueWithDescriptionChildWebhookPayload", "title", FieldKind::Scalar, "String"),
+    ("IssueWithDescriptionChildWebhookPayload", "url", FieldKind::Scalar, "String"),
+    ("LabelNotificationSubscription", "active", FieldKind::Scalar, "Boolean"),
+    ("LabelNotificationSubscription", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("LabelNotificationSubscription", "contextViewType", FieldKind::Scalar, "ContextViewType"),
+    ("LabelNotificationSubscription", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("LabelNotificationSubscription", "customView", FieldKind::Object, "CustomView"),
+    ("LabelNotificationSubscription", "customer", FieldKind::Object, "Customer"),
+    ("LabelNotificationSubscription", "cycle", FieldKind::Object, "Cycle"),
+    ("LabelNotificationSubscription", "id", FieldKind::Scalar, "ID"),
+    ("LabelNotificationSubscription", "initiative", FieldKind::Object, "Initiative"),
+    ("LabelNotificationSubscription", "label", FieldKind::Object, "IssueLabel"),
+    ("LabelNotificationSubscription", "notificationSubscriptionTypes", FieldKind::Scalar, "String"),
+    ("LabelNotificationSubscription", "project", FieldKind::Object, "Project"),
+    ("LabelNotificationSubscription", "subscriber", FieldKind::Object, "User"),
+    ("LabelNotificationSubscription", "team", FieldKind::Object, "Team"),
+    ("LabelNotificationSubscription", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("LabelNotificationSubscription", "user", FieldKind::Object, "User"),
+    ("LabelNotificationSubscription", "userContextViewType", FieldKind::Scalar, "UserContextViewType"),
+    ("LogoutResponse", "success", FieldKind::Scalar, "Boolean"),
+    ("Mutation", "agentActivityCreate", FieldKind::Object, "AgentActivityPayload"),
+    ("Mutation", "agentActivityCreatePrompt", FieldKind::Object, "AgentActivityPayload"),
+    ("Mutation", "agentSessionCreate", FieldKind::Object, "AgentSessionPayload"),
+    ("Mutation", "agentSessionCreateOnComment", FieldKind::Object, "AgentSessionPayload"),
+    ("Mutation", "agentSessionCreateOnIssue", FieldKind::Object, "AgentSessionPayload"),
+    ("Mutation", "agentSessionUpdate", FieldKind::Object, "AgentSessionPayload"),
+    ("Mutation", "agentSessionUpdateExternalUrl", FieldKind::Object, "AgentSessionPayload"),
+    ("Mutation", "airbyteIntegrationConnect", FieldKind::Object, "IntegrationPayload"),
+    ("Mutation", "asksWebFormsAuth", FieldKind::Object, "AsksWebFormsAuthResponse"),
+    ("Mutation", "attachmentCreate", FieldKind::Object, "AttachmentPayload"),
+    ("Mutation", "attachmentDelete", FieldKind::Object, "DeletePayload"),
+    ("Mutation", "attachmentLinkDiscord", FieldKind::Object, "AttachmentPayload"),
+    ("Mutation", "attachmentLinkFront", FieldKind::Object, "FrontAttachmentPayload"),
+    ("Mutation", "attachmentLinkGitHubIssue", FieldKind::Object, "AttachmentPayload"),
+    ("Mutation", "attachmentLinkGitHubPR", FieldKind::Object, "AttachmentPayload"),
+    ("Mutation", "attachmentLinkGitLabMR", FieldKind::Object, "AttachmentPayload"),
+    ("Mutation", "attachmentLinkIntercom", FieldKind::Object, "AttachmentPayload"),
+    ("Mutation", "attachmentLinkJiraIssue", FieldKind::Object, "AttachmentPayload"),
+    ("Mutation", "attachmentLinkSalesforce", FieldKind::Object, "AttachmentPayload"),
+    ("Mutation", "attachmentLinkSlack", FieldKind::Object, "AttachmentPayload"),
+    ("Mutation", "attachmentLinkURL", FieldKind::Object, "AttachmentPayload"),
+    ("Mutation", "attachmentLinkZendesk", FieldKind::Object, "AttachmentPayload"),
+    ("Mutation", "attachmentSyncToSlack", FieldKind::Object, "AttachmentPayload"),
+    ("Mutation", "attachmentUpdate", FieldKind::Object, "AttachmentPayload"),
+    ("Mutation", "commentCreate", FieldKind::Object, "CommentPayload"),
+    ("Mutation", "commentDelete", FieldKind::Object, "DeletePayload"),
+    ("Mutation", "commentResolve", FieldKind::Object, "CommentPayload"),
+    ("Mutation", "commentUnresolve", FieldKind::Object, "CommentPayload"),
+    ("Mutation", "commentUpdate", FieldKind::Object, "CommentPayload"),
+    ("Mutation", "contactCreate", FieldKind::Object, "ContactPayload"),
+    ("Mutation", "contactSalesCreate", FieldKind::Object, "ContactPayload"),
+    ("Mutation", "createCsvExportReport", FieldKind::Object, "CreateCsvExportReportPayload"),
+    ("Mutation", "createInitiativeUpdateReminder", FieldKind::Object, "InitiativeUpdateReminderPayload"),
+    ("Mutation", "createOrganizationFromOnboarding", FieldKind::Object, "CreateOrJoinOrganizationResponse"),
+    ("Mutation", "createProjectUpdateReminder", FieldKind::Object, "ProjectUpdateReminderPayload"),
+    ("Mutation", "customViewCreate", FieldKind::Object, "CustomViewPayload"),
+    ("Mutation", "customViewDelete", FieldKind::Object, "DeletePayload"),
+    ("Mutation", "customViewUpdate", FieldKind::Object, "CustomViewPayload"),
+    ("Mutation", "customerCreate", FieldKind::Object, "CustomerPayload"),
+    ("Mutation", "customerDelete", FieldKind::Object, "DeletePayload"),
+    ("Mutation", "customerMerge", FieldKind::Object, "CustomerPayload"),
+    ("Mutation", "customerNeedArchive", FieldKind::Object, "CustomerNeedArchivePayload"),
+    ("Mutation", "customerNeedCreate", FieldKind::Object, "CustomerNeedPayload"),
+    ("Mutation", "customerNeedCreateFromAttachment", FieldKind::Object, "CustomerNeedPayload"),
+    ("Mutation", "customerNeedDelete", FieldKind::Object, "DeletePayload"),
+    ("Mutation", "customerNeedUnarchive", FieldKind::Object, "CustomerNeedArchivePayload"),
+    ("Mutation", "customerNeedUpdate", FieldKind::Object, "CustomerNeedUpdatePayload"),
+    ("Mutation", "customerStatusCreate", FieldKind::Object, "CustomerStatusPayload"),
+    ("Mutation", "customerStatusDelete", FieldKind::Object, "DeletePayload"),
+    ("Mutation", "customerStatusUpdate", FieldKind::Object, "CustomerStatusPayload"),
+    ("Mutation", "customerTierCreate", FieldKind::Object, "CustomerTierPayload"),
+    ("Mutation", "customerTierDelete", FieldKind::Object, "DeletePayload"),
+    ("Mutation", "customerTierUpdate", FieldKind::Object, "CustomerTierPayload"),
+    ("Mutation", "customerUnsync", FieldKind::Object, "CustomerPayload"),
+    ("Mutation", "customerUpdate", FieldKind::Object, "CustomerPayload"),
+    ("Mutation", "customerUpsert", FieldKind::Object, "CustomerPayload"),
+    ("Mutation", "cycleArchive", FieldKind::Object, "CycleArchivePayload"),
+    ("Mutation", "cycleCreate", FieldKind::Object, "CyclePayload"),
+    ("Mutation", "cycleShiftAll", FieldKind::Object, "CyclePayload"),
+    ("Mutation", "cycleStartUpcomingCycleToday", FieldKind::Object, "CyclePayload"),
+    ("Mutation", "cycleUpdate", FieldKind::Object, "CyclePayload"),
+    ("Mutation", "documentCreate", FieldKind::Object, "DocumentPayload"),
+    ("Mutation", "documentDelete", FieldKind::Object, "DocumentArchivePayload"),
+    ("Mutation", "documentUnarchive", FieldKind::Object, "DocumentArchivePayload"),
+    ("Mutation", "documentUpdate", FieldKind::Object, "DocumentPayload"),
+    ("Mutation", "emailIntakeAddressCreate", FieldKind::Object, "EmailIntakeAddressPayload"),
+    ("Mutation", "emailIntakeAddressDelete", FieldKind::Object, "DeletePayload"),
+    ("Mutation", "emailIntakeAddressRotate", FieldKind::Object, "EmailIntakeAddressPayload"),
+    ("Mutation", "emailIntakeAddressUpdate", FieldKind::Object, "EmailIntakeAddressPayload"),
+    ("Mutation", "emailTokenUserAccountAuth", FieldKind::Object, "AuthResolverResponse"),
+    ("Mutation", "emailUnsubscribe", FieldKind::Object, "EmailUnsubscribePayload"),
+    ("Mutation", "emailUserAccountAuthChallenge", FieldKind::Object, "EmailUserAccountAuthChallengeResponse"),
+    ("Mutation", "emojiCreate", FieldKind::Object, "EmojiPayload"),
+    ("Mutation", "emojiDelete", FieldKind::Object, "DeletePayload"),
+    ("Mutation", "entityExternalLinkCreate", FieldKind::Object, "EntityExternalLinkPayload"),
+    ("Mutation", "entityExternalLinkDelete", FieldKind::Object, "DeletePayload"),
+    ("Mutation", "entityExternalLinkUpdate", FieldKind::Object, "EntityExternalLinkPayload"),
+    ("Mutation", "favoriteCreate", FieldKind::Object, "FavoritePayload"),
+    ("Mutation", "favoriteDelete", FieldKind::Object, "DeletePayload"),
+    ("Mutation", "favoriteUpdate", FieldKind::Object, "FavoritePayload"),
+    ("Mutation", "fileUpload", FieldKind::Object, "UploadPayload"),
+    ("Mutation", "fileUploadDangerouslyDelete", FieldKind::Object, "FileUploadDeletePayload"),
+    ("Mutation", "gitAutomationStateCreate", FieldKind::Object, "GitAutomationStatePayload"),
+    ("Mutation", "gitAutomationStateDelete", FieldKind::Object, "DeletePayload"),
+    ("Mutation", "gitAutomationStateUpdate", FieldKind::Object, "GitAutomationStatePayload"),
+    ("Mutation", "gitAutomationTargetBranchCreate", FieldKind::Object, "GitAutomationTargetBranchPayload"),
+    ("Mutation", "gitAutomationTargetBranchDelete", FieldKind::Object, "DeletePayload"),
+    ("Mutation", "gitAutomationTargetBranchUpdate", FieldKind::Object, "GitAutomationTargetBranchPayload"),
+    ("Mutation", "googleUserAccountAuth", FieldKind::Object, "AuthResolverResponse"),
+    ("Mutation", "imageUploadFromUrl", FieldKind::Object, "ImageUploadFromUrlPayload"),
+    ("Mutation", "importFileUpload", FieldKind::Object, "UploadPayload"),
+    ("Mutation", "initiativeArchive", FieldKind::Object, "InitiativeArchivePayload"),
+    ("Mutation", "initiativeCreate", FieldKind::Object, "InitiativePayload"),
+    ("Mutation", "initiativeDelete", FieldKind::Object, "DeletePayload"),
+    ("Mutation", "initiativeRelationCreate", FieldKind::Object, "InitiativeRelationPayload"),
+    ("Mutation", "initiativeRelationDelete", FieldKind::Object, "DeletePayload"),
+    ("Mutation", "initiativeRelationUpdate", FieldKind::Object, "DeletePayload"),
+    ("Mutation", "initiativeToProjectCreate", FieldKind::Object, "InitiativeToProjectPayload"),
+    ("Mutation", "initiativeToProjectDelete", FieldKind::Object, "DeletePayload"),
+    ("Mutation", "initiativeToProjectUpdate", FieldKind::Object, "InitiativeToProjectPayload"),
+    ("Mutation", "initiativeUnarchive", FieldKind::Object, "InitiativeArchivePayload"),
+    ("Mutation", "initiativeUpdate", FieldKind::Object, "InitiativePayload"),
+    ("Mutation", "initiativeUpdateArchive", FieldKind::Object, "InitiativeUpdateArchivePayload"),
+    ("Mutation", "initiativeUpdateCreate", FieldKind::Object, "InitiativeUpdatePayload"),
+    ("Mutation", "initiativeUpdateUnarchive", FieldKind::Object, "InitiativeUpdateArchivePayload"),
+    ("Mutation", "initiativeUpdateUpdate", FieldKind::Object, "InitiativeUpdatePayload"),
+    ("Mutation", "integrationArchive", FieldKind::Object, "DeletePayload"),
+    ("Mutation", "integrationAsksConnectChannel", FieldKind::Object, "AsksChannelConnectPayload"),
+    ("Mutation", "integrationCustomerDataAttributesRefresh", FieldKind::Object, "IntegrationPayload"),
+    ("Mutation", "integrationDelete", FieldKind::Object, "DeletePayload"),
+    ("Mutation", "integrationDiscord", FieldKind::Object, "IntegrationPayload"),
+    ("Mutation", "integrationFigma", FieldKind::Object, "IntegrationPayload"),
+    ("Mutation", "integrationFront", FieldKind::Object, "IntegrationPayload"),
+    ("Mutation", "integrationGitHubEnterpriseServerConnect", FieldKind::Object, "GitHubEnterpriseServerPayload"),
+    ("Mutation", "integrationGitHubPersonal", FieldKind::Object, "IntegrationPayload"),
+    ("Mutation", "integrationGithubCommitCreate", FieldKind::Object, "GitHubCommitIntegrationPayload"),
+    ("Mutation", "integrationGithubConnect", FieldKind::Object, "IntegrationPayload"),
+    ("Mutation", "integrationGithubImportConnect", FieldKind::Object, "IntegrationPayload"),
+    ("Mutation", "integrationGithubImportRefresh", FieldKind::Object, "IntegrationPayload"),
+    ("Mutation", "integrationGitlabConnect", FieldKind::Object, "GitLabIntegrationCreatePayload"),
+    ("Mutation", "integrationGong", FieldKind::Object, "IntegrationPayload"),
+    ("Mutation", "integrationGoogleCalendarPersonalConnect", FieldKind::Object, "IntegrationPayload"),
+    ("Mutation", "integrationGoogleSheets", FieldKind::Object, "IntegrationPayload"),
+    ("Mutation", "integrationIntercom", FieldKind::Object, "IntegrationPayload"),
+    ("Mutation", "integrationIntercomDelete", FieldKind::Object, "IntegrationPayload"),
+    ("Mutation", "integrationIntercomSettingsUpdate", FieldKind::Object, "IntegrationPayload"),
+    ("Mutation", "integrationJiraPersonal", FieldKind::Object, "IntegrationPayload"),
+    ("Mutation", "integrationJiraUpdate", FieldKind::Object, "IntegrationPayload"),
+    ("Mutation", "integrationLaunchDarklyConnect", FieldKind::Object, "IntegrationPayload"),
+    ("Mutation", "integrationLaunchDarklyPersonalConnect", FieldKind::Object, "IntegrationPayload"),
+    ("Mutation", "integrationLoom", FieldKind::Object, "IntegrationPayload"),
+    ("Mutation", "integrationMcpServerConnect", FieldKind::Object, "IntegrationPayload"),
+    ("Mutation", "integrationMcpServerPersonalConnect", FieldKind::Object, "IntegrationPayload"),
+    ("Mutation", "integrationOpsgenieConnect", FieldKind::Object, "IntegrationPayload"),
+    ("Mutation", "integrationOpsgenieRefreshScheduleMappings", FieldKind::Object, "IntegrationPayload"),
+    ("Mutation", "integrationPagerDutyConnect", FieldKind::Object, "IntegrationPayload"),
+    ("Mutation", "integrationPagerDutyRefreshScheduleMappings", FieldKind::Object, "IntegrationPayload"),
+    ("Mutation", "integrationRequest", FieldKind::Object, "IntegrationRequestPayload"),
+    ("Mutation", "integrationSalesforce", FieldKind::Object, "IntegrationPayload"),
+    ("Mutation", "integrationSalesforceMetadataRefresh", FieldKind::Object, "IntegrationPayload"),
+    ("Mutation", "integrationSentryConnect", FieldKind::Object, "IntegrationPayload"),
+    ("Mutation", "integrationSettingsUpdate", FieldKind::Object, "IntegrationPayload"),
+    ("Mutation", "integrationSlack", FieldKind::Object, "IntegrationPayload"),
+    ("Mutation", "integrationSlackAsks", FieldKind::Object, "IntegrationPayload"),
+    ("Mutation", "integrationSlackCustomViewNotifications", FieldKind::Object, "SlackChannelConnectPayload"),
+    ("Mutation", "integrationSlackCustomerChannelLink", FieldKind::Object, "SuccessPayload"),
+    ("Mutation", "integrationSlackImportEmojis", FieldKind::Object, "IntegrationPayload"),
+    ("Mutation", "integrationSlackInitiativePost", FieldKind::Object, "SlackChannelConnectPayload"),
+    ("Mutation", "integrationSlackOrAsksUpdateSlackTeamName", FieldKind::Object, "IntegrationSlackWorkspaceNamePayload"),
+    ("Mutation", "integrationSlackOrgInitiativeUpdatesPost", FieldKind::Object, "SlackChannelConnectPayload"),
+    ("Mutation", "integrationSlackOrgProjectUpdatesPost", FieldKind::Object, "SlackChannelConnectPayload"),
+    ("Mutation", "integrationSlackPersonal", FieldKind::Object, "IntegrationPayload"),
+    ("Mutation", "integrationSlackPost", FieldKind::Object, "SlackChannelConnectPayload"),
+    ("Mutation", "integrationSlackProjectPost", FieldKind::Object, "SlackChannelConnectPayload"),
+    ("Mutation", "integrationSlackWorkflowAccessUpdate", FieldKind::Object, "IntegrationPayload"),
+    ("Mutation", "integrationTemplateCreate", FieldKind::Object, "IntegrationTemplatePayload"),
+    ("Mutation", "integrationTemplateDelete", FieldKind::Object, "DeletePayload"),
+    ("Mutation", "integrationUpdate", FieldKind::Object, "IntegrationPayload"),
+    ("Mutation", "integrationZendesk", FieldKind::Object, "IntegrationPayload"),
+    ("Mutation", "integrationsSettingsCreate", FieldKind::Object, "IntegrationsSettingsPayload"),
+    ("Mutation", "integrationsSettingsUpdate", FieldKind::Object, "IntegrationsSettingsPayload"),
+    ("Mutation", "issueAddLabel", FieldKind::Object, "IssuePayload"),
+    ("Mutation", "issueArchive", FieldKind::Object, "IssueArchivePayload"),
+    ("Mutation", "issueBatchCreate", FieldKind::Object, "IssueBatchPayload"),
+    ("Mutation", "issueBatchUpdate", FieldKind::Object, "IssueBatchPayload"),
+    ("Mutation", "issueCreate", FieldKind::Object, "IssuePayload"),
+    ("Mutation", "issueDelete", FieldKind::Object, "IssueArchivePayload"),
+    ("Mutation", "issueDescriptionUpdateFromFront", FieldKind::Object, "IssuePayload"),
+    ("Mutation", "issueExternalSyncDisable", FieldKind::Object, "IssuePayload"),
+    ("Mutation", "issueImportCreateAsana", FieldKind::Object, "IssueImportPayload"),
+    ("Mutation", "issueImportCreateCSVJira", FieldKind::Object, "IssueImportPayload"),
+    ("Mutation", "issueImportCreateClubhouse", FieldKind::Object, "IssueImportPayload"),
+    ("Mutation", "issueImportCreateGithub", FieldKind::Object, "IssueImportPayload"),
+    ("Mutation", "issueImportCreateJira", FieldKind::Object, "IssueImportPayload"),
+    ("Mutation", "issueImportCreateLinearV2", FieldKind::Object, "IssueImportPayload"),
+    ("Mutation", "issueImportDelete", FieldKind::Object, "IssueImportDeletePayload"),
+    ("Mutation", "issueImportProcess", FieldKind::Object, "IssueImportPayload"),
+    ("Mutation", "issueImportUpdate", FieldKind::Object, "IssueImportPayload"),
+    ("Mutation", "issueLabelCreate", FieldKind::Object, "IssueLabelPayload"),
+    ("Mutation", "issueLabelDelete", FieldKind::Object, "DeletePayload"),
+    ("Mutation", "issueLabelRestore", FieldKind::Object, "IssueLabelPayload"),
+    ("Mutation", "issueLabelRetire", FieldKind::Object, "IssueLabelPayload"),
+    ("Mutation", "issueLabelUpdate", FieldKind::Object, "IssueLabelPayload"),
+    ("Mutation", "issueRelationCreate", FieldKind::Object, "IssueRelationPayload"),
+    ("Mutation", "issueRelationDelete", FieldKind::Object, "DeletePayload"),
+    ("Mutation", "issueRelationUpdate", FieldKind::Object, "IssueRelationPayload"),
+    ("Mutation", "issueReminder", FieldKind::Object, "IssuePayload"),
+    ("Mutation", "issueRemoveLabel", FieldKind::Object, "IssuePayload"),
+    ("Mutation", "issueSubscribe", FieldKind::Object, "IssuePayload"),
+    ("Mutation", "issueToReleaseCreate", FieldKind::Object, "IssueToReleasePayload"),
+    ("Mutation", "issueToReleaseDelete", FieldKind::Object, "DeletePayload"),
+    ("Mutation", "issueToReleaseDeleteByIssueAndRelease", FieldKind::Object, "DeletePayload"),
+    ("Mutation", "issueUnarchive", FieldKind::Object, "IssueArchivePayload"),
+    ("Mutation", "issueUnsubscribe", FieldKind::Object, "IssuePayload"),
+    ("Mutation", "issueUpdate", FieldKind::Object, "IssuePayload"),
+    ("Mutation", "jiraIntegrationConnect", FieldKind::Object, "IntegrationPayload"),
+    ("Mutation", "joinOrganizationFromOnboarding", FieldKind::Object, "CreateOrJoinOrganizationResponse"),
+    ("Mutation", "leaveOrganization", FieldKind::Object, "CreateOrJoinOrganizationResponse"),
+    ("Mutation", "logout", FieldKind::Object, "LogoutResponse"),
+    ("Mutation", "logoutAllSessions", FieldKind::Object, "LogoutResponse"),
+    ("Mutation", "logoutOtherSessions", FieldKind::Object, "LogoutResponse"),
+    ("Mutation", "logoutSession", FieldKind::Object, "LogoutResponse"),
+    ("Mutation", "notificationArchive", FieldKind::Object, "NotificationArchivePayload"),
+    ("Mutation", "notificationArchiveAll", FieldKind::Object, "NotificationBatchActionPayload"),
+    ("Mutation", "notificationCategoryChannelSubscriptionUpdate", FieldKind::Object, "UserSettingsPayload"),
+    ("Mutation", "notificationMarkReadAll", FieldKind::Object, "NotificationBatchActionPayload"),
+    ("Mutation", "notificationMarkUnreadAll", FieldKind::Object, "NotificationBatchActionPayload"),
+    ("Mutation", "notificationSnoozeAll", FieldKind::Object, "NotificationBatchActionPayload"),
+    ("Mutation", "notificationSubscriptionCreate", FieldKind::Object, "NotificationSubscriptionPayload"),
+    ("Mutation", "notificationSubscriptionDelete", FieldKind::Object, "DeletePayload"),
+    ("Mutation", "notificationSubscriptionUpdate", FieldKind::Object, "NotificationSubscriptionPayload"),
+    ("Mutation", "notificationUnarchive", FieldKind::Object, "NotificationArchivePayload"),
+    ("Mutation", "notificationUnsnoozeAll", FieldKind::Object, "NotificationBatchActionPayload"),
+    ("Mutation", "notificationUpdate", FieldKind::Object, "NotificationPayload"),
+    ("Mutation", "organizationCancelDelete", FieldKind::Object, "OrganizationCancelDeletePayload"),
+    ("Mutation", "organizationDelete", FieldKind::Object, "OrganizationDeletePayload"),
+    ("Mutation", "organizationDeleteChallenge", FieldKind::Object, "OrganizationDeletePayload"),
+    ("Mutation", "organizationDomainClaim", FieldKind::Object, "OrganizationDomainSimplePayload"),
+    ("Mutation", "organizationDomainCreate", FieldKind::Object, "OrganizationDomainPayload"),
+    ("Mutation", "organizationDomainDelete", FieldKind::Object, "DeletePayload"),
+    ("Mutation", "organizationDomainUpdate", FieldKind::Object, "OrganizationDomainPayload"),
+    ("Mutation", "organizationDomainVerify", FieldKind::Object, "OrganizationDomainPayload"),
+    ("Mutation", "organizationInviteCreate", FieldKind::Object, "OrganizationInvitePayload"),
+    ("Mutation", "organizationInviteDelete", FieldKind::Object, "DeletePayload"),
+    ("Mutation", "organizationInviteUpdate", FieldKind::Object, "OrganizationInvitePayload"),
+    ("Mutation", "organizationStartTrial", FieldKind::Object, "OrganizationStartTrialPayload"),
+    ("Mutation", "organizationStartTrialForPlan", FieldKind::Object, "OrganizationStartTrialPayload"),
+    ("Mutation", "organizationUpdate", FieldKind::Object, "OrganizationPayload"),
+    ("Mutation", "passkeyLoginFinish", FieldKind::Object, "AuthResolverResponse"),
+    ("Mutation", "passkeyLoginStart", FieldKind::Object, "PasskeyLoginStartResponse"),
+    ("Mutation", "projectAddLabel", FieldKind::Object, "ProjectPayload"),
+    ("Mutation", "projectArchive", FieldKind::Object, "ProjectArchivePayload"),
+    ("Mutation", "projectCreate", FieldKind::Object, "ProjectPayload"),
+    ("Mutation", "projectDelete", FieldKind::Object, "ProjectArchivePayload"),
+    ("Mutation", "projectExternalSyncDisable", FieldKind::Object, "ProjectPayload"),
+    ("Mutation", "projectLabelCreate", FieldKind::Object, "ProjectLabelPayload"),
+    ("Mutation", "projectLabelDelete", FieldKind::Object, "DeletePayload"),
+    ("Mutation", "projectLabelRestore", FieldKind::Object, "ProjectLabelPayload"),
+    ("Mutation", "projectLabelRetire", FieldKind::Object, "ProjectLabelPayload"),
+    ("Mutation", "projectLabelUpdate", FieldKind::Object, "ProjectLabelPayload"),
+    ("Mutation", "projectMilestoneCreate", FieldKind::Object, "ProjectMilestonePayload"),
+    ("Mutation", "projectMilestoneDelete", FieldKind::Object, "DeletePayload"),
+    ("Mutation", "projectMilestoneMove", FieldKind::Object, "ProjectMilestoneMovePayload"),
+    ("Mutation", "projectMilestoneUpdate", FieldKind::Object, "ProjectMilestonePayload"),
+    ("Mutation", "projectReassignStatus", FieldKind::Object, "SuccessPayload"),
+    ("Mutation", "projectRelationCreate", FieldKind::Object, "ProjectRelationPayload"),
+    ("Mutation", "projectRelationDelete", FieldKind::Object, "DeletePayload"),
+    ("Mutation", "projectRelationUpdate", FieldKind::Object, "ProjectRelationPayload"),
+    ("Mutation", "projectRemoveLabel", FieldKind::Object, "ProjectPayload"),
+    ("Mutation", "projectStatusArchive", FieldKind::Object, "ProjectStatusArchivePayload"),
+    ("Mutation", "projectStatusCreate", FieldKind::Object, "ProjectStatusPayload"),
+    ("Mutation", "projectStatusUnarchive", FieldKind::Object, "ProjectStatusArchivePayload"),
+    ("Mutation", "projectStatusUpdate", FieldKind::Object, "ProjectStatusPayload"),
+    ("Mutation", "projectUnarchive", FieldKind::Object, "ProjectArchivePayload"),
+    ("Mutation", "projectUpdate", FieldKind::Object, "ProjectPayload"),
+    ("Mutation", "projectUpdateArchive", FieldKind::Object, "ProjectUpdateArchivePayload"),
+    ("Mutation", "projectUpdateCreate", FieldKind::Object, "ProjectUpdatePayload"),
+    ("Mutation", "projectUpdateDelete", FieldKind::Object, "DeletePayload"),
+    ("Mutation", "projectUpdateUnarchive", FieldKind::Object, "ProjectUpdateArchivePayload"),
+    ("Mutation", "projectUpdateUpdate", FieldKind::Object, "ProjectUpdatePayload"),
+    ("Mutation", "pushSubscriptionCreate", FieldKind::Object, "PushSubscriptionPayload"),
+    ("Mutation", "pushSubscriptionDelete", FieldKind::Object, "PushSubscriptionPayload"),
+    ("Mutation", "reactionCreate", FieldKind::Object, "ReactionPayload"),
+    ("Mutation", "reactionDelete", FieldKind::Object, "DeletePayload"),
+    ("Mutation", "refreshGoogleSheetsData", FieldKind::Object, "IntegrationPayload"),
+    ("Mutation", "releaseArchive", FieldKind::Object, "ReleaseArchivePayload"),
+    ("Mutation", "releaseCreate", FieldKind::Object, "ReleasePayload"),
+    ("Mutation", "releasePipelineArchive", FieldKind::Object, "ReleasePipelineArchivePayload"),
+    ("Mutation", "releasePipelineCreate", FieldKind::Object, "ReleasePipelinePayload"),
+    ("Mutation", "releasePipelineDelete", FieldKind::Object, "DeletePayload"),
+    ("Mutation", "releasePipelineUnarchive", FieldKind::Object, "ReleasePipelineArchivePayload"),
+    ("Mutation", "releasePipelineUpdate", FieldKind::Object, "ReleasePipelinePayload"),
+    ("Mutation", "releaseStageArchive", FieldKind::Object, "ReleaseStageArchivePayload"),
+    ("Mutation", "releaseStageCreate", FieldKind::Object, "ReleaseStagePayload"),
+    ("Mutation", "releaseStageUnarchive", FieldKind::Object, "ReleaseStageArchivePayload"),
+    ("Mutation", "releaseStageUpdate", FieldKind::Object, "ReleaseStagePayload"),
+    ("Mutation", "releaseUnarchive", FieldKind::Object, "ReleaseArchivePayload"),
+    ("Mutation", "releaseUpdate", FieldKind::Object, "ReleasePayload"),
+    ("Mutation", "resendOrganizationInvite", FieldKind::Object, "DeletePayload"),
+    ("Mutation", "resendOrganizationInviteByEmail", FieldKind::Object, "DeletePayload"),
+    ("Mutation", "roadmapArchive", FieldKind::Object, "RoadmapArchivePayload"),
+    ("Mutation", "roadmapCreate", FieldKind::Object, "RoadmapPayload"),
+    ("Mutation", "roadmapDelete", FieldKind::Object, "DeletePayload"),
+    ("Mutation", "roadmapToProjectCreate", FieldKind::Object, "RoadmapToProjectPayload"),
+    ("Mutation", "roadmapToProjectDelete", FieldKind::Object, "DeletePayload"),
+    ("Mutation", "roadmapToProjectUpdate", FieldKind::Object, "RoadmapToProjectPayload"),
+    ("Mutation", "roadmapUnarchive", FieldKind::Object, "RoadmapArchivePayload"),
+    ("Mutation", "roadmapUpdate", FieldKind::Object, "RoadmapPayload"),
+    ("Mutation", "samlTokenUserAccountAuth", FieldKind::Object, "AuthResolverResponse"),
+    ("Mutation", "teamCreate", FieldKind::Object, "TeamPayload"),
+    ("Mutation", "teamCyclesDelete", FieldKind::Object, "TeamPayload"),
+    ("Mutation", "teamDelete", FieldKind::Object, "DeletePayload"),
+    ("Mutation", "teamKeyDelete", FieldKind::Object, "DeletePayload"),
+    ("Mutation", "teamMembershipCreate", FieldKind::Object, "TeamMembershipPayload"),
+    ("Mutation", "teamMembershipDelete", FieldKind::Object, "DeletePayload"),
+    ("Mutation", "teamMembershipUpdate", FieldKind::Object, "TeamMembershipPayload"),
+    ("Mutation", "teamUnarchive", FieldKind::Object, "TeamArchivePayload"),
+    ("Mutation", "teamUpdate", FieldKind::Object, "TeamPayload"),
+    ("Mutation", "templateCreate", FieldKind::Object, "TemplatePayload"),
+    ("Mutation", "templateDelete", FieldKind::Object, "DeletePayload"),
+    ("Mutation", "templateUpdate", FieldKind::Object, "TemplatePayload"),
+    ("Mutation", "timeScheduleCreate", FieldKind::Object, "TimeSchedulePayload"),
+    ("Mutation", "timeScheduleDelete", FieldKind::Object, "DeletePayload"),
+    ("Mutation", "timeScheduleRefreshIntegrationSchedule", FieldKind::Object, "TimeSchedulePayload"),
+    ("Mutation", "timeScheduleUpdate", FieldKind::Object, "TimeSchedulePayload"),
+    ("Mutation", "timeScheduleUpsertExternal", FieldKind::Object, "TimeSchedulePayload"),
+    ("Mutation", "triageResponsibilityCreate", FieldKind::Object, "TriageResponsibilityPayload"),
+    ("Mutation", "triageResponsibilityDelete", FieldKind::Object, "DeletePayload"),
+    ("Mutation", "triageResponsibilityUpdate", FieldKind::Object, "TriageResponsibilityPayload"),
+    ("Mutation", "updateIntegrationSlackScopes", FieldKind::Object, "IntegrationPayload"),
+    ("Mutation", "userChangeRole", FieldKind::Object, "UserAdminPayload"),
+    ("Mutation", "userDemoteAdmin", FieldKind::Object, "UserAdminPayload"),
+    ("Mutation", "userDemoteMember", FieldKind::Object, "UserAdminPayload"),
+    ("Mutation", "userDiscordConnect", FieldKind::Object, "UserPayload"),
+    ("Mutation", "userExternalUserDisconnect", FieldKind::Object, "UserPayload"),
+    ("Mutation", "userFlagUpdate", FieldKind::Object, "UserSettingsFlagPayload"),
+    ("Mutation", "userPromoteAdmin", FieldKind::Object, "UserAdminPayload"),
+    ("Mutation", "userPromoteMember", FieldKind::Object, "UserAdminPayload"),
+    ("Mutation", "userSettingsFlagsReset", FieldKind::Object, "UserSettingsFlagsResetPayload"),
+    ("Mutation", "userSettingsUpdate", FieldKind::Object, "UserSettingsPayload"),
+    ("Mutation", "userSuspend", FieldKind::Object, "UserAdminPayload"),
+    ("Mutation", "userUnlinkFromIdentityProvider", FieldKind::Object, "UserAdminPayload"),
+    ("Mutation", "userUnsuspend", FieldKind::Object, "UserAdminPayload"),
+    ("Mutation", "userUpdate", FieldKind::Object, "UserPayload"),
+    ("Mutation", "viewPreferencesCreate", FieldKind::Object, "ViewPreferencesPayload"),
+    ("Mutation", "viewPreferencesDelete", FieldKind::Object, "DeletePayload"),
+    ("Mutation", "viewPreferencesUpdate", FieldKind::Object, "ViewPreferencesPayload"),
+    ("Mutation", "webhookCreate", FieldKind::Object, "WebhookPayload"),
+    ("Mutation", "webhookDelete", FieldKind::Object, "DeletePayload"),
+    ("Mutation", "webhookUpdate", FieldKind::Object, "WebhookPayload"),
+    ("Mutation", "workflowStateArchive", FieldKind::Object, "WorkflowStateArchivePayload"),
+    ("Mutation", "workflowStateCreate", FieldKind::Object, "WorkflowStatePayload"),
+    ("Mutation", "workflowStateUpdate", FieldKind::Object, "WorkflowStatePayload"),
+    ("Node", "id", FieldKind::Scalar, "ID"),
+    ("Notification", "actor", FieldKind::Object, "User"),
+    ("Notification", "actorAvatarColor", FieldKind::Scalar, "String"),
+    ("Notification", "actorAvatarUrl", FieldKind::Scalar, "String"),
+    ("Notification", "actorInitials", FieldKind::Scalar, "String"),
+    ("Notification", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("Notification", "botActor", FieldKind::Object, "ActorBot"),
+    ("Notification", "category", FieldKind::Scalar, "NotificationCategory"),
+    ("Notification", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("Notification", "emailedAt", FieldKind::Scalar, "DateTime"),
+    ("Notification", "externalUserActor", FieldKind::Object, "ExternalUser"),
+    ("Notification", "groupingKey", FieldKind::Scalar, "String"),
+    ("Notification", "groupingPriority", FieldKind::Scalar, "Float"),
+    ("Notification", "id", FieldKind::Scalar, "ID"),
+    ("Notification", "inboxUrl", FieldKind::Scalar, "String"),
+    ("Notification", "initiativeUpdateHealth", FieldKind::Scalar, "String"),
+    ("Notification", "isLinearActor", FieldKind::Scalar, "Boolean"),
+    ("Notification", "issueStatusType", FieldKind::Scalar, "String"),
+    ("Notification", "projectUpdateHealth", FieldKind::Scalar, "String"),
+    ("Notification", "readAt", FieldKind::Scalar, "DateTime"),
+    ("Notification", "snoozedUntilAt", FieldKind::Scalar, "DateTime"),
+    ("Notification", "subtitle", FieldKind::Scalar, "String"),
+    ("Notification", "title", FieldKind::Scalar, "String"),
+    ("Notification", "type", FieldKind::Scalar, "String"),
+    ("Notification", "unsnoozedAt", FieldKind::Scalar, "DateTime"),
+    ("Notification", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("Notification", "url", FieldKind::Scalar, "String"),
+    ("Notification", "user", FieldKind::Object, "User"),
+    ("NotificationArchivePayload", "entity", FieldKind::Object, "Notification"),
+    ("NotificationArchivePayload", "lastSyncId", FieldKind::Scalar, "Float"),
+    ("NotificationArchivePayload", "success", FieldKind::Scalar, "Boolean"),
+    ("NotificationBatchActionPayload", "lastSyncId", FieldKind::Scalar, "Float"),
+    ("NotificationBatchActionPayload", "notifications", FieldKind::Object, "Notification"),
+    ("NotificationBatchActionPayload", "success", FieldKind::Scalar, "Boolean"),
+    ("NotificationCategoryPreferences", "appsAndIntegrations", FieldKind::Object, "NotificationChannelPreferences"),
+    ("NotificationCategoryPreferences", "assignments", FieldKind::Object, "NotificationChannelPreferences"),
+    ("NotificationCategoryPreferences", "commentsAndReplies", FieldKind::Object, "NotificationChannelPreferences"),
+    ("NotificationCategoryPreferences", "customers", FieldKind::Object, "NotificationChannelPreferences"),
+    ("NotificationCategoryPreferences", "documentChanges", FieldKind::Object, "NotificationChannelPreferences"),
+    ("NotificationCategoryPreferences", "feed", FieldKind::Object, "NotificationChannelPreferences"),
+    ("NotificationCategoryPreferences", "mentions", FieldKind::Object, "NotificationChannelPreferences"),
+    ("NotificationCategoryPreferences", "postsAndUpdates", FieldKind::Object, "NotificationChannelPreferences"),
+    ("NotificationCategoryPreferences", "reactions", FieldKind::Object, "NotificationChannelPreferences"),
+    ("NotificationCategoryPreferences", "reminders", FieldKind::Object, "NotificationChannelPreferences"),
+    ("NotificationCategoryPreferences", "reviews", FieldKind::Object, "NotificationChannelPreferences"),
+    ("NotificationCategoryPreferences", "statusChanges", FieldKind::Object, "NotificationChannelPreferences"),
+    ("NotificationCategoryPreferences", "subscriptions", FieldKind::Object, "NotificationChannelPreferences"),
+    ("NotificationCategoryPreferences", "system", FieldKind::Object, "NotificationChannelPreferences"),
+    ("NotificationCategoryPreferences", "triage", FieldKind::Object, "NotificationChannelPreferences"),
+    ("NotificationChannelPreferences", "desktop", FieldKind::Scalar, "Boolean"),
+    ("NotificationChannelPreferences", "email", FieldKind::Scalar, "Boolean"),
+    ("NotificationChannelPreferences", "mobile", FieldKind::Scalar, "Boolean"),
+    ("NotificationChannelPreferences", "slack", FieldKind::Scalar, "Boolean"),
+    ("NotificationConnection", "edges", FieldKind::Object, "NotificationEdge"),
+    ("NotificationConnection", "nodes", FieldKind::Object, "Notification"),
+    ("NotificationConnection", "pageInfo", FieldKind::Object, "PageInfo"),
+    ("NotificationDeliveryPreferences", "mobile", FieldKind::Object, "NotificationDeliveryPreferencesChannel"),
+    ("NotificationDeliveryPreferencesChannel", "notificationsDisabled", FieldKind::Scalar, "Boolean"),
+    ("NotificationDeliveryPreferencesChannel", "schedule", FieldKind::Object, "NotificationDeliveryPreferencesSchedule"),
+    ("NotificationDeliveryPreferencesDay", "end", FieldKind::Scalar, "String"),
+    ("NotificationDeliveryPreferencesDay", "start", FieldKind::Scalar, "String"),
+    ("NotificationDeliveryPreferencesSchedule", "disabled", FieldKind::Scalar, "Boolean"),
+    ("NotificationDeliveryPreferencesSchedule", "friday", FieldKind::Object, "NotificationDeliveryPreferencesDay"),
+    ("NotificationDeliveryPreferencesSchedule", "monday", FieldKind::Object, "NotificationDeliveryPreferencesDay"),
+    ("NotificationDeliveryPreferencesSchedule", "saturday", FieldKind::Object, "NotificationDeliveryPreferencesDay"),
+    ("NotificationDeliveryPreferencesSchedule", "sunday", FieldKind::Object, "NotificationDeliveryPreferencesDay"),
+    ("NotificationDeliveryPreferencesSchedule", "thursday", FieldKind::Object, "NotificationDeliveryPreferencesDay"),
+    ("NotificationDeliveryPreferencesSchedule", "tuesday", FieldKind::Object, "NotificationDeliveryPreferencesDay"),
+    ("NotificationDeliveryPreferencesSchedule", "wednesday", FieldKind::Object, "NotificationDeliveryPreferencesDay"),
+    ("NotificationEdge", "cursor", FieldKind::Scalar, "String"),
+    ("NotificationEdge", "node", FieldKind::Object, "Notification"),
+    ("NotificationPayload", "lastSyncId", FieldKind::Scalar, "Float"),
+    ("NotificationPayload", "notification", FieldKind::Object, "Notification"),
+    ("NotificationPayload", "success", FieldKind::Scalar, "Boolean"),
+    ("NotificationSubscription", "active", FieldKind::Scalar, "Boolean"),
+    ("NotificationSubscription", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("NotificationSubscription", "contextViewType", FieldKind::Scalar, "ContextViewType"),
+    ("NotificationSubscription", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("NotificationSubscription", "customView", FieldKind::Object, "CustomView"),
+    ("NotificationSubscription", "customer", FieldKind::Object, "Customer"),
+    ("NotificationSubscription", "cycle", FieldKind::Object, "Cycle"),
+    ("NotificationSubscription", "id", FieldKind::Scalar, "ID"),
+    ("NotificationSubscription", "initiative", FieldKind::Object, "Initiative"),
+    ("NotificationSubscription", "label", FieldKind::Object, "IssueLabel"),
+    ("NotificationSubscription", "project", FieldKind::Object, "Project"),
+    ("NotificationSubscription", "subscriber", FieldKind::Object, "User"),
+    ("NotificationSubscription", "team", FieldKind::Object, "Team"),
+    ("NotificationSubscription", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("NotificationSubscription", "user", FieldKind::Object, "User"),
+    ("NotificationSubscription", "userContextViewType", FieldKind::Scalar, "UserContextViewType"),
+    ("NotificationSubscriptionConnection", "edges", FieldKind::Object, "NotificationSubscriptionEdge"),
+    ("NotificationSubscriptionConnection", "nodes", FieldKind::Object, "NotificationSubscription"),
+    ("NotificationSubscriptionConnection", "pageInfo", FieldKind::Object, "PageInfo"),
+    ("NotificationSubscriptionEdge", "cursor", FieldKind::Scalar, "String"),
+    ("NotificationSubscriptionEdge", "node", FieldKind::Object, "NotificationSubscription"),
+    ("NotificationSubscriptionPayload", "lastSyncId", FieldKind::Scalar, "Float"),
+    ("NotificationSubscriptionPayload", "notificationSubscription", FieldKind::Object, "NotificationSubscription"),
+    ("NotificationSubscriptionPayload", "success", FieldKind::Scalar, "Boolean"),
+    ("OAuthAppWebhookPayload", "action", FieldKind::Scalar, "String"),
+    ("OAuthAppWebhookPayload", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("OAuthAppWebhookPayload", "oauthClientId", FieldKind::Scalar, "String"),
+    ("OAuthAppWebhookPayload", "organizationId", FieldKind::Scalar, "String"),
+    ("OAuthAppWebhookPayload", "type", FieldKind::Scalar, "String"),
+    ("OAuthAppWebhookPayload", "webhookId", FieldKind::Scalar, "String"),
+    ("OAuthAppWebhookPayload", "webhookTimestamp", FieldKind::Scalar, "Float"),
+    ("OAuthAuthorizationWebhookPayload", "action", FieldKind::Scalar, "String"),
+    ("OAuthAuthorizationWebhookPayload", "activeTokensForUser", FieldKind::Scalar, "Float"),
+    ("OAuthAuthorizationWebhookPayload", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("OAuthAuthorizationWebhookPayload", "oauthClient", FieldKind::Object, "OauthClientChildWebhookPayload"),
+    ("OAuthAuthorizationWebhookPayload", "oauthClientId", FieldKind::Scalar, "String"),
+    ("OAuthAuthorizationWebhookPayload", "organizationId", FieldKind::Scalar, "String"),
+    ("OAuthAuthorizationWebhookPayload", "type", FieldKind::Scalar, "String"),
+    ("OAuthAuthorizationWebhookPayload", "user", FieldKind::Object, "UserChildWebhookPayload"),
+    ("OAuthAuthorizationWebhookPayload", "userId", FieldKind::Scalar, "String"),
+    ("OAuthAuthorizationWebhookPayload", "webhookId", FieldKind::Scalar, "String"),
+    ("OAuthAuthorizationWebhookPayload", "webhookTimestamp", FieldKind::Scalar, "Float"),
+    ("OauthClientActorWebhookPayload", "id", FieldKind::Scalar, "String"),
+    ("OauthClientActorWebhookPayload", "name", FieldKind::Scalar, "String"),
+    ("OauthClientActorWebhookPayload", "type", FieldKind::Scalar, "String"),
+    ("OauthClientApproval", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("OauthClientApproval", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("OauthClientApproval", "denyReason", FieldKind::Scalar, "String"),
+    ("OauthClientApproval", "id", FieldKind::Scalar, "ID"),
+    ("OauthClientApproval", "newlyRequestedScopes", FieldKind::Scalar, "String"),
+    ("OauthClientApproval", "oauthClientId", FieldKind::Scalar, "String"),
+    ("OauthClientApproval", "requestReason", FieldKind::Scalar, "String"),
+    ("OauthClientApproval", "requesterId", FieldKind::Scalar, "String"),
+    ("OauthClientApproval", "responderId", FieldKind::Scalar, "String"),
+    ("OauthClientApproval", "scopes", FieldKind::Scalar, "String"),
+    ("OauthClientApproval", "status", FieldKind::Scalar, "OAuthClientApprovalStatus"),
+    ("OauthClientApproval", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("OauthClientApprovalNotification", "actor", FieldKind::Object, "User"),
+    ("OauthClientApprovalNotification", "actorAvatarColor", FieldKind::Scalar, "String"),
+    ("OauthClientApprovalNotification", "actorAvatarUrl", FieldKind::Scalar, "String"),
+    ("OauthClientApprovalNotification", "actorInitials", FieldKind::Scalar, "String"),
+    ("OauthClientApprovalNotification", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("OauthClientApprovalNotification", "botActor", FieldKind::Object, "ActorBot"),
+    ("OauthClientApprovalNotification", "category", FieldKind::Scalar, "NotificationCategory"),
+    ("OauthClientApprovalNotification", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("OauthClientApprovalNotification", "emailedAt", FieldKind::Scalar, "DateTime"),
+    ("OauthClientApprovalNotification", "externalUserActor", FieldKind::Object, "ExternalUser"),
+    ("OauthClientApprovalNotification", "groupingKey", FieldKind::Scalar, "String"),
+    ("OauthClientApprovalNotification", "groupingPriority", FieldKind::Scalar, "Float"),
+    ("OauthClientApprovalNotification", "id", FieldKind::Scalar, "ID"),
+    ("OauthClientApprovalNotification", "inboxUrl", FieldKind::Scalar, "String"),
+    ("OauthClientApprovalNotification", "initiativeUpdateHealth", FieldKind::Scalar, "String"),
+    ("OauthClientApprovalNotification", "isLinearActor", FieldKind::Scalar, "Boolean"),
+    ("OauthClientApprovalNotification", "issueStatusType", FieldKind::Scalar, "String"),
+    ("OauthClientApprovalNotification", "oauthClientApproval", FieldKind::Object, "OauthClientApproval"),
+    ("OauthClientApprovalNotification", "oauthClientApprovalId", FieldKind::Scalar, "String"),
+    ("OauthClientApprovalNotification", "projectUpdateHealth", FieldKind::Scalar, "String"),
+    ("OauthClientApprovalNotification", "readAt", FieldKind::Scalar, "DateTime"),
+    ("OauthClientApprovalNotification", "snoozedUntilAt", FieldKind::Scalar, "DateTime"),
+    ("OauthClientApprovalNotification", "subtitle", FieldKind::Scalar, "String"),
+    ("OauthClientApprovalNotification", "title", FieldKind::Scalar, "String"),
+    ("OauthClientApprovalNotification", "type", FieldKind::Scalar, "String"),
+    ("OauthClientApprovalNotification", "unsnoozedAt", FieldKind::Scalar, "DateTime"),
+    ("OauthClientApprovalNotification", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("OauthClientApprovalNotification", "url", FieldKind::Scalar, "String"),
+    ("OauthClientApprovalNotification", "user", FieldKind::Object, "User"),
+    ("OauthClientChildWebhookPayload", "id", FieldKind::Scalar, "String"),
+    ("OauthClientChildWebhookPayload", "name", FieldKind::Scalar, "String"),
+    ("Organization", "aiAddonEnabled", FieldKind::Scalar, "Boolean"),
+    ("Organization", "aiDiscussionSummariesEnabled", FieldKind::Scalar, "Boolean"),
+    ("Organization", "aiThreadSummariesEnabled", FieldKind::Scalar, "Boolean"),
+    ("Organization", "allowMembersToInvite", FieldKind::Scalar, "Boolean"),
+    ("Organization", "allowedAiProviders", FieldKind::Scalar, "String"),
+    ("Organization", "allowedAuthServices", FieldKind::Scalar, "String"),
+    ("Organization", "allowedFileUploadContentTypes", FieldKind::Scalar, "String"),
+    ("Organization", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("Organization", "codeIntelligenceEnabled", FieldKind::Scalar, "Boolean"),
+    ("Organization", "codeIntelligenceRepository", FieldKind::Scalar, "String"),
+    ("Organization", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("Organization", "createdIssueCount", FieldKind::Scalar, "Int"),
+    ("Organization", "customerCount", FieldKind::Scalar, "Int"),
+    ("Organization", "customersConfiguration", FieldKind::Scalar, "JSONObject"),
+    ("Organization", "customersEnabled", FieldKind::Scalar, "Boolean"),
+    ("Organization", "defaultFeedSummarySchedule", FieldKind::Scalar, "FeedSummarySchedule"),
+    ("Organization", "deletionRequestedAt", FieldKind::Scalar, "DateTime"),
+    ("Organization", "facets", FieldKind::Object, "Facet"),
+    ("Organization", "feedEnabled", FieldKind::Scalar, "Boolean"),
+    ("Organization", "fiscalYearStartMonth", FieldKind::Scalar, "Float"),
+    ("Organization", "generatedUpdatesEnabled", FieldKind::Scalar, "Boolean"),
+    ("Organization", "gitBranchFormat", FieldKind::Scalar, "String"),
+    ("Organization", "gitLinkbackDescriptionsEnabled", FieldKind::Scalar, "Boolean"),
+    ("Organization", "gitLinkbackMessagesEnabled", FieldKind::Scalar, "Boolean"),
+    ("Organization", "gitPublicLinkbackMessagesEnabled", FieldKind::Scalar, "Boolean"),
+    ("Organization", "hipaaComplianceEnabled", FieldKind::Scalar, "Boolean"),
+    ("Organization", "id", FieldKind::Scalar, "ID"),
+    ("Organization", "initiativeUpdateReminderFrequencyInWeeks", FieldKind::Scalar, "Float"),
+    ("Organization", "initiativeUpdateRemindersDay", FieldKind::Scalar, "Day"),
+    ("Organization", "initiativeUpdateRemindersHour", FieldKind::Scalar, "Float"),
+    ("Organization", "integrations", FieldKind::Connection, "IntegrationConnection"),
+    ("Organization", "ipRestrictions", FieldKind::Object, "OrganizationIpRestriction"),
+    ("Organization", "labels", FieldKind::Connection, "IssueLabelConnection"),
+    ("Organization", "logoUrl", FieldKind::Scalar, "String"),
+    ("Organization", "name", FieldKind::Scalar, "String"),
+    ("Organization", "periodUploadVolume", FieldKind::Scalar, "Float"),
+    ("Organization", "previousUrlKeys", FieldKind::Scalar, "String"),
+    ("Organization", "projectLabels", FieldKind::Connection, "ProjectLabelConnection"),
+    ("Organization", "projectStatuses", FieldKind::Object, "ProjectStatus"),
+    ("Organization", "projectUpdateReminderFrequencyInWeeks", FieldKind::Scalar, "Float"),
+    ("Organization", "projectUpdateRemindersDay", FieldKind::Scalar, "Day"),
+    ("Organization", "projectUpdateRemindersHour", FieldKind::Scalar, "Float"),
+    ("Organization", "projectUpdatesReminderFrequency", FieldKind::Scalar, "ProjectUpdateReminderFrequency"),
+    ("Organization", "releaseChannel", FieldKind::Scalar, "ReleaseChannel"),
+    ("Organization", "restrictLabelManagementToAdmins", FieldKind::Scalar, "Boolean"),
+    ("Organization", "restrictTeamCreationToAdmins", FieldKind::Scalar, "Boolean"),
+    ("Organization", "roadmapEnabled", FieldKind::Scalar, "Boolean"),
+    ("Organization", "samlEnabled", FieldKind::Scalar, "Boolean"),
+    ("Organization", "samlSettings", FieldKind::Scalar, "JSONObject"),
+    ("Organization", "scimEnabled", FieldKind::Scalar, "Boolean"),
+    ("Organization", "scimSettings", FieldKind::Scalar, "JSONObject"),
+    ("Organization", "securitySettings", FieldKind::Scalar, "JSONObject"),
+    ("Organization", "slaDayCount", FieldKind::Scalar, "SLADayCountType"),
+    ("Organization", "subscription", FieldKind::Object, "PaidSubscription"),
+    ("Organization", "teams", FieldKind::Connection, "TeamConnection"),
+    ("Organization", "templates", FieldKind::Connection, "TemplateConnection"),
+    ("Organization", "themeSettings", FieldKind::Scalar, "JSONObject"),
+    ("Organization", "trialEndsAt", FieldKind::Scalar, "DateTime"),
+    ("Organization", "trialStartsAt", FieldKind::Scalar, "DateTime"),
+    ("Organization", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("Organization", "urlKey", FieldKind::Scalar, "String"),
+    ("Organization", "userCount", FieldKind::Scalar, "Int"),
+    ("Organization", "users", FieldKind::Connection, "UserConnection"),
+    ("Organization", "workingDays", FieldKind::Scalar, "Float"),
+    ("OrganizationAcceptedOrExpiredInviteDetailsPayload", "status", FieldKind::Scalar, "OrganizationInviteStatus"),
+    ("OrganizationCancelDeletePayload", "success", FieldKind::Scalar, "Boolean"),
+    ("OrganizationDeletePayload", "success", FieldKind::Scalar, "Boolean"),
+    ("OrganizationDomain", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("OrganizationDomain", "authType", FieldKind::Scalar, "OrganizationDomainAuthType"),
+    ("OrganizationDomain", "claimed", FieldKind::Scalar, "Boolean"),
+    ("OrganizationDomain", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("OrganizationDomain", "creator", FieldKind::Object, "User"),
+    ("OrganizationDomain", "disableOrganizationCreation", FieldKind::Scalar, "Boolean"),
+    ("OrganizationDomain", "id", FieldKind::Scalar, "ID"),
+    ("OrganizationDomain", "identityProvider", FieldKind::Object, "IdentityProvider"),
+    ("OrganizationDomain", "name", FieldKind::Scalar, "String"),
+    ("OrganizationDomain", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("OrganizationDomain", "verificationEmail", FieldKind::Scalar, "String"),
+    ("OrganizationDomain", "verified", FieldKind::Scalar, "Boolean"),
+    ("OrganizationDomainClaimPayload", "verificationString", FieldKind::Scalar, "String"),
+    ("OrganizationDomainPayload", "lastSyncId", FieldKind::Scalar, "Float"),
+    ("OrganizationDomainPayload", "organizationDomain", FieldKind::Object, "OrganizationDomain"),
+    ("OrganizationDomainPayload", "success", FieldKind::Scalar, "Boolean"),
+    ("OrganizationDomainSimplePayload", "success", FieldKind::Scalar, "Boolean"),
+    ("OrganizationExistsPayload", "exists", FieldKind::Scalar, "Boolean"),
+    ("OrganizationExistsPayload", "success", FieldKind::Scalar, "Boolean"),
+    ("OrganizationInvite", "acceptedAt", FieldKind::Scalar, "DateTime"),
+    ("OrganizationInvite", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("OrganizationInvite", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("OrganizationInvite", "email", FieldKind::Scalar, "String"),
+    ("OrganizationInvite", "expiresAt", FieldKind::Scalar, "DateTime"),
+    ("OrganizationInvite", "external", FieldKind::Scalar, "Boolean"),
+    ("OrganizationInvite", "id", FieldKind::Scalar, "ID"),
+    ("OrganizationInvite", "invitee", FieldKind::Object, "User"),
+    ("OrganizationInvite", "inviter", FieldKind::Object, "User"),
+    ("OrganizationInvite", "metadata", FieldKind::Scalar, "JSONObject"),
+    ("OrganizationInvite", "organization", FieldKind::Object, "Organization"),
+    ("OrganizationInvite", "role", FieldKind::Scalar, "UserRoleType"),
+    ("OrganizationInvite", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("OrganizationInviteConnection", "edges", FieldKind::Object, "OrganizationInviteEdge"),
+    ("OrganizationInviteConnection", "nodes", FieldKind::Object, "OrganizationInvite"),
+    ("OrganizationInviteConnection", "pageInfo", FieldKind::Object, "PageInfo"),
+    ("OrganizationInviteEdge", "cursor", FieldKind::Scalar, "String"),
+    ("OrganizationInviteEdge", "node", FieldKind::Object, "OrganizationInvite"),
+    ("OrganizationInviteFullDetailsPayload", "accepted", FieldKind::Scalar, "Boolean"),
+    ("OrganizationInviteFullDetailsPayload", "allowedAuthServices", FieldKind::Scalar, "String"),
+    ("OrganizationInviteFullDetailsPayload", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("OrganizationInviteFullDetailsPayload", "email", FieldKind::Scalar, "String"),
+    ("OrganizationInviteFullDetailsPayload", "expired", FieldKind::Scalar, "Boolean"),
+    ("OrganizationInviteFullDetailsPayload", "inviter", FieldKind::Scalar, "String"),
+    ("OrganizationInviteFullDetailsPayload", "organizationId", FieldKind::Scalar, "String"),
+    ("OrganizationInviteFullDetailsPayload", "organizationLogoUrl", FieldKind::Scalar, "String"),
+    ("OrganizationInviteFullDetailsPayload", "organizationName", FieldKind::Scalar, "String"),
+    ("OrganizationInviteFullDetailsPayload", "role", FieldKind::Scalar, "UserRoleType"),
+    ("OrganizationInviteFullDetailsPayload", "status", FieldKind::Scalar, "OrganizationInviteStatus"),
+    ("OrganizationInvitePayload", "lastSyncId", FieldKind::Scalar, "Float"),
+    ("OrganizationInvitePayload", "organizationInvite", FieldKind::Object, "OrganizationInvite"),
+    ("OrganizationInvitePayload", "success", FieldKind::Scalar, "Boolean"),
+    ("OrganizationIpRestriction", "description", FieldKind::Scalar, "String"),
+    ("OrganizationIpRestriction", "enabled", FieldKind::Scalar, "Boolean"),
+    ("OrganizationIpRestriction", "range", FieldKind::Scalar, "String"),
+    ("OrganizationIpRestriction", "type", FieldKind::Scalar, "String"),
+    ("OrganizationMeta", "allowedAuthServices", FieldKind::Scalar, "String"),
+    ("OrganizationMeta", "region", FieldKind::Scalar, "String"),
+    ("OrganizationOriginWebhookPayload", "type", FieldKind::Scalar, "String"),
+    ("OrganizationPayload", "lastSyncId", FieldKind::Scalar, "Float"),
+    ("OrganizationPayload", "organization", FieldKind::Object, "Organization"),
+    ("OrganizationPayload", "success", FieldKind::Scalar, "Boolean"),
+    ("OrganizationStartTrialPayload", "success", FieldKind::Scalar, "Boolean"),
+    ("OtherNotificationWebhookPayload", "actor", FieldKind::Object, "UserChildWebhookPayload"),
+    ("OtherNotificationWebhookPayload", "actorId", FieldKind::Scalar, "String"),
+    ("OtherNotificationWebhookPayload", "archivedAt", FieldKind::Scalar, "String"),
+    ("OtherNotificationWebhookPayload", "comment", FieldKind::Object, "CommentChildWebhookPayload"),
+    ("OtherNotificationWebhookPayload", "commentId", FieldKind::Scalar, "String"),
+    ("OtherNotificationWebhookPayload", "createdAt", FieldKind::Scalar, "String"),
+    ("OtherNotificationWebhookPayload", "document", FieldKind::Object, "DocumentChildWebhookPayload"),
+    ("OtherNotificationWebhookPayload", "documentId", FieldKind::Scalar, "String"),
+    ("OtherNotificationWebhookPayload", "externalUserActorId", FieldKind::Scalar, "String"),
+    ("OtherNotificationWebhookPayload", "id", FieldKind::Scalar, "String"),
+    ("OtherNotificationWebhookPayload", "issue", FieldKind::Object, "IssueWithDescriptionChildWebhookPayload"),
+    ("OtherNotificationWebhookPayload", "issueId", FieldKind::Scalar, "String"),
+    ("OtherNotificationWebhookPayload", "parentComment", FieldKind::Object, "CommentChildWebhookPayload"),
+    ("OtherNotificationWebhookPayload", "parentCommentId", FieldKind::Scalar, "String"),
+    ("OtherNotificationWebhookPayload", "project", FieldKind::Object, "ProjectChildWebhookPayload"),
+    ("OtherNotificationWebhookPayload", "projectId", FieldKind::Scalar, "String"),
+    ("OtherNotificationWebhookPayload", "projectUpdate", FieldKind::Object, "ProjectUpdateChildWebhookPayload"),
+    ("OtherNotificationWebhookPayload", "projectUpdateId", FieldKind::Scalar, "String"),
+    ("OtherNotificationWebhookPayload", "reactionEmoji", FieldKind::Scalar, "String"),
+    ("OtherNotificationWebhookPayload", "type", FieldKind::Scalar, "OtherNotificationType"),
+    ("OtherNotificationWebhookPayload", "updatedAt", FieldKind::Scalar, "String"),
+    ("OtherNotificationWebhookPayload", "userId", FieldKind::Scalar, "String"),
+    ("PageInfo", "endCursor", FieldKind::Scalar, "String"),
+    ("PageInfo", "hasNextPage", FieldKind::Scalar, "Boolean"),
+    ("PageInfo", "hasPreviousPage", FieldKind::Scalar, "Boolean"),
+    ("PageInfo", "startCursor", FieldKind::Scalar, "String"),
+    ("PaidSubscription", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("PaidSubscription", "cancelAt", FieldKind::Scalar, "DateTime"),
+    ("PaidSubscription", "canceledAt", FieldKind::Scalar, "DateTime"),
+    ("PaidSubscription", "collectionMethod", FieldKind::Scalar, "String"),
+    ("PaidSubscription", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("PaidSubscription", "creator", FieldKind::Object, "User"),
+    ("PaidSubscription", "id", FieldKind::Scalar, "ID"),
+    ("PaidSubscription", "nextBillingAt", FieldKind::Scalar, "DateTime"),
+    ("PaidSubscription", "organization", FieldKind::Object, "Organization"),
+    ("PaidSubscription", "pendingChangeType", FieldKind::Scalar, "String"),
+    ("PaidSubscription", "seats", FieldKind::Scalar, "Float"),
+    ("PaidSubscription", "seatsMaximum", FieldKind::Scalar, "Float"),
+    ("PaidSubscription", "seatsMinimum", FieldKind::Scalar, "Float"),
+    ("PaidSubscription", "type", FieldKind::Scalar, "String"),
+    ("PaidSubscription", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("PasskeyLoginStartResponse", "options", FieldKind::Scalar, "JSONObject"),
+    ("PasskeyLoginStartResponse", "success", FieldKind::Scalar, "Boolean"),
+    ("Post", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("Post", "audioSummary", FieldKind::Scalar, "String"),
+    ("Post", "body", FieldKind::Scalar, "String"),
+    ("Post", "bodyData", FieldKind::Scalar, "String"),
+    ("Post", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("Post", "creator", FieldKind::Object, "User"),
+    ("Post", "editedAt", FieldKind::Scalar, "DateTime"),
+    ("Post", "evalLogId", FieldKind::Scalar, "String"),
+    ("Post", "feedSummaryScheduleAtCreate", FieldKind::Scalar, "FeedSummarySchedule"),
+    ("Post", "id", FieldKind::Scalar, "ID"),
+    ("Post", "reactionData", FieldKind::Scalar, "JSONObject"),
+    ("Post", "slugId", FieldKind::Scalar, "String"),
+    ("Post", "team", FieldKind::Object, "Team"),
+    ("Post", "title", FieldKind::Scalar, "String"),
+    ("Post", "ttlUrl", FieldKind::Scalar, "String"),
+    ("Post", "type", FieldKind::Scalar, "PostType"),
+    ("Post", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("Post", "user", FieldKind::Object, "User"),
+    ("Post", "writtenSummaryData", FieldKind::Scalar, "JSONObject"),
+    ("PostNotification", "actor", FieldKind::Object, "User"),
+    ("PostNotification", "actorAvatarColor", FieldKind::Scalar, "String"),
+    ("PostNotification", "actorAvatarUrl", FieldKind::Scalar, "String"),
+    ("PostNotification", "actorInitials", FieldKind::Scalar, "String"),
+    ("PostNotification", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("PostNotification", "botActor", FieldKind::Object, "ActorBot"),
+    ("PostNotification", "category", FieldKind::Scalar, "NotificationCategory"),
+    ("PostNotification", "commentId", FieldKind::Scalar, "String"),
+    ("PostNotification", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("PostNotification", "emailedAt", FieldKind::Scalar, "DateTime"),
+    ("PostNotification", "externalUserActor", FieldKind::Object, "ExternalUser"),
+    ("PostNotification", "groupingKey", FieldKind::Scalar, "String"),
+    ("PostNotification", "groupingPriority", FieldKind::Scalar, "Float"),
+    ("PostNotification", "id", FieldKind::Scalar, "ID"),
+    ("PostNotification", "inboxUrl", FieldKind::Scalar, "String"),
+    ("PostNotification", "initiativeUpdateHealth", FieldKind::Scalar, "String"),
+    ("PostNotification", "isLinearActor", FieldKind::Scalar, "Boolean"),
+    ("PostNotification", "issueStatusType", FieldKind::Scalar, "String"),
+    ("PostNotification", "parentCommentId", FieldKind::Scalar, "String"),
+    ("PostNotification", "postId", FieldKind::Scalar, "String"),
+    ("PostNotification", "projectUpdateHealth", FieldKind::Scalar, "String"),
+    ("PostNotification", "reactionEmoji", FieldKind::Scalar, "String"),
+    ("PostNotification", "readAt", FieldKind::Scalar, "DateTime"),
+    ("PostNotification", "snoozedUntilAt", FieldKind::Scalar, "DateTime"),
+    ("PostNotification", "subtitle", FieldKind::Scalar, "String"),
+    ("PostNotification", "title", FieldKind::Scalar, "String"),
+    ("PostNotification", "type", FieldKind::Scalar, "String"),
+    ("PostNotification", "unsnoozedAt", FieldKind::Scalar, "DateTime"),
+    ("PostNotification", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("PostNotification", "url", FieldKind::Scalar, "String"),
+    ("PostNotification", "user", FieldKind::Object, "User"),
+    ("Project", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("Project", "autoArchivedAt", FieldKind::Scalar, "DateTime"),
+    ("Project", "canceledAt", FieldKind::Scalar, "DateTime"),
+    ("Project", "color", FieldKind::Scalar, "String"),
+    ("Project", "comments", FieldKind::Connection, "CommentConnection"),
+    ("Project", "completedAt", FieldKind::Scalar, "DateTime"),
+    ("Project", "completedIssueCountHistory", FieldKind::Scalar, "Float"),
+    ("Project", "completedScopeHistory", FieldKind::Scalar, "Float"),
+    ("Project", "content", FieldKind::Scalar, "String"),
+    ("Project", "contentState", FieldKind::Scalar, "String"),
+    ("Project", "convertedFromIssue", FieldKind::Object, "Issue"),
+    ("Project", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("Project", "creator", FieldKind::Object, "User"),
+    ("Project", "currentProgress", FieldKind::Scalar, "JSONObject"),
+    ("Project", "description", FieldKind::Scalar, "String"),
+    ("Project", "documentContent", FieldKind::Object, "DocumentContent"),
+    ("Project", "documents", FieldKind::Connection, "DocumentConnection"),
+    ("Project", "externalLinks", FieldKind::Connection, "EntityExternalLinkConnection"),
+    ("Project", "facets", FieldKind::Object, "Facet"),
+    ("Project", "favorite", FieldKind::Object, "Favorite"),
+    ("Project", "frequencyResolution", FieldKind::Scalar, "FrequencyResolutionType"),
+    ("Project", "health", FieldKind::Scalar, "ProjectUpdateHealthType"),
+    ("Project", "healthUpdatedAt", FieldKind::Scalar, "DateTime"),
+    ("Project", "history", FieldKind::Connection, "ProjectHistoryConnection"),
+    ("Project", "icon", FieldKind::Scalar, "String"),
+    ("Project", "id", FieldKind::Scalar, "ID"),
+    ("Project", "inProgressScopeHistory", FieldKind::Scalar, "Float"),
+    ("Project", "initiativeToProjects", FieldKind::Connection, "InitiativeToProjectConnection"),
+    ("Project", "initiatives", FieldKind::Connection, "InitiativeConnection"),
+    ("Project", "integrationsSettings", FieldKind::Object, "IntegrationsSettings"),
+    ("Project", "inverseRelations", FieldKind::Connection, "ProjectRelationConnection"),
+    ("Project", "issueCountHistory", FieldKind::Scalar, "Float"),
+    ("Project", "issues", FieldKind::Connection, "IssueConnection"),
+    ("Project", "labelIds", FieldKind::Scalar, "String"),
+    ("Project", "labels", FieldKind::Connection, "ProjectLabelConnection"),
+    ("Project", "lastAppliedTemplate", FieldKind::Object, "Template"),
+    ("Project", "lastUpdate", FieldKind::Object, "ProjectUpdate"),
+    ("Project", "lead", FieldKind::Object, "User"),
+    ("Project", "members", FieldKind::Connection, "UserConnection"),
+    ("Project", "name", FieldKind::Scalar, "String"),
+    ("Project", "needs", FieldKind::Connection, "CustomerNeedConnection"),
+    ("Project", "priority", FieldKind::Scalar, "Int"),
+    ("Project", "priorityLabel", FieldKind::Scalar, "String"),
+    ("Project", "prioritySortOrder", FieldKind::Scalar, "Float"),
+    ("Project", "progress", FieldKind::Scalar, "Float"),
+    ("Project", "progressHistory", FieldKind::Scalar, "JSONObject"),
+    ("Project", "projectMilestones", FieldKind::Connection, "ProjectMilestoneConnection"),
+    ("Project", "projectUpdateRemindersPausedUntilAt", FieldKind::Scalar, "DateTime"),
+    ("Project", "projectUpdates", FieldKind::Connection, "ProjectUpdateConnection"),
+    ("Project", "relations", FieldKind::Connection, "ProjectRelationConnection"),
+    ("Project", "scope", FieldKind::Scalar, "Float"),
+    ("Project", "scopeHistory", FieldKind::Scalar, "Float"),
+    ("Project", "slackIssueComments", FieldKind::Scalar, "Boolean"),
+    ("Project", "slackIssueStatuses", FieldKind::Scalar, "Boolean"),
+    ("Project", "slackNewIssue", FieldKind::Scalar, "Boolean"),
+    ("Project", "slugId", FieldKind::Scalar, "String"),
+    ("Project", "sortOrder", FieldKind::Scalar, "Float"),
+    ("Project", "startDate", FieldKind::Scalar, "TimelessDate"),
+    ("Project", "startDateResolution", FieldKind::Scalar, "DateResolutionType"),
+    ("Project", "startedAt", FieldKind::Scalar, "DateTime"),
+    ("Project", "state", FieldKind::Scalar, "String"),
+    ("Project", "status", FieldKind::Object, "ProjectStatus"),
+    ("Project", "targetDate", FieldKind::Scalar, "TimelessDate"),
+    ("Project", "targetDateResolution", FieldKind::Scalar, "DateResolutionType"),
+    ("Project", "teams", FieldKind::Connection, "TeamConnection"),
+    ("Project", "trashed", FieldKind::Scalar, "Boolean"),
+    ("Project", "updateReminderFrequency", FieldKind::Scalar, "Float"),
+    ("Project", "updateReminderFrequencyInWeeks", FieldKind::Scalar, "Float"),
+    ("Project", "updateRemindersDay", FieldKind::Scalar, "Day"),
+    ("Project", "updateRemindersHour", FieldKind::Scalar, "Float"),
+    ("Project", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("Project", "url", FieldKind::Scalar, "String"),
+    ("ProjectArchivePayload", "entity", FieldKind::Object, "Project"),
+    ("ProjectArchivePayload", "lastSyncId", FieldKind::Scalar, "Float"),
+    ("ProjectArchivePayload", "success", FieldKind::Scalar, "Boolean"),
+    ("ProjectAttachment", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("ProjectAttachment", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("ProjectAttachment", "creator", FieldKind::Object, "User"),
+    ("ProjectAttachment", "id", FieldKind::Scalar, "ID"),
+    ("ProjectAttachment", "metadata", FieldKind::Scalar, "JSONObject"),
+    ("ProjectAttachment", "source", FieldKind::Scalar, "JSONObject"),
+    ("ProjectAttachment", "sourceType", FieldKind::Scalar, "String"),
+    ("ProjectAttachment", "subtitle", FieldKind::Scalar, "String"),
+    ("ProjectAttachment", "title", FieldKind::Scalar, "String"),
+    ("ProjectAttachment", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("ProjectAttachment", "url", FieldKind::Scalar, "String"),
+    ("ProjectChildWebhookPayload", "id", FieldKind::Scalar, "String"),
+    ("ProjectChildWebhookPayload", "name", FieldKind::Scalar, "String"),
+    ("ProjectChildWebhookPayload", "url", FieldKind::Scalar, "String"),
+    ("ProjectConnection", "edges", FieldKind::Object, "ProjectEdge"),
+    ("ProjectConnection", "nodes", FieldKind::Object, "Project"),
+    ("ProjectConnection", "pageInfo", FieldKind::Object, "PageInfo"),
+    ("ProjectEdge", "cursor", FieldKind::Scalar, "String"),
+    ("ProjectEdge", "node", FieldKind::Object, "Project"),
+    ("ProjectFilterSuggestionPayload", "filter", FieldKind::Scalar, "JSONObject"),
+    ("ProjectFilterSuggestionPayload", "logId", FieldKind::Scalar, "String"),
+    ("ProjectHistory", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("ProjectHistory", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("ProjectHistory", "entries", FieldKind::Scalar, "JSONObject"),
+    ("ProjectHistory", "id", FieldKind::Scalar, "ID"),
+    ("ProjectHistory", "project", FieldKind::Object, "Project"),
+    ("ProjectHistory", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("ProjectHistoryConnection", "edges", FieldKind::Object, "ProjectHistoryEdge"),
+    ("ProjectHistoryConnection", "nodes", FieldKind::Object, "ProjectHistory"),
+    ("ProjectHistoryConnection", "pageInfo", FieldKind::Object, "PageInfo"),
+    ("ProjectHistoryEdge", "cursor", FieldKind::Scalar, "String"),
+    ("ProjectHistoryEdge", "node", FieldKind::Object, "ProjectHistory"),
+    ("ProjectLabel", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("ProjectLabel", "children", FieldKind::Connection, "ProjectLabelConnection"),
+    ("ProjectLabel", "color", FieldKind::Scalar, "String"),
+    ("ProjectLabel", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("ProjectLabel", "creator", FieldKind::Object, "User"),
+    ("ProjectLabel", "description", FieldKind::Scalar, "String"),
+    ("ProjectLabel", "id", FieldKind::Scalar, "ID"),
+    ("ProjectLabel", "isGroup", FieldKind::Scalar, "Boolean"),
+    ("ProjectLabel", "lastAppliedAt", FieldKind::Scalar, "DateTime"),
+    ("ProjectLabel", "name", FieldKind::Scalar, "String"),
+    ("ProjectLabel", "organization", FieldKind::Object, "Organization"),
+    ("ProjectLabel", "parent", FieldKind::Object, "ProjectLabel"),
+    ("ProjectLabel", "projects", FieldKind::Connection, "ProjectConnection"),
+    ("ProjectLabel", "retiredAt", FieldKind::Scalar, "DateTime"),
+    ("ProjectLabel", "retiredBy", FieldKind::Object, "User"),
+    ("ProjectLabel", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("ProjectLabelChildWebhookPayload", "color", FieldKind::Scalar, "String"),
+    ("ProjectLabelChildWebhookPayload", "id", FieldKind::Scalar, "String"),
+    ("ProjectLabelChildWebhookPayload", "name", FieldKind::Scalar, "String"),
+    ("ProjectLabelChildWebhookPayload", "parentId", FieldKind::Scalar, "String"),
+    ("ProjectLabelConnection", "edges", FieldKind::Object, "ProjectLabelEdge"),
+    ("ProjectLabelConnection", "nodes", FieldKind::Object, "ProjectLabel"),
+    ("ProjectLabelConnection", "pageInfo", FieldKind::Object, "PageInfo"),
+    ("ProjectLabelEdge", "cursor", FieldKind::Scalar, "String"),
+    ("ProjectLabelEdge", "node", FieldKind::Object, "ProjectLabel"),
+    ("ProjectLabelPayload", "lastSyncId", FieldKind::Scalar, "Float"),
+    ("ProjectLabelPayload", "projectLabel", FieldKind::Object, "ProjectLabel"),
+    ("ProjectLabelPayload", "success", FieldKind::Scalar, "Boolean"),
+    ("ProjectLabelWebhookPayload", "archivedAt", FieldKind::Scalar, "String"),
+    ("ProjectLabelWebhookPayload", "color", FieldKind::Scalar, "String"),
+    ("ProjectLabelWebhookPayload", "createdAt", FieldKind::Scalar, "String"),
+    ("ProjectLabelWebhookPayload", "creatorId", FieldKind::Scalar, "String"),
+    ("ProjectLabelWebhookPayload", "description", FieldKind::Scalar, "String"),
+    ("ProjectLabelWebhookPayload", "id", FieldKind::Scalar, "String"),
+    ("ProjectLabelWebhookPayload", "isGroup", FieldKind::Scalar, "Boolean"),
+    ("ProjectLabelWebhookPayload", "name", FieldKind::Scalar, "String"),
+    ("ProjectLabelWebhookPayload", "parentId", FieldKind::Scalar, "String"),
+    ("ProjectLabelWebhookPayload", "updatedAt", FieldKind::Scalar, "String"),
+    ("ProjectMilestone", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("ProjectMilestone", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("ProjectMilestone", "currentProgress", FieldKind::Scalar, "JSONObject"),
+    ("ProjectMilestone", "description", FieldKind::Scalar, "String"),
+    ("ProjectMilestone", "descriptionState", FieldKind::Scalar, "String"),
+    ("ProjectMilestone", "documentContent", FieldKind::Object, "DocumentContent"),
+    ("ProjectMilestone", "id", FieldKind::Scalar, "ID"),
+    ("ProjectMilestone", "issues", FieldKind::Connection, "IssueConnection"),
+    ("ProjectMilestone", "name", FieldKind::Scalar, "String"),
+    ("ProjectMilestone", "progress", FieldKind::Scalar, "Float"),
+    ("ProjectMilestone", "progressHistory", FieldKind::Scalar, "JSONObject"),
+    ("ProjectMilestone", "project", FieldKind::Object, "Project"),
+    ("ProjectMilestone", "sortOrder", FieldKind::Scalar, "Float"),
+    ("ProjectMilestone", "status", FieldKind::Scalar, "ProjectMilestoneStatus"),
+    ("ProjectMilestone", "targetDate", FieldKind::Scalar, "TimelessDate"),
+    ("ProjectMilestone", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("ProjectMilestoneChildWebhookPayload", "id", FieldKind::Scalar, "String"),
+    ("ProjectMilestoneChildWebhookPayload", "name", FieldKind::Scalar, "String"),
+    ("ProjectMilestoneChildWebhookPayload", "targetDate", FieldKind::Scalar, "String"),
+    ("ProjectMilestoneConnection", "edges", FieldKind::Object, "ProjectMilestoneEdge"),
+    ("ProjectMilestoneConnection", "nodes", FieldKind::Object, "ProjectMilestone"),
+    ("ProjectMilestoneConnection", "pageInfo", FieldKind::Object, "PageInfo"),
+    ("ProjectMilestoneEdge", "cursor", FieldKind::Scalar, "String"),
+    ("ProjectMilestoneEdge", "node", FieldKind::Object, "ProjectMilestone"),
+    ("ProjectMilestoneMoveIssueToTeam", "issueId", FieldKind::Scalar, "String"),
+    ("ProjectMilestoneMoveIssueToTeam", "teamId", FieldKind::Scalar, "String"),
+    ("ProjectMilestoneMovePayload", "lastSyncId", FieldKind::Scalar, "Float"),
+    ("ProjectMilestoneMovePayload", "previousIssueTeamIds", FieldKind::Object, "ProjectMilestoneMoveIssueToTeam"),
+    ("ProjectMilestoneMovePayload", "previousProjectTeamIds", FieldKind::Object, "ProjectMilestoneMoveProjectTeams"),
+    ("ProjectMilestoneMovePayload", "projectMilestone", FieldKind::Object, "ProjectMilestone"),
+    ("ProjectMilestoneMovePayload", "success", FieldKind::Scalar, "Boolean"),
+    ("ProjectMilestoneMoveProjectTeams", "projectId", FieldKind::Scalar, "String"),
+    ("ProjectMilestoneMoveProjectTeams", "teamIds", FieldKind::Scalar, "String"),
+    ("ProjectMilestonePayload", "lastSyncId", FieldKind::Scalar, "Float"),
+    ("ProjectMilestonePayload", "projectMilestone", FieldKind::Object, "ProjectMilestone"),
+    ("ProjectMilestonePayload", "success", FieldKind::Scalar, "Boolean"),
+    ("ProjectNotification", "actor", FieldKind::Object, "User"),
+    ("ProjectNotification", "actorAvatarColor", FieldKind::Scalar, "String"),
+    ("ProjectNotification", "actorAvatarUrl", FieldKind::Scalar, "String"),
+    ("ProjectNotification", "actorInitials", FieldKind::Scalar, "String"),
+    ("ProjectNotification", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("ProjectNotification", "botActor", FieldKind::Object, "ActorBot"),
+    ("ProjectNotification", "category", FieldKind::Scalar, "NotificationCategory"),
+    ("ProjectNotification", "comment", FieldKind::Object, "Comment"),
+    ("ProjectNotification", "commentId", FieldKind::Scalar, "String"),
+    ("ProjectNotification", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("ProjectNotification", "document", FieldKind::Object, "Document"),
+    ("ProjectNotification", "emailedAt", FieldKind::Scalar, "DateTime"),
+    ("ProjectNotification", "externalUserActor", FieldKind::Object, "ExternalUser"),
+    ("ProjectNotification", "groupingKey", FieldKind::Scalar, "String"),
+    ("ProjectNotification", "groupingPriority", FieldKind::Scalar, "Float"),
+    ("ProjectNotification", "id", FieldKind::Scalar, "ID"),
+    ("ProjectNotification", "inboxUrl", FieldKind::Scalar, "String"),
+    ("ProjectNotification", "initiativeUpdateHealth", FieldKind::Scalar, "String"),
+    ("ProjectNotification", "isLinearActor", FieldKind::Scalar, "Boolean"),
+    ("ProjectNotification", "issueStatusType", FieldKind::Scalar, "String"),
+    ("ProjectNotification", "parentComment", FieldKind::Object, "Comment"),
+    ("ProjectNotification", "parentCommentId", FieldKind::Scalar, "String"),
+    ("ProjectNotification", "project", FieldKind::Object, "Project"),
+    ("ProjectNotification", "projectId", FieldKind::Scalar, "String"),
+    ("ProjectNotification", "projectMilestoneId", FieldKind::Scalar, "String"),
+    ("ProjectNotification", "projectUpdate", FieldKind::Object, "ProjectUpdate"),
+    ("ProjectNotification", "projectUpdateHealth", FieldKind::Scalar, "String"),
+    ("ProjectNotification", "projectUpdateId", FieldKind::Scalar, "String"),
+    ("ProjectNotification", "reactionEmoji", FieldKind::Scalar, "String"),
+    ("ProjectNotification", "readAt", FieldKind::Scalar, "DateTime"),
+    ("ProjectNotification", "snoozedUntilAt", FieldKind::Scalar, "DateTime"),
+    ("ProjectNotification", "subtitle", FieldKind::Scalar, "String"),
+    ("ProjectNotification", "title", FieldKind::Scalar, "String"),
+    ("ProjectNotification", "type", FieldKind::Scalar, "String"),
+    ("ProjectNotification", "unsnoozedAt", FieldKind::Scalar, "DateTime"),
+    ("ProjectNotification", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("ProjectNotification", "url", FieldKind::Scalar, "String"),
+    ("ProjectNotification", "user", FieldKind::Object, "User"),
+    ("ProjectNotificationSubscription", "active", FieldKind::Scalar, "Boolean"),
+    ("ProjectNotificationSubscription", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("ProjectNotificationSubscription", "contextViewType", FieldKind::Scalar, "ContextViewType"),
+    ("ProjectNotificationSubscription", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("ProjectNotificationSubscription", "customView", FieldKind::Object, "CustomView"),
+    ("ProjectNotificationSubscription", "customer", FieldKind::Object, "Customer"),
+    ("ProjectNotificationSubscription", "cycle", FieldKind::Object, "Cycle"),
+    ("ProjectNotificationSubscription", "id", FieldKind::Scalar, "ID"),
+    ("ProjectNotificationSubscription", "initiative", FieldKind::Object, "Initiative"),
+    ("ProjectNotificationSubscription", "label", FieldKind::Object, "IssueLabel"),
+    ("ProjectNotificationSubscription", "notificationSubscriptionTypes", FieldKind::Scalar, "String"),
+    ("ProjectNotificationSubscription", "project", FieldKind::Object, "Project"),
+    ("ProjectNotificationSubscription", "subscriber", FieldKind::Object, "User"),
+    ("ProjectNotificationSubscription", "team", FieldKind::Object, "Team"),
+    ("ProjectNotificationSubscription", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("ProjectNotificationSubscription", "user", FieldKind::Object, "User"),
+    ("ProjectNotificationSubscription", "userContextViewType", FieldKind::Scalar, "UserContextViewType"),
+    ("ProjectPayload", "lastSyncId", FieldKind::Scalar, "Float"),
+    ("ProjectPayload", "project", FieldKind::Object, "Project"),
+    ("ProjectPayload", "success", FieldKind::Scalar, "Boolean"),
+    ("ProjectRelation", "anchorType", FieldKind::Scalar, "String"),
+    ("ProjectRelation", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("ProjectRelation", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("ProjectRelation", "id", FieldKind::Scalar, "ID"),
+    ("ProjectRelation", "project", FieldKind::Object, "Project"),
+    ("ProjectRelation", "projectMilestone", FieldKind::Object, "ProjectMilestone"),
+    ("ProjectRelation", "relatedAnchorType", FieldKind::Scalar, "String"),
+    ("ProjectRelation", "relatedProject", FieldKind::Object, "Project"),
+    ("ProjectRelation", "relatedProjectMilestone", FieldKind::Object, "ProjectMilestone"),
+    ("ProjectRelation", "type", FieldKind::Scalar, "String"),
+    ("ProjectRelation", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("ProjectRelation", "user", FieldKind::Object, "User"),
+    ("ProjectRelationConnection", "edges", FieldKind::Object, "ProjectRelationEdge"),
+    ("ProjectRelationConnection", "nodes", FieldKind::Object, "ProjectRelation"),
+    ("ProjectRelationConnection", "pageInfo", FieldKind::Object, "PageInfo"),
+    ("ProjectRelationEdge", "cursor", FieldKind::Scalar, "String"),
+    ("ProjectRelationEdge", "node", FieldKind::Object, "ProjectRelation"),
+    ("ProjectRelationPayload", "lastSyncId", FieldKind::Scalar, "Float"),
+    ("ProjectRelationPayload", "projectRelation", FieldKind::Object, "ProjectRelation"),
+    ("ProjectRelationPayload", "success", FieldKind::Scalar, "Boolean"),
+    ("ProjectSearchPayload", "archivePayload", FieldKind::Object, "ArchiveResponse"),
+    ("ProjectSearchPayload", "edges", FieldKind::Object, "ProjectSearchResultEdge"),
+    ("ProjectSearchPayload", "nodes", FieldKind::Object, "ProjectSearchResult"),
+    ("ProjectSearchPayload", "pageInfo", FieldKind::Object, "PageInfo"),
+    ("ProjectSearchPayload", "totalCount", FieldKind::Scalar, "Float"),
+    ("ProjectSearchResult", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("ProjectSearchResult", "autoArchivedAt", FieldKind::Scalar, "DateTime"),
+    ("ProjectSearchResult", "canceledAt", FieldKind::Scalar, "DateTime"),
+    ("ProjectSearchResult", "color", FieldKind::Scalar, "String"),
+    ("ProjectSearchResult", "comments", FieldKind::Connection, "CommentConnection"),
+    ("ProjectSearchResult", "completedAt", FieldKind::Scalar, "DateTime"),
+    ("ProjectSearchResult", "completedIssueCountHistory", FieldKind::Scalar, "Float"),
+    ("ProjectSearchResult", "completedScopeHistory", FieldKind::Scalar, "Float"),
+    ("ProjectSearchResult", "content", FieldKind::Scalar, "String"),
+    ("ProjectSearchResult", "contentState", FieldKind::Scalar, "String"),
+    ("ProjectSearchResult", "convertedFromIssue", FieldKind::Object, "Issue"),
+    ("ProjectSearchResult", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("ProjectSearchResult", "creator", FieldKind::Object, "User"),
+    ("ProjectSearchResult", "currentProgress", FieldKind::Scalar, "JSONObject"),
+    ("ProjectSearchResult", "description", FieldKind::Scalar, "String"),
+    ("ProjectSearchResult", "documentContent", FieldKind::Object, "DocumentContent"),
+    ("ProjectSearchResult", "documents", FieldKind::Connection, "DocumentConnection"),
+    ("ProjectSearchResult", "externalLinks", FieldKind::Connection, "EntityExternalLinkConnection"),
+    ("ProjectSearchResult", "facets", FieldKind::Object, "Facet"),
+    ("ProjectSearchResult", "favorite", FieldKind::Object, "Favorite"),
+    ("ProjectSearchResult", "frequencyResolution", FieldKind::Scalar, "FrequencyResolutionType"),
+    ("ProjectSearchResult", "health", FieldKind::Scalar, "ProjectUpdateHealthType"),
+    ("ProjectSearchResult", "healthUpdatedAt", FieldKind::Scalar, "DateTime"),
+    ("ProjectSearchResult", "history", FieldKind::Connection, "ProjectHistoryConnection"),
+    ("ProjectSearchResult", "icon", FieldKind::Scalar, "String"),
+    ("ProjectSearchResult", "id", FieldKind::Scalar, "ID"),
+    ("ProjectSearchResult", "inProgressScopeHistory", FieldKind::Scalar, "Float"),
+    ("ProjectSearchResult", "initiativeToProjects", FieldKind::Connection, "InitiativeToProjectConnection"),
+    ("ProjectSearchResult", "initiatives", FieldKind::Connection, "InitiativeConnection"),
+    ("ProjectSearchResult", "integrationsSettings", FieldKind::Object, "IntegrationsSettings"),
+    ("ProjectSearchResult", "inverseRelations", FieldKind::Connection, "ProjectRelationConnection"),
+    ("ProjectSearchResult", "issueCountHistory", FieldKind::Scalar, "Float"),
+    ("ProjectSearchResult", "issues", FieldKind::Connection, "IssueConnection"),
+    ("ProjectSearchResult", "labelIds", FieldKind::Scalar, "String"),
+    ("ProjectSearchResult", "labels", FieldKind::Connection, "ProjectLabelConnection"),
+    ("ProjectSearchResult", "lastAppliedTemplate", FieldKind::Object, "Template"),
+    ("ProjectSearchResult", "lastUpdate", FieldKind::Object, "ProjectUpdate"),
+    ("ProjectSearchResult", "lead", FieldKind::Object, "User"),
+    ("ProjectSearchResult", "members", FieldKind::Connection, "UserConnection"),
+    ("ProjectSearchResult", "metadata", FieldKind::Scalar, "JSONObject"),
+    ("ProjectSearchResult", "name", FieldKind::Scalar, "String"),
+    ("ProjectSearchResult", "needs", FieldKind::Connection, "CustomerNeedConnection"),
+    ("ProjectSearchResult", "priority", FieldKind::Scalar, "Int"),
+    ("ProjectSearchResult", "priorityLabel", FieldKind::Scalar, "String"),
+    ("ProjectSearchResult", "prioritySortOrder", FieldKind::Scalar, "Float"),
+    ("ProjectSearchResult", "progress", FieldKind::Scalar, "Float"),
+    ("ProjectSearchResult", "progressHistory", FieldKind::Scalar, "JSONObject"),
+    ("ProjectSearchResult", "projectMilestones", FieldKind::Connection, "ProjectMilestoneConnection"),
+    ("ProjectSearchResult", "projectUpdateRemindersPausedUntilAt", FieldKind::Scalar, "DateTime"),
+    ("ProjectSearchResult", "projectUpdates", FieldKind::Connection, "ProjectUpdateConnection"),
+    ("ProjectSearchResult", "relations", FieldKind::Connection, "ProjectRelationConnection"),
+    ("ProjectSearchResult", "scope", FieldKind::Scalar, "Float"),
+    ("ProjectSearchResult", "scopeHistory", FieldKind::Scalar, "Float"),
+    ("ProjectSearchResult", "slackIssueComments", FieldKind::Scalar, "Boolean"),
+    ("ProjectSearchResult", "slackIssueStatuses", FieldKind::Scalar, "Boolean"),
+    ("ProjectSearchResult", "slackNewIssue", FieldKind::Scalar, "Boolean"),
+    ("ProjectSearchResult", "slugId", FieldKind::Scalar, "String"),
+    ("ProjectSearchResult", "sortOrder", FieldKind::Scalar, "Float"),
+    ("ProjectSearchResult", "startDate", FieldKind::Scalar, "TimelessDate"),
+    ("ProjectSearchResult", "startDateResolution", FieldKind::Scalar, "DateResolutionType"),
+    ("ProjectSearchResult", "startedAt", FieldKind::Scalar, "DateTime"),
+    ("ProjectSearchResult", "state", FieldKind::Scalar, "String"),
+    ("ProjectSearchResult", "status", FieldKind::Object, "ProjectStatus"),
+    ("ProjectSearchResult", "targetDate", FieldKind::Scalar, "TimelessDate"),
+    ("ProjectSearchResult", "targetDateResolution", FieldKind::Scalar, "DateResolutionType"),
+    ("ProjectSearchResult", "teams", FieldKind::Connection, "TeamConnection"),
+    ("ProjectSearchResult", "trashed", FieldKind::Scalar, "Boolean"),
+    ("ProjectSearchResult", "updateReminderFrequency", FieldKind::Scalar, "Float"),
+    ("ProjectSearchResult", "updateReminderFrequencyInWeeks", FieldKind::Scalar, "Float"),
+    ("ProjectSearchResult", "updateRemindersDay", FieldKind::Scalar, "Day"),
+    ("ProjectSearchResult", "updateRemindersHour", FieldKind::Scalar, "Float"),
+    ("ProjectSearchResult", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("ProjectSearchResult", "url", FieldKind::Scalar, "String"),
+    ("ProjectSearchResultEdge", "cursor", FieldKind::Scalar, "String"),
+    ("ProjectSearchResultEdge", "node", FieldKind::Object, "ProjectSearchResult"),
+    ("ProjectStatus", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("ProjectStatus", "color", FieldKind::Scalar, "String"),
+    ("ProjectStatus", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("ProjectStatus", "description", FieldKind::Scalar, "String"),
+    ("ProjectStatus", "id", FieldKind::Scalar, "ID"),
+    ("ProjectStatus", "indefinite", FieldKind::Scalar, "Boolean"),
+    ("ProjectStatus", "name", FieldKind::Scalar, "String"),
+    ("ProjectStatus", "position", FieldKind::Scalar, "Float"),
+    ("ProjectStatus", "type", FieldKind::Scalar, "ProjectStatusType"),
+    ("ProjectStatus", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("ProjectStatusArchivePayload", "entity", FieldKind::Object, "ProjectStatus"),
+    ("ProjectStatusArchivePayload", "lastSyncId", FieldKind::Scalar, "Float"),
+    ("ProjectStatusArchivePayload", "success", FieldKind::Scalar, "Boolean"),
+    ("ProjectStatusChildWebhookPayload", "color", FieldKind::Scalar, "String"),
+    ("ProjectStatusChildWebhookPayload", "id", FieldKind::Scalar, "String"),
+    ("ProjectStatusChildWebhookPayload", "name", FieldKind::Scalar, "String"),
+    ("ProjectStatusChildWebhookPayload", "type", FieldKind::Scalar, "String"),
+    ("ProjectStatusConnection", "edges", FieldKind::Object, "ProjectStatusEdge"),
+    ("ProjectStatusConnection", "nodes", FieldKind::Object, "ProjectStatus"),
+    ("ProjectStatusConnection", "pageInfo", FieldKind::Object, "PageInfo"),
+    ("ProjectStatusCountPayload", "archivedTeamCount", FieldKind::Scalar, "Float"),
+    ("ProjectStatusCountPayload", "count", FieldKind::Scalar, "Float"),
+    ("ProjectStatusCountPayload", "privateCount", FieldKind::Scalar, "Float"),
+    ("ProjectStatusEdge", "cursor", FieldKind::Scalar, "String"),
+    ("ProjectStatusEdge", "node", FieldKind::Object, "ProjectStatus"),
+    ("ProjectStatusPayload", "lastSyncId", FieldKind::Scalar, "Float"),
+    ("ProjectStatusPayload", "status", FieldKind::Object, "ProjectStatus"),
+    ("ProjectStatusPayload", "success", FieldKind::Scalar, "Boolean"),
+    ("ProjectUpdate", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("ProjectUpdate", "body", FieldKind::Scalar, "String"),
+    ("ProjectUpdate", "bodyData", FieldKind::Scalar, "String"),
+    ("ProjectUpdate", "commentCount", FieldKind::Scalar, "Int"),
+    ("ProjectUpdate", "comments", FieldKind::Connection, "CommentConnection"),
+    ("ProjectUpdate", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("ProjectUpdate", "diff", FieldKind::Scalar, "JSONObject"),
+    ("ProjectUpdate", "diffMarkdown", FieldKind::Scalar, "String"),
+    ("ProjectUpdate", "editedAt", FieldKind::Scalar, "DateTime"),
+    ("ProjectUpdate", "health", FieldKind::Scalar, "ProjectUpdateHealthType"),
+    ("ProjectUpdate", "id", FieldKind::Scalar, "ID"),
+    ("ProjectUpdate", "infoSnapshot", FieldKind::Scalar, "JSONObject"),
+    ("ProjectUpdate", "isDiffHidden", FieldKind::Scalar, "Boolean"),
+    ("ProjectUpdate", "isStale", FieldKind::Scalar, "Boolean"),
+    ("ProjectUpdate", "project", FieldKind::Object, "Project"),
+    ("ProjectUpdate", "reactionData", FieldKind::Scalar, "JSONObject"),
+    ("ProjectUpdate", "reactions", FieldKind::Object, "Reaction"),
+    ("ProjectUpdate", "slugId", FieldKind::Scalar, "String"),
+    ("ProjectUpdate", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("ProjectUpdate", "url", FieldKind::Scalar, "String"),
+    ("ProjectUpdate", "user", FieldKind::Object, "User"),
+    ("ProjectUpdateArchivePayload", "entity", FieldKind::Object, "ProjectUpdate"),
+    ("ProjectUpdateArchivePayload", "lastSyncId", FieldKind::Scalar, "Float"),
+    ("ProjectUpdateArchivePayload", "success", FieldKind::Scalar, "Boolean"),
+    ("ProjectUpdateChildWebhookPayload", "body", FieldKind::Scalar, "String"),
+    ("ProjectUpdateChildWebhookPayload", "id", FieldKind::Scalar, "String"),
+    ("ProjectUpdateChildWebhookPayload", "project", FieldKind::Object, "ProjectChildWebhookPayload"),
+    ("ProjectUpdateChildWebhookPayload", "userId", FieldKind::Scalar, "String"),
+    ("ProjectUpdateConnection", "edges", FieldKind::Object, "ProjectUpdateEdge"),
+    ("ProjectUpdateConnection", "nodes", FieldKind::Object, "ProjectUpdate"),
+    ("ProjectUpdateConnection", "pageInfo", FieldKind::Object, "PageInfo"),
+    ("ProjectUpdateEdge", "cursor", FieldKind::Scalar, "String"),
+    ("ProjectUpdateEdge", "node", FieldKind::Object, "ProjectUpdate"),
+    ("ProjectUpdatePayload", "lastSyncId", FieldKind::Scalar, "Float"),
+    ("ProjectUpdatePayload", "projectUpdate", FieldKind::Object, "ProjectUpdate"),
+    ("ProjectUpdatePayload", "success", FieldKind::Scalar, "Boolean"),
+    ("ProjectUpdateReminderPayload", "lastSyncId", FieldKind::Scalar, "Float"),
+    ("ProjectUpdateReminderPayload", "success", FieldKind::Scalar, "Boolean"),
+    ("ProjectUpdateWebhookPayload", "archivedAt", FieldKind::Scalar, "String"),
+    ("ProjectUpdateWebhookPayload", "body", FieldKind::Scalar, "String"),
+    ("ProjectUpdateWebhookPayload", "bodyData", FieldKind::Scalar, "String"),
+    ("ProjectUpdateWebhookPayload", "createdAt", FieldKind::Scalar, "String"),
+    ("ProjectUpdateWebhookPayload", "editedAt", FieldKind::Scalar, "String"),
+    ("ProjectUpdateWebhookPayload", "health", FieldKind::Scalar, "String"),
+    ("ProjectUpdateWebhookPayload", "id", FieldKind::Scalar, "String"),
+    ("ProjectUpdateWebhookPayload", "project", FieldKind::Object, "ProjectChildWebhookPayload"),
+    ("ProjectUpdateWebhookPayload", "projectId", FieldKind::Scalar, "String"),
+    ("ProjectUpdateWebhookPayload", "reactionData", FieldKind::Scalar, "JSONObject"),
+    ("ProjectUpdateWebhookPayload", "slugId", FieldKind::Scalar, "String"),
+    ("ProjectUpdateWebhookPayload", "updatedAt", FieldKind::Scalar, "String"),
+    ("ProjectUpdateWebhookPayload", "url", FieldKind::Scalar, "String"),
+    ("ProjectUpdateWebhookPayload", "user", FieldKind::Object, "UserChildWebhookPayload"),
+    ("ProjectUpdateWebhookPayload", "userId", FieldKind::Scalar, "String"),
+    ("ProjectWebhookPayload", "archivedAt", FieldKind::Scalar, "String"),
+    ("ProjectWebhookPayload", "autoArchivedAt", FieldKind::Scalar, "String"),
+    ("ProjectWebhookPayload", "canceledAt", FieldKind::Scalar, "String"),
+    ("ProjectWebhookPayload", "color", FieldKind::Scalar, "String"),
+    ("ProjectWebhookPayload", "completedAt", FieldKind::Scalar, "String"),
+    ("ProjectWebhookPayload", "completedIssueCountHistory", FieldKind::Scalar, "Float"),
+    ("ProjectWebhookPayload", "completedScopeHistory", FieldKind::Scalar, "Float"),
+    ("ProjectWebhookPayload", "content", FieldKind::Scalar, "String"),
+    ("ProjectWebhookPayload", "convertedFromIssueId", FieldKind::Scalar, "String"),
+    ("ProjectWebhookPayload", "createdAt", FieldKind::Scalar, "String"),
+    ("ProjectWebhookPayload", "creatorId", FieldKind::Scalar, "String"),
+    ("ProjectWebhookPayload", "description", FieldKind::Scalar, "String"),
+    ("ProjectWebhookPayload", "documentContentId", FieldKind::Scalar, "String"),
+    ("ProjectWebhookPayload", "health", FieldKind::Scalar, "String"),
+    ("ProjectWebhookPayload", "healthUpdatedAt", FieldKind::Scalar, "String"),
+    ("ProjectWebhookPayload", "icon", FieldKind::Scalar, "String"),
+    ("ProjectWebhookPayload", "id", FieldKind::Scalar, "String"),
+    ("ProjectWebhookPayload", "inProgressScopeHistory", FieldKind::Scalar, "Float"),
+    ("ProjectWebhookPayload", "initiatives", FieldKind::Object, "InitiativeChildWebhookPayload"),
+    ("ProjectWebhookPayload", "issueCountHistory", FieldKind::Scalar, "Float"),
+    ("ProjectWebhookPayload", "labelIds", FieldKind::Scalar, "String"),
+    ("ProjectWebhookPayload", "lastAppliedTemplateId", FieldKind::Scalar, "String"),
+    ("ProjectWebhookPayload", "lastUpdateId", FieldKind::Scalar, "String"),
+    ("ProjectWebhookPayload", "lead", FieldKind::Object, "UserChildWebhookPayload"),
+    ("ProjectWebhookPayload", "leadId", FieldKind::Scalar, "String"),
+    ("ProjectWebhookPayload", "memberIds", FieldKind::Scalar, "String"),
+    ("ProjectWebhookPayload", "milestones", FieldKind::Object, "ProjectMilestoneChildWebhookPayload"),
+    ("ProjectWebhookPayload", "name", FieldKind::Scalar, "String"),
+    ("ProjectWebhookPayload", "priority", FieldKind::Scalar, "Float"),
+    ("ProjectWebhookPayload", "prioritySortOrder", FieldKind::Scalar, "Float"),
+    ("ProjectWebhookPayload", "projectUpdateRemindersPausedUntilAt", FieldKind::Scalar, "String"),
+    ("ProjectWebhookPayload", "scopeHistory", FieldKind::Scalar, "Float"),
+    ("ProjectWebhookPayload", "slugId", FieldKind::Scalar, "String"),
+    ("ProjectWebhookPayload", "sortOrder", FieldKind::Scalar, "Float"),
+    ("ProjectWebhookPayload", "startDate", FieldKind::Scalar, "String"),
+    ("ProjectWebhookPayload", "startDateResolution", FieldKind::Scalar, "String"),
+    ("ProjectWebhookPayload", "startedAt", FieldKind::Scalar, "String"),
+    ("ProjectWebhookPayload", "status", FieldKind::Object, "ProjectStatusChildWebhookPayload"),
+    ("ProjectWebhookPayload", "statusId", FieldKind::Scalar, "String"),
+    ("ProjectWebhookPayload", "targetDate", FieldKind::Scalar, "String"),
+    ("ProjectWebhookPayload", "targetDateResolution", FieldKind::Scalar, "String"),
+    ("ProjectWebhookPayload", "teamIds", FieldKind::Scalar, "String"),
+    ("ProjectWebhookPayload", "trashed", FieldKind::Scalar, "Boolean"),
+    ("ProjectWebhookPayload", "updatedAt", FieldKind::Scalar, "String"),
+    ("ProjectWebhookPayload", "url", FieldKind::Scalar, "String"),
+    ("PullRequest", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("PullRequest", "commits", FieldKind::Object, "PullRequestCommit"),
+    ("PullRequest", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("PullRequest", "id", FieldKind::Scalar, "ID"),
+    ("PullRequest", "mergeCommit", FieldKind::Object, "PullRequestCommit"),
+    ("PullRequest", "mergeSettings", FieldKind::Object, "PullRequestMergeSettings"),
+    ("PullRequest", "number", FieldKind::Scalar, "Float"),
+    ("PullRequest", "sourceBranch", FieldKind::Scalar, "String"),
+    ("PullRequest", "status", FieldKind::Scalar, "PullRequestStatus"),
+    ("PullRequest", "targetBranch", FieldKind::Scalar, "String"),
+    ("PullRequest", "title", FieldKind::Scalar, "String"),
+    ("PullRequest", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("PullRequest", "url", FieldKind::Scalar, "String"),
+    ("PullRequestCommit", "additions", FieldKind::Scalar, "Float"),
+    ("PullRequestCommit", "authorExternalUserIds", FieldKind::Scalar, "String"),
+    ("PullRequestCommit", "authorUserIds", FieldKind::Scalar, "String"),
+    ("PullRequestCommit", "changedFiles", FieldKind::Scalar, "Float"),
+    ("PullRequestCommit", "committedAt", FieldKind::Scalar, "String"),
+    ("PullRequestCommit", "deletions", FieldKind::Scalar, "Float"),
+    ("PullRequestCommit", "message", FieldKind::Scalar, "String"),
+    ("PullRequestCommit", "sha", FieldKind::Scalar, "String"),
+    ("PullRequestMergeSettings", "autoMergeAllowed", FieldKind::Scalar, "Boolean"),
+    ("PullRequestMergeSettings", "deleteBranchOnMerge", FieldKind::Scalar, "Boolean"),
+    ("PullRequestMergeSettings", "isMergeQueueEnabled", FieldKind::Scalar, "Boolean"),
+    ("PullRequestMergeSettings", "mergeCommitAllowed", FieldKind::Scalar, "Boolean"),
+    ("PullRequestMergeSettings", "mergeQueueMergeMethod", FieldKind::Scalar, "PullRequestMergeMethod"),
+    ("PullRequestMergeSettings", "rebaseMergeAllowed", FieldKind::Scalar, "Boolean"),
+    ("PullRequestMergeSettings", "squashMergeAllowed", FieldKind::Scalar, "Boolean"),
+    ("PullRequestNotification", "actor", FieldKind::Object, "User"),
+    ("PullRequestNotification", "actorAvatarColor", FieldKind::Scalar, "String"),
+    ("PullRequestNotification", "actorAvatarUrl", FieldKind::Scalar, "String"),
+    ("PullRequestNotification", "actorInitials", FieldKind::Scalar, "String"),
+    ("PullRequestNotification", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("PullRequestNotification", "botActor", FieldKind::Object, "ActorBot"),
+    ("PullRequestNotification", "category", FieldKind::Scalar, "NotificationCategory"),
+    ("PullRequestNotification", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("PullRequestNotification", "emailedAt", FieldKind::Scalar, "DateTime"),
+    ("PullRequestNotification", "externalUserActor", FieldKind::Object, "ExternalUser"),
+    ("PullRequestNotification", "groupingKey", FieldKind::Scalar, "String"),
+    ("PullRequestNotification", "groupingPriority", FieldKind::Scalar, "Float"),
+    ("PullRequestNotification", "id", FieldKind::Scalar, "ID"),
+    ("PullRequestNotification", "inboxUrl", FieldKind::Scalar, "String"),
+    ("PullRequestNotification", "initiativeUpdateHealth", FieldKind::Scalar, "String"),
+    ("PullRequestNotification", "isLinearActor", FieldKind::Scalar, "Boolean"),
+    ("PullRequestNotification", "issueStatusType", FieldKind::Scalar, "String"),
+    ("PullRequestNotification", "projectUpdateHealth", FieldKind::Scalar, "String"),
+    ("PullRequestNotification", "pullRequest", FieldKind::Object, "PullRequest"),
+    ("PullRequestNotification", "pullRequestCommentId", FieldKind::Scalar, "String"),
+    ("PullRequestNotification", "pullRequestId", FieldKind::Scalar, "String"),
+    ("PullRequestNotification", "readAt", FieldKind::Scalar, "DateTime"),
+    ("PullRequestNotification", "snoozedUntilAt", FieldKind::Scalar, "DateTime"),
+    ("PullRequestNotification", "subtitle", FieldKind::Scalar, "String"),
+    ("PullRequestNotification", "title", FieldKind::Scalar, "String"),
+    ("PullRequestNotification", "type", FieldKind::Scalar, "String"),
+    ("PullRequestNotification", "unsnoozedAt", FieldKind::Scalar, "DateTime"),
+    ("PullRequestNotification", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("PullRequestNotification", "url", FieldKind::Scalar, "String"),
+    ("PullRequestNotification", "user", FieldKind::Object, "User"),
+    ("PushSubscription", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("PushSubscription", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("PushSubscription", "id", FieldKind::Scalar, "ID"),
+    ("PushSubscription", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("PushSubscriptionPayload", "entity", FieldKind::Object, "PushSubscription"),
+    ("PushSubscriptionPayload", "lastSyncId", FieldKind::Scalar, "Float"),
+    ("PushSubscriptionPayload", "success", FieldKind::Scalar, "Boolean"),
+    ("PushSubscriptionTestPayload", "success", FieldKind::Scalar, "Boolean"),
+    ("Query", "_dummy", FieldKind::Scalar, "String"),
+    ("Query", "administrableTeams", FieldKind::Connection, "TeamConnection"),
+    ("Query", "agentActivities", FieldKind::Connection, "AgentActivityConnection"),
+    ("Query", "agentActivity", FieldKind::Object, "AgentActivity"),
+    ("Query", "agentSession", FieldKind::Object, "AgentSession"),
+    ("Query", "agentSessions", FieldKind::Connection, "AgentSessionConnection"),
+    ("Query", "applicationInfo", FieldKind::Object, "Application"),
+    ("Query", "archivedTeams", FieldKind::Object, "Team"),
+    ("Query", "attachment", FieldKind::Object, "Attachment"),
+    ("Query", "attachmentIssue", FieldKind::Object, "Issue"),
+    ("Query", "attachmentSources", FieldKind::Object, "AttachmentSourcesPayload"),
+    ("Query", "attachments", FieldKind::Connection, "AttachmentConnection"),
+    ("Query", "attachmentsForURL", FieldKind::Connection, "AttachmentConnection"),
+    ("Query", "auditEntries", FieldKind::Connection, "AuditEntryConnection"),
+    ("Query", "auditEntryTypes", FieldKind::Object, "AuditEntryType"),
+    ("Query", "authenticationSessions", FieldKind::Object, "AuthenticationSessionResponse"),
+    ("Query", "availableUsers", FieldKind::Object, "AuthResolverResponse"),
+    ("Query", "comment", FieldKind::Object, "Comment"),
+    ("Query", "comments", FieldKind::Connection, "CommentConnection"),
+    ("Query", "customView", FieldKind::Object, "CustomView"),
+    ("Query", "customViewDetailsSuggestion", FieldKind::Object, "CustomViewSuggestionPayload"),
+    ("Query", "customViewHasSubscribers", FieldKind::Object, "CustomViewHasSubscribersPayload"),
+    ("Query", "customViews", FieldKind::Connection, "CustomViewConnection"),
+    ("Query", "customer", FieldKind::Object, "Customer"),
+    ("Query", "customerNeed", FieldKind::Object, "CustomerNeed"),
+    ("Query", "customerNeeds", FieldKind::Connection, "CustomerNeedConnection"),
+    ("Query", "customerStatus", FieldKind::Object, "CustomerStatus"),
+    ("Query", "customerStatuses", FieldKind::Connection, "CustomerStatusConnection"),
+    ("Query", "customerTier", FieldKind::Object, "CustomerTier"),
+    ("Query", "customerTiers", FieldKind::Connection, "CustomerTierConnection"),
+    ("Query", "customers", FieldKind::Connection, "CustomerConnection"),
+    ("Query", "cycle", FieldKind::Object, "Cycle"),
+    ("Query", "cycles", FieldKind::Connection, "CycleConnection"),
+    ("Query", "document", FieldKind::Object, "Document"),
+    ("Query", "documentContentHistory", FieldKind::Object, "DocumentContentHistoryPayload"),
+    ("Query", "documents", FieldKind::Connection, "DocumentConnection"),
+    ("Query", "emailIntakeAddress", FieldKind::Object, "EmailIntakeAddress"),
+    ("Query", "emoji", FieldKind::Object, "Emoji"),
+    ("Query", "emojis", FieldKind::Connection, "EmojiConnection"),
+    ("Query", "entityExternalLink", FieldKind::Object, "EntityExternalLink"),
+    ("Query", "externalUser", FieldKind::Object, "ExternalUser"),
+    ("Query", "externalUsers", FieldKind::Connection, "ExternalUserConnection"),
+    ("Query", "failuresForOauthWebhooks", FieldKind::Object, "WebhookFailureEvent"),
+    ("Query", "favorite", FieldKind::Object, "Favorite"),
+    ("Query", "favorites", FieldKind::Connection, "FavoriteConnection"),
+    ("Query", "fetchData", FieldKind::Object, "FetchDataPayload"),
+    ("Query", "initiative", FieldKind::Object, "Initiative"),
+    ("Query", "initiativeRelation", FieldKind::Object, "ProjectRelation"),
+    ("Query", "initiativeRelations", FieldKind::Connection, "InitiativeRelationConnection"),
+    ("Query", "initiativeToProject", FieldKind::Object, "InitiativeToProject"),
+    ("Query", "initiativeToProjects", FieldKind::Connection, "InitiativeToProjectConnection"),
+    ("Query", "initiativeUpdate", FieldKind::Object, "InitiativeUpdate"),
+    ("Query", "initiativeUpdates", FieldKind::Connection, "InitiativeUpdateConnection"),
+    ("Query", "initiatives", FieldKind::Connection, "InitiativeConnection"),
+    ("Query", "integration", FieldKind::Object, "Integration"),
+    ("Query", "integrationHasScopes", FieldKind::Object, "IntegrationHasScopesPayload"),
+    ("Query", "integrationTemplate", FieldKind::Object, "IntegrationTemplate"),
+    ("Query", "integrationTemplates", FieldKind::Connection, "IntegrationTemplateConnection"),
+    ("Query", "integrations", FieldKind::Connection, "IntegrationConnection"),
+    ("Query", "integrationsSettings", FieldKind::Object, "IntegrationsSettings"),
+    ("Query", "issue", FieldKind::Object, "Issue"),
+    ("Query", "issueFigmaFileKeySearch", FieldKind::Connection, "IssueConnection"),
+    ("Query", "issueFilterSuggestion", FieldKind::Object, "IssueFilterSuggestionPayload"),
+    ("Query", "issueImportCheckCSV", FieldKind::Object, "IssueImportCheckPayload"),
+    ("Query", "issueImportCheckSync", FieldKind::Object, "IssueImportSyncCheckPayload"),
+    ("Query", "issueImportJqlCheck", FieldKind::Object, "IssueImportJqlCheckPayload"),
+    ("Query", "issueLabel", FieldKind::Object, "IssueLabel"),
+    ("Query", "issueLabels", FieldKind::Connection, "IssueLabelConnection"),
+    ("Query", "issuePriorityValues", FieldKind::Object, "IssuePriorityValue"),
+    ("Query", "issueRelation", FieldKind::Object, "IssueRelation"),
+    ("Query", "issueRelations", FieldKind::Connection, "IssueRelationConnection"),
+    ("Query", "issueRepositorySuggestions", FieldKind::Object, "RepositorySuggestionsPayload"),
+    ("Query", "issueSearch", FieldKind::Connection, "IssueConnection"),
+    ("Query", "issueTitleSuggestionFromCustomerRequest", FieldKind::Object, "IssueTitleSuggestionFromCustomerRequestPayload"),
+    ("Query", "issueToRelease", FieldKind::Object, "IssueToRelease"),
+    ("Query", "issueToReleases", FieldKind::Connection, "IssueToReleaseConnection"),
+    ("Query", "issueVcsBranchSearch", FieldKind::Object, "Issue"),
+    ("Query", "issues", FieldKind::Connection, "IssueConnection"),
+    ("Query", "notification", FieldKind::Object, "Notification"),
+    ("Query", "notificationSubscription", FieldKind::Object, "NotificationSubscription"),
+    ("Query", "notificationSubscriptions", FieldKind::Connection, "NotificationSubscriptionConnection"),
+    ("Query", "notifications", FieldKind::Connection, "NotificationConnection"),
+    ("Query", "notificationsUnreadCount", FieldKind::Scalar, "Int"),
+    ("Query", "organization", FieldKind::Object, "Organization"),
+    ("Query", "organizationDomainClaimRequest", FieldKind::Object, "OrganizationDomainClaimPayload"),
+    ("Query", "organizationExists", FieldKind::Object, "OrganizationExistsPayload"),
+    ("Query", "organizationInvite", FieldKind::Object, "OrganizationInvite"),
+    ("Query", "organizationInviteDetails", FieldKind::Object, "OrganizationInviteDetailsPayload"),
+    ("Query", "organizationInvites", FieldKind::Connection, "OrganizationInviteConnection"),
+    ("Query", "organizationMeta", FieldKind::Object, "OrganizationMeta"),
+    ("Query", "project", FieldKind::Object, "Project"),
+    ("Query", "projectFilterSuggestion", FieldKind::Object, "ProjectFilterSuggestionPayload"),
+    ("Query", "projectLabel", FieldKind::Object, "ProjectLabel"),
+    ("Query", "projectLabels", FieldKind::Connection, "ProjectLabelConnection"),
+    ("Query", "projectMilestone", FieldKind::Object, "ProjectMilestone"),
+    ("Query", "projectMilestones", FieldKind::Connection, "ProjectMilestoneConnection"),
+    ("Query", "projectRelation", FieldKind::Object, "ProjectRelation"),
+    ("Query", "projectRelations", FieldKind::Connection, "ProjectRelationConnection"),
+    ("Query", "projectStatus", FieldKind::Object, "ProjectStatus"),
+    ("Query", "projectStatusProjectCount", FieldKind::Object, "ProjectStatusCountPayload"),
+    ("Query", "projectStatuses", FieldKind::Connection, "ProjectStatusConnection"),
+    ("Query", "projectUpdate", FieldKind::Object, "ProjectUpdate"),
+    ("Query", "projectUpdates", FieldKind::Connection, "ProjectUpdateConnection"),
+    ("Query", "projects", FieldKind::Connection, "ProjectConnection"),
+    ("Query", "pushSubscriptionTest", FieldKind::Object, "PushSubscriptionTestPayload"),
+    ("Query", "rateLimitStatus", FieldKind::Object, "RateLimitPayload"),
+    ("Query", "release", FieldKind::Object, "Release"),
+    ("Query", "releasePipeline", FieldKind::Object, "ReleasePipeline"),
+    ("Query", "releasePipelines", FieldKind::Connection, "ReleasePipelineConnection"),
+    ("Query", "releaseStage", FieldKind::Object, "ReleaseStage"),
+    ("Query", "releaseStages", FieldKind::Connection, "ReleaseStageConnection"),
+    ("Query", "releases", FieldKind::Connection, "ReleaseConnection"),
+    ("Query", "roadmap", FieldKind::Object, "Roadmap"),
+    ("Query", "roadmapToProject", FieldKind::Object, "RoadmapToProject"),
+    ("Query", "roadmapToProjects", FieldKind::Connection, "RoadmapToProjectConnection"),
+    ("Query", "roadmaps", FieldKind::Connection, "RoadmapConnection"),
+    ("Query", "searchDocuments", FieldKind::Object, "DocumentSearchPayload"),
+    ("Query", "searchIssues", FieldKind::Object, "IssueSearchPayload"),
+    ("Query", "searchProjects", FieldKind::Object, "ProjectSearchPayload"),
+    ("Query", "semanticSearch", FieldKind::Object, "SemanticSearchPayload"),
+    ("Query", "ssoUrlFromEmail", FieldKind::Object, "SsoUrlFromEmailResponse"),
+    ("Query", "team", FieldKind::Object, "Team"),
+    ("Query", "teamMembership", FieldKind::Object, "TeamMembership"),
+    ("Query", "teamMemberships", FieldKind::Connection, "TeamMembershipConnection"),
+    ("Query", "teams", FieldKind::Connection, "TeamConnection"),
+    ("Query", "template", FieldKind::Object, "Template"),
+    ("Query", "templates", FieldKind::Object, "Template"),
+    ("Query", "templatesForIntegration", FieldKind::Object, "Template"),
+    ("Query", "timeSchedule", FieldKind::Object, "TimeSchedule"),
+    ("Query", "timeSchedules", FieldKind::Connection, "TimeScheduleConnection"),
+    ("Query", "triageResponsibilities", FieldKind::Connection, "TriageResponsibilityConnection"),
+    ("Query", "triageResponsibility", FieldKind::Object, "TriageResponsibility"),
+    ("Query", "user", FieldKind::Object, "User"),
+    ("Query", "userSettings", FieldKind::Object, "UserSettings"),
+    ("Query", "users", FieldKind::Connection, "UserConnection"),
+    ("Query", "verifyGitHubEnterpriseServerInstallation", FieldKind::Object, "GitHubEnterpriseServerInstallVerificationPayload"),
+    ("Query", "viewer", FieldKind::Object, "User"),
+    ("Query", "webhook", FieldKind::Object, "Webhook"),
+    ("Query", "webhooks", FieldKind::Connection, "WebhookConnection"),
+    ("Query", "workflowState", FieldKind::Object, "WorkflowState"),
+    ("Query", "workflowStates", FieldKind::Connection, "WorkflowStateConnection"),
+    ("RateLimitPayload", "identifier", FieldKind::Scalar, "String"),
+    ("RateLimitPayload", "kind", FieldKind::Scalar, "String"),
+    ("RateLimitPayload", "limits", FieldKind::Object, "RateLimitResultPayload"),
+    ("RateLimitResultPayload", "allowedAmount", FieldKind::Scalar, "Float"),
+    ("RateLimitResultPayload", "period", FieldKind::Scalar, "Float"),
+    ("RateLimitResultPayload", "remainingAmount", FieldKind::Scalar, "Float"),
+    ("RateLimitResultPayload", "requestedAmount", FieldKind::Scalar, "Float"),
+    ("RateLimitResultPayload", "reset", FieldKind::Scalar, "Float"),
+    ("RateLimitResultPayload", "type", FieldKind::Scalar, "String"),
+    ("Reaction", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("Reaction", "comment", FieldKind::Object, "Comment"),
+    ("Reaction", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("Reaction", "emoji", FieldKind::Scalar, "String"),
+    ("Reaction", "externalUser", FieldKind::Object, "ExternalUser"),
+    ("Reaction", "id", FieldKind::Scalar, "ID"),
+    ("Reaction", "initiativeUpdate", FieldKind::Object, "InitiativeUpdate"),
+    ("Reaction", "issue", FieldKind::Object, "Issue"),
+    ("Reaction", "post", FieldKind::Object, "Post"),
+    ("Reaction", "projectUpdate", FieldKind::Object, "ProjectUpdate"),
+    ("Reaction", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("Reaction", "user", FieldKind::Object, "User"),
+    ("ReactionPayload", "lastSyncId", FieldKind::Scalar, "Float"),
+    ("ReactionPayload", "reaction", FieldKind::Object, "Reaction"),
+    ("ReactionPayload", "success", FieldKind::Scalar, "Boolean"),
+    ("ReactionWebhookPayload", "archivedAt", FieldKind::Scalar, "String"),
+    ("ReactionWebhookPayload", "comment", FieldKind::Object, "CommentChildWebhookPayload"),
+    ("ReactionWebhookPayload", "commentId", FieldKind::Scalar, "String"),
+    ("ReactionWebhookPayload", "createdAt", FieldKind::Scalar, "String"),
+    ("ReactionWebhookPayload", "emoji", FieldKind::Scalar, "String"),
+    ("ReactionWebhookPayload", "externalUserId", FieldKind::Scalar, "String"),
+    ("ReactionWebhookPayload", "id", FieldKind::Scalar, "String"),
+    ("ReactionWebhookPayload", "initiativeUpdateId", FieldKind::Scalar, "String"),
+    ("ReactionWebhookPayload", "issue", FieldKind::Object, "IssueChildWebhookPayload"),
+    ("ReactionWebhookPayload", "issueId", FieldKind::Scalar, "String"),
+    ("ReactionWebhookPayload", "postId", FieldKind::Scalar, "String"),
+    ("ReactionWebhookPayload", "projectUpdate", FieldKind::Object, "ProjectUpdateChildWebhookPayload"),
+    ("ReactionWebhookPayload", "projectUpdateId", FieldKind::Scalar, "String"),
+    ("ReactionWebhookPayload", "updatedAt", FieldKind::Scalar, "String"),
+    ("ReactionWebhookPayload", "user", FieldKind::Object, "UserChildWebhookPayload"),
+    ("ReactionWebhookPayload", "userId", FieldKind::Scalar, "String"),
+    ("Release", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("Release", "canceledAt", FieldKind::Scalar, "DateTime"),
+    ("Release", "commitSha", FieldKind::Scalar, "String"),
+    ("Release", "completedAt", FieldKind::Scalar, "DateTime"),
+    ("Release", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("Release", "id", FieldKind::Scalar, "ID"),
+    ("Release", "name", FieldKind::Scalar, "String"),
+    ("Release", "pipeline", FieldKind::Object, "ReleasePipeline"),
+    ("Release", "slugId", FieldKind::Scalar, "String"),
+    ("Release", "stage", FieldKind::Object, "ReleaseStage"),
+    ("Release", "startDate", FieldKind::Scalar, "TimelessDate"),
+    ("Release", "startedAt", FieldKind::Scalar, "DateTime"),
+    ("Release", "targetDate", FieldKind::Scalar, "TimelessDate"),
+    ("Release", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("Release", "version", FieldKind::Scalar, "String"),
+    ("ReleaseArchivePayload", "entity", FieldKind::Object, "Release"),
+    ("ReleaseArchivePayload", "lastSyncId", FieldKind::Scalar, "Float"),
+    ("ReleaseArchivePayload", "success", FieldKind::Scalar, "Boolean"),
+    ("ReleaseConnection", "edges", FieldKind::Object, "ReleaseEdge"),
+    ("ReleaseConnection", "nodes", FieldKind::Object, "Release"),
+    ("ReleaseConnection", "pageInfo", FieldKind::Object, "PageInfo"),
+    ("ReleaseEdge", "cursor", FieldKind::Scalar, "String"),
+    ("ReleaseEdge", "node", FieldKind::Object, "Release"),
+    ("ReleasePayload", "lastSyncId", FieldKind::Scalar, "Float"),
+    ("ReleasePayload", "release", FieldKind::Object, "Release"),
+    ("ReleasePayload", "success", FieldKind::Scalar, "Boolean"),
+    ("ReleasePipeline", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("ReleasePipeline", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("ReleasePipeline", "id", FieldKind::Scalar, "ID"),
+    ("ReleasePipeline", "name", FieldKind::Scalar, "String"),
+    ("ReleasePipeline", "releases", FieldKind::Connection, "ReleaseConnection"),
+    ("ReleasePipeline", "slugId", FieldKind::Scalar, "String"),
+    ("ReleasePipeline", "stages", FieldKind::Connection, "ReleaseStageConnection"),
+    ("ReleasePipeline", "type", FieldKind::Scalar, "ReleasePipelineType"),
+    ("ReleasePipeline", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("ReleasePipelineArchivePayload", "entity", FieldKind::Object, "ReleasePipeline"),
+    ("ReleasePipelineArchivePayload", "lastSyncId", FieldKind::Scalar, "Float"),
+    ("ReleasePipelineArchivePayload", "success", FieldKind::Scalar, "Boolean"),
+    ("ReleasePipelineConnection", "edges", FieldKind::Object, "ReleasePipelineEdge"),
+    ("ReleasePipelineConnection", "nodes", FieldKind::Object, "ReleasePipeline"),
+    ("ReleasePipelineConnection", "pageInfo", FieldKind::Object, "PageInfo"),
+    ("ReleasePipelineEdge", "cursor", FieldKind::Scalar, "String"),
+    ("ReleasePipelineEdge", "node", FieldKind::Object, "ReleasePipeline"),
+    ("ReleasePipelinePayload", "lastSyncId", FieldKind::Scalar, "Float"),
+    ("ReleasePipelinePayload", "releasePipeline", FieldKind::Object, "ReleasePipeline"),
+    ("ReleasePipelinePayload", "success", FieldKind::Scalar, "Boolean"),
+    ("ReleaseStage", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("ReleaseStage", "color", FieldKind::Scalar, "String"),
+    ("ReleaseStage", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("ReleaseStage", "id", FieldKind::Scalar, "ID"),
+    ("ReleaseStage", "name", FieldKind::Scalar, "String"),
+    ("ReleaseStage", "pipeline", FieldKind::Object, "ReleasePipeline"),
+    ("ReleaseStage", "position", FieldKind::Scalar, "Float"),
+    ("ReleaseStage", "releases", FieldKind::Connection, "ReleaseConnection"),
+    ("ReleaseStage", "type", FieldKind::Scalar, "ReleaseStageType"),
+    ("ReleaseStage", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("ReleaseStageArchivePayload", "entity", FieldKind::Object, "ReleaseStage"),
+    ("ReleaseStageArchivePayload", "lastSyncId", FieldKind::Scalar, "Float"),
+    ("ReleaseStageArchivePayload", "success", FieldKind::Scalar, "Boolean"),
+    ("ReleaseStageConnection", "edges", FieldKind::Object, "ReleaseStageEdge"),
+    ("ReleaseStageConnection", "nodes", FieldKind::Object, "ReleaseStage"),
+    ("ReleaseStageConnection", "pageInfo", FieldKind::Object, "PageInfo"),
+    ("ReleaseStageEdge", "cursor", FieldKind::Scalar, "String"),
+    ("ReleaseStageEdge", "node", FieldKind::Object, "ReleaseStage"),
+    ("ReleaseStagePayload", "lastSyncId", FieldKind::Scalar, "Float"),
+    ("ReleaseStagePayload", "releaseStage", FieldKind::Object, "ReleaseStage"),
+    ("ReleaseStagePayload", "success", FieldKind::Scalar, "Boolean"),
+    ("RepositorySuggestion", "confidence", FieldKind::Scalar, "Float"),
+    ("RepositorySuggestion", "hostname", FieldKind::Scalar, "String"),
+    ("RepositorySuggestion", "repositoryFullName", FieldKind::Scalar, "String"),
+    ("RepositorySuggestionsPayload", "suggestions", FieldKind::Object, "RepositorySuggestion"),
+    ("Roadmap", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("Roadmap", "color", FieldKind::Scalar, "String"),
+    ("Roadmap", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("Roadmap", "creator", FieldKind::Object, "User"),
+    ("Roadmap", "description", FieldKind::Scalar, "String"),
+    ("Roadmap", "id", FieldKind::Scalar, "ID"),
+    ("Roadmap", "name", FieldKind::Scalar, "String"),
+    ("Roadmap", "organization", FieldKind::Object, "Organization"),
+    ("Roadmap", "owner", FieldKind::Object, "User"),
+    ("Roadmap", "projects", FieldKind::Connection, "ProjectConnection"),
+    ("Roadmap", "slugId", FieldKind::Scalar, "String"),
+    ("Roadmap", "sortOrder", FieldKind::Scalar, "Float"),
+    ("Roadmap", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("Roadmap", "url", FieldKind::Scalar, "String"),
+    ("RoadmapArchivePayload", "entity", FieldKind::Object, "Roadmap"),
+    ("RoadmapArchivePayload", "lastSyncId", FieldKind::Scalar, "Float"),
+    ("RoadmapArchivePayload", "success", FieldKind::Scalar, "Boolean"),
+    ("RoadmapConnection", "edges", FieldKind::Object, "RoadmapEdge"),
+    ("RoadmapConnection", "nodes", FieldKind::Object, "Roadmap"),
+    ("RoadmapConnection", "pageInfo", FieldKind::Object, "PageInfo"),
+    ("RoadmapEdge", "cursor", FieldKind::Scalar, "String"),
+    ("RoadmapEdge", "node", FieldKind::Object, "Roadmap"),
+    ("RoadmapPayload", "lastSyncId", FieldKind::Scalar, "Float"),
+    ("RoadmapPayload", "roadmap", FieldKind::Object, "Roadmap"),
+    ("RoadmapPayload", "success", FieldKind::Scalar, "Boolean"),
+    ("RoadmapToProject", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("RoadmapToProject", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("RoadmapToProject", "id", FieldKind::Scalar, "ID"),
+    ("RoadmapToProject", "project", FieldKind::Object, "Project"),
+    ("RoadmapToProject", "roadmap", FieldKind::Object, "Roadmap"),
+    ("RoadmapToProject", "sortOrder", FieldKind::Scalar, "String"),
+    ("RoadmapToProject", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("RoadmapToProjectConnection", "edges", FieldKind::Object, "RoadmapToProjectEdge"),
+    ("RoadmapToProjectConnection", "nodes", FieldKind::Object, "RoadmapToProject"),
+    ("RoadmapToProjectConnection", "pageInfo", FieldKind::Object, "PageInfo"),
+    ("RoadmapToProjectEdge", "cursor", FieldKind::Scalar, "String"),
+    ("RoadmapToProjectEdge", "node", FieldKind::Object, "RoadmapToProject"),
+    ("RoadmapToProjectPayload", "lastSyncId", FieldKind::Scalar, "Float"),
+    ("RoadmapToProjectPayload", "roadmapToProject", FieldKind::Object, "RoadmapToProject"),
+    ("RoadmapToProjectPayload", "success", FieldKind::Scalar, "Boolean"),
+    ("SemanticSearchPayload", "enabled", FieldKind::Scalar, "Boolean"),
+    ("SemanticSearchPayload", "results", FieldKind::Object, "SemanticSearchResult"),
+    ("SemanticSearchResult", "document", FieldKind::Object, "Document"),
+    ("SemanticSearchResult", "id", FieldKind::Scalar, "ID"),
+    ("SemanticSearchResult", "initiative", FieldKind::Object, "Initiative"),
+    ("SemanticSearchResult", "issue", FieldKind::Object, "Issue"),
+    ("SemanticSearchResult", "project", FieldKind::Object, "Project"),
+    ("SemanticSearchResult", "type", FieldKind::Scalar, "SemanticSearchResultType"),
+    ("SesDomainIdentity", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("SesDomainIdentity", "canSendFromCustomDomain", FieldKind::Scalar, "Boolean"),
+    ("SesDomainIdentity", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("SesDomainIdentity", "creator", FieldKind::Object, "User"),
+    ("SesDomainIdentity", "dnsRecords", FieldKind::Object, "SesDomainIdentityDnsRecord"),
+    ("SesDomainIdentity", "domain", FieldKind::Scalar, "String"),
+    ("SesDomainIdentity", "id", FieldKind::Scalar, "ID"),
+    ("SesDomainIdentity", "organization", FieldKind::Object, "Organization"),
+    ("SesDomainIdentity", "region", FieldKind::Scalar, "String"),
+    ("SesDomainIdentity", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("SesDomainIdentityDnsRecord", "content", FieldKind::Scalar, "String"),
+    ("SesDomainIdentityDnsRecord", "isVerified", FieldKind::Scalar, "Boolean"),
+    ("SesDomainIdentityDnsRecord", "name", FieldKind::Scalar, "String"),
+    ("SesDomainIdentityDnsRecord", "type", FieldKind::Scalar, "String"),
+    ("SlackAsksTeamSettings", "hasDefaultAsk", FieldKind::Scalar, "Boolean"),
+    ("SlackAsksTeamSettings", "id", FieldKind::Scalar, "String"),
+    ("SlackChannelConnectPayload", "addBot", FieldKind::Scalar, "Boolean"),
+    ("SlackChannelConnectPayload", "integration", FieldKind::Object, "Integration"),
+    ("SlackChannelConnectPayload", "lastSyncId", FieldKind::Scalar, "Float"),
+    ("SlackChannelConnectPayload", "nudgeToConnectMainSlackIntegration", FieldKind::Scalar, "Boolean"),
+    ("SlackChannelConnectPayload", "nudgeToUpdateMainSlackIntegration", FieldKind::Scalar, "Boolean"),
+    ("SlackChannelConnectPayload", "success", FieldKind::Scalar, "Boolean"),
+    ("SlackChannelNameMapping", "aiTitles", FieldKind::Scalar, "Boolean"),
+    ("SlackChannelNameMapping", "autoCreateOnBotMention", FieldKind::Scalar, "Boolean"),
+    ("SlackChannelNameMapping", "autoCreateOnEmoji", FieldKind::Scalar, "Boolean"),
+    ("SlackChannelNameMapping", "autoCreateOnMessage", FieldKind::Scalar, "Boolean"),
+    ("SlackChannelNameMapping", "autoCreateTemplateId", FieldKind::Scalar, "String"),
+    ("SlackChannelNameMapping", "botAdded", FieldKind::Scalar, "Boolean"),
+    ("SlackChannelNameMapping", "id", FieldKind::Scalar, "String"),
+    ("SlackChannelNameMapping", "isPrivate", FieldKind::Scalar, "Boolean"),
+    ("SlackChannelNameMapping", "isShared", FieldKind::Scalar, "Boolean"),
+    ("SlackChannelNameMapping", "name", FieldKind::Scalar, "String"),
+    ("SlackChannelNameMapping", "postAcceptedFromTriageUpdates", FieldKind::Scalar, "Boolean"),
+    ("SlackChannelNameMapping", "postCancellationUpdates", FieldKind::Scalar, "Boolean"),
+    ("SlackChannelNameMapping", "postCompletionUpdates", FieldKind::Scalar, "Boolean"),
+    ("SlackChannelNameMapping", "teams", FieldKind::Object, "SlackAsksTeamSettings"),
+    ("SsoUrlFromEmailResponse", "samlSsoUrl", FieldKind::Scalar, "String"),
+    ("SsoUrlFromEmailResponse", "success", FieldKind::Scalar, "Boolean"),
+    ("SuccessPayload", "lastSyncId", FieldKind::Scalar, "Float"),
+    ("SuccessPayload", "success", FieldKind::Scalar, "Boolean"),
+    ("SyncedExternalThread", "displayName", FieldKind::Scalar, "String"),
+    ("SyncedExternalThread", "id", FieldKind::Scalar, "ID"),
+    ("SyncedExternalThread", "isConnected", FieldKind::Scalar, "Boolean"),
+    ("SyncedExternalThread", "isPersonalIntegrationConnected", FieldKind::Scalar, "Boolean"),
+    ("SyncedExternalThread", "isPersonalIntegrationRequired", FieldKind::Scalar, "Boolean"),
+    ("SyncedExternalThread", "name", FieldKind::Scalar, "String"),
+    ("SyncedExternalThread", "subType", FieldKind::Scalar, "String"),
+    ("SyncedExternalThread", "type", FieldKind::Scalar, "String"),
+    ("SyncedExternalThread", "url", FieldKind::Scalar, "String"),
+    ("Team", "activeCycle", FieldKind::Object, "Cycle"),
+    ("Team", "aiDiscussionSummariesEnabled", FieldKind::Scalar, "Boolean"),
+    ("Team", "aiThreadSummariesEnabled", FieldKind::Scalar, "Boolean"),
+    ("Team", "allMembersCanJoin", FieldKind::Scalar, "Boolean"),
+    ("Team", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("Team", "autoArchivePeriod", FieldKind::Scalar, "Float"),
+    ("Team", "autoCloseChildIssues", FieldKind::Scalar, "Boolean"),
+    ("Team", "autoCloseParentIssues", FieldKind::Scalar, "Boolean"),
+    ("Team", "autoClosePeriod", FieldKind::Scalar, "Float"),
+    ("Team", "autoCloseStateId", FieldKind::Scalar, "String"),
+    ("Team", "children", FieldKind::Object, "Team"),
+    ("Team", "color", FieldKind::Scalar, "String"),
+    ("Team", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("Team", "currentProgress", FieldKind::Scalar, "JSONObject"),
+    ("Team", "cycleCalenderUrl", FieldKind::Scalar, "String"),
+    ("Team", "cycleCooldownTime", FieldKind::Scalar, "Float"),
+    ("Team", "cycleDuration", FieldKind::Scalar, "Float"),
+    ("Team", "cycleIssueAutoAssignCompleted", FieldKind::Scalar, "Boolean"),
+    ("Team", "cycleIssueAutoAssignStarted", FieldKind::Scalar, "Boolean"),
+    ("Team", "cycleLockToActive", FieldKind::Scalar, "Boolean"),
+    ("Team", "cycleStartDay", FieldKind::Scalar, "Float"),
+    ("Team", "cycles", FieldKind::Connection, "CycleConnection"),
+    ("Team", "cyclesEnabled", FieldKind::Scalar, "Boolean"),
+    ("Team", "defaultIssueEstimate", FieldKind::Scalar, "Float"),
+    ("Team", "defaultIssueState", FieldKind::Object, "WorkflowState"),
+    ("Team", "defaultProjectTemplate", FieldKind::Object, "Template"),
+    ("Team", "defaultTemplateForMembers", FieldKind::Object, "Template"),
+    ("Team", "defaultTemplateForMembersId", FieldKind::Scalar, "String"),
+    ("Team", "defaultTemplateForNonMembers", FieldKind::Object, "Template"),
+    ("Team", "defaultTemplateForNonMembersId", FieldKind::Scalar, "String"),
+    ("Team", "description", FieldKind::Scalar, "String"),
+    ("Team", "displayName", FieldKind::Scalar, "String"),
+    ("Team", "draftWorkflowState", FieldKind::Object, "WorkflowState"),
+    ("Team", "facets", FieldKind::Object, "Facet"),
+    ("Team", "gitAutomationStates", FieldKind::Connection, "GitAutomationStateConnection"),
+    ("Team", "groupIssueHistory", FieldKind::Scalar, "Boolean"),
+    ("Team", "icon", FieldKind::Scalar, "String"),
+    ("Team", "id", FieldKind::Scalar, "ID"),
+    ("Team", "inheritIssueEstimation", FieldKind::Scalar, "Boolean"),
+    ("Team", "inheritWorkflowStatuses", FieldKind::Scalar, "Boolean"),
+    ("Team", "integrationsSettings", FieldKind::Object, "IntegrationsSettings"),
+    ("Team", "inviteHash", FieldKind::Scalar, "String"),
+    ("Team", "issueCount", FieldKind::Scalar, "Int"),
+    ("Team", "issueEstimationAllowZero", FieldKind::Scalar, "Boolean"),
+    ("Team", "issueEstimationExtended", FieldKind::Scalar, "Boolean"),
+    ("Team", "issueEstimationType", FieldKind::Scalar, "String"),
+    ("Team", "issueOrderingNoPriorityFirst", FieldKind::Scalar, "Boolean"),
+    ("Team", "issueSortOrderDefaultToBottom", FieldKind::Scalar, "Boolean"),
+    ("Team", "issues", FieldKind::Connection, "IssueConnection"),
+    ("Team", "joinByDefault", FieldKind::Scalar, "Boolean"),
+    ("Team", "key", FieldKind::Scalar, "String"),
+    ("Team", "labels", FieldKind::Connection, "IssueLabelConnection"),
+    ("Team", "markedAsDuplicateWorkflowState", FieldKind::Object, "WorkflowState"),
+    ("Team", "members", FieldKind::Connection, "UserConnection"),
+    ("Team", "membership", FieldKind::Object, "TeamMembership"),
+    ("Team", "memberships", FieldKind::Connection, "TeamMembershipConnection"),
+    ("Team", "mergeWorkflowState", FieldKind::Object, "WorkflowState"),
+    ("Team", "mergeableWorkflowState", FieldKind::Object, "WorkflowState"),
+    ("Team", "name", FieldKind::Scalar, "String"),
+    ("Team", "organization", FieldKind::Object, "Organization"),
+    ("Team", "parent", FieldKind::Object, "Team"),
+    ("Team", "posts", FieldKind::Object, "Post"),
+    ("Team", "private", FieldKind::Scalar, "Boolean"),
+    ("Team", "progressHistory", FieldKind::Scalar, "JSONObject"),
+    ("Team", "projects", FieldKind::Connection, "ProjectConnection"),
+    ("Team", "requirePriorityToLeaveTriage", FieldKind::Scalar, "Boolean"),
+    ("Team", "reviewWorkflowState", FieldKind::Object, "WorkflowState"),
+    ("Team", "scimGroupName", FieldKind::Scalar, "String"),
+    ("Team", "scimManaged", FieldKind::Scalar, "Boolean"),
+    ("Team", "securitySettings", FieldKind::Scalar, "JSONObject"),
+    ("Team", "setIssueSortOrderOnStateChange", FieldKind::Scalar, "String"),
+    ("Team", "slackIssueComments", FieldKind::Scalar, "Boolean"),
+    ("Team", "slackIssueStatuses", FieldKind::Scalar, "Boolean"),
+    ("Team", "slackNewIssue", FieldKind::Scalar, "Boolean"),
+    ("Team", "startWorkflowState", FieldKind::Object, "WorkflowState"),
+    ("Team", "states", FieldKind::Connection, "WorkflowStateConnection"),
+    ("Team", "templates", FieldKind::Connection, "TemplateConnection"),
+    ("Team", "timezone", FieldKind::Scalar, "String"),
+    ("Team", "triageEnabled", FieldKind::Scalar, "Boolean"),
+    ("Team", "triageIssueState", FieldKind::Object, "WorkflowState"),
+    ("Team", "triageResponsibility", FieldKind::Object, "TriageResponsibility"),
+    ("Team", "upcomingCycleCount", FieldKind::Scalar, "Float"),
+    ("Team", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("Team", "webhooks", FieldKind::Connection, "WebhookConnection"),
+    ("TeamArchivePayload", "entity", FieldKind::Object, "Team"),
+    ("TeamArchivePayload", "lastSyncId", FieldKind::Scalar, "Float"),
+    ("TeamArchivePayload", "success", FieldKind::Scalar, "Boolean"),
+    ("TeamChildWebhookPayload", "id", FieldKind::Scalar, "String"),
+    ("TeamChildWebhookPayload", "key", FieldKind::Scalar, "String"),
+    ("TeamChildWebhookPayload", "name", FieldKind::Scalar, "String"),
+    ("TeamConnection", "edges", FieldKind::Object, "TeamEdge"),
+    ("TeamConnection", "nodes", FieldKind::Object, "Team"),
+    ("TeamConnection", "pageInfo", FieldKind::Object, "PageInfo"),
+    ("TeamEdge", "cursor", FieldKind::Scalar, "String"),
+    ("TeamEdge", "node", FieldKind::Object, "Team"),
+    ("TeamMembership", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("TeamMembership", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("TeamMembership", "id", FieldKind::Scalar, "ID"),
+    ("TeamMembership", "owner", FieldKind::Scalar, "Boolean"),
+    ("TeamMembership", "sortOrder", FieldKind::Scalar, "Float"),
+    ("TeamMembership", "team", FieldKind::Object, "Team"),
+    ("TeamMembership", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("TeamMembership", "user", FieldKind::Object, "User"),
+    ("TeamMembershipConnection", "edges", FieldKind::Object, "TeamMembershipEdge"),
+    ("TeamMembershipConnection", "nodes", FieldKind::Object, "TeamMembership"),
+    ("TeamMembershipConnection", "pageInfo", FieldKind::Object, "PageInfo"),
+    ("TeamMembershipEdge", "cursor", FieldKind::Scalar, "String"),
+    ("TeamMembershipEdge", "node", FieldKind::Object, "TeamMembership"),
+    ("TeamMembershipPayload", "lastSyncId", FieldKind::Scalar, "Float"),
+    ("TeamMembershipPayload", "success", FieldKind::Scalar, "Boolean"),
+    ("TeamMembershipPayload", "teamMembership", FieldKind::Object, "TeamMembership"),
+    ("TeamNotificationSubscription", "active", FieldKind::Scalar, "Boolean"),
+    ("TeamNotificationSubscription", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("TeamNotificationSubscription", "contextViewType", FieldKind::Scalar, "ContextViewType"),
+    ("TeamNotificationSubscription", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("TeamNotificationSubscription", "customView", FieldKind::Object, "CustomView"),
+    ("TeamNotificationSubscription", "customer", FieldKind::Object, "Customer"),
+    ("TeamNotificationSubscription", "cycle", FieldKind::Object, "Cycle"),
+    ("TeamNotificationSubscription", "id", FieldKind::Scalar, "ID"),
+    ("TeamNotificationSubscription", "initiative", FieldKind::Object, "Initiative"),
+    ("TeamNotificationSubscription", "label", FieldKind::Object, "IssueLabel"),
+    ("TeamNotificationSubscription", "notificationSubscriptionTypes", FieldKind::Scalar, "String"),
+    ("TeamNotificationSubscription", "project", FieldKind::Object, "Project"),
+    ("TeamNotificationSubscription", "subscriber", FieldKind::Object, "User"),
+    ("TeamNotificationSubscription", "team", FieldKind::Object, "Team"),
+    ("TeamNotificationSubscription", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("TeamNotificationSubscription", "user", FieldKind::Object, "User"),
+    ("TeamNotificationSubscription", "userContextViewType", FieldKind::Scalar, "UserContextViewType"),
+    ("TeamOriginWebhookPayload", "team", FieldKind::Object, "TeamWithParentWebhookPayload"),
+    ("TeamOriginWebhookPayload", "type", FieldKind::Scalar, "String"),
+    ("TeamPayload", "lastSyncId", FieldKind::Scalar, "Float"),
+    ("TeamPayload", "success", FieldKind::Scalar, "Boolean"),
+    ("TeamPayload", "team", FieldKind::Object, "Team"),
+    ("TeamWithParentWebhookPayload", "displayName", FieldKind::Scalar, "String"),
+    ("TeamWithParentWebhookPayload", "id", FieldKind::Scalar, "String"),
+    ("TeamWithParentWebhookPayload", "key", FieldKind::Scalar, "String"),
+    ("TeamWithParentWebhookPayload", "name", FieldKind::Scalar, "String"),
+    ("TeamWithParentWebhookPayload", "parentId", FieldKind::Scalar, "String"),
+    ("Template", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("Template", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("Template", "creator", FieldKind::Object, "User"),
+    ("Template", "description", FieldKind::Scalar, "String"),
+    ("Template", "hasFormFields", FieldKind::Scalar, "Boolean"),
+    ("Template", "id", FieldKind::Scalar, "ID"),
+    ("Template", "inheritedFrom", FieldKind::Object, "Template"),
+    ("Template", "lastAppliedAt", FieldKind::Scalar, "DateTime"),
+    ("Template", "lastUpdatedBy", FieldKind::Object, "User"),
+    ("Template", "name", FieldKind::Scalar, "String"),
+    ("Template", "organization", FieldKind::Object, "Organization"),
+    ("Template", "sortOrder", FieldKind::Scalar, "Float"),
+    ("Template", "team", FieldKind::Object, "Team"),
+    ("Template", "templateData", FieldKind::Scalar, "JSON"),
+    ("Template", "type", FieldKind::Scalar, "String"),
+    ("Template", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("TemplateConnection", "edges", FieldKind::Object, "TemplateEdge"),
+    ("TemplateConnection", "nodes", FieldKind::Object, "Template"),
+    ("TemplateConnection", "pageInfo", FieldKind::Object, "PageInfo"),
+    ("TemplateEdge", "cursor", FieldKind::Scalar, "String"),
+    ("TemplateEdge", "node", FieldKind::Object, "Template"),
+    ("TemplatePayload", "lastSyncId", FieldKind::Scalar, "Float"),
+    ("TemplatePayload", "success", FieldKind::Scalar, "Boolean"),
+    ("TemplatePayload", "template", FieldKind::Object, "Template"),
+    ("TimeSchedule", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("TimeSchedule", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("TimeSchedule", "entries", FieldKind::Object, "TimeScheduleEntry"),
+    ("TimeSchedule", "externalId", FieldKind::Scalar, "String"),
+    ("TimeSchedule", "externalUrl", FieldKind::Scalar, "String"),
+    ("TimeSchedule", "id", FieldKind::Scalar, "ID"),
+    ("TimeSchedule", "integration", FieldKind::Object, "Integration"),
+    ("TimeSchedule", "name", FieldKind::Scalar, "String"),
+    ("TimeSchedule", "organization", FieldKind::Object, "Organization"),
+    ("TimeSchedule", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("TimeScheduleConnection", "edges", FieldKind::Object, "TimeScheduleEdge"),
+    ("TimeScheduleConnection", "nodes", FieldKind::Object, "TimeSchedule"),
+    ("TimeScheduleConnection", "pageInfo", FieldKind::Object, "PageInfo"),
+    ("TimeScheduleEdge", "cursor", FieldKind::Scalar, "String"),
+    ("TimeScheduleEdge", "node", FieldKind::Object, "TimeSchedule"),
+    ("TimeScheduleEntry", "endsAt", FieldKind::Scalar, "DateTime"),
+    ("TimeScheduleEntry", "startsAt", FieldKind::Scalar, "DateTime"),
+    ("TimeScheduleEntry", "userEmail", FieldKind::Scalar, "String"),
+    ("TimeScheduleEntry", "userId", FieldKind::Scalar, "String"),
+    ("TimeSchedulePayload", "lastSyncId", FieldKind::Scalar, "Float"),
+    ("TimeSchedulePayload", "success", FieldKind::Scalar, "Boolean"),
+    ("TimeSchedulePayload", "timeSchedule", FieldKind::Object, "TimeSchedule"),
+    ("TriageResponsibility", "action", FieldKind::Scalar, "TriageResponsibilityAction"),
+    ("TriageResponsibility", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("TriageResponsibility", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("TriageResponsibility", "currentUser", FieldKind::Object, "User"),
+    ("TriageResponsibility", "id", FieldKind::Scalar, "ID"),
+    ("TriageResponsibility", "manualSelection", FieldKind::Object, "TriageResponsibilityManualSelection"),
+    ("TriageResponsibility", "team", FieldKind::Object, "Team"),
+    ("TriageResponsibility", "timeSchedule", FieldKind::Object, "TimeSchedule"),
+    ("TriageResponsibility", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("TriageResponsibilityConnection", "edges", FieldKind::Object, "TriageResponsibilityEdge"),
+    ("TriageResponsibilityConnection", "nodes", FieldKind::Object, "TriageResponsibility"),
+    ("TriageResponsibilityConnection", "pageInfo", FieldKind::Object, "PageInfo"),
+    ("TriageResponsibilityEdge", "cursor", FieldKind::Scalar, "String"),
+    ("TriageResponsibilityEdge", "node", FieldKind::Object, "TriageResponsibility"),
+    ("TriageResponsibilityManualSelection", "assignmentIndex", FieldKind::Scalar, "Int"),
+    ("TriageResponsibilityManualSelection", "userIds", FieldKind::Scalar, "String"),
+    ("TriageResponsibilityPayload", "lastSyncId", FieldKind::Scalar, "Float"),
+    ("TriageResponsibilityPayload", "success", FieldKind::Scalar, "Boolean"),
+    ("TriageResponsibilityPayload", "triageResponsibility", FieldKind::Object, "TriageResponsibility"),
+    ("UploadFile", "assetUrl", FieldKind::Scalar, "String"),
+    ("UploadFile", "contentType", FieldKind::Scalar, "String"),
+    ("UploadFile", "filename", FieldKind::Scalar, "String"),
+    ("UploadFile", "headers", FieldKind::Object, "UploadFileHeader"),
+    ("UploadFile", "metaData", FieldKind::Scalar, "JSONObject"),
+    ("UploadFile", "size", FieldKind::Scalar, "Int"),
+    ("UploadFile", "uploadUrl", FieldKind::Scalar, "String"),
+    ("UploadFileHeader", "key", FieldKind::Scalar, "String"),
+    ("UploadFileHeader", "value", FieldKind::Scalar, "String"),
+    ("UploadPayload", "lastSyncId", FieldKind::Scalar, "Float"),
+    ("UploadPayload", "success", FieldKind::Scalar, "Boolean"),
+    ("UploadPayload", "uploadFile", FieldKind::Object, "UploadFile"),
+    ("User", "active", FieldKind::Scalar, "Boolean"),
+    ("User", "admin", FieldKind::Scalar, "Boolean"),
+    ("User", "app", FieldKind::Scalar, "Boolean"),
+    ("User", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("User", "assignedIssues", FieldKind::Connection, "IssueConnection"),
+    ("User", "avatarBackgroundColor", FieldKind::Scalar, "String"),
+    ("User", "avatarUrl", FieldKind::Scalar, "String"),
+    ("User", "calendarHash", FieldKind::Scalar, "String"),
+    ("User", "canAccessAnyPublicTeam", FieldKind::Scalar, "Boolean"),
+    ("User", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("User", "createdIssueCount", FieldKind::Scalar, "Int"),
+    ("User", "createdIssues", FieldKind::Connection, "IssueConnection"),
+    ("User", "delegatedIssues", FieldKind::Connection, "IssueConnection"),
+    ("User", "description", FieldKind::Scalar, "String"),
+    ("User", "disableReason", FieldKind::Scalar, "String"),
+    ("User", "displayName", FieldKind::Scalar, "String"),
+    ("User", "drafts", FieldKind::Connection, "DraftConnection"),
+    ("User", "email", FieldKind::Scalar, "String"),
+    ("User", "feedFacets", FieldKind::Connection, "FacetConnection"),
+    ("User", "gitHubUserId", FieldKind::Scalar, "String"),
+    ("User", "guest", FieldKind::Scalar, "Boolean"),
+    ("User", "id", FieldKind::Scalar, "ID"),
+    ("User", "identityProvider", FieldKind::Object, "IdentityProvider"),
+    ("User", "initials", FieldKind::Scalar, "String"),
+    ("User", "inviteHash", FieldKind::Scalar, "String"),
+    ("User", "isAssignable", FieldKind::Scalar, "Boolean"),
+    ("User", "isMe", FieldKind::Scalar, "Boolean"),
+    ("User", "isMentionable", FieldKind::Scalar, "Boolean"),
+    ("User", "issueDrafts", FieldKind::Connection, "IssueDraftConnection"),
+    ("User", "lastSeen", FieldKind::Scalar, "DateTime"),
+    ("User", "name", FieldKind::Scalar, "String"),
+    ("User", "organization", FieldKind::Object, "Organization"),
+    ("User", "owner", FieldKind::Scalar, "Boolean"),
+    ("User", "statusEmoji", FieldKind::Scalar, "String"),
+    ("User", "statusLabel", FieldKind::Scalar, "String"),
+    ("User", "statusUntilAt", FieldKind::Scalar, "DateTime"),
+    ("User", "supportsAgentSessions", FieldKind::Scalar, "Boolean"),
+    ("User", "teamMemberships", FieldKind::Connection, "TeamMembershipConnection"),
+    ("User", "teams", FieldKind::Connection, "TeamConnection"),
+    ("User", "timezone", FieldKind::Scalar, "String"),
+    ("User", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("User", "url", FieldKind::Scalar, "String"),
+    ("UserActorWebhookPayload", "avatarUrl", FieldKind::Scalar, "String"),
+    ("UserActorWebhookPayload", "email", FieldKind::Scalar, "String"),
+    ("UserActorWebhookPayload", "id", FieldKind::Scalar, "String"),
+    ("UserActorWebhookPayload", "name", FieldKind::Scalar, "String"),
+    ("UserActorWebhookPayload", "type", FieldKind::Scalar, "String"),
+    ("UserActorWebhookPayload", "url", FieldKind::Scalar, "String"),
+    ("UserAdminPayload", "success", FieldKind::Scalar, "Boolean"),
+    ("UserChildWebhookPayload", "avatarUrl", FieldKind::Scalar, "String"),
+    ("UserChildWebhookPayload", "email", FieldKind::Scalar, "String"),
+    ("UserChildWebhookPayload", "id", FieldKind::Scalar, "String"),
+    ("UserChildWebhookPayload", "name", FieldKind::Scalar, "String"),
+    ("UserChildWebhookPayload", "url", FieldKind::Scalar, "String"),
+    ("UserConnection", "edges", FieldKind::Object, "UserEdge"),
+    ("UserConnection", "nodes", FieldKind::Object, "User"),
+    ("UserConnection", "pageInfo", FieldKind::Object, "PageInfo"),
+    ("UserEdge", "cursor", FieldKind::Scalar, "String"),
+    ("UserEdge", "node", FieldKind::Object, "User"),
+    ("UserNotificationSubscription", "active", FieldKind::Scalar, "Boolean"),
+    ("UserNotificationSubscription", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("UserNotificationSubscription", "contextViewType", FieldKind::Scalar, "ContextViewType"),
+    ("UserNotificationSubscription", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("UserNotificationSubscription", "customView", FieldKind::Object, "CustomView"),
+    ("UserNotificationSubscription", "customer", FieldKind::Object, "Customer"),
+    ("UserNotificationSubscription", "cycle", FieldKind::Object, "Cycle"),
+    ("UserNotificationSubscription", "id", FieldKind::Scalar, "ID"),
+    ("UserNotificationSubscription", "initiative", FieldKind::Object, "Initiative"),
+    ("UserNotificationSubscription", "label", FieldKind::Object, "IssueLabel"),
+    ("UserNotificationSubscription", "notificationSubscriptionTypes", FieldKind::Scalar, "String"),
+    ("UserNotificationSubscription", "project", FieldKind::Object, "Project"),
+    ("UserNotificationSubscription", "subscriber", FieldKind::Object, "User"),
+    ("UserNotificationSubscription", "team", FieldKind::Object, "Team"),
+    ("UserNotificationSubscription", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("UserNotificationSubscription", "user", FieldKind::Object, "User"),
+    ("UserNotificationSubscription", "userContextViewType", FieldKind::Scalar, "UserContextViewType"),
+    ("UserPayload", "lastSyncId", FieldKind::Scalar, "Float"),
+    ("UserPayload", "success", FieldKind::Scalar, "Boolean"),
+    ("UserPayload", "user", FieldKind::Object, "User"),
+    ("UserSettings", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("UserSettings", "autoAssignToSelf", FieldKind::Scalar, "Boolean"),
+    ("UserSettings", "calendarHash", FieldKind::Scalar, "String"),
+    ("UserSettings", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("UserSettings", "feedLastSeenTime", FieldKind::Scalar, "DateTime"),
+    ("UserSettings", "feedSummarySchedule", FieldKind::Scalar, "FeedSummarySchedule"),
+    ("UserSettings", "id", FieldKind::Scalar, "ID"),
+    ("UserSettings", "notificationCategoryPreferences", FieldKind::Object, "NotificationCategoryPreferences"),
+    ("UserSettings", "notificationChannelPreferences", FieldKind::Object, "NotificationChannelPreferences"),
+    ("UserSettings", "notificationDeliveryPreferences", FieldKind::Object, "NotificationDeliveryPreferences"),
+    ("UserSettings", "showFullUserNames", FieldKind::Scalar, "Boolean"),
+    ("UserSettings", "subscribedToChangelog", FieldKind::Scalar, "Boolean"),
+    ("UserSettings", "subscribedToDPA", FieldKind::Scalar, "Boolean"),
+    ("UserSettings", "subscribedToInviteAccepted", FieldKind::Scalar, "Boolean"),
+    ("UserSettings", "subscribedToPrivacyLegalUpdates", FieldKind::Scalar, "Boolean"),
+    ("UserSettings", "theme", FieldKind::Object, "UserSettingsTheme"),
+    ("UserSettings", "unsubscribedFrom", FieldKind::Scalar, "String"),
+    ("UserSettings", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("UserSettings", "user", FieldKind::Object, "User"),
+    ("UserSettingsCustomSidebarTheme", "accent", FieldKind::Scalar, "Float"),
+    ("UserSettingsCustomSidebarTheme", "base", FieldKind::Scalar, "Float"),
+    ("UserSettingsCustomSidebarTheme", "contrast", FieldKind::Scalar, "Int"),
+    ("UserSettingsCustomTheme", "accent", FieldKind::Scalar, "Float"),
+    ("UserSettingsCustomTheme", "base", FieldKind::Scalar, "Float"),
+    ("UserSettingsCustomTheme", "contrast", FieldKind::Scalar, "Int"),
+    ("UserSettingsCustomTheme", "sidebar", FieldKind::Object, "UserSettingsCustomSidebarTheme"),
+    ("UserSettingsFlagPayload", "flag", FieldKind::Scalar, "String"),
+    ("UserSettingsFlagPayload", "lastSyncId", FieldKind::Scalar, "Float"),
+    ("UserSettingsFlagPayload", "success", FieldKind::Scalar, "Boolean"),
+    ("UserSettingsFlagPayload", "value", FieldKind::Scalar, "Int"),
+    ("UserSettingsFlagsResetPayload", "lastSyncId", FieldKind::Scalar, "Float"),
+    ("UserSettingsFlagsResetPayload", "success", FieldKind::Scalar, "Boolean"),
+    ("UserSettingsPayload", "lastSyncId", FieldKind::Scalar, "Float"),
+    ("UserSettingsPayload", "success", FieldKind::Scalar, "Boolean"),
+    ("UserSettingsPayload", "userSettings", FieldKind::Object, "UserSettings"),
+    ("UserSettingsTheme", "custom", FieldKind::Object, "UserSettingsCustomTheme"),
+    ("UserSettingsTheme", "preset", FieldKind::Scalar, "UserSettingsThemePreset"),
+    ("UserWebhookPayload", "active", FieldKind::Scalar, "Boolean"),
+    ("UserWebhookPayload", "admin", FieldKind::Scalar, "Boolean"),
+    ("UserWebhookPayload", "app", FieldKind::Scalar, "Boolean"),
+    ("UserWebhookPayload", "archivedAt", FieldKind::Scalar, "String"),
+    ("UserWebhookPayload", "avatarUrl", FieldKind::Scalar, "String"),
+    ("UserWebhookPayload", "createdAt", FieldKind::Scalar, "String"),
+    ("UserWebhookPayload", "description", FieldKind::Scalar, "String"),
+    ("UserWebhookPayload", "disableReason", FieldKind::Scalar, "String"),
+    ("UserWebhookPayload", "displayName", FieldKind::Scalar, "String"),
+    ("UserWebhookPayload", "email", FieldKind::Scalar, "String"),
+    ("UserWebhookPayload", "guest", FieldKind::Scalar, "Boolean"),
+    ("UserWebhookPayload", "id", FieldKind::Scalar, "String"),
+    ("UserWebhookPayload", "name", FieldKind::Scalar, "String"),
+    ("UserWebhookPayload", "owner", FieldKind::Scalar, "Boolean"),
+    ("UserWebhookPayload", "timezone", FieldKind::Scalar, "String"),
+    ("UserWebhookPayload", "updatedAt", FieldKind::Scalar, "String"),
+    ("UserWebhookPayload", "url", FieldKind::Scalar, "String"),
+    ("ViewPreferences", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("ViewPreferences", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("ViewPreferences", "id", FieldKind::Scalar, "ID"),
+    ("ViewPreferences", "preferences", FieldKind::Object, "ViewPreferencesValues"),
+    ("ViewPreferences", "type", FieldKind::Scalar, "String"),
+    ("ViewPreferences", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("ViewPreferences", "viewType", FieldKind::Scalar, "String"),
+    ("ViewPreferencesPayload", "lastSyncId", FieldKind::Scalar, "Float"),
+    ("ViewPreferencesPayload", "success", FieldKind::Scalar, "Boolean"),
+    ("ViewPreferencesPayload", "viewPreferences", FieldKind::Object, "ViewPreferences"),
+    ("ViewPreferencesValues", "issueGrouping", FieldKind::Scalar, "String"),
+    ("ViewPreferencesValues", "issueSubGrouping", FieldKind::Scalar, "String"),
+    ("ViewPreferencesValues", "showCompletedIssues", FieldKind::Scalar, "String"),
+    ("ViewPreferencesValues", "viewOrdering", FieldKind::Scalar, "String"),
+    ("Webhook", "allPublicTeams", FieldKind::Scalar, "Boolean"),
+    ("Webhook", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("Webhook", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("Webhook", "creator", FieldKind::Object, "User"),
+    ("Webhook", "enabled", FieldKind::Scalar, "Boolean"),
+    ("Webhook", "failures", FieldKind::Object, "WebhookFailureEvent"),
+    ("Webhook", "id", FieldKind::Scalar, "ID"),
+    ("Webhook", "label", FieldKind::Scalar, "String"),
+    ("Webhook", "resourceTypes", FieldKind::Scalar, "String"),
+    ("Webhook", "secret", FieldKind::Scalar, "String"),
+    ("Webhook", "team", FieldKind::Object, "Team"),
+    ("Webhook", "teamIds", FieldKind::Scalar, "String"),
+    ("Webhook", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("Webhook", "url", FieldKind::Scalar, "String"),
+    ("WebhookConnection", "edges", FieldKind::Object, "WebhookEdge"),
+    ("WebhookConnection", "nodes", FieldKind::Object, "Webhook"),
+    ("WebhookConnection", "pageInfo", FieldKind::Object, "PageInfo"),
+    ("WebhookEdge", "cursor", FieldKind::Scalar, "String"),
+    ("WebhookEdge", "node", FieldKind::Object, "Webhook"),
+    ("WebhookFailureEvent", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("WebhookFailureEvent", "executionId", FieldKind::Scalar, "String"),
+    ("WebhookFailureEvent", "httpStatus", FieldKind::Scalar, "Float"),
+    ("WebhookFailureEvent", "id", FieldKind::Scalar, "ID"),
+    ("WebhookFailureEvent", "responseOrError", FieldKind::Scalar, "String"),
+    ("WebhookFailureEvent", "url", FieldKind::Scalar, "String"),
+    ("WebhookFailureEvent", "webhook", FieldKind::Object, "Webhook"),
+    ("WebhookPayload", "lastSyncId", FieldKind::Scalar, "Float"),
+    ("WebhookPayload", "success", FieldKind::Scalar, "Boolean"),
+    ("WebhookPayload", "webhook", FieldKind::Object, "Webhook"),
+    ("WorkflowState", "archivedAt", FieldKind::Scalar, "DateTime"),
+    ("WorkflowState", "color", FieldKind::Scalar, "String"),
+    ("WorkflowState", "createdAt", FieldKind::Scalar, "DateTime"),
+    ("WorkflowState", "description", FieldKind::Scalar, "String"),
+    ("WorkflowState", "id", FieldKind::Scalar, "ID"),
+    ("WorkflowState", "inheritedFrom", FieldKind::Object, "WorkflowState"),
+    ("WorkflowState", "issues", FieldKind::Connection, "IssueConnection"),
+    ("WorkflowState", "name", FieldKind::Scalar, "String"),
+    ("WorkflowState", "position", FieldKind::Scalar, "Float"),
+    ("WorkflowState", "team", FieldKind::Object, "Team"),
+    ("WorkflowState", "type", FieldKind::Scalar, "String"),
+    ("WorkflowState", "updatedAt", FieldKind::Scalar, "DateTime"),
+    ("WorkflowStateArchivePayload", "entity", FieldKind::Object, "WorkflowState"),
+    ("WorkflowStateArchivePayload", "lastSyncId", FieldKind::Scalar, "Float"),
+    ("WorkflowStateArchivePayload", "success", FieldKind::Scalar, "Boolean"),
+    ("WorkflowStateChildWebhookPayload", "color", FieldKind::Scalar, "String"),
+    ("WorkflowStateChildWebhookPayload", "id", FieldKind::Scalar, "String"),
+    ("WorkflowStateChildWebhookPayload", "name", FieldKind::Scalar, "String"),
+    ("WorkflowStateChildWebhookPayload", "type", FieldKind::Scalar, "String"),
+    ("WorkflowStateConnection", "edges", FieldKind::Object, "WorkflowStateEdge"),
+    ("WorkflowStateConnection", "nodes", FieldKind::Object, "WorkflowState"),
+    ("WorkflowStateConnection", "pageInfo", FieldKind::Object, "PageInfo"),
+    ("WorkflowStateEdge", "cursor", FieldKind::Scalar, "String"),
+    ("WorkflowStateEdge", "node", FieldKind::Object, "WorkflowState"),
+    ("WorkflowStatePayload", "lastSyncId", FieldKind::Scalar, "Float"),
+    ("WorkflowStatePayload", "success", FieldKind::Scalar, "Boolean"),
+    ("WorkflowStatePayload", "workflowState", FieldKind::Object, "WorkflowState"),
+];
+
+/// Kind and named type of a field on an object or interface type
+pub fn get_field_type(parent_type: &str, field: &str) -> Option<(FieldKind, &'static str)> {
+    FIELD_TYPES
+        .binary_search_by(|(p, f, _, _)| (*p, *f).cmp(&(parent_type, field)))
+        .ok()
+        .map(|i| (FIELD_TYPES[i].2, FIELD_TYPES[i].3))
+}
@@ -1,6 +1,9 @@
 //! Generated code - DO NOT EDIT
 //! Run `cargo xtask codegen` to regenerate
 
+mod field_types;
+#[cfg(feature = "models")]
+pub mod models;
 mod mutation_ops;
 mod mutation_registry;
 mod order_by;
@@ -9,6 +12,7 @@ mod resources;
 mod search_plan;
 mod validation_schema;
 
+pub use field_types::{get_field_type, FieldKind};
 pub use mutation_ops::MutationOp;
 pub use mutation_registry::get_mutation_result_fields;
 pub use order_by::OrderBy;
@@ -0,0 +1,1412 @@
+//! Generated models: enums - DO NOT EDIT
+//! Run `cargo xtask codegen` to regenerate
+
+use serde::{Deserialize, Serialize};
+
+/// A modifier that provides additional instructions on how the activity should be interpreted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum AgentActivitySignal {
+    #[serde(rename = "auth")]
+    Auth,
+    #[serde(rename = "continue")]
+    Continue,
+    #[serde(rename = "select")]
+    Select,
+    #[serde(rename = "stop")]
+    Stop,
+    /// A value added to the schema after this code was generated
+    #[serde(other)]
+    Unknown,
+}
+
+/// The type of an agent activity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum AgentActivityType {
+    #[serde(rename = "action")]
+    Action,
+    #[serde(rename = "elicitation")]
+    Elicitation,
+    #[serde(rename = "error")]
+    Error,
+    #[serde(rename = "prompt")]
+    Prompt,
+    #[serde(rename = "response")]
+    Response,
+    #[serde(rename = "thought")]
+    Thought,
+    /// A value added to the schema after this code was generated
+    #[serde(other)]
+    Unknown,
+}
+
+/// The status of an agent session.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum AgentSessionStatus {
+    #[serde(rename = "active")]
+    Active,
+    #[serde(rename = "awaitingInput")]
+    AwaitingInput,
+    #[serde(rename = "complete")]
+    Complete,
+    #[serde(rename = "error")]
+    Error,
+    #[serde(rename = "pending")]
+    Pending,
+    #[serde(rename = "stale")]
+    Stale,
+    /// A value added to the schema after this code was generated
+    #[serde(other)]
+    Unknown,
+}
+
+/// The type of an agent session.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum AgentSessionType {
+    #[serde(rename = "commentThread")]
+    CommentThread,
+    /// A value added to the schema after this code was generated
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum AuthenticationSessionType {
+    #[serde(rename = "android")]
+    Android,
+    #[serde(rename = "desktop")]
+    Desktop,
+    #[serde(rename = "ios")]
+    Ios,
+    #[serde(rename = "web")]
+    Web,
+    /// A value added to the schema after this code was generated
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ContextViewType {
+    #[serde(rename = "activeCycle")]
+    ActiveCycle,
+    #[serde(rename = "activeIssues")]
+    ActiveIssues,
+    #[serde(rename = "backlog")]
+    Backlog,
+    #[serde(rename = "triage")]
+    Triage,
+    #[serde(rename = "upcomingCycle")]
+    UpcomingCycle,
+    /// A value added to the schema after this code was generated
+    #[serde(other)]
+    Unknown,
+}
+
+/// \[DEPRECATED\] A type of customer status.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum CustomerStatusType {
+    #[serde(rename = "active")]
+    Active,
+    #[serde(rename = "inactive")]
+    Inactive,
+    /// A value added to the schema after this code was generated
+    #[serde(other)]
+    Unknown,
+}
+
+/// Mode that controls who can see and set Customers in Slack Asks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum CustomerVisibilityMode {
+    LinearOnly,
+    SlackMembers,
+    SlackMembersAndGuests,
+    /// A value added to the schema after this code was generated
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum CyclePeriod {
+    #[serde(rename = "after")]
+    After,
+    #[serde(rename = "before")]
+    Before,
+    #[serde(rename = "during")]
+    During,
+    /// A value added to the schema after this code was generated
+    #[serde(other)]
+    Unknown,
+}
+
+/// By which resolution is a date defined.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum DateResolutionType {
+    #[serde(rename = "halfYear")]
+    HalfYear,
+    #[serde(rename = "month")]
+    Month,
+    #[serde(rename = "quarter")]
+    Quarter,
+    #[serde(rename = "year")]
+    Year,
+    /// A value added to the schema after this code was generated
+    #[serde(other)]
+    Unknown,
+}
+
+/// The day of the week.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Day {
+    Friday,
+    Monday,
+    Saturday,
+    Sunday,
+    Thursday,
+    Tuesday,
+    Wednesday,
+    /// A value added to the schema after this code was generated
+    #[serde(other)]
+    Unknown,
+}
+
+/// The type of the email address.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum EmailIntakeAddressType {
+    #[serde(rename = "asks")]
+    Asks,
+    #[serde(rename = "team")]
+    Team,
+    #[serde(rename = "template")]
+    Template,
+    /// A value added to the schema after this code was generated
+    #[serde(other)]
+    Unknown,
+}
+
+/// The service that syncs an external entity to Linear.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ExternalSyncService {
+    #[serde(rename = "github")]
+    Github,
+    #[serde(rename = "jira")]
+    Jira,
+    #[serde(rename = "slack")]
+    Slack,
+    /// A value added to the schema after this code was generated
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum FacetPageSource {
+    #[serde(rename = "feed")]
+    Feed,
+    #[serde(rename = "projects")]
+    Projects,
+    #[serde(rename = "teamIssues")]
+    TeamIssues,
+    /// A value added to the schema after this code was generated
+    #[serde(other)]
+    Unknown,
+}
+
+/// Cadence to generate feed summary
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum FeedSummarySchedule {
+    #[serde(rename = "daily")]
+    Daily,
+    #[serde(rename = "never")]
+    Never,
+    #[serde(rename = "weekly")]
+    Weekly,
+    /// A value added to the schema after this code was generated
+    #[serde(other)]
+    Unknown,
+}
+
+/// By which resolution is frequency defined.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum FrequencyResolutionType {
+    #[serde(rename = "daily")]
+    Daily,
+    #[serde(rename = "weekly")]
+    Weekly,
+    /// A value added to the schema after this code was generated
+    #[serde(other)]
+    Unknown,
+}
+
+/// The various states of a pull/merge request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum GitAutomationStates {
+    #[serde(rename = "draft")]
+    Draft,
+    #[serde(rename = "merge")]
+    Merge,
+    #[serde(rename = "mergeable")]
+    Mergeable,
+    #[serde(rename = "review")]
+    Review,
+    #[serde(rename = "start")]
+    Start,
+    /// A value added to the schema after this code was generated
+    #[serde(other)]
+    Unknown,
+}
+
+/// \[Internal\] The kind of link between an issue and a pull request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum GitLinkKind {
+    #[serde(rename = "closes")]
+    Closes,
+    #[serde(rename = "contributes")]
+    Contributes,
+    #[serde(rename = "links")]
+    Links,
+    /// A value added to the schema after this code was generated
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum GithubOrgType {
+    #[serde(rename = "organization")]
+    Organization,
+    #[serde(rename = "user")]
+    User,
+    /// A value added to the schema after this code was generated
+    #[serde(other)]
+    Unknown,
+}
+
+/// The type of identity provider.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum IdentityProviderType {
+    #[serde(rename = "general")]
+    General,
+    #[serde(rename = "webForms")]
+    WebForms,
+    /// A value added to the schema after this code was generated
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum InitiativeStatus {
+    Active,
+    Completed,
+    Planned,
+    /// A value added to the schema after this code was generated
+    #[serde(other)]
+    Unknown,
+}
+
+/// Different tabs available inside an initiative.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum InitiativeTab {
+    #[serde(rename = "overview")]
+    Overview,
+    #[serde(rename = "projects")]
+    Projects,
+    #[serde(rename = "updates")]
+    Updates,
+    /// A value added to the schema after this code was generated
+    #[serde(other)]
+    Unknown,
+}
+
+/// The health type when the update is created.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum InitiativeUpdateHealthType {
+    #[serde(rename = "atRisk")]
+    AtRisk,
+    #[serde(rename = "offTrack")]
+    OffTrack,
+    #[serde(rename = "onTrack")]
+    OnTrack,
+    /// A value added to the schema after this code was generated
+    #[serde(other)]
+    Unknown,
+}
+
+/// Linear supported integration services.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum IntegrationService {
+    #[serde(rename = "airbyte")]
+    Airbyte,
+    #[serde(rename = "discord")]
+    Discord,
+    #[serde(rename = "email")]
+    Email,
+    #[serde(rename = "figma")]
+    Figma,
+    #[serde(rename = "figmaPlugin")]
+    FigmaPlugin,
+    #[serde(rename = "front")]
+    Front,
+    #[serde(rename = "github")]
+    Github,
+    #[serde(rename = "githubCodeAccessPersonal")]
+    GithubCodeAccessPersonal,
+    #[serde(rename = "githubCommit")]
+    GithubCommit,
+    #[serde(rename = "githubEnterpriseServer")]
+    GithubEnterpriseServer,
+    #[serde(rename = "githubImport")]
+    GithubImport,
+    #[serde(rename = "githubPersonal")]
+    GithubPersonal,
+    #[serde(rename = "gitlab")]
+    Gitlab,
+    #[serde(rename = "gong")]
+    Gong,
+    #[serde(rename = "googleCalendarPersonal")]
+    GoogleCalendarPersonal,
+    #[serde(rename = "googleSheets")]
+    GoogleSheets,
+    #[serde(rename = "intercom")]
+    Intercom,
+    #[serde(rename = "jira")]
+    Jira,
+    #[serde(rename = "jiraPersonal")]
+    JiraPersonal,
+    #[serde(rename = "launchDarkly")]
+    LaunchDarkly,
+    #[serde(rename = "launchDarklyPersonal")]
+    LaunchDarklyPersonal,
+    #[serde(rename = "loom")]
+    Loom,
+    #[serde(rename = "mcpServer")]
+    McpServer,
+    #[serde(rename = "mcpServerPersonal")]
+    McpServerPersonal,
+    #[serde(rename = "notion")]
+    Notion,
+    #[serde(rename = "opsgenie")]
+    Opsgenie,
+    #[serde(rename = "pagerDuty")]
+    PagerDuty,
+    #[serde(rename = "salesforce")]
+    Salesforce,
+    #[serde(rename = "sentry")]
+    Sentry,
+    #[serde(rename = "slack")]
+    Slack,
+    #[serde(rename = "slackAsks")]
+    SlackAsks,
+    #[serde(rename = "slackCustomViewNotifications")]
+    SlackCustomViewNotifications,
+    #[serde(rename = "slackInitiativePost")]
+    SlackInitiativePost,
+    #[serde(rename = "slackOrgInitiativeUpdatesPost")]
+    SlackOrgInitiativeUpdatesPost,
+    #[serde(rename = "slackOrgProjectUpdatesPost")]
+    SlackOrgProjectUpdatesPost,
+    #[serde(rename = "slackPersonal")]
+    SlackPersonal,
+    #[serde(rename = "slackPost")]
+    SlackPost,
+    #[serde(rename = "slackProjectPost")]
+    SlackProjectPost,
+    #[serde(rename = "slackProjectUpdatesPost")]
+    SlackProjectUpdatesPost,
+    #[serde(rename = "zendesk")]
+    Zendesk,
+    /// A value added to the schema after this code was generated
+    #[serde(other)]
+    Unknown,
+}
+
+/// The type of the issue relation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum IssueRelationType {
+    #[serde(rename = "blocks")]
+    Blocks,
+    #[serde(rename = "duplicate")]
+    Duplicate,
+    #[serde(rename = "related")]
+    Related,
+    #[serde(rename = "similar")]
+    Similar,
+    /// A value added to the schema after this code was generated
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum IssueSuggestionState {
+    #[serde(rename = "accepted")]
+    Accepted,
+    #[serde(rename = "active")]
+    Active,
+    #[serde(rename = "dismissed")]
+    Dismissed,
+    #[serde(rename = "stale")]
+    Stale,
+    /// A value added to the schema after this code was generated
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum IssueSuggestionType {
+    #[serde(rename = "assignee")]
+    Assignee,
+    #[serde(rename = "label")]
+    Label,
+    #[serde(rename = "project")]
+    Project,
+    #[serde(rename = "relatedIssue")]
+    RelatedIssue,
+    #[serde(rename = "similarIssue")]
+    SimilarIssue,
+    #[serde(rename = "team")]
+    Team,
+    /// A value added to the schema after this code was generated
+    #[serde(other)]
+    Unknown,
+}
+
+/// The categories of notifications a user can subscribe to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum NotificationCategory {
+    #[serde(rename = "appsAndIntegrations")]
+    AppsAndIntegrations,
+    #[serde(rename = "assignments")]
+    Assignments,
+    #[serde(rename = "commentsAndReplies")]
+    CommentsAndReplies,
+    #[serde(rename = "customers")]
+    Customers,
+    #[serde(rename = "documentChanges")]
+    DocumentChanges,
+    #[serde(rename = "feed")]
+    Feed,
+    #[serde(rename = "mentions")]
+    Mentions,
+    #[serde(rename = "postsAndUpdates")]
+    PostsAndUpdates,
+    #[serde(rename = "reactions")]
+    Reactions,
+    #[serde(rename = "reminders")]
+    Reminders,
+    #[serde(rename = "reviews")]
+    Reviews,
+    #[serde(rename = "statusChanges")]
+    StatusChanges,
+    #[serde(rename = "subscriptions")]
+    Subscriptions,
+    #[serde(rename = "system")]
+    System,
+    #[serde(rename = "triage")]
+    Triage,
+    /// A value added to the schema after this code was generated
+    #[serde(other)]
+    Unknown,
+}
+
+/// The delivery channels a user can receive notifications in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum NotificationChannel {
+    #[serde(rename = "desktop")]
+    Desktop,
+    #[serde(rename = "email")]
+    Email,
+    #[serde(rename = "mobile")]
+    Mobile,
+    #[serde(rename = "slack")]
+    Slack,
+    /// A value added to the schema after this code was generated
+    #[serde(other)]
+    Unknown,
+}
+
+/// The different requests statuses possible for an OAuth client approval request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum OAuthClientApprovalStatus {
+    #[serde(rename = "approved")]
+    Approved,
+    #[serde(rename = "denied")]
+    Denied,
+    #[serde(rename = "requested")]
+    Requested,
+    /// A value added to the schema after this code was generated
+    #[serde(other)]
+    Unknown,
+}
+
+/// What type of auth is the domain used for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum OrganizationDomainAuthType {
+    #[serde(rename = "general")]
+    General,
+    #[serde(rename = "saml")]
+    Saml,
+    /// A value added to the schema after this code was generated
+    #[serde(other)]
+    Unknown,
+}
+
+/// The different statuses possible for an organization invite.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum OrganizationInviteStatus {
+    #[serde(rename = "accepted")]
+    Accepted,
+    #[serde(rename = "expired")]
+    Expired,
+    #[serde(rename = "pending")]
+    Pending,
+    /// A value added to the schema after this code was generated
+    #[serde(other)]
+    Unknown,
+}
+
+/// A generic type of notification.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum OtherNotificationType {
+    #[serde(rename = "customerAddedAsOwner")]
+    CustomerAddedAsOwner,
+    #[serde(rename = "customerNeedCreated")]
+    CustomerNeedCreated,
+    #[serde(rename = "customerNeedMarkedAsImportant")]
+    CustomerNeedMarkedAsImportant,
+    #[serde(rename = "customerNeedResolved")]
+    CustomerNeedResolved,
+    #[serde(rename = "documentCommentMention")]
+    DocumentCommentMention,
+    #[serde(rename = "documentCommentReaction")]
+    DocumentCommentReaction,
+    #[serde(rename = "documentContentChange")]
+    DocumentContentChange,
+    #[serde(rename = "documentDeleted")]
+    DocumentDeleted,
+    #[serde(rename = "documentMention")]
+    DocumentMention,
+    #[serde(rename = "documentMoved")]
+    DocumentMoved,
+    #[serde(rename = "documentNewComment")]
+    DocumentNewComment,
+    #[serde(rename = "documentReminder")]
+    DocumentReminder,
+    #[serde(rename = "documentRestored")]
+    DocumentRestored,
+    #[serde(rename = "documentSubscribed")]
+    DocumentSubscribed,
+    #[serde(rename = "documentThreadResolved")]
+    DocumentThreadResolved,
+    #[serde(rename = "documentUnsubscribed")]
+    DocumentUnsubscribed,
+    #[serde(rename = "feedSummaryGenerated")]
+    FeedSummaryGenerated,
+    #[serde(rename = "initiativeAddedAsOwner")]
+    InitiativeAddedAsOwner,
+    #[serde(rename = "initiativeCommentMention")]
+    InitiativeCommentMention,
+    #[serde(rename = "initiativeCommentReaction")]
+    InitiativeCommentReaction,
+    #[serde(rename = "initiativeDescriptionContentChange")]
+    InitiativeDescriptionContentChange,
+    #[serde(rename = "initiativeMention")]
+    InitiativeMention,
+    #[serde(rename = "initiativeNewComment")]
+    InitiativeNewComment,
+    #[serde(rename = "initiativeReminder")]
+    InitiativeReminder,
+    #[serde(rename = "initiativeThreadResolved")]
+    InitiativeThreadResolved,
+    #[serde(rename = "initiativeUpdateCommentMention")]
+    InitiativeUpdateCommentMention,
+    #[serde(rename = "initiativeUpdateCommentReaction")]
+    InitiativeUpdateCommentReaction,
+    #[serde(rename = "initiativeUpdateCreated")]
+    InitiativeUpdateCreated,
+    #[serde(rename = "initiativeUpdateMention")]
+    InitiativeUpdateMention,
+    #[serde(rename = "initiativeUpdateNewComment")]
+    InitiativeUpdateNewComment,
+    #[serde(rename = "initiativeUpdatePrompt")]
+    InitiativeUpdatePrompt,
+    #[serde(rename = "initiativeUpdateReaction")]
+    InitiativeUpdateReaction,
+    #[serde(rename = "issueAddedToTriage")]
+    IssueAddedToTriage,
+    #[serde(rename = "issueAddedToView")]
+    IssueAddedToView,
+    #[serde(rename = "issueBlocking")]
+    IssueBlocking,
+    #[serde(rename = "issueCreated")]
+    IssueCreated,
+    #[serde(rename = "issueDue")]
+    IssueDue,
+    #[serde(rename = "issuePriorityUrgent")]
+    IssuePriorityUrgent,
+    #[serde(rename = "issueReminder")]
+    IssueReminder,
+    #[serde(rename = "issueReopened")]
+    IssueReopened,
+    #[serde(rename = "issueSlaBreached")]
+    IssueSlaBreached,
+    #[serde(rename = "issueSlaHighRisk")]
+    IssueSlaHighRisk,
+    #[serde(rename = "issueStatusChangedAll")]
+    IssueStatusChangedAll,
+    #[serde(rename = "issueSubscribed")]
+    IssueSubscribed,
+    #[serde(rename = "issueThreadResolved")]
+    IssueThreadResolved,
+    #[serde(rename = "issueUnblocked")]
+    IssueUnblocked,
+    #[serde(rename = "issueUnsubscribed")]
+    IssueUnsubscribed,
+    #[serde(rename = "oauthClientApprovalCreated")]
+    OauthClientApprovalCreated,
+    #[serde(rename = "projectAddedAsLead")]
+    ProjectAddedAsLead,
+    #[serde(rename = "projectAddedAsMember")]
+    ProjectAddedAsMember,
+    #[serde(rename = "projectCommentMention")]
+    ProjectCommentMention,
+    #[serde(rename = "projectCommentReaction")]
+    ProjectCommentReaction,
+    #[serde(rename = "projectDescriptionContentChange")]
+    ProjectDescriptionContentChange,
+    #[serde(rename = "projectMention")]
+    ProjectMention,
+    #[serde(rename = "projectMilestoneCommentMention")]
+    ProjectMilestoneCommentMention,
+    #[serde(rename = "projectMilestoneCommentReaction")]
+    ProjectMilestoneCommentReaction,
+    #[serde(rename = "projectMilestoneDescriptionContentChange")]
+    ProjectMilestoneDescriptionContentChange,
+    #[serde(rename = "projectMilestoneMention")]
+    ProjectMilestoneMention,
+    #[serde(rename = "projectMilestoneNewComment")]
+    ProjectMilestoneNewComment,
+    #[serde(rename = "projectMilestoneThreadResolved")]
+    ProjectMilestoneThreadResolved,
+    #[serde(rename = "projectNewComment")]
+    ProjectNewComment,
+    #[serde(rename = "projectReminder")]
+    ProjectReminder,
+    #[serde(rename = "projectThreadResolved")]
+    ProjectThreadResolved,
+    #[serde(rename = "projectUpdateCommentMention")]
+    ProjectUpdateCommentMention,
+    #[serde(rename = "projectUpdateCommentReaction")]
+    ProjectUpdateCommentReaction,
+    #[serde(rename = "projectUpdateCreated")]
+    ProjectUpdateCreated,
+    #[serde(rename = "projectUpdateMention")]
+    ProjectUpdateMention,
+    #[serde(rename = "projectUpdateNewComment")]
+    ProjectUpdateNewComment,
+    #[serde(rename = "projectUpdatePrompt")]
+    ProjectUpdatePrompt,
+    #[serde(rename = "projectUpdateReaction")]
+    ProjectUpdateReaction,
+    #[serde(rename = "pullRequestApproved")]
+    PullRequestApproved,
+    #[serde(rename = "pullRequestChangesRequested")]
+    PullRequestChangesRequested,
+    #[serde(rename = "pullRequestChecksFailed")]
+    PullRequestChecksFailed,
+    #[serde(rename = "pullRequestCommentMention")]
+    PullRequestCommentMention,
+    #[serde(rename = "pullRequestCommented")]
+    PullRequestCommented,
+    #[serde(rename = "pullRequestMention")]
+    PullRequestMention,
+    #[serde(rename = "pullRequestRemovedFromMergeQueue")]
+    PullRequestRemovedFromMergeQueue,
+    #[serde(rename = "pullRequestReviewRequested")]
+    PullRequestReviewRequested,
+    #[serde(rename = "pullRequestReviewRerequested")]
+    PullRequestReviewRerequested,
+    #[serde(rename = "system")]
+    System,
+    #[serde(rename = "teamUpdateCommentMention")]
+    TeamUpdateCommentMention,
+    #[serde(rename = "teamUpdateCommentReaction")]
+    TeamUpdateCommentReaction,
+    #[serde(rename = "teamUpdateCreated")]
+    TeamUpdateCreated,
+    #[serde(rename = "teamUpdateMention")]
+    TeamUpdateMention,
+    #[serde(rename = "teamUpdateNewComment")]
+    TeamUpdateNewComment,
+    #[serde(rename = "teamUpdateReaction")]
+    TeamUpdateReaction,
+    #[serde(rename = "triageResponsibilityIssueAddedToTriage")]
+    TriageResponsibilityIssueAddedToTriage,
+    /// A value added to the schema after this code was generated
+    #[serde(other)]
+    Unknown,
+}
+
+/// How to treat NULL values, whether they should appear first or last
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum PaginationNulls {
+    #[serde(rename = "first")]
+    First,
+    #[serde(rename = "last")]
+    Last,
+    /// A value added to the schema after this code was generated
+    #[serde(other)]
+    Unknown,
+}
+
+/// By which field should the pagination order by
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum PaginationOrderBy {
+    #[serde(rename = "createdAt")]
+    CreatedAt,
+    #[serde(rename = "updatedAt")]
+    UpdatedAt,
+    /// A value added to the schema after this code was generated
+    #[serde(other)]
+    Unknown,
+}
+
+/// Whether to sort in ascending or descending order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum PaginationSortOrder {
+    Ascending,
+    Descending,
+    /// A value added to the schema after this code was generated
+    #[serde(other)]
+    Unknown,
+}
+
+/// Type of Post
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum PostType {
+    #[serde(rename = "summary")]
+    Summary,
+    #[serde(rename = "update")]
+    Update,
+    /// A value added to the schema after this code was generated
+    #[serde(other)]
+    Unknown,
+}
+
+/// \[Internal\] The scope of product intelligence suggestion data for a team.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ProductIntelligenceScope {
+    #[serde(rename = "none")]
+    None,
+    #[serde(rename = "team")]
+    Team,
+    #[serde(rename = "teamHierarchy")]
+    TeamHierarchy,
+    #[serde(rename = "workspace")]
+    Workspace,
+    /// A value added to the schema after this code was generated
+    #[serde(other)]
+    Unknown,
+}
+
+/// The status of a project milestone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ProjectMilestoneStatus {
+    #[serde(rename = "done")]
+    Done,
+    #[serde(rename = "next")]
+    Next,
+    #[serde(rename = "overdue")]
+    Overdue,
+    #[serde(rename = "unstarted")]
+    Unstarted,
+    /// A value added to the schema after this code was generated
+    #[serde(other)]
+    Unknown,
+}
+
+/// A type of project status.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ProjectStatusType {
+    #[serde(rename = "backlog")]
+    Backlog,
+    #[serde(rename = "canceled")]
+    Canceled,
+    #[serde(rename = "completed")]
+    Completed,
+    #[serde(rename = "paused")]
+    Paused,
+    #[serde(rename = "planned")]
+    Planned,
+    #[serde(rename = "started")]
+    Started,
+    /// A value added to the schema after this code was generated
+    #[serde(other)]
+    Unknown,
+}
+
+/// Different tabs available inside a project.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ProjectTab {
+    #[serde(rename = "customers")]
+    Customers,
+    #[serde(rename = "documents")]
+    Documents,
+    #[serde(rename = "issues")]
+    Issues,
+    #[serde(rename = "updates")]
+    Updates,
+    /// A value added to the schema after this code was generated
+    #[serde(other)]
+    Unknown,
+}
+
+/// The health type when the project update is created.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ProjectUpdateHealthType {
+    #[serde(rename = "atRisk")]
+    AtRisk,
+    #[serde(rename = "offTrack")]
+    OffTrack,
+    #[serde(rename = "onTrack")]
+    OnTrack,
+    /// A value added to the schema after this code was generated
+    #[serde(other)]
+    Unknown,
+}
+
+/// The frequency at which to send project update reminders.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ProjectUpdateReminderFrequency {
+    #[serde(rename = "month")]
+    Month,
+    #[serde(rename = "never")]
+    Never,
+    #[serde(rename = "twoWeeks")]
+    TwoWeeks,
+    #[serde(rename = "week")]
+    Week,
+    /// A value added to the schema after this code was generated
+    #[serde(other)]
+    Unknown,
+}
+
+/// The method used to merge a pull request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum PullRequestMergeMethod {
+    #[serde(rename = "MERGE")]
+    Merge,
+    #[serde(rename = "REBASE")]
+    Rebase,
+    #[serde(rename = "SQUASH")]
+    Squash,
+    /// A value added to the schema after this code was generated
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum PullRequestReviewTool {
+    #[serde(rename = "graphite")]
+    Graphite,
+    #[serde(rename = "source")]
+    Source,
+    /// A value added to the schema after this code was generated
+    #[serde(other)]
+    Unknown,
+}
+
+/// The status of a pull request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum PullRequestStatus {
+    #[serde(rename = "approved")]
+    Approved,
+    #[serde(rename = "closed")]
+    Closed,
+    #[serde(rename = "draft")]
+    Draft,
+    #[serde(rename = "inReview")]
+    InReview,
+    #[serde(rename = "merged")]
+    Merged,
+    #[serde(rename = "open")]
+    Open,
+    /// A value added to the schema after this code was generated
+    #[serde(other)]
+    Unknown,
+}
+
+/// The different push subscription types.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum PushSubscriptionType {
+    #[serde(rename = "apple")]
+    Apple,
+    #[serde(rename = "appleDevelopment")]
+    AppleDevelopment,
+    #[serde(rename = "firebase")]
+    Firebase,
+    #[serde(rename = "web")]
+    Web,
+    /// A value added to the schema after this code was generated
+    #[serde(other)]
+    Unknown,
+}
+
+/// Features release channel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ReleaseChannel {
+    #[serde(rename = "beta")]
+    Beta,
+    #[serde(rename = "development")]
+    Development,
+    #[serde(rename = "internal")]
+    Internal,
+    #[serde(rename = "preRelease")]
+    PreRelease,
+    #[serde(rename = "privateBeta")]
+    PrivateBeta,
+    #[serde(rename = "public")]
+    Public,
+    /// A value added to the schema after this code was generated
+    #[serde(other)]
+    Unknown,
+}
+
+/// A type of release pipeline.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ReleasePipelineType {
+    #[serde(rename = "continuous")]
+    Continuous,
+    #[serde(rename = "scheduled")]
+    Scheduled,
+    /// A value added to the schema after this code was generated
+    #[serde(other)]
+    Unknown,
+}
+
+/// A type of release stage.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ReleaseStageType {
+    #[serde(rename = "canceled")]
+    Canceled,
+    #[serde(rename = "completed")]
+    Completed,
+    #[serde(rename = "planned")]
+    Planned,
+    #[serde(rename = "started")]
+    Started,
+    /// A value added to the schema after this code was generated
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum SLADayCountType {
+    #[serde(rename = "all")]
+    All,
+    #[serde(rename = "onlyBusinessDays")]
+    OnlyBusinessDays,
+    /// A value added to the schema after this code was generated
+    #[serde(other)]
+    Unknown,
+}
+
+/// The type of the semantic search result.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum SemanticSearchResultType {
+    #[serde(rename = "document")]
+    Document,
+    #[serde(rename = "initiative")]
+    Initiative,
+    #[serde(rename = "issue")]
+    Issue,
+    #[serde(rename = "project")]
+    Project,
+    /// A value added to the schema after this code was generated
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum SendStrategy {
+    #[serde(rename = "desktop")]
+    Desktop,
+    #[serde(rename = "desktopAndPush")]
+    DesktopAndPush,
+    #[serde(rename = "desktopThenPush")]
+    DesktopThenPush,
+    #[serde(rename = "push")]
+    Push,
+    /// A value added to the schema after this code was generated
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum SlaStatus {
+    Breached,
+    Completed,
+    Failed,
+    HighRisk,
+    LowRisk,
+    MediumRisk,
+    /// A value added to the schema after this code was generated
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum SlackChannelType {
+    DirectMessage,
+    MultiPersonDirectMessage,
+    Private,
+    PrivateGroup,
+    Public,
+    /// A value added to the schema after this code was generated
+    #[serde(other)]
+    Unknown,
+}
+
+/// All possible roles within a team in terms of access to team settings and operations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum TeamRoleType {
+    #[serde(rename = "member")]
+    Member,
+    #[serde(rename = "owner")]
+    Owner,
+    /// A value added to the schema after this code was generated
+    #[serde(other)]
+    Unknown,
+}
+
+/// Which action should be taken after an issue is added to triage.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum TriageResponsibilityAction {
+    #[serde(rename = "assign")]
+    Assign,
+    #[serde(rename = "notify")]
+    Notify,
+    /// A value added to the schema after this code was generated
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum UserContextViewType {
+    #[serde(rename = "assigned")]
+    Assigned,
+    /// A value added to the schema after this code was generated
+    #[serde(other)]
+    Unknown,
+}
+
+/// The types of flags that the user can have.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum UserFlagType {
+    #[serde(rename = "all")]
+    All,
+    #[serde(rename = "analyticsWelcomeDismissed")]
+    AnalyticsWelcomeDismissed,
+    #[serde(rename = "canPlaySnake")]
+    CanPlaySnake,
+    #[serde(rename = "canPlayTetris")]
+    CanPlayTetris,
+    #[serde(rename = "commandMenuClearShortcutTip")]
+    CommandMenuClearShortcutTip,
+    #[serde(rename = "completedOnboarding")]
+    CompletedOnboarding,
+    #[serde(rename = "cycleWelcomeDismissed")]
+    CycleWelcomeDismissed,
+    #[serde(rename = "desktopDownloadToastDismissed")]
+    DesktopDownloadToastDismissed,
+    #[serde(rename = "desktopInstalled")]
+    DesktopInstalled,
+    #[serde(rename = "desktopTabsOnboardingDismissed")]
+    DesktopTabsOnboardingDismissed,
+    #[serde(rename = "dueDateShortcutMigration")]
+    DueDateShortcutMigration,
+    #[serde(rename = "editorSlashCommandUsed")]
+    EditorSlashCommandUsed,
+    #[serde(rename = "emptyActiveIssuesDismissed")]
+    EmptyActiveIssuesDismissed,
+    #[serde(rename = "emptyBacklogDismissed")]
+    EmptyBacklogDismissed,
+    #[serde(rename = "emptyCustomViewsDismissed")]
+    EmptyCustomViewsDismissed,
+    #[serde(rename = "emptyMyIssuesDismissed")]
+    EmptyMyIssuesDismissed,
+    #[serde(rename = "emptyParagraphSlashCommandTip")]
+    EmptyParagraphSlashCommandTip,
+    #[serde(rename = "figmaPluginBannerDismissed")]
+    FigmaPluginBannerDismissed,
+    #[serde(rename = "figmaPromptDismissed")]
+    FigmaPromptDismissed,
+    #[serde(rename = "helpIslandFeatureInsightsDismissed")]
+    HelpIslandFeatureInsightsDismissed,
+    #[serde(rename = "importBannerDismissed")]
+    ImportBannerDismissed,
+    #[serde(rename = "initiativesBannerDismissed")]
+    InitiativesBannerDismissed,
+    #[serde(rename = "insightsHelpDismissed")]
+    InsightsHelpDismissed,
+    #[serde(rename = "insightsWelcomeDismissed")]
+    InsightsWelcomeDismissed,
+    #[serde(rename = "issueLabelSuggestionUsed")]
+    IssueLabelSuggestionUsed,
+    #[serde(rename = "issueMovePromptCompleted")]
+    IssueMovePromptCompleted,
+    #[serde(rename = "joinTeamIntroductionDismissed")]
+    JoinTeamIntroductionDismissed,
+    #[serde(rename = "listSelectionTip")]
+    ListSelectionTip,
+    #[serde(rename = "migrateThemePreference")]
+    MigrateThemePreference,
+    #[serde(rename = "milestoneOnboardingIsSeenAndDismissed")]
+    MilestoneOnboardingIsSeenAndDismissed,
+    #[serde(rename = "projectBacklogWelcomeDismissed")]
+    ProjectBacklogWelcomeDismissed,
+    #[serde(rename = "projectBoardOnboardingIsSeenAndDismissed")]
+    ProjectBoardOnboardingIsSeenAndDismissed,
+    #[serde(rename = "projectUpdatesWelcomeDismissed")]
+    ProjectUpdatesWelcomeDismissed,
+    #[serde(rename = "projectWelcomeDismissed")]
+    ProjectWelcomeDismissed,
+    #[serde(rename = "pulseWelcomeDismissed")]
+    PulseWelcomeDismissed,
+    #[serde(rename = "rewindBannerDismissed")]
+    RewindBannerDismissed,
+    #[serde(rename = "slackAgentPromoFromCreateNewIssueShown")]
+    SlackAgentPromoFromCreateNewIssueShown,
+    #[serde(rename = "slackBotWelcomeMessageShown")]
+    SlackBotWelcomeMessageShown,
+    #[serde(rename = "slackCommentReactionTipShown")]
+    SlackCommentReactionTipShown,
+    #[serde(rename = "teamsPageIntroductionDismissed")]
+    TeamsPageIntroductionDismissed,
+    #[serde(rename = "threadedCommentsNudgeIsSeen")]
+    ThreadedCommentsNudgeIsSeen,
+    #[serde(rename = "triageWelcomeDismissed")]
+    TriageWelcomeDismissed,
+    #[serde(rename = "tryCyclesDismissed")]
+    TryCyclesDismissed,
+    #[serde(rename = "tryGithubDismissed")]
+    TryGithubDismissed,
+    #[serde(rename = "tryInvitePeopleDismissed")]
+    TryInvitePeopleDismissed,
+    #[serde(rename = "tryRoadmapsDismissed")]
+    TryRoadmapsDismissed,
+    #[serde(rename = "tryTriageDismissed")]
+    TryTriageDismissed,
+    #[serde(rename = "updatedSlackThreadSyncIntegration")]
+    UpdatedSlackThreadSyncIntegration,
+    /// A value added to the schema after this code was generated
+    #[serde(other)]
+    Unknown,
+}
+
+/// Operations that can be applied to UserFlagType.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum UserFlagUpdateOperation {
+    #[serde(rename = "clear")]
+    Clear,
+    #[serde(rename = "decr")]
+    Decr,
+    #[serde(rename = "incr")]
+    Incr,
+    #[serde(rename = "lock")]
+    Lock,
+    /// A value added to the schema after this code was generated
+    #[serde(other)]
+    Unknown,
+}
+
+/// The different permission roles available to users on an organization.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum UserRoleType {
+    #[serde(rename = "admin")]
+    Admin,
+    #[serde(rename = "app")]
+    App,
+    #[serde(rename = "guest")]
+    Guest,
+    #[serde(rename = "owner")]
+    Owner,
+    #[serde(rename = "user")]
+    User,
+    /// A value added to the schema after this code was generated
+    #[serde(other)]
+    Unknown,
+}
+
+/// Device type for theme
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum UserSettingsThemeDeviceType {
+    #[serde(rename = "desktop")]
+    Desktop,
+    #[serde(rename = "mobileWeb")]
+    MobileWeb,
+    /// A value added to the schema after this code was generated
+    #[serde(other)]
+    Unknown,
+}
+
+/// Theme color mode
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum UserSettingsThemeMode {
+    #[serde(rename = "dark")]
+    Dark,
+    #[serde(rename = "light")]
+    Light,
+    /// A value added to the schema after this code was generated
+    #[serde(other)]
+    Unknown,
+}
+
+/// Theme preset options
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum UserSettingsThemePreset {
+    #[serde(rename = "classicDark")]
+    ClassicDark,
+    #[serde(rename = "custom")]
+    Custom,
+    #[serde(rename = "dark")]
+    Dark,
+    #[serde(rename = "light")]
+    Light,
+    #[serde(rename = "magicBlue")]
+    MagicBlue,
+    #[serde(rename = "pureLight")]
+    PureLight,
+    #[serde(rename = "system")]
+    System,
+    /// A value added to the schema after this code was generated
+    #[serde(other)]
+    Unknown,
+}
+
+/// The type of view preferences (either user or organization level preferences).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ViewPreferencesType {
+    #[serde(rename = "organization")]
+    Organization,
+    #[serde(rename = "user")]
+    User,
+    /// A value added to the schema after this code was generated
+    #[serde(other)]
+    Unknown,
+}
+
+/// The client view this custom view is targeting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ViewType {
+    #[serde(rename = "activeIssues")]
+    ActiveIssues,
+    #[serde(rename = "agents")]
+    Agents,
+    #[serde(rename = "allIssues")]
+    AllIssues,
+    #[serde(rename = "archive")]
+    Archive,
+    #[serde(rename = "backlog")]
+    Backlog,
+    #[serde(rename = "board")]
+    Board,
+    #[serde(rename = "completedCycle")]
+    CompletedCycle,
+    #[serde(rename = "customView")]
+    CustomView,
+    #[serde(rename = "customViews")]
+    CustomViews,
+    #[serde(rename = "customer")]
+    Customer,
+    #[serde(rename = "customers")]
+    Customers,
+    #[serde(rename = "cycle")]
+    Cycle,
+    #[serde(rename = "dashboards")]
+    Dashboards,
+    #[serde(rename = "embeddedCustomerNeeds")]
+    EmbeddedCustomerNeeds,
+    #[serde(rename = "feedAll")]
+    FeedAll,
+    #[serde(rename = "feedCreated")]
+    FeedCreated,
+    #[serde(rename = "feedFollowing")]
+    FeedFollowing,
+    #[serde(rename = "feedPopular")]
+    FeedPopular,
+    #[serde(rename = "inbox")]
+    Inbox,
+    #[serde(rename = "initiative")]
+    Initiative,
+    #[serde(rename = "initiativeOverview")]
+    InitiativeOverview,
+    #[serde(rename = "initiativeOverviewSubInitiatives")]
+    InitiativeOverviewSubInitiatives,
+    #[serde(rename = "initiatives")]
+    Initiatives,
+    #[serde(rename = "initiativesCompleted")]
+    InitiativesCompleted,
+    #[serde(rename = "initiativesPlanned")]
+    InitiativesPlanned,
+    #[serde(rename = "issueIdentifiers")]
+    IssueIdentifiers,
+    #[serde(rename = "label")]
+    Label,
+    #[serde(rename = "myIssues")]
+    MyIssues,
+    #[serde(rename = "myIssuesActivity")]
+    MyIssuesActivity,
+    #[serde(rename = "myIssuesCreatedByMe")]
+    MyIssuesCreatedByMe,
+    #[serde(rename = "myIssuesSubscribedTo")]
+    MyIssuesSubscribedTo,
+    #[serde(rename = "myReviews")]
+    MyReviews,
+    #[serde(rename = "project")]
+    Project,
+    #[serde(rename = "projectCustomerNeeds")]
+    ProjectCustomerNeeds,
+    #[serde(rename = "projectDocuments")]
+    ProjectDocuments,
+    #[serde(rename = "projectLabel")]
+    ProjectLabel,
+    #[serde(rename = "projects")]
+    Projects,
+    #[serde(rename = "projectsAll")]
+    ProjectsAll,
+    #[serde(rename = "projectsBacklog")]
+    ProjectsBacklog,
+    #[serde(rename = "projectsClosed")]
+    ProjectsClosed,
+    #[serde(rename = "quickView")]
+    QuickView,
+    #[serde(rename = "release")]
+    Release,
+    #[serde(rename = "reviews")]
+    Reviews,
+    #[serde(rename = "roadmap")]
+    Roadmap,
+    #[serde(rename = "roadmapAll")]
+    RoadmapAll,
+    #[serde(rename = "roadmapBacklog")]
+    RoadmapBacklog,
+    #[serde(rename = "roadmapClosed")]
+    RoadmapClosed,
+    #[serde(rename = "roadmaps")]
+    Roadmaps,
+    #[serde(rename = "search")]
+    Search,
+    #[serde(rename = "splitSearch")]
+    SplitSearch,
+    #[serde(rename = "subIssues")]
+    SubIssues,
+    #[serde(rename = "teams")]
+    Teams,
+    #[serde(rename = "triage")]
+    Triage,
+    #[serde(rename = "userProfile")]
+    UserProfile,
+    #[serde(rename = "userProfileCreatedByUser")]
+    UserProfileCreatedByUser,
+    #[serde(rename = "workspaceMembers")]
+    WorkspaceMembers,
+    /// A value added to the schema after this code was generated
+    #[serde(other)]
+    Unknown,
+}
@@ -0,0 +1,10273 @@
+//! Generated models: input types - DO NOT EDIT
+//! Run `cargo xtask codegen` to regenerate
+
+use serde::{Deserialize, Serialize};
+
+use super::enums::*;
+use super::scalars::*;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AgentActivityCreateInput {
+    /// The agent session this activity belongs to.
+    pub agent_session_id: String,
+    /// The content payload of the agent activity. This object is not strictly typed.
+    pub content: JSONObject,
+    /// \[Internal\] Metadata about user-provided contextual information for this agent activity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub contextual_metadata: Option<JSONObject>,
+    /// Whether the activity is ephemeral, and should disappear after the next activity. Defaults to false.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ephemeral: Option<bool>,
+    /// The identifier in UUID v4 format. If none is provided, the backend will generate one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// An optional modifier that provides additional instructions on how the activity should be interpreted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signal: Option<AgentActivitySignal>,
+    /// Metadata about this agent activity's signal.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signal_metadata: Option<JSONObject>,
+}
+
+impl AgentActivityCreateInput {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(agent_session_id: String, content: JSONObject) -> Self {
+        Self {
+            agent_session_id,
+            content,
+            contextual_metadata: None,
+            ephemeral: None,
+            id: None,
+            signal: None,
+            signal_metadata: None,
+        }
+    }
+}
+
+/// \[Internal\] Input for creating prompt-type agent activities (created by users).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AgentActivityCreatePromptInput {
+    /// The agent session this activity belongs to.
+    pub agent_session_id: String,
+    /// The content payload of the prompt agent activity.
+    pub content: JSONObject,
+    /// \[Internal\] Metadata about user-provided contextual information for this agent activity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub contextual_metadata: Option<JSONObject>,
+    /// The identifier in UUID v4 format. If none is provided, the backend will generate one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// An optional modifier that provides additional instructions on how the activity should be interpreted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signal: Option<AgentActivitySignal>,
+    /// Metadata about this agent activity's signal.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signal_metadata: Option<JSONObject>,
+    /// The comment that contains the content of this activity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_comment_id: Option<String>,
+}
+
+impl AgentActivityCreatePromptInput {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(agent_session_id: String, content: JSONObject) -> Self {
+        Self {
+            agent_session_id,
+            content,
+            contextual_metadata: None,
+            id: None,
+            signal: None,
+            signal_metadata: None,
+            source_comment_id: None,
+        }
+    }
+}
+
+/// Agent activity filtering options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AgentActivityFilter {
+    /// Comparator for the agent session ID.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub agent_session_id: Option<Box<StringComparator>>,
+    /// Compound filters, all of which need to be matched by the agent activity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub and: Option<Vec<AgentActivityFilter>>,
+    /// Comparator for the created at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<Box<DateComparator>>,
+    /// Comparator for the identifier.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<Box<IDComparator>>,
+    /// Compound filters, one of which need to be matched by the agent activity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub or: Option<Vec<AgentActivityFilter>>,
+    /// Filters that the source comment must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_comment: Option<Box<NullableCommentFilter>>,
+    /// Comparator for the agent activity's content type.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<Box<StringComparator>>,
+    /// Comparator for the updated at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<Box<DateComparator>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AgentSessionCreateInput {
+    /// The app user (agent) to create a session for.
+    pub app_user_id: String,
+    /// The identifier in UUID v4 format. If none is provided, the backend will generate one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The issue that this session will be associated with. Can be a UUID or issue identifier (e.g., 'LIN-123').
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_id: Option<String>,
+}
+
+impl AgentSessionCreateInput {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(app_user_id: String) -> Self {
+        Self {
+            app_user_id,
+            id: None,
+            issue_id: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AgentSessionCreateOnComment {
+    /// The root comment that this session will be associated with.
+    pub comment_id: String,
+    /// The URL of an external agent-hosted page associated with this session.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub external_link: Option<String>,
+    /// URLs of external resources associated with this session.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub external_urls: Option<Vec<AgentSessionExternalUrlInput>>,
+}
+
+impl AgentSessionCreateOnComment {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(comment_id: String) -> Self {
+        Self {
+            comment_id,
+            external_link: None,
+            external_urls: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AgentSessionCreateOnIssue {
+    /// The URL of an external agent-hosted page associated with this session.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub external_link: Option<String>,
+    /// URLs of external resources associated with this session.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub external_urls: Option<Vec<AgentSessionExternalUrlInput>>,
+    /// The issue that this session will be associated with. Can be a UUID or issue identifier (e.g., 'LIN-123').
+    pub issue_id: String,
+}
+
+impl AgentSessionCreateOnIssue {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(issue_id: String) -> Self {
+        Self {
+            external_link: None,
+            external_urls: None,
+            issue_id,
+        }
+    }
+}
+
+/// Input for an external URL associated with an agent session.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AgentSessionExternalUrlInput {
+    /// Optional label for the URL.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    /// The URL of the external resource.
+    pub url: String,
+}
+
+impl AgentSessionExternalUrlInput {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(url: String) -> Self {
+        Self {
+            label: None,
+            url,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AgentSessionUpdateExternalUrlInput {
+    /// URLs of external resources to be added to this session.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub added_external_urls: Option<Vec<AgentSessionExternalUrlInput>>,
+    /// The URL of an external agent-hosted page associated with this session.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub external_link: Option<String>,
+    /// URLs of external resources associated with this session. Replaces existing URLs.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub external_urls: Option<Vec<AgentSessionExternalUrlInput>>,
+    /// URLs to be removed from this session.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub removed_external_urls: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AgentSessionUpdateInput {
+    /// URLs of external resources to be added to this session. Only updatable by the OAuth application that owns the session.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub added_external_urls: Option<Vec<AgentSessionExternalUrlInput>>,
+    /// \[Internal\] The time the agent session was dismissed. Only updatable by internal clients.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dismissed_at: Option<DateTime>,
+    /// The URL of an external agent-hosted page associated with this session. Only updatable by the OAuth application that owns the session.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub external_link: Option<String>,
+    /// URLs of external resources associated with this session. Replaces existing URLs. Only updatable by the OAuth application that owns the session. If supplied, addedExternalUrls and removedExternalUrls are ignored.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub external_urls: Option<Vec<AgentSessionExternalUrlInput>>,
+    /// A dynamically updated list of the agent's execution strategy. Only updatable by the OAuth application that owns the session.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub plan: Option<JSONObject>,
+    /// URLs to be removed from this session. Only updatable by the OAuth application that owns the session.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub removed_external_urls: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AirbyteConfigurationInput {
+    /// Linear export API key.
+    pub api_key: String,
+}
+
+impl AirbyteConfigurationInput {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(api_key: String) -> Self {
+        Self {
+            api_key,
+        }
+    }
+}
+
+/// Customer approximate need count sorting options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApproximateNeedCountSort {
+    /// Whether nulls should be sorted first or last
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nulls: Option<PaginationNulls>,
+    /// The order for the individual sort
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order: Option<PaginationSortOrder>,
+}
+
+/// Issue assignee sorting options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AssigneeSort {
+    /// Whether nulls should be sorted first or last
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nulls: Option<PaginationNulls>,
+    /// The order for the individual sort
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order: Option<PaginationSortOrder>,
+}
+
+/// Attachment collection filtering options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AttachmentCollectionFilter {
+    /// Compound filters, all of which need to be matched by the attachment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub and: Option<Vec<AttachmentCollectionFilter>>,
+    /// Comparator for the created at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<Box<DateComparator>>,
+    /// Filters that the attachments creator must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub creator: Option<Box<NullableUserFilter>>,
+    /// Filters that needs to be matched by all attachments.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub every: Option<Box<AttachmentFilter>>,
+    /// Comparator for the identifier.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<Box<IDComparator>>,
+    /// Comparator for the collection length.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub length: Option<Box<NumberComparator>>,
+    /// Compound filters, one of which need to be matched by the attachment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub or: Option<Vec<AttachmentCollectionFilter>>,
+    /// Filters that needs to be matched by some attachments.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub some: Option<Box<AttachmentFilter>>,
+    /// Comparator for the source type.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_type: Option<Box<SourceTypeComparator>>,
+    /// Comparator for the subtitle.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subtitle: Option<Box<NullableStringComparator>>,
+    /// Comparator for the title.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<Box<StringComparator>>,
+    /// Comparator for the updated at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<Box<DateComparator>>,
+    /// Comparator for the url.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<Box<StringComparator>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AttachmentCreateInput {
+    /// Create a linked comment with markdown body.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment_body: Option<String>,
+    /// \[Internal\] Create a linked comment with Prosemirror body. Please use `commentBody` instead.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment_body_data: Option<JSONObject>,
+    /// Create attachment as a user with the provided name. This option is only available to OAuth applications creating attachments in `actor=application` mode.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub create_as_user: Option<String>,
+    /// Indicates if attachments for the same source application should be grouped in the Linear UI.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub group_by_source: Option<bool>,
+    /// An icon url to display with the attachment. Should be of jpg or png format. Maximum of 1MB in size. Dimensions should be 20x20px for optimal display quality.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub icon_url: Option<String>,
+    /// The identifier in UUID v4 format. If none is provided, the backend will generate one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The issue to associate the attachment with. Can be a UUID or issue identifier (e.g., 'LIN-123').
+    pub issue_id: String,
+    /// Attachment metadata object with string and number values.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<JSONObject>,
+    /// The attachment subtitle.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subtitle: Option<String>,
+    /// The attachment title.
+    pub title: String,
+    /// Attachment location which is also used as an unique identifier for the attachment. If another attachment is created with the same `url` value, existing record is updated instead.
+    pub url: String,
+}
+
+impl AttachmentCreateInput {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(issue_id: String, title: String, url: String) -> Self {
+        Self {
+            comment_body: None,
+            comment_body_data: None,
+            create_as_user: None,
+            group_by_source: None,
+            icon_url: None,
+            id: None,
+            issue_id,
+            metadata: None,
+            subtitle: None,
+            title,
+            url,
+        }
+    }
+}
+
+/// Attachment filtering options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AttachmentFilter {
+    /// Compound filters, all of which need to be matched by the attachment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub and: Option<Vec<AttachmentFilter>>,
+    /// Comparator for the created at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<Box<DateComparator>>,
+    /// Filters that the attachments creator must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub creator: Option<Box<NullableUserFilter>>,
+    /// Comparator for the identifier.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<Box<IDComparator>>,
+    /// Compound filters, one of which need to be matched by the attachment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub or: Option<Vec<AttachmentFilter>>,
+    /// Comparator for the source type.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_type: Option<Box<SourceTypeComparator>>,
+    /// Comparator for the subtitle.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subtitle: Option<Box<NullableStringComparator>>,
+    /// Comparator for the title.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<Box<StringComparator>>,
+    /// Comparator for the updated at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<Box<DateComparator>>,
+    /// Comparator for the url.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<Box<StringComparator>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AttachmentUpdateInput {
+    /// An icon url to display with the attachment. Should be of jpg or png format. Maximum of 1MB in size. Dimensions should be 20x20px for optimal display quality.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub icon_url: Option<String>,
+    /// Attachment metadata object with string and number values.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<JSONObject>,
+    /// The attachment subtitle.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subtitle: Option<String>,
+    /// The attachment title.
+    pub title: String,
+}
+
+impl AttachmentUpdateInput {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(title: String) -> Self {
+        Self {
+            icon_url: None,
+            metadata: None,
+            subtitle: None,
+            title,
+        }
+    }
+}
+
+/// Audit entry filtering options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditEntryFilter {
+    /// Filters that the audit entry actor must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor: Option<Box<NullableUserFilter>>,
+    /// Compound filters, all of which need to be matched by the issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub and: Option<Vec<AuditEntryFilter>>,
+    /// Comparator for the country code.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub country_code: Option<Box<StringComparator>>,
+    /// Comparator for the created at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<Box<DateComparator>>,
+    /// Comparator for the identifier.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<Box<IDComparator>>,
+    /// Comparator for the IP address.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ip: Option<Box<StringComparator>>,
+    /// Compound filters, one of which need to be matched by the issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub or: Option<Vec<AuditEntryFilter>>,
+    /// Comparator for the type.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<Box<StringComparator>>,
+    /// Comparator for the updated at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<Box<DateComparator>>,
+}
+
+/// Comparator for booleans.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BooleanComparator {
+    /// Equals constraint.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub eq: Option<bool>,
+    /// Not equals constraint.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub neq: Option<bool>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CandidateRepository {
+    /// Hostname of the Git service (e.g., 'github.com', 'github.company.com').
+    pub hostname: String,
+    /// The full name of the repository in owner/name format (e.g., 'acme/backend').
+    pub repository_full_name: String,
+}
+
+impl CandidateRepository {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(hostname: String, repository_full_name: String) -> Self {
+        Self {
+            hostname,
+            repository_full_name,
+        }
+    }
+}
+
+/// Comment filtering options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommentCollectionFilter {
+    /// Compound filters, all of which need to be matched by the comment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub and: Option<Vec<CommentCollectionFilter>>,
+    /// Comparator for the comment's body.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<Box<StringComparator>>,
+    /// Comparator for the created at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<Box<DateComparator>>,
+    /// Filters that the comment's document content must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub document_content: Option<Box<NullableDocumentContentFilter>>,
+    /// Filters that needs to be matched by all comments.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub every: Option<Box<CommentFilter>>,
+    /// Comparator for the identifier.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<Box<IDComparator>>,
+    /// Filters that the comment's issue must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue: Option<Box<NullableIssueFilter>>,
+    /// Comparator for the collection length.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub length: Option<Box<NumberComparator>>,
+    /// Filters that the comment's customer needs must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub needs: Option<Box<CustomerNeedCollectionFilter>>,
+    /// Compound filters, one of which need to be matched by the comment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub or: Option<Vec<CommentCollectionFilter>>,
+    /// Filters that the comment parent must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent: Option<Box<NullableCommentFilter>>,
+    /// Filters that the comment's project update must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_update: Option<Box<NullableProjectUpdateFilter>>,
+    /// Filters that the comment's reactions must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reactions: Option<Box<ReactionCollectionFilter>>,
+    /// Filters that needs to be matched by some comments.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub some: Option<Box<CommentFilter>>,
+    /// Comparator for the updated at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<Box<DateComparator>>,
+    /// Filters that the comment's creator must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<Box<UserFilter>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommentCreateInput {
+    /// The comment content in markdown format.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    /// \[Internal\] The comment content as a Prosemirror document.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body_data: Option<JSON>,
+    /// Create comment as a user with the provided name. This option is only available to OAuth applications creating comments in `actor=app` mode.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub create_as_user: Option<String>,
+    /// Flag to indicate this comment should be created on the issue's synced Slack comment thread. If no synced Slack comment thread exists, the mutation will fail.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub create_on_synced_slack_thread: Option<bool>,
+    /// The date when the comment was created (e.g. if importing from another system). Must be a date in the past. If none is provided, the backend will generate the time as now.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// Provide an external user avatar URL. Can only be used in conjunction with the `createAsUser` options. This option is only available to OAuth applications creating comments in `actor=app` mode.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub display_icon_url: Option<String>,
+    /// Flag to prevent auto subscription to the issue the comment is created on.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub do_not_subscribe_to_issue: Option<bool>,
+    /// The document content to associate the comment with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub document_content_id: Option<String>,
+    /// The identifier in UUID v4 format. If none is provided, the backend will generate one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The initiative update to associate the comment with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative_update_id: Option<String>,
+    /// The issue to associate the comment with. Can be a UUID or issue identifier (e.g., 'LIN-123').
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_id: Option<String>,
+    /// The parent comment under which to nest a current comment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_id: Option<String>,
+    /// The post to associate the comment with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub post_id: Option<String>,
+    /// The project update to associate the comment with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_update_id: Option<String>,
+    /// The text that this comment references. Only defined for inline comments.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub quoted_text: Option<String>,
+    /// \[INTERNAL\] The identifiers of the users subscribing to this comment thread.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subscriber_ids: Option<Vec<String>>,
+}
+
+/// Comment filtering options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommentFilter {
+    /// Compound filters, all of which need to be matched by the comment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub and: Option<Vec<CommentFilter>>,
+    /// Comparator for the comment's body.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<Box<StringComparator>>,
+    /// Comparator for the created at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<Box<DateComparator>>,
+    /// Filters that the comment's document content must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub document_content: Option<Box<NullableDocumentContentFilter>>,
+    /// Comparator for the identifier.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<Box<IDComparator>>,
+    /// Filters that the comment's issue must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue: Option<Box<NullableIssueFilter>>,
+    /// Filters that the comment's customer needs must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub needs: Option<Box<CustomerNeedCollectionFilter>>,
+    /// Compound filters, one of which need to be matched by the comment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub or: Option<Vec<CommentFilter>>,
+    /// Filters that the comment parent must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent: Option<Box<NullableCommentFilter>>,
+    /// Filters that the comment's project update must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_update: Option<Box<NullableProjectUpdateFilter>>,
+    /// Filters that the comment's reactions must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reactions: Option<Box<ReactionCollectionFilter>>,
+    /// Comparator for the updated at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<Box<DateComparator>>,
+    /// Filters that the comment's creator must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<Box<UserFilter>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommentUpdateInput {
+    /// The comment content.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    /// \[Internal\] The comment content as a Prosemirror document.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body_data: Option<JSON>,
+    /// \[INTERNAL\] Flag to prevent auto subscription to the issue the comment is updated on.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub do_not_subscribe_to_issue: Option<bool>,
+    /// The text that this comment references. Only defined for inline comments.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub quoted_text: Option<String>,
+    /// \[INTERNAL\] The child comment that resolves this thread.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resolving_comment_id: Option<String>,
+    /// \[INTERNAL\] The user who resolved this thread.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resolving_user_id: Option<String>,
+    /// \[INTERNAL\] The identifiers of the users subscribing to this comment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subscriber_ids: Option<Vec<String>>,
+}
+
+/// Issue completion date sorting options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CompletedAtSort {
+    /// Whether nulls should be sorted first or last
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nulls: Option<PaginationNulls>,
+    /// The order for the individual sort
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order: Option<PaginationSortOrder>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContactCreateInput {
+    /// User's browser information.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub browser: Option<String>,
+    /// User's Linear client information.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_version: Option<String>,
+    /// User's device information.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub device: Option<String>,
+    /// How disappointed the user would be if they could no longer use Linear.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub disappointment_rating: Option<i64>,
+    /// The message the user sent.
+    pub message: String,
+    /// User's operating system.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub operating_system: Option<String>,
+    /// The type of support contact.
+    pub r#type: String,
+}
+
+impl ContactCreateInput {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(message: String, r#type: String) -> Self {
+        Self {
+            browser: None,
+            client_version: None,
+            device: None,
+            disappointment_rating: None,
+            message,
+            operating_system: None,
+            r#type,
+        }
+    }
+}
+
+/// \[INTERNAL\] Input for sending a message to the Linear Sales team.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContactSalesCreateInput {
+    /// Size of the company.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub company_size: Option<String>,
+    /// PostHog distinct ID for analytics correlation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub distinct_id: Option<String>,
+    /// Work email of the person requesting information.
+    pub email: String,
+    /// The message the user sent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    /// Name of the person requesting information.
+    pub name: String,
+    /// The URL this request was sent from.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+impl ContactSalesCreateInput {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(email: String, name: String) -> Self {
+        Self {
+            company_size: None,
+            distinct_id: None,
+            email,
+            message: None,
+            name,
+            url: None,
+        }
+    }
+}
+
+/// \[Internal\] Comparator for content.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContentComparator {
+    /// \[Internal\] Contains constraint.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub contains: Option<String>,
+    /// \[Internal\] Not-contains constraint.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub not_contains: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateOrganizationInput {
+    /// Whether the organization should allow email domain access.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub domain_access: Option<bool>,
+    /// The name of the organization.
+    pub name: String,
+    /// The timezone of the organization, passed in by client.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+    /// The URL key of the organization.
+    pub url_key: String,
+    /// JSON serialized UTM parameters associated with the creation of the workspace.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub utm: Option<String>,
+}
+
+impl CreateOrganizationInput {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(name: String, url_key: String) -> Self {
+        Self {
+            domain_access: None,
+            name,
+            timezone: None,
+            url_key,
+            utm: None,
+        }
+    }
+}
+
+/// Issue creation date sorting options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreatedAtSort {
+    /// Whether nulls should be sorted first or last
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nulls: Option<PaginationNulls>,
+    /// The order for the individual sort
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order: Option<PaginationSortOrder>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomViewCreateInput {
+    /// The color of the icon of the custom view.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    /// The description of the custom view.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The feed item filter applied to issues in the custom view.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub feed_item_filter_data: Option<Box<FeedItemFilter>>,
+    /// The filter applied to issues in the custom view.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filter_data: Option<Box<IssueFilter>>,
+    /// The icon of the custom view.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub icon: Option<String>,
+    /// The identifier in UUID v4 format. If none is provided, the backend will generate one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// \[ALPHA\] The initiative filter applied to issues in the custom view.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative_filter_data: Option<Box<InitiativeFilter>>,
+    /// The id of the initiative associated with the custom view.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative_id: Option<String>,
+    /// The name of the custom view.
+    pub name: String,
+    /// The owner of the custom view.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner_id: Option<String>,
+    /// The project filter applied to issues in the custom view.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_filter_data: Option<Box<ProjectFilter>>,
+    /// The id of the project associated with the custom view.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_id: Option<String>,
+    /// Whether the custom view is shared with everyone in the organization.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shared: Option<bool>,
+    /// The id of the team associated with the custom view.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team_id: Option<String>,
+}
+
+impl CustomViewCreateInput {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(name: String) -> Self {
+        Self {
+            color: None,
+            description: None,
+            feed_item_filter_data: None,
+            filter_data: None,
+            icon: None,
+            id: None,
+            initiative_filter_data: None,
+            initiative_id: None,
+            name,
+            owner_id: None,
+            project_filter_data: None,
+            project_id: None,
+            shared: None,
+            team_id: None,
+        }
+    }
+}
+
+/// Custom view creation date sorting options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomViewCreatedAtSort {
+    /// Whether nulls should be sorted first or last
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nulls: Option<PaginationNulls>,
+    /// The order for the individual sort
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order: Option<PaginationSortOrder>,
+}
+
+/// Custom view filtering options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomViewFilter {
+    /// Compound filters, all of which need to be matched by the custom view.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub and: Option<Vec<CustomViewFilter>>,
+    /// Comparator for the created at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<Box<DateComparator>>,
+    /// Filters that the custom view creator must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub creator: Option<Box<UserFilter>>,
+    /// \[INTERNAL\] Filter based on whether the custom view has a facet.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_facet: Option<bool>,
+    /// Comparator for the identifier.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<Box<IDComparator>>,
+    /// Comparator for the custom view model name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub model_name: Option<Box<StringComparator>>,
+    /// Comparator for the custom view name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<Box<StringComparator>>,
+    /// Compound filters, one of which need to be matched by the custom view.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub or: Option<Vec<CustomViewFilter>>,
+    /// Comparator for whether the custom view is shared.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shared: Option<Box<BooleanComparator>>,
+    /// Filters that the custom view's team must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team: Option<Box<NullableTeamFilter>>,
+    /// Comparator for the updated at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<Box<DateComparator>>,
+}
+
+/// Custom view name sorting options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomViewNameSort {
+    /// Whether nulls should be sorted first or last
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nulls: Option<PaginationNulls>,
+    /// The order for the individual sort
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order: Option<PaginationSortOrder>,
+}
+
+/// Custom view shared status sorting options. Ascending order puts shared views last.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomViewSharedSort {
+    /// Whether nulls should be sorted first or last
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nulls: Option<PaginationNulls>,
+    /// The order for the individual sort
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order: Option<PaginationSortOrder>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomViewSortInput {
+    /// Sort by custom view creation date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<Box<CustomViewCreatedAtSort>>,
+    /// Sort by custom view name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<Box<CustomViewNameSort>>,
+    /// Sort by custom view shared status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shared: Option<Box<CustomViewSharedSort>>,
+    /// Sort by custom view update date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<Box<CustomViewUpdatedAtSort>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomViewUpdateInput {
+    /// The color of the icon of the custom view.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    /// The description of the custom view.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The feed item filter applied to issues in the custom view.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub feed_item_filter_data: Option<Box<FeedItemFilter>>,
+    /// The filter applied to issues in the custom view.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filter_data: Option<Box<IssueFilter>>,
+    /// The icon of the custom view.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub icon: Option<String>,
+    /// \[ALPHA\] The initiative filter applied to issues in the custom view.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative_filter_data: Option<Box<InitiativeFilter>>,
+    /// \[Internal\] The id of the initiative associated with the custom view.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative_id: Option<String>,
+    /// The name of the custom view.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The owner of the custom view.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner_id: Option<String>,
+    /// The project filter applied to issues in the custom view.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_filter_data: Option<Box<ProjectFilter>>,
+    /// \[Internal\] The id of the project associated with the custom view.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_id: Option<String>,
+    /// Whether the custom view is shared with everyone in the organization.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shared: Option<bool>,
+    /// The id of the team associated with the custom view.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team_id: Option<String>,
+}
+
+/// Custom view update date sorting options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomViewUpdatedAtSort {
+    /// Whether nulls should be sorted first or last
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nulls: Option<PaginationNulls>,
+    /// The order for the individual sort
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order: Option<PaginationSortOrder>,
+}
+
+/// Issue customer count sorting options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomerCountSort {
+    /// Whether nulls should be sorted first or last
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nulls: Option<PaginationNulls>,
+    /// The order for the individual sort
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order: Option<PaginationSortOrder>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomerCreateInput {
+    /// The domains associated with this customer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub domains: Option<Vec<String>>,
+    /// The ids of the customers in external systems.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub external_ids: Option<Vec<String>>,
+    /// The identifier in UUID v4 format. If none is provided, the backend will generate one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The URL of the customer's logo.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub logo_url: Option<String>,
+    /// The main source of the customer, for customers with multiple sources. Must be one of externalIds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub main_source_id: Option<String>,
+    /// The name of the customer.
+    pub name: String,
+    /// The user who owns the customer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner_id: Option<String>,
+    /// The annual revenue generated by the customer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub revenue: Option<i64>,
+    /// The size of the customer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub size: Option<i64>,
+    /// The ID of the Slack channel used to interact with the customer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slack_channel_id: Option<String>,
+    /// The status of the customer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status_id: Option<String>,
+    /// The tier of the customer customer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tier_id: Option<String>,
+}
+
+impl CustomerCreateInput {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(name: String) -> Self {
+        Self {
+            domains: None,
+            external_ids: None,
+            id: None,
+            logo_url: None,
+            main_source_id: None,
+            name,
+            owner_id: None,
+            revenue: None,
+            size: None,
+            slack_channel_id: None,
+            status_id: None,
+            tier_id: None,
+        }
+    }
+}
+
+/// Customer creation date sorting options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomerCreatedAtSort {
+    /// Whether nulls should be sorted first or last
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nulls: Option<PaginationNulls>,
+    /// The order for the individual sort
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order: Option<PaginationSortOrder>,
+}
+
+/// Customer filtering options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomerFilter {
+    /// Compound filters, all of which need to be matched by the customer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub and: Option<Vec<CustomerFilter>>,
+    /// Comparator for the created at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<Box<DateComparator>>,
+    /// Comparator for the customer's domains.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub domains: Option<Box<StringArrayComparator>>,
+    /// Comparator for the customer's external IDs.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub external_ids: Option<Box<StringArrayComparator>>,
+    /// Comparator for the identifier.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<Box<IDComparator>>,
+    /// Comparator for the customer name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<Box<StringComparator>>,
+    /// Filters that the customer's needs must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub needs: Option<Box<CustomerNeedCollectionFilter>>,
+    /// Compound filters, one of which need to be matched by the customer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub or: Option<Vec<CustomerFilter>>,
+    /// Filters that the customer owner must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner: Option<Box<NullableUserFilter>>,
+    /// Comparator for the customer generated revenue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub revenue: Option<Box<NumberComparator>>,
+    /// Comparator for the customer size.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub size: Option<Box<NumberComparator>>,
+    /// Comparator for the customer slack channel ID.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slack_channel_id: Option<Box<StringComparator>>,
+    /// Filters that the customer's status must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<Box<CustomerStatusFilter>>,
+    /// Filters that the customer's tier must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tier: Option<Box<CustomerTierFilter>>,
+    /// Comparator for the updated at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<Box<DateComparator>>,
+}
+
+/// Issue customer important count sorting options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomerImportantCountSort {
+    /// Whether nulls should be sorted first or last
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nulls: Option<PaginationNulls>,
+    /// The order for the individual sort
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order: Option<PaginationSortOrder>,
+}
+
+/// Customer needs filtering options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomerNeedCollectionFilter {
+    /// Compound filters, all of which need to be matched by the customer needs.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub and: Option<Vec<CustomerNeedCollectionFilter>>,
+    /// Filters that the need's comment must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<Box<NullableCommentFilter>>,
+    /// Comparator for the created at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<Box<DateComparator>>,
+    /// Filters that the need's customer must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer: Option<Box<NullableCustomerFilter>>,
+    /// Filters that needs to be matched by all customer needs.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub every: Option<Box<CustomerNeedFilter>>,
+    /// Comparator for the identifier.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<Box<IDComparator>>,
+    /// Filters that the need's issue must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue: Option<Box<NullableIssueFilter>>,
+    /// Comparator for the collection length.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub length: Option<Box<NumberComparator>>,
+    /// Compound filters, one of which need to be matched by the customer needs.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub or: Option<Vec<CustomerNeedCollectionFilter>>,
+    /// Comparator for the customer need priority.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority: Option<Box<NumberComparator>>,
+    /// Filters that the need's project must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project: Option<Box<NullableProjectFilter>>,
+    /// Filters that needs to be matched by some customer needs.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub some: Option<Box<CustomerNeedFilter>>,
+    /// Comparator for the updated at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<Box<DateComparator>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomerNeedCreateFromAttachmentInput {
+    /// The attachment this need is created from.
+    pub attachment_id: String,
+}
+
+impl CustomerNeedCreateFromAttachmentInput {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(attachment_id: String) -> Self {
+        Self {
+            attachment_id,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomerNeedCreateInput {
+    /// The attachment this need is referencing.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub attachment_id: Option<String>,
+    /// Optional URL for the attachment associated with the customer need.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub attachment_url: Option<String>,
+    /// The content of the need in markdown format.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    /// \[Internal\] The content of the need as a Prosemirror document.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body_data: Option<JSON>,
+    /// The comment this need is referencing.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment_id: Option<String>,
+    /// Create need as a user with the provided name. This option is only available to OAuth applications creating needs in `actor=app` mode.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub create_as_user: Option<String>,
+    /// The external ID of the customer the need belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer_external_id: Option<String>,
+    /// The uuid of the customer the need belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer_id: Option<String>,
+    /// Provide an external user avatar URL. Can only be used in conjunction with the `createAsUser` options. This option is only available to OAuth applications creating needs in `actor=app` mode.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub display_icon_url: Option<String>,
+    /// The identifier in UUID v4 format. If none is provided, the backend will generate one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The issue this need is referencing. Can be a UUID or issue identifier (e.g., 'LIN-123').
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_id: Option<String>,
+    /// Whether the customer need is important or not. 0 = Not important, 1 = Important.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority: Option<f64>,
+    /// \[INTERNAL\] The project this need is referencing.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_id: Option<String>,
+}
+
+/// Customer filtering options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomerNeedFilter {
+    /// Compound filters, all of which need to be matched by the customer need.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub and: Option<Vec<CustomerNeedFilter>>,
+    /// Filters that the need's comment must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<Box<NullableCommentFilter>>,
+    /// Comparator for the created at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<Box<DateComparator>>,
+    /// Filters that the need's customer must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer: Option<Box<NullableCustomerFilter>>,
+    /// Comparator for the identifier.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<Box<IDComparator>>,
+    /// Filters that the need's issue must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue: Option<Box<NullableIssueFilter>>,
+    /// Compound filters, one of which need to be matched by the customer need.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub or: Option<Vec<CustomerNeedFilter>>,
+    /// Comparator for the customer need priority.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority: Option<Box<NumberComparator>>,
+    /// Filters that the need's project must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project: Option<Box<NullableProjectFilter>>,
+    /// Comparator for the updated at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<Box<DateComparator>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomerNeedUpdateInput {
+    /// Whether to also update the priority of needs from the same customer on the same issue/project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub apply_priority_to_related_needs: Option<bool>,
+    /// Optional URL for the attachment associated with the customer need.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub attachment_url: Option<String>,
+    /// The content of the need in markdown format.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    /// \[Internal\] The content of the need as a Prosemirror document.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body_data: Option<JSON>,
+    /// The external ID of the customer the need belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer_external_id: Option<String>,
+    /// The uuid of the customer the need belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer_id: Option<String>,
+    /// The identifier in UUID v4 format. If none is provided, the backend will generate one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The issue this need is referencing. Can be a UUID or issue identifier (e.g., 'LIN-123').
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_id: Option<String>,
+    /// Whether the customer need is important or not. 0 = Not important, 1 = Important.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority: Option<f64>,
+    /// \[INTERNAL\] The project this need is referencing.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_id: Option<String>,
+}
+
+/// Issue customer revenue sorting options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomerRevenueSort {
+    /// Whether nulls should be sorted first or last
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nulls: Option<PaginationNulls>,
+    /// The order for the individual sort
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order: Option<PaginationSortOrder>,
+}
+
+/// Issue customer sorting options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomerSort {
+    /// Whether nulls should be sorted first or last
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nulls: Option<PaginationNulls>,
+    /// The order for the individual sort
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order: Option<PaginationSortOrder>,
+}
+
+/// Customer sorting options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomerSortInput {
+    /// Sort by approximate customer need count
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub approximate_need_count: Option<Box<ApproximateNeedCountSort>>,
+    /// Sort by customer creation date
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<Box<CustomerCreatedAtSort>>,
+    /// Sort by name
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<Box<NameSort>>,
+    /// Sort by owner name
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner: Option<Box<OwnerSort>>,
+    /// Sort by customer generated revenue
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub revenue: Option<Box<RevenueSort>>,
+    /// Sort by customer size
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub size: Option<Box<SizeSort>>,
+    /// Sort by customer status
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<Box<CustomerStatusSort>>,
+    /// Sort by customer tier
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tier: Option<Box<TierSort>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomerStatusCreateInput {
+    /// The UI color of the status as a HEX string.
+    pub color: String,
+    /// Description of the status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The display name of the status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    /// The identifier in UUID v4 format. If none is provided, the backend will generate one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The name of the status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The position of the status in the workspace's customer flow.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub position: Option<f64>,
+}
+
+impl CustomerStatusCreateInput {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(color: String) -> Self {
+        Self {
+            color,
+            description: None,
+            display_name: None,
+            id: None,
+            name: None,
+            position: None,
+        }
+    }
+}
+
+/// Customer status filtering options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomerStatusFilter {
+    /// Compound filters, all of which need to be matched by the customer status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub and: Option<Vec<CustomerStatusFilter>>,
+    /// Comparator for the customer status color.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<Box<StringComparator>>,
+    /// Comparator for the created at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<Box<DateComparator>>,
+    /// Comparator for the customer status description.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<Box<StringComparator>>,
+    /// Comparator for the identifier.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<Box<IDComparator>>,
+    /// Comparator for the customer status name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<Box<StringComparator>>,
+    /// Compound filters, one of which needs to be matched by the customer status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub or: Option<Vec<CustomerStatusFilter>>,
+    /// Comparator for the customer status position.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub position: Option<Box<NumberComparator>>,
+    /// Comparator for the customer status type.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<Box<StringComparator>>,
+    /// Comparator for the updated at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<Box<DateComparator>>,
+}
+
+/// Customer status sorting options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomerStatusSort {
+    /// Whether nulls should be sorted first or last
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nulls: Option<PaginationNulls>,
+    /// The order for the individual sort
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order: Option<PaginationSortOrder>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomerStatusUpdateInput {
+    /// The UI color of the status as a HEX string.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    /// Description of the status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The display name of the status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    /// The name of the status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The position of the status in the workspace's customer flow.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub position: Option<f64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomerTierCreateInput {
+    /// The UI color of the tier as a HEX string.
+    pub color: String,
+    /// Description of the tier.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The display name of the tier.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    /// The identifier in UUID v4 format. If none is provided, the backend will generate one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The name of the tier.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The position of the tier in the workspace's customer flow.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub position: Option<f64>,
+}
+
+impl CustomerTierCreateInput {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(color: String) -> Self {
+        Self {
+            color,
+            description: None,
+            display_name: None,
+            id: None,
+            name: None,
+            position: None,
+        }
+    }
+}
+
+/// Customer tier filtering options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomerTierFilter {
+    /// Compound filters, all of which need to be matched by the customer tier.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub and: Option<Vec<CustomerTierFilter>>,
+    /// Comparator for the customer tier color.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<Box<StringComparator>>,
+    /// Comparator for the created at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<Box<DateComparator>>,
+    /// Comparator for the customer tier description.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<Box<StringComparator>>,
+    /// Comparator for the customer tier display name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<Box<StringComparator>>,
+    /// Comparator for the identifier.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<Box<IDComparator>>,
+    /// Compound filters, one of which needs to be matched by the customer tier.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub or: Option<Vec<CustomerTierFilter>>,
+    /// Comparator for the customer tier position.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub position: Option<Box<NumberComparator>>,
+    /// Comparator for the updated at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<Box<DateComparator>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomerTierUpdateInput {
+    /// The UI color of the tier as a HEX string.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    /// Description of the tier.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The display name of the tier.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    /// The name of the tier.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The position of the tier in the workspace's customer flow.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub position: Option<f64>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomerUpdateInput {
+    /// The domains associated with this customer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub domains: Option<Vec<String>>,
+    /// The ids of the customers in external systems.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub external_ids: Option<Vec<String>>,
+    /// The URL of the customer's logo.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub logo_url: Option<String>,
+    /// The main source of the customer, for customers with multiple sources. Must be one of externalIds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub main_source_id: Option<String>,
+    /// The name of the customer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The user who owns the customer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner_id: Option<String>,
+    /// The annual revenue generated by the customer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub revenue: Option<i64>,
+    /// The size of the customer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub size: Option<i64>,
+    /// The ID of the Slack channel used to interact with the customer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slack_channel_id: Option<String>,
+    /// The status of the customer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status_id: Option<String>,
+    /// The tier of the customer customer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tier_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomerUpsertInput {
+    /// The domains associated with this customer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub domains: Option<Vec<String>>,
+    /// The id of the customers in external systems.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub external_id: Option<String>,
+    /// The identifier in UUID v4 format.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The URL of the customer's logo.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub logo_url: Option<String>,
+    /// The name of the customer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The user who owns the customer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner_id: Option<String>,
+    /// The annual revenue generated by the customer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub revenue: Option<i64>,
+    /// The size of the customer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub size: Option<i64>,
+    /// The ID of the Slack channel used to interact with the customer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slack_channel_id: Option<String>,
+    /// The status of the customer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status_id: Option<String>,
+    /// The tier of the customer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tier_id: Option<String>,
+    /// The name tier of the customer. Will be created if doesn't exist
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tier_name: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CycleCreateInput {
+    /// The completion time of the cycle. If null, the cycle hasn't been completed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub completed_at: Option<DateTime>,
+    /// The description of the cycle.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The end date of the cycle.
+    pub ends_at: DateTime,
+    /// The identifier in UUID v4 format. If none is provided, the backend will generate one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The custom name of the cycle.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The start date of the cycle.
+    pub starts_at: DateTime,
+    /// The team to associate the cycle with.
+    pub team_id: String,
+}
+
+impl CycleCreateInput {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(ends_at: DateTime, starts_at: DateTime, team_id: String) -> Self {
+        Self {
+            completed_at: None,
+            description: None,
+            ends_at,
+            id: None,
+            name: None,
+            starts_at,
+            team_id,
+        }
+    }
+}
+
+/// Cycle filtering options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CycleFilter {
+    /// Compound filters, all of which need to be matched by the cycle.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub and: Option<Vec<CycleFilter>>,
+    /// Comparator for the cycle completed at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub completed_at: Option<Box<DateComparator>>,
+    /// Comparator for the created at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<Box<DateComparator>>,
+    /// Comparator for the cycle ends at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ends_at: Option<Box<DateComparator>>,
+    /// Comparator for the identifier.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<Box<IDComparator>>,
+    /// Comparator for the inherited cycle ID.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inherited_from_id: Option<Box<IDComparator>>,
+    /// Comparator for the filtering active cycle.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_active: Option<Box<BooleanComparator>>,
+    /// Comparator for the filtering future cycles.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_future: Option<Box<BooleanComparator>>,
+    /// Comparator for filtering for whether the cycle is currently in cooldown.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_in_cooldown: Option<Box<BooleanComparator>>,
+    /// Comparator for the filtering next cycle.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_next: Option<Box<BooleanComparator>>,
+    /// Comparator for the filtering past cycles.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_past: Option<Box<BooleanComparator>>,
+    /// Comparator for the filtering previous cycle.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_previous: Option<Box<BooleanComparator>>,
+    /// Filters that the cycles issues must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issues: Option<Box<IssueCollectionFilter>>,
+    /// Comparator for the cycle name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<Box<StringComparator>>,
+    /// Comparator for the cycle number.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub number: Option<Box<NumberComparator>>,
+    /// Compound filters, one of which need to be matched by the cycle.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub or: Option<Vec<CycleFilter>>,
+    /// Comparator for the cycle start date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub starts_at: Option<Box<DateComparator>>,
+    /// Filters that the cycles team must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team: Option<Box<TeamFilter>>,
+    /// Comparator for the updated at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<Box<DateComparator>>,
+}
+
+/// Comparator for period when issue was added to a cycle.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CyclePeriodComparator {
+    /// Equals constraint.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub eq: Option<CyclePeriod>,
+    /// In-array constraint.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#in: Option<Vec<CyclePeriod>>,
+    /// Not-equals constraint.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub neq: Option<CyclePeriod>,
+    /// Not-in-array constraint.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nin: Option<Vec<CyclePeriod>>,
+    /// Null constraint. Matches any non-null values if the given value is false, otherwise it matches null values.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub null: Option<bool>,
+}
+
+/// Input for shifting all cycles from a certain cycle onwards by a certain number of days
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CycleShiftAllInput {
+    /// The number of days to shift the cycles by.
+    pub days_to_shift: f64,
+    /// The cycle ID at which to start the shift.
+    pub id: String,
+}
+
+impl CycleShiftAllInput {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(days_to_shift: f64, id: String) -> Self {
+        Self {
+            days_to_shift,
+            id,
+        }
+    }
+}
+
+/// Issue cycle sorting options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CycleSort {
+    /// When set to true, cycles will be ordered with a custom order. Current cycle comes first, followed by upcoming cycles in ASC order, followed by previous cycles in DESC order.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub current_cycle_first: Option<bool>,
+    /// Whether nulls should be sorted first or last
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nulls: Option<PaginationNulls>,
+    /// The order for the individual sort
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order: Option<PaginationSortOrder>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CycleUpdateInput {
+    /// The end date of the cycle.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub completed_at: Option<DateTime>,
+    /// The description of the cycle.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The end date of the cycle.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ends_at: Option<DateTime>,
+    /// The custom name of the cycle.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The start date of the cycle.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub starts_at: Option<DateTime>,
+}
+
+/// Comparator for dates.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DateComparator {
+    /// Equals constraint.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub eq: Option<DateTimeOrDuration>,
+    /// Greater-than constraint. Matches any values that are greater than the given value.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gt: Option<DateTimeOrDuration>,
+    /// Greater-than-or-equal constraint. Matches any values that are greater than or equal to the given value.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gte: Option<DateTimeOrDuration>,
+    /// In-array constraint.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#in: Option<Vec<DateTimeOrDuration>>,
+    /// Less-than constraint. Matches any values that are less than the given value.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lt: Option<DateTimeOrDuration>,
+    /// Less-than-or-equal constraint. Matches any values that are less than or equal to the given value.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lte: Option<DateTimeOrDuration>,
+    /// Not-equals constraint.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub neq: Option<DateTimeOrDuration>,
+    /// Not-in-array constraint.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nin: Option<Vec<DateTimeOrDuration>>,
+}
+
+/// Issue delegate sorting options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DelegateSort {
+    /// Whether nulls should be sorted first or last
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nulls: Option<PaginationNulls>,
+    /// The order for the individual sort
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order: Option<PaginationSortOrder>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeleteOrganizationInput {
+    /// The deletion code to confirm operation.
+    pub deletion_code: String,
+}
+
+impl DeleteOrganizationInput {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(deletion_code: String) -> Self {
+        Self {
+            deletion_code,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DocumentCreateInput {
+    /// The color of the icon.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    /// The document content as markdown.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+    /// The icon of the document.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub icon: Option<String>,
+    /// The identifier in UUID v4 format. If none is provided, the backend will generate one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// \[Internal\] Related initiative for the document.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative_id: Option<String>,
+    /// Related issue for the document. Can be a UUID or issue identifier (e.g., 'LIN-123').
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_id: Option<String>,
+    /// The ID of the last template applied to the document.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_applied_template_id: Option<String>,
+    /// Related project for the document.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_id: Option<String>,
+    /// \[Internal\] The resource folder containing the document.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resource_folder_id: Option<String>,
+    /// The order of the item in the resources list.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort_order: Option<f64>,
+    /// \[INTERNAL\] The identifiers of the users subscribing to this document.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subscriber_ids: Option<Vec<String>>,
+    /// \[Internal\] Related team for the document.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team_id: Option<String>,
+    /// The title of the document.
+    pub title: String,
+}
+
+impl DocumentCreateInput {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(title: String) -> Self {
+        Self {
+            color: None,
+            content: None,
+            icon: None,
+            id: None,
+            initiative_id: None,
+            issue_id: None,
+            last_applied_template_id: None,
+            project_id: None,
+            resource_folder_id: None,
+            sort_order: None,
+            subscriber_ids: None,
+            team_id: None,
+            title,
+        }
+    }
+}
+
+/// Document filtering options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DocumentFilter {
+    /// Compound filters, all of which need to be matched by the document.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub and: Option<Vec<DocumentFilter>>,
+    /// Comparator for the created at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<Box<DateComparator>>,
+    /// Filters that the document's creator must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub creator: Option<Box<UserFilter>>,
+    /// Comparator for the identifier.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<Box<IDComparator>>,
+    /// Filters that the document's initiative must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative: Option<Box<InitiativeFilter>>,
+    /// Filters that the document's issue must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue: Option<Box<IssueFilter>>,
+    /// Compound filters, one of which need to be matched by the document.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub or: Option<Vec<DocumentFilter>>,
+    /// Filters that the document's project must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project: Option<Box<ProjectFilter>>,
+    /// Comparator for the document slug ID.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slug_id: Option<Box<StringComparator>>,
+    /// Comparator for the document title.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<Box<StringComparator>>,
+    /// Comparator for the updated at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<Box<DateComparator>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DocumentUpdateInput {
+    /// The color of the icon.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    /// The document content as markdown.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+    /// The time at which the document was hidden.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hidden_at: Option<DateTime>,
+    /// The icon of the document.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub icon: Option<String>,
+    /// \[Internal\] Related initiative for the document.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative_id: Option<String>,
+    /// Related issue for the document. Can be a UUID or issue identifier (e.g., 'LIN-123').
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_id: Option<String>,
+    /// The ID of the last template applied to the document.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_applied_template_id: Option<String>,
+    /// Related project for the document.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_id: Option<String>,
+    /// \[Internal\] The resource folder containing the document.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resource_folder_id: Option<String>,
+    /// The order of the item in the resources list.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort_order: Option<f64>,
+    /// \[INTERNAL\] The identifiers of the users subscribing to this document.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subscriber_ids: Option<Vec<String>>,
+    /// \[Internal\] Related team for the document.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team_id: Option<String>,
+    /// The title of the document.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Whether the document has been trashed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trashed: Option<bool>,
+}
+
+/// Issue due date sorting options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DueDateSort {
+    /// Whether nulls should be sorted first or last
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nulls: Option<PaginationNulls>,
+    /// The order for the individual sort
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order: Option<PaginationSortOrder>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EmailIntakeAddressCreateInput {
+    /// Whether customer requests are enabled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer_requests_enabled: Option<bool>,
+    /// The email address used to forward emails to the intake address.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub forwarding_email_address: Option<String>,
+    /// The identifier in UUID v4 format. If none is provided, the backend will generate one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The auto-reply message for issue canceled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_canceled_auto_reply: Option<String>,
+    /// Whether the issue canceled auto-reply is enabled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_canceled_auto_reply_enabled: Option<bool>,
+    /// The auto-reply message for issue completed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_completed_auto_reply: Option<String>,
+    /// Whether the issue completed auto-reply is enabled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_completed_auto_reply_enabled: Option<bool>,
+    /// The auto-reply message for issue created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_created_auto_reply: Option<String>,
+    /// Whether the issue created auto-reply is enabled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_created_auto_reply_enabled: Option<bool>,
+    /// Whether email replies are enabled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub replies_enabled: Option<bool>,
+    /// The name to be used for outgoing emails.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sender_name: Option<String>,
+    /// The identifier or key of the team this email address will intake issues for.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team_id: Option<String>,
+    /// The identifier of the template this email address will intake issues for.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub template_id: Option<String>,
+    /// The type of the email address. If not provided, the backend will default to team or template.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<EmailIntakeAddressType>,
+    /// Whether the commenter's name is included in the email replies.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub use_user_names_in_replies: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EmailIntakeAddressUpdateInput {
+    /// Whether customer requests are enabled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer_requests_enabled: Option<bool>,
+    /// Whether the email address is currently enabled. If set to false, the email address will be disabled and no longer accept incoming emails.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    /// The email address used to forward emails to the intake address.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub forwarding_email_address: Option<String>,
+    /// Custom auto-reply message for issue canceled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_canceled_auto_reply: Option<String>,
+    /// Whether the issue canceled auto-reply is enabled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_canceled_auto_reply_enabled: Option<bool>,
+    /// Custom auto-reply message for issue completed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_completed_auto_reply: Option<String>,
+    /// Whether the issue completed auto-reply is enabled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_completed_auto_reply_enabled: Option<bool>,
+    /// The auto-reply message for issue created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_created_auto_reply: Option<String>,
+    /// Whether the issue created auto-reply is enabled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_created_auto_reply_enabled: Option<bool>,
+    /// Whether email replies are enabled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub replies_enabled: Option<bool>,
+    /// The name to be used for outgoing emails.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sender_name: Option<String>,
+    /// The identifier or key of the team this email address will intake issues for.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team_id: Option<String>,
+    /// The identifier of the template this email address will intake issues for.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub template_id: Option<String>,
+    /// Whether the commenter's name is included in the email replies.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub use_user_names_in_replies: Option<bool>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EmailUnsubscribeInput {
+    /// The user's email validation token.
+    pub token: String,
+    /// Email type to unsubscribe from.
+    pub r#type: String,
+    /// The identifier of the user.
+    pub user_id: String,
+}
+
+impl EmailUnsubscribeInput {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(token: String, r#type: String, user_id: String) -> Self {
+        Self {
+            token,
+            r#type,
+            user_id,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EmailUserAccountAuthChallengeInput {
+    /// Auth code for the client initiating the sequence.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_auth_code: Option<String>,
+    /// The email for which to generate the magic login code.
+    pub email: String,
+    /// The organization invite link to associate with this authentication.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub invite_link: Option<String>,
+    /// Whether the login was requested from the desktop app.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_desktop: Option<bool>,
+    /// Whether to only return the login code. This is used by mobile apps to skip showing the login link.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub login_code_only: Option<bool>,
+}
+
+impl EmailUserAccountAuthChallengeInput {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(email: String) -> Self {
+        Self {
+            client_auth_code: None,
+            email,
+            invite_link: None,
+            is_desktop: None,
+            login_code_only: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EmojiCreateInput {
+    /// The identifier in UUID v4 format. If none is provided, the backend will generate one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The name of the custom emoji.
+    pub name: String,
+    /// The URL for the emoji.
+    pub url: String,
+}
+
+impl EmojiCreateInput {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(name: String, url: String) -> Self {
+        Self {
+            id: None,
+            name,
+            url,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EntityExternalLinkCreateInput {
+    /// The identifier in UUID v4 format. If none is provided, the backend will generate one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The initiative associated with the link.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative_id: Option<String>,
+    /// The label for the link.
+    pub label: String,
+    /// The project associated with the link.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_id: Option<String>,
+    /// \[Internal\] The resource folder containing the link.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resource_folder_id: Option<String>,
+    /// The order of the item in the entities resources list.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort_order: Option<f64>,
+    /// \[Internal\] The team associated with the link.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team_id: Option<String>,
+    /// The URL of the link.
+    pub url: String,
+}
+
+impl EntityExternalLinkCreateInput {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(label: String, url: String) -> Self {
+        Self {
+            id: None,
+            initiative_id: None,
+            label,
+            project_id: None,
+            resource_folder_id: None,
+            sort_order: None,
+            team_id: None,
+            url,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EntityExternalLinkUpdateInput {
+    /// The label for the link.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    /// \[Internal\] The resource folder containing the link.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resource_folder_id: Option<String>,
+    /// The order of the item in the entities resources list.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort_order: Option<f64>,
+    /// The URL of the link.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+/// Comparator for estimates.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EstimateComparator {
+    /// Compound filters, one of which need to be matched by the estimate.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub and: Option<Vec<NullableNumberComparator>>,
+    /// Equals constraint.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub eq: Option<f64>,
+    /// Greater-than constraint. Matches any values that are greater than the given value.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gt: Option<f64>,
+    /// Greater-than-or-equal constraint. Matches any values that are greater than or equal to the given value.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gte: Option<f64>,
+    /// In-array constraint.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#in: Option<Vec<f64>>,
+    /// Less-than constraint. Matches any values that are less than the given value.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lt: Option<f64>,
+    /// Less-than-or-equal constraint. Matches any values that are less than or equal to the given value.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lte: Option<f64>,
+    /// Not-equals constraint.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub neq: Option<f64>,
+    /// Not-in-array constraint.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nin: Option<Vec<f64>>,
+    /// Null constraint. Matches any non-null values if the given value is false, otherwise it matches null values.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub null: Option<bool>,
+    /// Compound filters, all of which need to be matched by the estimate.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub or: Option<Vec<NullableNumberComparator>>,
+}
+
+/// Issue estimate sorting options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EstimateSort {
+    /// Whether nulls should be sorted first or last
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nulls: Option<PaginationNulls>,
+    /// The order for the individual sort
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order: Option<PaginationSortOrder>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FavoriteCreateInput {
+    /// The identifier of the custom view to favorite.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub custom_view_id: Option<String>,
+    /// The identifier of the customer to favorite.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer_id: Option<String>,
+    /// The identifier of the cycle to favorite.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cycle_id: Option<String>,
+    /// The identifier of the dashboard to favorite.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dashboard_id: Option<String>,
+    /// The identifier of the document to favorite.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub document_id: Option<String>,
+    /// The identifier of the facet to favorite.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub facet_id: Option<String>,
+    /// The name of the favorite folder.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub folder_name: Option<String>,
+    /// The identifier. If none is provided, the backend will generate one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// \[INTERNAL\] The identifier of the initiative to favorite.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative_id: Option<String>,
+    /// The tab of the initiative to favorite.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative_tab: Option<InitiativeTab>,
+    /// The identifier of the issue to favorite. Can be a UUID or issue identifier (e.g., 'LIN-123').
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_id: Option<String>,
+    /// The identifier of the label to favorite.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label_id: Option<String>,
+    /// The parent folder of the favorite.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_id: Option<String>,
+    /// The identifier of team for the predefined view to favorite.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub predefined_view_team_id: Option<String>,
+    /// The type of the predefined view to favorite.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub predefined_view_type: Option<String>,
+    /// The identifier of the project to favorite.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_id: Option<String>,
+    /// The identifier of the label to favorite.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_label_id: Option<String>,
+    /// The tab of the project to favorite.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_tab: Option<ProjectTab>,
+    /// The identifier of the pull request to favorite.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pull_request_id: Option<String>,
+    /// \[ALPHA\] The identifier of the release to favorite.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub release_id: Option<String>,
+    /// \[ALPHA\] The identifier of the release pipeline to favorite.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub release_pipeline_id: Option<String>,
+    /// The position of the item in the favorites list.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort_order: Option<f64>,
+    /// The identifier of the user to favorite.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FavoriteUpdateInput {
+    /// The name of the favorite folder.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub folder_name: Option<String>,
+    /// The identifier (in UUID v4 format) of the folder to move the favorite under.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_id: Option<String>,
+    /// The position of the item in the favorites list.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort_order: Option<f64>,
+}
+
+/// Feed item filtering options
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FeedItemFilter {
+    /// Compound filters, all of which need to be matched by the feed item.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub and: Option<Vec<FeedItemFilter>>,
+    /// Filters that the feed item author must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub author: Option<Box<UserFilter>>,
+    /// Comparator for the created at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<Box<DateComparator>>,
+    /// Comparator for the identifier.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<Box<IDComparator>>,
+    /// Compound filters, one of which need to be matched by the feed item.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub or: Option<Vec<FeedItemFilter>>,
+    /// Filters that the feed item's project update must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_update: Option<Box<ProjectUpdateFilter>>,
+    /// Filters that the related feed item initiatives must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub related_initiatives: Option<Box<InitiativeCollectionFilter>>,
+    /// Filters that the related feed item team must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub related_teams: Option<Box<TeamCollectionFilter>>,
+    /// Comparator for the project or initiative update health: onTrack, atRisk, offTrack
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub update_health: Option<Box<StringComparator>>,
+    /// Comparator for the update type: initiative, project, team
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub update_type: Option<Box<StringComparator>>,
+    /// Comparator for the updated at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<Box<DateComparator>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FrontSettingsInput {
+    /// Whether a ticket should be automatically reopened when its linked Linear issue is cancelled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub automate_ticket_reopening_on_cancellation: Option<bool>,
+    /// Whether a ticket should be automatically reopened when a comment is posted on its linked Linear issue
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub automate_ticket_reopening_on_comment: Option<bool>,
+    /// Whether a ticket should be automatically reopened when its linked Linear issue is completed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub automate_ticket_reopening_on_completion: Option<bool>,
+    /// Whether a ticket should be automatically reopened when its linked Linear project is cancelled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub automate_ticket_reopening_on_project_cancellation: Option<bool>,
+    /// Whether a ticket should be automatically reopened when its linked Linear project is completed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub automate_ticket_reopening_on_project_completion: Option<bool>,
+    /// \[ALPHA\] Whether customer and customer requests should not be automatically created when conversations are linked to a Linear issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub disable_customer_requests_auto_creation: Option<bool>,
+    /// Whether Linear Agent should be enabled for this integration.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enable_ai_intake: Option<bool>,
+    /// Whether an internal message should be added when someone comments on an issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub send_note_on_comment: Option<bool>,
+    /// Whether an internal message should be added when a Linear issue changes status (for status types except completed or canceled).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub send_note_on_status_change: Option<bool>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GitAutomationStateCreateInput {
+    /// The event that triggers the automation.
+    pub event: GitAutomationStates,
+    /// The identifier in UUID v4 format. If none is provided, the backend will generate one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The associated workflow state. If null, will override default behaviour and take no action.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state_id: Option<String>,
+    /// The associated target branch. If null, all branches are targeted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_branch_id: Option<String>,
+    /// The team associated with the automation state.
+    pub team_id: String,
+}
+
+impl GitAutomationStateCreateInput {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(event: GitAutomationStates, team_id: String) -> Self {
+        Self {
+            event,
+            id: None,
+            state_id: None,
+            target_branch_id: None,
+            team_id,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GitAutomationStateUpdateInput {
+    /// The event that triggers the automation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub event: Option<GitAutomationStates>,
+    /// The associated workflow state.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state_id: Option<String>,
+    /// The associated target branch. If null, all branches are targeted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_branch_id: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GitAutomationTargetBranchCreateInput {
+    /// The target branch pattern.
+    pub branch_pattern: String,
+    /// The identifier in UUID v4 format. If none is provided, the backend will generate one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// Whether the branch pattern is a regular expression.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_regex: Option<bool>,
+    /// The team associated with the Git target branch automation.
+    pub team_id: String,
+}
+
+impl GitAutomationTargetBranchCreateInput {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(branch_pattern: String, team_id: String) -> Self {
+        Self {
+            branch_pattern,
+            id: None,
+            is_regex: None,
+            team_id,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GitAutomationTargetBranchUpdateInput {
+    /// The target branch pattern.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub branch_pattern: Option<String>,
+    /// Whether the branch pattern is a regular expression.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_regex: Option<bool>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GitHubImportSettingsInput {
+    /// A map storing all available issue labels per repository
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<JSONObject>,
+    /// The avatar URL for the GitHub organization.
+    pub org_avatar_url: String,
+    /// The GitHub organization's name.
+    pub org_login: String,
+    /// The type of Github org
+    pub org_type: GithubOrgType,
+    /// The names of the repositories connected for the GitHub integration.
+    pub repositories: Vec<GitHubRepoInput>,
+}
+
+impl GitHubImportSettingsInput {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(org_avatar_url: String, org_login: String, org_type: GithubOrgType, repositories: Vec<GitHubRepoInput>) -> Self {
+        Self {
+            labels: None,
+            org_avatar_url,
+            org_login,
+            org_type,
+            repositories,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GitHubPersonalSettingsInput {
+    /// The GitHub user's name.
+    pub login: String,
+}
+
+impl GitHubPersonalSettingsInput {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(login: String) -> Self {
+        Self {
+            login,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GitHubRepoInput {
+    /// Whether the repository is archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived: Option<bool>,
+    /// The full name of the repository.
+    pub full_name: String,
+    /// The GitHub repo id.
+    pub id: f64,
+}
+
+impl GitHubRepoInput {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(full_name: String, id: f64) -> Self {
+        Self {
+            archived: None,
+            full_name,
+            id,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GitHubRepoMappingInput {
+    /// Whether the sync for this mapping is bidirectional.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bidirectional: Option<bool>,
+    /// Whether this mapping is the default one for issue creation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default: Option<bool>,
+    /// Labels to filter incoming GitHub issue creation by.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub git_hub_labels: Option<Vec<String>>,
+    /// The GitHub repo id.
+    pub git_hub_repo_id: f64,
+    /// The unique identifier for this mapping.
+    pub id: String,
+    /// The Linear team id to map to the given project.
+    pub linear_team_id: String,
+}
+
+impl GitHubRepoMappingInput {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(git_hub_repo_id: f64, id: String, linear_team_id: String) -> Self {
+        Self {
+            bidirectional: None,
+            default: None,
+            git_hub_labels: None,
+            git_hub_repo_id,
+            id,
+            linear_team_id,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GitHubSettingsInput {
+    /// Whether the integration has code access
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub code_access: Option<bool>,
+    /// The avatar URL for the GitHub organization.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub org_avatar_url: Option<String>,
+    /// The GitHub organization's name.
+    pub org_login: String,
+    /// The type of Github org
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub org_type: Option<GithubOrgType>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pull_request_review_tool: Option<PullRequestReviewTool>,
+    /// The names of the repositories connected for the GitHub integration.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub repositories: Option<Vec<GitHubRepoInput>>,
+    /// Mapping of team to repository for syncing.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub repositories_mapping: Option<Vec<GitHubRepoMappingInput>>,
+}
+
+impl GitHubSettingsInput {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(org_login: String) -> Self {
+        Self {
+            code_access: None,
+            org_avatar_url: None,
+            org_login,
+            org_type: None,
+            pull_request_review_tool: None,
+            repositories: None,
+            repositories_mapping: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GitLabSettingsInput {
+    /// The ISO timestamp the GitLab access token expires.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<String>,
+    /// Whether the token is limited to a read-only scope.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub readonly: Option<bool>,
+    /// The self-hosted URL of the GitLab instance.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GongRecordingImportConfigInput {
+    /// The team ID to create issues in for imported recordings. Set to null to disable import.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GongSettingsInput {
+    /// Configuration for recording import.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub import_config: Option<Box<GongRecordingImportConfigInput>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GoogleSheetsExportSettings {
+    /// Whether the export is enabled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    /// The ID of the target sheet (tab) within the Google Sheet.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sheet_id: Option<f64>,
+    /// The ID of the exported Google Sheet.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub spreadsheet_id: Option<String>,
+    /// The URL of the exported Google Sheet.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub spreadsheet_url: Option<String>,
+    /// The date of the most recent export.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GoogleSheetsSettingsInput {
+    /// The export settings for initiatives.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative: Option<Box<GoogleSheetsExportSettings>>,
+    /// The export settings for issues.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue: Option<Box<GoogleSheetsExportSettings>>,
+    /// The export settings for projects.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project: Option<Box<GoogleSheetsExportSettings>>,
+    /// \[Deprecated\] The ID of the target sheet (tab) within the Google Sheet.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sheet_id: Option<f64>,
+    /// \[Deprecated\] The ID of the exported Google Sheet.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub spreadsheet_id: Option<String>,
+    /// \[Deprecated\] The URL of the exported Google Sheet.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub spreadsheet_url: Option<String>,
+    /// \[Deprecated\] The date of the most recent export.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_issues_at: Option<DateTime>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GoogleUserAccountAuthInput {
+    /// Code returned from Google's OAuth flow.
+    pub code: String,
+    /// An optional parameter to disable new user signup and force login. Default: false.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub disallow_signup: Option<bool>,
+    /// An optional invite link for an organization used to populate available organizations.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub invite_link: Option<String>,
+    /// The URI to redirect the user to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub redirect_uri: Option<String>,
+    /// The timezone of the user's browser.
+    pub timezone: String,
+}
+
+impl GoogleUserAccountAuthInput {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(code: String, timezone: String) -> Self {
+        Self {
+            code,
+            disallow_signup: None,
+            invite_link: None,
+            redirect_uri: None,
+            timezone,
+        }
+    }
+}
+
+/// Comparator for identifiers.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IDComparator {
+    /// Equals constraint.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub eq: Option<String>,
+    /// In-array constraint.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#in: Option<Vec<String>>,
+    /// Not-equals constraint.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub neq: Option<String>,
+    /// Not-in-array constraint.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nin: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InheritanceEntityMapping {
+    /// Mapping of the IssueLabel ID to the new IssueLabel name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_labels: Option<JSONObject>,
+    /// Mapping of the WorkflowState ID to the new WorkflowState ID.
+    pub workflow_states: JSONObject,
+}
+
+impl InheritanceEntityMapping {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(workflow_states: JSONObject) -> Self {
+        Self {
+            issue_labels: None,
+            workflow_states,
+        }
+    }
+}
+
+/// Initiative collection filtering options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InitiativeCollectionFilter {
+    /// Comparator for the initiative activity type.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub activity_type: Option<Box<StringComparator>>,
+    /// Filters that the initiative must be an ancestor of.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ancestors: Option<Box<InitiativeCollectionFilter>>,
+    /// Compound filters, all of which need to be matched by the initiative.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub and: Option<Vec<InitiativeCollectionFilter>>,
+    /// Comparator for the created at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<Box<DateComparator>>,
+    /// Filters that the initiative creator must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub creator: Option<Box<NullableUserFilter>>,
+    /// Filters that needs to be matched by all initiatives.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub every: Option<Box<InitiativeFilter>>,
+    /// Comparator for the initiative health: onTrack, atRisk, offTrack
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub health: Option<Box<StringComparator>>,
+    /// Comparator for the initiative health (with age): onTrack, atRisk, offTrack, outdated, noUpdate
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub health_with_age: Option<Box<StringComparator>>,
+    /// Comparator for the identifier.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<Box<IDComparator>>,
+    /// Comparator for the collection length.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub length: Option<Box<NumberComparator>>,
+    /// Comparator for the initiative name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<Box<StringComparator>>,
+    /// Compound filters, one of which need to be matched by the initiative.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub or: Option<Vec<InitiativeCollectionFilter>>,
+    /// Filters that the initiative owner must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner: Option<Box<NullableUserFilter>>,
+    /// Comparator for the initiative slug ID.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slug_id: Option<Box<StringComparator>>,
+    /// Filters that needs to be matched by some initiatives.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub some: Option<Box<InitiativeFilter>>,
+    /// Comparator for the initiative status: Planned, Active, Completed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<Box<StringComparator>>,
+    /// Comparator for the initiative target date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_date: Option<Box<NullableDateComparator>>,
+    /// Filters that the initiative teams must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub teams: Option<Box<TeamCollectionFilter>>,
+    /// Comparator for the updated at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<Box<DateComparator>>,
+}
+
+/// The properties of the initiative to create.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InitiativeCreateInput {
+    /// The initiative's color.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    /// The initiative's content in markdown format.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+    /// The description of the initiative.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The initiative's icon.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub icon: Option<String>,
+    /// The identifier in UUID v4 format. If none is provided, the backend will generate one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The name of the initiative.
+    pub name: String,
+    /// The owner of the initiative.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner_id: Option<String>,
+    /// The sort order of the initiative within the organization.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort_order: Option<f64>,
+    /// The initiative's status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<InitiativeStatus>,
+    /// The estimated completion date of the initiative.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_date: Option<TimelessDate>,
+    /// The resolution of the initiative's estimated completion date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_date_resolution: Option<DateResolutionType>,
+}
+
+impl InitiativeCreateInput {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(name: String) -> Self {
+        Self {
+            color: None,
+            content: None,
+            description: None,
+            icon: None,
+            id: None,
+            name,
+            owner_id: None,
+            sort_order: None,
+            status: None,
+            target_date: None,
+            target_date_resolution: None,
+        }
+    }
+}
+
+/// Initiative creation date sorting options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InitiativeCreatedAtSort {
+    /// Whether nulls should be sorted first or last
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nulls: Option<PaginationNulls>,
+    /// The order for the individual sort
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order: Option<PaginationSortOrder>,
+}
+
+/// Initiative filtering options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InitiativeFilter {
+    /// Comparator for the initiative activity type.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub activity_type: Option<Box<StringComparator>>,
+    /// Filters that the initiative must be an ancestor of.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ancestors: Option<Box<InitiativeCollectionFilter>>,
+    /// Compound filters, all of which need to be matched by the initiative.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub and: Option<Vec<InitiativeFilter>>,
+    /// Comparator for the created at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<Box<DateComparator>>,
+    /// Filters that the initiative creator must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub creator: Option<Box<NullableUserFilter>>,
+    /// Comparator for the initiative health: onTrack, atRisk, offTrack
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub health: Option<Box<StringComparator>>,
+    /// Comparator for the initiative health (with age): onTrack, atRisk, offTrack, outdated, noUpdate
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub health_with_age: Option<Box<StringComparator>>,
+    /// Comparator for the identifier.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<Box<IDComparator>>,
+    /// Comparator for the initiative name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<Box<StringComparator>>,
+    /// Compound filters, one of which need to be matched by the initiative.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub or: Option<Vec<InitiativeFilter>>,
+    /// Filters that the initiative owner must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner: Option<Box<NullableUserFilter>>,
+    /// Comparator for the initiative slug ID.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slug_id: Option<Box<StringComparator>>,
+    /// Comparator for the initiative status: Planned, Active, Completed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<Box<StringComparator>>,
+    /// Comparator for the initiative target date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_date: Option<Box<NullableDateComparator>>,
+    /// Filters that the initiative teams must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub teams: Option<Box<TeamCollectionFilter>>,
+    /// Comparator for the updated at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<Box<DateComparator>>,
+}
+
+/// Initiative health sorting options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InitiativeHealthSort {
+    /// Whether nulls should be sorted first or last
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nulls: Option<PaginationNulls>,
+    /// The order for the individual sort
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order: Option<PaginationSortOrder>,
+}
+
+/// Initiative health update date sorting options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InitiativeHealthUpdatedAtSort {
+    /// Whether nulls should be sorted first or last
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nulls: Option<PaginationNulls>,
+    /// The order for the individual sort
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order: Option<PaginationSortOrder>,
+}
+
+/// Initiative manual sorting options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InitiativeManualSort {
+    /// Whether nulls should be sorted first or last
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nulls: Option<PaginationNulls>,
+    /// The order for the individual sort
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order: Option<PaginationSortOrder>,
+}
+
+/// Initiative name sorting options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InitiativeNameSort {
+    /// Whether nulls should be sorted first or last
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nulls: Option<PaginationNulls>,
+    /// The order for the individual sort
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order: Option<PaginationSortOrder>,
+}
+
+/// Initiative owner sorting options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InitiativeOwnerSort {
+    /// Whether nulls should be sorted first or last
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nulls: Option<PaginationNulls>,
+    /// The order for the individual sort
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order: Option<PaginationSortOrder>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InitiativeRelationCreateInput {
+    /// The identifier in UUID v4 format. If none is provided, the backend will generate one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The identifier of the parent initiative.
+    pub initiative_id: String,
+    /// The identifier of the child initiative.
+    pub related_initiative_id: String,
+    /// The sort order of the initiative relation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort_order: Option<f64>,
+}
+
+impl InitiativeRelationCreateInput {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(initiative_id: String, related_initiative_id: String) -> Self {
+        Self {
+            id: None,
+            initiative_id,
+            related_initiative_id,
+            sort_order: None,
+        }
+    }
+}
+
+/// The properties of the initiativeRelation to update.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InitiativeRelationUpdateInput {
+    /// The sort order of the initiative relation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort_order: Option<f64>,
+}
+
+/// Initiative sorting options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InitiativeSortInput {
+    /// Sort by initiative creation date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<Box<InitiativeCreatedAtSort>>,
+    /// Sort by initiative health status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub health: Option<Box<InitiativeHealthSort>>,
+    /// Sort by initiative health update date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub health_updated_at: Option<Box<InitiativeHealthUpdatedAtSort>>,
+    /// Sort by manual order.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub manual: Option<Box<InitiativeManualSort>>,
+    /// Sort by initiative name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<Box<InitiativeNameSort>>,
+    /// Sort by initiative owner name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner: Option<Box<InitiativeOwnerSort>>,
+    /// Sort by initiative target date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_date: Option<Box<InitiativeTargetDateSort>>,
+    /// Sort by initiative update date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<Box<InitiativeUpdatedAtSort>>,
+}
+
+/// Initiative target date sorting options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InitiativeTargetDateSort {
+    /// Whether nulls should be sorted first or last
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nulls: Option<PaginationNulls>,
+    /// The order for the individual sort
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order: Option<PaginationSortOrder>,
+}
+
+/// The properties of the initiativeToProject to create.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InitiativeToProjectCreateInput {
+    /// The identifier in UUID v4 format. If none is provided, the backend will generate one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The identifier of the initiative.
+    pub initiative_id: String,
+    /// The identifier of the project.
+    pub project_id: String,
+    /// The sort order for the project within its organization.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort_order: Option<f64>,
+}
+
+impl InitiativeToProjectCreateInput {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(initiative_id: String, project_id: String) -> Self {
+        Self {
+            id: None,
+            initiative_id,
+            project_id,
+            sort_order: None,
+        }
+    }
+}
+
+/// The properties of the initiativeToProject to update.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InitiativeToProjectUpdateInput {
+    /// The sort order for the project within its organization.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort_order: Option<f64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InitiativeUpdateCreateInput {
+    /// The content of the update in markdown format.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    /// \[Internal\] The content of the update as a Prosemirror document.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body_data: Option<JSON>,
+    /// The health of the initiative at the time of the update.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub health: Option<InitiativeUpdateHealthType>,
+    /// The identifier in UUID v4 format. If none is provided, the backend will generate one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The initiative to associate the update with.
+    pub initiative_id: String,
+    /// Whether the diff between the current update and the previous one should be hidden.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_diff_hidden: Option<bool>,
+}
+
+impl InitiativeUpdateCreateInput {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(initiative_id: String) -> Self {
+        Self {
+            body: None,
+            body_data: None,
+            health: None,
+            id: None,
+            initiative_id,
+            is_diff_hidden: None,
+        }
+    }
+}
+
+/// Options for filtering initiative updates.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InitiativeUpdateFilter {
+    /// Compound filters, all of which need to be matched by the InitiativeUpdate.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub and: Option<Vec<InitiativeUpdateFilter>>,
+    /// Comparator for the created at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<Box<DateComparator>>,
+    /// Comparator for the identifier.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<Box<IDComparator>>,
+    /// Filters that the initiative update initiative must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative: Option<Box<InitiativeFilter>>,
+    /// Compound filters, one of which need to be matched by the InitiativeUpdate.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub or: Option<Vec<InitiativeUpdateFilter>>,
+    /// Filters that the initiative updates reactions must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reactions: Option<Box<ReactionCollectionFilter>>,
+    /// Comparator for the updated at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<Box<DateComparator>>,
+    /// Filters that the initiative update creator must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<Box<UserFilter>>,
+}
+
+/// The properties of the initiative to update.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InitiativeUpdateInput {
+    /// The initiative's color.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    /// The initiative's content in markdown format.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+    /// The description of the initiative.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The frequency resolution.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub frequency_resolution: Option<FrequencyResolutionType>,
+    /// The initiative's icon.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub icon: Option<String>,
+    /// The name of the initiative.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The owner of the initiative.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner_id: Option<String>,
+    /// The sort order of the initiative within the organization.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort_order: Option<f64>,
+    /// The initiative's status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<InitiativeStatus>,
+    /// The estimated completion date of the initiative.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_date: Option<TimelessDate>,
+    /// The resolution of the initiative's estimated completion date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_date_resolution: Option<DateResolutionType>,
+    /// Whether the initiative has been trashed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trashed: Option<bool>,
+    /// The frequency at which to prompt for updates. When not set, reminders are inherited from workspace.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub update_reminder_frequency: Option<f64>,
+    /// The n-weekly frequency at which to prompt for updates. When not set, reminders are inherited from workspace.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub update_reminder_frequency_in_weeks: Option<f64>,
+    /// The day at which to prompt for updates.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub update_reminders_day: Option<Day>,
+    /// The hour at which to prompt for updates.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub update_reminders_hour: Option<i64>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InitiativeUpdateUpdateInput {
+    /// The content of the update in markdown format.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    /// The content of the update as a Prosemirror document.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body_data: Option<JSON>,
+    /// The health of the initiative at the time of the update.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub health: Option<InitiativeUpdateHealthType>,
+    /// Whether the diff between the current update and the previous one should be hidden.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_diff_hidden: Option<bool>,
+}
+
+/// Initiative update date sorting options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InitiativeUpdatedAtSort {
+    /// Whether nulls should be sorted first or last
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nulls: Option<PaginationNulls>,
+    /// The order for the individual sort
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order: Option<PaginationSortOrder>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IntegrationCustomerDataAttributesRefreshInput {
+    /// The integration service to refresh customer data attributes from.
+    pub service: String,
+}
+
+impl IntegrationCustomerDataAttributesRefreshInput {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(service: String) -> Self {
+        Self {
+            service,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IntegrationRequestInput {
+    /// Email associated with the request.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    /// Name of the requested integration.
+    pub name: String,
+}
+
+impl IntegrationRequestInput {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(name: String) -> Self {
+        Self {
+            email: None,
+            name,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IntegrationSettingsInput {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub front: Option<Box<FrontSettingsInput>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub git_hub: Option<Box<GitHubSettingsInput>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub git_hub_import: Option<Box<GitHubImportSettingsInput>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub git_hub_personal: Option<Box<GitHubPersonalSettingsInput>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub git_lab: Option<Box<GitLabSettingsInput>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gong: Option<Box<GongSettingsInput>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub google_sheets: Option<Box<GoogleSheetsSettingsInput>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub intercom: Option<Box<IntercomSettingsInput>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub jira: Option<Box<JiraSettingsInput>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub jira_personal: Option<Box<JiraPersonalSettingsInput>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub launch_darkly: Option<Box<LaunchDarklySettingsInput>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notion: Option<Box<NotionSettingsInput>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub opsgenie: Option<Box<OpsgenieInput>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pager_duty: Option<Box<PagerDutyInput>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub salesforce: Option<Box<SalesforceSettingsInput>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sentry: Option<Box<SentrySettingsInput>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slack: Option<Box<SlackSettingsInput>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slack_asks: Option<Box<SlackAsksSettingsInput>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slack_custom_view_notifications: Option<Box<SlackPostSettingsInput>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slack_initiative_post: Option<Box<SlackPostSettingsInput>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slack_org_initiative_updates_post: Option<Box<SlackPostSettingsInput>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slack_org_project_updates_post: Option<Box<SlackPostSettingsInput>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slack_post: Option<Box<SlackPostSettingsInput>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slack_project_post: Option<Box<SlackPostSettingsInput>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub zendesk: Option<Box<ZendeskSettingsInput>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IntegrationTemplateCreateInput {
+    /// The foreign identifier in the other service.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub foreign_entity_id: Option<String>,
+    /// The identifier in UUID v4 format. If none is provided, the backend will generate one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The identifier of the integration.
+    pub integration_id: String,
+    /// The identifier of the template.
+    pub template_id: String,
+}
+
+impl IntegrationTemplateCreateInput {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(integration_id: String, template_id: String) -> Self {
+        Self {
+            foreign_entity_id: None,
+            id: None,
+            integration_id,
+            template_id,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IntegrationUpdateInput {
+    /// The settings to update.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub settings: Option<Box<IntegrationSettingsInput>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IntegrationsSettingsCreateInput {
+    /// The type of view to which the integration settings context is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub context_view_type: Option<ContextViewType>,
+    /// The identifier of the custom view to create settings for.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub custom_view_id: Option<String>,
+    /// The identifier in UUID v4 format. If none is provided, the backend will generate one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The identifier of the initiative to create settings for.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative_id: Option<String>,
+    /// The identifier of the project to create settings for.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_id: Option<String>,
+    /// Whether to send a Slack message when an initiative update is created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slack_initiative_update_created: Option<bool>,
+    /// Whether to send a Slack message when a new issue is added to triage.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slack_issue_added_to_triage: Option<bool>,
+    /// Whether to send a Slack message when an issue is added to a view.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slack_issue_added_to_view: Option<bool>,
+    /// Whether to send a Slack message when a new issue is created for the project or the team.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slack_issue_created: Option<bool>,
+    /// Whether to send a Slack message when a comment is created on any of the project or team's issues.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slack_issue_new_comment: Option<bool>,
+    /// Whether to receive notification when an SLA has breached on Slack.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slack_issue_sla_breached: Option<bool>,
+    /// Whether to send a Slack message when an SLA is at high risk.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slack_issue_sla_high_risk: Option<bool>,
+    /// Whether to send a Slack message when any of the project or team's issues has a change in status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slack_issue_status_changed_all: Option<bool>,
+    /// Whether to send a Slack message when any of the project or team's issues change to completed or cancelled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slack_issue_status_changed_done: Option<bool>,
+    /// Whether to send a Slack message when a project update is created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slack_project_update_created: Option<bool>,
+    /// Whether to send a Slack message when a project update is created to team channels.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slack_project_update_created_to_team: Option<bool>,
+    /// Whether to send a Slack message when a project update is created to workspace channel.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slack_project_update_created_to_workspace: Option<bool>,
+    /// The identifier of the team to create settings for.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IntegrationsSettingsUpdateInput {
+    /// Whether to send a Slack message when an initiative update is created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slack_initiative_update_created: Option<bool>,
+    /// Whether to send a Slack message when a new issue is added to triage.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slack_issue_added_to_triage: Option<bool>,
+    /// Whether to send a Slack message when an issue is added to a view.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slack_issue_added_to_view: Option<bool>,
+    /// Whether to send a Slack message when a new issue is created for the project or the team.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slack_issue_created: Option<bool>,
+    /// Whether to send a Slack message when a comment is created on any of the project or team's issues.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slack_issue_new_comment: Option<bool>,
+    /// Whether to receive notification when an SLA has breached on Slack.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slack_issue_sla_breached: Option<bool>,
+    /// Whether to send a Slack message when an SLA is at high risk.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slack_issue_sla_high_risk: Option<bool>,
+    /// Whether to send a Slack message when any of the project or team's issues has a change in status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slack_issue_status_changed_all: Option<bool>,
+    /// Whether to send a Slack message when any of the project or team's issues change to completed or cancelled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slack_issue_status_changed_done: Option<bool>,
+    /// Whether to send a Slack message when a project update is created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slack_project_update_created: Option<bool>,
+    /// Whether to send a Slack message when a project update is created to team channels.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slack_project_update_created_to_team: Option<bool>,
+    /// Whether to send a Slack message when a project update is created to workspace channel.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slack_project_update_created_to_workspace: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IntercomSettingsInput {
+    /// Whether a ticket should be automatically reopened when its linked Linear issue is cancelled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub automate_ticket_reopening_on_cancellation: Option<bool>,
+    /// Whether a ticket should be automatically reopened when a comment is posted on its linked Linear issue
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub automate_ticket_reopening_on_comment: Option<bool>,
+    /// Whether a ticket should be automatically reopened when its linked Linear issue is completed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub automate_ticket_reopening_on_completion: Option<bool>,
+    /// Whether a ticket should be automatically reopened when its linked Linear project is cancelled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub automate_ticket_reopening_on_project_cancellation: Option<bool>,
+    /// Whether a ticket should be automatically reopened when its linked Linear project is completed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub automate_ticket_reopening_on_project_completion: Option<bool>,
+    /// \[ALPHA\] Whether customer and customer requests should not be automatically created when conversations are linked to a Linear issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub disable_customer_requests_auto_creation: Option<bool>,
+    /// Whether Linear Agent should be enabled for this integration.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enable_ai_intake: Option<bool>,
+    /// Whether an internal message should be added when someone comments on an issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub send_note_on_comment: Option<bool>,
+    /// Whether an internal message should be added when a Linear issue changes status (for status types except completed or canceled).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub send_note_on_status_change: Option<bool>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueBatchCreateInput {
+    /// The issues to create.
+    pub issues: Vec<IssueCreateInput>,
+}
+
+impl IssueBatchCreateInput {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(issues: Vec<IssueCreateInput>) -> Self {
+        Self {
+            issues,
+        }
+    }
+}
+
+/// Issue filtering options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueCollectionFilter {
+    /// \[Internal\] Comparator for the issue's accumulatedStateUpdatedAt date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub accumulated_state_updated_at: Option<Box<NullableDateComparator>>,
+    /// Comparator for the issues added to cycle at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub added_to_cycle_at: Option<Box<NullableDateComparator>>,
+    /// Comparator for the period when issue was added to a cycle.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub added_to_cycle_period: Option<Box<CyclePeriodComparator>>,
+    /// \[Internal\] Age (created -> now) comparator, defined if the issue is still open.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub age_time: Option<Box<NullableDurationComparator>>,
+    /// Compound filters, all of which need to be matched by the issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub and: Option<Vec<IssueCollectionFilter>>,
+    /// Comparator for the issues archived at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<Box<NullableDateComparator>>,
+    /// Filters that the issues assignee must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub assignee: Option<Box<NullableUserFilter>>,
+    /// Filters that the issues attachments must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub attachments: Option<Box<AttachmentCollectionFilter>>,
+    /// Comparator for the issues auto archived at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_archived_at: Option<Box<NullableDateComparator>>,
+    /// Comparator for the issues auto closed at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_closed_at: Option<Box<NullableDateComparator>>,
+    /// Comparator for the issues canceled at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub canceled_at: Option<Box<NullableDateComparator>>,
+    /// Filters that the child issues must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub children: Option<Box<IssueCollectionFilter>>,
+    /// Filters that the issues comments must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comments: Option<Box<CommentCollectionFilter>>,
+    /// Comparator for the issues completed at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub completed_at: Option<Box<NullableDateComparator>>,
+    /// Comparator for the created at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<Box<DateComparator>>,
+    /// Filters that the issues creator must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub creator: Option<Box<NullableUserFilter>>,
+    /// Count of customers
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer_count: Option<Box<NumberComparator>>,
+    /// Count of important customers
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer_important_count: Option<Box<NumberComparator>>,
+    /// Filters that the issues cycle must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cycle: Option<Box<NullableCycleFilter>>,
+    /// \[Internal\] Cycle time (started -> completed) comparator.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cycle_time: Option<Box<NullableDurationComparator>>,
+    /// Filters that the issue's delegated agent must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub delegate: Option<Box<NullableUserFilter>>,
+    /// Comparator for the issues description.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<Box<NullableStringComparator>>,
+    /// Comparator for the issues due date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub due_date: Option<Box<NullableTimelessDateComparator>>,
+    /// Comparator for the issues estimate.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub estimate: Option<Box<EstimateComparator>>,
+    /// Filters that needs to be matched by all issues.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub every: Option<Box<IssueFilter>>,
+    /// Comparator for filtering issues which are blocked.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_blocked_by_relations: Option<Box<RelationExistsComparator>>,
+    /// Comparator for filtering issues which are blocking.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_blocking_relations: Option<Box<RelationExistsComparator>>,
+    /// Comparator for filtering issues which are duplicates.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_duplicate_relations: Option<Box<RelationExistsComparator>>,
+    /// Comparator for filtering issues with relations.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_related_relations: Option<Box<RelationExistsComparator>>,
+    /// \[Internal\] Comparator for filtering issues which have suggested assignees.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_suggested_assignees: Option<Box<RelationExistsComparator>>,
+    /// \[Internal\] Comparator for filtering issues which have suggested labels.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_suggested_labels: Option<Box<RelationExistsComparator>>,
+    /// \[Internal\] Comparator for filtering issues which have suggested projects.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_suggested_projects: Option<Box<RelationExistsComparator>>,
+    /// \[Internal\] Comparator for filtering issues which have suggested related issues.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_suggested_related_issues: Option<Box<RelationExistsComparator>>,
+    /// \[Internal\] Comparator for filtering issues which have suggested similar issues.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_suggested_similar_issues: Option<Box<RelationExistsComparator>>,
+    /// \[Internal\] Comparator for filtering issues which have suggested teams.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_suggested_teams: Option<Box<RelationExistsComparator>>,
+    /// Comparator for the identifier.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<Box<IssueIDComparator>>,
+    /// Filters that issue labels must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<Box<IssueLabelCollectionFilter>>,
+    /// Filters that the last applied template must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_applied_template: Option<Box<NullableTemplateFilter>>,
+    /// \[Internal\] Lead time (created -> completed) comparator.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lead_time: Option<Box<NullableDurationComparator>>,
+    /// Comparator for the collection length.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub length: Option<Box<NumberComparator>>,
+    /// Filters that the issue's customer needs must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub needs: Option<Box<CustomerNeedCollectionFilter>>,
+    /// Comparator for the issues number.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub number: Option<Box<NumberComparator>>,
+    /// Compound filters, one of which need to be matched by the issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub or: Option<Vec<IssueCollectionFilter>>,
+    /// Filters that the issue parent must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent: Option<Box<NullableIssueFilter>>,
+    /// Comparator for the issues priority. 0 = No priority, 1 = Urgent, 2 = High, 3 = Normal, 4 = Low.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority: Option<Box<NullableNumberComparator>>,
+    /// Filters that the issues project must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project: Option<Box<NullableProjectFilter>>,
+    /// Filters that the issues project milestone must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_milestone: Option<Box<NullableProjectMilestoneFilter>>,
+    /// Filters that the issues reactions must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reactions: Option<Box<ReactionCollectionFilter>>,
+    /// \[ALPHA\] Filters that the recurring issue template must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub recurring_issue_template: Option<Box<NullableTemplateFilter>>,
+    /// \[Internal\] Comparator for the issues content.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub searchable_content: Option<Box<ContentComparator>>,
+    /// Comparator for the issues sla status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sla_status: Option<Box<SlaStatusComparator>>,
+    /// Filters that the issues snoozer must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub snoozed_by: Option<Box<NullableUserFilter>>,
+    /// Comparator for the issues snoozed until date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub snoozed_until_at: Option<Box<NullableDateComparator>>,
+    /// Filters that needs to be matched by some issues.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub some: Option<Box<IssueFilter>>,
+    /// Filters that the source must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_metadata: Option<Box<SourceMetadataComparator>>,
+    /// Comparator for the issues started at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub started_at: Option<Box<NullableDateComparator>>,
+    /// Filters that the issues state must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<Box<WorkflowStateFilter>>,
+    /// Filters that issue subscribers must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subscribers: Option<Box<UserCollectionFilter>>,
+    /// \[Internal\] Filters that the issue's suggestions must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub suggestions: Option<Box<IssueSuggestionCollectionFilter>>,
+    /// Filters that the issues team must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team: Option<Box<TeamFilter>>,
+    /// Comparator for the issues title.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<Box<StringComparator>>,
+    /// \[Internal\] Triage time (entered triaged -> triaged) comparator.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub triage_time: Option<Box<NullableDurationComparator>>,
+    /// Comparator for the issues triaged at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub triaged_at: Option<Box<NullableDateComparator>>,
+    /// Comparator for the updated at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<Box<DateComparator>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueCreateInput {
+    /// The identifier of the user to assign the issue to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub assignee_id: Option<String>,
+    /// The date when the issue was completed (e.g. if importing from another system). Must be a date in the past and after createdAt date. Cannot be provided with an incompatible workflow state.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub completed_at: Option<DateTime>,
+    /// Create issue as a user with the provided name. This option is only available to OAuth applications creating issues in `actor=app` mode.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub create_as_user: Option<String>,
+    /// The date when the issue was created (e.g. if importing from another system). Must be a date in the past. If none is provided, the backend will generate the time as now.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// The cycle associated with the issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cycle_id: Option<String>,
+    /// The identifier of the agent user to delegate the issue to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub delegate_id: Option<String>,
+    /// The issue description in markdown format.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// \[Internal\] The issue description as a Prosemirror document.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description_data: Option<JSON>,
+    /// Provide an external user avatar URL. Can only be used in conjunction with the `createAsUser` options. This option is only available to OAuth applications creating comments in `actor=app` mode.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub display_icon_url: Option<String>,
+    /// The date at which the issue is due.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub due_date: Option<TimelessDate>,
+    /// The estimated complexity of the issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub estimate: Option<i64>,
+    /// The identifier in UUID v4 format. If none is provided, the backend will generate one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The identifiers of the issue labels associated with this ticket.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label_ids: Option<Vec<String>>,
+    /// The ID of the last template applied to the issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_applied_template_id: Option<String>,
+    /// The identifier of the parent issue. Can be a UUID or issue identifier (e.g., 'LIN-123').
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_id: Option<String>,
+    /// Whether the passed sort order should be preserved.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub preserve_sort_order_on_create: Option<bool>,
+    /// The priority of the issue. 0 = No priority, 1 = Urgent, 2 = High, 3 = Normal, 4 = Low.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority: Option<i64>,
+    /// The position of the issue related to other issues, when ordered by priority.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority_sort_order: Option<f64>,
+    /// The project associated with the issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_id: Option<String>,
+    /// The project milestone associated with the issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_milestone_id: Option<String>,
+    /// The comment the issue is referencing.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reference_comment_id: Option<String>,
+    /// \[Internal\] The timestamp at which an issue will be considered in breach of SLA.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sla_breaches_at: Option<DateTime>,
+    /// \[Internal\] The timestamp at which the issue's SLA was started.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sla_started_at: Option<DateTime>,
+    /// The SLA day count type for the issue. Whether SLA should be business days only or calendar days (default).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sla_type: Option<SLADayCountType>,
+    /// The position of the issue related to other issues.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort_order: Option<f64>,
+    /// The comment the issue is created from.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_comment_id: Option<String>,
+    /// \[Internal\] The pull request comment the issue is created from.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_pull_request_comment_id: Option<String>,
+    /// The team state of the issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state_id: Option<String>,
+    /// The position of the issue in parent's sub-issue list.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sub_issue_sort_order: Option<f64>,
+    /// The identifiers of the users subscribing to this ticket.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subscriber_ids: Option<Vec<String>>,
+    /// The identifier of the team associated with the issue.
+    pub team_id: String,
+    /// The identifier of a template the issue should be created from. If other values are provided in the input, they will override template values.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub template_id: Option<String>,
+    /// The title of the issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Whether to use the default template for the team. When set to true, the default template of this team based on user's membership will be applied.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub use_default_template: Option<bool>,
+}
+
+impl IssueCreateInput {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(team_id: String) -> Self {
+        Self {
+            assignee_id: None,
+            completed_at: None,
+            create_as_user: None,
+            created_at: None,
+            cycle_id: None,
+            delegate_id: None,
+            description: None,
+            description_data: None,
+            display_icon_url: None,
+            due_date: None,
+            estimate: None,
+            id: None,
+            label_ids: None,
+            last_applied_template_id: None,
+            parent_id: None,
+            preserve_sort_order_on_create: None,
+            priority: None,
+            priority_sort_order: None,
+            project_id: None,
+            project_milestone_id: None,
+            reference_comment_id: None,
+            sla_breaches_at: None,
+            sla_started_at: None,
+            sla_type: None,
+            sort_order: None,
+            source_comment_id: None,
+            source_pull_request_comment_id: None,
+            state_id: None,
+            sub_issue_sort_order: None,
+            subscriber_ids: None,
+            team_id,
+            template_id: None,
+            title: None,
+            use_default_template: None,
+        }
+    }
+}
+
+/// Issue filtering options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueFilter {
+    /// \[Internal\] Comparator for the issue's accumulatedStateUpdatedAt date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub accumulated_state_updated_at: Option<Box<NullableDateComparator>>,
+    /// Comparator for the issues added to cycle at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub added_to_cycle_at: Option<Box<NullableDateComparator>>,
+    /// Comparator for the period when issue was added to a cycle.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub added_to_cycle_period: Option<Box<CyclePeriodComparator>>,
+    /// \[Internal\] Age (created -> now) comparator, defined if the issue is still open.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub age_time: Option<Box<NullableDurationComparator>>,
+    /// Compound filters, all of which need to be matched by the issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub and: Option<Vec<IssueFilter>>,
+    /// Comparator for the issues archived at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<Box<NullableDateComparator>>,
+    /// Filters that the issues assignee must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub assignee: Option<Box<NullableUserFilter>>,
+    /// Filters that the issues attachments must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub attachments: Option<Box<AttachmentCollectionFilter>>,
+    /// Comparator for the issues auto archived at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_archived_at: Option<Box<NullableDateComparator>>,
+    /// Comparator for the issues auto closed at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_closed_at: Option<Box<NullableDateComparator>>,
+    /// Comparator for the issues canceled at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub canceled_at: Option<Box<NullableDateComparator>>,
+    /// Filters that the child issues must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub children: Option<Box<IssueCollectionFilter>>,
+    /// Filters that the issues comments must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comments: Option<Box<CommentCollectionFilter>>,
+    /// Comparator for the issues completed at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub completed_at: Option<Box<NullableDateComparator>>,
+    /// Comparator for the created at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<Box<DateComparator>>,
+    /// Filters that the issues creator must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub creator: Option<Box<NullableUserFilter>>,
+    /// Count of customers
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer_count: Option<Box<NumberComparator>>,
+    /// Count of important customers
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer_important_count: Option<Box<NumberComparator>>,
+    /// Filters that the issues cycle must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cycle: Option<Box<NullableCycleFilter>>,
+    /// \[Internal\] Cycle time (started -> completed) comparator.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cycle_time: Option<Box<NullableDurationComparator>>,
+    /// Filters that the issue's delegated agent must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub delegate: Option<Box<NullableUserFilter>>,
+    /// Comparator for the issues description.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<Box<NullableStringComparator>>,
+    /// Comparator for the issues due date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub due_date: Option<Box<NullableTimelessDateComparator>>,
+    /// Comparator for the issues estimate.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub estimate: Option<Box<EstimateComparator>>,
+    /// Comparator for filtering issues which are blocked.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_blocked_by_relations: Option<Box<RelationExistsComparator>>,
+    /// Comparator for filtering issues which are blocking.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_blocking_relations: Option<Box<RelationExistsComparator>>,
+    /// Comparator for filtering issues which are duplicates.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_duplicate_relations: Option<Box<RelationExistsComparator>>,
+    /// Comparator for filtering issues with relations.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_related_relations: Option<Box<RelationExistsComparator>>,
+    /// \[Internal\] Comparator for filtering issues which have suggested assignees.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_suggested_assignees: Option<Box<RelationExistsComparator>>,
+    /// \[Internal\] Comparator for filtering issues which have suggested labels.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_suggested_labels: Option<Box<RelationExistsComparator>>,
+    /// \[Internal\] Comparator for filtering issues which have suggested projects.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_suggested_projects: Option<Box<RelationExistsComparator>>,
+    /// \[Internal\] Comparator for filtering issues which have suggested related issues.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_suggested_related_issues: Option<Box<RelationExistsComparator>>,
+    /// \[Internal\] Comparator for filtering issues which have suggested similar issues.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_suggested_similar_issues: Option<Box<RelationExistsComparator>>,
+    /// \[Internal\] Comparator for filtering issues which have suggested teams.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_suggested_teams: Option<Box<RelationExistsComparator>>,
+    /// Comparator for the identifier.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<Box<IssueIDComparator>>,
+    /// Filters that issue labels must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<Box<IssueLabelCollectionFilter>>,
+    /// Filters that the last applied template must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_applied_template: Option<Box<NullableTemplateFilter>>,
+    /// \[Internal\] Lead time (created -> completed) comparator.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lead_time: Option<Box<NullableDurationComparator>>,
+    /// Filters that the issue's customer needs must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub needs: Option<Box<CustomerNeedCollectionFilter>>,
+    /// Comparator for the issues number.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub number: Option<Box<NumberComparator>>,
+    /// Compound filters, one of which need to be matched by the issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub or: Option<Vec<IssueFilter>>,
+    /// Filters that the issue parent must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent: Option<Box<NullableIssueFilter>>,
+    /// Comparator for the issues priority. 0 = No priority, 1 = Urgent, 2 = High, 3 = Normal, 4 = Low.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority: Option<Box<NullableNumberComparator>>,
+    /// Filters that the issues project must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project: Option<Box<NullableProjectFilter>>,
+    /// Filters that the issues project milestone must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_milestone: Option<Box<NullableProjectMilestoneFilter>>,
+    /// Filters that the issues reactions must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reactions: Option<Box<ReactionCollectionFilter>>,
+    /// \[ALPHA\] Filters that the recurring issue template must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub recurring_issue_template: Option<Box<NullableTemplateFilter>>,
+    /// \[Internal\] Comparator for the issues content.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub searchable_content: Option<Box<ContentComparator>>,
+    /// Comparator for the issues sla status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sla_status: Option<Box<SlaStatusComparator>>,
+    /// Filters that the issues snoozer must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub snoozed_by: Option<Box<NullableUserFilter>>,
+    /// Comparator for the issues snoozed until date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub snoozed_until_at: Option<Box<NullableDateComparator>>,
+    /// Filters that the source must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_metadata: Option<Box<SourceMetadataComparator>>,
+    /// Comparator for the issues started at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub started_at: Option<Box<NullableDateComparator>>,
+    /// Filters that the issues state must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<Box<WorkflowStateFilter>>,
+    /// Filters that issue subscribers must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subscribers: Option<Box<UserCollectionFilter>>,
+    /// \[Internal\] Filters that the issue's suggestions must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub suggestions: Option<Box<IssueSuggestionCollectionFilter>>,
+    /// Filters that the issues team must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team: Option<Box<TeamFilter>>,
+    /// Comparator for the issues title.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<Box<StringComparator>>,
+    /// \[Internal\] Triage time (entered triaged -> triaged) comparator.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub triage_time: Option<Box<NullableDurationComparator>>,
+    /// Comparator for the issues triaged at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub triaged_at: Option<Box<NullableDateComparator>>,
+    /// Comparator for the updated at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<Box<DateComparator>>,
+}
+
+/// Comparator for issue identifiers.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueIDComparator {
+    /// Equals constraint.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub eq: Option<String>,
+    /// In-array constraint.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#in: Option<Vec<String>>,
+    /// Not-equals constraint.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub neq: Option<String>,
+    /// Not-in-array constraint.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nin: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueImportUpdateInput {
+    /// The mapping configuration for the import.
+    pub mapping: JSONObject,
+}
+
+impl IssueImportUpdateInput {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(mapping: JSONObject) -> Self {
+        Self {
+            mapping,
+        }
+    }
+}
+
+/// Issue label filtering options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueLabelCollectionFilter {
+    /// Compound filters, all of which need to be matched by the label.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub and: Option<Vec<IssueLabelCollectionFilter>>,
+    /// Comparator for the created at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<Box<DateComparator>>,
+    /// Filters that the issue labels creator must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub creator: Option<Box<NullableUserFilter>>,
+    /// Filters that needs to be matched by all issue labels.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub every: Option<Box<IssueLabelFilter>>,
+    /// Comparator for the identifier.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<Box<IDComparator>>,
+    /// Comparator for whether the label is a group label.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_group: Option<Box<BooleanComparator>>,
+    /// Comparator for the collection length.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub length: Option<Box<NumberComparator>>,
+    /// Comparator for the name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<Box<StringComparator>>,
+    /// Filter based on the existence of the relation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub null: Option<bool>,
+    /// Compound filters, one of which need to be matched by the label.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub or: Option<Vec<IssueLabelCollectionFilter>>,
+    /// Filters that the issue label's parent label must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent: Option<Box<IssueLabelFilter>>,
+    /// Filters that needs to be matched by some issue labels.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub some: Option<Box<IssueLabelFilter>>,
+    /// Filters that the issue labels team must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team: Option<Box<NullableTeamFilter>>,
+    /// Comparator for the updated at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<Box<DateComparator>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueLabelCreateInput {
+    /// The color of the label.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    /// The description of the label.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The identifier in UUID v4 format. If none is provided, the backend will generate one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// Whether the label is a group.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_group: Option<bool>,
+    /// The name of the label.
+    pub name: String,
+    /// The identifier of the parent label.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_id: Option<String>,
+    /// When the label was retired.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retired_at: Option<DateTime>,
+    /// The team associated with the label. If not given, the label will be associated with the entire workspace.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team_id: Option<String>,
+}
+
+impl IssueLabelCreateInput {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(name: String) -> Self {
+        Self {
+            color: None,
+            description: None,
+            id: None,
+            is_group: None,
+            name,
+            parent_id: None,
+            retired_at: None,
+            team_id: None,
+        }
+    }
+}
+
+/// Issue label filtering options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueLabelFilter {
+    /// Compound filters, all of which need to be matched by the label.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub and: Option<Vec<IssueLabelFilter>>,
+    /// Comparator for the created at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<Box<DateComparator>>,
+    /// Filters that the issue labels creator must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub creator: Option<Box<NullableUserFilter>>,
+    /// Comparator for the identifier.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<Box<IDComparator>>,
+    /// Comparator for whether the label is a group label.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_group: Option<Box<BooleanComparator>>,
+    /// Comparator for the name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<Box<StringComparator>>,
+    /// Compound filters, one of which need to be matched by the label.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub or: Option<Vec<IssueLabelFilter>>,
+    /// Filters that the issue label's parent label must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent: Option<Box<IssueLabelFilter>>,
+    /// Filters that the issue labels team must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team: Option<Box<NullableTeamFilter>>,
+    /// Comparator for the updated at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<Box<DateComparator>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueLabelUpdateInput {
+    /// The color of the label.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    /// The description of the label.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Whether the label is a group.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_group: Option<bool>,
+    /// The name of the label.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The identifier of the parent label.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_id: Option<String>,
+    /// When the label was retired.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retired_at: Option<DateTime>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueRelationCreateInput {
+    /// The identifier in UUID v4 format. If none is provided, the backend will generate one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The identifier of the issue that is related to another issue. Can be a UUID or issue identifier (e.g., 'LIN-123').
+    pub issue_id: String,
+    /// The identifier of the related issue. Can be a UUID or issue identifier (e.g., 'LIN-123').
+    pub related_issue_id: String,
+    /// The type of relation of the issue to the related issue.
+    pub r#type: IssueRelationType,
+}
+
+impl IssueRelationCreateInput {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(issue_id: String, related_issue_id: String, r#type: IssueRelationType) -> Self {
+        Self {
+            id: None,
+            issue_id,
+            related_issue_id,
+            r#type,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueRelationUpdateInput {
+    /// The identifier of the issue that is related to another issue. Can be a UUID or issue identifier (e.g., 'LIN-123').
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_id: Option<String>,
+    /// The identifier of the related issue. Can be a UUID or issue identifier (e.g., 'LIN-123').
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub related_issue_id: Option<String>,
+    /// The type of relation of the issue to the related issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+}
+
+/// Issue sorting options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueSortInput {
+    /// \[Internal\] Sort by the accumulated time in the current workflow state
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub accumulated_state_updated_at: Option<Box<TimeInStatusSort>>,
+    /// Sort by assignee name
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub assignee: Option<Box<AssigneeSort>>,
+    /// Sort by issue completion date
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub completed_at: Option<Box<CompletedAtSort>>,
+    /// Sort by issue creation date
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<Box<CreatedAtSort>>,
+    /// Sort by customer name
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer: Option<Box<CustomerSort>>,
+    /// Sort by number of customers associated with the issue
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer_count: Option<Box<CustomerCountSort>>,
+    /// Sort by number of important customers associated with the issue
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer_important_count: Option<Box<CustomerImportantCountSort>>,
+    /// Sort by customer revenue
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer_revenue: Option<Box<CustomerRevenueSort>>,
+    /// Sort by Cycle start date
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cycle: Option<Box<CycleSort>>,
+    /// Sort by delegate name
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub delegate: Option<Box<DelegateSort>>,
+    /// Sort by issue due date
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub due_date: Option<Box<DueDateSort>>,
+    /// Sort by estimate
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub estimate: Option<Box<EstimateSort>>,
+    /// Sort by label
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<Box<LabelSort>>,
+    /// Sort by label group
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label_group: Option<Box<LabelGroupSort>>,
+    /// \[ALPHA\] Sort by number of links associated with the issue
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub link_count: Option<Box<LinkCountSort>>,
+    /// Sort by manual order
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub manual: Option<Box<ManualSort>>,
+    /// Sort by Project Milestone target date
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub milestone: Option<Box<MilestoneSort>>,
+    /// Sort by priority
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority: Option<Box<PrioritySort>>,
+    /// Sort by Project name
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project: Option<Box<ProjectSort>>,
+    /// Sort by the root issue
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub root_issue: Option<Box<RootIssueSort>>,
+    /// Sort by SLA status
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sla_status: Option<Box<SlaStatusSort>>,
+    /// Sort by Team name
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team: Option<Box<TeamSort>>,
+    /// Sort by issue title
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<Box<TitleSort>>,
+    /// Sort by issue update date
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<Box<UpdatedAtSort>>,
+    /// Sort by workflow state type
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub workflow_state: Option<Box<WorkflowStateSort>>,
+}
+
+/// IssueSuggestion collection filtering options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueSuggestionCollectionFilter {
+    /// Compound filters, all of which need to be matched by the suggestion.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub and: Option<Vec<IssueSuggestionCollectionFilter>>,
+    /// Comparator for the created at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<Box<DateComparator>>,
+    /// Filters that needs to be matched by all suggestions.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub every: Option<Box<IssueSuggestionFilter>>,
+    /// Comparator for the identifier.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<Box<IDComparator>>,
+    /// Comparator for the collection length.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub length: Option<Box<NumberComparator>>,
+    /// Compound filters, one of which need to be matched by the suggestion.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub or: Option<Vec<IssueSuggestionCollectionFilter>>,
+    /// Filters that needs to be matched by some suggestions.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub some: Option<Box<IssueSuggestionFilter>>,
+    /// Comparator for the suggestion state.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<Box<StringComparator>>,
+    /// Filters that the suggested label must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub suggested_label: Option<Box<IssueLabelFilter>>,
+    /// Filters that the suggested project must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub suggested_project: Option<Box<NullableProjectFilter>>,
+    /// Filters that the suggested team must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub suggested_team: Option<Box<NullableTeamFilter>>,
+    /// Filters that the suggested user must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub suggested_user: Option<Box<NullableUserFilter>>,
+    /// Comparator for the suggestion type.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<Box<StringComparator>>,
+    /// Comparator for the updated at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<Box<DateComparator>>,
+}
+
+/// IssueSuggestion filtering options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueSuggestionFilter {
+    /// Compound filters, all of which need to be matched by the suggestion.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub and: Option<Vec<IssueSuggestionFilter>>,
+    /// Comparator for the created at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<Box<DateComparator>>,
+    /// Comparator for the identifier.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<Box<IDComparator>>,
+    /// Compound filters, one of which need to be matched by the suggestion.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub or: Option<Vec<IssueSuggestionFilter>>,
+    /// Comparator for the suggestion state.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<Box<StringComparator>>,
+    /// Filters that the suggested label must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub suggested_label: Option<Box<IssueLabelFilter>>,
+    /// Filters that the suggested project must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub suggested_project: Option<Box<NullableProjectFilter>>,
+    /// Filters that the suggested team must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub suggested_team: Option<Box<NullableTeamFilter>>,
+    /// Filters that the suggested user must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub suggested_user: Option<Box<NullableUserFilter>>,
+    /// Comparator for the suggestion type.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<Box<StringComparator>>,
+    /// Comparator for the updated at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<Box<DateComparator>>,
+}
+
+/// \[ALPHA\] The properties of the issueToRelease to create.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueToReleaseCreateInput {
+    /// The identifier in UUID v4 format. If none is provided, the backend will generate one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The identifier of the issue. Can be a UUID or issue identifier (e.g., 'LIN-123').
+    pub issue_id: String,
+    /// The identifier of the release
+    pub release_id: String,
+}
+
+impl IssueToReleaseCreateInput {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(issue_id: String, release_id: String) -> Self {
+        Self {
+            id: None,
+            issue_id,
+            release_id,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueUpdateInput {
+    /// The identifiers of the issue labels to be added to this issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub added_label_ids: Option<Vec<String>>,
+    /// The identifier of the user to assign the issue to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub assignee_id: Option<String>,
+    /// Whether the issue was automatically closed because its parent issue was closed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_closed_by_parent_closing: Option<bool>,
+    /// The cycle associated with the issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cycle_id: Option<String>,
+    /// The identifier of the agent user to delegate the issue to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub delegate_id: Option<String>,
+    /// The issue description in markdown format.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// \[Internal\] The issue description as a Prosemirror document.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description_data: Option<JSON>,
+    /// The date at which the issue is due.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub due_date: Option<TimelessDate>,
+    /// The estimated complexity of the issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub estimate: Option<i64>,
+    /// The identifiers of the issue labels associated with this ticket.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label_ids: Option<Vec<String>>,
+    /// The ID of the last template applied to the issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_applied_template_id: Option<String>,
+    /// The identifier of the parent issue. Can be a UUID or issue identifier (e.g., 'LIN-123').
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_id: Option<String>,
+    /// The priority of the issue. 0 = No priority, 1 = Urgent, 2 = High, 3 = Normal, 4 = Low.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority: Option<i64>,
+    /// The position of the issue related to other issues, when ordered by priority.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority_sort_order: Option<f64>,
+    /// The project associated with the issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_id: Option<String>,
+    /// The project milestone associated with the issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_milestone_id: Option<String>,
+    /// The identifiers of the issue labels to be removed from this issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub removed_label_ids: Option<Vec<String>>,
+    /// \[Internal\] The timestamp at which an issue will be considered in breach of SLA.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sla_breaches_at: Option<DateTime>,
+    /// \[Internal\] The timestamp at which the issue's SLA was started.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sla_started_at: Option<DateTime>,
+    /// The SLA day count type for the issue. Whether SLA should be business days only or calendar days (default).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sla_type: Option<SLADayCountType>,
+    /// The identifier of the user who snoozed the issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub snoozed_by_id: Option<String>,
+    /// The time until an issue will be snoozed in Triage view.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub snoozed_until_at: Option<DateTime>,
+    /// The position of the issue related to other issues.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort_order: Option<f64>,
+    /// The team state of the issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state_id: Option<String>,
+    /// The position of the issue in parent's sub-issue list.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sub_issue_sort_order: Option<f64>,
+    /// The identifiers of the users subscribing to this ticket.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subscriber_ids: Option<Vec<String>>,
+    /// The identifier of the team associated with the issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team_id: Option<String>,
+    /// The issue title.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Whether the issue has been trashed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trashed: Option<bool>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JiraConfigurationInput {
+    /// The Jira personal access token.
+    pub access_token: String,
+    /// The Jira user's email address. A username is also accepted on Jira Server / DC.
+    pub email: String,
+    /// The Jira installation hostname.
+    pub hostname: String,
+    /// Whether this integration will be setup using the manual webhook flow.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub manual_setup: Option<bool>,
+}
+
+impl JiraConfigurationInput {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(access_token: String, email: String, hostname: String) -> Self {
+        Self {
+            access_token,
+            email,
+            hostname,
+            manual_setup: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JiraLinearMappingInput {
+    /// Whether the sync for this mapping is bidirectional.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bidirectional: Option<bool>,
+    /// Whether this mapping is the default one for issue creation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default: Option<bool>,
+    /// The Jira id for this project.
+    pub jira_project_id: String,
+    /// The Linear team id to map to the given project.
+    pub linear_team_id: String,
+}
+
+impl JiraLinearMappingInput {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(jira_project_id: String, linear_team_id: String) -> Self {
+        Self {
+            bidirectional: None,
+            default: None,
+            jira_project_id,
+            linear_team_id,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JiraPersonalSettingsInput {
+    /// The name of the Jira site currently authorized through the integration.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub site_name: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JiraProjectDataInput {
+    /// The Jira id for this project.
+    pub id: String,
+    /// The Jira key for this project, such as ENG.
+    pub key: String,
+    /// The Jira name for this project, such as Engineering.
+    pub name: String,
+}
+
+impl JiraProjectDataInput {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(id: String, key: String, name: String) -> Self {
+        Self {
+            id,
+            key,
+            name,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JiraSettingsInput {
+    /// Whether this integration is for Jira Server or not.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_jira_server: Option<bool>,
+    /// The label of the Jira instance, for visual identification purposes only
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    /// Whether this integration is using a manual setup flow.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub manual_setup: Option<bool>,
+    /// The mapping of Jira project id => Linear team id.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_mapping: Option<Vec<JiraLinearMappingInput>>,
+    /// The Jira projects for the organization.
+    pub projects: Vec<JiraProjectDataInput>,
+    /// Whether the user needs to provide setup information about the webhook to complete the integration setup. Only relevant for integrations that use a manual setup flow
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub setup_pending: Option<bool>,
+}
+
+impl JiraSettingsInput {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(projects: Vec<JiraProjectDataInput>) -> Self {
+        Self {
+            is_jira_server: None,
+            label: None,
+            manual_setup: None,
+            project_mapping: None,
+            projects,
+            setup_pending: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JiraUpdateInput {
+    /// The Jira personal access token.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub access_token: Option<String>,
+    /// Whether to delete the current manual webhook configuration.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub delete_webhook: Option<bool>,
+    /// The Jira user email address associated with the personal access token.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    /// The id of the integration to update.
+    pub id: String,
+    /// Whether the Jira instance does not support webhook secrets.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub no_secret: Option<bool>,
+    /// Whether to refresh Jira metadata for the integration.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub update_metadata: Option<bool>,
+    /// Whether to refresh Jira Projects for the integration.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub update_projects: Option<bool>,
+    /// Webhook secret for a new manual configuration.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub webhook_secret: Option<String>,
+}
+
+impl JiraUpdateInput {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(id: String) -> Self {
+        Self {
+            access_token: None,
+            delete_webhook: None,
+            email: None,
+            id,
+            no_secret: None,
+            update_metadata: None,
+            update_projects: None,
+            webhook_secret: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JoinOrganizationInput {
+    /// An optional invite link for an organization.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub invite_link: Option<String>,
+    /// The identifier of the organization.
+    pub organization_id: String,
+}
+
+impl JoinOrganizationInput {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(organization_id: String) -> Self {
+        Self {
+            invite_link: None,
+            organization_id,
+        }
+    }
+}
+
+/// Issue label-group sorting options.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LabelGroupSort {
+    /// The label-group id to sort by
+    pub label_group_id: String,
+    /// Whether nulls should be sorted first or last
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nulls: Option<PaginationNulls>,
+    /// The order for the individual sort
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order: Option<PaginationSortOrder>,
+}
+
+impl LabelGroupSort {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(label_group_id: String) -> Self {
+        Self {
+            label_group_id,
+            nulls: None,
+            order: None,
+        }
+    }
+}
+
+/// Issue label sorting options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LabelSort {
+    /// Whether nulls should be sorted first or last
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nulls: Option<PaginationNulls>,
+    /// The order for the individual sort
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order: Option<PaginationSortOrder>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LaunchDarklySettingsInput {
+    /// The environment of the LaunchDarkly integration.
+    pub environment: String,
+    /// The project key of the LaunchDarkly integration.
+    pub project_key: String,
+}
+
+impl LaunchDarklySettingsInput {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(environment: String, project_key: String) -> Self {
+        Self {
+            environment,
+            project_key,
+        }
+    }
+}
+
+/// \[ALPHA\] Issue link count sorting options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LinkCountSort {
+    /// Whether nulls should be sorted first or last
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nulls: Option<PaginationNulls>,
+    /// The order for the individual sort
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order: Option<PaginationSortOrder>,
+}
+
+/// Issue manual sorting options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ManualSort {
+    /// Whether nulls should be sorted first or last
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nulls: Option<PaginationNulls>,
+    /// The order for the individual sort
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order: Option<PaginationSortOrder>,
+}
+
+/// Issue project milestone options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MilestoneSort {
+    /// Whether nulls should be sorted first or last
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nulls: Option<PaginationNulls>,
+    /// The order for the individual sort
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order: Option<PaginationSortOrder>,
+}
+
+/// Customer name sorting options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NameSort {
+    /// Whether nulls should be sorted first or last
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nulls: Option<PaginationNulls>,
+    /// The order for the individual sort
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order: Option<PaginationSortOrder>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationCategoryPreferencesInput {
+    /// The preferences for notifications about apps and integrations.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub apps_and_integrations: Option<Box<PartialNotificationChannelPreferencesInput>>,
+    /// The preferences for notifications about assignments.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub assignments: Option<Box<PartialNotificationChannelPreferencesInput>>,
+    /// The preferences for notifications about comments and replies.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comments_and_replies: Option<Box<PartialNotificationChannelPreferencesInput>>,
+    /// The preferences for notifications about customers.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customers: Option<Box<PartialNotificationChannelPreferencesInput>>,
+    /// The preferences for notifications about document changes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub document_changes: Option<Box<PartialNotificationChannelPreferencesInput>>,
+    /// The preferences for notifications about feed summaries.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub feed: Option<Box<PartialNotificationChannelPreferencesInput>>,
+    /// The preferences for notifications about mentions.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mentions: Option<Box<PartialNotificationChannelPreferencesInput>>,
+    /// The preferences for notifications about posts and updates.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub posts_and_updates: Option<Box<PartialNotificationChannelPreferencesInput>>,
+    /// The preferences for notifications about reactions.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reactions: Option<Box<PartialNotificationChannelPreferencesInput>>,
+    /// The preferences for notifications about reminders.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reminders: Option<Box<PartialNotificationChannelPreferencesInput>>,
+    /// The preferences for notifications about reviews.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reviews: Option<Box<PartialNotificationChannelPreferencesInput>>,
+    /// The preferences for notifications about status changes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status_changes: Option<Box<PartialNotificationChannelPreferencesInput>>,
+    /// The preferences for notifications about subscriptions.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subscriptions: Option<Box<PartialNotificationChannelPreferencesInput>>,
+    /// The preferences for notifications about triage.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub triage: Option<Box<PartialNotificationChannelPreferencesInput>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationDeliveryPreferencesChannelInput {
+    /// The schedule for notifications on this channel.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schedule: Option<Box<NotificationDeliveryPreferencesScheduleInput>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationDeliveryPreferencesDayInput {
+    /// The time notifications end.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end: Option<String>,
+    /// The time notifications start.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationDeliveryPreferencesInput {
+    /// The delivery preferences for the mobile channel.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mobile: Option<Box<NotificationDeliveryPreferencesChannelInput>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationDeliveryPreferencesScheduleInput {
+    /// Whether the schedule is disabled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub disabled: Option<bool>,
+    /// Delivery preferences for Friday.
+    pub friday: Box<NotificationDeliveryPreferencesDayInput>,
+    /// Delivery preferences for Monday.
+    pub monday: Box<NotificationDeliveryPreferencesDayInput>,
+    /// Delivery preferences for Saturday.
+    pub saturday: Box<NotificationDeliveryPreferencesDayInput>,
+    /// Delivery preferences for Sunday.
+    pub sunday: Box<NotificationDeliveryPreferencesDayInput>,
+    /// Delivery preferences for Thursday.
+    pub thursday: Box<NotificationDeliveryPreferencesDayInput>,
+    /// Delivery preferences for Tuesday.
+    pub tuesday: Box<NotificationDeliveryPreferencesDayInput>,
+    /// Delivery preferences for Wednesday.
+    pub wednesday: Box<NotificationDeliveryPreferencesDayInput>,
+}
+
+impl NotificationDeliveryPreferencesScheduleInput {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(friday: Box<NotificationDeliveryPreferencesDayInput>, monday: Box<NotificationDeliveryPreferencesDayInput>, saturday: Box<NotificationDeliveryPreferencesDayInput>, sunday: Box<NotificationDeliveryPreferencesDayInput>, thursday: Box<NotificationDeliveryPreferencesDayInput>, tuesday: Box<NotificationDeliveryPreferencesDayInput>, wednesday: Box<NotificationDeliveryPreferencesDayInput>) -> Self {
+        Self {
+            disabled: None,
+            friday,
+            monday,
+            saturday,
+            sunday,
+            thursday,
+            tuesday,
+            wednesday,
+        }
+    }
+}
+
+/// Describes the type and id of the entity to target for notifications.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationEntityInput {
+    /// The id of the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The id of the initiative related to the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative_id: Option<String>,
+    /// The id of the initiative update related to the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative_update_id: Option<String>,
+    /// The id of the issue related to the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_id: Option<String>,
+    /// The id of the OAuth client approval related to the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub oauth_client_approval_id: Option<String>,
+    /// \[DEPRECATED\] The id of the project related to the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_id: Option<String>,
+    /// The id of the project update related to the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_update_id: Option<String>,
+}
+
+/// Notification filtering options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationFilter {
+    /// Compound filters, all of which need to be matched by the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub and: Option<Vec<NotificationFilter>>,
+    /// Comparator for the archived at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<Box<DateComparator>>,
+    /// Comparator for the created at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<Box<DateComparator>>,
+    /// Comparator for the identifier.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<Box<IDComparator>>,
+    /// Compound filters, one of which need to be matched by the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub or: Option<Vec<NotificationFilter>>,
+    /// Comparator for the notification type.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<Box<StringComparator>>,
+    /// Comparator for the updated at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<Box<DateComparator>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationSubscriptionCreateInput {
+    /// Whether the subscription is active.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+    /// The type of view to which the notification subscription context is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub context_view_type: Option<ContextViewType>,
+    /// The identifier of the custom view to subscribe to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub custom_view_id: Option<String>,
+    /// The identifier of the customer to subscribe to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer_id: Option<String>,
+    /// The identifier of the cycle to subscribe to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cycle_id: Option<String>,
+    /// The identifier in UUID v4 format. If none is provided, the backend will generate one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The identifier of the initiative to subscribe to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative_id: Option<String>,
+    /// The identifier of the label to subscribe to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label_id: Option<String>,
+    /// The types of notifications of the subscription.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notification_subscription_types: Option<Vec<String>>,
+    /// The identifier of the project to subscribe to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_id: Option<String>,
+    /// The identifier of the team to subscribe to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team_id: Option<String>,
+    /// The type of user view to which the notification subscription context is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_context_view_type: Option<UserContextViewType>,
+    /// The identifier of the user to subscribe to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationSubscriptionUpdateInput {
+    /// Whether the subscription is active.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+    /// The types of notifications of the subscription.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notification_subscription_types: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationUpdateInput {
+    /// The id of the project update related to the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative_update_id: Option<String>,
+    /// The id of the project update related to the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_update_id: Option<String>,
+    /// The time when notification was marked as read.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub read_at: Option<DateTime>,
+    /// The time until a notification will be snoozed. After that it will appear in the inbox again.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub snoozed_until_at: Option<DateTime>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotionSettingsInput {
+    /// The ID of the Notion workspace being connected.
+    pub workspace_id: String,
+    /// The name of the Notion workspace being connected.
+    pub workspace_name: String,
+}
+
+impl NotionSettingsInput {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(workspace_id: String, workspace_name: String) -> Self {
+        Self {
+            workspace_id,
+            workspace_name,
+        }
+    }
+}
+
+/// Comment filtering options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NullableCommentFilter {
+    /// Compound filters, all of which need to be matched by the comment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub and: Option<Vec<NullableCommentFilter>>,
+    /// Comparator for the comment's body.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<Box<StringComparator>>,
+    /// Comparator for the created at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<Box<DateComparator>>,
+    /// Filters that the comment's document content must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub document_content: Option<Box<NullableDocumentContentFilter>>,
+    /// Comparator for the identifier.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<Box<IDComparator>>,
+    /// Filters that the comment's issue must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue: Option<Box<NullableIssueFilter>>,
+    /// Filters that the comment's customer needs must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub needs: Option<Box<CustomerNeedCollectionFilter>>,
+    /// Filter based on the existence of the relation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub null: Option<bool>,
+    /// Compound filters, one of which need to be matched by the comment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub or: Option<Vec<NullableCommentFilter>>,
+    /// Filters that the comment parent must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent: Option<Box<NullableCommentFilter>>,
+    /// Filters that the comment's project update must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_update: Option<Box<NullableProjectUpdateFilter>>,
+    /// Filters that the comment's reactions must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reactions: Option<Box<ReactionCollectionFilter>>,
+    /// Comparator for the updated at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<Box<DateComparator>>,
+    /// Filters that the comment's creator must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<Box<UserFilter>>,
+}
+
+/// Customer filtering options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NullableCustomerFilter {
+    /// Compound filters, all of which need to be matched by the customer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub and: Option<Vec<NullableCustomerFilter>>,
+    /// Comparator for the created at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<Box<DateComparator>>,
+    /// Comparator for the customer's domains.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub domains: Option<Box<StringArrayComparator>>,
+    /// Comparator for the customer's external IDs.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub external_ids: Option<Box<StringArrayComparator>>,
+    /// Comparator for the identifier.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<Box<IDComparator>>,
+    /// Comparator for the customer name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<Box<StringComparator>>,
+    /// Filters that the customer's needs must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub needs: Option<Box<CustomerNeedCollectionFilter>>,
+    /// Filter based on the existence of the relation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub null: Option<bool>,
+    /// Compound filters, one of which need to be matched by the customer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub or: Option<Vec<NullableCustomerFilter>>,
+    /// Filters that the customer owner must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner: Option<Box<NullableUserFilter>>,
+    /// Comparator for the customer generated revenue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub revenue: Option<Box<NumberComparator>>,
+    /// Comparator for the customer size.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub size: Option<Box<NumberComparator>>,
+    /// Comparator for the customer slack channel ID.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slack_channel_id: Option<Box<StringComparator>>,
+    /// Filters that the customer's status must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<Box<CustomerStatusFilter>>,
+    /// Filters that the customer's tier must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tier: Option<Box<CustomerTierFilter>>,
+    /// Comparator for the updated at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<Box<DateComparator>>,
+}
+
+/// Cycle filtering options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NullableCycleFilter {
+    /// Compound filters, all of which need to be matched by the cycle.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub and: Option<Vec<NullableCycleFilter>>,
+    /// Comparator for the cycle completed at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub completed_at: Option<Box<DateComparator>>,
+    /// Comparator for the created at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<Box<DateComparator>>,
+    /// Comparator for the cycle ends at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ends_at: Option<Box<DateComparator>>,
+    /// Comparator for the identifier.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<Box<IDComparator>>,
+    /// Comparator for the inherited cycle ID.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inherited_from_id: Option<Box<IDComparator>>,
+    /// Comparator for the filtering active cycle.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_active: Option<Box<BooleanComparator>>,
+    /// Comparator for the filtering future cycles.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_future: Option<Box<BooleanComparator>>,
+    /// Comparator for filtering for whether the cycle is currently in cooldown.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_in_cooldown: Option<Box<BooleanComparator>>,
+    /// Comparator for the filtering next cycle.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_next: Option<Box<BooleanComparator>>,
+    /// Comparator for the filtering past cycles.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_past: Option<Box<BooleanComparator>>,
+    /// Comparator for the filtering previous cycle.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_previous: Option<Box<BooleanComparator>>,
+    /// Filters that the cycles issues must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issues: Option<Box<IssueCollectionFilter>>,
+    /// Comparator for the cycle name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<Box<StringComparator>>,
+    /// Filter based on the existence of the relation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub null: Option<bool>,
+    /// Comparator for the cycle number.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub number: Option<Box<NumberComparator>>,
+    /// Compound filters, one of which need to be matched by the cycle.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub or: Option<Vec<NullableCycleFilter>>,
+    /// Comparator for the cycle start date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub starts_at: Option<Box<DateComparator>>,
+    /// Filters that the cycles team must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team: Option<Box<TeamFilter>>,
+    /// Comparator for the updated at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<Box<DateComparator>>,
+}
+
+/// Comparator for optional dates.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NullableDateComparator {
+    /// Equals constraint.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub eq: Option<DateTimeOrDuration>,
+    /// Greater-than constraint. Matches any values that are greater than the given value.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gt: Option<DateTimeOrDuration>,
+    /// Greater-than-or-equal constraint. Matches any values that are greater than or equal to the given value.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gte: Option<DateTimeOrDuration>,
+    /// In-array constraint.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#in: Option<Vec<DateTimeOrDuration>>,
+    /// Less-than constraint. Matches any values that are less than the given value.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lt: Option<DateTimeOrDuration>,
+    /// Less-than-or-equal constraint. Matches any values that are less than or equal to the given value.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lte: Option<DateTimeOrDuration>,
+    /// Not-equals constraint.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub neq: Option<DateTimeOrDuration>,
+    /// Not-in-array constraint.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nin: Option<Vec<DateTimeOrDuration>>,
+    /// Null constraint. Matches any non-null values if the given value is false, otherwise it matches null values.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub null: Option<bool>,
+}
+
+/// Document content filtering options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NullableDocumentContentFilter {
+    /// Compound filters, all of which need to be matched by the user.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub and: Option<Vec<NullableDocumentContentFilter>>,
+    /// Comparator for the document content.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content: Option<Box<NullableStringComparator>>,
+    /// Comparator for the created at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<Box<DateComparator>>,
+    /// Filters that the document content document must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub document: Option<Box<DocumentFilter>>,
+    /// Comparator for the identifier.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<Box<IDComparator>>,
+    /// Filter based on the existence of the relation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub null: Option<bool>,
+    /// Compound filters, one of which need to be matched by the user.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub or: Option<Vec<NullableDocumentContentFilter>>,
+    /// Filters that the document content project must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project: Option<Box<ProjectFilter>>,
+    /// Comparator for the updated at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<Box<DateComparator>>,
+}
+
+/// Nullable comparator for optional durations.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NullableDurationComparator {
+    /// Equals constraint.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub eq: Option<Duration>,
+    /// Greater-than constraint. Matches any values that are greater than the given value.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gt: Option<Duration>,
+    /// Greater-than-or-equal constraint. Matches any values that are greater than or equal to the given value.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gte: Option<Duration>,
+    /// In-array constraint.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#in: Option<Vec<Duration>>,
+    /// Less-than constraint. Matches any values that are less than the given value.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lt: Option<Duration>,
+    /// Less-than-or-equal constraint. Matches any values that are less than or equal to the given value.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lte: Option<Duration>,
+    /// Not-equals constraint.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub neq: Option<Duration>,
+    /// Not-in-array constraint.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nin: Option<Vec<Duration>>,
+    /// Null constraint. Matches any non-null values if the given value is false, otherwise it matches null values.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub null: Option<bool>,
+}
+
+/// Issue filtering options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NullableIssueFilter {
+    /// \[Internal\] Comparator for the issue's accumulatedStateUpdatedAt date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub accumulated_state_updated_at: Option<Box<NullableDateComparator>>,
+    /// Comparator for the issues added to cycle at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub added_to_cycle_at: Option<Box<NullableDateComparator>>,
+    /// Comparator for the period when issue was added to a cycle.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub added_to_cycle_period: Option<Box<CyclePeriodComparator>>,
+    /// \[Internal\] Age (created -> now) comparator, defined if the issue is still open.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub age_time: Option<Box<NullableDurationComparator>>,
+    /// Compound filters, all of which need to be matched by the issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub and: Option<Vec<NullableIssueFilter>>,
+    /// Comparator for the issues archived at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<Box<NullableDateComparator>>,
+    /// Filters that the issues assignee must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub assignee: Option<Box<NullableUserFilter>>,
+    /// Filters that the issues attachments must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub attachments: Option<Box<AttachmentCollectionFilter>>,
+    /// Comparator for the issues auto archived at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_archived_at: Option<Box<NullableDateComparator>>,
+    /// Comparator for the issues auto closed at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_closed_at: Option<Box<NullableDateComparator>>,
+    /// Comparator for the issues canceled at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub canceled_at: Option<Box<NullableDateComparator>>,
+    /// Filters that the child issues must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub children: Option<Box<IssueCollectionFilter>>,
+    /// Filters that the issues comments must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comments: Option<Box<CommentCollectionFilter>>,
+    /// Comparator for the issues completed at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub completed_at: Option<Box<NullableDateComparator>>,
+    /// Comparator for the created at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<Box<DateComparator>>,
+    /// Filters that the issues creator must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub creator: Option<Box<NullableUserFilter>>,
+    /// Count of customers
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer_count: Option<Box<NumberComparator>>,
+    /// Count of important customers
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer_important_count: Option<Box<NumberComparator>>,
+    /// Filters that the issues cycle must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cycle: Option<Box<NullableCycleFilter>>,
+    /// \[Internal\] Cycle time (started -> completed) comparator.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cycle_time: Option<Box<NullableDurationComparator>>,
+    /// Filters that the issue's delegated agent must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub delegate: Option<Box<NullableUserFilter>>,
+    /// Comparator for the issues description.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<Box<NullableStringComparator>>,
+    /// Comparator for the issues due date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub due_date: Option<Box<NullableTimelessDateComparator>>,
+    /// Comparator for the issues estimate.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub estimate: Option<Box<EstimateComparator>>,
+    /// Comparator for filtering issues which are blocked.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_blocked_by_relations: Option<Box<RelationExistsComparator>>,
+    /// Comparator for filtering issues which are blocking.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_blocking_relations: Option<Box<RelationExistsComparator>>,
+    /// Comparator for filtering issues which are duplicates.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_duplicate_relations: Option<Box<RelationExistsComparator>>,
+    /// Comparator for filtering issues with relations.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_related_relations: Option<Box<RelationExistsComparator>>,
+    /// \[Internal\] Comparator for filtering issues which have suggested assignees.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_suggested_assignees: Option<Box<RelationExistsComparator>>,
+    /// \[Internal\] Comparator for filtering issues which have suggested labels.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_suggested_labels: Option<Box<RelationExistsComparator>>,
+    /// \[Internal\] Comparator for filtering issues which have suggested projects.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_suggested_projects: Option<Box<RelationExistsComparator>>,
+    /// \[Internal\] Comparator for filtering issues which have suggested related issues.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_suggested_related_issues: Option<Box<RelationExistsComparator>>,
+    /// \[Internal\] Comparator for filtering issues which have suggested similar issues.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_suggested_similar_issues: Option<Box<RelationExistsComparator>>,
+    /// \[Internal\] Comparator for filtering issues which have suggested teams.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_suggested_teams: Option<Box<RelationExistsComparator>>,
+    /// Comparator for the identifier.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<Box<IssueIDComparator>>,
+    /// Filters that issue labels must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<Box<IssueLabelCollectionFilter>>,
+    /// Filters that the last applied template must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_applied_template: Option<Box<NullableTemplateFilter>>,
+    /// \[Internal\] Lead time (created -> completed) comparator.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lead_time: Option<Box<NullableDurationComparator>>,
+    /// Filters that the issue's customer needs must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub needs: Option<Box<CustomerNeedCollectionFilter>>,
+    /// Filter based on the existence of the relation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub null: Option<bool>,
+    /// Comparator for the issues number.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub number: Option<Box<NumberComparator>>,
+    /// Compound filters, one of which need to be matched by the issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub or: Option<Vec<NullableIssueFilter>>,
+    /// Filters that the issue parent must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent: Option<Box<NullableIssueFilter>>,
+    /// Comparator for the issues priority. 0 = No priority, 1 = Urgent, 2 = High, 3 = Normal, 4 = Low.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority: Option<Box<NullableNumberComparator>>,
+    /// Filters that the issues project must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project: Option<Box<NullableProjectFilter>>,
+    /// Filters that the issues project milestone must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_milestone: Option<Box<NullableProjectMilestoneFilter>>,
+    /// Filters that the issues reactions must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reactions: Option<Box<ReactionCollectionFilter>>,
+    /// \[ALPHA\] Filters that the recurring issue template must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub recurring_issue_template: Option<Box<NullableTemplateFilter>>,
+    /// \[Internal\] Comparator for the issues content.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub searchable_content: Option<Box<ContentComparator>>,
+    /// Comparator for the issues sla status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sla_status: Option<Box<SlaStatusComparator>>,
+    /// Filters that the issues snoozer must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub snoozed_by: Option<Box<NullableUserFilter>>,
+    /// Comparator for the issues snoozed until date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub snoozed_until_at: Option<Box<NullableDateComparator>>,
+    /// Filters that the source must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_metadata: Option<Box<SourceMetadataComparator>>,
+    /// Comparator for the issues started at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub started_at: Option<Box<NullableDateComparator>>,
+    /// Filters that the issues state must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<Box<WorkflowStateFilter>>,
+    /// Filters that issue subscribers must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subscribers: Option<Box<UserCollectionFilter>>,
+    /// \[Internal\] Filters that the issue's suggestions must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub suggestions: Option<Box<IssueSuggestionCollectionFilter>>,
+    /// Filters that the issues team must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team: Option<Box<TeamFilter>>,
+    /// Comparator for the issues title.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<Box<StringComparator>>,
+    /// \[Internal\] Triage time (entered triaged -> triaged) comparator.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub triage_time: Option<Box<NullableDurationComparator>>,
+    /// Comparator for the issues triaged at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub triaged_at: Option<Box<NullableDateComparator>>,
+    /// Comparator for the updated at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<Box<DateComparator>>,
+}
+
+/// Comparator for optional numbers.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NullableNumberComparator {
+    /// Equals constraint.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub eq: Option<f64>,
+    /// Greater-than constraint. Matches any values that are greater than the given value.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gt: Option<f64>,
+    /// Greater-than-or-equal constraint. Matches any values that are greater than or equal to the given value.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gte: Option<f64>,
+    /// In-array constraint.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#in: Option<Vec<f64>>,
+    /// Less-than constraint. Matches any values that are less than the given value.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lt: Option<f64>,
+    /// Less-than-or-equal constraint. Matches any values that are less than or equal to the given value.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lte: Option<f64>,
+    /// Not-equals constraint.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub neq: Option<f64>,
+    /// Not-in-array constraint.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nin: Option<Vec<f64>>,
+    /// Null constraint. Matches any non-null values if the given value is false, otherwise it matches null values.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub null: Option<bool>,
+}
+
+/// Project filtering options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NullableProjectFilter {
+    /// Filters that the project's team must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub accessible_teams: Option<Box<TeamCollectionFilter>>,
+    /// \[ALPHA\] Comparator for the project activity type: buzzin, active, some, none
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub activity_type: Option<Box<StringComparator>>,
+    /// Compound filters, all of which need to be matched by the project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub and: Option<Vec<NullableProjectFilter>>,
+    /// Comparator for the project cancelation date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub canceled_at: Option<Box<NullableDateComparator>>,
+    /// Comparator for the project completion date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub completed_at: Option<Box<NullableDateComparator>>,
+    /// Filters that the project's completed milestones must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub completed_project_milestones: Option<Box<ProjectMilestoneCollectionFilter>>,
+    /// Comparator for the created at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<Box<DateComparator>>,
+    /// Filters that the projects creator must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub creator: Option<Box<UserFilter>>,
+    /// Count of customers
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer_count: Option<Box<NumberComparator>>,
+    /// Count of important customers
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer_important_count: Option<Box<NumberComparator>>,
+    /// Comparator for filtering projects which are blocked.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_blocked_by_relations: Option<Box<RelationExistsComparator>>,
+    /// Comparator for filtering projects which are blocking.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_blocking_relations: Option<Box<RelationExistsComparator>>,
+    /// \[Deprecated\] Comparator for filtering projects which this is depended on by.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_depended_on_by_relations: Option<Box<RelationExistsComparator>>,
+    /// \[Deprecated\]Comparator for filtering projects which this depends on.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_depends_on_relations: Option<Box<RelationExistsComparator>>,
+    /// Comparator for filtering projects with relations.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_related_relations: Option<Box<RelationExistsComparator>>,
+    /// Comparator for filtering projects with violated dependencies.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_violated_relations: Option<Box<RelationExistsComparator>>,
+    /// Comparator for the project health: onTrack, atRisk, offTrack
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub health: Option<Box<StringComparator>>,
+    /// Comparator for the project health (with age): onTrack, atRisk, offTrack, outdated, noUpdate
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub health_with_age: Option<Box<StringComparator>>,
+    /// Comparator for the identifier.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<Box<IDComparator>>,
+    /// Filters that the projects initiatives must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiatives: Option<Box<InitiativeCollectionFilter>>,
+    /// Filters that the projects issues must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issues: Option<Box<IssueCollectionFilter>>,
+    /// Filters that project labels must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<Box<ProjectLabelCollectionFilter>>,
+    /// Filters that the last applied template must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_applied_template: Option<Box<NullableTemplateFilter>>,
+    /// Filters that the projects lead must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lead: Option<Box<NullableUserFilter>>,
+    /// Filters that the projects members must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub members: Option<Box<UserCollectionFilter>>,
+    /// Comparator for the project name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<Box<StringComparator>>,
+    /// Filters that the project's customer needs must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub needs: Option<Box<CustomerNeedCollectionFilter>>,
+    /// Filters that the project's next milestone must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next_project_milestone: Option<Box<ProjectMilestoneFilter>>,
+    /// Filter based on the existence of the relation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub null: Option<bool>,
+    /// Compound filters, one of which need to be matched by the project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub or: Option<Vec<NullableProjectFilter>>,
+    /// Comparator for the projects priority.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority: Option<Box<NullableNumberComparator>>,
+    /// Filters that the project's milestones must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_milestones: Option<Box<ProjectMilestoneCollectionFilter>>,
+    /// Comparator for the project updates.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_updates: Option<Box<ProjectUpdatesCollectionFilter>>,
+    /// Filters that the projects roadmaps must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub roadmaps: Option<Box<RoadmapCollectionFilter>>,
+    /// \[Internal\] Comparator for the project's content.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub searchable_content: Option<Box<ContentComparator>>,
+    /// Comparator for the project slug ID.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slug_id: Option<Box<StringComparator>>,
+    /// Comparator for the project start date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_date: Option<Box<NullableDateComparator>>,
+    /// Comparator for the project started date (when it was moved to an "In Progress" status).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub started_at: Option<Box<NullableDateComparator>>,
+    /// \[DEPRECATED\] Comparator for the project state.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<Box<StringComparator>>,
+    /// Filters that the project's status must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<Box<ProjectStatusFilter>>,
+    /// Comparator for the project target date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_date: Option<Box<NullableDateComparator>>,
+    /// Comparator for the updated at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<Box<DateComparator>>,
+}
+
+/// Project milestone filtering options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NullableProjectMilestoneFilter {
+    /// Compound filters, all of which need to be matched by the project milestone.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub and: Option<Vec<NullableProjectMilestoneFilter>>,
+    /// Comparator for the created at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<Box<DateComparator>>,
+    /// Comparator for the identifier.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<Box<IDComparator>>,
+    /// Comparator for the project milestone name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<Box<NullableStringComparator>>,
+    /// Filter based on the existence of the relation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub null: Option<bool>,
+    /// Compound filters, one of which need to be matched by the project milestone.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub or: Option<Vec<NullableProjectMilestoneFilter>>,
+    /// Filters that the project milestone's project must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project: Option<Box<NullableProjectFilter>>,
+    /// Comparator for the project milestone target date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_date: Option<Box<NullableDateComparator>>,
+    /// Comparator for the updated at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<Box<DateComparator>>,
+}
+
+/// Nullable project update filtering options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NullableProjectUpdateFilter {
+    /// Compound filters, all of which need to be matched by the project update.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub and: Option<Vec<NullableProjectUpdateFilter>>,
+    /// Comparator for the created at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<Box<DateComparator>>,
+    /// Comparator for the identifier.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<Box<IDComparator>>,
+    /// Filter based on the existence of the relation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub null: Option<bool>,
+    /// Compound filters, one of which need to be matched by the project update.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub or: Option<Vec<NullableProjectUpdateFilter>>,
+    /// Filters that the project update project must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project: Option<Box<ProjectFilter>>,
+    /// Filters that the project updates reactions must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reactions: Option<Box<ReactionCollectionFilter>>,
+    /// Comparator for the updated at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<Box<DateComparator>>,
+    /// Filters that the project update creator must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<Box<UserFilter>>,
+}
+
+/// Comparator for optional strings.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NullableStringComparator {
+    /// Contains constraint. Matches any values that contain the given string.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub contains: Option<String>,
+    /// Contains case insensitive constraint. Matches any values that contain the given string case insensitive.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub contains_ignore_case: Option<String>,
+    /// Contains case and accent insensitive constraint. Matches any values that contain the given string case and accent insensitive.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub contains_ignore_case_and_accent: Option<String>,
+    /// Ends with constraint. Matches any values that end with the given string.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ends_with: Option<String>,
+    /// Equals constraint.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub eq: Option<String>,
+    /// Equals case insensitive. Matches any values that matches the given string case insensitive.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub eq_ignore_case: Option<String>,
+    /// In-array constraint.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#in: Option<Vec<String>>,
+    /// Not-equals constraint.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub neq: Option<String>,
+    /// Not-equals case insensitive. Matches any values that don't match the given string case insensitive.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub neq_ignore_case: Option<String>,
+    /// Not-in-array constraint.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nin: Option<Vec<String>>,
+    /// Doesn't contain constraint. Matches any values that don't contain the given string.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub not_contains: Option<String>,
+    /// Doesn't contain case insensitive constraint. Matches any values that don't contain the given string case insensitive.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub not_contains_ignore_case: Option<String>,
+    /// Doesn't end with constraint. Matches any values that don't end with the given string.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub not_ends_with: Option<String>,
+    /// Doesn't start with constraint. Matches any values that don't start with the given string.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub not_starts_with: Option<String>,
+    /// Null constraint. Matches any non-null values if the given value is false, otherwise it matches null values.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub null: Option<bool>,
+    /// Starts with constraint. Matches any values that start with the given string.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub starts_with: Option<String>,
+    /// Starts with case insensitive constraint. Matches any values that start with the given string.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub starts_with_ignore_case: Option<String>,
+}
+
+/// Team filtering options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NullableTeamFilter {
+    /// Compound filters, all of which need to be matched by the team.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub and: Option<Vec<NullableTeamFilter>>,
+    /// Comparator for the created at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<Box<DateComparator>>,
+    /// Comparator for the team description.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<Box<NullableStringComparator>>,
+    /// Comparator for the identifier.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<Box<IDComparator>>,
+    /// Filters that the teams issues must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issues: Option<Box<IssueCollectionFilter>>,
+    /// Comparator for the team key.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key: Option<Box<StringComparator>>,
+    /// Comparator for the team name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<Box<StringComparator>>,
+    /// Filter based on the existence of the relation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub null: Option<bool>,
+    /// Compound filters, one of which need to be matched by the team.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub or: Option<Vec<NullableTeamFilter>>,
+    /// Filters that the teams parent must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent: Option<Box<NullableTeamFilter>>,
+    /// Comparator for the team privacy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub private: Option<Box<BooleanComparator>>,
+    /// Comparator for the updated at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<Box<DateComparator>>,
+}
+
+/// Template filtering options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NullableTemplateFilter {
+    /// Compound filters, all of which need to be matched by the template.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub and: Option<Vec<NullableTemplateFilter>>,
+    /// Comparator for the created at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<Box<DateComparator>>,
+    /// Comparator for the identifier.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<Box<IDComparator>>,
+    /// Comparator for the inherited template's ID.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inherited_from_id: Option<Box<IDComparator>>,
+    /// Comparator for the template's name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<Box<StringComparator>>,
+    /// Filter based on the existence of the relation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub null: Option<bool>,
+    /// Compound filters, one of which need to be matched by the template.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub or: Option<Vec<NullableTemplateFilter>>,
+    /// Comparator for the template's type.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<Box<StringComparator>>,
+    /// Comparator for the updated at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<Box<DateComparator>>,
+}
+
+/// Comparator for optional timeless dates.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NullableTimelessDateComparator {
+    /// Equals constraint.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub eq: Option<TimelessDateOrDuration>,
+    /// Greater-than constraint. Matches any values that are greater than the given value.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gt: Option<TimelessDateOrDuration>,
+    /// Greater-than-or-equal constraint. Matches any values that are greater than or equal to the given value.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gte: Option<TimelessDateOrDuration>,
+    /// In-array constraint.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#in: Option<Vec<TimelessDateOrDuration>>,
+    /// Less-than constraint. Matches any values that are less than the given value.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lt: Option<TimelessDateOrDuration>,
+    /// Less-than-or-equal constraint. Matches any values that are less than or equal to the given value.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lte: Option<TimelessDateOrDuration>,
+    /// Not-equals constraint.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub neq: Option<TimelessDateOrDuration>,
+    /// Not-in-array constraint.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nin: Option<Vec<TimelessDateOrDuration>>,
+    /// Null constraint. Matches any non-null values if the given value is false, otherwise it matches null values.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub null: Option<bool>,
+}
+
+/// User filtering options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NullableUserFilter {
+    /// Comparator for the user's activity status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub active: Option<Box<BooleanComparator>>,
+    /// Comparator for the user's admin status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub admin: Option<Box<BooleanComparator>>,
+    /// Compound filters, all of which need to be matched by the user.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub and: Option<Vec<NullableUserFilter>>,
+    /// Comparator for the user's app status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub app: Option<Box<BooleanComparator>>,
+    /// Filters that the users assigned issues must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub assigned_issues: Option<Box<IssueCollectionFilter>>,
+    /// Comparator for the created at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<Box<DateComparator>>,
+    /// Comparator for the user's display name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<Box<StringComparator>>,
+    /// Comparator for the user's email.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email: Option<Box<StringComparator>>,
+    /// Comparator for the identifier.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<Box<IDComparator>>,
+    /// Comparator for the user's invited status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub invited: Option<Box<BooleanComparator>>,
+    /// Comparator for the user's invited status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_invited: Option<Box<BooleanComparator>>,
+    /// Filter based on the currently authenticated user. Set to true to filter for the authenticated user, false for any other user.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_me: Option<Box<BooleanComparator>>,
+    /// Comparator for the user's name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<Box<StringComparator>>,
+    /// Filter based on the existence of the relation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub null: Option<bool>,
+    /// Compound filters, one of which need to be matched by the user.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub or: Option<Vec<NullableUserFilter>>,
+    /// \[Internal\] Comparator for the user's owner status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner: Option<Box<BooleanComparator>>,
+    /// Comparator for the updated at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<Box<DateComparator>>,
+}
+
+/// Comparator for numbers.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NumberComparator {
+    /// Equals constraint.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub eq: Option<f64>,
+    /// Greater-than constraint. Matches any values that are greater than the given value.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gt: Option<f64>,
+    /// Greater-than-or-equal constraint. Matches any values that are greater than or equal to the given value.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gte: Option<f64>,
+    /// In-array constraint.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#in: Option<Vec<f64>>,
+    /// Less-than constraint. Matches any values that are less than the given value.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lt: Option<f64>,
+    /// Less-than-or-equal constraint. Matches any values that are less than or equal to the given value.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lte: Option<f64>,
+    /// Not-equals constraint.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub neq: Option<f64>,
+    /// Not-in-array constraint.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nin: Option<Vec<f64>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OnboardingCustomerSurvey {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub company_role: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub company_size: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OpsgenieInput {
+    /// The date when the Opsgenie API failed with an unauthorized error.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub api_failed_with_unauthorized_error_at: Option<DateTime>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrganizationDomainCreateInput {
+    /// The authentication type this domain is for.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auth_type: Option<String>,
+    /// The identifier in UUID v4 format. If none is provided, the backend will generate one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The identity provider to which to add the domain.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub identity_provider_id: Option<String>,
+    /// The domain name to add.
+    pub name: String,
+    /// The email address to which to send the verification code.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verification_email: Option<String>,
+}
+
+impl OrganizationDomainCreateInput {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(name: String) -> Self {
+        Self {
+            auth_type: None,
+            id: None,
+            identity_provider_id: None,
+            name,
+            verification_email: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrganizationDomainUpdateInput {
+    /// Prevent users with this domain to create new workspaces. Only allowed to set on claimed domains!
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub disable_organization_creation: Option<bool>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrganizationDomainVerificationInput {
+    /// The identifier in UUID v4 format of the domain being verified.
+    pub organization_domain_id: String,
+    /// The verification code sent via email.
+    pub verification_code: String,
+}
+
+impl OrganizationDomainVerificationInput {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(organization_domain_id: String, verification_code: String) -> Self {
+        Self {
+            organization_domain_id,
+            verification_code,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrganizationInviteCreateInput {
+    /// The email of the invitee.
+    pub email: String,
+    /// The identifier in UUID v4 format. If none is provided, the backend will generate one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// \[INTERNAL\] Optional metadata about the invite.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<JSONObject>,
+    /// What user role the invite should grant.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub role: Option<UserRoleType>,
+    /// The teams that the user has been invited to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team_ids: Option<Vec<String>>,
+}
+
+impl OrganizationInviteCreateInput {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(email: String) -> Self {
+        Self {
+            email,
+            id: None,
+            metadata: None,
+            role: None,
+            team_ids: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrganizationInviteUpdateInput {
+    /// The teams that the user has been invited to.
+    pub team_ids: Vec<String>,
+}
+
+impl OrganizationInviteUpdateInput {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(team_ids: Vec<String>) -> Self {
+        Self {
+            team_ids,
+        }
+    }
+}
+
+/// \[INTERNAL\] Organization IP restriction configuration.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrganizationIpRestrictionInput {
+    /// Optional restriction description.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Whether the restriction is enabled.
+    pub enabled: bool,
+    /// IP range in CIDR format.
+    pub range: String,
+    /// Restriction type.
+    pub r#type: String,
+}
+
+impl OrganizationIpRestrictionInput {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(enabled: bool, range: String, r#type: String) -> Self {
+        Self {
+            description: None,
+            enabled,
+            range,
+            r#type,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrganizationSecuritySettingsInput {
+    /// The minimum role required to manage agent guidance prompts and settings.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub agent_guidance_role: Option<UserRoleType>,
+    /// The minimum role required to manage API settings.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub api_settings_role: Option<UserRoleType>,
+    /// The minimum role required to import data.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub import_role: Option<UserRoleType>,
+    /// The minimum role required to invite users.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub invitations_role: Option<UserRoleType>,
+    /// The minimum role required to manage workspace labels.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label_management_role: Option<UserRoleType>,
+    /// The minimum role required to create personal API keys.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub personal_api_keys_role: Option<UserRoleType>,
+    /// The minimum role required to create teams.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team_creation_role: Option<UserRoleType>,
+    /// The minimum role required to manage workspace templates.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub template_management_role: Option<UserRoleType>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrganizationStartTrialInput {
+    /// The plan type to trial.
+    pub plan_type: String,
+}
+
+impl OrganizationStartTrialInput {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(plan_type: String) -> Self {
+        Self {
+            plan_type,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrganizationUpdateInput {
+    /// \[INTERNAL\] Whether the organization has enabled the AI add-on.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ai_addon_enabled: Option<bool>,
+    /// Whether the organization has enabled AI discussion summaries for issues.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ai_discussion_summaries_enabled: Option<bool>,
+    /// \[INTERNAL\] Whether the organization has opted in to AI telemetry.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ai_telemetry_enabled: Option<bool>,
+    /// Whether the organization has enabled resolved thread AI summaries.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ai_thread_summaries_enabled: Option<bool>,
+    /// \[INTERNAL\] Permitted AI providers in order of preference. Empty array means all providers are allowed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allowed_ai_providers: Option<Vec<String>>,
+    /// List of services that are allowed to be used for login.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allowed_auth_services: Option<Vec<String>>,
+    /// Allowed file upload content types.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allowed_file_upload_content_types: Option<Vec<String>>,
+    /// \[INTERNAL\] Whether code intelligence is enabled for the organization.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub code_intelligence_enabled: Option<bool>,
+    /// \[INTERNAL\] GitHub repository in owner/repo format for code intelligence.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub code_intelligence_repository: Option<String>,
+    /// \[INTERNAL\] Configuration settings for the Customers feature.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customers_configuration: Option<JSONObject>,
+    /// \[INTERNAL\] Whether the organization is using customers.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customers_enabled: Option<bool>,
+    /// Default schedule for how often feed summaries are generated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_feed_summary_schedule: Option<FeedSummarySchedule>,
+    /// Whether the organization has enabled the feed feature.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub feed_enabled: Option<bool>,
+    /// The month at which the fiscal year starts.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fiscal_year_start_month: Option<f64>,
+    /// \[INTERNAL\] Whether the organization has enabled generated updates.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub generated_updates_enabled: Option<bool>,
+    /// How git branches are formatted. If null, default formatting will be used.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub git_branch_format: Option<String>,
+    /// Whether issue descriptions should be included in Git integration linkback messages.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub git_linkback_descriptions_enabled: Option<bool>,
+    /// Whether the Git integration linkback messages should be sent for private repositories.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub git_linkback_messages_enabled: Option<bool>,
+    /// Whether the Git integration linkback messages should be sent for public repositories.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub git_public_linkback_messages_enabled: Option<bool>,
+    /// Whether HIPAA compliance is enabled for organization.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hipaa_compliance_enabled: Option<bool>,
+    /// \[ALPHA\] The n-weekly frequency at which to prompt for initiative updates.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative_update_reminder_frequency_in_weeks: Option<f64>,
+    /// \[ALPHA\] The day at which initiative updates are sent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative_update_reminders_day: Option<Day>,
+    /// \[ALPHA\] The hour at which initiative updates are sent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative_update_reminders_hour: Option<f64>,
+    /// IP restriction configurations controlling allowed access the workspace.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ip_restrictions: Option<Vec<OrganizationIpRestrictionInput>>,
+    /// The logo of the organization.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub logo_url: Option<String>,
+    /// The name of the organization.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Whether the organization has opted for having to approve all OAuth applications for install.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub oauth_app_review: Option<bool>,
+    /// The n-weekly frequency at which to prompt for project updates.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_update_reminder_frequency_in_weeks: Option<f64>,
+    /// The day at which project updates are sent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_update_reminders_day: Option<Day>,
+    /// The hour at which project updates are sent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_update_reminders_hour: Option<f64>,
+    /// Whether the organization has opted for reduced customer support attachment information.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reduced_personal_information: Option<bool>,
+    /// Whether agent invocation is restricted to full workspace members.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub restrict_agent_invocation_to_members: Option<bool>,
+    /// Whether the organization is using roadmap.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub roadmap_enabled: Option<bool>,
+    /// The security settings for the organization.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub security_settings: Option<Box<OrganizationSecuritySettingsInput>>,
+    /// Internal. Whether SLAs have been enabled for the organization.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sla_enabled: Option<bool>,
+    /// \[ALPHA\] Theme settings for the organization.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub theme_settings: Option<JSONObject>,
+    /// The URL key of the organization.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url_key: Option<String>,
+    /// \[Internal\] The list of working days. Sunday is 0, Monday is 1, etc.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub working_days: Option<Vec<f64>>,
+}
+
+/// Customer owner sorting options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OwnerSort {
+    /// Whether nulls should be sorted first or last
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nulls: Option<PaginationNulls>,
+    /// The order for the individual sort
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order: Option<PaginationSortOrder>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PagerDutyInput {
+    /// The date when the PagerDuty API failed with an unauthorized error.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub api_failed_with_unauthorized_error_at: Option<DateTime>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PartialNotificationChannelPreferencesInput {
+    /// Whether notifications are currently enabled for desktop.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub desktop: Option<bool>,
+    /// Whether notifications are currently enabled for email.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email: Option<bool>,
+    /// Whether notifications are currently enabled for mobile.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mobile: Option<bool>,
+    /// Whether notifications are currently enabled for Slack.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slack: Option<bool>,
+}
+
+/// Issue priority sorting options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PrioritySort {
+    /// Whether to consider no priority as the highest or lowest priority
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub no_priority_first: Option<bool>,
+    /// Whether nulls should be sorted first or last
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nulls: Option<PaginationNulls>,
+    /// The order for the individual sort
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order: Option<PaginationSortOrder>,
+}
+
+/// Project filtering options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectCollectionFilter {
+    /// Filters that the project's team must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub accessible_teams: Option<Box<TeamCollectionFilter>>,
+    /// \[ALPHA\] Comparator for the project activity type: buzzin, active, some, none
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub activity_type: Option<Box<StringComparator>>,
+    /// Compound filters, all of which need to be matched by the project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub and: Option<Vec<ProjectCollectionFilter>>,
+    /// Comparator for the project cancelation date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub canceled_at: Option<Box<NullableDateComparator>>,
+    /// Comparator for the project completion date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub completed_at: Option<Box<NullableDateComparator>>,
+    /// Filters that the project's completed milestones must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub completed_project_milestones: Option<Box<ProjectMilestoneCollectionFilter>>,
+    /// Comparator for the created at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<Box<DateComparator>>,
+    /// Filters that the projects creator must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub creator: Option<Box<UserFilter>>,
+    /// Count of customers
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer_count: Option<Box<NumberComparator>>,
+    /// Count of important customers
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer_important_count: Option<Box<NumberComparator>>,
+    /// Filters that needs to be matched by all projects.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub every: Option<Box<ProjectFilter>>,
+    /// Comparator for filtering projects which are blocked.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_blocked_by_relations: Option<Box<RelationExistsComparator>>,
+    /// Comparator for filtering projects which are blocking.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_blocking_relations: Option<Box<RelationExistsComparator>>,
+    /// \[Deprecated\] Comparator for filtering projects which this is depended on by.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_depended_on_by_relations: Option<Box<RelationExistsComparator>>,
+    /// \[Deprecated\]Comparator for filtering projects which this depends on.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_depends_on_relations: Option<Box<RelationExistsComparator>>,
+    /// Comparator for filtering projects with relations.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_related_relations: Option<Box<RelationExistsComparator>>,
+    /// Comparator for filtering projects with violated dependencies.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_violated_relations: Option<Box<RelationExistsComparator>>,
+    /// Comparator for the project health: onTrack, atRisk, offTrack
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub health: Option<Box<StringComparator>>,
+    /// Comparator for the project health (with age): onTrack, atRisk, offTrack, outdated, noUpdate
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub health_with_age: Option<Box<StringComparator>>,
+    /// Comparator for the identifier.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<Box<IDComparator>>,
+    /// Filters that the projects initiatives must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiatives: Option<Box<InitiativeCollectionFilter>>,
+    /// Filters that the projects issues must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issues: Option<Box<IssueCollectionFilter>>,
+    /// Filters that project labels must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<Box<ProjectLabelCollectionFilter>>,
+    /// Filters that the last applied template must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_applied_template: Option<Box<NullableTemplateFilter>>,
+    /// Filters that the projects lead must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lead: Option<Box<NullableUserFilter>>,
+    /// Comparator for the collection length.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub length: Option<Box<NumberComparator>>,
+    /// Filters that the projects members must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub members: Option<Box<UserCollectionFilter>>,
+    /// Comparator for the project name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<Box<StringComparator>>,
+    /// Filters that the project's customer needs must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub needs: Option<Box<CustomerNeedCollectionFilter>>,
+    /// Filters that the project's next milestone must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next_project_milestone: Option<Box<ProjectMilestoneFilter>>,
+    /// Compound filters, one of which need to be matched by the project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub or: Option<Vec<ProjectCollectionFilter>>,
+    /// Comparator for the projects priority.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority: Option<Box<NullableNumberComparator>>,
+    /// Filters that the project's milestones must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_milestones: Option<Box<ProjectMilestoneCollectionFilter>>,
+    /// Comparator for the project updates.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_updates: Option<Box<ProjectUpdatesCollectionFilter>>,
+    /// Filters that the projects roadmaps must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub roadmaps: Option<Box<RoadmapCollectionFilter>>,
+    /// \[Internal\] Comparator for the project's content.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub searchable_content: Option<Box<ContentComparator>>,
+    /// Comparator for the project slug ID.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slug_id: Option<Box<StringComparator>>,
+    /// Filters that needs to be matched by some projects.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub some: Option<Box<ProjectFilter>>,
+    /// Comparator for the project start date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_date: Option<Box<NullableDateComparator>>,
+    /// Comparator for the project started date (when it was moved to an "In Progress" status).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub started_at: Option<Box<NullableDateComparator>>,
+    /// \[DEPRECATED\] Comparator for the project state.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<Box<StringComparator>>,
+    /// Filters that the project's status must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<Box<ProjectStatusFilter>>,
+    /// Comparator for the project target date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_date: Option<Box<NullableDateComparator>>,
+    /// Comparator for the updated at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<Box<DateComparator>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectCreateInput {
+    /// The color of the project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    /// The project content as markdown.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+    /// The ID of the issue from which that project is created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub converted_from_issue_id: Option<String>,
+    /// The description for the project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The icon of the project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub icon: Option<String>,
+    /// The identifier in UUID v4 format. If none is provided, the backend will generate one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// \[Internal\]The identifiers of the project labels associated with this project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label_ids: Option<Vec<String>>,
+    /// The ID of the last template applied to the project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_applied_template_id: Option<String>,
+    /// The identifier of the project lead.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lead_id: Option<String>,
+    /// The identifiers of the members of this project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub member_ids: Option<Vec<String>>,
+    /// The name of the project.
+    pub name: String,
+    /// The priority of the project. 0 = No priority, 1 = Urgent, 2 = High, 3 = Normal, 4 = Low.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority: Option<i64>,
+    /// The sort order for the project within shared views, when ordered by priority.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority_sort_order: Option<f64>,
+    /// The sort order for the project within shared views.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort_order: Option<f64>,
+    /// The planned start date of the project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_date: Option<TimelessDate>,
+    /// The resolution of the project's start date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_date_resolution: Option<DateResolutionType>,
+    /// The ID of the project status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status_id: Option<String>,
+    /// The planned target date of the project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_date: Option<TimelessDate>,
+    /// The resolution of the project's estimated completion date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_date_resolution: Option<DateResolutionType>,
+    /// The identifiers of the teams this project is associated with.
+    pub team_ids: Vec<String>,
+    /// The ID of the template to apply when creating the project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub template_id: Option<String>,
+    /// When set to true, the default project template of the first team provided will be applied. If templateId is provided, this will be ignored.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub use_default_template: Option<bool>,
+}
+
+impl ProjectCreateInput {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(name: String, team_ids: Vec<String>) -> Self {
+        Self {
+            color: None,
+            content: None,
+            converted_from_issue_id: None,
+            description: None,
+            icon: None,
+            id: None,
+            label_ids: None,
+            last_applied_template_id: None,
+            lead_id: None,
+            member_ids: None,
+            name,
+            priority: None,
+            priority_sort_order: None,
+            sort_order: None,
+            start_date: None,
+            start_date_resolution: None,
+            status_id: None,
+            target_date: None,
+            target_date_resolution: None,
+            team_ids,
+            template_id: None,
+            use_default_template: None,
+        }
+    }
+}
+
+/// Project creation date sorting options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectCreatedAtSort {
+    /// Whether nulls should be sorted first or last
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nulls: Option<PaginationNulls>,
+    /// The order for the individual sort
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order: Option<PaginationSortOrder>,
+}
+
+/// Project filtering options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectFilter {
+    /// Filters that the project's team must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub accessible_teams: Option<Box<TeamCollectionFilter>>,
+    /// \[ALPHA\] Comparator for the project activity type: buzzin, active, some, none
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub activity_type: Option<Box<StringComparator>>,
+    /// Compound filters, all of which need to be matched by the project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub and: Option<Vec<ProjectFilter>>,
+    /// Comparator for the project cancelation date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub canceled_at: Option<Box<NullableDateComparator>>,
+    /// Comparator for the project completion date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub completed_at: Option<Box<NullableDateComparator>>,
+    /// Filters that the project's completed milestones must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub completed_project_milestones: Option<Box<ProjectMilestoneCollectionFilter>>,
+    /// Comparator for the created at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<Box<DateComparator>>,
+    /// Filters that the projects creator must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub creator: Option<Box<UserFilter>>,
+    /// Count of customers
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer_count: Option<Box<NumberComparator>>,
+    /// Count of important customers
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer_important_count: Option<Box<NumberComparator>>,
+    /// Comparator for filtering projects which are blocked.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_blocked_by_relations: Option<Box<RelationExistsComparator>>,
+    /// Comparator for filtering projects which are blocking.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_blocking_relations: Option<Box<RelationExistsComparator>>,
+    /// \[Deprecated\] Comparator for filtering projects which this is depended on by.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_depended_on_by_relations: Option<Box<RelationExistsComparator>>,
+    /// \[Deprecated\]Comparator for filtering projects which this depends on.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_depends_on_relations: Option<Box<RelationExistsComparator>>,
+    /// Comparator for filtering projects with relations.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_related_relations: Option<Box<RelationExistsComparator>>,
+    /// Comparator for filtering projects with violated dependencies.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_violated_relations: Option<Box<RelationExistsComparator>>,
+    /// Comparator for the project health: onTrack, atRisk, offTrack
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub health: Option<Box<StringComparator>>,
+    /// Comparator for the project health (with age): onTrack, atRisk, offTrack, outdated, noUpdate
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub health_with_age: Option<Box<StringComparator>>,
+    /// Comparator for the identifier.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<Box<IDComparator>>,
+    /// Filters that the projects initiatives must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiatives: Option<Box<InitiativeCollectionFilter>>,
+    /// Filters that the projects issues must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issues: Option<Box<IssueCollectionFilter>>,
+    /// Filters that project labels must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<Box<ProjectLabelCollectionFilter>>,
+    /// Filters that the last applied template must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_applied_template: Option<Box<NullableTemplateFilter>>,
+    /// Filters that the projects lead must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lead: Option<Box<NullableUserFilter>>,
+    /// Filters that the projects members must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub members: Option<Box<UserCollectionFilter>>,
+    /// Comparator for the project name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<Box<StringComparator>>,
+    /// Filters that the project's customer needs must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub needs: Option<Box<CustomerNeedCollectionFilter>>,
+    /// Filters that the project's next milestone must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next_project_milestone: Option<Box<ProjectMilestoneFilter>>,
+    /// Compound filters, one of which need to be matched by the project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub or: Option<Vec<ProjectFilter>>,
+    /// Comparator for the projects priority.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority: Option<Box<NullableNumberComparator>>,
+    /// Filters that the project's milestones must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_milestones: Option<Box<ProjectMilestoneCollectionFilter>>,
+    /// Comparator for the project updates.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_updates: Option<Box<ProjectUpdatesCollectionFilter>>,
+    /// Filters that the projects roadmaps must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub roadmaps: Option<Box<RoadmapCollectionFilter>>,
+    /// \[Internal\] Comparator for the project's content.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub searchable_content: Option<Box<ContentComparator>>,
+    /// Comparator for the project slug ID.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slug_id: Option<Box<StringComparator>>,
+    /// Comparator for the project start date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_date: Option<Box<NullableDateComparator>>,
+    /// Comparator for the project started date (when it was moved to an "In Progress" status).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub started_at: Option<Box<NullableDateComparator>>,
+    /// \[DEPRECATED\] Comparator for the project state.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<Box<StringComparator>>,
+    /// Filters that the project's status must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<Box<ProjectStatusFilter>>,
+    /// Comparator for the project target date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_date: Option<Box<NullableDateComparator>>,
+    /// Comparator for the updated at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<Box<DateComparator>>,
+}
+
+/// Project health sorting options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectHealthSort {
+    /// Whether nulls should be sorted first or last
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nulls: Option<PaginationNulls>,
+    /// The order for the individual sort
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order: Option<PaginationSortOrder>,
+}
+
+/// Project label filtering options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectLabelCollectionFilter {
+    /// Compound filters, all of which need to be matched by the label.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub and: Option<Vec<ProjectLabelCollectionFilter>>,
+    /// Comparator for the created at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<Box<DateComparator>>,
+    /// Filters that the project labels creator must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub creator: Option<Box<NullableUserFilter>>,
+    /// Filters that needs to be matched by all project labels.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub every: Option<Box<ProjectLabelFilter>>,
+    /// Comparator for the identifier.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<Box<IDComparator>>,
+    /// Comparator for whether the label is a group label.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_group: Option<Box<BooleanComparator>>,
+    /// Comparator for the collection length.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub length: Option<Box<NumberComparator>>,
+    /// Comparator for the name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<Box<StringComparator>>,
+    /// Filter based on the existence of the relation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub null: Option<bool>,
+    /// Compound filters, one of which need to be matched by the label.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub or: Option<Vec<ProjectLabelCollectionFilter>>,
+    /// Filters that the project label's parent label must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent: Option<Box<ProjectLabelFilter>>,
+    /// Filters that needs to be matched by some project labels.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub some: Option<Box<ProjectLabelCollectionFilter>>,
+    /// Comparator for the updated at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<Box<DateComparator>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectLabelCreateInput {
+    /// The color of the label.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    /// The description of the label.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The identifier in UUID v4 format. If none is provided, the backend will generate one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// Whether the label is a group.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_group: Option<bool>,
+    /// The name of the label.
+    pub name: String,
+    /// The identifier of the parent label.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_id: Option<String>,
+    /// When the label was retired.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retired_at: Option<DateTime>,
+}
+
+impl ProjectLabelCreateInput {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(name: String) -> Self {
+        Self {
+            color: None,
+            description: None,
+            id: None,
+            is_group: None,
+            name,
+            parent_id: None,
+            retired_at: None,
+        }
+    }
+}
+
+/// Project label filtering options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectLabelFilter {
+    /// Compound filters, all of which need to be matched by the label.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub and: Option<Vec<ProjectLabelFilter>>,
+    /// Comparator for the created at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<Box<DateComparator>>,
+    /// Filters that the project labels creator must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub creator: Option<Box<NullableUserFilter>>,
+    /// Comparator for the identifier.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<Box<IDComparator>>,
+    /// Comparator for whether the label is a group label.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_group: Option<Box<BooleanComparator>>,
+    /// Comparator for the name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<Box<StringComparator>>,
+    /// Compound filters, one of which need to be matched by the label.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub or: Option<Vec<ProjectLabelFilter>>,
+    /// Filters that the project label's parent label must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent: Option<Box<ProjectLabelFilter>>,
+    /// Comparator for the updated at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<Box<DateComparator>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectLabelUpdateInput {
+    /// The color of the label.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    /// The description of the label.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Whether the label is a group.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_group: Option<bool>,
+    /// The name of the label.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The identifier of the parent label.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_id: Option<String>,
+    /// When the label was retired.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retired_at: Option<DateTime>,
+}
+
+/// Project lead sorting options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectLeadSort {
+    /// Whether nulls should be sorted first or last
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nulls: Option<PaginationNulls>,
+    /// The order for the individual sort
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order: Option<PaginationSortOrder>,
+}
+
+/// Project manual order sorting options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectManualSort {
+    /// Whether nulls should be sorted first or last
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nulls: Option<PaginationNulls>,
+    /// The order for the individual sort
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order: Option<PaginationSortOrder>,
+}
+
+/// Milestone collection filtering options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectMilestoneCollectionFilter {
+    /// Compound filters, all of which need to be matched by the milestone.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub and: Option<Vec<ProjectMilestoneCollectionFilter>>,
+    /// Comparator for the created at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<Box<DateComparator>>,
+    /// Filters that needs to be matched by all milestones.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub every: Option<Box<ProjectMilestoneFilter>>,
+    /// Comparator for the identifier.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<Box<IDComparator>>,
+    /// Comparator for the collection length.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub length: Option<Box<NumberComparator>>,
+    /// Comparator for the project milestone name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<Box<NullableStringComparator>>,
+    /// Compound filters, one of which need to be matched by the milestone.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub or: Option<Vec<ProjectMilestoneCollectionFilter>>,
+    /// Filters that the project milestone's project must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project: Option<Box<NullableProjectFilter>>,
+    /// Filters that needs to be matched by some milestones.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub some: Option<Box<ProjectMilestoneFilter>>,
+    /// Comparator for the project milestone target date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_date: Option<Box<NullableDateComparator>>,
+    /// Comparator for the updated at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<Box<DateComparator>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectMilestoneCreateInput {
+    /// The description of the project milestone in markdown format.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// \[Internal\] The description of the project milestone as a Prosemirror document.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description_data: Option<JSONObject>,
+    /// The identifier in UUID v4 format. If none is provided, the backend will generate one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The name of the project milestone.
+    pub name: String,
+    /// Related project for the project milestone.
+    pub project_id: String,
+    /// The sort order for the project milestone within a project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort_order: Option<f64>,
+    /// The planned target date of the project milestone.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_date: Option<TimelessDate>,
+}
+
+impl ProjectMilestoneCreateInput {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(name: String, project_id: String) -> Self {
+        Self {
+            description: None,
+            description_data: None,
+            id: None,
+            name,
+            project_id,
+            sort_order: None,
+            target_date: None,
+        }
+    }
+}
+
+/// Project milestone filtering options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectMilestoneFilter {
+    /// Compound filters, all of which need to be matched by the project milestone.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub and: Option<Vec<ProjectMilestoneFilter>>,
+    /// Comparator for the created at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<Box<DateComparator>>,
+    /// Comparator for the identifier.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<Box<IDComparator>>,
+    /// Comparator for the project milestone name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<Box<NullableStringComparator>>,
+    /// Compound filters, one of which need to be matched by the project milestone.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub or: Option<Vec<ProjectMilestoneFilter>>,
+    /// Filters that the project milestone's project must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project: Option<Box<NullableProjectFilter>>,
+    /// Comparator for the project milestone target date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_date: Option<Box<NullableDateComparator>>,
+    /// Comparator for the updated at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<Box<DateComparator>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectMilestoneMoveInput {
+    /// Whether to add each milestone issue's team to the project. This is needed when there is a mismatch between a project's teams and the milestone's issues' teams. Either this or newIssueTeamId is required in that situation to resolve constraints.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub add_issue_team_to_project: Option<bool>,
+    /// The team id to move the attached issues to. This is needed when there is a mismatch between a project's teams and the milestone's issues' teams. Either this or addIssueTeamToProject is required in that situation to resolve constraints.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub new_issue_team_id: Option<String>,
+    /// The identifier of the project to move the milestone to.
+    pub project_id: String,
+    /// A list of issue id to team ids, used for undoing a previous milestone move where the specified issues were moved from the specified teams.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub undo_issue_team_ids: Option<Vec<ProjectMilestoneMoveIssueToTeamInput>>,
+    /// A mapping of project id to a previous set of team ids, used for undoing a previous milestone move where the specified teams were added to the project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub undo_project_team_ids: Option<Box<ProjectMilestoneMoveProjectTeamsInput>>,
+}
+
+impl ProjectMilestoneMoveInput {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(project_id: String) -> Self {
+        Self {
+            add_issue_team_to_project: None,
+            new_issue_team_id: None,
+            project_id,
+            undo_issue_team_ids: None,
+            undo_project_team_ids: None,
+        }
+    }
+}
+
+/// \[Internal\] Used for ProjectMilestoneMoveInput to describe a mapping between an issue and its team.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectMilestoneMoveIssueToTeamInput {
+    /// The issue id in this relationship, you can use * as wildcard if all issues are being moved to the same team
+    pub issue_id: String,
+    /// The team id in this relationship
+    pub team_id: String,
+}
+
+impl ProjectMilestoneMoveIssueToTeamInput {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(issue_id: String, team_id: String) -> Self {
+        Self {
+            issue_id,
+            team_id,
+        }
+    }
+}
+
+/// \[Internal\] Used for ProjectMilestoneMoveInput to describe a snapshot of a project and its team ids
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectMilestoneMoveProjectTeamsInput {
+    /// The project id
+    pub project_id: String,
+    /// The team ids for the project
+    pub team_ids: Vec<String>,
+}
+
+impl ProjectMilestoneMoveProjectTeamsInput {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(project_id: String, team_ids: Vec<String>) -> Self {
+        Self {
+            project_id,
+            team_ids,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectMilestoneUpdateInput {
+    /// The description of the project milestone in markdown format.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// \[Internal\] The description of the project milestone as a Prosemirror document.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description_data: Option<JSONObject>,
+    /// The name of the project milestone.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Related project for the project milestone.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_id: Option<String>,
+    /// The sort order for the project milestone within a project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort_order: Option<f64>,
+    /// The planned target date of the project milestone.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_date: Option<TimelessDate>,
+}
+
+/// Project name sorting options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectNameSort {
+    /// Whether nulls should be sorted first or last
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nulls: Option<PaginationNulls>,
+    /// The order for the individual sort
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order: Option<PaginationSortOrder>,
+}
+
+/// Project priority sorting options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectPrioritySort {
+    /// Whether to consider no priority as the highest or lowest priority
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub no_priority_first: Option<bool>,
+    /// Whether nulls should be sorted first or last
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nulls: Option<PaginationNulls>,
+    /// The order for the individual sort
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order: Option<PaginationSortOrder>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectRelationCreateInput {
+    /// The type of the anchor for the project.
+    pub anchor_type: String,
+    /// The identifier in UUID v4 format. If none is provided, the backend will generate one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The identifier of the project that is related to another project.
+    pub project_id: String,
+    /// The identifier of the project milestone.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_milestone_id: Option<String>,
+    /// The type of the anchor for the related project.
+    pub related_anchor_type: String,
+    /// The identifier of the related project.
+    pub related_project_id: String,
+    /// The identifier of the related project milestone.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub related_project_milestone_id: Option<String>,
+    /// The type of relation of the project to the related project.
+    pub r#type: String,
+}
+
+impl ProjectRelationCreateInput {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(anchor_type: String, project_id: String, related_anchor_type: String, related_project_id: String, r#type: String) -> Self {
+        Self {
+            anchor_type,
+            id: None,
+            project_id,
+            project_milestone_id: None,
+            related_anchor_type,
+            related_project_id,
+            related_project_milestone_id: None,
+            r#type,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectRelationUpdateInput {
+    /// The type of the anchor for the project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub anchor_type: Option<String>,
+    /// The identifier of the project that is related to another project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_id: Option<String>,
+    /// The identifier of the project milestone.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_milestone_id: Option<String>,
+    /// The type of the anchor for the related project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub related_anchor_type: Option<String>,
+    /// The identifier of the related project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub related_project_id: Option<String>,
+    /// The identifier of the related project milestone.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub related_project_milestone_id: Option<String>,
+    /// The type of relation of the project to the related project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+}
+
+/// Issue project sorting options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectSort {
+    /// Whether nulls should be sorted first or last
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nulls: Option<PaginationNulls>,
+    /// The order for the individual sort
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order: Option<PaginationSortOrder>,
+}
+
+/// Project sorting options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectSortInput {
+    /// Sort by project creation date
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<Box<ProjectCreatedAtSort>>,
+    /// Sort by project health status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub health: Option<Box<ProjectHealthSort>>,
+    /// Sort by project lead name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lead: Option<Box<ProjectLeadSort>>,
+    /// Sort by manual order
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub manual: Option<Box<ProjectManualSort>>,
+    /// Sort by project name
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<Box<ProjectNameSort>>,
+    /// Sort by project priority
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority: Option<Box<ProjectPrioritySort>>,
+    /// Sort by project start date
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_date: Option<Box<StartDateSort>>,
+    /// Sort by project status
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<Box<ProjectStatusSort>>,
+    /// Sort by project target date
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_date: Option<Box<TargetDateSort>>,
+    /// Sort by project update date
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<Box<ProjectUpdatedAtSort>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectStatusCreateInput {
+    /// The UI color of the status as a HEX string.
+    pub color: String,
+    /// Description of the status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The identifier in UUID v4 format. If none is provided, the backend will generate one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// Whether or not a project can be in this status indefinitely.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub indefinite: Option<bool>,
+    /// The name of the status.
+    pub name: String,
+    /// The position of the status in the workspace's project flow.
+    pub position: f64,
+    /// The type of the project status.
+    pub r#type: ProjectStatusType,
+}
+
+impl ProjectStatusCreateInput {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(color: String, name: String, position: f64, r#type: ProjectStatusType) -> Self {
+        Self {
+            color,
+            description: None,
+            id: None,
+            indefinite: None,
+            name,
+            position,
+            r#type,
+        }
+    }
+}
+
+/// Project status filtering options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectStatusFilter {
+    /// Compound filters, all of which need to be matched by the project status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub and: Option<Vec<ProjectStatusFilter>>,
+    /// Comparator for the created at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<Box<DateComparator>>,
+    /// Comparator for the project status description.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<Box<StringComparator>>,
+    /// Comparator for the identifier.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<Box<IDComparator>>,
+    /// Comparator for the project status name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<Box<StringComparator>>,
+    /// Compound filters, one of which needs to be matched by the project status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub or: Option<Vec<ProjectStatusFilter>>,
+    /// Comparator for the project status position.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub position: Option<Box<NumberComparator>>,
+    /// Filters that the project status projects must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub projects: Option<Box<ProjectCollectionFilter>>,
+    /// Comparator for the project status type.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<Box<StringComparator>>,
+    /// Comparator for the updated at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<Box<DateComparator>>,
+}
+
+/// Project status sorting options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectStatusSort {
+    /// Whether nulls should be sorted first or last
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nulls: Option<PaginationNulls>,
+    /// The order for the individual sort
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order: Option<PaginationSortOrder>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectStatusUpdateInput {
+    /// The UI color of the status as a HEX string.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    /// Description of the status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Whether or not a project can be in this status indefinitely.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub indefinite: Option<bool>,
+    /// The name of the status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The position of the status in the workspace's project flow.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub position: Option<f64>,
+    /// The type of the project status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<ProjectStatusType>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectUpdateCreateInput {
+    /// The content of the project update in markdown format.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    /// \[Internal\] The content of the project update as a Prosemirror document.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body_data: Option<JSON>,
+    /// The health of the project at the time of the update.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub health: Option<ProjectUpdateHealthType>,
+    /// The identifier. If none is provided, the backend will generate one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// Whether the diff between the current update and the previous one should be hidden.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_diff_hidden: Option<bool>,
+    /// The project to associate the project update with.
+    pub project_id: String,
+}
+
+impl ProjectUpdateCreateInput {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(project_id: String) -> Self {
+        Self {
+            body: None,
+            body_data: None,
+            health: None,
+            id: None,
+            is_diff_hidden: None,
+            project_id,
+        }
+    }
+}
+
+/// Options for filtering project updates.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectUpdateFilter {
+    /// Compound filters, all of which need to be matched by the ProjectUpdate.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub and: Option<Vec<ProjectUpdateFilter>>,
+    /// Comparator for the created at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<Box<DateComparator>>,
+    /// Comparator for the identifier.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<Box<IDComparator>>,
+    /// Compound filters, one of which need to be matched by the ProjectUpdate.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub or: Option<Vec<ProjectUpdateFilter>>,
+    /// Filters that the project update project must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project: Option<Box<ProjectFilter>>,
+    /// Filters that the project updates reactions must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reactions: Option<Box<ReactionCollectionFilter>>,
+    /// Comparator for the updated at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<Box<DateComparator>>,
+    /// Filters that the project update creator must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<Box<UserFilter>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectUpdateInput {
+    /// The date when the project was canceled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub canceled_at: Option<DateTime>,
+    /// The color of the project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    /// The date when the project was completed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub completed_at: Option<DateTime>,
+    /// The project content as markdown.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+    /// The ID of the issue from which that project is created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub converted_from_issue_id: Option<String>,
+    /// The description for the project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The frequency resolution.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub frequency_resolution: Option<FrequencyResolutionType>,
+    /// The icon of the project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub icon: Option<String>,
+    /// The identifiers of the project labels associated with this project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label_ids: Option<Vec<String>>,
+    /// The ID of the last template applied to the project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_applied_template_id: Option<String>,
+    /// The identifier of the project lead.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lead_id: Option<String>,
+    /// The identifiers of the members of this project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub member_ids: Option<Vec<String>>,
+    /// The name of the project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The priority of the project. 0 = No priority, 1 = Urgent, 2 = High, 3 = Normal, 4 = Low.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority: Option<i64>,
+    /// The sort order for the project within shared views, when ordered by priority.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority_sort_order: Option<f64>,
+    /// The time until which project update reminders are paused.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_update_reminders_paused_until_at: Option<DateTime>,
+    /// Whether to send new issue comment notifications to Slack.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slack_issue_comments: Option<bool>,
+    /// Whether to send issue status update notifications to Slack.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slack_issue_statuses: Option<bool>,
+    /// Whether to send new issue notifications to Slack.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slack_new_issue: Option<bool>,
+    /// The sort order for the project in shared views.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort_order: Option<f64>,
+    /// The planned start date of the project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_date: Option<TimelessDate>,
+    /// The resolution of the project's start date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_date_resolution: Option<DateResolutionType>,
+    /// The ID of the project status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status_id: Option<String>,
+    /// The planned target date of the project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_date: Option<TimelessDate>,
+    /// The resolution of the project's estimated completion date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_date_resolution: Option<DateResolutionType>,
+    /// The identifiers of the teams this project is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team_ids: Option<Vec<String>>,
+    /// Whether the project has been trashed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trashed: Option<bool>,
+    /// The frequency at which to prompt for updates. When not set, reminders are inherited from workspace.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub update_reminder_frequency: Option<f64>,
+    /// The n-weekly frequency at which to prompt for updates. When not set, reminders are inherited from workspace.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub update_reminder_frequency_in_weeks: Option<f64>,
+    /// The day at which to prompt for updates.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub update_reminders_day: Option<Day>,
+    /// The hour at which to prompt for updates.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub update_reminders_hour: Option<i64>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectUpdateUpdateInput {
+    /// The content of the project update in markdown format.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    /// The content of the project update as a Prosemirror document.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body_data: Option<JSON>,
+    /// The health of the project at the time of the update.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub health: Option<ProjectUpdateHealthType>,
+    /// Whether the diff between the current update and the previous one should be hidden.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_diff_hidden: Option<bool>,
+}
+
+/// Project update date sorting options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectUpdatedAtSort {
+    /// Whether nulls should be sorted first or last
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nulls: Option<PaginationNulls>,
+    /// The order for the individual sort
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order: Option<PaginationSortOrder>,
+}
+
+/// Collection filtering options for filtering projects by project updates.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectUpdatesCollectionFilter {
+    /// Compound filters, all of which need to be matched by the project update.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub and: Option<Vec<ProjectUpdatesCollectionFilter>>,
+    /// Comparator for the created at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<Box<DateComparator>>,
+    /// Filters that needs to be matched by all updates.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub every: Option<Box<ProjectUpdatesFilter>>,
+    /// Comparator for the project update health.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub health: Option<Box<StringComparator>>,
+    /// Comparator for the identifier.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<Box<IDComparator>>,
+    /// Comparator for the collection length.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub length: Option<Box<NumberComparator>>,
+    /// Compound filters, one of which need to be matched by the update.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub or: Option<Vec<ProjectUpdatesCollectionFilter>>,
+    /// Filters that needs to be matched by some updates.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub some: Option<Box<ProjectUpdatesFilter>>,
+    /// Comparator for the updated at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<Box<DateComparator>>,
+}
+
+/// Options for filtering projects by project updates.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectUpdatesFilter {
+    /// Compound filters, all of which need to be matched by the project updates.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub and: Option<Vec<ProjectUpdatesFilter>>,
+    /// Comparator for the created at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<Box<DateComparator>>,
+    /// Comparator for the project update health.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub health: Option<Box<StringComparator>>,
+    /// Comparator for the identifier.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<Box<IDComparator>>,
+    /// Compound filters, one of which need to be matched by the project updates.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub or: Option<Vec<ProjectUpdatesFilter>>,
+    /// Comparator for the updated at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<Box<DateComparator>>,
+}
+
+/// Input for referencing a pull request by repository and number.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PullRequestReferenceInput {
+    /// The pull request number.
+    pub number: f64,
+    /// The name of the repository.
+    pub repository_name: String,
+    /// The owner of the repository (e.g., organization or user name).
+    pub repository_owner: String,
+}
+
+impl PullRequestReferenceInput {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(number: f64, repository_name: String, repository_owner: String) -> Self {
+        Self {
+            number,
+            repository_name,
+            repository_owner,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PushSubscriptionCreateInput {
+    /// The data of the subscription in stringified JSON format.
+    pub data: String,
+    /// The identifier in UUID v4 format. If none is provided, the backend will generate one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// Whether this is a subscription payload for Google Cloud Messaging or Apple Push Notification service.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<PushSubscriptionType>,
+}
+
+impl PushSubscriptionCreateInput {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(data: String) -> Self {
+        Self {
+            data,
+            id: None,
+            r#type: None,
+        }
+    }
+}
+
+/// Reaction filtering options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReactionCollectionFilter {
+    /// Compound filters, all of which need to be matched by the reaction.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub and: Option<Vec<ReactionCollectionFilter>>,
+    /// Comparator for the created at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<Box<DateComparator>>,
+    /// Comparator for the reactions custom emoji.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub custom_emoji_id: Option<Box<IDComparator>>,
+    /// Comparator for the reactions emoji.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub emoji: Option<Box<StringComparator>>,
+    /// Filters that needs to be matched by all reactions.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub every: Option<Box<ReactionFilter>>,
+    /// Comparator for the identifier.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<Box<IDComparator>>,
+    /// Comparator for the collection length.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub length: Option<Box<NumberComparator>>,
+    /// Compound filters, one of which need to be matched by the reaction.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub or: Option<Vec<ReactionCollectionFilter>>,
+    /// Filters that needs to be matched by some reactions.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub some: Option<Box<ReactionFilter>>,
+    /// Comparator for the updated at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<Box<DateComparator>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReactionCreateInput {
+    /// The comment to associate the reaction with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment_id: Option<String>,
+    /// The emoji the user reacted with.
+    pub emoji: String,
+    /// The identifier in UUID v4 format. If none is provided, the backend will generate one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The update to associate the reaction with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative_update_id: Option<String>,
+    /// The issue to associate the reaction with. Can be a UUID or issue identifier (e.g., 'LIN-123').
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_id: Option<String>,
+    /// \[Internal\] The post to associate the reaction with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub post_id: Option<String>,
+    /// The project update to associate the reaction with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_update_id: Option<String>,
+    /// \[Internal\] The pull request comment to associate the reaction with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pull_request_comment_id: Option<String>,
+    /// \[Internal\] The pull request to associate the reaction with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pull_request_id: Option<String>,
+}
+
+impl ReactionCreateInput {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(emoji: String) -> Self {
+        Self {
+            comment_id: None,
+            emoji,
+            id: None,
+            initiative_update_id: None,
+            issue_id: None,
+            post_id: None,
+            project_update_id: None,
+            pull_request_comment_id: None,
+            pull_request_id: None,
+        }
+    }
+}
+
+/// Reaction filtering options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReactionFilter {
+    /// Compound filters, all of which need to be matched by the reaction.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub and: Option<Vec<ReactionFilter>>,
+    /// Comparator for the created at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<Box<DateComparator>>,
+    /// Comparator for the reactions custom emoji.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub custom_emoji_id: Option<Box<IDComparator>>,
+    /// Comparator for the reactions emoji.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub emoji: Option<Box<StringComparator>>,
+    /// Comparator for the identifier.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<Box<IDComparator>>,
+    /// Compound filters, one of which need to be matched by the reaction.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub or: Option<Vec<ReactionFilter>>,
+    /// Comparator for the updated at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<Box<DateComparator>>,
+}
+
+/// Comparator for relation existence.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RelationExistsComparator {
+    /// Equals constraint.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub eq: Option<bool>,
+    /// Not equals constraint.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub neq: Option<bool>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReleaseCreateInput {
+    /// The commit SHA associated with this release.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub commit_sha: Option<String>,
+    /// Debug information for release creation diagnostics.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub debug_sink: Option<Box<ReleaseDebugSinkInput>>,
+    /// The identifier in UUID v4 format. If none is provided, the backend will generate one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// Issue identifiers (e.g. ENG-123) to associate with this release.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_identifiers: Option<Vec<String>>,
+    /// The name of the release.
+    pub name: String,
+    /// The identifier of the pipeline this release belongs to.
+    pub pipeline_id: String,
+    /// Pull request references to look up. Issues linked to found PRs will be associated with this release.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pull_request_references: Option<Vec<PullRequestReferenceInput>>,
+    /// The current stage of the release. Defaults to the first 'completed' stage.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stage_id: Option<String>,
+    /// The estimated start date of the release.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_date: Option<TimelessDate>,
+    /// The estimated completion date of the release.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_date: Option<TimelessDate>,
+    /// The version of the release.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+}
+
+impl ReleaseCreateInput {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(name: String, pipeline_id: String) -> Self {
+        Self {
+            commit_sha: None,
+            debug_sink: None,
+            id: None,
+            issue_identifiers: None,
+            name,
+            pipeline_id,
+            pull_request_references: None,
+            stage_id: None,
+            start_date: None,
+            target_date: None,
+            version: None,
+        }
+    }
+}
+
+/// Debug sink for release creation diagnostics.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReleaseDebugSinkInput {
+    /// List of commit SHAs that were inspected.
+    pub inspected_shas: Vec<String>,
+    /// Map of issue identifiers to their source information.
+    pub issues: JSONObject,
+    /// Pull request debug information.
+    pub pull_requests: Vec<JSONObject>,
+}
+
+impl ReleaseDebugSinkInput {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(inspected_shas: Vec<String>, issues: JSONObject, pull_requests: Vec<JSONObject>) -> Self {
+        Self {
+            inspected_shas,
+            issues,
+            pull_requests,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReleasePipelineCreateInput {
+    /// The identifier in UUID v4 format. If none is provided, the backend will generate one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The name of the pipeline.
+    pub name: String,
+    /// The pipeline's unique slug identifier. If not provided, it will be auto-generated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slug_id: Option<String>,
+    /// The type of the pipeline.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<ReleasePipelineType>,
+}
+
+impl ReleasePipelineCreateInput {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(name: String) -> Self {
+        Self {
+            id: None,
+            name,
+            slug_id: None,
+            r#type: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReleasePipelineUpdateInput {
+    /// The name of the pipeline.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The pipeline's unique slug identifier.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slug_id: Option<String>,
+    /// The type of the pipeline.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<ReleasePipelineType>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReleaseStageCreateInput {
+    /// The UI color of the stage as a HEX string.
+    pub color: String,
+    /// The identifier in UUID v4 format. If none is provided, the backend will generate one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The name of the stage.
+    pub name: String,
+    /// The identifier of the pipeline this stage belongs to.
+    pub pipeline_id: String,
+    /// The position of the stage.
+    pub position: f64,
+    /// The type of the stage.
+    pub r#type: ReleaseStageType,
+}
+
+impl ReleaseStageCreateInput {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(color: String, name: String, pipeline_id: String, position: f64, r#type: ReleaseStageType) -> Self {
+        Self {
+            color,
+            id: None,
+            name,
+            pipeline_id,
+            position,
+            r#type,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReleaseStageUpdateInput {
+    /// The UI color of the stage as a HEX string.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    /// The name of the stage.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The position of the stage.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub position: Option<f64>,
+    /// The type of the stage.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<ReleaseStageType>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReleaseUpdateInput {
+    /// The commit SHA associated with this release.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub commit_sha: Option<String>,
+    /// The name of the release.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The identifier of the pipeline this release belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pipeline_id: Option<String>,
+    /// The current stage of the release.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stage_id: Option<String>,
+    /// The estimated start date of the release.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_date: Option<TimelessDate>,
+    /// The estimated completion date of the release.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_date: Option<TimelessDate>,
+    /// The version of the release.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+}
+
+/// Customer revenue sorting options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RevenueSort {
+    /// Whether nulls should be sorted first or last
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nulls: Option<PaginationNulls>,
+    /// The order for the individual sort
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order: Option<PaginationSortOrder>,
+}
+
+/// Roadmap collection filtering options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RoadmapCollectionFilter {
+    /// Compound filters, all of which need to be matched by the roadmap.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub and: Option<Vec<RoadmapCollectionFilter>>,
+    /// Comparator for the created at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<Box<DateComparator>>,
+    /// Filters that the roadmap creator must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub creator: Option<Box<UserFilter>>,
+    /// Filters that needs to be matched by all roadmaps.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub every: Option<Box<RoadmapFilter>>,
+    /// Comparator for the identifier.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<Box<IDComparator>>,
+    /// Comparator for the collection length.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub length: Option<Box<NumberComparator>>,
+    /// Comparator for the roadmap name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<Box<StringComparator>>,
+    /// Compound filters, one of which need to be matched by the roadmap.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub or: Option<Vec<RoadmapCollectionFilter>>,
+    /// Comparator for the roadmap slug ID.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slug_id: Option<Box<StringComparator>>,
+    /// Filters that needs to be matched by some roadmaps.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub some: Option<Box<RoadmapFilter>>,
+    /// Comparator for the updated at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<Box<DateComparator>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RoadmapCreateInput {
+    /// The roadmap's color.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    /// The description of the roadmap.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The identifier in UUID v4 format. If none is provided, the backend will generate one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The name of the roadmap.
+    pub name: String,
+    /// The owner of the roadmap.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner_id: Option<String>,
+    /// The sort order of the roadmap within the organization.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort_order: Option<f64>,
+}
+
+impl RoadmapCreateInput {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(name: String) -> Self {
+        Self {
+            color: None,
+            description: None,
+            id: None,
+            name,
+            owner_id: None,
+            sort_order: None,
+        }
+    }
+}
+
+/// Roadmap filtering options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RoadmapFilter {
+    /// Compound filters, all of which need to be matched by the roadmap.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub and: Option<Vec<RoadmapFilter>>,
+    /// Comparator for the created at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<Box<DateComparator>>,
+    /// Filters that the roadmap creator must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub creator: Option<Box<UserFilter>>,
+    /// Comparator for the identifier.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<Box<IDComparator>>,
+    /// Comparator for the roadmap name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<Box<StringComparator>>,
+    /// Compound filters, one of which need to be matched by the roadmap.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub or: Option<Vec<RoadmapFilter>>,
+    /// Comparator for the roadmap slug ID.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slug_id: Option<Box<StringComparator>>,
+    /// Comparator for the updated at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<Box<DateComparator>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RoadmapToProjectCreateInput {
+    /// The identifier in UUID v4 format. If none is provided, the backend will generate one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The identifier of the project.
+    pub project_id: String,
+    /// The identifier of the roadmap.
+    pub roadmap_id: String,
+    /// The sort order for the project within its organization.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort_order: Option<f64>,
+}
+
+impl RoadmapToProjectCreateInput {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(project_id: String, roadmap_id: String) -> Self {
+        Self {
+            id: None,
+            project_id,
+            roadmap_id,
+            sort_order: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RoadmapToProjectUpdateInput {
+    /// The sort order for the project within its organization.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort_order: Option<f64>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RoadmapUpdateInput {
+    /// The roadmap's color.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    /// The description of the roadmap.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The name of the roadmap.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The owner of the roadmap.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner_id: Option<String>,
+    /// The sort order of the roadmap within the organization.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort_order: Option<f64>,
+}
+
+/// Issue root-issue sorting options.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RootIssueSort {
+    /// Whether nulls should be sorted first or last
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nulls: Option<PaginationNulls>,
+    /// The order for the individual sort
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order: Option<PaginationSortOrder>,
+    /// The sort to apply to the root issues
+    pub sort: Box<IssueSortInput>,
+}
+
+impl RootIssueSort {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(sort: Box<IssueSortInput>) -> Self {
+        Self {
+            nulls: None,
+            order: None,
+            sort,
+        }
+    }
+}
+
+/// \[INTERNAL\] Comparator for Salesforce metadata.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SalesforceMetadataIntegrationComparator {
+    /// Salesforce Case metadata filter
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub case_metadata: Option<JSONObject>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SalesforceSettingsInput {
+    /// Whether a ticket should be automatically reopened when its linked Linear issue is cancelled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub automate_ticket_reopening_on_cancellation: Option<bool>,
+    /// Whether a ticket should be automatically reopened when a comment is posted on its linked Linear issue
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub automate_ticket_reopening_on_comment: Option<bool>,
+    /// Whether a ticket should be automatically reopened when its linked Linear issue is completed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub automate_ticket_reopening_on_completion: Option<bool>,
+    /// Whether a ticket should be automatically reopened when its linked Linear project is cancelled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub automate_ticket_reopening_on_project_cancellation: Option<bool>,
+    /// Whether a ticket should be automatically reopened when its linked Linear project is completed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub automate_ticket_reopening_on_project_completion: Option<bool>,
+    /// The Salesforce team to use when a template doesn't specify a team.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_team: Option<String>,
+    /// \[ALPHA\] Whether customer and customer requests should not be automatically created when conversations are linked to a Linear issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub disable_customer_requests_auto_creation: Option<bool>,
+    /// Whether Linear Agent should be enabled for this integration.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enable_ai_intake: Option<bool>,
+    /// The Salesforce case status to use to reopen cases.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reopen_case_status: Option<String>,
+    /// Whether to restrict visibility of the integration to issues that have been either created from Salesforce or linked to Salesforce.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub restrict_visibility: Option<bool>,
+    /// Whether an internal message should be added when someone comments on an issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub send_note_on_comment: Option<bool>,
+    /// Whether an internal message should be added when a Linear issue changes status (for status types except completed or canceled).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub send_note_on_status_change: Option<bool>,
+    /// The Salesforce subdomain.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subdomain: Option<String>,
+    /// The Salesforce instance URL.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+/// Filters for semantic search results.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SemanticSearchFilters {
+    /// Filters applied to documents.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub documents: Option<Box<DocumentFilter>>,
+    /// Filters applied to initiatives.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiatives: Option<Box<InitiativeFilter>>,
+    /// Filters applied to issues.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issues: Option<Box<IssueFilter>>,
+    /// Filters applied to projects.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub projects: Option<Box<ProjectFilter>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SentrySettingsInput {
+    /// The ID of the Sentry organization being connected.
+    pub organization_id: String,
+    /// The slug of the Sentry organization being connected.
+    pub organization_slug: String,
+    /// Whether Sentry issues resolving completes Linear issues.
+    pub resolving_completes_issues: bool,
+    /// Whether Sentry issues unresolving reopens Linear issues.
+    pub unresolving_reopens_issues: bool,
+}
+
+impl SentrySettingsInput {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(organization_id: String, organization_slug: String, resolving_completes_issues: bool, unresolving_reopens_issues: bool) -> Self {
+        Self {
+            organization_id,
+            organization_slug,
+            resolving_completes_issues,
+            unresolving_reopens_issues,
+        }
+    }
+}
+
+/// Customer size sorting options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SizeSort {
+    /// Whether nulls should be sorted first or last
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nulls: Option<PaginationNulls>,
+    /// The order for the individual sort
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order: Option<PaginationSortOrder>,
+}
+
+/// Comparator for sla status.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SlaStatusComparator {
+    /// Equals constraint.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub eq: Option<SlaStatus>,
+    /// In-array constraint.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#in: Option<Vec<SlaStatus>>,
+    /// Not-equals constraint.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub neq: Option<SlaStatus>,
+    /// Not-in-array constraint.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nin: Option<Vec<SlaStatus>>,
+    /// Null constraint. Matches any non-null values if the given value is false, otherwise it matches null values.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub null: Option<bool>,
+}
+
+/// Issue SLA status sorting options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SlaStatusSort {
+    /// Whether nulls should be sorted first or last
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nulls: Option<PaginationNulls>,
+    /// The order for the individual sort
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order: Option<PaginationSortOrder>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SlackAsksSettingsInput {
+    /// The user role type that is allowed to manage Asks settings.
+    pub can_administrate: UserRoleType,
+    /// Controls who can see and set Customers when creating Asks in Slack.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer_visibility: Option<CustomerVisibilityMode>,
+    /// Enterprise id of the connected Slack enterprise
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enterprise_id: Option<String>,
+    /// Enterprise name of the connected Slack enterprise
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enterprise_name: Option<String>,
+    /// Whether to allow external users to perform actions on unfurls
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub external_user_actions: Option<bool>,
+    /// Whether to show unfurl previews in Slack
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub should_unfurl: Option<bool>,
+    /// Whether to show unfurls in the default style instead of Work Objects in Slack
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub should_use_default_unfurl: Option<bool>,
+    /// The mapping of Slack channel ID => Slack channel name for connected channels.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slack_channel_mapping: Option<Vec<SlackChannelNameMappingInput>>,
+    /// Slack workspace id
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team_id: Option<String>,
+    /// Slack workspace name
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team_name: Option<String>,
+}
+
+impl SlackAsksSettingsInput {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(can_administrate: UserRoleType) -> Self {
+        Self {
+            can_administrate,
+            customer_visibility: None,
+            enterprise_id: None,
+            enterprise_name: None,
+            external_user_actions: None,
+            should_unfurl: None,
+            should_use_default_unfurl: None,
+            slack_channel_mapping: None,
+            team_id: None,
+            team_name: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SlackAsksTeamSettingsInput {
+    /// Whether the default Asks template is enabled in the given channel for this team.
+    pub has_default_ask: bool,
+    /// The Linear team ID.
+    pub id: String,
+}
+
+impl SlackAsksTeamSettingsInput {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(has_default_ask: bool, id: String) -> Self {
+        Self {
+            has_default_ask,
+            id,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SlackChannelNameMappingInput {
+    /// Whether or not to use AI to generate titles for Asks created in this channel.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ai_titles: Option<bool>,
+    /// Whether or not @-mentioning the bot should automatically create an Ask with the message.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_create_on_bot_mention: Option<bool>,
+    /// Whether or not using the :ticket: emoji in this channel should automatically create Asks.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_create_on_emoji: Option<bool>,
+    /// Whether or not top-level messages in this channel should automatically create Asks.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_create_on_message: Option<bool>,
+    /// The optional template ID to use for Asks auto-created in this channel. If not set, auto-created Asks won't use any template.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_create_template_id: Option<String>,
+    /// Whether or not the Linear Asks bot has been added to this Slack channel.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bot_added: Option<bool>,
+    /// The Slack channel ID.
+    pub id: String,
+    /// Whether or not the Slack channel is private.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_private: Option<bool>,
+    /// Whether or not the Slack channel is shared with an external org.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_shared: Option<bool>,
+    /// The Slack channel name.
+    pub name: String,
+    /// Whether or not synced Slack threads should be updated with a message when their Ask is accepted from triage.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub post_accepted_from_triage_updates: Option<bool>,
+    /// Whether or not synced Slack threads should be updated with a message and emoji when their Ask is canceled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub post_cancellation_updates: Option<bool>,
+    /// Whether or not synced Slack threads should be updated with a message and emoji when their Ask is completed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub post_completion_updates: Option<bool>,
+    /// Which teams are connected to the channel and settings for those teams.
+    pub teams: Vec<SlackAsksTeamSettingsInput>,
+}
+
+impl SlackChannelNameMappingInput {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(id: String, name: String, teams: Vec<SlackAsksTeamSettingsInput>) -> Self {
+        Self {
+            ai_titles: None,
+            auto_create_on_bot_mention: None,
+            auto_create_on_emoji: None,
+            auto_create_on_message: None,
+            auto_create_template_id: None,
+            bot_added: None,
+            id,
+            is_private: None,
+            is_shared: None,
+            name,
+            post_accepted_from_triage_updates: None,
+            post_cancellation_updates: None,
+            post_completion_updates: None,
+            teams,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SlackPostSettingsInput {
+    pub channel: String,
+    pub channel_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub channel_type: Option<SlackChannelType>,
+    pub configuration_url: String,
+    /// Slack workspace id
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team_id: Option<String>,
+}
+
+impl SlackPostSettingsInput {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(channel: String, channel_id: String, configuration_url: String) -> Self {
+        Self {
+            channel,
+            channel_id,
+            channel_type: None,
+            configuration_url,
+            team_id: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SlackSettingsInput {
+    /// Whether Linear Agent should be enabled for this Slack integration.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enable_agent: Option<bool>,
+    /// Whether Linear Agent should be given Org-wide access within Slack workflows.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enable_linear_agent_workflow_access: Option<bool>,
+    /// Enterprise id of the connected Slack enterprise
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enterprise_id: Option<String>,
+    /// Enterprise name of the connected Slack enterprise
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enterprise_name: Option<String>,
+    /// Whether to allow external users to perform actions on unfurls
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub external_user_actions: Option<bool>,
+    /// Whether Linear should automatically respond with issue unfurls when an issue identifier is mentioned in a Slack message.
+    pub link_on_issue_id_mention: bool,
+    /// Whether to show unfurl previews in Slack
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub should_unfurl: Option<bool>,
+    /// Whether to show unfurls in the default style instead of Work Objects in Slack
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub should_use_default_unfurl: Option<bool>,
+    /// Slack workspace id
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team_id: Option<String>,
+    /// Slack workspace name
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team_name: Option<String>,
+}
+
+impl SlackSettingsInput {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(link_on_issue_id_mention: bool) -> Self {
+        Self {
+            enable_agent: None,
+            enable_linear_agent_workflow_access: None,
+            enterprise_id: None,
+            enterprise_name: None,
+            external_user_actions: None,
+            link_on_issue_id_mention,
+            should_unfurl: None,
+            should_use_default_unfurl: None,
+            team_id: None,
+            team_name: None,
+        }
+    }
+}
+
+/// Comparator for issue source type.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SourceMetadataComparator {
+    /// Equals constraint.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub eq: Option<String>,
+    /// In-array constraint.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#in: Option<Vec<String>>,
+    /// Not-equals constraint.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub neq: Option<String>,
+    /// Not-in-array constraint.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nin: Option<Vec<String>>,
+    /// Null constraint. Matches any non-null values if the given value is false, otherwise it matches null values.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub null: Option<bool>,
+    /// \[INTERNAL\] Comparator for the salesforce metadata.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub salesforce_metadata: Option<Box<SalesforceMetadataIntegrationComparator>>,
+    /// Comparator for the sub type.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sub_type: Option<Box<SubTypeComparator>>,
+}
+
+/// Comparator for `sourceType` field.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SourceTypeComparator {
+    /// Contains constraint. Matches any values that contain the given string.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub contains: Option<String>,
+    /// Contains case insensitive constraint. Matches any values that contain the given string case insensitive.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub contains_ignore_case: Option<String>,
+    /// Contains case and accent insensitive constraint. Matches any values that contain the given string case and accent insensitive.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub contains_ignore_case_and_accent: Option<String>,
+    /// Ends with constraint. Matches any values that end with the given string.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ends_with: Option<String>,
+    /// Equals constraint.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub eq: Option<String>,
+    /// Equals case insensitive. Matches any values that matches the given string case insensitive.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub eq_ignore_case: Option<String>,
+    /// In-array constraint.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#in: Option<Vec<String>>,
+    /// Not-equals constraint.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub neq: Option<String>,
+    /// Not-equals case insensitive. Matches any values that don't match the given string case insensitive.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub neq_ignore_case: Option<String>,
+    /// Not-in-array constraint.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nin: Option<Vec<String>>,
+    /// Doesn't contain constraint. Matches any values that don't contain the given string.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub not_contains: Option<String>,
+    /// Doesn't contain case insensitive constraint. Matches any values that don't contain the given string case insensitive.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub not_contains_ignore_case: Option<String>,
+    /// Doesn't end with constraint. Matches any values that don't end with the given string.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub not_ends_with: Option<String>,
+    /// Doesn't start with constraint. Matches any values that don't start with the given string.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub not_starts_with: Option<String>,
+    /// Starts with constraint. Matches any values that start with the given string.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub starts_with: Option<String>,
+    /// Starts with case insensitive constraint. Matches any values that start with the given string.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub starts_with_ignore_case: Option<String>,
+}
+
+/// Project start date sorting options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StartDateSort {
+    /// Whether nulls should be sorted first or last
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nulls: Option<PaginationNulls>,
+    /// The order for the individual sort
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order: Option<PaginationSortOrder>,
+}
+
+/// Comparator for strings.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StringArrayComparator {
+    /// Compound filters, all of which need to be matched.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub every: Option<Box<StringItemComparator>>,
+    /// Length of the array. Matches any values that have the given length.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub length: Option<Box<NumberComparator>>,
+    /// Compound filters, one of which needs to be matched.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub some: Option<Box<StringItemComparator>>,
+}
+
+/// Comparator for strings.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StringComparator {
+    /// Contains constraint. Matches any values that contain the given string.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub contains: Option<String>,
+    /// Contains case insensitive constraint. Matches any values that contain the given string case insensitive.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub contains_ignore_case: Option<String>,
+    /// Contains case and accent insensitive constraint. Matches any values that contain the given string case and accent insensitive.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub contains_ignore_case_and_accent: Option<String>,
+    /// Ends with constraint. Matches any values that end with the given string.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ends_with: Option<String>,
+    /// Equals constraint.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub eq: Option<String>,
+    /// Equals case insensitive. Matches any values that matches the given string case insensitive.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub eq_ignore_case: Option<String>,
+    /// In-array constraint.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#in: Option<Vec<String>>,
+    /// Not-equals constraint.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub neq: Option<String>,
+    /// Not-equals case insensitive. Matches any values that don't match the given string case insensitive.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub neq_ignore_case: Option<String>,
+    /// Not-in-array constraint.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nin: Option<Vec<String>>,
+    /// Doesn't contain constraint. Matches any values that don't contain the given string.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub not_contains: Option<String>,
+    /// Doesn't contain case insensitive constraint. Matches any values that don't contain the given string case insensitive.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub not_contains_ignore_case: Option<String>,
+    /// Doesn't end with constraint. Matches any values that don't end with the given string.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub not_ends_with: Option<String>,
+    /// Doesn't start with constraint. Matches any values that don't start with the given string.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub not_starts_with: Option<String>,
+    /// Starts with constraint. Matches any values that start with the given string.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub starts_with: Option<String>,
+    /// Starts with case insensitive constraint. Matches any values that start with the given string.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub starts_with_ignore_case: Option<String>,
+}
+
+/// Comparator for strings in arrays.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StringItemComparator {
+    /// Contains constraint. Matches any values that contain the given string.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub contains: Option<String>,
+    /// Contains case insensitive constraint. Matches any values that contain the given string case insensitive.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub contains_ignore_case: Option<String>,
+    /// Contains case and accent insensitive constraint. Matches any values that contain the given string case and accent insensitive.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub contains_ignore_case_and_accent: Option<String>,
+    /// Ends with constraint. Matches any values that end with the given string.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ends_with: Option<String>,
+    /// Equals constraint.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub eq: Option<String>,
+    /// Equals case insensitive. Matches any values that matches the given string case insensitive.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub eq_ignore_case: Option<String>,
+    /// In-array constraint.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#in: Option<Vec<String>>,
+    /// Not-equals constraint.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub neq: Option<String>,
+    /// Not-equals case insensitive. Matches any values that don't match the given string case insensitive.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub neq_ignore_case: Option<String>,
+    /// Not-in-array constraint.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nin: Option<Vec<String>>,
+    /// Doesn't contain constraint. Matches any values that don't contain the given string.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub not_contains: Option<String>,
+    /// Doesn't contain case insensitive constraint. Matches any values that don't contain the given string case insensitive.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub not_contains_ignore_case: Option<String>,
+    /// Doesn't end with constraint. Matches any values that don't end with the given string.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub not_ends_with: Option<String>,
+    /// Doesn't start with constraint. Matches any values that don't start with the given string.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub not_starts_with: Option<String>,
+    /// Starts with constraint. Matches any values that start with the given string.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub starts_with: Option<String>,
+    /// Starts with case insensitive constraint. Matches any values that start with the given string.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub starts_with_ignore_case: Option<String>,
+}
+
+/// Comparator for source type.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubTypeComparator {
+    /// Equals constraint.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub eq: Option<String>,
+    /// In-array constraint.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#in: Option<Vec<String>>,
+    /// Not-equals constraint.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub neq: Option<String>,
+    /// Not-in-array constraint.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nin: Option<Vec<String>>,
+    /// Null constraint. Matches any non-null values if the given value is false, otherwise it matches null values.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub null: Option<bool>,
+}
+
+/// Project target date sorting options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TargetDateSort {
+    /// Whether nulls should be sorted first or last
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nulls: Option<PaginationNulls>,
+    /// The order for the individual sort
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order: Option<PaginationSortOrder>,
+}
+
+/// Team collection filtering options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TeamCollectionFilter {
+    /// Compound filters, all of which need to be matched by the team.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub and: Option<Vec<TeamCollectionFilter>>,
+    /// Comparator for the created at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<Box<DateComparator>>,
+    /// Filters that needs to be matched by all teams.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub every: Option<Box<TeamFilter>>,
+    /// Comparator for the identifier.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<Box<IDComparator>>,
+    /// Comparator for the collection length.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub length: Option<Box<NumberComparator>>,
+    /// Compound filters, one of which need to be matched by the team.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub or: Option<Vec<TeamCollectionFilter>>,
+    /// Filters that the teams parent must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent: Option<Box<NullableTeamFilter>>,
+    /// Filters that needs to be matched by some teams.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub some: Option<Box<TeamFilter>>,
+    /// Comparator for the updated at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<Box<DateComparator>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TeamCreateInput {
+    /// Period after which closed and completed issues are automatically archived, in months. 0 means disabled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_archive_period: Option<f64>,
+    /// Period after which issues are automatically closed, in months.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_close_period: Option<f64>,
+    /// The canceled workflow state which auto closed issues will be set to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_close_state_id: Option<String>,
+    /// The color of the team.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    /// The cooldown time after each cycle in weeks.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cycle_cooldown_time: Option<i64>,
+    /// The duration of each cycle in weeks.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cycle_duration: Option<i64>,
+    /// Auto assign completed issues to current active cycle setting.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cycle_issue_auto_assign_completed: Option<bool>,
+    /// Auto assign started issues to current active cycle setting.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cycle_issue_auto_assign_started: Option<bool>,
+    /// Only allow issues issues with cycles in Active Issues.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cycle_lock_to_active: Option<bool>,
+    /// The day of the week that a new cycle starts.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cycle_start_day: Option<f64>,
+    /// Whether the team uses cycles.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cycles_enabled: Option<bool>,
+    /// What to use as an default estimate for unestimated issues.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_issue_estimate: Option<f64>,
+    /// The identifier of the default project template of this team.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_project_template_id: Option<String>,
+    /// The identifier of the default template for members of this team.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_template_for_members_id: Option<String>,
+    /// The identifier of the default template for non-members of this team.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_template_for_non_members_id: Option<String>,
+    /// The description of the team.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Whether to group recent issue history entries.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub group_issue_history: Option<bool>,
+    /// The icon of the team.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub icon: Option<String>,
+    /// The identifier in UUID v4 format. If none is provided, the backend will generate one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// Whether the team should inherit estimation settings from its parent. Only applies to sub-teams.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inherit_issue_estimation: Option<bool>,
+    /// \[Internal\] Whether the team should inherit its product intelligence scope from its parent. Only applies to sub-teams.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inherit_product_intelligence_scope: Option<bool>,
+    /// \[Internal\] Whether the team should inherit workflow statuses from its parent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inherit_workflow_statuses: Option<bool>,
+    /// Whether to allow zeros in issues estimates.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_estimation_allow_zero: Option<bool>,
+    /// Whether to add additional points to the estimate scale.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_estimation_extended: Option<bool>,
+    /// The issue estimation type to use. Must be one of "notUsed", "exponential", "fibonacci", "linear", "tShirt".
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_estimation_type: Option<String>,
+    /// The key of the team. If not given, the key will be generated based on the name of the team.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key: Option<String>,
+    /// The workflow state into which issues are moved when they are marked as a duplicate of another issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub marked_as_duplicate_workflow_state_id: Option<String>,
+    /// The name of the team.
+    pub name: String,
+    /// The parent team ID.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_id: Option<String>,
+    /// Internal. Whether the team is private or not.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub private: Option<bool>,
+    /// \[Internal\] The scope of product intelligence suggestion data for the team.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub product_intelligence_scope: Option<ProductIntelligenceScope>,
+    /// Whether an issue needs to have a priority set before leaving triage.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub require_priority_to_leave_triage: Option<bool>,
+    /// Whether to move issues to bottom of the column when changing state.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub set_issue_sort_order_on_state_change: Option<String>,
+    /// The timezone of the team.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+    /// Whether triage mode is enabled for the team.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub triage_enabled: Option<bool>,
+    /// How many upcoming cycles to create.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub upcoming_cycle_count: Option<f64>,
+}
+
+impl TeamCreateInput {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(name: String) -> Self {
+        Self {
+            auto_archive_period: None,
+            auto_close_period: None,
+            auto_close_state_id: None,
+            color: None,
+            cycle_cooldown_time: None,
+            cycle_duration: None,
+            cycle_issue_auto_assign_completed: None,
+            cycle_issue_auto_assign_started: None,
+            cycle_lock_to_active: None,
+            cycle_start_day: None,
+            cycles_enabled: None,
+            default_issue_estimate: None,
+            default_project_template_id: None,
+            default_template_for_members_id: None,
+            default_template_for_non_members_id: None,
+            description: None,
+            group_issue_history: None,
+            icon: None,
+            id: None,
+            inherit_issue_estimation: None,
+            inherit_product_intelligence_scope: None,
+            inherit_workflow_statuses: None,
+            issue_estimation_allow_zero: None,
+            issue_estimation_extended: None,
+            issue_estimation_type: None,
+            key: None,
+            marked_as_duplicate_workflow_state_id: None,
+            name,
+            parent_id: None,
+            private: None,
+            product_intelligence_scope: None,
+            require_priority_to_leave_triage: None,
+            set_issue_sort_order_on_state_change: None,
+            timezone: None,
+            triage_enabled: None,
+            upcoming_cycle_count: None,
+        }
+    }
+}
+
+/// Team filtering options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TeamFilter {
+    /// Compound filters, all of which need to be matched by the team.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub and: Option<Vec<TeamFilter>>,
+    /// Comparator for the created at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<Box<DateComparator>>,
+    /// Comparator for the team description.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<Box<NullableStringComparator>>,
+    /// Comparator for the identifier.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<Box<IDComparator>>,
+    /// Filters that the teams issues must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issues: Option<Box<IssueCollectionFilter>>,
+    /// Comparator for the team key.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key: Option<Box<StringComparator>>,
+    /// Comparator for the team name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<Box<StringComparator>>,
+    /// Compound filters, one of which need to be matched by the team.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub or: Option<Vec<TeamFilter>>,
+    /// Filters that the teams parent must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent: Option<Box<NullableTeamFilter>>,
+    /// Comparator for the team privacy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub private: Option<Box<BooleanComparator>>,
+    /// Comparator for the updated at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<Box<DateComparator>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TeamMembershipCreateInput {
+    /// The identifier in UUID v4 format. If none is provided, the backend will generate one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// Internal. Whether the user is the owner of the team.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner: Option<bool>,
+    /// The position of the item in the users list.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort_order: Option<f64>,
+    /// The identifier of the team associated with the membership.
+    pub team_id: String,
+    /// The identifier of the user associated with the membership.
+    pub user_id: String,
+}
+
+impl TeamMembershipCreateInput {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(team_id: String, user_id: String) -> Self {
+        Self {
+            id: None,
+            owner: None,
+            sort_order: None,
+            team_id,
+            user_id,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TeamMembershipUpdateInput {
+    /// Internal. Whether the user is the owner of the team.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner: Option<bool>,
+    /// The position of the item in the users list.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort_order: Option<f64>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TeamSecuritySettingsInput {
+    /// The minimum team role required to manage labels in the team.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label_management: Option<TeamRoleType>,
+    /// The minimum team role required to manage full workspace members (non-guests) in the team.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub member_management: Option<TeamRoleType>,
+    /// The minimum team role required to manage team settings.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team_management: Option<TeamRoleType>,
+    /// The minimum team role required to manage templates in the team.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub template_management: Option<TeamRoleType>,
+}
+
+/// Issue team sorting options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TeamSort {
+    /// Whether nulls should be sorted first or last
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nulls: Option<PaginationNulls>,
+    /// The order for the individual sort
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order: Option<PaginationSortOrder>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TeamUpdateInput {
+    /// Whether to enable AI discussion summaries for issues.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ai_discussion_summaries_enabled: Option<bool>,
+    /// Whether to enable resolved thread AI summaries.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ai_thread_summaries_enabled: Option<bool>,
+    /// Whether all members in the workspace can join the team. Only used for public teams.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub all_members_can_join: Option<bool>,
+    /// Period after which closed and completed issues are automatically archived, in months.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_archive_period: Option<f64>,
+    /// Whether to automatically close all sub-issues when a parent issue in this team is closed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_close_child_issues: Option<bool>,
+    /// Whether to automatically close a parent issue in this team if all its sub-issues are closed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_close_parent_issues: Option<bool>,
+    /// Period after which issues are automatically closed, in months.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_close_period: Option<f64>,
+    /// The canceled workflow state which auto closed issues will be set to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_close_state_id: Option<String>,
+    /// The color of the team.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    /// The cooldown time after each cycle in weeks.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cycle_cooldown_time: Option<i64>,
+    /// The duration of each cycle in weeks.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cycle_duration: Option<i64>,
+    /// The date to begin cycles on.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cycle_enabled_start_date: Option<DateTime>,
+    /// Auto assign completed issues to current active cycle setting.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cycle_issue_auto_assign_completed: Option<bool>,
+    /// Auto assign started issues to current active cycle setting.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cycle_issue_auto_assign_started: Option<bool>,
+    /// Only allow issues with cycles in Active Issues.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cycle_lock_to_active: Option<bool>,
+    /// The day of the week that a new cycle starts.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cycle_start_day: Option<f64>,
+    /// Whether the team uses cycles.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cycles_enabled: Option<bool>,
+    /// What to use as an default estimate for unestimated issues.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_issue_estimate: Option<f64>,
+    /// Default status for newly created issues.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_issue_state_id: Option<String>,
+    /// The identifier of the default project template of this team.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_project_template_id: Option<String>,
+    /// The identifier of the default template for members of this team.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_template_for_members_id: Option<String>,
+    /// The identifier of the default template for non-members of this team.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_template_for_non_members_id: Option<String>,
+    /// The description of the team.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Whether to group recent issue history entries.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub group_issue_history: Option<bool>,
+    /// The icon of the team.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub icon: Option<String>,
+    /// Whether the team should inherit estimation settings from its parent. Only applies to sub-teams.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inherit_issue_estimation: Option<bool>,
+    /// \[Internal\] Whether the team should inherit its product intelligence scope from its parent. Only applies to sub-teams.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inherit_product_intelligence_scope: Option<bool>,
+    /// \[Internal\] Whether the team should inherit workflow statuses from its parent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inherit_workflow_statuses: Option<bool>,
+    /// Whether to allow zeros in issues estimates.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_estimation_allow_zero: Option<bool>,
+    /// Whether to add additional points to the estimate scale.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_estimation_extended: Option<bool>,
+    /// The issue estimation type to use. Must be one of "notUsed", "exponential", "fibonacci", "linear", "tShirt".
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_estimation_type: Option<String>,
+    /// Whether new users should join this team by default. Mutation restricted to workspace admins or owners!
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub join_by_default: Option<bool>,
+    /// The key of the team.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key: Option<String>,
+    /// The workflow state into which issues are moved when they are marked as a duplicate of another issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub marked_as_duplicate_workflow_state_id: Option<String>,
+    /// The name of the team.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The parent team ID.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_id: Option<String>,
+    /// Whether the team is private or not.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub private: Option<bool>,
+    /// \[Internal\] The scope of product intelligence suggestion data for the team.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub product_intelligence_scope: Option<ProductIntelligenceScope>,
+    /// Whether an issue needs to have a priority set before leaving triage.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub require_priority_to_leave_triage: Option<bool>,
+    /// \[Internal\] When the team was retired.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retired_at: Option<DateTime>,
+    /// Whether the team is managed by SCIM integration. Mutation restricted to workspace admins or owners and only unsetting is allowed!
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scim_managed: Option<bool>,
+    /// The security settings for the team.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub security_settings: Option<Box<TeamSecuritySettingsInput>>,
+    /// Whether to move issues to bottom of the column when changing state.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub set_issue_sort_order_on_state_change: Option<String>,
+    /// Whether to send new issue comment notifications to Slack.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slack_issue_comments: Option<bool>,
+    /// Whether to send issue status update notifications to Slack.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slack_issue_statuses: Option<bool>,
+    /// Whether to send new issue notifications to Slack.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slack_new_issue: Option<bool>,
+    /// The timezone of the team.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+    /// Whether triage mode is enabled for the team.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub triage_enabled: Option<bool>,
+    /// How many upcoming cycles to create.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub upcoming_cycle_count: Option<f64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TemplateCreateInput {
+    /// The template description.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The identifier in UUID v4 format. If none is provided, the backend will generate one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The template name.
+    pub name: String,
+    /// The position of the template in the templates list.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort_order: Option<f64>,
+    /// The identifier or key of the team associated with the template. If not given, the template will be shared across all teams.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team_id: Option<String>,
+    /// The template data as JSON encoded attributes of the type of entity, such as an issue.
+    pub template_data: JSON,
+    /// The template type, e.g. 'issue'.
+    pub r#type: String,
+}
+
+impl TemplateCreateInput {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(name: String, template_data: JSON, r#type: String) -> Self {
+        Self {
+            description: None,
+            id: None,
+            name,
+            sort_order: None,
+            team_id: None,
+            template_data,
+            r#type,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TemplateUpdateInput {
+    /// The template description.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The template name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The position of the template in the templates list.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort_order: Option<f64>,
+    /// The identifier or key of the team associated with the template. If set to null, the template will be shared across all teams.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team_id: Option<String>,
+    /// The template data as JSON encoded attributes of the type of entity, such as an issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub template_data: Option<JSON>,
+}
+
+/// Customer tier sorting options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TierSort {
+    /// Whether nulls should be sorted first or last
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nulls: Option<PaginationNulls>,
+    /// The order for the individual sort
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order: Option<PaginationSortOrder>,
+}
+
+/// Issue time in status sorting options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TimeInStatusSort {
+    /// Whether nulls should be sorted first or last
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nulls: Option<PaginationNulls>,
+    /// The order for the individual sort
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order: Option<PaginationSortOrder>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TimeScheduleCreateInput {
+    /// The schedule entries.
+    pub entries: Vec<TimeScheduleEntryInput>,
+    /// The unique identifier of the external schedule.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub external_id: Option<String>,
+    /// The URL to the external schedule.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub external_url: Option<String>,
+    /// The identifier in UUID v4 format. If none is provided, the backend will generate one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The name of the schedule.
+    pub name: String,
+}
+
+impl TimeScheduleCreateInput {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(entries: Vec<TimeScheduleEntryInput>, name: String) -> Self {
+        Self {
+            entries,
+            external_id: None,
+            external_url: None,
+            id: None,
+            name,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TimeScheduleEntryInput {
+    /// The end date of the schedule in ISO 8601 date-time format.
+    pub ends_at: DateTime,
+    /// The start date of the schedule in ISO 8601 date-time format.
+    pub starts_at: DateTime,
+    /// The email, name or reference to the user on schedule. This is used in case the external user could not be mapped to a Linear user id.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_email: Option<String>,
+    /// The Linear user id of the user on schedule. If the user cannot be mapped to a Linear user then `userEmail` can be used as a reference.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<String>,
+}
+
+impl TimeScheduleEntryInput {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(ends_at: DateTime, starts_at: DateTime) -> Self {
+        Self {
+            ends_at,
+            starts_at,
+            user_email: None,
+            user_id: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TimeScheduleUpdateInput {
+    /// The schedule entries.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub entries: Option<Vec<TimeScheduleEntryInput>>,
+    /// The unique identifier of the external schedule.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub external_id: Option<String>,
+    /// The URL to the external schedule.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub external_url: Option<String>,
+    /// The name of the schedule.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+/// Issue title sorting options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TitleSort {
+    /// Whether nulls should be sorted first or last
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nulls: Option<PaginationNulls>,
+    /// The order for the individual sort
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order: Option<PaginationSortOrder>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenUserAccountAuthInput {
+    /// The email which to login via the magic login code.
+    pub email: String,
+    /// An optional invite link for an organization.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub invite_link: Option<String>,
+    /// The timezone of the user's browser.
+    pub timezone: String,
+    /// The magic login code.
+    pub token: String,
+}
+
+impl TokenUserAccountAuthInput {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(email: String, timezone: String, token: String) -> Self {
+        Self {
+            email,
+            invite_link: None,
+            timezone,
+            token,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TriageResponsibilityCreateInput {
+    /// The action to take when an issue is added to triage.
+    pub action: String,
+    /// The identifier in UUID v4 format. If none is provided, the backend will generate one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The manual selection of users responsible for triage.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub manual_selection: Option<Box<TriageResponsibilityManualSelectionInput>>,
+    /// The identifier of the team associated with the triage responsibility.
+    pub team_id: String,
+    /// The identifier of the time schedule used for scheduling triage responsibility
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time_schedule_id: Option<String>,
+}
+
+impl TriageResponsibilityCreateInput {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(action: String, team_id: String) -> Self {
+        Self {
+            action,
+            id: None,
+            manual_selection: None,
+            team_id,
+            time_schedule_id: None,
+        }
+    }
+}
+
+/// Manual triage responsibility using a set of users.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TriageResponsibilityManualSelectionInput {
+    /// \[Internal\] The index of the current userId used for the assign action when having more than one user.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub assignment_index: Option<i64>,
+    /// The set of users responsible for triage.
+    pub user_ids: Vec<String>,
+}
+
+impl TriageResponsibilityManualSelectionInput {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(user_ids: Vec<String>) -> Self {
+        Self {
+            assignment_index: None,
+            user_ids,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TriageResponsibilityUpdateInput {
+    /// The action to take when an issue is added to triage.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub action: Option<String>,
+    /// The manual selection of users responsible for triage.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub manual_selection: Option<Box<TriageResponsibilityManualSelectionInput>>,
+    /// The identifier of the time schedule used for scheduling triage responsibility.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time_schedule_id: Option<String>,
+}
+
+/// Issue update date sorting options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdatedAtSort {
+    /// Whether nulls should be sorted first or last
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nulls: Option<PaginationNulls>,
+    /// The order for the individual sort
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order: Option<PaginationSortOrder>,
+}
+
+/// User filtering options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserCollectionFilter {
+    /// Comparator for the user's activity status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub active: Option<Box<BooleanComparator>>,
+    /// Comparator for the user's admin status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub admin: Option<Box<BooleanComparator>>,
+    /// Compound filters, all of which need to be matched by the user.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub and: Option<Vec<UserCollectionFilter>>,
+    /// Comparator for the user's app status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub app: Option<Box<BooleanComparator>>,
+    /// Filters that the users assigned issues must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub assigned_issues: Option<Box<IssueCollectionFilter>>,
+    /// Comparator for the created at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<Box<DateComparator>>,
+    /// Comparator for the user's display name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<Box<StringComparator>>,
+    /// Comparator for the user's email.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email: Option<Box<StringComparator>>,
+    /// Filters that needs to be matched by all users.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub every: Option<Box<UserFilter>>,
+    /// Comparator for the identifier.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<Box<IDComparator>>,
+    /// Comparator for the user's invited status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub invited: Option<Box<BooleanComparator>>,
+    /// Comparator for the user's invited status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_invited: Option<Box<BooleanComparator>>,
+    /// Filter based on the currently authenticated user. Set to true to filter for the authenticated user, false for any other user.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_me: Option<Box<BooleanComparator>>,
+    /// Comparator for the collection length.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub length: Option<Box<NumberComparator>>,
+    /// Comparator for the user's name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<Box<StringComparator>>,
+    /// Compound filters, one of which need to be matched by the user.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub or: Option<Vec<UserCollectionFilter>>,
+    /// \[Internal\] Comparator for the user's owner status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner: Option<Box<BooleanComparator>>,
+    /// Filters that needs to be matched by some users.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub some: Option<Box<UserFilter>>,
+    /// Comparator for the updated at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<Box<DateComparator>>,
+}
+
+/// User display name sorting options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserDisplayNameSort {
+    /// Whether nulls should be sorted first or last
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nulls: Option<PaginationNulls>,
+    /// The order for the individual sort
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order: Option<PaginationSortOrder>,
+}
+
+/// User filtering options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserFilter {
+    /// Comparator for the user's activity status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub active: Option<Box<BooleanComparator>>,
+    /// Comparator for the user's admin status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub admin: Option<Box<BooleanComparator>>,
+    /// Compound filters, all of which need to be matched by the user.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub and: Option<Vec<UserFilter>>,
+    /// Comparator for the user's app status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub app: Option<Box<BooleanComparator>>,
+    /// Filters that the users assigned issues must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub assigned_issues: Option<Box<IssueCollectionFilter>>,
+    /// Comparator for the created at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<Box<DateComparator>>,
+    /// Comparator for the user's display name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<Box<StringComparator>>,
+    /// Comparator for the user's email.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email: Option<Box<StringComparator>>,
+    /// Comparator for the identifier.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<Box<IDComparator>>,
+    /// Comparator for the user's invited status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub invited: Option<Box<BooleanComparator>>,
+    /// Comparator for the user's invited status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_invited: Option<Box<BooleanComparator>>,
+    /// Filter based on the currently authenticated user. Set to true to filter for the authenticated user, false for any other user.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_me: Option<Box<BooleanComparator>>,
+    /// Comparator for the user's name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<Box<StringComparator>>,
+    /// Compound filters, one of which need to be matched by the user.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub or: Option<Vec<UserFilter>>,
+    /// \[Internal\] Comparator for the user's owner status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner: Option<Box<BooleanComparator>>,
+    /// Comparator for the updated at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<Box<DateComparator>>,
+}
+
+/// User name sorting options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserNameSort {
+    /// Whether nulls should be sorted first or last
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nulls: Option<PaginationNulls>,
+    /// The order for the individual sort
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order: Option<PaginationSortOrder>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserSettingsUpdateInput {
+    /// \[Internal\] The user's last seen time for the pulse feed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub feed_last_seen_time: Option<DateTime>,
+    /// \[Internal\] How often to generate a feed summary.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub feed_summary_schedule: Option<FeedSummarySchedule>,
+    /// The user's notification category preferences.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notification_category_preferences: Option<Box<NotificationCategoryPreferencesInput>>,
+    /// The user's notification channel preferences.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notification_channel_preferences: Option<Box<PartialNotificationChannelPreferencesInput>>,
+    /// The user's notification delivery preferences.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notification_delivery_preferences: Option<Box<NotificationDeliveryPreferencesInput>>,
+    /// The user's settings.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub settings: Option<JSONObject>,
+    /// Whether this user is subscribed to changelog email or not.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subscribed_to_changelog: Option<bool>,
+    /// Whether this user is subscribed to DPA emails or not.
+    #[serde(rename = "subscribedToDPA", default, skip_serializing_if = "Option::is_none")]
+    pub subscribed_to_dpa: Option<bool>,
+    /// Whether this user is subscribed to general marketing communications or not.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subscribed_to_general_marketing_communications: Option<bool>,
+    /// Whether this user is subscribed to invite accepted emails or not.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subscribed_to_invite_accepted: Option<bool>,
+    /// Whether this user is subscribed to privacy and legal update emails or not.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subscribed_to_privacy_legal_updates: Option<bool>,
+    /// \[Internal\] The user's usage warning history.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub usage_warning_history: Option<JSONObject>,
+}
+
+/// User sorting options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserSortInput {
+    /// Sort by user display name
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<Box<UserDisplayNameSort>>,
+    /// Sort by user name
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<Box<UserNameSort>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserUpdateInput {
+    /// The avatar image URL of the user.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub avatar_url: Option<String>,
+    /// The user description or a short bio.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The display name of the user.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    /// The name of the user.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The emoji part of the user status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status_emoji: Option<String>,
+    /// The label part of the user status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status_label: Option<String>,
+    /// When the user status should be cleared.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status_until_at: Option<DateTime>,
+    /// The local timezone of the user.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ViewPreferencesCreateInput {
+    /// The custom view these view preferences are associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub custom_view_id: Option<String>,
+    /// The identifier in UUID v4 format. If none is provided, the backend will generate one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// \[Internal\] The initiative these view preferences are associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative_id: Option<String>,
+    /// The default parameters for the insight on that view.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub insights: Option<JSONObject>,
+    /// The label these view preferences are associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label_id: Option<String>,
+    /// View preferences object.
+    pub preferences: JSONObject,
+    /// The project these view preferences are associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_id: Option<String>,
+    /// The project label these view preferences are associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_label_id: Option<String>,
+    /// The team these view preferences are associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team_id: Option<String>,
+    /// The type of view preferences (either user or organization level preferences).
+    pub r#type: ViewPreferencesType,
+    /// The user profile these view preferences are associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<String>,
+    /// The view type of the view preferences are associated with.
+    pub view_type: ViewType,
+}
+
+impl ViewPreferencesCreateInput {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(preferences: JSONObject, r#type: ViewPreferencesType, view_type: ViewType) -> Self {
+        Self {
+            custom_view_id: None,
+            id: None,
+            initiative_id: None,
+            insights: None,
+            label_id: None,
+            preferences,
+            project_id: None,
+            project_label_id: None,
+            team_id: None,
+            r#type,
+            user_id: None,
+            view_type,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ViewPreferencesUpdateInput {
+    /// The default parameters for the insight on that view.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub insights: Option<JSONObject>,
+    /// View preferences.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub preferences: Option<JSONObject>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookCreateInput {
+    /// Whether this webhook is enabled for all public teams.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub all_public_teams: Option<bool>,
+    /// Whether this webhook is enabled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    /// The identifier in UUID v4 format. If none is provided, the backend will generate one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// Label for the webhook.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    /// List of resources the webhook should subscribe to.
+    pub resource_types: Vec<String>,
+    /// A secret token used to sign the webhook payload.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub secret: Option<String>,
+    /// The identifier or key of the team associated with the Webhook.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team_id: Option<String>,
+    /// The URL that will be called on data changes.
+    pub url: String,
+}
+
+impl WebhookCreateInput {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(resource_types: Vec<String>, url: String) -> Self {
+        Self {
+            all_public_teams: None,
+            enabled: None,
+            id: None,
+            label: None,
+            resource_types,
+            secret: None,
+            team_id: None,
+            url,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookUpdateInput {
+    /// Whether this webhook is enabled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    /// Label for the webhook.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    /// List of resources the webhook should subscribe to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resource_types: Option<Vec<String>>,
+    /// A secret token used to sign the webhook payload.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub secret: Option<String>,
+    /// The URL that will be called on data changes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkflowStateCreateInput {
+    /// The color of the state.
+    pub color: String,
+    /// The description of the state.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The identifier in UUID v4 format. If none is provided, the backend will generate one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The name of the state.
+    pub name: String,
+    /// The position of the state.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub position: Option<f64>,
+    /// The team associated with the state.
+    pub team_id: String,
+    /// The workflow type.
+    pub r#type: String,
+}
+
+impl WorkflowStateCreateInput {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(color: String, name: String, team_id: String, r#type: String) -> Self {
+        Self {
+            color,
+            description: None,
+            id: None,
+            name,
+            position: None,
+            team_id,
+            r#type,
+        }
+    }
+}
+
+/// Workflow state filtering options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkflowStateFilter {
+    /// Compound filters, all of which need to be matched by the workflow state.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub and: Option<Vec<WorkflowStateFilter>>,
+    /// Comparator for the created at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<Box<DateComparator>>,
+    /// Comparator for the workflow state description.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<Box<StringComparator>>,
+    /// Comparator for the identifier.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<Box<IDComparator>>,
+    /// Filters that the workflow states issues must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issues: Option<Box<IssueCollectionFilter>>,
+    /// Comparator for the workflow state name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<Box<StringComparator>>,
+    /// Compound filters, one of which need to be matched by the workflow state.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub or: Option<Vec<WorkflowStateFilter>>,
+    /// Comparator for the workflow state position.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub position: Option<Box<NumberComparator>>,
+    /// Filters that the workflow states team must satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team: Option<Box<TeamFilter>>,
+    /// Comparator for the workflow state type. Possible values are "triage", "backlog", "unstarted", "started", "completed", "canceled".
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<Box<StringComparator>>,
+    /// Comparator for the updated at date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<Box<DateComparator>>,
+}
+
+/// Issue workflow state sorting options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkflowStateSort {
+    /// Whether to sort closed issues by recency
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub closed_issues_ordered_by_recency: Option<bool>,
+    /// Whether nulls should be sorted first or last
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nulls: Option<PaginationNulls>,
+    /// The order for the individual sort
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order: Option<PaginationSortOrder>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkflowStateUpdateInput {
+    /// The color of the state.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    /// The description of the state.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The name of the state.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The position of the state.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub position: Option<f64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ZendeskSettingsInput {
+    /// Whether a ticket should be automatically reopened when its linked Linear issue is cancelled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub automate_ticket_reopening_on_cancellation: Option<bool>,
+    /// Whether a ticket should be automatically reopened when a comment is posted on its linked Linear issue
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub automate_ticket_reopening_on_comment: Option<bool>,
+    /// Whether a ticket should be automatically reopened when its linked Linear issue is completed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub automate_ticket_reopening_on_completion: Option<bool>,
+    /// Whether a ticket should be automatically reopened when its linked Linear project is cancelled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub automate_ticket_reopening_on_project_cancellation: Option<bool>,
+    /// Whether a ticket should be automatically reopened when its linked Linear project is completed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub automate_ticket_reopening_on_project_completion: Option<bool>,
+    /// The ID of the Linear bot user.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bot_user_id: Option<String>,
+    /// \[INTERNAL\] Temporary flag indicating if the integration has the necessary scopes for Customers
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub can_read_customers: Option<bool>,
+    /// \[ALPHA\] Whether customer and customer requests should not be automatically created when conversations are linked to a Linear issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub disable_customer_requests_auto_creation: Option<bool>,
+    /// Whether Linear Agent should be enabled for this integration.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enable_ai_intake: Option<bool>,
+    /// Whether an internal message should be added when someone comments on an issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub send_note_on_comment: Option<bool>,
+    /// Whether an internal message should be added when a Linear issue changes status (for status types except completed or canceled).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub send_note_on_status_change: Option<bool>,
+    /// The subdomain of the Zendesk organization being connected.
+    pub subdomain: String,
+    /// \[INTERNAL\] Flag indicating if the integration supports OAuth refresh tokens
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub supports_o_auth_refresh: Option<bool>,
+    /// The URL of the connected Zendesk organization.
+    pub url: String,
+}
+
+impl ZendeskSettingsInput {
+    /// Input with the required fields set and every optional field unset
+    pub fn new(subdomain: String, url: String) -> Self {
+        Self {
+            automate_ticket_reopening_on_cancellation: None,
+            automate_ticket_reopening_on_comment: None,
+            automate_ticket_reopening_on_completion: None,
+            automate_ticket_reopening_on_project_cancellation: None,
+            automate_ticket_reopening_on_project_completion: None,
+            bot_user_id: None,
+            can_read_customers: None,
+            disable_customer_requests_auto_creation: None,
+            enable_ai_intake: None,
+            send_note_on_comment: None,
+            send_note_on_status_change: None,
+            subdomain,
+            supports_o_auth_refresh: None,
+            url,
+        }
+    }
+}
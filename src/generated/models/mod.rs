@@ -0,0 +1,16 @@
+//! Generated models - DO NOT EDIT
+//! Run `cargo xtask codegen` to regenerate
+//!
+//! Serde types for the schema's objects, inputs and enums. Object fields are
+//! all optional because a query only returns the fields it selects; input
+//! fields are optional only where the schema allows it.
+
+mod enums;
+mod inputs;
+mod objects;
+mod scalars;
+
+pub use enums::*;
+pub use inputs::*;
+pub use objects::*;
+pub use scalars::*;
@@ -0,0 +1,14950 @@
+//! Generated models: object and interface types - DO NOT EDIT
+//! Run `cargo xtask codegen` to regenerate
+
+use serde::{Deserialize, Serialize};
+
+use super::enums::*;
+use super::scalars::*;
+
+/// A bot actor is an actor that is not a user, but an application or integration.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ActorBot {
+    /// A url pointing to the avatar representing this bot.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub avatar_url: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The display name of the bot.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The sub type of the bot.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sub_type: Option<String>,
+    /// The type of bot.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+    /// The display name of the external user on behalf of which the bot acted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_display_name: Option<String>,
+}
+
+/// An activity within an agent context.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AgentActivity {
+    /// The agent session this activity belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub agent_session: Option<Box<AgentSession>>,
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// The content of the activity
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content: Option<AgentActivityContent>,
+    /// \[Internal\] Metadata about user-provided contextual information for this agent activity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub contextual_metadata: Option<JSON>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// Whether the activity is ephemeral, and should disappear after the next agent activity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ephemeral: Option<bool>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// An optional modifier that provides additional instructions on how the activity should be interpreted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signal: Option<AgentActivitySignal>,
+    /// Metadata about this agent activity's signal.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signal_metadata: Option<JSON>,
+    /// The comment this activity is linked to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_comment: Option<Box<Comment>>,
+    /// Metadata about the external source that created this agent activity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_metadata: Option<JSON>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+    /// The user who created this agent activity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<Box<User>>,
+}
+
+/// Content for an action activity (tool call or action).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AgentActivityActionContent {
+    /// The action being performed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub action: Option<String>,
+    /// The parameters for the action, e.g. a file path, a keyword, etc.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parameter: Option<String>,
+    /// The result of the action in Markdown format.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub result: Option<String>,
+    /// The type of activity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<AgentActivityType>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AgentActivityConnection {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub edges: Option<Vec<AgentActivityEdge>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nodes: Option<Vec<AgentActivity>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page_info: Option<Box<PageInfo>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AgentActivityEdge {
+    /// Used in `before` and `after` args
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub node: Option<Box<AgentActivity>>,
+}
+
+/// Content for an elicitation activity.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AgentActivityElicitationContent {
+    /// The elicitation message in Markdown format.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    /// The type of activity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<AgentActivityType>,
+}
+
+/// Content for an error activity.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AgentActivityErrorContent {
+    /// The error message in Markdown format.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    /// The type of activity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<AgentActivityType>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AgentActivityPayload {
+    /// The agent activity that was created or updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub agent_activity: Option<Box<AgentActivity>>,
+    /// The identifier of the last sync operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_sync_id: Option<f64>,
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+}
+
+/// Content for a prompt activity.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AgentActivityPromptContent {
+    /// A message requesting additional information or action from user.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    /// The type of activity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<AgentActivityType>,
+}
+
+/// Content for a response activity.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AgentActivityResponseContent {
+    /// The response content in Markdown format.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    /// The type of activity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<AgentActivityType>,
+}
+
+/// Content for a thought activity.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AgentActivityThoughtContent {
+    /// The thought content in Markdown format.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    /// The type of activity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<AgentActivityType>,
+}
+
+/// Payload for an agent activity webhook.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AgentActivityWebhookPayload {
+    /// The ID of the agent session that this activity belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub agent_session_id: Option<String>,
+    /// The time at which the entity was archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<String>,
+    /// The content of the agent activity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content: Option<JSONObject>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    /// The ID of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// An optional modifier that provides additional instructions on how the activity should be interpreted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signal: Option<String>,
+    /// Metadata about this agent activity's signal.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signal_metadata: Option<JSONObject>,
+    /// The ID of the comment this activity is linked to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_comment_id: Option<String>,
+    /// The time at which the entity was updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+    /// The ID of the user who created this agent activity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<String>,
+}
+
+/// A session for agent activities and state management.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AgentSession {
+    /// Activities associated with this agent session.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub activities: Option<Box<AgentActivityConnection>>,
+    /// The agent user that is associated with this agent session.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub app_user: Option<Box<User>>,
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// The comment this agent session is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<Box<Comment>>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// The human user responsible for the agent session. Null if the session was initiated via automation or by an agent user, with no responsible human user.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub creator: Option<Box<User>>,
+    /// The time the agent session was dismissed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dismissed_at: Option<DateTime>,
+    /// The user who dismissed the agent session.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dismissed_by: Option<Box<User>>,
+    /// The time the agent session ended.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ended_at: Option<DateTime>,
+    /// The URL of an external agent-hosted page associated with this session.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub external_link: Option<String>,
+    /// URLs of external resources associated with this session.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub external_urls: Option<JSON>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The issue this agent session is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue: Option<Box<Issue>>,
+    /// A dynamically updated list of the agent's execution strategy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub plan: Option<JSON>,
+    /// \[Internal\] A formatted prompt string containing relevant context for the agent session, including issue details, comments, and guidance.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prompt_context: Option<String>,
+    /// \[Internal\] Pull requests associated with this agent session.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pull_requests: Option<Box<AgentSessionToPullRequestConnection>>,
+    /// The comment that this agent session was spawned from, if from a different thread.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_comment: Option<Box<Comment>>,
+    /// Metadata about the external source that created this agent session.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_metadata: Option<JSON>,
+    /// The time the agent session started.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub started_at: Option<DateTime>,
+    /// The current status of the agent session.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<AgentSessionStatus>,
+    /// A summary of the activities in this session.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    /// The type of the agent session.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<AgentSessionType>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AgentSessionConnection {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub edges: Option<Vec<AgentSessionEdge>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nodes: Option<Vec<AgentSession>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page_info: Option<Box<PageInfo>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AgentSessionEdge {
+    /// Used in `before` and `after` args
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub node: Option<Box<AgentSession>>,
+}
+
+/// Payload for agent session webhook events.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AgentSessionEventWebhookPayload {
+    /// The type of action that triggered the webhook.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub action: Option<String>,
+    /// The agent activity that was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub agent_activity: Option<Box<AgentActivityWebhookPayload>>,
+    /// The agent session that the event belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub agent_session: Option<Box<AgentSessionWebhookPayload>>,
+    /// ID of the app user the agent session belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub app_user_id: Option<String>,
+    /// The time the payload was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// Guidance to inform the agent's behavior, which comes from configuration at the level of the workspace, parent teams, and/or current team for this session. The nearest team-specific guidance should take highest precendence.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub guidance: Option<Vec<GuidanceRuleWebhookPayload>>,
+    /// ID of the OAuth client the app user is tied to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub oauth_client_id: Option<String>,
+    /// ID of the organization for which the webhook belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization_id: Option<String>,
+    /// The previous comments in the thread before this agent was mentioned and the session was initiated, if any. Present only for `created` events where the session was initiated by mentioning the agent in a child comment of a thread.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub previous_comments: Option<Vec<CommentChildWebhookPayload>>,
+    /// A formatted prompt string containing the relevant context for the agent session, including issue details, comments, and guidance. Present only for `created` events.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prompt_context: Option<String>,
+    /// The type of resource.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+    /// The ID of the webhook that sent this event.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub webhook_id: Option<String>,
+    /// Unix timestamp in milliseconds when the webhook was sent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub webhook_timestamp: Option<f64>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AgentSessionPayload {
+    /// The agent session that was created or updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub agent_session: Option<Box<AgentSession>>,
+    /// The identifier of the last sync operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_sync_id: Option<f64>,
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+}
+
+/// Join table between agent sessions and pull requests.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AgentSessionToPullRequest {
+    /// The agent session that the pull request is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub agent_session: Option<Box<AgentSession>>,
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The pull request that the agent session is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pull_request: Option<Box<PullRequest>>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AgentSessionToPullRequestConnection {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub edges: Option<Vec<AgentSessionToPullRequestEdge>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nodes: Option<Vec<AgentSessionToPullRequest>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page_info: Option<Box<PageInfo>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AgentSessionToPullRequestEdge {
+    /// Used in `before` and `after` args
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub node: Option<Box<AgentSessionToPullRequest>>,
+}
+
+/// Payload for an agent session webhook.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AgentSessionWebhookPayload {
+    /// The ID of the agent that the agent session belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub app_user_id: Option<String>,
+    /// The time at which the entity was archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<String>,
+    /// The root comment of the thread this agent session is attached to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<Box<CommentChildWebhookPayload>>,
+    /// The ID of the root comment of the thread this agent session is attached to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment_id: Option<String>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    /// The human user responsible for the agent session. Unset if the session was initiated via automation or by an agent user, with no responsible human user.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub creator: Option<Box<UserChildWebhookPayload>>,
+    /// The ID of the human user responsible for the agent session. Unset if the session was initiated via automation or by an agent user, with no responsible human user.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub creator_id: Option<String>,
+    /// The time the agent session ended.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ended_at: Option<String>,
+    /// The ID of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The issue this agent session is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue: Option<Box<IssueWithDescriptionChildWebhookPayload>>,
+    /// The ID of the issue this agent session is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_id: Option<String>,
+    /// The ID of the organization that the agent session belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization_id: Option<String>,
+    /// The ID of the comment that this agent session was spawned from, if from a different thread.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_comment_id: Option<String>,
+    /// Metadata about the external source that created this agent session.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_metadata: Option<JSONObject>,
+    /// The time the agent session started working.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub started_at: Option<String>,
+    /// The current status of the agent session.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    /// A summary of the activities in this session.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    /// The type of the agent session.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+    /// The time at which the entity was updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+}
+
+/// AI prompt rules for a team.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AiPromptRules {
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+    /// The user who last updated the AI prompt rules.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_by: Option<Box<User>>,
+}
+
+/// Payload for app user notification webhook events.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AppUserNotificationWebhookPayload {
+    /// The type of action that triggered the webhook.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub action: Option<String>,
+    /// ID of the app user the notification is for.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub app_user_id: Option<String>,
+    /// The time the payload was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// Details of the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notification: Option<NotificationWebhookPayload>,
+    /// ID of the OAuth client the app user is tied to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub oauth_client_id: Option<String>,
+    /// ID of the organization for which the webhook belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization_id: Option<String>,
+    /// The type of resource.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+    /// The ID of the webhook that sent this event.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub webhook_id: Option<String>,
+    /// Unix timestamp in milliseconds when the webhook was sent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub webhook_timestamp: Option<f64>,
+}
+
+/// Payload for app user team access change webhook events.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AppUserTeamAccessChangedWebhookPayload {
+    /// The type of action that triggered the webhook.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub action: Option<String>,
+    /// IDs of the teams the app user was added to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub added_team_ids: Option<Vec<String>>,
+    /// ID of the app user the notification is for.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub app_user_id: Option<String>,
+    /// Whether the app user can access all public teams.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub can_access_all_public_teams: Option<bool>,
+    /// The time the payload was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// ID of the OAuth client the app user is tied to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub oauth_client_id: Option<String>,
+    /// ID of the organization for which the webhook belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization_id: Option<String>,
+    /// IDs of the teams the app user was removed from.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub removed_team_ids: Option<Vec<String>>,
+    /// The type of resource.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+    /// The ID of the webhook that sent this event.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub webhook_id: Option<String>,
+    /// Unix timestamp in milliseconds when the webhook was sent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub webhook_timestamp: Option<f64>,
+}
+
+/// Public information of the OAuth application.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Application {
+    /// OAuth application's client ID.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_id: Option<String>,
+    /// Information about the application.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Name of the developer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub developer: Option<String>,
+    /// Url of the developer (homepage or docs).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub developer_url: Option<String>,
+    /// OAuth application's ID.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// Image of the application.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub image_url: Option<String>,
+    /// Application name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+/// A generic payload return from entity archive or deletion mutations.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArchivePayload {
+    /// The identifier of the last sync operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_sync_id: Option<f64>,
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+}
+
+/// Contains requested archived model objects.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArchiveResponse {
+    /// A JSON serialized collection of model objects loaded from the archive
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archive: Option<String>,
+    /// The version of the remote database. Incremented by 1 for each migration run on the database.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub database_version: Option<f64>,
+    /// Whether the dependencies for the model objects are included in the archive.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub includes_dependencies: Option<Vec<String>>,
+    /// The total number of entities in the archive.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub total_count: Option<f64>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AsksChannelConnectPayload {
+    /// Whether the bot needs to be manually added to the channel.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub add_bot: Option<bool>,
+    /// The integration that was created or updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integration: Option<Box<Integration>>,
+    /// The identifier of the last sync operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_sync_id: Option<f64>,
+    /// The new Asks Slack channel mapping for the connected channel.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mapping: Option<Box<SlackChannelNameMapping>>,
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AsksWebFormsAuthResponse {
+    /// User email.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    /// User display name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+/// Issue attachment (e.g. support ticket, pull request).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Attachment {
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// The body data of the attachment, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body_data: Option<String>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// The creator of the attachment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub creator: Option<Box<User>>,
+    /// The non-Linear user who created the attachment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub external_user_creator: Option<Box<ExternalUser>>,
+    /// Indicates if attachments for the same source application should be grouped in the Linear UI.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub group_by_source: Option<bool>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The issue this attachment belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue: Option<Box<Issue>>,
+    /// Custom metadata related to the attachment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<JSONObject>,
+    /// The issue this attachment was originally created on. Will be undefined if the attachment hasn't been moved.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub original_issue: Option<Box<Issue>>,
+    /// Information about the source which created the attachment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<JSONObject>,
+    /// An accessor helper to source.type, defines the source type of the attachment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_type: Option<String>,
+    /// Content for the subtitle line in the Linear attachment widget.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subtitle: Option<String>,
+    /// Content for the title line in the Linear attachment widget.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+    /// Location of the attachment which is also used as an identifier.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AttachmentConnection {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub edges: Option<Vec<AttachmentEdge>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nodes: Option<Vec<Attachment>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page_info: Option<Box<PageInfo>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AttachmentEdge {
+    /// Used in `before` and `after` args
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub node: Option<Box<Attachment>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AttachmentPayload {
+    /// The issue attachment that was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub attachment: Option<Box<Attachment>>,
+    /// The identifier of the last sync operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_sync_id: Option<f64>,
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AttachmentSourcesPayload {
+    /// A unique list of all source types used in this workspace.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sources: Option<JSONObject>,
+}
+
+/// Payload for an attachment webhook.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AttachmentWebhookPayload {
+    /// The time at which the entity was archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<String>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    /// The ID of the creator of the attachment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub creator_id: Option<String>,
+    /// The ID of the non-Linear user who created the attachment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub external_user_creator_id: Option<String>,
+    /// Whether attachments for the same source application should be grouped in the Linear UI.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub group_by_source: Option<bool>,
+    /// The ID of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The ID of the issue this attachment belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_id: Option<String>,
+    /// Custom metadata related to the attachment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<JSONObject>,
+    /// The ID of the issue this attachment belonged to originally.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub original_issue_id: Option<String>,
+    /// Information about the source which created the attachment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<JSONObject>,
+    /// The source type of the attachment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_type: Option<String>,
+    /// Optional subtitle of the attachment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subtitle: Option<String>,
+    /// The title of the attachment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// The time at which the entity was updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+    /// The URL of the attachment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+/// Workspace audit log entry object.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditEntry {
+    /// The user that caused the audit entry to be created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor: Option<Box<User>>,
+    /// The ID of the user that caused the audit entry to be created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor_id: Option<String>,
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// Country code of request resulting to audit entry.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub country_code: Option<String>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// IP from actor when entry was recorded.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ip: Option<String>,
+    /// Additional metadata related to the audit entry.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<JSONObject>,
+    /// The organization the audit log belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization: Option<Box<Organization>>,
+    /// Additional information related to the request which performed the action.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub request_information: Option<JSONObject>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditEntryConnection {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub edges: Option<Vec<AuditEntryEdge>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nodes: Option<Vec<AuditEntry>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page_info: Option<Box<PageInfo>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditEntryEdge {
+    /// Used in `before` and `after` args
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub node: Option<Box<AuditEntry>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditEntryType {
+    /// Description of the audit entry type.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The audit entry type.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+}
+
+/// Payload for an audit entry webhook.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditEntryWebhookPayload {
+    /// The ID of the user that caused the audit entry to be created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor_id: Option<String>,
+    /// The time at which the entity was archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<String>,
+    /// Country code of request resulting to audit entry.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub country_code: Option<String>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    /// The ID of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// IP from actor when entry was recorded.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ip: Option<String>,
+    /// Additional metadata related to the audit entry.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<JSONObject>,
+    /// The ID of the organization that the audit entry belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization_id: Option<String>,
+    /// Additional information related to the request which performed the action.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub request_information: Option<JSONObject>,
+    /// The type of the audit entry.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+    /// The time at which the entity was updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+}
+
+/// An identity provider.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuthIdentityProvider {
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// Whether the identity provider is the default identity provider migrated from organization level settings.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_migrated: Option<bool>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The issuer's custom entity ID.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issuer_entity_id: Option<String>,
+    /// The SAML priority used to pick default workspace in SAML SP initiated flow, when same domain is claimed for SAML by multiple workspaces. Lower priority value means higher preference.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority: Option<f64>,
+    /// Whether SAML authentication is enabled for organization.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub saml_enabled: Option<bool>,
+    /// Whether SCIM provisioning is enabled for organization.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scim_enabled: Option<bool>,
+    /// The service provider (Linear) custom entity ID. Defaults to https://auth.linear.app/sso
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sp_entity_id: Option<String>,
+    /// Binding method for authentication call. Can be either `post` (default) or `redirect`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sso_binding: Option<String>,
+    /// Sign in endpoint URL for the identity provider.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sso_endpoint: Option<String>,
+    /// The algorithm of the Signing Certificate. Can be one of `sha1`, `sha256` (default), or `sha512`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sso_sign_algo: Option<String>,
+    /// X.509 Signing Certificate in string form.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sso_signing_cert: Option<String>,
+    /// The type of identity provider.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<IdentityProviderType>,
+}
+
+/// An organization. Organizations are root-level objects that contain users and teams.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuthOrganization {
+    /// Allowed authentication providers, empty array means all are allowed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allowed_auth_services: Option<Vec<String>>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// The time at which deletion of the organization was requested.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deletion_requested_at: Option<DateTime>,
+    /// Whether the organization is enabled. Used as a superuser tool to lock down the org.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The organization's logo URL.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub logo_url: Option<String>,
+    /// The organization's name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Previously used URL keys for the organization (last 3 are kept and redirected).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub previous_url_keys: Option<Vec<String>>,
+    /// The region the organization is hosted in.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub region: Option<String>,
+    /// The feature release channel the organization belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub release_channel: Option<ReleaseChannel>,
+    /// Whether SAML authentication is enabled for organization.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub saml_enabled: Option<bool>,
+    /// \[INTERNAL\] SAML settings
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub saml_settings: Option<JSONObject>,
+    /// Whether SCIM provisioning is enabled for organization.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scim_enabled: Option<bool>,
+    /// The email domain or URL key for the organization.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub service_id: Option<String>,
+    /// The organization's unique URL key.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url_key: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_count: Option<f64>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuthResolverResponse {
+    /// Should the signup flow allow access for the domain.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allow_domain_access: Option<bool>,
+    /// List of organizations allowing this user account to join automatically.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub available_organizations: Option<Vec<AuthOrganization>>,
+    /// Email for the authenticated account.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    /// User account ID.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// ID of the organization last accessed by the user.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_used_organization_id: Option<String>,
+    /// List of organization available to this user account but locked due to the current auth method.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub locked_organizations: Option<Vec<AuthOrganization>>,
+    /// List of locked users that are locked by login restrictions
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub locked_users: Option<Vec<AuthUser>>,
+    /// Application token.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub token: Option<String>,
+    /// List of active users that belong to the user account.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub users: Option<Vec<AuthUser>>,
+}
+
+/// A user that has access to the the resources of an organization.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuthUser {
+    /// Whether the user is active.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+    /// An URL to the user's avatar image.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub avatar_url: Option<String>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// The user's display (nick) name. Unique within each organization.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    /// The user's email address.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// \[INTERNAL\] Identity provider the user is managed by.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub identity_provider: Option<Box<AuthIdentityProvider>>,
+    /// The user's full name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Organization the user belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization: Option<Box<AuthOrganization>>,
+    /// Whether the user is an organization admin or guest on a database level.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub role: Option<UserRoleType>,
+    /// User account ID the user belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_account_id: Option<String>,
+}
+
+/// Authentication session information.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuthenticationSessionResponse {
+    /// Used web browser.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub browser_type: Option<String>,
+    /// Client used for the session
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client: Option<String>,
+    /// Country codes of all seen locations.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub country_codes: Option<Vec<String>>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// IP address.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ip: Option<String>,
+    /// Identifies the session used to make the request.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_current_session: Option<bool>,
+    /// When was the session last seen
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_active_at: Option<DateTime>,
+    /// Human readable location
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+    /// Location city name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location_city: Option<String>,
+    /// Location country name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location_country: Option<String>,
+    /// Location country code.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location_country_code: Option<String>,
+    /// Location region code.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location_region_code: Option<String>,
+    /// Name of the session, derived from the client and operating system
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Operating system used for the session
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub operating_system: Option<String>,
+    /// Service used for logging in.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub service: Option<String>,
+    /// Type of application used to authenticate.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<AuthenticationSessionType>,
+    /// Date when the session was last updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+    /// Session's user-agent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_agent: Option<String>,
+}
+
+/// Base fields for all webhook payloads.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BaseWebhookPayload {
+    /// The time the payload was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// ID of the organization for which the webhook belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization_id: Option<String>,
+    /// The ID of the webhook that sent this event.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub webhook_id: Option<String>,
+    /// Unix timestamp in milliseconds when the webhook was sent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub webhook_timestamp: Option<f64>,
+}
+
+/// A comment associated with an issue.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Comment {
+    /// Agent session associated with this comment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub agent_session: Option<Box<AgentSession>>,
+    /// \[Internal\] Agent sessions associated with this comment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub agent_sessions: Option<Box<AgentSessionConnection>>,
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// The comment content in markdown format.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    /// \[Internal\] The comment content as a Prosemirror document.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body_data: Option<String>,
+    /// The bot that created the comment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bot_actor: Option<Box<ActorBot>>,
+    /// The children of the comment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub children: Option<Box<CommentConnection>>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// Issues created from this comment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_issues: Option<Box<IssueConnection>>,
+    /// The document content that the comment is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub document_content: Option<Box<DocumentContent>>,
+    /// The ID of the document content that the comment is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub document_content_id: Option<String>,
+    /// The time user edited the comment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub edited_at: Option<DateTime>,
+    /// The external thread that the comment is synced with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub external_thread: Option<Box<SyncedExternalThread>>,
+    /// The external user who wrote the comment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub external_user: Option<Box<ExternalUser>>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The initiative update that the comment is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative_update: Option<Box<InitiativeUpdate>>,
+    /// The ID of the initiative update that the comment is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative_update_id: Option<String>,
+    /// The issue that the comment is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue: Option<Box<Issue>>,
+    /// The ID of the issue that the comment is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_id: Option<String>,
+    /// The parent comment under which the current comment is nested.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent: Option<Box<Comment>>,
+    /// The ID of the parent comment under which the current comment is nested.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_id: Option<String>,
+    /// The post that the comment is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub post: Option<Box<Post>>,
+    /// The project update that the comment is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_update: Option<Box<ProjectUpdate>>,
+    /// The ID of the project update that the comment is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_update_id: Option<String>,
+    /// The text that this comment references. Only defined for inline comments.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub quoted_text: Option<String>,
+    /// Emoji reaction summary, grouped by emoji type.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reaction_data: Option<JSONObject>,
+    /// Reactions associated with the comment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reactions: Option<Vec<Reaction>>,
+    /// The time the resolvingUser resolved the thread.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resolved_at: Option<DateTime>,
+    /// The comment that resolved the thread.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resolving_comment: Option<Box<Comment>>,
+    /// The ID of the comment that resolved the thread.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resolving_comment_id: Option<String>,
+    /// The user that resolved the thread.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resolving_user: Option<Box<User>>,
+    /// The external services the comment is synced with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub synced_with: Option<Vec<ExternalEntityInfo>>,
+    /// \[Internal\] A generated summary of the comment thread.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub thread_summary: Option<JSONObject>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+    /// Comment's URL.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// The user who wrote the comment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<Box<User>>,
+}
+
+/// Certain properties of a comment.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommentChildWebhookPayload {
+    /// The body of the comment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    /// The ID of the document content this comment belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub document_content_id: Option<String>,
+    /// The ID of the comment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The ID of the initiative update this comment belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative_update_id: Option<String>,
+    /// The ID of the issue this comment belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_id: Option<String>,
+    /// The ID of the project update this comment belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_update_id: Option<String>,
+    /// The ID of the user who created this comment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommentConnection {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub edges: Option<Vec<CommentEdge>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nodes: Option<Vec<Comment>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page_info: Option<Box<PageInfo>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommentEdge {
+    /// Used in `before` and `after` args
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub node: Option<Box<Comment>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommentPayload {
+    /// The comment that was created or updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<Box<Comment>>,
+    /// The identifier of the last sync operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_sync_id: Option<f64>,
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+}
+
+/// Payload for a comment webhook.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommentWebhookPayload {
+    /// The time at which the entity was archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<String>,
+    /// The body of the comment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    /// The bot actor data for this comment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bot_actor: Option<String>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    /// The document content for this comment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub document_content: Option<Box<DocumentContentChildWebhookPayload>>,
+    /// The ID of the document content this comment belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub document_content_id: Option<String>,
+    /// When the comment was last edited.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub edited_at: Option<String>,
+    /// The external user who created this comment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub external_user: Option<Box<ExternalUserChildWebhookPayload>>,
+    /// The ID of the external user who created this comment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub external_user_id: Option<String>,
+    /// The ID of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The initiative update this comment belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative_update: Option<Box<InitiativeUpdateChildWebhookPayload>>,
+    /// The ID of the initiative update this comment belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative_update_id: Option<String>,
+    /// The issue this comment belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue: Option<Box<IssueChildWebhookPayload>>,
+    /// The ID of the issue this comment belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_id: Option<String>,
+    /// The parent comment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent: Option<Box<CommentChildWebhookPayload>>,
+    /// The ID of the parent comment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_id: Option<String>,
+    /// The ID of the post this comment belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub post_id: Option<String>,
+    /// The project update this comment belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_update: Option<Box<ProjectUpdateChildWebhookPayload>>,
+    /// The ID of the project update this comment belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_update_id: Option<String>,
+    /// The quoted text in this comment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub quoted_text: Option<String>,
+    /// The reaction data for this comment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reaction_data: Option<JSONObject>,
+    /// When the comment was resolved.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resolved_at: Option<String>,
+    /// The ID of the comment that resolved this comment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resolving_comment_id: Option<String>,
+    /// The ID of the user who resolved this comment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resolving_user_id: Option<String>,
+    /// The entity this comment is synced with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub synced_with: Option<JSONObject>,
+    /// The time at which the entity was updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+    /// The user who created this comment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<Box<UserChildWebhookPayload>>,
+    /// The ID of the user who created this comment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContactPayload {
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateCsvExportReportPayload {
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateOrJoinOrganizationResponse {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization: Option<Box<AuthOrganization>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<Box<AuthUser>>,
+}
+
+/// Payload for custom webhook resource events.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomResourceWebhookPayload {
+    /// The type of action that triggered the webhook.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub action: Option<String>,
+    /// The time the payload was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// ID of the organization for which the webhook belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization_id: Option<String>,
+    /// The type of resource.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+    /// The ID of the webhook that sent this event.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub webhook_id: Option<String>,
+    /// Unix timestamp in milliseconds when the webhook was sent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub webhook_timestamp: Option<f64>,
+}
+
+/// A custom view that has been saved by a user.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomView {
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// The color of the icon of the custom view.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// The user who created the custom view.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub creator: Option<Box<User>>,
+    /// The description of the custom view.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// \[INTERNAL\] The facet associated with the custom view.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub facet: Option<Box<Facet>>,
+    /// The filter applied to feed items in the custom view.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub feed_item_filter_data: Option<JSONObject>,
+    /// The filter applied to issues in the custom view.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filter_data: Option<JSONObject>,
+    /// The filters applied to issues in the custom view.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filters: Option<JSONObject>,
+    /// The icon of the custom view.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub icon: Option<String>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The filter applied to initiatives in the custom view.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative_filter_data: Option<JSONObject>,
+    /// Initiatives associated with the custom view.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiatives: Option<Box<InitiativeConnection>>,
+    /// Issues associated with the custom view.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issues: Option<Box<IssueConnection>>,
+    /// The model name of the custom view.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub model_name: Option<String>,
+    /// The name of the custom view.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The organization of the custom view.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization: Option<Box<Organization>>,
+    /// The organizations default view preferences for this custom view.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization_view_preferences: Option<Box<ViewPreferences>>,
+    /// The user who owns the custom view.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner: Option<Box<User>>,
+    /// The filter applied to projects in the custom view.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_filter_data: Option<JSONObject>,
+    /// Projects associated with the custom view.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub projects: Option<Box<ProjectConnection>>,
+    /// Whether the custom view is shared with everyone in the organization.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shared: Option<bool>,
+    /// The custom view's unique URL slug.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slug_id: Option<String>,
+    /// The team associated with the custom view.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team: Option<Box<Team>>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+    /// The user who last updated the custom view.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_by: Option<Box<User>>,
+    /// Feed items associated with the custom view.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updates: Option<Box<FeedItemConnection>>,
+    /// The current users view preferences for this custom view.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_view_preferences: Option<Box<ViewPreferences>>,
+    /// The calculated view preferences values for this custom view.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub view_preferences_values: Option<Box<ViewPreferencesValues>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomViewConnection {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub edges: Option<Vec<CustomViewEdge>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nodes: Option<Vec<CustomView>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page_info: Option<Box<PageInfo>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomViewEdge {
+    /// Used in `before` and `after` args
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub node: Option<Box<CustomView>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomViewHasSubscribersPayload {
+    /// Whether the custom view has subscribers.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_subscribers: Option<bool>,
+}
+
+/// A custom view notification subscription.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomViewNotificationSubscription {
+    /// Whether the subscription is active or not.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// The type of view to which the notification subscription context is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub context_view_type: Option<ContextViewType>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// The custom view subscribed to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub custom_view: Option<Box<CustomView>>,
+    /// The customer associated with the notification subscription.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer: Option<Box<Customer>>,
+    /// The contextual cycle view associated with the notification subscription.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cycle: Option<Box<Cycle>>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The contextual initiative view associated with the notification subscription.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative: Option<Box<Initiative>>,
+    /// The contextual label view associated with the notification subscription.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<Box<IssueLabel>>,
+    /// The type of subscription.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notification_subscription_types: Option<Vec<String>>,
+    /// The contextual project view associated with the notification subscription.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project: Option<Box<Project>>,
+    /// The user that subscribed to receive notifications.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subscriber: Option<Box<User>>,
+    /// The team associated with the notification subscription.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team: Option<Box<Team>>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+    /// The user view associated with the notification subscription.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<Box<User>>,
+    /// The type of user view to which the notification subscription context is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_context_view_type: Option<UserContextViewType>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomViewPayload {
+    /// The custom view that was created or updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub custom_view: Option<Box<CustomView>>,
+    /// The identifier of the last sync operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_sync_id: Option<f64>,
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomViewSuggestionPayload {
+    /// The suggested view description.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The suggested view icon.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub icon: Option<String>,
+    /// The suggested view name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+/// A customer whose needs will be tied to issues or projects.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Customer {
+    /// The approximate number of needs of the customer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub approximate_need_count: Option<f64>,
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// The domains associated with this customer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub domains: Option<Vec<String>>,
+    /// The ids of the customers in external systems.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub external_ids: Option<Vec<String>>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The integration that manages the Customer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integration: Option<Box<Integration>>,
+    /// The customer's logo URL.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub logo_url: Option<String>,
+    /// The ID of the main source, when a customer has multiple sources. Must be one of externalIds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub main_source_id: Option<String>,
+    /// The customer's name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The user who owns the customer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner: Option<Box<User>>,
+    /// The annual revenue generated by the customer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub revenue: Option<i64>,
+    /// The size of the customer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub size: Option<f64>,
+    /// The ID of the Slack channel used to interact with the customer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slack_channel_id: Option<String>,
+    /// The customer's unique URL slug.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slug_id: Option<String>,
+    /// The current status of the customer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<Box<CustomerStatus>>,
+    /// The tier of the customer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tier: Option<Box<CustomerTier>>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+    /// URL of the customer in Linear.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+/// Certain properties of a customer.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomerChildWebhookPayload {
+    /// The domains associated with this customer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub domains: Option<Vec<String>>,
+    /// The ids of the customers in external systems.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub external_ids: Option<Vec<String>>,
+    /// The ID of the customer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The name of the customer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomerConnection {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub edges: Option<Vec<CustomerEdge>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nodes: Option<Vec<Customer>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page_info: Option<Box<PageInfo>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomerEdge {
+    /// Used in `before` and `after` args
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub node: Option<Box<Customer>>,
+}
+
+/// A customer need, expressed through a reference to an issue, project, or comment.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomerNeed {
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// The attachment this need is referencing.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub attachment: Option<Box<Attachment>>,
+    /// The need content in markdown format.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    /// \[Internal\] The content of the need as a Prosemirror document.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body_data: Option<String>,
+    /// The comment this need is referencing.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<Box<Comment>>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// The creator of the customer need.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub creator: Option<Box<User>>,
+    /// The customer that this need is attached to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer: Option<Box<Customer>>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The issue this need is referencing.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue: Option<Box<Issue>>,
+    /// The issue this customer need was originally created on. Will be undefined if the customer need hasn't been moved.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub original_issue: Option<Box<Issue>>,
+    /// Whether the customer need is important or not. 0 = Not important, 1 = Important.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority: Option<f64>,
+    /// The project this need is referencing.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project: Option<Box<Project>>,
+    /// The project attachment this need is referencing.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_attachment: Option<Box<ProjectAttachment>>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+    /// The URL of the underlying attachment, if any
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+/// A generic payload return from entity archive mutations.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomerNeedArchivePayload {
+    /// The archived/unarchived entity. Null if entity was deleted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub entity: Option<Box<CustomerNeed>>,
+    /// The identifier of the last sync operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_sync_id: Option<f64>,
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+}
+
+/// Certain properties of a customer need.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomerNeedChildWebhookPayload {
+    /// The ID of the attachment this need is referencing.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub attachment_id: Option<String>,
+    /// The ID of the customer that this need is attached to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer_id: Option<String>,
+    /// The ID of the customer need.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The ID of the issue this need is referencing.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_id: Option<String>,
+    /// The ID of the project this need is referencing.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomerNeedConnection {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub edges: Option<Vec<CustomerNeedEdge>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nodes: Option<Vec<CustomerNeed>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page_info: Option<Box<PageInfo>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomerNeedEdge {
+    /// Used in `before` and `after` args
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub node: Option<Box<CustomerNeed>>,
+}
+
+/// A customer need related notification.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomerNeedNotification {
+    /// The user that caused the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor: Option<Box<User>>,
+    /// \[Internal\] Notification actor initials if avatar is not available.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor_avatar_color: Option<String>,
+    /// \[Internal\] Notification avatar URL.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor_avatar_url: Option<String>,
+    /// \[Internal\] Notification actor initials if avatar is not available.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor_initials: Option<String>,
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// The bot that caused the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bot_actor: Option<Box<ActorBot>>,
+    /// The category of the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub category: Option<NotificationCategory>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// The customer need related to the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer_need: Option<Box<CustomerNeed>>,
+    /// Related customer need.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer_need_id: Option<String>,
+    /// The time at when an email reminder for this notification was sent to the user. Null, if no email
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub emailed_at: Option<DateTime>,
+    /// The external user that caused the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub external_user_actor: Option<Box<ExternalUser>>,
+    /// \[Internal\] Notifications with the same grouping key will be grouped together in the UI.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub grouping_key: Option<String>,
+    /// \[Internal\] Priority of the notification with the same grouping key. Higher number means higher priority. If priority is the same, notifications should be sorted by `createdAt`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub grouping_priority: Option<f64>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// \[Internal\] Inbox URL for the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inbox_url: Option<String>,
+    /// \[Internal\] Initiative update health for new updates.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative_update_health: Option<String>,
+    /// \[Internal\] If notification actor was Linear.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_linear_actor: Option<bool>,
+    /// \[Internal\] Issue's status type for issue notifications.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_status_type: Option<String>,
+    /// \[Internal\] Project update health for new updates.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_update_health: Option<String>,
+    /// The time at when the user marked the notification as read. Null, if the the user hasn't read the notification
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub read_at: Option<DateTime>,
+    /// The issue related to the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub related_issue: Option<Box<Issue>>,
+    /// The project related to the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub related_project: Option<Box<Project>>,
+    /// The time until a notification will be snoozed. After that it will appear in the inbox again.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub snoozed_until_at: Option<DateTime>,
+    /// \[Internal\] Notification subtitle.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subtitle: Option<String>,
+    /// \[Internal\] Notification title.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Notification type.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+    /// The time at which a notification was unsnoozed..
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unsnoozed_at: Option<DateTime>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+    /// \[Internal\] URL to the target of the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// The user that received the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<Box<User>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomerNeedPayload {
+    /// The identifier of the last sync operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_sync_id: Option<f64>,
+    /// The customer need that was created or updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub need: Option<Box<CustomerNeed>>,
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomerNeedUpdatePayload {
+    /// The identifier of the last sync operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_sync_id: Option<f64>,
+    /// The customer need that was created or updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub need: Option<Box<CustomerNeed>>,
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+    /// The related customer needs that were updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_related_needs: Option<Vec<CustomerNeed>>,
+}
+
+/// Payload for a customer need webhook.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomerNeedWebhookPayload {
+    /// The time at which the entity was archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<String>,
+    /// The attachment this need is referencing.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub attachment: Option<Box<AttachmentWebhookPayload>>,
+    /// The ID of the attachment this need is referencing.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub attachment_id: Option<String>,
+    /// The body of the need in Markdown format.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    /// The ID of the comment this need is referencing.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment_id: Option<String>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    /// The ID of the creator of the customer need.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub creator_id: Option<String>,
+    /// The customer that this need is attached to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer: Option<Box<CustomerChildWebhookPayload>>,
+    /// The ID of the customer that this need is attached to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer_id: Option<String>,
+    /// The ID of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The issue this need is referencing.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue: Option<Box<IssueChildWebhookPayload>>,
+    /// The ID of the issue this need is referencing.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_id: Option<String>,
+    /// The issue ID this customer need was originally created on. Will be undefined if the customer need hasn't been moved.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub original_issue_id: Option<String>,
+    /// The priority of the need.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority: Option<f64>,
+    /// The project this need is referencing.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project: Option<Box<ProjectChildWebhookPayload>>,
+    /// The ID of the project attachment this need is referencing.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_attachment_id: Option<String>,
+    /// The ID of the project this need is referencing.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_id: Option<String>,
+    /// The time at which the entity was updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+}
+
+/// A customer related notification.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomerNotification {
+    /// The user that caused the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor: Option<Box<User>>,
+    /// \[Internal\] Notification actor initials if avatar is not available.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor_avatar_color: Option<String>,
+    /// \[Internal\] Notification avatar URL.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor_avatar_url: Option<String>,
+    /// \[Internal\] Notification actor initials if avatar is not available.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor_initials: Option<String>,
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// The bot that caused the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bot_actor: Option<Box<ActorBot>>,
+    /// The category of the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub category: Option<NotificationCategory>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// The customer related to the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer: Option<Box<Customer>>,
+    /// Related customer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer_id: Option<String>,
+    /// The time at when an email reminder for this notification was sent to the user. Null, if no email
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub emailed_at: Option<DateTime>,
+    /// The external user that caused the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub external_user_actor: Option<Box<ExternalUser>>,
+    /// \[Internal\] Notifications with the same grouping key will be grouped together in the UI.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub grouping_key: Option<String>,
+    /// \[Internal\] Priority of the notification with the same grouping key. Higher number means higher priority. If priority is the same, notifications should be sorted by `createdAt`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub grouping_priority: Option<f64>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// \[Internal\] Inbox URL for the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inbox_url: Option<String>,
+    /// \[Internal\] Initiative update health for new updates.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative_update_health: Option<String>,
+    /// \[Internal\] If notification actor was Linear.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_linear_actor: Option<bool>,
+    /// \[Internal\] Issue's status type for issue notifications.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_status_type: Option<String>,
+    /// \[Internal\] Project update health for new updates.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_update_health: Option<String>,
+    /// The time at when the user marked the notification as read. Null, if the the user hasn't read the notification
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub read_at: Option<DateTime>,
+    /// The time until a notification will be snoozed. After that it will appear in the inbox again.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub snoozed_until_at: Option<DateTime>,
+    /// \[Internal\] Notification subtitle.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subtitle: Option<String>,
+    /// \[Internal\] Notification title.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Notification type.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+    /// The time at which a notification was unsnoozed..
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unsnoozed_at: Option<DateTime>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+    /// \[Internal\] URL to the target of the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// The user that received the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<Box<User>>,
+}
+
+/// A customer notification subscription.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomerNotificationSubscription {
+    /// Whether the subscription is active or not.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// The type of view to which the notification subscription context is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub context_view_type: Option<ContextViewType>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// The contextual custom view associated with the notification subscription.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub custom_view: Option<Box<CustomView>>,
+    /// The customer subscribed to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer: Option<Box<Customer>>,
+    /// The contextual cycle view associated with the notification subscription.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cycle: Option<Box<Cycle>>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The contextual initiative view associated with the notification subscription.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative: Option<Box<Initiative>>,
+    /// The contextual label view associated with the notification subscription.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<Box<IssueLabel>>,
+    /// The type of subscription.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notification_subscription_types: Option<Vec<String>>,
+    /// The contextual project view associated with the notification subscription.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project: Option<Box<Project>>,
+    /// The user that subscribed to receive notifications.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subscriber: Option<Box<User>>,
+    /// The team associated with the notification subscription.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team: Option<Box<Team>>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+    /// The user view associated with the notification subscription.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<Box<User>>,
+    /// The type of user view to which the notification subscription context is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_context_view_type: Option<UserContextViewType>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomerPayload {
+    /// The customer that was created or updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer: Option<Box<Customer>>,
+    /// The identifier of the last sync operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_sync_id: Option<f64>,
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+}
+
+/// A customer status.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomerStatus {
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// The UI color of the status as a HEX string.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// Description of the status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The display name of the status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The name of the status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The position of the status in the workspace's customers flow.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub position: Option<f64>,
+    /// The type of the customer status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<CustomerStatusType>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+}
+
+/// Certain properties of a customer status.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomerStatusChildWebhookPayload {
+    /// The color of the customer status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    /// The description of the customer status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The display name of the customer status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    /// The ID of the customer status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The name of the customer status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The type of the customer status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomerStatusConnection {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub edges: Option<Vec<CustomerStatusEdge>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nodes: Option<Vec<CustomerStatus>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page_info: Option<Box<PageInfo>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomerStatusEdge {
+    /// Used in `before` and `after` args
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub node: Option<Box<CustomerStatus>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomerStatusPayload {
+    /// The identifier of the last sync operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_sync_id: Option<f64>,
+    /// The customer status that was created or updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<Box<CustomerStatus>>,
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+}
+
+/// A customer tier.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomerTier {
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// The UI color of the tier as a HEX string.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// Description of the tier.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The display name of the tier.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The name of the tier.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The position of the tier in the workspace's customers flow.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub position: Option<f64>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+}
+
+/// Certain properties of a customer tier.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomerTierChildWebhookPayload {
+    /// The color of the customer tier.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    /// The description of the customer tier.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The display name of the customer tier.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    /// The ID of the customer tier.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The name of the customer tier.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomerTierConnection {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub edges: Option<Vec<CustomerTierEdge>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nodes: Option<Vec<CustomerTier>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page_info: Option<Box<PageInfo>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomerTierEdge {
+    /// Used in `before` and `after` args
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub node: Option<Box<CustomerTier>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomerTierPayload {
+    /// The identifier of the last sync operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_sync_id: Option<f64>,
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+    /// The customer tier that was created or updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tier: Option<Box<CustomerTier>>,
+}
+
+/// Payload for a customer webhook.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomerWebhookPayload {
+    /// The approximate number of needs of the customer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub approximate_need_count: Option<f64>,
+    /// The time at which the entity was archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<String>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    /// The domains associated with this customer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub domains: Option<Vec<String>>,
+    /// The ids of the customers in external systems.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub external_ids: Option<Vec<String>>,
+    /// The ID of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The customer's logo URL.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub logo_url: Option<String>,
+    /// The ID of the main source, when a customer has multiple sources. Must be one of externalIds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub main_source_id: Option<String>,
+    /// The name of the customer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The ID of the user who owns the customer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner_id: Option<String>,
+    /// The annual revenue generated by the customer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub revenue: Option<f64>,
+    /// The size of the customer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub size: Option<f64>,
+    /// The ID of the Slack channel used to interact with the customer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slack_channel_id: Option<String>,
+    /// The customer's unique URL slug.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slug_id: Option<String>,
+    /// The customer status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<Box<CustomerStatusChildWebhookPayload>>,
+    /// The ID of the customer status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status_id: Option<String>,
+    /// The customer tier.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tier: Option<Box<CustomerTierChildWebhookPayload>>,
+    /// The ID of the customer tier.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tier_id: Option<String>,
+    /// The time at which the entity was updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+    /// The URL of the customer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+/// A set of issues to be resolved in a specified amount of time.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Cycle {
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// The time at which the cycle was automatically archived by the auto pruning process.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_archived_at: Option<DateTime>,
+    /// The completion time of the cycle. If null, the cycle hasn't been completed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub completed_at: Option<DateTime>,
+    /// The number of completed issues in the cycle after each day.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub completed_issue_count_history: Option<Vec<f64>>,
+    /// The number of completed estimation points after each day.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub completed_scope_history: Option<Vec<f64>>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// \[Internal\] The current progress of the cycle.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub current_progress: Option<JSONObject>,
+    /// The cycle's description.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The end time of the cycle.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ends_at: Option<DateTime>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The number of in progress estimation points after each day.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub in_progress_scope_history: Option<Vec<f64>>,
+    /// The cycle inherited from.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inherited_from: Option<Box<Cycle>>,
+    /// Whether the cycle is currently active.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_active: Option<bool>,
+    /// Whether the cycle is in the future.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_future: Option<bool>,
+    /// Whether the cycle is the next cycle for the team.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_next: Option<bool>,
+    /// Whether the cycle is in the past.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_past: Option<bool>,
+    /// Whether the cycle is the previous cycle for the team.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_previous: Option<bool>,
+    /// The total number of issues in the cycle after each day.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_count_history: Option<Vec<f64>>,
+    /// Issues associated with the cycle.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issues: Option<Box<IssueConnection>>,
+    /// The custom name of the cycle.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The number of the cycle.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub number: Option<f64>,
+    /// The overall progress of the cycle. This is the (completed estimate points + 0.25 * in progress estimate points) / total estimate points.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub progress: Option<f64>,
+    /// \[Internal\] The progress history of the cycle.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub progress_history: Option<JSONObject>,
+    /// The total number of estimation points after each day.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scope_history: Option<Vec<f64>>,
+    /// The start time of the cycle.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub starts_at: Option<DateTime>,
+    /// The team that the cycle is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team: Option<Box<Team>>,
+    /// Issues that weren't completed when the cycle was closed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub uncompleted_issues_upon_close: Option<Box<IssueConnection>>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+}
+
+/// A generic payload return from entity archive mutations.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CycleArchivePayload {
+    /// The archived/unarchived entity. Null if entity was deleted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub entity: Option<Box<Cycle>>,
+    /// The identifier of the last sync operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_sync_id: Option<f64>,
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+}
+
+/// Certain properties of a cycle.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CycleChildWebhookPayload {
+    /// The end date of the cycle.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ends_at: Option<String>,
+    /// The ID of the cycle.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The name of the cycle.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The number of the cycle.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub number: Option<f64>,
+    /// The start date of the cycle.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub starts_at: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CycleConnection {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub edges: Option<Vec<CycleEdge>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nodes: Option<Vec<Cycle>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page_info: Option<Box<PageInfo>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CycleEdge {
+    /// Used in `before` and `after` args
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub node: Option<Box<Cycle>>,
+}
+
+/// A cycle notification subscription.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CycleNotificationSubscription {
+    /// Whether the subscription is active or not.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// The type of view to which the notification subscription context is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub context_view_type: Option<ContextViewType>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// The contextual custom view associated with the notification subscription.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub custom_view: Option<Box<CustomView>>,
+    /// The customer associated with the notification subscription.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer: Option<Box<Customer>>,
+    /// The cycle subscribed to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cycle: Option<Box<Cycle>>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The contextual initiative view associated with the notification subscription.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative: Option<Box<Initiative>>,
+    /// The contextual label view associated with the notification subscription.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<Box<IssueLabel>>,
+    /// The type of subscription.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notification_subscription_types: Option<Vec<String>>,
+    /// The contextual project view associated with the notification subscription.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project: Option<Box<Project>>,
+    /// The user that subscribed to receive notifications.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subscriber: Option<Box<User>>,
+    /// The team associated with the notification subscription.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team: Option<Box<Team>>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+    /// The user view associated with the notification subscription.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<Box<User>>,
+    /// The type of user view to which the notification subscription context is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_context_view_type: Option<UserContextViewType>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CyclePayload {
+    /// The Cycle that was created or updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cycle: Option<Box<Cycle>>,
+    /// The identifier of the last sync operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_sync_id: Option<f64>,
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+}
+
+/// Payload for a cycle webhook.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CycleWebhookPayload {
+    /// The time at which the entity was archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<String>,
+    /// The time at which the cycle was automatically archived by the auto pruning process.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_archived_at: Option<String>,
+    /// The completion time of the cycle. If null, the cycle hasn't been completed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub completed_at: Option<String>,
+    /// The number of completed issues in the cycle after each day.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub completed_issue_count_history: Option<Vec<f64>>,
+    /// The number of completed estimation points after each day.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub completed_scope_history: Option<Vec<f64>>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    /// The cycle's description.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The end date of the cycle.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ends_at: Option<String>,
+    /// The ID of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The number of in progress estimation points after each day.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub in_progress_scope_history: Option<Vec<f64>>,
+    /// The ID of the cycle inherited from.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inherited_from_id: Option<String>,
+    /// The total number of issues in the cycle after each day.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_count_history: Option<Vec<f64>>,
+    /// The name of the cycle.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The number of the cycle.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub number: Option<f64>,
+    /// The total number of estimation points after each day.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scope_history: Option<Vec<f64>>,
+    /// The start date of the cycle.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub starts_at: Option<String>,
+    /// The team ID of the cycle.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team_id: Option<String>,
+    /// The IDs of the uncompleted issues upon close.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub uncompleted_issues_upon_close_ids: Option<Vec<String>>,
+    /// The time at which the entity was updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+}
+
+/// \[Internal\] A dashboard, usually a collection of widgets to display several insights at once.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Dashboard {
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// The color of the icon of the dashboard.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// The user who created the dashboard.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub creator: Option<Box<User>>,
+    /// The description of the dashboard.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The icon of the dashboard.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub icon: Option<String>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The filter applied to all dashboard widgets showing issues data.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_filter: Option<JSONObject>,
+    /// The name of the dashboard.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The organization of the dashboard.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization: Option<Box<Organization>>,
+    /// The owner of the dashboard.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner: Option<Box<User>>,
+    /// The filter applied to all dashboard widgets showing projects data.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_filter: Option<JSONObject>,
+    /// Whether the dashboard is shared with everyone in the organization.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shared: Option<bool>,
+    /// The dashboard's unique URL slug.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slug_id: Option<String>,
+    /// The sort order of the dashboard within the organization or its team.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort_order: Option<f64>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+    /// The user who last updated the dashboard.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_by: Option<Box<User>>,
+    /// The widgets on the dashboard.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub widgets: Option<JSONObject>,
+}
+
+/// A generic payload return from entity deletion mutations.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeletePayload {
+    /// The identifier of the deleted entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub entity_id: Option<String>,
+    /// The identifier of the last sync operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_sync_id: Option<f64>,
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+}
+
+/// A document that can be attached to different entities.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Document {
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// The color of the icon.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    /// Comments associated with the document.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comments: Option<Box<CommentConnection>>,
+    /// The documents content in markdown format.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+    /// \[Internal\] The documents content as YJS state.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_state: Option<String>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// The user who created the document.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub creator: Option<Box<User>>,
+    /// The ID of the document content associated with the document.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub document_content_id: Option<String>,
+    /// The time at which the document was hidden. Null if the entity has not been hidden.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hidden_at: Option<DateTime>,
+    /// The icon of the document.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub icon: Option<String>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The initiative that the document is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative: Option<Box<Initiative>>,
+    /// The issue that the document is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue: Option<Box<Issue>>,
+    /// The last template that was applied to this document.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_applied_template: Option<Box<Template>>,
+    /// The project that the document is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project: Option<Box<Project>>,
+    /// The document's unique URL slug.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slug_id: Option<String>,
+    /// The order of the item in the resources list.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort_order: Option<f64>,
+    /// \[Internal\] The team that the document is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team: Option<Box<Team>>,
+    /// The document title.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// A flag that indicates whether the document is in the trash bin.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trashed: Option<bool>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+    /// The user who last updated the document.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_by: Option<Box<User>>,
+    /// The canonical url for the document.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+/// A generic payload return from entity archive mutations.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DocumentArchivePayload {
+    /// The archived/unarchived entity. Null if entity was deleted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub entity: Option<Box<Document>>,
+    /// The identifier of the last sync operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_sync_id: Option<f64>,
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+}
+
+/// Certain properties of a document.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DocumentChildWebhookPayload {
+    /// The ID of the document.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The initiative this document belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative: Option<Box<InitiativeChildWebhookPayload>>,
+    /// The ID of the initiative this document belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative_id: Option<String>,
+    /// The project this document belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project: Option<Box<ProjectChildWebhookPayload>>,
+    /// The ID of the project this document belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_id: Option<String>,
+    /// The title of the document.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DocumentConnection {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub edges: Option<Vec<DocumentEdge>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nodes: Option<Vec<Document>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page_info: Option<Box<PageInfo>>,
+}
+
+/// A document content for a project.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DocumentContent {
+    /// The AI prompt rules that the content is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ai_prompt_rules: Option<Box<AiPromptRules>>,
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// The document content in markdown format.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+    /// The document content state as a base64 encoded string.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_state: Option<String>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// The document that the content is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub document: Option<Box<Document>>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The initiative that the content is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative: Option<Box<Initiative>>,
+    /// The issue that the content is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue: Option<Box<Issue>>,
+    /// The project that the content is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project: Option<Box<Project>>,
+    /// The project milestone that the content is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_milestone: Option<Box<ProjectMilestone>>,
+    /// The time at which the document content was restored from a previous version.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub restored_at: Option<DateTime>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+}
+
+/// Certain properties of a document content.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DocumentContentChildWebhookPayload {
+    /// The document this document content belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub document: Option<Box<DocumentChildWebhookPayload>>,
+    /// The project this document belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project: Option<Box<ProjectChildWebhookPayload>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DocumentContentHistoryPayload {
+    /// The document content history entries.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub history: Option<Vec<DocumentContentHistoryType>>,
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DocumentContentHistoryType {
+    /// The ID of the author of the change.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor_ids: Option<Vec<String>>,
+    /// \[Internal\] The document content as Prosemirror document.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_data: Option<JSON>,
+    /// The date when the document content history snapshot was taken. This can be different than createdAt since the content is captured from its state at the previously known updatedAt timestamp in the case of an update. On document create, these timestamps can be the same.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_data_snapshot_at: Option<DateTime>,
+    /// The date when the document content history entry was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// The UUID of the document content history entry.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DocumentEdge {
+    /// Used in `before` and `after` args
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub node: Option<Box<Document>>,
+}
+
+/// A document related notification.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DocumentNotification {
+    /// The user that caused the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor: Option<Box<User>>,
+    /// \[Internal\] Notification actor initials if avatar is not available.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor_avatar_color: Option<String>,
+    /// \[Internal\] Notification avatar URL.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor_avatar_url: Option<String>,
+    /// \[Internal\] Notification actor initials if avatar is not available.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor_initials: Option<String>,
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// The bot that caused the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bot_actor: Option<Box<ActorBot>>,
+    /// The category of the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub category: Option<NotificationCategory>,
+    /// Related comment ID. Null if the notification is not related to a comment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment_id: Option<String>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// Related document ID.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub document_id: Option<String>,
+    /// The time at when an email reminder for this notification was sent to the user. Null, if no email
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub emailed_at: Option<DateTime>,
+    /// The external user that caused the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub external_user_actor: Option<Box<ExternalUser>>,
+    /// \[Internal\] Notifications with the same grouping key will be grouped together in the UI.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub grouping_key: Option<String>,
+    /// \[Internal\] Priority of the notification with the same grouping key. Higher number means higher priority. If priority is the same, notifications should be sorted by `createdAt`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub grouping_priority: Option<f64>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// \[Internal\] Inbox URL for the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inbox_url: Option<String>,
+    /// \[Internal\] Initiative update health for new updates.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative_update_health: Option<String>,
+    /// \[Internal\] If notification actor was Linear.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_linear_actor: Option<bool>,
+    /// \[Internal\] Issue's status type for issue notifications.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_status_type: Option<String>,
+    /// Related parent comment ID. Null if the notification is not related to a comment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_comment_id: Option<String>,
+    /// \[Internal\] Project update health for new updates.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_update_health: Option<String>,
+    /// Name of the reaction emoji related to the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reaction_emoji: Option<String>,
+    /// The time at when the user marked the notification as read. Null, if the the user hasn't read the notification
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub read_at: Option<DateTime>,
+    /// The time until a notification will be snoozed. After that it will appear in the inbox again.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub snoozed_until_at: Option<DateTime>,
+    /// \[Internal\] Notification subtitle.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subtitle: Option<String>,
+    /// \[Internal\] Notification title.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Notification type.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+    /// The time at which a notification was unsnoozed..
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unsnoozed_at: Option<DateTime>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+    /// \[Internal\] URL to the target of the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// The user that received the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<Box<User>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DocumentPayload {
+    /// The document that was created or updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub document: Option<Box<Document>>,
+    /// The identifier of the last sync operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_sync_id: Option<f64>,
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DocumentSearchPayload {
+    /// Archived entities matching the search term along with all their dependencies.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archive_payload: Option<Box<ArchiveResponse>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub edges: Option<Vec<DocumentSearchResultEdge>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nodes: Option<Vec<DocumentSearchResult>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page_info: Option<Box<PageInfo>>,
+    /// Total number of results for query without filters applied.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub total_count: Option<f64>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DocumentSearchResult {
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// The color of the icon.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    /// Comments associated with the document.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comments: Option<Box<CommentConnection>>,
+    /// The documents content in markdown format.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+    /// \[Internal\] The documents content as YJS state.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_state: Option<String>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// The user who created the document.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub creator: Option<Box<User>>,
+    /// The ID of the document content associated with the document.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub document_content_id: Option<String>,
+    /// The time at which the document was hidden. Null if the entity has not been hidden.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hidden_at: Option<DateTime>,
+    /// The icon of the document.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub icon: Option<String>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The initiative that the document is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative: Option<Box<Initiative>>,
+    /// The issue that the document is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue: Option<Box<Issue>>,
+    /// The last template that was applied to this document.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_applied_template: Option<Box<Template>>,
+    /// Metadata related to search result.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<JSONObject>,
+    /// The project that the document is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project: Option<Box<Project>>,
+    /// The document's unique URL slug.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slug_id: Option<String>,
+    /// The order of the item in the resources list.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort_order: Option<f64>,
+    /// \[Internal\] The team that the document is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team: Option<Box<Team>>,
+    /// The document title.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// A flag that indicates whether the document is in the trash bin.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trashed: Option<bool>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+    /// The user who last updated the document.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_by: Option<Box<User>>,
+    /// The canonical url for the document.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DocumentSearchResultEdge {
+    /// Used in `before` and `after` args
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub node: Option<Box<DocumentSearchResult>>,
+}
+
+/// Payload for a document webhook.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DocumentWebhookPayload {
+    /// The time at which the entity was archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<String>,
+    /// The color of the document.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    /// The content of the document.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    /// The ID of the user who created the document.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub creator_id: Option<String>,
+    /// The description of the document.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The time at which the document was hidden.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hidden_at: Option<String>,
+    /// The icon of the document.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub icon: Option<String>,
+    /// The ID of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The ID of the initiative this document belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative_id: Option<String>,
+    /// The ID of the last template that was applied to this document.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_applied_template_id: Option<String>,
+    /// The ID of the project this document belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_id: Option<String>,
+    /// The ID of the resource folder this document belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resource_folder_id: Option<String>,
+    /// The document's unique URL slug.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slug_id: Option<String>,
+    /// The order of the item in the resources list.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort_order: Option<f64>,
+    /// The IDs of the users who are subscribed to this document.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subscriber_ids: Option<Vec<String>>,
+    /// The title of the document.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// A flag that indicates whether the document is in the trash bin.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trashed: Option<bool>,
+    /// The time at which the entity was updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+    /// The ID of the user who last updated the document.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_by_id: Option<String>,
+}
+
+/// A general purpose draft. Used for comments, project updates, etc.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Draft {
+    /// \[INTERNAL\] Allows for multiple drafts per entity (currently constrained to Pull Requests).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub anchor: Option<String>,
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// The text content as a Prosemirror document.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body_data: Option<JSON>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// The customer need that this draft is referencing.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer_need: Option<Box<CustomerNeed>>,
+    /// Additional properties for the draft.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data: Option<JSONObject>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The initiative for which this is a draft initiative update.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative: Option<Box<Initiative>>,
+    /// The initiative update for which this is a draft comment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative_update: Option<Box<InitiativeUpdate>>,
+    /// Whether the draft was autogenerated for the user.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_autogenerated: Option<bool>,
+    /// The issue for which this is a draft comment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue: Option<Box<Issue>>,
+    /// The comment for which this is a draft comment reply.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_comment: Option<Box<Comment>>,
+    /// The post for which this is a draft comment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub post: Option<Box<Post>>,
+    /// The project for which this is a draft project update.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project: Option<Box<Project>>,
+    /// The project update for which this is a draft comment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_update: Option<Box<ProjectUpdate>>,
+    /// The team for which this is a draft post.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team: Option<Box<Team>>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+    /// The user who created the draft.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<Box<User>>,
+    /// \[INTERNAL\] Whether the draft was ported from a local draft.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub was_local_draft: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DraftConnection {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub edges: Option<Vec<DraftEdge>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nodes: Option<Vec<Draft>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page_info: Option<Box<PageInfo>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DraftEdge {
+    /// Used in `before` and `after` args
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub node: Option<Box<Draft>>,
+}
+
+/// An email address that can be used for submitting issues.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EmailIntakeAddress {
+    /// Unique email address user name (before @) used for incoming email.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub address: Option<String>,
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// The user who created the email intake address.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub creator: Option<Box<User>>,
+    /// Whether issues created from that email address will be turned into customer requests.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer_requests_enabled: Option<bool>,
+    /// Whether the email address is enabled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    /// The email address used to forward emails to the intake address.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub forwarding_email_address: Option<String>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The auto-reply message for issue canceled. If not set, the default reply will be used.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_canceled_auto_reply: Option<String>,
+    /// The auto-reply ProseMirror JSON for issue canceled. If not set, the default reply will be used.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_canceled_auto_reply_data: Option<JSONObject>,
+    /// Whether the auto-reply for issue canceled is enabled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_canceled_auto_reply_enabled: Option<bool>,
+    /// The auto-reply message for issue completed. If not set, the default reply will be used.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_completed_auto_reply: Option<String>,
+    /// The auto-reply ProseMirror JSON for issue completed. If not set, the default reply will be used.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_completed_auto_reply_data: Option<JSONObject>,
+    /// Whether the auto-reply for issue completed is enabled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_completed_auto_reply_enabled: Option<bool>,
+    /// The auto-reply message for issue created. If not set, the default reply will be used.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_created_auto_reply: Option<String>,
+    /// The auto-reply ProseMirror JSON for issue created. If not set, the default reply will be used.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_created_auto_reply_data: Option<JSONObject>,
+    /// Whether the auto-reply for issue created is enabled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_created_auto_reply_enabled: Option<bool>,
+    /// The organization that the email address is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization: Option<Box<Organization>>,
+    /// Whether email replies are enabled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub replies_enabled: Option<bool>,
+    /// The name to be used for outgoing emails.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sender_name: Option<String>,
+    /// The SES domain identity that the email address is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ses_domain_identity: Option<Box<SesDomainIdentity>>,
+    /// The team that the email address is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team: Option<Box<Team>>,
+    /// The template that the email address is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub template: Option<Box<Template>>,
+    /// The type of the email address.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<EmailIntakeAddressType>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+    /// Whether the commenter's name is included in the email replies.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub use_user_names_in_replies: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EmailIntakeAddressPayload {
+    /// The email address that was created or updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email_intake_address: Option<Box<EmailIntakeAddress>>,
+    /// The identifier of the last sync operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_sync_id: Option<f64>,
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EmailUnsubscribePayload {
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EmailUserAccountAuthChallengeResponse {
+    /// Supported challenge for this user account. Can be either verificationCode or password.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auth_type: Option<String>,
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+}
+
+/// A custom emoji.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Emoji {
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// The user who created the emoji.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub creator: Option<Box<User>>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The emoji's name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The organization that the emoji belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization: Option<Box<Organization>>,
+    /// The source of the emoji.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+    /// The emoji image URL.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EmojiConnection {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub edges: Option<Vec<EmojiEdge>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nodes: Option<Vec<Emoji>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page_info: Option<Box<PageInfo>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EmojiEdge {
+    /// Used in `before` and `after` args
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub node: Option<Box<Emoji>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EmojiPayload {
+    /// The emoji that was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub emoji: Option<Box<Emoji>>,
+    /// The identifier of the last sync operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_sync_id: Option<f64>,
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+}
+
+/// A basic entity.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Entity {
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+}
+
+/// An external link for an entity like initiative, etc...
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EntityExternalLink {
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// The user who created the link.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub creator: Option<Box<User>>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The initiative that the link is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative: Option<Box<Initiative>>,
+    /// The link's label.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    /// The order of the item in the resources list.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort_order: Option<f64>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+    /// The link's URL.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EntityExternalLinkConnection {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub edges: Option<Vec<EntityExternalLinkEdge>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nodes: Option<Vec<EntityExternalLink>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page_info: Option<Box<PageInfo>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EntityExternalLinkEdge {
+    /// Used in `before` and `after` args
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub node: Option<Box<EntityExternalLink>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EntityExternalLinkPayload {
+    /// The link that was created or updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub entity_external_link: Option<Box<EntityExternalLink>>,
+    /// The identifier of the last sync operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_sync_id: Option<f64>,
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+}
+
+/// Payload for entity-related webhook events.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EntityWebhookPayload {
+    /// The type of action that triggered the webhook.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub action: Option<String>,
+    /// The actor who triggered the action.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor: Option<EntityActorWebhookPayload>,
+    /// The time the payload was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// The entity that was changed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data: Option<DataWebhookPayload>,
+    /// ID of the organization for which the webhook belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization_id: Option<String>,
+    /// The type of resource, i.e., the name of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+    /// In case of an update event, previous values of all updated properties.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_from: Option<JSONObject>,
+    /// URL for the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// The ID of the webhook that sent this event.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub webhook_id: Option<String>,
+    /// Unix timestamp in milliseconds when the webhook was sent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub webhook_timestamp: Option<f64>,
+}
+
+/// Information about an external entity.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExternalEntityInfo {
+    /// The id of the external entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// Metadata about the external entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<ExternalEntityInfoMetadata>,
+    /// The name of the service this entity is synced with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub service: Option<ExternalSyncService>,
+}
+
+/// Metadata about the external GitHub entity.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExternalEntityInfoGithubMetadata {
+    /// The number of the issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub number: Option<f64>,
+    /// The owner of the repository.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner: Option<String>,
+    /// The repository name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub repo: Option<String>,
+}
+
+/// Metadata about the external Jira entity.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExternalEntityInfoJiraMetadata {
+    /// The key of the Jira issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_key: Option<String>,
+    /// The id of the Jira issue type.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_type_id: Option<String>,
+    /// The id of the Jira project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_id: Option<String>,
+}
+
+/// Metadata about the external Slack entity.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExternalEntitySlackMetadata {
+    /// The id of the Slack channel.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub channel_id: Option<String>,
+    /// The name of the Slack channel.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub channel_name: Option<String>,
+    /// Whether the entity originated from Slack (not Linear).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_from_slack: Option<bool>,
+    /// The URL of the Slack message.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message_url: Option<String>,
+}
+
+/// An external authenticated (e.g., through Slack) user which doesn't have a Linear account, but can create and update entities in Linear from the external system that authenticated them.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExternalUser {
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// An URL to the external user's avatar image.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub avatar_url: Option<String>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// The external user's display name. Unique within each organization. Can match the display name of an actual user.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    /// The external user's email address.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The last time the external user was seen interacting with Linear.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_seen: Option<DateTime>,
+    /// The external user's full name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Organization the external user belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization: Option<Box<Organization>>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+}
+
+/// Certain properties of an external user.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExternalUserChildWebhookPayload {
+    /// The email of the external user.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    /// The ID of the external user.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The name of the external user.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExternalUserConnection {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub edges: Option<Vec<ExternalUserEdge>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nodes: Option<Vec<ExternalUser>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page_info: Option<Box<PageInfo>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExternalUserEdge {
+    /// Used in `before` and `after` args
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub node: Option<Box<ExternalUser>>,
+}
+
+/// A facet. Facets are joins between entities. A facet can tie a custom view to a project, or a a project to a roadmap for example.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Facet {
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The sort order of the facet.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort_order: Option<f64>,
+    /// The owning feed user.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_feed_user: Option<Box<User>>,
+    /// The owning initiative.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_initiative: Option<Box<Initiative>>,
+    /// The owning organization.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_organization: Option<Box<Organization>>,
+    /// The owning page.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_page: Option<FacetPageSource>,
+    /// The owning project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_project: Option<Box<Project>>,
+    /// The owning team.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_team: Option<Box<Team>>,
+    /// The targeted custom view.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_custom_view: Option<Box<CustomView>>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FacetConnection {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub edges: Option<Vec<FacetEdge>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nodes: Option<Vec<Facet>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page_info: Option<Box<PageInfo>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FacetEdge {
+    /// Used in `before` and `after` args
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub node: Option<Box<Facet>>,
+}
+
+/// User favorites presented in the sidebar.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Favorite {
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// Children of the favorite. Only applies to favorites of type folder.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub children: Option<Box<FavoriteConnection>>,
+    /// \[Internal\] Returns the color of the favorite's icon. Unavailable for avatars and views with fixed icons (e.g. cycle).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// The favorited custom view.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub custom_view: Option<Box<CustomView>>,
+    /// The favorited customer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer: Option<Box<Customer>>,
+    /// The favorited cycle.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cycle: Option<Box<Cycle>>,
+    /// The favorited dashboard.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dashboard: Option<Box<Dashboard>>,
+    /// \[Internal\] Detail text for favorite's `title` (e.g. team's name for a project).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+    /// The favorited document.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub document: Option<Box<Document>>,
+    /// \[INTERNAL\] The favorited facet.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub facet: Option<Box<Facet>>,
+    /// The name of the folder. Only applies to favorites of type folder.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub folder_name: Option<String>,
+    /// \[Internal\] Name of the favorite's icon. Unavailable for standard views, issues, and avatars
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub icon: Option<String>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The favorited initiative.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative: Option<Box<Initiative>>,
+    /// The targeted tab of the initiative.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative_tab: Option<InitiativeTab>,
+    /// The favorited issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue: Option<Box<Issue>>,
+    /// The favorited label.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<Box<IssueLabel>>,
+    /// The owner of the favorite.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner: Option<Box<User>>,
+    /// The parent folder of the favorite.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent: Option<Box<Favorite>>,
+    /// The team of the favorited predefined view.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub predefined_view_team: Option<Box<Team>>,
+    /// The type of favorited predefined view.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub predefined_view_type: Option<String>,
+    /// The favorited project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project: Option<Box<Project>>,
+    /// The favorited project label.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_label: Option<Box<ProjectLabel>>,
+    /// The targeted tab of the project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_tab: Option<ProjectTab>,
+    /// \[DEPRECATED\] The favorited team of the project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_team: Option<Box<Team>>,
+    /// The favorited pull request.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pull_request: Option<Box<PullRequest>>,
+    /// \[ALPHA\] The favorited release.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub release: Option<Box<Release>>,
+    /// \[ALPHA\] The favorited release pipeline.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub release_pipeline: Option<Box<ReleasePipeline>>,
+    /// The order of the item in the favorites list.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort_order: Option<f64>,
+    /// \[Internal\] Favorite's title text (name of the favorite'd object or folder).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// The type of the favorite.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+    /// URL of the favorited entity. Folders return 'null' value.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// The favorited user.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<Box<User>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FavoriteConnection {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub edges: Option<Vec<FavoriteEdge>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nodes: Option<Vec<Favorite>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page_info: Option<Box<PageInfo>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FavoriteEdge {
+    /// Used in `before` and `after` args
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub node: Option<Box<Favorite>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FavoritePayload {
+    /// The object that was added as a favorite.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub favorite: Option<Box<Favorite>>,
+    /// The identifier of the last sync operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_sync_id: Option<f64>,
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+}
+
+/// \[Internal\] An item in a users feed.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FeedItem {
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The initiative update that is in the feed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative_update: Option<Box<InitiativeUpdate>>,
+    /// The organization that will see this feed item.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization: Option<Box<Organization>>,
+    /// The post that is in the feed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub post: Option<Box<Post>>,
+    /// The project update that is in the feed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_update: Option<Box<ProjectUpdate>>,
+    /// The team that will see this feed item.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team: Option<Box<Team>>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+    /// The user that will see this feed item.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<Box<User>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FeedItemConnection {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub edges: Option<Vec<FeedItemEdge>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nodes: Option<Vec<FeedItem>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page_info: Option<Box<PageInfo>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FeedItemEdge {
+    /// Used in `before` and `after` args
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub node: Option<Box<FeedItem>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FetchDataPayload {
+    /// The fetched data based on the natural language query.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data: Option<JSONObject>,
+    /// The filters used to fetch the data.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filters: Option<JSONObject>,
+    /// The GraphQL query used to fetch the data.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub query: Option<String>,
+    /// Whether the fetch operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FileUploadDeletePayload {
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FrontAttachmentPayload {
+    /// The issue attachment that was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub attachment: Option<Box<Attachment>>,
+    /// The identifier of the last sync operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_sync_id: Option<f64>,
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+}
+
+/// A trigger that updates the issue status according to Git automations.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GitAutomationState {
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// \[DEPRECATED\] The target branch, if null, the automation will be triggered on any branch.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub branch_pattern: Option<String>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// The event that triggers the automation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub event: Option<GitAutomationStates>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The associated workflow state.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<Box<WorkflowState>>,
+    /// The target branch associated to this automation state.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_branch: Option<Box<GitAutomationTargetBranch>>,
+    /// The team to which this automation state belongs.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team: Option<Box<Team>>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GitAutomationStateConnection {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub edges: Option<Vec<GitAutomationStateEdge>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nodes: Option<Vec<GitAutomationState>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page_info: Option<Box<PageInfo>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GitAutomationStateEdge {
+    /// Used in `before` and `after` args
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub node: Option<Box<GitAutomationState>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GitAutomationStatePayload {
+    /// The automation state that was created or updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub git_automation_state: Option<Box<GitAutomationState>>,
+    /// The identifier of the last sync operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_sync_id: Option<f64>,
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+}
+
+/// A Git target branch for which there are automations (GitAutomationState).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GitAutomationTargetBranch {
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// Automation states associated with the target branch.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub automation_states: Option<Box<GitAutomationStateConnection>>,
+    /// The target branch pattern.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub branch_pattern: Option<String>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// Whether the branch pattern is a regular expression.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_regex: Option<bool>,
+    /// The team to which this Git target branch automation belongs.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team: Option<Box<Team>>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GitAutomationTargetBranchPayload {
+    /// The identifier of the last sync operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_sync_id: Option<f64>,
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+    /// The Git target branch automation that was created or updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_branch: Option<Box<GitAutomationTargetBranch>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GitHubCommitIntegrationPayload {
+    /// The integration that was created or updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integration: Option<Box<Integration>>,
+    /// The identifier of the last sync operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_sync_id: Option<f64>,
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+    /// The webhook secret to provide to GitHub.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub webhook_secret: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GitHubEnterpriseServerInstallVerificationPayload {
+    /// Has the install been successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GitHubEnterpriseServerPayload {
+    /// The app install address.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub install_url: Option<String>,
+    /// The integration that was created or updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integration: Option<Box<Integration>>,
+    /// The identifier of the last sync operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_sync_id: Option<f64>,
+    /// The setup address.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub setup_url: Option<String>,
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+    /// The webhook secret to provide to GitHub.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub webhook_secret: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GitLabIntegrationCreatePayload {
+    /// The integration that was created or updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integration: Option<Box<Integration>>,
+    /// The identifier of the last sync operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_sync_id: Option<f64>,
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+    /// The webhook secret to provide to GitLab.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub webhook_secret: Option<String>,
+}
+
+/// Metadata for guidance that should be provided to an AI agent.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GuidanceRuleWebhookPayload {
+    /// The content of the guidance as markdown.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    /// Where the guidance was defined within the organization.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub origin: Option<GuidanceRuleOriginWebhookPayload>,
+}
+
+/// An identity provider.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IdentityProvider {
+    /// \[INTERNAL\] SCIM admins group push settings.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub admins_group_push: Option<JSONObject>,
+    /// Whether users are allowed to change their name and display name even if SCIM is enabled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allow_name_change: Option<bool>,
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// Whether the identity provider is the default identity provider migrated from organization level settings.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_migrated: Option<bool>,
+    /// \[INTERNAL\] SCIM guests group push settings.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub guests_group_push: Option<JSONObject>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The issuer's custom entity ID.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issuer_entity_id: Option<String>,
+    /// \[INTERNAL\] SCIM owners group push settings.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owners_group_push: Option<JSONObject>,
+    /// The SAML priority used to pick default workspace in SAML SP initiated flow, when same domain is claimed for SAML by multiple workspaces. Lower priority value means higher preference.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority: Option<f64>,
+    /// Whether SAML authentication is enabled for organization.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub saml_enabled: Option<bool>,
+    /// Whether SCIM provisioning is enabled for organization.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scim_enabled: Option<bool>,
+    /// The service provider (Linear) custom entity ID. Defaults to https://auth.linear.app/sso
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sp_entity_id: Option<String>,
+    /// Binding method for authentication call. Can be either `post` (default) or `redirect`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sso_binding: Option<String>,
+    /// Sign in endpoint URL for the identity provider.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sso_endpoint: Option<String>,
+    /// The algorithm of the Signing Certificate. Can be one of `sha1`, `sha256` (default), or `sha512`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sso_sign_algo: Option<String>,
+    /// X.509 Signing Certificate in string form.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sso_signing_cert: Option<String>,
+    /// The type of identity provider.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<IdentityProviderType>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImageUploadFromUrlPayload {
+    /// The identifier of the last sync operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_sync_id: Option<f64>,
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+    /// The URL containing the image.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+/// An initiative to group projects.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Initiative {
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// The initiative's color.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    /// The time at which the initiative was moved into completed status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub completed_at: Option<DateTime>,
+    /// The initiative's content in markdown format.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// The user who created the initiative.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub creator: Option<Box<User>>,
+    /// The description of the initiative.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The content of the initiative description.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub document_content: Option<Box<DocumentContent>>,
+    /// Documents associated with the initiative.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub documents: Option<Box<DocumentConnection>>,
+    /// \[Internal\] Facets associated with the initiative.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub facets: Option<Vec<Facet>>,
+    /// The resolution of the reminder frequency.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub frequency_resolution: Option<FrequencyResolutionType>,
+    /// The health of the initiative.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub health: Option<InitiativeUpdateHealthType>,
+    /// The time at which the initiative health was updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub health_updated_at: Option<DateTime>,
+    /// History entries associated with the initiative.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub history: Option<Box<InitiativeHistoryConnection>>,
+    /// The icon of the initiative.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub icon: Option<String>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// Initiative updates associated with the initiative.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative_updates: Option<Box<InitiativeUpdateConnection>>,
+    /// Settings for all integrations associated with that initiative.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integrations_settings: Option<Box<IntegrationsSettings>>,
+    /// The last initiative update posted for this initiative.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_update: Option<Box<InitiativeUpdate>>,
+    /// Links associated with the initiative.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub links: Option<Box<EntityExternalLinkConnection>>,
+    /// The name of the initiative.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The organization of the initiative.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization: Option<Box<Organization>>,
+    /// The user who owns the initiative.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner: Option<Box<User>>,
+    /// Parent initiative associated with the initiative.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_initiative: Option<Box<Initiative>>,
+    /// Projects associated with the initiative.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub projects: Option<Box<ProjectConnection>>,
+    /// The initiative's unique URL slug.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slug_id: Option<String>,
+    /// The sort order of the initiative within the organization.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort_order: Option<f64>,
+    /// The time at which the initiative was moved into active status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub started_at: Option<DateTime>,
+    /// The status of the initiative. One of Planned, Active, Completed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<InitiativeStatus>,
+    /// Sub-initiatives associated with the initiative.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sub_initiatives: Option<Box<InitiativeConnection>>,
+    /// The estimated completion date of the initiative.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_date: Option<TimelessDate>,
+    /// The resolution of the initiative's estimated completion date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_date_resolution: Option<DateResolutionType>,
+    /// A flag that indicates whether the initiative is in the trash bin.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trashed: Option<bool>,
+    /// The frequency at which to prompt for updates. When not set, reminders are inherited from workspace.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub update_reminder_frequency: Option<f64>,
+    /// The n-weekly frequency at which to prompt for updates. When not set, reminders are inherited from workspace.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub update_reminder_frequency_in_weeks: Option<f64>,
+    /// The day at which to prompt for updates.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub update_reminders_day: Option<Day>,
+    /// The hour at which to prompt for updates.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub update_reminders_hour: Option<f64>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+    /// Initiative URL.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+/// A generic payload return from entity archive mutations.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InitiativeArchivePayload {
+    /// The archived/unarchived entity. Null if entity was deleted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub entity: Option<Box<Initiative>>,
+    /// The identifier of the last sync operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_sync_id: Option<f64>,
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+}
+
+/// Certain properties of an initiative.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InitiativeChildWebhookPayload {
+    /// The ID of the initiative.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The name of the initiative.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The URL of the initiative.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InitiativeConnection {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub edges: Option<Vec<InitiativeEdge>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nodes: Option<Vec<Initiative>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page_info: Option<Box<PageInfo>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InitiativeEdge {
+    /// Used in `before` and `after` args
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub node: Option<Box<Initiative>>,
+}
+
+/// A initiative history containing relevant change events.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InitiativeHistory {
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// The events that happened while recording that history.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub entries: Option<JSONObject>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The initiative that the history is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative: Option<Box<Initiative>>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InitiativeHistoryConnection {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub edges: Option<Vec<InitiativeHistoryEdge>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nodes: Option<Vec<InitiativeHistory>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page_info: Option<Box<PageInfo>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InitiativeHistoryEdge {
+    /// Used in `before` and `after` args
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub node: Option<Box<InitiativeHistory>>,
+}
+
+/// An initiative related notification.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InitiativeNotification {
+    /// The user that caused the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor: Option<Box<User>>,
+    /// \[Internal\] Notification actor initials if avatar is not available.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor_avatar_color: Option<String>,
+    /// \[Internal\] Notification avatar URL.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor_avatar_url: Option<String>,
+    /// \[Internal\] Notification actor initials if avatar is not available.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor_initials: Option<String>,
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// The bot that caused the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bot_actor: Option<Box<ActorBot>>,
+    /// The category of the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub category: Option<NotificationCategory>,
+    /// The comment related to the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<Box<Comment>>,
+    /// Related comment ID. Null if the notification is not related to a comment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment_id: Option<String>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// The document related to the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub document: Option<Box<Document>>,
+    /// The time at when an email reminder for this notification was sent to the user. Null, if no email
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub emailed_at: Option<DateTime>,
+    /// The external user that caused the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub external_user_actor: Option<Box<ExternalUser>>,
+    /// \[Internal\] Notifications with the same grouping key will be grouped together in the UI.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub grouping_key: Option<String>,
+    /// \[Internal\] Priority of the notification with the same grouping key. Higher number means higher priority. If priority is the same, notifications should be sorted by `createdAt`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub grouping_priority: Option<f64>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// \[Internal\] Inbox URL for the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inbox_url: Option<String>,
+    /// The initiative related to the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative: Option<Box<Initiative>>,
+    /// Related initiative ID.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative_id: Option<String>,
+    /// The initiative update related to the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative_update: Option<Box<InitiativeUpdate>>,
+    /// \[Internal\] Initiative update health for new updates.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative_update_health: Option<String>,
+    /// Related initiative update ID.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative_update_id: Option<String>,
+    /// \[Internal\] If notification actor was Linear.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_linear_actor: Option<bool>,
+    /// \[Internal\] Issue's status type for issue notifications.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_status_type: Option<String>,
+    /// The parent comment related to the notification, if a notification is a reply comment notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_comment: Option<Box<Comment>>,
+    /// Related parent comment ID. Null if the notification is not related to a comment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_comment_id: Option<String>,
+    /// \[Internal\] Project update health for new updates.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_update_health: Option<String>,
+    /// Name of the reaction emoji related to the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reaction_emoji: Option<String>,
+    /// The time at when the user marked the notification as read. Null, if the the user hasn't read the notification
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub read_at: Option<DateTime>,
+    /// The time until a notification will be snoozed. After that it will appear in the inbox again.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub snoozed_until_at: Option<DateTime>,
+    /// \[Internal\] Notification subtitle.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subtitle: Option<String>,
+    /// \[Internal\] Notification title.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Notification type.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+    /// The time at which a notification was unsnoozed..
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unsnoozed_at: Option<DateTime>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+    /// \[Internal\] URL to the target of the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// The user that received the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<Box<User>>,
+}
+
+/// An initiative notification subscription.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InitiativeNotificationSubscription {
+    /// Whether the subscription is active or not.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// The type of view to which the notification subscription context is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub context_view_type: Option<ContextViewType>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// The contextual custom view associated with the notification subscription.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub custom_view: Option<Box<CustomView>>,
+    /// The customer associated with the notification subscription.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer: Option<Box<Customer>>,
+    /// The contextual cycle view associated with the notification subscription.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cycle: Option<Box<Cycle>>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The initiative subscribed to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative: Option<Box<Initiative>>,
+    /// The contextual label view associated with the notification subscription.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<Box<IssueLabel>>,
+    /// The type of subscription.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notification_subscription_types: Option<Vec<String>>,
+    /// The contextual project view associated with the notification subscription.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project: Option<Box<Project>>,
+    /// The user that subscribed to receive notifications.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subscriber: Option<Box<User>>,
+    /// The team associated with the notification subscription.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team: Option<Box<Team>>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+    /// The user view associated with the notification subscription.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<Box<User>>,
+    /// The type of user view to which the notification subscription context is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_context_view_type: Option<UserContextViewType>,
+}
+
+/// The payload returned by the initiative mutations.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InitiativePayload {
+    /// The initiative that was created or updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative: Option<Box<Initiative>>,
+    /// The identifier of the last sync operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_sync_id: Option<f64>,
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+}
+
+/// A relation representing the dependency between two initiatives.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InitiativeRelation {
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The parent initiative.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative: Option<Box<Initiative>>,
+    /// The child initiative.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub related_initiative: Option<Box<Initiative>>,
+    /// The sort order of the relation within the initiative.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort_order: Option<f64>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+    /// The last user who created or modified the relation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<Box<User>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InitiativeRelationConnection {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub edges: Option<Vec<InitiativeRelationEdge>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nodes: Option<Vec<InitiativeRelation>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page_info: Option<Box<PageInfo>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InitiativeRelationEdge {
+    /// Used in `before` and `after` args
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub node: Option<Box<InitiativeRelation>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InitiativeRelationPayload {
+    /// The initiative relation that was created or updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative_relation: Option<Box<InitiativeRelation>>,
+    /// The identifier of the last sync operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_sync_id: Option<f64>,
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+}
+
+/// Join table between projects and initiatives.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InitiativeToProject {
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The initiative that the project is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative: Option<Box<Initiative>>,
+    /// The project that the initiative is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project: Option<Box<Project>>,
+    /// The sort order of the project within the initiative.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort_order: Option<String>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InitiativeToProjectConnection {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub edges: Option<Vec<InitiativeToProjectEdge>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nodes: Option<Vec<InitiativeToProject>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page_info: Option<Box<PageInfo>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InitiativeToProjectEdge {
+    /// Used in `before` and `after` args
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub node: Option<Box<InitiativeToProject>>,
+}
+
+/// The result of a initiativeToProject mutation.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InitiativeToProjectPayload {
+    /// The initiativeToProject that was created or updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative_to_project: Option<Box<InitiativeToProject>>,
+    /// The identifier of the last sync operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_sync_id: Option<f64>,
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+}
+
+/// An initiative update.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InitiativeUpdate {
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// The update content in markdown format.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    /// \[Internal\] The content of the update as a Prosemirror document.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body_data: Option<String>,
+    /// Number of comments associated with the initiative update.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment_count: Option<i64>,
+    /// Comments associated with the initiative update.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comments: Option<Box<CommentConnection>>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// The diff between the current update and the previous one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub diff: Option<JSONObject>,
+    /// The diff between the current update and the previous one, formatted as markdown.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub diff_markdown: Option<String>,
+    /// The time the update was edited.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub edited_at: Option<DateTime>,
+    /// The health at the time of the update.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub health: Option<InitiativeUpdateHealthType>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// \[Internal\] Serialized JSON representing current state of the initiative properties when posting the initiative update.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub info_snapshot: Option<JSONObject>,
+    /// The initiative that the update is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative: Option<Box<Initiative>>,
+    /// Whether initiative update diff should be hidden.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_diff_hidden: Option<bool>,
+    /// Whether the initiative update is stale.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_stale: Option<bool>,
+    /// Emoji reaction summary, grouped by emoji type.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reaction_data: Option<JSONObject>,
+    /// Reactions associated with the initiative update.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reactions: Option<Vec<Reaction>>,
+    /// The update's unique URL slug.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slug_id: Option<String>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+    /// The URL to the initiative update.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// The user who wrote the update.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<Box<User>>,
+}
+
+/// A generic payload return from entity archive mutations.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InitiativeUpdateArchivePayload {
+    /// The archived/unarchived entity. Null if entity was deleted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub entity: Option<Box<InitiativeUpdate>>,
+    /// The identifier of the last sync operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_sync_id: Option<f64>,
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+}
+
+/// Certain properties of an initiative update.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InitiativeUpdateChildWebhookPayload {
+    /// The body of the initiative update.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body_data: Option<String>,
+    /// The edited at timestamp of the initiative update.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub edited_at: Option<String>,
+    /// The health of the initiative update.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub health: Option<String>,
+    /// The ID of the initiative update.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InitiativeUpdateConnection {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub edges: Option<Vec<InitiativeUpdateEdge>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nodes: Option<Vec<InitiativeUpdate>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page_info: Option<Box<PageInfo>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InitiativeUpdateEdge {
+    /// Used in `before` and `after` args
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub node: Option<Box<InitiativeUpdate>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InitiativeUpdatePayload {
+    /// The initiative update that was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative_update: Option<Box<InitiativeUpdate>>,
+    /// The identifier of the last sync operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_sync_id: Option<f64>,
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InitiativeUpdateReminderPayload {
+    /// The identifier of the last sync operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_sync_id: Option<f64>,
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+}
+
+/// Payload for an initiative update webhook.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InitiativeUpdateWebhookPayload {
+    /// The time at which the entity was archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<String>,
+    /// The body of the initiative update.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    /// The body data of the initiative update.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body_data: Option<String>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    /// The edited at timestamp of the initiative update.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub edited_at: Option<String>,
+    /// The health of the initiative update.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub health: Option<String>,
+    /// The ID of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The initiative that the initiative update belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative: Option<Box<InitiativeChildWebhookPayload>>,
+    /// The initiative id of the initiative update.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative_id: Option<String>,
+    /// The reaction data for this initiative update.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reaction_data: Option<JSONObject>,
+    /// The slug id of the initiative update.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slug_id: Option<String>,
+    /// The time at which the entity was updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+    /// The URL of the initiative update.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// The user that created the initiative update.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<Box<UserChildWebhookPayload>>,
+    /// The user id of the initiative update.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<String>,
+}
+
+/// Payload for an initiative webhook.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InitiativeWebhookPayload {
+    /// The time at which the entity was archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<String>,
+    /// The color of the initiative.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    /// When the initiative was completed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub completed_at: Option<String>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    /// The user who created the initiative.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub creator: Option<Box<UserChildWebhookPayload>>,
+    /// The ID of the user who created the initiative.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub creator_id: Option<String>,
+    /// The description of the initiative.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The resolution of the update reminder frequency.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub frequency_resolution: Option<String>,
+    /// The health status of the initiative.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub health: Option<String>,
+    /// When the health status was last updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub health_updated_at: Option<String>,
+    /// The icon of the initiative.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub icon: Option<String>,
+    /// The ID of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The last update for this initiative.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_update: Option<Box<InitiativeUpdateChildWebhookPayload>>,
+    /// The ID of the last update for this initiative.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_update_id: Option<String>,
+    /// The name of the initiative.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The ID of the organization this initiative belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization_id: Option<String>,
+    /// The user who owns the initiative.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner: Option<Box<UserChildWebhookPayload>>,
+    /// The ID of the user who owns the initiative.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner_id: Option<String>,
+    /// The parent initiative associated with the initiative.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_initiative: Option<Box<InitiativeChildWebhookPayload>>,
+    /// The projects associated with the initiative.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub projects: Option<Vec<ProjectChildWebhookPayload>>,
+    /// The unique slug identifier of the initiative.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slug_id: Option<String>,
+    /// The sort order of the initiative within the organization.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort_order: Option<f64>,
+    /// When the initiative was started.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub started_at: Option<String>,
+    /// The current status of the initiative.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    /// The sub-initiatives associated with the initiative.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sub_initiatives: Option<Vec<InitiativeChildWebhookPayload>>,
+    /// The target date of the initiative.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_date: Option<String>,
+    /// The resolution of the target date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_date_resolution: Option<String>,
+    /// Whether the initiative is trashed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trashed: Option<bool>,
+    /// The frequency of update reminders.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub update_reminder_frequency: Option<f64>,
+    /// The frequency of update reminders in weeks.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub update_reminder_frequency_in_weeks: Option<f64>,
+    /// The day of the week for update reminders.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub update_reminders_day: Option<f64>,
+    /// The hour of the day for update reminders.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub update_reminders_hour: Option<f64>,
+    /// The time at which the entity was updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+    /// The URL of the initiative.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+/// An integration with an external service.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Integration {
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// The user that added the integration.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub creator: Option<Box<User>>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The organization that the integration is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization: Option<Box<Organization>>,
+    /// The integration's type.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub service: Option<String>,
+    /// The team that the integration is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team: Option<Box<Team>>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+}
+
+/// Integration actor payload for webhooks.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IntegrationActorWebhookPayload {
+    /// The ID of the integration.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The service of the integration.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub service: Option<String>,
+    /// The type of actor.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+}
+
+/// Certain properties of an integration.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IntegrationChildWebhookPayload {
+    /// The ID of the integration.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The service of the integration.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub service: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IntegrationConnection {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub edges: Option<Vec<IntegrationEdge>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nodes: Option<Vec<Integration>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page_info: Option<Box<PageInfo>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IntegrationEdge {
+    /// Used in `before` and `after` args
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub node: Option<Box<Integration>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IntegrationHasScopesPayload {
+    /// Whether the integration has the required scopes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_all_scopes: Option<bool>,
+    /// The missing scopes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub missing_scopes: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IntegrationPayload {
+    /// The integration that was created or updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integration: Option<Box<Integration>>,
+    /// The identifier of the last sync operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_sync_id: Option<f64>,
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IntegrationRequestPayload {
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IntegrationSlackWorkspaceNamePayload {
+    /// The current name of the Slack workspace.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+}
+
+/// Join table between templates and integrations.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IntegrationTemplate {
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// ID of the foreign entity in the external integration this template is for, e.g., Slack channel ID.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub foreign_entity_id: Option<String>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The integration that the template is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integration: Option<Box<Integration>>,
+    /// The template that the integration is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub template: Option<Box<Template>>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IntegrationTemplateConnection {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub edges: Option<Vec<IntegrationTemplateEdge>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nodes: Option<Vec<IntegrationTemplate>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page_info: Option<Box<PageInfo>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IntegrationTemplateEdge {
+    /// Used in `before` and `after` args
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub node: Option<Box<IntegrationTemplate>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IntegrationTemplatePayload {
+    /// The IntegrationTemplate that was created or updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integration_template: Option<Box<IntegrationTemplate>>,
+    /// The identifier of the last sync operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_sync_id: Option<f64>,
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+}
+
+/// The configuration of all integrations for different entities.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IntegrationsSettings {
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// The type of view to which the integration settings context is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub context_view_type: Option<ContextViewType>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// Initiative which those settings apply to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative: Option<Box<Initiative>>,
+    /// Project which those settings apply to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project: Option<Box<Project>>,
+    /// Whether to send a Slack message when a initiate update is created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slack_initiative_update_created: Option<bool>,
+    /// Whether to send a Slack message when a new issue is added to triage.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slack_issue_added_to_triage: Option<bool>,
+    /// Whether to send a Slack message when an issue is added to the custom view.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slack_issue_added_to_view: Option<bool>,
+    /// Whether to send a Slack message when a new issue is created for the project or the team.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slack_issue_created: Option<bool>,
+    /// Whether to send a Slack message when a comment is created on any of the project or team's issues.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slack_issue_new_comment: Option<bool>,
+    /// Whether to send a Slack message when an SLA is breached.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slack_issue_sla_breached: Option<bool>,
+    /// Whether to send a Slack message when an SLA is at high risk.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slack_issue_sla_high_risk: Option<bool>,
+    /// Whether to send a Slack message when any of the project or team's issues has a change in status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slack_issue_status_changed_all: Option<bool>,
+    /// Whether to send a Slack message when any of the project or team's issues change to completed or cancelled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slack_issue_status_changed_done: Option<bool>,
+    /// Whether to send a Slack message when a project update is created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slack_project_update_created: Option<bool>,
+    /// Whether to send a new project update to team Slack channels.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slack_project_update_created_to_team: Option<bool>,
+    /// Whether to send a new project update to workspace Slack channel.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slack_project_update_created_to_workspace: Option<bool>,
+    /// Team which those settings apply to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team: Option<Box<Team>>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IntegrationsSettingsPayload {
+    /// The settings that were created or updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integrations_settings: Option<Box<IntegrationsSettings>>,
+    /// The identifier of the last sync operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_sync_id: Option<f64>,
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+}
+
+/// An issue.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Issue {
+    /// \[Internal\] The activity summary information for this issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub activity_summary: Option<JSONObject>,
+    /// The time at which the issue was added to a cycle.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub added_to_cycle_at: Option<DateTime>,
+    /// The time at which the issue was added to a project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub added_to_project_at: Option<DateTime>,
+    /// The time at which the issue was added to a team.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub added_to_team_at: Option<DateTime>,
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// The external user who requested creation of the Asks issue on behalf of the creator.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub asks_external_user_requester: Option<Box<ExternalUser>>,
+    /// The internal user who requested creation of the Asks issue on behalf of the creator.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub asks_requester: Option<Box<User>>,
+    /// The user to whom the issue is assigned to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub assignee: Option<Box<User>>,
+    /// Attachments associated with the issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub attachments: Option<Box<AttachmentConnection>>,
+    /// The time at which the issue was automatically archived by the auto pruning process.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_archived_at: Option<DateTime>,
+    /// The time at which the issue was automatically closed by the auto pruning process.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_closed_at: Option<DateTime>,
+    /// The order of the item in its column on the board.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub board_order: Option<f64>,
+    /// The bot that created the issue, if applicable.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bot_actor: Option<Box<ActorBot>>,
+    /// Suggested branch name for the issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub branch_name: Option<String>,
+    /// The time at which the issue was moved into canceled state.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub canceled_at: Option<DateTime>,
+    /// Children of the issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub children: Option<Box<IssueConnection>>,
+    /// Comments associated with the issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comments: Option<Box<CommentConnection>>,
+    /// The time at which the issue was moved into completed state.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub completed_at: Option<DateTime>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// The user who created the issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub creator: Option<Box<User>>,
+    /// Returns the number of Attachment resources which are created by customer support ticketing systems (e.g. Zendesk).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer_ticket_count: Option<i64>,
+    /// The cycle that the issue is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cycle: Option<Box<Cycle>>,
+    /// The agent user that is delegated to work on this issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub delegate: Option<Box<User>>,
+    /// The issue's description in markdown format.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// \[Internal\] The issue's description content as YJS state.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description_state: Option<String>,
+    /// \[ALPHA\] The document content representing this issue description.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub document_content: Option<Box<DocumentContent>>,
+    /// Documents associated with the issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub documents: Option<Box<DocumentConnection>>,
+    /// The date at which the issue is due.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub due_date: Option<TimelessDate>,
+    /// The estimate of the complexity of the issue..
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub estimate: Option<f64>,
+    /// The external user who created the issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub external_user_creator: Option<Box<ExternalUser>>,
+    /// The users favorite associated with this issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub favorite: Option<Box<Favorite>>,
+    /// Attachments previously associated with the issue before being moved to another issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub former_attachments: Option<Box<AttachmentConnection>>,
+    /// Customer needs previously associated with the issue before being moved to another issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub former_needs: Option<Box<CustomerNeedConnection>>,
+    /// History entries associated with the issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub history: Option<Box<IssueHistoryConnection>>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// Issue's human readable identifier (e.g. ENG-123).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub identifier: Option<String>,
+    /// \[Internal\] Incoming product intelligence relation suggestions for the issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub incoming_suggestions: Option<Box<IssueSuggestionConnection>>,
+    /// Integration type that created this issue, if applicable.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integration_source_type: Option<IntegrationService>,
+    /// Inverse relations associated with this issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inverse_relations: Option<Box<IssueRelationConnection>>,
+    /// Id of the labels associated with this issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label_ids: Option<Vec<String>>,
+    /// Labels associated with this issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<Box<IssueLabelConnection>>,
+    /// The last template that was applied to this issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_applied_template: Option<Box<Template>>,
+    /// Customer needs associated with the issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub needs: Option<Box<CustomerNeedConnection>>,
+    /// The issue's unique number.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub number: Option<f64>,
+    /// The parent of the issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent: Option<Box<Issue>>,
+    /// Previous identifiers of the issue if it has been moved between teams.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub previous_identifiers: Option<Vec<String>>,
+    /// The priority of the issue. 0 = No priority, 1 = Urgent, 2 = High, 3 = Normal, 4 = Low.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority: Option<f64>,
+    /// Label for the priority.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority_label: Option<String>,
+    /// The order of the item in relation to other items in the organization, when ordered by priority.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority_sort_order: Option<f64>,
+    /// The project that the issue is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project: Option<Box<Project>>,
+    /// The projectMilestone that the issue is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_milestone: Option<Box<ProjectMilestone>>,
+    /// Emoji reaction summary, grouped by emoji type.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reaction_data: Option<JSONObject>,
+    /// Reactions associated with the issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reactions: Option<Vec<Reaction>>,
+    /// The recurring issue template that created this issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub recurring_issue_template: Option<Box<Template>>,
+    /// Relations associated with this issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub relations: Option<Box<IssueRelationConnection>>,
+    /// The time at which the issue's SLA will breach.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sla_breaches_at: Option<DateTime>,
+    /// The time at which the issue's SLA will enter high risk state.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sla_high_risk_at: Option<DateTime>,
+    /// The time at which the issue's SLA will enter medium risk state.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sla_medium_risk_at: Option<DateTime>,
+    /// The time at which the issue's SLA began.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sla_started_at: Option<DateTime>,
+    /// The type of SLA set on the issue. Calendar days or business days.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sla_type: Option<String>,
+    /// The user who snoozed the issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub snoozed_by: Option<Box<User>>,
+    /// The time until an issue will be snoozed in Triage view.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub snoozed_until_at: Option<DateTime>,
+    /// The order of the item in relation to other items in the organization.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort_order: Option<f64>,
+    /// The comment that this issue was created from.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_comment: Option<Box<Comment>>,
+    /// The time at which the issue was moved into started state.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub started_at: Option<DateTime>,
+    /// The time at which the issue entered triage.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub started_triage_at: Option<DateTime>,
+    /// The workflow state that the issue is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<Box<WorkflowState>>,
+    /// \[ALPHA\] The issue's workflow states over time.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state_history: Option<Box<IssueStateSpanConnection>>,
+    /// The order of the item in the sub-issue list. Only set if the issue has a parent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sub_issue_sort_order: Option<f64>,
+    /// Users who are subscribed to the issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subscribers: Option<Box<UserConnection>>,
+    /// \[Internal\] Product Intelligence suggestions for the issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub suggestions: Option<Box<IssueSuggestionConnection>>,
+    /// \[Internal\] The time at which the most recent suggestions for this issue were generated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub suggestions_generated_at: Option<DateTime>,
+    /// The external services the issue is synced with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub synced_with: Option<Vec<ExternalEntityInfo>>,
+    /// The team that the issue is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team: Option<Box<Team>>,
+    /// The issue's title.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// A flag that indicates whether the issue is in the trash bin.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trashed: Option<bool>,
+    /// The time at which the issue left triage.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub triaged_at: Option<DateTime>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+    /// Issue URL.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+/// A generic payload return from entity archive mutations.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueArchivePayload {
+    /// The archived/unarchived entity. Null if entity was deleted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub entity: Option<Box<Issue>>,
+    /// The identifier of the last sync operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_sync_id: Option<f64>,
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+}
+
+/// Payload for an issue assigned to you notification.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueAssignedToYouNotificationWebhookPayload {
+    /// The actor who caused the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor: Option<Box<UserChildWebhookPayload>>,
+    /// The ID of the actor who caused the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor_id: Option<String>,
+    /// The time at which the entity was archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<String>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    /// The ID of the external user who caused the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub external_user_actor_id: Option<String>,
+    /// The ID of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The issue this notification belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue: Option<Box<IssueWithDescriptionChildWebhookPayload>>,
+    /// The ID of the issue this notification belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_id: Option<String>,
+    /// An issue assigned to you notification type.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<IssueAssignedToYouNotificationType>,
+    /// The time at which the entity was updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+    /// The ID of the user who received the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueBatchPayload {
+    /// The issues that were updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issues: Option<Vec<Issue>>,
+    /// The identifier of the last sync operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_sync_id: Option<f64>,
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+}
+
+/// Certain properties of an issue.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueChildWebhookPayload {
+    /// The ID of the issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The identifier of the issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub identifier: Option<String>,
+    /// The ID of the team that the issue belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team: Option<Box<TeamChildWebhookPayload>>,
+    /// The ID of the team that the issue belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team_id: Option<String>,
+    /// The title of the issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// The URL of the issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+/// Payload for an issue comment mention notification.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueCommentMentionNotificationWebhookPayload {
+    /// The actor who caused the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor: Option<Box<UserChildWebhookPayload>>,
+    /// The ID of the actor who caused the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor_id: Option<String>,
+    /// The time at which the entity was archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<String>,
+    /// The comment this notification belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<Box<CommentChildWebhookPayload>>,
+    /// The ID of the comment this notification belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment_id: Option<String>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    /// The ID of the external user who caused the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub external_user_actor_id: Option<String>,
+    /// The ID of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The issue this notification belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue: Option<Box<IssueWithDescriptionChildWebhookPayload>>,
+    /// The ID of the issue this notification belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_id: Option<String>,
+    /// The parent comment for the comment this notification belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_comment: Option<Box<CommentChildWebhookPayload>>,
+    /// The ID of the parent comment for the comment this notification belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_comment_id: Option<String>,
+    /// An issue comment mention notification type.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<IssueCommentMentionNotificationType>,
+    /// The time at which the entity was updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+    /// The ID of the user who received the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<String>,
+}
+
+/// Payload for an issue comment reaction notification.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueCommentReactionNotificationWebhookPayload {
+    /// The actor who caused the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor: Option<Box<UserChildWebhookPayload>>,
+    /// The ID of the actor who caused the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor_id: Option<String>,
+    /// The time at which the entity was archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<String>,
+    /// The comment this notification belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<Box<CommentChildWebhookPayload>>,
+    /// The ID of the comment this notification belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment_id: Option<String>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    /// The ID of the external user who caused the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub external_user_actor_id: Option<String>,
+    /// The ID of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The issue this notification belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue: Option<Box<IssueWithDescriptionChildWebhookPayload>>,
+    /// The ID of the issue this notification belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_id: Option<String>,
+    /// The parent comment for the comment this notification belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_comment: Option<Box<CommentChildWebhookPayload>>,
+    /// The ID of the parent comment for the comment this notification belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_comment_id: Option<String>,
+    /// The emoji of the reaction this notification is for.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reaction_emoji: Option<String>,
+    /// An issue comment reaction notification type.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<IssueCommentReactionNotificationType>,
+    /// The time at which the entity was updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+    /// The ID of the user who received the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueConnection {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub edges: Option<Vec<IssueEdge>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nodes: Option<Vec<Issue>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page_info: Option<Box<PageInfo>>,
+}
+
+/// \[Internal\] A draft issue.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueDraft {
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// The user assigned to the draft.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub assignee_id: Option<String>,
+    /// Serialized array of JSONs representing attachments.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub attachments: Option<JSONObject>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// The user who created the draft.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub creator: Option<Box<User>>,
+    /// The cycle associated with the draft.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cycle_id: Option<String>,
+    /// The agent user delegated to work on the issue being drafted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub delegate_id: Option<String>,
+    /// The draft's description in markdown format.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// \[Internal\] The draft's description as a Prosemirror document.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description_data: Option<JSON>,
+    /// The date at which the issue would be due.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub due_date: Option<TimelessDate>,
+    /// The estimate of the complexity of the draft.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub estimate: Option<f64>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The IDs of labels added to the draft.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label_ids: Option<Vec<String>>,
+    /// Serialized array of JSONs representing customer needs.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub needs: Option<JSONObject>,
+    /// The parent draft of the draft.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent: Option<Box<IssueDraft>>,
+    /// The ID of the parent issue draft, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_id: Option<String>,
+    /// The parent issue of the draft.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_issue: Option<Box<Issue>>,
+    /// The ID of the parent issue, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_issue_id: Option<String>,
+    /// The priority of the draft.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority: Option<f64>,
+    /// Label for the priority.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority_label: Option<String>,
+    /// The project associated with the draft.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_id: Option<String>,
+    /// The project milestone associated with the draft.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_milestone_id: Option<String>,
+    /// Serialized array of JSONs representing the recurring issue's schedule.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schedule: Option<JSONObject>,
+    /// The ID of the comment that the draft was created from.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_comment_id: Option<String>,
+    /// The workflow state associated with the draft.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state_id: Option<String>,
+    /// The order of items in the sub-draft list. Only set if the draft has `parent` set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sub_issue_sort_order: Option<f64>,
+    /// The team associated with the draft.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team_id: Option<String>,
+    /// The draft's title.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueDraftConnection {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub edges: Option<Vec<IssueDraftEdge>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nodes: Option<Vec<IssueDraft>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page_info: Option<Box<PageInfo>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueDraftEdge {
+    /// Used in `before` and `after` args
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub node: Option<Box<IssueDraft>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueEdge {
+    /// Used in `before` and `after` args
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub node: Option<Box<Issue>>,
+}
+
+/// Payload for an issue emoji reaction notification.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueEmojiReactionNotificationWebhookPayload {
+    /// The actor who caused the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor: Option<Box<UserChildWebhookPayload>>,
+    /// The ID of the actor who caused the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor_id: Option<String>,
+    /// The time at which the entity was archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<String>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    /// The ID of the external user who caused the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub external_user_actor_id: Option<String>,
+    /// The ID of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The issue this notification belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue: Option<Box<IssueWithDescriptionChildWebhookPayload>>,
+    /// The ID of the issue this notification belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_id: Option<String>,
+    /// The emoji of the reaction this notification is for.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reaction_emoji: Option<String>,
+    /// An issue emoji reaction notification type.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<IssueEmojiReactionNotificationType>,
+    /// The time at which the entity was updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+    /// The ID of the user who received the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueFilterSuggestionPayload {
+    /// The json filter that is suggested.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filter: Option<JSONObject>,
+    /// The log id of the prompt, that created this filter.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub log_id: Option<String>,
+}
+
+/// A record of changes to an issue.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueHistory {
+    /// The actor that performed the actions. This field may be empty in the case of integrations or automations.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor: Option<Box<User>>,
+    /// The id of user who made these changes. If null, possibly means that the change made by an integration.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor_id: Option<String>,
+    /// The actors that performed the actions. This field may be empty in the case of integrations or automations.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actors: Option<Vec<User>>,
+    /// ID's of labels that were added.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub added_label_ids: Option<Vec<String>>,
+    /// The labels that were added to the issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub added_labels: Option<Vec<IssueLabel>>,
+    /// \[ALPHA\] ID's of releases that the issue was added to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub added_to_release_ids: Option<Vec<String>>,
+    /// The releases that the issue was added to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub added_to_releases: Option<Vec<Release>>,
+    /// Whether the issue is archived at the time of this history entry.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived: Option<bool>,
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// The linked attachment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub attachment: Option<Box<Attachment>>,
+    /// The id of linked attachment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub attachment_id: Option<String>,
+    /// Whether the issue was auto-archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_archived: Option<bool>,
+    /// Whether the issue was auto-closed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_closed: Option<bool>,
+    /// The bot that performed the action.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bot_actor: Option<Box<ActorBot>>,
+    /// \[Internal\] Serialized JSON representing changes for certain non-relational properties.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub changes: Option<JSONObject>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// The id of linked customer need.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer_need_id: Option<String>,
+    /// The actors that edited the description of the issue, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description_updated_by: Option<Vec<User>>,
+    /// The user that was unassigned from the issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub from_assignee: Option<Box<User>>,
+    /// The id of user from whom the issue was re-assigned from.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub from_assignee_id: Option<String>,
+    /// The cycle that the issue was moved from.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub from_cycle: Option<Box<Cycle>>,
+    /// The id of previous cycle of the issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub from_cycle_id: Option<String>,
+    /// The app user from whom the issue delegation was transferred.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub from_delegate: Option<Box<User>>,
+    /// What the due date was changed from.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub from_due_date: Option<TimelessDate>,
+    /// What the estimate was changed from.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub from_estimate: Option<f64>,
+    /// The parent issue that the issue was moved from.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub from_parent: Option<Box<Issue>>,
+    /// The id of previous parent of the issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub from_parent_id: Option<String>,
+    /// What the priority was changed from.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub from_priority: Option<f64>,
+    /// The project that the issue was moved from.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub from_project: Option<Box<Project>>,
+    /// The id of previous project of the issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub from_project_id: Option<String>,
+    /// The state that the issue was moved from.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub from_state: Option<Box<WorkflowState>>,
+    /// The id of previous workflow state of the issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub from_state_id: Option<String>,
+    /// The team that the issue was moved from.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub from_team: Option<Box<Team>>,
+    /// The id of team from which the issue was moved from.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub from_team_id: Option<String>,
+    /// What the title was changed from.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub from_title: Option<String>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The issue that was changed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue: Option<Box<Issue>>,
+    /// The import record.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_import: Option<Box<IssueImport>>,
+    /// Changed issue relationships.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub relation_changes: Option<Vec<IssueRelationHistoryPayload>>,
+    /// \[ALPHA\] ID's of releases that the issue was removed from.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub removed_from_release_ids: Option<Vec<String>>,
+    /// The releases that the issue was removed from.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub removed_from_releases: Option<Vec<Release>>,
+    /// ID's of labels that were removed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub removed_label_ids: Option<Vec<String>>,
+    /// The labels that were removed from the issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub removed_labels: Option<Vec<IssueLabel>>,
+    /// The user that was assigned to the issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub to_assignee: Option<Box<User>>,
+    /// The id of user to whom the issue was assigned to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub to_assignee_id: Option<String>,
+    /// The new project created from the issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub to_converted_project: Option<Box<Project>>,
+    /// The id of new project created from the issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub to_converted_project_id: Option<String>,
+    /// The cycle that the issue was moved to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub to_cycle: Option<Box<Cycle>>,
+    /// The id of new cycle of the issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub to_cycle_id: Option<String>,
+    /// The app user to whom the issue delegation was transferred.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub to_delegate: Option<Box<User>>,
+    /// What the due date was changed to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub to_due_date: Option<TimelessDate>,
+    /// What the estimate was changed to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub to_estimate: Option<f64>,
+    /// The parent issue that the issue was moved to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub to_parent: Option<Box<Issue>>,
+    /// The id of new parent of the issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub to_parent_id: Option<String>,
+    /// What the priority was changed to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub to_priority: Option<f64>,
+    /// The project that the issue was moved to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub to_project: Option<Box<Project>>,
+    /// The id of new project of the issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub to_project_id: Option<String>,
+    /// The state that the issue was moved to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub to_state: Option<Box<WorkflowState>>,
+    /// The id of new workflow state of the issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub to_state_id: Option<String>,
+    /// The team that the issue was moved to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub to_team: Option<Box<Team>>,
+    /// The id of team to which the issue was moved to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub to_team_id: Option<String>,
+    /// What the title was changed to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub to_title: Option<String>,
+    /// Whether the issue was trashed or un-trashed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trashed: Option<bool>,
+    /// Boolean indicating if the issue was auto-assigned using the triage responsibility feature.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub triage_responsibility_auto_assigned: Option<bool>,
+    /// The users that were notified of the issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub triage_responsibility_notified_users: Option<Vec<User>>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+    /// Whether the issue's description was updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_description: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueHistoryConnection {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub edges: Option<Vec<IssueHistoryEdge>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nodes: Option<Vec<IssueHistory>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page_info: Option<Box<PageInfo>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueHistoryEdge {
+    /// Used in `before` and `after` args
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub node: Option<Box<IssueHistory>>,
+}
+
+/// An import job for data from an external service.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueImport {
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// The id for the user that started the job.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub creator_id: Option<String>,
+    /// File URL for the uploaded CSV for the import, if there is one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub csv_file_url: Option<String>,
+    /// The display name of the import service.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    /// User readable error message, if one has occurred during the import.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// Error code and metadata, if one has occurred during the import.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error_metadata: Option<JSONObject>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The data mapping configuration for the import job.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mapping: Option<JSONObject>,
+    /// Current step progress in % (0-100).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub progress: Option<f64>,
+    /// The service from which data will be imported.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub service: Option<String>,
+    /// Metadata related to import service.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub service_metadata: Option<JSONObject>,
+    /// The status for the import job.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    /// New team's name in cases when teamId not set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team_name: Option<String>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueImportCheckPayload {
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueImportDeletePayload {
+    /// The import job that was deleted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_import: Option<Box<IssueImport>>,
+    /// The identifier of the last sync operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_sync_id: Option<f64>,
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+}
+
+/// Whether a custom JQL query is valid or not
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueImportJqlCheckPayload {
+    /// Returns an approximate number of issues matching the JQL query, if available
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub count: Option<f64>,
+    /// An error message returned by Jira when validating the JQL query.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// Returns true if the JQL query has been validated successfully, false otherwise
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueImportPayload {
+    /// The import job that was created or updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_import: Option<Box<IssueImport>>,
+    /// The identifier of the last sync operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_sync_id: Option<f64>,
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+}
+
+/// Whether an issue import can be synced at the end of an import or not
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueImportSyncCheckPayload {
+    /// Returns true if the import can be synced, false otherwise
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub can_sync: Option<bool>,
+    /// An error message with a root cause of why the import cannot be synced
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Labels that can be associated with issues.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueLabel {
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// Children of the label.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub children: Option<Box<IssueLabelConnection>>,
+    /// The label's color as a HEX string.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// The user who created the label.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub creator: Option<Box<User>>,
+    /// The label's description.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The original label inherited from.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inherited_from: Option<Box<IssueLabel>>,
+    /// Whether the label is a group.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_group: Option<bool>,
+    /// Issues associated with the label.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issues: Option<Box<IssueConnection>>,
+    /// The date when the label was last applied to an issue or project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_applied_at: Option<DateTime>,
+    /// The label's name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization: Option<Box<Organization>>,
+    /// The parent label.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent: Option<Box<IssueLabel>>,
+    /// \[Internal\] When the label was retired.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retired_at: Option<DateTime>,
+    /// The user who retired the label.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retired_by: Option<Box<User>>,
+    /// The team that the label is associated with. If null, the label is associated with the global workspace.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team: Option<Box<Team>>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+}
+
+/// Certain properties of an issue label.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueLabelChildWebhookPayload {
+    /// The color of the issue label.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    /// The ID of the issue label.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The name of the issue label.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The parent ID of the issue label.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueLabelConnection {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub edges: Option<Vec<IssueLabelEdge>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nodes: Option<Vec<IssueLabel>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page_info: Option<Box<PageInfo>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueLabelEdge {
+    /// Used in `before` and `after` args
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub node: Option<Box<IssueLabel>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueLabelPayload {
+    /// The label that was created or updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_label: Option<Box<IssueLabel>>,
+    /// The identifier of the last sync operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_sync_id: Option<f64>,
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+}
+
+/// Payload for an issue label webhook.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueLabelWebhookPayload {
+    /// The time at which the entity was archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<String>,
+    /// The color of the issue label.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    /// The creator ID of the issue label.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub creator_id: Option<String>,
+    /// The label's description.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The ID of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The original label inherited from.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inherited_from_id: Option<String>,
+    /// Whether the label is a group.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_group: Option<bool>,
+    /// The name of the issue label.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The parent ID of the issue label.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_id: Option<String>,
+    /// The team ID of the issue label.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team_id: Option<String>,
+    /// The time at which the entity was updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+}
+
+/// Payload for an issue mention notification.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueMentionNotificationWebhookPayload {
+    /// The actor who caused the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor: Option<Box<UserChildWebhookPayload>>,
+    /// The ID of the actor who caused the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor_id: Option<String>,
+    /// The time at which the entity was archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<String>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    /// The ID of the external user who caused the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub external_user_actor_id: Option<String>,
+    /// The ID of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The issue this notification belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue: Option<Box<IssueWithDescriptionChildWebhookPayload>>,
+    /// The ID of the issue this notification belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_id: Option<String>,
+    /// An issue mention notification type.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<IssueMentionNotificationType>,
+    /// The time at which the entity was updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+    /// The ID of the user who received the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<String>,
+}
+
+/// Payload for an issue new comment notification.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueNewCommentNotificationWebhookPayload {
+    /// The actor who caused the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor: Option<Box<UserChildWebhookPayload>>,
+    /// The ID of the actor who caused the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor_id: Option<String>,
+    /// The time at which the entity was archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<String>,
+    /// The comment this notification belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<Box<CommentChildWebhookPayload>>,
+    /// The ID of the comment this notification belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment_id: Option<String>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    /// The ID of the external user who caused the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub external_user_actor_id: Option<String>,
+    /// The ID of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The issue this notification belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue: Option<Box<IssueWithDescriptionChildWebhookPayload>>,
+    /// The ID of the issue this notification belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_id: Option<String>,
+    /// The parent comment for the comment this notification belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_comment: Option<Box<CommentChildWebhookPayload>>,
+    /// The ID of the parent comment for the comment this notification belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_comment_id: Option<String>,
+    /// An issue new comment notification type.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<IssueNewCommentNotificationType>,
+    /// The time at which the entity was updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+    /// The ID of the user who received the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<String>,
+}
+
+/// An issue related notification.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueNotification {
+    /// The user that caused the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor: Option<Box<User>>,
+    /// \[Internal\] Notification actor initials if avatar is not available.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor_avatar_color: Option<String>,
+    /// \[Internal\] Notification avatar URL.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor_avatar_url: Option<String>,
+    /// \[Internal\] Notification actor initials if avatar is not available.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor_initials: Option<String>,
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// The bot that caused the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bot_actor: Option<Box<ActorBot>>,
+    /// The category of the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub category: Option<NotificationCategory>,
+    /// The comment related to the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<Box<Comment>>,
+    /// Related comment ID. Null if the notification is not related to a comment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment_id: Option<String>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// The time at when an email reminder for this notification was sent to the user. Null, if no email
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub emailed_at: Option<DateTime>,
+    /// The external user that caused the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub external_user_actor: Option<Box<ExternalUser>>,
+    /// \[Internal\] Notifications with the same grouping key will be grouped together in the UI.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub grouping_key: Option<String>,
+    /// \[Internal\] Priority of the notification with the same grouping key. Higher number means higher priority. If priority is the same, notifications should be sorted by `createdAt`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub grouping_priority: Option<f64>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// \[Internal\] Inbox URL for the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inbox_url: Option<String>,
+    /// \[Internal\] Initiative update health for new updates.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative_update_health: Option<String>,
+    /// \[Internal\] If notification actor was Linear.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_linear_actor: Option<bool>,
+    /// The issue related to the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue: Option<Box<Issue>>,
+    /// Related issue ID.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_id: Option<String>,
+    /// \[Internal\] Issue's status type for issue notifications.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_status_type: Option<String>,
+    /// The parent comment related to the notification, if a notification is a reply comment notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_comment: Option<Box<Comment>>,
+    /// Related parent comment ID. Null if the notification is not related to a comment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_comment_id: Option<String>,
+    /// \[Internal\] Project update health for new updates.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_update_health: Option<String>,
+    /// Name of the reaction emoji related to the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reaction_emoji: Option<String>,
+    /// The time at when the user marked the notification as read. Null, if the the user hasn't read the notification
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub read_at: Option<DateTime>,
+    /// The time until a notification will be snoozed. After that it will appear in the inbox again.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub snoozed_until_at: Option<DateTime>,
+    /// The subscriptions related to the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subscriptions: Option<Vec<NotificationSubscription>>,
+    /// \[Internal\] Notification subtitle.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subtitle: Option<String>,
+    /// The team related to the issue notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team: Option<Box<Team>>,
+    /// \[Internal\] Notification title.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Notification type.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+    /// The time at which a notification was unsnoozed..
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unsnoozed_at: Option<DateTime>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+    /// \[Internal\] URL to the target of the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// The user that received the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<Box<User>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssuePayload {
+    /// The issue that was created or updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue: Option<Box<Issue>>,
+    /// The identifier of the last sync operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_sync_id: Option<f64>,
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssuePriorityValue {
+    /// Priority's label.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    /// Priority's number value.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority: Option<i64>,
+}
+
+/// A relation between two issues.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueRelation {
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The issue whose relationship is being described.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue: Option<Box<Issue>>,
+    /// The related issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub related_issue: Option<Box<Issue>>,
+    /// The relationship of the issue with the related issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueRelationConnection {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub edges: Option<Vec<IssueRelationEdge>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nodes: Option<Vec<IssueRelation>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page_info: Option<Box<PageInfo>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueRelationEdge {
+    /// Used in `before` and `after` args
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub node: Option<Box<IssueRelation>>,
+}
+
+/// Issue relation history's payload.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueRelationHistoryPayload {
+    /// The identifier of the related issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub identifier: Option<String>,
+    /// The type of the change.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueRelationPayload {
+    /// The issue relation that was created or updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_relation: Option<Box<IssueRelation>>,
+    /// The identifier of the last sync operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_sync_id: Option<f64>,
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueSearchPayload {
+    /// Archived entities matching the search term along with all their dependencies.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archive_payload: Option<Box<ArchiveResponse>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub edges: Option<Vec<IssueSearchResultEdge>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nodes: Option<Vec<IssueSearchResult>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page_info: Option<Box<PageInfo>>,
+    /// Total number of results for query without filters applied.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub total_count: Option<f64>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueSearchResult {
+    /// \[Internal\] The activity summary information for this issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub activity_summary: Option<JSONObject>,
+    /// The time at which the issue was added to a cycle.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub added_to_cycle_at: Option<DateTime>,
+    /// The time at which the issue was added to a project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub added_to_project_at: Option<DateTime>,
+    /// The time at which the issue was added to a team.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub added_to_team_at: Option<DateTime>,
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// The external user who requested creation of the Asks issue on behalf of the creator.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub asks_external_user_requester: Option<Box<ExternalUser>>,
+    /// The internal user who requested creation of the Asks issue on behalf of the creator.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub asks_requester: Option<Box<User>>,
+    /// The user to whom the issue is assigned to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub assignee: Option<Box<User>>,
+    /// Attachments associated with the issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub attachments: Option<Box<AttachmentConnection>>,
+    /// The time at which the issue was automatically archived by the auto pruning process.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_archived_at: Option<DateTime>,
+    /// The time at which the issue was automatically closed by the auto pruning process.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_closed_at: Option<DateTime>,
+    /// The order of the item in its column on the board.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub board_order: Option<f64>,
+    /// The bot that created the issue, if applicable.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bot_actor: Option<Box<ActorBot>>,
+    /// Suggested branch name for the issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub branch_name: Option<String>,
+    /// The time at which the issue was moved into canceled state.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub canceled_at: Option<DateTime>,
+    /// Children of the issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub children: Option<Box<IssueConnection>>,
+    /// Comments associated with the issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comments: Option<Box<CommentConnection>>,
+    /// The time at which the issue was moved into completed state.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub completed_at: Option<DateTime>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// The user who created the issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub creator: Option<Box<User>>,
+    /// Returns the number of Attachment resources which are created by customer support ticketing systems (e.g. Zendesk).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer_ticket_count: Option<i64>,
+    /// The cycle that the issue is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cycle: Option<Box<Cycle>>,
+    /// The agent user that is delegated to work on this issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub delegate: Option<Box<User>>,
+    /// The issue's description in markdown format.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// \[Internal\] The issue's description content as YJS state.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description_state: Option<String>,
+    /// \[ALPHA\] The document content representing this issue description.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub document_content: Option<Box<DocumentContent>>,
+    /// Documents associated with the issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub documents: Option<Box<DocumentConnection>>,
+    /// The date at which the issue is due.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub due_date: Option<TimelessDate>,
+    /// The estimate of the complexity of the issue..
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub estimate: Option<f64>,
+    /// The external user who created the issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub external_user_creator: Option<Box<ExternalUser>>,
+    /// The users favorite associated with this issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub favorite: Option<Box<Favorite>>,
+    /// Attachments previously associated with the issue before being moved to another issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub former_attachments: Option<Box<AttachmentConnection>>,
+    /// Customer needs previously associated with the issue before being moved to another issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub former_needs: Option<Box<CustomerNeedConnection>>,
+    /// History entries associated with the issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub history: Option<Box<IssueHistoryConnection>>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// Issue's human readable identifier (e.g. ENG-123).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub identifier: Option<String>,
+    /// \[Internal\] Incoming product intelligence relation suggestions for the issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub incoming_suggestions: Option<Box<IssueSuggestionConnection>>,
+    /// Integration type that created this issue, if applicable.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integration_source_type: Option<IntegrationService>,
+    /// Inverse relations associated with this issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inverse_relations: Option<Box<IssueRelationConnection>>,
+    /// Id of the labels associated with this issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label_ids: Option<Vec<String>>,
+    /// Labels associated with this issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<Box<IssueLabelConnection>>,
+    /// The last template that was applied to this issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_applied_template: Option<Box<Template>>,
+    /// Metadata related to search result.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<JSONObject>,
+    /// Customer needs associated with the issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub needs: Option<Box<CustomerNeedConnection>>,
+    /// The issue's unique number.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub number: Option<f64>,
+    /// The parent of the issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent: Option<Box<Issue>>,
+    /// Previous identifiers of the issue if it has been moved between teams.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub previous_identifiers: Option<Vec<String>>,
+    /// The priority of the issue. 0 = No priority, 1 = Urgent, 2 = High, 3 = Normal, 4 = Low.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority: Option<f64>,
+    /// Label for the priority.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority_label: Option<String>,
+    /// The order of the item in relation to other items in the organization, when ordered by priority.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority_sort_order: Option<f64>,
+    /// The project that the issue is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project: Option<Box<Project>>,
+    /// The projectMilestone that the issue is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_milestone: Option<Box<ProjectMilestone>>,
+    /// Emoji reaction summary, grouped by emoji type.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reaction_data: Option<JSONObject>,
+    /// Reactions associated with the issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reactions: Option<Vec<Reaction>>,
+    /// The recurring issue template that created this issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub recurring_issue_template: Option<Box<Template>>,
+    /// Relations associated with this issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub relations: Option<Box<IssueRelationConnection>>,
+    /// The time at which the issue's SLA will breach.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sla_breaches_at: Option<DateTime>,
+    /// The time at which the issue's SLA will enter high risk state.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sla_high_risk_at: Option<DateTime>,
+    /// The time at which the issue's SLA will enter medium risk state.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sla_medium_risk_at: Option<DateTime>,
+    /// The time at which the issue's SLA began.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sla_started_at: Option<DateTime>,
+    /// The type of SLA set on the issue. Calendar days or business days.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sla_type: Option<String>,
+    /// The user who snoozed the issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub snoozed_by: Option<Box<User>>,
+    /// The time until an issue will be snoozed in Triage view.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub snoozed_until_at: Option<DateTime>,
+    /// The order of the item in relation to other items in the organization.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort_order: Option<f64>,
+    /// The comment that this issue was created from.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_comment: Option<Box<Comment>>,
+    /// The time at which the issue was moved into started state.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub started_at: Option<DateTime>,
+    /// The time at which the issue entered triage.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub started_triage_at: Option<DateTime>,
+    /// The workflow state that the issue is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<Box<WorkflowState>>,
+    /// \[ALPHA\] The issue's workflow states over time.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state_history: Option<Box<IssueStateSpanConnection>>,
+    /// The order of the item in the sub-issue list. Only set if the issue has a parent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sub_issue_sort_order: Option<f64>,
+    /// Users who are subscribed to the issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subscribers: Option<Box<UserConnection>>,
+    /// \[Internal\] Product Intelligence suggestions for the issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub suggestions: Option<Box<IssueSuggestionConnection>>,
+    /// \[Internal\] The time at which the most recent suggestions for this issue were generated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub suggestions_generated_at: Option<DateTime>,
+    /// The external services the issue is synced with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub synced_with: Option<Vec<ExternalEntityInfo>>,
+    /// The team that the issue is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team: Option<Box<Team>>,
+    /// The issue's title.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// A flag that indicates whether the issue is in the trash bin.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trashed: Option<bool>,
+    /// The time at which the issue left triage.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub triaged_at: Option<DateTime>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+    /// Issue URL.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueSearchResultEdge {
+    /// Used in `before` and `after` args
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub node: Option<Box<IssueSearchResult>>,
+}
+
+/// Payload for issue SLA webhook events.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueSlaWebhookPayload {
+    /// The type of action that triggered the webhook.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub action: Option<String>,
+    /// The time the payload was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// The issue that the SLA event is about.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_data: Option<Box<IssueWebhookPayload>>,
+    /// ID of the organization for which the webhook belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization_id: Option<String>,
+    /// The type of resource.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+    /// URL for the issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// The ID of the webhook that sent this event.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub webhook_id: Option<String>,
+    /// Unix timestamp in milliseconds when the webhook was sent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub webhook_timestamp: Option<f64>,
+}
+
+/// A continuous period of time during which an issue remained in a specific workflow state.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueStateSpan {
+    /// The timestamp when the issue left this state. Null if the issue is currently in this state.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ended_at: Option<DateTime>,
+    /// The unique identifier of the state span.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The timestamp when the issue entered this state.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub started_at: Option<DateTime>,
+    /// The workflow state for this span.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<Box<WorkflowState>>,
+    /// The workflow state identifier for this span.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueStateSpanConnection {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub edges: Option<Vec<IssueStateSpanEdge>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nodes: Option<Vec<IssueStateSpan>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page_info: Option<Box<PageInfo>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueStateSpanEdge {
+    /// Used in `before` and `after` args
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub node: Option<Box<IssueStateSpan>>,
+}
+
+/// Payload for a terminal issue status change notification.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueStatusChangedNotificationWebhookPayload {
+    /// The actor who caused the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor: Option<Box<UserChildWebhookPayload>>,
+    /// The ID of the actor who caused the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor_id: Option<String>,
+    /// The time at which the entity was archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<String>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    /// The ID of the external user who caused the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub external_user_actor_id: Option<String>,
+    /// The ID of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The issue this notification belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue: Option<Box<IssueWithDescriptionChildWebhookPayload>>,
+    /// The ID of the issue this notification belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_id: Option<String>,
+    /// A terminal issue status change notification type.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<IssueStatusChangedNotificationType>,
+    /// The time at which the entity was updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+    /// The ID of the user who received the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueSuggestion {
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dismissal_reason: Option<String>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue: Option<Box<Issue>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Box<IssueSuggestionMetadata>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<IssueSuggestionState>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state_changed_at: Option<DateTime>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub suggested_issue: Option<Box<Issue>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub suggested_issue_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub suggested_label: Option<Box<IssueLabel>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub suggested_label_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub suggested_project: Option<Box<Project>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub suggested_team: Option<Box<Team>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub suggested_user: Option<Box<User>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub suggested_user_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<IssueSuggestionType>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueSuggestionConnection {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub edges: Option<Vec<IssueSuggestionEdge>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nodes: Option<Vec<IssueSuggestion>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page_info: Option<Box<PageInfo>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueSuggestionEdge {
+    /// Used in `before` and `after` args
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub node: Option<Box<IssueSuggestion>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueSuggestionMetadata {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub applied_automation_rule_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub classification: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub eval_log_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rank: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reasons: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub score: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub variant: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueTitleSuggestionFromCustomerRequestPayload {
+    /// The identifier of the last sync operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_sync_id: Option<f64>,
+    /// \[Internal\] The log id of the ai response.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub log_id: Option<String>,
+    /// The suggested issue title.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+}
+
+/// \[Internal\] Join table between issues and releases.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueToRelease {
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The issue associated with the release.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue: Option<Box<Issue>>,
+    /// The release associated with the issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub release: Option<Box<Release>>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueToReleaseConnection {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub edges: Option<Vec<IssueToReleaseEdge>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nodes: Option<Vec<IssueToRelease>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page_info: Option<Box<PageInfo>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueToReleaseEdge {
+    /// Used in `before` and `after` args
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub node: Option<Box<IssueToRelease>>,
+}
+
+/// \[ALPHA\] The result of an issueToRelease mutation.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueToReleasePayload {
+    /// The issueToRelease that was created or updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_to_release: Option<Box<IssueToRelease>>,
+    /// The identifier of the last sync operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_sync_id: Option<f64>,
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+}
+
+/// Payload for an issue unassignment notification.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueUnassignedFromYouNotificationWebhookPayload {
+    /// The actor who caused the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor: Option<Box<UserChildWebhookPayload>>,
+    /// The ID of the actor who caused the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor_id: Option<String>,
+    /// The time at which the entity was archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<String>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    /// The ID of the external user who caused the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub external_user_actor_id: Option<String>,
+    /// The ID of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The issue this notification belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue: Option<Box<IssueWithDescriptionChildWebhookPayload>>,
+    /// The ID of the issue this notification belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_id: Option<String>,
+    /// An issue unassignment notification type.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<IssueUnassignedFromYouNotificationType>,
+    /// The time at which the entity was updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+    /// The ID of the user who received the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<String>,
+}
+
+/// Payload for an issue webhook.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueWebhookPayload {
+    /// The time at which the issue was added to a cycle.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub added_to_cycle_at: Option<String>,
+    /// The time at which the issue was added to a project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub added_to_project_at: Option<String>,
+    /// The time at which the issue was added to a team.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub added_to_team_at: Option<String>,
+    /// The time at which the entity was archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<String>,
+    /// The user that is assigned to the issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub assignee: Option<Box<UserChildWebhookPayload>>,
+    /// The ID of the user that is assigned to the issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub assignee_id: Option<String>,
+    /// The time at which the issue was auto-archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_archived_at: Option<String>,
+    /// The time at which the issue was auto-closed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_closed_at: Option<String>,
+    /// The bot actor data for this issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bot_actor: Option<String>,
+    /// The time at which the issue was canceled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub canceled_at: Option<String>,
+    /// The time at which the issue was completed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub completed_at: Option<String>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    /// The user that created the issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub creator: Option<Box<UserChildWebhookPayload>>,
+    /// The ID of the user that created the issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub creator_id: Option<String>,
+    /// The cycle that the issue belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cycle: Option<Box<CycleChildWebhookPayload>>,
+    /// The ID of the cycle that the issue belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cycle_id: Option<String>,
+    /// The agent user that the issue is delegated to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub delegate: Option<Box<UserChildWebhookPayload>>,
+    /// The ID of the agent user that the issue is delegated to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub delegate_id: Option<String>,
+    /// The description of the issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The description data of the issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description_data: Option<String>,
+    /// The due date of the issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub due_date: Option<String>,
+    /// The estimate of the complexity of the issue..
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub estimate: Option<f64>,
+    /// The external user that created the issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub external_user_creator: Option<Box<ExternalUserChildWebhookPayload>>,
+    /// The ID of the external user that created the issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub external_user_creator_id: Option<String>,
+    /// The ID of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The identifier of the issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub identifier: Option<String>,
+    /// Integration type that created this issue, if applicable.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integration_source_type: Option<String>,
+    /// Id of the labels associated with this issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label_ids: Option<Vec<String>>,
+    /// The labels associated with this issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<Vec<IssueLabelChildWebhookPayload>>,
+    /// The ID of the last template that was applied to the issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_applied_template_id: Option<String>,
+    /// The issue's unique number.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub number: Option<f64>,
+    /// The ID of the parent issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_id: Option<String>,
+    /// Previous identifiers of the issue if it has been moved between teams.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub previous_identifiers: Option<Vec<String>>,
+    /// The priority of the issue. 0 = No priority, 1 = Urgent, 2 = High, 3 = Normal, 4 = Low.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority: Option<f64>,
+    /// The label of the issue's priority.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority_label: Option<String>,
+    /// The order of the item in relation to other items in the organization, when ordered by priority.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority_sort_order: Option<f64>,
+    /// The project that the issue belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project: Option<Box<ProjectChildWebhookPayload>>,
+    /// The ID of the project that the issue belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_id: Option<String>,
+    /// The project milestone that the issue belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_milestone: Option<Box<ProjectMilestoneChildWebhookPayload>>,
+    /// The ID of the project milestone that the issue belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_milestone_id: Option<String>,
+    /// The reaction data for this issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reaction_data: Option<JSONObject>,
+    /// The ID of the recurring issue template that created the issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub recurring_issue_template_id: Option<String>,
+    /// The time at which the issue would breach its SLA.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sla_breaches_at: Option<String>,
+    /// The time at which the issue would enter SLA high risk.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sla_high_risk_at: Option<String>,
+    /// The time at which the issue would enter SLA medium risk.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sla_medium_risk_at: Option<String>,
+    /// The time at which the issue's SLA started.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sla_started_at: Option<String>,
+    /// The type of SLA the issue is under.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sla_type: Option<String>,
+    /// The time until an issue will be snoozed in Triage view.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub snoozed_until_at: Option<String>,
+    /// The order of the item in relation to other items in the organization.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort_order: Option<f64>,
+    /// The ID of the source comment that the issue was created from.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_comment_id: Option<String>,
+    /// The time at which the issue was moved into started state.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub started_at: Option<String>,
+    /// The time at which the issue entered triage.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub started_triage_at: Option<String>,
+    /// The issue's current workflow state.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<Box<WorkflowStateChildWebhookPayload>>,
+    /// The ID of the issue's current workflow state.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state_id: Option<String>,
+    /// The order of the item in the sub-issue list. Only set if the issue has a parent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sub_issue_sort_order: Option<f64>,
+    /// The IDs of the users that are subscribed to the issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subscriber_ids: Option<Vec<String>>,
+    /// The entity this issue is synced with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub synced_with: Option<JSONObject>,
+    /// The team that the issue belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team: Option<Box<TeamChildWebhookPayload>>,
+    /// The ID of the team that the issue belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team_id: Option<String>,
+    /// The issue's title.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// A flag that indicates whether the issue is in the trash bin.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trashed: Option<bool>,
+    /// The time at which the issue was triaged.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub triaged_at: Option<String>,
+    /// The time at which the entity was updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+    /// The URL of the issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+/// Certain properties of an issue, including its description.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueWithDescriptionChildWebhookPayload {
+    /// The description of the issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The ID of the issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The identifier of the issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub identifier: Option<String>,
+    /// The ID of the team that the issue belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team: Option<Box<TeamChildWebhookPayload>>,
+    /// The ID of the team that the issue belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team_id: Option<String>,
+    /// The title of the issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// The URL of the issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+/// A label notification subscription.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LabelNotificationSubscription {
+    /// Whether the subscription is active or not.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// The type of view to which the notification subscription context is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub context_view_type: Option<ContextViewType>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// The contextual custom view associated with the notification subscription.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub custom_view: Option<Box<CustomView>>,
+    /// The customer associated with the notification subscription.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer: Option<Box<Customer>>,
+    /// The contextual cycle view associated with the notification subscription.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cycle: Option<Box<Cycle>>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The contextual initiative view associated with the notification subscription.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative: Option<Box<Initiative>>,
+    /// The label subscribed to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<Box<IssueLabel>>,
+    /// The type of subscription.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notification_subscription_types: Option<Vec<String>>,
+    /// The contextual project view associated with the notification subscription.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project: Option<Box<Project>>,
+    /// The user that subscribed to receive notifications.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subscriber: Option<Box<User>>,
+    /// The team associated with the notification subscription.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team: Option<Box<Team>>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+    /// The user view associated with the notification subscription.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<Box<User>>,
+    /// The type of user view to which the notification subscription context is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_context_view_type: Option<UserContextViewType>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LogoutResponse {
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Mutation {
+    /// Creates an agent activity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub agent_activity_create: Option<Box<AgentActivityPayload>>,
+    /// \[Internal\] Creates a prompt agent activity from Linear user input.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub agent_activity_create_prompt: Option<Box<AgentActivityPayload>>,
+    /// \[Internal\] Creates a new agent session on behalf of the current user
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub agent_session_create: Option<Box<AgentSessionPayload>>,
+    /// Creates a new agent session on a rootcomment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub agent_session_create_on_comment: Option<Box<AgentSessionPayload>>,
+    /// Creates a new agent session on an issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub agent_session_create_on_issue: Option<Box<AgentSessionPayload>>,
+    /// Updates an agent session.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub agent_session_update: Option<Box<AgentSessionPayload>>,
+    /// Updates the externalUrl of an agent session, which is an agent-hosted page associated with this session.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub agent_session_update_external_url: Option<Box<AgentSessionPayload>>,
+    /// Creates an integration api key for Airbyte to connect with Linear.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub airbyte_integration_connect: Option<Box<IntegrationPayload>>,
+    /// Authenticate a user to the Asks web forms app.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub asks_web_forms_auth: Option<Box<AsksWebFormsAuthResponse>>,
+    /// Creates a new attachment, or updates existing if the same `url` and `issueId` is used.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub attachment_create: Option<Box<AttachmentPayload>>,
+    /// Deletes an issue attachment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub attachment_delete: Option<Box<DeletePayload>>,
+    /// Link an existing Discord message to an issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub attachment_link_discord: Option<Box<AttachmentPayload>>,
+    /// Link an existing Front conversation to an issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub attachment_link_front: Option<Box<FrontAttachmentPayload>>,
+    /// Link a GitHub issue to a Linear issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub attachment_link_git_hub_issue: Option<Box<AttachmentPayload>>,
+    /// Link a GitHub pull request to an issue.
+    #[serde(rename = "attachmentLinkGitHubPR", default, skip_serializing_if = "Option::is_none")]
+    pub attachment_link_git_hub_pr: Option<Box<AttachmentPayload>>,
+    /// Link an existing GitLab MR to an issue.
+    #[serde(rename = "attachmentLinkGitLabMR", default, skip_serializing_if = "Option::is_none")]
+    pub attachment_link_git_lab_mr: Option<Box<AttachmentPayload>>,
+    /// Link an existing Intercom conversation to an issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub attachment_link_intercom: Option<Box<AttachmentPayload>>,
+    /// Link an existing Jira issue to an issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub attachment_link_jira_issue: Option<Box<AttachmentPayload>>,
+    /// Link an existing Salesforce case to an issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub attachment_link_salesforce: Option<Box<AttachmentPayload>>,
+    /// Link an existing Slack message to an issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub attachment_link_slack: Option<Box<AttachmentPayload>>,
+    /// Link any url to an issue.
+    #[serde(rename = "attachmentLinkURL", default, skip_serializing_if = "Option::is_none")]
+    pub attachment_link_url: Option<Box<AttachmentPayload>>,
+    /// Link an existing Zendesk ticket to an issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub attachment_link_zendesk: Option<Box<AttachmentPayload>>,
+    /// Begin syncing the thread for an existing Slack message attachment with a comment thread on its issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub attachment_sync_to_slack: Option<Box<AttachmentPayload>>,
+    /// Updates an existing issue attachment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub attachment_update: Option<Box<AttachmentPayload>>,
+    /// Creates a new comment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment_create: Option<Box<CommentPayload>>,
+    /// Deletes a comment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment_delete: Option<Box<DeletePayload>>,
+    /// Resolves a comment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment_resolve: Option<Box<CommentPayload>>,
+    /// Unresolves a comment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment_unresolve: Option<Box<CommentPayload>>,
+    /// Updates a comment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment_update: Option<Box<CommentPayload>>,
+    /// Saves user message.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub contact_create: Option<Box<ContactPayload>>,
+    /// \[INTERNAL\] Saves sales pricing inquiry to Front.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub contact_sales_create: Option<Box<ContactPayload>>,
+    /// Create CSV export report for the organization.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub create_csv_export_report: Option<Box<CreateCsvExportReportPayload>>,
+    /// Create a notification to remind a user about an initiative update.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub create_initiative_update_reminder: Option<Box<InitiativeUpdateReminderPayload>>,
+    /// Creates an organization from onboarding.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub create_organization_from_onboarding: Option<Box<CreateOrJoinOrganizationResponse>>,
+    /// Create a notification to remind a user about a project update.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub create_project_update_reminder: Option<Box<ProjectUpdateReminderPayload>>,
+    /// Creates a new custom view.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub custom_view_create: Option<Box<CustomViewPayload>>,
+    /// Deletes a custom view.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub custom_view_delete: Option<Box<DeletePayload>>,
+    /// Updates a custom view.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub custom_view_update: Option<Box<CustomViewPayload>>,
+    /// Creates a new customer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer_create: Option<Box<CustomerPayload>>,
+    /// Deletes a customer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer_delete: Option<Box<DeletePayload>>,
+    /// Merges two customers.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer_merge: Option<Box<CustomerPayload>>,
+    /// Archives a customer need.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer_need_archive: Option<Box<CustomerNeedArchivePayload>>,
+    /// Creates a new customer need.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer_need_create: Option<Box<CustomerNeedPayload>>,
+    /// Creates a new customer need out of an attachment
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer_need_create_from_attachment: Option<Box<CustomerNeedPayload>>,
+    /// Deletes a customer need.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer_need_delete: Option<Box<DeletePayload>>,
+    /// Unarchives a customer need.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer_need_unarchive: Option<Box<CustomerNeedArchivePayload>>,
+    /// Updates a customer need
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer_need_update: Option<Box<CustomerNeedUpdatePayload>>,
+    /// Creates a new customer status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer_status_create: Option<Box<CustomerStatusPayload>>,
+    /// Deletes a customer status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer_status_delete: Option<Box<DeletePayload>>,
+    /// Updates a customer status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer_status_update: Option<Box<CustomerStatusPayload>>,
+    /// Creates a new customer tier.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer_tier_create: Option<Box<CustomerTierPayload>>,
+    /// Deletes a customer tier.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer_tier_delete: Option<Box<DeletePayload>>,
+    /// Updates a customer tier.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer_tier_update: Option<Box<CustomerTierPayload>>,
+    /// Unsyncs a managed customer from the its current data source. External IDs mapping to the external source will be cleared.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer_unsync: Option<Box<CustomerPayload>>,
+    /// Updates a customer
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer_update: Option<Box<CustomerPayload>>,
+    /// Upserts a customer, creating it if it doesn't exists, updating it otherwise. Matches against an existing customer with `id` or `externalId`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer_upsert: Option<Box<CustomerPayload>>,
+    /// Archives a cycle.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cycle_archive: Option<Box<CycleArchivePayload>>,
+    /// Creates a new cycle.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cycle_create: Option<Box<CyclePayload>>,
+    /// Shifts all cycles starts and ends by a certain number of days, starting from the provided cycle onwards.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cycle_shift_all: Option<Box<CyclePayload>>,
+    /// Shifts all cycles starts and ends by a certain number of days, starting from the provided cycle onwards.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cycle_start_upcoming_cycle_today: Option<Box<CyclePayload>>,
+    /// Updates a cycle.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cycle_update: Option<Box<CyclePayload>>,
+    /// Creates a new document.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub document_create: Option<Box<DocumentPayload>>,
+    /// Deletes (trashes) a document.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub document_delete: Option<Box<DocumentArchivePayload>>,
+    /// Restores a document.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub document_unarchive: Option<Box<DocumentArchivePayload>>,
+    /// Updates a document.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub document_update: Option<Box<DocumentPayload>>,
+    /// Creates a new email intake address.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email_intake_address_create: Option<Box<EmailIntakeAddressPayload>>,
+    /// Deletes an email intake address object.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email_intake_address_delete: Option<Box<DeletePayload>>,
+    /// Rotates an existing email intake address.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email_intake_address_rotate: Option<Box<EmailIntakeAddressPayload>>,
+    /// Updates an existing email intake address.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email_intake_address_update: Option<Box<EmailIntakeAddressPayload>>,
+    /// Authenticates a user account via email and authentication token.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email_token_user_account_auth: Option<Box<AuthResolverResponse>>,
+    /// Unsubscribes the user from one type of email.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email_unsubscribe: Option<Box<EmailUnsubscribePayload>>,
+    /// Finds or creates a new user account by email and sends an email with token.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email_user_account_auth_challenge: Option<Box<EmailUserAccountAuthChallengeResponse>>,
+    /// Creates a custom emoji.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub emoji_create: Option<Box<EmojiPayload>>,
+    /// Deletes an emoji.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub emoji_delete: Option<Box<DeletePayload>>,
+    /// Creates a new entity link.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub entity_external_link_create: Option<Box<EntityExternalLinkPayload>>,
+    /// Deletes an entity link.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub entity_external_link_delete: Option<Box<DeletePayload>>,
+    /// Updates an entity link.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub entity_external_link_update: Option<Box<EntityExternalLinkPayload>>,
+    /// Creates a new favorite (project, cycle etc).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub favorite_create: Option<Box<FavoritePayload>>,
+    /// Deletes a favorite reference.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub favorite_delete: Option<Box<DeletePayload>>,
+    /// Updates a favorite.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub favorite_update: Option<Box<FavoritePayload>>,
+    /// XHR request payload to upload an images, video and other attachments directly to Linear's cloud storage.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file_upload: Option<Box<UploadPayload>>,
+    /// \[INTERNAL\] Permanently delete an uploaded file by asset URL. This should be used as a last resort and will break comments and documents that reference the asset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file_upload_dangerously_delete: Option<Box<FileUploadDeletePayload>>,
+    /// Creates a new automation state.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub git_automation_state_create: Option<Box<GitAutomationStatePayload>>,
+    /// Archives an automation state.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub git_automation_state_delete: Option<Box<DeletePayload>>,
+    /// Updates an existing state.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub git_automation_state_update: Option<Box<GitAutomationStatePayload>>,
+    /// Creates a Git target branch automation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub git_automation_target_branch_create: Option<Box<GitAutomationTargetBranchPayload>>,
+    /// Archives a Git target branch automation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub git_automation_target_branch_delete: Option<Box<DeletePayload>>,
+    /// Updates an existing Git target branch automation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub git_automation_target_branch_update: Option<Box<GitAutomationTargetBranchPayload>>,
+    /// Authenticate user account through Google OAuth. This is the 2nd step of OAuth flow.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub google_user_account_auth: Option<Box<AuthResolverResponse>>,
+    /// Upload an image from an URL to Linear.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub image_upload_from_url: Option<Box<ImageUploadFromUrlPayload>>,
+    /// XHR request payload to upload a file for import, directly to Linear's cloud storage.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub import_file_upload: Option<Box<UploadPayload>>,
+    /// Archives a initiative.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative_archive: Option<Box<InitiativeArchivePayload>>,
+    /// Creates a new initiative.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative_create: Option<Box<InitiativePayload>>,
+    /// Deletes (trashes) an initiative.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative_delete: Option<Box<DeletePayload>>,
+    /// Creates a new initiative relation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative_relation_create: Option<Box<InitiativeRelationPayload>>,
+    /// Deletes an initiative relation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative_relation_delete: Option<Box<DeletePayload>>,
+    /// Updates an initiative relation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative_relation_update: Option<Box<DeletePayload>>,
+    /// Creates a new initiativeToProject join.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative_to_project_create: Option<Box<InitiativeToProjectPayload>>,
+    /// Deletes a initiativeToProject.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative_to_project_delete: Option<Box<DeletePayload>>,
+    /// Updates a initiativeToProject.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative_to_project_update: Option<Box<InitiativeToProjectPayload>>,
+    /// Unarchives a initiative.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative_unarchive: Option<Box<InitiativeArchivePayload>>,
+    /// Updates a initiative.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative_update: Option<Box<InitiativePayload>>,
+    /// Archives an initiative update.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative_update_archive: Option<Box<InitiativeUpdateArchivePayload>>,
+    /// Creates a initiative update.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative_update_create: Option<Box<InitiativeUpdatePayload>>,
+    /// Unarchives an initiative update.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative_update_unarchive: Option<Box<InitiativeUpdateArchivePayload>>,
+    /// Updates an update.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative_update_update: Option<Box<InitiativeUpdatePayload>>,
+    /// Archives an integration.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integration_archive: Option<Box<DeletePayload>>,
+    /// Connect a Slack channel to Asks.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integration_asks_connect_channel: Option<Box<AsksChannelConnectPayload>>,
+    /// \[INTERNAL\] Refreshes the customer data attributes from the specified integration service.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integration_customer_data_attributes_refresh: Option<Box<IntegrationPayload>>,
+    /// Deletes an integration.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integration_delete: Option<Box<DeletePayload>>,
+    /// Integrates the organization with Discord.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integration_discord: Option<Box<IntegrationPayload>>,
+    /// Integrates the organization with Figma.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integration_figma: Option<Box<IntegrationPayload>>,
+    /// Integrates the organization with Front.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integration_front: Option<Box<IntegrationPayload>>,
+    /// Connects the organization with a GitHub Enterprise Server.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integration_git_hub_enterprise_server_connect: Option<Box<GitHubEnterpriseServerPayload>>,
+    /// Connect your GitHub account to Linear.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integration_git_hub_personal: Option<Box<IntegrationPayload>>,
+    /// Generates a webhook for the GitHub commit integration.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integration_github_commit_create: Option<Box<GitHubCommitIntegrationPayload>>,
+    /// Connects the organization with the GitHub App.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integration_github_connect: Option<Box<IntegrationPayload>>,
+    /// Connects the organization with the GitHub Import App.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integration_github_import_connect: Option<Box<IntegrationPayload>>,
+    /// Refreshes the data for a GitHub import integration.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integration_github_import_refresh: Option<Box<IntegrationPayload>>,
+    /// Connects the organization with a GitLab Access Token.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integration_gitlab_connect: Option<Box<GitLabIntegrationCreatePayload>>,
+    /// Integrates the organization with Gong.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integration_gong: Option<Box<IntegrationPayload>>,
+    /// \[Internal\] Connects the Google Calendar to the user to this Linear account via OAuth2.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integration_google_calendar_personal_connect: Option<Box<IntegrationPayload>>,
+    /// Integrates the organization with Google Sheets.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integration_google_sheets: Option<Box<IntegrationPayload>>,
+    /// Integrates the organization with Intercom.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integration_intercom: Option<Box<IntegrationPayload>>,
+    /// Disconnects the organization from Intercom.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integration_intercom_delete: Option<Box<IntegrationPayload>>,
+    /// \[DEPRECATED\] Updates settings on the Intercom integration.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integration_intercom_settings_update: Option<Box<IntegrationPayload>>,
+    /// Connect your Jira account to Linear.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integration_jira_personal: Option<Box<IntegrationPayload>>,
+    /// \[INTERNAL\] Updates a Jira Integration.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integration_jira_update: Option<Box<IntegrationPayload>>,
+    /// \[INTERNAL\] Integrates the organization with LaunchDarkly.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integration_launch_darkly_connect: Option<Box<IntegrationPayload>>,
+    /// \[INTERNAL\] Integrates your personal account with LaunchDarkly.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integration_launch_darkly_personal_connect: Option<Box<IntegrationPayload>>,
+    /// Enables Loom integration for the organization.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integration_loom: Option<Box<IntegrationPayload>>,
+    /// \[INTERNAL\] Connects the workspace with an MCP server.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integration_mcp_server_connect: Option<Box<IntegrationPayload>>,
+    /// \[INTERNAL\] Connects the user's personal account with an MCP server.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integration_mcp_server_personal_connect: Option<Box<IntegrationPayload>>,
+    /// \[INTERNAL\] Integrates the organization with Opsgenie.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integration_opsgenie_connect: Option<Box<IntegrationPayload>>,
+    /// \[INTERNAL\] Refresh Opsgenie schedule mappings.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integration_opsgenie_refresh_schedule_mappings: Option<Box<IntegrationPayload>>,
+    /// \[INTERNAL\] Integrates the organization with PagerDuty.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integration_pager_duty_connect: Option<Box<IntegrationPayload>>,
+    /// \[INTERNAL\] Refresh PagerDuty schedule mappings.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integration_pager_duty_refresh_schedule_mappings: Option<Box<IntegrationPayload>>,
+    /// Requests a currently unavailable integration.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integration_request: Option<Box<IntegrationRequestPayload>>,
+    /// Integrates the organization with Salesforce.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integration_salesforce: Option<Box<IntegrationPayload>>,
+    /// \[INTERNAL\] Refreshes the Salesforce integration metadata.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integration_salesforce_metadata_refresh: Option<Box<IntegrationPayload>>,
+    /// Integrates the organization with Sentry.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integration_sentry_connect: Option<Box<IntegrationPayload>>,
+    /// \[INTERNAL\] Updates the integration settings.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integration_settings_update: Option<Box<IntegrationPayload>>,
+    /// Integrates the organization with Slack.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integration_slack: Option<Box<IntegrationPayload>>,
+    /// Integrates the organization with the Slack Asks app.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integration_slack_asks: Option<Box<IntegrationPayload>>,
+    /// Slack integration for custom view notifications.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integration_slack_custom_view_notifications: Option<Box<SlackChannelConnectPayload>>,
+    /// Integrates a Slack Asks channel with a Customer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integration_slack_customer_channel_link: Option<Box<SuccessPayload>>,
+    /// Imports custom emojis from your Slack workspace.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integration_slack_import_emojis: Option<Box<IntegrationPayload>>,
+    /// \[Internal\] Slack integration for initiative notifications.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integration_slack_initiative_post: Option<Box<SlackChannelConnectPayload>>,
+    /// Updates the Slack team's name in Linear for an existing Slack or Asks integration.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integration_slack_or_asks_update_slack_team_name: Option<Box<IntegrationSlackWorkspaceNamePayload>>,
+    /// \[Internal\] Slack integration for organization level initiative update notifications.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integration_slack_org_initiative_updates_post: Option<Box<SlackChannelConnectPayload>>,
+    /// Slack integration for organization level project update notifications.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integration_slack_org_project_updates_post: Option<Box<SlackChannelConnectPayload>>,
+    /// Integrates your personal notifications with Slack.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integration_slack_personal: Option<Box<IntegrationPayload>>,
+    /// Slack integration for team notifications.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integration_slack_post: Option<Box<SlackChannelConnectPayload>>,
+    /// Slack integration for project notifications.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integration_slack_project_post: Option<Box<SlackChannelConnectPayload>>,
+    /// \[Internal\] Enables Linear Agent Slack workflow access for a Slack integration.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integration_slack_workflow_access_update: Option<Box<IntegrationPayload>>,
+    /// Creates a new integrationTemplate join.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integration_template_create: Option<Box<IntegrationTemplatePayload>>,
+    /// Deletes a integrationTemplate.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integration_template_delete: Option<Box<DeletePayload>>,
+    /// \[INTERNAL\] Updates the integration.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integration_update: Option<Box<IntegrationPayload>>,
+    /// Integrates the organization with Zendesk.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integration_zendesk: Option<Box<IntegrationPayload>>,
+    /// Creates new settings for one or more integrations.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integrations_settings_create: Option<Box<IntegrationsSettingsPayload>>,
+    /// Updates settings related to integrations for a project or a team.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integrations_settings_update: Option<Box<IntegrationsSettingsPayload>>,
+    /// Adds a label to an issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_add_label: Option<Box<IssuePayload>>,
+    /// Archives an issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_archive: Option<Box<IssueArchivePayload>>,
+    /// Creates a list of issues in one transaction.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_batch_create: Option<Box<IssueBatchPayload>>,
+    /// Updates multiple issues at once.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_batch_update: Option<Box<IssueBatchPayload>>,
+    /// Creates a new issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_create: Option<Box<IssuePayload>>,
+    /// Deletes (trashes) an issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_delete: Option<Box<IssueArchivePayload>>,
+    /// \[INTERNAL\] Updates an issue description from the Front app to handle Front attachments correctly.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_description_update_from_front: Option<Box<IssuePayload>>,
+    /// Disables external sync on an issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_external_sync_disable: Option<Box<IssuePayload>>,
+    /// Kicks off an Asana import job.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_import_create_asana: Option<Box<IssueImportPayload>>,
+    /// Kicks off a Jira import job from a CSV.
+    #[serde(rename = "issueImportCreateCSVJira", default, skip_serializing_if = "Option::is_none")]
+    pub issue_import_create_csv_jira: Option<Box<IssueImportPayload>>,
+    /// Kicks off a Shortcut (formerly Clubhouse) import job.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_import_create_clubhouse: Option<Box<IssueImportPayload>>,
+    /// Kicks off a GitHub import job.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_import_create_github: Option<Box<IssueImportPayload>>,
+    /// Kicks off a Jira import job.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_import_create_jira: Option<Box<IssueImportPayload>>,
+    /// \[INTERNAL\] Kicks off a Linear to Linear import job.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_import_create_linear_v2: Option<Box<IssueImportPayload>>,
+    /// Deletes an import job.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_import_delete: Option<Box<IssueImportDeletePayload>>,
+    /// Kicks off import processing.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_import_process: Option<Box<IssueImportPayload>>,
+    /// Updates the mapping for the issue import.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_import_update: Option<Box<IssueImportPayload>>,
+    /// Creates a new label.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_label_create: Option<Box<IssueLabelPayload>>,
+    /// Deletes an issue label.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_label_delete: Option<Box<DeletePayload>>,
+    /// Restores a label.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_label_restore: Option<Box<IssueLabelPayload>>,
+    /// Retires a label.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_label_retire: Option<Box<IssueLabelPayload>>,
+    /// Updates a label.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_label_update: Option<Box<IssueLabelPayload>>,
+    /// Creates a new issue relation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_relation_create: Option<Box<IssueRelationPayload>>,
+    /// Deletes an issue relation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_relation_delete: Option<Box<DeletePayload>>,
+    /// Updates an issue relation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_relation_update: Option<Box<IssueRelationPayload>>,
+    /// Adds an issue reminder. Will cause a notification to be sent when the issue reminder time is reached.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_reminder: Option<Box<IssuePayload>>,
+    /// Removes a label from an issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_remove_label: Option<Box<IssuePayload>>,
+    /// Subscribes a user to an issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_subscribe: Option<Box<IssuePayload>>,
+    /// \[ALPHA\] Creates a new issueToRelease join, adding an issue to a release.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_to_release_create: Option<Box<IssueToReleasePayload>>,
+    /// \[ALPHA\] Deletes an issueToRelease by its identifier, removing an issue from a release.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_to_release_delete: Option<Box<DeletePayload>>,
+    /// \[ALPHA\] Deletes an issueToRelease by issue and release identifiers
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_to_release_delete_by_issue_and_release: Option<Box<DeletePayload>>,
+    /// Unarchives an issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_unarchive: Option<Box<IssueArchivePayload>>,
+    /// Unsubscribes a user from an issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_unsubscribe: Option<Box<IssuePayload>>,
+    /// Updates an issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_update: Option<Box<IssuePayload>>,
+    /// \[INTERNAL\] Connects the organization with a Jira Personal Access Token.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub jira_integration_connect: Option<Box<IntegrationPayload>>,
+    /// Join an organization from onboarding.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub join_organization_from_onboarding: Option<Box<CreateOrJoinOrganizationResponse>>,
+    /// Leave an organization.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub leave_organization: Option<Box<CreateOrJoinOrganizationResponse>>,
+    /// Logout the client.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub logout: Option<Box<LogoutResponse>>,
+    /// Logout all of user's sessions including the active one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub logout_all_sessions: Option<Box<LogoutResponse>>,
+    /// Logout all of user's sessions excluding the current one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub logout_other_sessions: Option<Box<LogoutResponse>>,
+    /// Logout an individual session with its ID.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub logout_session: Option<Box<LogoutResponse>>,
+    /// Archives a notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notification_archive: Option<Box<NotificationArchivePayload>>,
+    /// Archives a notification and all related notifications.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notification_archive_all: Option<Box<NotificationBatchActionPayload>>,
+    /// Subscribes to or unsubscribes from a notification category for a given notification channel for the user
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notification_category_channel_subscription_update: Option<Box<UserSettingsPayload>>,
+    /// Marks notification and all related notifications as read.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notification_mark_read_all: Option<Box<NotificationBatchActionPayload>>,
+    /// Marks notification and all related notifications as unread.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notification_mark_unread_all: Option<Box<NotificationBatchActionPayload>>,
+    /// Snoozes a notification and all related notifications.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notification_snooze_all: Option<Box<NotificationBatchActionPayload>>,
+    /// Creates a new notification subscription for a cycle, custom view, label, project or team.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notification_subscription_create: Option<Box<NotificationSubscriptionPayload>>,
+    /// Deletes a notification subscription reference.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notification_subscription_delete: Option<Box<DeletePayload>>,
+    /// Updates a notification subscription.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notification_subscription_update: Option<Box<NotificationSubscriptionPayload>>,
+    /// Unarchives a notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notification_unarchive: Option<Box<NotificationArchivePayload>>,
+    /// Unsnoozes a notification and all related notifications.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notification_unsnooze_all: Option<Box<NotificationBatchActionPayload>>,
+    /// Updates a notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notification_update: Option<Box<NotificationPayload>>,
+    /// Cancels the deletion of an organization.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization_cancel_delete: Option<Box<OrganizationCancelDeletePayload>>,
+    /// Deletes an organization.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization_delete: Option<Box<OrganizationDeletePayload>>,
+    /// Get an organization's delete confirmation token.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization_delete_challenge: Option<Box<OrganizationDeletePayload>>,
+    /// \[INTERNAL\] Verifies a domain claim.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization_domain_claim: Option<Box<OrganizationDomainSimplePayload>>,
+    /// \[INTERNAL\] Adds a domain to be allowed for an organization.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization_domain_create: Option<Box<OrganizationDomainPayload>>,
+    /// Deletes a domain.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization_domain_delete: Option<Box<DeletePayload>>,
+    /// \[INTERNAL\] Updates an organization domain settings.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization_domain_update: Option<Box<OrganizationDomainPayload>>,
+    /// \[INTERNAL\] Verifies a domain to be added to an organization.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization_domain_verify: Option<Box<OrganizationDomainPayload>>,
+    /// Creates a new organization invite.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization_invite_create: Option<Box<OrganizationInvitePayload>>,
+    /// Deletes an organization invite.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization_invite_delete: Option<Box<DeletePayload>>,
+    /// Updates an organization invite.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization_invite_update: Option<Box<OrganizationInvitePayload>>,
+    /// \[DEPRECATED\] Starts a trial for the organization.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization_start_trial: Option<Box<OrganizationStartTrialPayload>>,
+    /// Starts a trial for the organization on the specified plan type.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization_start_trial_for_plan: Option<Box<OrganizationStartTrialPayload>>,
+    /// Updates the user's organization.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization_update: Option<Box<OrganizationPayload>>,
+    /// \[INTERNAL\] Finish passkey login process.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub passkey_login_finish: Option<Box<AuthResolverResponse>>,
+    /// \[INTERNAL\] Starts passkey login process.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub passkey_login_start: Option<Box<PasskeyLoginStartResponse>>,
+    /// Adds a label to a project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_add_label: Option<Box<ProjectPayload>>,
+    /// Archives a project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_archive: Option<Box<ProjectArchivePayload>>,
+    /// Creates a new project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_create: Option<Box<ProjectPayload>>,
+    /// Deletes (trashes) a project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_delete: Option<Box<ProjectArchivePayload>>,
+    /// Disables external sync on a project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_external_sync_disable: Option<Box<ProjectPayload>>,
+    /// Creates a new project label.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_label_create: Option<Box<ProjectLabelPayload>>,
+    /// Deletes a project label.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_label_delete: Option<Box<DeletePayload>>,
+    /// Restores a project label.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_label_restore: Option<Box<ProjectLabelPayload>>,
+    /// Retires a project label.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_label_retire: Option<Box<ProjectLabelPayload>>,
+    /// Updates a project label.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_label_update: Option<Box<ProjectLabelPayload>>,
+    /// Creates a new project milestone.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_milestone_create: Option<Box<ProjectMilestonePayload>>,
+    /// Deletes a project milestone.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_milestone_delete: Option<Box<DeletePayload>>,
+    /// \[Internal\] Moves a project milestone to another project, can be called to undo a prior move.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_milestone_move: Option<Box<ProjectMilestoneMovePayload>>,
+    /// Updates a project milestone.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_milestone_update: Option<Box<ProjectMilestonePayload>>,
+    /// \[INTERNAL\] Updates all projects currently assigned to to a project status to a new project status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_reassign_status: Option<Box<SuccessPayload>>,
+    /// Creates a new project relation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_relation_create: Option<Box<ProjectRelationPayload>>,
+    /// Deletes a project relation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_relation_delete: Option<Box<DeletePayload>>,
+    /// Updates a project relation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_relation_update: Option<Box<ProjectRelationPayload>>,
+    /// Removes a label from a project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_remove_label: Option<Box<ProjectPayload>>,
+    /// Archives a project status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_status_archive: Option<Box<ProjectStatusArchivePayload>>,
+    /// Creates a new project status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_status_create: Option<Box<ProjectStatusPayload>>,
+    /// Unarchives a project status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_status_unarchive: Option<Box<ProjectStatusArchivePayload>>,
+    /// Updates a project status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_status_update: Option<Box<ProjectStatusPayload>>,
+    /// Unarchives a project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_unarchive: Option<Box<ProjectArchivePayload>>,
+    /// Updates a project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_update: Option<Box<ProjectPayload>>,
+    /// Archives a project update.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_update_archive: Option<Box<ProjectUpdateArchivePayload>>,
+    /// Creates a new project update.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_update_create: Option<Box<ProjectUpdatePayload>>,
+    /// Deletes a project update.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_update_delete: Option<Box<DeletePayload>>,
+    /// Unarchives a project update.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_update_unarchive: Option<Box<ProjectUpdateArchivePayload>>,
+    /// Updates a project update.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_update_update: Option<Box<ProjectUpdatePayload>>,
+    /// Creates a push subscription.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub push_subscription_create: Option<Box<PushSubscriptionPayload>>,
+    /// Deletes a push subscription.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub push_subscription_delete: Option<Box<PushSubscriptionPayload>>,
+    /// Creates a new reaction.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reaction_create: Option<Box<ReactionPayload>>,
+    /// Deletes a reaction.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reaction_delete: Option<Box<DeletePayload>>,
+    /// Manually update Google Sheets data.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub refresh_google_sheets_data: Option<Box<IntegrationPayload>>,
+    /// \[ALPHA\] Archives a release.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub release_archive: Option<Box<ReleaseArchivePayload>>,
+    /// \[ALPHA\] Creates a new release.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub release_create: Option<Box<ReleasePayload>>,
+    /// \[ALPHA\] Archives a release pipeline.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub release_pipeline_archive: Option<Box<ReleasePipelineArchivePayload>>,
+    /// \[ALPHA\] Creates a new release pipeline.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub release_pipeline_create: Option<Box<ReleasePipelinePayload>>,
+    /// \[ALPHA\] Deletes a release pipeline.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub release_pipeline_delete: Option<Box<DeletePayload>>,
+    /// \[ALPHA\] Unarchives a release pipeline.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub release_pipeline_unarchive: Option<Box<ReleasePipelineArchivePayload>>,
+    /// \[ALPHA\] Updates a release pipeline.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub release_pipeline_update: Option<Box<ReleasePipelinePayload>>,
+    /// \[ALPHA\] Archives a release stage.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub release_stage_archive: Option<Box<ReleaseStageArchivePayload>>,
+    /// \[ALPHA\] Creates a new release stage.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub release_stage_create: Option<Box<ReleaseStagePayload>>,
+    /// \[ALPHA\] Unarchives a release stage.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub release_stage_unarchive: Option<Box<ReleaseStageArchivePayload>>,
+    /// \[ALPHA\] Updates a release stage.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub release_stage_update: Option<Box<ReleaseStagePayload>>,
+    /// \[ALPHA\] Unarchives a release.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub release_unarchive: Option<Box<ReleaseArchivePayload>>,
+    /// \[ALPHA\] Updates a release.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub release_update: Option<Box<ReleasePayload>>,
+    /// Re-send an organization invite.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resend_organization_invite: Option<Box<DeletePayload>>,
+    /// Re-send an organization invite tied to an email address.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resend_organization_invite_by_email: Option<Box<DeletePayload>>,
+    /// Archives a roadmap.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub roadmap_archive: Option<Box<RoadmapArchivePayload>>,
+    /// Creates a new roadmap.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub roadmap_create: Option<Box<RoadmapPayload>>,
+    /// Deletes a roadmap.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub roadmap_delete: Option<Box<DeletePayload>>,
+    /// Creates a new roadmapToProject join.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub roadmap_to_project_create: Option<Box<RoadmapToProjectPayload>>,
+    /// Deletes a roadmapToProject.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub roadmap_to_project_delete: Option<Box<DeletePayload>>,
+    /// Updates a roadmapToProject.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub roadmap_to_project_update: Option<Box<RoadmapToProjectPayload>>,
+    /// Unarchives a roadmap.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub roadmap_unarchive: Option<Box<RoadmapArchivePayload>>,
+    /// Updates a roadmap.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub roadmap_update: Option<Box<RoadmapPayload>>,
+    /// Authenticates a user account via email and authentication token for SAML.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub saml_token_user_account_auth: Option<Box<AuthResolverResponse>>,
+    /// Creates a new team. The user who creates the team will automatically be added as a member to the newly created team.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team_create: Option<Box<TeamPayload>>,
+    /// Deletes team's cycles data
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team_cycles_delete: Option<Box<TeamPayload>>,
+    /// Deletes a team.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team_delete: Option<Box<DeletePayload>>,
+    /// Deletes a previously used team key.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team_key_delete: Option<Box<DeletePayload>>,
+    /// Creates a new team membership.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team_membership_create: Option<Box<TeamMembershipPayload>>,
+    /// Deletes a team membership.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team_membership_delete: Option<Box<DeletePayload>>,
+    /// Updates a team membership.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team_membership_update: Option<Box<TeamMembershipPayload>>,
+    /// Unarchives a team and cancels deletion.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team_unarchive: Option<Box<TeamArchivePayload>>,
+    /// Updates a team.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team_update: Option<Box<TeamPayload>>,
+    /// Creates a new template.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub template_create: Option<Box<TemplatePayload>>,
+    /// Deletes a template.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub template_delete: Option<Box<DeletePayload>>,
+    /// Updates an existing template.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub template_update: Option<Box<TemplatePayload>>,
+    /// Creates a new time schedule.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time_schedule_create: Option<Box<TimeSchedulePayload>>,
+    /// Deletes a time schedule.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time_schedule_delete: Option<Box<DeletePayload>>,
+    /// Refresh the integration schedule information.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time_schedule_refresh_integration_schedule: Option<Box<TimeSchedulePayload>>,
+    /// Updates a time schedule.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time_schedule_update: Option<Box<TimeSchedulePayload>>,
+    /// Upsert an external time schedule.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time_schedule_upsert_external: Option<Box<TimeSchedulePayload>>,
+    /// Creates a new triage responsibility.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub triage_responsibility_create: Option<Box<TriageResponsibilityPayload>>,
+    /// Deletes a triage responsibility.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub triage_responsibility_delete: Option<Box<DeletePayload>>,
+    /// Updates an existing triage responsibility.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub triage_responsibility_update: Option<Box<TriageResponsibilityPayload>>,
+    /// \[Internal\] Updates existing Slack integration scopes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub update_integration_slack_scopes: Option<Box<IntegrationPayload>>,
+    /// Changes the role of a user.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_change_role: Option<Box<UserAdminPayload>>,
+    /// \[DEPRECATED\] Makes user a regular user. Can only be called by an admin or owner.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_demote_admin: Option<Box<UserAdminPayload>>,
+    /// \[DEPRECATED\] Makes user a guest. Can only be called by an admin.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_demote_member: Option<Box<UserAdminPayload>>,
+    /// Connects the Discord user to this Linear account via OAuth2.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_discord_connect: Option<Box<UserPayload>>,
+    /// Disconnects the external user from this Linear account.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_external_user_disconnect: Option<Box<UserPayload>>,
+    /// Updates a user's settings flag.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_flag_update: Option<Box<UserSettingsFlagPayload>>,
+    /// \[DEPRECATED\] Makes user an admin. Can only be called by an admin or owner.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_promote_admin: Option<Box<UserAdminPayload>>,
+    /// \[DEPRECATED\] Makes user a regular user. Can only be called by an admin.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_promote_member: Option<Box<UserAdminPayload>>,
+    /// Resets user's setting flags.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_settings_flags_reset: Option<Box<UserSettingsFlagsResetPayload>>,
+    /// Updates the user's settings.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_settings_update: Option<Box<UserSettingsPayload>>,
+    /// Suspends a user. Can only be called by an admin or owner.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_suspend: Option<Box<UserAdminPayload>>,
+    /// Unlinks a guest user from their identity provider. Can only be called by an admin when SCIM is enabled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_unlink_from_identity_provider: Option<Box<UserAdminPayload>>,
+    /// Un-suspends a user. Can only be called by an admin or owner.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_unsuspend: Option<Box<UserAdminPayload>>,
+    /// Updates a user. Only available to organization admins and the user themselves.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_update: Option<Box<UserPayload>>,
+    /// Creates a new ViewPreferences object.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub view_preferences_create: Option<Box<ViewPreferencesPayload>>,
+    /// Deletes a ViewPreferences.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub view_preferences_delete: Option<Box<DeletePayload>>,
+    /// Updates an existing ViewPreferences object.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub view_preferences_update: Option<Box<ViewPreferencesPayload>>,
+    /// Creates a new webhook.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub webhook_create: Option<Box<WebhookPayload>>,
+    /// Deletes a Webhook.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub webhook_delete: Option<Box<DeletePayload>>,
+    /// Updates an existing Webhook.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub webhook_update: Option<Box<WebhookPayload>>,
+    /// Archives a state. Only states with issues that have all been archived can be archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub workflow_state_archive: Option<Box<WorkflowStateArchivePayload>>,
+    /// Creates a new state, adding it to the workflow of a team.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub workflow_state_create: Option<Box<WorkflowStatePayload>>,
+    /// Updates a state.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub workflow_state_update: Option<Box<WorkflowStatePayload>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Node {
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+}
+
+/// A notification sent to a user.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Notification {
+    /// The user that caused the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor: Option<Box<User>>,
+    /// \[Internal\] Notification actor initials if avatar is not available.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor_avatar_color: Option<String>,
+    /// \[Internal\] Notification avatar URL.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor_avatar_url: Option<String>,
+    /// \[Internal\] Notification actor initials if avatar is not available.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor_initials: Option<String>,
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// The bot that caused the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bot_actor: Option<Box<ActorBot>>,
+    /// The category of the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub category: Option<NotificationCategory>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// The time at when an email reminder for this notification was sent to the user. Null, if no email
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub emailed_at: Option<DateTime>,
+    /// The external user that caused the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub external_user_actor: Option<Box<ExternalUser>>,
+    /// \[Internal\] Notifications with the same grouping key will be grouped together in the UI.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub grouping_key: Option<String>,
+    /// \[Internal\] Priority of the notification with the same grouping key. Higher number means higher priority. If priority is the same, notifications should be sorted by `createdAt`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub grouping_priority: Option<f64>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// \[Internal\] Inbox URL for the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inbox_url: Option<String>,
+    /// \[Internal\] Initiative update health for new updates.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative_update_health: Option<String>,
+    /// \[Internal\] If notification actor was Linear.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_linear_actor: Option<bool>,
+    /// \[Internal\] Issue's status type for issue notifications.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_status_type: Option<String>,
+    /// \[Internal\] Project update health for new updates.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_update_health: Option<String>,
+    /// The time at when the user marked the notification as read. Null, if the the user hasn't read the notification
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub read_at: Option<DateTime>,
+    /// The time until a notification will be snoozed. After that it will appear in the inbox again.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub snoozed_until_at: Option<DateTime>,
+    /// \[Internal\] Notification subtitle.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subtitle: Option<String>,
+    /// \[Internal\] Notification title.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Notification type.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+    /// The time at which a notification was unsnoozed..
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unsnoozed_at: Option<DateTime>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+    /// \[Internal\] URL to the target of the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// The user that received the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<Box<User>>,
+}
+
+/// A generic payload return from entity archive mutations.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationArchivePayload {
+    /// The archived/unarchived entity. Null if entity was deleted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub entity: Option<Box<Notification>>,
+    /// The identifier of the last sync operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_sync_id: Option<f64>,
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationBatchActionPayload {
+    /// The identifier of the last sync operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_sync_id: Option<f64>,
+    /// The notifications that were updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notifications: Option<Vec<Notification>>,
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+}
+
+/// A user's notification category preferences.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationCategoryPreferences {
+    /// The preferences for notifications about apps and integrations.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub apps_and_integrations: Option<Box<NotificationChannelPreferences>>,
+    /// The preferences for notifications about assignments.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub assignments: Option<Box<NotificationChannelPreferences>>,
+    /// The preferences for notifications about comments and replies.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comments_and_replies: Option<Box<NotificationChannelPreferences>>,
+    /// The preferences for customer notifications.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customers: Option<Box<NotificationChannelPreferences>>,
+    /// The preferences for notifications about document changes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub document_changes: Option<Box<NotificationChannelPreferences>>,
+    /// The preferences for feed summary notifications.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub feed: Option<Box<NotificationChannelPreferences>>,
+    /// The preferences for notifications about mentions.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mentions: Option<Box<NotificationChannelPreferences>>,
+    /// The preferences for notifications about posts and updates.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub posts_and_updates: Option<Box<NotificationChannelPreferences>>,
+    /// The preferences for notifications about reactions.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reactions: Option<Box<NotificationChannelPreferences>>,
+    /// The preferences for notifications about reminders.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reminders: Option<Box<NotificationChannelPreferences>>,
+    /// The preferences for notifications about reviews.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reviews: Option<Box<NotificationChannelPreferences>>,
+    /// The preferences for notifications about status changes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status_changes: Option<Box<NotificationChannelPreferences>>,
+    /// The preferences for notifications about subscriptions.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subscriptions: Option<Box<NotificationChannelPreferences>>,
+    /// The preferences for system notifications.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub system: Option<Box<NotificationChannelPreferences>>,
+    /// The preferences for triage notifications.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub triage: Option<Box<NotificationChannelPreferences>>,
+}
+
+/// A user's notification channel preferences, indicating if a channel is enabled or not
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationChannelPreferences {
+    /// Whether notifications are currently enabled for desktop.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub desktop: Option<bool>,
+    /// Whether notifications are currently enabled for email.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email: Option<bool>,
+    /// Whether notifications are currently enabled for mobile.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mobile: Option<bool>,
+    /// Whether notifications are currently enabled for Slack.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slack: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationConnection {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub edges: Option<Vec<NotificationEdge>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nodes: Option<Vec<Notification>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page_info: Option<Box<PageInfo>>,
+}
+
+/// A user's notification delivery preferences.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationDeliveryPreferences {
+    /// The delivery preferences for the mobile channel.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mobile: Option<Box<NotificationDeliveryPreferencesChannel>>,
+}
+
+/// A user's notification delivery preferences.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationDeliveryPreferencesChannel {
+    /// \[DEPRECATED\] Whether notifications are enabled for this channel. Use notificationChannelPreferences instead.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notifications_disabled: Option<bool>,
+    /// The schedule for notifications on this channel.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schedule: Option<Box<NotificationDeliveryPreferencesSchedule>>,
+}
+
+/// A user's notification delivery schedule for a particular day.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationDeliveryPreferencesDay {
+    /// The time notifications end.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end: Option<String>,
+    /// The time notifications start.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start: Option<String>,
+}
+
+/// A user's notification delivery schedule for a particular day.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationDeliveryPreferencesSchedule {
+    /// Whether the schedule is disabled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub disabled: Option<bool>,
+    /// Delivery preferences for Friday.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub friday: Option<Box<NotificationDeliveryPreferencesDay>>,
+    /// Delivery preferences for Monday.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub monday: Option<Box<NotificationDeliveryPreferencesDay>>,
+    /// Delivery preferences for Saturday.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub saturday: Option<Box<NotificationDeliveryPreferencesDay>>,
+    /// Delivery preferences for Sunday.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sunday: Option<Box<NotificationDeliveryPreferencesDay>>,
+    /// Delivery preferences for Thursday.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub thursday: Option<Box<NotificationDeliveryPreferencesDay>>,
+    /// Delivery preferences for Tuesday.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tuesday: Option<Box<NotificationDeliveryPreferencesDay>>,
+    /// Delivery preferences for Wednesday.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wednesday: Option<Box<NotificationDeliveryPreferencesDay>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationEdge {
+    /// Used in `before` and `after` args
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub node: Option<Box<Notification>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationPayload {
+    /// The identifier of the last sync operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_sync_id: Option<f64>,
+    /// The notification that was created or updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notification: Option<Box<Notification>>,
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+}
+
+/// Notification subscriptions for models.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationSubscription {
+    /// Whether the subscription is active or not.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// The type of view to which the notification subscription context is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub context_view_type: Option<ContextViewType>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// The contextual custom view associated with the notification subscription.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub custom_view: Option<Box<CustomView>>,
+    /// The customer associated with the notification subscription.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer: Option<Box<Customer>>,
+    /// The contextual cycle view associated with the notification subscription.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cycle: Option<Box<Cycle>>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The contextual initiative view associated with the notification subscription.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative: Option<Box<Initiative>>,
+    /// The contextual label view associated with the notification subscription.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<Box<IssueLabel>>,
+    /// The contextual project view associated with the notification subscription.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project: Option<Box<Project>>,
+    /// The user that subscribed to receive notifications.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subscriber: Option<Box<User>>,
+    /// The team associated with the notification subscription.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team: Option<Box<Team>>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+    /// The user view associated with the notification subscription.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<Box<User>>,
+    /// The type of user view to which the notification subscription context is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_context_view_type: Option<UserContextViewType>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationSubscriptionConnection {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub edges: Option<Vec<NotificationSubscriptionEdge>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nodes: Option<Vec<NotificationSubscription>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page_info: Option<Box<PageInfo>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationSubscriptionEdge {
+    /// Used in `before` and `after` args
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub node: Option<Box<NotificationSubscription>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationSubscriptionPayload {
+    /// The identifier of the last sync operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_sync_id: Option<f64>,
+    /// The notification subscription that was created or updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notification_subscription: Option<Box<NotificationSubscription>>,
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+}
+
+/// Payload for OAuth app webhook events.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OAuthAppWebhookPayload {
+    /// The type of action that triggered the webhook.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub action: Option<String>,
+    /// The time the payload was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// Id of the OAuth client that was revoked.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub oauth_client_id: Option<String>,
+    /// ID of the organization for which the webhook belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization_id: Option<String>,
+    /// The type of resource.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+    /// The ID of the webhook that sent this event.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub webhook_id: Option<String>,
+    /// Unix timestamp in milliseconds when the webhook was sent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub webhook_timestamp: Option<f64>,
+}
+
+/// Payload for OAuth authorization webhook events.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OAuthAuthorizationWebhookPayload {
+    /// The type of action that triggered the webhook.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub action: Option<String>,
+    /// The number of currently active tokens for the user for this client.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub active_tokens_for_user: Option<f64>,
+    /// The time the payload was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// Details of the OAuth client the authorization belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub oauth_client: Option<Box<OauthClientChildWebhookPayload>>,
+    /// ID of the OAuth client the authorization belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub oauth_client_id: Option<String>,
+    /// ID of the organization for which the webhook belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization_id: Option<String>,
+    /// The type of resource.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+    /// Details of the user that the authorization belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<Box<UserChildWebhookPayload>>,
+    /// ID of the user that the authorization belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<String>,
+    /// The ID of the webhook that sent this event.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub webhook_id: Option<String>,
+    /// Unix timestamp in milliseconds when the webhook was sent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub webhook_timestamp: Option<f64>,
+}
+
+/// OAuth client actor payload for webhooks.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OauthClientActorWebhookPayload {
+    /// The ID of the OAuth client.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The name of the OAuth client.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The type of actor.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+}
+
+/// Request to install OAuth clients on organizations and the response to the request.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OauthClientApproval {
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// The reason the request for the OAuth client approval was denied.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deny_reason: Option<String>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// New scopes that were requested for approval after the initial request.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub newly_requested_scopes: Option<Vec<String>>,
+    /// The uuid of the OAuth client being requested for installation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub oauth_client_id: Option<String>,
+    /// The reason the person wants to install this OAuth client.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub request_reason: Option<String>,
+    /// The person who requested installing the OAuth client.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub requester_id: Option<String>,
+    /// The person who responded to the request to install the OAuth client.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub responder_id: Option<String>,
+    /// The scopes the app has been approved for.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scopes: Option<Vec<String>>,
+    /// The status for the OAuth client approval request.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<OAuthClientApprovalStatus>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+}
+
+/// An oauth client approval related notification.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OauthClientApprovalNotification {
+    /// The user that caused the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor: Option<Box<User>>,
+    /// \[Internal\] Notification actor initials if avatar is not available.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor_avatar_color: Option<String>,
+    /// \[Internal\] Notification avatar URL.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor_avatar_url: Option<String>,
+    /// \[Internal\] Notification actor initials if avatar is not available.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor_initials: Option<String>,
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// The bot that caused the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bot_actor: Option<Box<ActorBot>>,
+    /// The category of the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub category: Option<NotificationCategory>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// The time at when an email reminder for this notification was sent to the user. Null, if no email
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub emailed_at: Option<DateTime>,
+    /// The external user that caused the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub external_user_actor: Option<Box<ExternalUser>>,
+    /// \[Internal\] Notifications with the same grouping key will be grouped together in the UI.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub grouping_key: Option<String>,
+    /// \[Internal\] Priority of the notification with the same grouping key. Higher number means higher priority. If priority is the same, notifications should be sorted by `createdAt`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub grouping_priority: Option<f64>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// \[Internal\] Inbox URL for the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inbox_url: Option<String>,
+    /// \[Internal\] Initiative update health for new updates.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative_update_health: Option<String>,
+    /// \[Internal\] If notification actor was Linear.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_linear_actor: Option<bool>,
+    /// \[Internal\] Issue's status type for issue notifications.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_status_type: Option<String>,
+    /// The OAuth client approval request related to the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub oauth_client_approval: Option<Box<OauthClientApproval>>,
+    /// Related OAuth client approval request ID.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub oauth_client_approval_id: Option<String>,
+    /// \[Internal\] Project update health for new updates.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_update_health: Option<String>,
+    /// The time at when the user marked the notification as read. Null, if the the user hasn't read the notification
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub read_at: Option<DateTime>,
+    /// The time until a notification will be snoozed. After that it will appear in the inbox again.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub snoozed_until_at: Option<DateTime>,
+    /// \[Internal\] Notification subtitle.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subtitle: Option<String>,
+    /// \[Internal\] Notification title.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Notification type.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+    /// The time at which a notification was unsnoozed..
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unsnoozed_at: Option<DateTime>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+    /// \[Internal\] URL to the target of the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// The user that received the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<Box<User>>,
+}
+
+/// Certain properties of an OAuth client.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OauthClientChildWebhookPayload {
+    /// The ID of the OAuth client.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The name of the OAuth client.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+/// An organization. Organizations are root-level objects that contain user accounts and teams.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Organization {
+    /// \[INTERNAL\] Whether the organization has enabled the AI add-on (which at this point only includes triage suggestions).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ai_addon_enabled: Option<bool>,
+    /// Whether the organization has enabled AI discussion summaries for issues.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ai_discussion_summaries_enabled: Option<bool>,
+    /// Whether the organization has enabled resolved thread AI summaries.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ai_thread_summaries_enabled: Option<bool>,
+    /// \[DEPRECATED\] Whether member users are allowed to send invites.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allow_members_to_invite: Option<bool>,
+    /// \[INTERNAL\] Permitted AI providers in order of preference. Empty array means all providers are allowed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allowed_ai_providers: Option<Vec<String>>,
+    /// Allowed authentication providers, empty array means all are allowed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allowed_auth_services: Option<Vec<String>>,
+    /// Allowed file upload content types
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allowed_file_upload_content_types: Option<Vec<String>>,
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// \[INTERNAL\] Whether code intelligence is enabled for the organization.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub code_intelligence_enabled: Option<bool>,
+    /// \[INTERNAL\] GitHub repository in owner/repo format for code intelligence.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub code_intelligence_repository: Option<String>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// Aproximate number of issues in the organization, including archived ones.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_issue_count: Option<i64>,
+    /// Number of customers in the organization.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer_count: Option<i64>,
+    /// Configuration settings for the Customers feature.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customers_configuration: Option<JSONObject>,
+    /// Whether the organization is using Customers.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customers_enabled: Option<bool>,
+    /// Default schedule for how often feed summaries are generated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_feed_summary_schedule: Option<FeedSummarySchedule>,
+    /// The time at which deletion of the organization was requested.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deletion_requested_at: Option<DateTime>,
+    /// \[Internal\] Facets associated with the organization.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub facets: Option<Vec<Facet>>,
+    /// Whether the organization has enabled the feed feature.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub feed_enabled: Option<bool>,
+    /// The month at which the fiscal year starts. Defaults to January (0).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fiscal_year_start_month: Option<f64>,
+    /// \[INTERNAL\] Whether the organization has enabled generated updates.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub generated_updates_enabled: Option<bool>,
+    /// How git branches are formatted. If null, default formatting will be used.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub git_branch_format: Option<String>,
+    /// Whether issue descriptions should be included in Git integration linkback messages.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub git_linkback_descriptions_enabled: Option<bool>,
+    /// Whether the Git integration linkback messages should be sent to private repositories.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub git_linkback_messages_enabled: Option<bool>,
+    /// Whether the Git integration linkback messages should be sent to public repositories.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub git_public_linkback_messages_enabled: Option<bool>,
+    /// Whether HIPAA compliance is enabled for organization.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hipaa_compliance_enabled: Option<bool>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The n-weekly frequency at which to prompt for initiative updates. When not set, reminders are off.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative_update_reminder_frequency_in_weeks: Option<f64>,
+    /// The day at which to prompt for initiative updates.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative_update_reminders_day: Option<Day>,
+    /// The hour at which to prompt for initiative updates.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative_update_reminders_hour: Option<f64>,
+    /// Integrations associated with the organization.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integrations: Option<Box<IntegrationConnection>>,
+    /// IP restriction configurations.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ip_restrictions: Option<Vec<OrganizationIpRestriction>>,
+    /// Labels associated with the organization.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<Box<IssueLabelConnection>>,
+    /// The organization's logo URL.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub logo_url: Option<String>,
+    /// The organization's name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Rolling 30-day total upload volume for the organization, in megabytes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub period_upload_volume: Option<f64>,
+    /// Previously used URL keys for the organization (last 3 are kept and redirected).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub previous_url_keys: Option<Vec<String>>,
+    /// Project labels associated with the organization.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_labels: Option<Box<ProjectLabelConnection>>,
+    /// The organization's project statuses.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_statuses: Option<Vec<ProjectStatus>>,
+    /// The n-weekly frequency at which to prompt for project updates. When not set, reminders are off.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_update_reminder_frequency_in_weeks: Option<f64>,
+    /// The day at which to prompt for project updates.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_update_reminders_day: Option<Day>,
+    /// The hour at which to prompt for project updates.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_update_reminders_hour: Option<f64>,
+    /// \[DEPRECATED\] The frequency at which to prompt for project updates.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_updates_reminder_frequency: Option<ProjectUpdateReminderFrequency>,
+    /// The feature release channel the organization belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub release_channel: Option<ReleaseChannel>,
+    /// \[DEPRECATED\] Whether workspace label creation, update, and deletion is restricted to admins.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub restrict_label_management_to_admins: Option<bool>,
+    /// \[DEPRECATED\] Whether team creation is restricted to admins.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub restrict_team_creation_to_admins: Option<bool>,
+    /// Whether the organization is using a roadmap.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub roadmap_enabled: Option<bool>,
+    /// Whether SAML authentication is enabled for organization.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub saml_enabled: Option<bool>,
+    /// \[INTERNAL\] SAML settings.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub saml_settings: Option<JSONObject>,
+    /// Whether SCIM provisioning is enabled for organization.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scim_enabled: Option<bool>,
+    /// \[INTERNAL\] SCIM settings.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scim_settings: Option<JSONObject>,
+    /// Security settings for the organization.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub security_settings: Option<JSONObject>,
+    /// \[DEPRECATED\] Which day count to use for SLA calculations.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sla_day_count: Option<SLADayCountType>,
+    /// The organization's subscription to a paid plan.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subscription: Option<Box<PaidSubscription>>,
+    /// Teams associated with the organization.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub teams: Option<Box<TeamConnection>>,
+    /// Templates associated with the organization.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub templates: Option<Box<TemplateConnection>>,
+    /// \[ALPHA\] Theme settings for the organization.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub theme_settings: Option<JSONObject>,
+    /// The time at which the trial will end.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trial_ends_at: Option<DateTime>,
+    /// The time at which the trial started.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trial_starts_at: Option<DateTime>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+    /// The organization's unique URL key.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url_key: Option<String>,
+    /// Number of active users in the organization.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_count: Option<i64>,
+    /// Users associated with the organization.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub users: Option<Box<UserConnection>>,
+    /// \[Internal\] The list of working days. Sunday is 0, Monday is 1, etc.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub working_days: Option<Vec<f64>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrganizationAcceptedOrExpiredInviteDetailsPayload {
+    /// The status of the invite.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<OrganizationInviteStatus>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrganizationCancelDeletePayload {
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrganizationDeletePayload {
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+}
+
+/// Defines the use of a domain by an organization.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrganizationDomain {
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// What type of auth is the domain used for.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auth_type: Option<OrganizationDomainAuthType>,
+    /// Whether the domains was claimed by the organization through DNS verification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub claimed: Option<bool>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// The user who added the domain.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub creator: Option<Box<User>>,
+    /// Prevent users with this domain to create new workspaces.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub disable_organization_creation: Option<bool>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The identity provider the domain belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub identity_provider: Option<Box<IdentityProvider>>,
+    /// Domain name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+    /// E-mail used to verify this domain.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verification_email: Option<String>,
+    /// Is this domain verified.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verified: Option<bool>,
+}
+
+/// \[INTERNAL\] Domain claim request response.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrganizationDomainClaimPayload {
+    /// String to put into DNS for verification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verification_string: Option<String>,
+}
+
+/// \[INTERNAL\] Organization domain operation response.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrganizationDomainPayload {
+    /// The identifier of the last sync operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_sync_id: Option<f64>,
+    /// The organization domain that was created or updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization_domain: Option<Box<OrganizationDomain>>,
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+}
+
+/// \[INTERNAL\] Organization domain operation response.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrganizationDomainSimplePayload {
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrganizationExistsPayload {
+    /// Whether the organization exists.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub exists: Option<bool>,
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+}
+
+/// An invitation to the organization that has been sent via email.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrganizationInvite {
+    /// The time at which the invite was accepted. Null, if the invite hasn't been accepted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub accepted_at: Option<DateTime>,
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// The invitees email address.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    /// The time at which the invite will be expiring. Null, if the invite shouldn't expire.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<DateTime>,
+    /// The invite was sent to external address.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub external: Option<bool>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The user who has accepted the invite. Null, if the invite hasn't been accepted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub invitee: Option<Box<User>>,
+    /// The user who created the invitation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inviter: Option<Box<User>>,
+    /// Extra metadata associated with the organization invite.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<JSONObject>,
+    /// The organization that the invite is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization: Option<Box<Organization>>,
+    /// The user role that the invitee will receive upon accepting the invite.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub role: Option<UserRoleType>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrganizationInviteConnection {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub edges: Option<Vec<OrganizationInviteEdge>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nodes: Option<Vec<OrganizationInvite>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page_info: Option<Box<PageInfo>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrganizationInviteEdge {
+    /// Used in `before` and `after` args
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub node: Option<Box<OrganizationInvite>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrganizationInviteFullDetailsPayload {
+    /// Whether the invite has already been accepted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub accepted: Option<bool>,
+    /// Allowed authentication providers, empty array means all are allowed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allowed_auth_services: Option<Vec<String>>,
+    /// When the invite was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// The email of the invitee.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    /// Whether the invite has expired.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expired: Option<bool>,
+    /// The name of the inviter.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inviter: Option<String>,
+    /// ID of the workspace the invite is for.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization_id: Option<String>,
+    /// URL of the workspace logo the invite is for.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization_logo_url: Option<String>,
+    /// Name of the workspace the invite is for.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization_name: Option<String>,
+    /// What user role the invite should grant.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub role: Option<UserRoleType>,
+    /// The status of the invite.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<OrganizationInviteStatus>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrganizationInvitePayload {
+    /// The identifier of the last sync operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_sync_id: Option<f64>,
+    /// The organization invite that was created or updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization_invite: Option<Box<OrganizationInvite>>,
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrganizationIpRestriction {
+    /// Optional restriction description.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Whether the restriction is enabled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    /// IP range in CIDR format.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub range: Option<String>,
+    /// Restriction type.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrganizationMeta {
+    /// Allowed authentication providers, empty array means all are allowed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allowed_auth_services: Option<Vec<String>>,
+    /// The region the organization is hosted in.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub region: Option<String>,
+}
+
+/// Organization origin for guidance rules.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrganizationOriginWebhookPayload {
+    /// The type of origin, always 'Organization'.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrganizationPayload {
+    /// The identifier of the last sync operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_sync_id: Option<f64>,
+    /// The organization that was created or updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization: Option<Box<Organization>>,
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrganizationStartTrialPayload {
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+}
+
+/// Generic notification payload.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OtherNotificationWebhookPayload {
+    /// The actor who caused the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor: Option<Box<UserChildWebhookPayload>>,
+    /// The ID of the actor who caused the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor_id: Option<String>,
+    /// The time at which the entity was archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<String>,
+    /// The comment this notification belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<Box<CommentChildWebhookPayload>>,
+    /// The ID of the comment this notification belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment_id: Option<String>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    /// The document this notification belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub document: Option<Box<DocumentChildWebhookPayload>>,
+    /// The ID of the document this notification belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub document_id: Option<String>,
+    /// The ID of the external user who caused the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub external_user_actor_id: Option<String>,
+    /// The ID of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The issue this notification belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue: Option<Box<IssueWithDescriptionChildWebhookPayload>>,
+    /// The ID of the issue this notification belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_id: Option<String>,
+    /// The parent comment this notification belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_comment: Option<Box<CommentChildWebhookPayload>>,
+    /// The ID of the parent comment this notification belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_comment_id: Option<String>,
+    /// The project this notification belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project: Option<Box<ProjectChildWebhookPayload>>,
+    /// The ID of the project this notification belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_id: Option<String>,
+    /// The project update this notification belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_update: Option<Box<ProjectUpdateChildWebhookPayload>>,
+    /// The ID of the project update this notification belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_update_id: Option<String>,
+    /// The emoji of the reaction this notification is for.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reaction_emoji: Option<String>,
+    /// The type of the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<OtherNotificationType>,
+    /// The time at which the entity was updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+    /// The ID of the user who received the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PageInfo {
+    /// Cursor representing the last result in the paginated results.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end_cursor: Option<String>,
+    /// Indicates if there are more results when paginating forward.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_next_page: Option<bool>,
+    /// Indicates if there are more results when paginating backward.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_previous_page: Option<bool>,
+    /// Cursor representing the first result in the paginated results.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_cursor: Option<String>,
+}
+
+/// The paid subscription of an organization.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PaidSubscription {
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// The date the subscription is scheduled to be canceled, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cancel_at: Option<DateTime>,
+    /// The date the subscription was canceled, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub canceled_at: Option<DateTime>,
+    /// The collection method for this subscription, either automatically charged or invoiced.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub collection_method: Option<String>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// The creator of the subscription.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub creator: Option<Box<User>>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The date the subscription will be billed next.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next_billing_at: Option<DateTime>,
+    /// The organization that the subscription is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization: Option<Box<Organization>>,
+    /// The subscription type of a pending change. Null if no change pending.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pending_change_type: Option<String>,
+    /// The number of seats in the subscription.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub seats: Option<f64>,
+    /// The maximum number of seats that will be billed in the subscription.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub seats_maximum: Option<f64>,
+    /// The minimum number of seats that will be billed in the subscription.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub seats_minimum: Option<f64>,
+    /// The subscription type.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PasskeyLoginStartResponse {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub options: Option<JSONObject>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+}
+
+/// \[Internal\] A generic post.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Post {
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// The update content summarized for audio consumption.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub audio_summary: Option<String>,
+    /// The update content in markdown format.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    /// \[Internal\] The content of the post as a Prosemirror document.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body_data: Option<String>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// The user who wrote the post.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub creator: Option<Box<User>>,
+    /// The time the post was edited.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub edited_at: Option<DateTime>,
+    /// The log id of the ai response.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub eval_log_id: Option<String>,
+    /// Schedule used to create a post summary.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub feed_summary_schedule_at_create: Option<FeedSummarySchedule>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// Emoji reaction summary, grouped by emoji type.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reaction_data: Option<JSONObject>,
+    /// The post's unique URL slug.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slug_id: Option<String>,
+    /// The team that the post is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team: Option<Box<Team>>,
+    /// The post's title.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// A URL of the TTL (text-to-language) for the body.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ttl_url: Option<String>,
+    /// The type of the post.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<PostType>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+    /// The user that the post is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<Box<User>>,
+    /// \[Internal\] The written update data used to compose the written post.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub written_summary_data: Option<JSONObject>,
+}
+
+/// A post related notification.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PostNotification {
+    /// The user that caused the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor: Option<Box<User>>,
+    /// \[Internal\] Notification actor initials if avatar is not available.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor_avatar_color: Option<String>,
+    /// \[Internal\] Notification avatar URL.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor_avatar_url: Option<String>,
+    /// \[Internal\] Notification actor initials if avatar is not available.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor_initials: Option<String>,
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// The bot that caused the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bot_actor: Option<Box<ActorBot>>,
+    /// The category of the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub category: Option<NotificationCategory>,
+    /// Related comment ID. Null if the notification is not related to a comment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment_id: Option<String>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// The time at when an email reminder for this notification was sent to the user. Null, if no email
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub emailed_at: Option<DateTime>,
+    /// The external user that caused the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub external_user_actor: Option<Box<ExternalUser>>,
+    /// \[Internal\] Notifications with the same grouping key will be grouped together in the UI.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub grouping_key: Option<String>,
+    /// \[Internal\] Priority of the notification with the same grouping key. Higher number means higher priority. If priority is the same, notifications should be sorted by `createdAt`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub grouping_priority: Option<f64>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// \[Internal\] Inbox URL for the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inbox_url: Option<String>,
+    /// \[Internal\] Initiative update health for new updates.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative_update_health: Option<String>,
+    /// \[Internal\] If notification actor was Linear.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_linear_actor: Option<bool>,
+    /// \[Internal\] Issue's status type for issue notifications.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_status_type: Option<String>,
+    /// Related parent comment ID. Null if the notification is not related to a comment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_comment_id: Option<String>,
+    /// Related post ID.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub post_id: Option<String>,
+    /// \[Internal\] Project update health for new updates.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_update_health: Option<String>,
+    /// Name of the reaction emoji related to the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reaction_emoji: Option<String>,
+    /// The time at when the user marked the notification as read. Null, if the the user hasn't read the notification
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub read_at: Option<DateTime>,
+    /// The time until a notification will be snoozed. After that it will appear in the inbox again.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub snoozed_until_at: Option<DateTime>,
+    /// \[Internal\] Notification subtitle.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subtitle: Option<String>,
+    /// \[Internal\] Notification title.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Notification type.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+    /// The time at which a notification was unsnoozed..
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unsnoozed_at: Option<DateTime>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+    /// \[Internal\] URL to the target of the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// The user that received the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<Box<User>>,
+}
+
+/// A project.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Project {
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// The time at which the project was automatically archived by the auto pruning process.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_archived_at: Option<DateTime>,
+    /// The time at which the project was moved into canceled state.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub canceled_at: Option<DateTime>,
+    /// The project's color.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    /// Comments associated with the project overview.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comments: Option<Box<CommentConnection>>,
+    /// The time at which the project was moved into completed state.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub completed_at: Option<DateTime>,
+    /// The number of completed issues in the project after each week.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub completed_issue_count_history: Option<Vec<f64>>,
+    /// The number of completed estimation points after each week.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub completed_scope_history: Option<Vec<f64>>,
+    /// The project's content in markdown format.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+    /// \[Internal\] The project's content as YJS state.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_state: Option<String>,
+    /// The project was created based on this issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub converted_from_issue: Option<Box<Issue>>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// The user who created the project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub creator: Option<Box<User>>,
+    /// \[INTERNAL\] The current progress of the project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub current_progress: Option<JSONObject>,
+    /// The project's description.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The content of the project description.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub document_content: Option<Box<DocumentContent>>,
+    /// Documents associated with the project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub documents: Option<Box<DocumentConnection>>,
+    /// External links associated with the project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub external_links: Option<Box<EntityExternalLinkConnection>>,
+    /// \[Internal\] Facets associated with the project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub facets: Option<Vec<Facet>>,
+    /// The user's favorite associated with this project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub favorite: Option<Box<Favorite>>,
+    /// The resolution of the reminder frequency.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub frequency_resolution: Option<FrequencyResolutionType>,
+    /// The health of the project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub health: Option<ProjectUpdateHealthType>,
+    /// The time at which the project health was updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub health_updated_at: Option<DateTime>,
+    /// History entries associated with the project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub history: Option<Box<ProjectHistoryConnection>>,
+    /// The icon of the project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub icon: Option<String>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The number of in progress estimation points after each week.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub in_progress_scope_history: Option<Vec<f64>>,
+    /// Associations of this project to parent initiatives.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative_to_projects: Option<Box<InitiativeToProjectConnection>>,
+    /// Initiatives that this project belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiatives: Option<Box<InitiativeConnection>>,
+    /// Settings for all integrations associated with that project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integrations_settings: Option<Box<IntegrationsSettings>>,
+    /// Inverse relations associated with this project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inverse_relations: Option<Box<ProjectRelationConnection>>,
+    /// The total number of issues in the project after each week.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_count_history: Option<Vec<f64>>,
+    /// Issues associated with the project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issues: Option<Box<IssueConnection>>,
+    /// Id of the labels associated with this project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label_ids: Option<Vec<String>>,
+    /// Labels associated with this project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<Box<ProjectLabelConnection>>,
+    /// The last template that was applied to this project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_applied_template: Option<Box<Template>>,
+    /// The last project update posted for this project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_update: Option<Box<ProjectUpdate>>,
+    /// The project lead.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lead: Option<Box<User>>,
+    /// Users that are members of the project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub members: Option<Box<UserConnection>>,
+    /// The project's name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Customer needs associated with the project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub needs: Option<Box<CustomerNeedConnection>>,
+    /// The priority of the project. 0 = No priority, 1 = Urgent, 2 = High, 3 = Normal, 4 = Low.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority: Option<i64>,
+    /// The priority of the project as a label.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority_label: Option<String>,
+    /// The sort order for the project within the organization, when ordered by priority.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority_sort_order: Option<f64>,
+    /// The overall progress of the project. This is the (completed estimate points + 0.25 * in progress estimate points) / total estimate points.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub progress: Option<f64>,
+    /// \[INTERNAL\] The progress history of the project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub progress_history: Option<JSONObject>,
+    /// Milestones associated with the project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_milestones: Option<Box<ProjectMilestoneConnection>>,
+    /// The time until which project update reminders are paused.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_update_reminders_paused_until_at: Option<DateTime>,
+    /// Project updates associated with the project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_updates: Option<Box<ProjectUpdateConnection>>,
+    /// Relations associated with this project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub relations: Option<Box<ProjectRelationConnection>>,
+    /// The overall scope (total estimate points) of the project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scope: Option<f64>,
+    /// The total number of estimation points after each week.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scope_history: Option<Vec<f64>>,
+    /// Whether to send new issue comment notifications to Slack.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slack_issue_comments: Option<bool>,
+    /// Whether to send new issue status updates to Slack.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slack_issue_statuses: Option<bool>,
+    /// Whether to send new issue notifications to Slack.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slack_new_issue: Option<bool>,
+    /// The project's unique URL slug.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slug_id: Option<String>,
+    /// The sort order for the project within the organization.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort_order: Option<f64>,
+    /// The estimated start date of the project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_date: Option<TimelessDate>,
+    /// The resolution of the project's start date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_date_resolution: Option<DateResolutionType>,
+    /// The time at which the project was moved into started state.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub started_at: Option<DateTime>,
+    /// \[DEPRECATED\] The type of the state.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+    /// The status that the project is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<Box<ProjectStatus>>,
+    /// The estimated completion date of the project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_date: Option<TimelessDate>,
+    /// The resolution of the project's estimated completion date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_date_resolution: Option<DateResolutionType>,
+    /// Teams associated with this project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub teams: Option<Box<TeamConnection>>,
+    /// A flag that indicates whether the project is in the trash bin.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trashed: Option<bool>,
+    /// The frequency at which to prompt for updates. When not set, reminders are inherited from workspace.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub update_reminder_frequency: Option<f64>,
+    /// The n-weekly frequency at which to prompt for updates. When not set, reminders are inherited from workspace.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub update_reminder_frequency_in_weeks: Option<f64>,
+    /// The day at which to prompt for updates.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub update_reminders_day: Option<Day>,
+    /// The hour at which to prompt for updates.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub update_reminders_hour: Option<f64>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+    /// Project URL.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+/// A generic payload return from entity archive mutations.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectArchivePayload {
+    /// The archived/unarchived entity. Null if entity was deleted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub entity: Option<Box<Project>>,
+    /// The identifier of the last sync operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_sync_id: Option<f64>,
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+}
+
+/// Project attachment
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectAttachment {
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// The creator of the attachment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub creator: Option<Box<User>>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// Custom metadata related to the attachment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<JSONObject>,
+    /// Information about the external source which created the attachment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<JSONObject>,
+    /// An accessor helper to source.type, defines the source type of the attachment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_type: Option<String>,
+    /// Optional subtitle of the attachment
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subtitle: Option<String>,
+    /// Title of the attachment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+    /// URL of the attachment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+/// Certain properties of a project.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectChildWebhookPayload {
+    /// The ID of the project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The name of the project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The URL of the project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectConnection {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub edges: Option<Vec<ProjectEdge>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nodes: Option<Vec<Project>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page_info: Option<Box<PageInfo>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectEdge {
+    /// Used in `before` and `after` args
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub node: Option<Box<Project>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectFilterSuggestionPayload {
+    /// The json filter that is suggested.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filter: Option<JSONObject>,
+    /// The log id of the prompt, that created this filter.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub log_id: Option<String>,
+}
+
+/// An history associated with a project.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectHistory {
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// The events that happened while recording that history.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub entries: Option<JSONObject>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The project that the history is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project: Option<Box<Project>>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectHistoryConnection {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub edges: Option<Vec<ProjectHistoryEdge>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nodes: Option<Vec<ProjectHistory>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page_info: Option<Box<PageInfo>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectHistoryEdge {
+    /// Used in `before` and `after` args
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub node: Option<Box<ProjectHistory>>,
+}
+
+/// Labels that can be associated with projects.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectLabel {
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// Children of the label.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub children: Option<Box<ProjectLabelConnection>>,
+    /// The label's color as a HEX string.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// The user who created the label.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub creator: Option<Box<User>>,
+    /// The label's description.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// Whether the label is a group.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_group: Option<bool>,
+    /// The date when the label was last applied to an issue or project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_applied_at: Option<DateTime>,
+    /// The label's name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization: Option<Box<Organization>>,
+    /// The parent label.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent: Option<Box<ProjectLabel>>,
+    /// Projects associated with the label.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub projects: Option<Box<ProjectConnection>>,
+    /// \[Internal\] When the label was retired.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retired_at: Option<DateTime>,
+    /// The user who retired the label.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retired_by: Option<Box<User>>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+}
+
+/// Certain properties of a project label.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectLabelChildWebhookPayload {
+    /// The color of the project label.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    /// The ID of the project label.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The name of the project label.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The parent ID of the project label.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectLabelConnection {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub edges: Option<Vec<ProjectLabelEdge>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nodes: Option<Vec<ProjectLabel>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page_info: Option<Box<PageInfo>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectLabelEdge {
+    /// Used in `before` and `after` args
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub node: Option<Box<ProjectLabel>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectLabelPayload {
+    /// The identifier of the last sync operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_sync_id: Option<f64>,
+    /// The label that was created or updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_label: Option<Box<ProjectLabel>>,
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+}
+
+/// Payload for a project label webhook.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectLabelWebhookPayload {
+    /// The time at which the entity was archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<String>,
+    /// The color of the project label.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    /// The creator ID of the project label.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub creator_id: Option<String>,
+    /// The label's description.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The ID of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// Whether the label is a group.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_group: Option<bool>,
+    /// The name of the project label.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The parent ID of the project label.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_id: Option<String>,
+    /// The time at which the entity was updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+}
+
+/// A milestone for a project.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectMilestone {
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// \[Internal\] The current progress of the project milestone.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub current_progress: Option<JSONObject>,
+    /// The project milestone's description in markdown format.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// \[Internal\] The project milestone's description as YJS state.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description_state: Option<String>,
+    /// The content of the project milestone description.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub document_content: Option<Box<DocumentContent>>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// Issues associated with the project milestone.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issues: Option<Box<IssueConnection>>,
+    /// The name of the project milestone.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The progress % of the project milestone.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub progress: Option<f64>,
+    /// \[Internal\] The progress history of the project milestone.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub progress_history: Option<JSONObject>,
+    /// The project of the milestone.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project: Option<Box<Project>>,
+    /// The order of the milestone in relation to other milestones within a project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort_order: Option<f64>,
+    /// The status of the project milestone.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<ProjectMilestoneStatus>,
+    /// The planned completion date of the milestone.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_date: Option<TimelessDate>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+}
+
+/// Certain properties of a project milestone.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectMilestoneChildWebhookPayload {
+    /// The ID of the project milestone.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The name of the project milestone.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The target date of the project milestone.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_date: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectMilestoneConnection {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub edges: Option<Vec<ProjectMilestoneEdge>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nodes: Option<Vec<ProjectMilestone>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page_info: Option<Box<PageInfo>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectMilestoneEdge {
+    /// Used in `before` and `after` args
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub node: Option<Box<ProjectMilestone>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectMilestoneMoveIssueToTeam {
+    /// The issue id in this relationship, you can use * as wildcard if all issues are being moved to the same team
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_id: Option<String>,
+    /// The team id in this relationship
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectMilestoneMovePayload {
+    /// The identifier of the last sync operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_sync_id: Option<f64>,
+    /// A snapshot of the issues that were moved to new teams, if the user selected to do it, containing an array of mappings between an issue and its previous team. Store on the client to use for undoing a previous milestone move.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub previous_issue_team_ids: Option<Vec<ProjectMilestoneMoveIssueToTeam>>,
+    /// A snapshot of the project that had new teams added to it, if the user selected to do it, containing an array of mappings between a project and its previous teams. Store on the client to use for undoing a previous milestone move.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub previous_project_team_ids: Option<Box<ProjectMilestoneMoveProjectTeams>>,
+    /// The project milestone that was created or updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_milestone: Option<Box<ProjectMilestone>>,
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectMilestoneMoveProjectTeams {
+    /// The project id
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_id: Option<String>,
+    /// The team ids for the project
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team_ids: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectMilestonePayload {
+    /// The identifier of the last sync operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_sync_id: Option<f64>,
+    /// The project milestone that was created or updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_milestone: Option<Box<ProjectMilestone>>,
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+}
+
+/// A project related notification.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectNotification {
+    /// The user that caused the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor: Option<Box<User>>,
+    /// \[Internal\] Notification actor initials if avatar is not available.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor_avatar_color: Option<String>,
+    /// \[Internal\] Notification avatar URL.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor_avatar_url: Option<String>,
+    /// \[Internal\] Notification actor initials if avatar is not available.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor_initials: Option<String>,
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// The bot that caused the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bot_actor: Option<Box<ActorBot>>,
+    /// The category of the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub category: Option<NotificationCategory>,
+    /// The comment related to the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<Box<Comment>>,
+    /// Related comment ID. Null if the notification is not related to a comment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment_id: Option<String>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// The document related to the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub document: Option<Box<Document>>,
+    /// The time at when an email reminder for this notification was sent to the user. Null, if no email
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub emailed_at: Option<DateTime>,
+    /// The external user that caused the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub external_user_actor: Option<Box<ExternalUser>>,
+    /// \[Internal\] Notifications with the same grouping key will be grouped together in the UI.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub grouping_key: Option<String>,
+    /// \[Internal\] Priority of the notification with the same grouping key. Higher number means higher priority. If priority is the same, notifications should be sorted by `createdAt`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub grouping_priority: Option<f64>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// \[Internal\] Inbox URL for the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inbox_url: Option<String>,
+    /// \[Internal\] Initiative update health for new updates.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative_update_health: Option<String>,
+    /// \[Internal\] If notification actor was Linear.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_linear_actor: Option<bool>,
+    /// \[Internal\] Issue's status type for issue notifications.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_status_type: Option<String>,
+    /// The parent comment related to the notification, if a notification is a reply comment notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_comment: Option<Box<Comment>>,
+    /// Related parent comment ID. Null if the notification is not related to a comment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_comment_id: Option<String>,
+    /// The project related to the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project: Option<Box<Project>>,
+    /// Related project ID.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_id: Option<String>,
+    /// Related project milestone ID.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_milestone_id: Option<String>,
+    /// The project update related to the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_update: Option<Box<ProjectUpdate>>,
+    /// \[Internal\] Project update health for new updates.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_update_health: Option<String>,
+    /// Related project update ID.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_update_id: Option<String>,
+    /// Name of the reaction emoji related to the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reaction_emoji: Option<String>,
+    /// The time at when the user marked the notification as read. Null, if the the user hasn't read the notification
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub read_at: Option<DateTime>,
+    /// The time until a notification will be snoozed. After that it will appear in the inbox again.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub snoozed_until_at: Option<DateTime>,
+    /// \[Internal\] Notification subtitle.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subtitle: Option<String>,
+    /// \[Internal\] Notification title.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Notification type.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+    /// The time at which a notification was unsnoozed..
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unsnoozed_at: Option<DateTime>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+    /// \[Internal\] URL to the target of the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// The user that received the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<Box<User>>,
+}
+
+/// A project notification subscription.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectNotificationSubscription {
+    /// Whether the subscription is active or not.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// The type of view to which the notification subscription context is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub context_view_type: Option<ContextViewType>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// The contextual custom view associated with the notification subscription.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub custom_view: Option<Box<CustomView>>,
+    /// The customer associated with the notification subscription.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer: Option<Box<Customer>>,
+    /// The contextual cycle view associated with the notification subscription.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cycle: Option<Box<Cycle>>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The contextual initiative view associated with the notification subscription.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative: Option<Box<Initiative>>,
+    /// The contextual label view associated with the notification subscription.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<Box<IssueLabel>>,
+    /// The type of subscription.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notification_subscription_types: Option<Vec<String>>,
+    /// The project subscribed to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project: Option<Box<Project>>,
+    /// The user that subscribed to receive notifications.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subscriber: Option<Box<User>>,
+    /// The team associated with the notification subscription.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team: Option<Box<Team>>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+    /// The user view associated with the notification subscription.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<Box<User>>,
+    /// The type of user view to which the notification subscription context is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_context_view_type: Option<UserContextViewType>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectPayload {
+    /// The identifier of the last sync operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_sync_id: Option<f64>,
+    /// The project that was created or updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project: Option<Box<Project>>,
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+}
+
+/// A relation between two projects.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectRelation {
+    /// The type of anchor on the project end of the relation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub anchor_type: Option<String>,
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The project whose relationship is being described.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project: Option<Box<Project>>,
+    /// The milestone within the project whose relationship is being described.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_milestone: Option<Box<ProjectMilestone>>,
+    /// The type of anchor on the relatedProject end of the relation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub related_anchor_type: Option<String>,
+    /// The related project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub related_project: Option<Box<Project>>,
+    /// The milestone within the related project whose relationship is being described.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub related_project_milestone: Option<Box<ProjectMilestone>>,
+    /// The relationship of the project with the related project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+    /// The last user who created or modified the relation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<Box<User>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectRelationConnection {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub edges: Option<Vec<ProjectRelationEdge>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nodes: Option<Vec<ProjectRelation>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page_info: Option<Box<PageInfo>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectRelationEdge {
+    /// Used in `before` and `after` args
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub node: Option<Box<ProjectRelation>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectRelationPayload {
+    /// The identifier of the last sync operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_sync_id: Option<f64>,
+    /// The project relation that was created or updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_relation: Option<Box<ProjectRelation>>,
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectSearchPayload {
+    /// Archived entities matching the search term along with all their dependencies.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archive_payload: Option<Box<ArchiveResponse>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub edges: Option<Vec<ProjectSearchResultEdge>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nodes: Option<Vec<ProjectSearchResult>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page_info: Option<Box<PageInfo>>,
+    /// Total number of results for query without filters applied.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub total_count: Option<f64>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectSearchResult {
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// The time at which the project was automatically archived by the auto pruning process.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_archived_at: Option<DateTime>,
+    /// The time at which the project was moved into canceled state.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub canceled_at: Option<DateTime>,
+    /// The project's color.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    /// Comments associated with the project overview.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comments: Option<Box<CommentConnection>>,
+    /// The time at which the project was moved into completed state.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub completed_at: Option<DateTime>,
+    /// The number of completed issues in the project after each week.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub completed_issue_count_history: Option<Vec<f64>>,
+    /// The number of completed estimation points after each week.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub completed_scope_history: Option<Vec<f64>>,
+    /// The project's content in markdown format.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+    /// \[Internal\] The project's content as YJS state.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_state: Option<String>,
+    /// The project was created based on this issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub converted_from_issue: Option<Box<Issue>>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// The user who created the project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub creator: Option<Box<User>>,
+    /// \[INTERNAL\] The current progress of the project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub current_progress: Option<JSONObject>,
+    /// The project's description.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The content of the project description.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub document_content: Option<Box<DocumentContent>>,
+    /// Documents associated with the project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub documents: Option<Box<DocumentConnection>>,
+    /// External links associated with the project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub external_links: Option<Box<EntityExternalLinkConnection>>,
+    /// \[Internal\] Facets associated with the project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub facets: Option<Vec<Facet>>,
+    /// The user's favorite associated with this project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub favorite: Option<Box<Favorite>>,
+    /// The resolution of the reminder frequency.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub frequency_resolution: Option<FrequencyResolutionType>,
+    /// The health of the project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub health: Option<ProjectUpdateHealthType>,
+    /// The time at which the project health was updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub health_updated_at: Option<DateTime>,
+    /// History entries associated with the project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub history: Option<Box<ProjectHistoryConnection>>,
+    /// The icon of the project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub icon: Option<String>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The number of in progress estimation points after each week.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub in_progress_scope_history: Option<Vec<f64>>,
+    /// Associations of this project to parent initiatives.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative_to_projects: Option<Box<InitiativeToProjectConnection>>,
+    /// Initiatives that this project belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiatives: Option<Box<InitiativeConnection>>,
+    /// Settings for all integrations associated with that project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integrations_settings: Option<Box<IntegrationsSettings>>,
+    /// Inverse relations associated with this project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inverse_relations: Option<Box<ProjectRelationConnection>>,
+    /// The total number of issues in the project after each week.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_count_history: Option<Vec<f64>>,
+    /// Issues associated with the project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issues: Option<Box<IssueConnection>>,
+    /// Id of the labels associated with this project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label_ids: Option<Vec<String>>,
+    /// Labels associated with this project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<Box<ProjectLabelConnection>>,
+    /// The last template that was applied to this project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_applied_template: Option<Box<Template>>,
+    /// The last project update posted for this project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_update: Option<Box<ProjectUpdate>>,
+    /// The project lead.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lead: Option<Box<User>>,
+    /// Users that are members of the project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub members: Option<Box<UserConnection>>,
+    /// Metadata related to search result.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<JSONObject>,
+    /// The project's name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Customer needs associated with the project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub needs: Option<Box<CustomerNeedConnection>>,
+    /// The priority of the project. 0 = No priority, 1 = Urgent, 2 = High, 3 = Normal, 4 = Low.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority: Option<i64>,
+    /// The priority of the project as a label.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority_label: Option<String>,
+    /// The sort order for the project within the organization, when ordered by priority.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority_sort_order: Option<f64>,
+    /// The overall progress of the project. This is the (completed estimate points + 0.25 * in progress estimate points) / total estimate points.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub progress: Option<f64>,
+    /// \[INTERNAL\] The progress history of the project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub progress_history: Option<JSONObject>,
+    /// Milestones associated with the project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_milestones: Option<Box<ProjectMilestoneConnection>>,
+    /// The time until which project update reminders are paused.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_update_reminders_paused_until_at: Option<DateTime>,
+    /// Project updates associated with the project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_updates: Option<Box<ProjectUpdateConnection>>,
+    /// Relations associated with this project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub relations: Option<Box<ProjectRelationConnection>>,
+    /// The overall scope (total estimate points) of the project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scope: Option<f64>,
+    /// The total number of estimation points after each week.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scope_history: Option<Vec<f64>>,
+    /// Whether to send new issue comment notifications to Slack.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slack_issue_comments: Option<bool>,
+    /// Whether to send new issue status updates to Slack.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slack_issue_statuses: Option<bool>,
+    /// Whether to send new issue notifications to Slack.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slack_new_issue: Option<bool>,
+    /// The project's unique URL slug.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slug_id: Option<String>,
+    /// The sort order for the project within the organization.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort_order: Option<f64>,
+    /// The estimated start date of the project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_date: Option<TimelessDate>,
+    /// The resolution of the project's start date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_date_resolution: Option<DateResolutionType>,
+    /// The time at which the project was moved into started state.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub started_at: Option<DateTime>,
+    /// \[DEPRECATED\] The type of the state.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+    /// The status that the project is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<Box<ProjectStatus>>,
+    /// The estimated completion date of the project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_date: Option<TimelessDate>,
+    /// The resolution of the project's estimated completion date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_date_resolution: Option<DateResolutionType>,
+    /// Teams associated with this project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub teams: Option<Box<TeamConnection>>,
+    /// A flag that indicates whether the project is in the trash bin.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trashed: Option<bool>,
+    /// The frequency at which to prompt for updates. When not set, reminders are inherited from workspace.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub update_reminder_frequency: Option<f64>,
+    /// The n-weekly frequency at which to prompt for updates. When not set, reminders are inherited from workspace.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub update_reminder_frequency_in_weeks: Option<f64>,
+    /// The day at which to prompt for updates.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub update_reminders_day: Option<Day>,
+    /// The hour at which to prompt for updates.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub update_reminders_hour: Option<f64>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+    /// Project URL.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectSearchResultEdge {
+    /// Used in `before` and `after` args
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub node: Option<Box<ProjectSearchResult>>,
+}
+
+/// A project status.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectStatus {
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// The UI color of the status as a HEX string.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// Description of the status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// Whether or not a project can be in this status indefinitely.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub indefinite: Option<bool>,
+    /// The name of the status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The position of the status in the workspace's project flow.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub position: Option<f64>,
+    /// The type of the project status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<ProjectStatusType>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+}
+
+/// A generic payload return from entity archive mutations.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectStatusArchivePayload {
+    /// The archived/unarchived entity. Null if entity was deleted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub entity: Option<Box<ProjectStatus>>,
+    /// The identifier of the last sync operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_sync_id: Option<f64>,
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+}
+
+/// Certain properties of a project status.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectStatusChildWebhookPayload {
+    /// The color of the project status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    /// The ID of the project status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The name of the project status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The type of the project status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectStatusConnection {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub edges: Option<Vec<ProjectStatusEdge>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nodes: Option<Vec<ProjectStatus>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page_info: Option<Box<PageInfo>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectStatusCountPayload {
+    /// Total number of projects using this project status that are not visible to the user because they are in an archived team.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_team_count: Option<f64>,
+    /// Total number of projects using this project status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub count: Option<f64>,
+    /// Total number of projects using this project status that are not visible to the user because they are in a private team.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub private_count: Option<f64>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectStatusEdge {
+    /// Used in `before` and `after` args
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub node: Option<Box<ProjectStatus>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectStatusPayload {
+    /// The identifier of the last sync operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_sync_id: Option<f64>,
+    /// The project status that was created or updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<Box<ProjectStatus>>,
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+}
+
+/// An update associated with a project.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectUpdate {
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// The update content in markdown format.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    /// \[Internal\] The content of the update as a Prosemirror document.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body_data: Option<String>,
+    /// Number of comments associated with the project update.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment_count: Option<i64>,
+    /// Comments associated with the project update.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comments: Option<Box<CommentConnection>>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// The diff between the current update and the previous one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub diff: Option<JSONObject>,
+    /// The diff between the current update and the previous one, formatted as markdown.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub diff_markdown: Option<String>,
+    /// The time the update was edited.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub edited_at: Option<DateTime>,
+    /// The health of the project at the time of the update.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub health: Option<ProjectUpdateHealthType>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// \[Internal\] Serialized JSON representing current state of the project properties when posting the project update.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub info_snapshot: Option<JSONObject>,
+    /// Whether project update diff should be hidden.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_diff_hidden: Option<bool>,
+    /// Whether the project update is stale.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_stale: Option<bool>,
+    /// The project that the update is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project: Option<Box<Project>>,
+    /// Emoji reaction summary, grouped by emoji type.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reaction_data: Option<JSONObject>,
+    /// Reactions associated with the project update.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reactions: Option<Vec<Reaction>>,
+    /// The update's unique URL slug.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slug_id: Option<String>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+    /// The URL to the project update.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// The user who wrote the update.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<Box<User>>,
+}
+
+/// A generic payload return from entity archive mutations.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectUpdateArchivePayload {
+    /// The archived/unarchived entity. Null if entity was deleted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub entity: Option<Box<ProjectUpdate>>,
+    /// The identifier of the last sync operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_sync_id: Option<f64>,
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+}
+
+/// Certain properties of a project update.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectUpdateChildWebhookPayload {
+    /// The body of the project update.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    /// The ID of the project update.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The project that the project update belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project: Option<Box<ProjectChildWebhookPayload>>,
+    /// The ID of the user who wrote the project update.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectUpdateConnection {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub edges: Option<Vec<ProjectUpdateEdge>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nodes: Option<Vec<ProjectUpdate>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page_info: Option<Box<PageInfo>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectUpdateEdge {
+    /// Used in `before` and `after` args
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub node: Option<Box<ProjectUpdate>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectUpdatePayload {
+    /// The identifier of the last sync operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_sync_id: Option<f64>,
+    /// The project update that was created or updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_update: Option<Box<ProjectUpdate>>,
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectUpdateReminderPayload {
+    /// The identifier of the last sync operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_sync_id: Option<f64>,
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+}
+
+/// Payload for a project update webhook.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectUpdateWebhookPayload {
+    /// The time at which the entity was archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<String>,
+    /// The body of the project update.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    /// The body data of the project update.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body_data: Option<String>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    /// The edited at timestamp of the project update.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub edited_at: Option<String>,
+    /// The health of the project update.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub health: Option<String>,
+    /// The ID of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The project that the project update belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project: Option<Box<ProjectChildWebhookPayload>>,
+    /// The project id of the project update.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_id: Option<String>,
+    /// The reaction data for this project update.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reaction_data: Option<JSONObject>,
+    /// The slug id of the project update.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slug_id: Option<String>,
+    /// The time at which the entity was updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+    /// The URL of the project update.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// The user who wrote the project update.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<Box<UserChildWebhookPayload>>,
+    /// The user id of the project update.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<String>,
+}
+
+/// Payload for a project webhook.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectWebhookPayload {
+    /// The time at which the entity was archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<String>,
+    /// The auto archived at timestamp of the project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_archived_at: Option<String>,
+    /// The canceled at timestamp of the project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub canceled_at: Option<String>,
+    /// The project's color.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    /// The completed at timestamp of the project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub completed_at: Option<String>,
+    /// The number of completed issues in the project after each week.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub completed_issue_count_history: Option<Vec<f64>>,
+    /// The number of completed estimation points after each week.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub completed_scope_history: Option<Vec<f64>>,
+    /// The content of the project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+    /// The ID of the issue that was converted to the project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub converted_from_issue_id: Option<String>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    /// The ID of the user who created the project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub creator_id: Option<String>,
+    /// The project's description.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The document content ID of the project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub document_content_id: Option<String>,
+    /// The health of the project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub health: Option<String>,
+    /// The time at which the project health was updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub health_updated_at: Option<String>,
+    /// The icon of the project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub icon: Option<String>,
+    /// The ID of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The number of in progress estimation points after each week.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub in_progress_scope_history: Option<Vec<f64>>,
+    /// The initiatives associated with the project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiatives: Option<Vec<InitiativeChildWebhookPayload>>,
+    /// The total number of issues in the project after each week.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_count_history: Option<Vec<f64>>,
+    /// IDs of the labels associated with this project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label_ids: Option<Vec<String>>,
+    /// The ID of the last template that was applied to the project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_applied_template_id: Option<String>,
+    /// The ID of the last update posted for this project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_update_id: Option<String>,
+    /// The project lead.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lead: Option<Box<UserChildWebhookPayload>>,
+    /// The ID of the project lead.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lead_id: Option<String>,
+    /// IDs of the members of the project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub member_ids: Option<Vec<String>>,
+    /// The milestones associated with the project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub milestones: Option<Vec<ProjectMilestoneChildWebhookPayload>>,
+    /// The project's name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The priority of the project. 0 = No priority, 1 = Urgent, 2 = High, 3 = Normal, 4 = Low.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority: Option<f64>,
+    /// The sort order for the project within the organization, when ordered by priority.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority_sort_order: Option<f64>,
+    /// The time at which the project update reminders were paused until.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_update_reminders_paused_until_at: Option<String>,
+    /// The total number of estimation points after each week.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scope_history: Option<Vec<f64>>,
+    /// The project's unique URL slug.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slug_id: Option<String>,
+    /// The sort order for the project within the organization.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort_order: Option<f64>,
+    /// The estimated start date of the project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_date: Option<String>,
+    /// The resolution of the project's estimated start date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_date_resolution: Option<String>,
+    /// The time at which the project was moved into started state.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub started_at: Option<String>,
+    /// The project status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<Box<ProjectStatusChildWebhookPayload>>,
+    /// The ID of the project status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status_id: Option<String>,
+    /// The target date of the project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_date: Option<String>,
+    /// The resolution of the project's target date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_date_resolution: Option<String>,
+    /// IDs of the teams associated with this project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team_ids: Option<Vec<String>>,
+    /// The trashed status of the project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trashed: Option<bool>,
+    /// The time at which the entity was updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+    /// The URL of the project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+/// \[Internal\] A pull request in a version control system.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PullRequest {
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// \[ALPHA\] The commits associated with the pull request.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub commits: Option<Vec<PullRequestCommit>>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The merge commit created when the PR was merged.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub merge_commit: Option<Box<PullRequestCommit>>,
+    /// Merge settings for this pull request.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub merge_settings: Option<Box<PullRequestMergeSettings>>,
+    /// The number of the pull request in the version control system.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub number: Option<f64>,
+    /// The source branch of the pull request.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_branch: Option<String>,
+    /// The status of the pull request.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<PullRequestStatus>,
+    /// The target branch of the pull request.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_branch: Option<String>,
+    /// The title of the pull request.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+    /// The URL of the pull request in the version control system.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+/// \[ALPHA\] A pull request commit.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PullRequestCommit {
+    /// Number of additions in this commit.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub additions: Option<f64>,
+    /// External user IDs for commit authors (includes co-authors).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub author_external_user_ids: Option<Vec<String>>,
+    /// Linear user IDs for commit authors (includes co-authors).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub author_user_ids: Option<Vec<String>>,
+    /// The number of changed files if available.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub changed_files: Option<f64>,
+    /// The timestamp when the commit was committed (ISO 8601 string).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub committed_at: Option<String>,
+    /// Number of deletions in this commit.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deletions: Option<f64>,
+    /// The full commit message.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    /// The Git commit SHA.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sha: Option<String>,
+}
+
+/// \[Internal\] Merge settings for a pull request
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PullRequestMergeSettings {
+    /// Whether auto-merge is allowed for the PR's repository.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_merge_allowed: Option<bool>,
+    /// Whether the branch will be deleted when the pull request is merged.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub delete_branch_on_merge: Option<bool>,
+    /// Whether merge queue is enabled for this repository.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_merge_queue_enabled: Option<bool>,
+    /// Whether merge commits are allowed for pull requests PR's repository.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub merge_commit_allowed: Option<bool>,
+    /// The method used to merge a pull request.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub merge_queue_merge_method: Option<PullRequestMergeMethod>,
+    /// Whether rebase merge is allowed for pull requests PR's repository.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rebase_merge_allowed: Option<bool>,
+    /// Whether squash merge is allowed for this pull request's repository.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub squash_merge_allowed: Option<bool>,
+}
+
+/// A pull request related notification.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PullRequestNotification {
+    /// The user that caused the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor: Option<Box<User>>,
+    /// \[Internal\] Notification actor initials if avatar is not available.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor_avatar_color: Option<String>,
+    /// \[Internal\] Notification avatar URL.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor_avatar_url: Option<String>,
+    /// \[Internal\] Notification actor initials if avatar is not available.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor_initials: Option<String>,
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// The bot that caused the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bot_actor: Option<Box<ActorBot>>,
+    /// The category of the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub category: Option<NotificationCategory>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// The time at when an email reminder for this notification was sent to the user. Null, if no email
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub emailed_at: Option<DateTime>,
+    /// The external user that caused the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub external_user_actor: Option<Box<ExternalUser>>,
+    /// \[Internal\] Notifications with the same grouping key will be grouped together in the UI.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub grouping_key: Option<String>,
+    /// \[Internal\] Priority of the notification with the same grouping key. Higher number means higher priority. If priority is the same, notifications should be sorted by `createdAt`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub grouping_priority: Option<f64>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// \[Internal\] Inbox URL for the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inbox_url: Option<String>,
+    /// \[Internal\] Initiative update health for new updates.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative_update_health: Option<String>,
+    /// \[Internal\] If notification actor was Linear.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_linear_actor: Option<bool>,
+    /// \[Internal\] Issue's status type for issue notifications.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_status_type: Option<String>,
+    /// \[Internal\] Project update health for new updates.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_update_health: Option<String>,
+    /// The pull request related to the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pull_request: Option<Box<PullRequest>>,
+    /// Related pull request comment ID. Null if the notification is not related to a pull request comment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pull_request_comment_id: Option<String>,
+    /// Related pull request.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pull_request_id: Option<String>,
+    /// The time at when the user marked the notification as read. Null, if the the user hasn't read the notification
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub read_at: Option<DateTime>,
+    /// The time until a notification will be snoozed. After that it will appear in the inbox again.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub snoozed_until_at: Option<DateTime>,
+    /// \[Internal\] Notification subtitle.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subtitle: Option<String>,
+    /// \[Internal\] Notification title.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Notification type.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+    /// The time at which a notification was unsnoozed..
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unsnoozed_at: Option<DateTime>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+    /// \[Internal\] URL to the target of the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// The user that received the notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<Box<User>>,
+}
+
+/// A user's web or mobile push notification subscription.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PushSubscription {
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PushSubscriptionPayload {
+    /// The push subscription that was created or updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub entity: Option<Box<PushSubscription>>,
+    /// The identifier of the last sync operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_sync_id: Option<f64>,
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PushSubscriptionTestPayload {
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Query {
+    #[serde(rename = "_dummy", default, skip_serializing_if = "Option::is_none")]
+    pub _dummy: Option<String>,
+    /// All teams you the user can administrate. Administrable teams are teams whose settings the user can change, but to whose issues the user doesn't necessarily have access to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub administrable_teams: Option<Box<TeamConnection>>,
+    /// All agent activities.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub agent_activities: Option<Box<AgentActivityConnection>>,
+    /// A specific agent activity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub agent_activity: Option<Box<AgentActivity>>,
+    /// A specific agent session.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub agent_session: Option<Box<AgentSession>>,
+    /// All agent sessions.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub agent_sessions: Option<Box<AgentSessionConnection>>,
+    /// Get basic information for an application.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub application_info: Option<Box<Application>>,
+    /// \[Internal\] All archived teams of the organization.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_teams: Option<Vec<Team>>,
+    /// One specific issue attachment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub attachment: Option<Box<Attachment>>,
+    /// Query an issue by its associated attachment, and its id.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub attachment_issue: Option<Box<Issue>>,
+    /// \[Internal\] Get a list of all unique attachment sources in the workspace.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub attachment_sources: Option<Box<AttachmentSourcesPayload>>,
+    /// All issue attachments.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub attachments: Option<Box<AttachmentConnection>>,
+    /// Returns issue attachments for a given `url`.
+    #[serde(rename = "attachmentsForURL", default, skip_serializing_if = "Option::is_none")]
+    pub attachments_for_url: Option<Box<AttachmentConnection>>,
+    /// All audit log entries.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub audit_entries: Option<Box<AuditEntryConnection>>,
+    /// List of audit entry types.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub audit_entry_types: Option<Vec<AuditEntryType>>,
+    /// User's active sessions.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub authentication_sessions: Option<Vec<AuthenticationSessionResponse>>,
+    /// Fetch users belonging to this user account.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub available_users: Option<Box<AuthResolverResponse>>,
+    /// A specific comment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<Box<Comment>>,
+    /// All comments.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comments: Option<Box<CommentConnection>>,
+    /// One specific custom view.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub custom_view: Option<Box<CustomView>>,
+    /// \[INTERNAL\] Suggests metadata for a view based on it's filters.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub custom_view_details_suggestion: Option<Box<CustomViewSuggestionPayload>>,
+    /// Whether a custom view has other subscribers than the current user in the organization.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub custom_view_has_subscribers: Option<Box<CustomViewHasSubscribersPayload>>,
+    /// Custom views for the user.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub custom_views: Option<Box<CustomViewConnection>>,
+    /// One specific customer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer: Option<Box<Customer>>,
+    /// One specific customer need
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer_need: Option<Box<CustomerNeed>>,
+    /// All customer needs.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer_needs: Option<Box<CustomerNeedConnection>>,
+    /// One specific customer status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer_status: Option<Box<CustomerStatus>>,
+    /// All customer statuses.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer_statuses: Option<Box<CustomerStatusConnection>>,
+    /// One specific customer tier.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer_tier: Option<Box<CustomerTier>>,
+    /// All customer tiers.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer_tiers: Option<Box<CustomerTierConnection>>,
+    /// All customers.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customers: Option<Box<CustomerConnection>>,
+    /// One specific cycle.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cycle: Option<Box<Cycle>>,
+    /// All cycles.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cycles: Option<Box<CycleConnection>>,
+    /// One specific document.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub document: Option<Box<Document>>,
+    /// A collection of document content history entries.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub document_content_history: Option<Box<DocumentContentHistoryPayload>>,
+    /// All documents in the workspace.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub documents: Option<Box<DocumentConnection>>,
+    /// One specific email intake address.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email_intake_address: Option<Box<EmailIntakeAddress>>,
+    /// A specific emoji.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub emoji: Option<Box<Emoji>>,
+    /// All custom emojis.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub emojis: Option<Box<EmojiConnection>>,
+    /// One specific entity link.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub entity_external_link: Option<Box<EntityExternalLink>>,
+    /// One specific external user.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub external_user: Option<Box<ExternalUser>>,
+    /// All external users for the organization.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub external_users: Option<Box<ExternalUserConnection>>,
+    /// \[INTERNAL\] Webhook failure events for webhooks that belong to an OAuth application. (last 50)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub failures_for_oauth_webhooks: Option<Vec<WebhookFailureEvent>>,
+    /// One specific favorite.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub favorite: Option<Box<Favorite>>,
+    /// The user's favorites.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub favorites: Option<Box<FavoriteConnection>>,
+    /// \[Internal\] Fetch an arbitrary set of data using natural language query. Be specific about what you want including properties for each entity, sort order, filters, limit and properties.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fetch_data: Option<Box<FetchDataPayload>>,
+    /// One specific initiative.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative: Option<Box<Initiative>>,
+    /// One specific initiative relation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative_relation: Option<Box<ProjectRelation>>,
+    /// All initiative relationships.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative_relations: Option<Box<InitiativeRelationConnection>>,
+    /// One specific initiativeToProject.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative_to_project: Option<Box<InitiativeToProject>>,
+    /// returns a list of initiative to project entities.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative_to_projects: Option<Box<InitiativeToProjectConnection>>,
+    /// A specific  initiative update.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative_update: Option<Box<InitiativeUpdate>>,
+    /// All  InitiativeUpdates.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative_updates: Option<Box<InitiativeUpdateConnection>>,
+    /// All initiatives in the workspace.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiatives: Option<Box<InitiativeConnection>>,
+    /// One specific integration.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integration: Option<Box<Integration>>,
+    /// Checks if the integration has all required scopes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integration_has_scopes: Option<Box<IntegrationHasScopesPayload>>,
+    /// One specific integrationTemplate.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integration_template: Option<Box<IntegrationTemplate>>,
+    /// Template and integration connections.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integration_templates: Option<Box<IntegrationTemplateConnection>>,
+    /// All integrations.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integrations: Option<Box<IntegrationConnection>>,
+    /// One specific set of settings.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integrations_settings: Option<Box<IntegrationsSettings>>,
+    /// One specific issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue: Option<Box<Issue>>,
+    /// Find issues that are related to a given Figma file key.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_figma_file_key_search: Option<Box<IssueConnection>>,
+    /// Suggests filters for an issue view based on a text prompt.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_filter_suggestion: Option<Box<IssueFilterSuggestionPayload>>,
+    /// Checks a CSV file validity against a specific import service.
+    #[serde(rename = "issueImportCheckCSV", default, skip_serializing_if = "Option::is_none")]
+    pub issue_import_check_csv: Option<Box<IssueImportCheckPayload>>,
+    /// Checks whether it will be possible to setup sync for this project or repository at the end of import
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_import_check_sync: Option<Box<IssueImportSyncCheckPayload>>,
+    /// Checks whether a custom JQL query is valid and can be used to filter issues of a Jira import
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_import_jql_check: Option<Box<IssueImportJqlCheckPayload>>,
+    /// One specific label.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_label: Option<Box<IssueLabel>>,
+    /// All issue labels.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_labels: Option<Box<IssueLabelConnection>>,
+    /// Issue priority values and corresponding labels.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_priority_values: Option<Vec<IssuePriorityValue>>,
+    /// One specific issue relation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_relation: Option<Box<IssueRelation>>,
+    /// All issue relationships.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_relations: Option<Box<IssueRelationConnection>>,
+    /// \[Internal\] Returns code repositories that are most likely to be relevant for implementing an issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_repository_suggestions: Option<Box<RepositorySuggestionsPayload>>,
+    /// \[DEPRECATED\] Search issues. This endpoint is deprecated and will be removed in the future – use `searchIssues` instead.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_search: Option<Box<IssueConnection>>,
+    /// Suggests issue title based on a customer request.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_title_suggestion_from_customer_request: Option<Box<IssueTitleSuggestionFromCustomerRequestPayload>>,
+    /// \[ALPHA\] One specific issueToRelease.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_to_release: Option<Box<IssueToRelease>>,
+    /// \[ALPHA\] Returns a list of issue to release entities.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_to_releases: Option<Box<IssueToReleaseConnection>>,
+    /// Find issue based on the VCS branch name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_vcs_branch_search: Option<Box<Issue>>,
+    /// All issues.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issues: Option<Box<IssueConnection>>,
+    /// One specific notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notification: Option<Box<Notification>>,
+    /// One specific notification subscription.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notification_subscription: Option<Box<NotificationSubscription>>,
+    /// The user's notification subscriptions.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notification_subscriptions: Option<Box<NotificationSubscriptionConnection>>,
+    /// All notifications.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notifications: Option<Box<NotificationConnection>>,
+    /// \[Internal\] A number of unread notifications.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notifications_unread_count: Option<i64>,
+    /// The user's organization.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization: Option<Box<Organization>>,
+    /// \[INTERNAL\] Checks whether the domain can be claimed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization_domain_claim_request: Option<Box<OrganizationDomainClaimPayload>>,
+    /// Does the organization exist.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization_exists: Option<Box<OrganizationExistsPayload>>,
+    /// One specific organization invite.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization_invite: Option<Box<OrganizationInvite>>,
+    /// One specific organization invite.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization_invite_details: Option<OrganizationInviteDetailsPayload>,
+    /// All invites for the organization.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization_invites: Option<Box<OrganizationInviteConnection>>,
+    /// \[INTERNAL\] Get organization metadata by urlKey or organization id.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization_meta: Option<Box<OrganizationMeta>>,
+    /// One specific project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project: Option<Box<Project>>,
+    /// Suggests filters for a project view based on a text prompt.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_filter_suggestion: Option<Box<ProjectFilterSuggestionPayload>>,
+    /// One specific label.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_label: Option<Box<ProjectLabel>>,
+    /// All project labels.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_labels: Option<Box<ProjectLabelConnection>>,
+    /// One specific project milestone.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_milestone: Option<Box<ProjectMilestone>>,
+    /// All milestones for the project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_milestones: Option<Box<ProjectMilestoneConnection>>,
+    /// One specific project relation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_relation: Option<Box<ProjectRelation>>,
+    /// All project relationships.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_relations: Option<Box<ProjectRelationConnection>>,
+    /// One specific project status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_status: Option<Box<ProjectStatus>>,
+    /// \[INTERNAL\] Count of projects using this project status across the organization.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_status_project_count: Option<Box<ProjectStatusCountPayload>>,
+    /// All project statuses.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_statuses: Option<Box<ProjectStatusConnection>>,
+    /// A specific project update.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_update: Option<Box<ProjectUpdate>>,
+    /// All project updates.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_updates: Option<Box<ProjectUpdateConnection>>,
+    /// All projects.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub projects: Option<Box<ProjectConnection>>,
+    /// Sends a test push message.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub push_subscription_test: Option<Box<PushSubscriptionTestPayload>>,
+    /// The status of the rate limiter.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rate_limit_status: Option<Box<RateLimitPayload>>,
+    /// \[ALPHA\] One specific release.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub release: Option<Box<Release>>,
+    /// \[ALPHA\] One specific release pipeline.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub release_pipeline: Option<Box<ReleasePipeline>>,
+    /// \[ALPHA\] All release pipelines.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub release_pipelines: Option<Box<ReleasePipelineConnection>>,
+    /// \[ALPHA\] One specific release stage.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub release_stage: Option<Box<ReleaseStage>>,
+    /// \[ALPHA\] All release stages.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub release_stages: Option<Box<ReleaseStageConnection>>,
+    /// \[ALPHA\] All releases.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub releases: Option<Box<ReleaseConnection>>,
+    /// One specific roadmap.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub roadmap: Option<Box<Roadmap>>,
+    /// One specific roadmapToProject.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub roadmap_to_project: Option<Box<RoadmapToProject>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub roadmap_to_projects: Option<Box<RoadmapToProjectConnection>>,
+    /// All roadmaps in the workspace.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub roadmaps: Option<Box<RoadmapConnection>>,
+    /// Search documents.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub search_documents: Option<Box<DocumentSearchPayload>>,
+    /// Search issues.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub search_issues: Option<Box<IssueSearchPayload>>,
+    /// Search projects.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub search_projects: Option<Box<ProjectSearchPayload>>,
+    /// Search for various resources using natural language.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub semantic_search: Option<Box<SemanticSearchPayload>>,
+    /// Fetch SSO login URL for the email provided.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sso_url_from_email: Option<Box<SsoUrlFromEmailResponse>>,
+    /// One specific team.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team: Option<Box<Team>>,
+    /// One specific team membership.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team_membership: Option<Box<TeamMembership>>,
+    /// All team memberships.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team_memberships: Option<Box<TeamMembershipConnection>>,
+    /// All teams whose issues can be accessed by the user. This might be different from `administrableTeams`, which also includes teams whose settings can be changed by the user.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub teams: Option<Box<TeamConnection>>,
+    /// A specific template.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub template: Option<Box<Template>>,
+    /// All templates from all users.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub templates: Option<Vec<Template>>,
+    /// Returns all templates that are associated with the integration type.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub templates_for_integration: Option<Vec<Template>>,
+    /// A specific time schedule.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time_schedule: Option<Box<TimeSchedule>>,
+    /// All time schedules.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time_schedules: Option<Box<TimeScheduleConnection>>,
+    /// All triage responsibilities.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub triage_responsibilities: Option<Box<TriageResponsibilityConnection>>,
+    /// A specific triage responsibility.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub triage_responsibility: Option<Box<TriageResponsibility>>,
+    /// One specific user.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<Box<User>>,
+    /// The user's settings.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_settings: Option<Box<UserSettings>>,
+    /// All users for the organization.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub users: Option<Box<UserConnection>>,
+    /// Verify that we received the correct response from the GitHub Enterprise Server.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verify_git_hub_enterprise_server_installation: Option<Box<GitHubEnterpriseServerInstallVerificationPayload>>,
+    /// The currently authenticated user.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub viewer: Option<Box<User>>,
+    /// A specific webhook.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub webhook: Option<Box<Webhook>>,
+    /// All webhooks.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub webhooks: Option<Box<WebhookConnection>>,
+    /// One specific state.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub workflow_state: Option<Box<WorkflowState>>,
+    /// All issue workflow states.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub workflow_states: Option<Box<WorkflowStateConnection>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RateLimitPayload {
+    /// The identifier we rate limit on.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub identifier: Option<String>,
+    /// The kind of rate limit selected for this request.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
+    /// The state of the rate limit.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub limits: Option<Vec<RateLimitResultPayload>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RateLimitResultPayload {
+    /// The total allowed quantity for this type of limit.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allowed_amount: Option<f64>,
+    /// The period in which the rate limit is fully replenished in ms.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub period: Option<f64>,
+    /// The remaining quantity for this type of limit after this request.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub remaining_amount: Option<f64>,
+    /// The requested quantity for this type of limit.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub requested_amount: Option<f64>,
+    /// The timestamp after the rate limit is fully replenished as a UNIX timestamp.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reset: Option<f64>,
+    /// What is being rate limited.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+}
+
+/// A reaction associated with a comment or a project update.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Reaction {
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// The comment that the reaction is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<Box<Comment>>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// Name of the reaction's emoji.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub emoji: Option<String>,
+    /// The external user that created the reaction.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub external_user: Option<Box<ExternalUser>>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The initiative update that the reaction is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative_update: Option<Box<InitiativeUpdate>>,
+    /// The issue that the reaction is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue: Option<Box<Issue>>,
+    /// The post that the reaction is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub post: Option<Box<Post>>,
+    /// The project update that the reaction is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_update: Option<Box<ProjectUpdate>>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+    /// The user that created the reaction.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<Box<User>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReactionPayload {
+    /// The identifier of the last sync operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_sync_id: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reaction: Option<Box<Reaction>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+}
+
+/// Payload for a reaction webhook.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReactionWebhookPayload {
+    /// The time at which the entity was archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<String>,
+    /// The comment the reaction is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<Box<CommentChildWebhookPayload>>,
+    /// The ID of the comment that the reaction is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment_id: Option<String>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    /// Name of the reaction's emoji.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub emoji: Option<String>,
+    /// The ID of the external user that created the reaction.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub external_user_id: Option<String>,
+    /// The ID of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The ID of the initiative update that the reaction is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative_update_id: Option<String>,
+    /// The issue the reaction is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue: Option<Box<IssueChildWebhookPayload>>,
+    /// The ID of the issue that the reaction is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_id: Option<String>,
+    /// The ID of the post that the reaction is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub post_id: Option<String>,
+    /// The project update the reaction is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_update: Option<Box<ProjectUpdateChildWebhookPayload>>,
+    /// The ID of the project update that the reaction is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_update_id: Option<String>,
+    /// The time at which the entity was updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+    /// The user that created the reaction.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<Box<UserChildWebhookPayload>>,
+    /// The ID of the user that created the reaction.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<String>,
+}
+
+/// \[Internal\] A release.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Release {
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// The time at which the release was canceled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub canceled_at: Option<DateTime>,
+    /// The commit SHA associated with this release.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub commit_sha: Option<String>,
+    /// The time at which the release was completed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub completed_at: Option<DateTime>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The name of the release.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The pipeline this release belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pipeline: Option<Box<ReleasePipeline>>,
+    /// The release's unique URL slug.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slug_id: Option<String>,
+    /// The current stage of the release.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stage: Option<Box<ReleaseStage>>,
+    /// The estimated start date of the release.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_date: Option<TimelessDate>,
+    /// The time at which the release was started.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub started_at: Option<DateTime>,
+    /// The estimated completion date of the release.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_date: Option<TimelessDate>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+    /// The version of the release.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+}
+
+/// A generic payload return from entity archive mutations.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReleaseArchivePayload {
+    /// The archived/unarchived entity. Null if entity was deleted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub entity: Option<Box<Release>>,
+    /// The identifier of the last sync operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_sync_id: Option<f64>,
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReleaseConnection {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub edges: Option<Vec<ReleaseEdge>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nodes: Option<Vec<Release>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page_info: Option<Box<PageInfo>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReleaseEdge {
+    /// Used in `before` and `after` args
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub node: Option<Box<Release>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReleasePayload {
+    /// The identifier of the last sync operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_sync_id: Option<f64>,
+    /// The release that was created or updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub release: Option<Box<Release>>,
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+}
+
+/// \[Internal\] A release pipeline.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReleasePipeline {
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The name of the pipeline.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// \[ALPHA\] Releases associated with this pipeline.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub releases: Option<Box<ReleaseConnection>>,
+    /// The pipeline's unique slug identifier.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slug_id: Option<String>,
+    /// \[ALPHA\] Stages associated with this pipeline.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stages: Option<Box<ReleaseStageConnection>>,
+    /// The type of the pipeline.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<ReleasePipelineType>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+}
+
+/// A generic payload return from entity archive mutations.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReleasePipelineArchivePayload {
+    /// The archived/unarchived entity. Null if entity was deleted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub entity: Option<Box<ReleasePipeline>>,
+    /// The identifier of the last sync operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_sync_id: Option<f64>,
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReleasePipelineConnection {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub edges: Option<Vec<ReleasePipelineEdge>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nodes: Option<Vec<ReleasePipeline>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page_info: Option<Box<PageInfo>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReleasePipelineEdge {
+    /// Used in `before` and `after` args
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub node: Option<Box<ReleasePipeline>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReleasePipelinePayload {
+    /// The identifier of the last sync operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_sync_id: Option<f64>,
+    /// The release pipeline that was created or updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub release_pipeline: Option<Box<ReleasePipeline>>,
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+}
+
+/// \[Internal\] A release stage.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReleaseStage {
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// The UI color of the stage as a HEX string.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The name of the stage.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The pipeline this stage belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pipeline: Option<Box<ReleasePipeline>>,
+    /// The position of the stage.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub position: Option<f64>,
+    /// \[ALPHA\] Releases associated with this stage.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub releases: Option<Box<ReleaseConnection>>,
+    /// The type of the stage.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<ReleaseStageType>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+}
+
+/// A generic payload return from entity archive mutations.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReleaseStageArchivePayload {
+    /// The archived/unarchived entity. Null if entity was deleted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub entity: Option<Box<ReleaseStage>>,
+    /// The identifier of the last sync operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_sync_id: Option<f64>,
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReleaseStageConnection {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub edges: Option<Vec<ReleaseStageEdge>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nodes: Option<Vec<ReleaseStage>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page_info: Option<Box<PageInfo>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReleaseStageEdge {
+    /// Used in `before` and `after` args
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub node: Option<Box<ReleaseStage>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReleaseStagePayload {
+    /// The identifier of the last sync operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_sync_id: Option<f64>,
+    /// The release stage that was created or updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub release_stage: Option<Box<ReleaseStage>>,
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RepositorySuggestion {
+    /// Confidence score from 0.0 to 1.0.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<f64>,
+    /// Hostname of the Git service (e.g., 'github.com', 'github.company.com').
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hostname: Option<String>,
+    /// The full name of the repository in owner/name format (e.g., 'acme/backend').
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub repository_full_name: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RepositorySuggestionsPayload {
+    /// The suggested repositories.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub suggestions: Option<Vec<RepositorySuggestion>>,
+}
+
+/// \[Deprecated\] A roadmap for projects.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Roadmap {
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// The roadmap's color.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// The user who created the roadmap.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub creator: Option<Box<User>>,
+    /// The description of the roadmap.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The name of the roadmap.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The organization of the roadmap.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization: Option<Box<Organization>>,
+    /// The user who owns the roadmap.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner: Option<Box<User>>,
+    /// Projects associated with the roadmap.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub projects: Option<Box<ProjectConnection>>,
+    /// The roadmap's unique URL slug.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slug_id: Option<String>,
+    /// The sort order of the roadmap within the organization.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort_order: Option<f64>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+    /// The canonical url for the roadmap.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+/// A generic payload return from entity archive mutations.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RoadmapArchivePayload {
+    /// The archived/unarchived entity. Null if entity was deleted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub entity: Option<Box<Roadmap>>,
+    /// The identifier of the last sync operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_sync_id: Option<f64>,
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RoadmapConnection {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub edges: Option<Vec<RoadmapEdge>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nodes: Option<Vec<Roadmap>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page_info: Option<Box<PageInfo>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RoadmapEdge {
+    /// Used in `before` and `after` args
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub node: Option<Box<Roadmap>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RoadmapPayload {
+    /// The identifier of the last sync operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_sync_id: Option<f64>,
+    /// The roadmap that was created or updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub roadmap: Option<Box<Roadmap>>,
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+}
+
+/// \[Deprecated\] Join table between projects and roadmaps.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RoadmapToProject {
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The project that the roadmap is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project: Option<Box<Project>>,
+    /// The roadmap that the project is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub roadmap: Option<Box<Roadmap>>,
+    /// The sort order of the project within the roadmap.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort_order: Option<String>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RoadmapToProjectConnection {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub edges: Option<Vec<RoadmapToProjectEdge>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nodes: Option<Vec<RoadmapToProject>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page_info: Option<Box<PageInfo>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RoadmapToProjectEdge {
+    /// Used in `before` and `after` args
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub node: Option<Box<RoadmapToProject>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RoadmapToProjectPayload {
+    /// The identifier of the last sync operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_sync_id: Option<f64>,
+    /// The roadmapToProject that was created or updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub roadmap_to_project: Option<Box<RoadmapToProject>>,
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+}
+
+/// Payload returned by semantic search.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SemanticSearchPayload {
+    /// Whether the semantic search is enabled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub results: Option<Vec<SemanticSearchResult>>,
+}
+
+/// A semantic search result reference.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SemanticSearchResult {
+    /// The document related to the semantic search result.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub document: Option<Box<Document>>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The initiative related to the semantic search result.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative: Option<Box<Initiative>>,
+    /// The issue related to the semantic search result.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue: Option<Box<Issue>>,
+    /// The project related to the semantic search result.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project: Option<Box<Project>>,
+    /// The type of the semantic search result.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<SemanticSearchResultType>,
+}
+
+/// SES domain identity used for sending emails from a custom domain.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SesDomainIdentity {
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// Whether the domain is fully verified and can be used for sending emails.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub can_send_from_custom_domain: Option<bool>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// The user who created the SES domain identity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub creator: Option<Box<User>>,
+    /// The DNS records for the SES domain identity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dns_records: Option<Vec<SesDomainIdentityDnsRecord>>,
+    /// The domain of the SES domain identity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub domain: Option<String>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The organization of the SES domain identity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization: Option<Box<Organization>>,
+    /// The AWS region of the SES domain identity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub region: Option<String>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+}
+
+/// A DNS record for a SES domain identity.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SesDomainIdentityDnsRecord {
+    /// The content of the DNS record.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+    /// Whether the DNS record is verified in the domain's DNS configuration.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_verified: Option<bool>,
+    /// The name of the DNS record.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The type of the DNS record.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+}
+
+/// Tuple for mapping Slack channel IDs to names.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SlackAsksTeamSettings {
+    /// Whether the default Asks template is enabled in the given channel for this team.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_default_ask: Option<bool>,
+    /// The Linear team ID.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SlackChannelConnectPayload {
+    /// Whether the bot needs to be manually added to the channel.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub add_bot: Option<bool>,
+    /// The integration that was created or updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integration: Option<Box<Integration>>,
+    /// The identifier of the last sync operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_sync_id: Option<f64>,
+    /// Whether it's recommended to connect main Slack integration.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nudge_to_connect_main_slack_integration: Option<bool>,
+    /// Whether it's recommended to update main Slack integration.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nudge_to_update_main_slack_integration: Option<bool>,
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+}
+
+/// Object for mapping Slack channel IDs to names and other settings.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SlackChannelNameMapping {
+    /// Whether or not to use AI to generate titles for Asks created in this channel.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ai_titles: Option<bool>,
+    /// Whether or not @-mentioning the bot should automatically create an Ask with the message.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_create_on_bot_mention: Option<bool>,
+    /// Whether or not using the :ticket: emoji in this channel should automatically create Asks.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_create_on_emoji: Option<bool>,
+    /// Whether or not top-level messages in this channel should automatically create Asks.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_create_on_message: Option<bool>,
+    /// The optional template ID to use for Asks auto-created in this channel. If not set, auto-created Asks won't use any template.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_create_template_id: Option<String>,
+    /// Whether or not the Linear Asks bot has been added to this Slack channel.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bot_added: Option<bool>,
+    /// The Slack channel ID.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// Whether or not the Slack channel is private.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_private: Option<bool>,
+    /// Whether or not the Slack channel is shared with an external org.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_shared: Option<bool>,
+    /// The Slack channel name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Whether or not synced Slack threads should be updated with a message when their Ask is accepted from triage.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub post_accepted_from_triage_updates: Option<bool>,
+    /// Whether or not synced Slack threads should be updated with a message and emoji when their Ask is canceled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub post_cancellation_updates: Option<bool>,
+    /// Whether or not synced Slack threads should be updated with a message and emoji when their Ask is completed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub post_completion_updates: Option<bool>,
+    /// Which teams are connected to the channel and settings for those teams.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub teams: Option<Vec<SlackAsksTeamSettings>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SsoUrlFromEmailResponse {
+    /// SAML SSO sign-in URL.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub saml_sso_url: Option<String>,
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SuccessPayload {
+    /// The identifier of the last sync operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_sync_id: Option<f64>,
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+}
+
+/// A comment thread that is synced with an external source.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncedExternalThread {
+    /// The display name of the thread.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// Whether this thread is syncing with the external service.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_connected: Option<bool>,
+    /// Whether the current user has the corresponding personal integration connected for the external service.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_personal_integration_connected: Option<bool>,
+    /// Whether a connected personal integration is required to comment in this thread.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_personal_integration_required: Option<bool>,
+    /// The display name of the source.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The sub type of the external source.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sub_type: Option<String>,
+    /// The type of the external source.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+    /// The external url of the thread.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+/// An organizational unit that contains issues.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Team {
+    /// Team's currently active cycle.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub active_cycle: Option<Box<Cycle>>,
+    /// Whether to enable AI discussion summaries for issues in this team.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ai_discussion_summaries_enabled: Option<bool>,
+    /// Whether to enable resolved thread AI summaries.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ai_thread_summaries_enabled: Option<bool>,
+    /// Whether all members in the workspace can join the team. Only used for public teams.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub all_members_can_join: Option<bool>,
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// Period after which automatically closed and completed issues are automatically archived in months.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_archive_period: Option<f64>,
+    /// Whether child issues should automatically close when their parent issue is closed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_close_child_issues: Option<bool>,
+    /// Whether parent issues should automatically close when all child issues are closed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_close_parent_issues: Option<bool>,
+    /// Period after which issues are automatically closed in months. Null/undefined means disabled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_close_period: Option<f64>,
+    /// The canceled workflow state which auto closed issues will be set to. Defaults to the first canceled state.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_close_state_id: Option<String>,
+    /// \[Internal\] The team's sub-teams.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub children: Option<Vec<Team>>,
+    /// The team's color.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// \[Internal\] The current progress of the team.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub current_progress: Option<JSONObject>,
+    /// Calendar feed URL (iCal) for cycles.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cycle_calender_url: Option<String>,
+    /// The cooldown time after each cycle in weeks.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cycle_cooldown_time: Option<f64>,
+    /// The duration of a cycle in weeks.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cycle_duration: Option<f64>,
+    /// Auto assign completed issues to current cycle.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cycle_issue_auto_assign_completed: Option<bool>,
+    /// Auto assign started issues to current cycle.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cycle_issue_auto_assign_started: Option<bool>,
+    /// Auto assign issues to current cycle if in active status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cycle_lock_to_active: Option<bool>,
+    /// The day of the week that a new cycle starts.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cycle_start_day: Option<f64>,
+    /// Cycles associated with the team.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cycles: Option<Box<CycleConnection>>,
+    /// Whether the team uses cycles.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cycles_enabled: Option<bool>,
+    /// What to use as a default estimate for unestimated issues.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_issue_estimate: Option<f64>,
+    /// The default workflow state into which issues are set when they are opened by team members.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_issue_state: Option<Box<WorkflowState>>,
+    /// The default template to use for new projects created for the team.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_project_template: Option<Box<Template>>,
+    /// The default template to use for new issues created by members of the team.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_template_for_members: Option<Box<Template>>,
+    /// The id of the default template to use for new issues created by members of the team.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_template_for_members_id: Option<String>,
+    /// The default template to use for new issues created by non-members of the team.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_template_for_non_members: Option<Box<Template>>,
+    /// The id of the default template to use for new issues created by non-members of the team.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_template_for_non_members_id: Option<String>,
+    /// The team's description.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The name of the team including its parent team name if it has one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    /// The workflow state into which issues are moved when a PR has been opened as draft.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub draft_workflow_state: Option<Box<WorkflowState>>,
+    /// \[Internal\] Facets associated with the team.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub facets: Option<Vec<Facet>>,
+    /// The Git automation states for the team.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub git_automation_states: Option<Box<GitAutomationStateConnection>>,
+    /// Whether to group recent issue history entries.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub group_issue_history: Option<bool>,
+    /// The icon of the team.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub icon: Option<String>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// Whether the team should inherit its estimation settings from its parent. Only applies to sub-teams.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inherit_issue_estimation: Option<bool>,
+    /// Whether the team should inherit its workflow statuses from its parent. Only applies to sub-teams.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inherit_workflow_statuses: Option<bool>,
+    /// Settings for all integrations associated with that team.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integrations_settings: Option<Box<IntegrationsSettings>>,
+    /// \[DEPRECATED\] Unique hash for the team to be used in invite URLs.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub invite_hash: Option<String>,
+    /// Number of issues in the team.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_count: Option<i64>,
+    /// Whether to allow zeros in issues estimates.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_estimation_allow_zero: Option<bool>,
+    /// Whether to add additional points to the estimate scale.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_estimation_extended: Option<bool>,
+    /// The issue estimation type to use. Must be one of "notUsed", "exponential", "fibonacci", "linear", "tShirt".
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_estimation_type: Option<String>,
+    /// \[DEPRECATED\] Whether issues without priority should be sorted first.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_ordering_no_priority_first: Option<bool>,
+    /// \[DEPRECATED\] Whether to move issues to bottom of the column when changing state.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_sort_order_default_to_bottom: Option<bool>,
+    /// Issues associated with the team.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issues: Option<Box<IssueConnection>>,
+    /// \[Internal\] Whether new users should join this team by default.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub join_by_default: Option<bool>,
+    /// The team's unique key. The key is used in URLs.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key: Option<String>,
+    /// Labels associated with the team.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<Box<IssueLabelConnection>>,
+    /// The workflow state into which issues are moved when they are marked as a duplicate of another issue. Defaults to the first canceled state.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub marked_as_duplicate_workflow_state: Option<Box<WorkflowState>>,
+    /// Users who are members of this team.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub members: Option<Box<UserConnection>>,
+    /// \[ALPHA\] The membership of the given user in the team.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub membership: Option<Box<TeamMembership>>,
+    /// Memberships associated with the team. For easier access of the same data, use `members` query.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub memberships: Option<Box<TeamMembershipConnection>>,
+    /// The workflow state into which issues are moved when a PR has been merged.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub merge_workflow_state: Option<Box<WorkflowState>>,
+    /// The workflow state into which issues are moved when a PR is ready to be merged.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mergeable_workflow_state: Option<Box<WorkflowState>>,
+    /// The team's name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The organization that the team is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization: Option<Box<Organization>>,
+    /// \[Internal\] The team's parent team.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent: Option<Box<Team>>,
+    /// \[Internal\] Posts associated with the team.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub posts: Option<Vec<Post>>,
+    /// Whether the team is private or not.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub private: Option<bool>,
+    /// \[Internal\] The progress history of the team.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub progress_history: Option<JSONObject>,
+    /// Projects associated with the team.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub projects: Option<Box<ProjectConnection>>,
+    /// Whether an issue needs to have a priority set before leaving triage.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub require_priority_to_leave_triage: Option<bool>,
+    /// The workflow state into which issues are moved when a review has been requested for the PR.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub review_workflow_state: Option<Box<WorkflowState>>,
+    /// The SCIM group name for the team.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scim_group_name: Option<String>,
+    /// Whether the team is managed by SCIM integration.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scim_managed: Option<bool>,
+    /// Security settings for the team.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub security_settings: Option<JSONObject>,
+    /// Where to move issues when changing state.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub set_issue_sort_order_on_state_change: Option<String>,
+    /// Whether to send new issue comment notifications to Slack.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slack_issue_comments: Option<bool>,
+    /// Whether to send new issue status updates to Slack.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slack_issue_statuses: Option<bool>,
+    /// Whether to send new issue notifications to Slack.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slack_new_issue: Option<bool>,
+    /// The workflow state into which issues are moved when a PR has been opened.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_workflow_state: Option<Box<WorkflowState>>,
+    /// The states that define the workflow associated with the team.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub states: Option<Box<WorkflowStateConnection>>,
+    /// Templates associated with the team.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub templates: Option<Box<TemplateConnection>>,
+    /// The timezone of the team. Defaults to "America/Los_Angeles"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+    /// Whether triage mode is enabled for the team or not.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub triage_enabled: Option<bool>,
+    /// The workflow state into which issues are set when they are opened by non-team members or integrations if triage is enabled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub triage_issue_state: Option<Box<WorkflowState>>,
+    /// Team's triage responsibility.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub triage_responsibility: Option<Box<TriageResponsibility>>,
+    /// How many upcoming cycles to create.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub upcoming_cycle_count: Option<f64>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+    /// Webhooks associated with the team.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub webhooks: Option<Box<WebhookConnection>>,
+}
+
+/// A generic payload return from entity archive mutations.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TeamArchivePayload {
+    /// The archived/unarchived entity. Null if entity was deleted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub entity: Option<Box<Team>>,
+    /// The identifier of the last sync operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_sync_id: Option<f64>,
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+}
+
+/// Certain properties of a team.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TeamChildWebhookPayload {
+    /// The ID of the team.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The key of the team.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key: Option<String>,
+    /// The name of the team.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TeamConnection {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub edges: Option<Vec<TeamEdge>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nodes: Option<Vec<Team>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page_info: Option<Box<PageInfo>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TeamEdge {
+    /// Used in `before` and `after` args
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub node: Option<Box<Team>>,
+}
+
+/// Defines the membership of a user to a team.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TeamMembership {
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// Whether the user is an owner of the team.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner: Option<bool>,
+    /// The order of the item in the users team list.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort_order: Option<f64>,
+    /// The team that the membership is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team: Option<Box<Team>>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+    /// The user that the membership is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<Box<User>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TeamMembershipConnection {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub edges: Option<Vec<TeamMembershipEdge>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nodes: Option<Vec<TeamMembership>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page_info: Option<Box<PageInfo>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TeamMembershipEdge {
+    /// Used in `before` and `after` args
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub node: Option<Box<TeamMembership>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TeamMembershipPayload {
+    /// The identifier of the last sync operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_sync_id: Option<f64>,
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+    /// The team membership that was created or updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team_membership: Option<Box<TeamMembership>>,
+}
+
+/// A team notification subscription.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TeamNotificationSubscription {
+    /// Whether the subscription is active or not.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// The type of view to which the notification subscription context is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub context_view_type: Option<ContextViewType>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// The contextual custom view associated with the notification subscription.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub custom_view: Option<Box<CustomView>>,
+    /// The customer associated with the notification subscription.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer: Option<Box<Customer>>,
+    /// The contextual cycle view associated with the notification subscription.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cycle: Option<Box<Cycle>>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The contextual initiative view associated with the notification subscription.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative: Option<Box<Initiative>>,
+    /// The contextual label view associated with the notification subscription.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<Box<IssueLabel>>,
+    /// The type of subscription.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notification_subscription_types: Option<Vec<String>>,
+    /// The contextual project view associated with the notification subscription.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project: Option<Box<Project>>,
+    /// The user that subscribed to receive notifications.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subscriber: Option<Box<User>>,
+    /// The team subscribed to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team: Option<Box<Team>>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+    /// The user view associated with the notification subscription.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<Box<User>>,
+    /// The type of user view to which the notification subscription context is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_context_view_type: Option<UserContextViewType>,
+}
+
+/// Team origin for guidance rules.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TeamOriginWebhookPayload {
+    /// The team that the guidance was defined in.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team: Option<Box<TeamWithParentWebhookPayload>>,
+    /// The type of origin, always 'Team'.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TeamPayload {
+    /// The identifier of the last sync operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_sync_id: Option<f64>,
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+    /// The team that was created or updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team: Option<Box<Team>>,
+}
+
+/// Team properties including parent information for guidance rules.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TeamWithParentWebhookPayload {
+    /// The team's display name including parent team names if applicable.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    /// The ID of the team.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The key of the team.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key: Option<String>,
+    /// The name of the team.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The parent team's unique identifier, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_id: Option<String>,
+}
+
+/// A template object used for creating entities faster.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Template {
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// The user who created the template.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub creator: Option<Box<User>>,
+    /// Template description.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// \[Internal\] Whether the template has form fields
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_form_fields: Option<bool>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The original template inherited from.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inherited_from: Option<Box<Template>>,
+    /// The date when the template was last applied.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_applied_at: Option<DateTime>,
+    /// The user who last updated the template.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_updated_by: Option<Box<User>>,
+    /// The name of the template.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The organization that the template is associated with. If null, the template is associated with a particular team.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization: Option<Box<Organization>>,
+    /// The sort order of the template.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort_order: Option<f64>,
+    /// The team that the template is associated with. If null, the template is global to the workspace.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team: Option<Box<Team>>,
+    /// Template data.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub template_data: Option<JSON>,
+    /// The entity type this template is for.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TemplateConnection {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub edges: Option<Vec<TemplateEdge>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nodes: Option<Vec<Template>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page_info: Option<Box<PageInfo>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TemplateEdge {
+    /// Used in `before` and `after` args
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub node: Option<Box<Template>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TemplatePayload {
+    /// The identifier of the last sync operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_sync_id: Option<f64>,
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+    /// The template that was created or updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub template: Option<Box<Template>>,
+}
+
+/// A time schedule.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TimeSchedule {
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// The schedule entries.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub entries: Option<Vec<TimeScheduleEntry>>,
+    /// The identifier of the external schedule.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub external_id: Option<String>,
+    /// The URL to the external schedule.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub external_url: Option<String>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The identifier of the Linear integration populating the schedule.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integration: Option<Box<Integration>>,
+    /// The name of the schedule.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The organization of the schedule.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization: Option<Box<Organization>>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TimeScheduleConnection {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub edges: Option<Vec<TimeScheduleEdge>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nodes: Option<Vec<TimeSchedule>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page_info: Option<Box<PageInfo>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TimeScheduleEdge {
+    /// Used in `before` and `after` args
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub node: Option<Box<TimeSchedule>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TimeScheduleEntry {
+    /// The end date of the schedule in ISO 8601 date-time format.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ends_at: Option<DateTime>,
+    /// The start date of the schedule in ISO 8601 date-time format.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub starts_at: Option<DateTime>,
+    /// The email, name or reference to the user on schedule. This is used in case the external user could not be mapped to a Linear user id.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_email: Option<String>,
+    /// The Linear user id of the user on schedule. If the user cannot be mapped to a Linear user then `userEmail` can be used as a reference.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TimeSchedulePayload {
+    /// The identifier of the last sync operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_sync_id: Option<f64>,
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time_schedule: Option<Box<TimeSchedule>>,
+}
+
+/// A team's triage responsibility.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TriageResponsibility {
+    /// The action to take when an issue is added to triage.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub action: Option<TriageResponsibilityAction>,
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// The user currently responsible for triage.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub current_user: Option<Box<User>>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// Set of users used for triage responsibility.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub manual_selection: Option<Box<TriageResponsibilityManualSelection>>,
+    /// The team to which the triage responsibility belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team: Option<Box<Team>>,
+    /// The time schedule used for scheduling.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time_schedule: Option<Box<TimeSchedule>>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TriageResponsibilityConnection {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub edges: Option<Vec<TriageResponsibilityEdge>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nodes: Option<Vec<TriageResponsibility>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page_info: Option<Box<PageInfo>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TriageResponsibilityEdge {
+    /// Used in `before` and `after` args
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub node: Option<Box<TriageResponsibility>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TriageResponsibilityManualSelection {
+    /// \[Internal\] The index of the current userId used for the assign action when having more than one user.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub assignment_index: Option<i64>,
+    /// The set of users responsible for triage.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_ids: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TriageResponsibilityPayload {
+    /// The identifier of the last sync operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_sync_id: Option<f64>,
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub triage_responsibility: Option<Box<TriageResponsibility>>,
+}
+
+/// Object representing Google Cloud upload policy, plus additional data.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UploadFile {
+    /// The asset URL for the uploaded file. (assigned automatically).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub asset_url: Option<String>,
+    /// The content type.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_type: Option<String>,
+    /// The filename.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filename: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub headers: Option<Vec<UploadFileHeader>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub meta_data: Option<JSONObject>,
+    /// The size of the uploaded file.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub size: Option<i64>,
+    /// The signed URL the for the uploaded file. (assigned automatically).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub upload_url: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UploadFileHeader {
+    /// Upload file header key.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key: Option<String>,
+    /// Upload file header value.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UploadPayload {
+    /// The identifier of the last sync operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_sync_id: Option<f64>,
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+    /// Object describing the file to be uploaded.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub upload_file: Option<Box<UploadFile>>,
+}
+
+/// A user that has access to the the resources of an organization.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct User {
+    /// Whether the user account is active or disabled (suspended).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+    /// Whether the user is an organization administrator.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub admin: Option<bool>,
+    /// Whether the user is an app.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub app: Option<bool>,
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// Issues assigned to the user.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub assigned_issues: Option<Box<IssueConnection>>,
+    /// The background color of the avatar for users without set avatar.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub avatar_background_color: Option<String>,
+    /// An URL to the user's avatar image.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub avatar_url: Option<String>,
+    /// \[DEPRECATED\] Hash for the user to be used in calendar URLs.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub calendar_hash: Option<String>,
+    /// Whether this user can access any public team in the organization.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub can_access_any_public_team: Option<bool>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// Number of issues created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_issue_count: Option<i64>,
+    /// Issues created by the user.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_issues: Option<Box<IssueConnection>>,
+    /// Issues delegated to this user.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub delegated_issues: Option<Box<IssueConnection>>,
+    /// A short description of the user, either its title or bio.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Reason why is the account disabled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub disable_reason: Option<String>,
+    /// The user's display (nick) name. Unique within each organization.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    /// The user's drafts
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub drafts: Option<Box<DraftConnection>>,
+    /// The user's email address.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    /// \[INTERNAL\] The user's pinned feeds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub feed_facets: Option<Box<FacetConnection>>,
+    /// The user's GitHub user ID.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub git_hub_user_id: Option<String>,
+    /// Whether the user is a guest in the workspace and limited to accessing a subset of teams.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub guest: Option<bool>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// \[INTERNAL\] Identity provider the user is managed by.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub identity_provider: Option<Box<IdentityProvider>>,
+    /// The initials of the user.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initials: Option<String>,
+    /// \[DEPRECATED\] Unique hash for the user to be used in invite URLs.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub invite_hash: Option<String>,
+    /// Whether the user is assignable.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_assignable: Option<bool>,
+    /// Whether the user is the currently authenticated user.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_me: Option<bool>,
+    /// Whether the user is mentionable.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_mentionable: Option<bool>,
+    /// The user's issue drafts
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_drafts: Option<Box<IssueDraftConnection>>,
+    /// The last time the user was seen online.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_seen: Option<DateTime>,
+    /// The user's full name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Organization the user belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization: Option<Box<Organization>>,
+    /// Whether the user is an organization owner.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner: Option<bool>,
+    /// The emoji to represent the user current status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status_emoji: Option<String>,
+    /// The label of the user current status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status_label: Option<String>,
+    /// A date at which the user current status should be cleared.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status_until_at: Option<DateTime>,
+    /// Whether this agent user supports agent sessions.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub supports_agent_sessions: Option<bool>,
+    /// Memberships associated with the user. For easier access of the same data, use `teams` query.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team_memberships: Option<Box<TeamMembershipConnection>>,
+    /// Teams the user is part of.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub teams: Option<Box<TeamConnection>>,
+    /// The local timezone of the user.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+    /// User's profile URL.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+/// User actor payload for webhooks.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserActorWebhookPayload {
+    /// The avatar URL of the user.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub avatar_url: Option<String>,
+    /// The email of the user.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    /// The ID of the user.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The name of the user.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The type of actor.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+    /// The URL of the user.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserAdminPayload {
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+}
+
+/// Certain properties of a user.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserChildWebhookPayload {
+    /// The avatar URL of the user.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub avatar_url: Option<String>,
+    /// The email of the user.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    /// The ID of the user.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The name of the user.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The URL of the user.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserConnection {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub edges: Option<Vec<UserEdge>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nodes: Option<Vec<User>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page_info: Option<Box<PageInfo>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserEdge {
+    /// Used in `before` and `after` args
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub node: Option<Box<User>>,
+}
+
+/// A user notification subscription.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserNotificationSubscription {
+    /// Whether the subscription is active or not.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// The type of view to which the notification subscription context is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub context_view_type: Option<ContextViewType>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// The contextual custom view associated with the notification subscription.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub custom_view: Option<Box<CustomView>>,
+    /// The customer associated with the notification subscription.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer: Option<Box<Customer>>,
+    /// The contextual cycle view associated with the notification subscription.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cycle: Option<Box<Cycle>>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The contextual initiative view associated with the notification subscription.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initiative: Option<Box<Initiative>>,
+    /// The contextual label view associated with the notification subscription.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<Box<IssueLabel>>,
+    /// The type of subscription.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notification_subscription_types: Option<Vec<String>>,
+    /// The contextual project view associated with the notification subscription.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project: Option<Box<Project>>,
+    /// The user that subscribed to receive notifications.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subscriber: Option<Box<User>>,
+    /// The team associated with the notification subscription.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team: Option<Box<Team>>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+    /// The user subscribed to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<Box<User>>,
+    /// The type of user view to which the notification subscription context is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_context_view_type: Option<UserContextViewType>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserPayload {
+    /// The identifier of the last sync operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_sync_id: Option<f64>,
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+    /// The user that was created or updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<Box<User>>,
+}
+
+/// The settings of a user as a JSON object.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserSettings {
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// Whether to auto-assign newly created issues to the current user by default.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_assign_to_self: Option<bool>,
+    /// Hash for the user to be used in calendar URLs.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub calendar_hash: Option<String>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// The user's last seen time for the pulse feed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub feed_last_seen_time: Option<DateTime>,
+    /// The user's feed summary schedule preference.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub feed_summary_schedule: Option<FeedSummarySchedule>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The user's notification category preferences.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notification_category_preferences: Option<Box<NotificationCategoryPreferences>>,
+    /// The user's notification channel preferences.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notification_channel_preferences: Option<Box<NotificationChannelPreferences>>,
+    /// The notification delivery preferences for the user. Note: notificationDisabled field is deprecated in favor of notificationChannelPreferences.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notification_delivery_preferences: Option<Box<NotificationDeliveryPreferences>>,
+    /// Whether to show full user names instead of display names.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub show_full_user_names: Option<bool>,
+    /// Whether this user is subscribed to changelog email or not.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subscribed_to_changelog: Option<bool>,
+    /// Whether this user is subscribed to DPA emails or not.
+    #[serde(rename = "subscribedToDPA", default, skip_serializing_if = "Option::is_none")]
+    pub subscribed_to_dpa: Option<bool>,
+    /// Whether this user is subscribed to invite accepted emails or not.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subscribed_to_invite_accepted: Option<bool>,
+    /// Whether this user is subscribed to privacy and legal update emails or not.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subscribed_to_privacy_legal_updates: Option<bool>,
+    /// The user's theme for a given mode and device type.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub theme: Option<Box<UserSettingsTheme>>,
+    /// The email types the user has unsubscribed from.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unsubscribed_from: Option<Vec<String>>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+    /// The user associated with these settings.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<Box<User>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserSettingsCustomSidebarTheme {
+    /// The accent color in LCH format.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub accent: Option<Vec<f64>>,
+    /// The base color in LCH format.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub base: Option<Vec<f64>>,
+    /// The contrast value.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub contrast: Option<i64>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserSettingsCustomTheme {
+    /// The accent color in LCH format.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub accent: Option<Vec<f64>>,
+    /// The base color in LCH format.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub base: Option<Vec<f64>>,
+    /// The contrast value.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub contrast: Option<i64>,
+    /// Optional sidebar theme colors.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sidebar: Option<Box<UserSettingsCustomSidebarTheme>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserSettingsFlagPayload {
+    /// The flag key which was updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub flag: Option<String>,
+    /// The identifier of the last sync operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_sync_id: Option<f64>,
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+    /// The flag value after update.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<i64>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserSettingsFlagsResetPayload {
+    /// The identifier of the last sync operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_sync_id: Option<f64>,
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserSettingsPayload {
+    /// The identifier of the last sync operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_sync_id: Option<f64>,
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+    /// The user's settings.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_settings: Option<Box<UserSettings>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserSettingsTheme {
+    /// The custom theme definition, only present when preset is 'custom'.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub custom: Option<Box<UserSettingsCustomTheme>>,
+    /// The theme preset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub preset: Option<UserSettingsThemePreset>,
+}
+
+/// Payload for a user webhook.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserWebhookPayload {
+    /// Whether the user is active.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+    /// Whether the user is an admin.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub admin: Option<bool>,
+    /// Whether the user is an app.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub app: Option<bool>,
+    /// The time at which the entity was archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<String>,
+    /// The avatar URL of the user.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub avatar_url: Option<String>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    /// The description of the user.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The reason the user is disabled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub disable_reason: Option<String>,
+    /// The display name of the user.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    /// The email of the user.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    /// Whether the user is a guest.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub guest: Option<bool>,
+    /// The ID of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The name of the user.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Whether the user is an owner.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner: Option<bool>,
+    /// The local timezone of the user.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+    /// The time at which the entity was updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+    /// The URL of the user.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+/// View preferences.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ViewPreferences {
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The view preferences
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub preferences: Option<Box<ViewPreferencesValues>>,
+    /// The view preference type.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+    /// The view type.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub view_type: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ViewPreferencesPayload {
+    /// The identifier of the last sync operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_sync_id: Option<f64>,
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+    /// The view preferences entity being mutated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub view_preferences: Option<Box<ViewPreferences>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ViewPreferencesValues {
+    /// The issue grouping.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_grouping: Option<String>,
+    /// The issue sub grouping.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_sub_grouping: Option<String>,
+    /// Whether to show completed issues.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub show_completed_issues: Option<String>,
+    /// The issue ordering.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub view_ordering: Option<String>,
+}
+
+/// A webhook used to send HTTP notifications over data updates.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Webhook {
+    /// Whether the Webhook is enabled for all public teams, including teams created after the webhook was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub all_public_teams: Option<bool>,
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// The user who created the webhook.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub creator: Option<Box<User>>,
+    /// Whether the Webhook is enabled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    /// \[INTERNAL\] Webhook failure events associated with the webhook (last 50).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub failures: Option<Vec<WebhookFailureEvent>>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// Webhook label.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    /// The resource types this webhook is subscribed to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resource_types: Option<Vec<String>>,
+    /// Secret token for verifying the origin on the recipient side.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub secret: Option<String>,
+    /// The team that the webhook is associated with. If null, the webhook is associated with all public teams of the organization or multiple teams.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team: Option<Box<Team>>,
+    /// \[INTERNAL\] The teams that the webhook is associated with. Used to represent a webhook that targets multiple teams, potentially in addition to all public teams of the organization.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team_ids: Option<Vec<String>>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+    /// Webhook URL.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookConnection {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub edges: Option<Vec<WebhookEdge>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nodes: Option<Vec<Webhook>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page_info: Option<Box<PageInfo>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookEdge {
+    /// Used in `before` and `after` args
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub node: Option<Box<Webhook>>,
+}
+
+/// Entity representing a webhook execution failure.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookFailureEvent {
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// The unique execution ID of the webhook push. This is retained between retries of the same push.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub execution_id: Option<String>,
+    /// The HTTP status code returned by the recipient.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub http_status: Option<f64>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The HTTP response body returned by the recipient or error occured.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub response_or_error: Option<String>,
+    /// The URL that the webhook was trying to push to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// The webhook that this failure event is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub webhook: Option<Box<Webhook>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookPayload {
+    /// The identifier of the last sync operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_sync_id: Option<f64>,
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+    /// The webhook entity being mutated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub webhook: Option<Box<Webhook>>,
+}
+
+/// A state in a team workflow.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkflowState {
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime>,
+    /// The state's UI color as a HEX string.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    /// The time at which the entity was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime>,
+    /// Description of the state.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The unique identifier of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The state inherited from
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inherited_from: Option<Box<WorkflowState>>,
+    /// Issues belonging in this state.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issues: Option<Box<IssueConnection>>,
+    /// The state's name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The position of the state in the team flow.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub position: Option<f64>,
+    /// The team to which this state belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team: Option<Box<Team>>,
+    /// The type of the state. One of "triage", "backlog", "unstarted", "started", "completed", "canceled".
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime>,
+}
+
+/// A generic payload return from entity archive mutations.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkflowStateArchivePayload {
+    /// The archived/unarchived entity. Null if entity was deleted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub entity: Option<Box<WorkflowState>>,
+    /// The identifier of the last sync operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_sync_id: Option<f64>,
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+}
+
+/// Certain properties of a workflow state.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkflowStateChildWebhookPayload {
+    /// The color of the workflow state.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    /// The ID of the workflow state.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The name of the workflow state.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The type of the workflow state.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkflowStateConnection {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub edges: Option<Vec<WorkflowStateEdge>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nodes: Option<Vec<WorkflowState>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page_info: Option<Box<PageInfo>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkflowStateEdge {
+    /// Used in `before` and `after` args
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub node: Option<Box<WorkflowState>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkflowStatePayload {
+    /// The identifier of the last sync operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_sync_id: Option<f64>,
+    /// Whether the operation was successful.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+    /// The state that was created or updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub workflow_state: Option<Box<WorkflowState>>,
+}
@@ -0,0 +1,82 @@
+//! Generated models: custom scalars and unions - DO NOT EDIT
+//! Run `cargo xtask codegen` to regenerate
+
+/// Content for different types of agent activities.
+///
+/// Union of AgentActivityActionContent, AgentActivityElicitationContent, AgentActivityErrorContent, AgentActivityPromptContent, AgentActivityResponseContent, AgentActivityThoughtContent; kept as raw JSON
+pub type AgentActivityContent = serde_json::Value;
+
+/// Union type for all possible webhook entity data payloads
+///
+/// Union of AgentActivityWebhookPayload, AgentSessionWebhookPayload, AttachmentWebhookPayload, AuditEntryWebhookPayload, CommentWebhookPayload, CustomerNeedWebhookPayload, CustomerWebhookPayload, CycleWebhookPayload, DocumentWebhookPayload, InitiativeUpdateWebhookPayload, InitiativeWebhookPayload, IssueLabelWebhookPayload, IssueWebhookPayload, ProjectLabelWebhookPayload, ProjectUpdateWebhookPayload, ProjectWebhookPayload, ReactionWebhookPayload, UserWebhookPayload; kept as raw JSON
+pub type DataWebhookPayload = serde_json::Value;
+
+/// Represents a date and time in ISO 8601 format. Accepts shortcuts like `2021` to represent midnight Fri Jan 01 2021. Also accepts ISO 8601 durations strings which are added to the current date to create the represented date (e.g '-P2W1D' represents the date that was two weeks and 1 day ago)
+pub type DateTime = chrono::DateTime<chrono::Utc>;
+
+/// Represents a date and time in ISO 8601 format. Accepts shortcuts like `2021` to represent midnight Fri Jan 01 2021. Also accepts ISO 8601 durations strings which are added to the current date to create the represented date (e.g '-P2W1D' represents the date that was two weeks and 1 day ago)
+pub type DateTimeOrDuration = String;
+
+/// Represents a duration in ISO 8601 format. Accepts ISO 8601 duration strings or integers in milliseconds.
+pub type Duration = String;
+
+/// Union type for webhook actor payloads
+///
+/// Union of IntegrationActorWebhookPayload, OauthClientActorWebhookPayload, UserActorWebhookPayload; kept as raw JSON
+pub type EntityActorWebhookPayload = serde_json::Value;
+
+///
+/// Union of ExternalEntityInfoGithubMetadata, ExternalEntityInfoJiraMetadata, ExternalEntitySlackMetadata; kept as raw JSON
+pub type ExternalEntityInfoMetadata = serde_json::Value;
+
+/// Union type for all possible guidance rule origins
+///
+/// Union of OrganizationOriginWebhookPayload, TeamOriginWebhookPayload; kept as raw JSON
+pub type GuidanceRuleOriginWebhookPayload = serde_json::Value;
+
+/// An issue assignment notification type.
+pub type IssueAssignedToYouNotificationType = String;
+
+/// An issue comment mention notification type.
+pub type IssueCommentMentionNotificationType = String;
+
+/// An issue comment reaction notification type.
+pub type IssueCommentReactionNotificationType = String;
+
+/// An issue emoji reaction notification type.
+pub type IssueEmojiReactionNotificationType = String;
+
+/// An issue mention notification type.
+pub type IssueMentionNotificationType = String;
+
+/// An issue new comment notification type.
+pub type IssueNewCommentNotificationType = String;
+
+/// An issue status changed notification type.
+pub type IssueStatusChangedNotificationType = String;
+
+/// An issue unassignment notification type.
+pub type IssueUnassignedFromYouNotificationType = String;
+
+/// The `JSON` scalar type represents arbitrary values as *stringified* JSON
+pub type JSON = serde_json::Value;
+
+/// The `JSONObject` scalar type represents arbitrary values as *embedded* JSON
+pub type JSONObject = serde_json::Value;
+
+///
+/// Union of IssueAssignedToYouNotificationWebhookPayload, IssueCommentMentionNotificationWebhookPayload, IssueCommentReactionNotificationWebhookPayload, IssueEmojiReactionNotificationWebhookPayload, IssueMentionNotificationWebhookPayload, IssueNewCommentNotificationWebhookPayload, IssueStatusChangedNotificationWebhookPayload, IssueUnassignedFromYouNotificationWebhookPayload, OtherNotificationWebhookPayload; kept as raw JSON
+pub type NotificationWebhookPayload = serde_json::Value;
+
+///
+/// Union of OrganizationAcceptedOrExpiredInviteDetailsPayload, OrganizationInviteFullDetailsPayload; kept as raw JSON
+pub type OrganizationInviteDetailsPayload = serde_json::Value;
+
+/// Represents a date in ISO 8601 format. Accepts shortcuts like `2021` to represent midnight Fri Jan 01 2021. Also accepts ISO 8601 durations strings which are added to the current date to create the represented date (e.g '-P2W1D' represents the date that was two weeks and 1 day ago)
+pub type TimelessDate = chrono::NaiveDate;
+
+/// Represents a date in ISO 8601 format or a duration. Accepts shortcuts like `2021` to represent midnight Fri Jan 01 2021. Also accepts ISO 8601 durations strings (e.g '-P2W1D'), which are not converted to dates.
+pub type TimelessDateOrDuration = String;
+
+/// A universally unique identifier as specified by RFC 4122.
+pub type UUID = String;
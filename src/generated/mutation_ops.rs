@@ -1,1010 +1,1009 @@
 //! Generated mutation operations - DO NOT EDIT
 //! Run `cargo xtask codegen` to regenerate
 
-use clap::ValueEnum;
-
 /// Available mutation operations derived from Linear's GraphQL schema
-#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
-#[value(rename_all = "camelCase")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
+#[cfg_attr(feature = "cli", value(rename_all = "camelCase"))]
 pub enum MutationOp {
     /// Execute agentActivityCreate mutation
-    #[value(name = "agentActivityCreate")]
+    #[cfg_attr(feature = "cli", value(name = "agentActivityCreate"))]
     AgentActivityCreate,
     /// Execute agentActivityCreatePrompt mutation
-    #[value(name = "agentActivityCreatePrompt")]
+    #[cfg_attr(feature = "cli", value(name = "agentActivityCreatePrompt"))]
     AgentActivityCreatePrompt,
     /// Execute agentSessionCreate mutation
-    #[value(name = "agentSessionCreate")]
+    #[cfg_attr(feature = "cli", value(name = "agentSessionCreate"))]
     AgentSessionCreate,
     /// Execute agentSessionCreateOnComment mutation
-    #[value(name = "agentSessionCreateOnComment")]
+    #[cfg_attr(feature = "cli", value(name = "agentSessionCreateOnComment"))]
     AgentSessionCreateOnComment,
     /// Execute agentSessionCreateOnIssue mutation
-    #[value(name = "agentSessionCreateOnIssue")]
+    #[cfg_attr(feature = "cli", value(name = "agentSessionCreateOnIssue"))]
     AgentSessionCreateOnIssue,
     /// Execute agentSessionUpdate mutation
-    #[value(name = "agentSessionUpdate")]
+    #[cfg_attr(feature = "cli", value(name = "agentSessionUpdate"))]
     AgentSessionUpdate,
     /// Execute agentSessionUpdateExternalUrl mutation
-    #[value(name = "agentSessionUpdateExternalUrl")]
+    #[cfg_attr(feature = "cli", value(name = "agentSessionUpdateExternalUrl"))]
     AgentSessionUpdateExternalUrl,
     /// Execute airbyteIntegrationConnect mutation
-    #[value(name = "airbyteIntegrationConnect")]
+    #[cfg_attr(feature = "cli", value(name = "airbyteIntegrationConnect"))]
     AirbyteIntegrationConnect,
     /// Execute asksWebFormsAuth mutation
-    #[value(name = "asksWebFormsAuth")]
+    #[cfg_attr(feature = "cli", value(name = "asksWebFormsAuth"))]
     AsksWebFormsAuth,
     /// Execute attachmentCreate mutation
-    #[value(name = "attachmentCreate")]
+    #[cfg_attr(feature = "cli", value(name = "attachmentCreate"))]
     AttachmentCreate,
     /// Execute attachmentDelete mutation
-    #[value(name = "attachmentDelete")]
+    #[cfg_attr(feature = "cli", value(name = "attachmentDelete"))]
     AttachmentDelete,
     /// Execute attachmentLinkDiscord mutation
-    #[value(name = "attachmentLinkDiscord")]
+    #[cfg_attr(feature = "cli", value(name = "attachmentLinkDiscord"))]
     AttachmentLinkDiscord,
     /// Execute attachmentLinkFront mutation
-    #[value(name = "attachmentLinkFront")]
+    #[cfg_attr(feature = "cli", value(name = "attachmentLinkFront"))]
     AttachmentLinkFront,
     /// Execute attachmentLinkGitHubIssue mutation
-    #[value(name = "attachmentLinkGitHubIssue")]
+    #[cfg_attr(feature = "cli", value(name = "attachmentLinkGitHubIssue"))]
     AttachmentLinkGitHubIssue,
     /// Execute attachmentLinkGitHubPR mutation
-    #[value(name = "attachmentLinkGitHubPR")]
+    #[cfg_attr(feature = "cli", value(name = "attachmentLinkGitHubPR"))]
     AttachmentLinkGitHubPR,
     /// Execute attachmentLinkGitLabMR mutation
-    #[value(name = "attachmentLinkGitLabMR")]
+    #[cfg_attr(feature = "cli", value(name = "attachmentLinkGitLabMR"))]
     AttachmentLinkGitLabMR,
     /// Execute attachmentLinkIntercom mutation
-    #[value(name = "attachmentLinkIntercom")]
+    #[cfg_attr(feature = "cli", value(name = "attachmentLinkIntercom"))]
     AttachmentLinkIntercom,
     /// Execute attachmentLinkJiraIssue mutation
-    #[value(name = "attachmentLinkJiraIssue")]
+    #[cfg_attr(feature = "cli", value(name = "attachmentLinkJiraIssue"))]
     AttachmentLinkJiraIssue,
     /// Execute attachmentLinkSalesforce mutation
-    #[value(name = "attachmentLinkSalesforce")]
+    #[cfg_attr(feature = "cli", value(name = "attachmentLinkSalesforce"))]
     AttachmentLinkSalesforce,
     /// Execute attachmentLinkSlack mutation
-    #[value(name = "attachmentLinkSlack")]
+    #[cfg_attr(feature = "cli", value(name = "attachmentLinkSlack"))]
     AttachmentLinkSlack,
     /// Execute attachmentLinkURL mutation
-    #[value(name = "attachmentLinkURL")]
+    #[cfg_attr(feature = "cli", value(name = "attachmentLinkURL"))]
     AttachmentLinkURL,
     /// Execute attachmentLinkZendesk mutation
-    #[value(name = "attachmentLinkZendesk")]
+    #[cfg_attr(feature = "cli", value(name = "attachmentLinkZendesk"))]
     AttachmentLinkZendesk,
     /// Execute attachmentSyncToSlack mutation
-    #[value(name = "attachmentSyncToSlack")]
+    #[cfg_attr(feature = "cli", value(name = "attachmentSyncToSlack"))]
     AttachmentSyncToSlack,
     /// Execute attachmentUpdate mutation
-    #[value(name = "attachmentUpdate")]
+    #[cfg_attr(feature = "cli", value(name = "attachmentUpdate"))]
     AttachmentUpdate,
     /// Execute commentCreate mutation
-    #[value(name = "commentCreate")]
+    #[cfg_attr(feature = "cli", value(name = "commentCreate"))]
     CommentCreate,
     /// Execute commentDelete mutation
-    #[value(name = "commentDelete")]
+    #[cfg_attr(feature = "cli", value(name = "commentDelete"))]
     CommentDelete,
     /// Execute commentResolve mutation
-    #[value(name = "commentResolve")]
+    #[cfg_attr(feature = "cli", value(name = "commentResolve"))]
     CommentResolve,
     /// Execute commentUnresolve mutation
-    #[value(name = "commentUnresolve")]
+    #[cfg_attr(feature = "cli", value(name = "commentUnresolve"))]
     CommentUnresolve,
     /// Execute commentUpdate mutation
-    #[value(name = "commentUpdate")]
+    #[cfg_attr(feature = "cli", value(name = "commentUpdate"))]
     CommentUpdate,
     /// Execute contactCreate mutation
-    #[value(name = "contactCreate")]
+    #[cfg_attr(feature = "cli", value(name = "contactCreate"))]
     ContactCreate,
     /// Execute contactSalesCreate mutation
-    #[value(name = "contactSalesCreate")]
+    #[cfg_attr(feature = "cli", value(name = "contactSalesCreate"))]
     ContactSalesCreate,
     /// Execute createCsvExportReport mutation
-    #[value(name = "createCsvExportReport")]
+    #[cfg_attr(feature = "cli", value(name = "createCsvExportReport"))]
     CreateCsvExportReport,
     /// Execute createInitiativeUpdateReminder mutation
-    #[value(name = "createInitiativeUpdateReminder")]
+    #[cfg_attr(feature = "cli", value(name = "createInitiativeUpdateReminder"))]
     CreateInitiativeUpdateReminder,
     /// Execute createOrganizationFromOnboarding mutation
-    #[value(name = "createOrganizationFromOnboarding")]
+    #[cfg_attr(feature = "cli", value(name = "createOrganizationFromOnboarding"))]
     CreateOrganizationFromOnboarding,
     /// Execute createProjectUpdateReminder mutation
-    #[value(name = "createProjectUpdateReminder")]
+    #[cfg_attr(feature = "cli", value(name = "createProjectUpdateReminder"))]
     CreateProjectUpdateReminder,
     /// Execute customViewCreate mutation
-    #[value(name = "customViewCreate")]
+    #[cfg_attr(feature = "cli", value(name = "customViewCreate"))]
     CustomViewCreate,
     /// Execute customViewDelete mutation
-    #[value(name = "customViewDelete")]
+    #[cfg_attr(feature = "cli", value(name = "customViewDelete"))]
     CustomViewDelete,
     /// Execute customViewUpdate mutation
-    #[value(name = "customViewUpdate")]
+    #[cfg_attr(feature = "cli", value(name = "customViewUpdate"))]
     CustomViewUpdate,
     /// Execute customerCreate mutation
-    #[value(name = "customerCreate")]
+    #[cfg_attr(feature = "cli", value(name = "customerCreate"))]
     CustomerCreate,
     /// Execute customerDelete mutation
-    #[value(name = "customerDelete")]
+    #[cfg_attr(feature = "cli", value(name = "customerDelete"))]
     CustomerDelete,
     /// Execute customerMerge mutation
-    #[value(name = "customerMerge")]
+    #[cfg_attr(feature = "cli", value(name = "customerMerge"))]
     CustomerMerge,
     /// Execute customerNeedArchive mutation
-    #[value(name = "customerNeedArchive")]
+    #[cfg_attr(feature = "cli", value(name = "customerNeedArchive"))]
     CustomerNeedArchive,
     /// Execute customerNeedCreate mutation
-    #[value(name = "customerNeedCreate")]
+    #[cfg_attr(feature = "cli", value(name = "customerNeedCreate"))]
     CustomerNeedCreate,
     /// Execute customerNeedCreateFromAttachment mutation
-    #[value(name = "customerNeedCreateFromAttachment")]
+    #[cfg_attr(feature = "cli", value(name = "customerNeedCreateFromAttachment"))]
     CustomerNeedCreateFromAttachment,
     /// Execute customerNeedDelete mutation
-    #[value(name = "customerNeedDelete")]
+    #[cfg_attr(feature = "cli", value(name = "customerNeedDelete"))]
     CustomerNeedDelete,
     /// Execute customerNeedUnarchive mutation
-    #[value(name = "customerNeedUnarchive")]
+    #[cfg_attr(feature = "cli", value(name = "customerNeedUnarchive"))]
     CustomerNeedUnarchive,
     /// Execute customerNeedUpdate mutation
-    #[value(name = "customerNeedUpdate")]
+    #[cfg_attr(feature = "cli", value(name = "customerNeedUpdate"))]
     CustomerNeedUpdate,
     /// Execute customerStatusCreate mutation
-    #[value(name = "customerStatusCreate")]
+    #[cfg_attr(feature = "cli", value(name = "customerStatusCreate"))]
     CustomerStatusCreate,
     /// Execute customerStatusDelete mutation
-    #[value(name = "customerStatusDelete")]
+    #[cfg_attr(feature = "cli", value(name = "customerStatusDelete"))]
     CustomerStatusDelete,
     /// Execute customerStatusUpdate mutation
-    #[value(name = "customerStatusUpdate")]
+    #[cfg_attr(feature = "cli", value(name = "customerStatusUpdate"))]
     CustomerStatusUpdate,
     /// Execute customerTierCreate mutation
-    #[value(name = "customerTierCreate")]
+    #[cfg_attr(feature = "cli", value(name = "customerTierCreate"))]
     CustomerTierCreate,
     /// Execute customerTierDelete mutation
-    #[value(name = "customerTierDelete")]
+    #[cfg_attr(feature = "cli", value(name = "customerTierDelete"))]
     CustomerTierDelete,
     /// Execute customerTierUpdate mutation
-    #[value(name = "customerTierUpdate")]
+    #[cfg_attr(feature = "cli", value(name = "customerTierUpdate"))]
     CustomerTierUpdate,
     /// Execute customerUnsync mutation
-    #[value(name = "customerUnsync")]
+    #[cfg_attr(feature = "cli", value(name = "customerUnsync"))]
     CustomerUnsync,
     /// Execute customerUpdate mutation
-    #[value(name = "customerUpdate")]
+    #[cfg_attr(feature = "cli", value(name = "customerUpdate"))]
     CustomerUpdate,
     /// Execute customerUpsert mutation
-    #[value(name = "customerUpsert")]
+    #[cfg_attr(feature = "cli", value(name = "customerUpsert"))]
     CustomerUpsert,
     /// Execute cycleArchive mutation
-    #[value(name = "cycleArchive")]
+    #[cfg_attr(feature = "cli", value(name = "cycleArchive"))]
     CycleArchive,
     /// Execute cycleCreate mutation
-    #[value(name = "cycleCreate")]
+    #[cfg_attr(feature = "cli", value(name = "cycleCreate"))]
     CycleCreate,
     /// Execute cycleShiftAll mutation
-    #[value(name = "cycleShiftAll")]
+    #[cfg_attr(feature = "cli", value(name = "cycleShiftAll"))]
     CycleShiftAll,
     /// Execute cycleStartUpcomingCycleToday mutation
-    #[value(name = "cycleStartUpcomingCycleToday")]
+    #[cfg_attr(feature = "cli", value(name = "cycleStartUpcomingCycleToday"))]
     CycleStartUpcomingCycleToday,
     /// Execute cycleUpdate mutation
-    #[value(name = "cycleUpdate")]
+    #[cfg_attr(feature = "cli", value(name = "cycleUpdate"))]
     CycleUpdate,
     /// Execute documentCreate mutation
-    #[value(name = "documentCreate")]
+    #[cfg_attr(feature = "cli", value(name = "documentCreate"))]
     DocumentCreate,
     /// Execute documentDelete mutation
-    #[value(name = "documentDelete")]
+    #[cfg_attr(feature = "cli", value(name = "documentDelete"))]
     DocumentDelete,
     /// Execute documentUnarchive mutation
-    #[value(name = "documentUnarchive")]
+    #[cfg_attr(feature = "cli", value(name = "documentUnarchive"))]
     DocumentUnarchive,
     /// Execute documentUpdate mutation
-    #[value(name = "documentUpdate")]
+    #[cfg_attr(feature = "cli", value(name = "documentUpdate"))]
     DocumentUpdate,
     /// Execute emailIntakeAddressCreate mutation
-    #[value(name = "emailIntakeAddressCreate")]
+    #[cfg_attr(feature = "cli", value(name = "emailIntakeAddressCreate"))]
     EmailIntakeAddressCreate,
     /// Execute emailIntakeAddressDelete mutation
-    #[value(name = "emailIntakeAddressDelete")]
+    #[cfg_attr(feature = "cli", value(name = "emailIntakeAddressDelete"))]
     EmailIntakeAddressDelete,
     /// Execute emailIntakeAddressRotate mutation
-    #[value(name = "emailIntakeAddressRotate")]
+    #[cfg_attr(feature = "cli", value(name = "emailIntakeAddressRotate"))]
     EmailIntakeAddressRotate,
     /// Execute emailIntakeAddressUpdate mutation
-    #[value(name = "emailIntakeAddressUpdate")]
+    #[cfg_attr(feature = "cli", value(name = "emailIntakeAddressUpdate"))]
     EmailIntakeAddressUpdate,
     /// Execute emailTokenUserAccountAuth mutation
-    #[value(name = "emailTokenUserAccountAuth")]
+    #[cfg_attr(feature = "cli", value(name = "emailTokenUserAccountAuth"))]
     EmailTokenUserAccountAuth,
     /// Execute emailUnsubscribe mutation
-    #[value(name = "emailUnsubscribe")]
+    #[cfg_attr(feature = "cli", value(name = "emailUnsubscribe"))]
     EmailUnsubscribe,
     /// Execute emailUserAccountAuthChallenge mutation
-    #[value(name = "emailUserAccountAuthChallenge")]
+    #[cfg_attr(feature = "cli", value(name = "emailUserAccountAuthChallenge"))]
     EmailUserAccountAuthChallenge,
     /// Execute emojiCreate mutation
-    #[value(name = "emojiCreate")]
+    #[cfg_attr(feature = "cli", value(name = "emojiCreate"))]
     EmojiCreate,
     /// Execute emojiDelete mutation
-    #[value(name = "emojiDelete")]
+    #[cfg_attr(feature = "cli", value(name = "emojiDelete"))]
     EmojiDelete,
     /// Execute entityExternalLinkCreate mutation
-    #[value(name = "entityExternalLinkCreate")]
+    #[cfg_attr(feature = "cli", value(name = "entityExternalLinkCreate"))]
     EntityExternalLinkCreate,
     /// Execute entityExternalLinkDelete mutation
-    #[value(name = "entityExternalLinkDelete")]
+    #[cfg_attr(feature = "cli", value(name = "entityExternalLinkDelete"))]
     EntityExternalLinkDelete,
     /// Execute entityExternalLinkUpdate mutation
-    #[value(name = "entityExternalLinkUpdate")]
+    #[cfg_attr(feature = "cli", value(name = "entityExternalLinkUpdate"))]
     EntityExternalLinkUpdate,
     /// Execute favoriteCreate mutation
-    #[value(name = "favoriteCreate")]
+    #[cfg_attr(feature = "cli", value(name = "favoriteCreate"))]
     FavoriteCreate,
     /// Execute favoriteDelete mutation
-    #[value(name = "favoriteDelete")]
+    #[cfg_attr(feature = "cli", value(name = "favoriteDelete"))]
     FavoriteDelete,
     /// Execute favoriteUpdate mutation
-    #[value(name = "favoriteUpdate")]
+    #[cfg_attr(feature = "cli", value(name = "favoriteUpdate"))]
     FavoriteUpdate,
     /// Execute fileUpload mutation
-    #[value(name = "fileUpload")]
+    #[cfg_attr(feature = "cli", value(name = "fileUpload"))]
     FileUpload,
     /// Execute fileUploadDangerouslyDelete mutation
-    #[value(name = "fileUploadDangerouslyDelete")]
+    #[cfg_attr(feature = "cli", value(name = "fileUploadDangerouslyDelete"))]
     FileUploadDangerouslyDelete,
     /// Execute gitAutomationStateCreate mutation
-    #[value(name = "gitAutomationStateCreate")]
+    #[cfg_attr(feature = "cli", value(name = "gitAutomationStateCreate"))]
     GitAutomationStateCreate,
     /// Execute gitAutomationStateDelete mutation
-    #[value(name = "gitAutomationStateDelete")]
+    #[cfg_attr(feature = "cli", value(name = "gitAutomationStateDelete"))]
     GitAutomationStateDelete,
     /// Execute gitAutomationStateUpdate mutation
-    #[value(name = "gitAutomationStateUpdate")]
+    #[cfg_attr(feature = "cli", value(name = "gitAutomationStateUpdate"))]
     GitAutomationStateUpdate,
     /// Execute gitAutomationTargetBranchCreate mutation
-    #[value(name = "gitAutomationTargetBranchCreate")]
+    #[cfg_attr(feature = "cli", value(name = "gitAutomationTargetBranchCreate"))]
     GitAutomationTargetBranchCreate,
     /// Execute gitAutomationTargetBranchDelete mutation
-    #[value(name = "gitAutomationTargetBranchDelete")]
+    #[cfg_attr(feature = "cli", value(name = "gitAutomationTargetBranchDelete"))]
     GitAutomationTargetBranchDelete,
     /// Execute gitAutomationTargetBranchUpdate mutation
-    #[value(name = "gitAutomationTargetBranchUpdate")]
+    #[cfg_attr(feature = "cli", value(name = "gitAutomationTargetBranchUpdate"))]
     GitAutomationTargetBranchUpdate,
     /// Execute googleUserAccountAuth mutation
-    #[value(name = "googleUserAccountAuth")]
+    #[cfg_attr(feature = "cli", value(name = "googleUserAccountAuth"))]
     GoogleUserAccountAuth,
     /// Execute imageUploadFromUrl mutation
-    #[value(name = "imageUploadFromUrl")]
+    #[cfg_attr(feature = "cli", value(name = "imageUploadFromUrl"))]
     ImageUploadFromUrl,
     /// Execute importFileUpload mutation
-    #[value(name = "importFileUpload")]
+    #[cfg_attr(feature = "cli", value(name = "importFileUpload"))]
     ImportFileUpload,
     /// Execute initiativeArchive mutation
-    #[value(name = "initiativeArchive")]
+    #[cfg_attr(feature = "cli", value(name = "initiativeArchive"))]
     InitiativeArchive,
     /// Execute initiativeCreate mutation
-    #[value(name = "initiativeCreate")]
+    #[cfg_attr(feature = "cli", value(name = "initiativeCreate"))]
     InitiativeCreate,
     /// Execute initiativeDelete mutation
-    #[value(name = "initiativeDelete")]
+    #[cfg_attr(feature = "cli", value(name = "initiativeDelete"))]
     InitiativeDelete,
     /// Execute initiativeRelationCreate mutation
-    #[value(name = "initiativeRelationCreate")]
+    #[cfg_attr(feature = "cli", value(name = "initiativeRelationCreate"))]
     InitiativeRelationCreate,
     /// Execute initiativeRelationDelete mutation
-    #[value(name = "initiativeRelationDelete")]
+    #[cfg_attr(feature = "cli", value(name = "initiativeRelationDelete"))]
     InitiativeRelationDelete,
     /// Execute initiativeRelationUpdate mutation
-    #[value(name = "initiativeRelationUpdate")]
+    #[cfg_attr(feature = "cli", value(name = "initiativeRelationUpdate"))]
     InitiativeRelationUpdate,
     /// Execute initiativeToProjectCreate mutation
-    #[value(name = "initiativeToProjectCreate")]
+    #[cfg_attr(feature = "cli", value(name = "initiativeToProjectCreate"))]
     InitiativeToProjectCreate,
     /// Execute initiativeToProjectDelete mutation
-    #[value(name = "initiativeToProjectDelete")]
+    #[cfg_attr(feature = "cli", value(name = "initiativeToProjectDelete"))]
     InitiativeToProjectDelete,
     /// Execute initiativeToProjectUpdate mutation
-    #[value(name = "initiativeToProjectUpdate")]
+    #[cfg_attr(feature = "cli", value(name = "initiativeToProjectUpdate"))]
     InitiativeToProjectUpdate,
     /// Execute initiativeUnarchive mutation
-    #[value(name = "initiativeUnarchive")]
+    #[cfg_attr(feature = "cli", value(name = "initiativeUnarchive"))]
     InitiativeUnarchive,
     /// Execute initiativeUpdate mutation
-    #[value(name = "initiativeUpdate")]
+    #[cfg_attr(feature = "cli", value(name = "initiativeUpdate"))]
     InitiativeUpdate,
     /// Execute initiativeUpdateArchive mutation
-    #[value(name = "initiativeUpdateArchive")]
+    #[cfg_attr(feature = "cli", value(name = "initiativeUpdateArchive"))]
     InitiativeUpdateArchive,
     /// Execute initiativeUpdateCreate mutation
-    #[value(name = "initiativeUpdateCreate")]
+    #[cfg_attr(feature = "cli", value(name = "initiativeUpdateCreate"))]
     InitiativeUpdateCreate,
     /// Execute initiativeUpdateUnarchive mutation
-    #[value(name = "initiativeUpdateUnarchive")]
+    #[cfg_attr(feature = "cli", value(name = "initiativeUpdateUnarchive"))]
     InitiativeUpdateUnarchive,
     /// Execute initiativeUpdateUpdate mutation
-    #[value(name = "initiativeUpdateUpdate")]
+    #[cfg_attr(feature = "cli", value(name = "initiativeUpdateUpdate"))]
     InitiativeUpdateUpdate,
     /// Execute integrationArchive mutation
-    #[value(name = "integrationArchive")]
+    #[cfg_attr(feature = "cli", value(name = "integrationArchive"))]
     IntegrationArchive,
     /// Execute integrationAsksConnectChannel mutation
-    #[value(name = "integrationAsksConnectChannel")]
+    #[cfg_attr(feature = "cli", value(name = "integrationAsksConnectChannel"))]
     IntegrationAsksConnectChannel,
     /// Execute integrationCustomerDataAttributesRefresh mutation
-    #[value(name = "integrationCustomerDataAttributesRefresh")]
+    #[cfg_attr(feature = "cli", value(name = "integrationCustomerDataAttributesRefresh"))]
     IntegrationCustomerDataAttributesRefresh,
     /// Execute integrationDelete mutation
-    #[value(name = "integrationDelete")]
+    #[cfg_attr(feature = "cli", value(name = "integrationDelete"))]
     IntegrationDelete,
     /// Execute integrationDiscord mutation
-    #[value(name = "integrationDiscord")]
+    #[cfg_attr(feature = "cli", value(name = "integrationDiscord"))]
     IntegrationDiscord,
     /// Execute integrationFigma mutation
-    #[value(name = "integrationFigma")]
+    #[cfg_attr(feature = "cli", value(name = "integrationFigma"))]
     IntegrationFigma,
     /// Execute integrationFront mutation
-    #[value(name = "integrationFront")]
+    #[cfg_attr(feature = "cli", value(name = "integrationFront"))]
     IntegrationFront,
     /// Execute integrationGitHubEnterpriseServerConnect mutation
-    #[value(name = "integrationGitHubEnterpriseServerConnect")]
+    #[cfg_attr(feature = "cli", value(name = "integrationGitHubEnterpriseServerConnect"))]
     IntegrationGitHubEnterpriseServerConnect,
     /// Execute integrationGitHubPersonal mutation
-    #[value(name = "integrationGitHubPersonal")]
+    #[cfg_attr(feature = "cli", value(name = "integrationGitHubPersonal"))]
     IntegrationGitHubPersonal,
     /// Execute integrationGithubCommitCreate mutation
-    #[value(name = "integrationGithubCommitCreate")]
+    #[cfg_attr(feature = "cli", value(name = "integrationGithubCommitCreate"))]
     IntegrationGithubCommitCreate,
     /// Execute integrationGithubConnect mutation
-    #[value(name = "integrationGithubConnect")]
+    #[cfg_attr(feature = "cli", value(name = "integrationGithubConnect"))]
     IntegrationGithubConnect,
     /// Execute integrationGithubImportConnect mutation
-    #[value(name = "integrationGithubImportConnect")]
+    #[cfg_attr(feature = "cli", value(name = "integrationGithubImportConnect"))]
     IntegrationGithubImportConnect,
     /// Execute integrationGithubImportRefresh mutation
-    #[value(name = "integrationGithubImportRefresh")]
+    #[cfg_attr(feature = "cli", value(name = "integrationGithubImportRefresh"))]
     IntegrationGithubImportRefresh,
     /// Execute integrationGitlabConnect mutation
-    #[value(name = "integrationGitlabConnect")]
+    #[cfg_attr(feature = "cli", value(name = "integrationGitlabConnect"))]
     IntegrationGitlabConnect,
     /// Execute integrationGong mutation
-    #[value(name = "integrationGong")]
+    #[cfg_attr(feature = "cli", value(name = "integrationGong"))]
     IntegrationGong,
     /// Execute integrationGoogleCalendarPersonalConnect mutation
-    #[value(name = "integrationGoogleCalendarPersonalConnect")]
+    #[cfg_attr(feature = "cli", value(name = "integrationGoogleCalendarPersonalConnect"))]
     IntegrationGoogleCalendarPersonalConnect,
     /// Execute integrationGoogleSheets mutation
-    #[value(name = "integrationGoogleSheets")]
+    #[cfg_attr(feature = "cli", value(name = "integrationGoogleSheets"))]
     IntegrationGoogleSheets,
     /// Execute integrationIntercom mutation
-    #[value(name = "integrationIntercom")]
+    #[cfg_attr(feature = "cli", value(name = "integrationIntercom"))]
     IntegrationIntercom,
     /// Execute integrationIntercomDelete mutation
-    #[value(name = "integrationIntercomDelete")]
+    #[cfg_attr(feature = "cli", value(name = "integrationIntercomDelete"))]
     IntegrationIntercomDelete,
     /// Execute integrationIntercomSettingsUpdate mutation
-    #[value(name = "integrationIntercomSettingsUpdate")]
+    #[cfg_attr(feature = "cli", value(name = "integrationIntercomSettingsUpdate"))]
     IntegrationIntercomSettingsUpdate,
     /// Execute integrationJiraPersonal mutation
-    #[value(name = "integrationJiraPersonal")]
+    #[cfg_attr(feature = "cli", value(name = "integrationJiraPersonal"))]
     IntegrationJiraPersonal,
     /// Execute integrationJiraUpdate mutation
-    #[value(name = "integrationJiraUpdate")]
+    #[cfg_attr(feature = "cli", value(name = "integrationJiraUpdate"))]
     IntegrationJiraUpdate,
     /// Execute integrationLaunchDarklyConnect mutation
-    #[value(name = "integrationLaunchDarklyConnect")]
+    #[cfg_attr(feature = "cli", value(name = "integrationLaunchDarklyConnect"))]
     IntegrationLaunchDarklyConnect,
     /// Execute integrationLaunchDarklyPersonalConnect mutation
-    #[value(name = "integrationLaunchDarklyPersonalConnect")]
+    #[cfg_attr(feature = "cli", value(name = "integrationLaunchDarklyPersonalConnect"))]
     IntegrationLaunchDarklyPersonalConnect,
     /// Execute integrationLoom mutation
-    #[value(name = "integrationLoom")]
+    #[cfg_attr(feature = "cli", value(name = "integrationLoom"))]
     IntegrationLoom,
     /// Execute integrationMcpServerConnect mutation
-    #[value(name = "integrationMcpServerConnect")]
+    #[cfg_attr(feature = "cli", value(name = "integrationMcpServerConnect"))]
     IntegrationMcpServerConnect,
     /// Execute integrationMcpServerPersonalConnect mutation
-    #[value(name = "integrationMcpServerPersonalConnect")]
+    #[cfg_attr(feature = "cli", value(name = "integrationMcpServerPersonalConnect"))]
     IntegrationMcpServerPersonalConnect,
     /// Execute integrationOpsgenieConnect mutation
-    #[value(name = "integrationOpsgenieConnect")]
+    #[cfg_attr(feature = "cli", value(name = "integrationOpsgenieConnect"))]
     IntegrationOpsgenieConnect,
     /// Execute integrationOpsgenieRefreshScheduleMappings mutation
-    #[value(name = "integrationOpsgenieRefreshScheduleMappings")]
+    #[cfg_attr(feature = "cli", value(name = "integrationOpsgenieRefreshScheduleMappings"))]
     IntegrationOpsgenieRefreshScheduleMappings,
     /// Execute integrationPagerDutyConnect mutation
-    #[value(name = "integrationPagerDutyConnect")]
+    #[cfg_attr(feature = "cli", value(name = "integrationPagerDutyConnect"))]
     IntegrationPagerDutyConnect,
     /// Execute integrationPagerDutyRefreshScheduleMappings mutation
-    #[value(name = "integrationPagerDutyRefreshScheduleMappings")]
+    #[cfg_attr(feature = "cli", value(name = "integrationPagerDutyRefreshScheduleMappings"))]
     IntegrationPagerDutyRefreshScheduleMappings,
     /// Execute integrationRequest mutation
-    #[value(name = "integrationRequest")]
+    #[cfg_attr(feature = "cli", value(name = "integrationRequest"))]
     IntegrationRequest,
     /// Execute integrationSalesforce mutation
-    #[value(name = "integrationSalesforce")]
+    #[cfg_attr(feature = "cli", value(name = "integrationSalesforce"))]
     IntegrationSalesforce,
     /// Execute integrationSalesforceMetadataRefresh mutation
-    #[value(name = "integrationSalesforceMetadataRefresh")]
+    #[cfg_attr(feature = "cli", value(name = "integrationSalesforceMetadataRefresh"))]
     IntegrationSalesforceMetadataRefresh,
     /// Execute integrationSentryConnect mutation
-    #[value(name = "integrationSentryConnect")]
+    #[cfg_attr(feature = "cli", value(name = "integrationSentryConnect"))]
     IntegrationSentryConnect,
     /// Execute integrationSettingsUpdate mutation
-    #[value(name = "integrationSettingsUpdate")]
+    #[cfg_attr(feature = "cli", value(name = "integrationSettingsUpdate"))]
     IntegrationSettingsUpdate,
     /// Execute integrationSlack mutation
-    #[value(name = "integrationSlack")]
+    #[cfg_attr(feature = "cli", value(name = "integrationSlack"))]
     IntegrationSlack,
     /// Execute integrationSlackAsks mutation
-    #[value(name = "integrationSlackAsks")]
+    #[cfg_attr(feature = "cli", value(name = "integrationSlackAsks"))]
     IntegrationSlackAsks,
     /// Execute integrationSlackCustomViewNotifications mutation
-    #[value(name = "integrationSlackCustomViewNotifications")]
+    #[cfg_attr(feature = "cli", value(name = "integrationSlackCustomViewNotifications"))]
     IntegrationSlackCustomViewNotifications,
     /// Execute integrationSlackCustomerChannelLink mutation
-    #[value(name = "integrationSlackCustomerChannelLink")]
+    #[cfg_attr(feature = "cli", value(name = "integrationSlackCustomerChannelLink"))]
     IntegrationSlackCustomerChannelLink,
     /// Execute integrationSlackImportEmojis mutation
-    #[value(name = "integrationSlackImportEmojis")]
+    #[cfg_attr(feature = "cli", value(name = "integrationSlackImportEmojis"))]
     IntegrationSlackImportEmojis,
     /// Execute integrationSlackInitiativePost mutation
-    #[value(name = "integrationSlackInitiativePost")]
+    #[cfg_attr(feature = "cli", value(name = "integrationSlackInitiativePost"))]
     IntegrationSlackInitiativePost,
     /// Execute integrationSlackOrAsksUpdateSlackTeamName mutation
-    #[value(name = "integrationSlackOrAsksUpdateSlackTeamName")]
+    #[cfg_attr(feature = "cli", value(name = "integrationSlackOrAsksUpdateSlackTeamName"))]
     IntegrationSlackOrAsksUpdateSlackTeamName,
     /// Execute integrationSlackOrgInitiativeUpdatesPost mutation
-    #[value(name = "integrationSlackOrgInitiativeUpdatesPost")]
+    #[cfg_attr(feature = "cli", value(name = "integrationSlackOrgInitiativeUpdatesPost"))]
     IntegrationSlackOrgInitiativeUpdatesPost,
     /// Execute integrationSlackOrgProjectUpdatesPost mutation
-    #[value(name = "integrationSlackOrgProjectUpdatesPost")]
+    #[cfg_attr(feature = "cli", value(name = "integrationSlackOrgProjectUpdatesPost"))]
     IntegrationSlackOrgProjectUpdatesPost,
     /// Execute integrationSlackPersonal mutation
-    #[value(name = "integrationSlackPersonal")]
+    #[cfg_attr(feature = "cli", value(name = "integrationSlackPersonal"))]
     IntegrationSlackPersonal,
     /// Execute integrationSlackPost mutation
-    #[value(name = "integrationSlackPost")]
+    #[cfg_attr(feature = "cli", value(name = "integrationSlackPost"))]
     IntegrationSlackPost,
     /// Execute integrationSlackProjectPost mutation
-    #[value(name = "integrationSlackProjectPost")]
+    #[cfg_attr(feature = "cli", value(name = "integrationSlackProjectPost"))]
     IntegrationSlackProjectPost,
     /// Execute integrationSlackWorkflowAccessUpdate mutation
-    #[value(name = "integrationSlackWorkflowAccessUpdate")]
+    #[cfg_attr(feature = "cli", value(name = "integrationSlackWorkflowAccessUpdate"))]
     IntegrationSlackWorkflowAccessUpdate,
     /// Execute integrationTemplateCreate mutation
-    #[value(name = "integrationTemplateCreate")]
+    #[cfg_attr(feature = "cli", value(name = "integrationTemplateCreate"))]
     IntegrationTemplateCreate,
     /// Execute integrationTemplateDelete mutation
-    #[value(name = "integrationTemplateDelete")]
+    #[cfg_attr(feature = "cli", value(name = "integrationTemplateDelete"))]
     IntegrationTemplateDelete,
     /// Execute integrationUpdate mutation
-    #[value(name = "integrationUpdate")]
+    #[cfg_attr(feature = "cli", value(name = "integrationUpdate"))]
     IntegrationUpdate,
     /// Execute integrationZendesk mutation
-    #[value(name = "integrationZendesk")]
+    #[cfg_attr(feature = "cli", value(name = "integrationZendesk"))]
     IntegrationZendesk,
     /// Execute integrationsSettingsCreate mutation
-    #[value(name = "integrationsSettingsCreate")]
+    #[cfg_attr(feature = "cli", value(name = "integrationsSettingsCreate"))]
     IntegrationsSettingsCreate,
     /// Execute integrationsSettingsUpdate mutation
-    #[value(name = "integrationsSettingsUpdate")]
+    #[cfg_attr(feature = "cli", value(name = "integrationsSettingsUpdate"))]
     IntegrationsSettingsUpdate,
     /// Execute issueAddLabel mutation
-    #[value(name = "issueAddLabel")]
+    #[cfg_attr(feature = "cli", value(name = "issueAddLabel"))]
     IssueAddLabel,
     /// Execute issueArchive mutation
-    #[value(name = "issueArchive")]
+    #[cfg_attr(feature = "cli", value(name = "issueArchive"))]
     IssueArchive,
     /// Execute issueBatchCreate mutation
-    #[value(name = "issueBatchCreate")]
+    #[cfg_attr(feature = "cli", value(name = "issueBatchCreate"))]
     IssueBatchCreate,
     /// Execute issueBatchUpdate mutation
-    #[value(name = "issueBatchUpdate")]
+    #[cfg_attr(feature = "cli", value(name = "issueBatchUpdate"))]
     IssueBatchUpdate,
     /// Execute issueCreate mutation
-    #[value(name = "issueCreate")]
+    #[cfg_attr(feature = "cli", value(name = "issueCreate"))]
     IssueCreate,
     /// Execute issueDelete mutation
-    #[value(name = "issueDelete")]
+    #[cfg_attr(feature = "cli", value(name = "issueDelete"))]
     IssueDelete,
     /// Execute issueDescriptionUpdateFromFront mutation
-    #[value(name = "issueDescriptionUpdateFromFront")]
+    #[cfg_attr(feature = "cli", value(name = "issueDescriptionUpdateFromFront"))]
     IssueDescriptionUpdateFromFront,
     /// Execute issueExternalSyncDisable mutation
-    #[value(name = "issueExternalSyncDisable")]
+    #[cfg_attr(feature = "cli", value(name = "issueExternalSyncDisable"))]
     IssueExternalSyncDisable,
     /// Execute issueImportCreateAsana mutation
-    #[value(name = "issueImportCreateAsana")]
+    #[cfg_attr(feature = "cli", value(name = "issueImportCreateAsana"))]
     IssueImportCreateAsana,
     /// Execute issueImportCreateCSVJira mutation
-    #[value(name = "issueImportCreateCSVJira")]
+    #[cfg_attr(feature = "cli", value(name = "issueImportCreateCSVJira"))]
     IssueImportCreateCSVJira,
     /// Execute issueImportCreateClubhouse mutation
-    #[value(name = "issueImportCreateClubhouse")]
+    #[cfg_attr(feature = "cli", value(name = "issueImportCreateClubhouse"))]
     IssueImportCreateClubhouse,
     /// Execute issueImportCreateGithub mutation
-    #[value(name = "issueImportCreateGithub")]
+    #[cfg_attr(feature = "cli", value(name = "issueImportCreateGithub"))]
     IssueImportCreateGithub,
     /// Execute issueImportCreateJira mutation
-    #[value(name = "issueImportCreateJira")]
+    #[cfg_attr(feature = "cli", value(name = "issueImportCreateJira"))]
     IssueImportCreateJira,
     /// Execute issueImportCreateLinearV2 mutation
-    #[value(name = "issueImportCreateLinearV2")]
+    #[cfg_attr(feature = "cli", value(name = "issueImportCreateLinearV2"))]
     IssueImportCreateLinearV2,
     /// Execute issueImportDelete mutation
-    #[value(name = "issueImportDelete")]
+    #[cfg_attr(feature = "cli", value(name = "issueImportDelete"))]
     IssueImportDelete,
     /// Execute issueImportProcess mutation
-    #[value(name = "issueImportProcess")]
+    #[cfg_attr(feature = "cli", value(name = "issueImportProcess"))]
     IssueImportProcess,
     /// Execute issueImportUpdate mutation
-    #[value(name = "issueImportUpdate")]
+    #[cfg_attr(feature = "cli", value(name = "issueImportUpdate"))]
     IssueImportUpdate,
     /// Execute issueLabelCreate mutation
-    #[value(name = "issueLabelCreate")]
+    #[cfg_attr(feature = "cli", value(name = "issueLabelCreate"))]
     IssueLabelCreate,
     /// Execute issueLabelDelete mutation
-    #[value(name = "issueLabelDelete")]
+    #[cfg_attr(feature = "cli", value(name = "issueLabelDelete"))]
     IssueLabelDelete,
     /// Execute issueLabelRestore mutation
-    #[value(name = "issueLabelRestore")]
+    #[cfg_attr(feature = "cli", value(name = "issueLabelRestore"))]
     IssueLabelRestore,
     /// Execute issueLabelRetire mutation
-    #[value(name = "issueLabelRetire")]
+    #[cfg_attr(feature = "cli", value(name = "issueLabelRetire"))]
     IssueLabelRetire,
     /// Execute issueLabelUpdate mutation
-    #[value(name = "issueLabelUpdate")]
+    #[cfg_attr(feature = "cli", value(name = "issueLabelUpdate"))]
     IssueLabelUpdate,
     /// Execute issueRelationCreate mutation
-    #[value(name = "issueRelationCreate")]
+    #[cfg_attr(feature = "cli", value(name = "issueRelationCreate"))]
     IssueRelationCreate,
     /// Execute issueRelationDelete mutation
-    #[value(name = "issueRelationDelete")]
+    #[cfg_attr(feature = "cli", value(name = "issueRelationDelete"))]
     IssueRelationDelete,
     /// Execute issueRelationUpdate mutation
-    #[value(name = "issueRelationUpdate")]
+    #[cfg_attr(feature = "cli", value(name = "issueRelationUpdate"))]
     IssueRelationUpdate,
     /// Execute issueReminder mutation
-    #[value(name = "issueReminder")]
+    #[cfg_attr(feature = "cli", value(name = "issueReminder"))]
     IssueReminder,
     /// Execute issueRemoveLabel mutation
-    #[value(name = "issueRemoveLabel")]
+    #[cfg_attr(feature = "cli", value(name = "issueRemoveLabel"))]
     IssueRemoveLabel,
     /// Execute issueSubscribe mutation
-    #[value(name = "issueSubscribe")]
+    #[cfg_attr(feature = "cli", value(name = "issueSubscribe"))]
     IssueSubscribe,
     /// Execute issueToReleaseCreate mutation
-    #[value(name = "issueToReleaseCreate")]
+    #[cfg_attr(feature = "cli", value(name = "issueToReleaseCreate"))]
     IssueToReleaseCreate,
     /// Execute issueToReleaseDelete mutation
-    #[value(name = "issueToReleaseDelete")]
+    #[cfg_attr(feature = "cli", value(name = "issueToReleaseDelete"))]
     IssueToReleaseDelete,
     /// Execute issueToReleaseDeleteByIssueAndRelease mutation
-    #[value(name = "issueToReleaseDeleteByIssueAndRelease")]
+    #[cfg_attr(feature = "cli", value(name = "issueToReleaseDeleteByIssueAndRelease"))]
     IssueToReleaseDeleteByIssueAndRelease,
     /// Execute issueUnarchive mutation
-    #[value(name = "issueUnarchive")]
+    #[cfg_attr(feature = "cli", value(name = "issueUnarchive"))]
     IssueUnarchive,
     /// Execute issueUnsubscribe mutation
-    #[value(name = "issueUnsubscribe")]
+    #[cfg_attr(feature = "cli", value(name = "issueUnsubscribe"))]
     IssueUnsubscribe,
     /// Execute issueUpdate mutation
-    #[value(name = "issueUpdate")]
+    #[cfg_attr(feature = "cli", value(name = "issueUpdate"))]
     IssueUpdate,
     /// Execute jiraIntegrationConnect mutation
-    #[value(name = "jiraIntegrationConnect")]
+    #[cfg_attr(feature = "cli", value(name = "jiraIntegrationConnect"))]
     JiraIntegrationConnect,
     /// Execute joinOrganizationFromOnboarding mutation
-    #[value(name = "joinOrganizationFromOnboarding")]
+    #[cfg_attr(feature = "cli", value(name = "joinOrganizationFromOnboarding"))]
     JoinOrganizationFromOnboarding,
     /// Execute leaveOrganization mutation
-    #[value(name = "leaveOrganization")]
+    #[cfg_attr(feature = "cli", value(name = "leaveOrganization"))]
     LeaveOrganization,
     /// Execute logout mutation
-    #[value(name = "logout")]
+    #[cfg_attr(feature = "cli", value(name = "logout"))]
     Logout,
     /// Execute logoutAllSessions mutation
-    #[value(name = "logoutAllSessions")]
+    #[cfg_attr(feature = "cli", value(name = "logoutAllSessions"))]
     LogoutAllSessions,
     /// Execute logoutOtherSessions mutation
-    #[value(name = "logoutOtherSessions")]
+    #[cfg_attr(feature = "cli", value(name = "logoutOtherSessions"))]
     LogoutOtherSessions,
     /// Execute logoutSession mutation
-    #[value(name = "logoutSession")]
+    #[cfg_attr(feature = "cli", value(name = "logoutSession"))]
     LogoutSession,
     /// Execute notificationArchive mutation
-    #[value(name = "notificationArchive")]
+    #[cfg_attr(feature = "cli", value(name = "notificationArchive"))]
     NotificationArchive,
     /// Execute notificationArchiveAll mutation
-    #[value(name = "notificationArchiveAll")]
+    #[cfg_attr(feature = "cli", value(name = "notificationArchiveAll"))]
     NotificationArchiveAll,
     /// Execute notificationCategoryChannelSubscriptionUpdate mutation
-    #[value(name = "notificationCategoryChannelSubscriptionUpdate")]
+    #[cfg_attr(feature = "cli", value(name = "notificationCategoryChannelSubscriptionUpdate"))]
     NotificationCategoryChannelSubscriptionUpdate,
     /// Execute notificationMarkReadAll mutation
-    #[value(name = "notificationMarkReadAll")]
+    #[cfg_attr(feature = "cli", value(name = "notificationMarkReadAll"))]
     NotificationMarkReadAll,
     /// Execute notificationMarkUnreadAll mutation
-    #[value(name = "notificationMarkUnreadAll")]
+    #[cfg_attr(feature = "cli", value(name = "notificationMarkUnreadAll"))]
     NotificationMarkUnreadAll,
     /// Execute notificationSnoozeAll mutation
-    #[value(name = "notificationSnoozeAll")]
+    #[cfg_attr(feature = "cli", value(name = "notificationSnoozeAll"))]
     NotificationSnoozeAll,
     /// Execute notificationSubscriptionCreate mutation
-    #[value(name = "notificationSubscriptionCreate")]
+    #[cfg_attr(feature = "cli", value(name = "notificationSubscriptionCreate"))]
     NotificationSubscriptionCreate,
     /// Execute notificationSubscriptionDelete mutation
-    #[value(name = "notificationSubscriptionDelete")]
+    #[cfg_attr(feature = "cli", value(name = "notificationSubscriptionDelete"))]
     NotificationSubscriptionDelete,
     /// Execute notificationSubscriptionUpdate mutation
-    #[value(name = "notificationSubscriptionUpdate")]
+    #[cfg_attr(feature = "cli", value(name = "notificationSubscriptionUpdate"))]
     NotificationSubscriptionUpdate,
     /// Execute notificationUnarchive mutation
-    #[value(name = "notificationUnarchive")]
+    #[cfg_attr(feature = "cli", value(name = "notificationUnarchive"))]
     NotificationUnarchive,
     /// Execute notificationUnsnoozeAll mutation
-    #[value(name = "notificationUnsnoozeAll")]
+    #[cfg_attr(feature = "cli", value(name = "notificationUnsnoozeAll"))]
     NotificationUnsnoozeAll,
     /// Execute notificationUpdate mutation
-    #[value(name = "notificationUpdate")]
+    #[cfg_attr(feature = "cli", value(name = "notificationUpdate"))]
     NotificationUpdate,
     /// Execute organizationCancelDelete mutation
-    #[value(name = "organizationCancelDelete")]
+    #[cfg_attr(feature = "cli", value(name = "organizationCancelDelete"))]
     OrganizationCancelDelete,
     /// Execute organizationDelete mutation
-    #[value(name = "organizationDelete")]
+    #[cfg_attr(feature = "cli", value(name = "organizationDelete"))]
     OrganizationDelete,
     /// Execute organizationDeleteChallenge mutation
-    #[value(name = "organizationDeleteChallenge")]
+    #[cfg_attr(feature = "cli", value(name = "organizationDeleteChallenge"))]
     OrganizationDeleteChallenge,
     /// Execute organizationDomainClaim mutation
-    #[value(name = "organizationDomainClaim")]
+    #[cfg_attr(feature = "cli", value(name = "organizationDomainClaim"))]
     OrganizationDomainClaim,
     /// Execute organizationDomainCreate mutation
-    #[value(name = "organizationDomainCreate")]
+    #[cfg_attr(feature = "cli", value(name = "organizationDomainCreate"))]
     OrganizationDomainCreate,
     /// Execute organizationDomainDelete mutation
-    #[value(name = "organizationDomainDelete")]
+    #[cfg_attr(feature = "cli", value(name = "organizationDomainDelete"))]
     OrganizationDomainDelete,
     /// Execute organizationDomainUpdate mutation
-    #[value(name = "organizationDomainUpdate")]
+    #[cfg_attr(feature = "cli", value(name = "organizationDomainUpdate"))]
     OrganizationDomainUpdate,
     /// Execute organizationDomainVerify mutation
-    #[value(name = "organizationDomainVerify")]
+    #[cfg_attr(feature = "cli", value(name = "organizationDomainVerify"))]
     OrganizationDomainVerify,
     /// Execute organizationInviteCreate mutation
-    #[value(name = "organizationInviteCreate")]
+    #[cfg_attr(feature = "cli", value(name = "organizationInviteCreate"))]
     OrganizationInviteCreate,
     /// Execute organizationInviteDelete mutation
-    #[value(name = "organizationInviteDelete")]
+    #[cfg_attr(feature = "cli", value(name = "organizationInviteDelete"))]
     OrganizationInviteDelete,
     /// Execute organizationInviteUpdate mutation
-    #[value(name = "organizationInviteUpdate")]
+    #[cfg_attr(feature = "cli", value(name = "organizationInviteUpdate"))]
     OrganizationInviteUpdate,
     /// Execute organizationStartTrial mutation
-    #[value(name = "organizationStartTrial")]
+    #[cfg_attr(feature = "cli", value(name = "organizationStartTrial"))]
     OrganizationStartTrial,
     /// Execute organizationStartTrialForPlan mutation
-    #[value(name = "organizationStartTrialForPlan")]
+    #[cfg_attr(feature = "cli", value(name = "organizationStartTrialForPlan"))]
     OrganizationStartTrialForPlan,
     /// Execute organizationUpdate mutation
-    #[value(name = "organizationUpdate")]
+    #[cfg_attr(feature = "cli", value(name = "organizationUpdate"))]
     OrganizationUpdate,
     /// Execute passkeyLoginFinish mutation
-    #[value(name = "passkeyLoginFinish")]
+    #[cfg_attr(feature = "cli", value(name = "passkeyLoginFinish"))]
     PasskeyLoginFinish,
     /// Execute passkeyLoginStart mutation
-    #[value(name = "passkeyLoginStart")]
+    #[cfg_attr(feature = "cli", value(name = "passkeyLoginStart"))]
     PasskeyLoginStart,
     /// Execute projectAddLabel mutation
-    #[value(name = "projectAddLabel")]
+    #[cfg_attr(feature = "cli", value(name = "projectAddLabel"))]
     ProjectAddLabel,
     /// Execute projectArchive mutation
-    #[value(name = "projectArchive")]
+    #[cfg_attr(feature = "cli", value(name = "projectArchive"))]
     ProjectArchive,
     /// Execute projectCreate mutation
-    #[value(name = "projectCreate")]
+    #[cfg_attr(feature = "cli", value(name = "projectCreate"))]
     ProjectCreate,
     /// Execute projectDelete mutation
-    #[value(name = "projectDelete")]
+    #[cfg_attr(feature = "cli", value(name = "projectDelete"))]
     ProjectDelete,
     /// Execute projectExternalSyncDisable mutation
-    #[value(name = "projectExternalSyncDisable")]
+    #[cfg_attr(feature = "cli", value(name = "projectExternalSyncDisable"))]
     ProjectExternalSyncDisable,
     /// Execute projectLabelCreate mutation
-    #[value(name = "projectLabelCreate")]
+    #[cfg_attr(feature = "cli", value(name = "projectLabelCreate"))]
     ProjectLabelCreate,
     /// Execute projectLabelDelete mutation
-    #[value(name = "projectLabelDelete")]
+    #[cfg_attr(feature = "cli", value(name = "projectLabelDelete"))]
     ProjectLabelDelete,
     /// Execute projectLabelRestore mutation
-    #[value(name = "projectLabelRestore")]
+    #[cfg_attr(feature = "cli", value(name = "projectLabelRestore"))]
     ProjectLabelRestore,
     /// Execute projectLabelRetire mutation
-    #[value(name = "projectLabelRetire")]
+    #[cfg_attr(feature = "cli", value(name = "projectLabelRetire"))]
     ProjectLabelRetire,
     /// Execute projectLabelUpdate mutation
-    #[value(name = "projectLabelUpdate")]
+    #[cfg_attr(feature = "cli", value(name = "projectLabelUpdate"))]
     ProjectLabelUpdate,
     /// Execute projectMilestoneCreate mutation
-    #[value(name = "projectMilestoneCreate")]
+    #[cfg_attr(feature = "cli", value(name = "projectMilestoneCreate"))]
     ProjectMilestoneCreate,
     /// Execute projectMilestoneDelete mutation
-    #[value(name = "projectMilestoneDelete")]
+    #[cfg_attr(feature = "cli", value(name = "projectMilestoneDelete"))]
     ProjectMilestoneDelete,
     /// Execute projectMilestoneMove mutation
-    #[value(name = "projectMilestoneMove")]
+    #[cfg_attr(feature = "cli", value(name = "projectMilestoneMove"))]
     ProjectMilestoneMove,
     /// Execute projectMilestoneUpdate mutation
-    #[value(name = "projectMilestoneUpdate")]
+    #[cfg_attr(feature = "cli", value(name = "projectMilestoneUpdate"))]
     ProjectMilestoneUpdate,
     /// Execute projectReassignStatus mutation
-    #[value(name = "projectReassignStatus")]
+    #[cfg_attr(feature = "cli", value(name = "projectReassignStatus"))]
     ProjectReassignStatus,
     /// Execute projectRelationCreate mutation
-    #[value(name = "projectRelationCreate")]
+    #[cfg_attr(feature = "cli", value(name = "projectRelationCreate"))]
     ProjectRelationCreate,
     /// Execute projectRelationDelete mutation
-    #[value(name = "projectRelationDelete")]
+    #[cfg_attr(feature = "cli", value(name = "projectRelationDelete"))]
     ProjectRelationDelete,
     /// Execute projectRelationUpdate mutation
-    #[value(name = "projectRelationUpdate")]
+    #[cfg_attr(feature = "cli", value(name = "projectRelationUpdate"))]
     ProjectRelationUpdate,
     /// Execute projectRemoveLabel mutation
-    #[value(name = "projectRemoveLabel")]
+    #[cfg_attr(feature = "cli", value(name = "projectRemoveLabel"))]
     ProjectRemoveLabel,
     /// Execute projectStatusArchive mutation
-    #[value(name = "projectStatusArchive")]
+    #[cfg_attr(feature = "cli", value(name = "projectStatusArchive"))]
     ProjectStatusArchive,
     /// Execute projectStatusCreate mutation
-    #[value(name = "projectStatusCreate")]
+    #[cfg_attr(feature = "cli", value(name = "projectStatusCreate"))]
     ProjectStatusCreate,
     /// Execute projectStatusUnarchive mutation
-    #[value(name = "projectStatusUnarchive")]
+    #[cfg_attr(feature = "cli", value(name = "projectStatusUnarchive"))]
     ProjectStatusUnarchive,
     /// Execute projectStatusUpdate mutation
-    #[value(name = "projectStatusUpdate")]
+    #[cfg_attr(feature = "cli", value(name = "projectStatusUpdate"))]
     ProjectStatusUpdate,
     /// Execute projectUnarchive mutation
-    #[value(name = "projectUnarchive")]
+    #[cfg_attr(feature = "cli", value(name = "projectUnarchive"))]
     ProjectUnarchive,
     /// Execute projectUpdate mutation
-    #[value(name = "projectUpdate")]
+    #[cfg_attr(feature = "cli", value(name = "projectUpdate"))]
     ProjectUpdate,
     /// Execute projectUpdateArchive mutation
-    #[value(name = "projectUpdateArchive")]
+    #[cfg_attr(feature = "cli", value(name = "projectUpdateArchive"))]
     ProjectUpdateArchive,
     /// Execute projectUpdateCreate mutation
-    #[value(name = "projectUpdateCreate")]
+    #[cfg_attr(feature = "cli", value(name = "projectUpdateCreate"))]
     ProjectUpdateCreate,
     /// Execute projectUpdateDelete mutation
-    #[value(name = "projectUpdateDelete")]
+    #[cfg_attr(feature = "cli", value(name = "projectUpdateDelete"))]
     ProjectUpdateDelete,
     /// Execute projectUpdateUnarchive mutation
-    #[value(name = "projectUpdateUnarchive")]
+    #[cfg_attr(feature = "cli", value(name = "projectUpdateUnarchive"))]
     ProjectUpdateUnarchive,
     /// Execute projectUpdateUpdate mutation
-    #[value(name = "projectUpdateUpdate")]
+    #[cfg_attr(feature = "cli", value(name = "projectUpdateUpdate"))]
     ProjectUpdateUpdate,
     /// Execute pushSubscriptionCreate mutation
-    #[value(name = "pushSubscriptionCreate")]
+    #[cfg_attr(feature = "cli", value(name = "pushSubscriptionCreate"))]
     PushSubscriptionCreate,
     /// Execute pushSubscriptionDelete mutation
-    #[value(name = "pushSubscriptionDelete")]
+    #[cfg_attr(feature = "cli", value(name = "pushSubscriptionDelete"))]
     PushSubscriptionDelete,
     /// Execute reactionCreate mutation
-    #[value(name = "reactionCreate")]
+    #[cfg_attr(feature = "cli", value(name = "reactionCreate"))]
     ReactionCreate,
     /// Execute reactionDelete mutation
-    #[value(name = "reactionDelete")]
+    #[cfg_attr(feature = "cli", value(name = "reactionDelete"))]
     ReactionDelete,
     /// Execute refreshGoogleSheetsData mutation
-    #[value(name = "refreshGoogleSheetsData")]
+    #[cfg_attr(feature = "cli", value(name = "refreshGoogleSheetsData"))]
     RefreshGoogleSheetsData,
     /// Execute releaseArchive mutation
-    #[value(name = "releaseArchive")]
+    #[cfg_attr(feature = "cli", value(name = "releaseArchive"))]
     ReleaseArchive,
     /// Execute releaseCreate mutation
-    #[value(name = "releaseCreate")]
+    #[cfg_attr(feature = "cli", value(name = "releaseCreate"))]
     ReleaseCreate,
     /// Execute releasePipelineArchive mutation
-    #[value(name = "releasePipelineArchive")]
+    #[cfg_attr(feature = "cli", value(name = "releasePipelineArchive"))]
     ReleasePipelineArchive,
     /// Execute releasePipelineCreate mutation
-    #[value(name = "releasePipelineCreate")]
+    #[cfg_attr(feature = "cli", value(name = "releasePipelineCreate"))]
     ReleasePipelineCreate,
     /// Execute releasePipelineDelete mutation
-    #[value(name = "releasePipelineDelete")]
+    #[cfg_attr(feature = "cli", value(name = "releasePipelineDelete"))]
     ReleasePipelineDelete,
     /// Execute releasePipelineUnarchive mutation
-    #[value(name = "releasePipelineUnarchive")]
+    #[cfg_attr(feature = "cli", value(name = "releasePipelineUnarchive"))]
     ReleasePipelineUnarchive,
     /// Execute releasePipelineUpdate mutation
-    #[value(name = "releasePipelineUpdate")]
+    #[cfg_attr(feature = "cli", value(name = "releasePipelineUpdate"))]
     ReleasePipelineUpdate,
     /// Execute releaseStageArchive mutation
-    #[value(name = "releaseStageArchive")]
+    #[cfg_attr(feature = "cli", value(name = "releaseStageArchive"))]
     ReleaseStageArchive,
     /// Execute releaseStageCreate mutation
-    #[value(name = "releaseStageCreate")]
+    #[cfg_attr(feature = "cli", value(name = "releaseStageCreate"))]
     ReleaseStageCreate,
     /// Execute releaseStageUnarchive mutation
-    #[value(name = "releaseStageUnarchive")]
+    #[cfg_attr(feature = "cli", value(name = "releaseStageUnarchive"))]
     ReleaseStageUnarchive,
     /// Execute releaseStageUpdate mutation
-    #[value(name = "releaseStageUpdate")]
+    #[cfg_attr(feature = "cli", value(name = "releaseStageUpdate"))]
     ReleaseStageUpdate,
     /// Execute releaseUnarchive mutation
-    #[value(name = "releaseUnarchive")]
+    #[cfg_attr(feature = "cli", value(name = "releaseUnarchive"))]
     ReleaseUnarchive,
     /// Execute releaseUpdate mutation
-    #[value(name = "releaseUpdate")]
+    #[cfg_attr(feature = "cli", value(name = "releaseUpdate"))]
     ReleaseUpdate,
     /// Execute resendOrganizationInvite mutation
-    #[value(name = "resendOrganizationInvite")]
+    #[cfg_attr(feature = "cli", value(name = "resendOrganizationInvite"))]
     ResendOrganizationInvite,
     /// Execute resendOrganizationInviteByEmail mutation
-    #[value(name = "resendOrganizationInviteByEmail")]
+    #[cfg_attr(feature = "cli", value(name = "resendOrganizationInviteByEmail"))]
     ResendOrganizationInviteByEmail,
     /// Execute roadmapArchive mutation
-    #[value(name = "roadmapArchive")]
+    #[cfg_attr(feature = "cli", value(name = "roadmapArchive"))]
     RoadmapArchive,
     /// Execute roadmapCreate mutation
-    #[value(name = "roadmapCreate")]
+    #[cfg_attr(feature = "cli", value(name = "roadmapCreate"))]
     RoadmapCreate,
     /// Execute roadmapDelete mutation
-    #[value(name = "roadmapDelete")]
+    #[cfg_attr(feature = "cli", value(name = "roadmapDelete"))]
     RoadmapDelete,
     /// Execute roadmapToProjectCreate mutation
-    #[value(name = "roadmapToProjectCreate")]
+    #[cfg_attr(feature = "cli", value(name = "roadmapToProjectCreate"))]
     RoadmapToProjectCreate,
     /// Execute roadmapToProjectDelete mutation
-    #[value(name = "roadmapToProjectDelete")]
+    #[cfg_attr(feature = "cli", value(name = "roadmapToProjectDelete"))]
     RoadmapToProjectDelete,
     /// Execute roadmapToProjectUpdate mutation
-    #[value(name = "roadmapToProjectUpdate")]
+    #[cfg_attr(feature = "cli", value(name = "roadmapToProjectUpdate"))]
     RoadmapToProjectUpdate,
     /// Execute roadmapUnarchive mutation
-    #[value(name = "roadmapUnarchive")]
+    #[cfg_attr(feature = "cli", value(name = "roadmapUnarchive"))]
     RoadmapUnarchive,
     /// Execute roadmapUpdate mutation
-    #[value(name = "roadmapUpdate")]
+    #[cfg_attr(feature = "cli", value(name = "roadmapUpdate"))]
     RoadmapUpdate,
     /// Execute samlTokenUserAccountAuth mutation
-    #[value(name = "samlTokenUserAccountAuth")]
+    #[cfg_attr(feature = "cli", value(name = "samlTokenUserAccountAuth"))]
     SamlTokenUserAccountAuth,
     /// Execute teamCreate mutation
-    #[value(name = "teamCreate")]
+    #[cfg_attr(feature = "cli", value(name = "teamCreate"))]
     TeamCreate,
     /// Execute teamCyclesDelete mutation
-    #[value(name = "teamCyclesDelete")]
+    #[cfg_attr(feature = "cli", value(name = "teamCyclesDelete"))]
     TeamCyclesDelete,
     /// Execute teamDelete mutation
-    #[value(name = "teamDelete")]
+    #[cfg_attr(feature = "cli", value(name = "teamDelete"))]
     TeamDelete,
     /// Execute teamKeyDelete mutation
-    #[value(name = "teamKeyDelete")]
+    #[cfg_attr(feature = "cli", value(name = "teamKeyDelete"))]
     TeamKeyDelete,
     /// Execute teamMembershipCreate mutation
-    #[value(name = "teamMembershipCreate")]
+    #[cfg_attr(feature = "cli", value(name = "teamMembershipCreate"))]
     TeamMembershipCreate,
     /// Execute teamMembershipDelete mutation
-    #[value(name = "teamMembershipDelete")]
+    #[cfg_attr(feature = "cli", value(name = "teamMembershipDelete"))]
     TeamMembershipDelete,
     /// Execute teamMembershipUpdate mutation
-    #[value(name = "teamMembershipUpdate")]
+    #[cfg_attr(feature = "cli", value(name = "teamMembershipUpdate"))]
     TeamMembershipUpdate,
     /// Execute teamUnarchive mutation
-    #[value(name = "teamUnarchive")]
+    #[cfg_attr(feature = "cli", value(name = "teamUnarchive"))]
     TeamUnarchive,
     /// Execute teamUpdate mutation
-    #[value(name = "teamUpdate")]
+    #[cfg_attr(feature = "cli", value(name = "teamUpdate"))]
     TeamUpdate,
     /// Execute templateCreate mutation
-    #[value(name = "templateCreate")]
+    #[cfg_attr(feature = "cli", value(name = "templateCreate"))]
     TemplateCreate,
     /// Execute templateDelete mutation
-    #[value(name = "templateDelete")]
+    #[cfg_attr(feature = "cli", value(name = "templateDelete"))]
     TemplateDelete,
     /// Execute templateUpdate mutation
-    #[value(name = "templateUpdate")]
+    #[cfg_attr(feature = "cli", value(name = "templateUpdate"))]
     TemplateUpdate,
     /// Execute timeScheduleCreate mutation
-    #[value(name = "timeScheduleCreate")]
+    #[cfg_attr(feature = "cli", value(name = "timeScheduleCreate"))]
     TimeScheduleCreate,
     /// Execute timeScheduleDelete mutation
-    #[value(name = "timeScheduleDelete")]
+    #[cfg_attr(feature = "cli", value(name = "timeScheduleDelete"))]
     TimeScheduleDelete,
     /// Execute timeScheduleRefreshIntegrationSchedule mutation
-    #[value(name = "timeScheduleRefreshIntegrationSchedule")]
+    #[cfg_attr(feature = "cli", value(name = "timeScheduleRefreshIntegrationSchedule"))]
     TimeScheduleRefreshIntegrationSchedule,
     /// Execute timeScheduleUpdate mutation
-    #[value(name = "timeScheduleUpdate")]
+    #[cfg_attr(feature = "cli", value(name = "timeScheduleUpdate"))]
     TimeScheduleUpdate,
     /// Execute timeScheduleUpsertExternal mutation
-    #[value(name = "timeScheduleUpsertExternal")]
+    #[cfg_attr(feature = "cli", value(name = "timeScheduleUpsertExternal"))]
     TimeScheduleUpsertExternal,
     /// Execute triageResponsibilityCreate mutation
-    #[value(name = "triageResponsibilityCreate")]
+    #[cfg_attr(feature = "cli", value(name = "triageResponsibilityCreate"))]
     TriageResponsibilityCreate,
     /// Execute triageResponsibilityDelete mutation
-    #[value(name = "triageResponsibilityDelete")]
+    #[cfg_attr(feature = "cli", value(name = "triageResponsibilityDelete"))]
     TriageResponsibilityDelete,
     /// Execute triageResponsibilityUpdate mutation
-    #[value(name = "triageResponsibilityUpdate")]
+    #[cfg_attr(feature = "cli", value(name = "triageResponsibilityUpdate"))]
     TriageResponsibilityUpdate,
     /// Execute updateIntegrationSlackScopes mutation
-    #[value(name = "updateIntegrationSlackScopes")]
+    #[cfg_attr(feature = "cli", value(name = "updateIntegrationSlackScopes"))]
     UpdateIntegrationSlackScopes,
     /// Execute userChangeRole mutation
-    #[value(name = "userChangeRole")]
+    #[cfg_attr(feature = "cli", value(name = "userChangeRole"))]
     UserChangeRole,
     /// Execute userDemoteAdmin mutation
-    #[value(name = "userDemoteAdmin")]
+    #[cfg_attr(feature = "cli", value(name = "userDemoteAdmin"))]
     UserDemoteAdmin,
     /// Execute userDemoteMember mutation
-    #[value(name = "userDemoteMember")]
+    #[cfg_attr(feature = "cli", value(name = "userDemoteMember"))]
     UserDemoteMember,
     /// Execute userDiscordConnect mutation
-    #[value(name = "userDiscordConnect")]
+    #[cfg_attr(feature = "cli", value(name = "userDiscordConnect"))]
     UserDiscordConnect,
     /// Execute userExternalUserDisconnect mutation
-    #[value(name = "userExternalUserDisconnect")]
+    #[cfg_attr(feature = "cli", value(name = "userExternalUserDisconnect"))]
     UserExternalUserDisconnect,
     /// Execute userFlagUpdate mutation
-    #[value(name = "userFlagUpdate")]
+    #[cfg_attr(feature = "cli", value(name = "userFlagUpdate"))]
     UserFlagUpdate,
     /// Execute userPromoteAdmin mutation
-    #[value(name = "userPromoteAdmin")]
+    #[cfg_attr(feature = "cli", value(name = "userPromoteAdmin"))]
     UserPromoteAdmin,
     /// Execute userPromoteMember mutation
-    #[value(name = "userPromoteMember")]
+    #[cfg_attr(feature = "cli", value(name = "userPromoteMember"))]
     UserPromoteMember,
     /// Execute userSettingsFlagsReset mutation
-    #[value(name = "userSettingsFlagsReset")]
+    #[cfg_attr(feature = "cli", value(name = "userSettingsFlagsReset"))]
     UserSettingsFlagsReset,
     /// Execute userSettingsUpdate mutation
-    #[value(name = "userSettingsUpdate")]
+    #[cfg_attr(feature = "cli", value(name = "userSettingsUpdate"))]
     UserSettingsUpdate,
     /// Execute userSuspend mutation
-    #[value(name = "userSuspend")]
+    #[cfg_attr(feature = "cli", value(name = "userSuspend"))]
     UserSuspend,
     /// Execute userUnlinkFromIdentityProvider mutation
-    #[value(name = "userUnlinkFromIdentityProvider")]
+    #[cfg_attr(feature = "cli", value(name = "userUnlinkFromIdentityProvider"))]
     UserUnlinkFromIdentityProvider,
     /// Execute userUnsuspend mutation
-    #[value(name = "userUnsuspend")]
+    #[cfg_attr(feature = "cli", value(name = "userUnsuspend"))]
     UserUnsuspend,
     /// Execute userUpdate mutation
-    #[value(name = "userUpdate")]
+    #[cfg_attr(feature = "cli", value(name = "userUpdate"))]
     UserUpdate,
     /// Execute viewPreferencesCreate mutation
-    #[value(name = "viewPreferencesCreate")]
+    #[cfg_attr(feature = "cli", value(name = "viewPreferencesCreate"))]
     ViewPreferencesCreate,
     /// Execute viewPreferencesDelete mutation
-    #[value(name = "viewPreferencesDelete")]
+    #[cfg_attr(feature = "cli", value(name = "viewPreferencesDelete"))]
     ViewPreferencesDelete,
     /// Execute viewPreferencesUpdate mutation
-    #[value(name = "viewPreferencesUpdate")]
+    #[cfg_attr(feature = "cli", value(name = "viewPreferencesUpdate"))]
     ViewPreferencesUpdate,
     /// Execute webhookCreate mutation
-    #[value(name = "webhookCreate")]
+    #[cfg_attr(feature = "cli", value(name = "webhookCreate"))]
     WebhookCreate,
     /// Execute webhookDelete mutation
-    #[value(name = "webhookDelete")]
+    #[cfg_attr(feature = "cli", value(name = "webhookDelete"))]
     WebhookDelete,
     /// Execute webhookUpdate mutation
-    #[value(name = "webhookUpdate")]
+    #[cfg_attr(feature = "cli", value(name = "webhookUpdate"))]
     WebhookUpdate,
     /// Execute workflowStateArchive mutation
-    #[value(name = "workflowStateArchive")]
+    #[cfg_attr(feature = "cli", value(name = "workflowStateArchive"))]
     WorkflowStateArchive,
     /// Execute workflowStateCreate mutation
-    #[value(name = "workflowStateCreate")]
+    #[cfg_attr(feature = "cli", value(name = "workflowStateCreate"))]
     WorkflowStateCreate,
     /// Execute workflowStateUpdate mutation
-    #[value(name = "workflowStateUpdate")]
+    #[cfg_attr(feature = "cli", value(name = "workflowStateUpdate"))]
     WorkflowStateUpdate,
 }
 
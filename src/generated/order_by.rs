@@ -1,11 +1,11 @@
 //! Generated order_by enum - DO NOT EDIT
 //! Run `cargo xtask codegen` to regenerate
 
-use clap::ValueEnum;
 use std::fmt;
 
 /// Pagination order by field
-#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
 pub enum OrderBy {
     /// Order by createdAt
     CreatedAt,
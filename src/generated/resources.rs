@@ -1,431 +1,430 @@
 //! Generated resource types - DO NOT EDIT
 //! Run `cargo xtask codegen` to regenerate
 
-use clap::ValueEnum;
-
 /// Available query resources derived from Linear's GraphQL schema
-#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
-#[value(rename_all = "camelCase")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
+#[cfg_attr(feature = "cli", value(rename_all = "camelCase"))]
 pub enum Resource {
     /// Query administrableTeams
-    #[value(name = "administrableTeams")]
+    #[cfg_attr(feature = "cli", value(name = "administrableTeams"))]
     AdministrableTeams,
     /// Query agentActivities
-    #[value(name = "agentActivities")]
+    #[cfg_attr(feature = "cli", value(name = "agentActivities"))]
     AgentActivities,
     /// Query agentActivity
-    #[value(name = "agentActivity")]
+    #[cfg_attr(feature = "cli", value(name = "agentActivity"))]
     AgentActivity,
     /// Query agentSession
-    #[value(name = "agentSession")]
+    #[cfg_attr(feature = "cli", value(name = "agentSession"))]
     AgentSession,
     /// Query agentSessions
-    #[value(name = "agentSessions")]
+    #[cfg_attr(feature = "cli", value(name = "agentSessions"))]
     AgentSessions,
     /// Query applicationInfo
-    #[value(name = "applicationInfo")]
+    #[cfg_attr(feature = "cli", value(name = "applicationInfo"))]
     ApplicationInfo,
     /// Query archivedTeams
-    #[value(name = "archivedTeams")]
+    #[cfg_attr(feature = "cli", value(name = "archivedTeams"))]
     ArchivedTeams,
     /// Query attachment
-    #[value(name = "attachment")]
+    #[cfg_attr(feature = "cli", value(name = "attachment"))]
     Attachment,
     /// Query attachmentIssue
-    #[value(name = "attachmentIssue")]
+    #[cfg_attr(feature = "cli", value(name = "attachmentIssue"))]
     AttachmentIssue,
     /// Query attachmentSources
-    #[value(name = "attachmentSources")]
+    #[cfg_attr(feature = "cli", value(name = "attachmentSources"))]
     AttachmentSources,
     /// Query attachments
-    #[value(name = "attachments")]
+    #[cfg_attr(feature = "cli", value(name = "attachments"))]
     Attachments,
     /// Query attachmentsForURL
-    #[value(name = "attachmentsForURL")]
+    #[cfg_attr(feature = "cli", value(name = "attachmentsForURL"))]
     AttachmentsForURL,
     /// Query auditEntries
-    #[value(name = "auditEntries")]
+    #[cfg_attr(feature = "cli", value(name = "auditEntries"))]
     AuditEntries,
     /// Query auditEntryTypes
-    #[value(name = "auditEntryTypes")]
+    #[cfg_attr(feature = "cli", value(name = "auditEntryTypes"))]
     AuditEntryTypes,
     /// Query authenticationSessions
-    #[value(name = "authenticationSessions")]
+    #[cfg_attr(feature = "cli", value(name = "authenticationSessions"))]
     AuthenticationSessions,
     /// Query availableUsers
-    #[value(name = "availableUsers")]
+    #[cfg_attr(feature = "cli", value(name = "availableUsers"))]
     AvailableUsers,
     /// Query comment
-    #[value(name = "comment")]
+    #[cfg_attr(feature = "cli", value(name = "comment"))]
     Comment,
     /// Query comments
-    #[value(name = "comments")]
+    #[cfg_attr(feature = "cli", value(name = "comments"))]
     Comments,
     /// Query customView
-    #[value(name = "customView")]
+    #[cfg_attr(feature = "cli", value(name = "customView"))]
     CustomView,
     /// Query customViewDetailsSuggestion
-    #[value(name = "customViewDetailsSuggestion")]
+    #[cfg_attr(feature = "cli", value(name = "customViewDetailsSuggestion"))]
     CustomViewDetailsSuggestion,
     /// Query customViewHasSubscribers
-    #[value(name = "customViewHasSubscribers")]
+    #[cfg_attr(feature = "cli", value(name = "customViewHasSubscribers"))]
     CustomViewHasSubscribers,
     /// Query customViews
-    #[value(name = "customViews")]
+    #[cfg_attr(feature = "cli", value(name = "customViews"))]
     CustomViews,
     /// Query customer
-    #[value(name = "customer")]
+    #[cfg_attr(feature = "cli", value(name = "customer"))]
     Customer,
     /// Query customerNeed
-    #[value(name = "customerNeed")]
+    #[cfg_attr(feature = "cli", value(name = "customerNeed"))]
     CustomerNeed,
     /// Query customerNeeds
-    #[value(name = "customerNeeds")]
+    #[cfg_attr(feature = "cli", value(name = "customerNeeds"))]
     CustomerNeeds,
     /// Query customerStatus
-    #[value(name = "customerStatus")]
+    #[cfg_attr(feature = "cli", value(name = "customerStatus"))]
     CustomerStatus,
     /// Query customerStatuses
-    #[value(name = "customerStatuses")]
+    #[cfg_attr(feature = "cli", value(name = "customerStatuses"))]
     CustomerStatuses,
     /// Query customerTier
-    #[value(name = "customerTier")]
+    #[cfg_attr(feature = "cli", value(name = "customerTier"))]
     CustomerTier,
     /// Query customerTiers
-    #[value(name = "customerTiers")]
+    #[cfg_attr(feature = "cli", value(name = "customerTiers"))]
     CustomerTiers,
     /// Query customers
-    #[value(name = "customers")]
+    #[cfg_attr(feature = "cli", value(name = "customers"))]
     Customers,
     /// Query cycle
-    #[value(name = "cycle")]
+    #[cfg_attr(feature = "cli", value(name = "cycle"))]
     Cycle,
     /// Query cycles
-    #[value(name = "cycles")]
+    #[cfg_attr(feature = "cli", value(name = "cycles"))]
     Cycles,
     /// Query document
-    #[value(name = "document")]
+    #[cfg_attr(feature = "cli", value(name = "document"))]
     Document,
     /// Query documentContentHistory
-    #[value(name = "documentContentHistory")]
+    #[cfg_attr(feature = "cli", value(name = "documentContentHistory"))]
     DocumentContentHistory,
     /// Query documents
-    #[value(name = "documents")]
+    #[cfg_attr(feature = "cli", value(name = "documents"))]
     Documents,
     /// Query emailIntakeAddress
-    #[value(name = "emailIntakeAddress")]
+    #[cfg_attr(feature = "cli", value(name = "emailIntakeAddress"))]
     EmailIntakeAddress,
     /// Query emoji
-    #[value(name = "emoji")]
+    #[cfg_attr(feature = "cli", value(name = "emoji"))]
     Emoji,
     /// Query emojis
-    #[value(name = "emojis")]
+    #[cfg_attr(feature = "cli", value(name = "emojis"))]
     Emojis,
     /// Query entityExternalLink
-    #[value(name = "entityExternalLink")]
+    #[cfg_attr(feature = "cli", value(name = "entityExternalLink"))]
     EntityExternalLink,
     /// Query externalUser
-    #[value(name = "externalUser")]
+    #[cfg_attr(feature = "cli", value(name = "externalUser"))]
     ExternalUser,
     /// Query externalUsers
-    #[value(name = "externalUsers")]
+    #[cfg_attr(feature = "cli", value(name = "externalUsers"))]
     ExternalUsers,
     /// Query failuresForOauthWebhooks
-    #[value(name = "failuresForOauthWebhooks")]
+    #[cfg_attr(feature = "cli", value(name = "failuresForOauthWebhooks"))]
     FailuresForOauthWebhooks,
     /// Query favorite
-    #[value(name = "favorite")]
+    #[cfg_attr(feature = "cli", value(name = "favorite"))]
     Favorite,
     /// Query favorites
-    #[value(name = "favorites")]
+    #[cfg_attr(feature = "cli", value(name = "favorites"))]
     Favorites,
     /// Query fetchData
-    #[value(name = "fetchData")]
+    #[cfg_attr(feature = "cli", value(name = "fetchData"))]
     FetchData,
     /// Query initiative
-    #[value(name = "initiative")]
+    #[cfg_attr(feature = "cli", value(name = "initiative"))]
     Initiative,
     /// Query initiativeRelation
-    #[value(name = "initiativeRelation")]
+    #[cfg_attr(feature = "cli", value(name = "initiativeRelation"))]
     InitiativeRelation,
     /// Query initiativeRelations
-    #[value(name = "initiativeRelations")]
+    #[cfg_attr(feature = "cli", value(name = "initiativeRelations"))]
     InitiativeRelations,
     /// Query initiativeToProject
-    #[value(name = "initiativeToProject")]
+    #[cfg_attr(feature = "cli", value(name = "initiativeToProject"))]
     InitiativeToProject,
     /// Query initiativeToProjects
-    #[value(name = "initiativeToProjects")]
+    #[cfg_attr(feature = "cli", value(name = "initiativeToProjects"))]
     InitiativeToProjects,
     /// Query initiativeUpdate
-    #[value(name = "initiativeUpdate")]
+    #[cfg_attr(feature = "cli", value(name = "initiativeUpdate"))]
     InitiativeUpdate,
     /// Query initiativeUpdates
-    #[value(name = "initiativeUpdates")]
+    #[cfg_attr(feature = "cli", value(name = "initiativeUpdates"))]
     InitiativeUpdates,
     /// Query initiatives
-    #[value(name = "initiatives")]
+    #[cfg_attr(feature = "cli", value(name = "initiatives"))]
     Initiatives,
     /// Query integration
-    #[value(name = "integration")]
+    #[cfg_attr(feature = "cli", value(name = "integration"))]
     Integration,
     /// Query integrationHasScopes
-    #[value(name = "integrationHasScopes")]
+    #[cfg_attr(feature = "cli", value(name = "integrationHasScopes"))]
     IntegrationHasScopes,
     /// Query integrationTemplate
-    #[value(name = "integrationTemplate")]
+    #[cfg_attr(feature = "cli", value(name = "integrationTemplate"))]
     IntegrationTemplate,
     /// Query integrationTemplates
-    #[value(name = "integrationTemplates")]
+    #[cfg_attr(feature = "cli", value(name = "integrationTemplates"))]
     IntegrationTemplates,
     /// Query integrations
-    #[value(name = "integrations")]
+    #[cfg_attr(feature = "cli", value(name = "integrations"))]
     Integrations,
     /// Query integrationsSettings
-    #[value(name = "integrationsSettings")]
+    #[cfg_attr(feature = "cli", value(name = "integrationsSettings"))]
     IntegrationsSettings,
     /// Query issue
-    #[value(name = "issue")]
+    #[cfg_attr(feature = "cli", value(name = "issue"))]
     Issue,
     /// Query issueFigmaFileKeySearch
-    #[value(name = "issueFigmaFileKeySearch")]
+    #[cfg_attr(feature = "cli", value(name = "issueFigmaFileKeySearch"))]
     IssueFigmaFileKeySearch,
     /// Query issueFilterSuggestion
-    #[value(name = "issueFilterSuggestion")]
+    #[cfg_attr(feature = "cli", value(name = "issueFilterSuggestion"))]
     IssueFilterSuggestion,
     /// Query issueImportCheckCSV
-    #[value(name = "issueImportCheckCSV")]
+    #[cfg_attr(feature = "cli", value(name = "issueImportCheckCSV"))]
     IssueImportCheckCSV,
     /// Query issueImportCheckSync
-    #[value(name = "issueImportCheckSync")]
+    #[cfg_attr(feature = "cli", value(name = "issueImportCheckSync"))]
     IssueImportCheckSync,
     /// Query issueImportJqlCheck
-    #[value(name = "issueImportJqlCheck")]
+    #[cfg_attr(feature = "cli", value(name = "issueImportJqlCheck"))]
     IssueImportJqlCheck,
     /// Query issueLabel
-    #[value(name = "issueLabel")]
+    #[cfg_attr(feature = "cli", value(name = "issueLabel"))]
     IssueLabel,
     /// Query issueLabels
-    #[value(name = "issueLabels")]
+    #[cfg_attr(feature = "cli", value(name = "issueLabels"))]
     IssueLabels,
     /// Query issuePriorityValues
-    #[value(name = "issuePriorityValues")]
+    #[cfg_attr(feature = "cli", value(name = "issuePriorityValues"))]
     IssuePriorityValues,
     /// Query issueRelation
-    #[value(name = "issueRelation")]
+    #[cfg_attr(feature = "cli", value(name = "issueRelation"))]
     IssueRelation,
     /// Query issueRelations
-    #[value(name = "issueRelations")]
+    #[cfg_attr(feature = "cli", value(name = "issueRelations"))]
     IssueRelations,
     /// Query issueRepositorySuggestions
-    #[value(name = "issueRepositorySuggestions")]
+    #[cfg_attr(feature = "cli", value(name = "issueRepositorySuggestions"))]
     IssueRepositorySuggestions,
     /// Query issueSearch
-    #[value(name = "issueSearch")]
+    #[cfg_attr(feature = "cli", value(name = "issueSearch"))]
     IssueSearch,
     /// Query issueTitleSuggestionFromCustomerRequest
-    #[value(name = "issueTitleSuggestionFromCustomerRequest")]
+    #[cfg_attr(feature = "cli", value(name = "issueTitleSuggestionFromCustomerRequest"))]
     IssueTitleSuggestionFromCustomerRequest,
     /// Query issueToRelease
-    #[value(name = "issueToRelease")]
+    #[cfg_attr(feature = "cli", value(name = "issueToRelease"))]
     IssueToRelease,
     /// Query issueToReleases
-    #[value(name = "issueToReleases")]
+    #[cfg_attr(feature = "cli", value(name = "issueToReleases"))]
     IssueToReleases,
     /// Query issueVcsBranchSearch
-    #[value(name = "issueVcsBranchSearch")]
+    #[cfg_attr(feature = "cli", value(name = "issueVcsBranchSearch"))]
     IssueVcsBranchSearch,
     /// Query issues
-    #[value(name = "issues")]
+    #[cfg_attr(feature = "cli", value(name = "issues"))]
     Issues,
     /// Query notification
-    #[value(name = "notification")]
+    #[cfg_attr(feature = "cli", value(name = "notification"))]
     Notification,
     /// Query notificationSubscription
-    #[value(name = "notificationSubscription")]
+    #[cfg_attr(feature = "cli", value(name = "notificationSubscription"))]
     NotificationSubscription,
     /// Query notificationSubscriptions
-    #[value(name = "notificationSubscriptions")]
+    #[cfg_attr(feature = "cli", value(name = "notificationSubscriptions"))]
     NotificationSubscriptions,
     /// Query notifications
-    #[value(name = "notifications")]
+    #[cfg_attr(feature = "cli", value(name = "notifications"))]
     Notifications,
     /// Query notificationsUnreadCount
-    #[value(name = "notificationsUnreadCount")]
+    #[cfg_attr(feature = "cli", value(name = "notificationsUnreadCount"))]
     NotificationsUnreadCount,
     /// Query organization
-    #[value(name = "organization")]
+    #[cfg_attr(feature = "cli", value(name = "organization"))]
     Organization,
     /// Query organizationDomainClaimRequest
-    #[value(name = "organizationDomainClaimRequest")]
+    #[cfg_attr(feature = "cli", value(name = "organizationDomainClaimRequest"))]
     OrganizationDomainClaimRequest,
     /// Query organizationExists
-    #[value(name = "organizationExists")]
+    #[cfg_attr(feature = "cli", value(name = "organizationExists"))]
     OrganizationExists,
     /// Query organizationInvite
-    #[value(name = "organizationInvite")]
+    #[cfg_attr(feature = "cli", value(name = "organizationInvite"))]
     OrganizationInvite,
     /// Query organizationInviteDetails
-    #[value(name = "organizationInviteDetails")]
+    #[cfg_attr(feature = "cli", value(name = "organizationInviteDetails"))]
     OrganizationInviteDetails,
     /// Query organizationInvites
-    #[value(name = "organizationInvites")]
+    #[cfg_attr(feature = "cli", value(name = "organizationInvites"))]
     OrganizationInvites,
     /// Query organizationMeta
-    #[value(name = "organizationMeta")]
+    #[cfg_attr(feature = "cli", value(name = "organizationMeta"))]
     OrganizationMeta,
     /// Query project
-    #[value(name = "project")]
+    #[cfg_attr(feature = "cli", value(name = "project"))]
     Project,
     /// Query projectFilterSuggestion
-    #[value(name = "projectFilterSuggestion")]
+    #[cfg_attr(feature = "cli", value(name = "projectFilterSuggestion"))]
     ProjectFilterSuggestion,
     /// Query projectLabel
-    #[value(name = "projectLabel")]
+    #[cfg_attr(feature = "cli", value(name = "projectLabel"))]
     ProjectLabel,
     /// Query projectLabels
-    #[value(name = "projectLabels")]
+    #[cfg_attr(feature = "cli", value(name = "projectLabels"))]
     ProjectLabels,
     /// Query projectMilestone
-    #[value(name = "projectMilestone")]
+    #[cfg_attr(feature = "cli", value(name = "projectMilestone"))]
     ProjectMilestone,
     /// Query projectMilestones
-    #[value(name = "projectMilestones")]
+    #[cfg_attr(feature = "cli", value(name = "projectMilestones"))]
     ProjectMilestones,
     /// Query projectRelation
-    #[value(name = "projectRelation")]
+    #[cfg_attr(feature = "cli", value(name = "projectRelation"))]
     ProjectRelation,
     /// Query projectRelations
-    #[value(name = "projectRelations")]
+    #[cfg_attr(feature = "cli", value(name = "projectRelations"))]
     ProjectRelations,
     /// Query projectStatus
-    #[value(name = "projectStatus")]
+    #[cfg_attr(feature = "cli", value(name = "projectStatus"))]
     ProjectStatus,
     /// Query projectStatusProjectCount
-    #[value(name = "projectStatusProjectCount")]
+    #[cfg_attr(feature = "cli", value(name = "projectStatusProjectCount"))]
     ProjectStatusProjectCount,
     /// Query projectStatuses
-    #[value(name = "projectStatuses")]
+    #[cfg_attr(feature = "cli", value(name = "projectStatuses"))]
     ProjectStatuses,
     /// Query projectUpdate
-    #[value(name = "projectUpdate")]
+    #[cfg_attr(feature = "cli", value(name = "projectUpdate"))]
     ProjectUpdate,
     /// Query projectUpdates
-    #[value(name = "projectUpdates")]
+    #[cfg_attr(feature = "cli", value(name = "projectUpdates"))]
     ProjectUpdates,
     /// Query projects
-    #[value(name = "projects")]
+    #[cfg_attr(feature = "cli", value(name = "projects"))]
     Projects,
     /// Query pushSubscriptionTest
-    #[value(name = "pushSubscriptionTest")]
+    #[cfg_attr(feature = "cli", value(name = "pushSubscriptionTest"))]
     PushSubscriptionTest,
     /// Query rateLimitStatus
-    #[value(name = "rateLimitStatus")]
+    #[cfg_attr(feature = "cli", value(name = "rateLimitStatus"))]
     RateLimitStatus,
     /// Query release
-    #[value(name = "release")]
+    #[cfg_attr(feature = "cli", value(name = "release"))]
     Release,
     /// Query releasePipeline
-    #[value(name = "releasePipeline")]
+    #[cfg_attr(feature = "cli", value(name = "releasePipeline"))]
     ReleasePipeline,
     /// Query releasePipelines
-    #[value(name = "releasePipelines")]
+    #[cfg_attr(feature = "cli", value(name = "releasePipelines"))]
     ReleasePipelines,
     /// Query releaseStage
-    #[value(name = "releaseStage")]
+    #[cfg_attr(feature = "cli", value(name = "releaseStage"))]
     ReleaseStage,
     /// Query releaseStages
-    #[value(name = "releaseStages")]
+    #[cfg_attr(feature = "cli", value(name = "releaseStages"))]
     ReleaseStages,
     /// Query releases
-    #[value(name = "releases")]
+    #[cfg_attr(feature = "cli", value(name = "releases"))]
     Releases,
     /// Query roadmap
-    #[value(name = "roadmap")]
+    #[cfg_attr(feature = "cli", value(name = "roadmap"))]
     Roadmap,
     /// Query roadmapToProject
-    #[value(name = "roadmapToProject")]
+    #[cfg_attr(feature = "cli", value(name = "roadmapToProject"))]
     RoadmapToProject,
     /// Query roadmapToProjects
-    #[value(name = "roadmapToProjects")]
+    #[cfg_attr(feature = "cli", value(name = "roadmapToProjects"))]
     RoadmapToProjects,
     /// Query roadmaps
-    #[value(name = "roadmaps")]
+    #[cfg_attr(feature = "cli", value(name = "roadmaps"))]
     Roadmaps,
     /// Query searchDocuments
-    #[value(name = "searchDocuments")]
+    #[cfg_attr(feature = "cli", value(name = "searchDocuments"))]
     SearchDocuments,
     /// Query searchIssues
-    #[value(name = "searchIssues")]
+    #[cfg_attr(feature = "cli", value(name = "searchIssues"))]
     SearchIssues,
     /// Query searchProjects
-    #[value(name = "searchProjects")]
+    #[cfg_attr(feature = "cli", value(name = "searchProjects"))]
     SearchProjects,
     /// Query semanticSearch
-    #[value(name = "semanticSearch")]
+    #[cfg_attr(feature = "cli", value(name = "semanticSearch"))]
     SemanticSearch,
     /// Query ssoUrlFromEmail
-    #[value(name = "ssoUrlFromEmail")]
+    #[cfg_attr(feature = "cli", value(name = "ssoUrlFromEmail"))]
     SsoUrlFromEmail,
     /// Query team
-    #[value(name = "team")]
+    #[cfg_attr(feature = "cli", value(name = "team"))]
     Team,
     /// Query teamMembership
-    #[value(name = "teamMembership")]
+    #[cfg_attr(feature = "cli", value(name = "teamMembership"))]
     TeamMembership,
     /// Query teamMemberships
-    #[value(name = "teamMemberships")]
+    #[cfg_attr(feature = "cli", value(name = "teamMemberships"))]
     TeamMemberships,
     /// Query teams
-    #[value(name = "teams")]
+    #[cfg_attr(feature = "cli", value(name = "teams"))]
     Teams,
     /// Query template
-    #[value(name = "template")]
+    #[cfg_attr(feature = "cli", value(name = "template"))]
     Template,
     /// Query templates
-    #[value(name = "templates")]
+    #[cfg_attr(feature = "cli", value(name = "templates"))]
     Templates,
     /// Query templatesForIntegration
-    #[value(name = "templatesForIntegration")]
+    #[cfg_attr(feature = "cli", value(name = "templatesForIntegration"))]
     TemplatesForIntegration,
     /// Query timeSchedule
-    #[value(name = "timeSchedule")]
+    #[cfg_attr(feature = "cli", value(name = "timeSchedule"))]
     TimeSchedule,
     /// Query timeSchedules
-    #[value(name = "timeSchedules")]
+    #[cfg_attr(feature = "cli", value(name = "timeSchedules"))]
     TimeSchedules,
     /// Query triageResponsibilities
-    #[value(name = "triageResponsibilities")]
+    #[cfg_attr(feature = "cli", value(name = "triageResponsibilities"))]
     TriageResponsibilities,
     /// Query triageResponsibility
-    #[value(name = "triageResponsibility")]
+    #[cfg_attr(feature = "cli", value(name = "triageResponsibility"))]
     TriageResponsibility,
     /// Query user
-    #[value(name = "user")]
+    #[cfg_attr(feature = "cli", value(name = "user"))]
     User,
     /// Query userSettings
-    #[value(name = "userSettings")]
+    #[cfg_attr(feature = "cli", value(name = "userSettings"))]
     UserSettings,
     /// Query users
-    #[value(name = "users")]
+    #[cfg_attr(feature = "cli", value(name = "users"))]
     Users,
     /// Query verifyGitHubEnterpriseServerInstallation
-    #[value(name = "verifyGitHubEnterpriseServerInstallation")]
+    #[cfg_attr(feature = "cli", value(name = "verifyGitHubEnterpriseServerInstallation"))]
     VerifyGitHubEnterpriseServerInstallation,
     /// Query viewer
-    #[value(name = "viewer")]
+    #[cfg_attr(feature = "cli", value(name = "viewer"))]
     Viewer,
     /// Query webhook
-    #[value(name = "webhook")]
+    #[cfg_attr(feature = "cli", value(name = "webhook"))]
     Webhook,
     /// Query webhooks
-    #[value(name = "webhooks")]
+    #[cfg_attr(feature = "cli", value(name = "webhooks"))]
     Webhooks,
     /// Query workflowState
-    #[value(name = "workflowState")]
+    #[cfg_attr(feature = "cli", value(name = "workflowState"))]
     WorkflowState,
     /// Query workflowStates
-    #[value(name = "workflowStates")]
+    #[cfg_attr(feature = "cli", value(name = "workflowStates"))]
     WorkflowStates,
 }
 
@@ -0,0 +1,125 @@
+//! Minimal HTTP/1.1 connection loop shared by the local servers
+//!
+//! Reads keep-alive requests with bounded headers and bodies, hands each POST
+//! to a handler and writes its JSON response. The fake API and the webhook
+//! receiver only differ in the handler and their error body shape.
+
+use std::future::Future;
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::TcpStream;
+
+/// Largest request line plus headers accepted (bytes)
+const MAX_HEADER_BYTES: usize = 64 * 1024;
+
+/// One POST request
+pub struct Request {
+    /// Header names are lowercased
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+}
+
+/// Response status line and JSON body
+pub type Response = (&'static str, String);
+
+/// Serve requests on one connection (keep-alive) until the client closes it
+///
+/// Oversized headers or bodies get 431/413 and close the connection; other
+/// methods than POST get 405. `error_body` renders those error messages.
+pub async fn serve_connection<F, Fut>(
+    stream: TcpStream,
+    max_body_bytes: usize,
+    error_body: fn(&str) -> String,
+    mut handle: F,
+) -> std::io::Result<()>
+where
+    F: FnMut(Request) -> Fut,
+    Fut: Future<Output = Response>,
+{
+    let mut reader = BufReader::new(stream);
+    loop {
+        let (method, headers) = match read_head(&mut reader).await? {
+            Head::Closed => return Ok(()),
+            Head::TooLarge => {
+                let body = error_body("Request headers too large");
+                return write_response(&mut reader, "431 Request Header Fields Too Large", &body, false).await;
+            }
+            Head::Request { method, headers } => (method, headers),
+        };
+        let header = |name: &str| headers.iter().find(|(n, _)| n == name).map(|(_, v)| v.as_str());
+        let content_length: usize = header("content-length").and_then(|v| v.parse().ok()).unwrap_or(0);
+        let mut keep_alive = !header("connection").is_some_and(|v| v.eq_ignore_ascii_case("close"));
+
+        let (status, body) = if content_length > max_body_bytes {
+            keep_alive = false;
+            ("413 Payload Too Large", error_body("Request body too large"))
+        } else {
+            let mut body = vec![0u8; content_length];
+            reader.read_exact(&mut body).await?;
+            if method == "POST" {
+                handle(Request { headers, body }).await
+            } else {
+                ("405 Method Not Allowed", error_body("Only POST requests are supported"))
+            }
+        };
+        write_response(&mut reader, status, &body, keep_alive).await?;
+        if !keep_alive {
+            return Ok(());
+        }
+    }
+}
+
+async fn write_response(
+    reader: &mut BufReader<TcpStream>,
+    status: &str,
+    body: &str,
+    keep_alive: bool,
+) -> std::io::Result<()> {
+    let response = format!(
+        "HTTP/1.1 {}\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: {}\r\n\r\n{}",
+        status,
+        body.len(),
+        if keep_alive { "keep-alive" } else { "close" },
+        body
+    );
+    reader.get_mut().write_all(response.as_bytes()).await
+}
+
+/// Request line and headers of the next request
+enum Head {
+    /// The client closed the connection
+    Closed,
+    /// Longer than [`MAX_HEADER_BYTES`]
+    TooLarge,
+    Request {
+        method: String,
+        headers: Vec<(String, String)>,
+    },
+}
+
+async fn read_head(reader: &mut BufReader<TcpStream>) -> std::io::Result<Head> {
+    let mut budget = MAX_HEADER_BYTES;
+    let mut method = None;
+    let mut headers = Vec::new();
+    loop {
+        let mut line = String::new();
+        let read = (&mut *reader).take(budget as u64).read_line(&mut line).await?;
+        budget -= read;
+        if !line.ends_with('\n') {
+            return Ok(if budget == 0 { Head::TooLarge } else { Head::Closed });
+        }
+        let line = line.trim_end();
+        let Some(method) = &method else {
+            method = Some(line.split_whitespace().next().unwrap_or_default().to_string());
+            continue;
+        };
+        if line.is_empty() {
+            return Ok(Head::Request {
+                method: method.clone(),
+                headers,
+            });
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            headers.push((name.trim().to_ascii_lowercase(), value.trim().to_string()));
+        }
+    }
+}
@@ -0,0 +1,46 @@
+//! CSV exports: a header row, then one issue per row
+
+use anyhow::{Context, Result};
+use serde_json::Value;
+
+use super::Record;
+
+/// Read rows keyed by header (blank cells are left out)
+pub fn read(content: &str) -> Result<Vec<Record>> {
+    let mut reader = csv::ReaderBuilder::new().flexible(true).from_reader(content.as_bytes());
+    let headers: Vec<String> = reader
+        .headers()
+        .context("Failed to read the CSV header")?
+        .iter()
+        .map(|h| h.trim().trim_start_matches('\u{feff}').to_string())
+        .collect();
+    reader
+        .records()
+        .enumerate()
+        .map(|(i, row)| {
+            // Row numbers as a spreadsheet shows them (the header is row 1)
+            let row = row.with_context(|| format!("Failed to read CSV row {}", i + 2))?;
+            Ok(headers
+                .iter()
+                .zip(row.iter())
+                .filter(|(_, cell)| !cell.trim().is_empty())
+                .map(|(header, cell)| (header.clone(), Value::from(cell)))
+                .collect())
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_quoted_and_blank_cells() {
+        let records = read("\u{feff}ID,Title,Labels\nA-1,\"Crash, on start\",\"bug,ios\"\nA-2,Short,\n").unwrap();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0]["ID"], "A-1");
+        assert_eq!(records[0]["Title"], "Crash, on start");
+        assert_eq!(records[0]["Labels"], "bug,ios");
+        assert!(records[1].get("Labels").is_none());
+    }
+}
@@ -0,0 +1,85 @@
+//! GitHub issue exports: `gh issue list --json ...` or the REST API's issue list
+
+use anyhow::{Context, Result};
+use serde_json::{Map, Value};
+
+use super::Record;
+
+/// Read issues, flattening labels and assignees to their names and logins
+pub fn read(content: &str) -> Result<Vec<Record>> {
+    let value: Value = serde_json::from_str(content).context("Failed to parse the GitHub JSON export")?;
+    let Value::Array(issues) = value else {
+        anyhow::bail!("Expected a JSON array of GitHub issues");
+    };
+    Ok(issues
+        .iter()
+        .filter_map(Value::as_object)
+        // The REST API lists pull requests as issues too
+        .filter(|issue| !issue.contains_key("pull_request"))
+        .map(flatten)
+        .collect())
+}
+
+fn flatten(issue: &Map<String, Value>) -> Record {
+    let mut record = Record::new();
+    for (key, value) in issue {
+        let value = match (key.as_str(), value) {
+            ("labels", Value::Array(labels)) => {
+                Value::Array(labels.iter().filter_map(|l| name(l, "name")).collect())
+            }
+            ("assignees", Value::Array(users)) => {
+                Value::Array(users.iter().filter_map(|u| name(u, "login")).collect())
+            }
+            (_, Value::Object(_)) => match name(value, "login").or_else(|| name(value, "title")) {
+                // `assignee`, `author` and `user` by login; `milestone` by title
+                Some(name) => name,
+                None => continue,
+            },
+            (_, Value::Number(n)) => Value::from(n.to_string()),
+            (_, Value::String(_) | Value::Null | Value::Array(_)) => value.clone(),
+            (_, Value::Bool(b)) => Value::from(b.to_string()),
+        };
+        record.insert(key.clone(), value);
+    }
+    // The REST API has a single `assignee`; `gh` exports only `assignees`
+    if !record.contains_key("assignees") {
+        if let Some(assignee) = record.get("assignee").cloned().filter(Value::is_string) {
+            record.insert("assignees".to_string(), Value::Array(vec![assignee]));
+        }
+    }
+    record
+}
+
+/// A nested object's field, or a plain string as-is
+fn name(value: &Value, field: &str) -> Option<Value> {
+    match value {
+        Value::String(s) => Some(Value::from(s.as_str())),
+        other => other.get(field).filter(|v| v.is_string()).cloned(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_gh_and_rest_shapes() {
+        let records = read(
+            r#"[
+                {"number": 7, "title": "Crash", "state": "OPEN",
+                 "labels": [{"name": "bug"}], "assignees": [{"login": "ann"}], "milestone": {"title": "v1"}},
+                {"number": 8, "title": "REST", "state": "open", "assignee": {"login": "bob"}, "labels": ["ui"]},
+                {"number": 9, "title": "A PR", "pull_request": {}}
+            ]"#,
+        )
+        .unwrap();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0]["number"], "7");
+        assert_eq!(records[0]["labels"], serde_json::json!(["bug"]));
+        assert_eq!(records[0]["assignees"], serde_json::json!(["ann"]));
+        assert_eq!(records[0]["milestone"], "v1");
+        assert_eq!(records[1]["assignees"], serde_json::json!(["bob"]));
+        assert_eq!(records[1]["labels"], serde_json::json!(["ui"]));
+        assert!(read("{}").is_err());
+    }
+}
@@ -0,0 +1,198 @@
+//! Jira XML exports (the RSS document from "Export → XML")
+//!
+//! Every `<item>` is an issue. Its direct children become fields; repeated
+//! children (`<fixVersion>`) and containers (`<labels><label>`) become lists.
+//! HTML descriptions are flattened to plain text.
+
+use anyhow::{Context, Result};
+use quick_xml::escape::resolve_predefined_entity;
+use quick_xml::events::Event;
+use quick_xml::Reader;
+use serde_json::Value;
+
+use super::Record;
+
+/// Fields Jira exports as escaped HTML
+const HTML_FIELDS: &[&str] = &["description", "environment"];
+
+/// Read every `<item>` as a record
+pub fn read(content: &str) -> Result<Vec<Record>> {
+    let mut reader = Reader::from_str(content);
+    let mut records = Vec::new();
+    // The item being read, and the element names below it
+    let mut current: Option<Record> = None;
+    let mut path: Vec<String> = Vec::new();
+    let mut text = String::new();
+
+    loop {
+        let event = reader
+            .read_event()
+            .with_context(|| format!("Invalid Jira XML at byte {}", reader.buffer_position()))?;
+        match event {
+            Event::Start(element) => {
+                let name = String::from_utf8_lossy(element.local_name().as_ref()).into_owned();
+                if current.is_none() {
+                    if name == "item" {
+                        current = Some(Record::new());
+                        path.clear();
+                    }
+                    continue;
+                }
+                path.push(name);
+                text.clear();
+            }
+            Event::Text(t) if current.is_some() => text.push_str(&t.decode()?),
+            Event::CData(t) if current.is_some() => text.push_str(&t.decode()?),
+            Event::GeneralRef(r) if current.is_some() => {
+                let name = r.decode()?;
+                match r.resolve_char_ref()? {
+                    Some(c) => text.push(c),
+                    None => match resolve_predefined_entity(&name) {
+                        Some(s) => text.push_str(s),
+                        None => text.push_str(&format!("&{};", name)),
+                    },
+                }
+            }
+            Event::End(_) => {
+                let Some(record) = current.as_mut() else {
+                    continue;
+                };
+                let Some(name) = path.pop() else {
+                    // </item>
+                    let mut record = current.take().unwrap_or_default();
+                    if record.get("assignee").and_then(Value::as_str) == Some("Unassigned") {
+                        record.remove("assignee");
+                    }
+                    records.push(record);
+                    continue;
+                };
+                let value = std::mem::take(&mut text).trim().to_string();
+                if value.is_empty() {
+                    continue;
+                }
+                match path.as_slice() {
+                    [] => {
+                        let value = if HTML_FIELDS.contains(&name.as_str()) {
+                            html_to_text(&value)
+                        } else {
+                            value
+                        };
+                        push(record, name, value);
+                    }
+                    // An item of a container such as <labels>
+                    [container] => push(record, container.clone(), value),
+                    _ => {}
+                }
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+    Ok(records)
+}
+
+/// Set a field, turning it into a list when it repeats
+fn push(record: &mut Record, key: String, value: String) {
+    match record.get_mut(&key) {
+        Some(Value::Array(items)) => items.push(Value::from(value)),
+        Some(existing) => {
+            let first = existing.take();
+            *existing = Value::Array(vec![first, Value::from(value)]);
+        }
+        None => {
+            record.insert(key, Value::from(value));
+        }
+    }
+}
+
+/// Plain text from Jira's HTML: block ends become newlines, other tags are dropped
+fn html_to_text(html: &str) -> String {
+    let mut out = String::new();
+    let mut rest = html;
+    while let Some(start) = rest.find('<') {
+        out.push_str(&rest[..start]);
+        let Some(end) = rest[start..].find('>') else {
+            rest = &rest[start..];
+            break;
+        };
+        let tag = rest[start + 1..start + end].trim().to_lowercase();
+        let tag_name = tag.trim_start_matches('/').split([' ', '/']).next().unwrap_or_default();
+        match tag_name {
+            "br" | "p" | "div" | "tr" | "h1" | "h2" | "h3" | "h4" if tag.starts_with('/') || tag_name == "br" => {
+                out.push('\n')
+            }
+            "li" if !tag.starts_with('/') => out.push_str("\n- "),
+            _ => {}
+        }
+        rest = &rest[start + end + 1..];
+    }
+    out.push_str(rest);
+
+    let text = out
+        .replace("&nbsp;", " ")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&");
+    // Collapse the blank runs left by nested blocks
+    let lines: Vec<&str> = text.lines().map(str::trim_end).collect();
+    let mut collapsed: Vec<&str> = Vec::new();
+    for line in lines {
+        if line.trim().is_empty() && collapsed.last().is_none_or(|l| l.trim().is_empty()) {
+            continue;
+        }
+        collapsed.push(line);
+    }
+    collapsed.join("\n").trim().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXPORT: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<rss version="0.92">
+  <channel>
+    <title>Jira</title>
+    <item>
+      <title>[OPS-1] Disk full</title>
+      <key id="10001">OPS-1</key>
+      <summary>Disk full &amp; alerts missing</summary>
+      <description>&lt;p&gt;The disk&lt;br/&gt;filled up.&lt;/p&gt;&lt;ul&gt;&lt;li&gt;db01&lt;/li&gt;&lt;/ul&gt;</description>
+      <priority id="2">Major</priority>
+      <status id="1">To Do</status>
+      <assignee username="-1">Unassigned</assignee>
+      <labels>
+        <label>infra</label>
+        <label>urgent</label>
+      </labels>
+      <fixVersion>1.0</fixVersion>
+      <fixVersion>1.1</fixVersion>
+      <customfields><customfield><customfieldname>Team</customfieldname></customfield></customfields>
+    </item>
+    <item>
+      <key>OPS-2</key>
+      <summary><![CDATA[Raw <text>]]></summary>
+      <assignee username="ann">Ann Lee</assignee>
+    </item>
+  </channel>
+</rss>"#;
+
+    #[test]
+    fn test_read_items() {
+        let records = read(EXPORT).unwrap();
+        assert_eq!(records.len(), 2);
+        let first = &records[0];
+        assert_eq!(first["key"], "OPS-1");
+        assert_eq!(first["summary"], "Disk full & alerts missing");
+        assert_eq!(first["description"], "The disk\nfilled up.\n\n- db01");
+        assert_eq!(first["labels"], serde_json::json!(["infra", "urgent"]));
+        assert_eq!(first["fixVersion"], serde_json::json!(["1.0", "1.1"]));
+        assert!(first.get("assignee").is_none());
+        assert!(first.get("customfields").is_none());
+        assert_eq!(records[1]["summary"], "Raw <text>");
+        assert_eq!(records[1]["assignee"], "Ann Lee");
+        assert!(read("<rss><item><key>x</rss>").is_err());
+    }
+}
@@ -0,0 +1,266 @@
+//! Issue imports from other trackers' export files
+//!
+//! Each source format is read into flat records (field name → string or list
+//! of strings). A [`Mapping`] then picks the fields that make up an
+//! `IssueCreateInput`: `title`, `description`, `assignee`, `labels`, `state`
+//! and `priority` take names that are resolved later; any other target field
+//! is passed through as-is. Mapping files are JSON or YAML and are merged over
+//! the format's defaults.
+
+mod csv_file;
+mod github_json;
+mod jira_xml;
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use serde_json::{Map, Value};
+use std::collections::BTreeMap;
+
+use crate::cli::ImportFormat;
+use crate::mutation_builder::input_fingerprint;
+use crate::resolve::parse_priority;
+
+/// One source row: field name → string, list of strings or null
+pub type Record = Map<String, Value>;
+
+/// Read every record of an export file
+pub fn read_records(format: ImportFormat, content: &str) -> Result<Vec<Record>> {
+    match format {
+        ImportFormat::Csv => csv_file::read(content),
+        ImportFormat::JiraXml => jira_xml::read(content),
+        ImportFormat::GithubJson => github_json::read(content),
+    }
+}
+
+/// Static `(from, to)` string pairs for the default mappings
+type Pairs = &'static [(&'static str, &'static str)];
+
+/// How source fields map onto `IssueCreateInput`
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct Mapping {
+    /// Source field holding the row's ID in the old tracker
+    pub id: Option<String>,
+    /// Target input field → source field
+    #[serde(default)]
+    pub fields: BTreeMap<String, String>,
+    /// Target input field → source value → replacement value
+    #[serde(default)]
+    pub values: BTreeMap<String, BTreeMap<String, String>>,
+}
+
+impl Mapping {
+    /// Default mapping for a source format
+    pub fn defaults(format: ImportFormat) -> Self {
+        let (id, fields, values): (&str, Pairs, &[(&str, Pairs)]) = match format {
+            ImportFormat::Csv => (
+                "ID",
+                &[
+                    ("title", "Title"),
+                    ("description", "Description"),
+                    ("assignee", "Assignee"),
+                    ("labels", "Labels"),
+                    ("state", "Status"),
+                    ("priority", "Priority"),
+                    ("estimate", "Estimate"),
+                    ("dueDate", "Due Date"),
+                ],
+                &[],
+            ),
+            ImportFormat::JiraXml => (
+                "key",
+                &[
+                    ("title", "summary"),
+                    ("description", "description"),
+                    ("assignee", "assignee"),
+                    ("labels", "labels"),
+                    ("state", "status"),
+                    ("priority", "priority"),
+                    ("dueDate", "due"),
+                ],
+                &[
+                    (
+                        "priority",
+                        &[
+                            ("Highest", "urgent"),
+                            ("Blocker", "urgent"),
+                            ("Critical", "urgent"),
+                            ("Major", "high"),
+                            ("Minor", "low"),
+                            ("Lowest", "low"),
+                            ("Trivial", "low"),
+                        ],
+                    ),
+                    ("state", &[("To Do", "Todo"), ("Open", "Todo")]),
+                ],
+            ),
+            ImportFormat::GithubJson => (
+                "number",
+                &[
+                    ("title", "title"),
+                    ("description", "body"),
+                    ("assignee", "assignees"),
+                    ("labels", "labels"),
+                    ("state", "state"),
+                ],
+                &[("state", &[("OPEN", "Todo"), ("open", "Todo"), ("CLOSED", "Done"), ("closed", "Done")])],
+            ),
+        };
+        Self {
+            id: Some(id.to_string()),
+            fields: fields.iter().map(|(t, s)| (t.to_string(), s.to_string())).collect(),
+            values: values
+                .iter()
+                .map(|(target, pairs)| {
+                    let pairs = pairs.iter().map(|(from, to)| (from.to_string(), to.to_string())).collect();
+                    (target.to_string(), pairs)
+                })
+                .collect(),
+        }
+    }
+
+    /// Merge a mapping file over these defaults
+    pub fn merge(mut self, overrides: Mapping) -> Self {
+        if overrides.id.is_some() {
+            self.id = overrides.id;
+        }
+        self.fields.extend(overrides.fields);
+        for (target, pairs) in overrides.values {
+            self.values.entry(target).or_default().extend(pairs);
+        }
+        self
+    }
+
+    /// Parse a JSON or YAML mapping file
+    pub fn parse(content: &str) -> Result<Self> {
+        let value: Value = serde_yaml::from_str(content).context("Failed to parse the mapping file")?;
+        serde_json::from_value(value).context("Invalid mapping file")
+    }
+
+    /// The record's ID in the old tracker (a content hash if it has none)
+    pub fn source_id(&self, record: &Record) -> String {
+        self.id
+            .as_ref()
+            .and_then(|field| text(record.get(field)?))
+            .unwrap_or_else(|| input_fingerprint(&Value::Object(record.clone()))[..16].to_string())
+    }
+
+    /// The issue input for a record, with names still unresolved
+    pub fn apply(&self, record: &Record) -> Result<Value> {
+        let mut input = Map::new();
+        for (target, source) in &self.fields {
+            let Some(value) = record.get(source).filter(|v| !is_blank(v)) else {
+                continue;
+            };
+            let value = match self.values.get(target) {
+                Some(replacements) => replace(value, replacements),
+                None => value.clone(),
+            };
+            let value = match target.as_str() {
+                // A single assignee, even if the source lists several
+                "assignee" => match value {
+                    Value::Array(items) => items.into_iter().next().unwrap_or_default(),
+                    other => other,
+                },
+                "labels" => Value::Array(names(&value).into_iter().map(Value::from).collect()),
+                "priority" => Value::from(parse_priority(&text(&value).unwrap_or_default())?),
+                "estimate" => {
+                    let estimate = text(&value).unwrap_or_default();
+                    let number: f64 = estimate
+                        .trim()
+                        .parse()
+                        .with_context(|| format!("Estimate '{}' is not a number", estimate))?;
+                    Value::from(number.round() as i64)
+                }
+                _ => value,
+            };
+            input.insert(target.clone(), value);
+        }
+        if input.get("title").is_none_or(is_blank) {
+            anyhow::bail!("No title (mapped from '{}')", self.fields.get("title").map_or("", String::as_str));
+        }
+        Ok(Value::Object(input))
+    }
+}
+
+/// A string field's text (the first item of a list)
+fn text(value: &Value) -> Option<String> {
+    match value {
+        Value::String(s) => Some(s.trim().to_string()).filter(|s| !s.is_empty()),
+        Value::Array(items) => items.first().and_then(text),
+        Value::Number(n) => Some(n.to_string()),
+        _ => None,
+    }
+}
+
+/// Names in a list field, or a comma-separated string
+fn names(value: &Value) -> Vec<String> {
+    match value {
+        Value::Array(items) => items.iter().filter_map(text).collect(),
+        Value::String(s) => s.split(',').map(str::trim).filter(|s| !s.is_empty()).map(String::from).collect(),
+        _ => Vec::new(),
+    }
+}
+
+fn is_blank(value: &Value) -> bool {
+    match value {
+        Value::Null => true,
+        Value::String(s) => s.trim().is_empty(),
+        Value::Array(items) => items.is_empty(),
+        _ => false,
+    }
+}
+
+/// Swap mapped values, item by item for lists
+fn replace(value: &Value, replacements: &BTreeMap<String, String>) -> Value {
+    match value {
+        Value::String(s) => Value::from(replacements.get(s.trim()).cloned().unwrap_or_else(|| s.clone())),
+        Value::Array(items) => Value::Array(items.iter().map(|item| replace(item, replacements)).collect()),
+        other => other.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn record(value: Value) -> Record {
+        value.as_object().unwrap().clone()
+    }
+
+    #[test]
+    fn test_apply_default_csv_mapping() {
+        let mapping = Mapping::defaults(ImportFormat::Csv);
+        let row = record(json!({
+            "ID": "OLD-1", "Title": "Login fails", "Description": "", "Labels": "bug, web",
+            "Priority": "High", "Estimate": "3", "Status": "Todo", "Extra": "ignored"
+        }));
+        assert_eq!(mapping.source_id(&row), "OLD-1");
+        assert_eq!(
+            mapping.apply(&row).unwrap(),
+            json!({ "title": "Login fails", "labels": ["bug", "web"], "priority": 2, "estimate": 3, "state": "Todo" })
+        );
+
+        assert!(mapping.apply(&record(json!({ "ID": "OLD-2", "Title": " " }))).is_err());
+        assert!(mapping.apply(&record(json!({ "Title": "x", "Priority": "P0" }))).is_err());
+        // Rows without an ID get a stable content hash
+        let untitled = record(json!({ "Title": "x" }));
+        assert_eq!(mapping.source_id(&untitled), mapping.source_id(&untitled.clone()));
+    }
+
+    #[test]
+    fn test_merge_and_value_replacements() {
+        let overrides = Mapping::parse("id: Key\nfields:\n  title: Summary\nvalues:\n  state:\n    Open: Backlog\n").unwrap();
+        let mapping = Mapping::defaults(ImportFormat::JiraXml).merge(overrides);
+        let row = record(json!({
+            "Key": "J-1", "Summary": "Crash", "status": "Open", "priority": "Highest", "assignee": ["ann", "bob"]
+        }));
+        assert_eq!(mapping.source_id(&row), "J-1");
+        assert_eq!(
+            mapping.apply(&row).unwrap(),
+            json!({ "title": "Crash", "state": "Backlog", "priority": 1, "assignee": "ann" })
+        );
+        assert!(Mapping::parse("{\"unknown\": 1}").is_err());
+    }
+}
@@ -0,0 +1,85 @@
+//! Ctrl-C handling for long operations
+//!
+//! Long loops (`list --all`, batched `get`) hold a [`Graceful`] guard. While a
+//! guard is alive, the first Ctrl-C cancels the request in flight and lets the
+//! loop flush what it has fetched; a second Ctrl-C aborts immediately. Without
+//! a guard, Ctrl-C aborts as usual.
+
+use std::future::Future;
+use std::io::{IsTerminal, Write};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Once;
+use tokio::sync::Notify;
+
+use crate::common::ExitCode;
+
+/// Set by the first Ctrl-C inside a graceful section
+static INTERRUPTED: AtomicBool = AtomicBool::new(false);
+/// Number of live [`Graceful`] guards
+static GRACEFUL: AtomicUsize = AtomicUsize::new(0);
+/// Wakes tasks waiting in [`cancellable`]
+static NOTIFY: Notify = Notify::const_new();
+static INSTALL: Once = Once::new();
+
+/// Error returned by commands that stopped early because of Ctrl-C
+#[derive(Debug, thiserror::Error)]
+#[error("Interrupted")]
+pub struct Interrupted;
+
+/// Guard marking a section that handles Ctrl-C itself
+pub struct Graceful(());
+
+impl Drop for Graceful {
+    fn drop(&mut self) {
+        GRACEFUL.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+/// Enter a section that stops cleanly on the first Ctrl-C
+pub fn graceful() -> Graceful {
+    INSTALL.call_once(|| {
+        tokio::spawn(listen());
+    });
+    GRACEFUL.fetch_add(1, Ordering::SeqCst);
+    Graceful(())
+}
+
+/// Whether Ctrl-C was pressed inside a graceful section
+pub fn is_interrupted() -> bool {
+    INTERRUPTED.load(Ordering::SeqCst)
+}
+
+/// Run a future, dropping it if Ctrl-C is pressed first (None)
+pub async fn cancellable<F: Future>(future: F) -> Option<F::Output> {
+    let notified = NOTIFY.notified();
+    tokio::pin!(notified);
+    notified.as_mut().enable();
+    if is_interrupted() {
+        return None;
+    }
+    tokio::select! {
+        output = future => Some(output),
+        _ = notified => None,
+    }
+}
+
+async fn listen() {
+    while tokio::signal::ctrl_c().await.is_ok() {
+        if is_interrupted() || GRACEFUL.load(Ordering::SeqCst) == 0 {
+            abort();
+        }
+        INTERRUPTED.store(true, Ordering::SeqCst);
+        NOTIFY.notify_waiters();
+        eprintln!("\nInterrupted: finishing up (press Ctrl-C again to abort)");
+    }
+}
+
+/// Exit now, clearing any half-drawn spinner line first
+fn abort() -> ! {
+    let mut stderr = std::io::stderr();
+    if stderr.is_terminal() {
+        let _ = write!(stderr, "\r\x1b[2K\x1b[?25h");
+        let _ = stderr.flush();
+    }
+    std::process::exit(ExitCode::Interrupted as i32);
+}
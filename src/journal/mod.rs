@@ -0,0 +1,334 @@
+//! Undo journal for mutations
+//!
+//! Every `update`, `archive` and `delete` appends an entry with the values the
+//! entity had before the change. `linears undo` replays the inverse mutations.
+//! Entries live in `$XDG_STATE_HOME/linears/journal.ndjson`.
+//!
+//! The file is only ever appended to, one line per write, so concurrent
+//! commands cannot lose each other's entries. An undo appends a tombstone
+//! (`{"undone": <entry id>, "undoneAt": ...}`) that `load` folds into the entry.
+
+use anyhow::{Context, Result};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use crate::common::constants::{env, state};
+use crate::generated::MutationOp;
+use crate::mutation_builder::{build_unarchive_mutation, build_update_mutation};
+
+/// Mutation kinds recorded in the journal
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum JournalOp {
+    Update,
+    Archive,
+    Delete,
+}
+
+impl JournalOp {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            JournalOp::Update => "update",
+            JournalOp::Archive => "archive",
+            JournalOp::Delete => "delete",
+        }
+    }
+}
+
+/// A single journaled mutation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JournalEntry {
+    /// Journal entry ID (for `linears undo --id`)
+    pub id: String,
+    pub timestamp: String,
+    pub op: JournalOp,
+    /// Resource field name (e.g. "issue")
+    pub resource: String,
+    pub entity_id: String,
+    /// Prior values of the changed fields (null if they could not be captured)
+    pub before: serde_json::Value,
+    pub after: serde_json::Value,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub undone_at: Option<String>,
+}
+
+impl JournalEntry {
+    /// Create a new entry stamped with the current time
+    pub fn new(
+        op: JournalOp,
+        resource: &str,
+        entity_id: &str,
+        before: serde_json::Value,
+        after: serde_json::Value,
+    ) -> Self {
+        let now = Utc::now();
+        Self {
+            id: format!("{:x}-{:04x}", now.timestamp_millis(), rand::random::<u16>()),
+            timestamp: now.to_rfc3339(),
+            op,
+            resource: resource.to_string(),
+            entity_id: entity_id.to_string(),
+            before,
+            after,
+            undone_at: None,
+        }
+    }
+
+    /// Build the inverse mutation, or None if Linear cannot reverse this change
+    pub fn inverse_mutation(&self) -> Option<(String, serde_json::Value)> {
+        match self.op {
+            JournalOp::Update if self.before.is_object() => Some(build_update_mutation(
+                &self.resource,
+                &self.entity_id,
+                self.before.clone(),
+            )),
+            JournalOp::Update => None,
+            JournalOp::Archive => supports_unarchive(&self.resource)
+                .then(|| build_unarchive_mutation(&self.resource, &self.entity_id)),
+            // Deleted issues go to the trash and can be restored; other deletes are permanent
+            JournalOp::Delete => (self.resource == "issue" && supports_unarchive(&self.resource))
+                .then(|| build_unarchive_mutation(&self.resource, &self.entity_id)),
+        }
+    }
+
+    /// Name of the inverse mutation operation, if any
+    pub fn inverse_op_name(&self) -> Option<String> {
+        self.inverse_mutation().map(|_| match self.op {
+            JournalOp::Update => format!("{}Update", self.resource),
+            JournalOp::Archive | JournalOp::Delete => format!("{}Unarchive", self.resource),
+        })
+    }
+}
+
+/// Check whether the schema has an unarchive mutation for a resource
+fn supports_unarchive(resource: &str) -> bool {
+    let op_name = format!("{}Unarchive", resource);
+    MutationOp::all().iter().any(|op| op.operation_name() == op_name)
+}
+
+/// Selection for the prior values of the given input keys
+///
+/// Input keys refer to relations by ID (`stateId`, `labelIds`), so those are
+/// selected through the relation and mapped back by [`prior_values_from_entity`].
+pub fn prior_values_selection(keys: &[&str]) -> String {
+    let mut fields = vec!["id".to_string()];
+    for key in keys {
+        if let Some(base) = key.strip_suffix("Ids") {
+            fields.push(format!("{}s {{ nodes {{ id }} }}", base));
+        } else if let Some(base) = key.strip_suffix("Id") {
+            fields.push(format!("{} {{ id }}", base));
+        } else if *key != "id" {
+            fields.push(key.to_string());
+        }
+    }
+    fields.join(" ")
+}
+
+/// Map a fetched entity back into input shape for the given keys
+pub fn prior_values_from_entity(entity: &serde_json::Value, keys: &[&str]) -> serde_json::Value {
+    let mut before = serde_json::Map::new();
+    for key in keys {
+        let value = if let Some(base) = key.strip_suffix("Ids") {
+            let ids: Vec<serde_json::Value> = entity[format!("{}s", base)]["nodes"]
+                .as_array()
+                .map(|nodes| nodes.iter().map(|n| n["id"].clone()).collect())
+                .unwrap_or_default();
+            serde_json::Value::Array(ids)
+        } else if let Some(base) = key.strip_suffix("Id") {
+            entity[base]["id"].clone()
+        } else {
+            entity[*key].clone()
+        };
+        before.insert(key.to_string(), value);
+    }
+    serde_json::Value::Object(before)
+}
+
+/// A journal line: an entry, or the tombstone of a later undo
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum JournalLine {
+    Entry(JournalEntry),
+    Undone(UndoneRecord),
+}
+
+/// Tombstone marking an entry as undone
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct UndoneRecord {
+    undone: String,
+    undone_at: String,
+}
+
+/// Path of the journal file
+pub fn journal_path() -> Result<PathBuf> {
+    let state_home = match std::env::var(env::STATE_HOME) {
+        Ok(dir) if !dir.trim().is_empty() => PathBuf::from(dir),
+        _ => {
+            let home = std::env::var(env::HOME)
+                .context("Cannot locate the journal: neither XDG_STATE_HOME nor HOME is set")?;
+            PathBuf::from(home).join(".local").join("state")
+        }
+    };
+    Ok(state_home.join(state::DIR_NAME).join(state::JOURNAL_FILE))
+}
+
+/// Append an entry to the journal
+pub fn append(entry: &JournalEntry) -> Result<()> {
+    append_line(serde_json::to_string(entry)?)
+}
+
+/// Record that an entry was undone at `undone_at`
+pub fn mark_undone(entry_id: &str, undone_at: &str) -> Result<()> {
+    append_line(serde_json::to_string(&UndoneRecord {
+        undone: entry_id.to_string(),
+        undone_at: undone_at.to_string(),
+    })?)
+}
+
+/// Append one line with a single write, so concurrent appends never interleave
+fn append_line(mut line: String) -> Result<()> {
+    let path = journal_path()?;
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)
+            .with_context(|| format!("Failed to create directory: {}", dir.display()))?;
+    }
+
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .with_context(|| format!("Failed to open journal: {}", path.display()))?;
+    line.push('\n');
+    file.write_all(line.as_bytes())
+        .with_context(|| format!("Failed to write journal: {}", path.display()))?;
+
+    Ok(())
+}
+
+/// Load all journal entries, oldest first
+pub fn load() -> Result<Vec<JournalEntry>> {
+    let path = journal_path()?;
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+
+    let content = std::fs::read_to_string(&path)
+        .with_context(|| format!("Failed to read journal: {}", path.display()))?;
+    parse(&content, &path)
+}
+
+/// Parse journal lines, applying undo tombstones to their entries
+fn parse(content: &str, path: &Path) -> Result<Vec<JournalEntry>> {
+    let mut entries: Vec<JournalEntry> = Vec::new();
+    for (i, line) in content.lines().filter(|line| !line.trim().is_empty()).enumerate() {
+        let line: JournalLine = serde_json::from_str(line)
+            .with_context(|| format!("Corrupt journal entry on line {} of {}", i + 1, path.display()))?;
+        match line {
+            JournalLine::Entry(entry) => entries.push(entry),
+            JournalLine::Undone(record) => {
+                if let Some(entry) = entries.iter_mut().find(|e| e.id == record.undone) {
+                    entry.undone_at = Some(record.undone_at);
+                }
+            }
+        }
+    }
+    Ok(entries)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_applies_undo_tombstones() {
+        let entry = JournalEntry::new(
+            JournalOp::Archive,
+            "issue",
+            "iss-1",
+            serde_json::json!({}),
+            serde_json::json!({}),
+        );
+        let tombstone = serde_json::to_string(&UndoneRecord {
+            undone: entry.id.clone(),
+            undone_at: "2025-01-10T14:30:00Z".to_string(),
+        })
+        .unwrap();
+        let content = format!("{}\n{}\n", serde_json::to_string(&entry).unwrap(), tombstone);
+        let entries = parse(&content, Path::new("journal.ndjson")).unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].undone_at.as_deref(), Some("2025-01-10T14:30:00Z"));
+        assert!(parse("{\"undone\": 1}\n", Path::new("journal.ndjson")).is_err());
+    }
+
+    #[test]
+    fn test_prior_values_selection() {
+        let selection = prior_values_selection(&["title", "stateId", "labelIds"]);
+        assert_eq!(selection, "id title state { id } labels { nodes { id } }");
+    }
+
+    #[test]
+    fn test_prior_values_from_entity() {
+        let entity = serde_json::json!({
+            "id": "iss-1",
+            "title": "Old title",
+            "state": { "id": "state-1" },
+            "assignee": null,
+            "labels": { "nodes": [{ "id": "lbl-1" }, { "id": "lbl-2" }] },
+        });
+        let before = prior_values_from_entity(&entity, &["title", "stateId", "assigneeId", "labelIds"]);
+        assert_eq!(
+            before,
+            serde_json::json!({
+                "title": "Old title",
+                "stateId": "state-1",
+                "assigneeId": null,
+                "labelIds": ["lbl-1", "lbl-2"],
+            })
+        );
+    }
+
+    #[test]
+    fn test_inverse_mutation() {
+        let update = JournalEntry::new(
+            JournalOp::Update,
+            "issue",
+            "iss-1",
+            serde_json::json!({ "title": "Old" }),
+            serde_json::json!({ "title": "New" }),
+        );
+        let (query, variables) = update.inverse_mutation().unwrap();
+        assert!(query.contains("issueUpdate"));
+        assert_eq!(variables["input"]["title"], "Old");
+
+        let uncaptured = JournalEntry::new(
+            JournalOp::Update,
+            "issue",
+            "iss-1",
+            serde_json::Value::Null,
+            serde_json::json!({ "title": "New" }),
+        );
+        assert!(uncaptured.inverse_mutation().is_none());
+
+        let archive = JournalEntry::new(
+            JournalOp::Archive,
+            "issue",
+            "iss-1",
+            serde_json::json!({}),
+            serde_json::json!({}),
+        );
+        assert_eq!(archive.inverse_op_name().as_deref(), Some("issueUnarchive"));
+
+        let delete = JournalEntry::new(
+            JournalOp::Delete,
+            "comment",
+            "c-1",
+            serde_json::json!({}),
+            serde_json::json!({}),
+        );
+        assert!(delete.inverse_mutation().is_none());
+    }
+}
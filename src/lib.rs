@@ -1,10 +1,36 @@
-//! linears library - exposes internal types for testing
+//! linears library - Linear's GraphQL API from Rust
 //!
-//! This module re-exports types needed by tests and external consumers.
+//! [`api::LinearClient`] is the entry point for embedding: typed request
+//! builders over the same client, query builders and renderers the `linears`
+//! binary uses.
+//!
+//! ```no_run
+//! use linears::api::{LinearClient, Resource};
+//!
+//! # async fn example() -> Result<(), linears::api::ClientError> {
+//! let client = LinearClient::new("lin_api_...")?;
+//! let issues = client
+//!     .list(Resource::Issues)
+//!     .filter(serde_json::json!({ "team": { "key": { "eq": "ENG" } } }))
+//!     .select(["id", "identifier", "title"])
+//!     .all()
+//!     .await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! The default `cli` feature builds the `linears` binary and its argument
+//! parser; embed with `default-features = false` to skip those dependencies.
 
-pub mod common;
+pub mod api;
+#[cfg(feature = "cli")]
 pub mod cli;
+pub mod client;
+pub mod common;
+pub mod complexity;
 pub mod generated;
 pub mod mutation_builder;
+pub mod paginate;
 pub mod query_builder;
+pub mod render;
 pub mod validate;
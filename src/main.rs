@@ -2,24 +2,38 @@
 //!
 //! Provides complete coverage of Linear's API surface area with:
 //! - Query commands: list, get, search, raw
-//! - Mutation commands: create, update, delete, archive, mutate
+//! - Mutation commands: create, update, delete, archive, mutate, undo
 //! - Discovery commands: resources, ops
 //! - Schema commands: info, diff, sync
+//! - Cache commands: stats, clear
+//! - Sync commands: pull, status (local mirror for --offline)
+//! - Dev commands: serve (local fake API)
+//! - Upload command: file uploads
+//! - Export command: NDJSON backups with a manifest
+//! - Import command: issues from CSV, Jira XML and GitHub JSON exports
+//! - Rate-limit command: API budget status
+//! - Watch command: change events from polling, with an --exec hook
+//! - Webhook commands: listen, send-test, list, create, update, delete, rotate-secret
 
 use anyhow::Result;
 use clap::Parser;
 
-mod cli;
-mod client;
 mod commands;
-mod common;
-mod generated;
-mod mutation_builder;
+mod fake_server;
+mod http_server;
+mod import;
+mod interrupt;
+mod journal;
+mod mirror;
 mod progress;
-mod query_builder;
-mod render;
+mod resolve;
 mod schema_diff;
-mod validate;
+mod webhook;
+
+// The binary sits on top of the library API
+use linears::{
+    cli, client, common, complexity, generated, mutation_builder, paginate, query_builder, render, validate,
+};
 
 use cli::{Cli, Commands};
 use commands::{get_api_key, *};
@@ -27,7 +41,22 @@ use common::ExitCode;
 
 /// Check if the command requires API access
 fn command_requires_api(cmd: &Commands) -> bool {
-    !matches!(cmd, Commands::Resources | Commands::Ops | Commands::Schema { .. })
+    match cmd {
+        Commands::Resources
+        | Commands::Ops
+        | Commands::Schema { .. }
+        | Commands::Cache { .. }
+        | Commands::Dev { .. } => false,
+        // Listing the journal and dry runs never touch the API
+        Commands::Undo { options } => !(options.list || options.dry_run),
+        Commands::Sync { action } => !matches!(action, cli::SyncAction::Status),
+        // The receiver and test deliveries work without an API key
+        Commands::Webhook { action } => !matches!(
+            action,
+            cli::WebhookAction::Listen { .. } | cli::WebhookAction::SendTest { .. }
+        ),
+        _ => true,
+    }
 }
 
 #[tokio::main]
@@ -37,8 +66,9 @@ async fn main() -> std::process::ExitCode {
 
     progress::set_no_color(cli.global.no_color);
 
-    // Check for API key if command requires it
-    if command_requires_api(&cli.command) {
+    // Check for API key if command requires it (replays are served from cassettes,
+    // --offline answers from the mirror)
+    if command_requires_api(&cli.command) && cli.global.replay.is_none() && !cli.global.offline {
         if let Err(msg) = get_api_key() {
             eprintln!("Error: {}", msg);
             return ExitCode::AuthError.into();
@@ -52,9 +82,11 @@ async fn main() -> std::process::ExitCode {
         Commands::List { resource, options } => {
             cmd_list(&cli, *resource, options.clone()).await
         }
-        Commands::Get { resource, id } => cmd_get(&cli, *resource, id.clone()).await,
-        Commands::Search { resource, text } => {
-            cmd_search(&cli, *resource, text.clone()).await
+        Commands::Get { resource, ids, ids_from } => {
+            cmd_get(&cli, *resource, ids.clone(), ids_from.clone()).await
+        }
+        Commands::Search { resource, text, limit } => {
+            cmd_search(&cli, *resource, text.clone(), *limit as usize).await
         }
         Commands::Raw { query, vars } => cmd_raw(&cli, query.clone(), vars.clone()).await,
         Commands::Create { resource, input } => {
@@ -71,7 +103,21 @@ async fn main() -> std::process::ExitCode {
             cmd_unarchive(&cli, *resource, id.clone()).await
         }
         Commands::Mutate { op, vars } => cmd_mutate(&cli, *op, vars.clone()).await,
+        Commands::Undo { options } => cmd_undo(&cli, options.clone()).await,
+        Commands::Upload { path, options } => {
+            cmd_upload(&cli, path.clone(), options.clone()).await
+        }
+        Commands::Import { format, file, options } => {
+            cmd_import(&cli, *format, file.clone(), options.clone()).await
+        }
+        Commands::Export { options } => cmd_export(&cli, options.clone()).await,
+        Commands::Watch { resource, options } => cmd_watch(&cli, *resource, options.clone()).await,
+        Commands::RateLimit => cmd_rate_limit(&cli).await,
         Commands::Schema { action } => cmd_schema(&cli, action.clone()).await,
+        Commands::Cache { action } => cmd_cache(&cli, action.clone()),
+        Commands::Sync { action } => cmd_sync(&cli, action.clone()).await,
+        Commands::Webhook { action } => cmd_webhook(&cli, action.clone()).await,
+        Commands::Dev { action } => cmd_dev(&cli, action.clone()).await,
     };
 
     // Handle result
@@ -83,19 +129,24 @@ async fn main() -> std::process::ExitCode {
                     let kind = match err {
                         client::ClientError::Auth(_) => "auth",
                         client::ClientError::Network(_) => "network",
+                        client::ClientError::Timeout(_) => "timeout",
                         client::ClientError::GraphQL(_, _) => "graphql",
+                        client::ClientError::PartialData(_) => "partial",
                         client::ClientError::RateLimited(_) => "rate_limited",
                         client::ClientError::RateLimitedTooLong(_) => "rate_limited",
                         client::ClientError::Server(_) => "server",
                         client::ClientError::Other(_) => "other",
                     };
                     let (gql_errors, details) = match err {
-                        client::ClientError::GraphQL(_, Some(errors)) => {
+                        client::ClientError::GraphQL(_, Some(errors))
+                        | client::ClientError::PartialData(errors) => {
                             (Some(errors.clone()), errors.first().and_then(|e| e.extensions.clone()))
                         }
                         _ => (None, None),
                     };
                     (err.exit_code(), kind, format!("{}", err), err.hint(), gql_errors, details)
+                } else if e.downcast_ref::<interrupt::Interrupted>().is_some() {
+                    (ExitCode::Interrupted, "interrupted", format!("{}", e), None, None, None)
                 } else {
                     (ExitCode::GeneralError, "general", format!("{:#}", e), None, None, None)
                 };
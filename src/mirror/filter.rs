@@ -0,0 +1,231 @@
+//! Linear filter expressions evaluated against mirrored entities
+//!
+//! Supports the shapes `list --filter` sends to the API: `and`/`or`, nested
+//! relation filters (`team: { key: { eq: "ENG" } }`), collection filters
+//! (`labels: { some: { name: { eq: "bug" } } }`) and the usual comparators.
+//! Dates compare as RFC 3339 strings; relative ISO 8601 durations such as
+//! `-P2W` are resolved against the current time. Comparators or collection
+//! keys outside that set are errors rather than matching everything.
+
+use anyhow::{bail, Result};
+use serde_json::Value;
+use std::cmp::Ordering;
+
+use crate::validate::relative_date;
+
+/// Whether an entity satisfies a filter
+///
+/// Every condition is evaluated, so an operator the mirror cannot answer is
+/// an error rather than silently matching.
+pub fn matches(entity: &Value, filter: &Value) -> Result<bool> {
+    let Some(conditions) = filter.as_object() else {
+        return Ok(true);
+    };
+    let results = conditions
+        .iter()
+        .map(|(key, condition)| match key.as_str() {
+            "and" => all(as_list(condition).iter().map(|f| matches(entity, f))),
+            "or" => any(as_list(condition).iter().map(|f| matches(entity, f))),
+            // Relation-level null check, e.g. `assignee: { null: true }`
+            "null" => Ok(condition.as_bool().is_none_or(|want| entity.is_null() == want)),
+            field => field_matches(&entity[field], condition),
+        });
+    all(results)
+}
+
+fn field_matches(value: &Value, condition: &Value) -> Result<bool> {
+    let Some(object) = condition.as_object() else {
+        return Ok(true);
+    };
+    if is_collection_filter(object) {
+        let items = collection_items(value);
+        return all(object.iter().map(|(key, filter)| match key.as_str() {
+            "some" => any(items.iter().map(|item| matches(item, filter))),
+            "every" => all(items.iter().map(|item| matches(item, filter))),
+            "none" => Ok(!any(items.iter().map(|item| matches(item, filter)))?),
+            "length" => compare_all(&Value::from(items.len()), filter),
+            other => bail!("Unsupported collection filter '{}' in offline mode", other),
+        }));
+    }
+    if object.keys().any(|key| COMPARATORS.contains(&key.as_str())) {
+        return compare_all(value, condition);
+    }
+    // Nested filter on a related entity
+    if value.is_null() {
+        return Ok(object.get("null").and_then(Value::as_bool) == Some(true));
+    }
+    // A scalar has no fields, so these keys can only be unknown comparators
+    if !value.is_object() {
+        let keys: Vec<_> = object.keys().map(String::as_str).collect();
+        bail!("Unsupported filter comparator '{}' in offline mode", keys.join("', '"));
+    }
+    matches(value, condition)
+}
+
+/// Evaluate every result, then require all of them to hold
+fn all(results: impl Iterator<Item = Result<bool>>) -> Result<bool> {
+    results.collect::<Result<Vec<_>>>().map(|r| r.into_iter().all(|b| b))
+}
+
+/// Evaluate every result, then require any of them to hold
+fn any(results: impl Iterator<Item = Result<bool>>) -> Result<bool> {
+    results.collect::<Result<Vec<_>>>().map(|r| r.into_iter().any(|b| b))
+}
+
+const COMPARATORS: &[&str] = &[
+    "eq",
+    "neq",
+    "in",
+    "nin",
+    "lt",
+    "lte",
+    "gt",
+    "gte",
+    "null",
+    "eqIgnoreCase",
+    "neqIgnoreCase",
+    "contains",
+    "notContains",
+    "containsIgnoreCase",
+    "notContainsIgnoreCase",
+    "containsIgnoreCaseAndAccent",
+    "startsWith",
+    "notStartsWith",
+    "endsWith",
+    "notEndsWith",
+];
+
+fn is_collection_filter(object: &serde_json::Map<String, Value>) -> bool {
+    object.keys().any(|k| matches!(k.as_str(), "some" | "every" | "none" | "length"))
+}
+
+/// Items of a stored list: a connection (`{ nodes: [...] }`) or a plain array
+fn collection_items(value: &Value) -> Vec<Value> {
+    value
+        .get("nodes")
+        .unwrap_or(value)
+        .as_array()
+        .cloned()
+        .unwrap_or_default()
+}
+
+fn compare_all(value: &Value, comparators: &Value) -> Result<bool> {
+    let Some(comparators) = comparators.as_object() else {
+        bail!("Expected comparators such as {{ \"eq\": ... }}, got {}", comparators);
+    };
+    all(comparators.iter().map(|(op, operand)| compare(value, op, operand)))
+}
+
+fn compare(value: &Value, op: &str, operand: &Value) -> Result<bool> {
+    let text = value.as_str().unwrap_or_default();
+    let operand_text = operand.as_str().unwrap_or_default();
+    let matched = match op {
+        "null" => operand.as_bool().is_none_or(|want| value.is_null() == want),
+        "eq" => values_equal(value, operand),
+        "neq" => !values_equal(value, operand),
+        "in" => as_list(operand).iter().any(|o| values_equal(value, o)),
+        "nin" => !as_list(operand).iter().any(|o| values_equal(value, o)),
+        "lt" => order(value, operand) == Some(Ordering::Less),
+        "lte" => matches!(order(value, operand), Some(Ordering::Less | Ordering::Equal)),
+        "gt" => order(value, operand) == Some(Ordering::Greater),
+        "gte" => matches!(order(value, operand), Some(Ordering::Greater | Ordering::Equal)),
+        _ if value.is_null() && COMPARATORS.contains(&op) => op.starts_with("not") || op == "neqIgnoreCase",
+        "eqIgnoreCase" => text.to_lowercase() == operand_text.to_lowercase(),
+        "neqIgnoreCase" => text.to_lowercase() != operand_text.to_lowercase(),
+        "contains" => text.contains(operand_text),
+        "notContains" => !text.contains(operand_text),
+        "containsIgnoreCase" | "containsIgnoreCaseAndAccent" => {
+            text.to_lowercase().contains(&operand_text.to_lowercase())
+        }
+        "notContainsIgnoreCase" => !text.to_lowercase().contains(&operand_text.to_lowercase()),
+        "startsWith" => text.starts_with(operand_text),
+        "notStartsWith" => !text.starts_with(operand_text),
+        "endsWith" => text.ends_with(operand_text),
+        "notEndsWith" => !text.ends_with(operand_text),
+        other => bail!("Unsupported filter comparator '{}' in offline mode", other),
+    };
+    Ok(matched)
+}
+
+fn as_list(value: &Value) -> Vec<Value> {
+    value.as_array().cloned().unwrap_or_else(|| vec![value.clone()])
+}
+
+fn values_equal(value: &Value, operand: &Value) -> bool {
+    match (value.as_f64(), operand.as_f64()) {
+        (Some(a), Some(b)) => a == b,
+        _ => value == operand,
+    }
+}
+
+fn order(value: &Value, operand: &Value) -> Option<Ordering> {
+    if let (Some(a), Some(b)) = (value.as_f64(), operand.as_f64()) {
+        return a.partial_cmp(&b);
+    }
+    let text = value.as_str()?;
+    let operand = operand.as_str()?;
+    let operand = relative_date(operand).unwrap_or_else(|| operand.to_string());
+    Some(text.cmp(operand.as_str()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn issue() -> Value {
+        json!({
+            "id": "a",
+            "title": "Login fails on Safari",
+            "priority": 2,
+            "createdAt": "2025-01-10T12:00:00.000Z",
+            "assignee": null,
+            "team": { "id": "t1", "key": "ENG" },
+            "state": { "type": "started" },
+            "labels": { "nodes": [{ "name": "bug" }, { "name": "web" }] }
+        })
+    }
+
+    fn matches_ok(entity: &Value, filter: &Value) -> bool {
+        matches(entity, filter).unwrap()
+    }
+
+    #[test]
+    fn test_comparators_and_relations() {
+        let issue = issue();
+        assert!(matches_ok(&issue, &json!({ "team": { "key": { "eq": "ENG" } } })));
+        assert!(!matches_ok(&issue, &json!({ "team": { "key": { "eq": "OPS" } } })));
+        assert!(matches_ok(&issue, &json!({ "priority": { "lte": 2, "gt": 1 } })));
+        assert!(matches_ok(&issue, &json!({ "priority": { "in": [1, 2] } })));
+        assert!(matches_ok(&issue, &json!({ "title": { "containsIgnoreCase": "safari" } })));
+        assert!(matches_ok(&issue, &json!({ "createdAt": { "gte": "2025-01-01" } })));
+        assert!(matches_ok(&issue, &json!({ "assignee": { "null": true } })));
+        assert!(!matches_ok(&issue, &json!({ "assignee": { "name": { "eq": "Ann" } } })));
+        assert!(matches_ok(&issue, &json!({ "createdAt": { "lt": "P1D" } })));
+    }
+
+    #[test]
+    fn test_logic_and_collections() {
+        let issue = issue();
+        assert!(matches_ok(
+            &issue,
+            &json!({ "or": [{ "state": { "type": { "eq": "completed" } } }, { "priority": { "eq": 2 } }] })
+        ));
+        assert!(!matches_ok(
+            &issue,
+            &json!({ "and": [{ "state": { "type": { "eq": "started" } } }, { "priority": { "eq": 1 } }] })
+        ));
+        assert!(matches_ok(&issue, &json!({ "labels": { "some": { "name": { "eq": "bug" } } } })));
+        assert!(!matches_ok(&issue, &json!({ "labels": { "every": { "name": { "eq": "bug" } } } })));
+        assert!(matches_ok(&issue, &json!({ "labels": { "none": { "name": { "eq": "ios" } } } })));
+    }
+
+    #[test]
+    fn test_unsupported_operators_are_errors() {
+        let issue = issue();
+        assert!(matches(&issue, &json!({ "priority": { "eq": 1, "between": [1, 3] } })).is_err());
+        assert!(matches(&issue, &json!({ "labels": { "some": {}, "any": {} } })).is_err());
+        // Evaluated even after an earlier condition already failed
+        assert!(matches(&issue, &json!({ "priority": { "eq": 1 }, "title": { "like": "%Safari%" } })).is_err());
+    }
+}
@@ -0,0 +1,414 @@
+//! Offline mirror of a workspace in SQLite
+//!
+//! `linears sync pull` stores issues, projects, cycles, teams, users, labels,
+//! workflow states and comments as JSON rows, plus a full-text index over
+//! titles and descriptions. Each resource remembers the newest `updatedAt`
+//! it has seen (never later than a few minutes before the pull started), so
+//! later pulls only fetch what changed (archived entities included). `--offline` answers `list`, `get` and `search` from here.
+//! The database lives in `$XDG_DATA_HOME/linears/mirror.db`.
+
+mod filter;
+mod select;
+
+pub use filter::matches;
+pub use select::project;
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Duration, SecondsFormat, Utc};
+use rusqlite::{params, Connection, OptionalExtension};
+use std::path::{Path, PathBuf};
+
+use crate::common::constants::{env, mirror, state};
+use crate::generated::Resource;
+
+/// Resources mirrored by `sync pull`, with the relations stored alongside
+/// them (as `{ id ... }` objects, so offline filters can follow them)
+pub const MIRRORED: &[(Resource, &[&str])] = &[
+    (Resource::Team, &[]),
+    (Resource::User, &[]),
+    (Resource::WorkflowState, &["team"]),
+    (Resource::IssueLabel, &["team"]),
+    (Resource::Project, &["creator"]),
+    (Resource::Cycle, &["team"]),
+    (
+        Resource::Issue,
+        &["team", "state", "assignee", "creator", "project", "cycle", "parent", "labels"],
+    ),
+    (Resource::Comment, &["user"]),
+];
+
+/// Relations expanded when mirroring a resource, or None if it is not mirrored
+pub fn mirrored_relations(resource: Resource) -> Option<&'static [&'static str]> {
+    let resource = canonical(resource);
+    MIRRORED
+        .iter()
+        .find(|(r, _)| *r == resource)
+        .map(|(_, relations)| *relations)
+}
+
+/// The singular resource a plural one (e.g. `issues`) is mirrored as
+pub fn canonical(resource: Resource) -> Resource {
+    Resource::all()
+        .iter()
+        .copied()
+        .find(|r| r.plural_name() == resource.plural_name() && r.field_name() != r.plural_name())
+        .unwrap_or(resource)
+}
+
+/// Location of the mirror database (`LINEARS_MIRROR` overrides it)
+pub fn mirror_path() -> Result<PathBuf> {
+    if let Ok(path) = std::env::var(env::MIRROR) {
+        if !path.trim().is_empty() {
+            return Ok(PathBuf::from(path));
+        }
+    }
+    let data_home = match std::env::var(env::DATA_HOME) {
+        Ok(dir) if !dir.trim().is_empty() => PathBuf::from(dir),
+        _ => {
+            let home = std::env::var(env::HOME)
+                .context("Cannot locate the mirror: neither XDG_DATA_HOME nor HOME is set")?;
+            PathBuf::from(home).join(".local").join("share")
+        }
+    };
+    Ok(data_home.join(state::DIR_NAME).join(mirror::DB_FILE))
+}
+
+/// High-water mark to store after a pull that started at `started`
+///
+/// Pages are read in `createdAt` order, so an entity updated mid-pull on a
+/// page already passed has an `updatedAt` below the newest one seen. The mark
+/// is held back to the pull's start, less a margin for clock skew between
+/// this machine and the API, so the next pull fetches such entities again.
+pub fn sync_mark(newest: &str, started: DateTime<Utc>) -> String {
+    let limit = started - Duration::seconds(mirror::SYNC_MARGIN_SECS);
+    match DateTime::parse_from_rfc3339(newest) {
+        Ok(parsed) if parsed.with_timezone(&Utc) <= limit => newest.to_string(),
+        _ => limit.to_rfc3339_opts(SecondsFormat::Millis, true),
+    }
+}
+
+/// Sync state of one resource
+#[derive(Debug, Clone)]
+pub struct SyncState {
+    /// Resource field name (e.g. "issue")
+    pub resource: String,
+    /// Newest `updatedAt` stored; the next pull fetches changes after it
+    pub last_updated_at: Option<String>,
+    /// When the last pull finished
+    pub synced_at: Option<String>,
+    pub entities: i64,
+    pub archived: i64,
+}
+
+/// An open mirror database
+pub struct Mirror {
+    conn: Connection,
+    path: PathBuf,
+}
+
+impl Mirror {
+    /// Open (and create if needed) the mirror at `path`
+    pub fn open(path: &Path) -> Result<Self> {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)
+                .with_context(|| format!("Failed to create mirror directory {}", dir.display()))?;
+        }
+        let conn = Connection::open(path)
+            .with_context(|| format!("Failed to open mirror {}", path.display()))?;
+        conn.execute_batch(
+            "PRAGMA journal_mode = WAL;
+             CREATE TABLE IF NOT EXISTS entities (
+                 resource TEXT NOT NULL,
+                 id TEXT NOT NULL,
+                 created_at TEXT,
+                 updated_at TEXT,
+                 archived_at TEXT,
+                 data TEXT NOT NULL,
+                 PRIMARY KEY (resource, id)
+             );
+             CREATE TABLE IF NOT EXISTS sync_state (
+                 resource TEXT PRIMARY KEY,
+                 last_updated_at TEXT,
+                 synced_at TEXT NOT NULL
+             );
+             CREATE VIRTUAL TABLE IF NOT EXISTS search USING fts5(
+                 resource UNINDEXED, id UNINDEXED, title, body
+             );",
+        )
+        .context("Failed to initialize the mirror schema")?;
+        Ok(Self {
+            conn,
+            path: path.to_path_buf(),
+        })
+    }
+
+    /// Open the mirror for reading, failing if `sync pull` never ran
+    pub fn open_existing(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            anyhow::bail!(
+                "No local mirror at {}; run 'linears sync pull' first",
+                path.display()
+            );
+        }
+        Self::open(path)
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Newest `updatedAt` stored for a resource
+    pub fn last_updated_at(&self, resource: Resource) -> Result<Option<String>> {
+        let value: Option<Option<String>> = self
+            .conn
+            .query_row(
+                "SELECT last_updated_at FROM sync_state WHERE resource = ?1",
+                params![resource.field_name()],
+                |row| row.get(0),
+            )
+            .optional()?;
+        Ok(value.flatten())
+    }
+
+    /// Insert or replace entities, returning the newest `updatedAt` among them
+    pub fn upsert(&mut self, resource: Resource, nodes: &[serde_json::Value]) -> Result<Option<String>> {
+        let tx = self.conn.transaction()?;
+        let mut newest: Option<String> = None;
+        for node in nodes {
+            let Some(id) = node["id"].as_str() else {
+                continue;
+            };
+            let updated_at = node["updatedAt"].as_str();
+            tx.execute(
+                "INSERT OR REPLACE INTO entities (resource, id, created_at, updated_at, archived_at, data)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+                params![
+                    resource.field_name(),
+                    id,
+                    node["createdAt"].as_str(),
+                    updated_at,
+                    node["archivedAt"].as_str(),
+                    node.to_string()
+                ],
+            )?;
+            tx.execute(
+                "DELETE FROM search WHERE resource = ?1 AND id = ?2",
+                params![resource.field_name(), id],
+            )?;
+            tx.execute(
+                "INSERT INTO search (resource, id, title, body) VALUES (?1, ?2, ?3, ?4)",
+                params![resource.field_name(), id, search_title(node), search_body(node)],
+            )?;
+            if let Some(updated_at) = updated_at {
+                if newest.as_deref().is_none_or(|n| updated_at > n) {
+                    newest = Some(updated_at.to_string());
+                }
+            }
+        }
+        tx.commit()?;
+        Ok(newest)
+    }
+
+    /// Record a finished pull (keeping the older high-water mark if nothing changed)
+    pub fn mark_synced(&self, resource: Resource, newest: Option<&str>) -> Result<()> {
+        self.conn.execute(
+            "INSERT INTO sync_state (resource, last_updated_at, synced_at) VALUES (?1, ?2, ?3)
+             ON CONFLICT(resource) DO UPDATE SET
+                 last_updated_at = COALESCE(excluded.last_updated_at, last_updated_at),
+                 synced_at = excluded.synced_at",
+            params![resource.field_name(), newest, Utc::now().to_rfc3339()],
+        )?;
+        Ok(())
+    }
+
+    /// Drop a resource's rows and sync state (for a full re-pull)
+    pub fn reset(&self, resource: Resource) -> Result<()> {
+        let name = resource.field_name();
+        self.conn.execute("DELETE FROM entities WHERE resource = ?1", params![name])?;
+        self.conn.execute("DELETE FROM search WHERE resource = ?1", params![name])?;
+        self.conn.execute("DELETE FROM sync_state WHERE resource = ?1", params![name])?;
+        Ok(())
+    }
+
+    /// Every stored entity of a resource, newest first
+    pub fn entities(&self, resource: Resource, include_archived: bool) -> Result<Vec<serde_json::Value>> {
+        let mut statement = self.conn.prepare(
+            "SELECT data FROM entities
+             WHERE resource = ?1 AND (?2 OR archived_at IS NULL)
+             ORDER BY created_at DESC, id",
+        )?;
+        let rows = statement.query_map(params![resource.field_name(), include_archived], |row| {
+            row.get::<_, String>(0)
+        })?;
+        rows.map(|data| Ok(serde_json::from_str(&data?)?)).collect()
+    }
+
+    /// Look up an entity by ID, identifier (`ENG-123`) or key (`ENG`)
+    pub fn find(&self, resource: Resource, id: &str) -> Result<Option<serde_json::Value>> {
+        let data: Option<String> = self
+            .conn
+            .query_row(
+                "SELECT data FROM entities
+                 WHERE resource = ?1 AND (id = ?2
+                     OR json_extract(data, '$.identifier') = upper(?2)
+                     OR json_extract(data, '$.key') = upper(?2))
+                 LIMIT 1",
+                params![resource.field_name(), id],
+                |row| row.get(0),
+            )
+            .optional()?;
+        Ok(data.map(|d| serde_json::from_str(&d)).transpose()?)
+    }
+
+    /// Full-text search over titles and descriptions, best matches first
+    pub fn search(&self, resource: Resource, text: &str, limit: usize) -> Result<Vec<serde_json::Value>> {
+        let Some(query) = fts_query(text) else {
+            return Ok(Vec::new());
+        };
+        let mut statement = self.conn.prepare(
+            "SELECT e.data FROM search
+             JOIN entities e ON e.resource = search.resource AND e.id = search.id
+             WHERE search MATCH ?1 AND search.resource = ?2 AND e.archived_at IS NULL
+             ORDER BY search.rank
+             LIMIT ?3",
+        )?;
+        let rows = statement.query_map(params![query, resource.field_name(), limit as i64], |row| {
+            row.get::<_, String>(0)
+        })?;
+        rows.map(|data| Ok(serde_json::from_str(&data?)?)).collect()
+    }
+
+    /// Sync state of every mirrored resource
+    pub fn status(&self) -> Result<Vec<SyncState>> {
+        MIRRORED
+            .iter()
+            .map(|(resource, _)| {
+                let name = resource.field_name();
+                let (entities, archived): (i64, i64) = self.conn.query_row(
+                    "SELECT COUNT(*), COUNT(archived_at) FROM entities WHERE resource = ?1",
+                    params![name],
+                    |row| Ok((row.get(0)?, row.get(1)?)),
+                )?;
+                let sync: Option<(Option<String>, String)> = self
+                    .conn
+                    .query_row(
+                        "SELECT last_updated_at, synced_at FROM sync_state WHERE resource = ?1",
+                        params![name],
+                        |row| Ok((row.get(0)?, row.get(1)?)),
+                    )
+                    .optional()?;
+                let (last_updated_at, synced_at) = match sync {
+                    Some((last, at)) => (last, Some(at)),
+                    None => (None, None),
+                };
+                Ok(SyncState {
+                    resource: name.to_string(),
+                    last_updated_at,
+                    synced_at,
+                    entities,
+                    archived,
+                })
+            })
+            .collect()
+    }
+}
+
+/// Text indexed as the title: issue titles, names elsewhere
+fn search_title(node: &serde_json::Value) -> String {
+    ["identifier", "title", "name", "key"]
+        .iter()
+        .filter_map(|f| node[*f].as_str())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Text indexed as the body: descriptions, comment bodies, project content
+fn search_body(node: &serde_json::Value) -> String {
+    ["description", "body", "content"]
+        .iter()
+        .filter_map(|f| node[*f].as_str())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Every word must match; words are quoted so FTS syntax in the text is literal
+fn fts_query(text: &str) -> Option<String> {
+    let terms: Vec<String> = text
+        .split_whitespace()
+        .map(|word| format!("\"{}\"", word.replace('"', "\"\"")))
+        .collect();
+    (!terms.is_empty()).then(|| terms.join(" "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn temp_mirror(name: &str) -> (Mirror, PathBuf) {
+        let path = std::env::temp_dir().join(format!("linears-mirror-{}-{}.db", name, std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        (Mirror::open(&path).unwrap(), path)
+    }
+
+    #[test]
+    fn test_upsert_find_and_search() {
+        let (mut mirror, path) = temp_mirror("upsert");
+        let newest = mirror
+            .upsert(
+                Resource::Issue,
+                &[
+                    json!({ "id": "a", "identifier": "ENG-1", "title": "Login fails", "description": "Safari only",
+                            "createdAt": "2025-01-01T00:00:00.000Z", "updatedAt": "2025-01-03T00:00:00.000Z" }),
+                    json!({ "id": "b", "identifier": "ENG-2", "title": "Dark mode",
+                            "createdAt": "2025-01-02T00:00:00.000Z", "updatedAt": "2025-01-02T00:00:00.000Z",
+                            "archivedAt": "2025-01-05T00:00:00.000Z" }),
+                ],
+            )
+            .unwrap();
+        assert_eq!(newest.as_deref(), Some("2025-01-03T00:00:00.000Z"));
+        mirror.mark_synced(Resource::Issue, newest.as_deref()).unwrap();
+        mirror.mark_synced(Resource::Issue, None).unwrap();
+        assert_eq!(
+            mirror.last_updated_at(Resource::Issue).unwrap().as_deref(),
+            Some("2025-01-03T00:00:00.000Z")
+        );
+
+        assert_eq!(mirror.find(Resource::Issue, "eng-2").unwrap().unwrap()["id"], "b");
+        assert!(mirror.find(Resource::Team, "a").unwrap().is_none());
+        assert_eq!(mirror.entities(Resource::Issue, false).unwrap().len(), 1);
+        assert_eq!(mirror.entities(Resource::Issue, true).unwrap()[0]["id"], "b");
+
+        // Replacing an entity replaces its index entry too
+        mirror
+            .upsert(Resource::Issue, &[json!({ "id": "a", "title": "Login fails", "description": "Chrome too" })])
+            .unwrap();
+        assert!(mirror.search(Resource::Issue, "safari", 10).unwrap().is_empty());
+        assert_eq!(mirror.search(Resource::Issue, "chrome login", 10).unwrap().len(), 1);
+        assert!(mirror.search(Resource::Issue, "\"unbalanced", 10).unwrap().is_empty());
+
+        let status = mirror.status().unwrap();
+        let issues = status.iter().find(|s| s.resource == "issue").unwrap();
+        assert_eq!((issues.entities, issues.archived), (2, 1));
+
+        mirror.reset(Resource::Issue).unwrap();
+        assert!(mirror.last_updated_at(Resource::Issue).unwrap().is_none());
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_canonical_mirrored_resource() {
+        assert_eq!(canonical(Resource::Issues), Resource::Issue);
+        assert_eq!(canonical(Resource::Issue), Resource::Issue);
+        assert!(mirrored_relations(Resource::Issues).is_some());
+        assert!(mirrored_relations(Resource::Roadmap).is_none());
+    }
+
+    #[test]
+    fn test_sync_mark_stays_behind_pull_start() {
+        let started = DateTime::parse_from_rfc3339("2025-01-10T12:00:00Z").unwrap().with_timezone(&Utc);
+        assert_eq!(sync_mark("2025-01-03T00:00:00.000Z", started), "2025-01-03T00:00:00.000Z");
+        // Changes seen from the last few minutes are fetched again next time
+        assert_eq!(sync_mark("2025-01-10T11:59:00.000Z", started), "2025-01-10T11:55:00.000Z");
+    }
+}
@@ -0,0 +1,120 @@
+//! GraphQL field selections applied to mirrored entities
+//!
+//! Mirrored rows hold every field a pull fetched; offline `list` trims them
+//! to the same selection the API query would have asked for.
+
+use anyhow::{bail, Context, Result};
+use graphql_parser::query::{parse_query, Definition, OperationDefinition, Selection, SelectionSet};
+use serde_json::Value;
+
+/// One selected field and its sub-selection
+#[derive(Debug, PartialEq)]
+struct Field {
+    /// Key in the result (the alias, if any)
+    key: String,
+    /// Field read from the stored entity
+    name: String,
+    children: Vec<Field>,
+}
+
+/// Keep only the fields of a selection such as `id title state { name }`
+pub fn project(nodes: &[Value], selection: &str) -> Result<Vec<Value>> {
+    let query = format!("{{ {} }}", selection);
+    let document = parse_query::<String>(&query)
+        .with_context(|| format!("Invalid field selection: {}", selection))?;
+    let Some(Definition::Operation(OperationDefinition::SelectionSet(set))) = document.definitions.first() else {
+        bail!("Invalid field selection: {}", selection);
+    };
+    let mut fields = Vec::new();
+    collect_fields(set, &mut fields)?;
+    Ok(nodes.iter().map(|node| project_fields(node, &fields)).collect())
+}
+
+fn collect_fields(set: &SelectionSet<String>, fields: &mut Vec<Field>) -> Result<()> {
+    for selection in &set.items {
+        match selection {
+            Selection::Field(field) => {
+                let key = field.alias.as_ref().unwrap_or(&field.name);
+                let mut children = Vec::new();
+                collect_fields(&field.selection_set, &mut children)?;
+                match fields.iter().position(|f| &f.key == key) {
+                    // `state { name }` and `state { id }` merge, as in GraphQL;
+                    // the merged field moves last, where it was selected again
+                    Some(index) => {
+                        let mut merged = fields.remove(index);
+                        merged.children.extend(children);
+                        fields.push(merged);
+                    }
+                    None => fields.push(Field {
+                        key: key.clone(),
+                        name: field.name.clone(),
+                        children,
+                    }),
+                }
+            }
+            // Mirrored rows all have the resource's own type
+            Selection::InlineFragment(fragment) => collect_fields(&fragment.selection_set, fields)?,
+            Selection::FragmentSpread(spread) => {
+                bail!("Fragment spreads are not supported offline: ...{}", spread.fragment_name)
+            }
+        }
+    }
+    Ok(())
+}
+
+fn project_fields(value: &Value, fields: &[Field]) -> Value {
+    match value {
+        Value::Array(items) => Value::Array(items.iter().map(|item| project_fields(item, fields)).collect()),
+        Value::Object(object) => Value::Object(
+            fields
+                .iter()
+                .map(|field| {
+                    let value = object.get(&field.name).cloned().unwrap_or_default();
+                    let value = if field.children.is_empty() {
+                        value
+                    } else {
+                        project_fields(&value, &field.children)
+                    };
+                    (field.key.clone(), value)
+                })
+                .collect(),
+        ),
+        other => other.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_project_nested_and_merged() {
+        let issue = json!({
+            "id": "a",
+            "title": "Login fails",
+            "description": "Safari only",
+            "state": { "id": "s1", "name": "Todo", "type": "unstarted" },
+            "labels": { "nodes": [{ "id": "l1", "name": "bug", "color": "#f00" }] },
+            "assignee": null
+        });
+        let projected = project(
+            &[issue],
+            "id title state { name } assignee { name } labels { nodes { name } } state { id } heading: title",
+        )
+        .unwrap();
+        assert_eq!(
+            projected[0],
+            json!({
+                "id": "a",
+                "title": "Login fails",
+                "assignee": null,
+                "labels": { "nodes": [{ "name": "bug" }] },
+                "state": { "name": "Todo", "id": "s1" },
+                "heading": "Login fails"
+            })
+        );
+        assert_eq!(project(&[json!({})], "url").unwrap(), [json!({ "url": null })]);
+        assert!(project(&[], "state { name").is_err());
+    }
+}
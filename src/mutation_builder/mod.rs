@@ -1,5 +1,7 @@
 //! Mutation construction for create, update, delete, and archive operations
 
+use sha2::{Digest, Sha256};
+
 use crate::common::{to_camel_case, to_pascal_case};
 use crate::generated::{get_mutation_result_fields, MutationOp};
 
@@ -95,6 +97,58 @@ pub fn build_create_mutation(
     (query, variables)
 }
 
+/// Build an `issueBatchCreate` mutation (the schema's only batch create)
+pub fn build_issue_batch_create_mutation(inputs: Vec<serde_json::Value>) -> (String, serde_json::Value) {
+    let query = format!(
+        r#"mutation IssueBatchCreate($input: IssueBatchCreateInput!) {{
+  issueBatchCreate(input: $input) {{
+    success
+    issues {{
+      {entity_fields}
+    }}
+  }}
+}}"#,
+        entity_fields = get_mutation_result_fields("issue"),
+    );
+
+    let variables = serde_json::json!({
+        "input": { "issues": inputs },
+    });
+
+    (query, variables)
+}
+
+/// Derive a deterministic entity UUID (v4 format) from an idempotency key
+///
+/// Create inputs accept a client-supplied `id`, so the same key always targets
+/// the same entity and a retried create can be detected by looking it up.
+pub fn idempotent_entity_id(resource_name: &str, key: &str) -> String {
+    let digest = Sha256::digest(format!("linears:{}:{}", resource_name, key).as_bytes());
+    let mut bytes = [0u8; 16];
+    bytes.copy_from_slice(&digest[..16]);
+
+    // Set version (4) and variant (RFC 4122) bits
+    bytes[6] = (bytes[6] & 0x0f) | 0x40;
+    bytes[8] = (bytes[8] & 0x3f) | 0x80;
+
+    let hex: String = bytes.iter().map(|b| format!("{:02x}", b)).collect();
+    format!(
+        "{}-{}-{}-{}-{}",
+        &hex[0..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..32]
+    )
+}
+
+/// Derive an idempotency key from the create input itself
+pub fn input_fingerprint(input: &serde_json::Value) -> String {
+    // serde_json maps are sorted, so equal inputs serialize identically
+    let digest = Sha256::digest(input.to_string().as_bytes());
+    digest.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
 /// Build an update mutation for a resource
 pub fn build_update_mutation(
     resource_name: &str,
@@ -191,3 +245,57 @@ pub fn build_unarchive_mutation(resource_name: &str, id: &str) -> (String, serde
     (query, variables)
 }
 
+/// Build a fileUpload mutation requesting a signed upload URL
+pub fn build_file_upload_mutation(
+    filename: &str,
+    content_type: &str,
+    size: u64,
+) -> (String, serde_json::Value) {
+    let query = r#"mutation FileUpload($contentType: String!, $filename: String!, $size: Int!) {
+  fileUpload(contentType: $contentType, filename: $filename, size: $size) {
+    success
+    uploadFile {
+      uploadUrl
+      assetUrl
+      contentType
+      filename
+      size
+      headers {
+        key
+        value
+      }
+    }
+  }
+}"#
+    .to_string();
+
+    let variables = serde_json::json!({
+        "contentType": content_type,
+        "filename": filename,
+        "size": size,
+    });
+
+    (query, variables)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_idempotent_entity_id() {
+        let id = idempotent_entity_id("issue", "nightly-2025-01-10");
+        assert_eq!(id, idempotent_entity_id("issue", "nightly-2025-01-10"));
+        assert_ne!(id, idempotent_entity_id("issue", "nightly-2025-01-11"));
+        assert_ne!(id, idempotent_entity_id("project", "nightly-2025-01-10"));
+        assert!(crate::validate::is_uuid(&id));
+        assert_eq!(&id[14..15], "4");
+    }
+
+    #[test]
+    fn test_input_fingerprint_is_order_independent() {
+        let a: serde_json::Value = serde_json::from_str(r#"{"title":"A","teamId":"t"}"#).unwrap();
+        let b: serde_json::Value = serde_json::from_str(r#"{"teamId":"t","title":"A"}"#).unwrap();
+        assert_eq!(input_fingerprint(&a), input_fingerprint(&b));
+    }
+}
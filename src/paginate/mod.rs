@@ -0,0 +1,269 @@
+//! Cursor pagination over any connection field
+//!
+//! A [`Paginator`] builds each page's request with a closure, reads the
+//! connection at a path in `data` (`issues`, or `team.issues` for a nested
+//! connection) and follows `endCursor`, or `startCursor` when paging
+//! backwards, until the last page or its node limit. The client's rate-limit
+//! budget is checked before every page.
+
+mod partition;
+
+pub use partition::{dedup_by_id, fetch_partitions, partition_filters, range_filter, CreatedRange};
+
+use futures::stream::{self, Stream, TryStreamExt};
+
+use crate::client::{Client, ClientError, GraphQLError, GraphQLRequest};
+use crate::common::constants::pagination;
+use crate::generated::Resource;
+use crate::query_builder::{build_list_query, ListQuery};
+use crate::render::PageInfo;
+
+/// Which way to follow cursors
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Direction {
+    /// `first`/`after`, following `endCursor`
+    #[default]
+    Forward,
+    /// `last`/`before`, following `startCursor`
+    Backward,
+}
+
+/// Connection arguments for one page
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PageArgs {
+    pub first: Option<i32>,
+    pub after: Option<String>,
+    pub last: Option<i32>,
+    pub before: Option<String>,
+}
+
+impl PageArgs {
+    fn new(direction: Direction, size: i32, cursor: Option<String>) -> Self {
+        match direction {
+            Direction::Forward => Self {
+                first: Some(size),
+                after: cursor,
+                ..Default::default()
+            },
+            Direction::Backward => Self {
+                last: Some(size),
+                before: cursor,
+                ..Default::default()
+            },
+        }
+    }
+
+    /// Set the arguments that have values in a variables object
+    pub fn apply(&self, variables: &mut serde_json::Value) {
+        let pairs = [
+            ("first", self.first.map(serde_json::Value::from)),
+            ("after", self.after.clone().map(serde_json::Value::from)),
+            ("last", self.last.map(serde_json::Value::from)),
+            ("before", self.before.clone().map(serde_json::Value::from)),
+        ];
+        for (name, value) in pairs {
+            if let Some(value) = value {
+                variables[name] = value;
+            }
+        }
+    }
+}
+
+/// One fetched page of a connection
+#[derive(Debug, Clone, Default)]
+pub struct ConnectionPage {
+    pub nodes: Vec<serde_json::Value>,
+    pub page_info: PageInfo,
+    /// GraphQL errors returned alongside the data (partial-result mode)
+    pub errors: Vec<GraphQLError>,
+}
+
+/// Async stream of a connection's pages or nodes
+pub struct Paginator<'a, F> {
+    client: &'a Client,
+    build: F,
+    path: Vec<String>,
+    direction: Direction,
+    page_size: i32,
+    cursor: Option<String>,
+    limit: Option<usize>,
+}
+
+impl<'a, F> Paginator<'a, F>
+where
+    F: Fn(&PageArgs) -> GraphQLRequest + 'a,
+{
+    /// Paginate the connection at a dotted path in `data` (e.g. `"team.issues"`)
+    pub fn new(client: &'a Client, connection_path: &str, build: F) -> Self {
+        Self {
+            client,
+            build,
+            path: connection_path.split('.').map(String::from).collect(),
+            direction: Direction::Forward,
+            page_size: pagination::PAGE_SIZE,
+            cursor: None,
+            limit: None,
+        }
+    }
+
+    /// Nodes per request (defaults to 50)
+    pub fn page_size(mut self, size: i32) -> Self {
+        self.page_size = size.max(1);
+        self
+    }
+
+    /// Follow `startCursor` with `last`/`before` instead
+    pub fn direction(mut self, direction: Direction) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Start after (or before, backwards) this cursor
+    pub fn cursor(mut self, cursor: Option<String>) -> Self {
+        self.cursor = cursor;
+        self
+    }
+
+    /// Stop after this many nodes in total
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Request for the first page, e.g. to check its complexity up front
+    pub fn first_request(&self) -> GraphQLRequest {
+        (self.build)(&self.args(self.cursor.clone(), 0))
+    }
+
+    /// Stream pages, fetching the next one as the stream is consumed
+    pub fn pages(mut self) -> impl Stream<Item = Result<ConnectionPage, ClientError>> + 'a {
+        let start = self.cursor.take();
+        // State: the next page's cursor and the nodes yielded so far,
+        // or None once the last page was fetched
+        stream::try_unfold((self, Some((start, 0usize))), |(paginator, state)| async move {
+            let Some((cursor, fetched)) = state else {
+                return Ok(None);
+            };
+            let mut page = paginator.fetch(cursor, fetched).await?;
+
+            let limit = paginator.limit.unwrap_or(usize::MAX);
+            page.nodes.truncate(limit - fetched);
+            let fetched = fetched + page.nodes.len();
+            let next = match paginator.direction {
+                Direction::Forward if page.page_info.has_next_page => page.page_info.end_cursor.clone(),
+                Direction::Backward if page.page_info.has_previous_page => page.page_info.start_cursor.clone(),
+                _ => None,
+            };
+            // An empty page with a cursor would loop forever
+            let state = match next {
+                Some(cursor) if fetched < limit && !page.nodes.is_empty() => Some((Some(cursor), fetched)),
+                _ => None,
+            };
+            Ok(Some((page, (paginator, state))))
+        })
+    }
+
+    /// Stream nodes across pages
+    pub fn stream(self) -> impl Stream<Item = Result<serde_json::Value, ClientError>> + 'a {
+        self.pages()
+            .map_ok(|page| stream::iter(page.nodes.into_iter().map(Ok)))
+            .try_flatten()
+    }
+
+    /// Collect every node up to the limit
+    pub async fn all(self) -> Result<Vec<serde_json::Value>, ClientError> {
+        self.stream().try_collect().await
+    }
+
+    fn args(&self, cursor: Option<String>, fetched: usize) -> PageArgs {
+        let remaining = self.limit.map_or(usize::MAX, |limit| limit.saturating_sub(fetched));
+        let size = self.page_size.min(i32::try_from(remaining).unwrap_or(i32::MAX)).max(1);
+        PageArgs::new(self.direction, size, cursor)
+    }
+
+    async fn fetch(&self, cursor: Option<String>, fetched: usize) -> Result<ConnectionPage, ClientError> {
+        self.client.throttle().await;
+        let request = (self.build)(&self.args(cursor, fetched));
+        let response = self.client.execute(request).await?;
+        let data = response.data.unwrap_or_default();
+        let mut page = connection_page(&data, &self.path);
+        page.errors = response.errors.unwrap_or_default();
+        Ok(page)
+    }
+}
+
+// The fn type only anchors the impl: `list` returns its own builder
+impl<'a> Paginator<'a, fn(&PageArgs) -> GraphQLRequest> {
+    /// Paginate a resource's top-level list, with each page's cursor arguments
+    /// set on `query`; `verbose` prints every request to stderr
+    pub fn list(
+        client: &'a Client,
+        resource: Resource,
+        query: ListQuery,
+        verbose: bool,
+    ) -> Paginator<'a, impl Fn(&PageArgs) -> GraphQLRequest + Clone + 'a> {
+        Paginator::new(client, resource.plural_name(), move |args: &PageArgs| {
+            let page_query = ListQuery {
+                first: args.first,
+                after: args.after.clone(),
+                last: args.last,
+                before: args.before.clone(),
+                ..query.clone()
+            };
+            let (query, variables) = build_list_query(resource, &page_query);
+            if verbose {
+                eprintln!("Query: {}", query);
+                eprintln!("Variables: {}", serde_json::to_string_pretty(&variables).unwrap_or_default());
+            }
+            GraphQLRequest {
+                query,
+                variables: Some(variables),
+                operation_name: None,
+            }
+        })
+    }
+}
+
+/// Nodes and page info of the connection at `path` (empty if absent)
+fn connection_page(data: &serde_json::Value, path: &[String]) -> ConnectionPage {
+    let connection = path.iter().fold(data, |value, key| &value[key]);
+    ConnectionPage {
+        nodes: connection["nodes"].as_array().cloned().unwrap_or_default(),
+        page_info: serde_json::from_value(connection["pageInfo"].clone()).unwrap_or_default(),
+        errors: Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_page_args_apply() {
+        let mut variables = json!({ "filter": {}, "first": 20 });
+        PageArgs::new(Direction::Forward, 5, Some("c1".to_string())).apply(&mut variables);
+        assert_eq!(variables, json!({ "filter": {}, "first": 5, "after": "c1" }));
+
+        let mut variables = json!({});
+        PageArgs::new(Direction::Backward, 5, None).apply(&mut variables);
+        assert_eq!(variables, json!({ "last": 5 }));
+    }
+
+    #[test]
+    fn test_nested_connection_page() {
+        let data = json!({
+            "team": { "issues": {
+                "nodes": [{ "id": "a" }],
+                "pageInfo": { "hasNextPage": true, "endCursor": "c1" }
+            } }
+        });
+        let page = connection_page(&data, &["team".to_string(), "issues".to_string()]);
+        assert_eq!(page.nodes, vec![json!({ "id": "a" })]);
+        assert!(page.page_info.has_next_page);
+        assert_eq!(page.page_info.end_cursor.as_deref(), Some("c1"));
+
+        let missing = connection_page(&json!({ "team": null }), &["team".to_string(), "issues".to_string()]);
+        assert!(missing.nodes.is_empty());
+    }
+}
@@ -0,0 +1,187 @@
+//! Partitioned prefetch: disjoint `createdAt` ranges paged concurrently
+//!
+//! Cursors make a single connection strictly sequential. A probe query finds
+//! the oldest and newest `createdAt` under the filter; the span is split into
+//! half-open ranges, each paged on its own, and the results are merged back
+//! in order.
+
+use chrono::{DateTime, Duration, SecondsFormat, Utc};
+use futures::stream::{self, Stream, StreamExt, TryStreamExt};
+use std::collections::HashSet;
+
+use super::{ConnectionPage, PageArgs, Paginator};
+use crate::client::{Client, ClientError, GraphQLRequest};
+use crate::generated::Resource;
+use crate::query_builder::build_created_range_query;
+
+/// Oldest and newest `createdAt` under a filter, and the server's sort direction
+#[derive(Debug, Clone, PartialEq)]
+pub struct CreatedRange {
+    pub oldest: DateTime<Utc>,
+    pub newest: DateTime<Utc>,
+    /// Whether `first` returns the newest entity
+    pub descending: bool,
+}
+
+impl CreatedRange {
+    /// Read the probe response (`first`/`last` aliases); None if nothing matched
+    pub fn from_probe(data: &serde_json::Value) -> Option<Self> {
+        let created = |alias: &str| {
+            data[alias]["nodes"][0]["createdAt"]
+                .as_str()
+                .and_then(|s| DateTime::parse_from_rfc3339(s).ok())
+                .map(|dt| dt.with_timezone(&Utc))
+        };
+        let (first, last) = (created("first")?, created("last")?);
+        Some(Self {
+            oldest: first.min(last),
+            newest: first.max(last),
+            descending: first > last,
+        })
+    }
+
+    /// `count` half-open `[start, end)` ranges covering the span, in result order
+    pub fn split(&self, count: usize) -> Vec<(DateTime<Utc>, DateTime<Utc>)> {
+        // One past the newest, so the last range includes it
+        let end = self.newest + Duration::milliseconds(1);
+        let span = (end - self.oldest).num_milliseconds();
+        let count = (count.max(1) as i64).min(span).max(1);
+        let mut ranges: Vec<_> = (0..count)
+            .map(|i| {
+                let start = self.oldest + Duration::milliseconds(span * i / count);
+                let stop = self.oldest + Duration::milliseconds(span * (i + 1) / count);
+                (start, stop)
+            })
+            .collect();
+        if self.descending {
+            ranges.reverse();
+        }
+        ranges
+    }
+}
+
+/// Probe the `createdAt` span under a filter and split it into up to `count`
+/// range filters, in result order; None if nothing matched
+pub async fn partition_filters(
+    client: &Client,
+    resource: Resource,
+    filter: Option<&serde_json::Value>,
+    include_archived: bool,
+    count: usize,
+) -> Result<Option<(CreatedRange, Vec<serde_json::Value>)>, ClientError> {
+    let (probe, variables) = build_created_range_query(resource, filter, include_archived);
+    let response = client
+        .execute(GraphQLRequest {
+            query: probe,
+            variables: Some(variables),
+            operation_name: None,
+        })
+        .await?;
+    let Some(range) = CreatedRange::from_probe(&response.data.unwrap_or_default()) else {
+        return Ok(None);
+    };
+    let filters = range.split(count).into_iter().map(|r| range_filter(filter, r)).collect();
+    Ok(Some((range, filters)))
+}
+
+/// The caller's filter restricted to one `createdAt` range
+pub fn range_filter(
+    filter: Option<&serde_json::Value>,
+    (start, end): (DateTime<Utc>, DateTime<Utc>),
+) -> serde_json::Value {
+    let range = serde_json::json!({
+        "createdAt": {
+            "gte": start.to_rfc3339_opts(SecondsFormat::Millis, true),
+            "lt": end.to_rfc3339_opts(SecondsFormat::Millis, true),
+        }
+    });
+    match filter {
+        Some(filter) => serde_json::json!({ "and": [filter, range] }),
+        None => range,
+    }
+}
+
+/// Page each filter's results concurrently, yielding one merged page per
+/// filter in the order given (at most `parallel` in flight)
+pub fn fetch_partitions<'a, P, F>(
+    filters: Vec<serde_json::Value>,
+    parallel: usize,
+    paginate: P,
+) -> impl Stream<Item = Result<ConnectionPage, ClientError>> + 'a
+where
+    P: Fn(serde_json::Value) -> Paginator<'a, F> + 'a,
+    F: Fn(&PageArgs) -> GraphQLRequest + 'a,
+{
+    stream::iter(filters)
+        .map(move |filter| {
+            paginate(filter)
+                .pages()
+                .try_fold(ConnectionPage::default(), |mut merged, page| async move {
+                    merged.nodes.extend(page.nodes);
+                    merged.errors.extend(page.errors);
+                    merged.page_info = page.page_info;
+                    Ok(merged)
+                })
+        })
+        .buffered(parallel.max(1))
+}
+
+/// Drop nodes whose `id` was already seen, keeping the first occurrence
+pub fn dedup_by_id(nodes: &mut Vec<serde_json::Value>, seen: &mut HashSet<String>) {
+    nodes.retain(|node| match node["id"].as_str() {
+        Some(id) => seen.insert(id.to_string()),
+        None => true,
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn probe(first: &str, last: &str) -> serde_json::Value {
+        json!({
+            "first": { "nodes": [{ "createdAt": first }] },
+            "last": { "nodes": [{ "createdAt": last }] },
+        })
+    }
+
+    #[test]
+    fn test_split_covers_range_in_order() {
+        let range = CreatedRange::from_probe(&probe("2025-01-01T00:00:00.000Z", "2025-01-01T00:00:00.999Z")).unwrap();
+        assert!(!range.descending);
+        let parts = range.split(4);
+        assert_eq!(parts.len(), 4);
+        assert_eq!(parts[0].0, range.oldest);
+        assert_eq!(parts[3].1, range.newest + Duration::milliseconds(1));
+        assert!(parts.windows(2).all(|w| w[0].1 == w[1].0));
+
+        let descending = CreatedRange::from_probe(&probe("2025-02-01T00:00:00.000Z", "2025-01-01T00:00:00.000Z")).unwrap();
+        assert!(descending.descending);
+        let parts = descending.split(3);
+        assert_eq!(parts[0].1, descending.newest + Duration::milliseconds(1));
+
+        // A single timestamp cannot be split further
+        let single = CreatedRange::from_probe(&probe("2025-01-01T00:00:00.000Z", "2025-01-01T00:00:00.000Z")).unwrap();
+        assert_eq!(single.split(8).len(), 1);
+
+        assert!(CreatedRange::from_probe(&json!({ "first": { "nodes": [] } })).is_none());
+    }
+
+    #[test]
+    fn test_range_filter_and_dedup() {
+        let start = DateTime::parse_from_rfc3339("2025-01-01T00:00:00Z").unwrap().with_timezone(&Utc);
+        let filter = json!({ "team": { "key": { "eq": "ENG" } } });
+        let combined = range_filter(Some(&filter), (start, start + Duration::days(1)));
+        assert_eq!(combined["and"][0], filter);
+        assert_eq!(combined["and"][1]["createdAt"]["gte"], "2025-01-01T00:00:00.000Z");
+        assert_eq!(combined["and"][1]["createdAt"]["lt"], "2025-01-02T00:00:00.000Z");
+
+        let mut seen = HashSet::new();
+        let mut first = vec![json!({ "id": "a" }), json!({ "id": "b" })];
+        let mut second = vec![json!({ "id": "b" }), json!({ "id": "c" })];
+        dedup_by_id(&mut first, &mut seen);
+        dedup_by_id(&mut second, &mut seen);
+        assert_eq!(second, vec![json!({ "id": "c" })]);
+    }
+}
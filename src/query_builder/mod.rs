@@ -1,108 +1,128 @@
 //! Query construction for list, get, and search operations
 
+#[cfg(feature = "cli")]
 use crate::cli::ListOptions;
+use crate::common::constants::pagination;
 use crate::common::{to_pascal_case, FieldsetPreset};
 use crate::generated::{
-    get_entity_fields, get_preset_fields, get_relation_fields, get_search_filter, Resource,
+    get_entity_fields, get_preset_fields, get_relation_fields, get_search_filter, OrderBy, Resource,
 };
+#[cfg(feature = "cli")]
+use crate::validate;
+
+mod where_expr;
+
+pub use where_expr::parse_where;
+
+/// Options for a list query, independent of the CLI
+#[derive(Debug, Clone, Default)]
+pub struct ListQuery {
+    pub first: Option<i32>,
+    pub after: Option<String>,
+    pub last: Option<i32>,
+    pub before: Option<String>,
+    pub include_archived: bool,
+    pub order_by: Option<OrderBy>,
+    pub filter: Option<serde_json::Value>,
+    pub preset: FieldsetPreset,
+    /// Scalar fields to select instead of the preset's
+    pub select: Option<Vec<String>>,
+    /// Relation expansions (`relation[:fields]`)
+    pub expand: Vec<String>,
+}
+
+#[cfg(feature = "cli")]
+impl ListQuery {
+    /// List options from CLI flags, with a pre-parsed filter value
+    ///
+    /// Without `filter_value`, `--filter`/`--filter-file` are parsed here and
+    /// parse errors are ignored (used by tests and legacy callers; `cmd_list`
+    /// resolves the filter itself with proper errors).
+    pub fn from_options(options: &ListOptions, filter_value: Option<serde_json::Value>) -> Self {
+        let filter = filter_value.or_else(|| {
+            if let Some(ref filter) = options.filter {
+                if filter == "-" {
+                    validate::read_stdin()
+                        .ok()
+                        .and_then(|content| validate::parse_input(&content).ok())
+                } else {
+                    validate::parse_input(filter).ok()
+                }
+            } else if let Some(ref path) = options.filter_file {
+                validate::read_file(path)
+                    .ok()
+                    .and_then(|content| validate::parse_input(&content).ok())
+            } else {
+                None
+            }
+        });
+        Self {
+            first: options.first,
+            after: options.after.clone(),
+            last: options.last,
+            before: options.before.clone(),
+            include_archived: options.include_archived,
+            order_by: options.order_by,
+            filter,
+            preset: options.preset,
+            select: options
+                .select
+                .as_ref()
+                .map(|select| select.split(',').map(|f| f.trim().to_string()).collect()),
+            expand: options.expand.clone().unwrap_or_default(),
+        }
+    }
+}
 
 /// Build a list query with a pre-parsed filter value
+#[cfg(feature = "cli")]
 pub fn build_list_query_with_filter(
     resource: Resource,
     options: &ListOptions,
     filter_value: Option<serde_json::Value>,
 ) -> (String, serde_json::Value) {
-    let field_name = resource.field_name();
-    // Use schema-derived plural name (avoids naive pluralization bugs)
-    let plural_name = resource.plural_name();
+    build_list_query(resource, &ListQuery::from_options(options, filter_value))
+}
 
-    // Get fields - use --select if provided, otherwise use preset
-    let mut node_fields: String = if let Some(ref select) = options.select {
-        // User specified exact fields with --select
-        select.replace(',', " ")
-    } else {
-        // Use preset-based fields
-        get_resource_fields_for_preset(resource, options.preset).to_string()
+/// Field selection for each node: `--select` or the preset, plus expansions
+pub fn node_selection(resource: Resource, list: &ListQuery) -> String {
+    let mut node_fields: String = match list.select {
+        Some(ref select) => select.join(" "),
+        None => get_resource_fields_for_preset(resource, list.preset).to_string(),
     };
 
-    // Handle --expand option for relation expansion
-    if let Some(ref expands) = options.expand {
-        for expand in expands {
-            let expand_fields = parse_expand_spec(expand);
-            node_fields.push_str(&expand_fields);
-        }
+    // Relation expansion
+    for expand in &list.expand {
+        node_fields.push_str(&parse_expand_spec(expand));
     }
+    node_fields
+}
 
-    // Check if filter is provided
-    let has_filter = options.filter.is_some() || options.filter_file.is_some();
+/// Build a list (connection) query and its variables
+pub fn build_list_query(resource: Resource, list: &ListQuery) -> (String, serde_json::Value) {
+    let field_name = resource.field_name();
+    // Use schema-derived plural name (avoids naive pluralization bugs)
+    let plural_name = resource.plural_name();
+    let type_name = to_pascal_case(field_name);
+    let node_fields = node_selection(resource, list);
 
-    // Check if orderBy is provided
-    let has_order_by = options.order_by.is_some();
+    // Only declare the filter and orderBy variables when they're used
+    let mut declarations = String::from("$first: Int, $after: String, $last: Int, $before: String");
+    let mut arguments = String::from("first: $first, after: $after, last: $last, before: $before");
+    if list.filter.is_some() {
+        declarations.push_str(&format!(", $filter: {}Filter", type_name));
+        arguments.push_str(", filter: $filter");
+    }
+    declarations.push_str(", $includeArchived: Boolean");
+    arguments.push_str(", includeArchived: $includeArchived");
+    if list.order_by.is_some() {
+        declarations.push_str(", $orderBy: PaginationOrderBy");
+        arguments.push_str(", orderBy: $orderBy");
+    }
 
-    // Build query with appropriate parameters
-    let query = if has_filter {
-        if has_order_by {
-            format!(
-                r#"query List{resource}($first: Int, $after: String, $last: Int, $before: String, $filter: {resource}Filter, $includeArchived: Boolean, $orderBy: PaginationOrderBy) {{
-  {field}(first: $first, after: $after, last: $last, before: $before, filter: $filter, includeArchived: $includeArchived, orderBy: $orderBy) {{
-    pageInfo {{
-      hasNextPage
-      hasPreviousPage
-      startCursor
-      endCursor
-    }}
-    nodes {{
-      {node_fields}
-    }}
-  }}
-}}"#,
-                resource = to_pascal_case(field_name),
-                field = plural_name,
-                node_fields = node_fields,
-            )
-        } else {
-            format!(
-                r#"query List{resource}($first: Int, $after: String, $last: Int, $before: String, $filter: {resource}Filter, $includeArchived: Boolean) {{
-  {field}(first: $first, after: $after, last: $last, before: $before, filter: $filter, includeArchived: $includeArchived) {{
-    pageInfo {{
-      hasNextPage
-      hasPreviousPage
-      startCursor
-      endCursor
-    }}
-    nodes {{
-      {node_fields}
-    }}
-  }}
-}}"#,
-                resource = to_pascal_case(field_name),
-                field = plural_name,
-                node_fields = node_fields,
-            )
-        }
-    } else if has_order_by {
-        format!(
-            r#"query List{resource}($first: Int, $after: String, $last: Int, $before: String, $includeArchived: Boolean, $orderBy: PaginationOrderBy) {{
-  {field}(first: $first, after: $after, last: $last, before: $before, includeArchived: $includeArchived, orderBy: $orderBy) {{
-    pageInfo {{
-      hasNextPage
-      hasPreviousPage
-      startCursor
-      endCursor
-    }}
-    nodes {{
-      {node_fields}
-    }}
-  }}
-}}"#,
-            resource = to_pascal_case(field_name),
-            field = plural_name,
-            node_fields = node_fields,
-        )
-    } else {
-        format!(
-            r#"query List{resource}($first: Int, $after: String, $last: Int, $before: String, $includeArchived: Boolean) {{
-  {field}(first: $first, after: $after, last: $last, before: $before, includeArchived: $includeArchived) {{
+    let query = format!(
+        r#"query List{resource}({declarations}) {{
+  {field}({arguments}) {{
     pageInfo {{
       hasNextPage
       hasPreviousPage
@@ -114,53 +134,25 @@ pub fn build_list_query_with_filter(
     }}
   }}
 }}"#,
-            resource = to_pascal_case(field_name),
-            field = plural_name,
-            node_fields = node_fields,
-        )
-    };
-
-    // Use pre-parsed filter if provided, otherwise parse from options (for backward compat)
-    let resolved_filter: Option<serde_json::Value> = if filter_value.is_some() {
-        filter_value
-    } else {
-        // Fallback: parse from options (used by tests and legacy callers)
-        // Note: This path uses .ok() for backward compatibility but cmd_list now
-        // uses resolve_filter() which provides proper error handling
-        use crate::validate;
-        if let Some(ref filter) = options.filter {
-            if filter == "-" {
-                validate::read_stdin()
-                    .ok()
-                    .and_then(|content| validate::parse_input(&content).ok())
-            } else {
-                validate::parse_input(filter).ok()
-            }
-        } else if let Some(ref path) = options.filter_file {
-            validate::read_file(path)
-                .ok()
-                .and_then(|content| validate::parse_input(&content).ok())
-        } else {
-            None
-        }
-    };
+        resource = type_name,
+        declarations = declarations,
+        field = plural_name,
+        arguments = arguments,
+        node_fields = node_fields,
+    );
 
     // Only include includeArchived if it's true
-    let include_archived: Option<bool> = if options.include_archived {
-        Some(true)
-    } else {
-        None
-    };
+    let include_archived: Option<bool> = list.include_archived.then_some(true);
 
     // Convert OrderBy to GraphQL enum value
-    let order_by_value: Option<&str> = options.order_by.as_ref().map(|o| o.as_graphql_value());
+    let order_by_value: Option<&str> = list.order_by.as_ref().map(|o| o.as_graphql_value());
 
     let variables = serde_json::json!({
-        "first": options.first,
-        "after": options.after,
-        "last": options.last,
-        "before": options.before,
-        "filter": resolved_filter,
+        "first": list.first,
+        "after": list.after,
+        "last": list.last,
+        "before": list.before,
+        "filter": list.filter,
         "includeArchived": include_archived,
         "orderBy": order_by_value,
     });
@@ -168,6 +160,42 @@ pub fn build_list_query_with_filter(
     (query, variables)
 }
 
+/// Probe for the oldest and newest `createdAt` under a filter
+///
+/// `first` and `last` under the same order give both ends whichever way the
+/// server sorts; used to partition large fetches by creation time.
+pub fn build_created_range_query(
+    resource: Resource,
+    filter: Option<&serde_json::Value>,
+    include_archived: bool,
+) -> (String, serde_json::Value) {
+    let type_name = to_pascal_case(resource.field_name());
+    let plural_name = resource.plural_name();
+    let arguments = "filter: $filter, includeArchived: $includeArchived, orderBy: createdAt";
+    let query = format!(
+        r#"query Probe{resource}($filter: {resource}Filter, $includeArchived: Boolean) {{
+  first: {field}({arguments}, first: 1) {{
+    nodes {{
+      createdAt
+    }}
+  }}
+  last: {field}({arguments}, last: 1) {{
+    nodes {{
+      createdAt
+    }}
+  }}
+}}"#,
+        resource = type_name,
+        field = plural_name,
+        arguments = arguments,
+    );
+    let variables = serde_json::json!({
+        "filter": filter,
+        "includeArchived": include_archived.then_some(true),
+    });
+    (query, variables)
+}
+
 /// Get the fields to select for a resource type with preset
 /// Delegates to generated registry
 fn get_resource_fields_for_preset(resource: Resource, preset: FieldsetPreset) -> &'static str {
@@ -181,9 +209,17 @@ fn get_resource_fields(resource: Resource) -> &'static str {
 
 /// Build a get query for a single entity
 pub fn build_get_query(resource: Resource, id: &str) -> (String, serde_json::Value) {
-    let field_name = resource.field_name();
     // Use generated registry for entity fields (wide preset)
-    let entity_fields = get_entity_fields(resource);
+    build_get_query_with_fields(resource, id, get_entity_fields(resource))
+}
+
+/// Build a get query for a single entity with an explicit field selection
+pub fn build_get_query_with_fields(
+    resource: Resource,
+    id: &str,
+    entity_fields: &str,
+) -> (String, serde_json::Value) {
+    let field_name = resource.field_name();
 
     let query = format!(
         r#"query Get{resource}($id: String!) {{
@@ -203,6 +239,44 @@ pub fn build_get_query(resource: Resource, id: &str) -> (String, serde_json::Val
     (query, variables)
 }
 
+/// Alias of the `index`-th entity in a batched get query
+pub fn batch_alias(index: usize) -> String {
+    format!("i{}", index)
+}
+
+/// Build one query fetching several entities, each under an alias (`i0: issue(id: $id0)`)
+pub fn build_batch_get_query(resource: Resource, ids: &[&str]) -> (String, serde_json::Value) {
+    let field_name = resource.field_name();
+    let entity_fields = get_entity_fields(resource);
+
+    let params: Vec<String> = (0..ids.len()).map(|i| format!("$id{}: String!", i)).collect();
+    let selections: Vec<String> = (0..ids.len())
+        .map(|i| {
+            format!(
+                "  {alias}: {field}(id: $id{i}) {{\n    {entity_fields}\n  }}",
+                alias = batch_alias(i),
+                field = field_name,
+                i = i,
+                entity_fields = entity_fields,
+            )
+        })
+        .collect();
+    let query = format!(
+        "query Get{resource}Batch({params}) {{\n{selections}\n}}",
+        resource = to_pascal_case(field_name),
+        params = params.join(", "),
+        selections = selections.join("\n"),
+    );
+
+    let variables: serde_json::Map<String, serde_json::Value> = ids
+        .iter()
+        .enumerate()
+        .map(|(i, id)| (format!("id{}", i), serde_json::json!(id)))
+        .collect();
+
+    (query, serde_json::Value::Object(variables))
+}
+
 /// Build a search query
 pub fn build_search_query(
     resource: Resource,
@@ -217,11 +291,15 @@ pub fn build_search_query(
     // Note: issueSearch was deprecated, so we use filter approach universally
     let filter = get_search_filter(resource, text);
     let query = format!(
-        r#"query Search{resource}($filter: {resource}Filter, $first: Int) {{
-  {field}(filter: $filter, first: $first) {{
+        r#"query Search{resource}($filter: {resource}Filter, $first: Int, $after: String) {{
+  {field}(filter: $filter, first: $first, after: $after) {{
     nodes {{
       {node_fields}
     }}
+    pageInfo {{
+      hasNextPage
+      endCursor
+    }}
   }}
 }}"#,
         resource = to_pascal_case(field_name),
@@ -230,7 +308,7 @@ pub fn build_search_query(
     );
     let variables = serde_json::json!({
         "filter": filter,
-        "first": 20,
+        "first": pagination::SEARCH_LIMIT,
     });
 
     (query, variables, SearchStrategy::FilterHeuristic)
@@ -0,0 +1,198 @@
+//! `--where` expressions: a terse alternative to JSON filters
+//!
+//! An expression is one or more `path op value` clauses joined by `and`, e.g.
+//! `team.key = ENG and priority <= 2 and title ~ "login"`. Dotted paths nest
+//! into relation filters. Operators: `=`, `!=`, `<`, `<=`, `>`, `>=`, `~`
+//! (contains, ignoring case), `!~` and `in (a, b)`. Unquoted numbers and
+//! `true`/`false` are typed; `= null` and `!= null` test for a missing value.
+
+use anyhow::Result;
+use serde_json::{Map, Value};
+
+/// Operators, longest first so `<=` wins over `<`
+const OPERATORS: &[(&str, &str)] = &[
+    ("!=", "neq"),
+    ("!~", "notContainsIgnoreCase"),
+    ("<=", "lte"),
+    (">=", "gte"),
+    ("=", "eq"),
+    ("<", "lt"),
+    (">", "gt"),
+    ("~", "containsIgnoreCase"),
+];
+
+/// Parse a `--where` expression into a filter value
+pub fn parse_where(expr: &str) -> Result<Value> {
+    let mut filter = Map::new();
+    for clause in split_and(expr) {
+        let (path, comparator, value) = parse_clause(clause)?;
+        insert(&mut filter, &path, comparator, value)
+            .map_err(|e| anyhow::anyhow!("Invalid --where clause '{}': {}", clause.trim(), e))?;
+    }
+    if filter.is_empty() {
+        anyhow::bail!("Empty --where expression");
+    }
+    Ok(Value::Object(filter))
+}
+
+/// Split on ` and ` outside quotes
+fn split_and(expr: &str) -> Vec<&str> {
+    let mut clauses = Vec::new();
+    let mut quote: Option<char> = None;
+    let mut start = 0;
+    let lower = expr.to_ascii_lowercase();
+    for (i, c) in expr.char_indices() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (None, '"' | '\'') => quote = Some(c),
+            (None, c) if c.is_whitespace() && i >= start => {
+                let next = i + c.len_utf8();
+                let rest = &lower[next..];
+                if rest.starts_with("and") && rest[3..].starts_with(char::is_whitespace) {
+                    clauses.push(&expr[start..i]);
+                    start = next + 3;
+                }
+            }
+            _ => {}
+        }
+    }
+    clauses.push(&expr[start..]);
+    clauses.into_iter().filter(|c| !c.trim().is_empty()).collect()
+}
+
+/// `path op value` → (path segments, comparator, value)
+fn parse_clause(clause: &str) -> Result<(Vec<String>, &'static str, Value)> {
+    let clause = clause.trim();
+    let path_end = clause
+        .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_' || c == '.'))
+        .unwrap_or(clause.len());
+    let path = &clause[..path_end];
+    let rest = clause[path_end..].trim_start();
+    if path.is_empty() || path.split('.').any(str::is_empty) {
+        anyhow::bail!("Invalid --where clause '{}': expected a field path first", clause);
+    }
+    let segments = path.split('.').map(String::from).collect();
+
+    if let Some(list) = strip_keyword(rest, "in") {
+        let list = list
+            .trim()
+            .strip_prefix('(')
+            .and_then(|l| l.strip_suffix(')'))
+            .ok_or_else(|| anyhow::anyhow!("Invalid --where clause '{}': expected 'in (a, b, ...)'", clause))?;
+        let items = split_list(list).into_iter().map(scalar).collect();
+        return Ok((segments, "in", Value::Array(items)));
+    }
+
+    let (op, comparator) = OPERATORS
+        .iter()
+        .find(|(op, _)| rest.starts_with(op))
+        .ok_or_else(|| {
+            anyhow::anyhow!(
+                "Invalid --where clause '{}': expected one of =, !=, <, <=, >, >=, ~, !~ or in",
+                clause
+            )
+        })?;
+    let raw = rest[op.len()..].trim();
+    if raw.is_empty() {
+        anyhow::bail!("Invalid --where clause '{}': missing value", clause);
+    }
+    match (raw, *comparator) {
+        ("null", "eq") => Ok((segments, "null", Value::Bool(true))),
+        ("null", "neq") => Ok((segments, "null", Value::Bool(false))),
+        _ => Ok((segments, comparator, scalar(raw))),
+    }
+}
+
+/// The rest of a clause after a whole-word keyword
+fn strip_keyword<'a>(rest: &'a str, keyword: &str) -> Option<&'a str> {
+    let after = rest.get(keyword.len()..)?;
+    (rest[..keyword.len()].eq_ignore_ascii_case(keyword) && after.starts_with(|c: char| c.is_whitespace() || c == '('))
+        .then_some(after)
+}
+
+/// Comma-separated items, keeping commas inside quotes
+fn split_list(list: &str) -> Vec<&str> {
+    let mut items = Vec::new();
+    let mut quote: Option<char> = None;
+    let mut start = 0;
+    for (i, c) in list.char_indices() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (None, '"' | '\'') => quote = Some(c),
+            (None, ',') => {
+                items.push(&list[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    items.push(&list[start..]);
+    items.into_iter().map(str::trim).filter(|i| !i.is_empty()).collect()
+}
+
+/// A quoted string, or an unquoted number, boolean or bare word
+fn scalar(raw: &str) -> Value {
+    let raw = raw.trim();
+    for q in ['"', '\''] {
+        if let Some(s) = raw.strip_prefix(q).and_then(|s| s.strip_suffix(q)) {
+            return Value::from(s);
+        }
+    }
+    match raw {
+        "true" => Value::Bool(true),
+        "false" => Value::Bool(false),
+        _ => raw
+            .parse::<i64>()
+            .map(Value::from)
+            .or_else(|_| raw.parse::<f64>().map(Value::from))
+            .unwrap_or_else(|_| Value::from(raw)),
+    }
+}
+
+/// Nest `comparator: value` under the path, merging with earlier clauses
+fn insert(filter: &mut Map<String, Value>, path: &[String], comparator: &str, value: Value) -> Result<(), String> {
+    let mut node = filter;
+    for segment in path {
+        let child = node
+            .entry(segment.clone())
+            .or_insert_with(|| Value::Object(Map::new()));
+        node = child
+            .as_object_mut()
+            .ok_or_else(|| format!("'{}' is already compared directly", segment))?;
+    }
+    if node.contains_key(comparator) {
+        return Err(format!("'{}' is repeated for {}", comparator, path.join(".")));
+    }
+    node.insert(comparator.to_string(), value);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_parse_where() {
+        assert_eq!(
+            parse_where("team.key = ENG").unwrap(),
+            json!({ "team": { "key": { "eq": "ENG" } } })
+        );
+        assert_eq!(
+            parse_where("priority>=1 AND priority <= 2 and title ~ 'a and b' and assignee = null").unwrap(),
+            json!({
+                "priority": { "gte": 1, "lte": 2 },
+                "title": { "containsIgnoreCase": "a and b" },
+                "assignee": { "null": true }
+            })
+        );
+        assert_eq!(
+            parse_where("state.name in (Todo, \"In Progress\") and updatedAt > -P1D").unwrap(),
+            json!({ "state": { "name": { "in": ["Todo", "In Progress"] } }, "updatedAt": { "gt": "-P1D" } })
+        );
+        assert!(parse_where("").is_err());
+        assert!(parse_where("team.key ENG").is_err());
+        assert!(parse_where("priority = 1 and priority = 2").is_err());
+        assert!(parse_where("= 1").is_err());
+    }
+}
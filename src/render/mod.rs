@@ -3,10 +3,11 @@
 //! This module provides centralized output formatting for all CLI commands.
 //! All command handlers should use these functions to ensure consistent output.
 
-use crate::cli::OutputFormat;
+use crate::client::GraphQLError;
+use crate::common::OutputFormat;
 use chrono::{DateTime, Utc};
 use chrono_humanize::HumanTime;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::common::constants::display;
 
@@ -20,16 +21,20 @@ pub fn render_list_json(
     resource: &str,
     nodes: &serde_json::Value,
     page_info: Option<&serde_json::Value>,
+    errors: Option<&[GraphQLError]>,
+    truncation: Option<&Truncation>,
     pretty: bool,
 ) -> String {
     match format {
         OutputFormat::Json => {
-            let output = serde_json::json!({
+            let mut output = serde_json::json!({
                 "resource": resource,
                 "operation": "list",
                 "pageInfo": page_info,
                 "nodes": nodes,
             });
+            attach_errors(&mut output, errors);
+            attach_truncation(&mut output, truncation);
             if pretty {
                 serde_json::to_string_pretty(&output).unwrap_or_default()
             } else {
@@ -37,12 +42,14 @@ pub fn render_list_json(
             }
         }
         OutputFormat::Yaml => {
-            let output = serde_json::json!({
+            let mut output = serde_json::json!({
                 "resource": resource,
                 "operation": "list",
                 "pageInfo": page_info,
                 "nodes": nodes,
             });
+            attach_errors(&mut output, errors);
+            attach_truncation(&mut output, truncation);
             serde_yaml::to_string(&output).unwrap_or_default()
         }
         OutputFormat::Ndjson => render_ndjson(nodes),
@@ -55,15 +62,17 @@ pub fn render_entity_json(
     format: OutputFormat,
     resource: &str,
     entity: &serde_json::Value,
+    errors: Option<&[GraphQLError]>,
     pretty: bool,
 ) -> String {
     match format {
         OutputFormat::Json => {
-            let output = serde_json::json!({
+            let mut output = serde_json::json!({
                 "resource": resource,
                 "operation": "get",
                 "entity": entity,
             });
+            attach_errors(&mut output, errors);
             if pretty {
                 serde_json::to_string_pretty(&output).unwrap_or_default()
             } else {
@@ -71,11 +80,12 @@ pub fn render_entity_json(
             }
         }
         OutputFormat::Yaml => {
-            let output = serde_json::json!({
+            let mut output = serde_json::json!({
                 "resource": resource,
                 "operation": "get",
                 "entity": entity,
             });
+            attach_errors(&mut output, errors);
             serde_yaml::to_string(&output).unwrap_or_default()
         }
         OutputFormat::Ndjson => serde_json::to_string(entity).unwrap_or_default(),
@@ -86,22 +96,64 @@ pub fn render_entity_json(
     }
 }
 
+/// Render a batched get: one `{id, entity}` or `{id, error}` entry per requested ID
+pub fn render_batch_get(
+    format: OutputFormat,
+    resource: &str,
+    entries: &[serde_json::Value],
+    truncation: Option<&Truncation>,
+    pretty: bool,
+) -> String {
+    let found: Vec<serde_json::Value> = entries
+        .iter()
+        .filter_map(|e| e.get("entity").cloned())
+        .collect();
+    match format {
+        OutputFormat::Json => {
+            let mut output = serde_json::json!({
+                "resource": resource,
+                "operation": "get",
+                "results": entries,
+            });
+            attach_truncation(&mut output, truncation);
+            if pretty {
+                serde_json::to_string_pretty(&output).unwrap_or_default()
+            } else {
+                serde_json::to_string(&output).unwrap_or_default()
+            }
+        }
+        OutputFormat::Yaml => {
+            let mut output = serde_json::json!({
+                "resource": resource,
+                "operation": "get",
+                "results": entries,
+            });
+            attach_truncation(&mut output, truncation);
+            serde_yaml::to_string(&output).unwrap_or_default()
+        }
+        OutputFormat::Ndjson => render_ndjson(&serde_json::json!(found)),
+        OutputFormat::Table | OutputFormat::Text => render_table(&serde_json::json!(found)),
+    }
+}
+
 /// Render search results with proper envelope
 pub fn render_search_json(
     format: OutputFormat,
     resource: &str,
     strategy: &str,
     nodes: &serde_json::Value,
+    errors: Option<&[GraphQLError]>,
     pretty: bool,
 ) -> String {
     match format {
         OutputFormat::Json => {
-            let output = serde_json::json!({
+            let mut output = serde_json::json!({
                 "resource": resource,
                 "operation": "search",
                 "strategy": strategy,
                 "nodes": nodes,
             });
+            attach_errors(&mut output, errors);
             if pretty {
                 serde_json::to_string_pretty(&output).unwrap_or_default()
             } else {
@@ -109,12 +161,13 @@ pub fn render_search_json(
             }
         }
         OutputFormat::Yaml => {
-            let output = serde_json::json!({
+            let mut output = serde_json::json!({
                 "resource": resource,
                 "operation": "search",
                 "strategy": strategy,
                 "nodes": nodes,
             });
+            attach_errors(&mut output, errors);
             serde_yaml::to_string(&output).unwrap_or_default()
         }
         OutputFormat::Ndjson => render_ndjson(nodes),
@@ -135,18 +188,21 @@ pub fn render_search_json(
 pub fn render_raw(
     format: OutputFormat,
     data: &Option<serde_json::Value>,
+    errors: Option<&[GraphQLError]>,
     pretty: bool,
 ) -> String {
+    // Partial results keep GraphQL's own {data, errors} shape
+    let output = data_with_errors(data, errors);
     match format {
         OutputFormat::Json => {
             if pretty {
-                serde_json::to_string_pretty(data).unwrap_or_default()
+                serde_json::to_string_pretty(&output).unwrap_or_default()
             } else {
-                serde_json::to_string(data).unwrap_or_default()
+                serde_json::to_string(&output).unwrap_or_default()
             }
         }
-        OutputFormat::Yaml => serde_yaml::to_string(data).unwrap_or_default(),
-        _ => serde_json::to_string_pretty(data).unwrap_or_default(),
+        OutputFormat::Yaml => serde_yaml::to_string(&output).unwrap_or_default(),
+        _ => serde_json::to_string_pretty(&output).unwrap_or_default(),
     }
 }
 
@@ -159,15 +215,17 @@ pub fn render_mutation_json(
     format: OutputFormat,
     op: &str,
     result: &serde_json::Value,
+    errors: Option<&[GraphQLError]>,
     pretty: bool,
 ) -> String {
     match format {
         OutputFormat::Json => {
-            let output = serde_json::json!({
+            let mut output = serde_json::json!({
                 "op": op,
                 "operation": "mutate",
                 "result": result,
             });
+            attach_errors(&mut output, errors);
             if pretty {
                 serde_json::to_string_pretty(&output).unwrap_or_default()
             } else {
@@ -175,11 +233,12 @@ pub fn render_mutation_json(
             }
         }
         OutputFormat::Yaml => {
-            let output = serde_json::json!({
+            let mut output = serde_json::json!({
                 "op": op,
                 "operation": "mutate",
                 "result": result,
             });
+            attach_errors(&mut output, errors);
             serde_yaml::to_string(&output).unwrap_or_default()
         }
         _ => {
@@ -198,18 +257,410 @@ pub fn render_mutation_json(
 pub fn render_helper_mutation(
     format: OutputFormat,
     data: &Option<serde_json::Value>,
+    errors: Option<&[GraphQLError]>,
     pretty: bool,
 ) -> String {
+    // Partial results keep GraphQL's own {data, errors} shape
+    let output = data_with_errors(data, errors);
+    match format {
+        OutputFormat::Json => {
+            if pretty {
+                serde_json::to_string_pretty(&output).unwrap_or_default()
+            } else {
+                serde_json::to_string(&output).unwrap_or_default()
+            }
+        }
+        OutputFormat::Yaml => serde_yaml::to_string(&output).unwrap_or_default(),
+        _ => serde_json::to_string_pretty(&output).unwrap_or_default(),
+    }
+}
+
+/// Render upload result; table/text output is just the asset URL
+pub fn render_upload(format: OutputFormat, result: &serde_json::Value, pretty: bool) -> String {
     match format {
         OutputFormat::Json => {
+            let output = serde_json::json!({
+                "operation": "upload",
+                "result": result,
+            });
             if pretty {
-                serde_json::to_string_pretty(data).unwrap_or_default()
+                serde_json::to_string_pretty(&output).unwrap_or_default()
             } else {
-                serde_json::to_string(data).unwrap_or_default()
+                serde_json::to_string(&output).unwrap_or_default()
             }
         }
-        OutputFormat::Yaml => serde_yaml::to_string(data).unwrap_or_default(),
-        _ => serde_json::to_string_pretty(data).unwrap_or_default(),
+        OutputFormat::Yaml => {
+            let output = serde_json::json!({
+                "operation": "upload",
+                "result": result,
+            });
+            serde_yaml::to_string(&output).unwrap_or_default()
+        }
+        OutputFormat::Ndjson => serde_json::to_string(result).unwrap_or_default(),
+        OutputFormat::Table | OutputFormat::Text => {
+            result["assetUrl"].as_str().unwrap_or_default().to_string()
+        }
+    }
+}
+
+/// Render rate-limit status (table shows one row per budget)
+pub fn render_rate_limit(format: OutputFormat, result: &serde_json::Value, pretty: bool) -> String {
+    match format {
+        OutputFormat::Json => {
+            let output = serde_json::json!({
+                "operation": "rateLimit",
+                "result": result,
+            });
+            if pretty {
+                serde_json::to_string_pretty(&output).unwrap_or_default()
+            } else {
+                serde_json::to_string(&output).unwrap_or_default()
+            }
+        }
+        OutputFormat::Yaml => {
+            let output = serde_json::json!({
+                "operation": "rateLimit",
+                "result": result,
+            });
+            serde_yaml::to_string(&output).unwrap_or_default()
+        }
+        OutputFormat::Ndjson => render_ndjson(&result["budgets"]),
+        OutputFormat::Table | OutputFormat::Text => render_table(&result["budgets"]),
+    }
+}
+
+/// Render response cache stats or a clear result
+pub fn render_cache(format: OutputFormat, operation: &str, result: &serde_json::Value, pretty: bool) -> String {
+    match format {
+        OutputFormat::Json => {
+            let output = serde_json::json!({
+                "operation": operation,
+                "result": result,
+            });
+            if pretty {
+                serde_json::to_string_pretty(&output).unwrap_or_default()
+            } else {
+                serde_json::to_string(&output).unwrap_or_default()
+            }
+        }
+        OutputFormat::Yaml => {
+            let output = serde_json::json!({
+                "operation": operation,
+                "result": result,
+            });
+            serde_yaml::to_string(&output).unwrap_or_default()
+        }
+        OutputFormat::Ndjson => render_ndjson(result),
+        OutputFormat::Table | OutputFormat::Text => {
+            if let Some(removed) = result["removed"].as_u64() {
+                return format!("Removed {} cached responses from {}", removed, result["dir"].as_str().unwrap_or_default());
+            }
+            let mut output = format!(
+                "Cache: {}\nEntries: {} ({} fresh, {} expired), {} bytes",
+                result["dir"].as_str().unwrap_or_default(),
+                result["entries"],
+                result["fresh"],
+                result["expired"],
+                result["bytes"],
+            );
+            if result["resources"].as_array().is_some_and(|r| !r.is_empty()) {
+                output.push_str("\n\n");
+                output.push_str(&render_table(&result["resources"]));
+            }
+            output
+        }
+    }
+}
+
+/// Render a mirror pull or status (table shows one row per resource)
+pub fn render_sync(format: OutputFormat, operation: &str, result: &serde_json::Value, pretty: bool) -> String {
+    match format {
+        OutputFormat::Json => {
+            let output = serde_json::json!({
+                "operation": operation,
+                "result": result,
+            });
+            if pretty {
+                serde_json::to_string_pretty(&output).unwrap_or_default()
+            } else {
+                serde_json::to_string(&output).unwrap_or_default()
+            }
+        }
+        OutputFormat::Yaml => {
+            let output = serde_json::json!({
+                "operation": operation,
+                "result": result,
+            });
+            serde_yaml::to_string(&output).unwrap_or_default()
+        }
+        OutputFormat::Ndjson => render_ndjson(&result["resources"]),
+        OutputFormat::Table | OutputFormat::Text => format!(
+            "Mirror: {}\n\n{}",
+            result["path"].as_str().unwrap_or_default(),
+            render_table(&result["resources"])
+        ),
+    }
+}
+
+/// Render an import report (table shows one row per source row)
+pub fn render_import(format: OutputFormat, result: &serde_json::Value, pretty: bool) -> String {
+    match format {
+        OutputFormat::Json => {
+            let output = serde_json::json!({
+                "operation": "import",
+                "result": result,
+            });
+            if pretty {
+                serde_json::to_string_pretty(&output).unwrap_or_default()
+            } else {
+                serde_json::to_string(&output).unwrap_or_default()
+            }
+        }
+        OutputFormat::Yaml => {
+            let output = serde_json::json!({
+                "operation": "import",
+                "result": result,
+            });
+            serde_yaml::to_string(&output).unwrap_or_default()
+        }
+        OutputFormat::Ndjson => render_ndjson(&result["rows"]),
+        OutputFormat::Table | OutputFormat::Text => {
+            let summary = if result["dryRun"].as_bool() == Some(true) {
+                format!(
+                    "Dry run: {} to create, {} already imported, {} invalid",
+                    result["ready"], result["skipped"], result["invalid"]
+                )
+            } else {
+                format!(
+                    "Imported {} issues ({} already imported, {} invalid, {} failed)",
+                    result["imported"], result["skipped"], result["invalid"], result["failed"]
+                )
+            };
+            let rows: Vec<serde_json::Value> = result["rows"]
+                .as_array()
+                .map(Vec::as_slice)
+                .unwrap_or_default()
+                .iter()
+                .map(|row| {
+                    serde_json::json!({
+                        "row": row["row"],
+                        "sourceId": row["sourceId"],
+                        "title": row["title"],
+                        "status": row["status"],
+                        "result": row["issue"]["identifier"].as_str().or(row["error"].as_str()),
+                    })
+                })
+                .collect();
+            format!("{}\n\n{}", summary, render_table(&serde_json::json!(rows)))
+        }
+    }
+}
+
+/// Render an export manifest (table shows one row per resource)
+pub fn render_export(format: OutputFormat, result: &serde_json::Value, pretty: bool) -> String {
+    match format {
+        OutputFormat::Json => {
+            let output = serde_json::json!({
+                "operation": "export",
+                "result": result,
+            });
+            if pretty {
+                serde_json::to_string_pretty(&output).unwrap_or_default()
+            } else {
+                serde_json::to_string(&output).unwrap_or_default()
+            }
+        }
+        OutputFormat::Yaml => {
+            let output = serde_json::json!({
+                "operation": "export",
+                "result": result,
+            });
+            serde_yaml::to_string(&output).unwrap_or_default()
+        }
+        OutputFormat::Ndjson => render_ndjson(&result["resources"]),
+        OutputFormat::Table | OutputFormat::Text => format!(
+            "Exported to {}\n\n{}",
+            result["dir"].as_str().unwrap_or_default(),
+            render_table(&result["resources"])
+        ),
+    }
+}
+
+/// Render one watch event: a JSON line, a YAML document, or a human line
+pub fn render_watch_event(format: OutputFormat, event: &serde_json::Value) -> String {
+    match format {
+        OutputFormat::Json | OutputFormat::Ndjson => serde_json::to_string(event).unwrap_or_default(),
+        OutputFormat::Yaml => format!("---\n{}", serde_yaml::to_string(event).unwrap_or_default().trim_end()),
+        OutputFormat::Table | OutputFormat::Text => {
+            let entity = &event["entity"];
+            let label = ["identifier", "name", "key"]
+                .iter()
+                .find_map(|field| entity[*field].as_str())
+                .or_else(|| event["id"].as_str())
+                .unwrap_or_default();
+            let mut line = format!(
+                "{}  {:<8} {} {}",
+                event["at"].as_str().unwrap_or_default(),
+                event["event"].as_str().unwrap_or_default(),
+                event["resource"].as_str().unwrap_or_default(),
+                label
+            );
+            if let Some(title) = entity["title"].as_str().filter(|t| *t != label) {
+                line.push_str(&format!(" \"{}\"", title));
+            }
+            if let Some(changes) = event["changes"].as_array() {
+                let fields: Vec<&str> = changes.iter().filter_map(|c| c.as_str()).collect();
+                line.push_str(&format!(" ({})", fields.join(", ")));
+            }
+            line
+        }
+    }
+}
+
+/// Render one received webhook: a JSON line, a YAML document, or a human line
+pub fn render_webhook_delivery(format: OutputFormat, delivery: &serde_json::Value, summary: &str) -> String {
+    match format {
+        OutputFormat::Json | OutputFormat::Ndjson => serde_json::to_string(delivery).unwrap_or_default(),
+        OutputFormat::Yaml => format!("---\n{}", serde_yaml::to_string(delivery).unwrap_or_default().trim_end()),
+        OutputFormat::Table | OutputFormat::Text => format!(
+            "{}  {:<24} {}",
+            delivery["receivedAt"].as_str().unwrap_or_default(),
+            delivery["event"].as_str().unwrap_or_default(),
+            summary
+        )
+        .trim_end()
+        .to_string(),
+    }
+}
+
+/// Render a test delivery: the receiver's answer, or the signed request itself
+pub fn render_webhook(format: OutputFormat, operation: &str, result: &serde_json::Value, pretty: bool) -> String {
+    match format {
+        OutputFormat::Json => {
+            let output = serde_json::json!({
+                "operation": operation,
+                "result": result,
+            });
+            if pretty {
+                serde_json::to_string_pretty(&output).unwrap_or_default()
+            } else {
+                serde_json::to_string(&output).unwrap_or_default()
+            }
+        }
+        OutputFormat::Yaml => {
+            let output = serde_json::json!({
+                "operation": operation,
+                "result": result,
+            });
+            serde_yaml::to_string(&output).unwrap_or_default()
+        }
+        OutputFormat::Ndjson => match result.get("webhooks") {
+            Some(webhooks) => render_ndjson(webhooks),
+            None => serde_json::to_string(result).unwrap_or_default(),
+        },
+        OutputFormat::Table | OutputFormat::Text => match result["status"].as_u64() {
+            _ if result["webhooks"].is_array() => render_table(&webhook_rows(&result["webhooks"])),
+            // A single webhook, one field per line
+            None if result.get("headers").is_none() => result
+                .as_object()
+                .map(|fields| {
+                    fields
+                        .iter()
+                        .map(|(name, value)| match value.as_array() {
+                            Some(items) => {
+                                let items: Vec<&str> = items.iter().filter_map(|i| i.as_str()).collect();
+                                format!("{}: {}", name, items.join(", "))
+                            }
+                            None => format!("{}: {}", name, format_value_for_table(Some(value), name)),
+                        })
+                        .collect::<Vec<_>>()
+                        .join("\n")
+                })
+                .unwrap_or_default(),
+            Some(status) => format!(
+                "Sent {} to {}: {}",
+                result["event"].as_str().unwrap_or_default(),
+                result["url"].as_str().unwrap_or_default(),
+                status
+            ),
+            // An HTTP-style dump of the signed request
+            None => {
+                let headers = result["headers"]
+                    .as_object()
+                    .map(|headers| {
+                        headers
+                            .iter()
+                            .map(|(name, value)| format!("{}: {}", name, value.as_str().unwrap_or_default()))
+                            .collect::<Vec<_>>()
+                            .join("\n")
+                    })
+                    .unwrap_or_default();
+                format!("{}\n\n{}", headers, result["body"].as_str().unwrap_or_default())
+            }
+        },
+    }
+}
+
+/// Webhook list rows: resource types joined, team key or "all public"
+fn webhook_rows(webhooks: &serde_json::Value) -> serde_json::Value {
+    let rows = webhooks
+        .as_array()
+        .map(|webhooks| {
+            webhooks
+                .iter()
+                .map(|webhook| {
+                    let types: Vec<&str> = webhook["resourceTypes"]
+                        .as_array()
+                        .map(|types| types.iter().filter_map(|t| t.as_str()).collect())
+                        .unwrap_or_default();
+                    let team = match webhook["team"]["key"].as_str() {
+                        Some(key) => key.to_string(),
+                        None if webhook["allPublicTeams"].as_bool() == Some(true) => "all public".to_string(),
+                        None => String::new(),
+                    };
+                    serde_json::json!({
+                        "id": webhook["id"],
+                        "label": webhook["label"],
+                        "url": webhook["url"],
+                        "enabled": webhook["enabled"],
+                        "resourceTypes": types.join(","),
+                        "team": team,
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    serde_json::Value::Array(rows)
+}
+
+/// Render journal entries or undo results
+pub fn render_journal(
+    format: OutputFormat,
+    operation: &str,
+    entries: &[serde_json::Value],
+    pretty: bool,
+) -> String {
+    match format {
+        OutputFormat::Json => {
+            let output = serde_json::json!({
+                "operation": operation,
+                "entries": entries,
+            });
+            if pretty {
+                serde_json::to_string_pretty(&output).unwrap_or_default()
+            } else {
+                serde_json::to_string(&output).unwrap_or_default()
+            }
+        }
+        OutputFormat::Yaml => {
+            let output = serde_json::json!({
+                "operation": operation,
+                "entries": entries,
+            });
+            serde_yaml::to_string(&output).unwrap_or_default()
+        }
+        OutputFormat::Ndjson => render_ndjson(&serde_json::json!(entries)),
+        OutputFormat::Table | OutputFormat::Text => render_table(&serde_json::json!(entries)),
     }
 }
 
@@ -297,7 +748,7 @@ pub struct ErrorInfo<'a> {
     pub message: &'a str,
     pub hint: Option<&'a str>,
     pub details: Option<&'a serde_json::Value>,
-    pub graphql_errors: Option<&'a Vec<crate::client::GraphQLError>>,
+    pub graphql_errors: Option<&'a Vec<GraphQLError>>,
 }
 
 /// Render error with proper envelope (per PRD spec)
@@ -359,6 +810,43 @@ pub fn render_error(
 // Helper Functions
 // ============================================================================
 
+/// Attach errors kept in partial-result mode to an output envelope
+fn attach_errors(output: &mut serde_json::Value, errors: Option<&[GraphQLError]>) {
+    if let Some(errors) = errors.filter(|e| !e.is_empty()) {
+        output["errors"] = serde_json::to_value(errors).unwrap_or_default();
+    }
+}
+
+/// Marker for output cut short by Ctrl-C
+#[derive(Debug, Default)]
+pub struct Truncation {
+    /// Cursor to pass as `--after` to continue where the output stopped
+    pub resume_cursor: Option<String>,
+}
+
+fn attach_truncation(output: &mut serde_json::Value, truncation: Option<&Truncation>) {
+    if let Some(truncation) = truncation {
+        output["truncated"] = serde_json::Value::Bool(true);
+        if let Some(ref cursor) = truncation.resume_cursor {
+            output["resumeCursor"] = serde_json::Value::String(cursor.clone());
+        }
+    }
+}
+
+/// Wrap bare response data as `{data, errors}` when partial-result errors exist
+fn data_with_errors(
+    data: &Option<serde_json::Value>,
+    errors: Option<&[GraphQLError]>,
+) -> serde_json::Value {
+    match errors.filter(|e| !e.is_empty()) {
+        Some(errors) => serde_json::json!({
+            "data": data,
+            "errors": errors,
+        }),
+        None => serde_json::to_value(data).unwrap_or_default(),
+    }
+}
+
 /// Render array as NDJSON (one JSON object per line)
 fn render_ndjson(nodes: &serde_json::Value) -> String {
     if let Some(arr) = nodes.as_array() {
@@ -476,9 +964,9 @@ pub fn format_datetime_smart(dt: &DateTime<Utc>) -> String {
 // Pagination Info
 // ============================================================================
 
-/// Pagination info (for typed usage if needed)
-#[derive(Debug, Clone, Serialize)]
-#[serde(rename_all = "camelCase")]
+/// Pagination info of a connection
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
 pub struct PageInfo {
     pub has_next_page: bool,
     pub has_previous_page: bool,
@@ -0,0 +1,339 @@
+//! Name resolution for human-friendly input fields
+//!
+//! Front matter and other hand-written inputs refer to teams, labels and users
+//! by name. This module translates them into the IDs Linear's input types expect:
+//! - `team: ENG` → `teamId`
+//! - `labels: [bug, ui]` → `labelIds`
+//! - `assignee: me | alice@example.com | Alice` → `assigneeId`
+//! - `priority: high` → `priority: 2`
+//!
+//! Imports resolve many names at once, so labels and workflow states can also
+//! be looked up in bulk.
+
+use anyhow::{Context, Result};
+use serde_json::Value;
+use std::collections::HashMap;
+
+use crate::client::{Client, GraphQLRequest};
+use crate::validate;
+
+const TEAM_QUERY: &str = r#"query ResolveTeam($filter: TeamFilter) {
+  teams(filter: $filter, first: 2) {
+    nodes {
+      id
+      key
+      name
+    }
+  }
+}"#;
+
+const USER_QUERY: &str = r#"query ResolveUser($filter: UserFilter) {
+  users(filter: $filter, first: 2) {
+    nodes {
+      id
+      name
+      email
+    }
+  }
+}"#;
+
+const VIEWER_QUERY: &str = r#"query ResolveViewer {
+  viewer {
+    id
+  }
+}"#;
+
+const LABEL_QUERY: &str = r#"query ResolveLabels($filter: IssueLabelFilter) {
+  issueLabels(filter: $filter, first: 250) {
+    nodes {
+      id
+      name
+      team {
+        id
+      }
+    }
+  }
+}"#;
+
+const STATE_QUERY: &str = r#"query ResolveStates($filter: WorkflowStateFilter) {
+  workflowStates(filter: $filter, first: 250) {
+    nodes {
+      id
+      name
+    }
+  }
+}"#;
+
+/// Replace friendly fields in an input object with their resolved IDs
+pub async fn resolve_friendly_fields(client: &Client, input: &mut Value) -> Result<()> {
+    let Some(obj) = input.as_object_mut() else {
+        return Ok(());
+    };
+
+    if let Some(team) = obj.remove("team") {
+        let team = value_as_name(&team, "team")?;
+        let team_id = resolve_team(client, &team).await?;
+        obj.insert("teamId".to_string(), Value::String(team_id));
+    }
+
+    if let Some(assignee) = obj.remove("assignee") {
+        let assignee = value_as_name(&assignee, "assignee")?;
+        let user_id = resolve_user(client, &assignee).await?;
+        obj.insert("assigneeId".to_string(), Value::String(user_id));
+    }
+
+    if let Some(labels) = obj.remove("labels") {
+        let names = value_as_name_list(&labels, "labels")?;
+        let team_id = obj.get("teamId").and_then(|t| t.as_str()).map(String::from);
+        let label_ids = resolve_labels(client, &names, team_id.as_deref()).await?;
+        obj.insert("labelIds".to_string(), serde_json::json!(label_ids));
+    }
+
+    if let Some(name) = obj.get("priority").and_then(|p| p.as_str()).map(String::from) {
+        let value = parse_priority(&name)?;
+        obj.insert("priority".to_string(), serde_json::json!(value));
+    }
+
+    Ok(())
+}
+
+/// Resolve a team key, name or ID to a team ID
+pub async fn resolve_team(client: &Client, team: &str) -> Result<String> {
+    if validate::is_uuid(team) {
+        return Ok(team.to_string());
+    }
+
+    let filter = serde_json::json!({
+        "or": [
+            { "key": { "eqIgnoreCase": team } },
+            { "name": { "eqIgnoreCase": team } },
+        ]
+    });
+    let nodes = fetch_nodes(client, TEAM_QUERY, filter, "teams").await?;
+
+    single_id(&nodes, "team", team)
+}
+
+/// Resolve a user email, name, display name, ID or `me` to a user ID
+pub async fn resolve_user(client: &Client, user: &str) -> Result<String> {
+    if validate::is_uuid(user) {
+        return Ok(user.to_string());
+    }
+
+    if user.eq_ignore_ascii_case("me") {
+        let data = execute(client, VIEWER_QUERY, None).await?;
+        return data["viewer"]["id"]
+            .as_str()
+            .map(String::from)
+            .context("Failed to resolve the current user");
+    }
+
+    let filter = if user.contains('@') {
+        serde_json::json!({ "email": { "eqIgnoreCase": user } })
+    } else {
+        serde_json::json!({
+            "or": [
+                { "name": { "eqIgnoreCase": user } },
+                { "displayName": { "eqIgnoreCase": user } },
+            ]
+        })
+    };
+    let nodes = fetch_nodes(client, USER_QUERY, filter, "users").await?;
+
+    single_id(&nodes, "user", user)
+}
+
+/// Resolve label names or IDs to label IDs, preferring labels of the given team
+pub async fn resolve_labels(
+    client: &Client,
+    names: &[String],
+    team_id: Option<&str>,
+) -> Result<Vec<String>> {
+    let found = label_ids(client, names, team_id).await?;
+    names
+        .iter()
+        .map(|name| {
+            found
+                .get(&name.to_lowercase())
+                .cloned()
+                .ok_or_else(|| anyhow::anyhow!("Unknown label '{}'", name))
+        })
+        .collect()
+}
+
+/// Look up label IDs in one query, keyed by lowercased name (unknown names are left out)
+pub async fn label_ids(
+    client: &Client,
+    names: &[String],
+    team_id: Option<&str>,
+) -> Result<HashMap<String, String>> {
+    let lookups: Vec<&String> = names.iter().filter(|n| !validate::is_uuid(n)).collect();
+
+    let nodes = if lookups.is_empty() {
+        Vec::new()
+    } else {
+        let filter = serde_json::json!({
+            "or": lookups
+                .iter()
+                .map(|name| serde_json::json!({ "name": { "eqIgnoreCase": name } }))
+                .collect::<Vec<_>>()
+        });
+        fetch_nodes(client, LABEL_QUERY, filter, "issueLabels").await?
+    };
+
+    Ok(names
+        .iter()
+        .filter_map(|name| {
+            let id = if validate::is_uuid(name) {
+                Some(name.clone())
+            } else {
+                pick_label(&nodes, name, team_id)
+            };
+            id.map(|id| (name.to_lowercase(), id))
+        })
+        .collect())
+}
+
+/// A team's workflow states, keyed by lowercased name
+pub async fn team_states(client: &Client, team_id: &str) -> Result<HashMap<String, String>> {
+    let filter = serde_json::json!({ "team": { "id": { "eq": team_id } } });
+    let nodes = fetch_nodes(client, STATE_QUERY, filter, "workflowStates").await?;
+    Ok(nodes
+        .iter()
+        .filter_map(|n| Some((n["name"].as_str()?.to_lowercase(), n["id"].as_str()?.to_string())))
+        .collect())
+}
+
+/// Parse a priority name or number into Linear's numeric priority
+pub fn parse_priority(priority: &str) -> Result<i64> {
+    let normalized = priority.trim().to_lowercase();
+    let value = match normalized.as_str() {
+        "none" | "no priority" | "0" => 0,
+        "urgent" | "1" => 1,
+        "high" | "2" => 2,
+        "medium" | "normal" | "3" => 3,
+        "low" | "4" => 4,
+        _ => anyhow::bail!(
+            "Unknown priority '{}'. Expected one of: none, urgent, high, medium, low",
+            priority
+        ),
+    };
+    Ok(value)
+}
+
+/// Pick the label matching `name`, preferring the team's own label over a workspace label
+fn pick_label(nodes: &[Value], name: &str, team_id: Option<&str>) -> Option<String> {
+    let matches: Vec<&Value> = nodes
+        .iter()
+        .filter(|n| {
+            n["name"]
+                .as_str()
+                .is_some_and(|label| label.eq_ignore_ascii_case(name))
+        })
+        .collect();
+
+    let team_label = team_id.and_then(|team_id| {
+        matches
+            .iter()
+            .find(|n| n["team"]["id"].as_str() == Some(team_id))
+    });
+    let workspace_label = matches.iter().find(|n| n["team"].is_null());
+
+    team_label
+        .or(workspace_label)
+        .or(matches.first())
+        .and_then(|n| n["id"].as_str())
+        .map(String::from)
+}
+
+/// Read a friendly field as a single name
+fn value_as_name(value: &Value, field: &str) -> Result<String> {
+    value
+        .as_str()
+        .map(|s| s.trim().to_string())
+        .ok_or_else(|| anyhow::anyhow!("'{}' must be a string", field))
+}
+
+/// Read a friendly field as a list of names (array or comma-separated string)
+fn value_as_name_list(value: &Value, field: &str) -> Result<Vec<String>> {
+    match value {
+        Value::String(s) => Ok(s
+            .split(',')
+            .map(|n| n.trim().to_string())
+            .filter(|n| !n.is_empty())
+            .collect()),
+        Value::Array(items) => items.iter().map(|item| value_as_name(item, field)).collect(),
+        _ => anyhow::bail!("'{}' must be a string or a list of strings", field),
+    }
+}
+
+/// Expect exactly one matching node and return its ID
+fn single_id(nodes: &[Value], kind: &str, name: &str) -> Result<String> {
+    match nodes {
+        [node] => node["id"]
+            .as_str()
+            .map(String::from)
+            .with_context(|| format!("Resolved {} '{}' has no id", kind, name)),
+        [] => anyhow::bail!("Unknown {} '{}'", kind, name),
+        _ => anyhow::bail!("Ambiguous {} '{}'. Use its ID instead", kind, name),
+    }
+}
+
+/// Execute a lookup query and return the nodes of the given connection
+async fn fetch_nodes(
+    client: &Client,
+    query: &str,
+    filter: Value,
+    connection: &str,
+) -> Result<Vec<Value>> {
+    let data = execute(client, query, Some(serde_json::json!({ "filter": filter }))).await?;
+    Ok(data[connection]["nodes"]
+        .as_array()
+        .cloned()
+        .unwrap_or_default())
+}
+
+/// Execute a lookup query and return its data
+async fn execute(client: &Client, query: &str, variables: Option<Value>) -> Result<Value> {
+    let request = GraphQLRequest {
+        query: query.to_string(),
+        variables,
+        operation_name: None,
+    };
+    let response = client.execute(request).await?;
+    Ok(response.data.unwrap_or_default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_priority() {
+        assert_eq!(parse_priority("High").unwrap(), 2);
+        assert_eq!(parse_priority("urgent").unwrap(), 1);
+        assert_eq!(parse_priority("No priority").unwrap(), 0);
+        assert_eq!(parse_priority("4").unwrap(), 4);
+        assert!(parse_priority("critical").is_err());
+    }
+
+    #[test]
+    fn test_pick_label_prefers_team() {
+        let nodes = vec![
+            serde_json::json!({ "id": "workspace", "name": "Bug", "team": null }),
+            serde_json::json!({ "id": "eng", "name": "bug", "team": { "id": "team-eng" } }),
+        ];
+        assert_eq!(pick_label(&nodes, "bug", Some("team-eng")).as_deref(), Some("eng"));
+        assert_eq!(pick_label(&nodes, "BUG", Some("team-other")).as_deref(), Some("workspace"));
+        assert_eq!(pick_label(&nodes, "feature", None), None);
+    }
+
+    #[test]
+    fn test_value_as_name_list() {
+        let list = value_as_name_list(&serde_json::json!("bug, ui"), "labels").unwrap();
+        assert_eq!(list, vec!["bug", "ui"]);
+        let list = value_as_name_list(&serde_json::json!(["bug"]), "labels").unwrap();
+        assert_eq!(list, vec!["bug"]);
+        assert!(value_as_name_list(&serde_json::json!(1), "labels").is_err());
+    }
+}
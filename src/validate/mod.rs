@@ -1,11 +1,15 @@
 //! Input validation utilities
 
 use anyhow::{Context, Result};
+use chrono::{DateTime, Duration, NaiveDate, SecondsFormat, Utc};
 use std::io::{self, Read};
 
 pub use crate::common::IdType;
 
-/// Parse input from JSON or YAML string
+/// Input field that receives the markdown body when no resource-specific field applies
+pub const MARKDOWN_BODY_FIELD: &str = "description";
+
+/// Parse input from JSON or YAML
 /// Returns an error if the input is not a valid JSON/YAML object
 pub fn parse_input(input: &str) -> Result<serde_json::Value> {
     // Try JSON first
@@ -27,6 +31,67 @@ pub fn parse_input(input: &str) -> Result<serde_json::Value> {
     }
 }
 
+/// Split a markdown document into its front matter and body
+/// Returns None if the document does not open with a closed `---` block
+fn split_front_matter(input: &str) -> Option<(&str, &str)> {
+    let input = input.strip_prefix('\u{feff}').unwrap_or(input);
+    let rest = input
+        .strip_prefix("---\r\n")
+        .or_else(|| input.strip_prefix("---\n"))?;
+
+    // Find the closing delimiter line (`---` or YAML's `...`)
+    let mut offset = 0;
+    for line in rest.split_inclusive('\n') {
+        let trimmed = line.trim_end();
+        if trimmed == "---" || trimmed == "..." {
+            return Some((&rest[..offset], &rest[offset + line.len()..]));
+        }
+        offset += line.len();
+    }
+
+    None
+}
+
+/// Check if a file path names a markdown document (`.md` or `.markdown`)
+pub fn is_markdown_path(path: &str) -> bool {
+    std::path::Path::new(path)
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("md") || ext.eq_ignore_ascii_case("markdown"))
+}
+
+/// Parse a markdown document with optional YAML front matter
+///
+/// Front matter keys become input fields and the markdown body is stored
+/// under `body_field` (e.g. `description` for issues, `content` for documents).
+pub fn parse_markdown(input: &str, body_field: &str) -> Result<serde_json::Value> {
+    let (front_matter, body) = split_front_matter(input).unwrap_or(("", input));
+
+    let mut value = if front_matter.trim().is_empty() {
+        serde_json::json!({})
+    } else {
+        serde_yaml::from_str::<serde_json::Value>(front_matter)
+            .context("Failed to parse markdown front matter as YAML")?
+    };
+
+    let Some(obj) = value.as_object_mut() else {
+        anyhow::bail!("Front matter must be a YAML object, got: {}", value_type_name(&value));
+    };
+
+    let body = body.trim();
+    if !body.is_empty() {
+        if obj.contains_key(body_field) {
+            anyhow::bail!(
+                "Front matter sets '{}' but the markdown body would also be used for it",
+                body_field
+            );
+        }
+        obj.insert(body_field.to_string(), serde_json::Value::String(body.to_string()));
+    }
+
+    Ok(value)
+}
+
 /// Get a human-readable name for a JSON value type
 fn value_type_name(value: &serde_json::Value) -> &'static str {
     match value {
@@ -64,7 +129,11 @@ pub fn resolve_input(inline: Option<&str>, file: Option<&str>) -> Result<serde_j
         }
     } else if let Some(path) = file {
         let content = read_file(path)?;
-        parse_input(&content)
+        if is_markdown_path(path) {
+            parse_markdown(&content, MARKDOWN_BODY_FIELD)
+        } else {
+            parse_input(&content)
+        }
     } else {
         anyhow::bail!("No input provided. Use --input or --input-file")
     }
@@ -113,6 +182,59 @@ pub fn detect_id_type(id: &str) -> IdType {
     }
 }
 
+/// Resolve `-P2W`, `P1D`, `-PT12H` style durations to a timestamp
+pub fn relative_date(duration: &str) -> Option<String> {
+    let (sign, rest) = match duration.strip_prefix('-') {
+        Some(rest) => (-1, rest),
+        None => (1, duration),
+    };
+    let rest = rest.strip_prefix('P')?;
+    let (date_part, time_part) = rest.split_once('T').unwrap_or((rest, ""));
+    let mut total = Duration::zero();
+    for (part, units) in [(date_part, "YMWD"), (time_part, "HMS")] {
+        let mut number = String::new();
+        for c in part.chars() {
+            if c.is_ascii_digit() {
+                number.push(c);
+                continue;
+            }
+            if !units.contains(c) {
+                return None;
+            }
+            let n: i64 = number.parse().ok()?;
+            number.clear();
+            total += match (units, c) {
+                ("YMWD", 'Y') => Duration::days(365 * n),
+                ("YMWD", 'M') => Duration::days(30 * n),
+                ("YMWD", 'W') => Duration::weeks(n),
+                ("YMWD", 'D') => Duration::days(n),
+                (_, 'H') => Duration::hours(n),
+                (_, 'M') => Duration::minutes(n),
+                _ => Duration::seconds(n),
+            };
+        }
+        if !number.is_empty() {
+            return None;
+        }
+    }
+    let at: DateTime<Utc> = Utc::now() + total * sign;
+    Some(at.to_rfc3339_opts(SecondsFormat::Millis, true))
+}
+
+/// Resolve a date argument: a relative duration (see [`relative_date`]), an RFC 3339 timestamp or a date
+pub fn resolve_date(value: &str) -> Result<String> {
+    if let Some(at) = relative_date(value) {
+        return Ok(at);
+    }
+    if DateTime::parse_from_rfc3339(value).is_ok() || NaiveDate::parse_from_str(value, "%Y-%m-%d").is_ok() {
+        return Ok(value.to_string());
+    }
+    anyhow::bail!(
+        "Invalid date '{}': expected a duration like -P2W, an RFC 3339 timestamp or YYYY-MM-DD",
+        value
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -145,6 +267,49 @@ mod tests {
         assert_eq!(result["title"], "Test");
     }
 
+    #[test]
+    fn test_parse_input_explicit_yaml_document() {
+        // A leading `---` is YAML's document marker, not front matter
+        let result = parse_input("---\ntitle: Test\n...\n").unwrap();
+        assert_eq!(result["title"], "Test");
+        assert!(result.get("description").is_none());
+    }
+
+    #[test]
+    fn test_parse_markdown_front_matter() {
+        let input = "---\ntitle: Crash on save\nteam: ENG\nlabels: [bug]\n---\n\n# Steps\n\n1. Save\n";
+        let result = parse_markdown(input, MARKDOWN_BODY_FIELD).unwrap();
+        assert_eq!(result["title"], "Crash on save");
+        assert_eq!(result["team"], "ENG");
+        assert_eq!(result["labels"][0], "bug");
+        assert_eq!(result["description"], "# Steps\n\n1. Save");
+    }
+
+    #[test]
+    fn test_parse_markdown_body_field() {
+        let result = parse_markdown("---\ntitle: Spec\n---\nBody text\n", "content").unwrap();
+        assert_eq!(result["content"], "Body text");
+        assert!(result.get("description").is_none());
+
+        // No front matter: the whole document is the body
+        let result = parse_markdown("Just notes", "body").unwrap();
+        assert_eq!(result["body"], "Just notes");
+
+        // Conflicting body field is rejected
+        assert!(parse_markdown("---\ncontent: x\n---\nBody\n", "content").is_err());
+
+        // Non-object front matter is rejected
+        assert!(parse_markdown("---\n- a\n---\nBody\n", "content").is_err());
+    }
+
+    #[test]
+    fn test_is_markdown_path() {
+        assert!(is_markdown_path("bug.md"));
+        assert!(is_markdown_path("notes/Spec.Markdown"));
+        assert!(!is_markdown_path("input.yaml"));
+        assert!(!is_markdown_path("md"));
+    }
+
     #[test]
     fn test_parse_input_invalid() {
         // Test that non-object inputs are rejected
@@ -163,4 +328,21 @@ mod tests {
         let result = parse_input(r#"{"key": "value"}"#);
         assert!(result.is_ok(), "Expected success for object");
     }
+
+    #[test]
+    fn test_relative_date() {
+        assert!(relative_date("-P2W").unwrap() < Utc::now().to_rfc3339());
+        assert!(relative_date("PT12H").unwrap() > Utc::now().to_rfc3339());
+        assert!(relative_date("2025-01-01").is_none());
+        assert!(relative_date("P2X").is_none());
+    }
+
+    #[test]
+    fn test_resolve_date() {
+        assert!(resolve_date("-P1D").unwrap() < Utc::now().to_rfc3339());
+        assert_eq!(resolve_date("2025-01-01").unwrap(), "2025-01-01");
+        assert_eq!(resolve_date("2025-01-01T08:00:00Z").unwrap(), "2025-01-01T08:00:00Z");
+        assert!(resolve_date("last week").is_err());
+        assert!(resolve_date("2025-13-01").is_err());
+    }
 }
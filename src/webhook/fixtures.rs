@@ -0,0 +1,118 @@
+//! Example payloads for `webhook send-test`, shaped like Linear's deliveries
+
+use chrono::{DateTime, SecondsFormat, Utc};
+use serde_json::{json, Value};
+
+const ORGANIZATION_ID: &str = "7f4f9a3e-4c8b-4b8e-9c1d-2a6b0c1d2e3f";
+const WEBHOOK_ID: &str = "0b8f2c4d-6e1a-4f3b-8c5d-9e7a1b2c3d4e";
+const OAUTH_CLIENT_ID: &str = "5c2e8a1f-3b7d-4e9a-a6c4-1d8f0b2e7a9c";
+const APP_USER_ID: &str = "9d1a4f7c-2e8b-4c3a-b5d6-7f0e1a2b3c4d";
+
+/// A random `Linear-Delivery` ID (UUID v4)
+pub fn delivery_id() -> String {
+    let mut bytes: [u8; 16] = rand::random();
+    bytes[6] = (bytes[6] & 0x0f) | 0x40;
+    bytes[8] = (bytes[8] & 0x3f) | 0x80;
+    let hex: String = bytes.iter().map(|b| format!("{:02x}", b)).collect();
+    format!("{}-{}-{}-{}-{}", &hex[0..8], &hex[8..12], &hex[12..16], &hex[16..20], &hex[20..32])
+}
+
+/// A random signing secret
+pub fn secret() -> String {
+    let bytes: [u8; 24] = rand::random();
+    format!("lin_wh_{}", bytes.iter().map(|b| format!("{:02x}", b)).collect::<String>())
+}
+
+/// A payload of the given type and action, sent at `now`
+pub fn payload(kind: &str, action: &str, now: DateTime<Utc>) -> Value {
+    let created_at = now.to_rfc3339_opts(SecondsFormat::Millis, true);
+    let mut payload = json!({
+        "type": kind,
+        "action": action,
+        "createdAt": created_at,
+        "organizationId": ORGANIZATION_ID,
+        "webhookId": WEBHOOK_ID,
+        "webhookTimestamp": now.timestamp_millis(),
+    });
+    match kind {
+        "AgentSessionEvent" => {
+            payload["agentSession"] = json!({
+                "id": "3e6b9c2a-8d1f-4a7e-b4c5-6d2a9f1e8b3c",
+                "status": "pending",
+                "createdAt": created_at,
+                "updatedAt": created_at,
+                "appUserId": APP_USER_ID,
+                "issue": issue(&created_at),
+            });
+            payload["appUserId"] = json!(APP_USER_ID);
+            payload["oauthClientId"] = json!(OAUTH_CLIENT_ID);
+            payload["promptContext"] = json!("<issue identifier=\"ENG-1\"><title>Example issue</title></issue>");
+        }
+        "AppUserNotification" => {
+            payload["notification"] = json!({
+                "id": "6a3c8e1b-4f2d-4b9a-8e7c-1d5f3a9b2c6e",
+                "type": "issueAssignedToYou",
+                "createdAt": created_at,
+                "issue": issue(&created_at),
+            });
+            payload["appUserId"] = json!(APP_USER_ID);
+            payload["oauthClientId"] = json!(OAUTH_CLIENT_ID);
+        }
+        "OAuthApp" => {
+            payload["oauthClientId"] = json!(OAUTH_CLIENT_ID);
+        }
+        "IssueSLA" => {
+            payload["issueData"] = issue(&created_at);
+            payload["url"] = json!("https://linear.app/example/issue/ENG-1/example-issue");
+        }
+        _ => {
+            payload["data"] = entity(kind, &created_at);
+            payload["url"] = json!(format!("https://linear.app/example/{}/1", kind.to_lowercase()));
+            if action == "update" {
+                let mut updated_from = json!({ "updatedAt": created_at });
+                if let Some(field) = ["title", "name", "body"].iter().find(|f| payload["data"].get(**f).is_some()) {
+                    updated_from[*field] = json!("Old value");
+                }
+                payload["updatedFrom"] = updated_from;
+            }
+        }
+    }
+    payload
+}
+
+fn issue(created_at: &str) -> Value {
+    json!({
+        "id": "c1f0a3b2-5d4e-4f6a-9b8c-7d6e5f4a3b2c",
+        "identifier": "ENG-1",
+        "number": 1,
+        "title": "Example issue",
+        "priority": 2,
+        "createdAt": created_at,
+        "updatedAt": created_at,
+        "teamId": "e2d1c0b9-8a7f-4e6d-9c5b-4a3f2e1d0c9b",
+        "team": { "id": "e2d1c0b9-8a7f-4e6d-9c5b-4a3f2e1d0c9b", "key": "ENG", "name": "Engineering" },
+        "state": { "id": "a9b8c7d6-e5f4-4a3b-8c2d-1e0f9a8b7c6d", "name": "Todo", "type": "unstarted" },
+        "url": "https://linear.app/example/issue/ENG-1/example-issue",
+    })
+}
+
+/// The `data` object for entity events
+fn entity(kind: &str, created_at: &str) -> Value {
+    match kind {
+        "Issue" => issue(created_at),
+        "Comment" => json!({
+            "id": "b4a3c2d1-e0f9-4a8b-9c7d-6e5f4a3b2c1d",
+            "body": "Example comment",
+            "issueId": "c1f0a3b2-5d4e-4f6a-9b8c-7d6e5f4a3b2c",
+            "issue": { "id": "c1f0a3b2-5d4e-4f6a-9b8c-7d6e5f4a3b2c", "identifier": "ENG-1", "title": "Example issue" },
+            "createdAt": created_at,
+            "updatedAt": created_at,
+        }),
+        _ => json!({
+            "id": "d5c4b3a2-f1e0-4d9c-8b7a-6f5e4d3c2b1a",
+            "name": format!("Example {}", kind),
+            "createdAt": created_at,
+            "updatedAt": created_at,
+        }),
+    }
+}
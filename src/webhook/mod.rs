@@ -0,0 +1,216 @@
+//! Linear webhook deliveries: signatures, payload checks and a local receiver
+//!
+//! Linear signs each delivery with a hex HMAC-SHA256 of the raw body in the
+//! `Linear-Signature` header and stamps the payload with `webhookTimestamp`
+//! (milliseconds). A delivery is accepted when the signature matches and the
+//! timestamp is within the allowed window. Every payload carries `type` and
+//! `action`; the changed object sits under `data` for entity events, or a
+//! type-specific field such as `agentSession` or `notification`.
+
+pub mod fixtures;
+mod server;
+
+use hmac::{Hmac, Mac};
+use serde_json::Value;
+use sha2::Sha256;
+use std::time::Duration;
+
+pub use server::WebhookServer;
+
+/// Fields holding the payload's subject, by payload kind
+const SUBJECT_FIELDS: &[&str] = &["data", "agentSession", "notification", "issueData"];
+
+/// Why a delivery was refused (the HTTP status the sender gets)
+#[derive(Debug, thiserror::Error)]
+pub enum Rejection {
+    #[error("Missing Linear-Signature header")]
+    MissingSignature,
+    #[error("Signature does not match the secret")]
+    InvalidSignature,
+    #[error("Invalid payload: {0}")]
+    InvalidPayload(String),
+    #[error("webhookTimestamp is {age}s away from now (allowed: {max_age}s)")]
+    Stale { age: i64, max_age: u64 },
+}
+
+impl Rejection {
+    pub fn status(&self) -> &'static str {
+        match self {
+            Rejection::MissingSignature | Rejection::InvalidSignature => "401 Unauthorized",
+            Rejection::InvalidPayload(_) | Rejection::Stale { .. } => "400 Bad Request",
+        }
+    }
+}
+
+/// A verified delivery
+#[derive(Debug, Clone)]
+pub struct Delivery {
+    /// `Linear-Delivery` header, if sent
+    pub id: Option<String>,
+    pub payload: Value,
+    /// The body as received, for forwarding
+    pub body: Vec<u8>,
+    /// Linear's headers (`linear-*`), for forwarding
+    pub headers: Vec<(String, String)>,
+    pub received_at: String,
+}
+
+impl Delivery {
+    pub fn kind(&self) -> &str {
+        self.payload["type"].as_str().unwrap_or_default()
+    }
+
+    pub fn action(&self) -> &str {
+        self.payload["action"].as_str().unwrap_or_default()
+    }
+
+    /// `Type.action`, e.g. `Issue.create`
+    pub fn event(&self) -> String {
+        format!("{}.{}", self.kind(), self.action())
+    }
+
+    /// The object the event is about
+    pub fn subject(&self) -> Option<&Value> {
+        SUBJECT_FIELDS.iter().find_map(|field| self.payload.get(*field))
+    }
+
+    /// Short human label: the subject's identifier or name, and its title
+    pub fn summary(&self) -> String {
+        let Some(subject) = self.subject() else {
+            return String::new();
+        };
+        let issue = &subject["issue"];
+        let label = [&subject["identifier"], &issue["identifier"], &subject["name"], &subject["id"]]
+            .into_iter()
+            .find_map(Value::as_str)
+            .unwrap_or_default();
+        match [&subject["title"], &issue["title"]].into_iter().find_map(Value::as_str) {
+            Some(title) => format!("{} \"{}\"", label, title),
+            None => label.to_string(),
+        }
+    }
+
+    /// Whether the delivery passes `--event` filters (`Type` or `Type.action`, any case)
+    pub fn matches(&self, filters: &[String]) -> bool {
+        filters.is_empty()
+            || filters.iter().any(|filter| match filter.split_once('.') {
+                Some((kind, action)) => {
+                    kind.eq_ignore_ascii_case(self.kind()) && action.eq_ignore_ascii_case(self.action())
+                }
+                None => filter.eq_ignore_ascii_case(self.kind()),
+            })
+    }
+
+    /// The delivery as printed by `webhook listen`
+    pub fn to_json(&self) -> Value {
+        serde_json::json!({
+            "deliveryId": self.id,
+            "event": self.event(),
+            "type": self.kind(),
+            "action": self.action(),
+            "receivedAt": self.received_at,
+            "payload": self.payload,
+        })
+    }
+}
+
+/// Hex HMAC-SHA256 of a body
+pub fn sign(secret: &str, body: &[u8]) -> String {
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts any key length");
+    mac.update(body);
+    mac.finalize().into_bytes().iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// Check a `Linear-Signature` header against the body (constant-time)
+pub fn verify_signature(secret: &str, body: &[u8], signature: Option<&str>) -> Result<(), Rejection> {
+    let signature = signature.ok_or(Rejection::MissingSignature)?;
+    let bytes = decode_hex(signature.trim()).ok_or(Rejection::InvalidSignature)?;
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts any key length");
+    mac.update(body);
+    mac.verify_slice(&bytes).map_err(|_| Rejection::InvalidSignature)
+}
+
+/// Parse a payload, requiring `type`, `action` and a `webhookTimestamp` within `max_age` of `now_ms`
+pub fn parse_payload(body: &[u8], now_ms: i64, max_age: Option<Duration>) -> Result<Value, Rejection> {
+    let payload: Value =
+        serde_json::from_slice(body).map_err(|e| Rejection::InvalidPayload(format!("not JSON ({})", e)))?;
+    if !payload.is_object() {
+        return Err(Rejection::InvalidPayload("expected a JSON object".to_string()));
+    }
+    for field in ["type", "action"] {
+        if !payload[field].is_string() {
+            return Err(Rejection::InvalidPayload(format!("missing '{}'", field)));
+        }
+    }
+    let timestamp = payload["webhookTimestamp"]
+        .as_f64()
+        .ok_or_else(|| Rejection::InvalidPayload("missing 'webhookTimestamp'".to_string()))?;
+    if let Some(max_age) = max_age {
+        let age = (now_ms - timestamp as i64) / 1000;
+        if age.unsigned_abs() > max_age.as_secs() {
+            return Err(Rejection::Stale {
+                age,
+                max_age: max_age.as_secs(),
+            });
+        }
+    }
+    Ok(payload)
+}
+
+fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+    if hex.len() % 2 != 0 {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sign_and_verify() {
+        let body = br#"{"type":"Issue"}"#;
+        let signature = sign("s3cret", body);
+        assert_eq!(signature.len(), 64);
+        assert!(verify_signature("s3cret", body, Some(&signature)).is_ok());
+        assert!(verify_signature("s3cret", body, Some(&signature.to_uppercase())).is_ok());
+        assert!(matches!(verify_signature("other", body, Some(&signature)), Err(Rejection::InvalidSignature)));
+        assert!(matches!(verify_signature("s3cret", b"{}", Some(&signature)), Err(Rejection::InvalidSignature)));
+        assert!(matches!(verify_signature("s3cret", body, Some("zz")), Err(Rejection::InvalidSignature)));
+        assert!(matches!(verify_signature("s3cret", body, None), Err(Rejection::MissingSignature)));
+    }
+
+    #[test]
+    fn test_parse_payload_window() {
+        let body = br#"{"type":"Issue","action":"create","webhookTimestamp":1000000}"#;
+        let window = Some(Duration::from_secs(60));
+        assert!(parse_payload(body, 1_030_000, window).is_ok());
+        assert!(matches!(parse_payload(body, 1_090_000, window), Err(Rejection::Stale { age: 90, .. })));
+        assert!(matches!(parse_payload(body, 910_000, window), Err(Rejection::Stale { age: -90, .. })));
+        assert!(parse_payload(body, 9_000_000, None).is_ok());
+        assert!(parse_payload(br#"{"type":"Issue","webhookTimestamp":1}"#, 1, None).is_err());
+        assert!(parse_payload(b"[]", 1, None).is_err());
+    }
+
+    #[test]
+    fn test_event_filters() {
+        let delivery = Delivery {
+            id: None,
+            payload: serde_json::json!({ "type": "Issue", "action": "update", "data": { "id": "x" } }),
+            body: Vec::new(),
+            headers: Vec::new(),
+            received_at: String::new(),
+        };
+        assert_eq!(delivery.event(), "Issue.update");
+        assert_eq!(delivery.subject().unwrap()["id"], "x");
+        assert_eq!(delivery.summary(), "x");
+        assert!(delivery.matches(&[]));
+        assert!(delivery.matches(&["issue".to_string()]));
+        assert!(delivery.matches(&["Comment".to_string(), "Issue.update".to_string()]));
+        assert!(!delivery.matches(&["Issue.create".to_string()]));
+    }
+}
@@ -0,0 +1,99 @@
+//! Minimal HTTP/1.1 receiver for webhook POSTs
+//!
+//! Verified deliveries and rejections are both passed on through a channel;
+//! the sender gets 200 as soon as a delivery is verified, before it is handled.
+
+use chrono::{SecondsFormat, Utc};
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::net::TcpListener;
+use tokio::sync::mpsc;
+
+use super::{parse_payload, verify_signature, Delivery, Rejection};
+use crate::common::constants::webhook as webhook_const;
+use crate::http_server::{serve_connection, Request, Response};
+
+/// A bound webhook receiver
+pub struct WebhookServer {
+    listener: TcpListener,
+    secret: Arc<str>,
+    max_age: Option<Duration>,
+}
+
+impl WebhookServer {
+    /// Bind to `host:port` (port 0 picks a free port); `max_age` of None skips the timestamp check
+    pub async fn bind(host: &str, port: u16, secret: &str, max_age: Option<Duration>) -> std::io::Result<Self> {
+        Ok(Self {
+            listener: TcpListener::bind((host, port)).await?,
+            secret: Arc::from(secret),
+            max_age,
+        })
+    }
+
+    pub fn local_addr(&self) -> std::io::Result<SocketAddr> {
+        self.listener.local_addr()
+    }
+
+    /// Accept connections until the task is dropped or the receiver closes
+    pub async fn serve(self, deliveries: mpsc::Sender<Result<Delivery, Rejection>>) -> std::io::Result<()> {
+        loop {
+            let (stream, _) = self.listener.accept().await?;
+            if deliveries.is_closed() {
+                return Ok(());
+            }
+            let deliveries = deliveries.clone();
+            let secret = Arc::clone(&self.secret);
+            let max_age = self.max_age;
+            tokio::spawn(async move {
+                let handle = |request| handle_request(request, &secret, max_age, &deliveries);
+                let _ = serve_connection(stream, webhook_const::MAX_BODY_BYTES, error_body, handle).await;
+            });
+        }
+    }
+}
+
+/// Verify each POST and pass it on
+async fn handle_request(
+    request: Request,
+    secret: &str,
+    max_age: Option<Duration>,
+    deliveries: &mpsc::Sender<Result<Delivery, Rejection>>,
+) -> Response {
+    let headers = request.headers.into_iter().filter(|(name, _)| name.starts_with("linear-")).collect();
+    match receive(secret, max_age, request.body, headers) {
+        Ok(delivery) => {
+            let _ = deliveries.send(Ok(delivery)).await;
+            ("200 OK", serde_json::json!({ "ok": true }).to_string())
+        }
+        Err(rejection) => {
+            let response = (rejection.status(), error_body(&rejection.to_string()));
+            let _ = deliveries.send(Err(rejection)).await;
+            response
+        }
+    }
+}
+
+/// Verify and parse one POST
+fn receive(
+    secret: &str,
+    max_age: Option<Duration>,
+    body: Vec<u8>,
+    headers: Vec<(String, String)>,
+) -> Result<Delivery, Rejection> {
+    let header = |name: &str| headers.iter().find(|(n, _)| n == name).map(|(_, v)| v.clone());
+    verify_signature(secret, &body, header(webhook_const::SIGNATURE_HEADER).as_deref())?;
+    let now = Utc::now();
+    let payload = parse_payload(&body, now.timestamp_millis(), max_age)?;
+    Ok(Delivery {
+        id: header(webhook_const::DELIVERY_HEADER),
+        payload,
+        body,
+        headers,
+        received_at: now.to_rfc3339_opts(SecondsFormat::Millis, true),
+    })
+}
+
+fn error_body(message: &str) -> String {
+    serde_json::json!({ "error": message }).to_string()
+}
@@ -10,9 +10,10 @@ use assert_cmd::prelude::*;
 use predicates::prelude::*;
 use std::process::Command;
 
-use factories::generated::{issue, team, user};
+use factories::generated::{graphql_error, issue, team, user};
 use support::mock_server::{
     cli_with_mock_server, create_mock_server, mock_auth_error, mock_list_issues, mock_list_teams,
+    mock_rate_limit_status,
 };
 
 /// Test that missing API key results in exit code 2
@@ -418,3 +419,1868 @@ fn test_invalid_vars_in_raw_command() {
         .failure()
         .stderr(predicate::str::contains("Input must be a JSON/YAML object"));
 }
+
+// ============= Markdown Input Tests =============
+
+/// Test --from-markdown and a `.md` --input-file resolve front matter names and use the body as description
+#[test]
+fn test_create_issue_from_markdown() {
+    let server = create_mock_server();
+    let team_id = "550e8400-e29b-41d4-a716-tea000000001";
+
+    let team_mock = server.mock(|when, then| {
+        when.method(httpmock::Method::POST)
+            .path("/graphql")
+            .body_includes("ResolveTeam")
+            .body_includes("\"eqIgnoreCase\":\"ENG\"");
+        then.status(200).json_body(serde_json::json!({
+            "data": { "teams": { "nodes": [{ "id": team_id, "key": "ENG", "name": "Engineering" }] } }
+        }));
+    });
+    let create_mock = server.mock(|when, then| {
+        when.method(httpmock::Method::POST)
+            .path("/graphql")
+            .body_includes("IssueCreate")
+            .json_body_includes(
+                serde_json::json!({
+                    "variables": {
+                        "input": {
+                            "title": "Crash on save",
+                            "teamId": team_id,
+                            "priority": 2,
+                            "description": "Steps:\n\n1. Save"
+                        }
+                    }
+                })
+                .to_string(),
+            );
+        then.status(200).json_body(serde_json::json!({
+            "data": { "issueCreate": { "success": true, "issue": issue(1) } }
+        }));
+    });
+
+    let path = std::env::temp_dir().join(format!("linears-md-{}.md", std::process::id()));
+    std::fs::write(
+        &path,
+        "---\ntitle: Crash on save\nteam: ENG\npriority: high\n---\n\nSteps:\n\n1. Save\n",
+    )
+    .unwrap();
+
+    cli_with_mock_server(&server)
+        .args(["--out", "json", "create", "issue", "--from-markdown"])
+        .arg(&path)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("ENG-1"));
+
+    // A `.md` input file is read the same way
+    cli_with_mock_server(&server)
+        .args(["--out", "json", "create", "issue", "--input-file"])
+        .arg(&path)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("ENG-1"));
+
+    let _ = std::fs::remove_file(&path);
+    team_mock.assert_calls(2);
+    create_mock.assert_calls(2);
+}
+
+/// Test --from-markdown conflicts with --input
+#[test]
+fn test_from_markdown_conflicts_with_input() {
+    let mut cmd = Command::cargo_bin("linears").unwrap();
+    cmd.args(["create", "issue", "--input", "{}", "--from-markdown", "bug.md"])
+        .env("LINEARS_API_KEY", "test")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot be used with"));
+}
+
+// ============= Upload Tests =============
+
+/// Write a temporary file for upload tests
+fn upload_fixture(name: &str) -> std::path::PathBuf {
+    let path = std::env::temp_dir().join(format!("linears-{}-{}", std::process::id(), name));
+    std::fs::write(&path, "hello").unwrap();
+    path
+}
+
+/// Test upload requests a signed URL, PUTs the bytes and prints the asset URL
+#[test]
+fn test_upload_prints_asset_url() {
+    let server = create_mock_server();
+    let asset_url = "https://uploads.linear.app/asset/upload.log";
+    let upload_mock = support::mock_server::mock_file_upload(&server, asset_url);
+    let put_mock = support::mock_server::mock_upload_put(&server);
+    let path = upload_fixture("upload.log");
+
+    cli_with_mock_server(&server)
+        .arg("upload")
+        .arg(&path)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(asset_url));
+
+    let _ = std::fs::remove_file(&path);
+    upload_mock.assert();
+    put_mock.assert();
+}
+
+/// Test upload --issue links the asset via attachmentCreate
+#[test]
+fn test_upload_links_attachment() {
+    let server = create_mock_server();
+    let asset_url = "https://uploads.linear.app/asset/upload.log";
+    let _upload_mock = support::mock_server::mock_file_upload(&server, asset_url);
+    let _put_mock = support::mock_server::mock_upload_put(&server);
+    let attach_mock = server.mock(|when, then| {
+        when.method(httpmock::Method::POST)
+            .path("/graphql")
+            .body_includes("attachmentCreate")
+            .json_body_includes(
+                serde_json::json!({
+                    "variables": { "input": { "issueId": "ENG-1", "url": asset_url } }
+                })
+                .to_string(),
+            );
+        then.status(200).json_body(serde_json::json!({
+            "data": { "attachmentCreate": { "success": true, "attachment": { "id": "att-1", "title": "upload.log" } } }
+        }));
+    });
+    let path = upload_fixture("attach.log");
+
+    cli_with_mock_server(&server)
+        .args(["--out", "json", "upload", "--issue", "ENG-1"])
+        .arg(&path)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("att-1"));
+
+    let _ = std::fs::remove_file(&path);
+    attach_mock.assert();
+}
+
+/// Test failed signed upload surfaces an error
+#[test]
+fn test_upload_put_failure() {
+    let server = create_mock_server();
+    let _upload_mock = support::mock_server::mock_file_upload(&server, "https://x/asset");
+    let _put_mock = server.mock(|when, then| {
+        when.method(httpmock::Method::PUT).path("/upload");
+        then.status(403);
+    });
+    let path = upload_fixture("denied.log");
+
+    cli_with_mock_server(&server)
+        .arg("upload")
+        .arg(&path)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Upload failed: HTTP 403"));
+
+    let _ = std::fs::remove_file(&path);
+}
+
+// ============= Undo Journal Tests =============
+
+/// Test update is journaled and `undo` replays the prior values
+#[test]
+fn test_update_then_undo() {
+    let server = create_mock_server();
+    let state_dir = std::env::temp_dir().join(format!("linears-undo-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&state_dir);
+    let issue_id = "550e8400-e29b-41d4-a716-446655440001";
+
+    let prefetch_mock = server.mock(|when, then| {
+        when.method(httpmock::Method::POST)
+            .path("/graphql")
+            .body_includes("GetIssue");
+        then.status(200).json_body(serde_json::json!({
+            "data": { "issue": { "id": issue_id, "title": "Old title" } }
+        }));
+    });
+    let update_mock = server.mock(|when, then| {
+        when.method(httpmock::Method::POST)
+            .path("/graphql")
+            .body_includes("IssueUpdate")
+            .body_includes("New title");
+        then.status(200).json_body(serde_json::json!({
+            "data": { "issueUpdate": { "success": true, "issue": { "id": issue_id } } }
+        }));
+    });
+    let undo_mock = server.mock(|when, then| {
+        when.method(httpmock::Method::POST)
+            .path("/graphql")
+            .body_includes("IssueUpdate")
+            .json_body_includes(
+                serde_json::json!({
+                    "variables": { "id": issue_id, "input": { "title": "Old title" } }
+                })
+                .to_string(),
+            );
+        then.status(200).json_body(serde_json::json!({
+            "data": { "issueUpdate": { "success": true, "issue": { "id": issue_id } } }
+        }));
+    });
+
+    cli_with_mock_server(&server)
+        .env("XDG_STATE_HOME", &state_dir)
+        .args(["update", "issue", "ENG-1", "--set", r#"{"title":"New title"}"#])
+        .assert()
+        .success();
+    prefetch_mock.assert();
+    update_mock.assert();
+
+    cli_with_mock_server(&server)
+        .env("XDG_STATE_HOME", &state_dir)
+        .args(["--out", "json", "undo", "--dry-run"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\"status\":\"planned\""))
+        .stdout(predicate::str::contains("issueUpdate"));
+    undo_mock.assert_calls(0);
+
+    cli_with_mock_server(&server)
+        .env("XDG_STATE_HOME", &state_dir)
+        .args(["--out", "json", "undo"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\"status\":\"undone\""));
+    undo_mock.assert();
+
+    cli_with_mock_server(&server)
+        .env("XDG_STATE_HOME", &state_dir)
+        .arg("undo")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Nothing to undo"));
+
+    let _ = std::fs::remove_dir_all(&state_dir);
+}
+
+/// Test undo --list works without an API key
+#[test]
+fn test_undo_list_without_api_key() {
+    let state_dir = std::env::temp_dir().join(format!("linears-undo-list-{}", std::process::id()));
+    let mut cmd = Command::cargo_bin("linears").unwrap();
+    cmd.args(["undo", "--list"])
+        .env_remove("LINEARS_API_KEY")
+        .env("XDG_STATE_HOME", &state_dir)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("No results found"));
+}
+
+// ============= Idempotency Tests =============
+
+/// Test a create whose response was lost is not retried once the entity exists
+#[test]
+fn test_idempotent_create_skips_duplicate_retry() {
+    let server = create_mock_server();
+
+    let create_mock = server.mock(|when, then| {
+        when.method(httpmock::Method::POST)
+            .path("/graphql")
+            .body_includes("IssueCreate")
+            .body_includes("\"id\":");
+        then.status(502);
+    });
+    let lookup_mock = server.mock(|when, then| {
+        when.method(httpmock::Method::POST)
+            .path("/graphql")
+            .body_includes("GetIssue");
+        then.status(200).json_body(serde_json::json!({
+            "data": { "issue": issue(7) }
+        }));
+    });
+
+    cli_with_mock_server(&server)
+        .args([
+            "--out",
+            "json",
+            "create",
+            "issue",
+            "--input",
+            r#"{"title":"Nightly report","teamId":"team-1"}"#,
+            "--idempotency-key",
+            "nightly-2025-01-10",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("ENG-7"))
+        .stderr(predicate::str::contains("already exists"));
+
+    create_mock.assert_calls(1);
+    lookup_mock.assert_calls(1);
+}
+
+// ============= Partial Result Tests =============
+
+fn partial_issue_response() -> serde_json::Value {
+    serde_json::json!({
+        "data": { "issue": issue(3) },
+        "errors": [{
+            "message": "Cannot return null for non-nullable field Issue.cycle",
+            "path": ["issue", "cycle"]
+        }]
+    })
+}
+
+/// Test --partial keeps data, reports errors and exits with code 5
+#[test]
+fn test_partial_result_exit_code() {
+    let server = create_mock_server();
+    server.mock(|when, then| {
+        when.method(httpmock::Method::POST).path("/graphql");
+        then.status(200).json_body(partial_issue_response());
+    });
+
+    cli_with_mock_server(&server)
+        .args(["--out", "json", "--partial", "get", "issue", "ENG-3"])
+        .assert()
+        .code(5)
+        .stdout(predicate::str::contains("ENG-3"))
+        .stdout(predicate::str::contains("\"errors\""))
+        .stdout(predicate::str::contains("Issue.cycle"));
+}
+
+/// Test data alongside errors is still a GraphQL error without --partial
+#[test]
+fn test_partial_result_strict_by_default() {
+    let server = create_mock_server();
+    server.mock(|when, then| {
+        when.method(httpmock::Method::POST).path("/graphql");
+        then.status(200).json_body(partial_issue_response());
+    });
+
+    cli_with_mock_server(&server)
+        .args(["--out", "json", "get", "issue", "ENG-3"])
+        .assert()
+        .code(4);
+}
+
+// ============= Rate Limit Tests =============
+
+/// Test rate-limit command reports budgets from response headers
+#[test]
+fn test_rate_limit_command() {
+    let server = create_mock_server();
+    let mock = mock_rate_limit_status(&server, 1234);
+
+    cli_with_mock_server(&server)
+        .args(["--out", "json", "rate-limit"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\"remaining\":1234"))
+        .stdout(predicate::str::contains("\"limit\":1500"))
+        .stdout(predicate::str::contains("\"lastComplexity\":12"));
+
+    mock.assert();
+}
+
+/// Test --verbose shows the remaining budget after a request
+#[test]
+fn test_verbose_shows_rate_limit_budget() {
+    let server = create_mock_server();
+    mock_rate_limit_status(&server, 99);
+
+    cli_with_mock_server(&server)
+        .args(["--verbose", "rate-limit"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Rate limit: 99/1500 requests remaining (query cost 12)"));
+}
+
+// ============= Complexity Tests =============
+
+/// Test an over-budget list is refused before any request, with a page size hint
+#[test]
+fn test_complexity_refuse_suggests_page_size() {
+    let server = create_mock_server();
+    let mock = mock_list_issues(&server, vec![issue(1)]);
+
+    cli_with_mock_server(&server)
+        .args([
+            "--max-complexity",
+            "2000",
+            "--complexity-check",
+            "refuse",
+            "list",
+            "issue",
+            "--first",
+            "250",
+            "--expand",
+            "comments",
+        ])
+        .assert()
+        .code(1)
+        .stderr(predicate::str::contains("exceeds the limit of 2000"))
+        .stderr(predicate::str::contains("--first"));
+
+    mock.assert_calls(0);
+}
+
+/// Test --verbose prints the estimate and the default check only warns
+#[test]
+fn test_complexity_estimate_verbose_and_warn() {
+    let server = create_mock_server();
+    let mock = mock_list_issues(&server, vec![issue(1)]);
+
+    cli_with_mock_server(&server)
+        .args(["--verbose", "--max-complexity", "100", "list", "issue", "--expand", "comments"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Estimated complexity:"))
+        .stderr(predicate::str::contains("Warning: Estimated query complexity"));
+
+    mock.assert();
+}
+
+// ============= Retry Policy Tests =============
+
+/// Test --max-retries bounds attempts and JSON modes report structured retry events
+#[test]
+fn test_retry_policy_flags_and_structured_events() {
+    let server = create_mock_server();
+    let mock = support::mock_server::mock_server_error(&server, 503);
+
+    cli_with_mock_server(&server)
+        .args(["--out", "json", "--max-retries", "2", "--retry-base-delay", "1", "list", "issue"])
+        .assert()
+        .code(1)
+        .stderr(predicate::str::contains(r#""event":"retry","reason":"server""#))
+        .stderr(predicate::str::contains(r#""attempt":2,"maxRetries":2"#));
+
+    mock.assert_calls(3);
+}
+
+/// Test a rate limit longer than --max-rate-limit-wait is not waited out
+#[test]
+fn test_rate_limit_wait_cap() {
+    let server = create_mock_server();
+    let mock = support::mock_server::mock_rate_limit(&server, 5);
+
+    cli_with_mock_server(&server)
+        .args(["--max-rate-limit-wait", "2", "list", "issue"])
+        .assert()
+        .code(1)
+        .stderr(predicate::str::contains("Too long to wait"));
+
+    mock.assert_calls(1);
+}
+
+// ============= Cassette Tests =============
+
+/// Test a recorded run can be replayed offline, without an API key
+#[test]
+fn test_record_then_replay() {
+    let cassettes = std::env::temp_dir().join(format!("linears-cassettes-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&cassettes);
+    let cassette_dir = cassettes.to_str().unwrap();
+
+    let server = create_mock_server();
+    let mock = mock_list_issues(&server, vec![issue(1), issue(2)]);
+
+    cli_with_mock_server(&server)
+        .args(["--out", "json", "--record", cassette_dir, "list", "issue"])
+        .assert()
+        .success();
+    mock.assert();
+
+    let recorded = std::fs::read_dir(&cassettes).unwrap().count();
+    assert_eq!(recorded, 1);
+
+    // Replay with no server and no API key
+    let mut cmd = Command::cargo_bin("linears").unwrap();
+    cmd.args(["--out", "json", "--replay", cassette_dir, "list", "issue"])
+        .env_remove("LINEARS_API_KEY")
+        .env("LINEARS_ENDPOINT", "http://127.0.0.1:9/graphql")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("ENG-2"));
+
+    // Different variables: strict misses, lenient falls back to the same query
+    let mut cmd = Command::cargo_bin("linears").unwrap();
+    cmd.args(["--replay", cassette_dir, "list", "issue", "--first", "5"])
+        .env_remove("LINEARS_API_KEY")
+        .assert()
+        .code(1)
+        .stderr(predicate::str::contains("No cassette"));
+
+    let mut cmd = Command::cargo_bin("linears").unwrap();
+    cmd.args([
+        "--out",
+        "json",
+        "--replay",
+        cassette_dir,
+        "--replay-match",
+        "lenient",
+        "list",
+        "issue",
+        "--first",
+        "5",
+    ])
+    .env_remove("LINEARS_API_KEY")
+    .assert()
+    .success()
+    .stdout(predicate::str::contains("ENG-1"));
+
+    let _ = std::fs::remove_dir_all(&cassettes);
+}
+
+/// Test the CLI end to end against `dev serve`
+#[test]
+fn test_dev_serve_fake_api() {
+    use std::io::{BufRead, BufReader, Write};
+    use std::process::Stdio;
+
+    let mut server = Command::cargo_bin("linears")
+        .unwrap()
+        .args(["dev", "serve", "--port", "0", "--issues", "3"])
+        .env_remove("LINEARS_API_KEY")
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .unwrap();
+
+    let mut stdout = BufReader::new(server.stdout.take().unwrap());
+    let mut line = String::new();
+    stdout.read_line(&mut line).unwrap();
+    let endpoint = line.split_whitespace().last().unwrap().to_string();
+
+    let cli = |args: &[&str]| {
+        let mut cmd = Command::cargo_bin("linears").unwrap();
+        cmd.args(["--out", "json", "--endpoint", &endpoint])
+            .args(args)
+            .env("LINEARS_API_KEY", "fake-key");
+        cmd
+    };
+
+    cli(&["list", "issue", "--select", "identifier,title"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("ENG-3"));
+
+    cli(&["create", "issue", "--input", r#"{"title":"From CLI","teamId":"ENG"}"#])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("ENG-4"));
+
+    cli(&["update", "issue", "ENG-4", "--set", r#"{"title":"Renamed"}"#])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Renamed"));
+
+    cli(&["archive", "issue", "ENG-4"]).assert().success();
+
+    cli(&["list", "issue", "--select", "identifier", "--filter", r#"{"title":{"eq":"Renamed"}}"#])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("ENG-4").not());
+
+    cli(&["get", "issue", "ENG-4"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("archivedAt"));
+
+    // Oversized headers are refused without reading them to the end
+    let address = endpoint.trim_start_matches("http://").trim_end_matches("/graphql");
+    let mut stream = std::net::TcpStream::connect(address).unwrap();
+    let padding = "x".repeat(128 * 1024);
+    let _ = write!(stream, "POST /graphql HTTP/1.1\r\nx-padding: {}\r\n\r\n", padding);
+    let mut response = String::new();
+    BufReader::new(stream).read_line(&mut response).unwrap();
+    assert!(response.starts_with("HTTP/1.1 431"), "{}", response);
+
+    server.kill().unwrap();
+    let _ = server.wait();
+}
+
+/// Test that lookups are served from the opt-in response cache until refreshed
+#[test]
+fn test_response_cache() {
+    let cache_home = std::env::temp_dir().join(format!("linears-cache-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&cache_home);
+    let server = create_mock_server();
+    let mock = mock_list_teams(&server, vec![team(1)]);
+    let cli = || {
+        let mut cmd = cli_with_mock_server(&server);
+        cmd.env("XDG_CACHE_HOME", &cache_home);
+        cmd
+    };
+
+    // Without --cache nothing is stored
+    cli().args(["list", "team"]).assert().success();
+    for _ in 0..2 {
+        cli()
+            .args(["--out", "json", "--cache", "list", "team"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("Design"));
+    }
+    mock.assert_calls(2);
+
+    cli()
+        .args(["--refresh", "list", "team"])
+        .assert()
+        .success();
+    cli()
+        .env("LINEARS_CACHE", "1")
+        .args(["--no-cache", "list", "team"])
+        .assert()
+        .success();
+    mock.assert_calls(4);
+
+    cli()
+        .args(["--out", "json", "cache", "stats"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\"team\""));
+}
+
+/// Test that several ids are fetched in one aliased query, with misses reported per id
+#[test]
+fn test_get_many_batched() {
+    let server = create_mock_server();
+    // Linear nulls the whole response when any aliased lookup fails
+    let failing = server.mock(|when, then| {
+        when.method(httpmock::Method::POST)
+            .path("/graphql")
+            .body_includes(r#""id1":"ENG-404""#);
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(serde_json::json!({
+                "data": null,
+                "errors": [{ "message": "Entity not found", "path": ["i1"] }]
+            }));
+    });
+    let retried = server.mock(|when, then| {
+        when.method(httpmock::Method::POST)
+            .path("/graphql")
+            .body_includes(r#""id1":"ENG-2""#);
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(serde_json::json!({
+                "data": { "i0": issue(1), "i1": issue(2) }
+            }));
+    });
+
+    let output = cli_with_mock_server(&server)
+        .args(["--out", "json", "get", "issue", "ENG-1", "ENG-404", "ENG-2"])
+        .assert()
+        .failure()
+        .code(5)
+        .get_output()
+        .stdout
+        .clone();
+
+    let json: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    let results = json["results"].as_array().unwrap();
+    let ids: Vec<_> = results.iter().map(|r| r["id"].as_str().unwrap()).collect();
+    assert_eq!(ids, ["ENG-1", "ENG-404", "ENG-2"]);
+    assert_eq!(results[0]["entity"]["identifier"], "ENG-1");
+    assert!(results[1]["error"].as_str().unwrap().contains("not found"));
+    assert_eq!(results[2]["entity"]["identifier"], "ENG-2");
+    failing.assert_calls(1);
+    retried.assert_calls(1);
+
+    // Ids can also come from stdin, one per line
+    cli_with_mock_server(&server)
+        .args(["--out", "json", "get", "issue", "--ids-from", "-"])
+        .write_stdin("ENG-1\n\n# skipped\nENG-2\n")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("ENG-2"));
+}
+
+/// Test that a batch answered with null data and no per-id errors is not re-sent forever
+#[test]
+fn test_get_many_null_data_without_alias_errors() {
+    let server = create_mock_server();
+    let mock = server.mock(|when, then| {
+        when.method(httpmock::Method::POST)
+            .path("/graphql")
+            .body_includes(r#""id0":"ENG-7""#);
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(serde_json::json!({ "data": null }));
+    });
+
+    let output = cli_with_mock_server(&server)
+        .args(["--out", "json", "get", "issue", "ENG-7", "ENG-8"])
+        .timeout(std::time::Duration::from_secs(30))
+        .assert()
+        .failure()
+        .code(5)
+        .get_output()
+        .stdout
+        .clone();
+    let json: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    let results = json["results"].as_array().unwrap();
+    assert_eq!(results.len(), 2);
+    assert!(results.iter().all(|r| r["error"] == "No data returned"));
+    mock.assert_calls(1);
+}
+
+/// Test that custom headers, user agent and compression reach the API
+#[test]
+fn test_custom_headers_and_compression() {
+    let server = create_mock_server();
+    let flags = server.mock(|when, then| {
+        when.method(httpmock::Method::POST)
+            .path("/graphql")
+            .header("x-gateway-key", "secret")
+            .header("user-agent", "acme-bot/1.0")
+            .header("content-encoding", "gzip");
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(serde_json::json!({ "data": { "teams": { "nodes": [team(1)] } } }));
+    });
+
+    cli_with_mock_server(&server)
+        .args([
+            "--out",
+            "json",
+            "--header",
+            "X-Gateway-Key: secret",
+            "--user-agent",
+            "acme-bot/1.0",
+            "--compress",
+            "list",
+            "team",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Design"));
+    flags.assert_calls(1);
+
+    // Headers can also come from the environment, one per line
+    let env = server.mock(|when, then| {
+        when.method(httpmock::Method::POST)
+            .path("/graphql")
+            .header("x-team", "platform")
+            .header("x-region", "eu");
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(serde_json::json!({ "data": { "teams": { "nodes": [team(2)] } } }));
+    });
+    cli_with_mock_server(&server)
+        .env("LINEARS_HEADERS", "X-Team: platform\nX-Region: eu")
+        .args(["--out", "json", "list", "team"])
+        .assert()
+        .success();
+    env.assert_calls(1);
+
+    cli_with_mock_server(&server)
+        .args(["--header", "missing-colon", "list", "team"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("NAME:VALUE"));
+}
+
+/// Test that --proxy routes API requests through the proxy
+#[test]
+fn test_proxy() {
+    let proxy = create_mock_server();
+    let mock = mock_list_teams(&proxy, vec![team(1)]);
+
+    cli_with_mock_server(&proxy)
+        .env("LINEARS_ENDPOINT", "http://linear.invalid/graphql")
+        .args(["--out", "json", "--proxy", &proxy.base_url(), "list", "team"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Design"));
+    mock.assert_calls(1);
+}
+
+/// Test that Ctrl-C during --all prints the pages fetched so far with a resume cursor
+#[cfg(unix)]
+#[test]
+fn test_interrupt_all_pagination() {
+    let server = create_mock_server();
+    let _slow = server.mock(|when, then| {
+        when.method(httpmock::Method::POST)
+            .path("/graphql")
+            .body_includes(r#""after":"cursor-1""#);
+        then.status(200)
+            .delay(std::time::Duration::from_secs(30))
+            .json_body(serde_json::json!({ "data": { "issues": { "nodes": [] } } }));
+    });
+    let _first = server.mock(|when, then| {
+        when.method(httpmock::Method::POST)
+            .path("/graphql")
+            .body_includes(r#""after":null"#);
+        then.status(200).json_body(serde_json::json!({
+            "data": {
+                "issues": {
+                    "nodes": [issue(1), issue(2)],
+                    "pageInfo": { "hasNextPage": true, "endCursor": "cursor-1" }
+                }
+            }
+        }));
+    });
+
+    let cache_home = std::env::temp_dir().join(format!("linears-interrupt-{}", std::process::id()));
+    let child = Command::cargo_bin("linears")
+        .unwrap()
+        .env("LINEARS_API_KEY", "test-key")
+        .env("LINEARS_ENDPOINT", format!("{}/graphql", server.base_url()))
+        .env("XDG_CACHE_HOME", &cache_home)
+        .args(["--out", "json", "list", "issue", "--all"])
+        .stdout(std::process::Stdio::piped())
+        .stderr(std::process::Stdio::piped())
+        .spawn()
+        .unwrap();
+    std::thread::sleep(std::time::Duration::from_secs(1));
+    let status = std::process::Command::new("kill")
+        .args(["-INT", &child.id().to_string()])
+        .status()
+        .unwrap();
+    assert!(status.success());
+
+    let output = child.wait_with_output().unwrap();
+    assert_eq!(output.status.code(), Some(130));
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["truncated"], true);
+    assert_eq!(json["resumeCursor"], "cursor-1");
+    assert_eq!(json["nodes"].as_array().unwrap().len(), 2);
+    assert!(String::from_utf8_lossy(&output.stderr).contains("--after cursor-1"));
+}
+
+/// Test that --trace writes every exchange with credentials redacted
+#[test]
+fn test_trace_export() {
+    let dir = std::env::temp_dir().join(format!("linears-trace-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let har_path = dir.join("session.har");
+
+    let server = create_mock_server();
+    let _mock = mock_list_teams(&server, vec![team(1)]);
+    cli_with_mock_server(&server)
+        .args(["--out", "json", "--no-cache", "--trace", har_path.to_str().unwrap(), "list", "team"])
+        .assert()
+        .success();
+
+    let har: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(&har_path).unwrap()).unwrap();
+    let entry = &har["log"]["entries"][0];
+    assert_eq!(entry["response"]["status"], 200);
+    assert_eq!(entry["_attempt"], 1);
+    assert!(entry["request"]["postData"]["text"].as_str().unwrap().contains("teams"));
+    let auth = entry["request"]["headers"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|h| h["name"] == "authorization")
+        .unwrap();
+    assert_eq!(auth["value"], "[REDACTED]");
+    assert!(!std::fs::read_to_string(&har_path).unwrap().contains("lin_api_test_key"));
+
+    // Retries show up as separate attempts
+    let jsonl_path = dir.join("session.jsonl");
+    let failing = create_mock_server();
+    let _error = failing.mock(|when, then| {
+        when.method(httpmock::Method::POST).path("/graphql");
+        then.status(502).body("bad gateway");
+    });
+    cli_with_mock_server(&failing)
+        .args([
+            "--trace",
+            jsonl_path.to_str().unwrap(),
+            "--max-retries",
+            "1",
+            "--retry-base-delay",
+            "1",
+            "list",
+            "team",
+        ])
+        .assert()
+        .failure();
+
+    let lines: Vec<serde_json::Value> = std::fs::read_to_string(&jsonl_path)
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(lines.len(), 2);
+    assert_eq!(lines[1]["attempt"], 2);
+    assert_eq!(lines[1]["response"]["status"], 502);
+    assert_eq!(lines[1]["response"]["body"], "bad gateway");
+
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+/// Test that --all resumes from --after and pages backwards from --before
+#[test]
+fn test_list_all_resume_and_backward() {
+    let server = create_mock_server();
+    let _resume = server.mock(|when, then| {
+        when.method(httpmock::Method::POST)
+            .path("/graphql")
+            .body_includes(r#""after":"cursor-1""#);
+        then.status(200).json_body(serde_json::json!({
+            "data": { "issues": {
+                "nodes": [issue(3)],
+                "pageInfo": { "hasNextPage": false, "endCursor": "cursor-2" }
+            } }
+        }));
+    });
+    cli_with_mock_server(&server)
+        .args(["--out", "json", "list", "issue", "--all", "--after", "cursor-1"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("ENG-3"))
+        .stdout(predicate::str::contains("ENG-1").not());
+
+    let newest = server.mock(|when, then| {
+        when.method(httpmock::Method::POST)
+            .path("/graphql")
+            .body_includes(r#""before":"cursor-9""#)
+            .body_includes(r#""last":50"#);
+        then.status(200).json_body(serde_json::json!({
+            "data": { "issues": {
+                "nodes": [issue(8)],
+                "pageInfo": { "hasPreviousPage": true, "startCursor": "cursor-8" }
+            } }
+        }));
+    });
+    let older = server.mock(|when, then| {
+        when.method(httpmock::Method::POST)
+            .path("/graphql")
+            .body_includes(r#""before":"cursor-8""#);
+        then.status(200).json_body(serde_json::json!({
+            "data": { "issues": {
+                "nodes": [issue(7)],
+                "pageInfo": { "hasPreviousPage": false, "startCursor": "cursor-7" }
+            } }
+        }));
+    });
+    let output = cli_with_mock_server(&server)
+        .args(["--out", "json", "list", "issue", "--all", "--before", "cursor-9"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["nodes"].as_array().unwrap().len(), 2);
+    newest.assert_calls(1);
+    older.assert_calls(1);
+}
+
+/// Test that search --limit follows cursors and asks only for what is missing
+#[test]
+fn test_search_limit_paginates() {
+    let server = create_mock_server();
+    let first = server.mock(|when, then| {
+        when.method(httpmock::Method::POST)
+            .path("/graphql")
+            .body_includes(r#""first":50"#);
+        then.status(200).json_body(serde_json::json!({
+            "data": { "issues": {
+                "nodes": (1..=50).map(issue).collect::<Vec<_>>(),
+                "pageInfo": { "hasNextPage": true, "endCursor": "cursor-50" }
+            } }
+        }));
+    });
+    let rest = server.mock(|when, then| {
+        when.method(httpmock::Method::POST)
+            .path("/graphql")
+            .body_includes(r#""first":10"#)
+            .body_includes(r#""after":"cursor-50""#);
+        then.status(200).json_body(serde_json::json!({
+            "data": { "issues": {
+                "nodes": (51..=60).map(issue).collect::<Vec<_>>(),
+                "pageInfo": { "hasNextPage": true, "endCursor": "cursor-60" }
+            } }
+        }));
+    });
+
+    let output = cli_with_mock_server(&server)
+        .args(["--out", "json", "search", "issue", "login", "--limit", "60"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["nodes"].as_array().unwrap().len(), 60);
+    first.assert_calls(1);
+    rest.assert_calls(1);
+}
+
+/// Test that --parallel pages createdAt ranges and merges them in order without duplicates
+#[test]
+fn test_list_all_parallel_partitions() {
+    let server = create_mock_server();
+    let probe = server.mock(|when, then| {
+        when.method(httpmock::Method::POST)
+            .path("/graphql")
+            .body_includes("query ProbeIssue(");
+        then.status(200).json_body(serde_json::json!({
+            "data": {
+                "first": { "nodes": [{ "createdAt": "2025-01-01T00:00:00.000Z" }] },
+                "last": { "nodes": [{ "createdAt": "2025-01-01T00:00:00.003Z" }] }
+            }
+        }));
+    });
+    // A 4ms span splits into four 1ms ranges; issue 2 sits on a boundary in both
+    let ranges = [vec![1, 2], vec![2, 3], vec![4], vec![]];
+    let mocks: Vec<_> = ranges
+        .iter()
+        .enumerate()
+        .map(|(i, nums)| {
+            server.mock(|when, then| {
+                when.method(httpmock::Method::POST)
+                    .path("/graphql")
+                    .body_includes(format!(r#""gte":"2025-01-01T00:00:00.00{}Z""#, i));
+                then.status(200).json_body(serde_json::json!({
+                    "data": { "issues": {
+                        "nodes": nums.iter().map(|n| issue(*n)).collect::<Vec<_>>(),
+                        "pageInfo": { "hasNextPage": false, "endCursor": null }
+                    } }
+                }));
+            })
+        })
+        .collect();
+
+    let output = cli_with_mock_server(&server)
+        .args(["--out", "json", "list", "issue", "--all", "--parallel", "2"])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let identifiers: Vec<_> = json["nodes"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|n| n["identifier"].as_str().unwrap().to_string())
+        .collect();
+    assert_eq!(identifiers, ["ENG-1", "ENG-2", "ENG-3", "ENG-4"]);
+    probe.assert_calls(1);
+    for mock in &mocks {
+        mock.assert_calls(1);
+    }
+
+    cli_with_mock_server(&server)
+        .args(["list", "issue", "--parallel", "2"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--all"));
+}
+
+/// Test that --parallel returns every range in full, past the sequential 1000 cap
+#[test]
+fn test_list_all_parallel_is_not_capped() {
+    let server = create_mock_server();
+    let _probe = server.mock(|when, then| {
+        when.method(httpmock::Method::POST)
+            .path("/graphql")
+            .body_includes("query ProbeIssue(");
+        then.status(200).json_body(serde_json::json!({
+            "data": {
+                "first": { "nodes": [{ "createdAt": "2025-01-01T00:00:00.000Z" }] },
+                "last": { "nodes": [{ "createdAt": "2025-01-01T00:00:00.001Z" }] }
+            }
+        }));
+    });
+    // Two 1ms ranges of 600 issues each
+    let _ranges: Vec<_> = (0..2u32)
+        .map(|i| {
+            server.mock(|when, then| {
+                when.method(httpmock::Method::POST)
+                    .path("/graphql")
+                    .body_includes(format!(r#""gte":"2025-01-01T00:00:00.00{}Z""#, i));
+                then.status(200).json_body(serde_json::json!({
+                    "data": { "issues": {
+                        "nodes": (i * 600 + 1..=(i + 1) * 600).map(issue).collect::<Vec<_>>(),
+                        "pageInfo": { "hasNextPage": false, "endCursor": null }
+                    } }
+                }));
+            })
+        })
+        .collect();
+
+    let output = cli_with_mock_server(&server)
+        .args(["--out", "json", "list", "issue", "--all", "--parallel", "2"])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["nodes"].as_array().unwrap().len(), 1200);
+}
+
+/// Test that sync pull mirrors issues incrementally and --offline answers list, get and search
+#[test]
+fn test_sync_pull_and_offline_queries() {
+    let server = create_mock_server();
+    let mirror = std::env::temp_dir().join(format!("linears-test-mirror-{}.db", std::process::id()));
+    let _ = std::fs::remove_file(&mirror);
+
+    let mut archived = issue(3);
+    archived["archivedAt"] = serde_json::json!("2025-01-11T00:00:00.000Z");
+    archived["updatedAt"] = serde_json::json!("2025-01-11T00:00:00.000Z");
+    let first_page = server.mock(|when, then| {
+        when.method(httpmock::Method::POST)
+            .path("/graphql")
+            .body_includes("query ListIssue(")
+            .body_includes(r#""includeArchived":true"#)
+            .body_excludes(r#""after":"c1""#)
+            .body_excludes(r#""gt""#);
+        then.status(200).json_body(serde_json::json!({
+            "data": { "issues": {
+                "nodes": [issue(1), issue(2)],
+                "pageInfo": { "hasNextPage": true, "endCursor": "c1" }
+            } }
+        }));
+    });
+    let second_page = server.mock(|when, then| {
+        when.method(httpmock::Method::POST)
+            .path("/graphql")
+            .body_includes(r#""after":"c1""#);
+        then.status(200).json_body(serde_json::json!({
+            "data": { "issues": {
+                "nodes": [archived],
+                "pageInfo": { "hasNextPage": false, "endCursor": "c2" }
+            } }
+        }));
+    });
+    let mut changed = issue(2);
+    changed["title"] = serde_json::json!("Renamed offline issue");
+    changed["updatedAt"] = serde_json::json!("2025-01-12T00:00:00.000Z");
+    let incremental = server.mock(|when, then| {
+        when.method(httpmock::Method::POST)
+            .path("/graphql")
+            .body_includes(r#""updatedAt":{"gt":"2025-01-11T00:00:00.000Z"}"#);
+        then.status(200).json_body(serde_json::json!({
+            "data": { "issues": {
+                "nodes": [changed],
+                "pageInfo": { "hasNextPage": false, "endCursor": "c3" }
+            } }
+        }));
+    });
+
+    let pull = |server: &httpmock::MockServer, resource: &str| {
+        let output = cli_with_mock_server(server)
+            .env("LINEARS_MIRROR", &mirror)
+            .args(["--out", "json", "sync", "pull", "--resource", resource])
+            .output()
+            .unwrap();
+        assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+        serde_json::from_slice::<serde_json::Value>(&output.stdout).unwrap()
+    };
+    let json = pull(&server, "issue");
+    assert_eq!(json["result"]["resources"][0]["fetched"], 3);
+    first_page.assert_calls(1);
+    second_page.assert_calls(1);
+    incremental.assert_calls(0);
+
+    // The second pull asks only for changes since the newest updatedAt seen; plurals name the same resource
+    let json = pull(&server, "issues");
+    assert_eq!(json["result"]["resources"][0]["fetched"], 1);
+    incremental.assert_calls(1);
+
+    // Offline queries need neither the API nor a key
+    let offline = |args: &[&str]| {
+        let mut cmd = assert_cmd::Command::cargo_bin("linears").unwrap();
+        cmd.env_remove("LINEARS_API_KEY")
+            .env("LINEARS_MIRROR", &mirror)
+            .args(["--out", "json", "--offline"])
+            .args(args);
+        let output = cmd.output().unwrap();
+        assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+        serde_json::from_slice::<serde_json::Value>(&output.stdout).unwrap()
+    };
+    let json = offline(&["list", "issue", "--filter", r#"{"state":{"type":{"eq":"started"}}}"#]);
+    let nodes = json["nodes"].as_array().unwrap();
+    assert_eq!(nodes.len(), 1);
+    assert_eq!(nodes[0]["title"], "Renamed offline issue");
+    assert_eq!(nodes[0]["state"], serde_json::json!({ "name": "In Progress" }));
+    assert!(nodes[0].get("description").is_none());
+
+    let json = offline(&["list", "issues", "--include-archived", "--first", "1"]);
+    assert_eq!(json["nodes"].as_array().unwrap().len(), 1);
+    assert_eq!(json["pageInfo"]["hasNextPage"], true);
+
+    let json = offline(&["get", "issue", "eng-1"]);
+    assert_eq!(json["entity"]["identifier"], "ENG-1");
+
+    let json = offline(&["search", "issue", "description issue 1"]);
+    assert_eq!(json["strategy"], "offlineFullText");
+    let nodes = json["nodes"].as_array().unwrap();
+    assert_eq!(nodes.len(), 1);
+    assert_eq!(nodes[0]["identifier"], "ENG-1");
+
+    let json = offline(&["sync", "status"]);
+    let issues = json["result"]["resources"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|r| r["resource"] == "issue")
+        .unwrap()
+        .clone();
+    assert_eq!((issues["entities"].as_i64(), issues["archived"].as_i64()), (Some(3), Some(1)));
+
+    cli_with_mock_server(&server)
+        .env("LINEARS_MIRROR", &mirror)
+        .args(["--offline", "get", "issue", "ENG-9"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("not found in the local mirror"));
+    cli_with_mock_server(&server)
+        .env("LINEARS_MIRROR", &mirror)
+        .args(["--offline", "list", "attachment"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("not mirrored"));
+    let _ = std::fs::remove_file(&mirror);
+}
+
+/// Test that export rejects a --since that is neither a duration nor a date before fetching anything
+#[test]
+fn test_export_rejects_invalid_since() {
+    let server = create_mock_server();
+    let any = server.mock(|when, then| {
+        when.method(httpmock::Method::POST).path("/graphql");
+        then.status(200).json_body(serde_json::json!({ "data": {} }));
+    });
+    let dir = std::env::temp_dir().join(format!("linears-test-export-since-{}", std::process::id()));
+    cli_with_mock_server(&server)
+        .args(["export", "--resources", "issue", "--since", "last week", "--out-dir"])
+        .arg(&dir)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Invalid date 'last week'"));
+    any.assert_calls(0);
+    assert!(!dir.join("manifest.json").exists());
+}
+
+/// Test that export writes each resource as NDJSON with a manifest of the run
+#[test]
+fn test_export_ndjson_and_manifest() {
+    use std::io::Read;
+
+    let server = create_mock_server();
+    let dir = std::env::temp_dir().join(format!("linears-test-export-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+
+    let first_page = server.mock(|when, then| {
+        when.method(httpmock::Method::POST)
+            .path("/graphql")
+            .body_includes("query ListIssue(")
+            .body_includes(r#""updatedAt":{"gte":"20"#)
+            .body_includes(r#""includeArchived":true"#)
+            .body_excludes(r#""after":"c1""#);
+        then.status(200).json_body(serde_json::json!({
+            "data": { "issues": {
+                "nodes": [issue(1), issue(2)],
+                "pageInfo": { "hasNextPage": true, "endCursor": "c1" }
+            } }
+        }));
+    });
+    let second_page = server.mock(|when, then| {
+        when.method(httpmock::Method::POST)
+            .path("/graphql")
+            .body_includes("query ListIssue(")
+            .body_includes(r#""after":"c1""#);
+        then.status(200).json_body(serde_json::json!({
+            "data": { "issues": {
+                "nodes": [issue(3)],
+                "pageInfo": { "hasNextPage": false, "endCursor": "c2" }
+            } }
+        }));
+    });
+    let teams = server.mock(|when, then| {
+        when.method(httpmock::Method::POST)
+            .path("/graphql")
+            .body_includes("query ListTeam(");
+        then.status(200).json_body(serde_json::json!({
+            "data": { "teams": {
+                "nodes": [team(1)],
+                "pageInfo": { "hasNextPage": false, "endCursor": null }
+            } }
+        }));
+    });
+
+    let output = cli_with_mock_server(&server)
+        .args(["--out", "json", "export", "--resources", "issue,team", "--since", "-P1W", "--gzip", "--out-dir"])
+        .arg(&dir)
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    first_page.assert_calls(1);
+    second_page.assert_calls(1);
+    teams.assert_calls(1);
+
+    let manifest: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(dir.join("manifest.json")).unwrap()).unwrap();
+    assert_eq!(manifest["formatVersion"], 1);
+    // The relative --since is recorded as the cutoff it resolved to
+    let since = chrono::DateTime::parse_from_rfc3339(manifest["since"].as_str().unwrap()).unwrap();
+    let age = chrono::Utc::now().signed_duration_since(since);
+    assert!(age >= chrono::Duration::days(7) && age < chrono::Duration::days(8));
+    assert!(manifest["schema"]["commit"].is_string());
+    assert!(manifest["completedAt"].is_string());
+    assert_eq!(manifest["resources"][0]["file"], "issues.ndjson.gz");
+    assert_eq!(manifest["resources"][0]["count"], 3);
+    assert_eq!(manifest["resources"][1]["count"], 1);
+    assert_eq!(manifest["resources"][1]["complete"], true);
+
+    let mut lines = String::new();
+    flate2::read::GzDecoder::new(std::fs::File::open(dir.join("issues.ndjson.gz")).unwrap())
+        .read_to_string(&mut lines)
+        .unwrap();
+    let identifiers: Vec<_> = lines
+        .lines()
+        .map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap()["identifier"].clone())
+        .collect();
+    assert_eq!(identifiers, ["ENG-1", "ENG-2", "ENG-3"]);
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+/// Test that export --parallel pages createdAt ranges and still writes the manifest when a resource fails
+#[test]
+fn test_export_parallel_and_failed_resource() {
+    let server = create_mock_server();
+    let dir = std::env::temp_dir().join(format!("linears-test-export-failed-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+
+    let probe = server.mock(|when, then| {
+        when.method(httpmock::Method::POST)
+            .path("/graphql")
+            .body_includes("query ProbeIssue(");
+        then.status(200).json_body(serde_json::json!({
+            "data": {
+                "first": { "nodes": [{ "createdAt": "2025-01-01T00:00:00.000Z" }] },
+                "last": { "nodes": [{ "createdAt": "2025-01-01T00:00:00.001Z" }] }
+            }
+        }));
+    });
+    // Two 1ms ranges; issue 2 sits on the boundary in both
+    let ranges: Vec<_> = [vec![1, 2], vec![2, 3]]
+        .into_iter()
+        .enumerate()
+        .map(|(i, nums)| {
+            server.mock(move |when, then| {
+                when.method(httpmock::Method::POST)
+                    .path("/graphql")
+                    .body_includes("query ListIssue(")
+                    .body_includes(format!(r#""gte":"2025-01-01T00:00:00.00{}Z""#, i));
+                then.status(200).json_body(serde_json::json!({
+                    "data": { "issues": {
+                        "nodes": nums.iter().map(|n| issue(*n)).collect::<Vec<_>>(),
+                        "pageInfo": { "hasNextPage": false, "endCursor": null }
+                    } }
+                }));
+            })
+        })
+        .collect();
+    let _teams = server.mock(|when, then| {
+        when.method(httpmock::Method::POST)
+            .path("/graphql")
+            .body_includes("query ProbeTeam(");
+        then.status(200).json_body(graphql_error("Teams are unavailable"));
+    });
+
+    let output = cli_with_mock_server(&server)
+        .args(["--out", "json", "export", "--resources", "issue,team", "--parallel", "2", "--out-dir"])
+        .arg(&dir)
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    probe.assert_calls(1);
+    for range in &ranges {
+        range.assert_calls(1);
+    }
+
+    let manifest: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(dir.join("manifest.json")).unwrap()).unwrap();
+    assert!(manifest["completedAt"].is_null());
+    assert_eq!(manifest["resources"][0]["count"], 3);
+    assert_eq!(manifest["resources"][0]["complete"], true);
+    assert_eq!(manifest["resources"][1]["resource"], "team");
+    assert_eq!(manifest["resources"][1]["complete"], false);
+    assert!(manifest["resources"][1]["error"].as_str().unwrap().contains("Teams are unavailable"));
+
+    let lines = std::fs::read_to_string(dir.join("issues.ndjson")).unwrap();
+    assert_eq!(lines.lines().count(), 3);
+    assert!(!dir.join("teams.ndjson").exists());
+    assert!(!dir.join("teams.ndjson.tmp").exists());
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+/// Test that import resolves names, reports a dry run, and skips rows already created on a re-run
+#[test]
+fn test_import_csv_dry_run_mapping_and_rerun() {
+    let server = create_mock_server();
+    let dir = std::env::temp_dir().join(format!("linears-test-import-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    let csv = dir.join("backlog.csv");
+    std::fs::write(
+        &csv,
+        "ID,Title,Labels,Status,Assignee,Priority\n\
+         OLD-1,Login fails,bug,Todo,ann@example.com,High\n\
+         OLD-2,Dark mode,,,,\n\
+         OLD-3,Bad label,defect,,,\n",
+    )
+    .unwrap();
+
+    let lookups = [
+        ("query ResolveTeam(", serde_json::json!({ "teams": { "nodes": [{ "id": "team-1", "key": "ENG", "name": "Engineering" }] } })),
+        ("query ResolveLabels(", serde_json::json!({ "issueLabels": { "nodes": [{ "id": "label-bug", "name": "bug", "team": null }] } })),
+        ("query ResolveStates(", serde_json::json!({ "workflowStates": { "nodes": [{ "id": "state-todo", "name": "Todo" }] } })),
+        ("query ResolveUser(", serde_json::json!({ "users": { "nodes": [{ "id": "user-ann", "name": "Ann", "email": "ann@example.com" }] } })),
+    ];
+    for (operation, data) in lookups {
+        server.mock(|when, then| {
+            when.method(httpmock::Method::POST).path("/graphql").body_includes(operation);
+            then.status(200).json_body(serde_json::json!({ "data": data }));
+        });
+    }
+    let batch = server.mock(|when, then| {
+        when.method(httpmock::Method::POST)
+            .path("/graphql")
+            .body_includes("mutation IssueBatchCreate(")
+            .body_includes(r#""labelIds":["label-bug"]"#)
+            .body_includes(r#""stateId":"state-todo""#)
+            .body_includes(r#""assigneeId":"user-ann""#)
+            .body_includes(r#""teamId":"team-1""#);
+        then.status(200).json_body(serde_json::json!({
+            "data": { "issueBatchCreate": { "success": true, "issues": [
+                { "id": "new-1", "identifier": "ENG-101", "title": "Login fails" },
+                { "id": "new-2", "identifier": "ENG-102", "title": "Dark mode" },
+                { "id": "new-3", "identifier": "ENG-103", "title": "Bad label" }
+            ] } }
+        }));
+    });
+
+    let import = |extra: &[&str]| {
+        cli_with_mock_server(&server)
+            .args(["--out", "json", "import", "csv"])
+            .arg(&csv)
+            .args(["--team", "ENG"])
+            .args(extra)
+            .output()
+            .unwrap()
+    };
+
+    // The dry run reports the unknown label and creates nothing
+    let output = import(&["--dry-run"]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("1 of 3 rows would not be imported"));
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["result"]["ready"], 2);
+    assert_eq!(json["result"]["rows"][0]["input"]["priority"], 2);
+    assert_eq!(json["result"]["rows"][2]["status"], "invalid");
+    assert!(json["result"]["rows"][2]["error"].as_str().unwrap().contains("defect"));
+    batch.assert_calls(0);
+
+    let mapping = dir.join("mapping.yaml");
+    std::fs::write(&mapping, "values:\n  labels:\n    defect: bug\n").unwrap();
+    let output = import(&["--mapping", mapping.to_str().unwrap()]);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["result"]["imported"], 3);
+    batch.assert_calls(1);
+
+    let id_map: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(dir.join("backlog.csv.idmap.json")).unwrap()).unwrap();
+    assert_eq!(id_map["OLD-3"]["identifier"], "ENG-103");
+
+    // A re-run skips everything already in the ID map
+    let output = import(&["--mapping", mapping.to_str().unwrap()]);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["result"]["skipped"], 3);
+    batch.assert_calls(1);
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+/// Test that rows left pending by failed batches are looked up, in pages, not created again on the next run
+#[test]
+fn test_import_checks_pending_rows_before_creating() {
+    let server = create_mock_server();
+    let dir = std::env::temp_dir().join(format!("linears-test-import-pending-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    let csv = dir.join("backlog.csv");
+    let rows: String = (1..=60).map(|n| format!("OLD-{},Issue {}\n", n, n)).collect();
+    std::fs::write(&csv, format!("ID,Title\n{}", rows)).unwrap();
+
+    server.mock(|when, then| {
+        when.method(httpmock::Method::POST).path("/graphql").body_includes("query ResolveTeam(");
+        then.status(200).json_body(serde_json::json!({
+            "data": { "teams": { "nodes": [{ "id": "team-1", "key": "ENG", "name": "Engineering" }] } }
+        }));
+    });
+    let batch = server.mock(|when, then| {
+        when.method(httpmock::Method::POST).path("/graphql").body_includes("mutation IssueBatchCreate(");
+        then.status(200).json_body(graphql_error("Internal error"));
+    });
+    let empty_page = serde_json::json!({
+        "data": { "issues": { "nodes": [], "pageInfo": { "hasNextPage": false, "endCursor": null } } }
+    });
+    let mut missing = server.mock(|when, then| {
+        when.method(httpmock::Method::POST).path("/graphql").body_includes("query ListIssue(");
+        then.status(200).json_body(empty_page.clone());
+    });
+
+    let import = || {
+        cli_with_mock_server(&server)
+            .args(["--out", "json", "import", "csv"])
+            .arg(&csv)
+            .args(["--team", "ENG"])
+            .output()
+            .unwrap()
+    };
+
+    // Each failed batch is checked once, then its rows stay pending in the ID map
+    let output = import();
+    assert!(!output.status.success());
+    batch.assert_calls(2);
+    missing.assert_calls(2);
+    let id_map: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(dir.join("backlog.csv.idmap.json")).unwrap()).unwrap();
+    assert_eq!(id_map["OLD-60"]["pending"], true);
+    let id = id_map["OLD-1"]["id"].as_str().unwrap().to_string();
+
+    // OLD-1 was created after all: the re-run looks the 60 pending IDs up 50 at a time and skips it
+    missing.delete();
+    let lookup = server.mock(|when, then| {
+        when.method(httpmock::Method::POST)
+            .path("/graphql")
+            .body_includes("query ListIssue(")
+            .body_includes(r#""first":50"#)
+            .body_includes(format!(r#"{{"id":{{"in":["{}","#, id));
+        then.status(200).json_body(serde_json::json!({
+            "data": { "issues": {
+                "nodes": [{ "id": id, "identifier": "ENG-101", "title": "Issue 1" }],
+                "pageInfo": { "hasNextPage": false, "endCursor": null }
+            } }
+        }));
+    });
+    let rest = server.mock(|when, then| {
+        when.method(httpmock::Method::POST)
+            .path("/graphql")
+            .body_includes("query ListIssue(")
+            .body_excludes(&id);
+        then.status(200).json_body(empty_page.clone());
+    });
+    let output = import();
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["result"]["skipped"], 1);
+    assert_eq!(json["result"]["rows"][0]["issue"]["identifier"], "ENG-101");
+    lookup.assert_calls(1);
+    // The other pending chunk, then one check per failed batch
+    rest.assert_calls(3);
+    batch.assert_calls(4);
+    let id_map: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(dir.join("backlog.csv.idmap.json")).unwrap()).unwrap();
+    assert_eq!(id_map["OLD-1"]["identifier"], "ENG-101");
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+/// Test that watch loads a snapshot, then reports changes since the newest updatedAt
+#[cfg(unix)]
+#[test]
+fn test_watch_emits_change_events_and_runs_hook() {
+    let server = create_mock_server();
+    let dir = std::env::temp_dir().join(format!("linears-test-watch-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    let hook_log = dir.join("events.ndjson");
+
+    let snapshot = server.mock(|when, then| {
+        when.method(httpmock::Method::POST)
+            .path("/graphql")
+            .body_includes("query ListIssue(")
+            .body_includes(r#""filter":{"team":{"key":{"eq":"ENG"}}}"#)
+            .body_excludes(r#""includeArchived":true"#);
+        then.status(200).json_body(serde_json::json!({
+            "data": { "issues": {
+                "nodes": [issue(1), issue(2)],
+                "pageInfo": { "hasNextPage": false, "endCursor": null }
+            } }
+        }));
+    });
+    let mut renamed = issue(1);
+    renamed["title"] = serde_json::json!("Renamed");
+    renamed["updatedAt"] = serde_json::json!("2025-01-11T09:00:00.000Z");
+    let mut archived = issue(2);
+    archived["archivedAt"] = serde_json::json!("2025-01-11T10:00:00.000Z");
+    archived["updatedAt"] = serde_json::json!("2025-01-11T10:00:00.000Z");
+    let mut created = issue(3);
+    created["updatedAt"] = serde_json::json!("2025-01-11T11:00:00.000Z");
+    let changes = server.mock(|when, then| {
+        when.method(httpmock::Method::POST)
+            .path("/graphql")
+            .body_includes(r#""updatedAt":{"gt":"2025-01-10T14:30:00.000Z"}"#)
+            .body_includes(r#""includeArchived":true"#);
+        then.status(200).json_body(serde_json::json!({
+            "data": { "issues": {
+                "nodes": [renamed, archived, created],
+                "pageInfo": { "hasNextPage": false, "endCursor": null }
+            } }
+        }));
+    });
+
+    let output = cli_with_mock_server(&server)
+        .args(["--out", "ndjson", "watch", "issue", "--where", "team.key = ENG"])
+        .args(["--interval", "10ms", "--polls", "2", "--exec"])
+        .arg(format!("cat >> '{}'", hook_log.display()))
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    snapshot.assert_calls(1);
+    changes.assert_calls(1);
+
+    let events: Vec<serde_json::Value> = String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    let kinds: Vec<_> = events.iter().map(|e| e["event"].as_str().unwrap()).collect();
+    assert_eq!(kinds, ["updated", "archived", "created"]);
+    assert_eq!(events[0]["changes"], serde_json::json!(["title"]));
+    assert_eq!(events[0]["previous"]["title"], "Test Issue 1");
+    assert_eq!(events[1]["at"], "2025-01-11T10:00:00.000Z");
+    assert_eq!(events[2]["entity"]["identifier"], "ENG-3");
+
+    let hooked = std::fs::read_to_string(&hook_log).unwrap();
+    assert_eq!(hooked.lines().count(), 3);
+    assert!(hooked.lines().next().unwrap().contains(r#""event":"updated""#));
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+/// Test that watch polls without an updatedAt bound until the first entity arrives
+#[test]
+fn test_watch_empty_snapshot_is_not_bounded_by_the_local_clock() {
+    let server = create_mock_server();
+    let unbounded = server.mock(|when, then| {
+        when.method(httpmock::Method::POST)
+            .path("/graphql")
+            .body_includes("query ListIssue(")
+            .body_excludes("updatedAt\":{");
+        then.status(200).json_body(serde_json::json!({
+            "data": { "issues": {
+                "nodes": [],
+                "pageInfo": { "hasNextPage": false, "endCursor": null }
+            } }
+        }));
+    });
+    let bounded = server.mock(|when, then| {
+        when.method(httpmock::Method::POST)
+            .path("/graphql")
+            .body_includes("updatedAt\":{");
+        then.status(200).json_body(serde_json::json!({
+            "data": { "issues": {
+                "nodes": [],
+                "pageInfo": { "hasNextPage": false, "endCursor": null }
+            } }
+        }));
+    });
+
+    let output = cli_with_mock_server(&server)
+        .args(["--out", "ndjson", "watch", "issue", "--interval", "10ms", "--polls", "3"])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    unbounded.assert_calls(3);
+    bounded.assert_calls(0);
+    assert!(output.stdout.is_empty());
+}
+
+/// Test that signed test deliveries reach a local listener and bad or stale ones are refused
+#[test]
+fn test_webhook_listen_verifies_send_test_deliveries() {
+    use std::io::{BufRead, BufReader, Read};
+    use std::process::Stdio;
+
+    let mut listener = Command::cargo_bin("linears")
+        .unwrap()
+        .args(["--out", "ndjson", "webhook", "listen", "--port", "0", "--secret", "s3cret", "--count", "2"])
+        .env_remove("LINEARS_API_KEY")
+        .env_remove("LINEARS_WEBHOOK_SECRET")
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    let mut stderr = BufReader::new(listener.stderr.take().unwrap());
+    let mut first_line = String::new();
+    stderr.read_line(&mut first_line).unwrap();
+    let url = first_line.trim().rsplit(' ').next().unwrap().to_string();
+    assert!(url.starts_with("http://127.0.0.1:"), "{}", first_line);
+
+    let send_test = |args: &[&str]| {
+        Command::cargo_bin("linears")
+            .unwrap()
+            .args(["--out", "json", "webhook", "send-test", "--url", &url])
+            .args(args)
+            .env_remove("LINEARS_API_KEY")
+            .env_remove("LINEARS_WEBHOOK_SECRET")
+            .output()
+            .unwrap()
+    };
+
+    let output = send_test(&["--secret", "s3cret"]);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["result"]["status"], 200);
+    assert_eq!(json["result"]["event"], "Issue.create");
+
+    let output = send_test(&["--secret", "wrong"]);
+    assert!(!output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["result"]["status"], 401);
+
+    let output = send_test(&["--secret", "s3cret", "--age", "300"]);
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["result"]["status"], 400);
+
+    let output = send_test(&["--secret", "s3cret", "--type", "Comment", "--action", "update"]);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+
+    let status = listener.wait().unwrap();
+    assert!(status.success());
+    let mut stdout = String::new();
+    listener.stdout.take().unwrap().read_to_string(&mut stdout).unwrap();
+    let deliveries: Vec<serde_json::Value> = stdout.lines().map(|l| serde_json::from_str(l).unwrap()).collect();
+    assert_eq!(deliveries.len(), 2);
+    assert_eq!(deliveries[0]["event"], "Issue.create");
+    assert_eq!(deliveries[0]["payload"]["data"]["identifier"], "ENG-1");
+    assert!(deliveries[0]["deliveryId"].is_string());
+    assert_eq!(deliveries[1]["event"], "Comment.update");
+    assert_eq!(deliveries[1]["payload"]["updatedFrom"]["body"], "Old value");
+
+    let mut rejected = String::new();
+    stderr.read_to_string(&mut rejected).unwrap();
+    assert!(rejected.contains("Signature does not match"), "{}", rejected);
+    assert!(rejected.contains("webhookTimestamp is 300s away"), "{}", rejected);
+}
+
+/// Test that send-test prints the signed request when no --url is given
+#[test]
+fn test_webhook_send_test_prints_signed_request() {
+    let output = Command::cargo_bin("linears")
+        .unwrap()
+        .args(["--out", "json", "webhook", "send-test", "--type", "AgentSessionEvent", "--action", "created"])
+        .env("LINEARS_WEBHOOK_SECRET", "s3cret")
+        .env_remove("LINEARS_API_KEY")
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["result"]["headers"]["linear-event"], "AgentSessionEvent");
+    assert_eq!(json["result"]["headers"]["linear-signature"].as_str().unwrap().len(), 64);
+    let body: serde_json::Value = serde_json::from_str(json["result"]["body"].as_str().unwrap()).unwrap();
+    assert_eq!(body["agentSession"]["issue"]["identifier"], "ENG-1");
+    assert!(body["webhookTimestamp"].is_i64());
+}
+
+/// Test that webhooks are created with friendly types and a team, listed by team, rotated and verified
+#[test]
+fn test_webhook_management_commands() {
+    let server = create_mock_server();
+    server.mock(|when, then| {
+        when.method(httpmock::Method::POST).path("/graphql").body_includes("query ResolveTeam(");
+        then.status(200).json_body(serde_json::json!({
+            "data": { "teams": { "nodes": [{ "id": "team-1", "key": "ENG", "name": "Engineering" }] } }
+        }));
+    });
+    let create = server.mock(|when, then| {
+        when.method(httpmock::Method::POST)
+            .path("/graphql")
+            .body_includes("mutation WebhookCreate(")
+            .body_includes(r#""resourceTypes":["Issue","IssueLabel","IssueSLA"]"#)
+            .body_includes(r#""teamId":"team-1""#)
+            .body_includes(r#""enabled":true"#)
+            .body_includes(r#""secret":"lin_wh_"#);
+        then.status(200).json_body(serde_json::json!({
+            "data": { "webhookCreate": { "success": true, "webhook": { "id": "wh-1", "url": "https://example.com/hook" } } }
+        }));
+    });
+    server.mock(|when, then| {
+        when.method(httpmock::Method::POST).path("/graphql").body_includes("webhooks(");
+        then.status(200).json_body(serde_json::json!({
+            "data": { "webhooks": {
+                "nodes": [
+                    { "id": "wh-1", "label": null, "url": "https://example.com/hook", "enabled": true,
+                      "resourceTypes": ["Issue"], "allPublicTeams": false, "team": { "id": "team-1", "key": "ENG", "name": "Engineering" } },
+                    { "id": "wh-2", "label": "All", "url": "https://example.com/all", "enabled": false,
+                      "resourceTypes": ["Comment"], "allPublicTeams": true, "team": null }
+                ],
+                "pageInfo": { "hasNextPage": false, "endCursor": null }
+            } }
+        }));
+    });
+    let rotate = server.mock(|when, then| {
+        when.method(httpmock::Method::POST)
+            .path("/graphql")
+            .body_includes("mutation WebhookUpdate(")
+            .body_includes(r#""input":{"secret":"n3w""#);
+        then.status(200).json_body(serde_json::json!({
+            "data": { "webhookUpdate": { "success": true, "webhook": { "id": "wh-2", "url": "https://example.com/all" } } }
+        }));
+    });
+    server.mock(|when, then| {
+        when.method(httpmock::Method::POST).path("/graphql").body_includes("query GetWebhook(");
+        then.status(200).json_body(serde_json::json!({
+            "data": { "webhook": { "id": "wh-2", "url": "https://example.com/all", "enabled": true,
+                                   "resourceTypes": ["Comment"], "secret": "n3w" } }
+        }));
+    });
+
+    let output = cli_with_mock_server(&server)
+        .args(["--out", "json", "webhook", "create", "--url", "https://example.com/hook"])
+        .args(["--resource-types", "Issue,IssueLabel,issue-sla", "--team", "ENG"])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["result"]["id"], "wh-1");
+    assert!(json["result"]["secret"].as_str().unwrap().starts_with("lin_wh_"));
+    create.assert_calls(1);
+
+    let output = cli_with_mock_server(&server)
+        .args(["--out", "json", "webhook", "list", "--team", "ENG"])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let webhooks = json["result"]["webhooks"].as_array().unwrap();
+    assert_eq!(webhooks.len(), 1);
+    assert_eq!(webhooks[0]["id"], "wh-1");
+
+    cli_with_mock_server(&server)
+        .args(["webhook", "list"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("all public"))
+        .stdout(predicate::str::contains("Issue"));
+
+    cli_with_mock_server(&server)
+        .args(["webhook", "rotate-secret", "wh-2", "--secret", "n3w"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("secret: n3w"));
+    rotate.assert_calls(1);
+
+    cli_with_mock_server(&server)
+        .args(["webhook", "update", "wh-2"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Nothing to update"));
+
+    // verify signs a fixture of the webhook's first resource type with its secret
+    let receiver = create_mock_server();
+    let delivery = receiver.mock(|when, then| {
+        when.method(httpmock::Method::POST)
+            .path("/hook")
+            .header("linear-event", "Comment")
+            .header_exists("linear-signature")
+            .body_includes(r#""webhookId":"wh-2""#);
+        then.status(200).json_body(serde_json::json!({ "ok": true }));
+    });
+    cli_with_mock_server(&server)
+        .args(["webhook", "verify", "wh-2", "--url", &receiver.url("/hook")])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Sent Comment.create"));
+    delivery.assert_calls(1);
+}
@@ -0,0 +1,207 @@
+//! Tests for the embeddable library API
+
+use futures::StreamExt;
+use httpmock::prelude::*;
+use serde_json::json;
+
+use linears::api::{ClientError, GraphQLRequest, LinearClient, PageArgs, Paginator, Resource};
+
+fn client(server: &MockServer) -> LinearClient {
+    LinearClient::builder("lin_api_test")
+        .endpoint(&server.url("/graphql"))
+        .build()
+        .unwrap()
+}
+
+fn issues_page(ids: &[&str], end_cursor: Option<&str>) -> serde_json::Value {
+    let nodes: Vec<_> = ids.iter().map(|id| json!({ "id": id })).collect();
+    json!({
+        "data": {
+            "issues": {
+                "nodes": nodes,
+                "pageInfo": { "hasNextPage": end_cursor.is_some(), "endCursor": end_cursor }
+            }
+        }
+    })
+}
+
+/// Test that list().all() follows cursors and applies filter and selection
+#[tokio::test]
+async fn test_list_all_paginates() {
+    let server = MockServer::start_async().await;
+    let first = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/graphql")
+                .body_includes(r#""after":null"#)
+                .body_includes("identifier title")
+                .body_includes(r#""filter":{"team":{"key":{"eq":"ENG"}}}"#);
+            then.status(200).json_body(issues_page(&["a", "b"], Some("c1")));
+        })
+        .await;
+    let second = server
+        .mock_async(|when, then| {
+            when.method(POST).path("/graphql").body_includes(r#""after":"c1""#);
+            then.status(200).json_body(issues_page(&["c"], None));
+        })
+        .await;
+
+    let issues = client(&server)
+        .list(Resource::Issues)
+        .filter(json!({ "team": { "key": { "eq": "ENG" } } }))
+        .select(["id", "identifier", "title"])
+        .all()
+        .await
+        .unwrap();
+
+    let ids: Vec<_> = issues.iter().map(|i| i["id"].as_str().unwrap()).collect();
+    assert_eq!(ids, ["a", "b", "c"]);
+    first.assert_calls_async(1).await;
+    second.assert_calls_async(1).await;
+}
+
+/// Test that a stream stops fetching pages once its limit is reached
+#[tokio::test]
+async fn test_list_stream_limit() {
+    let server = MockServer::start_async().await;
+    let first = server
+        .mock_async(|when, then| {
+            when.method(POST).path("/graphql").body_includes(r#""after":null"#);
+            then.status(200).json_body(issues_page(&["a", "b"], Some("c1")));
+        })
+        .await;
+    let second = server
+        .mock_async(|when, then| {
+            when.method(POST).path("/graphql").body_includes(r#""after":"c1""#);
+            then.status(200).json_body(issues_page(&["c"], None));
+        })
+        .await;
+
+    let client = client(&server);
+    let stream = client.list(Resource::Issues).limit(2).stream();
+    let nodes: Vec<_> = stream.collect().await;
+    assert_eq!(nodes.len(), 2);
+    first.assert_calls_async(1).await;
+    second.assert_calls_async(0).await;
+}
+
+/// Test get and the clap-free error type
+#[tokio::test]
+async fn test_get_and_errors() {
+    let server = MockServer::start_async().await;
+    server
+        .mock_async(|when, then| {
+            when.method(POST).path("/graphql").body_includes("ENG-1");
+            then.status(200)
+                .json_body(json!({ "data": { "issue": { "id": "i1", "identifier": "ENG-1" } } }));
+        })
+        .await;
+    server
+        .mock_async(|when, then| {
+            when.method(POST).path("/graphql").body_includes("ENG-404");
+            then.status(200).json_body(json!({
+                "data": null,
+                "errors": [{ "message": "Entity not found", "path": ["issue"] }]
+            }));
+        })
+        .await;
+
+    let client = client(&server);
+    let issue = client.get(Resource::Issue, "ENG-1").await.unwrap();
+    assert_eq!(issue["identifier"], "ENG-1");
+
+    match client.get(Resource::Issue, "ENG-404").await {
+        Err(ClientError::GraphQL(message, Some(errors))) => {
+            assert!(message.contains("not found"));
+            assert_eq!(errors.len(), 1);
+        }
+        other => panic!("expected a GraphQL error, got {:?}", other.map(|_| ())),
+    }
+}
+
+/// Test that a Paginator follows a nested connection with a custom query
+#[tokio::test]
+async fn test_paginator_nested_connection() {
+    let server = MockServer::start_async().await;
+    let team_issues = |ids: &[&str], cursor: Option<&str>| {
+        let page = issues_page(ids, cursor);
+        json!({ "data": { "team": page["data"].clone() } })
+    };
+    let first = team_issues(&["a"], Some("c1"));
+    let second = team_issues(&["b"], None);
+    server
+        .mock_async(|when, then| {
+            when.method(POST).path("/graphql").body_excludes(r#""after""#);
+            then.status(200).json_body(first);
+        })
+        .await;
+    server
+        .mock_async(|when, then| {
+            when.method(POST).path("/graphql").body_includes(r#""after":"c1""#);
+            then.status(200).json_body(second);
+        })
+        .await;
+
+    let client = client(&server);
+    let nodes = Paginator::new(client.client(), "team.issues", |args: &PageArgs| {
+        let mut variables = json!({ "team": "ENG" });
+        args.apply(&mut variables);
+        GraphQLRequest {
+            query: "query($team: String!, $first: Int, $after: String) { team(id: $team) { issues(first: $first, after: $after) { nodes { id } pageInfo { hasNextPage endCursor } } } }".to_string(),
+            variables: Some(variables),
+            operation_name: None,
+        }
+    })
+    .page_size(1)
+    .all()
+    .await
+    .unwrap();
+
+    let ids: Vec<_> = nodes.iter().map(|n| n["id"].as_str().unwrap()).collect();
+    assert_eq!(ids, ["a", "b"]);
+}
+
+/// Test typed models: nodes deserialize into `Issue`, inputs serialize to camelCase
+#[cfg(feature = "models")]
+#[tokio::test]
+async fn test_typed_models() {
+    use linears::api::models::{Issue, IssueCreateInput};
+
+    let server = MockServer::start_async().await;
+    server
+        .mock_async(|when, then| {
+            when.method(POST).path("/graphql").body_includes("issues(");
+            then.status(200).json_body(json!({
+                "data": { "issues": {
+                    "nodes": [{ "id": "a", "identifier": "ENG-1", "createdAt": "2025-01-10T12:00:00.000Z",
+                                "team": { "key": "ENG" } }],
+                    "pageInfo": { "hasNextPage": false, "endCursor": null }
+                } }
+            }));
+        })
+        .await;
+    let create = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/graphql")
+                .body_includes(r#""input":{"teamId":"t1","title":"Typed"}"#);
+            then.status(200).json_body(json!({
+                "data": { "issueCreate": { "success": true, "issue": { "id": "b" } } }
+            }));
+        })
+        .await;
+
+    let client = client(&server);
+    let issues: Vec<Issue> = client.list(Resource::Issues).all_as().await.unwrap();
+    assert_eq!(issues[0].identifier.as_deref(), Some("ENG-1"));
+    assert_eq!(issues[0].team.as_ref().unwrap().key.as_deref(), Some("ENG"));
+    assert!(issues[0].title.is_none());
+
+    let input = IssueCreateInput {
+        title: Some("Typed".to_string()),
+        ..IssueCreateInput::new("t1".to_string())
+    };
+    let created = client.create(Resource::Issue, &input).await.unwrap();
+    assert_eq!(created["id"], "b");
+    create.assert_async().await;
+}
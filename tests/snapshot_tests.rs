@@ -112,6 +112,7 @@ fn default_list_options() -> ListOptions {
         last: None,
         before: None,
         all: false,
+        parallel: None,
         include_archived: false,
         order_by: None,
         filter: None,
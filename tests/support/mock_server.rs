@@ -82,6 +82,30 @@ pub fn mock_server_error<'a>(server: &'a MockServer, status_code: u16) -> httpmo
     })
 }
 
+/// Create a mock for rateLimitStatus that also sends budget headers
+pub fn mock_rate_limit_status<'a>(server: &'a MockServer, requests_remaining: u64) -> httpmock::Mock<'a> {
+    server.mock(|when, then| {
+        when.method(POST)
+            .path("/graphql")
+            .body_includes("RateLimitStatus");
+        then.status(200)
+            .header("content-type", "application/json")
+            .header("X-RateLimit-Requests-Limit", "1500")
+            .header("X-RateLimit-Requests-Remaining", requests_remaining.to_string())
+            .header("X-RateLimit-Requests-Reset", "1736500000000")
+            .header("X-Complexity", "12")
+            .json_body(serde_json::json!({
+                "data": {
+                    "rateLimitStatus": {
+                        "identifier": "api-key",
+                        "kind": "api",
+                        "limits": []
+                    }
+                }
+            }));
+    })
+}
+
 /// Create a mock for listing issues
 pub fn mock_list_issues<'a>(server: &'a MockServer, issues: Vec<Value>) -> httpmock::Mock<'a> {
     mock_graphql_success(
@@ -141,11 +165,59 @@ pub fn mock_list_users<'a>(server: &'a MockServer, users: Vec<Value>) -> httpmoc
     )
 }
 
+/// Create a mock for the fileUpload mutation, pointing the signed URL at `/upload`
+pub fn mock_file_upload<'a>(server: &'a MockServer, asset_url: &str) -> httpmock::Mock<'a> {
+    let upload_url = format!("{}/upload", server.base_url());
+    server.mock(|when, then| {
+        when.method(POST).path("/graphql").body_includes("fileUpload");
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(serde_json::json!({
+                "data": {
+                    "fileUpload": {
+                        "success": true,
+                        "uploadFile": {
+                            "uploadUrl": upload_url,
+                            "assetUrl": asset_url,
+                            "contentType": "text/plain",
+                            "filename": "upload.log",
+                            "size": 5,
+                            "headers": [{ "key": "x-goog-meta-test", "value": "signed" }]
+                        }
+                    }
+                }
+            }));
+    })
+}
+
+/// Create a mock for the signed upload PUT endpoint
+pub fn mock_upload_put<'a>(server: &'a MockServer) -> httpmock::Mock<'a> {
+    server.mock(|when, then| {
+        when.method(PUT)
+            .path("/upload")
+            .header("x-goog-meta-test", "signed")
+            .header_missing("authorization");
+        then.status(200);
+    })
+}
+
 /// Helper to run CLI with mock server
 pub fn cli_with_mock_server(server: &MockServer) -> assert_cmd::Command {
     let mut cmd = assert_cmd::Command::cargo_bin("linears").unwrap();
     cmd.env("LINEARS_API_KEY", TEST_API_KEY);
     cmd.env("LINEARS_ENDPOINT", format!("{}/graphql", server.base_url()));
+    // Keep the undo journal out of the real state directory
+    cmd.env(
+        "XDG_STATE_HOME",
+        std::env::temp_dir().join(format!("linears-test-state-{}", std::process::id())),
+    );
+    // Mock servers are pooled and reuse ports, so each command gets a fresh response cache
+    static CACHE_DIRS: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
+    let n = CACHE_DIRS.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+    cmd.env(
+        "XDG_CACHE_HOME",
+        std::env::temp_dir().join(format!("linears-test-cache-{}-{}", std::process::id(), n)),
+    );
     cmd
 }
 
@@ -8,7 +8,7 @@ use clap::{Parser, Subcommand};
 use serde::Deserialize;
 use std::collections::HashMap;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 const SCHEMA_URL: &str =
     "https://raw.githubusercontent.com/linear/linear/master/packages/sdk/src/schema.graphql";
@@ -245,6 +245,12 @@ fn run_codegen() -> Result<()> {
     // Generate validation schema (filter keys per resource)
     generate_validation_schema(&ast, &generated_dir)?;
 
+    // Generate field types (for complexity estimation)
+    generate_field_types(&ast, &generated_dir)?;
+
+    // Generate typed models (behind the `models` feature)
+    generate_models(&ast, &generated_dir)?;
+
     // Generate mod.rs
     generate_mod_rs(&generated_dir)?;
 
@@ -321,11 +327,10 @@ fn generate_resources(
         r#"//! Generated resource types - DO NOT EDIT
 //! Run `cargo xtask codegen` to regenerate
 
-use clap::ValueEnum;
-
 /// Available query resources derived from Linear's GraphQL schema
-#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
-#[value(rename_all = "camelCase")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
+#[cfg_attr(feature = "cli", value(rename_all = "camelCase"))]
 pub enum Resource {
 "#,
     );
@@ -334,7 +339,7 @@ pub enum Resource {
         let variant = to_pascal_case(resource);
         code.push_str(&format!("    /// Query {}\n", resource));
         code.push_str(&format!(
-            "    #[value(name = \"{}\")]\n    {},\n",
+            "    #[cfg_attr(feature = \"cli\", value(name = \"{}\"))]\n    {},\n",
             resource, variant
         ));
     }
@@ -440,11 +445,10 @@ fn generate_mutation_ops(
         r#"//! Generated mutation operations - DO NOT EDIT
 //! Run `cargo xtask codegen` to regenerate
 
-use clap::ValueEnum;
-
 /// Available mutation operations derived from Linear's GraphQL schema
-#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
-#[value(rename_all = "camelCase")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
+#[cfg_attr(feature = "cli", value(rename_all = "camelCase"))]
 pub enum MutationOp {
 "#,
     );
@@ -453,7 +457,7 @@ pub enum MutationOp {
         let variant = to_pascal_case(op);
         code.push_str(&format!("    /// Execute {} mutation\n", op));
         code.push_str(&format!(
-            "    #[value(name = \"{}\")]\n    {},\n",
+            "    #[cfg_attr(feature = \"cli\", value(name = \"{}\"))]\n    {},\n",
             op, variant
         ));
     }
@@ -1293,11 +1297,11 @@ fn generate_order_by(
         r#"//! Generated order_by enum - DO NOT EDIT
 //! Run `cargo xtask codegen` to regenerate
 
-use clap::ValueEnum;
 use std::fmt;
 
 /// Pagination order by field
-#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
 pub enum OrderBy {
 "#,
     );
@@ -1546,10 +1550,495 @@ fn levenshtein_distance(a: &str, b: &str) -> usize {
     Ok(())
 }
 
+/// Output of one model file: the `models/` module is split by definition kind
+struct ModelFile {
+    name: &'static str,
+    doc: &'static str,
+    code: String,
+}
+
+fn generate_models(
+    ast: &graphql_parser::schema::Document<String>,
+    output_dir: &PathBuf,
+) -> Result<()> {
+    use graphql_parser::schema::{Definition, TypeDefinition};
+
+    let models_dir = output_dir.join("models");
+    fs::create_dir_all(&models_dir)?;
+
+    // Classify every named type so fields know how to refer to it
+    let mut kinds: HashMap<String, ModelKind> = HashMap::new();
+    for def in &ast.definitions {
+        if let Definition::TypeDefinition(type_def) = def {
+            let (name, kind) = match type_def {
+                TypeDefinition::Scalar(s) => (&s.name, ModelKind::Scalar),
+                TypeDefinition::Enum(e) => (&e.name, ModelKind::Enum),
+                TypeDefinition::Object(o) => (&o.name, ModelKind::Object),
+                TypeDefinition::Interface(i) => (&i.name, ModelKind::Object),
+                TypeDefinition::Union(u) => (&u.name, ModelKind::Scalar),
+                TypeDefinition::InputObject(i) => (&i.name, ModelKind::Input),
+            };
+            kinds.insert(name.clone(), kind);
+        }
+    }
+
+    let mut scalars = Vec::new();
+    let mut enums = Vec::new();
+    let mut objects = Vec::new();
+    let mut inputs = Vec::new();
+    for def in &ast.definitions {
+        if let Definition::TypeDefinition(type_def) = def {
+            match type_def {
+                TypeDefinition::Scalar(s) => scalars.push((s.name.clone(), s.description.clone(), None)),
+                TypeDefinition::Union(u) => scalars.push((
+                    u.name.clone(),
+                    u.description.clone(),
+                    Some(u.types.join(", ")),
+                )),
+                TypeDefinition::Enum(e) => enums.push(e),
+                TypeDefinition::Object(o) => {
+                    objects.push((&o.name, &o.description, &o.fields));
+                }
+                TypeDefinition::Interface(i) => {
+                    objects.push((&i.name, &i.description, &i.fields));
+                }
+                TypeDefinition::InputObject(i) => inputs.push(i),
+            }
+        }
+    }
+    scalars.sort_by(|a, b| a.0.cmp(&b.0));
+    enums.sort_by(|a, b| a.name.cmp(&b.name));
+    objects.sort_by(|a, b| a.0.cmp(b.0));
+    inputs.sort_by(|a, b| a.name.cmp(&b.name));
+
+    // scalars.rs: custom scalars and unions as aliases
+    let mut scalar_code = String::new();
+    for (name, description, members) in &scalars {
+        scalar_code.push_str(&model_doc(description.as_deref(), ""));
+        if let Some(members) = members {
+            scalar_code.push_str(&format!("///\n/// Union of {}; kept as raw JSON\n", members));
+        }
+        scalar_code.push_str(&format!("pub type {} = {};\n\n", name, scalar_rust_type(name)));
+    }
+
+    // enums.rs: unit enums with the GraphQL values as serde names
+    let mut enum_code = String::from("use serde::{Deserialize, Serialize};\n\n");
+    for enum_def in &enums {
+        enum_code.push_str(&model_doc(enum_def.description.as_deref(), ""));
+        enum_code.push_str(
+            "#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]\n",
+        );
+        enum_code.push_str(&format!("pub enum {} {{\n", enum_def.name));
+        let mut variants = std::collections::HashSet::new();
+        for value in &enum_def.values {
+            let variant = enum_variant_name(&value.name);
+            if !variants.insert(variant.clone()) {
+                continue;
+            }
+            enum_code.push_str(&model_doc(value.description.as_deref(), "    "));
+            if variant != value.name {
+                enum_code.push_str(&format!("    #[serde(rename = \"{}\")]\n", value.name));
+            }
+            enum_code.push_str(&format!("    {},\n", variant));
+        }
+        // Linear adds enum values without notice; keep deserialization working
+        enum_code.push_str("    /// A value added to the schema after this code was generated\n");
+        enum_code.push_str("    #[serde(other)]\n    Unknown,\n}\n\n");
+    }
+
+    // objects.rs: every field optional, since queries select a subset
+    let mut object_code = String::from(
+        "use serde::{Deserialize, Serialize};\n\nuse super::enums::*;\nuse super::scalars::*;\n\n",
+    );
+    for (name, description, fields) in &objects {
+        object_code.push_str(&model_doc(description.as_deref(), ""));
+        object_code.push_str(
+            "#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]\n#[serde(rename_all = \"camelCase\")]\n",
+        );
+        object_code.push_str(&format!("pub struct {} {{\n", name));
+        for field in fields.iter() {
+            let ty = model_field_type(&field.field_type, &kinds, true);
+            object_code.push_str(&model_doc(field.description.as_deref(), "    "));
+            object_code.push_str(&model_field_attrs(&field.name, true));
+            object_code.push_str(&format!("    pub {}: {},\n", field_ident(&field.name), ty));
+        }
+        object_code.push_str("}\n\n");
+    }
+
+    // inputs.rs: required fields stay required, so missing ones fail to compile
+    let mut input_code = String::from(
+        "use serde::{Deserialize, Serialize};\n\nuse super::enums::*;\nuse super::scalars::*;\n\n",
+    );
+    for input in &inputs {
+        let required: Vec<_> = input
+            .fields
+            .iter()
+            .filter(|f| matches!(f.value_type, graphql_parser::schema::Type::NonNullType(_)))
+            .collect();
+        let derive_default = if required.is_empty() { "Default, " } else { "" };
+        input_code.push_str(&model_doc(input.description.as_deref(), ""));
+        input_code.push_str(&format!(
+            "#[derive(Debug, Clone, {}PartialEq, Serialize, Deserialize)]\n#[serde(rename_all = \"camelCase\")]\n",
+            derive_default
+        ));
+        input_code.push_str(&format!("pub struct {} {{\n", input.name));
+        for field in &input.fields {
+            let optional = !matches!(field.value_type, graphql_parser::schema::Type::NonNullType(_));
+            let ty = model_field_type(&field.value_type, &kinds, optional);
+            input_code.push_str(&model_doc(field.description.as_deref(), "    "));
+            input_code.push_str(&model_field_attrs(&field.name, optional));
+            input_code.push_str(&format!("    pub {}: {},\n", field_ident(&field.name), ty));
+        }
+        input_code.push_str("}\n\n");
+
+        if !required.is_empty() {
+            let params: Vec<String> = required
+                .iter()
+                .map(|f| {
+                    format!(
+                        "{}: {}",
+                        field_ident(&f.name),
+                        model_field_type(&f.value_type, &kinds, false)
+                    )
+                })
+                .collect();
+            input_code.push_str(&format!("impl {} {{\n", input.name));
+            input_code.push_str("    /// Input with the required fields set and every optional field unset\n");
+            if required.len() > 7 {
+                input_code.push_str("    #[allow(clippy::too_many_arguments)]\n");
+            }
+            input_code.push_str(&format!("    pub fn new({}) -> Self {{\n        Self {{\n", params.join(", ")));
+            for field in &input.fields {
+                let ident = field_ident(&field.name);
+                if matches!(field.value_type, graphql_parser::schema::Type::NonNullType(_)) {
+                    input_code.push_str(&format!("            {},\n", ident));
+                } else {
+                    input_code.push_str(&format!("            {}: None,\n", ident));
+                }
+            }
+            input_code.push_str("        }\n    }\n}\n\n");
+        }
+    }
+
+    let files = [
+        ModelFile {
+            name: "scalars",
+            doc: "Custom scalars and unions",
+            code: scalar_code,
+        },
+        ModelFile {
+            name: "enums",
+            doc: "Enums",
+            code: enum_code,
+        },
+        ModelFile {
+            name: "objects",
+            doc: "Object and interface types",
+            code: object_code,
+        },
+        ModelFile {
+            name: "inputs",
+            doc: "Input types",
+            code: input_code,
+        },
+    ];
+    for file in &files {
+        let code = format!(
+            "//! Generated models: {} - DO NOT EDIT\n//! Run `cargo xtask codegen` to regenerate\n\n{}",
+            file.doc.to_lowercase(),
+            file.code.trim_end()
+        );
+        fs::write(models_dir.join(format!("{}.rs", file.name)), code + "\n")?;
+    }
+
+    let mod_code = r#"//! Generated models - DO NOT EDIT
+//! Run `cargo xtask codegen` to regenerate
+//!
+//! Serde types for the schema's objects, inputs and enums. Object fields are
+//! all optional because a query only returns the fields it selects; input
+//! fields are optional only where the schema allows it.
+
+mod enums;
+mod inputs;
+mod objects;
+mod scalars;
+
+pub use enums::*;
+pub use inputs::*;
+pub use objects::*;
+pub use scalars::*;
+"#;
+    fs::write(models_dir.join("mod.rs"), mod_code)?;
+    println!(
+        "  Generated models/ ({} objects, {} inputs, {} enums)",
+        objects.len(),
+        inputs.len(),
+        enums.len()
+    );
+
+    Ok(())
+}
+
+/// How a named GraphQL type is referenced from a model field
+#[derive(Clone, Copy, PartialEq)]
+enum ModelKind {
+    Scalar,
+    Enum,
+    Object,
+    Input,
+}
+
+/// Rust type behind a scalar name (custom scalars are aliases in `scalars.rs`)
+fn scalar_rust_type(name: &str) -> &'static str {
+    match name {
+        "DateTime" => "chrono::DateTime<chrono::Utc>",
+        "TimelessDate" => "chrono::NaiveDate",
+        "JSON" | "JSONObject" => "serde_json::Value",
+        _ if name.ends_with("NotificationType") => "String",
+        "UUID" | "Duration" | "DateTimeOrDuration" | "TimelessDateOrDuration" => "String",
+        // Unions
+        _ => "serde_json::Value",
+    }
+}
+
+/// Rust type of a field; `optional` wraps it in `Option`
+fn model_field_type(
+    ty: &graphql_parser::schema::Type<String>,
+    kinds: &HashMap<String, ModelKind>,
+    optional: bool,
+) -> String {
+    use graphql_parser::schema::Type;
+
+    let inner = match ty {
+        Type::NonNullType(inner) => model_inner_type(inner, kinds, true),
+        _ => model_inner_type(ty, kinds, true),
+    };
+    if optional {
+        format!("Option<{}>", inner)
+    } else {
+        inner
+    }
+}
+
+/// Type without its outer nullability; nested structs are boxed to allow recursion
+fn model_inner_type(
+    ty: &graphql_parser::schema::Type<String>,
+    kinds: &HashMap<String, ModelKind>,
+    boxed: bool,
+) -> String {
+    use graphql_parser::schema::Type;
+
+    match ty {
+        Type::NonNullType(inner) => model_inner_type(inner, kinds, boxed),
+        Type::ListType(element) => {
+            let element_type = match element.as_ref() {
+                Type::NonNullType(inner) => model_inner_type(inner, kinds, false),
+                other => format!("Option<{}>", model_inner_type(other, kinds, false)),
+            };
+            format!("Vec<{}>", element_type)
+        }
+        Type::NamedType(name) => {
+            let base = match name.as_str() {
+                "ID" | "String" => "String".to_string(),
+                "Int" => "i64".to_string(),
+                "Float" => "f64".to_string(),
+                "Boolean" => "bool".to_string(),
+                other => other.to_string(),
+            };
+            match kinds.get(name) {
+                Some(ModelKind::Object) | Some(ModelKind::Input) if boxed => format!("Box<{}>", base),
+                _ => base,
+            }
+        }
+    }
+}
+
+/// Serde attributes of a field: skip unset optionals, rename where camelCase differs
+fn model_field_attrs(name: &str, optional: bool) -> String {
+    let mut attrs = Vec::new();
+    if snake_to_camel(&to_snake_case(name)) != name {
+        attrs.push(format!("rename = \"{}\"", name));
+    }
+    if optional {
+        attrs.push("default".to_string());
+        attrs.push("skip_serializing_if = \"Option::is_none\"".to_string());
+    }
+    if attrs.is_empty() {
+        String::new()
+    } else {
+        format!("    #[serde({})]\n", attrs.join(", "))
+    }
+}
+
+/// Doc comment from the first line of a GraphQL description
+fn model_doc(description: Option<&str>, indent: &str) -> String {
+    match description.and_then(|d| d.lines().map(str::trim).find(|l| !l.is_empty())) {
+        Some(line) => format!("{}/// {}\n", indent, line.replace("[", "\\[").replace("]", "\\]")),
+        None => String::new(),
+    }
+}
+
+/// Rust field name for a GraphQL field, escaping keywords
+fn field_ident(name: &str) -> String {
+    const KEYWORDS: &[&str] = &[
+        "as", "async", "await", "break", "const", "continue", "dyn", "else", "enum", "extern",
+        "false", "fn", "for", "gen", "if", "impl", "in", "let", "loop", "match", "mod", "move",
+        "mut", "pub", "ref", "return", "static", "struct", "trait", "true", "try", "type",
+        "unsafe", "use", "where", "while", "abstract", "become", "box", "do", "final", "macro",
+        "override", "priv", "typeof", "unsized", "virtual", "yield",
+    ];
+    let snake = to_snake_case(name);
+    if matches!(snake.as_str(), "self" | "super" | "crate") {
+        format!("{}_", snake)
+    } else if KEYWORDS.contains(&snake.as_str()) {
+        format!("r#{}", snake)
+    } else {
+        snake
+    }
+}
+
+/// Enum variant for a GraphQL value (`SCREAMING_CASE` and `camelCase` alike)
+fn enum_variant_name(value: &str) -> String {
+    if value.chars().any(|c| c.is_ascii_lowercase()) {
+        to_pascal_case(value)
+    } else {
+        to_pascal_case(&value.to_ascii_lowercase())
+    }
+}
+
+/// `iconURL` -> `icon_url`, `slugId` -> `slug_id`
+fn to_snake_case(s: &str) -> String {
+    let chars: Vec<char> = s.chars().collect();
+    let mut result = String::new();
+    for (i, &c) in chars.iter().enumerate() {
+        if c.is_ascii_uppercase() {
+            let prev_lower = i > 0 && (chars[i - 1].is_ascii_lowercase() || chars[i - 1].is_ascii_digit());
+            let acronym_end = i > 0
+                && chars[i - 1].is_ascii_uppercase()
+                && chars.get(i + 1).is_some_and(|n| n.is_ascii_lowercase());
+            if prev_lower || acronym_end {
+                result.push('_');
+            }
+            result.push(c.to_ascii_lowercase());
+        } else {
+            result.push(c);
+        }
+    }
+    result
+}
+
+/// The camelCase serde derives from a snake_case field name
+fn snake_to_camel(s: &str) -> String {
+    let mut result = String::new();
+    let mut capitalize_next = false;
+    for c in s.chars() {
+        if c == '_' {
+            capitalize_next = true;
+        } else if capitalize_next {
+            result.push(c.to_ascii_uppercase());
+            capitalize_next = false;
+        } else {
+            result.push(c);
+        }
+    }
+    result
+}
+
+fn generate_field_types(
+    ast: &graphql_parser::schema::Document<String>,
+    output_dir: &Path,
+) -> Result<()> {
+    use graphql_parser::schema::{Definition, Field, TypeDefinition};
+
+    // Leaf types: built-in and custom scalars, and enums
+    let mut leaf_types: Vec<&str> = vec!["ID", "String", "Int", "Float", "Boolean"];
+    // Connection types: objects paginated through `nodes`/`edges`
+    let mut connection_types: Vec<&str> = Vec::new();
+    let mut fields_by_type: Vec<(&str, &[Field<String>])> = Vec::new();
+    for def in &ast.definitions {
+        match def {
+            Definition::TypeDefinition(TypeDefinition::Scalar(scalar)) => leaf_types.push(&scalar.name),
+            Definition::TypeDefinition(TypeDefinition::Enum(enum_def)) => leaf_types.push(&enum_def.name),
+            Definition::TypeDefinition(TypeDefinition::Object(obj)) => {
+                if obj.name.ends_with("Connection") && obj.fields.iter().any(|f| f.name == "nodes") {
+                    connection_types.push(&obj.name);
+                }
+                fields_by_type.push((&obj.name, &obj.fields));
+            }
+            Definition::TypeDefinition(TypeDefinition::Interface(iface)) => {
+                fields_by_type.push((&iface.name, &iface.fields));
+            }
+            _ => {}
+        }
+    }
+
+    let mut entries: Vec<(String, String, &str, String)> = Vec::new();
+    for (type_name, fields) in fields_by_type {
+        for field in fields {
+            let (named_type, _) = extract_type_info(&field.field_type);
+            let kind = if leaf_types.contains(&named_type.as_str()) {
+                "Scalar"
+            } else if connection_types.contains(&named_type.as_str()) {
+                "Connection"
+            } else {
+                "Object"
+            };
+            entries.push((type_name.to_string(), field.name.clone(), kind, named_type));
+        }
+    }
+    entries.sort();
+
+    let mut code = String::from(
+        r#"//! Generated field type registry - DO NOT EDIT
+//! Run `cargo xtask codegen` to regenerate
+
+/// Shape of a field's value
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldKind {
+    /// Scalar or enum value
+    Scalar,
+    /// Object, interface or union value (single or list)
+    Object,
+    /// Paginated connection (`nodes`/`edges` repeat per page entry)
+    Connection,
+}
+
+/// (parent type, field, kind, named field type), sorted by parent type then field
+static FIELD_TYPES: &[(&str, &str, FieldKind, &str)] = &[
+"#,
+    );
+    for (type_name, field, kind, named_type) in &entries {
+        code.push_str(&format!(
+            "    (\"{}\", \"{}\", FieldKind::{}, \"{}\"),\n",
+            type_name, field, kind, named_type
+        ));
+    }
+    code.push_str(
+        r#"];
+
+/// Kind and named type of a field on an object or interface type
+pub fn get_field_type(parent_type: &str, field: &str) -> Option<(FieldKind, &'static str)> {
+    FIELD_TYPES
+        .binary_search_by(|(p, f, _, _)| (*p, *f).cmp(&(parent_type, field)))
+        .ok()
+        .map(|i| (FIELD_TYPES[i].2, FIELD_TYPES[i].3))
+}
+"#,
+    );
+
+    fs::write(output_dir.join("field_types.rs"), code)?;
+    println!("  Generated field_types.rs ({} fields)", entries.len());
+
+    Ok(())
+}
+
 fn generate_mod_rs(output_dir: &PathBuf) -> Result<()> {
     let code = r#"//! Generated code - DO NOT EDIT
 //! Run `cargo xtask codegen` to regenerate
 
+mod field_types;
+#[cfg(feature = "models")]
+pub mod models;
 mod mutation_ops;
 mod mutation_registry;
 mod order_by;
@@ -1558,6 +2047,7 @@ mod resources;
 mod search_plan;
 mod validation_schema;
 
+pub use field_types::{get_field_type, FieldKind};
 pub use mutation_ops::MutationOp;
 pub use mutation_registry::get_mutation_result_fields;
 pub use order_by::OrderBy;